@@ -0,0 +1,4 @@
+//! Conversions between common data elements and the controlled vocabularies
+//! of other systems.
+
+pub mod sra;
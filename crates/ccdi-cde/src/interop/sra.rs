@@ -0,0 +1,402 @@
+//! Conversions between [`LibraryStrategy`] and the experiment strategies used
+//! by the Sequence Read Archive (SRA) and the European Nucleotide Archive
+//! (ENA).
+//!
+//! The `library_strategy` controlled vocabulary of the SRA mostly (but not
+//! exactly) overlaps the permissible values of [`LibraryStrategy`]: some
+//! values differ only in casing (e.g., `ATAC-seq` and `ATAC-Seq`), some have
+//! no equivalent within the common data element, and some permissible values
+//! have no equivalent within the SRA.
+//!
+//! * [`LibraryStrategy::from_sra()`] maps an SRA value to a permissible value.
+//!   SRA values are matched without regard to case or surrounding whitespace,
+//!   and values without an equivalent are returned as an [`UnmappedStrategy`].
+//! * [`LibraryStrategy::to_sra()`] maps a permissible value to the SRA value
+//!   that most closely describes it. Every permissible value has such a value.
+//!
+//! ```
+//! use ccdi_cde as cde;
+//!
+//! use cde::v1::sample::LibraryStrategy;
+//!
+//! let strategy = LibraryStrategy::from_sra("atac-seq")?;
+//! assert_eq!(strategy, LibraryStrategy::AtacSeq);
+//! assert_eq!(strategy.to_sra(), "ATAC-seq");
+//!
+//! let err = LibraryStrategy::from_sra("Ribo-Seq").unwrap_err();
+//! assert_eq!(err.value(), "Ribo-Seq");
+//!
+//! # Ok::<(), Box<dyn std::error::Error>>(())
+//! ```
+//!
+//! ### Mapping
+//!
+//! The table below is the same data that is used for the conversions at
+//! runtime. The direction of each row is one of the following:
+//!
+//! * `both`: the SRA value maps to the permissible value and vice versa.
+//! * `from SRA`: the SRA value is a synonym that maps to the permissible value,
+//!   but the permissible value maps to a different SRA value.
+//! * `to SRA`: the permissible value has no equivalent within the SRA, so it
+//!   maps to the SRA value that most closely describes it (but not vice versa).
+//! * `unmapped`: the SRA value has no equivalent permissible value.
+#![doc = include_str!("../../sra.md")]
+
+use lazy_static::lazy_static;
+
+use crate::v1::sample::LibraryStrategy;
+
+/// The mapping table between SRA values and permissible values.
+pub const TABLE: &str = include_str!("../../sra.md");
+
+lazy_static! {
+    // SAFETY: the table is tested to parse successfully below.
+    static ref ROWS: Vec<Row> = parse(TABLE).unwrap();
+}
+
+/// The direction in which a [`Row`] of the mapping table applies.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum Direction {
+    /// The row applies when mapping from the SRA and when mapping to the SRA.
+    Both,
+
+    /// The row only applies when mapping from the SRA.
+    FromSra,
+
+    /// The row only applies when mapping to the SRA.
+    ToSra,
+
+    /// The SRA value has no equivalent permissible value.
+    Unmapped,
+}
+
+impl Direction {
+    /// Gets whether the direction applies when mapping from the SRA.
+    fn applies_from_sra(&self) -> bool {
+        matches!(self, Direction::Both | Direction::FromSra)
+    }
+
+    /// Gets whether the direction applies when mapping to the SRA.
+    fn applies_to_sra(&self) -> bool {
+        matches!(self, Direction::Both | Direction::ToSra)
+    }
+}
+
+/// A row of the mapping table.
+#[derive(Debug)]
+struct Row {
+    /// The SRA value.
+    sra: &'static str,
+
+    /// The permissible value (if the SRA value has an equivalent).
+    ccdi: Option<LibraryStrategy>,
+
+    /// The direction in which the row applies.
+    direction: Direction,
+}
+
+/// Parses the rows of the mapping table.
+///
+/// Every line of the table that starts with a backticked SRA value is a row.
+/// An error is returned (describing the offending line) if any row is
+/// malformed.
+fn parse(table: &'static str) -> Result<Vec<Row>, String> {
+    table
+        .lines()
+        .filter(|line| line.starts_with("| `"))
+        .map(|line| {
+            let cells = line
+                .trim_matches('|')
+                .split('|')
+                .map(|cell| cell.trim())
+                .collect::<Vec<_>>();
+
+            let (sra, ccdi, direction) = match cells.as_slice() {
+                [sra, ccdi, direction] => (*sra, *ccdi, *direction),
+                _ => return Err(format!("expected three cells: {line}")),
+            };
+
+            let sra = sra
+                .strip_prefix('`')
+                .and_then(|sra| sra.strip_suffix('`'))
+                .ok_or_else(|| format!("the SRA value must be backticked: {line}"))?;
+
+            let direction = match direction {
+                "both" => Direction::Both,
+                "from SRA" => Direction::FromSra,
+                "to SRA" => Direction::ToSra,
+                "unmapped" => Direction::Unmapped,
+                _ => return Err(format!("unknown direction: {line}")),
+            };
+
+            let ccdi = match (ccdi, direction) {
+                ("—", Direction::Unmapped) => None,
+                (_, Direction::Unmapped) => {
+                    return Err(format!(
+                        "an unmapped row must not have a CCDI value: {line}"
+                    ))
+                }
+                (ccdi, _) => Some(
+                    ccdi.strip_prefix('`')
+                        .and_then(|ccdi| ccdi.strip_suffix('`'))
+                        .ok_or_else(|| format!("the CCDI value must be backticked: {line}"))?
+                        .parse::<LibraryStrategy>()
+                        .map_err(|err| format!("{err}: {line}"))?,
+                ),
+            };
+
+            Ok(Row {
+                sra,
+                ccdi,
+                direction,
+            })
+        })
+        .collect()
+}
+
+/// An SRA value that has no equivalent [`LibraryStrategy`].
+#[derive(Debug, Eq, PartialEq)]
+pub struct UnmappedStrategy {
+    /// The SRA value (as it was provided).
+    value: String,
+}
+
+impl UnmappedStrategy {
+    /// Gets the SRA value (as it was provided).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ccdi_cde as cde;
+    ///
+    /// use cde::v1::sample::LibraryStrategy;
+    ///
+    /// let err = LibraryStrategy::from_sra("VALIDATION").unwrap_err();
+    /// assert_eq!(err.value(), "VALIDATION");
+    /// ```
+    pub fn value(&self) -> &str {
+        self.value.as_str()
+    }
+}
+
+impl std::fmt::Display for UnmappedStrategy {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "the SRA library strategy `{}` has no equivalent CCDI library strategy",
+            self.value
+        )
+    }
+}
+
+impl std::error::Error for UnmappedStrategy {}
+
+impl LibraryStrategy {
+    /// Maps an SRA (or ENA) library strategy to a [`LibraryStrategy`].
+    ///
+    /// The value is matched without regard to case or surrounding whitespace.
+    /// If the value has no equivalent (or is not an SRA value at all), an
+    /// [`UnmappedStrategy`] carrying the provided value is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ccdi_cde as cde;
+    ///
+    /// use cde::v1::sample::LibraryStrategy;
+    ///
+    /// assert_eq!(LibraryStrategy::from_sra("WGS")?, LibraryStrategy::Wgs);
+    /// assert_eq!(
+    ///     LibraryStrategy::from_sra("rna-seq")?,
+    ///     LibraryStrategy::RnaSeq
+    /// );
+    /// assert_eq!(LibraryStrategy::from_sra("OTHER")?, LibraryStrategy::Other);
+    /// assert!(LibraryStrategy::from_sra("snRNA-seq").is_err());
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn from_sra(value: &str) -> Result<LibraryStrategy, UnmappedStrategy> {
+        let needle = value.trim();
+
+        ROWS.iter()
+            .filter(|row| row.direction.applies_from_sra())
+            .find(|row| row.sra.eq_ignore_ascii_case(needle))
+            .and_then(|row| row.ccdi.clone())
+            .ok_or_else(|| UnmappedStrategy {
+                value: value.to_string(),
+            })
+    }
+
+    /// Maps the [`LibraryStrategy`] to the SRA (or ENA) library strategy that
+    /// most closely describes it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ccdi_cde as cde;
+    ///
+    /// use cde::v1::sample::LibraryStrategy;
+    ///
+    /// assert_eq!(LibraryStrategy::Wgs.to_sra(), "WGS");
+    /// assert_eq!(LibraryStrategy::Other.to_sra(), "OTHER");
+    /// assert_eq!(LibraryStrategy::SnatacSeq.to_sra(), "ATAC-seq");
+    /// ```
+    pub fn to_sra(&self) -> &'static str {
+        ROWS.iter()
+            .filter(|row| row.direction.applies_to_sra())
+            .find(|row| row.ccdi.as_ref() == Some(self))
+            .map(|row| row.sra)
+            // SAFETY: every permissible value is tested to have exactly one row
+            // that applies when mapping to the SRA below.
+            .unwrap()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::CDE;
+
+    use super::*;
+
+    /// Every value within the `library_strategy` controlled vocabulary of the
+    /// ENA along with the expected permissible value (if any).
+    const ENA: &[(&str, Option<&str>)] = &[
+        ("AMPLICON", Some("AMPLICON")),
+        ("ATAC-seq", Some("ATAC-Seq")),
+        ("Bisulfite-Seq", Some("Bisulfite-Seq")),
+        ("ChIA-PET", Some("ChIA-PET")),
+        ("ChIP-Seq", Some("ChIP-Seq")),
+        ("ChM-Seq", None),
+        ("CLONE", Some("CLONE")),
+        ("CLONEEND", Some("CLONEEND")),
+        ("CTS", Some("CTS")),
+        ("DNase-Hypersensitivity", Some("DNase-Hypersensitivity")),
+        ("EST", Some("EST")),
+        ("FAIRE-seq", Some("FAIRE-seq")),
+        ("FINISHING", Some("FINISHING")),
+        ("FL-cDNA", Some("FL-cDNA")),
+        ("GBS", None),
+        ("Hi-C", Some("Hi-C")),
+        ("MBD-Seq", Some("MBD-Seq")),
+        ("MeDIP-Seq", Some("MeDIP-Seq")),
+        ("miRNA-Seq", Some("miRNA-Seq")),
+        ("MNase-Seq", Some("MNase-Seq")),
+        ("MRE-Seq", Some("MRE-Seq")),
+        ("ncRNA-Seq", Some("ncRNA-Seq")),
+        ("NOMe-Seq", None),
+        ("OTHER", Some("Other")),
+        ("POOLCLONE", Some("POOLCLONE")),
+        ("RAD-Seq", Some("RAD-Seq")),
+        ("Ribo-Seq", None),
+        ("RIP-Seq", Some("RIP-Seq")),
+        ("RNA-Seq", Some("RNA-Seq")),
+        ("SELEX", Some("SELEX")),
+        ("snRNA-seq", None),
+        ("ssRNA-seq", Some("ssRNA-seq")),
+        ("Synthetic-Long-Read", Some("Synthetic-Long-Read")),
+        ("Targeted-Capture", Some("Targeted-Capture")),
+        (
+            "Tethered Chromatin Conformation Capture",
+            Some("Tethered Chromatin Conformation Capture"),
+        ),
+        ("Tn-Seq", Some("Tn-Seq")),
+        ("VALIDATION", None),
+        ("WCS", Some("WCS")),
+        ("WGA", Some("WGA")),
+        ("WGS", Some("WGS")),
+        ("WXS", Some("WXS")),
+    ];
+
+    #[test]
+    fn the_table_parses() {
+        assert!(!ROWS.is_empty());
+    }
+
+    #[test]
+    fn it_maps_every_ena_value() {
+        for (value, expected) in ENA {
+            for value in [
+                value.to_string(),
+                value.to_lowercase(),
+                value.to_uppercase(),
+                format!(" {value}\t"),
+            ] {
+                match expected {
+                    Some(expected) => assert_eq!(
+                        LibraryStrategy::from_sra(&value).unwrap().to_string(),
+                        *expected,
+                        "{value}"
+                    ),
+                    None => {
+                        let err = LibraryStrategy::from_sra(&value).unwrap_err();
+                        assert_eq!(err.value(), value);
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn every_table_row_is_an_ena_value() {
+        for row in ROWS.iter() {
+            assert!(
+                ENA.iter().any(|(value, _)| *value == row.sra),
+                "{}",
+                row.sra
+            );
+        }
+    }
+
+    #[test]
+    fn every_permissible_value_maps_to_exactly_one_sra_value() {
+        for (_, member) in LibraryStrategy::members().unwrap().unwrap() {
+            let strategy = member
+                .get_variant()
+                .unwrap()
+                .permissible_value()
+                .parse::<LibraryStrategy>()
+                .unwrap();
+
+            let rows = ROWS
+                .iter()
+                .filter(|row| {
+                    row.direction.applies_to_sra() && row.ccdi.as_ref() == Some(&strategy)
+                })
+                .count();
+            assert_eq!(rows, 1, "{strategy}");
+
+            let sra = strategy.to_sra();
+            assert!(ENA.iter().any(|(value, _)| *value == sra), "{sra}");
+        }
+    }
+
+    #[test]
+    fn mapped_values_round_trip() {
+        for row in ROWS.iter().filter(|row| row.direction == Direction::Both) {
+            let strategy = LibraryStrategy::from_sra(row.sra).unwrap();
+            assert_eq!(strategy.to_sra(), row.sra);
+        }
+    }
+
+    #[test]
+    fn it_rejects_values_outside_of_the_vocabulary() {
+        for value in ["", "Foo-Seq", "WGS WXS", "DNA-Seq", "snATAC-Seq"] {
+            let err = LibraryStrategy::from_sra(value).unwrap_err();
+            assert_eq!(err.value(), value);
+        }
+
+        assert_eq!(
+            LibraryStrategy::from_sra("GBS").unwrap_err().to_string(),
+            "the SRA library strategy `GBS` has no equivalent CCDI library strategy"
+        );
+    }
+
+    #[test]
+    fn it_rejects_malformed_rows() {
+        assert!(parse("| `WGS` | `WGS` |").is_err());
+        assert!(parse("| `WGS` | `WGS` | sideways |").is_err());
+        assert!(parse("| `WGS` | `Foo-Seq` | both |").is_err());
+        assert!(parse("| `GBS` | `WGS` | unmapped |").is_err());
+        assert!(parse("| `GBS` | — | unmapped |").unwrap()[0].ccdi.is_none());
+    }
+}
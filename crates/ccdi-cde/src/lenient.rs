@@ -0,0 +1,334 @@
+//! Lenient deserialization of common data elements.
+//!
+//! By default, deserializing a common data element fails if the value is not
+//! one of the permissible values known to this crate. This is the desired
+//! behavior for servers, which should never emit a value outside of the
+//! specification.
+//!
+//! Consumers of the API (such as aggregators that combine the responses of
+//! many servers), however, may receive a permissible value that was added to a
+//! common data element before this crate was updated. Rather than failing to
+//! deserialize the entire response, such consumers may opt in to wrapping a
+//! common data element in a [`MaybeKnown`]. Unrecognized values are then
+//! preserved verbatim (and serialized back exactly as they were received),
+//! and they can be collected within a [`Report`] so that curators know that
+//! the common data element needs to be updated.
+//!
+//! ```
+//! use ccdi_cde as cde;
+//!
+//! use cde::lenient::MaybeKnown;
+//! use cde::lenient::Report;
+//! use cde::v1::sample::LibraryStrategy;
+//!
+//! let value = serde_json::from_str::<MaybeKnown<LibraryStrategy>>(r#""Foo-Seq""#)?;
+//! assert_eq!(value.unknown(), Some("Foo-Seq"));
+//!
+//! let mut report = Report::default();
+//! report.check("library_strategy", &value);
+//! assert_eq!(report.unrecognized().len(), 1);
+//!
+//! # Ok::<(), Box<dyn std::error::Error>>(())
+//! ```
+
+use serde::Deserialize;
+use serde::Serialize;
+use utoipa::ToSchema;
+
+use crate::CDE;
+
+/// A value that is either a known permissible value of a common data element
+/// or an unrecognized value that is preserved verbatim.
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize, ToSchema)]
+#[serde(untagged)]
+pub enum MaybeKnown<T> {
+    /// A permissible value that is known to this crate.
+    Known(T),
+
+    /// A value that is not a known permissible value.
+    Unknown(String),
+}
+
+impl<T> MaybeKnown<T> {
+    /// Gets the known permissible value (if the value was recognized).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ccdi_cde as cde;
+    ///
+    /// use cde::lenient::MaybeKnown;
+    /// use cde::v1::subject::Sex;
+    ///
+    /// let value = MaybeKnown::Known(Sex::Female);
+    /// assert_eq!(value.known(), Some(&Sex::Female));
+    ///
+    /// let value = MaybeKnown::<Sex>::Unknown(String::from("Foo"));
+    /// assert_eq!(value.known(), None);
+    /// ```
+    pub fn known(&self) -> Option<&T> {
+        match self {
+            MaybeKnown::Known(value) => Some(value),
+            MaybeKnown::Unknown(_) => None,
+        }
+    }
+
+    /// Gets the unrecognized value (if the value was not recognized).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ccdi_cde as cde;
+    ///
+    /// use cde::lenient::MaybeKnown;
+    /// use cde::v1::subject::Sex;
+    ///
+    /// let value = MaybeKnown::Known(Sex::Female);
+    /// assert_eq!(value.unknown(), None);
+    ///
+    /// let value = MaybeKnown::<Sex>::Unknown(String::from("Foo"));
+    /// assert_eq!(value.unknown(), Some("Foo"));
+    /// ```
+    pub fn unknown(&self) -> Option<&str> {
+        match self {
+            MaybeKnown::Known(_) => None,
+            MaybeKnown::Unknown(value) => Some(value.as_str()),
+        }
+    }
+}
+
+impl<T> From<T> for MaybeKnown<T> {
+    fn from(value: T) -> Self {
+        MaybeKnown::Known(value)
+    }
+}
+
+impl<T: std::fmt::Display> std::fmt::Display for MaybeKnown<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            MaybeKnown::Known(value) => write!(f, "{value}"),
+            MaybeKnown::Unknown(value) => write!(f, "{value}"),
+        }
+    }
+}
+
+/// An unrecognized value encountered during lenient deserialization.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Unrecognized {
+    /// The field within which the value was encountered.
+    field: String,
+
+    /// The standard name of the common data element (if it could be
+    /// determined).
+    standard: Option<String>,
+
+    /// The unrecognized value.
+    value: String,
+}
+
+impl Unrecognized {
+    /// Gets the field within which the value was encountered.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ccdi_cde as cde;
+    ///
+    /// use cde::lenient::MaybeKnown;
+    /// use cde::lenient::Report;
+    /// use cde::v1::subject::Sex;
+    ///
+    /// let mut report = Report::default();
+    /// report.check("sex", &MaybeKnown::<Sex>::Unknown(String::from("Foo")));
+    ///
+    /// assert_eq!(report.unrecognized()[0].field(), "sex");
+    /// ```
+    pub fn field(&self) -> &str {
+        &self.field
+    }
+
+    /// Gets the standard name of the common data element (if it could be
+    /// determined).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ccdi_cde as cde;
+    ///
+    /// use cde::lenient::MaybeKnown;
+    /// use cde::lenient::Report;
+    /// use cde::v1::subject::Sex;
+    ///
+    /// let mut report = Report::default();
+    /// report.check("sex", &MaybeKnown::<Sex>::Unknown(String::from("Foo")));
+    ///
+    /// assert_eq!(
+    ///     report.unrecognized()[0].standard(),
+    ///     Some("caDSR CDE 6343385 v1.00")
+    /// );
+    /// ```
+    pub fn standard(&self) -> Option<&str> {
+        self.standard.as_deref()
+    }
+
+    /// Gets the unrecognized value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ccdi_cde as cde;
+    ///
+    /// use cde::lenient::MaybeKnown;
+    /// use cde::lenient::Report;
+    /// use cde::v1::subject::Sex;
+    ///
+    /// let mut report = Report::default();
+    /// report.check("sex", &MaybeKnown::<Sex>::Unknown(String::from("Foo")));
+    ///
+    /// assert_eq!(report.unrecognized()[0].value(), "Foo");
+    /// ```
+    pub fn value(&self) -> &str {
+        &self.value
+    }
+}
+
+impl std::fmt::Display for Unrecognized {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}: unrecognized value `{}`", self.field, self.value)?;
+
+        if let Some(standard) = &self.standard {
+            write!(f, " for {standard}")?;
+        }
+
+        Ok(())
+    }
+}
+
+/// A report of the unrecognized values encountered during lenient
+/// deserialization.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct Report {
+    /// The unrecognized values.
+    unrecognized: Vec<Unrecognized>,
+}
+
+impl Report {
+    /// Checks a [`MaybeKnown`] value and records it within the [`Report`] if
+    /// the value was not recognized.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ccdi_cde as cde;
+    ///
+    /// use cde::lenient::MaybeKnown;
+    /// use cde::lenient::Report;
+    /// use cde::v1::subject::Sex;
+    ///
+    /// let mut report = Report::default();
+    /// report.check("sex", &MaybeKnown::Known(Sex::Female));
+    /// assert!(report.is_empty());
+    ///
+    /// report.check("sex", &MaybeKnown::<Sex>::Unknown(String::from("Foo")));
+    /// assert!(!report.is_empty());
+    /// ```
+    pub fn check<T: CDE>(&mut self, field: impl Into<String>, value: &MaybeKnown<T>) {
+        if let MaybeKnown::Unknown(value) = value {
+            self.unrecognized.push(Unrecognized {
+                field: field.into(),
+                standard: T::entity()
+                    .ok()
+                    .map(|entity| entity.standard_name().to_owned()),
+                value: value.clone(),
+            });
+        }
+    }
+
+    /// Returns whether the [`Report`] contains no unrecognized values.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ccdi_cde as cde;
+    ///
+    /// use cde::lenient::Report;
+    ///
+    /// assert!(Report::default().is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.unrecognized.is_empty()
+    }
+
+    /// Gets the unrecognized values within the [`Report`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ccdi_cde as cde;
+    ///
+    /// use cde::lenient::Report;
+    ///
+    /// assert!(Report::default().unrecognized().is_empty());
+    /// ```
+    pub fn unrecognized(&self) -> &[Unrecognized] {
+        &self.unrecognized
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use crate::v1::sample::LibraryStrategy;
+
+    use super::*;
+
+    #[derive(Debug, Deserialize, Serialize)]
+    struct Sample {
+        library_strategy: MaybeKnown<LibraryStrategy>,
+    }
+
+    #[test]
+    fn it_deserializes_known_values() {
+        let sample = serde_json::from_value::<Sample>(json!({
+            "library_strategy": "WGS"
+        }))
+        .unwrap();
+
+        assert_eq!(sample.library_strategy.known(), Some(&LibraryStrategy::Wgs));
+    }
+
+    #[test]
+    fn it_round_trips_an_unknown_library_strategy() {
+        let json = r#"{"library_strategy":"Foo-Seq"}"#;
+
+        let sample = serde_json::from_str::<Sample>(json).unwrap();
+        assert_eq!(sample.library_strategy.unknown(), Some("Foo-Seq"));
+        assert_eq!(serde_json::to_string(&sample).unwrap(), json);
+
+        // The strict type still rejects the value.
+        assert!(serde_json::from_str::<LibraryStrategy>(r#""Foo-Seq""#).is_err());
+    }
+
+    #[test]
+    fn it_reports_unknown_values() {
+        let sample = serde_json::from_value::<Sample>(json!({
+            "library_strategy": "Foo-Seq"
+        }))
+        .unwrap();
+
+        let mut report = Report::default();
+        report.check("library_strategy", &sample.library_strategy);
+
+        assert_eq!(report.unrecognized().len(), 1);
+        assert_eq!(
+            report.unrecognized()[0].to_string(),
+            "library_strategy: unrecognized value `Foo-Seq` for caDSR CDE 6273393 v1.00"
+        );
+    }
+
+    #[test]
+    fn it_does_not_accept_values_that_are_not_strings() {
+        assert!(serde_json::from_value::<Sample>(json!({ "library_strategy": 1 })).is_err());
+    }
+}
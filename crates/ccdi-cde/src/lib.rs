@@ -8,16 +8,25 @@
 #![warn(missing_debug_implementations)]
 #![deny(rustdoc::broken_intra_doc_links)]
 
+use chrono::NaiveDate;
+use indexmap::IndexMap;
+use indexmap::IndexSet;
 use introspect::Entity;
 use introspect::Introspected;
 use introspect::Member;
 
 use crate::parse::cde::member;
+use crate::translations::Translations;
 
+pub mod interop;
+pub mod lenient;
 pub mod parse;
+pub mod registry;
+pub mod translations;
 pub mod v1;
 pub mod v2;
 pub mod v4;
+pub mod value;
 
 /// An error related to a [`CDE`].
 #[derive(Debug)]
@@ -99,11 +108,165 @@ pub trait CDE: std::fmt::Display + Eq + PartialEq + Introspected {
             // .map(|member| member.unwrap_or(Err(Error::MissingDocumentation)))
             .collect::<Option<Result<Vec<_>>>>()
     }
+
+    /// Gets the parsed variants of the common data element keyed by their
+    /// identifiers (in declaration order).
+    ///
+    /// Common data elements that are not enums (i.e., those with field members)
+    /// have no variants, so an empty map is returned for them.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ccdi_cde as cde;
+    ///
+    /// use cde::v1::subject::Sex;
+    /// use cde::CDE as _;
+    ///
+    /// let variants = Sex::value_metadata()?;
+    /// assert_eq!(variants.get("Female").unwrap().permissible_value(), "F");
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    fn value_metadata() -> Result<IndexMap<String, member::Variant>> {
+        if let Entity::Struct(_) = Self::introspected_entity() {
+            return Ok(IndexMap::new());
+        }
+
+        let members = Self::members().unwrap_or(Err(Error::MissingDocumentation))?;
+
+        Ok(members
+            .into_iter()
+            .filter_map(|(identifier, member)| match member {
+                crate::parse::cde::Member::Variant(variant) => {
+                    identifier.map(|identifier| (identifier, variant))
+                }
+                crate::parse::cde::Member::Field(_) => None,
+            })
+            .collect())
+    }
+
+    /// Gets the permissible values of the common data element (in declaration
+    /// order).
+    ///
+    /// Common data elements that are not enums (i.e., those with field members)
+    /// have no permissible values, so an empty list is returned for them.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ccdi_cde as cde;
+    ///
+    /// use cde::v1::file::Size;
+    /// use cde::v1::subject::Sex;
+    /// use cde::CDE as _;
+    ///
+    /// assert_eq!(
+    ///     Sex::permissible_values()?,
+    ///     vec!["U", "F", "M", "UNDIFFERENTIATED"]
+    /// );
+    /// assert!(Size::permissible_values()?.is_empty());
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    fn permissible_values() -> Result<Vec<String>> {
+        Ok(Self::value_metadata()?
+            .into_values()
+            .map(|variant| variant.permissible_value().to_string())
+            .collect())
+    }
+
+    /// Gets the permissible values of the common data element that have been
+    /// retired (i.e., those with an `End Date` or `Deprecated` bullet) along
+    /// with the date on which each was retired (in declaration order).
+    ///
+    /// Retired permissible values are still accepted (as existing data may
+    /// still reference them), but they should not be used for new data.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ccdi_cde as cde;
+    ///
+    /// use cde::v1::subject::Sex;
+    /// use cde::CDE as _;
+    ///
+    /// assert!(Sex::deprecated_values()?.is_empty());
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    fn deprecated_values() -> Result<IndexMap<String, NaiveDate>> {
+        Ok(Self::value_metadata()?
+            .into_values()
+            .filter_map(|variant| {
+                variant
+                    .deprecated()
+                    .map(|date| (variant.permissible_value().to_string(), date))
+            })
+            .collect())
+    }
+
+    /// Gets the number of distinct permissible values of the common data
+    /// element.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ccdi_cde as cde;
+    ///
+    /// use cde::v1::subject::Sex;
+    /// use cde::CDE as _;
+    ///
+    /// assert_eq!(Sex::distinct_value_count()?, 4);
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    fn distinct_value_count() -> Result<usize> {
+        Ok(Self::permissible_values()?
+            .into_iter()
+            .collect::<IndexSet<_>>()
+            .len())
+    }
+
+    /// Gets the localized display label for this value of the common data
+    /// element (if a label exists for the provided locale within the provided
+    /// [`Translations`]).
+    ///
+    /// This does not change the value that is sent over the wire—it is only
+    /// intended for display purposes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ccdi_cde as cde;
+    ///
+    /// use cde::translations::Translations;
+    /// use cde::v1::subject::Sex;
+    /// use cde::CDE as _;
+    ///
+    /// let translations = Translations::embedded();
+    ///
+    /// assert_eq!(
+    ///     Sex::Female.localized_label("es", translations),
+    ///     Some("Femenino")
+    /// );
+    /// assert_eq!(Sex::Female.localized_label("de", translations), None);
+    /// ```
+    fn localized_label<'a>(&self, locale: &str, translations: &'a Translations) -> Option<&'a str> {
+        let entity = Self::entity().ok()?;
+        translations.label(entity.standard_name(), &self.to_string(), locale)
+    }
 }
 
 #[cfg(test)]
 mod tests {
+    use introspect::Introspect;
+    use serde::Deserialize;
+
+    use crate::v1::file::Size;
+    use crate::v1::sample::LibraryStrategy;
     use crate::v1::subject::Sex;
+    use crate::v2::sample::LibrarySelectionMethod;
 
     use super::*;
 
@@ -112,6 +275,59 @@ mod tests {
         let entity = Sex::entity().unwrap();
 
         assert_eq!(entity.standard_name(), "caDSR CDE 6343385 v1.00");
+        assert_eq!(entity.cde_id(), 6343385);
+        assert_eq!(entity.version(), (1, 0));
+        assert_eq!(entity.link().host_str(), Some("cadsr.cancer.gov"));
+        assert_eq!(entity.link(), entity.standard_url());
+    }
+
+    /// **`caDSR CDE 6343385A v1.00`**
+    ///
+    /// A fixture with a malformed ID within its standard name.
+    ///
+    /// Link:
+    /// <https://example.com>
+    #[derive(Debug, Deserialize, Eq, PartialEq, Introspect)]
+    struct MalformedId(String);
+
+    impl std::fmt::Display for MalformedId {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            write!(f, "{}", self.0)
+        }
+    }
+
+    impl CDE for MalformedId {}
+
+    /// **`caDSR CDE 6343385 version 1`**
+    ///
+    /// A fixture with a malformed version within its standard name.
+    ///
+    /// Link:
+    /// <https://example.com>
+    #[derive(Debug, Deserialize, Eq, PartialEq, Introspect)]
+    struct MalformedVersion(String);
+
+    impl std::fmt::Display for MalformedVersion {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            write!(f, "{}", self.0)
+        }
+    }
+
+    impl CDE for MalformedVersion {}
+
+    #[test]
+    fn entity_parsing_reports_a_malformed_standard_name() {
+        assert!(matches!(
+            MalformedId::entity(),
+            Err(Error::EntityError(parse::cde::entity::ParseError::InvalidCdeId(id)))
+                if id == "6343385A"
+        ));
+
+        assert!(matches!(
+            MalformedVersion::entity(),
+            Err(Error::EntityError(parse::cde::entity::ParseError::InvalidVersion(version)))
+                if version == "version 1"
+        ));
     }
 
     #[test]
@@ -120,7 +336,13 @@ mod tests {
 
         let (identifer, variant) = entity.next().unwrap();
         assert_eq!(identifer.as_deref(), Some("Unknown"));
-        assert_eq!(variant.get_variant().unwrap().permissible_value(), "U");
+
+        let variant = variant.get_variant().unwrap();
+        assert_eq!(variant.permissible_value(), "U");
+        assert_eq!(variant.vm_long_name(), Some("Unknown"));
+        assert_eq!(variant.vm_public_id(), Some(5682944));
+        assert_eq!(variant.concept_code(), Some("C17998"));
+        assert_eq!(variant.begin_date(), NaiveDate::from_ymd_opt(2018, 6, 27));
 
         let (identifer, variant) = entity.next().unwrap();
         assert_eq!(identifer.as_deref(), Some("Female"));
@@ -137,4 +359,108 @@ mod tests {
             "UNDIFFERENTIATED"
         );
     }
+
+    #[test]
+    fn member_parsing_handles_an_empty_concept_code() {
+        let variants = LibrarySelectionMethod::value_metadata().unwrap();
+
+        let variant = variants.get("RandomPCR").unwrap();
+        assert_eq!(variant.permissible_value(), "Random PCR");
+        assert_eq!(variant.vm_public_id(), Some(6287022));
+        assert_eq!(variant.concept_code(), None);
+        assert_eq!(variant.begin_date(), NaiveDate::from_ymd_opt(2018, 5, 15));
+
+        let variant = variants.get("PCR").unwrap();
+        assert_eq!(variant.concept_code(), Some("C17003"));
+    }
+
+    #[test]
+    fn permissible_values_are_in_declaration_order() {
+        assert_eq!(
+            Sex::permissible_values().unwrap(),
+            vec!["U", "F", "M", "UNDIFFERENTIATED"]
+        );
+        assert_eq!(Sex::distinct_value_count().unwrap(), 4);
+
+        let values = LibraryStrategy::permissible_values().unwrap();
+        assert_eq!(values.len(), 37);
+        assert_eq!(LibraryStrategy::distinct_value_count().unwrap(), 37);
+        assert_eq!(values.first().map(String::as_str), Some("AMPLICON"));
+        assert_eq!(values.last().map(String::as_str), Some("WXS"));
+
+        // NOTE: the permissible values are always in the same order as the
+        // variants themselves.
+        let variants = LibraryStrategy::value_metadata().unwrap();
+        assert_eq!(variants.keys().next().map(String::as_str), Some("Amplicon"));
+        assert_eq!(
+            variants
+                .values()
+                .map(|variant| variant.permissible_value().to_string())
+                .collect::<Vec<_>>(),
+            values
+        );
+    }
+
+    /// **`caDSR CDE 1 v1.00`**
+    ///
+    /// A fixture with a retired permissible value.
+    ///
+    /// Link:
+    /// <https://example.com>
+    #[derive(Debug, Deserialize, Eq, PartialEq, Introspect)]
+    enum Fixture {
+        /// `Current`
+        ///
+        /// * **VM Long Name**: Current
+        /// * **VM Public ID**: 1
+        /// * **Concept Code**: C1
+        /// * **Begin Date**:   01/01/2023
+        ///
+        /// A permissible value that is still in use.
+        Current,
+
+        /// `Retired`
+        ///
+        /// * **VM Long Name**: Retired
+        /// * **VM Public ID**: 2
+        /// * **Concept Code**: C2
+        /// * **Begin Date**:   01/01/2023
+        /// * **End Date**:     01/31/2024
+        ///
+        /// A permissible value that has been retired.
+        Retired,
+    }
+
+    impl std::fmt::Display for Fixture {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            write!(f, "{self:?}")
+        }
+    }
+
+    impl CDE for Fixture {}
+
+    #[test]
+    fn deprecated_values_are_those_with_an_end_date() {
+        let deprecated = Fixture::deprecated_values().unwrap();
+
+        assert_eq!(deprecated.len(), 1);
+        assert_eq!(
+            deprecated.get("Retired"),
+            NaiveDate::from_ymd_opt(2024, 1, 31).as_ref()
+        );
+
+        // NOTE: retired permissible values are still permissible.
+        assert_eq!(
+            Fixture::permissible_values().unwrap(),
+            vec!["Current", "Retired"]
+        );
+        assert!(Sex::deprecated_values().unwrap().is_empty());
+    }
+
+    #[test]
+    fn struct_based_cdes_have_no_permissible_values() {
+        assert!(Size::value_metadata().unwrap().is_empty());
+        assert!(Size::permissible_values().unwrap().is_empty());
+        assert_eq!(Size::distinct_value_count().unwrap(), 0);
+    }
 }
@@ -1,7 +1,198 @@
 //! Parsing information for common data elements.
+//!
+//! Common data elements are described entirely by the documentation on their
+//! Rust types: the documentation of the type describes the _entity_, and the
+//! documentation of each field or variant describes a _member_. That
+//! documentation is parsed at runtime, so it must follow the layouts below
+//! exactly. [`lint::lint()`] checks a common data element against these
+//! layouts (and `ccdi-spec lint-cdes` checks every common data element in
+//! this crate), which is the easiest way to validate a new contribution.
+//!
+//! # Entities
+//!
+//! The documentation of an entity (a `struct` or an `enum`) is made up of
+//! three blocks separated by empty lines:
+//!
+//! 1. the name of the standard in bold code (`` **`STANDARD`** ``), where
+//!    the name of the standard is made up of the numeric ID and the version of
+//!    the common data element within the caDSR (`caDSR CDE <ID>
+//!    v<MAJOR>.<MINOR>`),
+//! 2. a description of the common data element (which may span multiple
+//!    lines), and
+//! 3. a link to the standard (`Link: <URL>`).
+//!
+//! ```
+//! use ccdi_cde as cde;
+//!
+//! use cde::parse::cde::Entity;
+//!
+//! let entity = r#"**`caDSR CDE 6343385 v1.00`**
+//!
+//! Sex of the subject as determined by the investigator.
+//!
+//! Link:
+//! <https://cadsr.cancer.gov/onedata/dmdirect/NIH/NCI/CO/CDEDD?filter=CDEDD.ITEM_ID=6343385%20and%20ver_nr=1>"#
+//!     .parse::<Entity>()?;
+//!
+//! assert_eq!(entity.standard_name(), "caDSR CDE 6343385 v1.00");
+//! assert_eq!(entity.cde_id(), 6343385);
+//! assert_eq!(entity.version(), (1, 0));
+//!
+//! # Ok::<(), Box<dyn std::error::Error>>(())
+//! ```
+//!
+//! # Members
+//!
+//! The documentation of a field of a `struct` is a free-form description.
+//!
+//! The documentation of a variant of an `enum` is made up of the following
+//! blocks separated by empty lines:
+//!
+//! 1. the permissible value in code (`` `PERMISSIBLE VALUE` ``), which must
+//!    match the value that the variant serializes to (usually via
+//!    `#[serde(rename = "...")]`),
+//! 2. (optionally) a list of metadata in the form `* **LABEL**: VALUE`, where
+//!    the `VM Long Name`, `VM Public ID`, and `Concept Code` labels are
+//!    expected and the `Begin Date` label (in the `MM/DD/YYYY` format) is
+//!    allowed. A permissible value that has been retired keeps its variant
+//!    (as existing data may still reference it) and is marked with an `End
+//!    Date` (or, equivalently, a `Deprecated`) label in the same format, and
+//! 3. a description of the permissible value (which may span multiple lines).
+//!
+//! ```
+//! use ccdi_cde as cde;
+//!
+//! use cde::parse::cde::member::Variant;
+//!
+//! let variant = r#"`Unknown`
+//!
+//! * **VM Long Name**: Unknown
+//! * **VM Public ID**: 4266671
+//! * **Concept Code**: C17998
+//! * **Begin Date**:   03/09/2023
+//!
+//! Not known, not observed, not recorded, or refused."#
+//!     .parse::<Variant>()?;
+//!
+//! assert_eq!(variant.permissible_value(), "Unknown");
+//! assert_eq!(
+//!     variant.metadata().unwrap().get("Begin Date").unwrap(),
+//!     "03/09/2023"
+//! );
+//!
+//! # Ok::<(), Box<dyn std::error::Error>>(())
+//! ```
+
+/// Expands to a [`Vec`] containing the path of every common data element
+/// within this crate alongside the result of calling `$f::<T>()` for that
+/// common data element.
+///
+/// New common data elements must be added here to be included within the
+/// registry (see [`crate::registry::all()`]) and the registry-wide checks (see
+/// [`lint::lint_registry()`] and [`snapshot::snapshot_registry()`]).
+macro_rules! registry {
+    ($f:ident) => {
+        vec![
+            (
+                "v1::deposition::DbgapPhsAccession",
+                $f::<crate::v1::deposition::DbgapPhsAccession>(),
+            ),
+            (
+                "v1::file::Description",
+                $f::<crate::v1::file::Description>(),
+            ),
+            ("v1::file::Name", $f::<crate::v1::file::Name>()),
+            ("v1::file::Size", $f::<crate::v1::file::Size>()),
+            ("v1::file::Type", $f::<crate::v1::file::Type>()),
+            (
+                "v1::file::checksum::MD5",
+                $f::<crate::v1::file::checksum::MD5>(),
+            ),
+            (
+                "v1::namespace::StudyFundingId",
+                $f::<crate::v1::namespace::StudyFundingId>(),
+            ),
+            (
+                "v1::namespace::StudyId",
+                $f::<crate::v1::namespace::StudyId>(),
+            ),
+            (
+                "v1::namespace::StudyName",
+                $f::<crate::v1::namespace::StudyName>(),
+            ),
+            (
+                "v1::sample::DiagnosisCategory",
+                $f::<crate::v1::sample::DiagnosisCategory>(),
+            ),
+            (
+                "v1::sample::DiseasePhase",
+                $f::<crate::v1::sample::DiseasePhase>(),
+            ),
+            (
+                "v1::sample::LibrarySourceMaterial",
+                $f::<crate::v1::sample::LibrarySourceMaterial>(),
+            ),
+            (
+                "v1::sample::LibraryStrategy",
+                $f::<crate::v1::sample::LibraryStrategy>(),
+            ),
+            (
+                "v1::sample::SpecimenMolecularAnalyteType",
+                $f::<crate::v1::sample::SpecimenMolecularAnalyteType>(),
+            ),
+            (
+                "v1::sample::TissueType",
+                $f::<crate::v1::sample::TissueType>(),
+            ),
+            (
+                "v1::sample::TumorClassification",
+                $f::<crate::v1::sample::TumorClassification>(),
+            ),
+            (
+                "v1::sample::TumorTissueMorphology",
+                $f::<crate::v1::sample::TumorTissueMorphology>(),
+            ),
+            ("v1::subject::Name", $f::<crate::v1::subject::Name>()),
+            ("v1::subject::Race", $f::<crate::v1::subject::Race>()),
+            ("v1::subject::Sex", $f::<crate::v1::subject::Sex>()),
+            (
+                "v1::subject::VitalStatus",
+                $f::<crate::v1::subject::VitalStatus>(),
+            ),
+            (
+                "v2::namespace::StudyShortTitle",
+                $f::<crate::v2::namespace::StudyShortTitle>(),
+            ),
+            (
+                "v2::sample::LibrarySelectionMethod",
+                $f::<crate::v2::sample::LibrarySelectionMethod>(),
+            ),
+            (
+                "v2::sample::PreservationMethod",
+                $f::<crate::v2::sample::PreservationMethod>(),
+            ),
+            (
+                "v2::sample::TumorGrade",
+                $f::<crate::v2::sample::TumorGrade>(),
+            ),
+            (
+                "v2::subject::Ethnicity",
+                $f::<crate::v2::subject::Ethnicity>(),
+            ),
+            (
+                "v4::organization::Institution",
+                $f::<crate::v4::organization::Institution>(),
+            ),
+        ]
+    };
+}
+
+pub(crate) use registry;
 
 pub mod entity;
+pub mod lint;
 pub mod member;
+pub mod snapshot;
 
 pub use entity::Entity;
 pub use member::Member;
@@ -13,6 +13,9 @@ use crate::parse::trim_and_concat_contiguous_lines;
 const STANDARD_PATTERN: &str = r"^\*\*`(?P<standard>.*?)`\*\*$";
 const URL_PATTERN: &str = r"^Link: <(?P<url>.*)>$";
 
+/// The prefix of the name of every standard within the caDSR.
+const STANDARD_NAME_PREFIX: &str = "caDSR CDE ";
+
 /// A error related to parsing an [`Entity`].
 #[derive(Debug, Eq, PartialEq)]
 pub enum ParseError {
@@ -34,6 +37,19 @@ pub enum ParseError {
 
     /// The URL itself was not valid.
     InvalidURL(url::ParseError),
+
+    /// The standard name does not match the format we expect (`caDSR CDE
+    /// <ID> v<MAJOR>.<MINOR>`). The argument is the standard name.
+    InvalidStandardName(String),
+
+    /// The ID of the common data element within the standard name is not a
+    /// valid numeric ID. The argument is the ID that we attempted to parse.
+    InvalidCdeId(String),
+
+    /// The version of the common data element within the standard name is not
+    /// a valid version. The argument is the version that we attempted to
+    /// parse.
+    InvalidVersion(String),
 }
 
 impl std::fmt::Display for ParseError {
@@ -59,6 +75,19 @@ impl std::fmt::Display for ParseError {
                 )
             }
             ParseError::InvalidURL(err) => write!(f, "invalid url: {err}"),
+            ParseError::InvalidStandardName(value) => write!(
+                f,
+                "entity's standard name does not match expected format: \"{value}\". \
+                 The following format is expected: \"caDSR CDE <ID> v<MAJOR>.<MINOR>\""
+            ),
+            ParseError::InvalidCdeId(value) => {
+                write!(f, "invalid CDE id: \"{value}\" is not a numeric id")
+            }
+            ParseError::InvalidVersion(value) => write!(
+                f,
+                "invalid CDE version: \"{value}\" does not match the \"v<MAJOR>.<MINOR>\" \
+                 format"
+            ),
         }
     }
 }
@@ -74,6 +103,8 @@ pub type Result<T> = std::result::Result<T, ParseError>;
 pub struct Entity {
     description: String,
     standard_name: String,
+    cde_id: u64,
+    version: (u16, u16),
     standard_url: Url,
 }
 
@@ -87,7 +118,7 @@ impl Entity {
     ///
     /// use cde::parse::cde::Entity;
     ///
-    /// let entity = r#"**`A Standard`**
+    /// let entity = r#"**`caDSR CDE 6343385 v1.00`**
     ///
     /// A description that spans
     /// multiple lines.
@@ -115,7 +146,7 @@ impl Entity {
     ///
     /// use cde::parse::cde::Entity;
     ///
-    /// let entity = r#"**`A Standard`**
+    /// let entity = r#"**`caDSR CDE 6343385 v1.00`**
     ///
     /// A description that spans
     /// multiple lines.
@@ -123,7 +154,7 @@ impl Entity {
     /// Link: <https://example.com>"#
     ///     .parse::<Entity>()?;
     ///
-    /// assert_eq!(entity.standard_name(), "A Standard");
+    /// assert_eq!(entity.standard_name(), "caDSR CDE 6343385 v1.00");
     ///
     /// # Ok::<(), Box<dyn std::error::Error>>(())
     /// ```
@@ -140,7 +171,7 @@ impl Entity {
     ///
     /// use cde::parse::cde::Entity;
     ///
-    /// let entity = r#"**`A Standard`**
+    /// let entity = r#"**`caDSR CDE 6343385 v1.00`**
     ///
     /// A description that spans
     /// multiple lines.
@@ -155,6 +186,86 @@ impl Entity {
     pub fn standard_url(&self) -> &Url {
         &self.standard_url
     }
+
+    /// Gets the numeric ID of the common data element within the caDSR (parsed
+    /// from the standard name).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ccdi_cde as cde;
+    ///
+    /// use cde::parse::cde::Entity;
+    ///
+    /// let entity = r#"**`caDSR CDE 6343385 v1.00`**
+    ///
+    /// A description that spans
+    /// multiple lines.
+    ///
+    /// Link: <https://example.com>"#
+    ///     .parse::<Entity>()?;
+    ///
+    /// assert_eq!(entity.cde_id(), 6343385);
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn cde_id(&self) -> u64 {
+        self.cde_id
+    }
+
+    /// Gets the version of the common data element within the caDSR as a
+    /// `(major, minor)` pair (parsed from the standard name).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ccdi_cde as cde;
+    ///
+    /// use cde::parse::cde::Entity;
+    ///
+    /// let entity = r#"**`caDSR CDE 6343385 v2.01`**
+    ///
+    /// A description that spans
+    /// multiple lines.
+    ///
+    /// Link: <https://example.com>"#
+    ///     .parse::<Entity>()?;
+    ///
+    /// assert_eq!(entity.version(), (2, 1));
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn version(&self) -> (u16, u16) {
+        self.version
+    }
+
+    /// Gets the link to the common data element within the caDSR (parsed from
+    /// the `Link:` line) by reference.
+    ///
+    /// This is the same URL as [`Entity::standard_url()`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ccdi_cde as cde;
+    ///
+    /// use cde::parse::cde::Entity;
+    ///
+    /// let entity = r#"**`caDSR CDE 6343385 v1.00`**
+    ///
+    /// A description that spans
+    /// multiple lines.
+    ///
+    /// Link: <https://example.com>"#
+    ///     .parse::<Entity>()?;
+    ///
+    /// assert_eq!(entity.link().host_str(), Some("example.com"));
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn link(&self) -> &Url {
+        &self.standard_url
+    }
 }
 
 impl std::str::FromStr for Entity {
@@ -168,12 +279,15 @@ impl std::str::FromStr for Entity {
         }
 
         let standard = parse_standard_line(&mut lines)?;
+        let (cde_id, version) = parse_standard_name(&standard)?;
         let description = parse_description_line(&mut lines)?;
         let url = parse_url_line(&mut lines)?;
         let url = Url::parse(&url).map_err(ParseError::InvalidURL)?;
 
         Ok(Self {
             standard_name: standard,
+            cde_id,
+            version,
             description,
             standard_url: url,
         })
@@ -200,6 +314,35 @@ fn parse_standard_line(lines: &mut Peekable<Lines<'_>>) -> Result<String> {
     }
 }
 
+/// Parses the numeric ID and the version of a common data element from a
+/// standard name (`caDSR CDE <ID> v<MAJOR>.<MINOR>`).
+///
+/// A handful of standards within the caDSR omit the `v` before the version
+/// (e.g., `caDSR CDE 16607972 1.00`), so the `v` is optional.
+fn parse_standard_name(name: &str) -> Result<(u64, (u16, u16))> {
+    let (id, version) = name
+        .strip_prefix(STANDARD_NAME_PREFIX)
+        .and_then(|rest| rest.split_once(' '))
+        .ok_or_else(|| ParseError::InvalidStandardName(name.to_owned()))?;
+
+    let cde_id = id
+        .parse::<u64>()
+        .map_err(|_| ParseError::InvalidCdeId(id.to_owned()))?;
+
+    let invalid_version = || ParseError::InvalidVersion(version.to_owned());
+
+    let (major, minor) = version
+        .strip_prefix('v')
+        .unwrap_or(version)
+        .split_once('.')
+        .ok_or_else(invalid_version)?;
+
+    let major = major.parse::<u16>().map_err(|_| invalid_version())?;
+    let minor = minor.parse::<u16>().map_err(|_| invalid_version())?;
+
+    Ok((cde_id, (major, minor)))
+}
+
 fn parse_description_line(lines: &mut Peekable<Lines<'_>>) -> Result<String> {
     trim_and_concat_contiguous_lines(lines)
         .map(Ok)
@@ -250,8 +393,8 @@ mod tests {
 
     #[test]
     fn it_parses_a_multiline_standard() -> std::result::Result<(), Box<dyn std::error::Error>> {
-        let entity = r#"**`A Standard
-        That Spans Multiple Lines`**
+        let entity = r#"**`caDSR CDE
+        6343385 v1.00`**
     
         A description that spans
         multiple lines.
@@ -259,17 +402,16 @@ mod tests {
         Link: <https://example.com>"#
             .parse::<Entity>()?;
 
-        assert_eq!(
-            entity.standard_name(),
-            "A Standard That Spans Multiple Lines"
-        );
+        assert_eq!(entity.standard_name(), "caDSR CDE 6343385 v1.00");
+        assert_eq!(entity.cde_id(), 6343385);
+        assert_eq!(entity.version(), (1, 0));
 
         Ok(())
     }
 
     #[test]
     fn it_parses_a_multiline_url() -> std::result::Result<(), Box<dyn std::error::Error>> {
-        let entity = r#"**`A Standard`**
+        let entity = r#"**`caDSR CDE 6343385 v1.00`**
     
         A description that spans
         multiple lines.
@@ -295,7 +437,7 @@ mod tests {
     #[test]
     fn it_fails_to_parse_a_field_with_a_missing_description(
     ) -> std::result::Result<(), Box<dyn std::error::Error>> {
-        let err = r#"**`A Standard`**
+        let err = r#"**`caDSR CDE 6343385 v1.00`**
         "#
         .parse::<Entity>()
         .unwrap_err();
@@ -311,7 +453,7 @@ mod tests {
     #[test]
     fn it_fails_to_parse_a_field_with_a_missing_url(
     ) -> std::result::Result<(), Box<dyn std::error::Error>> {
-        let err = r#"**`A Standard`**
+        let err = r#"**`caDSR CDE 6343385 v1.00`**
 
         A description.
         "#
@@ -366,7 +508,7 @@ mod tests {
     #[test]
     fn it_fails_to_parse_an_incorrectly_formatted_url(
     ) -> std::result::Result<(), Box<dyn std::error::Error>> {
-        let err = r#"**`A Standard`**
+        let err = r#"**`caDSR CDE 6343385 v1.00`**
     
         A description that spans
         multiple lines.
@@ -384,7 +526,7 @@ mod tests {
 
         // Ensure that we must have code backticks in the standard name.
 
-        let err = r#"**`A Standard`**
+        let err = r#"**`caDSR CDE 6343385 v1.00`**
     
         A description that spans
         multiple lines.
@@ -405,7 +547,7 @@ mod tests {
 
     #[test]
     fn it_fails_to_parse_an_invalid_url() {
-        let err = r#"**`A Standard`**
+        let err = r#"**`caDSR CDE 6343385 v1.00`**
     
         A description that spans
         multiple lines.
@@ -416,4 +558,52 @@ mod tests {
 
         assert!(matches!(err, ParseError::InvalidURL(_)));
     }
+
+    #[test]
+    fn it_parses_a_version_without_a_leading_v(
+    ) -> std::result::Result<(), Box<dyn std::error::Error>> {
+        let entity = r#"**`caDSR CDE 16607972 1.00`**
+
+        A description.
+
+        Link: <https://example.com>"#
+            .parse::<Entity>()?;
+
+        assert_eq!(entity.cde_id(), 16607972);
+        assert_eq!(entity.version(), (1, 0));
+
+        Ok(())
+    }
+
+    #[test]
+    fn it_fails_to_parse_a_malformed_standard_name() {
+        let entity = |standard: &str| {
+            format!("**`{standard}`**\n\nA description.\n\nLink: <https://example.com>")
+                .parse::<Entity>()
+        };
+
+        assert_eq!(
+            entity("A Standard").unwrap_err(),
+            ParseError::InvalidStandardName(String::from("A Standard"))
+        );
+        assert_eq!(
+            entity("caDSR CDE 6343385").unwrap_err(),
+            ParseError::InvalidStandardName(String::from("caDSR CDE 6343385"))
+        );
+        assert_eq!(
+            entity("caDSR CDE abc v1.00").unwrap_err(),
+            ParseError::InvalidCdeId(String::from("abc"))
+        );
+        assert_eq!(
+            entity("caDSR CDE 6343385 v1").unwrap_err(),
+            ParseError::InvalidVersion(String::from("v1"))
+        );
+
+        let err = entity("caDSR CDE 6343385 v1.x").unwrap_err();
+        assert_eq!(err, ParseError::InvalidVersion(String::from("v1.x")));
+        assert_eq!(
+            err.to_string(),
+            "invalid CDE version: \"v1.x\" does not match the \"v<MAJOR>.<MINOR>\" format"
+        );
+    }
 }
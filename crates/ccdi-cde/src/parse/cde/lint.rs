@@ -0,0 +1,534 @@
+//! Linting the documentation of common data elements.
+//!
+//! The documentation of a common data element is parsed at runtime (see the
+//! [module-level documentation](crate::parse::cde) for the expected layout),
+//! so a mistake in that documentation would otherwise only surface when the
+//! entity or its members are first parsed. [`lint()`] checks every part of the
+//! documentation up front and reports each problem as a [`Finding`].
+
+use introspect::Member;
+use lazy_static::lazy_static;
+use regex::Regex;
+use serde::de::DeserializeOwned;
+
+use crate::parse::cde::entity;
+use crate::parse::cde::member::variant;
+use crate::parse::cde::member::Variant;
+use crate::Error;
+use crate::CDE;
+
+/// The metadata labels that every variant is expected to have.
+pub const REQUIRED_LABELS: &[&str] = &["VM Long Name", "VM Public ID", "Concept Code"];
+
+/// The metadata labels that a variant may optionally have.
+pub const OPTIONAL_LABELS: &[&str] = &["Begin Date", "End Date", "Deprecated"];
+
+/// The metadata labels that mark a variant as retired.
+const END_DATE_LABELS: &[&str] = &["End Date", "Deprecated"];
+
+/// The pattern that a `Begin Date` (or an `End Date`) must match
+/// (`MM/DD/YYYY`).
+const BEGIN_DATE_PATTERN: &str = r"^(0[1-9]|1[0-2])/(0[1-9]|[12][0-9]|3[01])/[0-9]{4}$";
+
+lazy_static! {
+    // SAFETY: this pattern is tested to compile below.
+    static ref BEGIN_DATE_REGEX: Regex = Regex::new(BEGIN_DATE_PATTERN).unwrap();
+}
+
+/// A kind of [`Finding`].
+#[derive(Debug, Eq, PartialEq)]
+pub enum Kind {
+    /// The entity has no documentation.
+    MissingEntityDocumentation,
+
+    /// The documentation of the entity could not be parsed.
+    InvalidEntity(entity::ParseError),
+
+    /// A variant has no documentation.
+    MissingVariantDocumentation,
+
+    /// The documentation of a variant could not be parsed.
+    InvalidVariant(variant::ParseError),
+
+    /// A variant is missing a required metadata label.
+    MissingLabel(String),
+
+    /// A variant has a metadata label that is not recognized.
+    UnknownLabel(String),
+
+    /// The `Begin Date` of a variant is not in the `MM/DD/YYYY` format.
+    MalformedBeginDate(String),
+
+    /// The `End Date` (or `Deprecated`) of a variant is not in the
+    /// `MM/DD/YYYY` format.
+    MalformedEndDate {
+        /// The label of the bullet.
+        label: String,
+
+        /// The value of the bullet.
+        date: String,
+    },
+
+    /// The documented permissible value of a variant does not deserialize to
+    /// that variant (usually because the `#[serde(rename = "...")]` attribute
+    /// does not match the documentation).
+    PermissibleValueMismatch {
+        /// The documented permissible value.
+        permissible_value: String,
+
+        /// The variant that the permissible value deserialized to (if any).
+        deserialized: Option<String>,
+    },
+}
+
+impl std::fmt::Display for Kind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Kind::MissingEntityDocumentation => write!(f, "missing entity documentation"),
+            Kind::InvalidEntity(err) => write!(f, "invalid entity documentation: {err}"),
+            Kind::MissingVariantDocumentation => write!(f, "missing variant documentation"),
+            Kind::InvalidVariant(err) => write!(f, "invalid variant documentation: {err}"),
+            Kind::MissingLabel(label) => write!(f, "missing `{label}`"),
+            Kind::UnknownLabel(label) => write!(f, "unknown label `{label}`"),
+            Kind::MalformedBeginDate(date) => {
+                write!(
+                    f,
+                    "malformed `Begin Date` (expected `MM/DD/YYYY`): `{date}`"
+                )
+            }
+            Kind::MalformedEndDate { label, date } => {
+                write!(f, "malformed `{label}` (expected `MM/DD/YYYY`): `{date}`")
+            }
+            Kind::PermissibleValueMismatch {
+                permissible_value,
+                deserialized,
+            } => match deserialized {
+                Some(deserialized) => write!(
+                    f,
+                    "permissible value `{permissible_value}` deserializes to `{deserialized}`"
+                ),
+                None => write!(
+                    f,
+                    "permissible value `{permissible_value}` does not deserialize"
+                ),
+            },
+        }
+    }
+}
+
+/// A problem found within the documentation of a common data element.
+#[derive(Debug, Eq, PartialEq)]
+pub struct Finding {
+    /// The identifier of the variant (or [`None`] if the finding relates to
+    /// the entity itself).
+    variant: Option<String>,
+
+    /// The kind of finding.
+    kind: Kind,
+}
+
+impl Finding {
+    /// Gets the identifier of the variant to which the [`Finding`] relates (or
+    /// [`None`] if the finding relates to the entity itself).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ccdi_cde as cde;
+    ///
+    /// use cde::parse::cde::lint::lint;
+    /// use cde::v1::subject::Sex;
+    ///
+    /// for finding in lint::<Sex>() {
+    ///     println!("{}", finding.variant().unwrap_or("<entity>"));
+    /// }
+    /// ```
+    pub fn variant(&self) -> Option<&str> {
+        self.variant.as_deref()
+    }
+
+    /// Gets the [`Kind`] of the [`Finding`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ccdi_cde as cde;
+    ///
+    /// use cde::parse::cde::lint::lint;
+    /// use cde::v1::sample::LibraryStrategy;
+    ///
+    /// for finding in lint::<LibraryStrategy>() {
+    ///     println!("{}", finding.kind());
+    /// }
+    /// ```
+    pub fn kind(&self) -> &Kind {
+        &self.kind
+    }
+}
+
+impl std::fmt::Display for Finding {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match &self.variant {
+            Some(variant) => write!(f, "{variant}: {}", self.kind),
+            None => write!(f, "{}", self.kind),
+        }
+    }
+}
+
+/// Lints the documentation of a common data element.
+///
+/// Every problem is reported (rather than only the first), and an empty list
+/// is returned if the documentation is well-formed.
+///
+/// # Examples
+///
+/// ```
+/// use ccdi_cde as cde;
+///
+/// use cde::parse::cde::lint::lint;
+/// use cde::v1::sample::LibraryStrategy;
+///
+/// assert!(lint::<LibraryStrategy>().is_empty());
+/// ```
+pub fn lint<T>() -> Vec<Finding>
+where
+    T: CDE + DeserializeOwned + std::fmt::Debug,
+{
+    let mut findings = Vec::new();
+
+    match T::entity() {
+        Ok(_) => {}
+        Err(Error::EntityError(err)) => findings.push(Finding {
+            variant: None,
+            kind: Kind::InvalidEntity(err),
+        }),
+        Err(_) => findings.push(Finding {
+            variant: None,
+            kind: Kind::MissingEntityDocumentation,
+        }),
+    }
+
+    for member in T::introspected_members() {
+        // NOTE: the fields of `struct` common data elements are not required
+        // to be documented, so only the variants of `enum`s are linted.
+        let member = match member {
+            Member::Variant(member) => member,
+            Member::Field(_) => continue,
+        };
+
+        let identifier = member.identifier().to_string();
+
+        let kinds = match member.documentation() {
+            // NOTE: the metadata bullets are validated below (rather than when
+            // parsing) so that every problem with them is reported.
+            Some(documentation) => match Variant::parse_unvalidated(documentation) {
+                Ok(variant) => lint_variant::<T>(&identifier, &variant),
+                Err(err) => vec![Kind::InvalidVariant(err)],
+            },
+            None => vec![Kind::MissingVariantDocumentation],
+        };
+
+        findings.extend(kinds.into_iter().map(|kind| Finding {
+            variant: Some(identifier.clone()),
+            kind,
+        }));
+    }
+
+    findings
+}
+
+/// Lints a single parsed variant.
+fn lint_variant<T>(identifier: &str, variant: &Variant) -> Vec<Kind>
+where
+    T: DeserializeOwned + std::fmt::Debug,
+{
+    let mut kinds = Vec::new();
+
+    match variant.metadata() {
+        Some(metadata) => {
+            for label in REQUIRED_LABELS {
+                if !metadata.contains_key(*label) {
+                    kinds.push(Kind::MissingLabel(label.to_string()));
+                }
+            }
+
+            for (label, value) in metadata {
+                if !REQUIRED_LABELS.contains(&label.as_str())
+                    && !OPTIONAL_LABELS.contains(&label.as_str())
+                {
+                    kinds.push(Kind::UnknownLabel(label.clone()));
+                }
+
+                if label == "Begin Date" && !BEGIN_DATE_REGEX.is_match(value) {
+                    kinds.push(Kind::MalformedBeginDate(value.clone()));
+                }
+
+                if END_DATE_LABELS.contains(&label.as_str()) && !BEGIN_DATE_REGEX.is_match(value) {
+                    kinds.push(Kind::MalformedEndDate {
+                        label: label.clone(),
+                        date: value.clone(),
+                    });
+                }
+            }
+        }
+        None => kinds.extend(
+            REQUIRED_LABELS
+                .iter()
+                .map(|label| Kind::MissingLabel(label.to_string())),
+        ),
+    }
+
+    // The [`Debug`] representation of a unit variant is its identifier, so
+    // this checks that the permissible value deserializes to _this_ variant.
+    let deserialized =
+        serde_json::from_value::<T>(serde_json::Value::from(variant.permissible_value()))
+            .ok()
+            .map(|value| format!("{value:?}"));
+
+    if deserialized.as_deref() != Some(identifier) {
+        kinds.push(Kind::PermissibleValueMismatch {
+            permissible_value: variant.permissible_value().to_string(),
+            deserialized,
+        });
+    }
+
+    kinds
+}
+
+/// Lints every common data element within this crate.
+///
+/// The findings are returned alongside the path of each common data element
+/// (only those with at least one finding are included).
+///
+/// # Examples
+///
+/// ```
+/// use ccdi_cde as cde;
+///
+/// use cde::parse::cde::lint::lint_registry;
+///
+/// assert!(lint_registry().is_empty());
+/// ```
+pub fn lint_registry() -> Vec<(&'static str, Vec<Finding>)> {
+    registry!(lint)
+        .into_iter()
+        .filter(|(_, findings)| !findings.is_empty())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use introspect::Introspect;
+    use serde::Deserialize;
+
+    use super::*;
+
+    /// **`caDSR CDE 1 v1.00`**
+    ///
+    /// A fixture.
+    ///
+    /// Link:
+    /// <https://example.com>
+    #[derive(Debug, Deserialize, Eq, PartialEq, Introspect)]
+    enum Fixture {
+        /// `Clean`
+        ///
+        /// * **VM Long Name**: Clean
+        /// * **VM Public ID**: 1
+        /// * **Concept Code**: C1
+        /// * **Begin Date**:   01/01/2024
+        ///
+        /// A variant with no findings.
+        Clean,
+
+        /// `Missing Public ID`
+        ///
+        /// * **VM Long Name**: Missing Public ID
+        /// * **Concept Code**: C2
+        /// * **Begin Date**:   01/01/2024
+        ///
+        /// A variant without a VM Public ID.
+        #[serde(rename = "Missing Public ID")]
+        MissingPublicId,
+
+        /// `Malformed Begin Date`
+        ///
+        /// * **VM Long Name**: Malformed Begin Date
+        /// * **VM Public ID**: 3
+        /// * **Concept Code**: C3
+        /// * **Begin Date**:   2024-01-01
+        ///
+        /// A variant with a malformed begin date.
+        #[serde(rename = "Malformed Begin Date")]
+        MalformedBeginDate,
+
+        /// `Malformed End Date`
+        ///
+        /// * **VM Long Name**: Malformed End Date
+        /// * **VM Public ID**: 6
+        /// * **Concept Code**: C6
+        /// * **End Date**:     2024-01-31
+        ///
+        /// A variant with a malformed end date.
+        #[serde(rename = "Malformed End Date")]
+        MalformedEndDate,
+
+        /// `Renamed`
+        ///
+        /// * **VM Long Name**: Renamed
+        /// * **VM Public ID**: 4
+        /// * **Concept Code**: C4
+        /// * **Begin Date**:   01/01/2024
+        ///
+        /// A variant whose serde rename does not match its permissible value.
+        #[serde(rename = "Something Else")]
+        Renamed,
+
+        /// `Wrong Label`
+        ///
+        /// * **VM Long Name**: Wrong Label
+        /// * **VM Public Id**: 5
+        /// * **Concept Code**: C5
+        ///
+        /// A variant with a misspelled label.
+        #[serde(rename = "Wrong Label")]
+        WrongLabel,
+
+        /// `Missing Bold`
+        ///
+        /// * VM Long Name: Missing Bold
+        ///
+        /// A variant without bold markers around its labels.
+        #[serde(rename = "Missing Bold")]
+        MissingBold,
+
+        Undocumented,
+    }
+
+    impl std::fmt::Display for Fixture {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            write!(f, "{self:?}")
+        }
+    }
+
+    impl CDE for Fixture {}
+
+    /// A fixture without a standard.
+    #[derive(Debug, Deserialize, Eq, PartialEq, Introspect)]
+    struct MissingStandard(String);
+
+    impl std::fmt::Display for MissingStandard {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            write!(f, "{}", self.0)
+        }
+    }
+
+    impl CDE for MissingStandard {}
+
+    fn findings_for(variant: &str) -> Vec<String> {
+        lint::<Fixture>()
+            .into_iter()
+            .filter(|finding| finding.variant() == Some(variant))
+            .map(|finding| finding.kind().to_string())
+            .collect()
+    }
+
+    #[test]
+    fn the_begin_date_pattern_compiles() {
+        assert!(BEGIN_DATE_REGEX.is_match("12/27/2022"));
+        assert!(!BEGIN_DATE_REGEX.is_match("2/27/2022"));
+        assert!(!BEGIN_DATE_REGEX.is_match("13/01/2022"));
+    }
+
+    #[test]
+    fn it_finds_nothing_for_a_clean_variant() {
+        assert!(findings_for("Clean").is_empty());
+    }
+
+    #[test]
+    fn it_finds_a_missing_public_id() {
+        assert_eq!(
+            findings_for("MissingPublicId"),
+            vec!["missing `VM Public ID`"]
+        );
+    }
+
+    #[test]
+    fn it_finds_a_malformed_begin_date() {
+        assert_eq!(
+            findings_for("MalformedBeginDate"),
+            vec!["malformed `Begin Date` (expected `MM/DD/YYYY`): `2024-01-01`"]
+        );
+    }
+
+    #[test]
+    fn it_finds_a_malformed_end_date() {
+        assert_eq!(
+            findings_for("MalformedEndDate"),
+            vec!["malformed `End Date` (expected `MM/DD/YYYY`): `2024-01-31`"]
+        );
+    }
+
+    #[test]
+    fn it_finds_a_permissible_value_mismatch() {
+        assert_eq!(
+            findings_for("Renamed"),
+            vec!["permissible value `Renamed` does not deserialize"]
+        );
+    }
+
+    #[test]
+    fn it_finds_a_wrong_label() {
+        assert_eq!(
+            findings_for("WrongLabel"),
+            vec!["missing `VM Public ID`", "unknown label `VM Public Id`"]
+        );
+    }
+
+    #[test]
+    fn it_finds_missing_bold_markers() {
+        let findings = findings_for("MissingBold");
+
+        assert_eq!(findings.len(), 1);
+        assert!(findings[0].starts_with("invalid variant documentation: variant metadata"));
+    }
+
+    #[test]
+    fn it_finds_a_missing_variant_documentation() {
+        assert_eq!(
+            findings_for("Undocumented"),
+            vec!["missing variant documentation"]
+        );
+    }
+
+    #[test]
+    fn it_finds_an_invalid_entity() {
+        let findings = lint::<MissingStandard>();
+
+        assert_eq!(findings.len(), 1);
+        assert!(findings[0].variant().is_none());
+        assert!(matches!(
+            findings[0].kind(),
+            Kind::InvalidEntity(entity::ParseError::InvalidStandardFormat(_))
+        ));
+    }
+
+    #[test]
+    fn the_registry_is_clean() {
+        let findings = lint_registry();
+
+        assert!(
+            findings.is_empty(),
+            "{}",
+            findings
+                .iter()
+                .flat_map(|(name, findings)| {
+                    findings
+                        .iter()
+                        .map(move |finding| format!("{name}: {finding}"))
+                })
+                .collect::<Vec<_>>()
+                .join("\n")
+        );
+    }
+}
@@ -1,5 +1,6 @@
 //! Parsing the members of `enum`s ("variants") as common data elements.
 
+use chrono::NaiveDate;
 use regex::Regex;
 use serde::Deserialize;
 use serde::Serialize;
@@ -14,6 +15,27 @@ use crate::parse::trim_and_concat_contiguous_lines;
 const PERMISSIBLE_VALUE_PATTERN: &str = r"^`(?P<permissible_value>.*)`$";
 const METADATA_PATTERN: &str = r"^\*\s*\*\*(?P<key>.*)\*\*:\s*(?P<value>.*)$";
 
+/// The label of the value meaning long name bullet.
+const VM_LONG_NAME: &str = "VM Long Name";
+
+/// The label of the value meaning public identifier bullet.
+const VM_PUBLIC_ID: &str = "VM Public ID";
+
+/// The label of the concept code bullet.
+const CONCEPT_CODE: &str = "Concept Code";
+
+/// The label of the begin date bullet.
+const BEGIN_DATE: &str = "Begin Date";
+
+/// The label of the end date bullet.
+const END_DATE: &str = "End Date";
+
+/// The label of the deprecated bullet (an alias of the end date bullet).
+const DEPRECATED: &str = "Deprecated";
+
+/// The format of the date bullets (`MM/DD/YYYY`).
+const DATE_FORMAT: &str = "%m/%d/%Y";
+
 /// An error related to parsing a [`Variant`].
 #[derive(Debug, Eq, PartialEq)]
 pub enum ParseError {
@@ -32,6 +54,28 @@ pub enum ParseError {
     /// A variant metadata line was does not match the format we expect. The
     /// argument is the line that we are attempting to parse.
     InvalidMemberMetadataFormat(String),
+
+    /// A required metadata bullet is missing. The argument is the label of the
+    /// missing bullet.
+    MissingMetadata(String),
+
+    /// The `VM Public ID` bullet is not an unsigned integer. The argument is
+    /// the value of the bullet.
+    InvalidVmPublicId(String),
+
+    /// The `Begin Date` bullet is not a valid date in the `MM/DD/YYYY` format.
+    /// The argument is the value of the bullet.
+    InvalidBeginDate(String),
+
+    /// The `End Date` (or `Deprecated`) bullet is not a valid date in the
+    /// `MM/DD/YYYY` format.
+    InvalidEndDate {
+        /// The label of the bullet.
+        label: String,
+
+        /// The value of the bullet.
+        value: String,
+    },
 }
 
 impl std::fmt::Display for ParseError {
@@ -55,6 +99,29 @@ impl std::fmt::Display for ParseError {
                      The following format is expected: \"* **NAME**: DESCRIPTION\""
                 )
             }
+            ParseError::MissingMetadata(label) => {
+                write!(f, "variant metadata is missing the `{label}` bullet")
+            }
+            ParseError::InvalidVmPublicId(value) => {
+                write!(
+                    f,
+                    "the `{VM_PUBLIC_ID}` bullet is not an unsigned integer: \"{value}\""
+                )
+            }
+            ParseError::InvalidBeginDate(value) => {
+                write!(
+                    f,
+                    "the `{BEGIN_DATE}` bullet is not a valid date: \"{value}\". The \
+                     following format is expected: \"MM/DD/YYYY\""
+                )
+            }
+            ParseError::InvalidEndDate { label, value } => {
+                write!(
+                    f,
+                    "the `{label}` bullet is not a valid date: \"{value}\". The following \
+                     format is expected: \"MM/DD/YYYY\""
+                )
+            }
         }
     }
 }
@@ -160,12 +227,209 @@ impl Variant {
     pub fn description(&self) -> &str {
         self.description.as_str()
     }
-}
 
-impl FromStr for Variant {
-    type Err = ParseError;
+    /// Gets the value meaning long name (the `VM Long Name` bullet) for the
+    /// [`Variant`] by reference.
+    ///
+    /// This is only [`None`] when the [`Variant`] has no metadata.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ccdi_cde as cde;
+    ///
+    /// use cde::parse::cde::member::Variant;
+    ///
+    /// let variant = r#"`Unknown`
+    ///
+    /// * **VM Long Name**: Unknown
+    /// * **VM Public ID**: 4266671
+    /// * **Concept Code**: C17998
+    /// * **Begin Date**:   03/09/2023
+    ///
+    /// Not known, not observed, not recorded, or refused."#
+    ///     .parse::<Variant>()?;
+    ///
+    /// assert_eq!(variant.vm_long_name(), Some("Unknown"));
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn vm_long_name(&self) -> Option<&str> {
+        self.get(VM_LONG_NAME)
+    }
 
-    fn from_str(s: &str) -> Result<Self> {
+    /// Gets the value meaning public identifier (the `VM Public ID` bullet)
+    /// for the [`Variant`].
+    ///
+    /// This is only [`None`] when the [`Variant`] has no metadata.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ccdi_cde as cde;
+    ///
+    /// use cde::parse::cde::member::Variant;
+    ///
+    /// let variant = r#"`Unknown`
+    ///
+    /// * **VM Long Name**: Unknown
+    /// * **VM Public ID**: 4266671
+    /// * **Concept Code**: C17998
+    /// * **Begin Date**:   03/09/2023
+    ///
+    /// Not known, not observed, not recorded, or refused."#
+    ///     .parse::<Variant>()?;
+    ///
+    /// assert_eq!(variant.vm_public_id(), Some(4266671));
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn vm_public_id(&self) -> Option<u64> {
+        self.get(VM_PUBLIC_ID)
+            .and_then(|value| value.parse::<u64>().ok())
+    }
+
+    /// Gets the concept code (the `Concept Code` bullet) for the [`Variant`]
+    /// by reference.
+    ///
+    /// Some permissible values are not mapped to a concept, so the bullet is
+    /// present but left blank. In that case (or when the [`Variant`] has no
+    /// metadata), [`None`] is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ccdi_cde as cde;
+    ///
+    /// use cde::parse::cde::member::Variant;
+    ///
+    /// let variant = r#"`Unknown`
+    ///
+    /// * **VM Long Name**: Unknown
+    /// * **VM Public ID**: 4266671
+    /// * **Concept Code**: C17998
+    /// * **Begin Date**:   03/09/2023
+    ///
+    /// Not known, not observed, not recorded, or refused."#
+    ///     .parse::<Variant>()?;
+    ///
+    /// assert_eq!(variant.concept_code(), Some("C17998"));
+    ///
+    /// let variant = r#"`Random`
+    ///
+    /// * **VM Long Name**: Random
+    /// * **VM Public ID**: 6287023
+    /// * **Concept Code**:
+    /// * **Begin Date**:   05/15/2018
+    ///
+    /// Random selection by shearing or other method"#
+    ///     .parse::<Variant>()?;
+    ///
+    /// assert_eq!(variant.concept_code(), None);
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn concept_code(&self) -> Option<&str> {
+        self.get(CONCEPT_CODE).filter(|value| !value.is_empty())
+    }
+
+    /// Gets the date on which the permissible value became effective (the
+    /// `Begin Date` bullet) for the [`Variant`].
+    ///
+    /// The bullet is optional, so [`None`] is returned when it is not present
+    /// (or when the [`Variant`] has no metadata).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ccdi_cde as cde;
+    /// use chrono::NaiveDate;
+    ///
+    /// use cde::parse::cde::member::Variant;
+    ///
+    /// let variant = r#"`Unknown`
+    ///
+    /// * **VM Long Name**: Unknown
+    /// * **VM Public ID**: 4266671
+    /// * **Concept Code**: C17998
+    /// * **Begin Date**:   03/09/2023
+    ///
+    /// Not known, not observed, not recorded, or refused."#
+    ///     .parse::<Variant>()?;
+    ///
+    /// assert_eq!(variant.begin_date(), NaiveDate::from_ymd_opt(2023, 3, 9));
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn begin_date(&self) -> Option<NaiveDate> {
+        self.get(BEGIN_DATE)
+            .and_then(|value| NaiveDate::parse_from_str(value, DATE_FORMAT).ok())
+    }
+
+    /// Gets the date on which the permissible value was retired (the `End
+    /// Date` bullet or, equivalently, the `Deprecated` bullet) for the
+    /// [`Variant`].
+    ///
+    /// Retired permissible values are kept (rather than removed) because
+    /// existing data may still reference them. [`None`] is returned when the
+    /// permissible value has not been retired (or when the [`Variant`] has no
+    /// metadata).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ccdi_cde as cde;
+    /// use chrono::NaiveDate;
+    ///
+    /// use cde::parse::cde::member::Variant;
+    ///
+    /// let variant = r#"`TIP`
+    ///
+    /// * **VM Long Name**: TIP
+    /// * **VM Public ID**: 12345678
+    /// * **Concept Code**:
+    /// * **Begin Date**:   03/09/2023
+    /// * **End Date**:     01/31/2024
+    ///
+    /// A retired permissible value."#
+    ///     .parse::<Variant>()?;
+    ///
+    /// assert_eq!(variant.deprecated(), NaiveDate::from_ymd_opt(2024, 1, 31));
+    ///
+    /// let variant = r#"`Unknown`
+    ///
+    /// * **VM Long Name**: Unknown
+    /// * **VM Public ID**: 4266671
+    /// * **Concept Code**: C17998
+    /// * **Begin Date**:   03/09/2023
+    ///
+    /// Not known, not observed, not recorded, or refused."#
+    ///     .parse::<Variant>()?;
+    ///
+    /// assert_eq!(variant.deprecated(), None);
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn deprecated(&self) -> Option<NaiveDate> {
+        self.get(END_DATE)
+            .or_else(|| self.get(DEPRECATED))
+            .and_then(|value| NaiveDate::parse_from_str(value, DATE_FORMAT).ok())
+    }
+
+    /// Gets the (trimmed) value of a metadata bullet by label.
+    fn get(&self, label: &str) -> Option<&str> {
+        self.metadata
+            .as_ref()
+            .and_then(|metadata| metadata.get(label))
+            .map(|value| value.trim())
+    }
+
+    /// Parses a [`Variant`] without validating the values of its metadata
+    /// bullets.
+    ///
+    /// This is used when linting so that every problem with the metadata
+    /// bullets is reported rather than only the first.
+    pub(crate) fn parse_unvalidated(s: &str) -> Result<Self> {
         let mut lines = s.lines().peekable();
 
         if lines.peek().is_none() {
@@ -184,6 +448,20 @@ impl FromStr for Variant {
     }
 }
 
+impl FromStr for Variant {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let variant = Self::parse_unvalidated(s)?;
+
+        if let Some(metadata) = variant.metadata() {
+            validate_metadata(metadata)?;
+        }
+
+        Ok(variant)
+    }
+}
+
 fn parse_permissible_value(lines: &mut Peekable<Lines<'_>>) -> Result<String> {
     let permissible_value = trim_and_concat_contiguous_lines(lines)
         .map(Ok)
@@ -219,11 +497,28 @@ fn parse_metadata(lines: &mut Peekable<Lines<'_>>) -> Result<Option<IndexMap<Str
     let regex = Regex::new(METADATA_PATTERN).unwrap();
     let mut results = IndexMap::<String, String>::new();
 
-    while let Some(line) = lines.next().map(|line| line.trim()) {
+    // NOTE: the indentation of the most recent bullet. A line that is
+    // indented further than the bullet continues the value of the bullet
+    // (e.g., when a long `VM Long Name` is wrapped onto a second line).
+    let mut indentation = 0;
+
+    while let Some(raw) = lines.next() {
+        let line = raw.trim();
+
+        if !line.is_empty() && !line.starts_with('*') && indent(raw) > indentation {
+            if let Some((_, value)) = results.last_mut() {
+                value.push(' ');
+                value.push_str(line);
+                continue;
+            }
+        }
+
         if !line.starts_with('*') {
             break;
         }
 
+        indentation = indent(raw);
+
         match regex.captures(line) {
             Some(captures) => results.insert(
                 // SAFETY: these two keys are tested for existence in the regex
@@ -238,6 +533,11 @@ fn parse_metadata(lines: &mut Peekable<Lines<'_>>) -> Result<Option<IndexMap<Str
     Ok(Some(results))
 }
 
+/// Gets the number of leading whitespace characters within a line.
+fn indent(line: &str) -> usize {
+    line.len() - line.trim_start().len()
+}
+
 fn parse_description(lines: &mut Peekable<Lines<'_>>) -> Result<String> {
     match trim_and_concat_contiguous_lines(lines) {
         Some(line) => Ok(line.to_owned()),
@@ -245,6 +545,47 @@ fn parse_description(lines: &mut Peekable<Lines<'_>>) -> Result<String> {
     }
 }
 
+/// Validates the metadata bullets of a variant.
+///
+/// The `VM Long Name`, `VM Public ID`, and `Concept Code` bullets are required
+/// (though the value of the `Concept Code` bullet may be blank), the `VM Public
+/// ID` must be an unsigned integer, and the `Begin Date` and `End Date` (or
+/// `Deprecated`) bullets (if present) must be valid dates in the `MM/DD/YYYY`
+/// format.
+fn validate_metadata(metadata: &IndexMap<String, String>) -> Result<()> {
+    for label in [VM_LONG_NAME, VM_PUBLIC_ID, CONCEPT_CODE] {
+        if !metadata.contains_key(label) {
+            return Err(ParseError::MissingMetadata(label.to_string()));
+        }
+    }
+
+    // SAFETY: the presence of this bullet was checked above.
+    let public_id = metadata.get(VM_PUBLIC_ID).unwrap().trim();
+
+    if public_id.parse::<u64>().is_err() {
+        return Err(ParseError::InvalidVmPublicId(public_id.to_string()));
+    }
+
+    if let Some(begin_date) = metadata.get(BEGIN_DATE).map(|value| value.trim()) {
+        if NaiveDate::parse_from_str(begin_date, DATE_FORMAT).is_err() {
+            return Err(ParseError::InvalidBeginDate(begin_date.to_string()));
+        }
+    }
+
+    for label in [END_DATE, DEPRECATED] {
+        if let Some(end_date) = metadata.get(label).map(|value| value.trim()) {
+            if NaiveDate::parse_from_str(end_date, DATE_FORMAT).is_err() {
+                return Err(ParseError::InvalidEndDate {
+                    label: label.to_string(),
+                    value: end_date.to_string(),
+                });
+            }
+        }
+    }
+
+    Ok(())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -293,11 +634,46 @@ mod tests {
         assert_eq!(metadata.get("Concept Code").unwrap().as_str(), "C43234");
         assert_eq!(metadata.get("Begin Date").unwrap().as_str(), "10/03/2023");
 
+        assert_eq!(value.vm_long_name(), Some("Not Reported"));
+        assert_eq!(value.vm_public_id(), Some(5612322));
+        assert_eq!(value.concept_code(), Some("C43234"));
+        assert_eq!(value.begin_date(), NaiveDate::from_ymd_opt(2023, 10, 3));
+
         assert_eq!(value.description(), "Not provided or available.");
 
         Ok(())
     }
 
+    #[test]
+    fn it_folds_wrapped_metadata_lines_into_the_previous_bullet(
+    ) -> std::result::Result<(), Box<dyn std::error::Error>> {
+        let value = "`Fresh Dissociated and Single Cell Sorted`
+
+        * **VM Long Name**: Fresh Dissociated Tissue Preparation, Single Cell
+          Sorted
+        * **VM Public ID**: 8031344
+        * **Concept Code**: C185405
+        * **Begin Date**:   02/09/2022
+
+        A tissue preparation process that separates fresh dissociated tissue
+        cells into cell populations by single cell sorting."
+            .parse::<Variant>()?;
+
+        assert_eq!(
+            value.vm_long_name(),
+            Some("Fresh Dissociated Tissue Preparation, Single Cell Sorted")
+        );
+        assert_eq!(value.vm_public_id(), Some(8031344));
+        assert_eq!(value.concept_code(), Some("C185405"));
+        assert_eq!(
+            value.description(),
+            "A tissue preparation process that separates fresh dissociated tissue cells into \
+             cell populations by single cell sorting."
+        );
+
+        Ok(())
+    }
+
     #[test]
     fn it_parses_a_variant_with_no_metadata_correctly(
     ) -> std::result::Result<(), Box<dyn std::error::Error>> {
@@ -308,6 +684,11 @@ mod tests {
 
         assert_eq!(value.permissible_value(), "Not Reported");
         assert_eq!(value.metadata(), None);
+        assert_eq!(value.vm_long_name(), None);
+        assert_eq!(value.vm_public_id(), None);
+        assert_eq!(value.concept_code(), None);
+        assert_eq!(value.begin_date(), None);
+        assert_eq!(value.deprecated(), None);
         assert_eq!(value.description(), "Not provided or available.");
 
         Ok(())
@@ -383,6 +764,172 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn it_parses_a_variant_with_an_empty_concept_code(
+    ) -> std::result::Result<(), Box<dyn std::error::Error>> {
+        let value = "`CF-M`
+
+        * **VM Long Name**: Cell-Free Methylated DNA
+        * **VM Public ID**: 14807413
+        * **Concept Code**:
+        * **Begin Date**:   04/12/2024
+
+        Cell-free DNA enriched for methylated fragments."
+            .parse::<Variant>()?;
+
+        assert_eq!(value.vm_long_name(), Some("Cell-Free Methylated DNA"));
+        assert_eq!(value.vm_public_id(), Some(14807413));
+        assert_eq!(value.concept_code(), None);
+        assert_eq!(value.begin_date(), NaiveDate::from_ymd_opt(2024, 4, 12));
+
+        // NOTE: the bullet itself is still present within the metadata.
+        assert_eq!(value.metadata().unwrap().get("Concept Code").unwrap(), "");
+
+        Ok(())
+    }
+
+    #[test]
+    fn it_parses_a_variant_without_a_begin_date(
+    ) -> std::result::Result<(), Box<dyn std::error::Error>> {
+        let value = "`ZIP`
+
+        * **VM Long Name**: ZIP Format
+        * **VM Public ID**: 11426806
+        * **Concept Code**: C190192
+
+        An archive file format."
+            .parse::<Variant>()?;
+
+        assert_eq!(value.concept_code(), Some("C190192"));
+        assert_eq!(value.begin_date(), None);
+
+        Ok(())
+    }
+
+    #[test]
+    fn it_parses_a_variant_with_an_end_date() -> std::result::Result<(), Box<dyn std::error::Error>>
+    {
+        let value = "`GC1`
+
+        * **VM Long Name**: GC1
+        * **VM Public ID**: 12345678
+        * **Concept Code**:
+        * **Begin Date**:   03/09/2023
+        * **End Date**:     01/31/2024
+
+        A retired permissible value."
+            .parse::<Variant>()?;
+
+        assert_eq!(value.permissible_value(), "GC1");
+        assert_eq!(value.begin_date(), NaiveDate::from_ymd_opt(2023, 3, 9));
+        assert_eq!(value.deprecated(), NaiveDate::from_ymd_opt(2024, 1, 31));
+
+        let value = "`GC1`
+
+        * **VM Long Name**: GC1
+        * **VM Public ID**: 12345678
+        * **Concept Code**:
+        * **Deprecated**:   01/31/2024
+
+        A retired permissible value."
+            .parse::<Variant>()?;
+
+        assert_eq!(value.deprecated(), NaiveDate::from_ymd_opt(2024, 1, 31));
+
+        Ok(())
+    }
+
+    #[test]
+    fn it_fails_to_parse_a_variant_with_a_malformed_end_date() {
+        for label in ["End Date", "Deprecated"] {
+            let err = format!(
+                "`Not Reported`
+
+                * **VM Long Name**: Not Reported
+                * **VM Public ID**: 5612322
+                * **Concept Code**: C43234
+                * **{label}**:   2024-01-31
+
+                Not provided or available."
+            )
+            .parse::<Variant>()
+            .unwrap_err();
+
+            assert_eq!(
+                err,
+                ParseError::InvalidEndDate {
+                    label: String::from(label),
+                    value: String::from("2024-01-31")
+                }
+            );
+            assert!(err.to_string().contains(&format!("`{label}`")));
+        }
+    }
+
+    #[test]
+    fn it_fails_to_parse_a_variant_with_a_missing_required_bullet() {
+        let err = "`Not Reported`
+
+        * **VM Long Name**: Not Reported
+        * **Concept Code**: C43234
+
+        Not provided or available."
+            .parse::<Variant>()
+            .unwrap_err();
+        assert_eq!(
+            err,
+            ParseError::MissingMetadata(String::from("VM Public ID"))
+        );
+
+        let err = "`Not Reported`
+
+        * **VM Long Name**: Not Reported
+        * **VM Public ID**: 5612322
+
+        Not provided or available."
+            .parse::<Variant>()
+            .unwrap_err();
+        assert_eq!(
+            err,
+            ParseError::MissingMetadata(String::from("Concept Code"))
+        );
+    }
+
+    #[test]
+    fn it_fails_to_parse_a_variant_with_an_invalid_public_id() {
+        let err = "`Not Reported`
+
+        * **VM Long Name**: Not Reported
+        * **VM Public ID**: 5612322a
+        * **Concept Code**: C43234
+
+        Not provided or available."
+            .parse::<Variant>()
+            .unwrap_err();
+        assert_eq!(err, ParseError::InvalidVmPublicId(String::from("5612322a")));
+    }
+
+    #[test]
+    fn it_fails_to_parse_a_variant_with_a_malformed_begin_date() {
+        for date in ["2023-10-03", "13/03/2023", "02/30/2023"] {
+            let err = format!(
+                "`Not Reported`
+
+                * **VM Long Name**: Not Reported
+                * **VM Public ID**: 5612322
+                * **Concept Code**: C43234
+                * **Begin Date**:   {date}
+
+                Not provided or available."
+            )
+            .parse::<Variant>()
+            .unwrap_err();
+
+            assert_eq!(err, ParseError::InvalidBeginDate(String::from(date)));
+            assert!(err.to_string().contains("`Begin Date`"));
+        }
+    }
+
     #[test]
     fn it_fails_to_parse_a_variant_with_no_documentation(
     ) -> std::result::Result<(), Box<dyn std::error::Error>> {
@@ -0,0 +1,754 @@
+//! Snapshotting the common data elements for auditing changes between
+//! releases.
+//!
+//! A [`Snapshot`] captures the standard of every common data element within
+//! this crate alongside the permissible value and metadata (e.g., the `VM
+//! Public ID`, `Concept Code`, and `Begin Date`) of each of its variants. The
+//! JSON representation of a snapshot is canonical: every object has its keys
+//! sorted, and nothing within it depends on when or where it was generated.
+//! As such, two snapshots of the same release are byte-for-byte identical.
+//!
+//! Two snapshots are compared with [`diff()`], which reports each [`Change`]
+//! per common data element.
+
+use std::collections::BTreeMap;
+use std::collections::BTreeSet;
+
+use introspect::Member;
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::parse::cde::member;
+use crate::Error;
+use crate::Result;
+use crate::CDE;
+
+/// A snapshot of a single variant of a common data element.
+//
+// NOTE: the fields are declared in alphabetical order so that the keys of the
+// serialized object are sorted.
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub struct Variant {
+    /// The metadata of the variant (keyed by label).
+    metadata: BTreeMap<String, String>,
+
+    /// The permissible value of the variant.
+    permissible_value: String,
+}
+
+impl Variant {
+    /// Gets the metadata of the [`Variant`] (keyed by label).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ccdi_cde as cde;
+    ///
+    /// use cde::parse::cde::snapshot::snapshot;
+    /// use cde::v1::subject::Sex;
+    ///
+    /// let element = snapshot::<Sex>()?;
+    /// let variant = element.variants().get("Female").unwrap();
+    ///
+    /// assert_eq!(variant.metadata().get("VM Public ID").unwrap(), "2567172");
+    /// assert_eq!(variant.metadata().get("Concept Code").unwrap(), "C16576");
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn metadata(&self) -> &BTreeMap<String, String> {
+        &self.metadata
+    }
+
+    /// Gets the permissible value of the [`Variant`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ccdi_cde as cde;
+    ///
+    /// use cde::parse::cde::snapshot::snapshot;
+    /// use cde::v1::subject::Sex;
+    ///
+    /// let element = snapshot::<Sex>()?;
+    /// let variant = element.variants().get("Female").unwrap();
+    ///
+    /// assert_eq!(variant.permissible_value(), "F");
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn permissible_value(&self) -> &str {
+        self.permissible_value.as_str()
+    }
+}
+
+/// A snapshot of a single common data element.
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub struct Element {
+    /// The standard of the common data element.
+    standard: String,
+
+    /// The variants of the common data element (keyed by identifier).
+    ///
+    /// This is empty for common data elements that are not `enum`s.
+    variants: BTreeMap<String, Variant>,
+}
+
+impl Element {
+    /// Gets the standard of the [`Element`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ccdi_cde as cde;
+    ///
+    /// use cde::parse::cde::snapshot::snapshot;
+    /// use cde::v1::subject::Sex;
+    ///
+    /// let element = snapshot::<Sex>()?;
+    /// assert_eq!(element.standard(), "caDSR CDE 6343385 v1.00");
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn standard(&self) -> &str {
+        self.standard.as_str()
+    }
+
+    /// Gets the variants of the [`Element`] (keyed by identifier).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ccdi_cde as cde;
+    ///
+    /// use cde::parse::cde::snapshot::snapshot;
+    /// use cde::v1::subject::Sex;
+    ///
+    /// let element = snapshot::<Sex>()?;
+    ///
+    /// assert_eq!(
+    ///     element.variants().keys().collect::<Vec<_>>(),
+    ///     vec!["Female", "Male", "Undifferentiated", "Unknown"]
+    /// );
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn variants(&self) -> &BTreeMap<String, Variant> {
+        &self.variants
+    }
+}
+
+/// A snapshot of every common data element within this crate.
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub struct Snapshot {
+    /// The common data elements (keyed by their path within this crate).
+    elements: BTreeMap<String, Element>,
+
+    /// The version of this crate from which the snapshot was taken.
+    version: String,
+}
+
+impl Snapshot {
+    /// Gets the common data elements within the [`Snapshot`] (keyed by their
+    /// path within this crate).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ccdi_cde as cde;
+    ///
+    /// use cde::parse::cde::snapshot::snapshot_registry;
+    ///
+    /// let snapshot = snapshot_registry()?;
+    /// assert!(snapshot.elements().contains_key("v1::subject::Sex"));
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn elements(&self) -> &BTreeMap<String, Element> {
+        &self.elements
+    }
+
+    /// Gets the version of this crate from which the [`Snapshot`] was taken.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ccdi_cde as cde;
+    ///
+    /// use cde::parse::cde::snapshot::snapshot_registry;
+    ///
+    /// let snapshot = snapshot_registry()?;
+    /// assert_eq!(snapshot.version(), env!("CARGO_PKG_VERSION"));
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn version(&self) -> &str {
+        self.version.as_str()
+    }
+
+    /// Serializes the [`Snapshot`] to its canonical JSON representation.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ccdi_cde as cde;
+    ///
+    /// use cde::parse::cde::snapshot::snapshot_registry;
+    /// use cde::parse::cde::snapshot::Snapshot;
+    ///
+    /// let json = snapshot_registry()?.to_json();
+    /// let snapshot = serde_json::from_str::<Snapshot>(&json)?;
+    ///
+    /// assert_eq!(snapshot.to_json(), json);
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn to_json(&self) -> String {
+        // SAFETY: every map within a snapshot is keyed by strings, so
+        // serialization cannot fail.
+        let mut json = serde_json::to_string_pretty(self).unwrap();
+        json.push('\n');
+        json
+    }
+}
+
+/// Takes a snapshot of a single common data element.
+///
+/// Only the variants of `enum` common data elements are captured (the fields
+/// of `struct` common data elements have no permissible values).
+///
+/// # Examples
+///
+/// ```
+/// use ccdi_cde as cde;
+///
+/// use cde::parse::cde::snapshot::snapshot;
+/// use cde::v1::subject::Sex;
+///
+/// let element = snapshot::<Sex>()?;
+///
+/// assert_eq!(element.variants().len(), 4);
+/// assert_eq!(
+///     element
+///         .variants()
+///         .get("Unknown")
+///         .unwrap()
+///         .permissible_value(),
+///     "U"
+/// );
+///
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub fn snapshot<T: CDE>() -> Result<Element> {
+    let entity = T::entity()?;
+    let mut variants = BTreeMap::new();
+
+    for introspected in T::introspected_members() {
+        let introspected = match introspected {
+            Member::Variant(introspected) => introspected,
+            Member::Field(_) => continue,
+        };
+
+        let variant = introspected
+            .documentation()
+            .ok_or(Error::MissingDocumentation)?
+            .parse::<member::Variant>()
+            .map_err(|err| Error::MemberError(member::ParseError::VariantError(err)))?;
+
+        variants.insert(
+            introspected.identifier().to_string(),
+            Variant {
+                metadata: variant
+                    .metadata()
+                    .map(|metadata| {
+                        metadata
+                            .iter()
+                            .map(|(label, value)| (label.clone(), value.clone()))
+                            .collect()
+                    })
+                    .unwrap_or_default(),
+                permissible_value: variant.permissible_value().to_string(),
+            },
+        );
+    }
+
+    Ok(Element {
+        standard: entity.standard_name().to_string(),
+        variants,
+    })
+}
+
+/// Takes a snapshot of every common data element within this crate.
+///
+/// # Examples
+///
+/// ```
+/// use ccdi_cde as cde;
+///
+/// use cde::parse::cde::snapshot::snapshot_registry;
+///
+/// // Snapshots are deterministic.
+/// assert_eq!(snapshot_registry()?, snapshot_registry()?);
+///
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub fn snapshot_registry() -> Result<Snapshot> {
+    let elements = registry!(snapshot)
+        .into_iter()
+        .map(|(name, element)| element.map(|element| (name.to_string(), element)))
+        .collect::<Result<BTreeMap<_, _>>>()?;
+
+    Ok(Snapshot {
+        elements,
+        version: env!("CARGO_PKG_VERSION").to_string(),
+    })
+}
+
+/// A change to a common data element between two [`Snapshot`]s.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Change {
+    /// The common data element was added.
+    ElementAdded {
+        /// The standard of the added common data element.
+        standard: String,
+    },
+
+    /// The common data element was removed.
+    ElementRemoved {
+        /// The standard of the removed common data element.
+        standard: String,
+    },
+
+    /// The standard of the common data element changed.
+    StandardChanged {
+        /// The previous standard.
+        old: String,
+
+        /// The new standard.
+        new: String,
+    },
+
+    /// A variant was added.
+    VariantAdded {
+        /// The identifier of the variant.
+        identifier: String,
+
+        /// The added variant.
+        variant: Variant,
+    },
+
+    /// A variant was removed.
+    VariantRemoved {
+        /// The identifier of the variant.
+        identifier: String,
+
+        /// The removed variant.
+        variant: Variant,
+    },
+
+    /// The permissible value of a variant changed (its metadata may have also
+    /// changed).
+    PermissibleValueChanged {
+        /// The identifier of the variant.
+        identifier: String,
+
+        /// The previous variant.
+        old: Variant,
+
+        /// The new variant.
+        new: Variant,
+    },
+
+    /// Only the metadata of a variant changed.
+    MetadataChanged {
+        /// The identifier of the variant.
+        identifier: String,
+
+        /// The previous variant.
+        old: Variant,
+
+        /// The new variant.
+        new: Variant,
+    },
+}
+
+impl std::fmt::Display for Change {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Change::ElementAdded { standard } => write!(f, "added (`{standard}`)"),
+            Change::ElementRemoved { standard } => write!(f, "removed (`{standard}`)"),
+            Change::StandardChanged { old, new } => {
+                write!(f, "standard changed from `{old}` to `{new}`")
+            }
+            Change::VariantAdded {
+                identifier,
+                variant,
+            } => write!(
+                f,
+                "{identifier}: added with permissible value `{}`",
+                variant.permissible_value()
+            ),
+            Change::VariantRemoved {
+                identifier,
+                variant,
+            } => write!(
+                f,
+                "{identifier}: removed with permissible value `{}`",
+                variant.permissible_value()
+            ),
+            Change::PermissibleValueChanged {
+                identifier,
+                old,
+                new,
+            } => write!(
+                f,
+                "{identifier}: permissible value changed from `{}` to `{}`",
+                old.permissible_value(),
+                new.permissible_value()
+            ),
+            Change::MetadataChanged {
+                identifier,
+                old,
+                new,
+            } => {
+                let labels = old
+                    .metadata()
+                    .keys()
+                    .chain(new.metadata().keys())
+                    .collect::<BTreeSet<_>>()
+                    .into_iter()
+                    .filter(|label| old.metadata().get(*label) != new.metadata().get(*label))
+                    .map(|label| {
+                        format!(
+                            "`{label}` from {} to {}",
+                            quote_or_none(old.metadata().get(label)),
+                            quote_or_none(new.metadata().get(label))
+                        )
+                    })
+                    .collect::<Vec<_>>();
+
+                write!(f, "{identifier}: metadata changed ({})", labels.join(", "))
+            }
+        }
+    }
+}
+
+/// Quotes a value (or returns `none` if the value does not exist).
+fn quote_or_none(value: Option<&String>) -> String {
+    match value {
+        Some(value) => format!("`{value}`"),
+        None => String::from("none"),
+    }
+}
+
+/// Computes the changes to each common data element from the `old`
+/// [`Snapshot`] to the `new` [`Snapshot`].
+///
+/// The changes are returned alongside the path of each common data element
+/// (sorted by path, and only those with at least one change are included).
+///
+/// # Examples
+///
+/// ```
+/// use ccdi_cde as cde;
+///
+/// use cde::parse::cde::snapshot::diff;
+/// use cde::parse::cde::snapshot::snapshot_registry;
+///
+/// let snapshot = snapshot_registry()?;
+/// assert!(diff(&snapshot, &snapshot).is_empty());
+///
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub fn diff(old: &Snapshot, new: &Snapshot) -> Vec<(String, Vec<Change>)> {
+    let names = old
+        .elements
+        .keys()
+        .chain(new.elements.keys())
+        .collect::<BTreeSet<_>>();
+
+    names
+        .into_iter()
+        .map(|name| {
+            let changes =
+                match (old.elements.get(name), new.elements.get(name)) {
+                    (Some(old), Some(new)) => diff_element(old, new),
+                    (Some(old), None) => std::iter::once(Change::ElementRemoved {
+                        standard: old.standard.clone(),
+                    })
+                    .chain(old.variants.iter().map(|(identifier, variant)| {
+                        Change::VariantRemoved {
+                            identifier: identifier.clone(),
+                            variant: variant.clone(),
+                        }
+                    }))
+                    .collect(),
+                    (None, Some(new)) => {
+                        std::iter::once(Change::ElementAdded {
+                            standard: new.standard.clone(),
+                        })
+                        .chain(new.variants.iter().map(|(identifier, variant)| {
+                            Change::VariantAdded {
+                                identifier: identifier.clone(),
+                                variant: variant.clone(),
+                            }
+                        }))
+                        .collect()
+                    }
+                    (None, None) => unreachable!("element was in neither snapshot: {name}"),
+                };
+
+            (name.clone(), changes)
+        })
+        .filter(|(_, changes)| !changes.is_empty())
+        .collect()
+}
+
+/// Computes the changes from the `old` [`Element`] to the `new` [`Element`].
+fn diff_element(old: &Element, new: &Element) -> Vec<Change> {
+    let mut changes = Vec::new();
+
+    if old.standard != new.standard {
+        changes.push(Change::StandardChanged {
+            old: old.standard.clone(),
+            new: new.standard.clone(),
+        });
+    }
+
+    let identifiers = old
+        .variants
+        .keys()
+        .chain(new.variants.keys())
+        .collect::<BTreeSet<_>>();
+
+    for identifier in identifiers {
+        let change = match (old.variants.get(identifier), new.variants.get(identifier)) {
+            (Some(old), Some(new)) if old == new => continue,
+            (Some(old), Some(new)) if old.permissible_value != new.permissible_value => {
+                Change::PermissibleValueChanged {
+                    identifier: identifier.clone(),
+                    old: old.clone(),
+                    new: new.clone(),
+                }
+            }
+            (Some(old), Some(new)) => Change::MetadataChanged {
+                identifier: identifier.clone(),
+                old: old.clone(),
+                new: new.clone(),
+            },
+            (Some(old), None) => Change::VariantRemoved {
+                identifier: identifier.clone(),
+                variant: old.clone(),
+            },
+            (None, Some(new)) => Change::VariantAdded {
+                identifier: identifier.clone(),
+                variant: new.clone(),
+            },
+            (None, None) => unreachable!("variant was in neither element: {identifier}"),
+        };
+
+        changes.push(change);
+    }
+
+    changes
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn old() -> Snapshot {
+        serde_json::from_str(
+            r#"{
+  "elements": {
+    "v1::subject::Removed": {
+      "standard": "caDSR CDE 1 v1.00",
+      "variants": {}
+    },
+    "v1::subject::Sex": {
+      "standard": "caDSR CDE 6343385 v1.00",
+      "variants": {
+        "Female": {
+          "metadata": {
+            "Begin Date": "06/27/2018",
+            "Concept Code": "C16576",
+            "VM Public ID": "2567172"
+          },
+          "permissible_value": "F"
+        },
+        "Male": {
+          "metadata": {
+            "Begin Date": "06/27/2018",
+            "Concept Code": "C20197",
+            "VM Public ID": "2567171"
+          },
+          "permissible_value": "M"
+        },
+        "Unknown": {
+          "metadata": {
+            "Begin Date": "06/27/2018",
+            "Concept Code": "C17998",
+            "VM Public ID": "5682944"
+          },
+          "permissible_value": "U"
+        },
+        "Unspecified": {
+          "metadata": {},
+          "permissible_value": "Unspecified"
+        }
+      }
+    }
+  },
+  "version": "1.0.0"
+}"#,
+        )
+        .unwrap()
+    }
+
+    fn new() -> Snapshot {
+        let mut snapshot = old();
+        snapshot.version = String::from("2.0.0");
+
+        snapshot.elements.remove("v1::subject::Removed");
+        snapshot.elements.insert(
+            String::from("v2::subject::Added"),
+            Element {
+                standard: String::from("caDSR CDE 2 v2.00"),
+                variants: BTreeMap::new(),
+            },
+        );
+
+        let sex = snapshot.elements.get_mut("v1::subject::Sex").unwrap();
+        sex.standard = String::from("caDSR CDE 6343385 v2.00");
+
+        // A new permissible value.
+        sex.variants.get_mut("Unknown").unwrap().permissible_value = String::from("UNKNOWN");
+
+        // A metadata-only change.
+        sex.variants
+            .get_mut("Male")
+            .unwrap()
+            .metadata
+            .insert(String::from("Begin Date"), String::from("01/01/2024"));
+
+        // A removed variant and an added variant.
+        sex.variants.remove("Unspecified");
+        sex.variants.insert(
+            String::from("Undifferentiated"),
+            Variant {
+                metadata: BTreeMap::new(),
+                permissible_value: String::from("UNDIFFERENTIATED"),
+            },
+        );
+
+        snapshot
+    }
+
+    #[test]
+    fn an_identity_diff_is_empty() {
+        assert!(diff(&old(), &old()).is_empty());
+
+        let snapshot = snapshot_registry().unwrap();
+        assert!(diff(&snapshot, &snapshot).is_empty());
+    }
+
+    #[test]
+    fn it_categorizes_each_change() {
+        let changes = diff(&old(), &new())
+            .into_iter()
+            .map(|(name, changes)| {
+                (
+                    name,
+                    changes
+                        .into_iter()
+                        .map(|change| change.to_string())
+                        .collect::<Vec<_>>(),
+                )
+            })
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            changes,
+            vec![
+                (
+                    String::from("v1::subject::Removed"),
+                    vec![String::from("removed (`caDSR CDE 1 v1.00`)")]
+                ),
+                (
+                    String::from("v1::subject::Sex"),
+                    vec![
+                        String::from(
+                            "standard changed from `caDSR CDE 6343385 v1.00` to `caDSR CDE \
+                             6343385 v2.00`"
+                        ),
+                        String::from(
+                            "Male: metadata changed (`Begin Date` from `06/27/2018` to \
+                             `01/01/2024`)"
+                        ),
+                        String::from(
+                            "Undifferentiated: added with permissible value `UNDIFFERENTIATED`"
+                        ),
+                        String::from("Unknown: permissible value changed from `U` to `UNKNOWN`"),
+                        String::from("Unspecified: removed with permissible value `Unspecified`"),
+                    ]
+                ),
+                (
+                    String::from("v2::subject::Added"),
+                    vec![String::from("added (`caDSR CDE 2 v2.00`)")]
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn it_reports_added_and_removed_metadata_labels() {
+        let old = Variant {
+            metadata: BTreeMap::from([(String::from("Begin Date"), String::from("01/01/2024"))]),
+            permissible_value: String::from("A"),
+        };
+        let new = Variant {
+            metadata: BTreeMap::from([(String::from("VM Public ID"), String::from("1"))]),
+            permissible_value: String::from("A"),
+        };
+
+        let change = Change::MetadataChanged {
+            identifier: String::from("A"),
+            old,
+            new,
+        };
+
+        assert_eq!(
+            change.to_string(),
+            "A: metadata changed (`Begin Date` from `01/01/2024` to none, `VM Public ID` from \
+             none to `1`)"
+        );
+    }
+
+    fn assert_sorted(value: &serde_json::Value) {
+        match value {
+            serde_json::Value::Object(object) => {
+                let keys = object.keys().collect::<Vec<_>>();
+
+                let mut sorted = keys.clone();
+                sorted.sort();
+
+                assert_eq!(keys, sorted);
+                object.values().for_each(assert_sorted);
+            }
+            serde_json::Value::Array(array) => array.iter().for_each(assert_sorted),
+            _ => {}
+        }
+    }
+
+    #[test]
+    fn the_registry_snapshot_is_canonical() {
+        let json = snapshot_registry().unwrap().to_json();
+
+        // The snapshot round trips exactly.
+        let snapshot = serde_json::from_str::<Snapshot>(&json).unwrap();
+        assert_eq!(snapshot.to_json(), json);
+
+        // NOTE: `serde_json` preserves the order of keys within this
+        // workspace, so this checks the order in which they were serialized.
+        assert_sorted(&serde_json::from_str::<serde_json::Value>(&json).unwrap());
+    }
+}
@@ -0,0 +1,283 @@
+//! A registry of every common data element within this crate.
+//!
+//! The registry allows consumers to enumerate the common data elements without
+//! knowing their concrete types. Every common data element must be listed
+//! within the registry (the tests within this module fail when a common data
+//! element is not).
+
+use introspect::Entity;
+use introspect::Introspected as _;
+
+use crate::parse;
+use crate::parse::cde::registry;
+use crate::Result;
+use crate::CDE;
+
+/// The kind of Rust type that describes a common data element.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Kind {
+    /// An enum (a common data element with a set of permissible values).
+    Enum,
+
+    /// A struct.
+    Struct,
+}
+
+impl std::fmt::Display for Kind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Kind::Enum => write!(f, "enum"),
+            Kind::Struct => write!(f, "struct"),
+        }
+    }
+}
+
+/// A description of a registered common data element.
+///
+/// The documentation of the common data element is parsed each time that
+/// [`CdeDescriptor::entity()`] or [`CdeDescriptor::members()`] is called.
+#[derive(Clone, Debug)]
+pub struct CdeDescriptor {
+    /// The path of the common data element within this crate (e.g.,
+    /// `v1::subject::Sex`).
+    path: &'static str,
+
+    /// The kind of Rust type that describes the common data element.
+    kind: Kind,
+
+    /// Parses the entity of the common data element.
+    entity: fn() -> Result<parse::cde::Entity>,
+
+    /// Parses the members of the common data element.
+    #[allow(clippy::type_complexity)]
+    members: fn() -> Option<Result<Vec<(Option<String>, parse::cde::Member)>>>,
+}
+
+impl CdeDescriptor {
+    /// Gets the path of the common data element within this crate.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ccdi_cde as cde;
+    ///
+    /// let descriptor = cde::registry::get("v1::subject::Sex").unwrap();
+    /// assert_eq!(descriptor.path(), "v1::subject::Sex");
+    /// ```
+    pub fn path(&self) -> &'static str {
+        self.path
+    }
+
+    /// Gets the kind of Rust type that describes the common data element.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ccdi_cde as cde;
+    ///
+    /// use cde::registry::Kind;
+    ///
+    /// let descriptor = cde::registry::get("v1::subject::Sex").unwrap();
+    /// assert_eq!(descriptor.kind(), Kind::Enum);
+    ///
+    /// let descriptor = cde::registry::get("v1::subject::Name").unwrap();
+    /// assert_eq!(descriptor.kind(), Kind::Struct);
+    /// ```
+    pub fn kind(&self) -> Kind {
+        self.kind
+    }
+
+    /// Parses the entity of the common data element (which includes the name
+    /// of the standard and the link to the caDSR).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ccdi_cde as cde;
+    ///
+    /// let descriptor = cde::registry::get("v1::subject::Sex").unwrap();
+    /// let entity = descriptor.entity()?;
+    ///
+    /// assert_eq!(entity.standard_name(), "caDSR CDE 6343385 v1.00");
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn entity(&self) -> Result<parse::cde::Entity> {
+        (self.entity)()
+    }
+
+    /// Parses the members of the common data element.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ccdi_cde as cde;
+    ///
+    /// let descriptor = cde::registry::get("v1::subject::Sex").unwrap();
+    /// let members = descriptor.members().unwrap()?;
+    ///
+    /// assert!(!members.is_empty());
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    #[allow(clippy::type_complexity)]
+    pub fn members(&self) -> Option<Result<Vec<(Option<String>, parse::cde::Member)>>> {
+        (self.members)()
+    }
+}
+
+/// Creates a [`CdeDescriptor`] for a common data element (without its path,
+/// which is provided by the registry).
+fn descriptor<T: CDE>() -> CdeDescriptor {
+    let kind = match T::introspected_entity() {
+        Entity::Enum(_) => Kind::Enum,
+        Entity::Struct(_) => Kind::Struct,
+    };
+
+    CdeDescriptor {
+        path: "",
+        kind,
+        entity: T::entity,
+        members: T::members,
+    }
+}
+
+/// Gets a [`CdeDescriptor`] for every common data element within this crate
+/// (ordered by path).
+///
+/// # Examples
+///
+/// ```
+/// use ccdi_cde as cde;
+///
+/// let descriptors = cde::registry::all();
+///
+/// assert!(descriptors
+///     .iter()
+///     .any(|descriptor| descriptor.path() == "v1::subject::Sex"));
+/// ```
+pub fn all() -> Vec<CdeDescriptor> {
+    registry!(descriptor)
+        .into_iter()
+        .map(|(path, descriptor)| CdeDescriptor { path, ..descriptor })
+        .collect()
+}
+
+/// Gets the [`CdeDescriptor`] for the common data element at the provided path
+/// (if it is registered).
+///
+/// # Examples
+///
+/// ```
+/// use ccdi_cde as cde;
+///
+/// assert!(cde::registry::get("v1::subject::Sex").is_some());
+/// assert!(cde::registry::get("v1::subject::Unknown").is_none());
+/// ```
+pub fn get(path: &str) -> Option<CdeDescriptor> {
+    all()
+        .into_iter()
+        .find(|descriptor| descriptor.path() == path)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeSet;
+    use std::path::Path;
+
+    use regex::Regex;
+
+    use super::*;
+
+    /// Gets the key (`VERSION::MODULE::TYPE`) of every type within the source
+    /// of the provided directory that implements [`CDE`].
+    fn implementations(directory: &Path, prefix: &[String], keys: &mut BTreeSet<String>) {
+        let pattern = Regex::new(r"impl CDE for (?P<name>\w+)").unwrap();
+
+        for entry in std::fs::read_dir(directory).unwrap() {
+            let path = entry.unwrap().path();
+            let stem = path.file_stem().unwrap().to_str().unwrap().to_string();
+
+            let mut prefix = prefix.to_vec();
+            prefix.push(stem);
+
+            if path.is_dir() {
+                implementations(&path, &prefix, keys);
+                continue;
+            }
+
+            let contents = std::fs::read_to_string(&path).unwrap();
+
+            for captures in pattern.captures_iter(&contents) {
+                keys.insert(format!(
+                    "{}::{}::{}",
+                    prefix[0], prefix[1], &captures["name"]
+                ));
+            }
+        }
+    }
+
+    #[test]
+    fn every_cde_is_registered() {
+        let source = Path::new(env!("CARGO_MANIFEST_DIR")).join("src");
+
+        let mut implemented = BTreeSet::new();
+
+        // NOTE: every version module (e.g., `v1`) is searched so that the
+        // common data elements within a new version module are also checked.
+        let version = Regex::new(r"^v\d+$").unwrap();
+
+        for entry in std::fs::read_dir(&source).unwrap() {
+            let path = entry.unwrap().path();
+            let name = path.file_name().unwrap().to_str().unwrap().to_string();
+
+            if path.is_dir() && version.is_match(&name) {
+                implementations(&path, &[name], &mut implemented);
+            }
+        }
+
+        // NOTE: common data elements may be nested within submodules (e.g.,
+        // `v1::file::checksum::MD5`), so only the version, the top-level
+        // module, and the name of the type are compared.
+        let registered = all()
+            .iter()
+            .map(|descriptor| {
+                let parts = descriptor.path().split("::").collect::<Vec<_>>();
+                format!("{}::{}::{}", parts[0], parts[1], parts[parts.len() - 1])
+            })
+            .collect::<BTreeSet<_>>();
+
+        let unregistered = implemented.difference(&registered).collect::<Vec<_>>();
+
+        assert!(
+            unregistered.is_empty(),
+            "the following common data elements are not registered: {unregistered:?}"
+        );
+    }
+
+    #[test]
+    fn every_registered_cde_parses() {
+        for descriptor in all() {
+            if let Err(err) = descriptor.entity() {
+                panic!("{}: {err}", descriptor.path());
+            }
+
+            match descriptor.members() {
+                Some(Ok(_)) => {}
+                Some(Err(err)) => panic!("{}: {err}", descriptor.path()),
+                None => panic!("{}: missing member documentation", descriptor.path()),
+            }
+        }
+    }
+
+    #[test]
+    fn registered_paths_are_unique() {
+        let descriptors = all();
+        let paths = descriptors
+            .iter()
+            .map(|descriptor| descriptor.path())
+            .collect::<BTreeSet<_>>();
+
+        assert_eq!(paths.len(), descriptors.len());
+    }
+}
@@ -0,0 +1,309 @@
+//! Localized display labels for the permissible values of common data
+//! elements.
+//!
+//! The permissible values of a common data element are what is sent over the
+//! wire, and they are never translated. Some consumers, however, wish to show
+//! a localized label for a permissible value to their users (e.g., `Femenino`
+//! for the `F` permissible value of `caDSR CDE 6343385 v1.00`). A
+//! [`Translations`] table provides those labels.
+//!
+//! Translations are written in TOML and are keyed by the standard name of the
+//! common data element, then by the permissible value, and then by the locale
+//! (an [IETF BCP 47](https://www.rfc-editor.org/info/bcp47) language tag):
+//!
+//! ```toml
+//! ["caDSR CDE 6343385 v1.00".F]
+//! es = "Femenino"
+//! ```
+
+use std::collections::HashMap;
+#[cfg(not(target_arch = "wasm32"))]
+use std::path::Path;
+use std::str::FromStr;
+
+use lazy_static::lazy_static;
+use regex::Regex;
+
+/// The translations that are embedded within this crate.
+pub const EMBEDDED: &str = include_str!("../translations.toml");
+
+/// The pattern that a locale must match.
+const LOCALE_PATTERN: &str = r"^[A-Za-z]{2,3}(-[A-Za-z0-9]{1,8})*$";
+
+lazy_static! {
+    // SAFETY: the embedded translations are tested to load successfully below.
+    static ref EMBEDDED_TRANSLATIONS: Translations = EMBEDDED.parse().unwrap();
+}
+
+/// An error related to loading [`Translations`].
+#[derive(Debug)]
+pub enum LoadError {
+    /// An input/output error occurred while reading the translations.
+    Io(std::io::Error),
+
+    /// The translations were not valid TOML or did not match the expected
+    /// structure.
+    Toml(toml::de::Error),
+
+    /// A locale within the translations was not a valid language tag.
+    InvalidLocale {
+        /// The standard name of the common data element.
+        standard: String,
+
+        /// The permissible value.
+        value: String,
+
+        /// The invalid locale.
+        locale: String,
+    },
+}
+
+impl std::fmt::Display for LoadError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            LoadError::Io(err) => write!(f, "i/o error: {err}"),
+            LoadError::Toml(err) => write!(f, "toml error: {err}"),
+            LoadError::InvalidLocale {
+                standard,
+                value,
+                locale,
+            } => write!(
+                f,
+                "invalid locale for permissible value \"{value}\" of {standard}: \"{locale}\""
+            ),
+        }
+    }
+}
+
+impl std::error::Error for LoadError {}
+
+/// A table of localized display labels for permissible values.
+///
+/// Locales are matched case-insensitively. If no label exists for a region
+/// specific locale (e.g., `es-MX`), the label for the primary language (e.g.,
+/// `es`) is used instead.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct Translations(HashMap<String, HashMap<String, HashMap<String, String>>>);
+
+impl Translations {
+    /// Gets the translations that are embedded within this crate.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ccdi_cde as cde;
+    ///
+    /// use cde::translations::Translations;
+    ///
+    /// let translations = Translations::embedded();
+    /// assert_eq!(
+    ///     translations.label("caDSR CDE 6343385 v1.00", "F", "es"),
+    ///     Some("Femenino")
+    /// );
+    /// ```
+    pub fn embedded() -> &'static Translations {
+        &EMBEDDED_TRANSLATIONS
+    }
+
+    /// Loads [`Translations`] from a TOML file.
+    ///
+    /// This method is not available when compiling to WebAssembly, as there is
+    /// no filesystem to read from. Parse the contents of the file using
+    /// [`str::parse()`] instead.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use ccdi_cde as cde;
+    ///
+    /// use cde::translations::Translations;
+    ///
+    /// let translations = Translations::from_path("translations.toml")?;
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn from_path(path: impl AsRef<Path>) -> Result<Self, LoadError> {
+        std::fs::read_to_string(path)
+            .map_err(LoadError::Io)?
+            .parse()
+    }
+
+    /// Gets the label for a permissible value of the common data element with
+    /// the provided standard name in the provided locale (if it exists).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ccdi_cde as cde;
+    ///
+    /// use cde::translations::Translations;
+    ///
+    /// let translations = r#"["caDSR CDE 6343385 v1.00".F]
+    /// es = "Femenino"
+    /// "#
+    /// .parse::<Translations>()?;
+    ///
+    /// assert_eq!(
+    ///     translations.label("caDSR CDE 6343385 v1.00", "F", "es-MX"),
+    ///     Some("Femenino")
+    /// );
+    /// assert_eq!(
+    ///     translations.label("caDSR CDE 6343385 v1.00", "M", "es"),
+    ///     None
+    /// );
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn label(&self, standard: &str, value: &str, locale: &str) -> Option<&str> {
+        let labels = self.0.get(standard)?.get(value)?;
+        let locale = locale.to_ascii_lowercase();
+
+        labels
+            .get(&locale)
+            .or_else(|| {
+                locale
+                    .split_once('-')
+                    .and_then(|(language, _)| labels.get(language))
+            })
+            .map(|label| label.as_str())
+    }
+}
+
+impl FromStr for Translations {
+    type Err = LoadError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let table = toml::from_str::<HashMap<String, HashMap<String, HashMap<String, String>>>>(s)
+            .map_err(LoadError::Toml)?;
+
+        // SAFETY: we test that this pattern unwraps statically below.
+        let regex = Regex::new(LOCALE_PATTERN).unwrap();
+        let mut results = HashMap::new();
+
+        for (standard, values) in table {
+            let mut translated_values = HashMap::new();
+
+            for (value, labels) in values {
+                let mut translated_labels = HashMap::new();
+
+                for (locale, label) in labels {
+                    if !regex.is_match(&locale) {
+                        return Err(LoadError::InvalidLocale {
+                            standard,
+                            value,
+                            locale,
+                        });
+                    }
+
+                    translated_labels.insert(locale.to_ascii_lowercase(), label);
+                }
+
+                translated_values.insert(value, translated_labels);
+            }
+
+            results.insert(standard, translated_values);
+        }
+
+        Ok(Self(results))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::v1::subject::Sex;
+    use crate::v1::subject::VitalStatus;
+    use crate::CDE as _;
+
+    use super::*;
+
+    #[test]
+    fn the_locale_pattern_compiles_and_matches() {
+        let regex = Regex::new(LOCALE_PATTERN).unwrap();
+
+        assert!(regex.is_match("es"));
+        assert!(regex.is_match("es-MX"));
+        assert!(regex.is_match("zh-Hant-TW"));
+        assert!(!regex.is_match("spanish language"));
+        assert!(!regex.is_match(""));
+    }
+
+    #[test]
+    fn the_embedded_translations_load() {
+        let translations = Translations::embedded();
+        assert_eq!(
+            translations.label("caDSR CDE 2847330 v1.00", "Not reported", "es"),
+            Some("No informado")
+        );
+    }
+
+    #[test]
+    fn it_localizes_a_translated_value() {
+        let translations = Translations::embedded();
+
+        assert_eq!(
+            Sex::Female.localized_label("es", translations),
+            Some("Femenino")
+        );
+        assert_eq!(
+            Sex::Female.localized_label("ES-mx", translations),
+            Some("Femenino")
+        );
+        assert_eq!(
+            VitalStatus::Dead.localized_label("es", translations),
+            Some("Fallecido")
+        );
+    }
+
+    #[test]
+    fn it_does_not_localize_a_missing_translation() {
+        let translations = Translations::embedded();
+
+        assert_eq!(Sex::Female.localized_label("fr", translations), None);
+        assert_eq!(
+            Sex::Female.localized_label("es", &Translations::default()),
+            None
+        );
+    }
+
+    #[test]
+    fn it_fails_to_load_malformed_toml() {
+        let err = r#"["caDSR CDE 6343385 v1.00".F]
+es = "Femenino"#
+            .parse::<Translations>()
+            .unwrap_err();
+
+        assert!(matches!(err, LoadError::Toml(_)));
+    }
+
+    #[test]
+    fn it_fails_to_load_translations_with_the_wrong_structure() {
+        let err = r#"["caDSR CDE 6343385 v1.00"]
+F = "Femenino""#
+            .parse::<Translations>()
+            .unwrap_err();
+
+        assert!(matches!(err, LoadError::Toml(_)));
+
+        let err = r#"["caDSR CDE 6343385 v1.00".F]
+es = 1"#
+            .parse::<Translations>()
+            .unwrap_err();
+
+        assert!(matches!(err, LoadError::Toml(_)));
+    }
+
+    #[test]
+    fn it_fails_to_load_translations_with_an_invalid_locale() {
+        let err = r#"["caDSR CDE 6343385 v1.00".F]
+"spanish language" = "Femenino""#
+            .parse::<Translations>()
+            .unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            "invalid locale for permissible value \"F\" of caDSR CDE 6343385 v1.00: \"spanish \
+             language\""
+        );
+    }
+}
@@ -1,107 +1,78 @@
 use introspect::Introspect;
-use rand::distributions::Standard;
-use rand::prelude::Distribution;
 use serde::Deserialize;
 use serde::Serialize;
 use utoipa::ToSchema;
 
 use crate::CDE;
 
-/// **`caDSR CDE 14688604 v1.00`**
-///
-/// This metadata element is defined by the caDSR as "The category assigned to the
-/// cytologic atypia found in cellular molecules, cells, tissues, organs,
-/// body fluids, or body excretory products."
-///
-/// Link:
-/// <https://cadsr.cancer.gov/onedata/dmdirect/NIH/NCI/CO/CDEDD?filter=CDEDD.ITEM_ID=14688604%20and%20ver_nr=1>
-#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize, ToSchema, Introspect)]
-#[schema(as = cde::v1::sample::TissueType)]
-pub enum TissueType {
-    /// `Not Reported`
+crate::value::cde_enum! {
+    /// **`caDSR CDE 14688604 v1.00`**
     ///
-    /// * **VM Long Name**: Not Reported
-    /// * **VM Public ID**: 5612322
-    /// * **Concept Code**: C43234
-    /// * **Begin Date**:   01/18/2024
+    /// This metadata element is defined by the caDSR as "The category assigned to the
+    /// cytologic atypia found in cellular molecules, cells, tissues, organs,
+    /// body fluids, or body excretory products."
     ///
-    /// Not provided or available.
-    #[serde(rename = "Not Reported")]
-    NotReported,
+    /// Link:
+    /// <https://cadsr.cancer.gov/onedata/dmdirect/NIH/NCI/CO/CDEDD?filter=CDEDD.ITEM_ID=14688604%20and%20ver_nr=1>
+    #[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize, ToSchema, Introspect)]
+    #[schema(as = cde::v1::sample::TissueType)]
+    pub enum TissueType {
+        /// `Not Reported`
+        ///
+        /// * **VM Long Name**: Not Reported
+        /// * **VM Public ID**: 5612322
+        /// * **Concept Code**: C43234
+        /// * **Begin Date**:   01/18/2024
+        ///
+        /// Not provided or available.
+        NotReported => "Not Reported",
 
-    /// `Normal`
-    ///
-    /// * **VM Long Name**: Normal Tissue Sample
-    /// * **VM Public ID**: 14741231
-    /// * **Concept Code**: C162623
-    /// * **Begin Date**:   01/31/2024
-    ///
-    /// Tissue sample with cellular composition and architectural patterns expected
-    /// for the particular anatomic site in which it belongs. There is no evidence
-    /// of abnormal cellular infiltrates or tumor mass formation.
-    #[serde(rename = "Normal")]
-    Normal,
+        /// `Normal`
+        ///
+        /// * **VM Long Name**: Normal Tissue Sample
+        /// * **VM Public ID**: 14741231
+        /// * **Concept Code**: C162623
+        /// * **Begin Date**:   01/31/2024
+        ///
+        /// Tissue sample with cellular composition and architectural patterns expected
+        /// for the particular anatomic site in which it belongs. There is no evidence
+        /// of abnormal cellular infiltrates or tumor mass formation.
+        Normal => "Normal",
 
-    /// `Peritumoral`
-    ///
-    /// * **VM Long Name**: Tumor-Adjacent Normal Specimen
-    /// * **VM Public ID**: 13332906
-    /// * **Concept Code**: C164032
-    /// * **Begin Date**:   01/18/2024
-    ///
-    /// A specimen comprised of morphologically normal tissue collected from the
-    /// area immediately surrounding a tumor in an experimental subject.
-    #[serde(rename = "Peritumoral")]
-    Peritumoral,
+        /// `Peritumoral`
+        ///
+        /// * **VM Long Name**: Tumor-Adjacent Normal Specimen
+        /// * **VM Public ID**: 13332906
+        /// * **Concept Code**: C164032
+        /// * **Begin Date**:   01/18/2024
+        ///
+        /// A specimen comprised of morphologically normal tissue collected from the
+        /// area immediately surrounding a tumor in an experimental subject.
+        Peritumoral => "Peritumoral",
 
-    /// `Tumor`
-    ///
-    /// * **VM Long Name**: Tumor Tissue
-    /// * **VM Public ID**: 3184945
-    /// * **Concept Code**: C18009
-    /// * **Begin Date**:   01/31/2024
-    ///
-    /// A tissue sample, or entire tumor that is removed for microscopic examination.
-    #[serde(rename = "Tumor")]
-    Tumor,
+        /// `Tumor`
+        ///
+        /// * **VM Long Name**: Tumor Tissue
+        /// * **VM Public ID**: 3184945
+        /// * **Concept Code**: C18009
+        /// * **Begin Date**:   01/31/2024
+        ///
+        /// A tissue sample, or entire tumor that is removed for microscopic examination.
+        Tumor => "Tumor",
 
-    /// `Unknown`
-    ///
-    /// * **VM Long Name**: Unknown
-    /// * **VM Public ID**: 5682953
-    /// * **Concept Code**: C17998
-    /// * **Begin Date**:   05/16/2017
-    ///
-    /// Not known, not observed, not recorded, or refused.
-    #[serde(rename = "Unknown")]
-    Unknown,
-}
-
-impl CDE for TissueType {}
-
-impl std::fmt::Display for TissueType {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match self {
-            TissueType::NotReported => write!(f, "Not Reported"),
-            TissueType::Normal => write!(f, "Normal"),
-            TissueType::Peritumoral => write!(f, "Peritumoral"),
-            TissueType::Tumor => write!(f, "Tumor"),
-            TissueType::Unknown => write!(f, "Unknown"),
-        }
+        /// `Unknown`
+        ///
+        /// * **VM Long Name**: Unknown
+        /// * **VM Public ID**: 5682953
+        /// * **Concept Code**: C17998
+        /// * **Begin Date**:   05/16/2017
+        ///
+        /// Not known, not observed, not recorded, or refused.
+        Unknown => "Unknown",
     }
 }
 
-impl Distribution<TissueType> for Standard {
-    fn sample<R: rand::Rng + ?Sized>(&self, rng: &mut R) -> TissueType {
-        match rng.gen_range(0..=4) {
-            0 => TissueType::NotReported,
-            1 => TissueType::Normal,
-            2 => TissueType::Peritumoral,
-            3 => TissueType::Tumor,
-            _ => TissueType::Unknown,
-        }
-    }
-}
+impl CDE for TissueType {}
 
 #[cfg(test)]
 mod tests {
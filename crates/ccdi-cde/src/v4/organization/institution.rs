@@ -46,3 +46,50 @@ impl std::fmt::Display for Institution {
         write!(f, "{}", self.0)
     }
 }
+
+impl std::str::FromStr for Institution {
+    type Err = std::convert::Infallible;
+
+    /// Parses an [`Institution`] from a string.
+    ///
+    /// Any name of an institution is permissible, so parsing never fails.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ccdi_cde as cde;
+    ///
+    /// use cde::v4::organization::Institution;
+    ///
+    /// let institution = "St. Jude Children's Research Hospital"
+    ///     .parse::<Institution>()
+    ///     .unwrap();
+    /// assert_eq!(
+    ///     institution.to_string(),
+    ///     "St. Jude Children's Research Hospital"
+    /// );
+    /// ```
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(Self(s.to_owned()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::v4::organization::Institution;
+
+    #[test]
+    fn it_round_trips_any_institution_name() {
+        let institution = Institution::from(String::from("An Institution Not Known by caDSR"));
+
+        let serialized = serde_json::to_string(&institution).unwrap();
+        assert_eq!(serialized, r#""An Institution Not Known by caDSR""#);
+
+        let deserialized = serde_json::from_str::<Institution>(&serialized).unwrap();
+        assert_eq!(deserialized, institution);
+        assert_eq!(
+            deserialized.to_string(),
+            "An Institution Not Known by caDSR"
+        );
+    }
+}
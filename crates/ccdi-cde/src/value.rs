@@ -0,0 +1,422 @@
+//! Parsing common data elements from their permissible values.
+//!
+//! Every common data element that is an enum implements
+//! [`FromStr`](std::str::FromStr) as the exact inverse of its
+//! [`Display`](std::fmt::Display) implementation, so a permissible value that
+//! was received as a plain string (e.g., from a TSV or a command line
+//! argument) can be parsed without first quoting it as JSON.
+//!
+//! ```
+//! use ccdi_cde as cde;
+//!
+//! use cde::v1::sample::LibraryStrategy;
+//!
+//! let strategy = "WGS".parse::<LibraryStrategy>()?;
+//! assert_eq!(strategy, LibraryStrategy::Wgs);
+//! assert_eq!(strategy.to_string(), "WGS");
+//!
+//! let err = "Foo-Seq".parse::<LibraryStrategy>().unwrap_err();
+//! assert_eq!(err.value(), "Foo-Seq");
+//! assert_eq!(err.standard_name(), "caDSR CDE 6273393 v1.00");
+//!
+//! # Ok::<(), Box<dyn std::error::Error>>(())
+//! ```
+//!
+//! Common data elements that are enums are declared with `cde_enum!`, which
+//! takes the permissible value of each variant exactly once and derives the
+//! serialized form, the [`Display`](std::fmt::Display) form, parsing, and
+//! sampling from it. Adding a permissible value is thus a one-line change
+//! (alongside its documentation).
+
+use crate::CDE;
+
+/// An error when parsing a common data element from a string that is not one
+/// of its permissible values.
+#[derive(Debug, Eq, PartialEq)]
+pub struct ParseError {
+    /// The value that could not be parsed.
+    value: String,
+
+    /// The standard name of the common data element.
+    standard_name: String,
+}
+
+impl ParseError {
+    /// Creates a new [`ParseError`] for the common data element `T`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ccdi_cde as cde;
+    ///
+    /// use cde::v1::subject::Sex;
+    /// use cde::value::ParseError;
+    ///
+    /// let err = ParseError::new::<Sex>("X");
+    /// assert_eq!(err.value(), "X");
+    /// assert_eq!(err.standard_name(), "caDSR CDE 6343385 v1.00");
+    /// ```
+    pub fn new<T: CDE>(value: impl Into<String>) -> Self {
+        let standard_name = T::entity()
+            .map(|entity| entity.standard_name().to_owned())
+            // NOTE: every common data element within this crate is tested to
+            // have a parsable entity, so this is only a fallback.
+            .unwrap_or_else(|_| std::any::type_name::<T>().to_owned());
+
+        Self {
+            value: value.into(),
+            standard_name,
+        }
+    }
+
+    /// Gets the value that could not be parsed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ccdi_cde as cde;
+    ///
+    /// use cde::v1::subject::Sex;
+    ///
+    /// let err = "X".parse::<Sex>().unwrap_err();
+    /// assert_eq!(err.value(), "X");
+    /// ```
+    pub fn value(&self) -> &str {
+        self.value.as_str()
+    }
+
+    /// Gets the standard name of the common data element.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ccdi_cde as cde;
+    ///
+    /// use cde::v1::subject::Sex;
+    ///
+    /// let err = "X".parse::<Sex>().unwrap_err();
+    /// assert_eq!(err.standard_name(), "caDSR CDE 6343385 v1.00");
+    /// ```
+    pub fn standard_name(&self) -> &str {
+        self.standard_name.as_str()
+    }
+}
+
+impl std::fmt::Display for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "`{}` is not a permissible value of {}",
+            self.value, self.standard_name
+        )
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// Implements [`FromStr`](std::str::FromStr) for a common data element that
+/// is an enum.
+///
+/// The permissible value of each variant is its serialized form, which is
+/// tested to be identical to its [`Display`](std::fmt::Display) form, so
+/// parsing is the exact inverse of displaying.
+macro_rules! impl_from_str {
+    ($ty:ty) => {
+        impl std::str::FromStr for $ty {
+            type Err = $crate::value::ParseError;
+
+            fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+                serde_json::from_value(serde_json::Value::String(s.to_owned()))
+                    .map_err(|_| $crate::value::ParseError::new::<Self>(s))
+            }
+        }
+    };
+}
+
+pub(crate) use impl_from_str;
+
+/// Filters `variants` down to those whose permissible values have not been
+/// retired (see [`CDE::deprecated_values()`]).
+///
+/// If every variant has been retired (or the variants cannot be parsed), all
+/// of the `variants` are returned so that there is always something to sample.
+pub(crate) fn current<T>(variants: &[T]) -> Vec<T>
+where
+    T: CDE + Clone,
+{
+    let deprecated = T::deprecated_values().unwrap_or_default();
+
+    let current = variants
+        .iter()
+        .filter(|variant| !deprecated.contains_key(&variant.to_string()))
+        .cloned()
+        .collect::<Vec<_>>();
+
+    match current.is_empty() {
+        true => variants.to_vec(),
+        false => current,
+    }
+}
+
+/// Implements [`Distribution`](rand::distributions::Distribution) for a common
+/// data element that is an enum such that each of its `variants` is sampled
+/// with equal probability.
+///
+/// The variants are listed exactly once (in declaration order), so the range
+/// that is sampled from can never drift from the list itself. Whether every
+/// variant of each enum is listed is tested below. Variants whose permissible
+/// values have been retired are never sampled.
+macro_rules! impl_distribution {
+    ($ty:ident, [$($variant:ident),+ $(,)?]) => {
+        impl rand::distributions::Distribution<$ty> for rand::distributions::Standard {
+            fn sample<R: rand::Rng + ?Sized>(&self, rng: &mut R) -> $ty {
+                const VARIANTS: &[$ty] = &[$($ty::$variant),+];
+
+                lazy_static::lazy_static! {
+                    // NOTE: the documentation is parsed only once (rather than
+                    // on every sample).
+                    static ref CURRENT: Vec<$ty> = $crate::value::current(VARIANTS);
+                }
+
+                CURRENT[rng.gen_range(0..CURRENT.len())].clone()
+            }
+        }
+    };
+}
+
+pub(crate) use impl_distribution;
+
+/// Declares a common data element that is an enum from a single list of its
+/// variants and their permissible values.
+///
+/// Each variant is written as `Variant => "Permissible Value"` (after its
+/// documentation and any other attributes). The permissible value is used as
+///
+/// * the serialized form of the variant (`#[serde(rename)]`),
+/// * the [`Display`](std::fmt::Display) form of the variant,
+/// * the value parsed by [`FromStr`](std::str::FromStr) (see
+///   [`impl_from_str!`]), and
+/// * the variants that are sampled from (see [`impl_distribution!`]).
+///
+/// The attributes of the enum (including its derives) are passed through
+/// unchanged, so the enum must derive [`Serialize`](serde::Serialize) and
+/// [`Deserialize`](serde::Deserialize). [`CDE`] is implemented separately.
+macro_rules! cde_enum {
+    (
+        $(#[$meta:meta])*
+        $vis:vis enum $name:ident {
+            $(
+                $(#[$variant_meta:meta])*
+                $variant:ident => $value:literal
+            ),+ $(,)?
+        }
+    ) => {
+        $(#[$meta])*
+        $vis enum $name {
+            $(
+                $(#[$variant_meta])*
+                #[serde(rename = $value)]
+                $variant,
+            )+
+        }
+
+        $crate::value::impl_from_str!($name);
+
+        impl std::fmt::Display for $name {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str(match self {
+                    $($name::$variant => $value,)+
+                })
+            }
+        }
+
+        $crate::value::impl_distribution!($name, [$($variant),+]);
+    };
+}
+
+pub(crate) use cde_enum;
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+    use std::fmt::Debug;
+    use std::str::FromStr;
+
+    use introspect::Introspect;
+    use rand::distributions::Distribution;
+    use rand::distributions::Standard;
+    use rand::rngs::StdRng;
+    use rand::Rng as _;
+    use rand::SeedableRng as _;
+    use serde::Deserialize;
+    use serde::Serialize;
+
+    use crate::v1;
+    use crate::v2;
+
+    use super::*;
+
+    /// Asserts that every permissible value of `T` (as documented on each of
+    /// its variants) round trips through [`FromStr`] and
+    /// [`Display`](std::fmt::Display).
+    fn assert_round_trips<T>()
+    where
+        T: CDE + Debug + FromStr<Err = ParseError>,
+    {
+        let members = T::members().unwrap().unwrap();
+        assert!(!members.is_empty());
+
+        for (_, member) in members {
+            let permissible_value = member.get_variant().unwrap().permissible_value();
+
+            let value = permissible_value.parse::<T>().unwrap();
+            assert_eq!(value.to_string(), permissible_value);
+            assert_eq!(value.to_string().parse::<T>().unwrap(), value);
+        }
+
+        let err = "Not a Permissible Value".parse::<T>().unwrap_err();
+        assert_eq!(err.value(), "Not a Permissible Value");
+        assert_eq!(err.standard_name(), T::entity().unwrap().standard_name());
+    }
+
+    #[test]
+    fn every_enum_round_trips() {
+        assert_round_trips::<v1::file::Type>();
+        assert_round_trips::<v1::sample::DiagnosisCategory>();
+        assert_round_trips::<v1::sample::DiseasePhase>();
+        assert_round_trips::<v1::sample::LibrarySourceMaterial>();
+        assert_round_trips::<v1::sample::LibraryStrategy>();
+        assert_round_trips::<v1::sample::SpecimenMolecularAnalyteType>();
+        assert_round_trips::<v1::sample::TissueType>();
+        assert_round_trips::<v1::sample::TumorClassification>();
+        assert_round_trips::<v1::subject::Race>();
+        assert_round_trips::<v1::subject::Sex>();
+        assert_round_trips::<v1::subject::VitalStatus>();
+        assert_round_trips::<v2::sample::LibrarySelectionMethod>();
+        assert_round_trips::<v2::sample::PreservationMethod>();
+        assert_round_trips::<v2::sample::TumorGrade>();
+        assert_round_trips::<v2::subject::Ethnicity>();
+    }
+
+    /// The number of times each enum is sampled.
+    const SAMPLES: usize = 10_000;
+
+    /// Asserts that every variant of `T` (except those that have been retired)
+    /// is reachable when sampling from the [`Standard`] distribution.
+    fn assert_every_variant_is_sampled<T>()
+    where
+        T: CDE + Debug + std::fmt::Display,
+        Standard: Distribution<T>,
+    {
+        let mut rng = StdRng::seed_from_u64(0);
+
+        let sampled = (0..SAMPLES)
+            .map(|_| rng.gen::<T>().to_string())
+            .collect::<HashSet<_>>();
+
+        let deprecated = T::deprecated_values().unwrap();
+        assert!(deprecated.keys().all(|value| !sampled.contains(value)));
+
+        assert_eq!(
+            sampled.len(),
+            T::distinct_value_count().unwrap() - deprecated.len(),
+            "{}",
+            T::entity().unwrap().standard_name()
+        );
+    }
+
+    #[test]
+    fn every_variant_of_every_enum_is_sampled() {
+        assert_every_variant_is_sampled::<v1::file::Type>();
+        assert_every_variant_is_sampled::<v1::sample::DiagnosisCategory>();
+        assert_every_variant_is_sampled::<v1::sample::DiseasePhase>();
+        assert_every_variant_is_sampled::<v1::sample::LibrarySourceMaterial>();
+        assert_every_variant_is_sampled::<v1::sample::LibraryStrategy>();
+        assert_every_variant_is_sampled::<v1::sample::SpecimenMolecularAnalyteType>();
+        assert_every_variant_is_sampled::<v1::sample::TissueType>();
+        assert_every_variant_is_sampled::<v1::sample::TumorClassification>();
+        assert_every_variant_is_sampled::<v1::subject::Race>();
+        assert_every_variant_is_sampled::<v1::subject::Sex>();
+        assert_every_variant_is_sampled::<v1::subject::VitalStatus>();
+        assert_every_variant_is_sampled::<v2::sample::LibrarySelectionMethod>();
+        assert_every_variant_is_sampled::<v2::sample::PreservationMethod>();
+        assert_every_variant_is_sampled::<v2::sample::TumorGrade>();
+        assert_every_variant_is_sampled::<v2::subject::Ethnicity>();
+    }
+
+    cde_enum! {
+        /// **`caDSR CDE 1 v1.00`**
+        ///
+        /// A fixture with a retired permissible value.
+        ///
+        /// Link:
+        /// <https://example.com>
+        #[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize, Introspect)]
+        enum Fixture {
+            /// `Current Value`
+            ///
+            /// * **VM Long Name**: Current
+            /// * **VM Public ID**: 1
+            /// * **Concept Code**: C1
+            ///
+            /// A permissible value that is still in use.
+            Current => "Current Value",
+
+            /// `Retired Value`
+            ///
+            /// * **VM Long Name**: Retired
+            /// * **VM Public ID**: 2
+            /// * **Concept Code**: C2
+            /// * **Deprecated**:   01/31/2024
+            ///
+            /// A permissible value that has been retired.
+            Retired => "Retired Value",
+        }
+    }
+
+    impl CDE for Fixture {}
+
+    #[test]
+    fn retired_variants_are_never_sampled() {
+        assert_eq!(
+            current(&[Fixture::Current, Fixture::Retired]),
+            vec![Fixture::Current]
+        );
+        assert_eq!(current(&[Fixture::Retired]), vec![Fixture::Retired]);
+
+        let mut rng = StdRng::seed_from_u64(0);
+        assert!((0..SAMPLES).all(|_| rng.gen::<Fixture>() == Fixture::Current));
+    }
+
+    #[test]
+    fn it_derives_every_form_from_the_permissible_value() {
+        assert_round_trips::<Fixture>();
+
+        assert_eq!(Fixture::Current.to_string(), "Current Value");
+        assert_eq!(
+            serde_json::to_string(&Fixture::Retired).unwrap(),
+            r#""Retired Value""#
+        );
+        assert_eq!(
+            "Retired Value".parse::<Fixture>().unwrap(),
+            Fixture::Retired
+        );
+        assert!("Retired".parse::<Fixture>().is_err());
+    }
+
+    #[test]
+    fn it_does_not_trim_or_ignore_case() {
+        assert!(" F".parse::<v1::subject::Sex>().is_err());
+        assert!("f".parse::<v1::subject::Sex>().is_err());
+        assert!("".parse::<v1::subject::Sex>().is_err());
+    }
+
+    #[test]
+    fn it_displays_the_value_and_standard_name() {
+        let err = "X".parse::<v1::subject::Sex>().unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "`X` is not a permissible value of caDSR CDE 6343385 v1.00"
+        );
+    }
+}
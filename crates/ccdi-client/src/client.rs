@@ -0,0 +1,1023 @@
+//! A client for a server implementing the federation API.
+//!
+//! Every request is retried (with backoff) when it fails with a transient
+//! error: a connection error, a timeout, a `429 Too Many Requests`, or a
+//! `5xx` status. When the server provides a `Retry-After` header (in seconds),
+//! it is respected instead of the backoff.
+
+use std::num::NonZeroUsize;
+use std::time::Duration;
+
+use futures::stream;
+use futures::Stream;
+use futures::TryStreamExt as _;
+use log::info;
+use reqwest::header::HeaderMap;
+use reqwest::header::RETRY_AFTER;
+use reqwest::StatusCode;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use serde_json::Value;
+use url::Url;
+
+use ccdi_models as models;
+use ccdi_server as server;
+
+use server::params::filter::File as FileFilterParams;
+use server::params::filter::Sample as SampleFilterParams;
+use server::params::filter::Subject as SubjectFilterParams;
+use server::params::pagination::DEFAULT_PER_PAGE;
+use server::params::PaginationParams;
+use server::responses;
+use server::responses::Errors;
+
+use crate::links;
+use crate::Error;
+use crate::Result;
+
+/// The default number of times that a request is retried.
+pub const DEFAULT_RETRIES: usize = 5;
+
+/// The default delay before the first retry of a request.
+pub const DEFAULT_BASE_BACKOFF: Duration = Duration::from_millis(500);
+
+/// The maximum delay between retries when backing off.
+const MAX_BACKOFF: Duration = Duration::from_secs(60);
+
+/// An entity with metadata fields described by the server.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Entity {
+    /// Subjects.
+    Subject,
+
+    /// Samples.
+    Sample,
+
+    /// Files.
+    File,
+
+    /// Namespaces.
+    Namespace,
+
+    /// Organizations.
+    Organization,
+}
+
+impl Entity {
+    /// Gets the name of the [`Entity`] as it appears within paths.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ccdi_client::Entity;
+    ///
+    /// assert_eq!(Entity::Subject.name(), "subject");
+    /// assert_eq!(Entity::Organization.name(), "organization");
+    /// ```
+    pub fn name(&self) -> &'static str {
+        match self {
+            Entity::Subject => "subject",
+            Entity::Sample => "sample",
+            Entity::File => "file",
+            Entity::Namespace => "namespace",
+            Entity::Organization => "organization",
+        }
+    }
+}
+
+/// A response that has not been decoded.
+#[derive(Debug)]
+pub struct Response {
+    /// The status of the response.
+    status: StatusCode,
+
+    /// The headers of the response.
+    headers: HeaderMap,
+
+    /// The body of the response.
+    body: String,
+}
+
+impl Response {
+    /// Gets the status of the [`Response`].
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use ccdi_client::Client;
+    ///
+    /// # async fn run() -> ccdi_client::Result<()> {
+    /// let client = Client::new("https://ccdi.example.com/api/v1")?;
+    /// let response = client.get(&client.endpoint(&["info"])?).await?;
+    ///
+    /// assert!(response.status().is_success());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn status(&self) -> StatusCode {
+        self.status
+    }
+
+    /// Gets the headers of the [`Response`].
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use ccdi_client::Client;
+    ///
+    /// # async fn run() -> ccdi_client::Result<()> {
+    /// let client = Client::new("https://ccdi.example.com/api/v1")?;
+    /// let response = client.get(&client.endpoint(&["subject"])?).await?;
+    ///
+    /// let link = response.headers().get("link");
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn headers(&self) -> &HeaderMap {
+        &self.headers
+    }
+
+    /// Gets the body of the [`Response`].
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use ccdi_client::Client;
+    ///
+    /// # async fn run() -> ccdi_client::Result<()> {
+    /// let client = Client::new("https://ccdi.example.com/api/v1")?;
+    /// let response = client.get(&client.endpoint(&["info"])?).await?;
+    ///
+    /// println!("{}", response.body());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn body(&self) -> &str {
+        &self.body
+    }
+
+    /// Consumes the [`Response`] and returns its status, headers, and body.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use ccdi_client::Client;
+    ///
+    /// # async fn run() -> ccdi_client::Result<()> {
+    /// let client = Client::new("https://ccdi.example.com/api/v1")?;
+    /// let (status, headers, body) = client.get(&client.endpoint(&["info"])?).await?.into_parts();
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn into_parts(self) -> (StatusCode, HeaderMap, String) {
+        (self.status, self.headers, self.body)
+    }
+
+    /// Decodes the body of a successful response.
+    ///
+    /// The body of an unsuccessful response is decoded as the errors reported
+    /// by the server (when it matches the specification).
+    fn decode<T: DeserializeOwned>(self) -> Result<T> {
+        if !self.status.is_success() {
+            return Err(match serde_json::from_str::<Errors>(&self.body) {
+                Ok(errors) => Error::Api {
+                    status: self.status,
+                    errors,
+                },
+                Err(_) => Error::UnexpectedStatus {
+                    status: self.status,
+                    body: self.body,
+                },
+            });
+        }
+
+        serde_json::from_str(&self.body).map_err(Error::Decode)
+    }
+
+    /// Decodes the body of a successful response as a page of entities.
+    ///
+    /// Servers respond with an empty array (rather than an empty page) when no
+    /// entities match the request, so an empty array is decoded as an empty
+    /// page.
+    fn decode_page<P: Page>(self) -> Result<P> {
+        if self.status.is_success()
+            && serde_json::from_str::<Vec<Value>>(&self.body).is_ok_and(|page| page.is_empty())
+        {
+            return Ok(P::empty());
+        }
+
+        self.decode()
+    }
+}
+
+/// A paginated response.
+trait Page: DeserializeOwned {
+    /// The entities within the page.
+    type Entity;
+
+    /// Creates an empty page.
+    fn empty() -> Self;
+
+    /// Consumes the page and returns the entities within it.
+    fn into_data(self) -> Vec<Self::Entity>;
+}
+
+impl Page for responses::Subjects {
+    type Entity = models::Subject;
+
+    fn empty() -> Self {
+        Self::from((Vec::new(), 0))
+    }
+
+    fn into_data(self) -> Vec<Self::Entity> {
+        self.into_data()
+    }
+}
+
+impl Page for responses::Samples {
+    type Entity = models::Sample;
+
+    fn empty() -> Self {
+        Self::from((Vec::new(), 0))
+    }
+
+    fn into_data(self) -> Vec<Self::Entity> {
+        self.into_data()
+    }
+}
+
+impl Page for responses::Files {
+    type Entity = models::File;
+
+    fn empty() -> Self {
+        Self::from((Vec::new(), 0))
+    }
+
+    fn into_data(self) -> Vec<Self::Entity> {
+        self.into_data()
+    }
+}
+
+/// A builder for a [`Client`].
+#[derive(Debug)]
+pub struct Builder {
+    /// The base URL of the server.
+    base_url: Url,
+
+    /// The timeout of each request (if any).
+    timeout: Option<Duration>,
+
+    /// The number of times a request is retried before giving up.
+    retries: usize,
+
+    /// The delay before the first retry of a request (doubled on each
+    /// subsequent retry).
+    base_backoff: Duration,
+
+    /// The number of entities requested per page when iterating through every
+    /// page of results.
+    per_page: NonZeroUsize,
+}
+
+impl Builder {
+    /// Creates a new [`Builder`] for a client of the server at `base_url`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ccdi_client::Builder;
+    ///
+    /// let client = Builder::new("https://ccdi.example.com/api/v1".parse().unwrap())
+    ///     .build()
+    ///     .unwrap();
+    ///
+    /// assert_eq!(
+    ///     client.base_url().as_str(),
+    ///     "https://ccdi.example.com/api/v1"
+    /// );
+    /// ```
+    pub fn new(base_url: Url) -> Self {
+        Self {
+            base_url,
+            timeout: None,
+            retries: DEFAULT_RETRIES,
+            base_backoff: DEFAULT_BASE_BACKOFF,
+            // SAFETY: the default number of entities per page is not zero.
+            per_page: NonZeroUsize::new(DEFAULT_PER_PAGE).unwrap(),
+        }
+    }
+
+    /// Sets the timeout of each request (by default, requests do not time
+    /// out).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::time::Duration;
+    ///
+    /// use ccdi_client::Builder;
+    ///
+    /// let client = Builder::new("https://ccdi.example.com".parse().unwrap())
+    ///     .timeout(Duration::from_secs(30))
+    ///     .build()
+    ///     .unwrap();
+    /// ```
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Sets the number of times a request is retried when it fails with a
+    /// transient error.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ccdi_client::Builder;
+    ///
+    /// let client = Builder::new("https://ccdi.example.com".parse().unwrap())
+    ///     .retries(0)
+    ///     .build()
+    ///     .unwrap();
+    /// ```
+    pub fn retries(mut self, retries: usize) -> Self {
+        self.retries = retries;
+        self
+    }
+
+    /// Sets the delay before the first retry of a request (the delay is
+    /// doubled on each subsequent retry).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::time::Duration;
+    ///
+    /// use ccdi_client::Builder;
+    ///
+    /// let client = Builder::new("https://ccdi.example.com".parse().unwrap())
+    ///     .base_backoff(Duration::from_millis(100))
+    ///     .build()
+    ///     .unwrap();
+    /// ```
+    pub fn base_backoff(mut self, base_backoff: Duration) -> Self {
+        self.base_backoff = base_backoff;
+        self
+    }
+
+    /// Sets the number of entities requested per page when iterating through
+    /// every page of results (e.g., with [`Client::subjects_all()`]).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::num::NonZeroUsize;
+    ///
+    /// use ccdi_client::Builder;
+    ///
+    /// let client = Builder::new("https://ccdi.example.com".parse().unwrap())
+    ///     .per_page(NonZeroUsize::new(1000).unwrap())
+    ///     .build()
+    ///     .unwrap();
+    /// ```
+    pub fn per_page(mut self, per_page: NonZeroUsize) -> Self {
+        self.per_page = per_page;
+        self
+    }
+
+    /// Builds the [`Client`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ccdi_client::Builder;
+    ///
+    /// let client = Builder::new("https://ccdi.example.com".parse().unwrap())
+    ///     .build()
+    ///     .unwrap();
+    /// ```
+    pub fn build(self) -> Result<Client> {
+        let mut builder = reqwest::Client::builder().user_agent(concat!(
+            env!("CARGO_PKG_NAME"),
+            "/",
+            env!("CARGO_PKG_VERSION"),
+        ));
+
+        if let Some(timeout) = self.timeout {
+            builder = builder.timeout(timeout);
+        }
+
+        Ok(Client {
+            inner: builder.build().map_err(Error::Request)?,
+            base_url: self.base_url,
+            retries: self.retries,
+            base_backoff: self.base_backoff,
+            per_page: self.per_page,
+        })
+    }
+}
+
+/// A client for a server implementing the federation API.
+#[derive(Clone, Debug)]
+pub struct Client {
+    /// The inner HTTP client.
+    inner: reqwest::Client,
+
+    /// The base URL of the server.
+    base_url: Url,
+
+    /// The number of times a request is retried before giving up.
+    retries: usize,
+
+    /// The delay before the first retry of a request.
+    base_backoff: Duration,
+
+    /// The number of entities requested per page when iterating through every
+    /// page of results.
+    per_page: NonZeroUsize,
+}
+
+impl Client {
+    /// Creates a new [`Client`] for the server at `base_url` with the default
+    /// configuration.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ccdi_client::Client;
+    ///
+    /// let client = Client::new("https://ccdi.example.com/api/v1").unwrap();
+    /// assert!(Client::new("not a url").is_err());
+    /// ```
+    pub fn new(base_url: &str) -> Result<Self> {
+        Self::builder(base_url)?.build()
+    }
+
+    /// Creates a new [`Builder`] for a client of the server at `base_url`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ccdi_client::Client;
+    ///
+    /// let client = Client::builder("https://ccdi.example.com/api/v1")
+    ///     .unwrap()
+    ///     .retries(2)
+    ///     .build()
+    ///     .unwrap();
+    /// ```
+    pub fn builder(base_url: &str) -> Result<Builder> {
+        base_url
+            .parse::<Url>()
+            .map(Builder::new)
+            .map_err(Error::InvalidUrl)
+    }
+
+    /// Gets the base URL of the server.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ccdi_client::Client;
+    ///
+    /// let client = Client::new("https://ccdi.example.com/api/v1").unwrap();
+    /// assert_eq!(
+    ///     client.base_url().as_str(),
+    ///     "https://ccdi.example.com/api/v1"
+    /// );
+    /// ```
+    pub fn base_url(&self) -> &Url {
+        &self.base_url
+    }
+
+    /// Gets the URL of an endpoint from its path segments (which are
+    /// percent-encoded as needed).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ccdi_client::Client;
+    ///
+    /// let client = Client::new("https://ccdi.example.com/api/v1/").unwrap();
+    ///
+    /// assert_eq!(
+    ///     client
+    ///         .endpoint(&["subject", "org", "namespace", "Subject 1"])
+    ///         .unwrap()
+    ///         .as_str(),
+    ///     "https://ccdi.example.com/api/v1/subject/org/namespace/Subject%201"
+    /// );
+    /// ```
+    pub fn endpoint(&self, segments: &[&str]) -> Result<Url> {
+        let mut url = self.base_url.clone();
+
+        url.path_segments_mut()
+            .map_err(|_| Error::InvalidUrl(url::ParseError::RelativeUrlWithCannotBeABaseBase))?
+            .pop_if_empty()
+            .extend(segments);
+
+        Ok(url)
+    }
+
+    /// Requests a URL without decoding the response.
+    ///
+    /// Requests that fail with a transient error are retried (see the
+    /// [module documentation](self)). Responses with any other status are
+    /// returned (rather than treated as errors) so that their bodies can be
+    /// inspected.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use ccdi_client::Client;
+    ///
+    /// # async fn run() -> ccdi_client::Result<()> {
+    /// let client = Client::new("https://ccdi.example.com/api/v1")?;
+    /// let response = client.get(&client.endpoint(&["info"])?).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get(&self, url: &Url) -> Result<Response> {
+        let mut attempt = 0;
+
+        loop {
+            let (err, retry_after) = match self.inner.get(url.clone()).send().await {
+                Ok(response) if !is_transient(response.status()) => {
+                    let status = response.status();
+                    let headers = response.headers().clone();
+                    let body = response.text().await.map_err(Error::Request)?;
+
+                    return Ok(Response {
+                        status,
+                        headers,
+                        body,
+                    });
+                }
+                Ok(response) => (
+                    Error::Status(response.status()),
+                    retry_after(response.headers()),
+                ),
+                Err(err) if err.is_connect() || err.is_timeout() => (Error::Request(err), None),
+                Err(err) => return Err(Error::Request(err)),
+            };
+
+            if attempt >= self.retries {
+                return Err(err);
+            }
+
+            let delay = retry_after.unwrap_or_else(|| self.backoff(attempt));
+            info!("retrying {url} in {delay:?} ({err})");
+            tokio::time::sleep(delay).await;
+
+            attempt += 1;
+        }
+    }
+
+    /// Gets information about the server.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use ccdi_client::Client;
+    ///
+    /// # async fn run() -> ccdi_client::Result<()> {
+    /// let client = Client::new("https://ccdi.example.com/api/v1")?;
+    /// let info = client.info().await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn info(&self) -> Result<responses::Information> {
+        self.get(&self.endpoint(&["info"])?).await?.decode()
+    }
+
+    /// Gets a page of the subjects that match the provided filters.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use ccdi_client::Client;
+    /// use ccdi_server as server;
+    ///
+    /// use server::params::filter::Subject as SubjectFilterParams;
+    /// use server::params::PaginationParams;
+    ///
+    /// # async fn run() -> ccdi_client::Result<()> {
+    /// let client = Client::new("https://ccdi.example.com/api/v1")?;
+    ///
+    /// let subjects = client
+    ///     .subjects(
+    ///         SubjectFilterParams::default(),
+    ///         PaginationParams::new(Some(2), Some(50)),
+    ///     )
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn subjects(
+        &self,
+        filter: SubjectFilterParams,
+        page: PaginationParams,
+    ) -> Result<responses::Subjects> {
+        self.page("subject", &filter, &page).await
+    }
+
+    /// Gets the subject with the provided identifier.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use ccdi_client::Client;
+    ///
+    /// # async fn run() -> ccdi_client::Result<()> {
+    /// let client = Client::new("https://ccdi.example.com/api/v1")?;
+    /// let subject = client
+    ///     .subject_by_id("example-organization", "ExampleNamespace", "Subject1")
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn subject_by_id(
+        &self,
+        organization: &str,
+        namespace: &str,
+        name: &str,
+    ) -> Result<models::Subject> {
+        let url = self.endpoint(&["subject", organization, namespace, name])?;
+        self.get(&url).await?.decode()
+    }
+
+    /// Gets every subject that matches the provided filters.
+    ///
+    /// Each page is requested (by following the `next` relationship within the
+    /// `link` header) as the stream is consumed.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use ccdi_client::Client;
+    /// use ccdi_server as server;
+    /// use futures::TryStreamExt as _;
+    ///
+    /// use server::params::filter::Subject as SubjectFilterParams;
+    ///
+    /// # async fn run() -> ccdi_client::Result<()> {
+    /// let client = Client::new("https://ccdi.example.com/api/v1")?;
+    ///
+    /// let subjects = client
+    ///     .subjects_all(SubjectFilterParams::default())
+    ///     .try_collect::<Vec<_>>()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn subjects_all(
+        &self,
+        filter: SubjectFilterParams,
+    ) -> impl Stream<Item = Result<models::Subject>> + '_ {
+        self.all::<responses::Subjects, _>("subject", filter)
+    }
+
+    /// Gets a page of the samples that match the provided filters.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use ccdi_client::Client;
+    /// use ccdi_server as server;
+    ///
+    /// use server::params::filter::Sample as SampleFilterParams;
+    /// use server::params::PaginationParams;
+    ///
+    /// # async fn run() -> ccdi_client::Result<()> {
+    /// let client = Client::new("https://ccdi.example.com/api/v1")?;
+    ///
+    /// let filter = SampleFilterParams {
+    ///     library_strategy: Some(String::from("RNA-Seq")),
+    ///     ..Default::default()
+    /// };
+    ///
+    /// let samples = client.samples(filter, PaginationParams::default()).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn samples(
+        &self,
+        filter: SampleFilterParams,
+        page: PaginationParams,
+    ) -> Result<responses::Samples> {
+        self.page("sample", &filter, &page).await
+    }
+
+    /// Gets the sample with the provided identifier.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use ccdi_client::Client;
+    ///
+    /// # async fn run() -> ccdi_client::Result<()> {
+    /// let client = Client::new("https://ccdi.example.com/api/v1")?;
+    /// let sample = client
+    ///     .sample_by_id("example-organization", "ExampleNamespace", "Sample1")
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn sample_by_id(
+        &self,
+        organization: &str,
+        namespace: &str,
+        name: &str,
+    ) -> Result<models::Sample> {
+        let url = self.endpoint(&["sample", organization, namespace, name])?;
+        self.get(&url).await?.decode()
+    }
+
+    /// Gets every sample that matches the provided filters.
+    ///
+    /// Each page is requested (by following the `next` relationship within the
+    /// `link` header) as the stream is consumed.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use ccdi_client::Client;
+    /// use ccdi_server as server;
+    /// use futures::TryStreamExt as _;
+    ///
+    /// use server::params::filter::Sample as SampleFilterParams;
+    ///
+    /// # async fn run() -> ccdi_client::Result<()> {
+    /// let client = Client::new("https://ccdi.example.com/api/v1")?;
+    ///
+    /// let samples = client
+    ///     .samples_all(SampleFilterParams::default())
+    ///     .try_collect::<Vec<_>>()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn samples_all(
+        &self,
+        filter: SampleFilterParams,
+    ) -> impl Stream<Item = Result<models::Sample>> + '_ {
+        self.all::<responses::Samples, _>("sample", filter)
+    }
+
+    /// Gets a page of the files that match the provided filters.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use ccdi_client::Client;
+    /// use ccdi_server as server;
+    ///
+    /// use server::params::filter::File as FileFilterParams;
+    /// use server::params::PaginationParams;
+    ///
+    /// # async fn run() -> ccdi_client::Result<()> {
+    /// let client = Client::new("https://ccdi.example.com/api/v1")?;
+    ///
+    /// let files = client
+    ///     .files(FileFilterParams::default(), PaginationParams::default())
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn files(
+        &self,
+        filter: FileFilterParams,
+        page: PaginationParams,
+    ) -> Result<responses::Files> {
+        self.page("file", &filter, &page).await
+    }
+
+    /// Gets the file with the provided identifier.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use ccdi_client::Client;
+    ///
+    /// # async fn run() -> ccdi_client::Result<()> {
+    /// let client = Client::new("https://ccdi.example.com/api/v1")?;
+    /// let file = client
+    ///     .file_by_id("example-organization", "ExampleNamespace", "File1.txt")
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn file_by_id(
+        &self,
+        organization: &str,
+        namespace: &str,
+        name: &str,
+    ) -> Result<models::File> {
+        let url = self.endpoint(&["file", organization, namespace, name])?;
+        self.get(&url).await?.decode()
+    }
+
+    /// Gets every file that matches the provided filters.
+    ///
+    /// Each page is requested (by following the `next` relationship within the
+    /// `link` header) as the stream is consumed.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use ccdi_client::Client;
+    /// use ccdi_server as server;
+    /// use futures::TryStreamExt as _;
+    ///
+    /// use server::params::filter::File as FileFilterParams;
+    ///
+    /// # async fn run() -> ccdi_client::Result<()> {
+    /// let client = Client::new("https://ccdi.example.com/api/v1")?;
+    ///
+    /// let files = client
+    ///     .files_all(FileFilterParams::default())
+    ///     .try_collect::<Vec<_>>()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn files_all(
+        &self,
+        filter: FileFilterParams,
+    ) -> impl Stream<Item = Result<models::File>> + '_ {
+        self.all::<responses::Files, _>("file", filter)
+    }
+
+    /// Gets the descriptions of the metadata fields of an entity that are
+    /// supported by the server.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use ccdi_client::Client;
+    /// use ccdi_client::Entity;
+    ///
+    /// # async fn run() -> ccdi_client::Result<()> {
+    /// let client = Client::new("https://ccdi.example.com/api/v1")?;
+    /// let fields = client.metadata_fields(Entity::Sample).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn metadata_fields(
+        &self,
+        entity: Entity,
+    ) -> Result<responses::metadata::FieldDescriptions> {
+        let url = self.endpoint(&["metadata", "fields", entity.name()])?;
+        self.get(&url).await?.decode()
+    }
+
+    /// Gets the delay to wait before the retry following the provided
+    /// (zero-based) attempt.
+    fn backoff(&self, attempt: usize) -> Duration {
+        let factor = 2u32.saturating_pow(attempt.try_into().unwrap_or(u32::MAX));
+        self.base_backoff.saturating_mul(factor).min(MAX_BACKOFF)
+    }
+
+    /// Gets a page of a paginated endpoint.
+    async fn page<P: Page, F: Serialize>(
+        &self,
+        path: &str,
+        filter: &F,
+        page: &PaginationParams,
+    ) -> Result<P> {
+        let mut url = self.endpoint(&[path])?;
+        append_query(&mut url, filter);
+        append_query(&mut url, page);
+
+        self.get(&url).await?.decode_page()
+    }
+
+    /// Gets every entity from a paginated endpoint by following the `next`
+    /// relationship within the `link` header of each page.
+    fn all<P, F>(&self, path: &'static str, filter: F) -> impl Stream<Item = Result<P::Entity>> + '_
+    where
+        P: Page,
+        F: Serialize,
+    {
+        let first = self.endpoint(&[path]).map(|mut url| {
+            append_query(&mut url, &filter);
+            append_query(
+                &mut url,
+                &PaginationParams::new(Some(1), Some(self.per_page.get())),
+            );
+            (url, 1)
+        });
+
+        stream::try_unfold(Some(first), move |next| async move {
+            let (url, current) = match next {
+                Some(next) => next?,
+                None => return Ok(None),
+            };
+
+            let response = self.get(&url).await?;
+
+            // NOTE: a `next` link that does not advance would otherwise
+            // request the same pages forever.
+            let next = links::next_page(response.headers())
+                .filter(|page| *page > current)
+                .map(|page| Ok((links::with_page(&url, page), page)));
+
+            let page = response.decode_page::<P>()?;
+            let entities = stream::iter(page.into_data().into_iter().map(Ok));
+
+            Ok(Some((entities, next)))
+        })
+        .try_flatten()
+    }
+}
+
+/// Appends the (non-null) fields of a set of parameters to the query of a
+/// URL.
+fn append_query<T: Serialize>(url: &mut Url, params: &T) {
+    // SAFETY: parameters are always serializable to a JSON object.
+    let params = serde_json::to_value(params).unwrap();
+
+    if let Value::Object(params) = params {
+        let mut pairs = url.query_pairs_mut();
+
+        for (key, value) in params {
+            match value {
+                Value::Null => {}
+                Value::String(value) => {
+                    pairs.append_pair(&key, &value);
+                }
+                // NOTE: numbers, booleans, and numeric ranges are appended as
+                // JSON (e.g., `age_at_collection={"gte":0.0}`).
+                value => {
+                    pairs.append_pair(&key, &value.to_string());
+                }
+            }
+        }
+    }
+
+    // NOTE: a URL with an empty query is rendered with a trailing `?`.
+    if url.query() == Some("") {
+        url.set_query(None);
+    }
+}
+
+/// Returns whether a status indicates a transient failure.
+fn is_transient(status: StatusCode) -> bool {
+    status == StatusCode::TOO_MANY_REQUESTS || status.is_server_error()
+}
+
+/// Gets the delay requested by a `Retry-After` header (if it is expressed in
+/// seconds).
+fn retry_after(headers: &HeaderMap) -> Option<Duration> {
+    headers
+        .get(RETRY_AFTER)?
+        .to_str()
+        .ok()?
+        .trim()
+        .parse::<u64>()
+        .ok()
+        .map(Duration::from_secs)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_respects_retry_after() {
+        let mut headers = HeaderMap::new();
+        headers.insert(RETRY_AFTER, "3".parse().unwrap());
+        assert_eq!(retry_after(&headers), Some(Duration::from_secs(3)));
+        assert_eq!(retry_after(&HeaderMap::new()), None);
+
+        let client = Client::builder("http://localhost")
+            .unwrap()
+            .base_backoff(Duration::from_millis(1))
+            .build()
+            .unwrap();
+
+        assert_eq!(client.backoff(0), Duration::from_millis(1));
+        assert_eq!(client.backoff(3), Duration::from_millis(8));
+        assert_eq!(client.backoff(usize::MAX), MAX_BACKOFF);
+    }
+
+    #[test]
+    fn it_only_appends_the_provided_parameters() {
+        let mut url = "http://localhost/subject".parse::<Url>().unwrap();
+        append_query(&mut url, &SubjectFilterParams::default());
+        assert_eq!(url.as_str(), "http://localhost/subject");
+
+        let filter = SubjectFilterParams {
+            sex: Some(String::from("F")),
+            has_metadata: Some(true),
+            ..Default::default()
+        };
+
+        append_query(&mut url, &filter);
+        append_query(&mut url, &PaginationParams::new(Some(2), None));
+        assert_eq!(
+            url.as_str(),
+            "http://localhost/subject?sex=F&has_metadata=true&page=2"
+        );
+    }
+}
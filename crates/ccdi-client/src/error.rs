@@ -0,0 +1,61 @@
+//! Errors returned by the client.
+
+use reqwest::StatusCode;
+
+use ccdi_server as server;
+
+use server::responses::Errors;
+
+/// An error returned by the client.
+#[derive(Debug)]
+pub enum Error {
+    /// A URL could not be parsed (or joined to the base URL).
+    InvalidUrl(url::ParseError),
+
+    /// A request could not be completed.
+    Request(reqwest::Error),
+
+    /// A request failed with a status code that indicates a transient failure
+    /// (a `429 Too Many Requests` or a `5xx`), and all retries were exhausted.
+    Status(StatusCode),
+
+    /// The server responded with an unsuccessful status and an error body
+    /// that matches the specification.
+    Api {
+        /// The status of the response.
+        status: StatusCode,
+
+        /// The errors reported by the server.
+        errors: Errors,
+    },
+
+    /// The server responded with an unsuccessful status and a body that is
+    /// not an error body matching the specification.
+    UnexpectedStatus {
+        /// The status of the response.
+        status: StatusCode,
+
+        /// The body of the response.
+        body: String,
+    },
+
+    /// The body of a successful response did not match the specification.
+    Decode(serde_json::Error),
+}
+
+impl std::fmt::Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::InvalidUrl(err) => write!(f, "invalid url: {err}"),
+            Error::Request(err) => write!(f, "request error: {err}"),
+            Error::Status(status) => write!(f, "unsuccessful status: {status}"),
+            Error::Api { status, errors } => write!(f, "api error ({status}): {errors}"),
+            Error::UnexpectedStatus { status, body } => {
+                write!(f, "unexpected status ({status}): {body}")
+            }
+            Error::Decode(err) => write!(f, "decode error: {err}"),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
@@ -0,0 +1,48 @@
+//! A strongly-typed client for the Childhood Cancer Data Initiative federation
+//! API.
+//!
+//! The requests and responses of the client are the same wire types that the
+//! reference server uses (from `ccdi-server` and `ccdi-models`), so a response
+//! that does not match the specification fails to decode rather than being
+//! silently misread.
+//!
+//! ```no_run
+//! use ccdi_client::Client;
+//! use ccdi_server as server;
+//! use futures::TryStreamExt as _;
+//!
+//! use server::params::filter::Subject as SubjectFilterParams;
+//!
+//! # async fn run() -> ccdi_client::Result<()> {
+//! let client = Client::new("https://ccdi.example.com/api/v1")?;
+//!
+//! let filter = SubjectFilterParams {
+//!     sex: Some(String::from("F")),
+//!     ..Default::default()
+//! };
+//!
+//! // Each page is requested (following the `link` header) as the stream is
+//! // consumed.
+//! let subjects = client.subjects_all(filter).try_collect::<Vec<_>>().await?;
+//! # Ok(())
+//! # }
+//! ```
+
+#![warn(missing_docs)]
+#![warn(rust_2018_idioms)]
+#![warn(rust_2021_compatibility)]
+#![warn(missing_debug_implementations)]
+#![deny(rustdoc::broken_intra_doc_links)]
+
+pub mod client;
+pub mod error;
+pub mod links;
+
+pub use client::Builder;
+pub use client::Client;
+pub use client::Entity;
+pub use client::Response;
+pub use error::Error;
+
+/// A [`Result`](std::result::Result) with an [`Error`].
+pub type Result<T> = std::result::Result<T, Error>;
@@ -0,0 +1,201 @@
+//! Parsing the `link` header of paginated responses.
+//!
+//! Only the page number is taken from each link: the pages are requested from
+//! the URL that was originally requested, as servers may not know the URL at
+//! which they are reachable (e.g., behind a proxy).
+
+use std::collections::BTreeMap;
+
+use reqwest::header::HeaderMap;
+use reqwest::header::LINK;
+use url::Url;
+
+/// Gets the page number within a URL (if it is specified).
+///
+/// # Examples
+///
+/// ```
+/// use ccdi_client::links::page_of;
+/// use url::Url;
+///
+/// let url = "http://localhost/subject?page=2".parse::<Url>().unwrap();
+/// assert_eq!(page_of(&url), Some(2));
+///
+/// let url = "http://localhost/subject".parse::<Url>().unwrap();
+/// assert_eq!(page_of(&url), None);
+/// ```
+pub fn page_of(url: &Url) -> Option<usize> {
+    url.query_pairs()
+        .find(|(key, _)| key == "page")
+        .and_then(|(_, value)| value.parse().ok())
+}
+
+/// Gets the page number of each relationship within the `link` header (if it
+/// exists).
+///
+/// Links without a parsable URL or page number are ignored.
+///
+/// # Examples
+///
+/// ```
+/// use ccdi_client::links::links;
+/// use reqwest::header::HeaderMap;
+/// use reqwest::header::LINK;
+///
+/// let mut headers = HeaderMap::new();
+/// headers.insert(
+///     LINK,
+///     "<http://localhost/subject?page=2>; rel=\"next\""
+///         .parse()
+///         .unwrap(),
+/// );
+///
+/// assert_eq!(links(&headers).unwrap().get("next"), Some(&2));
+/// assert!(links(&HeaderMap::new()).is_none());
+/// ```
+pub fn links(headers: &HeaderMap) -> Option<BTreeMap<String, usize>> {
+    let links = headers
+        .get(LINK)?
+        .to_str()
+        .ok()?
+        .split(',')
+        .filter_map(|link| link.split_once(';'))
+        .filter_map(|(url, params)| {
+            let rel = params.split(';').find_map(|param| {
+                param
+                    .trim()
+                    .strip_prefix("rel=\"")
+                    .and_then(|rel| rel.strip_suffix('"'))
+            })?;
+
+            let url = url
+                .trim()
+                .trim_start_matches('<')
+                .trim_end_matches('>')
+                .parse::<Url>()
+                .ok()?;
+
+            Some((rel.to_string(), page_of(&url)?))
+        })
+        .collect();
+
+    Some(links)
+}
+
+/// Gets the number of the next page from the `link` header (if it exists).
+///
+/// # Examples
+///
+/// ```
+/// use ccdi_client::links::next_page;
+/// use reqwest::header::HeaderMap;
+/// use reqwest::header::LINK;
+///
+/// let mut headers = HeaderMap::new();
+/// headers.insert(
+///     LINK,
+///     "<http://localhost/subject?page=3>; rel=\"next\""
+///         .parse()
+///         .unwrap(),
+/// );
+///
+/// assert_eq!(next_page(&headers), Some(3));
+/// assert_eq!(next_page(&HeaderMap::new()), None);
+/// ```
+pub fn next_page(headers: &HeaderMap) -> Option<usize> {
+    links(headers)?.get("next").copied()
+}
+
+/// Gets the number of the last page from the `link` header (if it exists).
+///
+/// # Examples
+///
+/// ```
+/// use ccdi_client::links::last_page;
+/// use reqwest::header::HeaderMap;
+/// use reqwest::header::LINK;
+///
+/// let mut headers = HeaderMap::new();
+/// headers.insert(
+///     LINK,
+///     "<http://localhost/subject?page=10>; rel=\"last\""
+///         .parse()
+///         .unwrap(),
+/// );
+///
+/// assert_eq!(last_page(&headers), Some(10));
+/// assert_eq!(last_page(&HeaderMap::new()), None);
+/// ```
+pub fn last_page(headers: &HeaderMap) -> Option<usize> {
+    links(headers)?.get("last").copied()
+}
+
+/// Returns the URL with the `page` query parameter set to the provided page.
+///
+/// # Examples
+///
+/// ```
+/// use ccdi_client::links::with_page;
+/// use url::Url;
+///
+/// let url = "http://localhost/subject?page=2&per_page=5"
+///     .parse::<Url>()
+///     .unwrap();
+///
+/// assert_eq!(
+///     with_page(&url, 7).as_str(),
+///     "http://localhost/subject?per_page=5&page=7"
+/// );
+/// ```
+pub fn with_page(url: &Url, page: usize) -> Url {
+    let pairs = url
+        .query_pairs()
+        .filter(|(key, _)| key != "page")
+        .map(|(key, value)| (key.into_owned(), value.into_owned()))
+        .collect::<Vec<_>>();
+
+    let mut url = url.clone();
+    url.query_pairs_mut()
+        .clear()
+        .extend_pairs(pairs)
+        .append_pair("page", &page.to_string());
+
+    url
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn headers(link: &str) -> HeaderMap {
+        let mut headers = HeaderMap::new();
+        headers.insert(LINK, link.parse().unwrap());
+        headers
+    }
+
+    #[test]
+    fn it_parses_the_links_from_the_link_header() {
+        let links = links(&headers(
+            "<http://localhost:8000/subject?page=2&per_page=5>; rel=\"next\", \
+             <http://localhost:8000/subject?per_page=5>; rel=\"prev\", \
+             <not a url>; rel=\"first\"",
+        ))
+        .unwrap();
+
+        assert_eq!(links.len(), 1);
+        assert_eq!(links.get("next"), Some(&2));
+        assert!(super::links(&HeaderMap::new()).is_none());
+    }
+
+    #[test]
+    fn it_parses_the_last_page_from_the_link_header() {
+        let headers = headers(
+            "<http://localhost:8000/subject?page=1&per_page=5>; rel=\"first\", \
+             <http://localhost:8000/subject?page=10&per_page=5>; rel=\"last\"",
+        );
+
+        assert_eq!(last_page(&headers), Some(10));
+        assert_eq!(next_page(&headers), None);
+        assert_eq!(last_page(&HeaderMap::new()), None);
+    }
+}
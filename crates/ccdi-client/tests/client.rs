@@ -0,0 +1,240 @@
+//! Integration tests for the client.
+//!
+//! Each test serves canned responses from a mock server and checks the
+//! requests made by (and the values returned from) the client.
+
+use std::num::NonZeroUsize;
+use std::time::Duration;
+
+use futures::TryStreamExt as _;
+use rand::rngs::StdRng;
+use rand::SeedableRng as _;
+use reqwest::StatusCode;
+use wiremock::matchers::method;
+use wiremock::matchers::path;
+use wiremock::matchers::query_param;
+use wiremock::matchers::query_param_is_missing;
+use wiremock::Mock;
+use wiremock::MockServer;
+use wiremock::ResponseTemplate;
+
+use ccdi_client as client;
+use ccdi_models as models;
+use ccdi_server as server;
+
+use client::Client;
+use client::Error;
+use server::params::filter::Subject as SubjectFilterParams;
+use server::params::PaginationParams;
+use server::responses::error::Kind;
+use server::responses::Errors;
+use server::responses::Subjects;
+use server::routes::subject;
+
+/// Creates a seeded set of random subjects (the same seed always creates the
+/// same subjects).
+fn subjects(count: usize) -> Vec<models::Subject> {
+    let mut rng = StdRng::seed_from_u64(0);
+
+    subject::Store::random_with_rng(count, 0.5, &mut rng)
+        .subjects
+        .into_inner()
+        .unwrap()
+}
+
+/// Creates a client of the mock server that retries quickly.
+fn client(server: &MockServer) -> Client {
+    Client::builder(&server.uri())
+        .unwrap()
+        .base_backoff(Duration::from_millis(1))
+        .per_page(NonZeroUsize::new(2).unwrap())
+        .build()
+        .unwrap()
+}
+
+/// Creates a page of subjects with a `link` header pointing to the next page
+/// (if there is one).
+fn page(server: &MockServer, subjects: &[models::Subject], page: usize) -> ResponseTemplate {
+    let data = subjects
+        .iter()
+        .skip((page - 1) * 2)
+        .take(2)
+        .cloned()
+        .collect();
+    let response = ResponseTemplate::new(200).set_body_json(Subjects::from((data, subjects.len())));
+
+    match page * 2 < subjects.len() {
+        true => response.insert_header(
+            "link",
+            format!(
+                "<{}/subject?per_page=2&page={}>; rel=\"next\"",
+                server.uri(),
+                page + 1
+            )
+            .as_str(),
+        ),
+        false => response,
+    }
+}
+
+#[tokio::test]
+async fn it_streams_every_page() {
+    let server = MockServer::start().await;
+    let subjects = subjects(5);
+
+    for number in 1..=3 {
+        Mock::given(method("GET"))
+            .and(path("/subject"))
+            .and(query_param("page", number.to_string()))
+            .and(query_param("per_page", "2"))
+            .respond_with(page(&server, &subjects, number))
+            .expect(1)
+            .mount(&server)
+            .await;
+    }
+
+    let results = client(&server)
+        .subjects_all(SubjectFilterParams::default())
+        .try_collect::<Vec<_>>()
+        .await
+        .unwrap();
+
+    assert_eq!(results, subjects);
+}
+
+#[tokio::test]
+async fn it_streams_nothing_from_an_empty_array() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/subject"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!([])))
+        .mount(&server)
+        .await;
+
+    let results = client(&server)
+        .subjects_all(SubjectFilterParams::default())
+        .try_collect::<Vec<_>>()
+        .await
+        .unwrap();
+
+    assert!(results.is_empty());
+}
+
+#[tokio::test]
+async fn it_serializes_filters_into_the_query() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/subject"))
+        .and(query_param("sex", "F"))
+        .and(query_param("has_metadata", "true"))
+        .and(query_param("page", "2"))
+        .and(query_param_is_missing("race"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(Subjects::from((Vec::new(), 0))))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let filter = SubjectFilterParams {
+        sex: Some(String::from("F")),
+        has_metadata: Some(true),
+        ..Default::default()
+    };
+
+    let subjects = client(&server)
+        .subjects(filter, PaginationParams::new(Some(2), None))
+        .await
+        .unwrap();
+
+    assert!(subjects.data().is_empty());
+}
+
+#[tokio::test]
+async fn it_maps_error_responses() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/subject/org/namespace/missing"))
+        .respond_with(
+            ResponseTemplate::new(404)
+                .set_body_json(Errors::from(Kind::not_found(String::from("Subject")))),
+        )
+        .mount(&server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/subject/org/namespace/teapot"))
+        .respond_with(ResponseTemplate::new(418).set_body_string("I'm a teapot"))
+        .mount(&server)
+        .await;
+
+    let client = client(&server);
+
+    match client.subject_by_id("org", "namespace", "missing").await {
+        Err(Error::Api { status, errors }) => {
+            assert_eq!(status, StatusCode::NOT_FOUND);
+            assert_eq!(errors.to_string(), "errors: Subject not found.");
+        }
+        result => panic!("expected an api error, found {result:?}"),
+    }
+
+    match client.subject_by_id("org", "namespace", "teapot").await {
+        Err(Error::UnexpectedStatus { status, body }) => {
+            assert_eq!(status, StatusCode::IM_A_TEAPOT);
+            assert_eq!(body, "I'm a teapot");
+        }
+        result => panic!("expected an unexpected status, found {result:?}"),
+    }
+}
+
+#[tokio::test]
+async fn it_retries_transient_failures() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/info"))
+        .respond_with(ResponseTemplate::new(503))
+        .up_to_n_times(2)
+        .expect(2)
+        .mount(&server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/info"))
+        .respond_with(ResponseTemplate::new(200).set_body_string("{"))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    // NOTE: the body of the successful response is malformed, so the request
+    // must have made it past the retries to fail while decoding.
+    assert!(matches!(
+        client(&server).info().await,
+        Err(Error::Decode(_))
+    ));
+}
+
+#[tokio::test]
+async fn it_gives_up_after_the_configured_retries() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/info"))
+        .respond_with(ResponseTemplate::new(429))
+        .expect(3)
+        .mount(&server)
+        .await;
+
+    let client = Client::builder(&server.uri())
+        .unwrap()
+        .retries(2)
+        .base_backoff(Duration::from_millis(1))
+        .build()
+        .unwrap();
+
+    assert!(matches!(
+        client.info().await,
+        Err(Error::Status(StatusCode::TOO_MANY_REQUESTS))
+    ));
+}
@@ -0,0 +1,132 @@
+//! Maps free-text anatomical sites onto Uberon terms.
+
+use std::path::Path;
+use std::path::PathBuf;
+
+use clap::Parser;
+use clap::ValueHint;
+use eyre::eyre;
+use eyre::Context;
+use eyre::Result;
+use tracing::info;
+
+use crate::uberon;
+use crate::uberon::matcher::Matcher;
+
+/// The confidence reported for sites that could not be matched.
+const UNMATCHED: &str = "unmatched";
+
+////////////////////////////////////////////////////////////////////////////////////////
+// Command line tool.
+////////////////////////////////////////////////////////////////////////////////////////
+
+#[derive(Clone, Debug, Parser)]
+pub struct Args {
+    /// A file of free-text anatomical sites. Files ending in `.csv` are read as
+    /// CSV with a header row (see `--column`). Otherwise, each non-empty line
+    /// is a site.
+    #[arg(value_hint = ValueHint::FilePath)]
+    pub input: PathBuf,
+
+    /// The column of a CSV input that contains the sites (defaults to the
+    /// first column).
+    #[arg(long)]
+    pub column: Option<String>,
+
+    /// The Uberon ontology to match against.
+    #[command(flatten)]
+    pub uberon: uberon::Args,
+}
+
+/// Reads the free-text sites from a file.
+fn read_sites(path: &Path, column: Option<&str>) -> Result<Vec<String>> {
+    let is_csv = path
+        .extension()
+        .map(|extension| extension.eq_ignore_ascii_case("csv"))
+        .unwrap_or(false);
+
+    if !is_csv {
+        if column.is_some() {
+            return Err(eyre!("`--column` can only be provided for CSV inputs"));
+        }
+
+        let contents = std::fs::read_to_string(path)
+            .with_context(|| format!("reading sites from `{}`", path.display()))?;
+
+        return Ok(contents
+            .lines()
+            .filter(|line| !line.trim().is_empty())
+            .map(String::from)
+            .collect());
+    }
+
+    let mut reader = csv::Reader::from_path(path)
+        .with_context(|| format!("reading sites from `{}`", path.display()))?;
+
+    let index = match column {
+        Some(column) => reader
+            .headers()?
+            .iter()
+            .position(|header| header == column)
+            .ok_or_else(|| eyre!("column `{column}` not found in `{}`", path.display()))?,
+        None => 0,
+    };
+
+    let mut sites = Vec::new();
+
+    for record in reader.records() {
+        let record = record.context("parsing a CSV record")?;
+
+        if let Some(site) = record.get(index).filter(|site| !site.trim().is_empty()) {
+            sites.push(site.to_string());
+        }
+    }
+
+    Ok(sites)
+}
+
+pub fn main(args: Args) -> Result<()> {
+    let sites = read_sites(&args.input, args.column.as_deref())?;
+
+    let (version, nodes) = uberon::load(args.uberon)?;
+    info!(
+        "matching {} site(s) against Uberon {}",
+        sites.len(),
+        version
+    );
+
+    let matcher = Matcher::new(nodes);
+    let mut matched = 0usize;
+
+    let mut wtr = csv::WriterBuilder::new()
+        .delimiter(b'\t')
+        .from_writer(std::io::stdout());
+
+    wtr.write_record(["input", "uberon_id", "label", "confidence"])?;
+
+    for site in &sites {
+        match matcher.find(site) {
+            Some(m) => {
+                matched += 1;
+                wtr.write_record([
+                    site.as_str(),
+                    m.node.id.as_str(),
+                    m.node.label.as_str(),
+                    m.confidence.to_string().as_str(),
+                ])?;
+            }
+            None => wtr.write_record([site.as_str(), "", "", UNMATCHED])?,
+        }
+    }
+
+    wtr.flush()?;
+
+    info!(
+        "matched {} of {} site(s) ({} unmatched)",
+        matched,
+        sites.len(),
+        sites.len() - matched
+    );
+
+    Ok(())
+}
@@ -8,13 +8,21 @@ use clap_verbosity_flag::Verbosity;
 use eyre::Result;
 use tracing_log::AsTrace as _;
 
+mod anatomical_sites;
 pub(crate) mod http;
 mod uberon;
 
 #[derive(Debug, Subcommand)]
 pub enum Command {
+    /// Maps free-text anatomical sites onto the curated Uberon terms.
+    MapAnatomicalSites(anatomical_sites::Args),
+
     /// Curates the list of Uberon codes as a Rust enum.
     Uberon(uberon::Args),
+
+    /// Writes the `is_a` hierarchy of the curated Uberon codes (for rolling
+    /// anatomical sites up to an ancestor term within the server).
+    UberonHierarchy(uberon::Args),
 }
 
 /// A tool to execute the curation of CCDI CDEs from public standards.
@@ -44,6 +52,8 @@ fn main() -> Result<()> {
     tracing::subscriber::set_global_default(subscriber)?;
 
     match args.command {
+        Command::MapAnatomicalSites(args) => anatomical_sites::main(args),
         Command::Uberon(args) => uberon::main(args),
+        Command::UberonHierarchy(args) => uberon::hierarchy(args),
     }
 }
@@ -1,4 +1,5 @@
 pub mod graph;
+pub mod matcher;
 
 use std::path::PathBuf;
 use std::sync::LazyLock;
@@ -174,7 +175,16 @@ pub fn output_rust_enum(version: String, graph: Vec<Node>) {
     println!("}}")
 }
 
-pub fn main(args: Args) -> Result<()> {
+/// Loads the Uberon ontology and returns its version along with every node
+/// underneath the `anatomical entity` node.
+pub fn load(args: Args) -> Result<(String, Vec<Node>)> {
+    let (version, graph, anatomical_entity_id) = load_graph(args)?;
+    Ok((version, graph.dfs(anatomical_entity_id)))
+}
+
+/// Loads the Uberon ontology and returns its version along with the graph of
+/// `is_a` relationships and the identifier of the `anatomical entity` node.
+fn load_graph(args: Args) -> Result<(String, UberonGraph, String)> {
     let client = http::Client::default();
 
     let (version, json) = if let Some(path) = args.path {
@@ -323,11 +333,50 @@ pub fn main(args: Args) -> Result<()> {
         graph.connect(&edge);
     }
 
-    let nodes = graph.dfs(anatomical_entity_id);
+    Ok((version, graph, anatomical_entity_id))
+}
+
+pub fn main(args: Args) -> Result<()> {
+    let (version, nodes) = load(args)?;
     output_rust_enum(version, nodes);
 
     Ok(())
 }
 
+/// Outputs the `is_a` relationships between the curated Uberon terms as
+/// tab-separated values (one row per relationship).
+pub fn hierarchy(args: Args) -> Result<()> {
+    let (version, graph, anatomical_entity_id) = load_graph(args)?;
+    let edges = graph.edges(anatomical_entity_id);
+
+    info!(
+        "writing {} relationship(s) from Uberon {}",
+        edges.len(),
+        version
+    );
+
+    // NOTE: the hierarchy is read by splitting each row on tabs, so values are
+    // never quoted.
+    let mut wtr = csv::WriterBuilder::new()
+        .delimiter(b'\t')
+        .quote_style(csv::QuoteStyle::Never)
+        .from_writer(std::io::stdout());
+
+    wtr.write_record(["child_id", "child_label", "parent_id", "parent_label"])?;
+
+    for (child, parent) in &edges {
+        wtr.write_record([
+            child.id.as_str(),
+            child.label.as_str(),
+            parent.id.as_str(),
+            parent.label.as_str(),
+        ])?;
+    }
+
+    wtr.flush()?;
+
+    Ok(())
+}
+
 #[cfg(test)]
 mod tests {}
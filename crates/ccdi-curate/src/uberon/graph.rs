@@ -14,7 +14,7 @@ use serde::Serialize;
 use serde_json::Value;
 
 /// A node in the Uberon ontology.
-#[derive(Clone, Debug, Deserialize, Serialize)]
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
 pub struct Node {
     /// The identifier.
     pub id: String,
@@ -190,4 +190,29 @@ impl UberonGraph {
 
         results
     }
+
+    /// Does a DFS on the tree by starting at the node with the provided node
+    /// name, returning every `is_a` relationship therein as a pair of the
+    /// child node and the parent node.
+    pub fn edges(&self, node_name: String) -> Vec<(Node, Node)> {
+        let mut results = Vec::new();
+
+        let node_index = *self
+            .node_map
+            .get(&node_name)
+            .expect("root node for DFS does not exist in node map");
+
+        let mut dfs = Dfs::new(&self.graph, node_index);
+
+        while let Some(parent) = dfs.next(&self.graph) {
+            for child in self.graph.neighbors(parent) {
+                results.push((
+                    self.graph.node_weight(child).unwrap().clone(),
+                    self.graph.node_weight(parent).unwrap().clone(),
+                ));
+            }
+        }
+
+        results
+    }
 }
@@ -0,0 +1,267 @@
+//! Harmonization of free-text anatomical sites against Uberon terms.
+//!
+//! Free-text sites are matched against the labels and synonyms of the curated
+//! Uberon terms (the nodes underneath the `anatomical entity` node). In order
+//! of preference, a site is matched
+//!
+//! * exactly against the label of a term,
+//! * against the label of a term after both are [normalized](normalize), or
+//! * against a synonym of a term after both are [normalized](normalize).
+//!
+//! A normalized synonym that is shared by more than one term is ambiguous and
+//! is never matched.
+
+use std::collections::HashMap;
+
+use crate::uberon::graph::Node;
+
+/// The confidence of a [`Match`].
+///
+/// Variants are ordered from the most confident to the least confident.
+#[derive(Clone, Copy, Debug, Eq, Ord, PartialEq, PartialOrd)]
+pub enum Confidence {
+    /// The site is identical to the label of the term.
+    Exact,
+
+    /// The site matches the label of the term once both are normalized.
+    Normalized,
+
+    /// The site matches a synonym of the term once both are normalized.
+    Synonym,
+}
+
+impl std::fmt::Display for Confidence {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Confidence::Exact => write!(f, "exact"),
+            Confidence::Normalized => write!(f, "normalized"),
+            Confidence::Synonym => write!(f, "synonym"),
+        }
+    }
+}
+
+/// A match between a free-text site and an Uberon term.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Match<'a> {
+    /// The matched term.
+    pub node: &'a Node,
+
+    /// The confidence of the match.
+    pub confidence: Confidence,
+}
+
+/// Normalizes a free-text site for matching.
+///
+/// Normalization lowercases the site, treats any run of punctuation or
+/// whitespace as a single space, and trims the result.
+pub fn normalize(value: &str) -> String {
+    value
+        .to_lowercase()
+        .split(|c: char| !c.is_alphanumeric())
+        .filter(|word| !word.is_empty())
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Matches free-text sites against a list of Uberon terms.
+#[derive(Debug)]
+pub struct Matcher {
+    /// The terms to match against.
+    nodes: Vec<Node>,
+
+    /// A map between labels and the index of their term.
+    labels: HashMap<String, usize>,
+
+    /// A map between normalized labels and the index of their term.
+    normalized_labels: HashMap<String, usize>,
+
+    /// A map between normalized synonyms and the index of their term (or
+    /// [`None`] when the synonym is shared by more than one term).
+    synonyms: HashMap<String, Option<usize>>,
+}
+
+impl Matcher {
+    /// Creates a new [`Matcher`] from a list of Uberon terms.
+    ///
+    /// When more than one term has the same (normalized) label, the first term
+    /// is preferred.
+    pub fn new(nodes: Vec<Node>) -> Self {
+        let mut labels = HashMap::new();
+        let mut normalized_labels = HashMap::new();
+        let mut synonyms = HashMap::new();
+
+        for (index, node) in nodes.iter().enumerate() {
+            labels.entry(node.label.clone()).or_insert(index);
+            normalized_labels
+                .entry(normalize(&node.label))
+                .or_insert(index);
+
+            for synonym in &node.synonyms {
+                synonyms
+                    .entry(normalize(synonym))
+                    .and_modify(|existing: &mut Option<usize>| {
+                        if *existing != Some(index) {
+                            *existing = None;
+                        }
+                    })
+                    .or_insert(Some(index));
+            }
+        }
+
+        Self {
+            nodes,
+            labels,
+            normalized_labels,
+            synonyms,
+        }
+    }
+
+    /// Attempts to match a free-text site to an Uberon term.
+    ///
+    /// Returns [`None`] when the site cannot be matched to exactly one term.
+    pub fn find(&self, site: &str) -> Option<Match<'_>> {
+        let matched = |index: usize, confidence| Match {
+            node: &self.nodes[index],
+            confidence,
+        };
+
+        if let Some(index) = self.labels.get(site) {
+            return Some(matched(*index, Confidence::Exact));
+        }
+
+        let site = normalize(site);
+
+        if let Some(index) = self.normalized_labels.get(&site) {
+            return Some(matched(*index, Confidence::Normalized));
+        }
+
+        self.synonyms
+            .get(&site)
+            .copied()
+            .flatten()
+            .map(|index| matched(index, Confidence::Synonym))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn node(id: &str, label: &str, synonyms: &[&str]) -> Node {
+        Node {
+            id: format!("http://purl.obolibrary.org/obo/{id}"),
+            label: label.to_string(),
+            synonyms: synonyms.iter().map(|synonym| synonym.to_string()).collect(),
+        }
+    }
+
+    fn matcher() -> Matcher {
+        Matcher::new(vec![
+            node("UBERON_0000955", "brain", &["encephalon"]),
+            node(
+                "UBERON_0002113",
+                "kidney",
+                &["renal organ", "nephros", "Kidney (organ)"],
+            ),
+            node("UBERON_0000981", "femur", &["thigh bone"]),
+            node("UBERON_0001155", "colon", &["large bowel"]),
+            node("UBERON_0000059", "large intestine", &["large bowel"]),
+            node("UBERON_0002107", "liver", &[]),
+        ])
+    }
+
+    fn find(site: &str) -> Option<(String, Confidence)> {
+        let matcher = matcher();
+        matcher
+            .find(site)
+            .map(|m| (m.node.label.clone(), m.confidence))
+    }
+
+    #[test]
+    fn it_normalizes_sites() {
+        assert_eq!(normalize("Kidney"), "kidney");
+        assert_eq!(normalize("  Renal   organ\t"), "renal organ");
+        assert_eq!(normalize("Kidney (organ)"), "kidney organ");
+        assert_eq!(normalize("thigh-bone"), "thigh bone");
+        assert_eq!(normalize("Ammon's horn"), "ammon s horn");
+        assert_eq!(normalize(" ,. "), "");
+    }
+
+    #[test]
+    fn it_matches_labels_exactly() {
+        assert_eq!(
+            find("brain"),
+            Some((String::from("brain"), Confidence::Exact))
+        );
+    }
+
+    #[test]
+    fn it_matches_labels_regardless_of_case_and_whitespace() {
+        for (site, expected) in [
+            ("Brain", "brain"),
+            ("BRAIN", "brain"),
+            ("  brain ", "brain"),
+            ("brain\t", "brain"),
+            ("Large  Intestine", "large intestine"),
+        ] {
+            assert_eq!(
+                find(site),
+                Some((String::from(expected), Confidence::Normalized)),
+                "{site:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn it_matches_labels_regardless_of_punctuation() {
+        assert_eq!(
+            find("large-intestine"),
+            Some((String::from("large intestine"), Confidence::Normalized))
+        );
+        assert_eq!(
+            find("Liver."),
+            Some((String::from("liver"), Confidence::Normalized))
+        );
+    }
+
+    #[test]
+    fn it_matches_synonyms() {
+        for (site, expected) in [
+            ("encephalon", "brain"),
+            ("Renal Organ", "kidney"),
+            ("nephros", "kidney"),
+            ("kidney, organ", "kidney"),
+            ("Thigh-Bone", "femur"),
+        ] {
+            assert_eq!(
+                find(site),
+                Some((String::from(expected), Confidence::Synonym)),
+                "{site:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn it_does_not_match_ambiguous_synonyms() {
+        assert_eq!(find("large bowel"), None);
+    }
+
+    #[test]
+    fn it_prefers_labels_to_synonyms() {
+        let matcher = Matcher::new(vec![
+            node("UBERON_0000001", "first", &["second"]),
+            node("UBERON_0000002", "second", &[]),
+        ]);
+
+        let matched = matcher.find("Second").unwrap();
+        assert_eq!(matched.node.label, "second");
+        assert_eq!(matched.confidence, Confidence::Normalized);
+    }
+
+    #[test]
+    fn it_does_not_match_unknown_sites() {
+        assert_eq!(find("spleen"), None);
+        assert_eq!(find(""), None);
+        assert_eq!(find("brain stem"), None);
+    }
+}
@@ -2,11 +2,12 @@
 
 use nonempty::NonEmpty;
 use rand::thread_rng;
-use rand::Rng as _;
+use rand::Rng;
 use serde::Deserialize;
 use serde::Serialize;
 use utoipa::ToSchema;
 
+pub mod checksum;
 mod identifier;
 pub mod metadata;
 
@@ -15,9 +16,8 @@ pub use metadata::Metadata;
 
 use crate::gateway;
 use crate::gateway::AnonymousOrReference;
-use crate::gateway::Link;
+use crate::metadata::DEFAULT_METADATA_LESS_FRACTION;
 use crate::Entity;
-use crate::Url;
 
 /// A file.
 ///
@@ -36,6 +36,9 @@ pub struct File {
     /// subsequent `/namespace/<name>` endpoint). Failure to include the
     /// namespace in the results at that endpoint signifies non-compliance with
     /// the API.
+    ///
+    /// The name within this identifier must be unique within the namespace,
+    /// but the same name may be used by files within other namespaces.
     #[schema(value_type = models::file::Identifier)]
     id: Identifier,
 
@@ -108,6 +111,7 @@ impl File {
     /// use models::file::Metadata;
     /// use models::gateway::AnonymousOrReference;
     /// use models::gateway::Link;
+    /// use models::gateway::Open;
     /// use models::namespace;
     /// use models::organization;
     /// use models::sample;
@@ -146,11 +150,15 @@ impl File {
     ///     Identifier::new(namespace.id().clone(), cde::v1::file::Name::new("Foo.txt")),
     ///     NonEmpty::new(sample_id),
     ///     Some(NonEmpty::new(AnonymousOrReference::Anonymous {
-    ///         gateway: Gateway::Open {
-    ///             link: Link::Direct {
-    ///                 url: "https://example.com".parse::<Url>().unwrap(),
-    ///             },
-    ///         },
+    ///         gateway: Gateway::Open(
+    ///             Open::try_new(
+    ///                 Link::Direct {
+    ///                     url: "https://example.com".parse::<Url>().unwrap(),
+    ///                 },
+    ///                 None,
+    ///             )
+    ///             .unwrap(),
+    ///         ),
     ///     })),
     ///     Some(Metadata::random()),
     /// );
@@ -183,6 +191,7 @@ impl File {
     /// use models::file::Metadata;
     /// use models::gateway::AnonymousOrReference;
     /// use models::gateway::Link;
+    /// use models::gateway::Open;
     /// use models::namespace;
     /// use models::organization;
     /// use models::sample;
@@ -221,11 +230,15 @@ impl File {
     ///     Identifier::new(namespace.id().clone(), cde::v1::file::Name::new("Foo.txt")),
     ///     NonEmpty::new(sample_id),
     ///     Some(NonEmpty::new(AnonymousOrReference::Anonymous {
-    ///         gateway: Gateway::Open {
-    ///             link: Link::Direct {
-    ///                 url: "https://example.com".parse::<Url>().unwrap(),
-    ///             },
-    ///         },
+    ///         gateway: Gateway::Open(
+    ///             Open::try_new(
+    ///                 Link::Direct {
+    ///                     url: "https://example.com".parse::<Url>().unwrap(),
+    ///                 },
+    ///                 None,
+    ///             )
+    ///             .unwrap(),
+    ///         ),
     ///     })),
     ///     Some(Metadata::random()),
     /// );
@@ -256,6 +269,7 @@ impl File {
     /// use models::file::Metadata;
     /// use models::gateway::AnonymousOrReference;
     /// use models::gateway::Link;
+    /// use models::gateway::Open;
     /// use models::namespace;
     /// use models::organization;
     /// use models::sample;
@@ -294,11 +308,15 @@ impl File {
     ///     Identifier::new(namespace.id().clone(), cde::v1::file::Name::new("Foo.txt")),
     ///     NonEmpty::new(sample_id),
     ///     Some(NonEmpty::new(AnonymousOrReference::Anonymous {
-    ///         gateway: Gateway::Open {
-    ///             link: Link::Direct {
-    ///                 url: "https://example.com".parse::<Url>().unwrap(),
-    ///             },
-    ///         },
+    ///         gateway: Gateway::Open(
+    ///             Open::try_new(
+    ///                 Link::Direct {
+    ///                     url: "https://example.com".parse::<Url>().unwrap(),
+    ///                 },
+    ///                 None,
+    ///             )
+    ///             .unwrap(),
+    ///         ),
     ///     })),
     ///     Some(Metadata::random()),
     /// );
@@ -327,6 +345,7 @@ impl File {
     /// use models::file::Metadata;
     /// use models::gateway::AnonymousOrReference;
     /// use models::gateway::Link;
+    /// use models::gateway::Open;
     /// use models::namespace;
     /// use models::organization;
     /// use models::sample;
@@ -365,11 +384,15 @@ impl File {
     ///     Identifier::new(namespace.id().clone(), cde::v1::file::Name::new("Foo.txt")),
     ///     NonEmpty::new(sample_id),
     ///     Some(NonEmpty::new(AnonymousOrReference::Anonymous {
-    ///         gateway: Gateway::Open {
-    ///             link: Link::Direct {
-    ///                 url: "https://example.com".parse::<Url>().unwrap(),
-    ///             },
-    ///         },
+    ///         gateway: Gateway::Open(
+    ///             Open::try_new(
+    ///                 Link::Direct {
+    ///                     url: "https://example.com".parse::<Url>().unwrap(),
+    ///                 },
+    ///                 None,
+    ///             )
+    ///             .unwrap(),
+    ///         ),
     ///     })),
     ///     Some(Metadata::random()),
     /// );
@@ -397,6 +420,7 @@ impl File {
     /// use models::file::Metadata;
     /// use models::gateway::AnonymousOrReference;
     /// use models::gateway::Link;
+    /// use models::gateway::Open;
     /// use models::namespace;
     /// use models::organization;
     /// use models::sample;
@@ -435,11 +459,15 @@ impl File {
     ///     Identifier::new(namespace.id().clone(), cde::v1::file::Name::new("Foo.txt")),
     ///     NonEmpty::new(sample_id),
     ///     Some(NonEmpty::new(AnonymousOrReference::Anonymous {
-    ///         gateway: Gateway::Open {
-    ///             link: Link::Direct {
-    ///                 url: "https://example.com".parse::<Url>().unwrap(),
-    ///             },
-    ///         },
+    ///         gateway: Gateway::Open(
+    ///             Open::try_new(
+    ///                 Link::Direct {
+    ///                     url: "https://example.com".parse::<Url>().unwrap(),
+    ///                 },
+    ///                 None,
+    ///             )
+    ///             .unwrap(),
+    ///         ),
     ///     })),
     ///     Some(Metadata::random()),
     /// );
@@ -452,6 +480,54 @@ impl File {
         self.metadata.as_ref()
     }
 
+    /// Returns whether the [`File`] has metadata.
+    ///
+    /// A [`File`] whose metadata block is missing or is [effectively
+    /// empty](Metadata::is_effectively_empty) is considered to have no
+    /// metadata.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ccdi_cde as cde;
+    /// use ccdi_models as models;
+    ///
+    /// use models::file::metadata::Builder;
+    /// use models::metadata::field::unowned::file::Size;
+    /// use models::namespace;
+    /// use models::File;
+    /// use nonempty::NonEmpty;
+    ///
+    /// let namespace = namespace::Identifier::new(
+    ///     "example-organization"
+    ///         .parse::<models::organization::Identifier>()
+    ///         .unwrap(),
+    ///     namespace::identifier::Name::try_new("ExampleNamespace").unwrap(),
+    /// );
+    ///
+    /// let id = models::file::Identifier::new(namespace.clone(), cde::v1::file::Name::new("Foo.txt"));
+    /// let samples = NonEmpty::new(models::sample::Identifier::new(namespace, "SampleName001"));
+    ///
+    /// let file = File::new(id.clone(), samples.clone(), None, None);
+    /// assert!(!file.has_metadata());
+    ///
+    /// let metadata = Builder::default().build();
+    /// let file = File::new(id.clone(), samples.clone(), None, Some(metadata));
+    /// assert!(!file.has_metadata());
+    ///
+    /// let metadata = Builder::default()
+    ///     .size(Size::new(cde::v1::file::Size::new(42), None, None, None))
+    ///     .build();
+    /// let file = File::new(id, samples, None, Some(metadata));
+    /// assert!(file.has_metadata());
+    /// ```
+    pub fn has_metadata(&self) -> bool {
+        self.metadata
+            .as_ref()
+            .map(|metadata| !metadata.is_effectively_empty())
+            .unwrap_or(false)
+    }
+
     /// Generates a random [`File`].
     ///
     /// # Examples
@@ -464,6 +540,7 @@ impl File {
     /// use models::file::Metadata;
     /// use models::gateway::AnonymousOrReference;
     /// use models::gateway::Link;
+    /// use models::gateway::Open;
     /// use models::namespace;
     /// use models::organization;
     /// use models::sample;
@@ -506,25 +583,114 @@ impl File {
     /// assert_eq!(file.gateways().unwrap().len(), 1);
     /// ```
     pub fn random(identifier: Identifier, sample: crate::sample::Identifier) -> Self {
-        let mut rng = thread_rng();
+        Self::random_with_metadata_less_fraction(identifier, sample, DEFAULT_METADATA_LESS_FRACTION)
+    }
+
+    /// Generates a random [`File`] based on a particular [`Identifier`] where
+    /// `metadata_less_fraction` is the probability that the generated
+    /// [`File`] has no metadata.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `metadata_less_fraction` is not within `0.0..=1.0`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ccdi_cde as cde;
+    /// use ccdi_models as models;
+    ///
+    /// use models::namespace;
+    /// use models::File;
+    ///
+    /// let namespace = namespace::Identifier::new(
+    ///     "example-organization"
+    ///         .parse::<models::organization::Identifier>()
+    ///         .unwrap(),
+    ///     namespace::identifier::Name::try_new("ExampleNamespace").unwrap(),
+    /// );
+    ///
+    /// let id = models::file::Identifier::new(namespace.clone(), cde::v1::file::Name::new("Foo.txt"));
+    /// let sample = models::sample::Identifier::new(namespace, "SampleName001");
+    ///
+    /// let file = File::random_with_metadata_less_fraction(id.clone(), sample.clone(), 1.0);
+    /// assert!(file.metadata().is_none());
+    ///
+    /// let file = File::random_with_metadata_less_fraction(id, sample, 0.0);
+    /// assert!(file.has_metadata());
+    /// ```
+    pub fn random_with_metadata_less_fraction(
+        identifier: Identifier,
+        sample: crate::sample::Identifier,
+        metadata_less_fraction: f64,
+    ) -> Self {
+        Self::random_with_rng(
+            identifier,
+            sample,
+            metadata_less_fraction,
+            &mut thread_rng(),
+        )
+    }
 
+    /// Generates a random [`File`] based on a particular [`Identifier`] using
+    /// the provided random number generator, where `metadata_less_fraction` is
+    /// the probability that the generated [`File`] has no metadata.
+    ///
+    /// The same seed always generates the same [`File`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `metadata_less_fraction` is not within `0.0..=1.0`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ccdi_cde as cde;
+    /// use ccdi_models as models;
+    /// use rand::rngs::StdRng;
+    /// use rand::SeedableRng as _;
+    ///
+    /// use models::namespace;
+    /// use models::File;
+    ///
+    /// let namespace = namespace::Identifier::new(
+    ///     "example-organization"
+    ///         .parse::<models::organization::Identifier>()
+    ///         .unwrap(),
+    ///     namespace::identifier::Name::try_new("ExampleNamespace").unwrap(),
+    /// );
+    ///
+    /// let id = models::file::Identifier::new(namespace.clone(), cde::v1::file::Name::new("Foo.txt"));
+    /// let sample = models::sample::Identifier::new(namespace, "SampleName001");
+    ///
+    /// let a = File::random_with_rng(
+    ///     id.clone(),
+    ///     sample.clone(),
+    ///     0.5,
+    ///     &mut StdRng::seed_from_u64(42),
+    /// );
+    /// let b = File::random_with_rng(id, sample, 0.5, &mut StdRng::seed_from_u64(42));
+    /// assert_eq!(a, b);
+    /// ```
+    pub fn random_with_rng<R: Rng + ?Sized>(
+        identifier: Identifier,
+        sample: crate::sample::Identifier,
+        metadata_less_fraction: f64,
+        rng: &mut R,
+    ) -> Self {
         Self {
             id: identifier.clone(),
             samples: NonEmpty::new(sample),
             gateways: match rng.gen_bool(0.9) {
                 true => Some(NonEmpty::new(AnonymousOrReference::Anonymous {
-                    gateway: crate::Gateway::Open {
-                        link: Link::Direct {
-                            url: "https://example.com".parse::<Url>().unwrap(),
-                        },
-                    },
+                    gateway: crate::Gateway::random_with_rng(rng),
                 })),
                 false => Some(NonEmpty::new(AnonymousOrReference::Reference {
                     gateway: String::from("gateway"),
                 })),
             },
-            metadata: match rng.gen_bool(0.7) {
-                true => Some(Metadata::random()),
+            metadata: match rng.gen_bool(1.0 - metadata_less_fraction) {
+                true => Some(Metadata::random_with_rng(rng)),
                 false => None,
             },
         }
@@ -0,0 +1,473 @@
+//! Checksums of files.
+
+use std::str::FromStr;
+
+use rand::distributions::Distribution;
+use rand::distributions::Standard;
+use rand::seq::SliceRandom as _;
+use serde::Deserialize;
+use serde::Serialize;
+
+use ccdi_cde as cde;
+
+/// The hexadecimal digits from which random digests are drawn.
+const HEX_DIGITS: &[u8] = b"0123456789abcdef";
+
+/// An error related to a [`Checksum`].
+#[derive(Debug, Eq, PartialEq)]
+pub enum Error {
+    /// The algorithm is not a supported [`Algorithm`].
+    UnknownAlgorithm(String),
+
+    /// A checksum was parsed without an algorithm (i.e., it was not of the form
+    /// `algorithm:value`).
+    MissingAlgorithm(String),
+
+    /// The value is not a valid digest for the algorithm.
+    InvalidDigest {
+        /// The algorithm.
+        algorithm: Algorithm,
+
+        /// The value.
+        value: String,
+    },
+}
+
+impl std::fmt::Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::UnknownAlgorithm(algorithm) => write!(
+                f,
+                "unknown checksum algorithm `{algorithm}`: expected one of {}",
+                Algorithm::ALL
+                    .iter()
+                    .map(|algorithm| format!("`{algorithm}`"))
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            ),
+            Error::MissingAlgorithm(value) => write!(
+                f,
+                "checksum `{value}` is missing an algorithm: expected `algorithm:value`"
+            ),
+            Error::InvalidDigest { algorithm, value } => write!(
+                f,
+                "invalid {algorithm} checksum `{value}`: expected {}",
+                algorithm.expected()
+            ),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+/// A [`Result`](std::result::Result) with an [`Error`].
+pub type Result<T> = std::result::Result<T, Error>;
+
+/// An algorithm with which a [`Checksum`] is computed.
+#[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize)]
+pub enum Algorithm {
+    /// MD5 (a 32-character hexadecimal digest).
+    #[serde(rename = "md5")]
+    MD5,
+
+    /// SHA-1 (a 40-character hexadecimal digest).
+    #[serde(rename = "sha1")]
+    SHA1,
+
+    /// SHA-256 (a 64-character hexadecimal digest).
+    #[serde(rename = "sha256")]
+    SHA256,
+
+    /// An entity tag as computed by object stores such as Amazon S3 (a
+    /// 32-character hexadecimal digest optionally followed by a hyphen and the
+    /// number of parts for multipart uploads).
+    #[serde(rename = "etag")]
+    ETag,
+}
+
+impl Algorithm {
+    /// All of the supported [`Algorithm`]s.
+    pub const ALL: &'static [Algorithm] = &[
+        Algorithm::MD5,
+        Algorithm::SHA1,
+        Algorithm::SHA256,
+        Algorithm::ETag,
+    ];
+
+    /// Gets the name of the [`Algorithm`] as it is written within a
+    /// [`Checksum`] (e.g., `sha256`).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ccdi_models as models;
+    ///
+    /// use models::file::checksum::Algorithm;
+    ///
+    /// assert_eq!(Algorithm::MD5.as_str(), "md5");
+    /// assert_eq!(Algorithm::SHA256.as_str(), "sha256");
+    /// ```
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Algorithm::MD5 => "md5",
+            Algorithm::SHA1 => "sha1",
+            Algorithm::SHA256 => "sha256",
+            Algorithm::ETag => "etag",
+        }
+    }
+
+    /// The number of hexadecimal digits in a digest computed by the
+    /// [`Algorithm`].
+    fn hex_length(&self) -> usize {
+        match self {
+            Algorithm::MD5 | Algorithm::ETag => 32,
+            Algorithm::SHA1 => 40,
+            Algorithm::SHA256 => 64,
+        }
+    }
+
+    /// A description of the digests expected for the [`Algorithm`].
+    fn expected(&self) -> String {
+        match self {
+            Algorithm::ETag => String::from(
+                "32 hexadecimal characters optionally followed by a hyphen and a part count",
+            ),
+            algorithm => format!("{} hexadecimal characters", algorithm.hex_length()),
+        }
+    }
+}
+
+impl std::fmt::Display for Algorithm {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl FromStr for Algorithm {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s.to_ascii_lowercase().as_str() {
+            "md5" => Ok(Algorithm::MD5),
+            "sha1" | "sha-1" => Ok(Algorithm::SHA1),
+            "sha256" | "sha-256" => Ok(Algorithm::SHA256),
+            "etag" => Ok(Algorithm::ETag),
+            _ => Err(Error::UnknownAlgorithm(s.to_string())),
+        }
+    }
+}
+
+/// Samples a random [`Checksum`] computed with the [`Algorithm`].
+impl Distribution<Checksum> for Algorithm {
+    fn sample<R: rand::prelude::Rng + ?Sized>(&self, rng: &mut R) -> Checksum {
+        let digest = (0..self.hex_length())
+            // SAFETY: the list of hexadecimal digits is not empty.
+            .map(|_| *HEX_DIGITS.choose(rng).unwrap() as char)
+            .collect::<String>();
+
+        // SAFETY: the digest has the number of hexadecimal digits expected for
+        // the algorithm, so it will always be valid.
+        Checksum::try_new(*self, digest).unwrap()
+    }
+}
+
+/// The unvalidated form of a [`Checksum`] as it is deserialized.
+#[derive(Deserialize)]
+struct Unvalidated {
+    /// The algorithm.
+    algorithm: Algorithm,
+
+    /// The value.
+    value: String,
+}
+
+/// A checksum of a file: the [`Algorithm`] with which it was computed along
+/// with its digest.
+///
+/// Digests are validated for the algorithm when a [`Checksum`] is created (or
+/// deserialized) and are normalized to lowercase. A [`Checksum`] is written
+/// (and parsed) as `algorithm:value` (e.g., `md5:d41d8cd98f00b204e9800998ecf8427e`)
+/// and serialized as `{ "algorithm": "md5", "value": "..." }`.
+#[derive(Clone, Debug, Deserialize, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize)]
+#[serde(try_from = "Unvalidated")]
+pub struct Checksum {
+    /// The algorithm.
+    algorithm: Algorithm,
+
+    /// The digest.
+    value: String,
+}
+
+impl Checksum {
+    /// Attempts to create a new [`Checksum`].
+    ///
+    /// Hexadecimal digests are normalized to lowercase, and the quotes
+    /// surrounding an entity tag (if any) are removed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ccdi_models as models;
+    ///
+    /// use models::file::checksum::Algorithm;
+    /// use models::file::checksum::Checksum;
+    ///
+    /// let checksum = Checksum::try_new(Algorithm::MD5, "D41D8CD98F00B204E9800998ECF8427E")?;
+    /// assert_eq!(checksum.value(), "d41d8cd98f00b204e9800998ecf8427e");
+    ///
+    /// assert!(Checksum::try_new(Algorithm::SHA1, "d41d8cd98f00b204e9800998ecf8427e").is_err());
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn try_new(algorithm: Algorithm, value: impl Into<String>) -> Result<Self> {
+        let value = value.into();
+
+        let digest = match algorithm {
+            Algorithm::ETag => value
+                .strip_prefix('"')
+                .and_then(|digest| digest.strip_suffix('"'))
+                .unwrap_or(&value),
+            _ => &value,
+        };
+
+        let (hex, parts) = match (algorithm, digest.split_once('-')) {
+            (Algorithm::ETag, Some((hex, parts))) => (hex, Some(parts)),
+            _ => (digest, None),
+        };
+
+        let valid_hex =
+            hex.len() == algorithm.hex_length() && hex.chars().all(|c| c.is_ascii_hexdigit());
+        let valid_parts = parts
+            .map(|parts| {
+                !parts.is_empty()
+                    && !parts.starts_with('0')
+                    && parts.chars().all(|c| c.is_ascii_digit())
+            })
+            .unwrap_or(true);
+
+        if !valid_hex || !valid_parts {
+            return Err(Error::InvalidDigest { algorithm, value });
+        }
+
+        Ok(Self {
+            algorithm,
+            value: digest.to_ascii_lowercase(),
+        })
+    }
+
+    /// Gets the [`Algorithm`] of the [`Checksum`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ccdi_models as models;
+    ///
+    /// use models::file::checksum::Algorithm;
+    /// use models::file::checksum::Checksum;
+    ///
+    /// let checksum = "etag:\"d41d8cd98f00b204e9800998ecf8427e-2\"".parse::<Checksum>()?;
+    /// assert_eq!(checksum.algorithm(), Algorithm::ETag);
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn algorithm(&self) -> Algorithm {
+        self.algorithm
+    }
+
+    /// Gets the (normalized) digest of the [`Checksum`] by reference.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ccdi_models as models;
+    ///
+    /// use models::file::checksum::Checksum;
+    ///
+    /// let checksum = "etag:\"D41D8CD98F00B204E9800998ECF8427E-2\"".parse::<Checksum>()?;
+    /// assert_eq!(checksum.value(), "d41d8cd98f00b204e9800998ecf8427e-2");
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn value(&self) -> &str {
+        &self.value
+    }
+
+    /// Consumes `self` and returns the (normalized) digest of the
+    /// [`Checksum`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ccdi_models as models;
+    ///
+    /// use models::file::checksum::Checksum;
+    ///
+    /// let checksum = "md5:d41d8cd98f00b204e9800998ecf8427e".parse::<Checksum>()?;
+    /// assert_eq!(
+    ///     checksum.into_value(),
+    ///     String::from("d41d8cd98f00b204e9800998ecf8427e")
+    /// );
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn into_value(self) -> String {
+        self.value
+    }
+}
+
+impl std::fmt::Display for Checksum {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}:{}", self.algorithm, self.value)
+    }
+}
+
+impl FromStr for Checksum {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let (algorithm, value) = s
+            .split_once(':')
+            .ok_or_else(|| Error::MissingAlgorithm(s.to_string()))?;
+
+        Checksum::try_new(algorithm.parse::<Algorithm>()?, value)
+    }
+}
+
+impl TryFrom<Unvalidated> for Checksum {
+    type Error = Error;
+
+    fn try_from(value: Unvalidated) -> Result<Self> {
+        Checksum::try_new(value.algorithm, value.value)
+    }
+}
+
+impl From<&cde::v1::file::checksum::MD5> for Checksum {
+    fn from(md5: &cde::v1::file::checksum::MD5) -> Self {
+        // SAFETY: md5 checksums are validated to be 32 hexadecimal characters
+        // when they are created, so this will always unwrap.
+        Checksum::try_new(Algorithm::MD5, md5.inner()).unwrap()
+    }
+}
+
+impl Distribution<Checksum> for Standard {
+    fn sample<R: rand::prelude::Rng + ?Sized>(&self, rng: &mut R) -> Checksum {
+        // SAFETY: the list of algorithms is not empty.
+        let algorithm = *Algorithm::ALL.choose(rng).unwrap();
+        rng.sample(algorithm)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MD5: &str = "d41d8cd98f00b204e9800998ecf8427e";
+    const SHA1: &str = "da39a3ee5e6b4b0d3255bfef95601890afd80709";
+    const SHA256: &str = "e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855";
+
+    #[test]
+    fn it_creates_checksums_for_each_algorithm() {
+        for (algorithm, value) in [
+            (Algorithm::MD5, MD5),
+            (Algorithm::SHA1, SHA1),
+            (Algorithm::SHA256, SHA256),
+            (Algorithm::ETag, MD5),
+        ] {
+            let checksum = Checksum::try_new(algorithm, value).unwrap();
+            assert_eq!(checksum.algorithm(), algorithm);
+            assert_eq!(checksum.value(), value);
+        }
+    }
+
+    #[test]
+    fn it_rejects_invalid_hex() {
+        for value in [
+            "g41d8cd98f00b204e9800998ecf8427e",
+            "d41d8cd98f00b204e9800998ecf8427 ",
+            "d41d8cd98f00b204e9800998ecf8427.",
+        ] {
+            assert_eq!(
+                Checksum::try_new(Algorithm::MD5, value),
+                Err(Error::InvalidDigest {
+                    algorithm: Algorithm::MD5,
+                    value: value.to_string(),
+                })
+            );
+        }
+    }
+
+    #[test]
+    fn it_rejects_digests_of_the_wrong_length() {
+        assert!(Checksum::try_new(Algorithm::MD5, "").is_err());
+        assert!(Checksum::try_new(Algorithm::MD5, &MD5[1..]).is_err());
+        assert!(Checksum::try_new(Algorithm::MD5, SHA1).is_err());
+        assert!(Checksum::try_new(Algorithm::SHA1, MD5).is_err());
+        assert!(Checksum::try_new(Algorithm::SHA1, SHA256).is_err());
+        assert!(Checksum::try_new(Algorithm::SHA256, SHA1).is_err());
+        assert!(Checksum::try_new(Algorithm::SHA256, format!("{SHA256}0")).is_err());
+    }
+
+    #[test]
+    fn it_normalizes_the_case_of_digests() {
+        let checksum = Checksum::try_new(Algorithm::SHA1, SHA1.to_uppercase()).unwrap();
+        assert_eq!(checksum.value(), SHA1);
+        assert_eq!(checksum, Checksum::try_new(Algorithm::SHA1, SHA1).unwrap());
+
+        let checksum = "SHA-256:E3B0C44298FC1C149AFBF4C8996FB92427AE41E4649B934CA495991B7852B855"
+            .parse::<Checksum>()
+            .unwrap();
+        assert_eq!(checksum.algorithm(), Algorithm::SHA256);
+        assert_eq!(checksum.to_string(), format!("sha256:{SHA256}"));
+    }
+
+    #[test]
+    fn it_validates_entity_tags() {
+        let checksum = Checksum::try_new(Algorithm::ETag, format!("\"{MD5}-12\"")).unwrap();
+        assert_eq!(checksum.value(), format!("{MD5}-12"));
+
+        assert!(Checksum::try_new(Algorithm::ETag, format!("{MD5}-")).is_err());
+        assert!(Checksum::try_new(Algorithm::ETag, format!("{MD5}-0")).is_err());
+        assert!(Checksum::try_new(Algorithm::ETag, format!("{MD5}-a")).is_err());
+        assert!(Checksum::try_new(Algorithm::ETag, format!("\"{MD5}")).is_err());
+        assert!(Checksum::try_new(Algorithm::MD5, format!("{MD5}-2")).is_err());
+    }
+
+    #[test]
+    fn it_parses_checksums() {
+        assert_eq!(
+            format!("md5:{MD5}").parse::<Checksum>().unwrap(),
+            Checksum::try_new(Algorithm::MD5, MD5).unwrap()
+        );
+        assert_eq!(
+            MD5.parse::<Checksum>(),
+            Err(Error::MissingAlgorithm(MD5.to_string()))
+        );
+        assert_eq!(
+            format!("crc32:{MD5}").parse::<Checksum>(),
+            Err(Error::UnknownAlgorithm(String::from("crc32")))
+        );
+    }
+
+    #[test]
+    fn it_serializes_and_validates_when_deserializing() {
+        let checksum = Checksum::try_new(Algorithm::MD5, MD5).unwrap();
+        let json = serde_json::to_string(&checksum).unwrap();
+        assert_eq!(
+            json,
+            format!("{{\"algorithm\":\"md5\",\"value\":\"{MD5}\"}}")
+        );
+        assert_eq!(serde_json::from_str::<Checksum>(&json).unwrap(), checksum);
+
+        assert!(
+            serde_json::from_str::<Checksum>("{\"algorithm\":\"sha1\",\"value\":\"abc\"}").is_err()
+        );
+    }
+
+    #[test]
+    fn random_checksums_are_valid() {
+        for _ in 0..1000 {
+            let checksum: Checksum = rand::random();
+            assert_eq!(checksum.to_string().parse::<Checksum>().unwrap(), checksum);
+        }
+    }
+}
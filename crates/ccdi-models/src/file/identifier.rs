@@ -7,6 +7,11 @@ use ccdi_cde as cde;
 use crate::namespace;
 
 /// The primary name and namespace for a file within the source server.
+///
+/// The name of a file must be unique within the namespace to which the file
+/// belongs, but it is not required to be unique across namespaces. As such, a
+/// file is only uniquely identified by the combination of its namespace and its
+/// name.
 #[derive(Clone, Debug, Deserialize, Eq, Ord, PartialEq, PartialOrd, Serialize, ToSchema)]
 #[schema(as = models::file::Identifier)]
 pub struct Identifier {
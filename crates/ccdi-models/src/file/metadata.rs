@@ -1,7 +1,7 @@
 //! Metadata for a [`File`](super::File).
 
 use ccdi_cde as cde;
-use rand::Rng as _;
+use rand::Rng;
 use serde::Deserialize;
 use serde::Serialize;
 use utoipa::ToSchema;
@@ -10,16 +10,22 @@ use crate::metadata::common;
 use crate::metadata::field;
 use crate::metadata::fields;
 
+pub mod access_url;
 pub mod builder;
 mod checksums;
+pub mod drs_uri;
 
+pub use access_url::AccessUrl;
 pub use builder::Builder;
 pub use checksums::Checksums;
+pub use drs_uri::DrsUri;
 
 /// Metadata associated with a file.
 #[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize, ToSchema)]
 #[schema(as = models::file::Metadata)]
 pub struct Metadata {
+    // NOTE: the declaration order of these fields is contractual (see the
+    // module-level documentation of `crate::metadata`).
     /// The type of the file.
     #[schema(value_type = field::unowned::file::Type, nullable = true)]
     r#type: Option<field::unowned::file::Type>,
@@ -36,6 +42,14 @@ pub struct Metadata {
     #[schema(value_type = field::unowned::file::Description, nullable = true)]
     description: Option<field::unowned::file::Description>,
 
+    /// A GA4GH Data Repository Service (DRS) URI for the file.
+    #[schema(value_type = field::unowned::file::DrsUri, nullable = true)]
+    drs_uri: Option<field::unowned::file::DrsUri>,
+
+    /// An HTTP or HTTPS URL from which the file can be accessed.
+    #[schema(value_type = field::unowned::file::AccessUrl, nullable = true)]
+    access_url: Option<field::unowned::file::AccessUrl>,
+
     /// Common metadata elements for all metadata blocks.
     #[schema(value_type = models::metadata::common::Metadata)]
     #[serde(flatten)]
@@ -151,6 +165,52 @@ impl Metadata {
         self.description.as_ref()
     }
 
+    /// Gets the DRS URI for the [`Metadata`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ccdi_models as models;
+    ///
+    /// use models::file::metadata::Builder;
+    /// use models::file::metadata::DrsUri;
+    /// use models::metadata::field::unowned::file;
+    ///
+    /// let uri = "drs://drs.example.com/314159265358979fhqwhgads".parse::<DrsUri>()?;
+    /// let field = file::DrsUri::new(uri.clone(), None, None, None);
+    /// let metadata = Builder::default().drs_uri(field).build();
+    ///
+    /// assert_eq!(metadata.drs_uri().unwrap().value(), &uri);
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn drs_uri(&self) -> Option<&field::unowned::file::DrsUri> {
+        self.drs_uri.as_ref()
+    }
+
+    /// Gets the access URL for the [`Metadata`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ccdi_models as models;
+    ///
+    /// use models::file::metadata::AccessUrl;
+    /// use models::file::metadata::Builder;
+    /// use models::metadata::field::unowned::file;
+    ///
+    /// let url = "https://example.com/files/File1.txt".parse::<AccessUrl>()?;
+    /// let field = file::AccessUrl::new(url.clone(), None, None, None);
+    /// let metadata = Builder::default().access_url(field).build();
+    ///
+    /// assert_eq!(metadata.access_url().unwrap().value(), &url);
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn access_url(&self) -> Option<&field::unowned::file::AccessUrl> {
+        self.access_url.as_ref()
+    }
+
     /// Gets the common metadata fields for the [`Metadata`].
     ///
     /// # Examples
@@ -195,7 +255,7 @@ impl Metadata {
     ///             None,
     ///             None,
     ///         )),
-    ///     )
+    ///     )?
     ///     .insert_unharmonized(
     ///         "owned",
     ///         UnharmonizedField::Owned(owned::Field::new(
@@ -205,7 +265,7 @@ impl Metadata {
     ///             None,
     ///             None,
     ///         )),
-    ///     )
+    ///     )?
     ///     .build();
     ///
     /// assert!(matches!(
@@ -223,6 +283,41 @@ impl Metadata {
         &self.unharmonized
     }
 
+    /// Returns whether the [`Metadata`] is effectively empty.
+    ///
+    /// A metadata block is effectively empty when every harmonized field
+    /// (including the common metadata fields) is `None` and there are no
+    /// unharmonized fields. For the purposes of filtering and summarizing, an
+    /// effectively empty metadata block is treated the same as a missing one.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ccdi_cde as cde;
+    /// use ccdi_models as models;
+    ///
+    /// use models::file::metadata::Builder;
+    /// use models::metadata::field::unowned::file::Size;
+    ///
+    /// assert!(Builder::default().build().is_effectively_empty());
+    ///
+    /// let metadata = Builder::default()
+    ///     .size(Size::new(cde::v1::file::Size::new(42), None, None, None))
+    ///     .build();
+    ///
+    /// assert!(!metadata.is_effectively_empty());
+    /// ```
+    pub fn is_effectively_empty(&self) -> bool {
+        self.r#type.is_none()
+            && self.size.is_none()
+            && self.checksums.is_none()
+            && self.description.is_none()
+            && self.drs_uri.is_none()
+            && self.access_url.is_none()
+            && self.common.is_empty()
+            && self.unharmonized.is_empty()
+    }
+
     /// Generates a random [`Metadata`].
     ///
     /// # Examples
@@ -235,26 +330,46 @@ impl Metadata {
     /// let metadata = Metadata::random();
     /// ```
     pub fn random() -> Metadata {
+        Self::random_with_rng(&mut rand::thread_rng())
+    }
+
+    /// Generates a random [`Metadata`] using the provided random number
+    /// generator.
+    ///
+    /// The same seed always generates the same [`Metadata`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ccdi_models as models;
+    /// use rand::rngs::StdRng;
+    /// use rand::SeedableRng as _;
+    ///
+    /// use models::file::Metadata;
+    ///
+    /// assert_eq!(
+    ///     Metadata::random_with_rng(&mut StdRng::seed_from_u64(42)),
+    ///     Metadata::random_with_rng(&mut StdRng::seed_from_u64(42))
+    /// );
+    /// ```
+    pub fn random_with_rng<R: Rng + ?Sized>(rng: &mut R) -> Metadata {
         Metadata {
-            r#type: Some(field::unowned::file::Type::new(
-                cde::v1::file::Type::TXT,
-                None,
-                None,
-                None,
-            )),
+            r#type: Some(field::unowned::file::Type::new(rng.gen(), None, None, None)),
             size: Some(field::unowned::file::Size::new(
-                cde::v1::file::Size::new(rand::thread_rng().gen_range(usize::MIN..=usize::MAX)),
+                cde::v1::file::Size::new(rng.gen_range(usize::MIN..=usize::MAX)),
                 None,
                 None,
                 None,
             )),
-            checksums: Some(rand::random()),
+            checksums: Some(rng.gen()),
             description: Some(field::unowned::file::Description::new(
                 cde::v1::file::Description::new("This is an example description."),
                 None,
                 None,
                 None,
             )),
+            drs_uri: Some(rng.gen()),
+            access_url: Some(rng.gen()),
             common: Default::default(),
             unharmonized: Default::default(),
         }
@@ -270,7 +385,7 @@ mod tests {
         let metadata = builder::Builder::default().build();
         assert_eq!(
             &serde_json::to_string(&metadata).unwrap(),
-            "{\"type\":null,\"size\":null,\"checksums\":null,\"description\":null,\"depositions\":null}",
+            "{\"type\":null,\"size\":null,\"checksums\":null,\"description\":null,\"drs_uri\":null,\"access_url\":null,\"depositions\":null}",
         );
     }
 }
@@ -0,0 +1,190 @@
+//! An access URL for a file.
+
+use std::str::FromStr;
+
+use introspect::Introspect;
+use rand::distributions::Alphanumeric;
+use rand::distributions::Distribution;
+use rand::distributions::Standard;
+use serde::Deserialize;
+use serde::Serialize;
+use utoipa::ToSchema;
+
+/// The schemes allowed for an [`AccessUrl`].
+const SCHEMES: &[&str] = &["http", "https"];
+
+/// An error when parsing an [`AccessUrl`].
+#[derive(Debug)]
+pub enum ParseError {
+    /// The value could not be parsed as a URL.
+    InvalidUrl(url::ParseError),
+
+    /// The scheme component of the URL was not `http` or `https`.
+    InvalidScheme(String),
+
+    /// The host component of the URL was missing.
+    MissingHost,
+}
+
+impl std::fmt::Display for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ParseError::InvalidUrl(err) => write!(f, "invalid access URL: {err}"),
+            ParseError::InvalidScheme(scheme) => write!(
+                f,
+                "invalid scheme component in access URL: expected `http` or `https`, found \
+                 `{scheme}`"
+            ),
+            ParseError::MissingHost => write!(f, "missing host component in access URL"),
+        }
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// An HTTP or HTTPS URL from which a file can be accessed.
+///
+/// Access URLs are intended to point directly at the contents of the file (or
+/// at a landing page that facilitates access to the file). Only the `http` and
+/// `https` schemes are allowed.
+#[derive(
+    Clone, Debug, Deserialize, Eq, Introspect, Ord, PartialEq, PartialOrd, Serialize, ToSchema,
+)]
+#[serde(try_from = "String", into = "String")]
+#[schema(
+    as = models::file::metadata::AccessUrl,
+    value_type = String,
+    format = "uri",
+    example = "https://example.com/files/File1.txt"
+)]
+pub struct AccessUrl(url::Url);
+
+impl AccessUrl {
+    /// Gets the host component of the [`AccessUrl`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ccdi_models as models;
+    ///
+    /// use models::file::metadata::AccessUrl;
+    ///
+    /// let url = "https://example.com/files/File1.txt".parse::<AccessUrl>()?;
+    /// assert_eq!(url.host(), "example.com");
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn host(&self) -> &str {
+        // SAFETY: the presence of a host is checked when the [`AccessUrl`] is
+        // parsed, so this will always unwrap.
+        self.0.host_str().unwrap()
+    }
+}
+
+impl FromStr for AccessUrl {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let url = url::Url::parse(s).map_err(ParseError::InvalidUrl)?;
+
+        if !SCHEMES.contains(&url.scheme()) {
+            return Err(ParseError::InvalidScheme(url.scheme().to_string()));
+        }
+
+        if url.host_str().map(|host| host.is_empty()).unwrap_or(true) {
+            return Err(ParseError::MissingHost);
+        }
+
+        Ok(Self(url))
+    }
+}
+
+impl TryFrom<String> for AccessUrl {
+    type Error = ParseError;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        value.parse()
+    }
+}
+
+impl From<AccessUrl> for String {
+    fn from(value: AccessUrl) -> Self {
+        value.0.into()
+    }
+}
+
+impl std::fmt::Display for AccessUrl {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl Distribution<AccessUrl> for Standard {
+    fn sample<R: rand::Rng + ?Sized>(&self, rng: &mut R) -> AccessUrl {
+        let name = (0..16)
+            .map(|_| rng.sample(Alphanumeric) as char)
+            .collect::<String>();
+
+        // SAFETY: this is tested to always unwrap below.
+        format!("https://example.com/files/{name}").parse().unwrap()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_parses_a_valid_access_url() -> Result<(), Box<dyn std::error::Error>> {
+        let url = "https://example.com/files/File1.txt".parse::<AccessUrl>()?;
+        assert_eq!(url.host(), "example.com");
+        assert_eq!(url.to_string(), "https://example.com/files/File1.txt");
+
+        let url = "http://example.com/files/File1.txt".parse::<AccessUrl>()?;
+        assert_eq!(url.host(), "example.com");
+
+        Ok(())
+    }
+
+    #[test]
+    fn it_fails_to_parse_an_access_url_with_the_wrong_scheme() {
+        let err = "ftp://example.com/files/File1.txt"
+            .parse::<AccessUrl>()
+            .unwrap_err();
+
+        assert!(matches!(err, ParseError::InvalidScheme(ref scheme) if scheme == "ftp"));
+        assert_eq!(
+            err.to_string(),
+            "invalid scheme component in access URL: expected `http` or `https`, found `ftp`"
+        );
+    }
+
+    #[test]
+    fn it_fails_to_parse_an_access_url_without_a_host() {
+        // NOTE: the URL standard does not allow special schemes (such as
+        // `https`) without a host, so the error is reported by the URL parser.
+        let err = "https:///".parse::<AccessUrl>().unwrap_err();
+        assert!(matches!(
+            err,
+            ParseError::InvalidUrl(url::ParseError::EmptyHost)
+        ));
+
+        let err = "example.com/files/File1.txt"
+            .parse::<AccessUrl>()
+            .unwrap_err();
+        assert!(matches!(err, ParseError::InvalidUrl(_)));
+    }
+
+    #[test]
+    fn it_names_the_malformed_component_when_deserializing() {
+        let err = serde_json::from_str::<AccessUrl>("\"drs://example.com/File1\"").unwrap_err();
+        assert!(err.to_string().contains("scheme component"));
+    }
+
+    #[test]
+    fn a_random_access_url_is_generated_correctly() {
+        for _ in 0..1000 {
+            let _: AccessUrl = rand::random();
+        }
+    }
+}
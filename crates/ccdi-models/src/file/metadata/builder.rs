@@ -1,9 +1,20 @@
 //! A builder for [`Metadata`].
 
+use lazy_static::lazy_static;
+
 use crate::file::Metadata;
 use crate::metadata::common;
 use crate::metadata::field;
 use crate::metadata::fields;
+use crate::metadata::harmonized_keys;
+use crate::metadata::validate_unharmonized_keys;
+use crate::metadata::UnharmonizedKeyError;
+
+lazy_static! {
+    /// The keys of the harmonized fields of a file (which unharmonized keys
+    /// must not shadow).
+    static ref HARMONIZED_KEYS: Vec<String> = harmonized_keys(&Builder::default().build());
+}
 
 /// A builder for [`Metadata`].
 #[derive(Clone, Debug, Default)]
@@ -20,6 +31,12 @@ pub struct Builder {
     /// A free-text description of the file.
     description: Option<field::unowned::file::Description>,
 
+    /// A GA4GH Data Repository Service (DRS) URI for the file.
+    drs_uri: Option<field::unowned::file::DrsUri>,
+
+    /// An HTTP or HTTPS URL from which the file can be accessed.
+    access_url: Option<field::unowned::file::AccessUrl>,
+
     /// Common metadata elements for all metadata blocks.
     common: common::Metadata,
 
@@ -115,6 +132,58 @@ impl Builder {
         self
     }
 
+    /// Sets the `drs_uri` field of the [`Builder`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ccdi_models as models;
+    ///
+    /// use models::file::metadata::Builder;
+    /// use models::file::metadata::DrsUri;
+    /// use models::metadata::field::unowned::file;
+    ///
+    /// let field = file::DrsUri::new(
+    ///     "drs://drs.example.com/314159265358979fhqwhgads".parse::<DrsUri>()?,
+    ///     None,
+    ///     None,
+    ///     None,
+    /// );
+    /// let builder = Builder::default().drs_uri(field);
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn drs_uri(mut self, field: field::unowned::file::DrsUri) -> Self {
+        self.drs_uri = Some(field);
+        self
+    }
+
+    /// Sets the `access_url` field of the [`Builder`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ccdi_models as models;
+    ///
+    /// use models::file::metadata::AccessUrl;
+    /// use models::file::metadata::Builder;
+    /// use models::metadata::field::unowned::file;
+    ///
+    /// let field = file::AccessUrl::new(
+    ///     "https://example.com/files/File1.txt".parse::<AccessUrl>()?,
+    ///     None,
+    ///     None,
+    ///     None,
+    /// );
+    /// let builder = Builder::default().access_url(field);
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn access_url(mut self, field: field::unowned::file::AccessUrl) -> Self {
+        self.access_url = Some(field);
+        self
+    }
+
     /// Sets the common metadata for the [`Metadata`].
     ///
     /// # Examples
@@ -137,6 +206,9 @@ impl Builder {
     /// Inserts an [`UnharmonizedField`](field::UnharmonizedField) into the
     /// `unharmonized` map.
     ///
+    /// The key is validated exactly as the keys provided to
+    /// [`Builder::extend_unharmonized()`] are.
+    ///
     /// # Examples
     ///
     /// ```
@@ -159,7 +231,7 @@ impl Builder {
     ///             None,
     ///             None,
     ///         )),
-    ///     )
+    ///     )?
     ///     .insert_unharmonized(
     ///         "owned",
     ///         UnharmonizedField::Owned(owned::Field::new(
@@ -169,23 +241,75 @@ impl Builder {
     ///             None,
     ///             None,
     ///         )),
-    ///     );
+    ///     )?;
     ///
     /// # Ok::<(), Box<dyn std::error::Error>>(())
     /// ```
     pub fn insert_unharmonized<S: Into<String>>(
-        mut self,
+        self,
         key: S,
         field: field::UnharmonizedField,
-    ) -> Self {
-        let key = key.into();
+    ) -> Result<Self, UnharmonizedKeyError> {
+        self.extend_unharmonized([(key.into(), field)])
+    }
 
-        let mut unharmonized = self.unharmonized;
-        unharmonized.inner_mut().insert(key, field);
+    /// Inserts every [`UnharmonizedField`](field::UnharmonizedField) within
+    /// `fields` into the `unharmonized` map.
+    ///
+    /// Every key must follow the rules for harmonized keys (see
+    /// [`validate_harmonized_key()`](crate::metadata::validate_harmonized_key))
+    /// and must not be the name of a harmonized field of a file (e.g.,
+    /// `type`). If any key is invalid, no fields are inserted and an
+    /// [`UnharmonizedKeyError`] identifying every offending key is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use serde_json::Value;
+    ///
+    /// use ccdi_models as models;
+    ///
+    /// use models::file::metadata::Builder;
+    /// use models::metadata::field::unowned;
+    /// use models::metadata::field::UnharmonizedField;
+    ///
+    /// let field = |value: &str| {
+    ///     UnharmonizedField::Unowned(unowned::Field::new(
+    ///         Value::String(value.into()),
+    ///         None,
+    ///         None,
+    ///         None,
+    ///     ))
+    /// };
+    ///
+    /// let metadata = Builder::default()
+    ///     .extend_unharmonized([
+    ///         (String::from("enrollment_site"), field("Example Hospital")),
+    ///         (String::from("treatment_arm"), field("A")),
+    ///     ])?
+    ///     .build();
+    /// assert_eq!(metadata.unharmonized().inner().len(), 2);
+    ///
+    /// let err = Builder::default()
+    ///     .extend_unharmonized([
+    ///         (String::from("type"), field("A")),
+    ///         (String::from("Treatment Arm"), field("A")),
+    ///     ])
+    ///     .unwrap_err();
+    /// assert_eq!(err.keys().count(), 2);
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn extend_unharmonized<I>(mut self, fields: I) -> Result<Self, UnharmonizedKeyError>
+    where
+        I: IntoIterator<Item = (String, field::UnharmonizedField)>,
+    {
+        let fields = fields.into_iter().collect::<Vec<_>>();
 
-        self.unharmonized = unharmonized;
+        validate_unharmonized_keys(fields.iter().map(|(key, _)| key.as_str()), &HARMONIZED_KEYS)?;
 
-        self
+        self.unharmonized.inner_mut().extend(fields);
+        Ok(self)
     }
 
     /// Consumes `self` to build a [`Metadata`].
@@ -205,6 +329,8 @@ impl Builder {
             size: self.size,
             checksums: self.checksums,
             description: self.description,
+            drs_uri: self.drs_uri,
+            access_url: self.access_url,
             common: self.common,
             unharmonized: self.unharmonized,
         }
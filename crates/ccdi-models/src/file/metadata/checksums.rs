@@ -7,6 +7,9 @@ use serde::Deserialize;
 use serde::Serialize;
 use utoipa::ToSchema;
 
+use crate::file::checksum::Algorithm;
+use crate::file::checksum::Checksum;
+
 /// A list of checksums for a file.
 #[derive(
     Clone, Debug, Default, Deserialize, Eq, Ord, PartialEq, PartialOrd, Serialize, ToSchema,
@@ -17,7 +20,7 @@ pub struct Checksums {
     #[schema(example = "AAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAA")]
     md5: Option<cde::v1::file::checksum::MD5>,
     // NOTE: if more checksums are added here, they also need to be added to the
-    // `as_map()` function below.
+    // `as_map()` and `checksums()` functions below.
 }
 
 impl Checksums {
@@ -86,6 +89,30 @@ impl Checksums {
 
         map
     }
+
+    /// Gets each of the checksums within the [`Checksums`] as a (validated and
+    /// normalized) [`Checksum`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ccdi_cde as cde;
+    /// use ccdi_models as models;
+    ///
+    /// let checksums = models::file::metadata::Checksums::new(Some(
+    ///     cde::v1::file::checksum::MD5::try_new("AAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAA").unwrap(),
+    /// ));
+    ///
+    /// let checksums = checksums.checksums();
+    /// assert_eq!(checksums.len(), 1);
+    /// assert_eq!(
+    ///     checksums[0].to_string(),
+    ///     "md5:aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa"
+    /// );
+    /// ```
+    pub fn checksums(&self) -> Vec<Checksum> {
+        self.md5.iter().map(Checksum::from).collect()
+    }
 }
 
 impl std::fmt::Display for Checksums {
@@ -100,8 +127,12 @@ impl std::fmt::Display for Checksums {
 
 impl Distribution<Checksums> for Standard {
     fn sample<R: rand::prelude::Rng + ?Sized>(&self, rng: &mut R) -> Checksums {
+        let checksum: Checksum = rng.sample(Algorithm::MD5);
+
         Checksums {
-            md5: Some(rng.gen()),
+            // SAFETY: random md5 [`Checksum`]s are always 32 hexadecimal
+            // characters, so this will always unwrap.
+            md5: Some(cde::v1::file::checksum::MD5::try_new(checksum.into_value()).unwrap()),
         }
     }
 }
@@ -0,0 +1,259 @@
+//! A Data Repository Service (DRS) URI for a file.
+
+use std::str::FromStr;
+
+use introspect::Introspect;
+use rand::distributions::Alphanumeric;
+use rand::distributions::Distribution;
+use rand::distributions::Standard;
+use serde::Deserialize;
+use serde::Serialize;
+use utoipa::ToSchema;
+
+/// The scheme required for a [`DrsUri`].
+const SCHEME: &str = "drs";
+
+/// An error when parsing a [`DrsUri`].
+#[derive(Debug)]
+pub enum ParseError {
+    /// The value could not be parsed as a URI.
+    InvalidUri(url::ParseError),
+
+    /// The scheme component of the URI was not `drs`.
+    InvalidScheme(String),
+
+    /// The host component of the URI was missing.
+    MissingHost,
+
+    /// The object identifier component of the URI was missing.
+    MissingObjectId,
+
+    /// The object identifier component of the URI was not a single path
+    /// segment.
+    InvalidObjectId(String),
+}
+
+impl std::fmt::Display for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ParseError::InvalidUri(err) => write!(f, "invalid DRS URI: {err}"),
+            ParseError::InvalidScheme(scheme) => write!(
+                f,
+                "invalid scheme component in DRS URI: expected `{SCHEME}`, found `{scheme}`"
+            ),
+            ParseError::MissingHost => write!(f, "missing host component in DRS URI"),
+            ParseError::MissingObjectId => {
+                write!(f, "missing object identifier component in DRS URI")
+            }
+            ParseError::InvalidObjectId(id) => write!(
+                f,
+                "invalid object identifier component in DRS URI: `{id}` is not a single path \
+                 segment"
+            ),
+        }
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// A [GA4GH Data Repository Service
+/// (DRS)](https://ga4gh.github.io/data-repository-service-schemas/) URI for a
+/// file.
+///
+/// DRS URIs are required to be in the hostname-based form of
+/// `drs://<hostname>/<object_id>`, where the object identifier is a single path
+/// segment.
+#[derive(
+    Clone, Debug, Deserialize, Eq, Introspect, Ord, PartialEq, PartialOrd, Serialize, ToSchema,
+)]
+#[serde(try_from = "String", into = "String")]
+#[schema(
+    as = models::file::metadata::DrsUri,
+    value_type = String,
+    format = "uri",
+    example = "drs://drs.example.com/314159265358979fhqwhgads"
+)]
+pub struct DrsUri(url::Url);
+
+impl DrsUri {
+    /// Gets the host component of the [`DrsUri`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ccdi_models as models;
+    ///
+    /// use models::file::metadata::DrsUri;
+    ///
+    /// let uri = "drs://drs.example.com/314159265358979fhqwhgads".parse::<DrsUri>()?;
+    /// assert_eq!(uri.host(), "drs.example.com");
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn host(&self) -> &str {
+        // SAFETY: the presence of a non-empty host is checked when the
+        // [`DrsUri`] is parsed, so this will always unwrap.
+        self.0.host_str().unwrap()
+    }
+
+    /// Gets the object identifier component of the [`DrsUri`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ccdi_models as models;
+    ///
+    /// use models::file::metadata::DrsUri;
+    ///
+    /// let uri = "drs://drs.example.com/314159265358979fhqwhgads".parse::<DrsUri>()?;
+    /// assert_eq!(uri.object_id(), "314159265358979fhqwhgads");
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn object_id(&self) -> &str {
+        self.0.path().trim_start_matches('/')
+    }
+}
+
+impl FromStr for DrsUri {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let url = url::Url::parse(s).map_err(ParseError::InvalidUri)?;
+
+        if url.scheme() != SCHEME {
+            return Err(ParseError::InvalidScheme(url.scheme().to_string()));
+        }
+
+        if url.host_str().map(|host| host.is_empty()).unwrap_or(true) {
+            return Err(ParseError::MissingHost);
+        }
+
+        let id = url.path().trim_start_matches('/');
+
+        if id.is_empty() {
+            return Err(ParseError::MissingObjectId);
+        }
+
+        if id.contains('/') {
+            return Err(ParseError::InvalidObjectId(id.to_string()));
+        }
+
+        Ok(Self(url))
+    }
+}
+
+impl TryFrom<String> for DrsUri {
+    type Error = ParseError;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        value.parse()
+    }
+}
+
+impl From<DrsUri> for String {
+    fn from(value: DrsUri) -> Self {
+        value.0.into()
+    }
+}
+
+impl std::fmt::Display for DrsUri {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl Distribution<DrsUri> for Standard {
+    fn sample<R: rand::Rng + ?Sized>(&self, rng: &mut R) -> DrsUri {
+        let id = (0..24)
+            .map(|_| rng.sample(Alphanumeric) as char)
+            .collect::<String>();
+
+        // SAFETY: this is tested to always unwrap below.
+        format!("drs://drs.example.com/{id}").parse().unwrap()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_parses_a_valid_drs_uri() -> Result<(), Box<dyn std::error::Error>> {
+        let uri = "drs://drs.example.com/314159265358979fhqwhgads".parse::<DrsUri>()?;
+
+        assert_eq!(uri.host(), "drs.example.com");
+        assert_eq!(uri.object_id(), "314159265358979fhqwhgads");
+        assert_eq!(
+            uri.to_string(),
+            "drs://drs.example.com/314159265358979fhqwhgads"
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn it_fails_to_parse_a_drs_uri_with_the_wrong_scheme() {
+        let err = "https://drs.example.com/314159265358979fhqwhgads"
+            .parse::<DrsUri>()
+            .unwrap_err();
+
+        assert!(matches!(err, ParseError::InvalidScheme(ref scheme) if scheme == "https"));
+        assert_eq!(
+            err.to_string(),
+            "invalid scheme component in DRS URI: expected `drs`, found `https`"
+        );
+    }
+
+    #[test]
+    fn it_fails_to_parse_a_drs_uri_without_a_host() {
+        let err = "drs:///314159265358979fhqwhgads"
+            .parse::<DrsUri>()
+            .unwrap_err();
+        assert!(matches!(err, ParseError::MissingHost));
+
+        let err = "drs:314159265358979fhqwhgads"
+            .parse::<DrsUri>()
+            .unwrap_err();
+        assert!(matches!(err, ParseError::MissingHost));
+    }
+
+    #[test]
+    fn it_fails_to_parse_a_drs_uri_without_an_object_id() {
+        let err = "drs://drs.example.com/".parse::<DrsUri>().unwrap_err();
+        assert!(matches!(err, ParseError::MissingObjectId));
+
+        let err = "drs://drs.example.com/foo/bar"
+            .parse::<DrsUri>()
+            .unwrap_err();
+        assert!(matches!(err, ParseError::InvalidObjectId(_)));
+    }
+
+    #[test]
+    fn it_names_the_malformed_component_when_deserializing() {
+        let err = serde_json::from_str::<DrsUri>("\"https://drs.example.com/foo\"").unwrap_err();
+        assert!(err.to_string().contains("scheme component"));
+
+        let err = serde_json::from_str::<DrsUri>("\"drs:///foo\"").unwrap_err();
+        assert!(err.to_string().contains("host component"));
+    }
+
+    #[test]
+    fn it_serializes_as_a_string() -> Result<(), Box<dyn std::error::Error>> {
+        let uri = "drs://drs.example.com/314159265358979fhqwhgads".parse::<DrsUri>()?;
+
+        assert_eq!(
+            serde_json::to_string(&uri)?,
+            "\"drs://drs.example.com/314159265358979fhqwhgads\""
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn a_random_drs_uri_is_generated_correctly() {
+        for _ in 0..1000 {
+            let _: DrsUri = rand::random();
+        }
+    }
+}
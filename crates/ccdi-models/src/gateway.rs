@@ -1,16 +1,98 @@
 //! Representations of gateways.
 
+use chrono::DateTime;
+use chrono::Utc;
+use rand::Rng;
 use serde::Deserialize;
 use serde::Serialize;
 use utoipa::ToSchema;
 
 pub mod closed;
+pub mod controlled;
 mod link;
 pub mod named;
+pub mod open;
+pub mod registered;
 
 pub use closed::Closed;
+pub use controlled::Controlled;
 pub use link::Link;
 pub use named::Named;
+pub use open::Open;
+pub use registered::Registered;
+
+use crate::Url;
+
+/// An error related to constructing a [`Gateway`].
+#[derive(Debug)]
+pub enum Error {
+    /// The URL of a link is not valid for the kind of link.
+    InvalidUrl(String),
+
+    /// The accession is not a valid dbGaP study accession.
+    InvalidAccession(String),
+
+    /// The gateway expired before it was constructed.
+    Expired(DateTime<Utc>),
+}
+
+impl std::fmt::Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::InvalidUrl(reason) => write!(f, "invalid url: {reason}"),
+            Error::InvalidAccession(accession) => write!(
+                f,
+                "invalid accession: `{accession}` is not a dbGaP study accession (e.g., \
+                 `phs000000.v1.p1`)"
+            ),
+            Error::Expired(expires_at) => write!(
+                f,
+                "expired gateway: the gateway expired at {}",
+                expires_at.to_rfc3339()
+            ),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+/// A [`Result`](std::result::Result) with an [`Error`].
+pub type Result<T> = std::result::Result<T, Error>;
+
+/// Ensures that the URL of a [`Link`] is valid for the kind of link.
+///
+/// A [`Link::MailTo`] must point to a `mailto:` URL, and every other kind of
+/// [`Link`] must point to an `http` or `https` URL with a host.
+fn validate_link(link: &Link) -> Result<()> {
+    let url = link.url();
+
+    let valid = match link {
+        Link::MailTo { .. } => url.scheme() == "mailto",
+        _ => matches!(url.scheme(), "http" | "https") && url.host().is_some(),
+    };
+
+    match valid {
+        true => Ok(()),
+        false => Err(Error::InvalidUrl(match link {
+            Link::MailTo { .. } => format!(
+                "expected a `mailto:` URL, but `{}` was provided",
+                url.as_str()
+            ),
+            _ => format!(
+                "expected an `http` or `https` URL with a host, but `{}` was provided",
+                url.as_str()
+            ),
+        })),
+    }
+}
+
+/// Ensures that a gateway has not already expired.
+fn validate_expiry(expires_at: Option<&DateTime<Utc>>) -> Result<()> {
+    match expires_at {
+        Some(expires_at) if *expires_at <= Utc::now() => Err(Error::Expired(*expires_at)),
+        _ => Ok(()),
+    }
+}
 
 /// Gateways, which notify of resources that are external to the API.
 #[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize, ToSchema)]
@@ -21,11 +103,8 @@ pub enum Gateway {
     ///
     /// This gateway notifies of resources that are accessible without any
     /// authentication or authorization.
-    Open {
-        /// The link.
-        #[schema(value_type = models::gateway::Link)]
-        link: Link,
-    },
+    #[schema(value_type = models::gateway::Open)]
+    Open(Open),
 
     /// A registered access gateway.
     ///
@@ -37,11 +116,8 @@ pub enum Gateway {
     /// is used to identify an individual (such as a username or IP address),
     /// and (b) the term "registered" is used more commonly in a colloquial
     /// context.
-    Registered {
-        /// The link.
-        #[schema(value_type = models::gateway::Link)]
-        link: Link,
-    },
+    #[schema(value_type = models::gateway::Registered)]
+    Registered(Registered),
 
     /// A controlled access gateway.
     ///
@@ -57,11 +133,11 @@ pub enum Gateway {
     /// controlling the resource, OR if authorization is given to _all_
     /// authenticated individuals, then a [`Gateway::Registered`] should be used
     /// instead.
-    Controlled {
-        /// The link.
-        #[schema(value_type = models::gateway::Link)]
-        link: Link,
-    },
+    ///
+    /// A controlled access gateway names the dbGaP study through which access
+    /// is granted and includes instructions for applying for that access.
+    #[schema(value_type = models::gateway::Controlled)]
+    Controlled(Controlled),
 
     /// A closed access gateway.
     ///
@@ -83,6 +159,111 @@ pub enum Gateway {
     Closed(Closed),
 }
 
+impl Gateway {
+    /// Generates a random [`Gateway`] using the provided random number
+    /// generator.
+    ///
+    /// The gateway is either open, registered, or controlled access, always
+    /// links to `https://example.com`, and never expires.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ccdi_models as models;
+    /// use rand::rngs::StdRng;
+    /// use rand::SeedableRng as _;
+    ///
+    /// use models::Gateway;
+    ///
+    /// let a = Gateway::random_with_rng(&mut StdRng::seed_from_u64(42));
+    /// let b = Gateway::random_with_rng(&mut StdRng::seed_from_u64(42));
+    /// assert_eq!(a, b);
+    /// assert_eq!(a.expires_at(), None);
+    /// ```
+    pub fn random_with_rng<R: Rng + ?Sized>(rng: &mut R) -> Self {
+        let link = Link::Direct {
+            url: "https://example.com".parse::<Url>().unwrap(),
+        };
+
+        // SAFETY: the link, the accession, and the (lack of an) expiry are
+        // always valid, so these always unwrap.
+        match rng.gen_range(0..4) {
+            0 | 1 => Gateway::Open(Open::try_new(link, None).unwrap()),
+            2 => Gateway::Registered(Registered::try_new(link, None).unwrap()),
+            _ => Gateway::Controlled(
+                Controlled::try_new(
+                    link,
+                    format!("phs{:06}.v1.p1", rng.gen_range(0..1_000_000)),
+                    "Apply for access to the study through dbGaP.",
+                    None,
+                )
+                .unwrap(),
+            ),
+        }
+    }
+
+    /// Gets the kind of the [`Gateway`] (the value of the `kind` field when it
+    /// is serialized).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ccdi_models as models;
+    ///
+    /// use models::gateway::Link;
+    /// use models::gateway::Open;
+    /// use models::Gateway;
+    /// use models::Url;
+    ///
+    /// let link = Link::Direct {
+    ///     url: "https://example.com".parse::<Url>().unwrap(),
+    /// };
+    ///
+    /// let gateway = Gateway::Open(Open::try_new(link, None).unwrap());
+    /// assert_eq!(gateway.kind(), "Open");
+    /// ```
+    pub fn kind(&self) -> &'static str {
+        match self {
+            Gateway::Open(_) => "Open",
+            Gateway::Registered(_) => "Registered",
+            Gateway::Controlled(_) => "Controlled",
+            Gateway::Closed(_) => "Closed",
+        }
+    }
+
+    /// Gets the date and time at which the [`Gateway`] expires (if it
+    /// expires).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ccdi_models as models;
+    /// use chrono::Duration;
+    /// use chrono::Utc;
+    ///
+    /// use models::gateway::Link;
+    /// use models::gateway::Registered;
+    /// use models::Gateway;
+    /// use models::Url;
+    ///
+    /// let link = Link::Direct {
+    ///     url: "https://example.com".parse::<Url>().unwrap(),
+    /// };
+    /// let expires_at = Utc::now() + Duration::days(1);
+    ///
+    /// let gateway = Gateway::Registered(Registered::try_new(link, Some(expires_at)).unwrap());
+    /// assert_eq!(gateway.expires_at(), Some(&expires_at));
+    /// ```
+    pub fn expires_at(&self) -> Option<&DateTime<Utc>> {
+        match self {
+            Gateway::Open(gateway) => gateway.expires_at(),
+            Gateway::Registered(gateway) => gateway.expires_at(),
+            Gateway::Controlled(gateway) => gateway.expires_at(),
+            Gateway::Closed(gateway) => gateway.expires_at(),
+        }
+    }
+}
+
 /// An anonymous [`Gateway`] or a reference to a named [`Gateway`].
 #[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize, ToSchema)]
 #[serde(tag = "kind")]
@@ -113,15 +294,16 @@ impl AnonymousOrReference {
     ///
     /// use models::gateway::AnonymousOrReference;
     /// use models::gateway::Link;
+    /// use models::gateway::Open;
     /// use models::Gateway;
     /// use models::Url;
     ///
+    /// let link = Link::Direct {
+    ///     url: "https://example.com".parse::<Url>().unwrap(),
+    /// };
+    ///
     /// let gateway = AnonymousOrReference::Anonymous {
-    ///     gateway: Gateway::Open {
-    ///         link: Link::Direct {
-    ///             url: "https://example.com".parse::<Url>().unwrap(),
-    ///         },
-    ///     },
+    ///     gateway: Gateway::Open(Open::try_new(link, None).unwrap()),
     /// };
     ///
     /// assert!(matches!(gateway.as_anonymous(), Some(_)));
@@ -149,15 +331,16 @@ impl AnonymousOrReference {
     ///
     /// use models::gateway::AnonymousOrReference;
     /// use models::gateway::Link;
+    /// use models::gateway::Open;
     /// use models::Gateway;
     /// use models::Url;
     ///
+    /// let link = Link::Direct {
+    ///     url: "https://example.com".parse::<Url>().unwrap(),
+    /// };
+    ///
     /// let gateway = AnonymousOrReference::Anonymous {
-    ///     gateway: Gateway::Open {
-    ///         link: Link::Direct {
-    ///             url: "https://example.com".parse::<Url>().unwrap(),
-    ///         },
-    ///     },
+    ///     gateway: Gateway::Open(Open::try_new(link, None).unwrap()),
     /// };
     ///
     /// assert!(matches!(gateway.as_reference(), None));
@@ -175,3 +358,122 @@ impl AnonymousOrReference {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use chrono::TimeZone as _;
+
+    use super::*;
+
+    fn link() -> Link {
+        Link::Direct {
+            url: "https://example.com".parse::<Url>().unwrap(),
+        }
+    }
+
+    fn assert_round_trips(gateway: Gateway, json: &str) {
+        assert_eq!(serde_json::to_string(&gateway).unwrap(), json);
+        assert_eq!(serde_json::from_str::<Gateway>(json).unwrap(), gateway);
+    }
+
+    #[test]
+    fn open_gateways_round_trip() {
+        assert_round_trips(
+            Gateway::Open(Open::try_new(link(), None).unwrap()),
+            r#"{"kind":"Open","link":{"kind":"Direct","url":"https://example.com/"}}"#,
+        );
+
+        assert_round_trips(
+            Gateway::Open(
+                Open::try_new(
+                    link(),
+                    Some(Utc.with_ymd_and_hms(2999, 1, 1, 0, 0, 0).unwrap()),
+                )
+                .unwrap(),
+            ),
+            r#"{"kind":"Open","link":{"kind":"Direct","url":"https://example.com/"},"expires_at":"2999-01-01T00:00:00Z"}"#,
+        );
+    }
+
+    #[test]
+    fn registered_gateways_round_trip() {
+        assert_round_trips(
+            Gateway::Registered(Registered::try_new(link(), None).unwrap()),
+            r#"{"kind":"Registered","link":{"kind":"Direct","url":"https://example.com/"}}"#,
+        );
+    }
+
+    #[test]
+    fn controlled_gateways_round_trip() {
+        assert_round_trips(
+            Gateway::Controlled(
+                Controlled::try_new(link(), "phs000000.v1.p1", "Apply through dbGaP.", None)
+                    .unwrap(),
+            ),
+            r#"{"kind":"Controlled","link":{"kind":"Direct","url":"https://example.com/"},"accession":"phs000000.v1.p1","instructions":"Apply through dbGaP."}"#,
+        );
+    }
+
+    #[test]
+    fn closed_gateways_round_trip() {
+        assert_round_trips(
+            Gateway::Closed(
+                Closed::try_new(
+                    "Closed until publication.",
+                    closed::Status::Embargoed {
+                        available_at: Utc.with_ymd_and_hms(2999, 1, 1, 0, 0, 0).unwrap(),
+                    },
+                    None,
+                )
+                .unwrap(),
+            ),
+            r#"{"kind":"Closed","description":"Closed until publication.","status":"Embargoed","available_at":"2999-01-01T00:00:00Z"}"#,
+        );
+    }
+
+    #[test]
+    fn it_rejects_invalid_urls() {
+        let mailto = Link::MailTo {
+            url: "mailto:data@example.com".parse::<Url>().unwrap(),
+            instructions: String::from("Include your institution."),
+        };
+        assert!(Registered::try_new(mailto, None).is_ok());
+
+        let mailto = Link::MailTo {
+            url: "https://example.com".parse::<Url>().unwrap(),
+            instructions: String::from("Include your institution."),
+        };
+        assert_eq!(
+            Registered::try_new(mailto, None).unwrap_err().to_string(),
+            "invalid url: expected a `mailto:` URL, but `https://example.com/` was provided"
+        );
+
+        let direct = Link::Direct {
+            url: "file:///data/file.txt".parse::<Url>().unwrap(),
+        };
+        assert!(matches!(
+            Open::try_new(direct, None),
+            Err(Error::InvalidUrl(_))
+        ));
+    }
+
+    #[test]
+    fn it_rejects_expired_gateways() {
+        let expired = Some(Utc.with_ymd_and_hms(2000, 1, 1, 0, 0, 0).unwrap());
+
+        assert!(matches!(
+            Open::try_new(link(), expired),
+            Err(Error::Expired(_))
+        ));
+        assert!(matches!(
+            Controlled::try_new(link(), "phs000000", "Apply through dbGaP.", expired),
+            Err(Error::Expired(_))
+        ));
+        assert_eq!(
+            Closed::try_new("Closed.", closed::Status::IndefinitelyClosed, expired)
+                .unwrap_err()
+                .to_string(),
+            "expired gateway: the gateway expired at 2000-01-01T00:00:00+00:00"
+        );
+    }
+}
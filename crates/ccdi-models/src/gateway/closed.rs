@@ -1,5 +1,7 @@
 //! Closed gateways.
 
+use chrono::DateTime;
+use chrono::Utc;
 use serde::Deserialize;
 use serde::Serialize;
 use utoipa::ToSchema;
@@ -8,6 +10,9 @@ mod status;
 
 pub use status::Status;
 
+use crate::gateway::validate_expiry;
+use crate::gateway::Result;
+
 /// A closed gateway.
 #[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize, ToSchema)]
 #[schema(as = models::gateway::Closed)]
@@ -32,4 +37,104 @@ pub struct Closed {
     #[serde(flatten)]
     #[schema(value_type = models::gateway::closed::Status)]
     status: Status,
+
+    /// If known, the ISO 8601 formatted, UTC-based date and time after which
+    /// the gateway definition is no longer valid.
+    ///
+    /// This field is intended to indicate to the consumer that they should
+    /// request an updated gateway definition after the listed time.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    expires_at: Option<DateTime<Utc>>,
+}
+
+impl Closed {
+    /// Attempts to create a new [`Closed`] gateway.
+    ///
+    /// The gateway must not have already expired.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ccdi_models as models;
+    ///
+    /// use models::gateway::closed::Status;
+    /// use models::gateway::Closed;
+    ///
+    /// let gateway = Closed::try_new(
+    ///     "The data will be released once the study is published.",
+    ///     Status::AwaitingPublication { available_at: None },
+    ///     None,
+    /// )
+    /// .unwrap();
+    ///
+    /// assert_eq!(
+    ///     gateway.description(),
+    ///     "The data will be released once the study is published."
+    /// );
+    /// ```
+    pub fn try_new(
+        description: impl Into<String>,
+        status: Status,
+        expires_at: Option<DateTime<Utc>>,
+    ) -> Result<Self> {
+        validate_expiry(expires_at.as_ref())?;
+
+        Ok(Self {
+            description: description.into(),
+            status,
+            expires_at,
+        })
+    }
+
+    /// Gets the description of the [`Closed`] gateway by reference.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ccdi_models as models;
+    ///
+    /// use models::gateway::closed::Status;
+    /// use models::gateway::Closed;
+    ///
+    /// let gateway = Closed::try_new("Closed.", Status::IndefinitelyClosed, None).unwrap();
+    /// assert_eq!(gateway.description(), "Closed.");
+    /// ```
+    pub fn description(&self) -> &str {
+        self.description.as_str()
+    }
+
+    /// Gets the [`Status`] of the [`Closed`] gateway by reference.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ccdi_models as models;
+    ///
+    /// use models::gateway::closed::Status;
+    /// use models::gateway::Closed;
+    ///
+    /// let gateway = Closed::try_new("Closed.", Status::IndefinitelyClosed, None).unwrap();
+    /// assert_eq!(gateway.status(), &Status::IndefinitelyClosed);
+    /// ```
+    pub fn status(&self) -> &Status {
+        &self.status
+    }
+
+    /// Gets the date and time at which the [`Closed`] gateway expires (if it
+    /// expires).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ccdi_models as models;
+    ///
+    /// use models::gateway::closed::Status;
+    /// use models::gateway::Closed;
+    ///
+    /// let gateway = Closed::try_new("Closed.", Status::IndefinitelyClosed, None).unwrap();
+    /// assert_eq!(gateway.expires_at(), None);
+    /// ```
+    pub fn expires_at(&self) -> Option<&DateTime<Utc>> {
+        self.expires_at.as_ref()
+    }
 }
@@ -0,0 +1,231 @@
+//! Controlled access gateways.
+
+use ccdi_cde::v1::deposition::DbgapPhsAccession;
+use chrono::DateTime;
+use chrono::Utc;
+use lazy_static::lazy_static;
+use regex::Regex;
+use serde::Deserialize;
+use serde::Serialize;
+use utoipa::ToSchema;
+
+use crate::gateway::validate_expiry;
+use crate::gateway::validate_link;
+use crate::gateway::Error;
+use crate::gateway::Link;
+use crate::gateway::Result;
+
+lazy_static! {
+    static ref ACCESSION: Regex = Regex::new(r"^phs\d{6}(\.v\d+\.p\d+)?$").unwrap();
+}
+
+/// A controlled access gateway (see
+/// [`Gateway::Controlled`](crate::Gateway::Controlled)).
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize, ToSchema)]
+#[schema(as = models::gateway::Controlled)]
+pub struct Controlled {
+    /// The link.
+    ///
+    /// Typically, this points to the page where access to the study can be
+    /// requested.
+    #[schema(value_type = models::gateway::Link)]
+    link: Link,
+
+    /// The accession of the dbGaP study through which access is granted (e.g.,
+    /// `phs000000.v1.p1`).
+    #[schema(value_type = cde::v1::deposition::DbgapPhsAccession)]
+    accession: DbgapPhsAccession,
+
+    /// The instructions to follow when applying for access to the study.
+    ///
+    /// At a minimum, explaining who may apply, what information is requested
+    /// within the application, and what to expect after applying in terms of
+    /// communication and timeline is recommended.
+    instructions: String,
+
+    /// If known, the ISO 8601 formatted, UTC-based date and time after which
+    /// the link is no longer valid.
+    ///
+    /// This field is intended to indicate to the consumer that they should
+    /// request an updated gateway definition after the listed time.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    expires_at: Option<DateTime<Utc>>,
+}
+
+impl Controlled {
+    /// Attempts to create a new [`Controlled`] gateway.
+    ///
+    /// The URL of the link must be valid for the kind of link (see
+    /// [`Open::try_new()`](crate::gateway::Open::try_new)), the accession must
+    /// be a dbGaP study accession (matching the pattern
+    /// `^phs\d{6}(\.v\d+\.p\d+)?$`), and the gateway must not have already
+    /// expired.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ccdi_models as models;
+    ///
+    /// use models::gateway::Controlled;
+    /// use models::gateway::Link;
+    /// use models::Url;
+    ///
+    /// let link = Link::Informational {
+    ///     url: "https://dbgap.ncbi.nlm.nih.gov/aa/wga.cgi?page=login"
+    ///         .parse::<Url>()
+    ///         .unwrap(),
+    /// };
+    ///
+    /// let gateway = Controlled::try_new(
+    ///     link.clone(),
+    ///     "phs000000.v1.p1",
+    ///     "Apply for access to the study through dbGaP.",
+    ///     None,
+    /// )
+    /// .unwrap();
+    /// assert_eq!(gateway.accession().as_str(), "phs000000.v1.p1");
+    ///
+    /// let err = Controlled::try_new(link, "study-1", "Apply through dbGaP.", None).unwrap_err();
+    /// assert_eq!(
+    ///     err.to_string(),
+    ///     "invalid accession: `study-1` is not a dbGaP study accession (e.g., \
+    ///      `phs000000.v1.p1`)"
+    /// );
+    /// ```
+    pub fn try_new(
+        link: Link,
+        accession: impl Into<String>,
+        instructions: impl Into<String>,
+        expires_at: Option<DateTime<Utc>>,
+    ) -> Result<Self> {
+        let accession = accession.into();
+
+        if !ACCESSION.is_match(&accession) {
+            return Err(Error::InvalidAccession(accession));
+        }
+
+        validate_link(&link)?;
+        validate_expiry(expires_at.as_ref())?;
+
+        Ok(Self {
+            link,
+            accession: DbgapPhsAccession::from(accession),
+            instructions: instructions.into(),
+            expires_at,
+        })
+    }
+
+    /// Gets the link for the [`Controlled`] gateway by reference.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ccdi_models as models;
+    ///
+    /// use models::gateway::Controlled;
+    /// use models::gateway::Link;
+    /// use models::Url;
+    ///
+    /// let link = Link::Direct {
+    ///     url: "https://example.com".parse::<Url>().unwrap(),
+    /// };
+    ///
+    /// let gateway =
+    ///     Controlled::try_new(link.clone(), "phs000000", "Apply through dbGaP.", None).unwrap();
+    /// assert_eq!(gateway.link(), &link);
+    /// ```
+    pub fn link(&self) -> &Link {
+        &self.link
+    }
+
+    /// Gets the accession of the dbGaP study for the [`Controlled`] gateway by
+    /// reference.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ccdi_models as models;
+    ///
+    /// use models::gateway::Controlled;
+    /// use models::gateway::Link;
+    /// use models::Url;
+    ///
+    /// let link = Link::Direct {
+    ///     url: "https://example.com".parse::<Url>().unwrap(),
+    /// };
+    ///
+    /// let gateway = Controlled::try_new(link, "phs000000", "Apply through dbGaP.", None).unwrap();
+    /// assert_eq!(gateway.accession().as_str(), "phs000000");
+    /// ```
+    pub fn accession(&self) -> &DbgapPhsAccession {
+        &self.accession
+    }
+
+    /// Gets the instructions for applying for access for the [`Controlled`]
+    /// gateway by reference.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ccdi_models as models;
+    ///
+    /// use models::gateway::Controlled;
+    /// use models::gateway::Link;
+    /// use models::Url;
+    ///
+    /// let link = Link::Direct {
+    ///     url: "https://example.com".parse::<Url>().unwrap(),
+    /// };
+    ///
+    /// let gateway = Controlled::try_new(link, "phs000000", "Apply through dbGaP.", None).unwrap();
+    /// assert_eq!(gateway.instructions(), "Apply through dbGaP.");
+    /// ```
+    pub fn instructions(&self) -> &str {
+        self.instructions.as_str()
+    }
+
+    /// Gets the date and time at which the [`Controlled`] gateway expires (if
+    /// it expires).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ccdi_models as models;
+    ///
+    /// use models::gateway::Controlled;
+    /// use models::gateway::Link;
+    /// use models::Url;
+    ///
+    /// let link = Link::Direct {
+    ///     url: "https://example.com".parse::<Url>().unwrap(),
+    /// };
+    ///
+    /// let gateway = Controlled::try_new(link, "phs000000", "Apply through dbGaP.", None).unwrap();
+    /// assert_eq!(gateway.expires_at(), None);
+    /// ```
+    pub fn expires_at(&self) -> Option<&DateTime<Utc>> {
+        self.expires_at.as_ref()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_only_allows_dbgap_study_accessions() {
+        for accession in ["phs000000", "phs000000.v1.p1", "phs001234.v12.p3"] {
+            assert!(ACCESSION.is_match(accession), "{accession}");
+        }
+
+        for accession in [
+            "",
+            "phs0000",
+            "PHS000000",
+            "phs000000.v1",
+            "phs000000.p1.v1",
+        ] {
+            assert!(!ACCESSION.is_match(accession), "{accession}");
+        }
+    }
+}
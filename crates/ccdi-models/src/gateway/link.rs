@@ -151,3 +151,31 @@ pub enum Link {
         instructions: String,
     },
 }
+
+impl Link {
+    /// Gets the URL of the [`Link`] by reference.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ccdi_models as models;
+    ///
+    /// use models::gateway::Link;
+    /// use models::Url;
+    ///
+    /// let link = Link::MailTo {
+    ///     url: "mailto:data@example.com".parse::<Url>().unwrap(),
+    ///     instructions: String::from("Include your institution."),
+    /// };
+    ///
+    /// assert_eq!(link.url().as_str(), "mailto:data@example.com");
+    /// ```
+    pub fn url(&self) -> &Url {
+        match self {
+            Link::Direct { url }
+            | Link::Approximate { url, .. }
+            | Link::Informational { url }
+            | Link::MailTo { url, .. } => url,
+        }
+    }
+}
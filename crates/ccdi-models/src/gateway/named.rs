@@ -37,18 +37,23 @@ impl Named {
     ///
     /// use models::gateway::Link;
     /// use models::gateway::Named;
+    /// use models::gateway::Open;
     /// use models::Gateway;
     /// use models::Url;
     ///
-    /// let gateway = Gateway::Open {
-    ///     link: Link::Direct {
-    ///         url: "https://example.com".parse::<Url>().unwrap(),
-    ///     },
-    /// };
+    /// let gateway = Gateway::Open(
+    ///     Open::try_new(
+    ///         Link::Direct {
+    ///             url: "https://example.com".parse::<Url>().unwrap(),
+    ///         },
+    ///         None,
+    ///     )
+    ///     .unwrap(),
+    /// );
     ///
     /// let named = Named::new(String::from("name"), gateway);
     /// assert_eq!(named.name(), "name");
-    /// assert!(matches!(named.gateway(), Gateway::Open { .. }));
+    /// assert!(matches!(named.gateway(), Gateway::Open(_)));
     /// ```
     pub fn new(name: String, gateway: Gateway) -> Self {
         Self { name, gateway }
@@ -63,14 +68,19 @@ impl Named {
     ///
     /// use models::gateway::Link;
     /// use models::gateway::Named;
+    /// use models::gateway::Open;
     /// use models::Gateway;
     /// use models::Url;
     ///
-    /// let gateway = Gateway::Open {
-    ///     link: Link::Direct {
-    ///         url: "https://example.com".parse::<Url>().unwrap(),
-    ///     },
-    /// };
+    /// let gateway = Gateway::Open(
+    ///     Open::try_new(
+    ///         Link::Direct {
+    ///             url: "https://example.com".parse::<Url>().unwrap(),
+    ///         },
+    ///         None,
+    ///     )
+    ///     .unwrap(),
+    /// );
     ///
     /// let named = Named::new(String::from("name"), gateway);
     /// assert_eq!(named.name(), "name");
@@ -88,17 +98,22 @@ impl Named {
     ///
     /// use models::gateway::Link;
     /// use models::gateway::Named;
+    /// use models::gateway::Open;
     /// use models::Gateway;
     /// use models::Url;
     ///
-    /// let gateway = Gateway::Open {
-    ///     link: Link::Direct {
-    ///         url: "https://example.com".parse::<Url>().unwrap(),
-    ///     },
-    /// };
+    /// let gateway = Gateway::Open(
+    ///     Open::try_new(
+    ///         Link::Direct {
+    ///             url: "https://example.com".parse::<Url>().unwrap(),
+    ///         },
+    ///         None,
+    ///     )
+    ///     .unwrap(),
+    /// );
     ///
     /// let named = Named::new(String::from("name"), gateway);
-    /// assert!(matches!(named.gateway(), Gateway::Open { .. }));
+    /// assert!(matches!(named.gateway(), Gateway::Open(_)));
     /// ```
     pub fn gateway(&self) -> &Gateway {
         &self.gateway
@@ -0,0 +1,122 @@
+//! Open access gateways.
+
+use chrono::DateTime;
+use chrono::Utc;
+use serde::Deserialize;
+use serde::Serialize;
+use utoipa::ToSchema;
+
+use crate::gateway::validate_expiry;
+use crate::gateway::validate_link;
+use crate::gateway::Link;
+use crate::gateway::Result;
+
+/// An open access gateway (see [`Gateway::Open`](crate::Gateway::Open)).
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize, ToSchema)]
+#[schema(as = models::gateway::Open)]
+pub struct Open {
+    /// The link.
+    #[schema(value_type = models::gateway::Link)]
+    link: Link,
+
+    /// If known, the ISO 8601 formatted, UTC-based date and time after which
+    /// the link is no longer valid.
+    ///
+    /// This field is intended to indicate to the consumer that they should
+    /// request an updated gateway definition after the listed time.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    expires_at: Option<DateTime<Utc>>,
+}
+
+impl Open {
+    /// Attempts to create a new [`Open`] gateway.
+    ///
+    /// The URL of the link must be valid for the kind of link: a
+    /// [`Link::MailTo`] must point to a `mailto:` URL, and every other kind of
+    /// link must point to an `http` or `https` URL with a host. The gateway
+    /// must also not have already expired.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ccdi_models as models;
+    /// use chrono::Duration;
+    /// use chrono::Utc;
+    ///
+    /// use models::gateway::Link;
+    /// use models::gateway::Open;
+    /// use models::Url;
+    ///
+    /// let link = Link::Direct {
+    ///     url: "https://example.com".parse::<Url>().unwrap(),
+    /// };
+    ///
+    /// let gateway = Open::try_new(link.clone(), None).unwrap();
+    /// assert_eq!(gateway.link(), &link);
+    ///
+    /// let err = Open::try_new(link, Some(Utc::now() - Duration::days(1))).unwrap_err();
+    /// assert!(err.to_string().starts_with("expired gateway"));
+    ///
+    /// let link = Link::Direct {
+    ///     url: "ftp://example.com".parse::<Url>().unwrap(),
+    /// };
+    ///
+    /// let err = Open::try_new(link, None).unwrap_err();
+    /// assert_eq!(
+    ///     err.to_string(),
+    ///     "invalid url: expected an `http` or `https` URL with a host, but \
+    ///      `ftp://example.com/` was provided"
+    /// );
+    /// ```
+    pub fn try_new(link: Link, expires_at: Option<DateTime<Utc>>) -> Result<Self> {
+        validate_link(&link)?;
+        validate_expiry(expires_at.as_ref())?;
+
+        Ok(Self { link, expires_at })
+    }
+
+    /// Gets the link for the [`Open`] gateway by reference.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ccdi_models as models;
+    ///
+    /// use models::gateway::Link;
+    /// use models::gateway::Open;
+    /// use models::Url;
+    ///
+    /// let link = Link::Direct {
+    ///     url: "https://example.com".parse::<Url>().unwrap(),
+    /// };
+    ///
+    /// let gateway = Open::try_new(link.clone(), None).unwrap();
+    /// assert_eq!(gateway.link(), &link);
+    /// ```
+    pub fn link(&self) -> &Link {
+        &self.link
+    }
+
+    /// Gets the date and time at which the [`Open`] gateway expires (if it
+    /// expires).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ccdi_models as models;
+    ///
+    /// use models::gateway::Link;
+    /// use models::gateway::Open;
+    /// use models::Url;
+    ///
+    /// let link = Link::Direct {
+    ///     url: "https://example.com".parse::<Url>().unwrap(),
+    /// };
+    ///
+    /// let gateway = Open::try_new(link, None).unwrap();
+    /// assert_eq!(gateway.expires_at(), None);
+    /// ```
+    pub fn expires_at(&self) -> Option<&DateTime<Utc>> {
+        self.expires_at.as_ref()
+    }
+}
@@ -21,6 +21,7 @@ pub mod organization;
 pub mod sample;
 pub mod subject;
 mod url;
+pub mod validation;
 
 pub use file::File;
 pub use gateway::Gateway;
@@ -30,13 +31,30 @@ pub use sample::Sample;
 pub use subject::Subject;
 pub use url::Url;
 
-/// The regex that all harmonized keys must conform to.
+/// The version of this crate.
 ///
-/// We accept non-empty string comprised of any lowercase character (`[a-z]`)
-/// and an underscore.
+/// # Examples
+///
+/// ```
+/// use ccdi_models as models;
+///
+/// assert!(!models::VERSION.is_empty());
+/// ```
+pub const VERSION: &str = env!("CARGO_PKG_VERSION");
+
+/// A regex that harmonized keys were historically checked against.
+///
+/// This regex accepts any non-empty string comprised of lowercase letters
+/// (`[a-z]`), digits (`[0-9]`), underscores, and periods, so it does not
+/// enforce all of the rules for harmonized keys. Use
+/// [`metadata::validate_harmonized_key()`] (or
+/// [`metadata::validate_harmonized_path()`] for period-delimited paths)
+/// instead.
+#[deprecated(note = "use `metadata::validate_harmonized_key()` instead")]
 pub const HARMONIZED_KEY_REGEX: &str = r"^[a-z0-9_.]+$";
 
 #[cfg(test)]
+#[allow(deprecated)]
 mod tests {
     use regex::Regex;
 
@@ -1,5 +1,47 @@
 //! Representations of metadata.
+//!
+//! ## Serialization order
+//!
+//! Aggregators compare serialized entities across time, so the serialized
+//! form of a metadata block is kept stable:
+//!
+//! * Harmonized fields are serialized in the order in which they are declared
+//!   within each entity's `Metadata` struct. The fields of the
+//!   [`common::Metadata`] are flattened into the block in their own declaration
+//!   order, at the position of the `common` field. The declaration order of
+//!   these structs is therefore part of the API: new fields should be added
+//!   after the existing harmonized fields rather than reordering them.
+//! * Unharmonized fields are always serialized with their keys in
+//!   lexicographical order (see [`fields::Unharmonized`]).
 
 pub mod common;
 pub mod field;
 pub mod fields;
+pub mod key;
+
+pub use key::harmonized_keys;
+pub use key::validate_harmonized_key;
+pub use key::validate_harmonized_path;
+pub use key::validate_unharmonized_keys;
+pub use key::KeyError;
+pub use key::UnharmonizedKeyError;
+pub use key::UnharmonizedKeyProblem;
+
+/// The default fraction of randomly generated entities that have no metadata.
+pub const DEFAULT_METADATA_LESS_FRACTION: f64 = 0.1;
+
+/// The strategy used to generate the metadata of random entities.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum Generation {
+    /// Related fields are generated jointly so that the metadata is realistic
+    /// (for example, a diagnosis always belongs to the reported diagnosis
+    /// category).
+    #[default]
+    Coherent,
+
+    /// Every field is generated independently of every other field.
+    ///
+    /// The resulting metadata is frequently contradictory, which is useful for
+    /// fuzzing consumers of the API.
+    Independent,
+}
@@ -1,5 +1,6 @@
 //! Common functionality regarding metadata fields.
 
+pub mod age;
 pub mod deposition;
 pub mod metadata;
 
@@ -0,0 +1,188 @@
+//! Ages reported with an explicit unit.
+//!
+//! Ages are always stored as an approximate number of days (the canonical
+//! unit). On the wire, an age is either the legacy bare number (interpreted as
+//! a number of days) or an object with a `value` and a `unit` (e.g.,
+//! `{ "value": 5.2, "unit": "years" }`), which is converted to days when it is
+//! deserialized.
+//!
+//! Ages are serialized as the legacy bare number of days unless the
+//! `age-units` feature is enabled, in which case they are serialized as the
+//! canonical object form (e.g., `{ "value": 1899.3, "unit": "days" }`). The
+//! object form will become the default in the next major version of the API.
+
+use ordered_float::OrderedFloat;
+use serde::Deserialize;
+use serde::Deserializer;
+use serde::Serialize;
+use serde::Serializer;
+use utoipa::ToSchema;
+
+/// The approximate number of days within a year.
+pub const DAYS_PER_YEAR: f32 = 365.25;
+
+/// The approximate number of days within a month.
+pub const DAYS_PER_MONTH: f32 = DAYS_PER_YEAR / 12.0;
+
+/// A unit in which an age may be reported.
+#[derive(Clone, Copy, Debug, Deserialize, Eq, PartialEq, Serialize, ToSchema)]
+#[serde(rename_all = "lowercase")]
+#[schema(as = models::metadata::common::age::Unit)]
+pub enum Unit {
+    /// Days (the canonical unit).
+    Days,
+
+    /// Months (of 30.4375 days).
+    Months,
+
+    /// Years (of 365.25 days).
+    Years,
+}
+
+impl Unit {
+    /// Gets the approximate number of days within one of the [`Unit`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ccdi_models as models;
+    ///
+    /// use models::metadata::common::age::Unit;
+    ///
+    /// assert_eq!(Unit::Days.days(), 1.0);
+    /// assert_eq!(Unit::Months.days(), 30.4375);
+    /// assert_eq!(Unit::Years.days(), 365.25);
+    /// ```
+    pub fn days(&self) -> f32 {
+        match self {
+            Unit::Days => 1.0,
+            Unit::Months => DAYS_PER_MONTH,
+            Unit::Years => DAYS_PER_YEAR,
+        }
+    }
+
+    /// Converts a value in the [`Unit`] to an approximate number of days.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ccdi_models as models;
+    ///
+    /// use models::metadata::common::age::Unit;
+    ///
+    /// assert_eq!(Unit::Years.to_days(2.0), 730.5);
+    /// assert_eq!(Unit::Days.to_days(2.0), 2.0);
+    /// ```
+    pub fn to_days(&self, value: f32) -> f32 {
+        value * self.days()
+    }
+}
+
+impl std::fmt::Display for Unit {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Unit::Days => write!(f, "days"),
+            Unit::Months => write!(f, "months"),
+            Unit::Years => write!(f, "years"),
+        }
+    }
+}
+
+/// An age as it is accepted on the wire.
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum Repr {
+    /// The legacy bare number of days.
+    Days(f32),
+
+    /// A value along with its unit.
+    WithUnit {
+        /// The value.
+        value: f32,
+
+        /// The unit of the value.
+        unit: Unit,
+    },
+}
+
+/// The canonical object form of an age.
+#[cfg(feature = "age-units")]
+#[derive(Serialize)]
+struct Canonical {
+    /// The number of days.
+    value: f32,
+
+    /// The unit (always [`Unit::Days`]).
+    unit: Unit,
+}
+
+/// Deserializes an age (in either form) as a number of days.
+pub(crate) fn deserialize<'de, D>(deserializer: D) -> Result<OrderedFloat<f32>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    match Repr::deserialize(deserializer)? {
+        Repr::Days(days) => Ok(OrderedFloat(days)),
+        Repr::WithUnit { value, unit } => Ok(OrderedFloat(unit.to_days(value))),
+    }
+}
+
+/// Serializes a number of days as an age.
+pub(crate) fn serialize<S>(days: &OrderedFloat<f32>, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    #[cfg(feature = "age-units")]
+    {
+        Canonical {
+            value: days.0,
+            unit: Unit::Days,
+        }
+        .serialize(serializer)
+    }
+
+    #[cfg(not(feature = "age-units"))]
+    {
+        days.0.serialize(serializer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug, Deserialize, PartialEq, Serialize)]
+    struct Age(#[serde(with = "super")] OrderedFloat<f32>);
+
+    #[test]
+    fn it_deserializes_the_legacy_form_as_days() {
+        let age = serde_json::from_str::<Age>("730.5").unwrap();
+        assert_eq!(age, Age(OrderedFloat(730.5)));
+    }
+
+    #[test]
+    fn it_deserializes_the_object_form_as_days() {
+        for (json, days) in [
+            (r#"{"value":2,"unit":"years"}"#, 730.5),
+            (r#"{"value":3,"unit":"months"}"#, 91.3125),
+            (r#"{"value":7,"unit":"days"}"#, 7.0),
+        ] {
+            let age = serde_json::from_str::<Age>(json).unwrap();
+            assert_eq!(age, Age(OrderedFloat(days)), "{json}");
+        }
+
+        assert!(serde_json::from_str::<Age>(r#"{"value":2,"unit":"weeks"}"#).is_err());
+        assert!(serde_json::from_str::<Age>(r#"{"value":2}"#).is_err());
+    }
+
+    #[test]
+    fn it_serializes_the_configured_form() {
+        let json = serde_json::to_string(&Age(OrderedFloat(730.5))).unwrap();
+
+        #[cfg(feature = "age-units")]
+        assert_eq!(json, r#"{"value":730.5,"unit":"days"}"#);
+
+        #[cfg(not(feature = "age-units"))]
+        assert_eq!(json, "730.5");
+    }
+}
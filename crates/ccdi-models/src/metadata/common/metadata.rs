@@ -24,7 +24,8 @@ pub struct Metadata {
     #[schema(value_type = Vec<models::metadata::common::deposition::Accession>, nullable = true)]
     depositions: Option<NonEmpty<Accession>>,
     // NOTE: ensure that any new items added to this struct are also checked in
-    // the `is_empty()` method.
+    // the `is_empty()` method. The declaration order of these fields is
+    // contractual (see the module-level documentation of `crate::metadata`).
 }
 
 impl Metadata {
@@ -53,4 +54,27 @@ impl Metadata {
     pub fn depositions(&self) -> Option<&NonEmpty<Accession>> {
         self.depositions.as_ref()
     }
+
+    /// Returns whether every field within the [`Metadata`] is `None`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ccdi_cde as cde;
+    /// use ccdi_models as models;
+    ///
+    /// use cde::v1::deposition::DbgapPhsAccession;
+    /// use models::metadata::common::deposition::Accession;
+    /// use models::metadata::common::metadata::Builder;
+    ///
+    /// assert!(Builder::default().build().is_empty());
+    ///
+    /// let accession = Accession::dbGaP(DbgapPhsAccession::from(String::from("phs000000.v1.p1")));
+    /// let metadata = Builder::default().push_deposition(accession).build();
+    ///
+    /// assert!(!metadata.is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.depositions.is_none()
+    }
 }
@@ -9,6 +9,7 @@ pub mod unharmonized;
 
 pub use harmonized::Harmonized;
 pub use unharmonized::Unharmonized;
+pub use unharmonized::UnharmonizedRegistry;
 
 /// A description for a metadata field.
 #[derive(Debug, Deserialize, Serialize, ToSchema)]
@@ -7,16 +7,19 @@ use utoipa::ToSchema;
 use ccdi_cde as cde;
 
 use cde::parse::cde::Member;
+use cde::translations::Translations;
 
 use crate::Url;
 
 pub mod file;
 pub mod namespace;
 pub mod organization;
+mod permissible_value;
 pub mod sample;
 mod standard;
 pub mod subject;
 
+pub use permissible_value::PermissibleValue;
 pub use standard::Standard;
 
 /// A kind of harmonized value.
@@ -31,7 +34,10 @@ pub enum Kind {
 
 /// A harmonized metadata field description.
 ///
-/// Harmonized keys _must_ fit the regex pattern `^[a-z_]+$`.
+/// Harmonized keys _must_ start with a lowercase letter, contain only
+/// lowercase letters, digits, and underscores, and neither end with an
+/// underscore nor contain consecutive underscores (see
+/// [`validate_harmonized_key()`](crate::metadata::validate_harmonized_key)).
 #[derive(Debug, Deserialize, Serialize, ToSchema)]
 #[schema(as = models::metadata::field::description::Harmonized)]
 pub struct Harmonized {
@@ -71,6 +77,17 @@ pub struct Harmonized {
     /// the `enum`.
     #[serde(skip_serializing)]
     members: Option<Vec<(Option<String>, Member)>>,
+
+    /// The permissible values for the field alongside a display label for each
+    /// value.
+    ///
+    /// This is only included when labels are requested (and only for fields
+    /// that are backed by an `enum`).
+    #[schema(
+        value_type = Option<Vec<models::metadata::field::description::harmonized::PermissibleValue>>
+    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    permissible_values: Option<Vec<PermissibleValue>>,
 }
 
 impl Harmonized {
@@ -131,6 +148,7 @@ impl Harmonized {
             wiki_url,
             standard,
             members,
+            permissible_values: None,
         }
     }
 
@@ -438,4 +456,182 @@ impl Harmonized {
     pub fn members(&self) -> Option<&Vec<(Option<String>, Member)>> {
         self.members.as_ref()
     }
+
+    /// Gets the labeled permissible values for the [`Harmonized`] by reference.
+    ///
+    /// These are only present once [`Harmonized::localize()`] has been called.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ccdi_models as models;
+    ///
+    /// use models::metadata::field::description::harmonized::Kind;
+    /// use models::metadata::field::description::Harmonized;
+    /// use models::Url;
+    ///
+    /// let description = Harmonized::new(
+    ///     Kind::Struct,
+    ///     String::from("entity"),
+    ///     String::from("A description for the entity."),
+    ///     "https://github.com/CBIIT/ccdi-federation-api/wiki"
+    ///         .parse::<Url>()
+    ///         .unwrap(),
+    ///     None,
+    ///     None,
+    /// );
+    ///
+    /// assert!(description.permissible_values().is_none());
+    /// ```
+    pub fn permissible_values(&self) -> Option<&Vec<PermissibleValue>> {
+        self.permissible_values.as_ref()
+    }
+
+    /// Populates the labeled permissible values for the [`Harmonized`].
+    ///
+    /// For each permissible value, the label for the first of the provided
+    /// `locales` (in order of preference) that has a translation is used. If
+    /// none of the locales have a translation, the English long name of the
+    /// permissible value is used. Fields that are not backed by an `enum` are
+    /// left untouched.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ccdi_cde as cde;
+    /// use ccdi_models as models;
+    ///
+    /// use cde::translations::Translations;
+    /// use models::metadata::field::description::r#trait::Description as _;
+    /// use models::metadata::field::Description;
+    ///
+    /// let description = match cde::v1::subject::Sex::description() {
+    ///     Description::Harmonized(mut description) => {
+    ///         description.localize(&["es"], Translations::embedded());
+    ///         description
+    ///     }
+    ///     _ => unreachable!(),
+    /// };
+    ///
+    /// let value = description
+    ///     .permissible_values()
+    ///     .unwrap()
+    ///     .iter()
+    ///     .find(|value| value.value() == "F")
+    ///     .unwrap();
+    ///
+    /// assert_eq!(value.label(), "Femenino");
+    /// ```
+    pub fn localize(&mut self, locales: &[&str], translations: &Translations) {
+        if self.kind != Kind::Enum {
+            return;
+        }
+
+        let standard = self.standard.as_ref().map(|standard| standard.name());
+
+        let values = self
+            .members
+            .iter()
+            .flatten()
+            .filter_map(|(_, member)| member.get_variant())
+            .map(|variant| {
+                let value = variant.permissible_value();
+
+                let label = standard
+                    .and_then(|standard| {
+                        locales
+                            .iter()
+                            .find_map(|locale| translations.label(standard, value, locale))
+                    })
+                    .or_else(|| {
+                        variant
+                            .metadata()
+                            .and_then(|metadata| metadata.get("VM Long Name"))
+                            .map(|name| name.as_str())
+                    })
+                    .unwrap_or(value);
+
+                PermissibleValue::new(value.to_string(), label.to_string())
+            })
+            .collect::<Vec<_>>();
+
+        self.permissible_values = Some(values);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ccdi_cde::v1::subject::Sex;
+
+    use crate::metadata::field::description::r#trait::Description as _;
+    use crate::metadata::field::Description;
+
+    use super::*;
+
+    fn sex() -> Harmonized {
+        match Sex::description() {
+            Description::Harmonized(description) => description,
+            _ => unreachable!(),
+        }
+    }
+
+    fn label<'a>(description: &'a Harmonized, value: &str) -> &'a str {
+        description
+            .permissible_values()
+            .unwrap()
+            .iter()
+            .find(|permissible_value| permissible_value.value() == value)
+            .unwrap()
+            .label()
+    }
+
+    #[test]
+    fn it_localizes_a_translated_value() {
+        let mut description = sex();
+        description.localize(&["fr", "es-MX"], Translations::embedded());
+
+        assert_eq!(label(&description, "F"), "Femenino");
+        assert_eq!(label(&description, "M"), "Masculino");
+    }
+
+    #[test]
+    fn it_falls_back_to_the_english_long_name() {
+        let mut description = sex();
+        description.localize(&["fr"], Translations::embedded());
+        assert_eq!(label(&description, "F"), "Female");
+
+        let mut description = sex();
+        description.localize(&[], Translations::embedded());
+        assert_eq!(label(&description, "F"), "Female");
+    }
+
+    #[test]
+    fn it_never_changes_the_wire_values() {
+        let mut description = sex();
+        description.localize(&["es"], Translations::embedded());
+
+        let values = description
+            .permissible_values()
+            .unwrap()
+            .iter()
+            .map(|value| value.value())
+            .collect::<Vec<_>>();
+
+        assert_eq!(values, vec!["U", "F", "M", "UNDIFFERENTIATED"]);
+    }
+
+    #[test]
+    fn it_only_serializes_permissible_values_when_localized(
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        let description = sex();
+        assert!(!serde_json::to_string(&description)?.contains("permissible_values"));
+
+        let mut description = sex();
+        description.localize(&["es"], Translations::embedded());
+        assert!(
+            serde_json::to_string(&description)?.contains(r#"{"value":"F","label":"Femenino"}"#)
+        );
+
+        Ok(())
+    }
 }
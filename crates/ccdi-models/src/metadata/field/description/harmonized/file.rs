@@ -18,6 +18,8 @@ pub fn get_field_descriptions() -> Vec<description::Description> {
         cde::v1::file::Size::description(),
         cde::v1::file::checksum::MD5::description(),
         cde::v1::file::Description::description(),
+        crate::file::metadata::DrsUri::description(),
+        crate::file::metadata::AccessUrl::description(),
     ]
 }
 
@@ -113,12 +115,52 @@ impl description::r#trait::Description for cde::v1::file::Description {
     }
 }
 
+impl description::r#trait::Description for crate::file::metadata::DrsUri {
+    fn description() -> description::Description {
+        description::Description::Harmonized(Harmonized::new(
+            Kind::Struct,
+            String::from("drs_uri"),
+            String::from(
+                "A GA4GH Data Repository Service (DRS) URI for the file. The URI must \
+                 be in the hostname-based form of `drs://<hostname>/<object_id>`, where \
+                 the object identifier is a single path segment.",
+            ),
+            "https://github.com/CBIIT/ccdi-federation-api/wiki/File-Metadata-Fields#drs_uri"
+                .parse::<Url>()
+                .unwrap(),
+            Some(Standard::new(
+                String::from("GA4GH Data Repository Service (DRS)"),
+                "https://ga4gh.github.io/data-repository-service-schemas/"
+                    .parse::<Url>()
+                    .unwrap(),
+            )),
+            None,
+        ))
+    }
+}
+
+impl description::r#trait::Description for crate::file::metadata::AccessUrl {
+    fn description() -> description::Description {
+        description::Description::Harmonized(Harmonized::new(
+            Kind::Struct,
+            String::from("access_url"),
+            String::from(
+                "An HTTP or HTTPS URL from which the file can be accessed. Only the \
+                 `http` and `https` schemes are allowed.",
+            ),
+            "https://github.com/CBIIT/ccdi-federation-api/wiki/File-Metadata-Fields#access_url"
+                .parse::<Url>()
+                .unwrap(),
+            None,
+            None,
+        ))
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use regex::Regex;
-
     use crate::metadata::field::description::Description;
-    use crate::HARMONIZED_KEY_REGEX;
+    use crate::metadata::validate_harmonized_path;
 
     use super::*;
 
@@ -131,9 +173,7 @@ mod tests {
     }
 
     #[test]
-    fn all_of_the_harmonized_keys_conform_to_the_harmonized_key_regex() {
-        let regex = Regex::new(HARMONIZED_KEY_REGEX).unwrap();
-
+    fn all_of_the_harmonized_keys_are_valid() {
         for field in get_field_descriptions() {
             let path = match field {
                 Description::Harmonized(description) => description.path,
@@ -142,7 +182,9 @@ mod tests {
                 Description::Unharmonized(_) => continue,
             };
 
-            assert!(regex.is_match(path.as_str()))
+            if let Err(err) = validate_harmonized_path(&path) {
+                panic!("{err}");
+            }
         }
     }
 }
@@ -10,7 +10,7 @@ use crate::metadata::field::description::r#trait::Description as _;
 use crate::metadata::field::description::Harmonized;
 use crate::Url;
 
-/// Gets the harmonized fields for samples.
+/// Gets the harmonized fields for organizations.
 pub fn get_field_descriptions() -> Vec<description::Description> {
     vec![cde::v4::organization::Institution::description()]
 }
@@ -35,10 +35,8 @@ impl description::r#trait::Description for cde::v4::organization::Institution {
 
 #[cfg(test)]
 mod tests {
-    use regex::Regex;
-
     use crate::metadata::field::description::Description;
-    use crate::HARMONIZED_KEY_REGEX;
+    use crate::metadata::validate_harmonized_path;
 
     use super::*;
 
@@ -51,9 +49,7 @@ mod tests {
     }
 
     #[test]
-    fn all_of_the_harmonized_keys_conform_to_the_harmonized_key_regex() {
-        let regex = Regex::new(HARMONIZED_KEY_REGEX).unwrap();
-
+    fn all_of_the_harmonized_keys_are_valid() {
         for field in get_field_descriptions() {
             let path = match field {
                 Description::Harmonized(description) => description.path,
@@ -62,7 +58,9 @@ mod tests {
                 Description::Unharmonized(_) => continue,
             };
 
-            assert!(regex.is_match(path.as_str()))
+            if let Err(err) = validate_harmonized_path(&path) {
+                panic!("{err}");
+            }
         }
     }
 }
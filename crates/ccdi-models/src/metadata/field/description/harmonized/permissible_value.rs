@@ -0,0 +1,70 @@
+use serde::Deserialize;
+use serde::Serialize;
+use utoipa::ToSchema;
+
+/// A permissible value for a harmonized field alongside a display label.
+#[derive(Debug, Deserialize, Eq, PartialEq, Serialize, ToSchema)]
+#[schema(as = models::metadata::field::description::harmonized::PermissibleValue)]
+pub struct PermissibleValue {
+    /// The permissible value (exactly as it is sent over the wire).
+    value: String,
+
+    /// A display label for the permissible value.
+    ///
+    /// This label is localized when a translation is available for the
+    /// requested locale. Otherwise, it is the English long name of the
+    /// permissible value.
+    label: String,
+}
+
+impl PermissibleValue {
+    /// Creates a new [`PermissibleValue`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ccdi_models as models;
+    ///
+    /// use models::metadata::field::description::harmonized::PermissibleValue;
+    ///
+    /// let value = PermissibleValue::new(String::from("F"), String::from("Femenino"));
+    ///
+    /// assert_eq!(value.value(), "F");
+    /// assert_eq!(value.label(), "Femenino");
+    /// ```
+    pub fn new(value: String, label: String) -> Self {
+        Self { value, label }
+    }
+
+    /// Gets the value of the [`PermissibleValue`] by reference.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ccdi_models as models;
+    ///
+    /// use models::metadata::field::description::harmonized::PermissibleValue;
+    ///
+    /// let value = PermissibleValue::new(String::from("F"), String::from("Female"));
+    /// assert_eq!(value.value(), "F");
+    /// ```
+    pub fn value(&self) -> &str {
+        self.value.as_str()
+    }
+
+    /// Gets the label of the [`PermissibleValue`] by reference.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ccdi_models as models;
+    ///
+    /// use models::metadata::field::description::harmonized::PermissibleValue;
+    ///
+    /// let value = PermissibleValue::new(String::from("F"), String::from("Female"));
+    /// assert_eq!(value.label(), "Female");
+    /// ```
+    pub fn label(&self) -> &str {
+        self.label.as_str()
+    }
+}
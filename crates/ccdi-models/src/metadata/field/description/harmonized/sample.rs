@@ -31,6 +31,7 @@ pub fn get_field_descriptions() -> Vec<description::Description> {
         cde::v1::sample::TumorClassification::description(),
         cde::v1::sample::TumorTissueMorphology::description(),
         crate::sample::metadata::AgeAtCollection::description(),
+        crate::sample::metadata::TumorTissueSite::description(),
     ]
 }
 
@@ -333,12 +334,28 @@ impl description::r#trait::Description for crate::sample::metadata::AgeAtCollect
     }
 }
 
+impl description::r#trait::Description for crate::sample::metadata::TumorTissueSite {
+    fn description() -> description::Description {
+        let description = match Self::introspected_entity() {
+            Entity::Enum(entity) => entity.documentation().unwrap().to_string(),
+            Entity::Struct(entity) => entity.documentation().unwrap().to_string(),
+        };
+
+        description::Description::Harmonized(Harmonized::new(
+            Kind::Struct,
+            String::from("tumor_tissue_site"),
+            description,
+            "https://github.com/CBIIT/ccdi-federation-api/wiki/Sample-Metadata-Fields#tumor_tissue_site".parse::<Url>().unwrap(),
+            None,
+            None,
+        ))
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use regex::Regex;
-
     use crate::metadata::field::description::Description;
-    use crate::HARMONIZED_KEY_REGEX;
+    use crate::metadata::validate_harmonized_path;
 
     use super::*;
 
@@ -351,9 +368,7 @@ mod tests {
     }
 
     #[test]
-    fn all_of_the_harmonized_keys_conform_to_the_harmonized_key_regex() {
-        let regex = Regex::new(HARMONIZED_KEY_REGEX).unwrap();
-
+    fn all_of_the_harmonized_keys_are_valid() {
         for field in get_field_descriptions() {
             let path = match field {
                 Description::Harmonized(description) => description.path,
@@ -362,7 +377,9 @@ mod tests {
                 Description::Unharmonized(_) => continue,
             };
 
-            assert!(regex.is_match(path.as_str()))
+            if let Err(err) = validate_harmonized_path(&path) {
+                panic!("{err}");
+            }
         }
     }
 }
@@ -6,10 +6,47 @@ use utoipa::ToSchema;
 
 use crate::Url;
 
+pub mod registry;
+
+pub use registry::UnharmonizedRegistry;
+
+/// The kind of value held by an unharmonized metadata field.
+#[derive(Clone, Copy, Debug, Deserialize, Eq, PartialEq, Serialize, ToSchema)]
+#[serde(rename_all = "lowercase")]
+#[schema(as = models::metadata::field::description::unharmonized::ValueKind)]
+pub enum ValueKind {
+    /// A string.
+    String,
+
+    /// A number.
+    Number,
+
+    /// A boolean.
+    Boolean,
+
+    /// An array of values.
+    Array,
+
+    /// An object.
+    Object,
+}
+
+impl std::fmt::Display for ValueKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ValueKind::String => write!(f, "string"),
+            ValueKind::Number => write!(f, "number"),
+            ValueKind::Boolean => write!(f, "boolean"),
+            ValueKind::Array => write!(f, "array"),
+            ValueKind::Object => write!(f, "object"),
+        }
+    }
+}
+
 /// An unharmonized metadata field description.
 ///
 /// Unharmonized keys may be any valid JSON string.
-#[derive(Debug, Deserialize, Serialize, ToSchema)]
+#[derive(Clone, Debug, Deserialize, Serialize, ToSchema)]
 #[schema(as = models::metadata::field::description::Unharmonized)]
 pub struct Unharmonized {
     /// Whether or not this field is harmonized across the ecosystem.
@@ -41,6 +78,17 @@ pub struct Unharmonized {
     /// A URL that describes more about the metadata field, if available.
     #[schema(value_type = Option<models::Url>)]
     url: Option<Url>,
+
+    /// The kind of value held by the field, if known.
+    ///
+    /// This is only known for fields that the server has registered ahead of
+    /// time (see [`UnharmonizedRegistry`]).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[schema(
+        value_type = Option<models::metadata::field::description::unharmonized::ValueKind>,
+        nullable = false
+    )]
+    value_kind: Option<ValueKind>,
 }
 
 impl Unharmonized {
@@ -75,6 +123,7 @@ impl Unharmonized {
             path,
             standard,
             url,
+            value_kind: None,
         }
     }
 
@@ -195,4 +244,32 @@ impl Unharmonized {
     pub fn url(&self) -> Option<&Url> {
         self.url.as_ref()
     }
+
+    /// Gets the kind of value held by the field described by the
+    /// [`Unharmonized`] (if known).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ccdi_models as models;
+    ///
+    /// use models::metadata::field::description::unharmonized::ValueKind;
+    /// use models::metadata::field::description::Unharmonized;
+    /// use models::metadata::field::description::UnharmonizedRegistry;
+    ///
+    /// let field = Unharmonized::new(None, None, String::from("test"), None, None);
+    /// assert_eq!(field.value_kind(), None);
+    ///
+    /// let mut registry = UnharmonizedRegistry::default();
+    /// registry
+    ///     .register("test", "A description.", ValueKind::String)
+    ///     .unwrap();
+    /// assert_eq!(
+    ///     registry.get("test").unwrap().value_kind(),
+    ///     Some(ValueKind::String)
+    /// );
+    /// ```
+    pub fn value_kind(&self) -> Option<ValueKind> {
+        self.value_kind
+    }
 }
@@ -0,0 +1,289 @@
+//! A registry of unharmonized metadata field descriptions.
+//!
+//! Unharmonized metadata is a free-form map, so nothing is known about the
+//! unharmonized keys that a server emits ahead of time. Servers may describe
+//! those keys by registering them within an [`UnharmonizedRegistry`] at
+//! startup, which allows the keys to be listed alongside the harmonized
+//! fields within the `/metadata/fields/<entity>` responses.
+
+use indexmap::IndexMap;
+
+use crate::metadata::field::description::unharmonized::ValueKind;
+use crate::metadata::field::description::Unharmonized;
+
+/// The prefix of the path of every unharmonized field within a `metadata`
+/// block.
+const PATH_PREFIX: &str = "unharmonized.";
+
+/// An error related to an [`UnharmonizedRegistry`].
+#[derive(Debug, Eq, PartialEq)]
+pub enum Error {
+    /// Attempted to register a key that has already been registered.
+    Duplicate(String),
+}
+
+impl std::fmt::Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::Duplicate(key) => {
+                write!(f, "unharmonized field `{key}` is already registered")
+            }
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+/// A [`Result`](std::result::Result) with an [`Error`].
+type Result<T> = std::result::Result<T, Error>;
+
+/// A registry of the unharmonized fields emitted by a server for a single
+/// entity (e.g., subjects).
+///
+/// Fields are kept in the order in which they were registered.
+#[derive(Clone, Debug, Default)]
+pub struct UnharmonizedRegistry(IndexMap<String, Unharmonized>);
+
+impl UnharmonizedRegistry {
+    /// Registers an unharmonized field by its `key` within the `unharmonized`
+    /// map of a `metadata` block.
+    ///
+    /// An [`Error::Duplicate`] is returned if the key has already been
+    /// registered.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ccdi_models as models;
+    ///
+    /// use models::metadata::field::description::unharmonized::ValueKind;
+    /// use models::metadata::field::description::UnharmonizedRegistry;
+    ///
+    /// let mut registry = UnharmonizedRegistry::default();
+    /// registry
+    ///     .register(
+    ///         "consortium_id",
+    ///         "The consortium identifier.",
+    ///         ValueKind::String,
+    ///     )
+    ///     .unwrap();
+    ///
+    /// let err = registry
+    ///     .register("consortium_id", "Another description.", ValueKind::Number)
+    ///     .unwrap_err();
+    /// assert_eq!(
+    ///     err.to_string(),
+    ///     "unharmonized field `consortium_id` is already registered"
+    /// );
+    /// ```
+    pub fn register(
+        &mut self,
+        key: impl Into<String>,
+        description: impl Into<String>,
+        value_kind: ValueKind,
+    ) -> Result<()> {
+        let key = key.into();
+
+        if self.0.contains_key(&key) {
+            return Err(Error::Duplicate(key));
+        }
+
+        let field = Unharmonized {
+            harmonized: false,
+            name: None,
+            description: Some(description.into()),
+            path: format!("{PATH_PREFIX}{key}"),
+            standard: None,
+            url: None,
+            value_kind: Some(value_kind),
+        };
+
+        self.0.insert(key, field);
+        Ok(())
+    }
+
+    /// Gets the description of a registered unharmonized field by its `key`
+    /// (if it has been registered).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ccdi_models as models;
+    ///
+    /// use models::metadata::field::description::unharmonized::ValueKind;
+    /// use models::metadata::field::description::UnharmonizedRegistry;
+    ///
+    /// let mut registry = UnharmonizedRegistry::default();
+    /// registry
+    ///     .register(
+    ///         "consortium_id",
+    ///         "The consortium identifier.",
+    ///         ValueKind::String,
+    ///     )
+    ///     .unwrap();
+    ///
+    /// let field = registry.get("consortium_id").unwrap();
+    /// assert_eq!(field.path(), "unharmonized.consortium_id");
+    /// assert!(registry.get("unknown").is_none());
+    /// ```
+    pub fn get(&self, key: &str) -> Option<&Unharmonized> {
+        self.0.get(key)
+    }
+
+    /// Gets whether an unharmonized field has been registered with the `key`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ccdi_models as models;
+    ///
+    /// use models::metadata::field::description::unharmonized::ValueKind;
+    /// use models::metadata::field::description::UnharmonizedRegistry;
+    ///
+    /// let mut registry = UnharmonizedRegistry::default();
+    /// registry
+    ///     .register(
+    ///         "consortium_id",
+    ///         "The consortium identifier.",
+    ///         ValueKind::String,
+    ///     )
+    ///     .unwrap();
+    ///
+    /// assert!(registry.contains("consortium_id"));
+    /// assert!(!registry.contains("unknown"));
+    /// ```
+    pub fn contains(&self, key: &str) -> bool {
+        self.0.contains_key(key)
+    }
+
+    /// Gets the descriptions of every registered unharmonized field (in the
+    /// order in which they were registered).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ccdi_models as models;
+    ///
+    /// use models::metadata::field::description::unharmonized::ValueKind;
+    /// use models::metadata::field::description::UnharmonizedRegistry;
+    ///
+    /// let mut registry = UnharmonizedRegistry::default();
+    /// registry
+    ///     .register(
+    ///         "consortium_id",
+    ///         "The consortium identifier.",
+    ///         ValueKind::String,
+    ///     )
+    ///     .unwrap();
+    /// registry
+    ///     .register("batch", "The batch number.", ValueKind::Number)
+    ///     .unwrap();
+    ///
+    /// let paths = registry
+    ///     .descriptions()
+    ///     .map(|field| field.path().as_str())
+    ///     .collect::<Vec<_>>();
+    /// assert_eq!(
+    ///     paths,
+    ///     vec!["unharmonized.consortium_id", "unharmonized.batch"]
+    /// );
+    /// ```
+    pub fn descriptions(&self) -> impl Iterator<Item = &Unharmonized> {
+        self.0.values()
+    }
+
+    /// Gets the number of registered unharmonized fields.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ccdi_models as models;
+    ///
+    /// use models::metadata::field::description::unharmonized::ValueKind;
+    /// use models::metadata::field::description::UnharmonizedRegistry;
+    ///
+    /// let mut registry = UnharmonizedRegistry::default();
+    /// assert_eq!(registry.len(), 0);
+    ///
+    /// registry
+    ///     .register(
+    ///         "consortium_id",
+    ///         "The consortium identifier.",
+    ///         ValueKind::String,
+    ///     )
+    ///     .unwrap();
+    /// assert_eq!(registry.len(), 1);
+    /// ```
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    /// Gets whether no unharmonized fields have been registered.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ccdi_models as models;
+    ///
+    /// use models::metadata::field::description::UnharmonizedRegistry;
+    ///
+    /// assert!(UnharmonizedRegistry::default().is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_rejects_duplicate_registrations() {
+        let mut registry = UnharmonizedRegistry::default();
+
+        registry
+            .register(
+                "consortium_id",
+                "The consortium identifier.",
+                ValueKind::String,
+            )
+            .unwrap();
+
+        assert_eq!(
+            registry.register("consortium_id", "Another description.", ValueKind::Number),
+            Err(Error::Duplicate(String::from("consortium_id")))
+        );
+
+        // NOTE: the original registration is left untouched.
+        let field = registry.get("consortium_id").unwrap();
+        assert_eq!(
+            field.description(),
+            Some(&String::from("The consortium identifier."))
+        );
+        assert_eq!(field.value_kind(), Some(ValueKind::String));
+        assert_eq!(registry.len(), 1);
+    }
+
+    #[test]
+    fn it_serializes_registered_fields_as_unharmonized_descriptions() {
+        let mut registry = UnharmonizedRegistry::default();
+
+        registry
+            .register("batch", "The batch number.", ValueKind::Number)
+            .unwrap();
+
+        assert_eq!(
+            serde_json::to_value(registry.get("batch").unwrap()).unwrap(),
+            serde_json::json!({
+                "harmonized": false,
+                "name": null,
+                "description": "The batch number.",
+                "path": "unharmonized.batch",
+                "standard": null,
+                "url": null,
+                "value_kind": "number"
+            })
+        );
+    }
+}
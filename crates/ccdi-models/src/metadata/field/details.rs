@@ -12,10 +12,25 @@ use utoipa::ToSchema;
 
 use crate::Url;
 
-/// Details regarding the harmonization process.
+/// Details regarding the provenance of a harmonized value.
+///
+/// The details are provided within the `details` key of a harmonized field
+/// (alongside the `value` and the free-text `comment` of the field). They
+/// describe the `source` from which the value was drawn (e.g., a pathology
+/// report), the `method` by which the value was harmonized, the `harmonizer`
+/// that harmonized the value, and a `url` at which more can be learned about
+/// the value. Each is optional.
+///
+/// The `source`, the `method`, and the `comment` of a field may be used to
+/// filter entities (see the documentation of the filter parameters).
 #[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize, ToSchema)]
 #[schema(as = models::metadata::field::Details)]
 pub struct Details {
+    /// The source from which the value was drawn (e.g., `pathology report`).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[schema(nullable = false)]
+    source: Option<String>,
+
     /// The method by which the data was harmonized.
     #[schema(value_type = Option<models::metadata::field::details::Method>)]
     method: Option<Method>,
@@ -57,12 +72,51 @@ impl Details {
     /// ```
     pub fn new(method: Option<Method>, harmonizer: Option<Harmonizer>, url: Option<Url>) -> Self {
         Self {
+            source: None,
             method,
             harmonizer,
             url,
         }
     }
 
+    /// Sets the source from which the value was drawn for the [`Details`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ccdi_models as models;
+    ///
+    /// use models::metadata::field::details::Method;
+    /// use models::metadata::field::Details;
+    ///
+    /// let details = Details::new(Some(Method::Mapped), None, None).with_source("pathology report");
+    ///
+    /// assert_eq!(details.source(), Some("pathology report"));
+    /// ```
+    pub fn with_source(mut self, source: impl Into<String>) -> Self {
+        self.source = Some(source.into());
+        self
+    }
+
+    /// Gets the source from which the value was drawn from the [`Details`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ccdi_models as models;
+    ///
+    /// use models::metadata::field::Details;
+    ///
+    /// let details = Details::new(None, None, None);
+    /// assert_eq!(details.source(), None);
+    ///
+    /// let details = details.with_source("pathology report");
+    /// assert_eq!(details.source(), Some("pathology report"));
+    /// ```
+    pub fn source(&self) -> Option<&str> {
+        self.source.as_deref()
+    }
+
     /// Gets the [`Method`] from the [`Details`].
     ///
     /// # Examples
@@ -115,7 +169,7 @@ impl Details {
         self.harmonizer.as_ref()
     }
 
-    /// Gets the [`Harmonizer`] from the [`Details`].
+    /// Gets the [`Url`] from the [`Details`].
     ///
     /// # Examples
     ///
@@ -234,8 +234,8 @@ macro_rules! owned_field {
         where
             Standard: Distribution<$inner>,
         {
-            fn sample<R: rand::Rng + ?Sized>(&self, _: &mut R) -> $name {
-                $name::new(rand::random(), None, None, None, Some(false))
+            fn sample<R: rand::Rng + ?Sized>(&self, rng: &mut R) -> $name {
+                $name::new(rng.gen(), None, None, None, Some(false))
             }
         }
 
@@ -13,6 +13,69 @@ use serde::Serialize;
 use serde_json::Value;
 use utoipa::ToSchema;
 
+/// The probability that a randomly generated field describes its provenance.
+const RANDOM_PROVENANCE_PROBABILITY: f64 = 0.25;
+
+/// The sources from which the values of randomly generated fields are drawn.
+const RANDOM_SOURCES: &[&str] = &[
+    "pathology report",
+    "clinical record",
+    "sequencing report",
+    "study protocol",
+];
+
+/// The comments attached to randomly generated fields.
+const RANDOM_COMMENTS: &[&str] = &[
+    "Reviewed by the curation team.",
+    "Transcribed from a scanned document.",
+];
+
+/// Generates the provenance of a random field (its details and its comment).
+///
+/// The provenance is only described for a fraction of the fields (see
+/// [`RANDOM_PROVENANCE_PROBABILITY`]), and only some of those fields have a
+/// comment.
+///
+/// # Examples
+///
+/// ```
+/// use ccdi_models as models;
+/// use rand::rngs::StdRng;
+/// use rand::SeedableRng as _;
+///
+/// use models::metadata::field::unowned::random_provenance;
+///
+/// let mut rng = StdRng::seed_from_u64(0);
+///
+/// let provenance = (0..100)
+///     .map(|_| random_provenance(&mut rng))
+///     .collect::<Vec<_>>();
+///
+/// assert!(provenance.iter().any(|(details, _)| details.is_some()));
+/// assert!(provenance.iter().any(|(details, _)| details.is_none()));
+/// ```
+pub fn random_provenance<R: rand::Rng + ?Sized>(
+    rng: &mut R,
+) -> (Option<crate::metadata::field::Details>, Option<String>) {
+    if !rng.gen_bool(RANDOM_PROVENANCE_PROBABILITY) {
+        return (None, None);
+    }
+
+    let source = RANDOM_SOURCES[rng.gen_range(0..RANDOM_SOURCES.len())];
+    let details = crate::metadata::field::Details::new(
+        Some(crate::metadata::field::details::Method::Mapped),
+        None,
+        None,
+    )
+    .with_source(source);
+
+    let comment = rng
+        .gen_bool(0.5)
+        .then(|| RANDOM_COMMENTS[rng.gen_range(0..RANDOM_COMMENTS.len())].to_string());
+
+    (Some(details), comment)
+}
+
 #[macropol::macropol]
 macro_rules! unowned_field {
     ($name: ident, $as: ty, $inner: ty, $inner_as: ty, $value: expr, $import: expr) => {
@@ -38,7 +101,10 @@ macro_rules! unowned_field {
             /// Harmonization details _can_ be included for any metadata field,
             /// but, generally speaking, should be omitted unless there is
             /// important information to communicate regarding how the data in
-            /// the field specifically was harmonized.
+            /// the field specifically was harmonized. Together with the
+            /// `comment`, the `source` and the `method` within the details
+            /// describe the provenance of the value, and each may be used to
+            /// filter entities.
             ///
             /// See the "Interpreting metadata assignments" section of the
             /// specification for more details on when information should be
@@ -169,6 +235,61 @@ macro_rules! unowned_field {
                 self.details.as_ref()
             }
 
+            /// Gets the source from which the value of the
+            /// [`${stringify!($name)}`] was drawn (from its details).
+            ///
+            /// # Examples
+            ///
+            /// ```
+            /// use ${stringify!($import)};
+            /// use ccdi_models as models;
+            ///
+            /// use models::metadata::${stringify!($as)};
+            /// use models::metadata::field::Details;
+            ///
+            /// let details = Details::new(None, None, None).with_source("pathology report");
+            ///
+            /// let field = ${stringify!($name)}::new(
+            ///     ${stringify!($value)},
+            ///     None,
+            ///     Some(details),
+            ///     None
+            /// );
+            ///
+            /// assert_eq!(field.source(), Some("pathology report"));
+            /// ```
+            pub fn source(&self) -> Option<&str> {
+                self.details.as_ref().and_then(|details| details.source())
+            }
+
+            /// Gets the method by which the value of the
+            /// [`${stringify!($name)}`] was harmonized (from its details).
+            ///
+            /// # Examples
+            ///
+            /// ```
+            /// use ${stringify!($import)};
+            /// use ccdi_models as models;
+            ///
+            /// use models::metadata::${stringify!($as)};
+            /// use models::metadata::field::details::Method;
+            /// use models::metadata::field::Details;
+            ///
+            /// let details = Details::new(Some(Method::Mapped), None, None);
+            ///
+            /// let field = ${stringify!($name)}::new(
+            ///     ${stringify!($value)},
+            ///     None,
+            ///     Some(details),
+            ///     None
+            /// );
+            ///
+            /// assert_eq!(field.method(), Some(&Method::Mapped));
+            /// ```
+            pub fn method(&self) -> Option<&crate::metadata::field::details::Method> {
+                self.details.as_ref().and_then(|details| details.method())
+            }
+
             /// Gets the comment from the [`${stringify!($name)}`] by reference.
             ///
             /// # Examples
@@ -198,8 +319,11 @@ macro_rules! unowned_field {
         where
             Standard: Distribution<$inner>,
         {
-            fn sample<R: rand::Rng + ?Sized>(&self, _: &mut R) -> $name {
-                $name::new(rand::random(), None, None, None)
+            fn sample<R: rand::Rng + ?Sized>(&self, rng: &mut R) -> $name {
+                let value = rng.gen();
+                let (details, comment) = random_provenance(rng);
+
+                $name::new(value, None, details, comment)
             }
         }
 
@@ -322,9 +446,18 @@ pub mod sample {
     unowned_field!(
         TumorTissueMorphology,
         field::unowned::sample::TumorTissueMorphology,
-        cde::v1::sample::TumorTissueMorphology,
-        cde::v1::sample::TumorTissueMorphology,
-        cde::v1::sample::TumorTissueMorphology::from(String::from("8010/0")),
+        crate::sample::metadata::TumorTissueMorphology,
+        models::sample::metadata::TumorTissueMorphology,
+        models::sample::metadata::TumorTissueMorphology::try_new("8010/0").unwrap(),
+        ccdi_cde as cde
+    );
+
+    unowned_field!(
+        TumorTissueSite,
+        field::unowned::sample::TumorTissueSite,
+        crate::sample::metadata::TumorTissueSite,
+        models::sample::metadata::TumorTissueSite,
+        models::sample::metadata::TumorTissueSite::try_new("C71.9").unwrap(),
         ccdi_cde as cde
     );
 
@@ -569,6 +702,28 @@ pub mod file {
         cde::v1::file::Description::new("Hello, world!"),
         ccdi_cde as cde
     );
+
+    unowned_field!(
+        DrsUri,
+        field::unowned::file::DrsUri,
+        crate::file::metadata::DrsUri,
+        models::file::metadata::DrsUri,
+        "drs://drs.example.com/314159265358979fhqwhgads"
+            .parse::<models::file::metadata::DrsUri>()
+            .unwrap(),
+        ccdi_cde as cde
+    );
+
+    unowned_field!(
+        AccessUrl,
+        field::unowned::file::AccessUrl,
+        crate::file::metadata::AccessUrl,
+        models::file::metadata::AccessUrl,
+        "https://example.com/files/File1.txt"
+            .parse::<models::file::metadata::AccessUrl>()
+            .unwrap(),
+        ccdi_cde as cde
+    );
 }
 
 pub mod namespace {
@@ -627,3 +782,63 @@ pub mod organization {
         ccdi_cde as cde
     );
 }
+
+#[cfg(test)]
+mod tests {
+    use ccdi_cde as cde;
+
+    use crate::metadata::field::details::Method;
+    use crate::metadata::field::Details;
+
+    use super::*;
+
+    #[test]
+    fn it_round_trips_the_provenance_of_a_field() {
+        let field = sample::Diagnosis::new(
+            crate::sample::metadata::Diagnosis::from(String::from("Neuroblastoma")),
+            None,
+            Some(Details::new(Some(Method::Mapped), None, None).with_source("pathology report")),
+            Some(String::from("Reviewed by the curation team.")),
+        );
+
+        let value = serde_json::to_value(&field).unwrap();
+        assert_eq!(
+            value,
+            serde_json::json!({
+                "value": "Neuroblastoma",
+                "details": {
+                    "source": "pathology report",
+                    "method": "Mapped",
+                    "harmonizer": null,
+                    "url": null
+                },
+                "comment": "Reviewed by the curation team."
+            })
+        );
+
+        let field = serde_json::from_value::<sample::Diagnosis>(value).unwrap();
+        assert_eq!(field.source(), Some("pathology report"));
+        assert_eq!(field.method(), Some(&Method::Mapped));
+        assert_eq!(
+            field.comment(),
+            Some(&String::from("Reviewed by the curation team."))
+        );
+    }
+
+    #[test]
+    fn it_omits_a_missing_source() {
+        let field = subject::Sex::new(
+            cde::v1::subject::Sex::Female,
+            None,
+            Some(Details::new(Some(Method::Mapped), None, None)),
+            None,
+        );
+
+        let value = serde_json::to_value(&field).unwrap();
+        assert!(value["details"].get("source").is_none());
+        assert_eq!(field.source(), None);
+
+        let field = serde_json::from_value::<subject::Sex>(value).unwrap();
+        assert_eq!(field.method(), Some(&Method::Mapped));
+    }
+}
@@ -3,6 +3,7 @@
 use indexmap::IndexMap;
 use serde::Deserialize;
 use serde::Serialize;
+use serde::Serializer;
 use utoipa::ToSchema;
 
 use crate::metadata::field;
@@ -11,7 +12,11 @@ use crate::metadata::field::UnharmonizedField;
 /// A map of unharmonized metadata fields.
 ///
 /// Unharmonized keys may be any valid JSON string.
-#[derive(Clone, Default, Debug, Deserialize, Eq, PartialEq, Serialize, ToSchema)]
+///
+/// Regardless of the order in which the fields were inserted, the keys are
+/// always serialized in lexicographical order (by bytes). This ensures that two
+/// serializations of the same set of fields are byte-identical.
+#[derive(Clone, Default, Debug, Deserialize, Eq, PartialEq, ToSchema)]
 #[schema(as = fields::Unharmonized)]
 pub struct Unharmonized {
     /// The inner [`IndexMap`].
@@ -131,6 +136,17 @@ impl Unharmonized {
     }
 }
 
+impl Serialize for Unharmonized {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut entries = self.inner.iter().collect::<Vec<_>>();
+        entries.sort_by(|(a, _), (b, _)| a.cmp(b));
+        serializer.collect_map(entries)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use serde_json::Value;
@@ -167,9 +183,50 @@ mod tests {
 
         assert_eq!(
             serde_json::to_string(&unharmonized)?,
-            "{\"hello\":{\"value\":\"world\"},\"foo\":{\"value\":\"bar\",\"owned\":true}}"
+            "{\"foo\":{\"value\":\"bar\",\"owned\":true},\"hello\":{\"value\":\"world\"}}"
         );
 
         Ok(())
     }
+
+    #[test]
+    fn it_serializes_canonically_regardless_of_insertion_order(
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        let field = |value: &str| {
+            UnharmonizedField::Unowned(unowned::Field::new(
+                Value::String(String::from(value)),
+                None,
+                None,
+                None,
+            ))
+        };
+
+        let mut forward = Unharmonized::default();
+        let mut reverse = Unharmonized::default();
+
+        for key in ["Zeta", "alpha", "beta", "_gamma"] {
+            forward.inner_mut().insert(String::from(key), field(key));
+        }
+
+        for key in ["_gamma", "beta", "alpha", "Zeta"] {
+            reverse.inner_mut().insert(String::from(key), field(key));
+        }
+
+        let serialized = serde_json::to_string(&forward)?;
+
+        assert_eq!(serialized, serde_json::to_string(&forward)?);
+        assert_eq!(serialized, serde_json::to_string(&reverse)?);
+
+        let keys = serde_json::from_str::<serde_json::Map<String, Value>>(&serialized)?
+            .keys()
+            .cloned()
+            .collect::<Vec<_>>();
+        assert_eq!(keys, vec!["Zeta", "_gamma", "alpha", "beta"]);
+
+        // Deserializing and then serializing again is also byte-identical.
+        let roundtrip = serde_json::from_str::<Unharmonized>(&serialized)?;
+        assert_eq!(serialized, serde_json::to_string(&roundtrip)?);
+
+        Ok(())
+    }
 }
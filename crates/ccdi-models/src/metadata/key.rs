@@ -0,0 +1,456 @@
+//! Validation of metadata keys.
+//!
+//! Harmonized keys are validated with [`validate_harmonized_key()`]. The
+//! unharmonized keys given to the metadata builders are held to the same rules
+//! and must not shadow a harmonized field (see
+//! [`validate_unharmonized_keys()`]).
+
+use serde::Serialize;
+use serde_json::Value;
+
+/// An error related to a harmonized key.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum KeyError {
+    /// The key is empty.
+    Empty,
+
+    /// The key contains a character other than a lowercase letter (`a-z`), a
+    /// digit (`0-9`), or an underscore (`_`).
+    InvalidCharacter {
+        /// The key.
+        key: String,
+
+        /// The offending character.
+        character: char,
+    },
+
+    /// The key does not start with a lowercase letter (`a-z`).
+    InvalidStart(String),
+
+    /// The key ends with an underscore (`_`).
+    TrailingUnderscore(String),
+
+    /// The key contains two or more consecutive underscores (`__`).
+    DoubledUnderscore(String),
+}
+
+impl std::fmt::Display for KeyError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            KeyError::Empty => write!(f, "harmonized keys must not be empty"),
+            KeyError::InvalidCharacter { key, character } => write!(
+                f,
+                "harmonized key `{key}` contains the character {character:?}, but harmonized \
+                keys may only contain lowercase letters (`a-z`), digits (`0-9`), and \
+                underscores (`_`)"
+            ),
+            KeyError::InvalidStart(key) => write!(
+                f,
+                "harmonized key `{key}` must start with a lowercase letter (`a-z`)"
+            ),
+            KeyError::TrailingUnderscore(key) => write!(
+                f,
+                "harmonized key `{key}` must not end with an underscore (`_`)"
+            ),
+            KeyError::DoubledUnderscore(key) => write!(
+                f,
+                "harmonized key `{key}` must not contain consecutive underscores (`__`)"
+            ),
+        }
+    }
+}
+
+impl std::error::Error for KeyError {}
+
+/// Validates a harmonized key.
+///
+/// A harmonized key must:
+///
+/// * be comprised only of lowercase letters (`a-z`), digits (`0-9`), and
+///   underscores (`_`),
+/// * start with a lowercase letter,
+/// * not end with an underscore, and
+/// * not contain consecutive underscores.
+///
+/// The first rule that is broken is reported as a [`KeyError`]. Note that
+/// unharmonized keys received from other sources may be any valid JSON string,
+/// so they are only subject to these rules when they are given to a metadata
+/// builder (see [`validate_unharmonized_keys()`]).
+///
+/// # Examples
+///
+/// ```
+/// use ccdi_models as models;
+///
+/// use models::metadata::validate_harmonized_key;
+/// use models::metadata::KeyError;
+///
+/// assert!(validate_harmonized_key("age_at_diagnosis_v2").is_ok());
+/// assert_eq!(
+///     validate_harmonized_key("_x_"),
+///     Err(KeyError::InvalidStart(String::from("_x_")))
+/// );
+/// ```
+pub fn validate_harmonized_key(key: &str) -> Result<(), KeyError> {
+    let first = match key.chars().next() {
+        Some(first) => first,
+        None => return Err(KeyError::Empty),
+    };
+
+    if let Some(character) = key
+        .chars()
+        .find(|c| !(c.is_ascii_lowercase() || c.is_ascii_digit() || *c == '_'))
+    {
+        return Err(KeyError::InvalidCharacter {
+            key: key.to_string(),
+            character,
+        });
+    }
+
+    if !first.is_ascii_lowercase() {
+        return Err(KeyError::InvalidStart(key.to_string()));
+    }
+
+    if key.ends_with('_') {
+        return Err(KeyError::TrailingUnderscore(key.to_string()));
+    }
+
+    if key.contains("__") {
+        return Err(KeyError::DoubledUnderscore(key.to_string()));
+    }
+
+    Ok(())
+}
+
+/// Validates a period (`.`) delimited path of harmonized keys (e.g.,
+/// `checksums.md5`).
+///
+/// Each key within the path must pass [`validate_harmonized_key()`].
+///
+/// # Examples
+///
+/// ```
+/// use ccdi_models as models;
+///
+/// use models::metadata::validate_harmonized_path;
+/// use models::metadata::KeyError;
+///
+/// assert!(validate_harmonized_path("checksums.md5").is_ok());
+/// assert_eq!(validate_harmonized_path("checksums."), Err(KeyError::Empty));
+/// ```
+pub fn validate_harmonized_path(path: &str) -> Result<(), KeyError> {
+    path.split('.').try_for_each(validate_harmonized_key)
+}
+
+/// A problem with an unharmonized key.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum UnharmonizedKeyProblem {
+    /// The key breaks one of the rules for harmonized keys.
+    Invalid(KeyError),
+
+    /// The key is the name of a harmonized field.
+    Shadowed,
+}
+
+/// An error identifying every offending key within a set of unharmonized
+/// keys.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct UnharmonizedKeyError(Vec<(String, UnharmonizedKeyProblem)>);
+
+impl UnharmonizedKeyError {
+    /// Gets the offending keys (in the order they were provided) along with
+    /// the problem with each key.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ccdi_models as models;
+    ///
+    /// use models::metadata::key::validate_unharmonized_keys;
+    /// use models::metadata::key::UnharmonizedKeyProblem;
+    ///
+    /// let err = validate_unharmonized_keys(["sex", "site"], &[String::from("sex")]).unwrap_err();
+    ///
+    /// assert_eq!(
+    ///     err.problems(),
+    ///     &[(String::from("sex"), UnharmonizedKeyProblem::Shadowed)]
+    /// );
+    /// ```
+    pub fn problems(&self) -> &[(String, UnharmonizedKeyProblem)] {
+        &self.0
+    }
+
+    /// Gets an iterator over the offending keys (in the order they were
+    /// provided).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ccdi_models as models;
+    ///
+    /// use models::metadata::key::validate_unharmonized_keys;
+    ///
+    /// let harmonized = vec![String::from("sex")];
+    /// let err = validate_unharmonized_keys(["Site", "sex", "site"], &harmonized).unwrap_err();
+    ///
+    /// assert_eq!(err.keys().collect::<Vec<_>>(), vec!["Site", "sex"]);
+    /// ```
+    pub fn keys(&self) -> impl Iterator<Item = &str> {
+        self.0.iter().map(|(key, _)| key.as_str())
+    }
+}
+
+impl std::fmt::Display for UnharmonizedKeyError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "invalid unharmonized keys: ")?;
+
+        for (i, (key, problem)) in self.0.iter().enumerate() {
+            if i > 0 {
+                write!(f, "; ")?;
+            }
+
+            match problem {
+                UnharmonizedKeyProblem::Invalid(err) => write!(f, "{err}")?,
+                UnharmonizedKeyProblem::Shadowed => write!(
+                    f,
+                    "key `{key}` shadows the harmonized field of the same name"
+                )?,
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl std::error::Error for UnharmonizedKeyError {}
+
+/// Validates a set of unharmonized keys.
+///
+/// Each key must pass [`validate_harmonized_key()`], and no key may be one of
+/// the `harmonized` keys of the entity (e.g., `sex` for a subject), as an
+/// unharmonized field of the same name would be indistinguishable from the
+/// harmonized field to consumers. Every offending key is reported at once
+/// within the returned [`UnharmonizedKeyError`].
+///
+/// # Examples
+///
+/// ```
+/// use ccdi_models as models;
+///
+/// use models::metadata::key::validate_unharmonized_keys;
+///
+/// let harmonized = vec![String::from("sex")];
+///
+/// assert!(validate_unharmonized_keys(["enrollment_site"], &harmonized).is_ok());
+///
+/// let err = validate_unharmonized_keys(["sex", "Site", "site"], &harmonized).unwrap_err();
+/// assert_eq!(err.keys().collect::<Vec<_>>(), vec!["sex", "Site"]);
+/// ```
+pub fn validate_unharmonized_keys<'a, I>(
+    keys: I,
+    harmonized: &[String],
+) -> Result<(), UnharmonizedKeyError>
+where
+    I: IntoIterator<Item = &'a str>,
+{
+    let problems = keys
+        .into_iter()
+        .filter_map(|key| {
+            let problem = match validate_harmonized_key(key) {
+                Err(err) => UnharmonizedKeyProblem::Invalid(err),
+                Ok(()) if harmonized.iter().any(|harmonized| harmonized == key) => {
+                    UnharmonizedKeyProblem::Shadowed
+                }
+                Ok(()) => return None,
+            };
+
+            Some((key.to_string(), problem))
+        })
+        .collect::<Vec<_>>();
+
+    match problems.is_empty() {
+        true => Ok(()),
+        false => Err(UnharmonizedKeyError(problems)),
+    }
+}
+
+/// Gets the keys of the harmonized fields within a metadata block.
+///
+/// The keys are read from the serialized form of the metadata block, so the
+/// fields of the common metadata (which are flattened into the block) are
+/// included. Every harmonized field is serialized (as `null` when it has no
+/// value), so an empty metadata block may be provided.
+///
+/// # Examples
+///
+/// ```
+/// use ccdi_models as models;
+///
+/// use models::metadata::key::harmonized_keys;
+/// use models::subject::metadata::Builder;
+///
+/// let keys = harmonized_keys(&Builder::default().build());
+///
+/// assert!(keys.contains(&String::from("sex")));
+/// assert!(keys.contains(&String::from("depositions")));
+/// assert!(!keys.contains(&String::from("unharmonized")));
+/// ```
+pub fn harmonized_keys<T: Serialize>(metadata: &T) -> Vec<String> {
+    // SAFETY: metadata blocks are always serializable to a JSON object.
+    match serde_json::to_value(metadata).unwrap() {
+        Value::Object(fields) => fields
+            .into_iter()
+            .map(|(key, _)| key)
+            .filter(|key| key != "unharmonized")
+            .collect(),
+        _ => unreachable!("metadata blocks always serialize to a JSON object"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_accepts_valid_keys() {
+        for key in [
+            "a",
+            "sex",
+            "age_at_diagnosis",
+            "age_at_diagnosis_v2",
+            "icd_o_3",
+            "md5",
+            "a1_b2",
+        ] {
+            assert_eq!(validate_harmonized_key(key), Ok(()), "{key}");
+        }
+    }
+
+    #[test]
+    fn it_rejects_an_empty_key() {
+        assert_eq!(validate_harmonized_key(""), Err(KeyError::Empty));
+    }
+
+    #[test]
+    fn it_rejects_invalid_characters() {
+        for (key, character) in [
+            ("HeLlOwOrLd", 'H'),
+            ("key ", ' '),
+            ("checksums.md5", '.'),
+            ("age-at-diagnosis", '-'),
+            ("café", 'é'),
+        ] {
+            assert_eq!(
+                validate_harmonized_key(key),
+                Err(KeyError::InvalidCharacter {
+                    key: key.to_string(),
+                    character
+                }),
+                "{key}"
+            );
+        }
+    }
+
+    #[test]
+    fn it_rejects_keys_that_do_not_start_with_a_letter() {
+        for key in ["_x_", "____", "_sex", "2nd_diagnosis", "0"] {
+            assert_eq!(
+                validate_harmonized_key(key),
+                Err(KeyError::InvalidStart(key.to_string())),
+                "{key}"
+            );
+        }
+    }
+
+    #[test]
+    fn it_rejects_trailing_underscores() {
+        for key in ["sex_", "age_at_diagnosis__", "v2_"] {
+            assert_eq!(
+                validate_harmonized_key(key),
+                Err(KeyError::TrailingUnderscore(key.to_string())),
+                "{key}"
+            );
+        }
+    }
+
+    #[test]
+    fn it_rejects_doubled_underscores() {
+        for key in ["age__at_diagnosis", "a___b"] {
+            assert_eq!(
+                validate_harmonized_key(key),
+                Err(KeyError::DoubledUnderscore(key.to_string())),
+                "{key}"
+            );
+        }
+    }
+
+    #[test]
+    fn it_validates_each_key_within_a_path() {
+        assert_eq!(validate_harmonized_path("checksums.md5"), Ok(()));
+        assert_eq!(validate_harmonized_path("identifiers"), Ok(()));
+        assert_eq!(validate_harmonized_path(".md5"), Err(KeyError::Empty));
+        assert_eq!(
+            validate_harmonized_path("checksums..md5"),
+            Err(KeyError::Empty)
+        );
+        assert_eq!(
+            validate_harmonized_path("checksums.md5_"),
+            Err(KeyError::TrailingUnderscore(String::from("md5_")))
+        );
+    }
+
+    #[test]
+    fn it_describes_the_broken_rule() {
+        assert_eq!(
+            validate_harmonized_key("age__at_diagnosis")
+                .unwrap_err()
+                .to_string(),
+            "harmonized key `age__at_diagnosis` must not contain consecutive underscores (`__`)"
+        );
+        assert_eq!(
+            validate_harmonized_key("Sex").unwrap_err().to_string(),
+            "harmonized key `Sex` contains the character 'S', but harmonized keys may only \
+            contain lowercase letters (`a-z`), digits (`0-9`), and underscores (`_`)"
+        );
+    }
+
+    #[test]
+    fn it_reports_every_offending_unharmonized_key() {
+        let harmonized = vec![String::from("sex"), String::from("race")];
+
+        let err = validate_unharmonized_keys(
+            ["race", "site", "Treatment Arm", "sex", "arm_"],
+            &harmonized,
+        )
+        .unwrap_err();
+
+        assert_eq!(
+            err.problems(),
+            &[
+                (String::from("race"), UnharmonizedKeyProblem::Shadowed),
+                (
+                    String::from("Treatment Arm"),
+                    UnharmonizedKeyProblem::Invalid(KeyError::InvalidCharacter {
+                        key: String::from("Treatment Arm"),
+                        character: 'T'
+                    })
+                ),
+                (String::from("sex"), UnharmonizedKeyProblem::Shadowed),
+                (
+                    String::from("arm_"),
+                    UnharmonizedKeyProblem::Invalid(KeyError::TrailingUnderscore(String::from(
+                        "arm_"
+                    )))
+                ),
+            ]
+        );
+        assert_eq!(
+            err.to_string(),
+            "invalid unharmonized keys: key `race` shadows the harmonized field of the same \
+            name; harmonized key `Treatment Arm` contains the character 'T', but harmonized \
+            keys may only contain lowercase letters (`a-z`), digits (`0-9`), and underscores \
+            (`_`); key `sex` shadows the harmonized field of the same name; harmonized key \
+            `arm_` must not end with an underscore (`_`)"
+        );
+    }
+}
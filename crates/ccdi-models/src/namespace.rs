@@ -7,10 +7,12 @@ use utoipa::ToSchema;
 mod description;
 pub mod identifier;
 pub mod metadata;
+pub mod study;
 
 pub use description::Description;
 pub use identifier::Identifier;
 pub use metadata::Metadata;
+pub use study::Study;
 
 /// A namespace.
 #[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize, ToSchema)]
@@ -43,6 +45,11 @@ pub struct Namespace {
         nullable = true
     )]
     metadata: Option<Metadata>,
+
+    /// If available, the study described by this [`Namespace`].
+    #[serde(default)]
+    #[schema(value_type = Option<models::namespace::Study>, nullable = true)]
+    study: Option<Study>,
 }
 
 impl Namespace {
@@ -91,9 +98,52 @@ impl Namespace {
             contact_email: contact_email.into(),
             description,
             metadata,
+            study: None,
         }
     }
 
+    /// Sets the study described by the [`Namespace`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ccdi_models as models;
+    ///
+    /// use models::namespace;
+    /// use models::namespace::study;
+    /// use models::namespace::Study;
+    /// use models::organization;
+    /// use models::Namespace;
+    ///
+    /// let namespace = Namespace::new(
+    ///     namespace::Identifier::new(
+    ///         "example-organization"
+    ///             .parse::<organization::Identifier>()
+    ///             .unwrap(),
+    ///         "ExampleNamespace"
+    ///             .parse::<namespace::identifier::Name>()
+    ///             .unwrap(),
+    ///     ),
+    ///     "support@example.com",
+    ///     None,
+    ///     None,
+    /// )
+    /// .with_study(Study::new(
+    ///     study::Identifier::FreeText(String::from("STUDY001")),
+    ///     "An Example Study",
+    ///     None,
+    ///     Some("phs000000.v1.p1".parse::<study::Accession>()?),
+    /// ));
+    ///
+    /// assert_eq!(namespace.study().unwrap().name(), "An Example Study");
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn with_study(mut self, study: Study) -> Self {
+        self.study = Some(study);
+        self
+    }
+
     /// Gets the identifier of the [`Namespace`] by reference.
     ///
     /// # Examples
@@ -259,4 +309,36 @@ impl Namespace {
     pub fn metadata(&self) -> Option<&Metadata> {
         self.metadata.as_ref()
     }
+
+    /// Gets the study described by the [`Namespace`] by reference (if it
+    /// exists).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ccdi_models as models;
+    ///
+    /// use models::namespace;
+    /// use models::organization;
+    /// use models::Namespace;
+    ///
+    /// let namespace = Namespace::new(
+    ///     namespace::Identifier::new(
+    ///         "example-organization"
+    ///             .parse::<organization::Identifier>()
+    ///             .unwrap(),
+    ///         "ExampleNamespace"
+    ///             .parse::<namespace::identifier::Name>()
+    ///             .unwrap(),
+    ///     ),
+    ///     "support@example.com",
+    ///     None,
+    ///     None,
+    /// );
+    ///
+    /// assert!(namespace.study().is_none());
+    /// ```
+    pub fn study(&self) -> Option<&Study> {
+        self.study.as_ref()
+    }
 }
@@ -183,7 +183,7 @@ impl Metadata {
     ///             None,
     ///             None,
     ///         )),
-    ///     )
+    ///     )?
     ///     .insert_unharmonized(
     ///         "owned",
     ///         UnharmonizedField::Owned(owned::Field::new(
@@ -193,7 +193,7 @@ impl Metadata {
     ///             None,
     ///             None,
     ///         )),
-    ///     )
+    ///     )?
     ///     .build();
     ///
     /// assert!(matches!(
@@ -0,0 +1,273 @@
+//! The study described by a namespace.
+
+use ccdi_cde as cde;
+use serde::Deserialize;
+use serde::Serialize;
+use utoipa::ToSchema;
+
+pub mod accession;
+
+pub use accession::Accession;
+
+/// The identifier of a study.
+///
+/// When the study is listed within the caDSR, the identifier is the
+/// [`cde::v1::namespace::StudyId`] common data element. Otherwise, the
+/// identifier is provided as free text.
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize, ToSchema)]
+#[serde(tag = "kind", content = "value")]
+#[schema(as = models::namespace::study::Identifier)]
+pub enum Identifier {
+    /// An identifier for a study that is listed within the caDSR.
+    #[schema(value_type = cde::v1::namespace::StudyId)]
+    Cde(cde::v1::namespace::StudyId),
+
+    /// A free-text identifier for a study that is not listed within the caDSR.
+    FreeText(String),
+}
+
+impl Identifier {
+    /// Gets the [`Identifier`] as a string slice (regardless of whether it is
+    /// listed within the caDSR).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ccdi_cde as cde;
+    /// use ccdi_models as models;
+    ///
+    /// use models::namespace::study::Identifier;
+    ///
+    /// let id = Identifier::Cde(cde::v1::namespace::StudyId::from(String::from("STUDY001")));
+    /// assert_eq!(id.as_str(), "STUDY001");
+    ///
+    /// let id = Identifier::FreeText(String::from("An Unlisted Study"));
+    /// assert_eq!(id.as_str(), "An Unlisted Study");
+    /// ```
+    pub fn as_str(&self) -> &str {
+        match self {
+            Identifier::Cde(id) => id.as_str(),
+            Identifier::FreeText(id) => id.as_str(),
+        }
+    }
+}
+
+impl std::fmt::Display for Identifier {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+/// The study described by a namespace.
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize, ToSchema)]
+#[schema(as = models::namespace::Study)]
+pub struct Study {
+    /// The identifier of the study.
+    #[schema(value_type = models::namespace::study::Identifier)]
+    id: Identifier,
+
+    /// The name of the study.
+    #[schema(example = "An Example Study")]
+    name: String,
+
+    /// If available, a short description of the study.
+    #[serde(default)]
+    #[schema(
+        value_type = Option<String>,
+        nullable = true,
+        example = "A study of childhood cancers."
+    )]
+    description: Option<String>,
+
+    /// If available, the dbGaP accession of the study.
+    ///
+    /// The accession **must** conform to the pattern
+    /// `^phs\d{6}(\.v\d+\.p\d+)?$`.
+    #[serde(default)]
+    #[schema(
+        value_type = Option<models::namespace::study::Accession>,
+        nullable = true
+    )]
+    accession: Option<Accession>,
+}
+
+impl Study {
+    /// Creates a new [`Study`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ccdi_models as models;
+    ///
+    /// use models::namespace::study::Accession;
+    /// use models::namespace::study::Identifier;
+    /// use models::namespace::Study;
+    ///
+    /// let study = Study::new(
+    ///     Identifier::FreeText(String::from("STUDY001")),
+    ///     "An Example Study",
+    ///     Some(String::from("A study of childhood cancers.")),
+    ///     Some("phs000000.v1.p1".parse::<Accession>()?),
+    /// );
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn new(
+        id: Identifier,
+        name: impl Into<String>,
+        description: Option<String>,
+        accession: Option<Accession>,
+    ) -> Self {
+        Self {
+            id,
+            name: name.into(),
+            description,
+            accession,
+        }
+    }
+
+    /// Gets the identifier of the [`Study`] by reference.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ccdi_models as models;
+    ///
+    /// use models::namespace::study::Identifier;
+    /// use models::namespace::Study;
+    ///
+    /// let study = Study::new(
+    ///     Identifier::FreeText(String::from("STUDY001")),
+    ///     "An Example Study",
+    ///     None,
+    ///     None,
+    /// );
+    ///
+    /// assert_eq!(study.id().as_str(), "STUDY001");
+    /// ```
+    pub fn id(&self) -> &Identifier {
+        &self.id
+    }
+
+    /// Gets the name of the [`Study`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ccdi_models as models;
+    ///
+    /// use models::namespace::study::Identifier;
+    /// use models::namespace::Study;
+    ///
+    /// let study = Study::new(
+    ///     Identifier::FreeText(String::from("STUDY001")),
+    ///     "An Example Study",
+    ///     None,
+    ///     None,
+    /// );
+    ///
+    /// assert_eq!(study.name(), "An Example Study");
+    /// ```
+    pub fn name(&self) -> &str {
+        self.name.as_str()
+    }
+
+    /// Gets the description of the [`Study`] (if it exists).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ccdi_models as models;
+    ///
+    /// use models::namespace::study::Identifier;
+    /// use models::namespace::Study;
+    ///
+    /// let study = Study::new(
+    ///     Identifier::FreeText(String::from("STUDY001")),
+    ///     "An Example Study",
+    ///     Some(String::from("A study of childhood cancers.")),
+    ///     None,
+    /// );
+    ///
+    /// assert_eq!(study.description(), Some("A study of childhood cancers."));
+    /// ```
+    pub fn description(&self) -> Option<&str> {
+        self.description.as_deref()
+    }
+
+    /// Gets the dbGaP accession of the [`Study`] by reference (if it exists).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ccdi_models as models;
+    ///
+    /// use models::namespace::study::Accession;
+    /// use models::namespace::study::Identifier;
+    /// use models::namespace::Study;
+    ///
+    /// let study = Study::new(
+    ///     Identifier::FreeText(String::from("STUDY001")),
+    ///     "An Example Study",
+    ///     None,
+    ///     Some("phs000000.v1.p1".parse::<Accession>()?),
+    /// );
+    ///
+    /// assert_eq!(study.accession().unwrap().as_str(), "phs000000.v1.p1");
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn accession(&self) -> Option<&Accession> {
+        self.accession.as_ref()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_serializes_a_cde_study_id() {
+        let study = Study::new(
+            Identifier::Cde(cde::v1::namespace::StudyId::from(String::from("STUDY001"))),
+            "An Example Study",
+            None,
+            Some("phs000000.v1.p1".parse::<Accession>().unwrap()),
+        );
+
+        assert_eq!(
+            serde_json::to_string(&study).unwrap(),
+            "{\"id\":{\"kind\":\"Cde\",\"value\":\"STUDY001\"},\"name\":\"An Example \
+             Study\",\"description\":null,\"accession\":\"phs000000.v1.p1\"}"
+        );
+    }
+
+    #[test]
+    fn it_deserializes_a_free_text_study_id() {
+        let study = serde_json::from_str::<Study>(
+            "{\"id\":{\"kind\":\"FreeText\",\"value\":\"An Unlisted Study\"},\"name\":\"An \
+             Unlisted Study\"}",
+        )
+        .unwrap();
+
+        assert_eq!(
+            study.id(),
+            &Identifier::FreeText(String::from("An Unlisted Study"))
+        );
+        assert!(study.description().is_none());
+        assert!(study.accession().is_none());
+    }
+
+    #[test]
+    fn it_rejects_a_study_with_an_invalid_accession() {
+        let err = serde_json::from_str::<Study>(
+            "{\"id\":{\"kind\":\"Cde\",\"value\":\"STUDY001\"},\"name\":\"An Example \
+             Study\",\"accession\":\"phs000000.v1\"}",
+        )
+        .unwrap_err();
+
+        assert!(err
+            .to_string()
+            .contains("invalid dbGaP accession `phs000000.v1`"));
+    }
+}
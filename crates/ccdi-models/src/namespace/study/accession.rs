@@ -0,0 +1,169 @@
+//! A dbGaP study accession.
+
+use std::str::FromStr;
+
+use lazy_static::lazy_static;
+use regex::Regex;
+use serde::Deserialize;
+use serde::Serialize;
+use utoipa::ToSchema;
+
+lazy_static! {
+    static ref PATTERN: Regex = Regex::new(r"^phs\d{6}(\.v\d+\.p\d+)?$").unwrap();
+}
+
+/// An error when parsing an [`Accession`].
+#[derive(Debug)]
+pub enum ParseError {
+    /// Attempted to create an accession with an invalid format.
+    InvalidFormat(String),
+}
+
+impl std::fmt::Display for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ParseError::InvalidFormat(value) => write!(
+                f,
+                "invalid dbGaP accession `{value}`: accession must conform to the pattern {}",
+                PATTERN.as_str()
+            ),
+        }
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// The dbGaP accession of a study (e.g., `phs000000` or `phs000000.v1.p1`).
+///
+/// The accession **must** conform to the pattern `^phs\d{6}(\.v\d+\.p\d+)?$`:
+/// the study accession optionally followed by its version and participant set.
+#[derive(Clone, Debug, Deserialize, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize, ToSchema)]
+#[serde(try_from = "String", into = "String")]
+#[schema(
+    as = models::namespace::study::Accession,
+    value_type = String,
+    example = "phs000000.v1.p1"
+)]
+pub struct Accession(String);
+
+impl Accession {
+    /// Attempts to create a new [`Accession`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ccdi_models as models;
+    ///
+    /// use models::namespace::study::Accession;
+    ///
+    /// let accession = Accession::try_new("phs000000.v1.p1")?;
+    /// assert_eq!(accession.as_str(), "phs000000.v1.p1");
+    ///
+    /// assert!(Accession::try_new("phs000000").is_ok());
+    /// assert!(Accession::try_new("phs0000").is_err());
+    /// assert!(Accession::try_new("phs000000.v1").is_err());
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn try_new(value: impl Into<String>) -> Result<Self, ParseError> {
+        let value = value.into();
+
+        if !PATTERN.is_match(&value) {
+            return Err(ParseError::InvalidFormat(value));
+        }
+
+        Ok(Self(value))
+    }
+
+    /// Gets the [`Accession`] as a string slice.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ccdi_models as models;
+    ///
+    /// use models::namespace::study::Accession;
+    ///
+    /// let accession = "phs000000".parse::<Accession>()?;
+    /// assert_eq!(accession.as_str(), "phs000000");
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn as_str(&self) -> &str {
+        self.0.as_str()
+    }
+}
+
+impl FromStr for Accession {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::try_new(s)
+    }
+}
+
+impl TryFrom<String> for Accession {
+    type Error = ParseError;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        Self::try_new(value)
+    }
+}
+
+impl From<Accession> for String {
+    fn from(value: Accession) -> Self {
+        value.0
+    }
+}
+
+impl std::fmt::Display for Accession {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_allows_valid_accessions() {
+        "phs000000".parse::<Accession>().unwrap();
+        "phs000123.v1.p1".parse::<Accession>().unwrap();
+        "phs002431.v12.p10".parse::<Accession>().unwrap();
+    }
+
+    #[test]
+    fn it_does_not_allow_invalid_accessions() {
+        for value in [
+            "",
+            "phs",
+            "phs00000",
+            "phs0000000",
+            "PHS000000",
+            "phs000000.v1",
+            "phs000000.p1",
+            "phs000000.v1.p1 ",
+            "phs000000.va.p1",
+        ] {
+            let err = value.parse::<Accession>().unwrap_err();
+            assert!(
+                err.to_string().starts_with("invalid dbGaP accession"),
+                "{value}"
+            );
+        }
+    }
+
+    #[test]
+    fn it_validates_accessions_when_deserializing() {
+        let accession = serde_json::from_str::<Accession>("\"phs000000.v1.p1\"").unwrap();
+        assert_eq!(accession.as_str(), "phs000000.v1.p1");
+        assert_eq!(
+            serde_json::to_string(&accession).unwrap(),
+            "\"phs000000.v1.p1\""
+        );
+
+        let err = serde_json::from_str::<Accession>("\"phs1\"").unwrap_err();
+        assert!(err.to_string().contains("invalid dbGaP accession `phs1`"));
+    }
+}
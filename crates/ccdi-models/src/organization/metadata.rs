@@ -1,7 +1,9 @@
 //! Metadata for an [`Organization`](super::Organization).
 
 use nonempty::NonEmpty;
+use serde::de::Error as _;
 use serde::Deserialize;
+use serde::Deserializer;
 use serde::Serialize;
 use utoipa::ToSchema;
 
@@ -25,7 +27,14 @@ pub struct Metadata {
     /// only one organization in the API specification. As such, if the above is
     /// not true, there is no way to make a namespace where data is contributed
     /// from multiple institutions.
+    ///
+    /// When provided, this list must contain at least one institution (use
+    /// `null` when there are no known institutions). For compatibility with
+    /// earlier versions of the specification, a single institution (rather
+    /// than a list) is also accepted and is treated as a list with one
+    /// element.
     #[schema(value_type = Vec<field::unowned::organization::Institution>, nullable = true)]
+    #[serde(default, deserialize_with = "deserialize_institution")]
     institution: Option<NonEmpty<field::unowned::organization::Institution>>,
 
     /// Common metadata elements for all metadata blocks.
@@ -39,6 +48,35 @@ pub struct Metadata {
     unharmonized: fields::Unharmonized,
 }
 
+/// Deserializes the institution(s) of an organization from either a list of
+/// institutions or (for compatibility) a single institution.
+fn deserialize_institution<'de, D>(
+    deserializer: D,
+) -> Result<Option<NonEmpty<field::unowned::organization::Institution>>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum OneOrMany {
+        Many(Vec<field::unowned::organization::Institution>),
+        One(field::unowned::organization::Institution),
+    }
+
+    match Option::<OneOrMany>::deserialize(deserializer)? {
+        Some(OneOrMany::Many(institutions)) => {
+            NonEmpty::from_vec(institutions).map(Some).ok_or_else(|| {
+                D::Error::custom(
+                    "`institution` must contain at least one institution (use `null` when \
+                     there are no known institutions)",
+                )
+            })
+        }
+        Some(OneOrMany::One(institution)) => Ok(Some(NonEmpty::new(institution))),
+        None => Ok(None),
+    }
+}
+
 impl Metadata {
     /// Gets the institution(s) from the [`Metadata`].
     ///
@@ -117,7 +155,7 @@ impl Metadata {
     ///             None,
     ///             None,
     ///         )),
-    ///     )
+    ///     )?
     ///     .insert_unharmonized(
     ///         "owned",
     ///         UnharmonizedField::Owned(owned::Field::new(
@@ -127,7 +165,7 @@ impl Metadata {
     ///             None,
     ///             Some(true),
     ///         )),
-    ///     )
+    ///     )?
     ///     .build();
     ///
     /// assert!(matches!(
@@ -149,6 +187,7 @@ impl Metadata {
 #[cfg(test)]
 mod tests {
     use crate::organization::metadata::builder;
+    use crate::organization::Metadata;
 
     #[test]
     fn it_skips_serializing_the_unharmonized_key_when_it_is_empty() {
@@ -158,4 +197,58 @@ mod tests {
             "{\"institution\":null,\"depositions\":null}",
         );
     }
+
+    #[test]
+    fn it_deserializes_a_list_of_institutions() {
+        let metadata = serde_json::from_str::<Metadata>(
+            r#"{"institution":[{"value":"COG"},{"value":"NCI"}],"depositions":null}"#,
+        )
+        .unwrap();
+
+        let institutions = metadata
+            .institution()
+            .unwrap()
+            .iter()
+            .map(|institution| institution.value().as_str())
+            .collect::<Vec<_>>();
+
+        assert_eq!(institutions, vec!["COG", "NCI"]);
+    }
+
+    #[test]
+    fn it_deserializes_a_single_institution_into_a_list() {
+        let metadata = serde_json::from_str::<Metadata>(
+            r#"{"institution":{"value":"COG"},"depositions":null}"#,
+        )
+        .unwrap();
+
+        let institutions = metadata.institution().unwrap();
+        assert_eq!(institutions.len(), 1);
+        assert_eq!(institutions.first().value().as_str(), "COG");
+
+        // The institution is always serialized as a list.
+        assert!(serde_json::to_string(&metadata)
+            .unwrap()
+            .starts_with("{\"institution\":[{\"value\":\"COG\""));
+    }
+
+    #[test]
+    fn it_deserializes_a_missing_or_null_institution() {
+        let metadata = serde_json::from_str::<Metadata>(r#"{"depositions":null}"#).unwrap();
+        assert!(metadata.institution().is_none());
+
+        let metadata =
+            serde_json::from_str::<Metadata>(r#"{"institution":null,"depositions":null}"#).unwrap();
+        assert!(metadata.institution().is_none());
+    }
+
+    #[test]
+    fn it_rejects_an_empty_list_of_institutions() {
+        let err = serde_json::from_str::<Metadata>(r#"{"institution":[],"depositions":null}"#)
+            .unwrap_err();
+
+        assert!(err
+            .to_string()
+            .starts_with("`institution` must contain at least one institution"));
+    }
 }
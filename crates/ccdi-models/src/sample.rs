@@ -14,11 +14,53 @@ pub use metadata::Metadata;
 
 use crate::gateway;
 use crate::gateway::AnonymousOrReference;
-use crate::gateway::Link;
+use crate::metadata::Generation;
+use crate::metadata::DEFAULT_METADATA_LESS_FRACTION;
 use crate::Entity;
-use crate::Url;
 use nonempty::NonEmpty;
 
+/// An error related to a [`Sample`].
+#[derive(Debug, Eq, PartialEq)]
+pub enum Error {
+    /// The identifiers within the metadata of the sample do not include the
+    /// primary identifier of the sample.
+    MissingPrimaryIdentifier(Identifier),
+
+    /// The identifiers within the metadata of the sample include a linked
+    /// identifier within the namespace of the sample that is not the primary
+    /// identifier (the name of a sample is unique within its namespace, so
+    /// the two identifiers contradict one another).
+    ConflictingIdentifier {
+        /// The primary identifier of the sample.
+        primary: Identifier,
+
+        /// The identifier that contradicts the primary identifier.
+        conflicting: Identifier,
+    },
+}
+
+impl std::fmt::Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::MissingPrimaryIdentifier(primary) => write!(
+                f,
+                "the identifiers within the metadata do not include the primary identifier: \
+                 {primary}"
+            ),
+            Error::ConflictingIdentifier {
+                primary,
+                conflicting,
+            } => write!(
+                f,
+                "the identifiers within the metadata include {conflicting}, which \
+                 contradicts the primary identifier: {primary}"
+            ),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
 /// A sample.
 ///
 /// **Note:** the `subject` identifier **must** match a
@@ -95,6 +137,7 @@ impl Sample {
     ///
     /// use models::gateway::AnonymousOrReference;
     /// use models::gateway::Link;
+    /// use models::gateway::Open;
     /// use models::namespace;
     /// use models::organization;
     /// use models::sample::metadata::Builder;
@@ -134,11 +177,15 @@ impl Sample {
     ///     sample_id,
     ///     subject_id,
     ///     Some(NonEmpty::new(AnonymousOrReference::Anonymous {
-    ///         gateway: Gateway::Open {
-    ///             link: Link::Direct {
-    ///                 url: "https://example.com".parse::<Url>().unwrap(),
-    ///             },
-    ///         },
+    ///         gateway: Gateway::Open(
+    ///             Open::try_new(
+    ///                 Link::Direct {
+    ///                     url: "https://example.com".parse::<Url>().unwrap(),
+    ///                 },
+    ///                 None,
+    ///             )
+    ///             .unwrap(),
+    ///         ),
     ///     })),
     ///     Some(Builder::default().build()),
     /// );
@@ -159,6 +206,71 @@ impl Sample {
         }
     }
 
+    /// Attempts to create a new [`Sample`].
+    ///
+    /// The identifiers within the metadata of a sample (if there are any)
+    /// *must* include the primary identifier of the sample as a [linked
+    /// identifier](identifier::linked::Identifier). An error is returned if
+    /// they do not, or if they include a linked identifier within the same
+    /// namespace that is not the primary identifier.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ccdi_models as models;
+    ///
+    /// use models::metadata::field::unowned::sample::Identifier;
+    /// use models::namespace;
+    /// use models::sample::identifier::linked;
+    /// use models::sample::identifier::referenced;
+    /// use models::sample::metadata::Builder;
+    /// use models::Sample;
+    ///
+    /// let namespace = namespace::Identifier::new(
+    ///     "example-organization"
+    ///         .parse::<models::organization::Identifier>()
+    ///         .unwrap(),
+    ///     namespace::identifier::Name::try_new("ExampleNamespace").unwrap(),
+    /// );
+    ///
+    /// let subject_id = models::subject::Identifier::new(namespace.clone(), "SubjectName001");
+    /// let id = models::sample::Identifier::new(namespace.clone(), "SampleName001");
+    /// let linked = |id: models::sample::Identifier| {
+    ///     Identifier::new(
+    ///         referenced::Identifier::Linked(linked::Identifier::new(
+    ///             id,
+    ///             "https://ccdi.example.com/api/v0"
+    ///                 .parse::<models::Url>()
+    ///                 .unwrap(),
+    ///         )),
+    ///         None,
+    ///         None,
+    ///         None,
+    ///     )
+    /// };
+    ///
+    /// let metadata = Builder::default()
+    ///     .append_identifier(linked(id.clone()))
+    ///     .build();
+    /// assert!(Sample::try_new(id.clone(), subject_id.clone(), None, Some(metadata)).is_ok());
+    ///
+    /// let other = models::sample::Identifier::new(namespace, "SampleName002");
+    /// let metadata = Builder::default().append_identifier(linked(other)).build();
+    /// assert!(Sample::try_new(id, subject_id, None, Some(metadata)).is_err());
+    /// ```
+    pub fn try_new(
+        id: Identifier,
+        subject: crate::subject::Identifier,
+        gateways: Option<NonEmpty<gateway::AnonymousOrReference>>,
+        metadata: Option<Metadata>,
+    ) -> Result<Self, Error> {
+        if let Some(metadata) = &metadata {
+            check_identifiers(&id, metadata)?;
+        }
+
+        Ok(Self::new(id, subject, gateways, metadata))
+    }
+
     /// Gets the name for this [`Sample`] by reference.
     ///
     /// # Examples
@@ -168,6 +280,7 @@ impl Sample {
     ///
     /// use models::gateway::AnonymousOrReference;
     /// use models::gateway::Link;
+    /// use models::gateway::Open;
     /// use models::namespace;
     /// use models::organization;
     /// use models::sample::metadata::Builder;
@@ -207,11 +320,15 @@ impl Sample {
     ///     sample_id,
     ///     subject_id,
     ///     Some(NonEmpty::new(AnonymousOrReference::Anonymous {
-    ///         gateway: Gateway::Open {
-    ///             link: Link::Direct {
-    ///                 url: "https://example.com".parse::<Url>().unwrap(),
-    ///             },
-    ///         },
+    ///         gateway: Gateway::Open(
+    ///             Open::try_new(
+    ///                 Link::Direct {
+    ///                     url: "https://example.com".parse::<Url>().unwrap(),
+    ///                 },
+    ///                 None,
+    ///             )
+    ///             .unwrap(),
+    ///         ),
     ///     })),
     ///     Some(Builder::default().build()),
     /// );
@@ -239,6 +356,7 @@ impl Sample {
     ///
     /// use models::gateway::AnonymousOrReference;
     /// use models::gateway::Link;
+    /// use models::gateway::Open;
     /// use models::namespace;
     /// use models::organization;
     /// use models::sample::metadata::Builder;
@@ -278,11 +396,15 @@ impl Sample {
     ///     sample_id,
     ///     subject_id,
     ///     Some(NonEmpty::new(AnonymousOrReference::Anonymous {
-    ///         gateway: Gateway::Open {
-    ///             link: Link::Direct {
-    ///                 url: "https://example.com".parse::<Url>().unwrap(),
-    ///             },
-    ///         },
+    ///         gateway: Gateway::Open(
+    ///             Open::try_new(
+    ///                 Link::Direct {
+    ///                     url: "https://example.com".parse::<Url>().unwrap(),
+    ///                 },
+    ///                 None,
+    ///             )
+    ///             .unwrap(),
+    ///         ),
     ///     })),
     ///     Some(Builder::default().build()),
     /// );
@@ -311,6 +433,7 @@ impl Sample {
     ///
     /// use models::gateway::AnonymousOrReference;
     /// use models::gateway::Link;
+    /// use models::gateway::Open;
     /// use models::namespace;
     /// use models::organization;
     /// use models::sample::metadata::Builder;
@@ -351,11 +474,15 @@ impl Sample {
     ///     sample_id,
     ///     subject_id,
     ///     Some(NonEmpty::new(AnonymousOrReference::Anonymous {
-    ///         gateway: Gateway::Open {
-    ///             link: Link::Direct {
-    ///                 url: "https://example.com".parse::<Url>().unwrap(),
-    ///             },
-    ///         },
+    ///         gateway: Gateway::Open(
+    ///             Open::try_new(
+    ///                 Link::Direct {
+    ///                     url: "https://example.com".parse::<Url>().unwrap(),
+    ///                 },
+    ///                 None,
+    ///             )
+    ///             .unwrap(),
+    ///         ),
     ///     })),
     ///     Some(metadata.clone()),
     /// );
@@ -367,6 +494,58 @@ impl Sample {
         self.metadata.as_ref()
     }
 
+    /// Returns whether the [`Sample`] has metadata.
+    ///
+    /// A [`Sample`] whose metadata block is missing or is [effectively
+    /// empty](Metadata::is_effectively_empty) is considered to have no
+    /// metadata.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ccdi_cde as cde;
+    /// use ccdi_models as models;
+    ///
+    /// use models::metadata::field::unowned::sample::DiseasePhase;
+    /// use models::namespace;
+    /// use models::sample::metadata::Builder;
+    /// use models::Sample;
+    ///
+    /// let namespace = namespace::Identifier::new(
+    ///     "example-organization"
+    ///         .parse::<models::organization::Identifier>()
+    ///         .unwrap(),
+    ///     namespace::identifier::Name::try_new("ExampleNamespace").unwrap(),
+    /// );
+    ///
+    /// let id = models::sample::Identifier::new(namespace.clone(), "SampleName001");
+    /// let subject = models::subject::Identifier::new(namespace, "SubjectName001");
+    ///
+    /// let sample = Sample::new(id.clone(), subject.clone(), None, None);
+    /// assert!(!sample.has_metadata());
+    ///
+    /// let metadata = Builder::default().build();
+    /// let sample = Sample::new(id.clone(), subject.clone(), None, Some(metadata));
+    /// assert!(!sample.has_metadata());
+    ///
+    /// let metadata = Builder::default()
+    ///     .disease_phase(DiseasePhase::new(
+    ///         cde::v1::sample::DiseasePhase::InitialDiagnosis,
+    ///         None,
+    ///         None,
+    ///         None,
+    ///     ))
+    ///     .build();
+    /// let sample = Sample::new(id, subject, None, Some(metadata));
+    /// assert!(sample.has_metadata());
+    /// ```
+    pub fn has_metadata(&self) -> bool {
+        self.metadata
+            .as_ref()
+            .map(|metadata| !metadata.is_effectively_empty())
+            .unwrap_or(false)
+    }
+
     /// Gets the [gateway(s)](AnonymousOrReference) for the [`Sample`] (by reference).
     ///
     /// # Examples
@@ -379,6 +558,7 @@ impl Sample {
     /// use models::file::Metadata;
     /// use models::gateway::AnonymousOrReference;
     /// use models::gateway::Link;
+    /// use models::gateway::Open;
     /// use models::namespace;
     /// use models::organization;
     /// use models::sample;
@@ -421,11 +601,15 @@ impl Sample {
     ///     sample_id,
     ///     subject_id,
     ///     Some(NonEmpty::new(AnonymousOrReference::Anonymous {
-    ///         gateway: Gateway::Open {
-    ///             link: Link::Direct {
-    ///                 url: "https://example.com".parse::<Url>().unwrap(),
-    ///             },
-    ///         },
+    ///         gateway: Gateway::Open(
+    ///             Open::try_new(
+    ///                 Link::Direct {
+    ///                     url: "https://example.com".parse::<Url>().unwrap(),
+    ///                 },
+    ///                 None,
+    ///             )
+    ///             .unwrap(),
+    ///         ),
     ///     })),
     ///     Some(metadata.clone()),
     /// );
@@ -483,29 +667,213 @@ impl Sample {
     /// let sample = Sample::random(sample_id, subject_id);
     /// ```
     pub fn random(identifier: Identifier, subject: crate::subject::Identifier) -> Self {
-        let mut rng = thread_rng();
+        Self::random_with_metadata_less_fraction(
+            identifier,
+            subject,
+            DEFAULT_METADATA_LESS_FRACTION,
+        )
+    }
 
-        Self {
-            id: identifier.clone(),
+    /// Generates a random [`Sample`] based on a particular [`Identifier`] where
+    /// `metadata_less_fraction` is the probability that the generated
+    /// [`Sample`] has no metadata.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `metadata_less_fraction` is not within `0.0..=1.0`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ccdi_models as models;
+    ///
+    /// use models::namespace;
+    /// use models::Sample;
+    ///
+    /// let namespace = namespace::Identifier::new(
+    ///     "example-organization"
+    ///         .parse::<models::organization::Identifier>()
+    ///         .unwrap(),
+    ///     namespace::identifier::Name::try_new("ExampleNamespace").unwrap(),
+    /// );
+    ///
+    /// let id = models::sample::Identifier::new(namespace.clone(), "SampleName001");
+    /// let subject = models::subject::Identifier::new(namespace, "SubjectName001");
+    ///
+    /// let sample = Sample::random_with_metadata_less_fraction(id.clone(), subject.clone(), 1.0);
+    /// assert!(sample.metadata().is_none());
+    ///
+    /// let sample = Sample::random_with_metadata_less_fraction(id, subject, 0.0);
+    /// assert!(sample.has_metadata());
+    /// ```
+    pub fn random_with_metadata_less_fraction(
+        identifier: Identifier,
+        subject: crate::subject::Identifier,
+        metadata_less_fraction: f64,
+    ) -> Self {
+        Self::random_with_rng(
+            identifier,
+            subject,
+            metadata_less_fraction,
+            &mut thread_rng(),
+        )
+    }
+
+    /// Generates a random [`Sample`] based on a particular [`Identifier`] using
+    /// the provided random number generator, where `metadata_less_fraction` is
+    /// the probability that the generated [`Sample`] has no metadata.
+    ///
+    /// The same seed always generates the same [`Sample`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `metadata_less_fraction` is not within `0.0..=1.0`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ccdi_models as models;
+    /// use rand::rngs::StdRng;
+    /// use rand::SeedableRng as _;
+    ///
+    /// use models::namespace;
+    /// use models::Sample;
+    ///
+    /// let namespace = namespace::Identifier::new(
+    ///     "example-organization"
+    ///         .parse::<models::organization::Identifier>()
+    ///         .unwrap(),
+    ///     namespace::identifier::Name::try_new("ExampleNamespace").unwrap(),
+    /// );
+    ///
+    /// let id = models::sample::Identifier::new(namespace.clone(), "SampleName001");
+    /// let subject = models::subject::Identifier::new(namespace, "SubjectName001");
+    ///
+    /// let a = Sample::random_with_rng(
+    ///     id.clone(),
+    ///     subject.clone(),
+    ///     0.5,
+    ///     &mut StdRng::seed_from_u64(42),
+    /// );
+    /// let b = Sample::random_with_rng(id, subject, 0.5, &mut StdRng::seed_from_u64(42));
+    /// assert_eq!(a, b);
+    /// ```
+    pub fn random_with_rng<R: Rng + ?Sized>(
+        identifier: Identifier,
+        subject: crate::subject::Identifier,
+        metadata_less_fraction: f64,
+        rng: &mut R,
+    ) -> Self {
+        Self::random_with_generation(
+            identifier,
             subject,
-            metadata: match rng.gen_bool(0.7) {
-                true => Some(Metadata::random(identifier)),
-                false => None,
-            },
-            gateways: match rng.gen_bool(0.9) {
-                true => Some(NonEmpty::new(AnonymousOrReference::Anonymous {
-                    gateway: crate::Gateway::Open {
-                        link: Link::Direct {
-                            url: "https://example.com".parse::<Url>().unwrap(),
-                        },
-                    },
-                })),
-                false => Some(NonEmpty::new(AnonymousOrReference::Reference {
-                    gateway: String::from("gateway"),
-                })),
-            },
+            metadata_less_fraction,
+            Generation::Coherent,
+            rng,
+        )
+    }
+
+    /// Generates a random [`Sample`] based on a particular [`Identifier`] using
+    /// the provided random number generator, where `metadata_less_fraction` is
+    /// the probability that the generated [`Sample`] has no metadata and
+    /// `generation` is the strategy used to generate any metadata.
+    ///
+    /// The same seed always generates the same [`Sample`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `metadata_less_fraction` is not within `0.0..=1.0`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ccdi_models as models;
+    /// use rand::rngs::StdRng;
+    /// use rand::SeedableRng as _;
+    ///
+    /// use models::metadata::Generation;
+    /// use models::namespace;
+    /// use models::Sample;
+    ///
+    /// let namespace = namespace::Identifier::new(
+    ///     "example-organization"
+    ///         .parse::<models::organization::Identifier>()
+    ///         .unwrap(),
+    ///     namespace::identifier::Name::try_new("ExampleNamespace").unwrap(),
+    /// );
+    ///
+    /// let id = models::sample::Identifier::new(namespace.clone(), "SampleName001");
+    /// let subject = models::subject::Identifier::new(namespace, "SubjectName001");
+    ///
+    /// let sample = Sample::random_with_generation(
+    ///     id,
+    ///     subject,
+    ///     0.0,
+    ///     Generation::Independent,
+    ///     &mut StdRng::seed_from_u64(42),
+    /// );
+    /// assert!(sample.has_metadata());
+    /// ```
+    pub fn random_with_generation<R: Rng + ?Sized>(
+        identifier: Identifier,
+        subject: crate::subject::Identifier,
+        metadata_less_fraction: f64,
+        generation: Generation,
+        rng: &mut R,
+    ) -> Self {
+        let metadata = match rng.gen_bool(1.0 - metadata_less_fraction) {
+            true => Some(Metadata::random_with_generation(
+                identifier.clone(),
+                generation,
+                rng,
+            )),
+            false => None,
+        };
+
+        let gateways = match rng.gen_bool(0.9) {
+            true => Some(NonEmpty::new(AnonymousOrReference::Anonymous {
+                gateway: crate::Gateway::random_with_rng(rng),
+            })),
+            false => Some(NonEmpty::new(AnonymousOrReference::Reference {
+                gateway: String::from("gateway"),
+            })),
+        };
+
+        // SAFETY: randomly generated metadata always includes the primary
+        // identifier as its only linked identifier, so this always unwraps.
+        Self::try_new(identifier, subject, gateways, metadata).unwrap()
+    }
+}
+
+/// Checks that the identifiers within the metadata of a [`Sample`] include the
+/// primary identifier of the [`Sample`] (and do not contradict it).
+fn check_identifiers(primary: &Identifier, metadata: &Metadata) -> Result<(), Error> {
+    let identifiers = match metadata.identifiers() {
+        Some(identifiers) => identifiers,
+        None => return Ok(()),
+    };
+
+    let mut found = false;
+
+    for identifier in identifiers {
+        if let identifier::referenced::Identifier::Linked(linked) = identifier.value() {
+            let inner = linked.inner();
+
+            if inner == primary {
+                found = true;
+            } else if inner.namespace() == primary.namespace() {
+                return Err(Error::ConflictingIdentifier {
+                    primary: primary.clone(),
+                    conflicting: inner.clone(),
+                });
+            }
         }
     }
+
+    match found {
+        true => Ok(()),
+        false => Err(Error::MissingPrimaryIdentifier(primary.clone())),
+    }
 }
 
 impl Entity for Sample {}
@@ -534,6 +902,7 @@ mod tests {
     use crate::namespace;
     use crate::organization;
     use crate::Namespace;
+    use crate::Url;
 
     use super::*;
 
@@ -700,4 +1069,112 @@ mod tests {
 
         assert!(foo != bar);
     }
+
+    fn namespace_id(name: &str) -> namespace::Identifier {
+        namespace::Identifier::new(
+            organization::Identifier::try_new("example-organization").unwrap(),
+            namespace::identifier::Name::try_new(name).unwrap(),
+        )
+    }
+
+    fn subject_id() -> crate::subject::Identifier {
+        crate::subject::Identifier::new(namespace_id("ExampleNamespace"), "Subject1")
+    }
+
+    fn linked(id: Identifier) -> crate::metadata::field::unowned::sample::Identifier {
+        crate::metadata::field::unowned::sample::Identifier::new(
+            identifier::referenced::Identifier::Linked(identifier::linked::Identifier::new(
+                id,
+                "https://ccdi.example.com/api/v0".parse::<Url>().unwrap(),
+            )),
+            None,
+            None,
+            None,
+        )
+    }
+
+    #[test]
+    fn it_accepts_metadata_that_includes_the_primary_identifier() {
+        let id = Identifier::new(namespace_id("ExampleNamespace"), "Sample1");
+
+        // Linked identifiers within other namespaces and unlinked identifiers
+        // are allowed alongside the primary identifier.
+        let metadata = metadata::Builder::default()
+            .append_identifier(linked(Identifier::new(
+                namespace_id("OtherNamespace"),
+                "Sample2",
+            )))
+            .append_identifier(crate::metadata::field::unowned::sample::Identifier::new(
+                identifier::referenced::Identifier::Unlinked(
+                    identifier::unlinked::Identifier::from(String::from("Sample-ABC")),
+                ),
+                None,
+                None,
+                None,
+            ))
+            .append_identifier(linked(id.clone()))
+            .build();
+
+        Sample::try_new(id.clone(), subject_id(), None, Some(metadata)).unwrap();
+
+        // Metadata without any identifiers (and no metadata at all) is also
+        // allowed.
+        Sample::try_new(
+            id.clone(),
+            subject_id(),
+            None,
+            Some(metadata::Builder::default().build()),
+        )
+        .unwrap();
+        Sample::try_new(id, subject_id(), None, None).unwrap();
+    }
+
+    #[test]
+    fn it_rejects_metadata_without_the_primary_identifier() {
+        let id = Identifier::new(namespace_id("ExampleNamespace"), "Sample1");
+        let metadata = metadata::Builder::default()
+            .append_identifier(linked(Identifier::new(
+                namespace_id("OtherNamespace"),
+                "Sample1",
+            )))
+            .build();
+
+        let err = Sample::try_new(id.clone(), subject_id(), None, Some(metadata)).unwrap_err();
+
+        assert_eq!(err, Error::MissingPrimaryIdentifier(id));
+        assert_eq!(
+            err.to_string(),
+            "the identifiers within the metadata do not include the primary identifier: { \
+             namespace: { organization: example-organization, name: ExampleNamespace }, name: \
+             Sample1 }"
+        );
+    }
+
+    #[test]
+    fn it_rejects_metadata_with_a_conflicting_identifier() {
+        let id = Identifier::new(namespace_id("ExampleNamespace"), "Sample1");
+        let conflicting = Identifier::new(namespace_id("ExampleNamespace"), "Sample2");
+        let metadata = metadata::Builder::default()
+            .append_identifier(linked(id.clone()))
+            .append_identifier(linked(conflicting.clone()))
+            .build();
+
+        let err = Sample::try_new(id.clone(), subject_id(), None, Some(metadata)).unwrap_err();
+
+        assert_eq!(
+            err,
+            Error::ConflictingIdentifier {
+                primary: id,
+                conflicting,
+            }
+        );
+    }
+
+    #[test]
+    fn it_generates_random_samples_that_include_the_primary_identifier() {
+        let id = Identifier::new(namespace_id("ExampleNamespace"), "Sample1");
+        let sample = Sample::random_with_metadata_less_fraction(id.clone(), subject_id(), 0.0);
+
+        check_identifiers(&id, sample.metadata().unwrap()).unwrap();
+    }
 }
@@ -3,7 +3,7 @@
 use ordered_float::OrderedFloat;
 use rand::distributions::Alphanumeric;
 use rand::thread_rng;
-use rand::Rng as _;
+use rand::Rng;
 use serde::Deserialize;
 use serde::Serialize;
 use utoipa::ToSchema;
@@ -11,24 +11,31 @@ use utoipa::ToSchema;
 use crate::metadata::common;
 use crate::metadata::field;
 use crate::metadata::fields;
+use crate::metadata::Generation;
 use crate::sample::Identifier;
 
 mod age_at_collection;
 mod age_at_diagnosis;
 mod anatomical_site;
 pub mod builder;
-mod diagnosis;
+pub(crate) mod diagnosis;
+mod tumor_tissue_morphology;
+mod tumor_tissue_site;
 
 pub use age_at_collection::AgeAtCollection;
 pub use age_at_diagnosis::AgeAtDiagnosis;
 pub use anatomical_site::AnatomicalSite;
 pub use builder::Builder;
 pub use diagnosis::Diagnosis;
+pub use tumor_tissue_morphology::TumorTissueMorphology;
+pub use tumor_tissue_site::TumorTissueSite;
 
 /// Metadata associated with a sample.
 #[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize, ToSchema)]
 #[schema(as = models::sample::Metadata)]
 pub struct Metadata {
+    // NOTE: the declaration order of these fields is contractual (see the
+    // module-level documentation of `crate::metadata`).
     /// The approximate age at diagnosis.
     #[schema(value_type = field::unowned::sample::AgeAtDiagnosis, nullable = true)]
     age_at_diagnosis: Option<field::unowned::sample::AgeAtDiagnosis>,
@@ -100,6 +107,10 @@ pub struct Metadata {
     #[schema(value_type = Vec<field::unowned::sample::Identifier>, nullable = true)]
     identifiers: Option<Vec<field::unowned::sample::Identifier>>,
 
+    /// The ICD-O-3 topography code for the site of the tumor tissue.
+    #[schema(value_type = field::unowned::sample::TumorTissueSite, nullable = true)]
+    tumor_tissue_site: Option<field::unowned::sample::TumorTissueSite>,
+
     /// Common metadata elements for all metadata blocks.
     #[schema(value_type = models::metadata::common::Metadata)]
     #[serde(flatten)]
@@ -562,7 +573,7 @@ impl Metadata {
     ///
     /// let metadata = Builder::default()
     ///     .tumor_tissue_morphology(TumorTissueMorphology::new(
-    ///         cde::v1::sample::TumorTissueMorphology::from(String::from("8010/0")),
+    ///         models::sample::metadata::TumorTissueMorphology::try_new("8010/0").unwrap(),
     ///         None,
     ///         None,
     ///         None,
@@ -572,7 +583,7 @@ impl Metadata {
     /// assert_eq!(
     ///     metadata.tumor_tissue_morphology(),
     ///     Some(&TumorTissueMorphology::new(
-    ///         cde::v1::sample::TumorTissueMorphology::from(String::from("8010/0")),
+    ///         models::sample::metadata::TumorTissueMorphology::try_new("8010/0").unwrap(),
     ///         None,
     ///         None,
     ///         None
@@ -673,6 +684,31 @@ impl Metadata {
         self.identifiers.as_ref()
     }
 
+    /// Gets the harmonized tumor tissue site (ICD-O-3 topography) code for the
+    /// [`Metadata`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ccdi_models as models;
+    ///
+    /// use models::metadata::field::unowned::sample::TumorTissueSite;
+    /// use models::sample::metadata::Builder;
+    ///
+    /// let field = TumorTissueSite::new(
+    ///     models::sample::metadata::TumorTissueSite::try_new("C71.9").unwrap(),
+    ///     None,
+    ///     None,
+    ///     None,
+    /// );
+    /// let metadata = Builder::default().tumor_tissue_site(field.clone()).build();
+    ///
+    /// assert_eq!(metadata.tumor_tissue_site(), Some(&field));
+    /// ```
+    pub fn tumor_tissue_site(&self) -> Option<&field::unowned::sample::TumorTissueSite> {
+        self.tumor_tissue_site.as_ref()
+    }
+
     /// Gets the common metadata fields for the [`Metadata`].
     ///
     /// # Examples
@@ -717,7 +753,7 @@ impl Metadata {
     ///             None,
     ///             None,
     ///         )),
-    ///     )
+    ///     )?
     ///     .insert_unharmonized(
     ///         "owned",
     ///         UnharmonizedField::Owned(owned::Field::new(
@@ -727,7 +763,7 @@ impl Metadata {
     ///             None,
     ///             None,
     ///         )),
-    ///     )
+    ///     )?
     ///     .build();
     ///
     /// assert!(matches!(
@@ -745,6 +781,57 @@ impl Metadata {
         &self.unharmonized
     }
 
+    /// Returns whether the [`Metadata`] is effectively empty.
+    ///
+    /// A metadata block is effectively empty when every harmonized field
+    /// (including the common metadata fields) is `None` and there are no
+    /// unharmonized fields. For the purposes of filtering and summarizing, an
+    /// effectively empty metadata block is treated the same as a missing one.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ccdi_cde as cde;
+    /// use ccdi_models as models;
+    ///
+    /// use models::metadata::field::unowned::sample::DiseasePhase;
+    /// use models::sample::metadata::Builder;
+    ///
+    /// assert!(Builder::default().build().is_effectively_empty());
+    ///
+    /// let metadata = Builder::default()
+    ///     .disease_phase(DiseasePhase::new(
+    ///         cde::v1::sample::DiseasePhase::InitialDiagnosis,
+    ///         None,
+    ///         None,
+    ///         None,
+    ///     ))
+    ///     .build();
+    ///
+    /// assert!(!metadata.is_effectively_empty());
+    /// ```
+    pub fn is_effectively_empty(&self) -> bool {
+        self.age_at_diagnosis.is_none()
+            && self.anatomical_sites.is_none()
+            && self.diagnosis.is_none()
+            && self.diagnosis_category.is_none()
+            && self.disease_phase.is_none()
+            && self.library_selection_method.is_none()
+            && self.tissue_type.is_none()
+            && self.tumor_classification.is_none()
+            && self.tumor_tissue_morphology.is_none()
+            && self.age_at_collection.is_none()
+            && self.library_strategy.is_none()
+            && self.library_source_material.is_none()
+            && self.preservation_method.is_none()
+            && self.tumor_grade.is_none()
+            && self.specimen_molecular_analyte_type.is_none()
+            && self.identifiers.is_none()
+            && self.tumor_tissue_site.is_none()
+            && self.common.is_empty()
+            && self.unharmonized.is_empty()
+    }
+
     /// Generates a random [`Metadata`].
     ///
     /// # Examples
@@ -786,8 +873,109 @@ impl Metadata {
     /// let metadata = Metadata::random(sample_id);
     /// ```
     pub fn random(identifier: Identifier) -> Metadata {
-        let mut rng = thread_rng();
+        Self::random_with_rng(identifier, &mut thread_rng())
+    }
 
+    /// Generates a random [`Metadata`] based on a particular [`Identifier`]
+    /// using the provided random number generator.
+    ///
+    /// Related fields are generated jointly so that the metadata is realistic
+    /// (see [`Builder::random_coherent()`]).
+    ///
+    /// The same seed always generates the same [`Metadata`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ccdi_models as models;
+    /// use rand::rngs::StdRng;
+    /// use rand::SeedableRng as _;
+    ///
+    /// use models::namespace;
+    /// use models::sample::Metadata;
+    ///
+    /// let namespace = namespace::Identifier::new(
+    ///     "example-organization"
+    ///         .parse::<models::organization::Identifier>()
+    ///         .unwrap(),
+    ///     namespace::identifier::Name::try_new("ExampleNamespace").unwrap(),
+    /// );
+    ///
+    /// let id = models::sample::Identifier::new(namespace, "SampleName001");
+    ///
+    /// assert_eq!(
+    ///     Metadata::random_with_rng(id.clone(), &mut StdRng::seed_from_u64(42)),
+    ///     Metadata::random_with_rng(id, &mut StdRng::seed_from_u64(42))
+    /// );
+    /// ```
+    pub fn random_with_rng<R: Rng + ?Sized>(identifier: Identifier, rng: &mut R) -> Metadata {
+        Self::random_with_generation(identifier, Generation::Coherent, rng)
+    }
+
+    /// Generates a random [`Metadata`] based on a particular [`Identifier`]
+    /// using the provided random number generator and [`Generation`] strategy.
+    ///
+    /// The same seed always generates the same [`Metadata`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ccdi_models as models;
+    /// use rand::rngs::StdRng;
+    /// use rand::SeedableRng as _;
+    ///
+    /// use models::metadata::Generation;
+    /// use models::namespace;
+    /// use models::sample::Metadata;
+    ///
+    /// let namespace = namespace::Identifier::new(
+    ///     "example-organization"
+    ///         .parse::<models::organization::Identifier>()
+    ///         .unwrap(),
+    ///     namespace::identifier::Name::try_new("ExampleNamespace").unwrap(),
+    /// );
+    ///
+    /// let id = models::sample::Identifier::new(namespace, "SampleName001");
+    ///
+    /// assert_eq!(
+    ///     Metadata::random_with_generation(
+    ///         id.clone(),
+    ///         Generation::Independent,
+    ///         &mut StdRng::seed_from_u64(42)
+    ///     ),
+    ///     Metadata::random_with_generation(
+    ///         id,
+    ///         Generation::Independent,
+    ///         &mut StdRng::seed_from_u64(42)
+    ///     )
+    /// );
+    /// ```
+    pub fn random_with_generation<R: Rng + ?Sized>(
+        identifier: Identifier,
+        generation: Generation,
+        rng: &mut R,
+    ) -> Metadata {
+        match generation {
+            Generation::Coherent => {
+                let builder = Builder::random_coherent(rng);
+
+                random_identifiers(identifier, rng)
+                    .into_iter()
+                    .fold(builder, |builder, identifier| {
+                        builder.append_identifier(identifier)
+                    })
+                    .build()
+            }
+            Generation::Independent => Self::random_independent_with_rng(identifier, rng),
+        }
+    }
+
+    /// Generates a random [`Metadata`] where every field is generated
+    /// independently of every other field.
+    fn random_independent_with_rng<R: Rng + ?Sized>(
+        identifier: Identifier,
+        rng: &mut R,
+    ) -> Metadata {
         Metadata {
             age_at_diagnosis: Some(field::unowned::sample::AgeAtDiagnosis::new(
                 crate::sample::metadata::AgeAtDiagnosis::from(OrderedFloat(365.25)),
@@ -810,63 +998,68 @@ impl Metadata {
                 None,
                 None,
             )),
-            diagnosis_category: rand::random(),
-            disease_phase: rand::random(),
-            library_selection_method: rand::random(),
-            library_strategy: rand::random(),
-            library_source_material: rand::random(),
-            preservation_method: rand::random(),
-            tumor_grade: rand::random(),
-            specimen_molecular_analyte_type: rand::random(),
-            tissue_type: rand::random(),
-            tumor_classification: rand::random(),
-            tumor_tissue_morphology: Some(field::unowned::sample::TumorTissueMorphology::new(
-                // "8000/0" is the ICD-O-3 code for a "Neoplasm".
-                ccdi_cde::v1::sample::TumorTissueMorphology::from(String::from("8000/0")),
-                None,
-                None,
-                None,
-            )),
+            diagnosis_category: rng.gen(),
+            disease_phase: rng.gen(),
+            library_selection_method: rng.gen(),
+            library_strategy: rng.gen(),
+            library_source_material: rng.gen(),
+            preservation_method: rng.gen(),
+            tumor_grade: rng.gen(),
+            specimen_molecular_analyte_type: rng.gen(),
+            tissue_type: rng.gen(),
+            tumor_classification: rng.gen(),
+            tumor_tissue_morphology: rng.gen(),
             age_at_collection: Some(field::unowned::sample::AgeAtCollection::new(
                 crate::sample::metadata::AgeAtCollection::from(OrderedFloat(365.25)),
                 None,
                 None,
                 None,
             )),
-            identifiers: Some(vec![
-                field::unowned::sample::Identifier::new(
-                    crate::sample::identifier::referenced::Identifier::Linked(
-                        crate::sample::identifier::linked::Identifier::new(
-                            identifier.clone(),
-                            "https://ccdi.example.com/api/v0"
-                                .parse::<crate::Url>()
-                                .unwrap(),
-                        ),
-                    ),
-                    None,
-                    None,
-                    None,
-                ),
-                field::unowned::sample::Identifier::new(
-                    crate::sample::identifier::referenced::Identifier::Unlinked(
-                        crate::sample::identifier::unlinked::Identifier::from(format!(
-                            "Sample-{}",
-                            (0..8)
-                                .map(|_| rng.sample(Alphanumeric).to_ascii_uppercase() as char)
-                                .collect::<String>()
-                        )),
-                    ),
-                    None,
-                    None,
-                    None,
-                ),
-            ]),
+            identifiers: Some(random_identifiers(identifier, rng)),
+            tumor_tissue_site: rng.gen(),
             unharmonized: Default::default(),
             common: Default::default(),
         }
     }
 }
 
+/// Generates the identifiers for randomly generated [`Metadata`]: a linked
+/// identifier referring to the primary `identifier` and a random unlinked
+/// identifier.
+fn random_identifiers<R: Rng + ?Sized>(
+    identifier: Identifier,
+    rng: &mut R,
+) -> Vec<field::unowned::sample::Identifier> {
+    vec![
+        field::unowned::sample::Identifier::new(
+            crate::sample::identifier::referenced::Identifier::Linked(
+                crate::sample::identifier::linked::Identifier::new(
+                    identifier,
+                    "https://ccdi.example.com/api/v0"
+                        .parse::<crate::Url>()
+                        .unwrap(),
+                ),
+            ),
+            None,
+            None,
+            None,
+        ),
+        field::unowned::sample::Identifier::new(
+            crate::sample::identifier::referenced::Identifier::Unlinked(
+                crate::sample::identifier::unlinked::Identifier::from(format!(
+                    "Sample-{}",
+                    (0..8)
+                        .map(|_| rng.sample(Alphanumeric).to_ascii_uppercase() as char)
+                        .collect::<String>()
+                )),
+            ),
+            None,
+            None,
+            None,
+        ),
+    ]
+}
+
 #[cfg(test)]
 mod tests {
     use crate::sample::metadata::builder;
@@ -876,7 +1069,7 @@ mod tests {
         let metadata = builder::Builder::default().build();
         assert_eq!(
             &serde_json::to_string(&metadata).unwrap(),
-            "{\"age_at_diagnosis\":null,\"anatomical_sites\":null,\"diagnosis\":null,\"diagnosis_category\":null,\"disease_phase\":null,\"library_selection_method\":null,\"tissue_type\":null,\"tumor_classification\":null,\"tumor_tissue_morphology\":null,\"age_at_collection\":null,\"library_strategy\":null,\"library_source_material\":null,\"preservation_method\":null,\"tumor_grade\":null,\"specimen_molecular_analyte_type\":null,\"identifiers\":null,\"depositions\":null}"
+            "{\"age_at_diagnosis\":null,\"anatomical_sites\":null,\"diagnosis\":null,\"diagnosis_category\":null,\"disease_phase\":null,\"library_selection_method\":null,\"tissue_type\":null,\"tumor_classification\":null,\"tumor_tissue_morphology\":null,\"age_at_collection\":null,\"library_strategy\":null,\"library_source_material\":null,\"preservation_method\":null,\"tumor_grade\":null,\"specimen_molecular_analyte_type\":null,\"identifiers\":null,\"tumor_tissue_site\":null,\"depositions\":null}"
         );
     }
 }
@@ -1,9 +1,13 @@
+use std::ops::Deref;
+
 use introspect::Introspect;
 use ordered_float::OrderedFloat;
 use serde::Deserialize;
 use serde::Serialize;
 use utoipa::ToSchema;
 
+use crate::metadata::common::age::Unit;
+
 /// The approximate age of collection in days.
 ///
 /// * When the age at collection is collected by the source server in days, the
@@ -11,11 +15,86 @@ use utoipa::ToSchema;
 /// * When the age at collection is collected by the source server in years, the
 ///   number of years is multiplied by 365.25 to arrive at an approximate number
 ///   of days.
+///
+/// The age is always stored in days. It may be deserialized from either a bare
+/// number of days (the legacy form) or an object with a `value` and a `unit`
+/// (e.g., `{ "value": 5.2, "unit": "years" }`). See
+/// [`age`](crate::metadata::common::age) for how it is serialized.
 #[derive(
     Clone, Debug, Deserialize, Eq, Introspect, Ord, PartialEq, PartialOrd, Serialize, ToSchema,
 )]
 #[schema(as = models::sample::metadata::AgeAtCollection, value_type = f32)]
-pub struct AgeAtCollection(OrderedFloat<f32>);
+pub struct AgeAtCollection(#[serde(with = "crate::metadata::common::age")] OrderedFloat<f32>);
+
+impl AgeAtCollection {
+    /// Creates a new [`AgeAtCollection`] from a value in the provided [`Unit`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ccdi_models as models;
+    ///
+    /// use models::metadata::common::age::Unit;
+    /// use models::sample::metadata::AgeAtCollection;
+    ///
+    /// let age = AgeAtCollection::from_unit(24.0, Unit::Months);
+    /// assert_eq!(age.as_days(), 730.5);
+    /// ```
+    pub fn from_unit(value: f32, unit: Unit) -> Self {
+        Self(OrderedFloat(unit.to_days(value)))
+    }
+
+    /// Creates a new [`AgeAtCollection`] from a number of years.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ccdi_models as models;
+    ///
+    /// use models::sample::metadata::AgeAtCollection;
+    ///
+    /// let age = AgeAtCollection::from_years(2.0);
+    /// assert_eq!(age.as_days(), 730.5);
+    /// ```
+    pub fn from_years(years: f32) -> Self {
+        Self::from_unit(years, Unit::Years)
+    }
+
+    /// Gets the age in days.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ccdi_models as models;
+    /// use ordered_float::OrderedFloat;
+    ///
+    /// use models::sample::metadata::AgeAtCollection;
+    ///
+    /// let age = AgeAtCollection::from(OrderedFloat(365.25));
+    /// assert_eq!(age.as_days(), 365.25);
+    /// ```
+    pub fn as_days(&self) -> f32 {
+        self.0.into_inner()
+    }
+
+    /// Gets the age in the provided [`Unit`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ccdi_models as models;
+    ///
+    /// use models::metadata::common::age::Unit;
+    /// use models::sample::metadata::AgeAtCollection;
+    ///
+    /// let age = AgeAtCollection::from_years(2.0);
+    /// assert_eq!(age.as_unit(Unit::Years), 2.0);
+    /// assert_eq!(age.as_unit(Unit::Months), 24.0);
+    /// ```
+    pub fn as_unit(&self, unit: Unit) -> f32 {
+        self.as_days() / unit.days()
+    }
+}
 
 impl From<OrderedFloat<f32>> for AgeAtCollection {
     fn from(value: OrderedFloat<f32>) -> Self {
@@ -23,6 +102,14 @@ impl From<OrderedFloat<f32>> for AgeAtCollection {
     }
 }
 
+impl Deref for AgeAtCollection {
+    type Target = OrderedFloat<f32>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
 impl std::fmt::Display for AgeAtCollection {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.0)
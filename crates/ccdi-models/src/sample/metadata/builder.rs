@@ -1,10 +1,35 @@
 //! A builder for [`Metadata`].
 
+use ccdi_cde as cde;
+use lazy_static::lazy_static;
+use ordered_float::OrderedFloat;
+use rand::Rng;
+
 use crate::metadata::common;
 use crate::metadata::field;
 use crate::metadata::fields;
+use crate::metadata::harmonized_keys;
+use crate::metadata::validate_unharmonized_keys;
+use crate::metadata::UnharmonizedKeyError;
+use crate::sample::metadata::diagnosis;
+use crate::sample::metadata::AgeAtCollection;
+use crate::sample::metadata::AgeAtDiagnosis;
+use crate::sample::metadata::AnatomicalSite;
 use crate::sample::Metadata;
 
+/// The maximum age at diagnosis (in days) of randomly generated metadata.
+const MAX_RANDOM_AGE_AT_DIAGNOSIS: u32 = 18 * 365;
+
+/// The maximum number of days between diagnosis and collection within randomly
+/// generated metadata.
+const MAX_RANDOM_DAYS_FROM_DIAGNOSIS_TO_COLLECTION: u32 = 5 * 365;
+
+lazy_static! {
+    /// The keys of the harmonized fields of a sample (which unharmonized keys
+    /// must not shadow).
+    static ref HARMONIZED_KEYS: Vec<String> = harmonized_keys(&Builder::default().build());
+}
+
 /// A builder for [`Metadata`].
 #[derive(Clone, Debug, Default)]
 pub struct Builder {
@@ -57,6 +82,9 @@ pub struct Builder {
     /// The alternate identifiers for the sample.
     identifiers: Option<Vec<field::unowned::sample::Identifier>>,
 
+    /// The tumor tissue site (ICD-O-3 topography) for this sample.
+    tumor_tissue_site: Option<field::unowned::sample::TumorTissueSite>,
+
     /// Common metadata elements for all metadata blocks.
     common: common::Metadata,
 
@@ -270,7 +298,7 @@ impl Builder {
     /// use models::sample::metadata::Builder;
     ///
     /// let field = TumorTissueMorphology::new(
-    ///     cde::v1::sample::TumorTissueMorphology::from(String::from("8010/0")),
+    ///     models::sample::metadata::TumorTissueMorphology::try_new("8010/0").unwrap(),
     ///     None,
     ///     None,
     ///     None,
@@ -485,6 +513,29 @@ impl Builder {
         self
     }
 
+    /// Sets the `tumor_tissue_site` field of the [`Builder`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ccdi_models as models;
+    ///
+    /// use models::metadata::field::unowned::sample::TumorTissueSite;
+    /// use models::sample::metadata::Builder;
+    ///
+    /// let field = TumorTissueSite::new(
+    ///     models::sample::metadata::TumorTissueSite::try_new("C71.9").unwrap(),
+    ///     None,
+    ///     None,
+    ///     None,
+    /// );
+    /// let builder = Builder::default().tumor_tissue_site(field);
+    /// ```
+    pub fn tumor_tissue_site(mut self, field: field::unowned::sample::TumorTissueSite) -> Self {
+        self.tumor_tissue_site = Some(field);
+        self
+    }
+
     /// Sets the common metadata for the [`Metadata`].
     ///
     /// # Examples
@@ -507,6 +558,9 @@ impl Builder {
     /// Inserts an [`UnharmonizedField`](field::UnharmonizedField) into the
     /// `unharmonized` map.
     ///
+    /// The key is validated exactly as the keys provided to
+    /// [`Builder::extend_unharmonized()`] are.
+    ///
     /// # Examples
     ///
     /// ```
@@ -529,7 +583,7 @@ impl Builder {
     ///             None,
     ///             None,
     ///         )),
-    ///     )
+    ///     )?
     ///     .insert_unharmonized(
     ///         "owned",
     ///         UnharmonizedField::Owned(owned::Field::new(
@@ -539,23 +593,163 @@ impl Builder {
     ///             None,
     ///             None,
     ///         )),
-    ///     );
+    ///     )?;
     ///
     /// # Ok::<(), Box<dyn std::error::Error>>(())
     /// ```
     pub fn insert_unharmonized<S: Into<String>>(
-        mut self,
+        self,
         key: S,
         field: field::UnharmonizedField,
-    ) -> Self {
-        let key = key.into();
+    ) -> Result<Self, UnharmonizedKeyError> {
+        self.extend_unharmonized([(key.into(), field)])
+    }
+
+    /// Inserts every [`UnharmonizedField`](field::UnharmonizedField) within
+    /// `fields` into the `unharmonized` map.
+    ///
+    /// Every key must follow the rules for harmonized keys (see
+    /// [`validate_harmonized_key()`](crate::metadata::validate_harmonized_key))
+    /// and must not be the name of a harmonized field of a sample (e.g.,
+    /// `diagnosis`). If any key is invalid, no fields are inserted and an
+    /// [`UnharmonizedKeyError`] identifying every offending key is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use serde_json::Value;
+    ///
+    /// use ccdi_models as models;
+    ///
+    /// use models::metadata::field::unowned;
+    /// use models::metadata::field::UnharmonizedField;
+    /// use models::sample::metadata::Builder;
+    ///
+    /// let field = |value: &str| {
+    ///     UnharmonizedField::Unowned(unowned::Field::new(
+    ///         Value::String(value.into()),
+    ///         None,
+    ///         None,
+    ///         None,
+    ///     ))
+    /// };
+    ///
+    /// let metadata = Builder::default()
+    ///     .extend_unharmonized([
+    ///         (String::from("enrollment_site"), field("Example Hospital")),
+    ///         (String::from("treatment_arm"), field("A")),
+    ///     ])?
+    ///     .build();
+    /// assert_eq!(metadata.unharmonized().inner().len(), 2);
+    ///
+    /// let err = Builder::default()
+    ///     .extend_unharmonized([
+    ///         (String::from("diagnosis"), field("A")),
+    ///         (String::from("Treatment Arm"), field("A")),
+    ///     ])
+    ///     .unwrap_err();
+    /// assert_eq!(err.keys().count(), 2);
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn extend_unharmonized<I>(mut self, fields: I) -> Result<Self, UnharmonizedKeyError>
+    where
+        I: IntoIterator<Item = (String, field::UnharmonizedField)>,
+    {
+        let fields = fields.into_iter().collect::<Vec<_>>();
 
-        let mut unharmonized = self.unharmonized;
-        unharmonized.inner_mut().insert(key, field);
+        validate_unharmonized_keys(fields.iter().map(|(key, _)| key.as_str()), &HARMONIZED_KEYS)?;
 
-        self.unharmonized = unharmonized;
+        self.unharmonized.inner_mut().extend(fields);
+        Ok(self)
+    }
 
-        self
+    /// Generates a [`Builder`] populated with random, but realistic, values
+    /// using the provided random number generator.
+    ///
+    /// Unlike generating every field independently, related fields are
+    /// generated jointly so that the resulting metadata is coherent:
+    ///
+    /// * the diagnosis is always present and is drawn along with the diagnosis
+    ///   category to which it belongs,
+    /// * the age at collection is never less than the age at diagnosis, and
+    /// * tumor-specific fields (the tumor classification, grade, morphology,
+    ///   and tissue site) are only populated for tumor tissue.
+    ///
+    /// No identifiers are populated.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ccdi_models as models;
+    /// use rand::rngs::StdRng;
+    /// use rand::SeedableRng as _;
+    ///
+    /// use models::sample::metadata::Builder;
+    ///
+    /// let metadata = Builder::random_coherent(&mut StdRng::seed_from_u64(42)).build();
+    /// assert!(metadata.diagnosis().is_some());
+    /// assert!(metadata.diagnosis_category().is_some());
+    /// ```
+    pub fn random_coherent<R: Rng + ?Sized>(rng: &mut R) -> Self {
+        let (category, diagnosis) = diagnosis::random_with_category(rng);
+
+        let age_at_diagnosis = rng.gen_range(0..=MAX_RANDOM_AGE_AT_DIAGNOSIS);
+        let age_at_collection =
+            age_at_diagnosis + rng.gen_range(0..=MAX_RANDOM_DAYS_FROM_DIAGNOSIS_TO_COLLECTION);
+
+        let tissue_type = rng.gen::<cde::v1::sample::TissueType>();
+        let is_tumor = tissue_type == cde::v1::sample::TissueType::Tumor;
+
+        // NOTE: the diagnosis is constructed directly (rather than generated
+        // with `rng.gen()`), so its provenance must be generated separately.
+        let (details, comment) = field::unowned::random_provenance(rng);
+
+        Self {
+            age_at_diagnosis: Some(field::unowned::sample::AgeAtDiagnosis::new(
+                AgeAtDiagnosis::from(OrderedFloat(age_at_diagnosis as f32)),
+                None,
+                None,
+                None,
+            )),
+            anatomical_sites: Some(vec![field::unowned::sample::AnatomicalSite::new(
+                AnatomicalSite::AnatomicalEntity,
+                None,
+                None,
+                None,
+            )]),
+            diagnosis: Some(field::unowned::sample::Diagnosis::new(
+                diagnosis, None, details, comment,
+            )),
+            diagnosis_category: Some(field::unowned::sample::DiagnosisCategory::new(
+                category, None, None, None,
+            )),
+            disease_phase: rng.gen(),
+            library_selection_method: rng.gen(),
+            tissue_type: Some(field::unowned::sample::TissueType::new(
+                tissue_type,
+                None,
+                None,
+                None,
+            )),
+            tumor_classification: if is_tumor { rng.gen() } else { None },
+            tumor_tissue_morphology: if is_tumor { rng.gen() } else { None },
+            age_at_collection: Some(field::unowned::sample::AgeAtCollection::new(
+                AgeAtCollection::from(OrderedFloat(age_at_collection as f32)),
+                None,
+                None,
+                None,
+            )),
+            library_strategy: rng.gen(),
+            library_source_material: rng.gen(),
+            preservation_method: rng.gen(),
+            tumor_grade: if is_tumor { rng.gen() } else { None },
+            specimen_molecular_analyte_type: rng.gen(),
+            identifiers: None,
+            tumor_tissue_site: if is_tumor { rng.gen() } else { None },
+            common: Default::default(),
+            unharmonized: Default::default(),
+        }
     }
 
     /// Consumes `self` to build a [`Metadata`].
@@ -587,8 +781,60 @@ impl Builder {
             tumor_classification: self.tumor_classification,
             tumor_tissue_morphology: self.tumor_tissue_morphology,
             identifiers: self.identifiers,
+            tumor_tissue_site: self.tumor_tissue_site,
             unharmonized: self.unharmonized,
             common: self.common,
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use rand::rngs::StdRng;
+    use rand::SeedableRng as _;
+
+    use super::*;
+
+    /// The number of records generated when checking the invariants of
+    /// randomly generated metadata.
+    const RECORDS: usize = 5_000;
+
+    #[test]
+    fn random_coherent_metadata_is_coherent() {
+        let mut rng = StdRng::seed_from_u64(0);
+
+        for _ in 0..RECORDS {
+            let metadata = Builder::random_coherent(&mut rng).build();
+
+            let diagnosis = metadata.diagnosis().unwrap().value();
+            let category = metadata.diagnosis_category().unwrap().value();
+            assert!(diagnosis::DIAGNOSES
+                .iter()
+                .any(|(c, d)| c == category && *d == diagnosis.as_str()));
+
+            assert!(
+                **metadata.age_at_collection().unwrap().value()
+                    >= **metadata.age_at_diagnosis().unwrap().value()
+            );
+
+            if metadata.tissue_type().unwrap().value() == &cde::v1::sample::TissueType::Normal {
+                assert!(metadata.tumor_classification().is_none());
+                assert!(metadata.tumor_grade().is_none());
+                assert!(metadata.tumor_tissue_morphology().is_none());
+                assert!(metadata.tumor_tissue_site().is_none());
+            }
+        }
+    }
+
+    #[test]
+    fn random_coherent_metadata_covers_the_tumor_fields() {
+        let mut rng = StdRng::seed_from_u64(0);
+
+        // NOTE: the tumor-specific fields should still be generated for tumor
+        // tissue, otherwise the invariant above holds trivially.
+        assert!((0..RECORDS).any(|_| {
+            let metadata = Builder::random_coherent(&mut rng).build();
+            metadata.tumor_grade().is_some() && metadata.tumor_tissue_site().is_some()
+        }));
+    }
+}
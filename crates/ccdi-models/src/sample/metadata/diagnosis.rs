@@ -1,11 +1,107 @@
 use std::ops::Deref;
 use std::ops::DerefMut;
 
+use ccdi_cde as cde;
 use introspect::Introspect;
+use rand::seq::SliceRandom as _;
+use rand::Rng;
 use serde::Deserialize;
 use serde::Serialize;
 use utoipa::ToSchema;
 
+/// A selection of diagnoses (and the diagnosis category each belongs to) used
+/// when generating realistic random metadata.
+pub(crate) const DIAGNOSES: &[(cde::v1::sample::DiagnosisCategory, &str)] = &[
+    (
+        cde::v1::sample::DiagnosisCategory::LymphoblasticLeukemia,
+        "Acute Lymphoblastic Leukemia",
+    ),
+    (
+        cde::v1::sample::DiagnosisCategory::LymphoblasticLeukemia,
+        "B Acute Lymphoblastic Leukemia",
+    ),
+    (
+        cde::v1::sample::DiagnosisCategory::MyeloidLeukemia,
+        "Acute Myeloid Leukemia",
+    ),
+    (
+        cde::v1::sample::DiagnosisCategory::HodgkinLymphoma,
+        "Classic Hodgkin Lymphoma",
+    ),
+    (
+        cde::v1::sample::DiagnosisCategory::NonHodgkinLymphoma,
+        "Burkitt Lymphoma",
+    ),
+    (
+        cde::v1::sample::DiagnosisCategory::Medulloblastoma,
+        "Medulloblastoma, SHH-Activated",
+    ),
+    (
+        cde::v1::sample::DiagnosisCategory::Ependymoma,
+        "Posterior Fossa Ependymoma",
+    ),
+    (
+        cde::v1::sample::DiagnosisCategory::HighGradeGlioma,
+        "Diffuse Midline Glioma, H3 K27-Altered",
+    ),
+    (
+        cde::v1::sample::DiagnosisCategory::LowGradeGliomas,
+        "Pilocytic Astrocytoma",
+    ),
+    (
+        cde::v1::sample::DiagnosisCategory::Neuroblastoma,
+        "Neuroblastoma",
+    ),
+    (
+        cde::v1::sample::DiagnosisCategory::Osteosarcoma,
+        "Conventional Osteosarcoma",
+    ),
+    (
+        cde::v1::sample::DiagnosisCategory::EwingsSarcoma,
+        "Ewing Sarcoma",
+    ),
+    (
+        cde::v1::sample::DiagnosisCategory::Rhabdomyosarcoma,
+        "Embryonal Rhabdomyosarcoma",
+    ),
+    (
+        cde::v1::sample::DiagnosisCategory::RenalTumors,
+        "Wilms Tumor",
+    ),
+    (
+        cde::v1::sample::DiagnosisCategory::LiverTumors,
+        "Hepatoblastoma",
+    ),
+    (
+        cde::v1::sample::DiagnosisCategory::Retinoblastoma,
+        "Retinoblastoma",
+    ),
+];
+
+/// Generates a random [`Diagnosis`] along with the diagnosis category that it
+/// belongs to.
+pub(crate) fn random_with_category<R: Rng + ?Sized>(
+    rng: &mut R,
+) -> (cde::v1::sample::DiagnosisCategory, Diagnosis) {
+    // SAFETY: the list of diagnoses is not empty, so this always unwraps.
+    let (category, diagnosis) = DIAGNOSES.choose(rng).unwrap();
+    (category.clone(), Diagnosis::from(diagnosis.to_string()))
+}
+
+/// Generates between one and `count` distinct random diagnoses along with the
+/// diagnosis category that each belongs to.
+pub(crate) fn random_many_with_category<R: Rng + ?Sized>(
+    count: usize,
+    rng: &mut R,
+) -> Vec<(cde::v1::sample::DiagnosisCategory, Diagnosis)> {
+    let count = rng.gen_range(1..=count);
+
+    DIAGNOSES
+        .choose_multiple(rng, count)
+        .map(|(category, diagnosis)| (category.clone(), Diagnosis::from(diagnosis.to_string())))
+        .collect()
+}
+
 /// The diagnosis for a [`Sample`](crate::Sample).
 ///
 /// This value can be any permissible diagnosis in v1.7.2 of the CCDI Submission
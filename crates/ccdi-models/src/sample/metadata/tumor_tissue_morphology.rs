@@ -0,0 +1,225 @@
+//! Tumor tissue morphology.
+
+use std::str::FromStr;
+
+use introspect::Introspect;
+use lazy_static::lazy_static;
+use rand::distributions::Distribution;
+use rand::distributions::Standard;
+use rand::seq::SliceRandom as _;
+use regex::Regex;
+use serde::Deserialize;
+use serde::Serialize;
+use utoipa::ToSchema;
+
+/// The pattern that an ICD-O-3 morphology code must match.
+pub const PATTERN: &str = r"^\d{4}(/\d)?$";
+
+lazy_static! {
+    // SAFETY: we test that this pattern compiles statically below.
+    static ref REGEX: Regex = Regex::new(PATTERN).unwrap();
+}
+
+/// The morphology codes from which random [`TumorTissueMorphology`]s are
+/// drawn.
+const RANDOM_CODES: &[&str] = &[
+    "8000/0", // Neoplasm, benign.
+    "8000/3", // Neoplasm, malignant.
+    "8010/0", // Epithelial tumor, benign.
+    "8010/3", // Carcinoma, NOS.
+    "8500/3", // Infiltrating duct carcinoma, NOS.
+    "8900/3", // Rhabdomyosarcoma, NOS.
+    "9180/3", // Osteosarcoma, NOS.
+    "9500/3", // Neuroblastoma, NOS.
+    "9510/3", // Retinoblastoma, NOS.
+    "9960/3", // Wilms tumor.
+];
+
+/// An error related to a [`TumorTissueMorphology`].
+#[derive(Debug)]
+pub enum Error {
+    /// Attempted to create a tumor tissue morphology from a value that is not
+    /// an ICD-O-3 morphology code.
+    Invalid(String),
+}
+
+impl std::fmt::Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::Invalid(value) => write!(
+                f,
+                "invalid ICD-O-3 morphology code `{value}`: the code must match the pattern \
+                 `{PATTERN}`"
+            ),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+/// A [`Result`](std::result::Result) with an [`Error`].
+type Result<T> = std::result::Result<T, Error>;
+
+/// The unvalidated form of a [`TumorTissueMorphology`] as it is deserialized.
+#[derive(Deserialize)]
+struct Unvalidated {
+    /// The ICD-O-3 morphology code.
+    icd_o_3: String,
+}
+
+/// The microscopic anatomy of the tumor tissue for a
+/// [`Sample`](crate::Sample) as captured in the morphology codes of the
+/// International Classification of Diseases for Oncology, 3rd Edition
+/// (ICD-O-3).
+///
+/// Morphology codes are made up of a four digit histology code optionally
+/// followed by a slash and a single behavior digit (e.g., `8000/3` for
+/// "Neoplasm, malignant" or `8000` when the behavior is not known).
+#[derive(
+    Clone, Debug, Deserialize, Eq, Introspect, Ord, PartialEq, PartialOrd, Serialize, ToSchema,
+)]
+#[serde(try_from = "Unvalidated")]
+#[schema(as = models::sample::metadata::TumorTissueMorphology)]
+pub struct TumorTissueMorphology {
+    /// The ICD-O-3 morphology code.
+    #[schema(pattern = r"^\d{4}(/\d)?$", example = "8000/3")]
+    icd_o_3: String,
+}
+
+impl TumorTissueMorphology {
+    /// Attempts to create a new [`TumorTissueMorphology`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ccdi_models as models;
+    ///
+    /// use models::sample::metadata::TumorTissueMorphology;
+    ///
+    /// assert!(TumorTissueMorphology::try_new("8000/3").is_ok());
+    /// assert!(TumorTissueMorphology::try_new("8000").is_ok());
+    /// assert!(TumorTissueMorphology::try_new("banana").is_err());
+    /// ```
+    pub fn try_new(value: impl Into<String>) -> Result<Self> {
+        let value = value.into();
+
+        if !REGEX.is_match(&value) {
+            return Err(Error::Invalid(value));
+        }
+
+        Ok(Self { icd_o_3: value })
+    }
+
+    /// Gets the ICD-O-3 morphology code of the [`TumorTissueMorphology`] (by
+    /// reference).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ccdi_models as models;
+    ///
+    /// use models::sample::metadata::TumorTissueMorphology;
+    ///
+    /// let morphology = TumorTissueMorphology::try_new("8000/3").unwrap();
+    /// assert_eq!(morphology.icd_o_3(), "8000/3");
+    /// ```
+    pub fn icd_o_3(&self) -> &str {
+        &self.icd_o_3
+    }
+}
+
+impl TryFrom<Unvalidated> for TumorTissueMorphology {
+    type Error = Error;
+
+    fn try_from(value: Unvalidated) -> Result<Self> {
+        Self::try_new(value.icd_o_3)
+    }
+}
+
+impl FromStr for TumorTissueMorphology {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        Self::try_new(s)
+    }
+}
+
+impl Distribution<TumorTissueMorphology> for Standard {
+    fn sample<R: rand::Rng + ?Sized>(&self, rng: &mut R) -> TumorTissueMorphology {
+        // SAFETY: the list of codes is not empty and every code is valid (both
+        // are tested below).
+        TumorTissueMorphology::try_new(*RANDOM_CODES.choose(rng).unwrap()).unwrap()
+    }
+}
+
+impl std::fmt::Display for TumorTissueMorphology {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.icd_o_3)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn the_pattern_compiles_and_matches() {
+        assert!(REGEX.is_match("8000/0"));
+    }
+
+    #[test]
+    fn it_accepts_codes_with_and_without_a_behavior() {
+        assert_eq!(
+            "8000/3".parse::<TumorTissueMorphology>().unwrap().icd_o_3(),
+            "8000/3"
+        );
+        assert_eq!(
+            "9500".parse::<TumorTissueMorphology>().unwrap().icd_o_3(),
+            "9500"
+        );
+    }
+
+    #[test]
+    fn it_rejects_malformed_codes() {
+        for value in [
+            "", "banana", "800", "80000", "8000/", "8000/33", "8000-3", "8000/a", " 8000/3",
+            "C71.9",
+        ] {
+            let err = value.parse::<TumorTissueMorphology>().unwrap_err();
+            assert!(matches!(err, Error::Invalid(_)), "{value}");
+            assert!(err.to_string().contains(PATTERN), "{value}");
+        }
+    }
+
+    #[test]
+    fn it_validates_when_deserializing() {
+        for value in ["8010/0", "8010"] {
+            let json = format!(r#"{{"icd_o_3":"{value}"}}"#);
+
+            let morphology = serde_json::from_str::<TumorTissueMorphology>(&json).unwrap();
+            assert_eq!(morphology.icd_o_3(), value);
+            assert_eq!(serde_json::to_string(&morphology).unwrap(), json);
+        }
+
+        for value in ["banana", "8010/", "8010/00", "801/0"] {
+            let err = serde_json::from_str::<TumorTissueMorphology>(&format!(
+                r#"{{"icd_o_3":"{value}"}}"#
+            ))
+            .unwrap_err();
+            assert!(err.to_string().contains(PATTERN), "{value}");
+        }
+    }
+
+    #[test]
+    fn every_random_code_is_valid() {
+        assert!(!RANDOM_CODES.is_empty());
+
+        for code in RANDOM_CODES {
+            TumorTissueMorphology::try_new(*code).unwrap();
+        }
+
+        for _ in 0..1000 {
+            let _: TumorTissueMorphology = rand::random();
+        }
+    }
+}
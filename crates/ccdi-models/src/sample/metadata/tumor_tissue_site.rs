@@ -0,0 +1,211 @@
+//! Tumor tissue site.
+
+use std::str::FromStr;
+
+use introspect::Introspect;
+use lazy_static::lazy_static;
+use rand::distributions::Distribution;
+use rand::distributions::Standard;
+use rand::seq::SliceRandom as _;
+use regex::Regex;
+use serde::Deserialize;
+use serde::Serialize;
+use utoipa::ToSchema;
+
+/// The pattern that an ICD-O-3 topography code must match.
+pub const PATTERN: &str = r"^C\d{2}(\.\d)?$";
+
+lazy_static! {
+    // SAFETY: we test that this pattern compiles statically below.
+    static ref REGEX: Regex = Regex::new(PATTERN).unwrap();
+}
+
+/// The topography codes from which random [`TumorTissueSite`]s are drawn.
+const RANDOM_CODES: &[&str] = &[
+    "C71.9", // Brain, NOS.
+    "C22.0", // Liver.
+    "C40.2", // Long bones of lower limb and associated joints.
+    "C64.9", // Kidney, NOS.
+    "C69.2", // Retina.
+    "C74.9", // Adrenal gland, NOS.
+    "C49.9", // Connective, subcutaneous and other soft tissues, NOS.
+    "C42.1", // Bone marrow.
+];
+
+/// An error related to a [`TumorTissueSite`].
+#[derive(Debug)]
+pub enum Error {
+    /// Attempted to create a tumor tissue site from a value that is not an
+    /// ICD-O-3 topography code.
+    Invalid(String),
+}
+
+impl std::fmt::Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::Invalid(value) => write!(
+                f,
+                "invalid ICD-O-3 topography code `{value}`: the code must match the pattern \
+                 `{PATTERN}`"
+            ),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+/// A [`Result`](std::result::Result) with an [`Error`].
+type Result<T> = std::result::Result<T, Error>;
+
+/// The unvalidated form of a [`TumorTissueSite`] as it is deserialized.
+#[derive(Deserialize)]
+struct Unvalidated {
+    /// The ICD-O-3 topography code.
+    icd_o_3: String,
+}
+
+/// The site of the tumor tissue for a [`Sample`](crate::Sample) as captured in
+/// the topography codes of the International Classification of Diseases for
+/// Oncology, 3rd Edition (ICD-O-3).
+///
+/// Topography codes are of the form `Cxx` or `Cxx.x` (e.g., `C71.9` for
+/// "Brain, NOS") and complement the morphology code captured within the
+/// `tumor_tissue_morphology` field.
+#[derive(
+    Clone, Debug, Deserialize, Eq, Introspect, Ord, PartialEq, PartialOrd, Serialize, ToSchema,
+)]
+#[serde(try_from = "Unvalidated")]
+#[schema(as = models::sample::metadata::TumorTissueSite)]
+pub struct TumorTissueSite {
+    /// The ICD-O-3 topography code.
+    #[schema(pattern = r"^C\d{2}(\.\d)?$", example = "C71.9")]
+    icd_o_3: String,
+}
+
+impl TumorTissueSite {
+    /// Attempts to create a new [`TumorTissueSite`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ccdi_models as models;
+    ///
+    /// use models::sample::metadata::TumorTissueSite;
+    ///
+    /// assert!(TumorTissueSite::try_new("C71.9").is_ok());
+    /// assert!(TumorTissueSite::try_new("C71").is_ok());
+    /// assert!(TumorTissueSite::try_new("71.9").is_err());
+    /// ```
+    pub fn try_new(value: impl Into<String>) -> Result<Self> {
+        let value = value.into();
+
+        if !REGEX.is_match(&value) {
+            return Err(Error::Invalid(value));
+        }
+
+        Ok(Self { icd_o_3: value })
+    }
+
+    /// Gets the ICD-O-3 topography code of the [`TumorTissueSite`] (by
+    /// reference).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ccdi_models as models;
+    ///
+    /// use models::sample::metadata::TumorTissueSite;
+    ///
+    /// let site = TumorTissueSite::try_new("C71.9").unwrap();
+    /// assert_eq!(site.icd_o_3(), "C71.9");
+    /// ```
+    pub fn icd_o_3(&self) -> &str {
+        &self.icd_o_3
+    }
+}
+
+impl TryFrom<Unvalidated> for TumorTissueSite {
+    type Error = Error;
+
+    fn try_from(value: Unvalidated) -> Result<Self> {
+        Self::try_new(value.icd_o_3)
+    }
+}
+
+impl FromStr for TumorTissueSite {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        Self::try_new(s)
+    }
+}
+
+impl Distribution<TumorTissueSite> for Standard {
+    fn sample<R: rand::Rng + ?Sized>(&self, rng: &mut R) -> TumorTissueSite {
+        // SAFETY: the list of codes is not empty and every code is valid (both
+        // are tested below).
+        TumorTissueSite::try_new(*RANDOM_CODES.choose(rng).unwrap()).unwrap()
+    }
+}
+
+impl std::fmt::Display for TumorTissueSite {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.icd_o_3)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn the_pattern_compiles_and_matches() {
+        assert!(REGEX.is_match("C71.9"));
+    }
+
+    #[test]
+    fn it_accepts_codes_with_and_without_a_subsite() {
+        assert_eq!(
+            "C71.9".parse::<TumorTissueSite>().unwrap().icd_o_3(),
+            "C71.9"
+        );
+        assert_eq!("C42".parse::<TumorTissueSite>().unwrap().icd_o_3(), "C42");
+    }
+
+    #[test]
+    fn it_rejects_malformed_codes() {
+        for value in [
+            "", "C7", "C719", "C71.", "C71.10", "c71.9", "D71.9", "71.9", " C71.9", "8010/0",
+        ] {
+            let err = value.parse::<TumorTissueSite>().unwrap_err();
+            assert!(matches!(err, Error::Invalid(_)), "{value}");
+            assert!(err.to_string().contains(PATTERN), "{value}");
+        }
+    }
+
+    #[test]
+    fn it_validates_when_deserializing() {
+        let site = serde_json::from_str::<TumorTissueSite>(r#"{"icd_o_3":"C22.0"}"#).unwrap();
+        assert_eq!(site.icd_o_3(), "C22.0");
+        assert_eq!(
+            serde_json::to_string(&site).unwrap(),
+            r#"{"icd_o_3":"C22.0"}"#
+        );
+
+        let err = serde_json::from_str::<TumorTissueSite>(r#"{"icd_o_3":"C22.00"}"#).unwrap_err();
+        assert!(err.to_string().contains(PATTERN));
+    }
+
+    #[test]
+    fn every_random_code_is_valid() {
+        assert!(!RANDOM_CODES.is_empty());
+
+        for code in RANDOM_CODES {
+            TumorTissueSite::try_new(*code).unwrap();
+        }
+
+        for _ in 0..1000 {
+            let _: TumorTissueSite = rand::random();
+        }
+    }
+}
@@ -17,9 +17,51 @@ pub use metadata::Metadata;
 
 use crate::gateway;
 use crate::gateway::AnonymousOrReference;
-use crate::gateway::Link;
+use crate::metadata::Generation;
+use crate::metadata::DEFAULT_METADATA_LESS_FRACTION;
 use crate::Entity;
-use crate::Url;
+
+/// An error related to a [`Subject`].
+#[derive(Debug, Eq, PartialEq)]
+pub enum Error {
+    /// The identifiers within the metadata of the subject do not include the
+    /// primary identifier of the subject.
+    MissingPrimaryIdentifier(Identifier),
+
+    /// The identifiers within the metadata of the subject include a linked
+    /// identifier within the namespace of the subject that is not the primary
+    /// identifier (the name of a subject is unique within its namespace, so
+    /// the two identifiers contradict one another).
+    ConflictingIdentifier {
+        /// The primary identifier of the subject.
+        primary: Identifier,
+
+        /// The identifier that contradicts the primary identifier.
+        conflicting: Identifier,
+    },
+}
+
+impl std::fmt::Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::MissingPrimaryIdentifier(primary) => write!(
+                f,
+                "the identifiers within the metadata do not include the primary identifier: \
+                 {primary}"
+            ),
+            Error::ConflictingIdentifier {
+                primary,
+                conflicting,
+            } => write!(
+                f,
+                "the identifiers within the metadata include {conflicting}, which \
+                 contradicts the primary identifier: {primary}"
+            ),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
 
 /// A subject.
 #[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize, ToSchema)]
@@ -91,6 +133,7 @@ impl Subject {
     ///
     /// use models::gateway::AnonymousOrReference;
     /// use models::gateway::Link;
+    /// use models::gateway::Open;
     /// use models::metadata::field::unowned::subject::Identifier;
     /// use models::namespace;
     /// use models::organization;
@@ -131,11 +174,15 @@ impl Subject {
     ///     subject_id,
     ///     Kind::Participant,
     ///     Some(NonEmpty::new(AnonymousOrReference::Anonymous {
-    ///         gateway: Gateway::Open {
-    ///             link: Link::Direct {
-    ///                 url: "https://example.com".parse::<Url>().unwrap(),
-    ///             },
-    ///         },
+    ///         gateway: Gateway::Open(
+    ///             Open::try_new(
+    ///                 Link::Direct {
+    ///                     url: "https://example.com".parse::<Url>().unwrap(),
+    ///                 },
+    ///                 None,
+    ///             )
+    ///             .unwrap(),
+    ///         ),
     ///     })),
     ///     Some(Builder::default().build()),
     /// );
@@ -156,6 +203,71 @@ impl Subject {
         }
     }
 
+    /// Attempts to create a new [`Subject`].
+    ///
+    /// The identifiers within the metadata of a subject (if there are any)
+    /// *must* include the primary identifier of the subject as a [linked
+    /// identifier](identifier::linked::Identifier). An error is returned if
+    /// they do not, or if they include a linked identifier within the same
+    /// namespace that is not the primary identifier.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ccdi_models as models;
+    ///
+    /// use models::metadata::field::unowned::subject::Identifier;
+    /// use models::namespace;
+    /// use models::subject::identifier::linked;
+    /// use models::subject::identifier::referenced;
+    /// use models::subject::metadata::Builder;
+    /// use models::subject::Kind;
+    /// use models::Subject;
+    ///
+    /// let namespace = namespace::Identifier::new(
+    ///     "example-organization"
+    ///         .parse::<models::organization::Identifier>()
+    ///         .unwrap(),
+    ///     namespace::identifier::Name::try_new("ExampleNamespace").unwrap(),
+    /// );
+    ///
+    /// let id = models::subject::Identifier::new(namespace.clone(), "SubjectName001");
+    /// let linked = |id: models::subject::Identifier| {
+    ///     Identifier::new(
+    ///         referenced::Identifier::Linked(linked::Identifier::new(
+    ///             id,
+    ///             "https://ccdi.example.com/api/v0"
+    ///                 .parse::<models::Url>()
+    ///                 .unwrap(),
+    ///         )),
+    ///         None,
+    ///         None,
+    ///         None,
+    ///     )
+    /// };
+    ///
+    /// let metadata = Builder::default()
+    ///     .append_identifier(linked(id.clone()))
+    ///     .build();
+    /// assert!(Subject::try_new(id.clone(), Kind::Participant, None, Some(metadata)).is_ok());
+    ///
+    /// let other = models::subject::Identifier::new(namespace, "SubjectName002");
+    /// let metadata = Builder::default().append_identifier(linked(other)).build();
+    /// assert!(Subject::try_new(id, Kind::Participant, None, Some(metadata)).is_err());
+    /// ```
+    pub fn try_new(
+        id: Identifier,
+        kind: Kind,
+        gateways: Option<NonEmpty<gateway::AnonymousOrReference>>,
+        metadata: Option<Metadata>,
+    ) -> Result<Self, Error> {
+        if let Some(metadata) = &metadata {
+            check_identifiers(&id, metadata)?;
+        }
+
+        Ok(Self::new(id, kind, gateways, metadata))
+    }
+
     /// Gets the identifier for this [`Subject`] by reference.
     ///
     /// # Examples
@@ -165,6 +277,7 @@ impl Subject {
     ///
     /// use models::gateway::AnonymousOrReference;
     /// use models::gateway::Link;
+    /// use models::gateway::Open;
     /// use models::metadata::field::unowned::subject::Identifier;
     /// use models::namespace;
     /// use models::organization;
@@ -205,11 +318,15 @@ impl Subject {
     ///     subject_id,
     ///     Kind::Participant,
     ///     Some(NonEmpty::new(AnonymousOrReference::Anonymous {
-    ///         gateway: Gateway::Open {
-    ///             link: Link::Direct {
-    ///                 url: "https://example.com".parse::<Url>().unwrap(),
-    ///             },
-    ///         },
+    ///         gateway: Gateway::Open(
+    ///             Open::try_new(
+    ///                 Link::Direct {
+    ///                     url: "https://example.com".parse::<Url>().unwrap(),
+    ///                 },
+    ///                 None,
+    ///             )
+    ///             .unwrap(),
+    ///         ),
     ///     })),
     ///     Some(Builder::default().build()),
     /// );
@@ -230,6 +347,7 @@ impl Subject {
     ///
     /// use models::gateway::AnonymousOrReference;
     /// use models::gateway::Link;
+    /// use models::gateway::Open;
     /// use models::metadata::field::unowned::subject::Identifier;
     /// use models::namespace;
     /// use models::organization;
@@ -270,11 +388,15 @@ impl Subject {
     ///     subject_id,
     ///     Kind::Participant,
     ///     Some(NonEmpty::new(AnonymousOrReference::Anonymous {
-    ///         gateway: Gateway::Open {
-    ///             link: Link::Direct {
-    ///                 url: "https://example.com".parse::<Url>().unwrap(),
-    ///             },
-    ///         },
+    ///         gateway: Gateway::Open(
+    ///             Open::try_new(
+    ///                 Link::Direct {
+    ///                     url: "https://example.com".parse::<Url>().unwrap(),
+    ///                 },
+    ///                 None,
+    ///             )
+    ///             .unwrap(),
+    ///         ),
     ///     })),
     ///     Some(Builder::default().build()),
     /// );
@@ -299,6 +421,7 @@ impl Subject {
     /// use models::file::Metadata;
     /// use models::gateway::AnonymousOrReference;
     /// use models::gateway::Link;
+    /// use models::gateway::Open;
     /// use models::namespace;
     /// use models::organization;
     /// use models::sample;
@@ -340,11 +463,15 @@ impl Subject {
     ///     subject_id,
     ///     Kind::Participant,
     ///     Some(NonEmpty::new(AnonymousOrReference::Anonymous {
-    ///         gateway: Gateway::Open {
-    ///             link: Link::Direct {
-    ///                 url: "https://example.com".parse::<Url>().unwrap(),
-    ///             },
-    ///         },
+    ///         gateway: Gateway::Open(
+    ///             Open::try_new(
+    ///                 Link::Direct {
+    ///                     url: "https://example.com".parse::<Url>().unwrap(),
+    ///                 },
+    ///                 None,
+    ///             )
+    ///             .unwrap(),
+    ///         ),
     ///     })),
     ///     Some(Builder::default().build()),
     /// );
@@ -369,6 +496,7 @@ impl Subject {
     ///
     /// use models::gateway::AnonymousOrReference;
     /// use models::gateway::Link;
+    /// use models::gateway::Open;
     /// use models::metadata::field::unowned::subject::Identifier;
     /// use models::namespace;
     /// use models::organization;
@@ -409,11 +537,15 @@ impl Subject {
     ///     subject_id,
     ///     Kind::Participant,
     ///     Some(NonEmpty::new(AnonymousOrReference::Anonymous {
-    ///         gateway: Gateway::Open {
-    ///             link: Link::Direct {
-    ///                 url: "https://example.com".parse::<Url>().unwrap(),
-    ///             },
-    ///         },
+    ///         gateway: Gateway::Open(
+    ///             Open::try_new(
+    ///                 Link::Direct {
+    ///                     url: "https://example.com".parse::<Url>().unwrap(),
+    ///                 },
+    ///                 None,
+    ///             )
+    ///             .unwrap(),
+    ///         ),
     ///     })),
     ///     Some(metadata.clone()),
     /// );
@@ -426,6 +558,53 @@ impl Subject {
         self.metadata.as_ref()
     }
 
+    /// Returns whether the [`Subject`] has metadata.
+    ///
+    /// A [`Subject`] whose metadata block is missing or is [effectively
+    /// empty](Metadata::is_effectively_empty) is considered to have no
+    /// metadata.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ccdi_cde as cde;
+    /// use ccdi_models as models;
+    ///
+    /// use models::metadata::field::unowned::subject::Sex;
+    /// use models::namespace;
+    /// use models::subject::metadata::Builder;
+    /// use models::subject::Kind;
+    /// use models::Subject;
+    ///
+    /// let namespace = namespace::Identifier::new(
+    ///     "example-organization"
+    ///         .parse::<models::organization::Identifier>()
+    ///         .unwrap(),
+    ///     namespace::identifier::Name::try_new("ExampleNamespace").unwrap(),
+    /// );
+    ///
+    /// let id = models::subject::Identifier::new(namespace, "SubjectName001");
+    ///
+    /// let subject = Subject::new(id.clone(), Kind::Participant, None, None);
+    /// assert!(!subject.has_metadata());
+    ///
+    /// let metadata = Builder::default().build();
+    /// let subject = Subject::new(id.clone(), Kind::Participant, None, Some(metadata));
+    /// assert!(!subject.has_metadata());
+    ///
+    /// let metadata = Builder::default()
+    ///     .sex(Sex::new(cde::v1::subject::Sex::Female, None, None, None))
+    ///     .build();
+    /// let subject = Subject::new(id, Kind::Participant, None, Some(metadata));
+    /// assert!(subject.has_metadata());
+    /// ```
+    pub fn has_metadata(&self) -> bool {
+        self.metadata
+            .as_ref()
+            .map(|metadata| !metadata.is_effectively_empty())
+            .unwrap_or(false)
+    }
+
     /// Generates a random [`Subject`] based on a particular [`Identifier`].
     ///
     /// # Examples
@@ -468,29 +647,191 @@ impl Subject {
     /// let subject = Subject::random(subject_id);
     /// ```
     pub fn random(identifier: Identifier) -> Self {
-        let mut rng = thread_rng();
+        Self::random_with_metadata_less_fraction(identifier, DEFAULT_METADATA_LESS_FRACTION)
+    }
 
-        Self {
-            id: identifier.clone(),
-            kind: rand::random(),
-            gateways: match rng.gen_bool(0.9) {
-                true => Some(NonEmpty::new(AnonymousOrReference::Anonymous {
-                    gateway: crate::Gateway::Open {
-                        link: Link::Direct {
-                            url: "https://example.com".parse::<Url>().unwrap(),
-                        },
-                    },
-                })),
-                false => Some(NonEmpty::new(AnonymousOrReference::Reference {
-                    gateway: String::from("gateway"),
-                })),
-            },
-            metadata: match rng.gen_bool(0.7) {
-                true => Some(Metadata::random(identifier)),
-                false => None,
-            },
+    /// Generates a random [`Subject`] based on a particular [`Identifier`] where
+    /// `metadata_less_fraction` is the probability that the generated
+    /// [`Subject`] has no metadata.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `metadata_less_fraction` is not within `0.0..=1.0`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ccdi_models as models;
+    ///
+    /// use models::namespace;
+    /// use models::Subject;
+    ///
+    /// let namespace = namespace::Identifier::new(
+    ///     "example-organization"
+    ///         .parse::<models::organization::Identifier>()
+    ///         .unwrap(),
+    ///     namespace::identifier::Name::try_new("ExampleNamespace").unwrap(),
+    /// );
+    ///
+    /// let id = models::subject::Identifier::new(namespace, "SubjectName001");
+    ///
+    /// let subject = Subject::random_with_metadata_less_fraction(id.clone(), 1.0);
+    /// assert!(subject.metadata().is_none());
+    ///
+    /// let subject = Subject::random_with_metadata_less_fraction(id, 0.0);
+    /// assert!(subject.has_metadata());
+    /// ```
+    pub fn random_with_metadata_less_fraction(
+        identifier: Identifier,
+        metadata_less_fraction: f64,
+    ) -> Self {
+        Self::random_with_rng(identifier, metadata_less_fraction, &mut thread_rng())
+    }
+
+    /// Generates a random [`Subject`] based on a particular [`Identifier`] using
+    /// the provided random number generator, where `metadata_less_fraction` is
+    /// the probability that the generated [`Subject`] has no metadata.
+    ///
+    /// The same seed always generates the same [`Subject`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `metadata_less_fraction` is not within `0.0..=1.0`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ccdi_models as models;
+    /// use rand::rngs::StdRng;
+    /// use rand::SeedableRng as _;
+    ///
+    /// use models::namespace;
+    /// use models::Subject;
+    ///
+    /// let namespace = namespace::Identifier::new(
+    ///     "example-organization"
+    ///         .parse::<models::organization::Identifier>()
+    ///         .unwrap(),
+    ///     namespace::identifier::Name::try_new("ExampleNamespace").unwrap(),
+    /// );
+    ///
+    /// let id = models::subject::Identifier::new(namespace, "SubjectName001");
+    ///
+    /// let a = Subject::random_with_rng(id.clone(), 0.5, &mut StdRng::seed_from_u64(42));
+    /// let b = Subject::random_with_rng(id, 0.5, &mut StdRng::seed_from_u64(42));
+    /// assert_eq!(a, b);
+    /// ```
+    pub fn random_with_rng<R: Rng + ?Sized>(
+        identifier: Identifier,
+        metadata_less_fraction: f64,
+        rng: &mut R,
+    ) -> Self {
+        Self::random_with_generation(
+            identifier,
+            metadata_less_fraction,
+            Generation::Coherent,
+            rng,
+        )
+    }
+
+    /// Generates a random [`Subject`] based on a particular [`Identifier`] using
+    /// the provided random number generator, where `metadata_less_fraction` is
+    /// the probability that the generated [`Subject`] has no metadata and
+    /// `generation` is the strategy used to generate any metadata.
+    ///
+    /// The same seed always generates the same [`Subject`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `metadata_less_fraction` is not within `0.0..=1.0`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ccdi_models as models;
+    /// use rand::rngs::StdRng;
+    /// use rand::SeedableRng as _;
+    ///
+    /// use models::metadata::Generation;
+    /// use models::namespace;
+    /// use models::Subject;
+    ///
+    /// let namespace = namespace::Identifier::new(
+    ///     "example-organization"
+    ///         .parse::<models::organization::Identifier>()
+    ///         .unwrap(),
+    ///     namespace::identifier::Name::try_new("ExampleNamespace").unwrap(),
+    /// );
+    ///
+    /// let id = models::subject::Identifier::new(namespace, "SubjectName001");
+    ///
+    /// let subject = Subject::random_with_generation(
+    ///     id,
+    ///     0.0,
+    ///     Generation::Independent,
+    ///     &mut StdRng::seed_from_u64(42),
+    /// );
+    /// assert!(subject.has_metadata());
+    /// ```
+    pub fn random_with_generation<R: Rng + ?Sized>(
+        identifier: Identifier,
+        metadata_less_fraction: f64,
+        generation: Generation,
+        rng: &mut R,
+    ) -> Self {
+        let metadata = match rng.gen_bool(1.0 - metadata_less_fraction) {
+            true => Some(Metadata::random_with_generation(
+                identifier.clone(),
+                generation,
+                rng,
+            )),
+            false => None,
+        };
+
+        let gateways = match rng.gen_bool(0.9) {
+            true => Some(NonEmpty::new(AnonymousOrReference::Anonymous {
+                gateway: crate::Gateway::random_with_rng(rng),
+            })),
+            false => Some(NonEmpty::new(AnonymousOrReference::Reference {
+                gateway: String::from("gateway"),
+            })),
+        };
+
+        // SAFETY: randomly generated metadata always includes the primary
+        // identifier as its only linked identifier, so this always unwraps.
+        Self::try_new(identifier, rng.gen(), gateways, metadata).unwrap()
+    }
+}
+
+/// Checks that the identifiers within the metadata of a [`Subject`] include the
+/// primary identifier of the [`Subject`] (and do not contradict it).
+fn check_identifiers(primary: &Identifier, metadata: &Metadata) -> Result<(), Error> {
+    let identifiers = match metadata.identifiers() {
+        Some(identifiers) => identifiers,
+        None => return Ok(()),
+    };
+
+    let mut found = false;
+
+    for identifier in identifiers {
+        if let identifier::referenced::Identifier::Linked(linked) = identifier.value() {
+            let inner = linked.inner();
+
+            if inner == primary {
+                found = true;
+            } else if inner.namespace() == primary.namespace() {
+                return Err(Error::ConflictingIdentifier {
+                    primary: primary.clone(),
+                    conflicting: inner.clone(),
+                });
+            }
         }
     }
+
+    match found {
+        true => Ok(()),
+        false => Err(Error::MissingPrimaryIdentifier(primary.clone())),
+    }
 }
 
 impl Entity for Subject {}
@@ -519,6 +860,7 @@ mod tests {
     use crate::namespace;
     use crate::organization;
     use crate::Namespace;
+    use crate::Url;
 
     use super::*;
 
@@ -649,4 +991,110 @@ mod tests {
 
         assert!(foo != bar);
     }
+
+    fn namespace_id(name: &str) -> namespace::Identifier {
+        namespace::Identifier::new(
+            organization::Identifier::try_new("example-organization").unwrap(),
+            namespace::identifier::Name::try_new(name).unwrap(),
+        )
+    }
+
+    fn linked(id: Identifier) -> crate::metadata::field::unowned::subject::Identifier {
+        crate::metadata::field::unowned::subject::Identifier::new(
+            identifier::referenced::Identifier::Linked(identifier::linked::Identifier::new(
+                id,
+                "https://ccdi.example.com/api/v0".parse::<Url>().unwrap(),
+            )),
+            None,
+            None,
+            None,
+        )
+    }
+
+    #[test]
+    fn it_accepts_metadata_that_includes_the_primary_identifier() {
+        let id = Identifier::new(namespace_id("ExampleNamespace"), "Subject1");
+
+        // Linked identifiers within other namespaces and unlinked identifiers
+        // are allowed alongside the primary identifier.
+        let metadata = metadata::Builder::default()
+            .append_identifier(linked(Identifier::new(
+                namespace_id("OtherNamespace"),
+                "Subject2",
+            )))
+            .append_identifier(crate::metadata::field::unowned::subject::Identifier::new(
+                identifier::referenced::Identifier::Unlinked(
+                    identifier::unlinked::Identifier::from(String::from("Subject-ABC")),
+                ),
+                None,
+                None,
+                None,
+            ))
+            .append_identifier(linked(id.clone()))
+            .build();
+
+        Subject::try_new(id.clone(), Kind::Participant, None, Some(metadata)).unwrap();
+
+        // Metadata without any identifiers (and no metadata at all) is also
+        // allowed.
+        Subject::try_new(
+            id.clone(),
+            Kind::Participant,
+            None,
+            Some(metadata::Builder::default().build()),
+        )
+        .unwrap();
+        Subject::try_new(id, Kind::Participant, None, None).unwrap();
+    }
+
+    #[test]
+    fn it_rejects_metadata_without_the_primary_identifier() {
+        let id = Identifier::new(namespace_id("ExampleNamespace"), "Subject1");
+        let metadata = metadata::Builder::default()
+            .append_identifier(linked(Identifier::new(
+                namespace_id("OtherNamespace"),
+                "Subject1",
+            )))
+            .build();
+
+        let err =
+            Subject::try_new(id.clone(), Kind::Participant, None, Some(metadata)).unwrap_err();
+
+        assert_eq!(err, Error::MissingPrimaryIdentifier(id));
+        assert_eq!(
+            err.to_string(),
+            "the identifiers within the metadata do not include the primary identifier: { \
+             namespace: { organization: example-organization, name: ExampleNamespace }, name: \
+             Subject1 }"
+        );
+    }
+
+    #[test]
+    fn it_rejects_metadata_with_a_conflicting_identifier() {
+        let id = Identifier::new(namespace_id("ExampleNamespace"), "Subject1");
+        let conflicting = Identifier::new(namespace_id("ExampleNamespace"), "Subject2");
+        let metadata = metadata::Builder::default()
+            .append_identifier(linked(id.clone()))
+            .append_identifier(linked(conflicting.clone()))
+            .build();
+
+        let err =
+            Subject::try_new(id.clone(), Kind::Participant, None, Some(metadata)).unwrap_err();
+
+        assert_eq!(
+            err,
+            Error::ConflictingIdentifier {
+                primary: id,
+                conflicting,
+            }
+        );
+    }
+
+    #[test]
+    fn it_generates_random_subjects_that_include_the_primary_identifier() {
+        let id = Identifier::new(namespace_id("ExampleNamespace"), "Subject1");
+        let subject = Subject::random_with_metadata_less_fraction(id.clone(), 0.0);
+
+        check_identifiers(&id, subject.metadata().unwrap()).unwrap();
+    }
 }
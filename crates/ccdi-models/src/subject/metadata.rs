@@ -3,7 +3,7 @@
 use ordered_float::OrderedFloat;
 use rand::distributions::Alphanumeric;
 use rand::thread_rng;
-use rand::Rng as _;
+use rand::Rng;
 use serde::Deserialize;
 use serde::Serialize;
 use utoipa::ToSchema;
@@ -11,6 +11,7 @@ use utoipa::ToSchema;
 use crate::metadata::common;
 use crate::metadata::field;
 use crate::metadata::fields;
+use crate::metadata::Generation;
 use crate::subject::Identifier;
 
 mod age_at_vital_status;
@@ -27,6 +28,8 @@ pub use builder::Builder;
 #[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize, ToSchema)]
 #[schema(as = models::subject::Metadata)]
 pub struct Metadata {
+    // NOTE: the declaration order of these fields is contractual (see the
+    // module-level documentation of `crate::metadata`).
     /// The sex of the subject.
     #[schema(value_type = field::unowned::subject::Sex, nullable = true)]
     sex: Option<field::unowned::subject::Sex>,
@@ -403,7 +406,7 @@ impl Metadata {
     ///             None,
     ///             None,
     ///         )),
-    ///     )
+    ///     )?
     ///     .insert_unharmonized(
     ///         "owned",
     ///         UnharmonizedField::Owned(owned::Field::new(
@@ -413,7 +416,7 @@ impl Metadata {
     ///             None,
     ///             None,
     ///         )),
-    ///     )
+    ///     )?
     ///     .build();
     ///
     /// assert!(matches!(
@@ -431,6 +434,43 @@ impl Metadata {
         &self.unharmonized
     }
 
+    /// Returns whether the [`Metadata`] is effectively empty.
+    ///
+    /// A metadata block is effectively empty when every harmonized field
+    /// (including the common metadata fields) is `None` and there are no
+    /// unharmonized fields. For the purposes of filtering and summarizing, an
+    /// effectively empty metadata block is treated the same as a missing one.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ccdi_cde as cde;
+    /// use ccdi_models as models;
+    ///
+    /// use models::metadata::field::unowned::subject::Sex;
+    /// use models::subject::metadata::Builder;
+    ///
+    /// assert!(Builder::default().build().is_effectively_empty());
+    ///
+    /// let metadata = Builder::default()
+    ///     .sex(Sex::new(cde::v1::subject::Sex::Female, None, None, None))
+    ///     .build();
+    ///
+    /// assert!(!metadata.is_effectively_empty());
+    /// ```
+    pub fn is_effectively_empty(&self) -> bool {
+        self.sex.is_none()
+            && self.race.is_none()
+            && self.ethnicity.is_none()
+            && self.identifiers.is_none()
+            && self.vital_status.is_none()
+            && self.age_at_vital_status.is_none()
+            && self.associated_diagnoses.is_none()
+            && self.associated_diagnosis_categories.is_none()
+            && self.common.is_empty()
+            && self.unharmonized.is_empty()
+    }
+
     /// Generates a random [`Metadata`] based on a particular [`Identifier`].
     ///
     /// # Examples
@@ -471,41 +511,115 @@ impl Metadata {
     /// let metadata = Metadata::random(subject_id);
     /// ```
     pub fn random(identifier: Identifier) -> Metadata {
-        let mut rng = thread_rng();
+        Self::random_with_rng(identifier, &mut thread_rng())
+    }
 
+    /// Generates a random [`Metadata`] based on a particular [`Identifier`]
+    /// using the provided random number generator.
+    ///
+    /// Related fields are generated jointly so that the metadata is realistic
+    /// (see [`Builder::random_coherent()`]).
+    ///
+    /// The same seed always generates the same [`Metadata`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ccdi_models as models;
+    /// use rand::rngs::StdRng;
+    /// use rand::SeedableRng as _;
+    ///
+    /// use models::namespace;
+    /// use models::subject::Metadata;
+    ///
+    /// let namespace = namespace::Identifier::new(
+    ///     "example-organization"
+    ///         .parse::<models::organization::Identifier>()
+    ///         .unwrap(),
+    ///     namespace::identifier::Name::try_new("ExampleNamespace").unwrap(),
+    /// );
+    ///
+    /// let id = models::subject::Identifier::new(namespace, "SubjectName001");
+    ///
+    /// assert_eq!(
+    ///     Metadata::random_with_rng(id.clone(), &mut StdRng::seed_from_u64(42)),
+    ///     Metadata::random_with_rng(id, &mut StdRng::seed_from_u64(42))
+    /// );
+    /// ```
+    pub fn random_with_rng<R: Rng + ?Sized>(identifier: Identifier, rng: &mut R) -> Metadata {
+        Self::random_with_generation(identifier, Generation::Coherent, rng)
+    }
+
+    /// Generates a random [`Metadata`] based on a particular [`Identifier`]
+    /// using the provided random number generator and [`Generation`] strategy.
+    ///
+    /// The same seed always generates the same [`Metadata`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ccdi_models as models;
+    /// use rand::rngs::StdRng;
+    /// use rand::SeedableRng as _;
+    ///
+    /// use models::metadata::Generation;
+    /// use models::namespace;
+    /// use models::subject::Metadata;
+    ///
+    /// let namespace = namespace::Identifier::new(
+    ///     "example-organization"
+    ///         .parse::<models::organization::Identifier>()
+    ///         .unwrap(),
+    ///     namespace::identifier::Name::try_new("ExampleNamespace").unwrap(),
+    /// );
+    ///
+    /// let id = models::subject::Identifier::new(namespace, "SubjectName001");
+    ///
+    /// assert_eq!(
+    ///     Metadata::random_with_generation(
+    ///         id.clone(),
+    ///         Generation::Independent,
+    ///         &mut StdRng::seed_from_u64(42)
+    ///     ),
+    ///     Metadata::random_with_generation(
+    ///         id,
+    ///         Generation::Independent,
+    ///         &mut StdRng::seed_from_u64(42)
+    ///     )
+    /// );
+    /// ```
+    pub fn random_with_generation<R: Rng + ?Sized>(
+        identifier: Identifier,
+        generation: Generation,
+        rng: &mut R,
+    ) -> Metadata {
+        match generation {
+            Generation::Coherent => {
+                let builder = Builder::random_coherent(rng);
+
+                random_identifiers(identifier, rng)
+                    .into_iter()
+                    .fold(builder, |builder, identifier| {
+                        builder.append_identifier(identifier)
+                    })
+                    .build()
+            }
+            Generation::Independent => Self::random_independent_with_rng(identifier, rng),
+        }
+    }
+
+    /// Generates a random [`Metadata`] where every field is generated
+    /// independently of every other field.
+    fn random_independent_with_rng<R: Rng + ?Sized>(
+        identifier: Identifier,
+        rng: &mut R,
+    ) -> Metadata {
         Metadata {
-            sex: Some(rand::random()),
-            race: Some(vec![rand::random()]),
-            ethnicity: Some(rand::random()),
-            identifiers: Some(vec![
-                field::unowned::subject::Identifier::new(
-                    crate::subject::identifier::referenced::Identifier::Linked(
-                        crate::subject::identifier::linked::Identifier::new(
-                            identifier.clone(),
-                            "https://ccdi.example.com/api/v0"
-                                .parse::<crate::Url>()
-                                .unwrap(),
-                        ),
-                    ),
-                    None,
-                    None,
-                    None,
-                ),
-                field::unowned::subject::Identifier::new(
-                    crate::subject::identifier::referenced::Identifier::Unlinked(
-                        crate::subject::identifier::unlinked::Identifier::from(format!(
-                            "Subject-{}",
-                            (0..8)
-                                .map(|_| rng.sample(Alphanumeric).to_ascii_uppercase() as char)
-                                .collect::<String>()
-                        )),
-                    ),
-                    None,
-                    None,
-                    None,
-                ),
-            ]),
-            vital_status: Some(rand::random()),
+            sex: Some(rng.gen()),
+            race: Some(vec![rng.gen()]),
+            ethnicity: Some(rng.gen()),
+            identifiers: Some(random_identifiers(identifier, rng)),
+            vital_status: Some(rng.gen()),
             age_at_vital_status: Some(field::unowned::subject::AgeAtVitalStatus::new(
                 crate::subject::metadata::AgeAtVitalStatus::from(OrderedFloat(365.25)),
                 None,
@@ -528,7 +642,7 @@ impl Metadata {
                     })
                     .collect(),
             ),
-            associated_diagnosis_categories: Some(vec![rand::random()]),
+            associated_diagnosis_categories: Some(vec![rng.gen()]),
 
             common: Default::default(),
             unharmonized: Default::default(),
@@ -536,6 +650,43 @@ impl Metadata {
     }
 }
 
+/// Generates the identifiers for randomly generated [`Metadata`]: a linked
+/// identifier referring to the primary `identifier` and a random unlinked
+/// identifier.
+fn random_identifiers<R: Rng + ?Sized>(
+    identifier: Identifier,
+    rng: &mut R,
+) -> Vec<field::unowned::subject::Identifier> {
+    vec![
+        field::unowned::subject::Identifier::new(
+            crate::subject::identifier::referenced::Identifier::Linked(
+                crate::subject::identifier::linked::Identifier::new(
+                    identifier,
+                    "https://ccdi.example.com/api/v0"
+                        .parse::<crate::Url>()
+                        .unwrap(),
+                ),
+            ),
+            None,
+            None,
+            None,
+        ),
+        field::unowned::subject::Identifier::new(
+            crate::subject::identifier::referenced::Identifier::Unlinked(
+                crate::subject::identifier::unlinked::Identifier::from(format!(
+                    "Subject-{}",
+                    (0..8)
+                        .map(|_| rng.sample(Alphanumeric).to_ascii_uppercase() as char)
+                        .collect::<String>()
+                )),
+            ),
+            None,
+            None,
+            None,
+        ),
+    ]
+}
+
 #[cfg(test)]
 mod tests {
     use crate::subject::metadata::builder;
@@ -1,10 +1,35 @@
 //! A builder for [`Metadata`].
 
+use ccdi_cde as cde;
+use lazy_static::lazy_static;
+use ordered_float::OrderedFloat;
+use rand::Rng;
+
 use crate::metadata::common;
 use crate::metadata::field;
 use crate::metadata::fields;
+use crate::metadata::harmonized_keys;
+use crate::metadata::validate_unharmonized_keys;
+use crate::metadata::UnharmonizedKeyError;
+use crate::sample::metadata::diagnosis;
+use crate::subject::metadata::AgeAtVitalStatus;
+use crate::subject::metadata::AssociatedDiagnoses;
+use crate::subject::metadata::AssociatedDiagnosisCategories;
 use crate::subject::Metadata;
 
+/// The maximum age at vital status (in days) of randomly generated metadata.
+const MAX_RANDOM_AGE_AT_VITAL_STATUS: u32 = 25 * 365;
+
+/// The maximum number of associated diagnoses within randomly generated
+/// metadata.
+const MAX_RANDOM_ASSOCIATED_DIAGNOSES: usize = 3;
+
+lazy_static! {
+    /// The keys of the harmonized fields of a subject (which unharmonized keys
+    /// must not shadow).
+    static ref HARMONIZED_KEYS: Vec<String> = harmonized_keys(&Builder::default().build());
+}
+
 /// A builder for [`Metadata`].
 #[derive(Clone, Debug, Default)]
 pub struct Builder {
@@ -297,6 +322,9 @@ impl Builder {
     /// Inserts an [`UnharmonizedField`](field::UnharmonizedField) into the
     /// `unharmonized` map.
     ///
+    /// The key is validated exactly as the keys provided to
+    /// [`Builder::extend_unharmonized()`] are.
+    ///
     /// # Examples
     ///
     /// ```
@@ -319,7 +347,7 @@ impl Builder {
     ///             None,
     ///             None,
     ///         )),
-    ///     )
+    ///     )?
     ///     .insert_unharmonized(
     ///         "owned",
     ///         UnharmonizedField::Owned(owned::Field::new(
@@ -329,23 +357,163 @@ impl Builder {
     ///             None,
     ///             None,
     ///         )),
-    ///     );
+    ///     )?;
     ///
     /// # Ok::<(), Box<dyn std::error::Error>>(())
     /// ```
     pub fn insert_unharmonized<S: Into<String>>(
-        mut self,
+        self,
         key: S,
         field: field::UnharmonizedField,
-    ) -> Self {
-        let key = key.into();
+    ) -> Result<Self, UnharmonizedKeyError> {
+        self.extend_unharmonized([(key.into(), field)])
+    }
+
+    /// Inserts every [`UnharmonizedField`](field::UnharmonizedField) within
+    /// `fields` into the `unharmonized` map.
+    ///
+    /// Every key must follow the rules for harmonized keys (see
+    /// [`validate_harmonized_key()`](crate::metadata::validate_harmonized_key))
+    /// and must not be the name of a harmonized field of a subject (e.g.,
+    /// `sex`). If any key is invalid, no fields are inserted and an
+    /// [`UnharmonizedKeyError`] identifying every offending key is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use serde_json::Value;
+    ///
+    /// use ccdi_models as models;
+    ///
+    /// use models::metadata::field::unowned;
+    /// use models::metadata::field::UnharmonizedField;
+    /// use models::subject::metadata::Builder;
+    ///
+    /// let field = |value: &str| {
+    ///     UnharmonizedField::Unowned(unowned::Field::new(
+    ///         Value::String(value.into()),
+    ///         None,
+    ///         None,
+    ///         None,
+    ///     ))
+    /// };
+    ///
+    /// let metadata = Builder::default()
+    ///     .extend_unharmonized([
+    ///         (String::from("enrollment_site"), field("Example Hospital")),
+    ///         (String::from("treatment_arm"), field("A")),
+    ///     ])?
+    ///     .build();
+    /// assert_eq!(metadata.unharmonized().inner().len(), 2);
+    ///
+    /// let err = Builder::default()
+    ///     .extend_unharmonized([
+    ///         (String::from("sex"), field("A")),
+    ///         (String::from("Treatment Arm"), field("A")),
+    ///     ])
+    ///     .unwrap_err();
+    /// assert_eq!(err.keys().count(), 2);
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn extend_unharmonized<I>(mut self, fields: I) -> Result<Self, UnharmonizedKeyError>
+    where
+        I: IntoIterator<Item = (String, field::UnharmonizedField)>,
+    {
+        let fields = fields.into_iter().collect::<Vec<_>>();
 
-        let mut unharmonized = self.unharmonized;
-        unharmonized.inner_mut().insert(key, field);
+        validate_unharmonized_keys(fields.iter().map(|(key, _)| key.as_str()), &HARMONIZED_KEYS)?;
 
-        self.unharmonized = unharmonized;
+        self.unharmonized.inner_mut().extend(fields);
+        Ok(self)
+    }
 
-        self
+    /// Generates a [`Builder`] populated with random, but realistic, values
+    /// using the provided random number generator.
+    ///
+    /// Unlike generating every field independently, related fields are
+    /// generated jointly so that the resulting metadata is coherent:
+    ///
+    /// * the associated diagnosis categories are exactly the categories to
+    ///   which the associated diagnoses belong, and
+    /// * the age at vital status is always present when the subject is
+    ///   deceased.
+    ///
+    /// No identifiers are populated.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ccdi_models as models;
+    /// use rand::rngs::StdRng;
+    /// use rand::SeedableRng as _;
+    ///
+    /// use models::subject::metadata::Builder;
+    ///
+    /// let metadata = Builder::random_coherent(&mut StdRng::seed_from_u64(42)).build();
+    /// assert!(metadata.associated_diagnoses().is_some());
+    /// assert!(metadata.associated_diagnosis_categories().is_some());
+    /// ```
+    pub fn random_coherent<R: Rng + ?Sized>(rng: &mut R) -> Self {
+        let diagnoses = diagnosis::random_many_with_category(MAX_RANDOM_ASSOCIATED_DIAGNOSES, rng);
+
+        let mut categories = Vec::<cde::v1::sample::DiagnosisCategory>::new();
+        for (category, _) in &diagnoses {
+            if !categories.contains(category) {
+                categories.push(category.clone());
+            }
+        }
+
+        let vital_status = rng.gen::<cde::v1::subject::VitalStatus>();
+        let age_at_vital_status =
+            match vital_status == cde::v1::subject::VitalStatus::Dead || rng.gen_bool(0.5) {
+                true => Some(field::unowned::subject::AgeAtVitalStatus::new(
+                    AgeAtVitalStatus::from(OrderedFloat(
+                        rng.gen_range(0..=MAX_RANDOM_AGE_AT_VITAL_STATUS) as f32,
+                    )),
+                    None,
+                    None,
+                    None,
+                )),
+                false => None,
+            };
+
+        Self {
+            sex: Some(rng.gen()),
+            race: Some(vec![rng.gen()]),
+            ethnicity: Some(rng.gen()),
+            identifiers: None,
+            vital_status: Some(field::unowned::subject::VitalStatus::new(
+                vital_status,
+                None,
+                None,
+                None,
+            )),
+            age_at_vital_status,
+            associated_diagnoses: Some(
+                diagnoses
+                    .into_iter()
+                    .map(|(_, diagnosis)| {
+                        field::unowned::subject::AssociatedDiagnoses::new(
+                            AssociatedDiagnoses::from(diagnosis.to_string()),
+                            None,
+                            None,
+                            None,
+                        )
+                    })
+                    .collect(),
+            ),
+            associated_diagnosis_categories: Some(vec![
+                field::unowned::subject::AssociatedDiagnosisCategories::new(
+                    AssociatedDiagnosisCategories::from(categories),
+                    None,
+                    None,
+                    None,
+                ),
+            ]),
+            common: Default::default(),
+            unharmonized: Default::default(),
+        }
     }
 
     /// Consumes `self` to build a [`Metadata`].
@@ -374,3 +542,132 @@ impl Builder {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use rand::rngs::StdRng;
+    use rand::SeedableRng as _;
+    use serde_json::Value;
+
+    use crate::metadata::field::unowned;
+    use crate::metadata::field::UnharmonizedField;
+    use crate::metadata::KeyError;
+    use crate::metadata::UnharmonizedKeyProblem;
+
+    use super::*;
+
+    fn unharmonized(value: &str) -> UnharmonizedField {
+        UnharmonizedField::Unowned(unowned::Field::new(
+            Value::String(value.into()),
+            None,
+            None,
+            None,
+        ))
+    }
+
+    /// The number of records generated when checking the invariants of
+    /// randomly generated metadata.
+    const RECORDS: usize = 5_000;
+
+    #[test]
+    fn random_coherent_metadata_is_coherent() {
+        let mut rng = StdRng::seed_from_u64(0);
+
+        for _ in 0..RECORDS {
+            let metadata = Builder::random_coherent(&mut rng).build();
+
+            let categories = metadata.associated_diagnosis_categories().unwrap();
+            assert_eq!(categories.len(), 1);
+            let categories = categories[0].value();
+
+            for diagnosis in metadata.associated_diagnoses().unwrap() {
+                let (category, _) = diagnosis::DIAGNOSES
+                    .iter()
+                    .find(|(_, d)| *d == diagnosis.value().as_str())
+                    .unwrap();
+                assert!(categories.contains(category));
+            }
+
+            if metadata.vital_status().unwrap().value() == &cde::v1::subject::VitalStatus::Dead {
+                assert!(metadata.age_at_vital_status().is_some());
+            }
+        }
+    }
+
+    #[test]
+    fn it_ingests_unharmonized_fields_in_bulk() {
+        let metadata = Builder::default()
+            .extend_unharmonized(
+                (0..50).map(|i| (format!("field_{i}"), unharmonized(&i.to_string()))),
+            )
+            .unwrap()
+            .build();
+
+        let unharmonized = metadata.unharmonized().inner();
+        assert_eq!(unharmonized.len(), 50);
+        assert!(unharmonized.contains_key("field_0"));
+        assert!(unharmonized.contains_key("field_49"));
+    }
+
+    #[test]
+    fn it_rejects_unharmonized_keys_that_shadow_harmonized_fields() {
+        let err = Builder::default()
+            .extend_unharmonized([
+                (String::from("sex"), unharmonized("F")),
+                (
+                    String::from("enrollment_site"),
+                    unharmonized("Example Hospital"),
+                ),
+                (String::from("depositions"), unharmonized("phs000000.v1.p1")),
+            ])
+            .unwrap_err();
+
+        assert_eq!(
+            err.problems(),
+            &[
+                (String::from("sex"), UnharmonizedKeyProblem::Shadowed),
+                (
+                    String::from("depositions"),
+                    UnharmonizedKeyProblem::Shadowed
+                ),
+            ]
+        );
+
+        // The single-insert path is validated exactly the same way.
+        let err = Builder::default()
+            .insert_unharmonized("vital_status", unharmonized("Alive"))
+            .unwrap_err();
+        assert_eq!(err.keys().collect::<Vec<_>>(), vec!["vital_status"]);
+    }
+
+    #[test]
+    fn it_rejects_unharmonized_keys_with_invalid_characters() {
+        let err = Builder::default()
+            .extend_unharmonized([
+                (String::from("Treatment Arm"), unharmonized("A")),
+                (String::from("treatment_arm"), unharmonized("A")),
+                (String::from("site-id"), unharmonized("1")),
+            ])
+            .unwrap_err();
+
+        assert_eq!(
+            err.problems(),
+            &[
+                (
+                    String::from("Treatment Arm"),
+                    UnharmonizedKeyProblem::Invalid(KeyError::InvalidCharacter {
+                        key: String::from("Treatment Arm"),
+                        character: 'T'
+                    })
+                ),
+                (
+                    String::from("site-id"),
+                    UnharmonizedKeyProblem::Invalid(KeyError::InvalidCharacter {
+                        key: String::from("site-id"),
+                        character: '-'
+                    })
+                ),
+            ]
+        );
+    }
+}
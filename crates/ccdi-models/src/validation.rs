@@ -0,0 +1,780 @@
+//! Validation of the longitudinal consistency of a subject and its samples.
+//!
+//! The metadata of a subject and of the samples collected from it describe a
+//! timeline: a subject is diagnosed, samples are collected (perhaps again at
+//! relapse or progression), and the subject's vital status is last recorded.
+//! The rules within this module report records whose metadata describe an
+//! unlikely timeline (or an unlikely combination of fields).
+//!
+//! Each rule is a [`Rule`] within [`RULES`] and returns zero or more
+//! [`ValidationWarning`]s. Warnings are not errors—the data may well be
+//! correct—so they are intended to be reviewed by the submitter. Each rule
+//! only reports a warning when every field it compares is present (missing
+//! fields are never considered inconsistent).
+
+use serde::Serialize;
+
+use ccdi_cde as cde;
+
+use cde::v1::sample::DiseasePhase;
+use cde::v1::sample::TissueType;
+use cde::v1::sample::TumorClassification;
+use cde::v1::subject::VitalStatus;
+use cde::v2::sample::TumorGrade;
+
+use crate::sample;
+use crate::subject;
+use crate::Sample;
+use crate::Subject;
+
+/// The kind of a [`ValidationWarning`] (one for each rule).
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum Kind {
+    /// The ages at which a sample was diagnosed or collected are out of order
+    /// with respect to one another or to the vital status of the subject.
+    AgeOrdering,
+
+    /// The tissue type of a sample contradicts its tumor fields.
+    TumorFieldsCoherence,
+
+    /// A sample was collected after the age at which the subject was recorded
+    /// as dead.
+    DeadBeforeCollection,
+}
+
+impl std::fmt::Display for Kind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Kind::AgeOrdering => write!(f, "age-ordering"),
+            Kind::TumorFieldsCoherence => write!(f, "tumor-fields-coherence"),
+            Kind::DeadBeforeCollection => write!(f, "dead-before-collection"),
+        }
+    }
+}
+
+/// A warning about a subject (or one of its samples) reported by a [`Rule`].
+#[derive(Clone, Debug, Eq, PartialEq, Serialize)]
+pub struct ValidationWarning {
+    /// The kind of the warning.
+    kind: Kind,
+
+    /// The subject involved in the warning.
+    subject: subject::Identifier,
+
+    /// The sample involved in the warning (if the warning concerns a
+    /// particular sample).
+    sample: Option<sample::Identifier>,
+
+    /// A description of the warning.
+    message: String,
+}
+
+impl ValidationWarning {
+    /// Creates a new [`ValidationWarning`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ccdi_models as models;
+    ///
+    /// use models::namespace;
+    /// use models::validation::Kind;
+    /// use models::validation::ValidationWarning;
+    ///
+    /// let namespace = namespace::Identifier::new(
+    ///     "example-organization"
+    ///         .parse::<models::organization::Identifier>()
+    ///         .unwrap(),
+    ///     namespace::identifier::Name::try_new("ExampleNamespace").unwrap(),
+    /// );
+    ///
+    /// let warning = ValidationWarning::new(
+    ///     Kind::AgeOrdering,
+    ///     models::subject::Identifier::new(namespace, "Subject1"),
+    ///     None,
+    ///     "a description",
+    /// );
+    ///
+    /// assert_eq!(warning.kind(), Kind::AgeOrdering);
+    /// assert_eq!(warning.subject().name().as_str(), "Subject1");
+    /// assert!(warning.sample().is_none());
+    /// assert_eq!(warning.to_string(), "age-ordering: a description");
+    /// ```
+    pub fn new(
+        kind: Kind,
+        subject: subject::Identifier,
+        sample: Option<sample::Identifier>,
+        message: impl Into<String>,
+    ) -> Self {
+        Self {
+            kind,
+            subject,
+            sample,
+            message: message.into(),
+        }
+    }
+
+    /// Gets the kind of the [`ValidationWarning`].
+    pub fn kind(&self) -> Kind {
+        self.kind
+    }
+
+    /// Gets the subject involved in the [`ValidationWarning`].
+    pub fn subject(&self) -> &subject::Identifier {
+        &self.subject
+    }
+
+    /// Gets the sample involved in the [`ValidationWarning`] (if any).
+    pub fn sample(&self) -> Option<&sample::Identifier> {
+        self.sample.as_ref()
+    }
+
+    /// Gets the description of the [`ValidationWarning`].
+    pub fn message(&self) -> &str {
+        self.message.as_str()
+    }
+}
+
+impl std::fmt::Display for ValidationWarning {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}: {}", self.kind, self.message)
+    }
+}
+
+/// A rule checking a subject given the samples collected from it.
+///
+/// Rules may assume that every provided sample refers to the subject (see
+/// [`validate()`], which ensures this).
+pub type Rule = fn(&Subject, &[&Sample]) -> Vec<ValidationWarning>;
+
+/// The rules that are checked by [`validate()`].
+pub const RULES: &[Rule] = &[age_ordering, tumor_fields_coherence, dead_before_collection];
+
+/// Creates a [`ValidationWarning`] of the provided kind about a sample.
+fn warning(kind: Kind, subject: &Subject, sample: &Sample, message: String) -> ValidationWarning {
+    ValidationWarning::new(
+        kind,
+        subject.id().clone(),
+        Some(sample.id().clone()),
+        message,
+    )
+}
+
+/// Checks the ordering of the ages at which the samples of a subject were
+/// diagnosed and collected.
+///
+/// A warning is reported when
+///
+/// * a sample was collected before the age at which it was diagnosed,
+/// * a sample with a disease phase following the initial diagnosis (e.g.,
+///   `Relapse`) was diagnosed or collected before the earliest initial
+///   diagnosis of the subject, or
+/// * a sample from a living subject was diagnosed or collected after the age
+///   at vital status of the subject (samples collected after the death of a
+///   subject are reported by [`dead_before_collection()`]).
+///
+/// # Examples
+///
+/// ```
+/// use ccdi_models as models;
+///
+/// use models::namespace;
+/// use models::subject::Kind;
+/// use models::validation;
+/// use models::Subject;
+///
+/// let namespace = namespace::Identifier::new(
+///     "example-organization"
+///         .parse::<models::organization::Identifier>()
+///         .unwrap(),
+///     namespace::identifier::Name::try_new("ExampleNamespace").unwrap(),
+/// );
+///
+/// let subject = Subject::new(
+///     models::subject::Identifier::new(namespace, "Subject1"),
+///     Kind::Participant,
+///     None,
+///     None,
+/// );
+///
+/// assert!(validation::age_ordering(&subject, &[]).is_empty());
+/// ```
+pub fn age_ordering(subject: &Subject, samples: &[&Sample]) -> Vec<ValidationWarning> {
+    let mut warnings = Vec::new();
+
+    let metadata = samples
+        .iter()
+        .filter_map(|sample| sample.metadata().map(|metadata| (*sample, metadata)))
+        .collect::<Vec<_>>();
+
+    for (sample, metadata) in &metadata {
+        if let (Some(age_at_diagnosis), Some(age_at_collection)) =
+            (metadata.age_at_diagnosis(), metadata.age_at_collection())
+        {
+            let age_at_diagnosis = age_at_diagnosis.value();
+            let age_at_collection = age_at_collection.value();
+
+            if **age_at_collection < **age_at_diagnosis {
+                warnings.push(warning(
+                    Kind::AgeOrdering,
+                    subject,
+                    sample,
+                    format!(
+                        "the sample was collected at {age_at_collection} days of age, which is \
+                         before its age at diagnosis ({age_at_diagnosis} days)"
+                    ),
+                ));
+            }
+        }
+    }
+
+    // NOTE: the earliest age at which the subject was initially diagnosed
+    // (among the samples with an `Initial Diagnosis` disease phase).
+    let initial_diagnosis = metadata
+        .iter()
+        .filter(|(_, metadata)| {
+            matches!(
+                metadata.disease_phase().map(|field| field.value()),
+                Some(DiseasePhase::InitialDiagnosis)
+            )
+        })
+        .filter_map(|(_, metadata)| metadata.age_at_diagnosis())
+        .map(|field| **field.value())
+        .min();
+
+    if let Some(initial_diagnosis) = initial_diagnosis {
+        for (sample, metadata) in &metadata {
+            let disease_phase = match metadata.disease_phase().map(|field| field.value()) {
+                Some(
+                    disease_phase @ (DiseasePhase::Progression
+                    | DiseasePhase::Refractory
+                    | DiseasePhase::Relapse
+                    | DiseasePhase::RelapseOrProgression),
+                ) => disease_phase,
+                _ => continue,
+            };
+
+            let age = metadata
+                .age_at_diagnosis()
+                .map(|field| **field.value())
+                .or_else(|| metadata.age_at_collection().map(|field| **field.value()));
+
+            if let Some(age) = age {
+                if age < initial_diagnosis {
+                    warnings.push(warning(
+                        Kind::AgeOrdering,
+                        subject,
+                        sample,
+                        format!(
+                            "the disease phase of the sample is `{disease_phase}` at {age} days \
+                             of age, which is before the initial diagnosis of the subject \
+                             ({initial_diagnosis} days)"
+                        ),
+                    ));
+                }
+            }
+        }
+    }
+
+    let subject_metadata = match subject.metadata() {
+        Some(metadata) => metadata,
+        None => return warnings,
+    };
+
+    if let (Some(VitalStatus::Alive), Some(age_at_vital_status)) = (
+        subject_metadata.vital_status().map(|field| field.value()),
+        subject_metadata.age_at_vital_status(),
+    ) {
+        let age_at_vital_status = age_at_vital_status.value();
+
+        for (sample, metadata) in &metadata {
+            let ages = [
+                (
+                    "diagnosed",
+                    metadata.age_at_diagnosis().map(|field| **field.value()),
+                ),
+                (
+                    "collected",
+                    metadata.age_at_collection().map(|field| **field.value()),
+                ),
+            ];
+
+            for (event, age) in ages {
+                if let Some(age) = age.filter(|age| *age > **age_at_vital_status) {
+                    warnings.push(warning(
+                        Kind::AgeOrdering,
+                        subject,
+                        sample,
+                        format!(
+                            "the sample was {event} at {age} days of age, which is after the \
+                             age at vital status of the subject ({age_at_vital_status} days)"
+                        ),
+                    ));
+                }
+            }
+        }
+    }
+
+    warnings
+}
+
+/// Checks that the tumor fields of each sample agree with its tissue type.
+///
+/// A warning is reported when a sample with a `Normal` tissue type has a
+/// tumor classification (e.g., `Primary`) or a tumor grade (e.g., `G1 Low
+/// Grade`) that describes a tumor.
+///
+/// # Examples
+///
+/// ```
+/// use ccdi_models as models;
+///
+/// use models::namespace;
+/// use models::subject::Kind;
+/// use models::validation;
+/// use models::Subject;
+///
+/// let namespace = namespace::Identifier::new(
+///     "example-organization"
+///         .parse::<models::organization::Identifier>()
+///         .unwrap(),
+///     namespace::identifier::Name::try_new("ExampleNamespace").unwrap(),
+/// );
+///
+/// let subject = Subject::new(
+///     models::subject::Identifier::new(namespace, "Subject1"),
+///     Kind::Participant,
+///     None,
+///     None,
+/// );
+///
+/// assert!(validation::tumor_fields_coherence(&subject, &[]).is_empty());
+/// ```
+pub fn tumor_fields_coherence(subject: &Subject, samples: &[&Sample]) -> Vec<ValidationWarning> {
+    let mut warnings = Vec::new();
+
+    for sample in samples {
+        let metadata = match sample.metadata() {
+            Some(metadata) => metadata,
+            None => continue,
+        };
+
+        let tissue_type = match metadata.tissue_type().map(|field| field.value()) {
+            Some(tissue_type @ TissueType::Normal) => tissue_type,
+            _ => continue,
+        };
+
+        if let Some(
+            tumor_classification @ (TumorClassification::Metastatic
+            | TumorClassification::Primary
+            | TumorClassification::Regional),
+        ) = metadata.tumor_classification().map(|field| field.value())
+        {
+            warnings.push(warning(
+                Kind::TumorFieldsCoherence,
+                subject,
+                sample,
+                format!(
+                    "the tissue type of the sample is `{tissue_type}`, but its tumor \
+                     classification is `{tumor_classification}`"
+                ),
+            ));
+        }
+
+        if let Some(
+            tumor_grade @ (TumorGrade::G1LowGrade
+            | TumorGrade::G2IntermediateGrade
+            | TumorGrade::G3HighGrade
+            | TumorGrade::G4Anaplastic
+            | TumorGrade::GBBorderline),
+        ) = metadata.tumor_grade().map(|field| field.value())
+        {
+            warnings.push(warning(
+                Kind::TumorFieldsCoherence,
+                subject,
+                sample,
+                format!(
+                    "the tissue type of the sample is `{tissue_type}`, but its tumor grade is \
+                     `{tumor_grade}`"
+                ),
+            ));
+        }
+    }
+
+    warnings
+}
+
+/// Checks that no sample was collected after the subject was recorded as
+/// dead.
+///
+/// A warning is reported when the vital status of the subject is `Dead` and a
+/// sample was collected after the age at vital status of the subject.
+///
+/// # Examples
+///
+/// ```
+/// use ccdi_models as models;
+///
+/// use models::namespace;
+/// use models::subject::Kind;
+/// use models::validation;
+/// use models::Subject;
+///
+/// let namespace = namespace::Identifier::new(
+///     "example-organization"
+///         .parse::<models::organization::Identifier>()
+///         .unwrap(),
+///     namespace::identifier::Name::try_new("ExampleNamespace").unwrap(),
+/// );
+///
+/// let subject = Subject::new(
+///     models::subject::Identifier::new(namespace, "Subject1"),
+///     Kind::Participant,
+///     None,
+///     None,
+/// );
+///
+/// assert!(validation::dead_before_collection(&subject, &[]).is_empty());
+/// ```
+pub fn dead_before_collection(subject: &Subject, samples: &[&Sample]) -> Vec<ValidationWarning> {
+    let metadata = match subject.metadata() {
+        Some(metadata) => metadata,
+        None => return Vec::new(),
+    };
+
+    let age_at_vital_status = match (
+        metadata.vital_status().map(|field| field.value()),
+        metadata.age_at_vital_status(),
+    ) {
+        (Some(VitalStatus::Dead), Some(age_at_vital_status)) => age_at_vital_status.value(),
+        _ => return Vec::new(),
+    };
+
+    samples
+        .iter()
+        .filter_map(|sample| {
+            let age_at_collection = sample.metadata()?.age_at_collection()?.value();
+
+            match **age_at_collection > **age_at_vital_status {
+                true => Some(warning(
+                    Kind::DeadBeforeCollection,
+                    subject,
+                    sample,
+                    format!(
+                        "the sample was collected at {age_at_collection} days of age, but the \
+                         subject was recorded as dead at {age_at_vital_status} days of age"
+                    ),
+                )),
+                false => None,
+            }
+        })
+        .collect()
+}
+
+/// Checks a subject against every [`Rule`] within [`RULES`] given the samples
+/// collected from it.
+///
+/// Samples that do not refer to the subject are ignored. Warnings are
+/// returned in the order of the rules within [`RULES`].
+///
+/// # Examples
+///
+/// ```
+/// use ccdi_models as models;
+///
+/// use models::namespace;
+/// use models::subject::Kind;
+/// use models::validation;
+/// use models::Subject;
+///
+/// let namespace = namespace::Identifier::new(
+///     "example-organization"
+///         .parse::<models::organization::Identifier>()
+///         .unwrap(),
+///     namespace::identifier::Name::try_new("ExampleNamespace").unwrap(),
+/// );
+///
+/// let subject = Subject::new(
+///     models::subject::Identifier::new(namespace, "Subject1"),
+///     Kind::Participant,
+///     None,
+///     None,
+/// );
+///
+/// // A subject without any samples never produces a warning.
+/// assert!(validation::validate(&subject, &[]).is_empty());
+/// ```
+pub fn validate(subject: &Subject, samples: &[&Sample]) -> Vec<ValidationWarning> {
+    let samples = samples
+        .iter()
+        .copied()
+        .filter(|sample| sample.subject() == subject.id())
+        .collect::<Vec<_>>();
+
+    RULES
+        .iter()
+        .flat_map(|rule| rule(subject, &samples))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use ordered_float::OrderedFloat;
+
+    use crate::metadata::field::unowned::sample::AgeAtCollection;
+    use crate::metadata::field::unowned::sample::AgeAtDiagnosis;
+    use crate::metadata::field::unowned::sample::DiseasePhase as DiseasePhaseField;
+    use crate::metadata::field::unowned::sample::TissueType as TissueTypeField;
+    use crate::metadata::field::unowned::sample::TumorClassification as TumorClassificationField;
+    use crate::metadata::field::unowned::sample::TumorGrade as TumorGradeField;
+    use crate::metadata::field::unowned::subject::AgeAtVitalStatus;
+    use crate::metadata::field::unowned::subject::VitalStatus as VitalStatusField;
+    use crate::namespace;
+
+    use super::*;
+
+    fn namespace_id() -> namespace::Identifier {
+        namespace::Identifier::new(
+            "example-organization"
+                .parse::<crate::organization::Identifier>()
+                .unwrap(),
+            namespace::identifier::Name::try_new("ExampleNamespace").unwrap(),
+        )
+    }
+
+    fn subject(vital_status: VitalStatus, age_at_vital_status: f32) -> Subject {
+        Subject::new(
+            subject::Identifier::new(namespace_id(), "Subject1"),
+            subject::Kind::Participant,
+            None,
+            Some(
+                subject::metadata::Builder::default()
+                    .vital_status(VitalStatusField::new(vital_status, None, None, None))
+                    .age_at_vital_status(AgeAtVitalStatus::new(
+                        subject::metadata::AgeAtVitalStatus::from(OrderedFloat(
+                            age_at_vital_status,
+                        )),
+                        None,
+                        None,
+                        None,
+                    ))
+                    .build(),
+            ),
+        )
+    }
+
+    fn sample(name: &str, builder: sample::metadata::Builder) -> Sample {
+        Sample::new(
+            sample::Identifier::new(namespace_id(), name),
+            subject::Identifier::new(namespace_id(), "Subject1"),
+            None,
+            Some(builder.build()),
+        )
+    }
+
+    fn ages(
+        disease_phase: DiseasePhase,
+        age_at_diagnosis: f32,
+        age_at_collection: f32,
+    ) -> sample::metadata::Builder {
+        sample::metadata::Builder::default()
+            .disease_phase(DiseasePhaseField::new(disease_phase, None, None, None))
+            .age_at_diagnosis(AgeAtDiagnosis::new(
+                sample::metadata::AgeAtDiagnosis::from(OrderedFloat(age_at_diagnosis)),
+                None,
+                None,
+                None,
+            ))
+            .age_at_collection(AgeAtCollection::new(
+                sample::metadata::AgeAtCollection::from(OrderedFloat(age_at_collection)),
+                None,
+                None,
+                None,
+            ))
+    }
+
+    fn tumor(
+        tissue_type: TissueType,
+        tumor_classification: TumorClassification,
+        tumor_grade: TumorGrade,
+    ) -> sample::metadata::Builder {
+        sample::metadata::Builder::default()
+            .tissue_type(TissueTypeField::new(tissue_type, None, None, None))
+            .tumor_classification(TumorClassificationField::new(
+                tumor_classification,
+                None,
+                None,
+                None,
+            ))
+            .tumor_grade(TumorGradeField::new(tumor_grade, None, None, None))
+    }
+
+    #[test]
+    fn it_accepts_ordered_ages() {
+        let subject = subject(VitalStatus::Alive, 3650.0);
+        let samples = [
+            sample(
+                "Sample1",
+                ages(DiseasePhase::InitialDiagnosis, 365.0, 370.0),
+            ),
+            sample("Sample2", ages(DiseasePhase::Relapse, 730.0, 730.0)),
+        ];
+
+        assert!(age_ordering(&subject, &samples.iter().collect::<Vec<_>>()).is_empty());
+    }
+
+    #[test]
+    fn it_warns_about_a_sample_collected_before_its_diagnosis() {
+        let subject = subject(VitalStatus::Alive, 3650.0);
+        let samples = [sample(
+            "Sample1",
+            ages(DiseasePhase::InitialDiagnosis, 365.0, 300.0),
+        )];
+
+        let warnings = age_ordering(&subject, &samples.iter().collect::<Vec<_>>());
+
+        assert_eq!(warnings.len(), 1);
+        assert_eq!(warnings[0].kind(), Kind::AgeOrdering);
+        assert_eq!(warnings[0].sample().unwrap().name(), "Sample1");
+        assert_eq!(
+            warnings[0].message(),
+            "the sample was collected at 300 days of age, which is before its age at diagnosis \
+             (365 days)"
+        );
+    }
+
+    #[test]
+    fn it_warns_about_a_relapse_before_the_initial_diagnosis() {
+        let subject = subject(VitalStatus::Alive, 3650.0);
+        let samples = [
+            sample(
+                "Sample1",
+                ages(DiseasePhase::InitialDiagnosis, 730.0, 730.0),
+            ),
+            sample("Sample2", ages(DiseasePhase::Relapse, 365.0, 365.0)),
+        ];
+
+        let warnings = age_ordering(&subject, &samples.iter().collect::<Vec<_>>());
+
+        assert_eq!(warnings.len(), 1);
+        assert_eq!(warnings[0].sample().unwrap().name(), "Sample2");
+        assert_eq!(
+            warnings[0].message(),
+            "the disease phase of the sample is `Relapse` at 365 days of age, which is before \
+             the initial diagnosis of the subject (730 days)"
+        );
+    }
+
+    #[test]
+    fn it_warns_about_a_sample_after_the_age_at_vital_status_of_a_living_subject() {
+        let subject = subject(VitalStatus::Alive, 365.0);
+        let samples = [sample(
+            "Sample1",
+            ages(DiseasePhase::InitialDiagnosis, 365.0, 400.0),
+        )];
+
+        let warnings = age_ordering(&subject, &samples.iter().collect::<Vec<_>>());
+
+        assert_eq!(warnings.len(), 1);
+        assert_eq!(
+            warnings[0].to_string(),
+            "age-ordering: the sample was collected at 400 days of age, which is after the age \
+             at vital status of the subject (365 days)"
+        );
+    }
+
+    #[test]
+    fn it_accepts_a_tumor_sample_with_tumor_fields() {
+        let subject = subject(VitalStatus::Alive, 3650.0);
+        let samples = [
+            sample(
+                "Sample1",
+                tumor(
+                    TissueType::Tumor,
+                    TumorClassification::Primary,
+                    TumorGrade::G3HighGrade,
+                ),
+            ),
+            sample(
+                "Sample2",
+                tumor(
+                    TissueType::Normal,
+                    TumorClassification::NotReported,
+                    TumorGrade::NotApplicable,
+                ),
+            ),
+        ];
+
+        assert!(tumor_fields_coherence(&subject, &samples.iter().collect::<Vec<_>>()).is_empty());
+    }
+
+    #[test]
+    fn it_warns_about_a_normal_sample_with_tumor_fields() {
+        let subject = subject(VitalStatus::Alive, 3650.0);
+        let samples = [sample(
+            "Sample1",
+            tumor(
+                TissueType::Normal,
+                TumorClassification::Metastatic,
+                TumorGrade::G1LowGrade,
+            ),
+        )];
+
+        let warnings = tumor_fields_coherence(&subject, &samples.iter().collect::<Vec<_>>());
+
+        assert_eq!(warnings.len(), 2);
+        assert!(warnings
+            .iter()
+            .all(|warning| warning.kind() == Kind::TumorFieldsCoherence));
+        assert_eq!(
+            warnings[0].message(),
+            "the tissue type of the sample is `Normal`, but its tumor classification is \
+             `Metastatic`"
+        );
+    }
+
+    #[test]
+    fn it_accepts_a_sample_collected_at_death() {
+        let subject = subject(VitalStatus::Dead, 3650.0);
+        let samples = [sample(
+            "Sample1",
+            ages(DiseasePhase::PostMortem, 365.0, 3650.0),
+        )];
+
+        assert!(dead_before_collection(&subject, &samples.iter().collect::<Vec<_>>()).is_empty());
+    }
+
+    #[test]
+    fn it_warns_about_a_sample_collected_after_death() {
+        let subject = subject(VitalStatus::Dead, 3650.0);
+        let samples = [sample(
+            "Sample1",
+            ages(DiseasePhase::Relapse, 365.0, 4000.0),
+        )];
+
+        let warnings = dead_before_collection(&subject, &samples.iter().collect::<Vec<_>>());
+
+        assert_eq!(warnings.len(), 1);
+        assert_eq!(warnings[0].kind(), Kind::DeadBeforeCollection);
+        assert_eq!(warnings[0].subject().name().as_str(), "Subject1");
+        assert_eq!(
+            warnings[0].message(),
+            "the sample was collected at 4000 days of age, but the subject was recorded as \
+             dead at 3650 days of age"
+        );
+    }
+
+    #[test]
+    fn it_ignores_samples_of_other_subjects() {
+        let subject = subject(VitalStatus::Dead, 365.0);
+        let other = Sample::new(
+            sample::Identifier::new(namespace_id(), "Sample1"),
+            subject::Identifier::new(namespace_id(), "Subject2"),
+            None,
+            Some(ages(DiseasePhase::Relapse, 730.0, 730.0).build()),
+        );
+
+        assert!(validate(&subject, &[&other]).is_empty());
+    }
+}
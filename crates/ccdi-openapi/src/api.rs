@@ -2,13 +2,24 @@ use models::metadata::field;
 use models::metadata::fields;
 use utoipa::Modify;
 use utoipa::OpenApi;
+use utoipa::ToSchema;
 
 use ccdi_cde as cde;
 use ccdi_models as models;
 use ccdi_server as server;
 
+use cde::CDE;
+use server::filter::alias;
+use server::filter::alias::Aliases;
+use server::filter::provenance;
+use server::filter::rule::Rules;
+use server::params;
 use server::responses;
 use utoipa::openapi;
+use utoipa::openapi::path::ParameterIn;
+use utoipa::openapi::PathItemType;
+use utoipa::openapi::RefOr;
+use utoipa::openapi::Schema;
 
 /// The OpenAPI specification.
 #[derive(Debug, OpenApi)]
@@ -100,6 +111,14 @@ use utoipa::openapi;
             name = "Info",
             description = "Information about the API implementation itself."
         ),
+        (
+            name = "Aggregate",
+            description = "Counts that span multiple kinds of entities."
+        ),
+        (
+            name = "Search",
+            description = "Search for entities across every kind of entity."
+        ),
         (
             name = "Experimental",
             description = "Endpoints and features in an experimental phase."
@@ -108,30 +127,52 @@ use utoipa::openapi;
     paths(
         // Subject routes.
         server::routes::subject::subject_index,
+        server::routes::subject::subject_filter,
         server::routes::subject::subject_show,
+        server::routes::subject::subject_samples,
+        server::routes::subject::subject_files,
         server::routes::subject::subjects_by_count,
+        server::routes::subject::subject_values,
         server::routes::subject::subject_summary,
+        server::routes::subject::subject_demographics,
 
         // Sample routes.
         server::routes::sample::sample_index,
+        server::routes::sample::sample_filter,
         server::routes::sample::sample_show,
+        server::routes::sample::sample_files,
         server::routes::sample::samples_by_count,
+        server::routes::sample::samples_by_group,
+        server::routes::sample::sample_values,
         server::routes::sample::sample_summary,
+        server::routes::sample::sample_validation,
 
         // File routes.
         server::routes::file::file_index,
+        server::routes::file::file_filter,
+        server::routes::file::file_lookup,
+        server::routes::file::file_download_manifest,
         server::routes::file::file_show,
         server::routes::file::files_by_count,
+        server::routes::file::file_values,
         server::routes::file::file_summary,
 
+        // Aggregate routes.
+        server::routes::aggregate::aggregate_counts,
+
+        // Search routes.
+        server::routes::search::search_index,
+
         // Metadata.
         server::routes::metadata::metadata_fields_subject,
         server::routes::metadata::metadata_fields_sample,
         server::routes::metadata::metadata_fields_file,
+        server::routes::metadata::metadata_field_show,
 
         // Namespaces.
         server::routes::namespace::namespace_index,
         server::routes::namespace::namespace_show,
+        server::routes::namespace::namespace_summary,
 
         // Organizations.
         server::routes::organization::organization_index,
@@ -139,6 +180,7 @@ use utoipa::openapi;
 
         // Information.
         server::routes::info::info_index,
+        server::routes::info::info_versions,
 
         // Experimental.
         server::routes::sample_diagnosis::sample_diagnosis_index,
@@ -176,6 +218,8 @@ use utoipa::openapi;
         cde::v2::sample::TumorGrade,
         cde::v1::sample::TumorTissueMorphology,
         models::sample::metadata::AgeAtCollection,
+        models::sample::metadata::TumorTissueMorphology,
+        models::sample::metadata::TumorTissueSite,
 
         // Harmonized file metadata elements.
         cde::v1::file::Name,
@@ -184,6 +228,8 @@ use utoipa::openapi;
         models::file::metadata::Checksums,
         cde::v1::file::checksum::MD5,
         cde::v1::file::Description,
+        models::file::metadata::DrsUri,
+        models::file::metadata::AccessUrl,
 
         // General harmonized field concepts.
         field::Details,
@@ -225,6 +271,7 @@ use utoipa::openapi;
         field::unowned::sample::TumorGrade,
         field::unowned::sample::TumorTissueMorphology,
         field::unowned::sample::AgeAtCollection,
+        field::unowned::sample::TumorTissueSite,
         field::unowned::sample::Identifier,
 
         // Harmonized file fields.
@@ -232,6 +279,8 @@ use utoipa::openapi;
         field::unowned::file::Size,
         field::unowned::file::Checksums,
         field::unowned::file::Description,
+        field::unowned::file::DrsUri,
+        field::unowned::file::AccessUrl,
 
         // Harmonized namespace fields.
         field::unowned::namespace::StudyFundingId,
@@ -275,7 +324,10 @@ use utoipa::openapi;
         models::gateway::AnonymousOrReference,
         models::gateway::closed::Status,
         models::gateway::Closed,
+        models::gateway::Controlled,
         models::gateway::Named,
+        models::gateway::Open,
+        models::gateway::Registered,
         models::Gateway,
 
         // Metadata models.
@@ -283,6 +335,8 @@ use utoipa::openapi;
         models::metadata::field::description::Harmonized,
         models::metadata::field::description::Unharmonized,
         models::metadata::field::description::harmonized::Standard,
+        models::metadata::field::description::harmonized::PermissibleValue,
+        models::metadata::field::description::unharmonized::ValueKind,
 
         // Namespace models.
         models::Namespace,
@@ -290,6 +344,9 @@ use utoipa::openapi;
         models::namespace::Identifier,
         models::namespace::Description,
         models::namespace::Metadata,
+        models::namespace::Study,
+        models::namespace::study::Identifier,
+        models::namespace::study::Accession,
 
         // Organization models.
         models::Organization,
@@ -310,31 +367,65 @@ use utoipa::openapi;
         // Cross-entity responses.
         responses::entity::Summary,
         responses::entity::Counts,
+        responses::entity::Links,
 
         // Count by response components.
         responses::by::count::ValueCount,
 
+        // Distinct values responses.
+        responses::values::Response,
+
         // Subject responses.
         responses::Subject,
+        responses::subject::SurvivalDays,
+        responses::subject::Provenance,
         responses::Subjects,
         responses::by::count::subject::Results,
+        responses::Demographics,
+        responses::demographics::Cell,
 
         // Sample responses.
         responses::Sample,
         responses::Samples,
+        responses::sample::Embedded,
         responses::by::count::sample::Results,
+        responses::by::count::sample::CategoryCount,
+        responses::by::count::sample::NestedResults,
+        responses::by::group::sample::Group,
+        responses::by::group::sample::Response,
+        responses::validation::Finding,
+        responses::validation::Report,
 
         // File responses.
         responses::File,
         responses::Files,
         responses::by::count::file::Results,
+        responses::by::count::file::Response,
+        responses::by::count::file::SizedValueCount,
+        responses::FileManifest,
+        responses::manifest::Entry,
+
+        // Aggregate responses.
+        responses::aggregate::Results,
+        responses::aggregate::Counts,
+
+        // Search responses.
+        responses::Search,
+        responses::search::Entry,
+        responses::search::Kind,
+        responses::search::MatchedOn,
 
         // Metadata responses.
         responses::metadata::FieldDescriptions,
+        responses::metadata::FieldDescription,
+        responses::metadata::Cde,
+        responses::metadata::CdePermissibleValue,
 
         // Namespace responses.
         responses::Namespace,
         responses::Namespaces,
+        responses::namespace::Counts,
+        responses::namespace::Summary,
 
         // Organization responses.
         responses::Organization,
@@ -347,6 +438,8 @@ use utoipa::openapi;
         responses::info::data::Version,
         responses::info::data::version::About,
         responses::info::server::Information,
+        responses::info::Versions,
+        responses::info::versions::Capabilities,
 
         // Error responses.
         responses::error::Kind,
@@ -354,6 +447,10 @@ use utoipa::openapi;
     )),
     modifiers(
         &RemoveLicense,
+        &FilterAliases,
+        &FilterProvenance,
+        &FilterStrategies,
+        &DeprecatedValues,
     )
 )]
 pub struct Api;
@@ -365,3 +462,405 @@ impl Modify for RemoveLicense {
         openapi.info.license = None;
     }
 }
+
+/// Calls out the alias of each filter parameter (as declared within the filter
+/// engine's alias table) within the description of that parameter.
+pub struct FilterAliases;
+
+impl Modify for FilterAliases {
+    fn modify(&self, openapi: &mut openapi::OpenApi) {
+        describe_filter_aliases::<params::filter::Subject>(openapi, "/subject");
+        describe_filter_aliases::<params::filter::Sample>(openapi, "/sample");
+        describe_filter_aliases::<params::filter::File>(openapi, "/file");
+        describe_filter_aliases::<params::filter::File>(openapi, "/file/download-manifest");
+    }
+}
+
+/// Describes the provenance queries accepted by each filter parameter that is
+/// backed by a harmonized metadata field (as declared within the filter
+/// engine's rule table) within the description of that parameter.
+pub struct FilterProvenance;
+
+impl Modify for FilterProvenance {
+    fn modify(&self, openapi: &mut openapi::OpenApi) {
+        describe_filter_provenance::<params::filter::Subject>(openapi, "/subject");
+        describe_filter_provenance::<params::filter::Sample>(openapi, "/sample");
+        describe_filter_provenance::<params::filter::File>(openapi, "/file");
+        describe_filter_provenance::<params::filter::File>(openapi, "/file/download-manifest");
+    }
+}
+
+/// Appends the description of the match strategy of each filter parameter (as
+/// declared within the filter engine's rule table) to the description of that
+/// parameter.
+pub struct FilterStrategies;
+
+impl Modify for FilterStrategies {
+    fn modify(&self, openapi: &mut openapi::OpenApi) {
+        describe_filter_strategies::<params::filter::Subject>(openapi, "/subject");
+        describe_filter_strategies::<params::filter::Sample>(openapi, "/sample");
+        describe_filter_strategies::<params::filter::File>(openapi, "/file");
+        describe_filter_strategies::<params::filter::File>(openapi, "/file/download-manifest");
+        describe_filter_strategies::<params::filter::SubjectDiagnosis>(
+            openapi,
+            "/subject-diagnosis",
+        );
+        describe_filter_strategies::<params::filter::SampleDiagnosis>(openapi, "/sample-diagnosis");
+    }
+}
+
+/// Calls out the permissible values of each common data element that have been
+/// retired (see [`CDE::deprecated_values()`]) within the description of the
+/// schema of that common data element.
+pub struct DeprecatedValues;
+
+impl Modify for DeprecatedValues {
+    fn modify(&self, openapi: &mut openapi::OpenApi) {
+        describe_deprecated_values::<cde::v1::file::Type>(openapi);
+        describe_deprecated_values::<cde::v1::sample::DiagnosisCategory>(openapi);
+        describe_deprecated_values::<cde::v1::sample::DiseasePhase>(openapi);
+        describe_deprecated_values::<cde::v1::sample::LibrarySourceMaterial>(openapi);
+        describe_deprecated_values::<cde::v1::sample::LibraryStrategy>(openapi);
+        describe_deprecated_values::<cde::v1::sample::SpecimenMolecularAnalyteType>(openapi);
+        describe_deprecated_values::<cde::v1::sample::TissueType>(openapi);
+        describe_deprecated_values::<cde::v1::sample::TumorClassification>(openapi);
+        describe_deprecated_values::<cde::v1::subject::Race>(openapi);
+        describe_deprecated_values::<cde::v1::subject::Sex>(openapi);
+        describe_deprecated_values::<cde::v1::subject::VitalStatus>(openapi);
+        describe_deprecated_values::<cde::v2::sample::LibrarySelectionMethod>(openapi);
+        describe_deprecated_values::<cde::v2::sample::PreservationMethod>(openapi);
+        describe_deprecated_values::<cde::v2::sample::TumorGrade>(openapi);
+        describe_deprecated_values::<cde::v2::subject::Ethnicity>(openapi);
+    }
+}
+
+fn query_parameters<'a>(
+    openapi: &'a mut openapi::OpenApi,
+    path: &str,
+) -> impl Iterator<Item = &'a mut openapi::path::Parameter> {
+    openapi
+        .paths
+        .paths
+        .get_mut(path)
+        .and_then(|item| item.operations.get_mut(&PathItemType::Get))
+        .and_then(|operation| operation.parameters.as_mut())
+        .into_iter()
+        .flatten()
+        .filter(|parameter| parameter.parameter_in == ParameterIn::Query)
+}
+
+fn append_description(parameter: &mut openapi::path::Parameter, sentence: String) {
+    parameter.description = Some(match parameter.description.take() {
+        Some(description) => format!("{description}\n\n{sentence}"),
+        None => sentence,
+    });
+}
+
+fn describe_deprecated_values<'s, T: CDE + ToSchema<'s>>(openapi: &mut openapi::OpenApi) {
+    let deprecated = match T::deprecated_values() {
+        Ok(deprecated) if !deprecated.is_empty() => deprecated,
+        _ => return,
+    };
+
+    let (name, _) = T::schema();
+
+    let schema = openapi
+        .components
+        .as_mut()
+        .and_then(|components| components.schemas.get_mut(name));
+
+    if let Some(RefOr::T(Schema::Object(object))) = schema {
+        let values = deprecated
+            .iter()
+            .map(|(value, date)| format!("* `{value}` (retired on {})", date.format("%m/%d/%Y")))
+            .collect::<Vec<_>>()
+            .join("\n");
+
+        let note = format!(
+            "**Deprecated values.** The following permissible values have been retired. \
+             They are still accepted (as existing data may reference them), but they should \
+             not be used for new data.\n\n{values}"
+        );
+
+        object.description = Some(match object.description.take() {
+            Some(description) => format!("{description}\n\n{note}"),
+            None => note,
+        });
+    }
+}
+
+fn describe_filter_aliases<P: Aliases>(openapi: &mut openapi::OpenApi, path: &str) {
+    for parameter in query_parameters(openapi, path) {
+        if let Some(alias) = P::alias(&parameter.name) {
+            append_description(parameter, alias::sentence(alias));
+        }
+    }
+}
+
+fn describe_filter_provenance<P: Rules>(openapi: &mut openapi::OpenApi, path: &str) {
+    for parameter in query_parameters(openapi, path) {
+        if P::PROVENANCE.contains(&parameter.name.as_str()) {
+            append_description(parameter, provenance::SENTENCE.to_string());
+        }
+    }
+}
+
+fn describe_filter_strategies<P: Rules>(openapi: &mut openapi::OpenApi, path: &str) {
+    for parameter in query_parameters(openapi, path) {
+        if let Some(strategy) = P::strategy(&parameter.name) {
+            append_description(parameter, strategy.description());
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use introspect::Introspect;
+    use utoipa::openapi::ComponentsBuilder;
+    use utoipa::openapi::OpenApiBuilder;
+
+    use super::*;
+
+    fn assert_described<P: Rules>(path: &str) {
+        let api = Api::openapi();
+        let operation = api
+            .paths
+            .paths
+            .get(path)
+            .and_then(|item| item.operations.get(&PathItemType::Get))
+            .unwrap();
+
+        for (field, strategy) in P::RULES {
+            let parameter = operation
+                .parameters
+                .iter()
+                .flatten()
+                .find(|parameter| parameter.name == *field)
+                .unwrap_or_else(|| panic!("missing filter parameter `{field}` for `{path}`"));
+
+            let description = parameter.description.as_deref().unwrap_or_default();
+
+            assert!(
+                description.ends_with(&strategy.description()),
+                "the description of filter parameter `{field}` for `{path}` does not end \
+                 with the description of its strategy ({strategy:?})"
+            );
+        }
+    }
+
+    fn assert_aliased<P: Aliases>(path: &str) {
+        let api = Api::openapi();
+        let operation = api
+            .paths
+            .paths
+            .get(path)
+            .and_then(|item| item.operations.get(&PathItemType::Get))
+            .unwrap();
+
+        for (field, alias) in P::ALIASES {
+            let parameter = operation
+                .parameters
+                .iter()
+                .flatten()
+                .find(|parameter| parameter.name == *field)
+                .unwrap_or_else(|| panic!("missing filter parameter `{field}` for `{path}`"));
+
+            let description = parameter.description.as_deref().unwrap_or_default();
+
+            assert!(
+                description.contains(&alias::sentence(alias)),
+                "the description of filter parameter `{field}` for `{path}` does not call \
+                 out its alias (`{alias}`)"
+            );
+            assert!(
+                operation
+                    .parameters
+                    .iter()
+                    .flatten()
+                    .all(|parameter| parameter.name != *alias),
+                "the alias `{alias}` for `{path}` is documented as its own parameter"
+            );
+        }
+    }
+
+    #[test]
+    fn every_filter_alias_is_called_out() {
+        assert_aliased::<params::filter::Subject>("/subject");
+        assert_aliased::<params::filter::Sample>("/sample");
+        assert_aliased::<params::filter::File>("/file");
+        assert_aliased::<params::filter::File>("/file/download-manifest");
+    }
+
+    fn assert_provenance_described<P: Rules>(path: &str) {
+        let api = Api::openapi();
+        let operation = api
+            .paths
+            .paths
+            .get(path)
+            .and_then(|item| item.operations.get(&PathItemType::Get))
+            .unwrap();
+
+        for parameter in operation.parameters.iter().flatten() {
+            let description = parameter.description.as_deref().unwrap_or_default();
+
+            assert_eq!(
+                description.contains(provenance::SENTENCE),
+                P::PROVENANCE.contains(&parameter.name.as_str()),
+                "the description of filter parameter `{}` for `{path}` does not match its \
+                 provenance rule",
+                parameter.name
+            );
+        }
+    }
+
+    #[test]
+    fn every_provenance_filter_is_described() {
+        assert_provenance_described::<params::filter::Subject>("/subject");
+        assert_provenance_described::<params::filter::Sample>("/sample");
+        assert_provenance_described::<params::filter::File>("/file");
+        assert_provenance_described::<params::filter::File>("/file/download-manifest");
+    }
+
+    #[test]
+    fn every_filter_parameter_describes_its_strategy() {
+        assert_described::<params::filter::Subject>("/subject");
+        assert_described::<params::filter::Sample>("/sample");
+        assert_described::<params::filter::File>("/file");
+        assert_described::<params::filter::File>("/file/download-manifest");
+        assert_described::<params::filter::SubjectDiagnosis>("/subject-diagnosis");
+        assert_described::<params::filter::SampleDiagnosis>("/sample-diagnosis");
+    }
+
+    #[test]
+    fn the_main_responses_have_examples() {
+        let api = Api::openapi();
+
+        for (path, method) in [
+            ("/subject", PathItemType::Get),
+            ("/subject/filter", PathItemType::Post),
+            (
+                "/subject/{organization}/{namespace}/{name}",
+                PathItemType::Get,
+            ),
+            (
+                "/subject/{organization}/{namespace}/{name}/samples",
+                PathItemType::Get,
+            ),
+            (
+                "/subject/{organization}/{namespace}/{name}/files",
+                PathItemType::Get,
+            ),
+            ("/subject/by/{field}/count", PathItemType::Get),
+            ("/subject/summary", PathItemType::Get),
+            ("/sample", PathItemType::Get),
+            ("/sample/filter", PathItemType::Post),
+            (
+                "/sample/{organization}/{namespace}/{name}",
+                PathItemType::Get,
+            ),
+            (
+                "/sample/{organization}/{namespace}/{name}/files",
+                PathItemType::Get,
+            ),
+            ("/sample/by/{field}/count", PathItemType::Get),
+            ("/sample/summary", PathItemType::Get),
+            ("/file", PathItemType::Get),
+            ("/file/filter", PathItemType::Post),
+            ("/file/{organization}/{namespace}/{name}", PathItemType::Get),
+            ("/file/lookup", PathItemType::Get),
+            ("/file/by/{field}/count", PathItemType::Get),
+            ("/file/summary", PathItemType::Get),
+        ] {
+            let response = api
+                .paths
+                .paths
+                .get(path)
+                .and_then(|item| item.operations.get(&method))
+                .and_then(|operation| operation.responses.responses.get("200"))
+                .unwrap_or_else(|| panic!("missing successful response for `{path}`"));
+
+            let example = match response {
+                RefOr::T(response) => response
+                    .content
+                    .get("application/json")
+                    .and_then(|content| content.example.as_ref()),
+                RefOr::Ref(_) => None,
+            };
+
+            assert!(
+                example.is_some_and(|example| !example.is_null()),
+                "the successful response for `{path}` has no example"
+            );
+        }
+    }
+
+    /// **`caDSR CDE 1 v1.00`**
+    ///
+    /// A fixture with a retired permissible value.
+    ///
+    /// Link:
+    /// <https://example.com>
+    // NOTE: only the schema of the fixture is inspected, so its variants are
+    // never constructed.
+    #[allow(dead_code)]
+    #[derive(Debug, Eq, PartialEq, Introspect, ToSchema)]
+    enum Fixture {
+        /// `Current`
+        ///
+        /// * **VM Long Name**: Current
+        /// * **VM Public ID**: 1
+        /// * **Concept Code**: C1
+        ///
+        /// A permissible value that is still in use.
+        Current,
+
+        /// `Retired`
+        ///
+        /// * **VM Long Name**: Retired
+        /// * **VM Public ID**: 2
+        /// * **Concept Code**: C2
+        /// * **End Date**:     01/31/2024
+        ///
+        /// A permissible value that has been retired.
+        Retired,
+    }
+
+    impl std::fmt::Display for Fixture {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            write!(f, "{self:?}")
+        }
+    }
+
+    impl CDE for Fixture {}
+
+    fn description(openapi: &openapi::OpenApi, name: &str) -> String {
+        match openapi.components.as_ref().unwrap().schemas.get(name) {
+            Some(RefOr::T(Schema::Object(object))) => object.description.clone().unwrap(),
+            _ => panic!("missing schema `{name}`"),
+        }
+    }
+
+    #[test]
+    fn deprecated_values_are_called_out() {
+        let mut openapi = OpenApiBuilder::new()
+            .components(Some(
+                ComponentsBuilder::new().schema_from::<Fixture>().build(),
+            ))
+            .build();
+
+        describe_deprecated_values::<Fixture>(&mut openapi);
+
+        let description = description(&openapi, "Fixture");
+        assert!(description.starts_with("**`caDSR CDE 1 v1.00`**"));
+        assert!(description.contains("**Deprecated values.**"));
+        assert!(description.ends_with("* `Retired` (retired on 01/31/2024)"));
+        assert!(!description.contains("`Current` (retired"));
+    }
+
+    #[test]
+    fn current_values_are_not_called_out() {
+        let openapi = Api::openapi();
+
+        assert!(!description(&openapi, "cde.v1.subject.Sex").contains("**Deprecated values.**"));
+    }
+}
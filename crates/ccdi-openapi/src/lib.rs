@@ -8,5 +8,6 @@
 #![deny(rustdoc::broken_intra_doc_links)]
 
 mod api;
+pub mod servers;
 
 pub use api::Api;
@@ -0,0 +1,388 @@
+//! Configuring the servers listed within the specification.
+//!
+//! The specification lists the servers of the known federation members by
+//! default. Federation members that host their own copy of the specification
+//! (for example, behind Swagger UI) can add their own servers at generation
+//! time rather than editing the generated YAML by hand.
+//!
+//! Servers are provided either one at a time (see [`Servers::push()`]) or from
+//! a TOML file (see [`Servers::from_toml()`]) such as the following:
+//!
+//! ```toml
+//! [[servers]]
+//! url = "https://ccdi.example.com/api/v1"
+//! description = "Example CCDI API server"
+//! ```
+
+use serde::Deserialize;
+use url::Url;
+use utoipa::openapi;
+use utoipa::openapi::server::ServerBuilder;
+use utoipa::Modify;
+
+/// An error related to configuring [`Servers`].
+#[derive(Debug)]
+pub enum Error {
+    /// A server URL was not a valid URL.
+    InvalidUrl(String, url::ParseError),
+
+    /// A server URL did not have an `http` or `https` scheme.
+    UnsupportedScheme(String),
+
+    /// The TOML configuration could not be parsed.
+    Toml(toml::de::Error),
+}
+
+impl std::fmt::Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::InvalidUrl(url, err) => write!(f, "invalid server url `{url}`: {err}"),
+            Error::UnsupportedScheme(url) => write!(
+                f,
+                "invalid server url `{url}`: the scheme must be `http` or `https`"
+            ),
+            Error::Toml(err) => write!(f, "invalid servers configuration: {err}"),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+/// A server to list within the specification.
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq)]
+pub struct Server {
+    /// The URL of the server (exactly as provided).
+    url: String,
+
+    /// The description of the server.
+    #[serde(default)]
+    description: Option<String>,
+}
+
+impl Server {
+    /// Attempts to create a new [`Server`].
+    ///
+    /// The URL must be an absolute URL with an `http` or `https` scheme. It is
+    /// listed exactly as it was provided.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ccdi_openapi as api;
+    ///
+    /// use api::servers::Server;
+    ///
+    /// let server = Server::try_new(
+    ///     "https://ccdi.example.com/api/v1",
+    ///     Some(String::from("Example CCDI API server")),
+    /// )?;
+    ///
+    /// assert_eq!(server.url(), "https://ccdi.example.com/api/v1");
+    /// assert_eq!(server.description(), Some("Example CCDI API server"));
+    ///
+    /// assert!(Server::try_new("ftp://ccdi.example.com", None).is_err());
+    /// assert!(Server::try_new("/api/v1", None).is_err());
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn try_new(url: impl Into<String>, description: Option<String>) -> Result<Self, Error> {
+        let server = Self {
+            url: url.into(),
+            description,
+        };
+
+        server.validate()?;
+        Ok(server)
+    }
+
+    /// Gets the URL of the [`Server`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ccdi_openapi as api;
+    ///
+    /// use api::servers::Server;
+    ///
+    /// let server = Server::try_new("https://ccdi.example.com/api/v1", None)?;
+    /// assert_eq!(server.url(), "https://ccdi.example.com/api/v1");
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn url(&self) -> &str {
+        self.url.as_str()
+    }
+
+    /// Gets the description of the [`Server`] (if it exists).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ccdi_openapi as api;
+    ///
+    /// use api::servers::Server;
+    ///
+    /// let server = Server::try_new("https://ccdi.example.com/api/v1", None)?;
+    /// assert_eq!(server.description(), None);
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn description(&self) -> Option<&str> {
+        self.description.as_deref()
+    }
+
+    fn validate(&self) -> Result<(), Error> {
+        let url = Url::parse(&self.url).map_err(|err| Error::InvalidUrl(self.url.clone(), err))?;
+
+        match url.scheme() {
+            "http" | "https" => Ok(()),
+            _ => Err(Error::UnsupportedScheme(self.url.clone())),
+        }
+    }
+}
+
+/// The servers to add to the specification.
+///
+/// The servers are appended (in order) after the servers that are listed by
+/// default. If [`Servers::replace_defaults()`] is set, the default servers are
+/// removed first. When no servers are configured and the defaults are not
+/// replaced, the specification is left unchanged.
+#[derive(Clone, Debug, Default, Deserialize, Eq, PartialEq)]
+pub struct Servers {
+    /// The servers.
+    #[serde(default)]
+    servers: Vec<Server>,
+
+    /// Whether the servers replace the servers that are listed by default.
+    #[serde(default)]
+    replace_defaults: bool,
+}
+
+impl Servers {
+    /// Parses [`Servers`] from a TOML configuration.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ccdi_openapi as api;
+    ///
+    /// use api::servers::Servers;
+    ///
+    /// let servers = Servers::from_toml(
+    ///     r#"
+    /// [[servers]]
+    /// url = "https://one.example.com/api/v1"
+    /// description = "The first server"
+    ///
+    /// [[servers]]
+    /// url = "https://two.example.com/api/v1"
+    /// "#,
+    /// )?;
+    ///
+    /// assert_eq!(servers.servers().len(), 2);
+    /// assert_eq!(servers.servers()[1].description(), None);
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn from_toml(contents: &str) -> Result<Self, Error> {
+        let servers = toml::from_str::<Self>(contents).map_err(Error::Toml)?;
+
+        for server in &servers.servers {
+            server.validate()?;
+        }
+
+        Ok(servers)
+    }
+
+    /// Adds a [`Server`] after any existing servers.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ccdi_openapi as api;
+    ///
+    /// use api::servers::Server;
+    /// use api::servers::Servers;
+    ///
+    /// let mut servers = Servers::default();
+    /// servers.push(Server::try_new("https://ccdi.example.com/api/v1", None)?);
+    ///
+    /// assert_eq!(servers.servers().len(), 1);
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn push(&mut self, server: Server) {
+        self.servers.push(server);
+    }
+
+    /// Sets whether the servers replace the servers that are listed by
+    /// default.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ccdi_openapi as api;
+    ///
+    /// use api::servers::Servers;
+    ///
+    /// let mut servers = Servers::default();
+    /// servers.replace_defaults(true);
+    /// ```
+    pub fn replace_defaults(&mut self, value: bool) {
+        self.replace_defaults = value;
+    }
+
+    /// Gets the configured servers.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ccdi_openapi as api;
+    ///
+    /// use api::servers::Servers;
+    ///
+    /// assert!(Servers::default().servers().is_empty());
+    /// ```
+    pub fn servers(&self) -> &[Server] {
+        &self.servers
+    }
+}
+
+impl Modify for Servers {
+    fn modify(&self, openapi: &mut openapi::OpenApi) {
+        if self.servers.is_empty() && !self.replace_defaults {
+            return;
+        }
+
+        let mut servers = match self.replace_defaults {
+            true => Vec::new(),
+            false => openapi.servers.take().unwrap_or_default(),
+        };
+
+        servers.extend(self.servers.iter().map(|server| {
+            ServerBuilder::new()
+                .url(server.url.clone())
+                .description(server.description.clone())
+                .build()
+        }));
+
+        openapi.servers = Some(servers);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use utoipa::OpenApi as _;
+
+    use crate::Api;
+
+    use super::*;
+
+    fn servers() -> Servers {
+        let mut servers = Servers::default();
+
+        servers.push(
+            Server::try_new(
+                "https://one.example.com/api/v1",
+                Some(String::from("The first server")),
+            )
+            .unwrap(),
+        );
+        servers.push(
+            Server::try_new(
+                "https://two.example.com/api/v1",
+                Some(String::from("The second server")),
+            )
+            .unwrap(),
+        );
+
+        servers
+    }
+
+    #[test]
+    fn it_leaves_the_specification_unchanged_by_default() {
+        let mut api = Api::openapi();
+        Servers::default().modify(&mut api);
+
+        assert_eq!(api.to_yaml().unwrap(), Api::openapi().to_yaml().unwrap());
+    }
+
+    #[test]
+    fn it_appends_servers_in_order() {
+        let mut api = Api::openapi();
+        servers().modify(&mut api);
+
+        let yaml = api.to_yaml().unwrap();
+
+        let default = yaml.find("url: https://ccdi.stjude.cloud/api/v1").unwrap();
+        let one = yaml
+            .find("- url: https://one.example.com/api/v1\n  description: The first server\n")
+            .unwrap();
+        let two = yaml
+            .find("- url: https://two.example.com/api/v1\n  description: The second server\n")
+            .unwrap();
+
+        assert!(default < one);
+        assert!(one < two);
+    }
+
+    #[test]
+    fn it_replaces_the_default_servers() {
+        let mut servers = servers();
+        servers.replace_defaults(true);
+
+        let mut api = Api::openapi();
+        servers.modify(&mut api);
+
+        let urls = api
+            .servers
+            .unwrap()
+            .into_iter()
+            .map(|server| server.url)
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            urls,
+            vec![
+                "https://one.example.com/api/v1",
+                "https://two.example.com/api/v1"
+            ]
+        );
+    }
+
+    #[test]
+    fn it_parses_servers_from_toml() {
+        let servers = Servers::from_toml(
+            r#"
+replace_defaults = true
+
+[[servers]]
+url = "https://one.example.com/api/v1"
+description = "The first server"
+"#,
+        )
+        .unwrap();
+
+        assert!(servers.replace_defaults);
+        assert_eq!(servers.servers()[0].url(), "https://one.example.com/api/v1");
+    }
+
+    #[test]
+    fn it_rejects_invalid_urls() {
+        let err = Servers::from_toml(
+            r#"
+[[servers]]
+url = "not a url"
+"#,
+        )
+        .unwrap_err();
+
+        assert!(matches!(err, Error::InvalidUrl(_, _)));
+
+        let err = Server::try_new("ftp://one.example.com", None).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "invalid server url `ftp://one.example.com`: the scheme must be `http` or `https`"
+        );
+    }
+}
@@ -0,0 +1,783 @@
+//! Snapshot tests for the JSON shape of every endpoint.
+//!
+//! Each route listed in the OpenAPI specification has (at least) one
+//! representative request below whose response is snapshotted using
+//! [`insta`]. The server is booted in-process against a small, fixed set of
+//! entities so that the responses are the same on every run.
+//!
+//! If one of these tests fails, the wire format of the API has changed. When
+//! the change is intentional, review (and accept) the new snapshots using
+//! `cargo insta review` and describe the change in the `CHANGELOG.md` at the
+//! root of the repository.
+
+use std::collections::BTreeMap;
+use std::sync::Mutex;
+
+use actix_web::test::call_service;
+use actix_web::test::init_service;
+use actix_web::test::read_body_json;
+use actix_web::test::TestRequest;
+use actix_web::web::Data;
+use actix_web::App;
+use insta::Settings;
+use nonempty::NonEmpty;
+use serde::Serialize;
+use serde_json::Value;
+use utoipa::openapi::PathItemType;
+use utoipa::OpenApi as _;
+
+use ccdi_cde as cde;
+use ccdi_models as models;
+use ccdi_openapi as api;
+use ccdi_server as server;
+
+use models::metadata::field;
+use models::File;
+use models::Sample;
+use models::Subject;
+use server::routes::aggregate;
+use server::routes::file;
+use server::routes::info;
+use server::routes::metadata;
+use server::routes::namespace;
+use server::routes::organization;
+use server::routes::sample;
+use server::routes::sample_diagnosis;
+use server::routes::search;
+use server::routes::subject;
+use server::routes::subject_diagnosis;
+
+/// The message shown alongside every snapshot (and every snapshot failure).
+const CHANGELOG_REMINDER: &str = "This snapshot captures the JSON shape of an API response. If \
+                                  this snapshot changed, the wire format of the API changed: \
+                                  accept the change with `cargo insta review` only if it is \
+                                  intentional, and add an entry describing it to the \
+                                  `[Unreleased]` section of `CHANGELOG.md`.";
+
+/// Every route (as written in the OpenAPI specification) that is covered by a
+/// snapshot test below.
+///
+/// When adding a new route, add a snapshot test for it below and then add the
+/// route here.
+const SNAPSHOTTED_ROUTES: &[&str] = &[
+    "/subject",
+    "/subject/filter",
+    "/subject/{organization}/{namespace}/{name}",
+    "/subject/{organization}/{namespace}/{name}/samples",
+    "/subject/{organization}/{namespace}/{name}/files",
+    "/subject/by/{field}/count",
+    "/subject/values/{field}",
+    "/subject/summary",
+    "/subject/demographics",
+    "/sample",
+    "/sample/filter",
+    "/sample/{organization}/{namespace}/{name}",
+    "/sample/{organization}/{namespace}/{name}/files",
+    "/sample/by/{field}/count",
+    "/sample/by/{field}/group",
+    "/sample/values/{field}",
+    "/sample/summary",
+    "/sample/validation",
+    "/file",
+    "/file/filter",
+    "/file/lookup",
+    "/file/download-manifest",
+    "/file/{organization}/{namespace}/{name}",
+    "/file/by/{field}/count",
+    "/file/values/{field}",
+    "/file/summary",
+    "/aggregate/counts",
+    "/metadata/fields/subject",
+    "/metadata/fields/sample",
+    "/metadata/fields/file",
+    "/metadata/fields/namespace",
+    "/metadata/fields/organization",
+    "/metadata/fields/{entity}/{field_name}",
+    "/namespace",
+    "/namespace/{organization}/{namespace}",
+    "/namespace/{organization}/{namespace}/summary",
+    "/organization",
+    "/organization/{name}",
+    "/info",
+    "/info/versions",
+    "/sample-diagnosis",
+    "/subject-diagnosis",
+    "/search",
+];
+
+/// A response as captured within a snapshot.
+#[derive(Debug, Serialize)]
+struct Response {
+    /// The status code of the response.
+    status: u16,
+
+    /// The body of the response.
+    body: Value,
+}
+
+fn namespace() -> models::namespace::Identifier {
+    namespace::NAMESPACES
+        .get("example-organization-namespace-one")
+        .unwrap()
+        .id()
+        .clone()
+}
+
+fn subjects() -> subject::Store {
+    let subjects = vec![
+        Subject::new(
+            models::subject::Identifier::new(namespace(), "Subject1"),
+            models::subject::Kind::Participant,
+            None,
+            Some(
+                models::subject::metadata::Builder::default()
+                    .sex(field::unowned::subject::Sex::new(
+                        cde::v1::subject::Sex::Female,
+                        None,
+                        None,
+                        None,
+                    ))
+                    .vital_status(field::unowned::subject::VitalStatus::new(
+                        cde::v1::subject::VitalStatus::Alive,
+                        None,
+                        None,
+                        None,
+                    ))
+                    .common(
+                        models::metadata::common::metadata::Builder::default()
+                            .push_deposition(
+                                models::metadata::common::deposition::Accession::dbGaP(
+                                    cde::v1::deposition::DbgapPhsAccession::from(String::from(
+                                        "phs000000.v1.p1",
+                                    )),
+                                ),
+                            )
+                            .build(),
+                    )
+                    // NOTE: these are intentionally inserted out of order to
+                    // lock the (alphabetical) serialization order of
+                    // unharmonized keys within the snapshots.
+                    .insert_unharmonized(
+                        "treatment_arm",
+                        field::UnharmonizedField::Unowned(field::unowned::Field::new(
+                            Value::String(String::from("A")),
+                            None,
+                            None,
+                            None,
+                        )),
+                    )
+                    .unwrap()
+                    .insert_unharmonized(
+                        "enrollment_site",
+                        field::UnharmonizedField::Unowned(field::unowned::Field::new(
+                            Value::String(String::from("Example Hospital")),
+                            None,
+                            None,
+                            None,
+                        )),
+                    )
+                    .unwrap()
+                    .build(),
+            ),
+        ),
+        Subject::new(
+            models::subject::Identifier::new(namespace(), "Subject2"),
+            models::subject::Kind::Participant,
+            None,
+            Some(
+                models::subject::metadata::Builder::default()
+                    .sex(field::unowned::subject::Sex::new(
+                        cde::v1::subject::Sex::Male,
+                        None,
+                        None,
+                        None,
+                    ))
+                    .build(),
+            ),
+        ),
+    ];
+
+    subject::Store {
+        subjects: Mutex::new(subjects),
+    }
+}
+
+fn samples() -> sample::Store {
+    let samples = vec![
+        Sample::new(
+            models::sample::Identifier::new(namespace(), "Sample1"),
+            models::subject::Identifier::new(namespace(), "Subject1"),
+            None,
+            Some(
+                models::sample::metadata::Builder::default()
+                    .diagnosis(field::unowned::sample::Diagnosis::new(
+                        models::sample::metadata::Diagnosis::from(String::from("Neuroblastoma")),
+                        None,
+                        None,
+                        None,
+                    ))
+                    .disease_phase(field::unowned::sample::DiseasePhase::new(
+                        cde::v1::sample::DiseasePhase::InitialDiagnosis,
+                        None,
+                        None,
+                        None,
+                    ))
+                    .build(),
+            ),
+        ),
+        Sample::new(
+            models::sample::Identifier::new(namespace(), "Sample2"),
+            models::subject::Identifier::new(namespace(), "Subject2"),
+            None,
+            None,
+        ),
+    ];
+
+    sample::Store {
+        samples: Mutex::new(samples),
+    }
+}
+
+fn files() -> file::Store {
+    let files = vec![File::new(
+        models::file::Identifier::new(namespace(), cde::v1::file::Name::new("File1.txt")),
+        NonEmpty::new(models::sample::Identifier::new(namespace(), "Sample1")),
+        None,
+        Some(
+            models::file::metadata::Builder::default()
+                .r#type(field::unowned::file::Type::new(
+                    cde::v1::file::Type::TXT,
+                    None,
+                    None,
+                    None,
+                ))
+                .size(field::unowned::file::Size::new(
+                    cde::v1::file::Size::new(42),
+                    None,
+                    None,
+                    None,
+                ))
+                .build(),
+        ),
+    )];
+
+    file::Store {
+        files: Mutex::new(files),
+    }
+}
+
+/// Performs a `GET` request against an in-process server populated with the
+/// fixed entities above.
+async fn get(uri: &str) -> Response {
+    call(TestRequest::get().uri(uri)).await
+}
+
+/// Performs a `POST` request with a JSON `body` against an in-process server
+/// populated with the fixed entities above.
+async fn post(uri: &str, body: Value) -> Response {
+    call(TestRequest::post().uri(uri).set_json(body)).await
+}
+
+/// Performs a request against an in-process server populated with the fixed
+/// entities above.
+async fn call(request: TestRequest) -> Response {
+    let subjects = Data::new(subjects());
+    let samples = Data::new(samples());
+    let files = Data::new(files());
+
+    let app = init_service(
+        App::new()
+            .configure(subject::configure(subjects.clone()))
+            .configure(sample::configure(samples.clone()))
+            .configure(file::configure(files.clone()))
+            .configure(aggregate::configure(
+                subjects.clone(),
+                samples.clone(),
+                files.clone(),
+            ))
+            .configure(search::configure(subjects.clone(), samples.clone(), files))
+            .configure(metadata::configure())
+            .configure(namespace::configure())
+            .configure(organization::configure())
+            .configure(info::configure())
+            .configure(sample_diagnosis::configure(samples))
+            .configure(subject_diagnosis::configure(subjects)),
+    )
+    .await;
+
+    let response = call_service(&app, request.to_request()).await;
+    let status = response.status().as_u16();
+    let body = read_body_json::<Value, _>(response).await;
+
+    Response { status, body }
+}
+
+/// Snapshots a [`Response`] with the intentionally variable fields redacted.
+fn assert_snapshot(name: &str, response: Response) {
+    let mut settings = Settings::clone_current();
+    settings.set_description(CHANGELOG_REMINDER);
+    settings.set_omit_expression(true);
+
+    settings.bind(|| {
+        insta::assert_json_snapshot!(name, response, {
+            // The time of the last data update changes on every run.
+            ".body.data.last_updated" => "[last_updated]",
+            // The identity of the server is specific to each deployment.
+            ".body.server" => "[server]",
+            // The commit depends on how the server was built.
+            ".body.commit" => "[commit]",
+        });
+    });
+}
+
+#[test]
+fn every_route_has_a_snapshot_test() {
+    let missing = api::Api::openapi()
+        .paths
+        .paths
+        .keys()
+        .filter(|route| !SNAPSHOTTED_ROUTES.contains(&route.as_str()))
+        .cloned()
+        .collect::<Vec<_>>();
+
+    assert!(
+        missing.is_empty(),
+        "the following routes are missing a snapshot test: {}. Please add a snapshot test for \
+         each route in `crates/ccdi-openapi/tests/snapshots.rs` (and add it to \
+         `SNAPSHOTTED_ROUTES`). Remember that any change to the wire format of the API must \
+         also be described in `CHANGELOG.md`.",
+        missing.join(", ")
+    );
+}
+
+#[test]
+fn filter_parameter_descriptions() {
+    // The descriptions of the filter parameters are generated from the match
+    // strategies within the filter engine's rule table, so changing a strategy
+    // changes this snapshot.
+    let api = api::Api::openapi();
+
+    let descriptions = [
+        "/subject",
+        "/sample",
+        "/file",
+        "/subject-diagnosis",
+        "/sample-diagnosis",
+    ]
+    .into_iter()
+    .map(|path| {
+        let parameters = api.paths.paths[path].operations[&PathItemType::Get]
+            .parameters
+            .iter()
+            .flatten()
+            .map(|parameter| (parameter.name.clone(), parameter.description.clone()))
+            .collect::<BTreeMap<_, _>>();
+
+        (path, parameters)
+    })
+    .collect::<BTreeMap<_, _>>();
+
+    let mut settings = Settings::clone_current();
+    settings.set_description(CHANGELOG_REMINDER);
+    settings.set_omit_expression(true);
+
+    settings.bind(|| {
+        insta::assert_json_snapshot!("filter_parameter_descriptions", descriptions);
+    });
+}
+
+#[actix_web::test]
+async fn subject_index() {
+    assert_snapshot("subject_index", get("/subject").await);
+}
+
+#[actix_web::test]
+async fn subject_index_filtered() {
+    assert_snapshot("subject_index_filtered", get("/subject?sex=F").await);
+}
+
+#[actix_web::test]
+async fn subject_index_invalid_pagination() {
+    assert_snapshot(
+        "subject_index_invalid_pagination",
+        get("/subject?page=0").await,
+    );
+}
+
+#[actix_web::test]
+async fn subject_filter() {
+    assert_snapshot(
+        "subject_filter",
+        post(
+            "/subject/filter",
+            serde_json::json!({ "any": [{ "sex": "F" }, { "vital_status": "Alive" }] }),
+        )
+        .await,
+    );
+}
+
+#[actix_web::test]
+async fn subject_filter_empty_group() {
+    assert_snapshot(
+        "subject_filter_empty_group",
+        post("/subject/filter", serde_json::json!({ "any": [] })).await,
+    );
+}
+
+#[actix_web::test]
+async fn subject_show() {
+    assert_snapshot(
+        "subject_show",
+        get("/subject/example-organization/ExampleNamespaceOne/Subject1").await,
+    );
+}
+
+#[actix_web::test]
+async fn subject_show_not_found() {
+    assert_snapshot(
+        "subject_show_not_found",
+        get("/subject/example-organization/ExampleNamespaceOne/Subject3").await,
+    );
+}
+
+#[actix_web::test]
+async fn subject_samples() {
+    assert_snapshot(
+        "subject_samples",
+        get("/subject/example-organization/ExampleNamespaceOne/Subject1/samples").await,
+    );
+}
+
+#[actix_web::test]
+async fn subject_files() {
+    assert_snapshot(
+        "subject_files",
+        get("/subject/example-organization/ExampleNamespaceOne/Subject1/files").await,
+    );
+}
+
+#[actix_web::test]
+async fn subjects_by_count() {
+    assert_snapshot("subjects_by_count", get("/subject/by/sex/count").await);
+}
+
+#[actix_web::test]
+async fn subjects_by_count_unsupported_field() {
+    assert_snapshot(
+        "subjects_by_count_unsupported_field",
+        get("/subject/by/handedness/count").await,
+    );
+}
+
+#[actix_web::test]
+async fn subject_values() {
+    assert_snapshot("subject_values", get("/subject/values/race").await);
+}
+
+#[actix_web::test]
+async fn subject_values_unsupported_field() {
+    assert_snapshot(
+        "subject_values_unsupported_field",
+        get("/subject/values/handedness").await,
+    );
+}
+
+#[actix_web::test]
+async fn subject_summary() {
+    assert_snapshot("subject_summary", get("/subject/summary").await);
+}
+
+#[actix_web::test]
+async fn subject_demographics() {
+    assert_snapshot("subject_demographics", get("/subject/demographics").await);
+}
+
+#[actix_web::test]
+async fn sample_index() {
+    assert_snapshot("sample_index", get("/sample").await);
+}
+
+#[actix_web::test]
+async fn sample_index_filtered() {
+    assert_snapshot(
+        "sample_index_filtered",
+        get("/sample?disease_phase=Initial%20Diagnosis").await,
+    );
+}
+
+#[actix_web::test]
+async fn sample_index_expanded() {
+    assert_snapshot("sample_index_expanded", get("/sample?expand=subject").await);
+}
+
+#[actix_web::test]
+async fn sample_filter() {
+    assert_snapshot(
+        "sample_filter",
+        post(
+            "/sample/filter",
+            serde_json::json!({ "any": [{ "disease_phase": "Initial Diagnosis" }, { "has_metadata": false }] }),
+        )
+        .await,
+    );
+}
+
+#[actix_web::test]
+async fn sample_show() {
+    assert_snapshot(
+        "sample_show",
+        get("/sample/example-organization/ExampleNamespaceOne/Sample1").await,
+    );
+}
+
+#[actix_web::test]
+async fn sample_files() {
+    assert_snapshot(
+        "sample_files",
+        get("/sample/example-organization/ExampleNamespaceOne/Sample1/files").await,
+    );
+}
+
+#[actix_web::test]
+async fn samples_by_count() {
+    assert_snapshot(
+        "samples_by_count",
+        get("/sample/by/disease_phase/count").await,
+    );
+}
+
+#[actix_web::test]
+async fn samples_by_count_nested() {
+    assert_snapshot(
+        "samples_by_count_nested",
+        get("/sample/by/diagnosis_category/count?include=diagnoses").await,
+    );
+}
+
+#[actix_web::test]
+async fn samples_by_group() {
+    assert_snapshot(
+        "samples_by_group",
+        get("/sample/by/disease_phase/group").await,
+    );
+}
+
+#[actix_web::test]
+async fn sample_values() {
+    assert_snapshot("sample_values", get("/sample/values/disease_phase").await);
+}
+
+#[actix_web::test]
+async fn sample_summary() {
+    assert_snapshot("sample_summary", get("/sample/summary").await);
+}
+
+#[actix_web::test]
+async fn sample_validation() {
+    assert_snapshot("sample_validation", get("/sample/validation").await);
+}
+
+#[actix_web::test]
+async fn file_index() {
+    assert_snapshot("file_index", get("/file").await);
+}
+
+#[actix_web::test]
+async fn file_index_filtered() {
+    assert_snapshot("file_index_filtered", get("/file?type=TXT").await);
+}
+
+#[actix_web::test]
+async fn file_lookup() {
+    assert_snapshot(
+        "file_lookup",
+        get("/file/lookup?organization=example-organization&namespace=ExampleNamespaceOne&name=File1.txt").await,
+    );
+}
+
+#[actix_web::test]
+async fn file_download_manifest() {
+    assert_snapshot(
+        "file_download_manifest",
+        get("/file/download-manifest").await,
+    );
+}
+
+#[actix_web::test]
+async fn file_filter() {
+    assert_snapshot(
+        "file_filter",
+        post(
+            "/file/filter",
+            serde_json::json!({ "all": [{ "type": "TXT" }, { "has_metadata": true }] }),
+        )
+        .await,
+    );
+}
+
+#[actix_web::test]
+async fn file_show() {
+    assert_snapshot(
+        "file_show",
+        get("/file/example-organization/ExampleNamespaceOne/File1.txt").await,
+    );
+}
+
+#[actix_web::test]
+async fn files_by_count() {
+    assert_snapshot("files_by_count", get("/file/by/type/count").await);
+}
+
+#[actix_web::test]
+async fn file_values() {
+    assert_snapshot("file_values", get("/file/values/type").await);
+}
+
+#[actix_web::test]
+async fn files_by_count_size() {
+    assert_snapshot("files_by_count_size", get("/file/by/size/count").await);
+}
+
+#[actix_web::test]
+async fn file_summary() {
+    assert_snapshot("file_summary", get("/file/summary").await);
+}
+
+#[actix_web::test]
+async fn aggregate_counts() {
+    assert_snapshot(
+        "aggregate_counts",
+        get("/aggregate/counts?include=subject.sex,sample.library_strategy,file.type").await,
+    );
+}
+
+#[actix_web::test]
+async fn aggregate_counts_invalid() {
+    assert_snapshot(
+        "aggregate_counts_invalid",
+        get("/aggregate/counts?include=subject.sex,subject.handedness").await,
+    );
+}
+
+#[actix_web::test]
+async fn metadata_fields_subject() {
+    assert_snapshot(
+        "metadata_fields_subject",
+        get("/metadata/fields/subject").await,
+    );
+}
+
+#[actix_web::test]
+async fn metadata_fields_sample() {
+    assert_snapshot(
+        "metadata_fields_sample",
+        get("/metadata/fields/sample").await,
+    );
+}
+
+#[actix_web::test]
+async fn metadata_fields_file() {
+    assert_snapshot("metadata_fields_file", get("/metadata/fields/file").await);
+}
+
+#[actix_web::test]
+async fn metadata_fields_namespace() {
+    assert_snapshot(
+        "metadata_fields_namespace",
+        get("/metadata/fields/namespace").await,
+    );
+}
+
+#[actix_web::test]
+async fn metadata_fields_organization() {
+    assert_snapshot(
+        "metadata_fields_organization",
+        get("/metadata/fields/organization").await,
+    );
+}
+
+#[actix_web::test]
+async fn metadata_field_show() {
+    assert_snapshot(
+        "metadata_field_show",
+        get("/metadata/fields/subject/sex").await,
+    );
+}
+
+#[actix_web::test]
+async fn metadata_field_show_not_found() {
+    assert_snapshot(
+        "metadata_field_show_not_found",
+        get("/metadata/fields/subject/not_a_field").await,
+    );
+}
+
+#[actix_web::test]
+async fn namespace_index() {
+    assert_snapshot("namespace_index", get("/namespace").await);
+}
+
+#[actix_web::test]
+async fn namespace_show() {
+    assert_snapshot(
+        "namespace_show",
+        get("/namespace/example-organization/ExampleNamespaceOne").await,
+    );
+}
+
+#[actix_web::test]
+async fn namespace_summary() {
+    assert_snapshot(
+        "namespace_summary",
+        get("/namespace/example-organization/ExampleNamespaceOne/summary").await,
+    );
+}
+
+#[actix_web::test]
+async fn organization_index() {
+    assert_snapshot("organization_index", get("/organization").await);
+}
+
+#[actix_web::test]
+async fn organization_show() {
+    assert_snapshot(
+        "organization_show",
+        get("/organization/example-organization").await,
+    );
+}
+
+#[actix_web::test]
+async fn info_index() {
+    assert_snapshot("info_index", get("/info").await);
+}
+
+#[actix_web::test]
+async fn info_versions() {
+    assert_snapshot("info_versions", get("/info/versions").await);
+}
+
+#[actix_web::test]
+async fn sample_diagnosis_index() {
+    assert_snapshot(
+        "sample_diagnosis_index",
+        get("/sample-diagnosis?search=neuro").await,
+    );
+}
+
+#[actix_web::test]
+async fn subject_diagnosis_index() {
+    assert_snapshot(
+        "subject_diagnosis_index",
+        get("/subject-diagnosis?search=neuro").await,
+    );
+}
+
+#[actix_web::test]
+async fn search_index() {
+    assert_snapshot("search_index", get("/search?q=sample").await);
+}
+
+#[actix_web::test]
+async fn search_index_empty_query() {
+    assert_snapshot("search_index_empty_query", get("/search?q=").await);
+}
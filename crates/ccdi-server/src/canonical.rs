@@ -0,0 +1,362 @@
+//! Canonicalizing requested paths that only differ from a known route by a
+//! trailing slash or by case.
+//!
+//! Some gateways append trailing slashes or normalize the case of the paths
+//! they forward, so a request for `/subject/` or `/Subject` would otherwise be
+//! reported as a generic invalid route. Requests that do not match any route
+//! are handled by [`fallback()`], which compares the requested path against a
+//! small registry of the routes served by this server ([`ROUTES`], along with
+//! [`METRICS_ROUTE`] when metrics are served):
+//!
+//! * A path that only differs from a known route by a trailing slash is
+//!   permanently redirected (`308`, so the method and body are preserved) to
+//!   the known route.
+//! * Otherwise, a path that only differs from a known route by case (and,
+//!   optionally, a trailing slash) is reported as an
+//!   [`InvalidRoute`](crate::responses::error::kind::Inner::InvalidRoute)
+//!   error that suggests the canonical form of the known route.
+//!
+//! Only the static segments of a route are compared case-insensitively—the
+//! values provided for the parameters of a route (e.g., the name of an entity)
+//! are left untouched.
+
+use actix_web::http::header;
+use actix_web::web::Data;
+use actix_web::HttpRequest;
+use actix_web::HttpResponse;
+
+use crate::metrics::Registry;
+use crate::responses::error;
+use crate::responses::Errors;
+
+/// The routes served by this server (as written in the OpenAPI
+/// specification).
+///
+/// The tests within this module fail when a route is not listed here (or as
+/// the [`METRICS_ROUTE`]).
+pub const ROUTES: &[&str] = &[
+    "/subject",
+    "/subject/filter",
+    "/subject/{organization}/{namespace}/{name}",
+    "/subject/{organization}/{namespace}/{name}/samples",
+    "/subject/{organization}/{namespace}/{name}/files",
+    "/subject/by/{field}/count",
+    "/subject/values/{field}",
+    "/subject/summary",
+    "/subject/demographics",
+    "/sample",
+    "/sample/filter",
+    "/sample/{organization}/{namespace}/{name}",
+    "/sample/{organization}/{namespace}/{name}/files",
+    "/sample/by/{field}/count",
+    "/sample/by/{field}/group",
+    "/sample/values/{field}",
+    "/sample/summary",
+    "/sample/validation",
+    "/file",
+    "/file/filter",
+    "/file/lookup",
+    "/file/download-manifest",
+    "/file/{organization}/{namespace}/{name}",
+    "/file/by/{field}/count",
+    "/file/values/{field}",
+    "/file/summary",
+    "/aggregate/counts",
+    "/search",
+    "/metadata/fields/subject",
+    "/metadata/fields/sample",
+    "/metadata/fields/file",
+    "/metadata/fields/namespace",
+    "/metadata/fields/organization",
+    "/metadata/fields/{entity}/{field_name}",
+    "/namespace",
+    "/namespace/{organization}/{namespace}",
+    "/namespace/{organization}/{namespace}/summary",
+    "/organization",
+    "/organization/{name}",
+    "/info",
+    "/info/versions",
+    "/sample-diagnosis",
+    "/subject-diagnosis",
+];
+
+/// The route at which request metrics are served.
+///
+/// This route is not a part of the specification and is only served when
+/// metrics are enabled (see [`crate::metrics`]), so it is only considered by
+/// [`canonical()`] when requested.
+pub const METRICS_ROUTE: &str = "/metrics";
+
+/// Removes any trailing slashes from a path (the root path is left as is).
+fn trim(path: &str) -> &str {
+    match path.trim_end_matches('/') {
+        "" => "/",
+        trimmed => trimmed,
+    }
+}
+
+/// Matches a path against a route, comparing the static segments of the route
+/// case-insensitively.
+///
+/// If the path matches, the canonical form of the path (the static segments of
+/// the route along with the parameters provided within the path) and the
+/// number of static segments within the route are returned.
+fn matches(route: &str, path: &str) -> Option<(String, usize)> {
+    let segments = route.split('/').collect::<Vec<_>>();
+    let provided = path.split('/').collect::<Vec<_>>();
+
+    if segments.len() != provided.len() {
+        return None;
+    }
+
+    let mut statics = 0usize;
+    let mut canonical = Vec::with_capacity(segments.len());
+
+    for (segment, provided) in segments.into_iter().zip(provided) {
+        if segment.starts_with('{') && segment.ends_with('}') {
+            if provided.is_empty() {
+                return None;
+            }
+
+            canonical.push(provided);
+        } else if segment.eq_ignore_ascii_case(provided) {
+            statics += 1;
+            canonical.push(segment);
+        } else {
+            return None;
+        }
+    }
+
+    Some((canonical.join("/"), statics))
+}
+
+/// Gets the canonical form of a path that only differs from a known route by a
+/// trailing slash or by case.
+///
+/// When multiple routes match, the route with the most static segments is
+/// preferred (e.g., `/subject/by/{field}/count` over
+/// `/subject/{organization}/{namespace}/{name}`). If the path does not match
+/// any route, or if the path is already canonical, `None` is returned.
+///
+/// The [`METRICS_ROUTE`] is only considered when `metrics` is `true` (i.e.,
+/// when metrics are served).
+///
+/// # Examples
+///
+/// ```
+/// use ccdi_server as server;
+///
+/// use server::canonical::canonical;
+///
+/// assert_eq!(
+///     canonical("/subject/", false),
+///     Some(String::from("/subject"))
+/// );
+/// assert_eq!(canonical("/Subject", false), Some(String::from("/subject")));
+/// assert_eq!(
+///     canonical("/SUBJECT/By/sex/Count/", false),
+///     Some(String::from("/subject/by/sex/count"))
+/// );
+/// assert_eq!(
+///     canonical(
+///         "/Sample/example-organization/ExampleNamespaceOne/Sample1",
+///         false
+///     ),
+///     Some(String::from(
+///         "/sample/example-organization/ExampleNamespaceOne/Sample1"
+///     ))
+/// );
+///
+/// assert_eq!(canonical("/subject", false), None);
+/// assert_eq!(canonical("/foobar", false), None);
+///
+/// assert_eq!(canonical("/Metrics", true), Some(String::from("/metrics")));
+/// assert_eq!(canonical("/Metrics", false), None);
+/// ```
+pub fn canonical(path: &str, metrics: bool) -> Option<String> {
+    let trimmed = trim(path);
+
+    let mut best: Option<(String, usize)> = None;
+
+    let routes = ROUTES
+        .iter()
+        .copied()
+        .chain(metrics.then_some(METRICS_ROUTE));
+
+    for route in routes {
+        if let Some((canonical, statics)) = matches(route, trimmed) {
+            // NOTE: the first route with the most static segments is kept so
+            // that ties are broken by the order of the registry.
+            if best.as_ref().is_none_or(|(_, best)| statics > *best) {
+                best = Some((canonical, statics));
+            }
+        }
+    }
+
+    best.map(|(canonical, _)| canonical)
+        .filter(|canonical| canonical != path)
+}
+
+/// Responds to a request that does not match any route.
+///
+/// A path that only differs from a known route by a trailing slash is
+/// redirected (`308 Permanent Redirect`) to the known route (retaining the
+/// query string). Every other request is reported as an
+/// [`InvalidRoute`](error::kind::Inner::InvalidRoute) error, which suggests the
+/// canonical form of the known route when the path only differs from it by
+/// case.
+///
+/// The [`METRICS_ROUTE`] is only considered when a [`Registry`] has been
+/// provided to the server (as is done when the metrics routes are configured),
+/// so a server without metrics does not redirect to (or suggest) a route that
+/// it does not serve.
+///
+/// This is intended to be used as the default service of the server.
+///
+/// # Examples
+///
+/// ```
+/// use actix_web::web;
+/// use actix_web::App;
+/// use ccdi_server as server;
+///
+/// use server::canonical::fallback;
+///
+/// let app = App::new().default_service(web::to(fallback));
+/// ```
+pub async fn fallback(req: HttpRequest, registry: Option<Data<Registry>>) -> HttpResponse {
+    let path = req.path();
+    let suggestion = canonical(path, registry.is_some());
+
+    if let Some(canonical) = suggestion.as_deref() {
+        if path != "/" && path.ends_with('/') && trim(path) == canonical {
+            let location = match req.query_string() {
+                "" => canonical.to_string(),
+                query => format!("{canonical}?{query}"),
+            };
+
+            return HttpResponse::PermanentRedirect()
+                .insert_header((header::LOCATION, location))
+                .finish();
+        }
+    }
+
+    HttpResponse::NotFound().json(Errors::from(error::Kind::invalid_route(
+        req.method().to_string(),
+        path.to_string(),
+        suggestion,
+    )))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeSet;
+    use std::path::Path;
+
+    use actix_web::http::StatusCode;
+    use actix_web::test::call_service;
+    use actix_web::test::init_service;
+    use actix_web::test::read_body_json;
+    use actix_web::test::TestRequest;
+    use actix_web::web;
+    use actix_web::App;
+    use regex::Regex;
+    use serde_json::Value;
+
+    use super::*;
+
+    #[test]
+    fn every_route_is_registered() {
+        let pattern = Regex::new(r#"#\[(?:get|post)\("(?P<route>[^"]+)"\)\]"#).unwrap();
+        let directory = Path::new(env!("CARGO_MANIFEST_DIR")).join("src/routes");
+
+        let mut served = BTreeSet::new();
+
+        for entry in std::fs::read_dir(directory).unwrap() {
+            let path = entry.unwrap().path();
+
+            if path.is_file() {
+                let contents = std::fs::read_to_string(&path).unwrap();
+
+                for captures in pattern.captures_iter(&contents) {
+                    served.insert(captures["route"].to_string());
+                }
+            }
+        }
+
+        let registered = ROUTES
+            .iter()
+            .chain([&METRICS_ROUTE])
+            .map(|route| route.to_string())
+            .collect::<BTreeSet<_>>();
+        let unregistered = served.difference(&registered).collect::<Vec<_>>();
+
+        assert!(
+            unregistered.is_empty(),
+            "the following routes are not registered: {unregistered:?}"
+        );
+    }
+
+    #[actix_web::test]
+    async fn it_redirects_or_suggests_canonical_routes() {
+        let app = init_service(App::new().default_service(web::to(fallback))).await;
+
+        // A trailing slash is redirected (retaining the query string).
+        let request = TestRequest::get().uri("/subject/?page=2").to_request();
+        let response = call_service(&app, request).await;
+
+        assert_eq!(response.status(), StatusCode::PERMANENT_REDIRECT);
+        assert_eq!(
+            response.headers().get(header::LOCATION).unwrap(),
+            "/subject?page=2"
+        );
+
+        // A difference in case is reported with a suggestion.
+        let request = TestRequest::get().uri("/Subject").to_request();
+        let response = call_service(&app, request).await;
+
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+        let body: Value = read_body_json(response).await;
+        assert_eq!(body["errors"][0]["kind"], "InvalidRoute");
+        assert_eq!(body["errors"][0]["route"], "/Subject");
+        assert_eq!(body["errors"][0]["suggestion"], "/subject");
+
+        // A genuinely unknown path is reported without a suggestion.
+        let request = TestRequest::get().uri("/foobar/").to_request();
+        let response = call_service(&app, request).await;
+
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+        let body: Value = read_body_json(response).await;
+        assert_eq!(body["errors"][0]["kind"], "InvalidRoute");
+        assert!(body["errors"][0].get("suggestion").is_none());
+    }
+
+    #[actix_web::test]
+    async fn it_only_considers_the_metrics_route_when_metrics_are_served() {
+        let app = init_service(App::new().default_service(web::to(fallback))).await;
+
+        let request = TestRequest::get().uri("/metrics/").to_request();
+        let response = call_service(&app, request).await;
+
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+        let body: Value = read_body_json(response).await;
+        assert_eq!(body["errors"][0]["kind"], "InvalidRoute");
+        assert!(body["errors"][0].get("suggestion").is_none());
+
+        let app = init_service(
+            App::new()
+                .app_data(Data::new(Registry::default()))
+                .default_service(web::to(fallback)),
+        )
+        .await;
+
+        let request = TestRequest::get().uri("/metrics/").to_request();
+        let response = call_service(&app, request).await;
+
+        assert_eq!(response.status(), StatusCode::PERMANENT_REDIRECT);
+        assert_eq!(
+            response.headers().get(header::LOCATION).unwrap(),
+            "/metrics"
+        );
+    }
+}
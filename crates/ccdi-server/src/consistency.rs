@@ -0,0 +1,403 @@
+//! Consistency checks between the metadata of samples and their files.
+//!
+//! A sample declares how it was prepared (e.g., its library strategy and the
+//! type of its molecular analyte), and the files derived from that sample
+//! should generally agree with that declaration. For instance, a sample that
+//! claims a `WGS` library strategy but whose only files are whole-slide
+//! images is suspicious.
+//!
+//! Each check is a [`Rule`] within [`RULES`]. The rules are deliberately
+//! conservative: a sample is only checked when at least one file is derived
+//! from it and the type of every one of those files is known, and the rules
+//! only consider the broad [`Category`] of each file type.
+//!
+//! Consistency checks between subjects and the samples collected from them are
+//! within [`subject`].
+
+use std::collections::BTreeMap;
+
+use ccdi_cde as cde;
+use ccdi_models as models;
+
+use cde::v1::file::Type;
+use cde::v1::sample::LibraryStrategy;
+use cde::v1::sample::SpecimenMolecularAnalyteType;
+use models::File;
+use models::Sample;
+
+use crate::responses::validation::Finding;
+
+pub mod subject;
+
+/// A broad category of file types.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Category {
+    /// Sequencing reads, alignments of those reads, and variants called from
+    /// them.
+    Sequence,
+
+    /// Images (including radiology and pathology images).
+    Imaging,
+
+    /// Any other file type.
+    Other,
+}
+
+impl From<&Type> for Category {
+    fn from(value: &Type) -> Self {
+        match value {
+            Type::BAI
+            | Type::BAM
+            | Type::CRAI
+            | Type::CRAM
+            | Type::FASTQ
+            | Type::GVCF
+            | Type::MAF
+            | Type::SequenceRecordFormat
+            | Type::VCF => Category::Sequence,
+            Type::DICOM
+            | Type::JPEG
+            | Type::JPEG2000
+            | Type::NIFTIFormat
+            | Type::OMETIFF
+            | Type::PNG
+            | Type::SVS
+            | Type::TIFF => Category::Imaging,
+            _ => Category::Other,
+        }
+    }
+}
+
+/// A rule comparing the metadata of a sample to the categories of the files
+/// derived from it.
+#[derive(Debug)]
+pub struct Rule {
+    /// The name of the rule.
+    pub name: &'static str,
+
+    /// Checks the metadata of a sample against the (non-empty) categories of
+    /// its files and returns a description of the inconsistency (if any).
+    pub check: fn(&models::sample::Metadata, &[Category]) -> Option<String>,
+}
+
+/// The rules that are checked for every sample.
+pub const RULES: &[Rule] = &[
+    Rule {
+        name: "sequencing-strategy-without-sequence-files",
+        check: sequencing_strategy_without_sequence_files,
+    },
+    Rule {
+        name: "imaging-only-sequencing-strategy",
+        check: imaging_only_sequencing_strategy,
+    },
+    Rule {
+        name: "imaging-only-molecular-analyte",
+        check: imaging_only_molecular_analyte,
+    },
+];
+
+/// Gets the library strategy of a sample if it is a sequencing strategy.
+fn sequencing_strategy(metadata: &models::sample::Metadata) -> Option<&LibraryStrategy> {
+    metadata
+        .library_strategy()
+        .map(|strategy| strategy.value())
+        // NOTE: `Other` may not describe a sequencing strategy, so it is not
+        // considered (to remain conservative).
+        .filter(|strategy| **strategy != LibraryStrategy::Other)
+}
+
+fn is_imaging_only(categories: &[Category]) -> bool {
+    categories
+        .iter()
+        .all(|category| *category == Category::Imaging)
+}
+
+fn sequencing_strategy_without_sequence_files(
+    metadata: &models::sample::Metadata,
+    categories: &[Category],
+) -> Option<String> {
+    let strategy = sequencing_strategy(metadata)?;
+
+    // NOTE: samples with only imaging files are reported by the more specific
+    // `imaging-only-sequencing-strategy` rule.
+    if is_imaging_only(categories) || categories.contains(&Category::Sequence) {
+        return None;
+    }
+
+    Some(format!(
+        "the library strategy is `{strategy}`, but none of the {} file(s) derived from the \
+         sample contain sequence data",
+        categories.len()
+    ))
+}
+
+fn imaging_only_sequencing_strategy(
+    metadata: &models::sample::Metadata,
+    categories: &[Category],
+) -> Option<String> {
+    let strategy = sequencing_strategy(metadata)?;
+
+    match is_imaging_only(categories) {
+        true => Some(format!(
+            "the library strategy is `{strategy}`, but every file derived from the sample is \
+             an image"
+        )),
+        false => None,
+    }
+}
+
+fn imaging_only_molecular_analyte(
+    metadata: &models::sample::Metadata,
+    categories: &[Category],
+) -> Option<String> {
+    let analyte = metadata
+        .specimen_molecular_analyte_type()
+        .map(|analyte| analyte.value())
+        .filter(|analyte| {
+            matches!(
+                analyte,
+                SpecimenMolecularAnalyteType::Dna | SpecimenMolecularAnalyteType::Rna
+            )
+        })?;
+
+    match is_imaging_only(categories) {
+        true => Some(format!(
+            "the specimen molecular analyte type is `{analyte}`, but every file derived from \
+             the sample is an image"
+        )),
+        false => None,
+    }
+}
+
+/// Checks a single sample against every [`Rule`] given the files derived from
+/// it.
+///
+/// # Examples
+///
+/// ```
+/// use ccdi_models as models;
+/// use ccdi_server as server;
+///
+/// use models::namespace;
+/// use models::sample;
+/// use models::subject;
+/// use models::Sample;
+/// use server::consistency;
+///
+/// let namespace = namespace::Identifier::new(
+///     "example-organization"
+///         .parse::<models::organization::Identifier>()
+///         .unwrap(),
+///     namespace::identifier::Name::try_new("ExampleNamespace").unwrap(),
+/// );
+///
+/// let sample = Sample::new(
+///     sample::Identifier::new(namespace.clone(), "Sample1"),
+///     subject::Identifier::new(namespace, "Subject1"),
+///     None,
+///     None,
+/// );
+///
+/// // A sample without any files never produces a finding.
+/// assert!(consistency::check_sample(&sample, &[]).is_empty());
+/// ```
+pub fn check_sample(sample: &Sample, files: &[&File]) -> Vec<Finding> {
+    let metadata = match sample.metadata() {
+        Some(metadata) => metadata,
+        None => return Vec::new(),
+    };
+
+    let categories = files
+        .iter()
+        .map(|file| {
+            file.metadata()
+                .and_then(|metadata| metadata.r#type())
+                .map(|r#type| Category::from(r#type.value()))
+        })
+        .collect::<Option<Vec<_>>>();
+
+    let categories = match categories {
+        Some(categories) if !categories.is_empty() => categories,
+        // Samples with no files (or with any file of an unknown type) are not
+        // checked.
+        _ => return Vec::new(),
+    };
+
+    RULES
+        .iter()
+        .filter_map(|rule| {
+            (rule.check)(metadata, &categories)
+                .map(|message| Finding::new(sample.id().clone(), rule.name, message))
+        })
+        .collect()
+}
+
+/// Checks every sample against every [`Rule`].
+///
+/// Findings are returned in the order of the samples to which they relate.
+///
+/// # Examples
+///
+/// ```
+/// use ccdi_server as server;
+///
+/// use server::consistency;
+///
+/// assert!(consistency::check(&[], &[]).is_empty());
+/// ```
+pub fn check(samples: &[Sample], files: &[File]) -> Vec<Finding> {
+    let mut files_by_sample = BTreeMap::<&models::sample::Identifier, Vec<&File>>::new();
+
+    for file in files {
+        for sample in file.samples().iter() {
+            files_by_sample.entry(sample).or_default().push(file);
+        }
+    }
+
+    samples
+        .iter()
+        .flat_map(|sample| {
+            check_sample(
+                sample,
+                files_by_sample
+                    .get(sample.id())
+                    .map(|files| files.as_slice())
+                    .unwrap_or_default(),
+            )
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use nonempty::NonEmpty;
+
+    use models::metadata::field::unowned::file::Type as TypeField;
+    use models::metadata::field::unowned::sample::LibraryStrategy as LibraryStrategyField;
+    use models::metadata::field::unowned::sample::SpecimenMolecularAnalyteType as AnalyteField;
+    use models::namespace;
+
+    use super::*;
+
+    fn namespace_id() -> namespace::Identifier {
+        namespace::Identifier::new(
+            "example-organization"
+                .parse::<models::organization::Identifier>()
+                .unwrap(),
+            namespace::identifier::Name::try_new("ExampleNamespace").unwrap(),
+        )
+    }
+
+    fn sample(
+        name: &str,
+        strategy: Option<LibraryStrategy>,
+        analyte: Option<SpecimenMolecularAnalyteType>,
+    ) -> Sample {
+        let mut builder = models::sample::metadata::Builder::default();
+
+        if let Some(strategy) = strategy {
+            builder =
+                builder.library_strategy(LibraryStrategyField::new(strategy, None, None, None));
+        }
+
+        if let Some(analyte) = analyte {
+            builder = builder
+                .specimen_molecular_analyte_type(AnalyteField::new(analyte, None, None, None));
+        }
+
+        Sample::new(
+            models::sample::Identifier::new(namespace_id(), name),
+            models::subject::Identifier::new(namespace_id(), "Subject1"),
+            None,
+            Some(builder.build()),
+        )
+    }
+
+    fn file(name: &str, sample: &str, r#type: Type) -> File {
+        File::new(
+            models::file::Identifier::new(namespace_id(), cde::v1::file::Name::new(name)),
+            NonEmpty::new(models::sample::Identifier::new(namespace_id(), sample)),
+            None,
+            Some(
+                models::file::metadata::Builder::default()
+                    .r#type(TypeField::new(r#type, None, None, None))
+                    .build(),
+            ),
+        )
+    }
+
+    #[test]
+    fn it_accepts_a_clean_sequencing_sample() {
+        let samples = [sample(
+            "Sample1",
+            Some(LibraryStrategy::Wgs),
+            Some(SpecimenMolecularAnalyteType::Dna),
+        )];
+        let files = [
+            file("File1.bam", "Sample1", Type::BAM),
+            file("File1.svs", "Sample1", Type::SVS),
+        ];
+
+        assert!(check(&samples, &files).is_empty());
+    }
+
+    #[test]
+    fn it_reports_an_imaging_only_sequencing_sample() {
+        let samples = [sample(
+            "Sample1",
+            Some(LibraryStrategy::Wgs),
+            Some(SpecimenMolecularAnalyteType::Dna),
+        )];
+        let files = [
+            file("File1.dcm", "Sample1", Type::DICOM),
+            file("File1.svs", "Sample1", Type::SVS),
+        ];
+
+        let findings = check(&samples, &files);
+
+        assert_eq!(
+            findings
+                .iter()
+                .map(|finding| finding.rule())
+                .collect::<Vec<_>>(),
+            vec![
+                "imaging-only-sequencing-strategy",
+                "imaging-only-molecular-analyte"
+            ]
+        );
+        assert_eq!(
+            findings[0].message(),
+            "the library strategy is `WGS`, but every file derived from the sample is an image"
+        );
+        assert_eq!(findings[0].sample().name(), "Sample1");
+    }
+
+    #[test]
+    fn it_reports_a_sequencing_strategy_without_sequence_files() {
+        let samples = [sample("Sample1", Some(LibraryStrategy::RnaSeq), None)];
+        let files = [
+            file("File1.pdf", "Sample1", Type::PDF),
+            file("File1.svs", "Sample1", Type::SVS),
+        ];
+
+        let findings = check(&samples, &files);
+
+        assert_eq!(findings.len(), 1);
+        assert_eq!(
+            findings[0].rule(),
+            "sequencing-strategy-without-sequence-files"
+        );
+    }
+
+    #[test]
+    fn it_does_not_report_a_sample_without_files() {
+        let samples = [sample(
+            "Sample1",
+            Some(LibraryStrategy::Wgs),
+            Some(SpecimenMolecularAnalyteType::Dna),
+        )];
+        let files = [file("File2.svs", "Sample2", Type::SVS)];
+
+        assert!(check(&samples, &files).is_empty());
+    }
+}
@@ -0,0 +1,362 @@
+//! Consistency checks between the metadata of subjects and their samples.
+//!
+//! A subject's vital status and age at vital status constrain the samples
+//! that can have been collected from it. For instance, a sample with a
+//! `Post-Mortem` disease phase cannot have been collected from a subject whose
+//! vital status is `Alive`.
+//!
+//! Each check is a [`Rule`] within [`RULES`]. A rule is only evaluated when
+//! both the subject and the sample have metadata, and each rule only reports a
+//! violation when every field it compares is present (missing fields are
+//! never considered inconsistent).
+
+use std::collections::BTreeMap;
+
+use serde::Serialize;
+
+use ccdi_cde as cde;
+use ccdi_models as models;
+
+use cde::v1::sample::DiseasePhase;
+use cde::v1::subject::VitalStatus;
+use models::Sample;
+use models::Subject;
+
+/// A rule comparing the metadata of a subject to the metadata of one of its
+/// samples.
+#[derive(Debug)]
+pub struct Rule {
+    /// The name of the rule.
+    pub name: &'static str,
+
+    /// Checks the metadata of a subject against the metadata of one of its
+    /// samples and returns a description of the inconsistency (if any).
+    pub check: fn(&models::subject::Metadata, &models::sample::Metadata) -> Option<String>,
+}
+
+/// The rules that are checked for every pair of a subject and its sample.
+pub const RULES: &[Rule] = &[
+    Rule {
+        name: "post-mortem-sample-from-living-subject",
+        check: post_mortem_sample_from_living_subject,
+    },
+    Rule {
+        name: "collected-after-vital-status",
+        check: collected_after_vital_status,
+    },
+];
+
+fn post_mortem_sample_from_living_subject(
+    subject: &models::subject::Metadata,
+    sample: &models::sample::Metadata,
+) -> Option<String> {
+    let vital_status = subject.vital_status()?.value();
+    let disease_phase = sample.disease_phase()?.value();
+
+    match (vital_status, disease_phase) {
+        (VitalStatus::Alive, DiseasePhase::PostMortem) => Some(format!(
+            "the disease phase of the sample is `{disease_phase}`, but the vital status of the \
+             subject is `{vital_status}`"
+        )),
+        _ => None,
+    }
+}
+
+fn collected_after_vital_status(
+    subject: &models::subject::Metadata,
+    sample: &models::sample::Metadata,
+) -> Option<String> {
+    let age_at_vital_status = subject.age_at_vital_status()?.value();
+    let age_at_collection = sample.age_at_collection()?.value();
+
+    match **age_at_collection > **age_at_vital_status {
+        true => Some(format!(
+            "the sample was collected at {age_at_collection} days of age, which is after the \
+             age at vital status of the subject ({age_at_vital_status} days)"
+        )),
+        false => None,
+    }
+}
+
+/// A violation of a [`Rule`] by a subject and one of its samples.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize)]
+pub struct Violation {
+    /// The subject involved in the violation.
+    subject: models::subject::Identifier,
+
+    /// The sample involved in the violation.
+    sample: models::sample::Identifier,
+
+    /// The name of the rule that was violated.
+    rule: &'static str,
+
+    /// A description of the violation.
+    message: String,
+}
+
+impl Violation {
+    /// Gets the subject involved in the [`Violation`].
+    pub fn subject(&self) -> &models::subject::Identifier {
+        &self.subject
+    }
+
+    /// Gets the sample involved in the [`Violation`].
+    pub fn sample(&self) -> &models::sample::Identifier {
+        &self.sample
+    }
+
+    /// Gets the name of the rule that was violated.
+    pub fn rule(&self) -> &'static str {
+        self.rule
+    }
+
+    /// Gets the description of the [`Violation`].
+    pub fn message(&self) -> &str {
+        self.message.as_str()
+    }
+}
+
+impl std::fmt::Display for Violation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}: {}", self.rule, self.message)
+    }
+}
+
+/// Checks a single subject against every [`Rule`] given the samples collected
+/// from it.
+///
+/// Samples that do not refer to the subject are ignored.
+///
+/// # Examples
+///
+/// ```
+/// use ccdi_models as models;
+/// use ccdi_server as server;
+///
+/// use models::namespace;
+/// use models::subject::Kind;
+/// use models::Subject;
+/// use server::consistency;
+///
+/// let namespace = namespace::Identifier::new(
+///     "example-organization"
+///         .parse::<models::organization::Identifier>()
+///         .unwrap(),
+///     namespace::identifier::Name::try_new("ExampleNamespace").unwrap(),
+/// );
+///
+/// let subject = Subject::new(
+///     models::subject::Identifier::new(namespace, "Subject1"),
+///     Kind::Participant,
+///     None,
+///     None,
+/// );
+///
+/// // A subject without any samples never produces a violation.
+/// assert!(consistency::subject::check_subject(&subject, &[]).is_empty());
+/// ```
+pub fn check_subject(subject: &Subject, samples: &[&Sample]) -> Vec<Violation> {
+    let metadata = match subject.metadata() {
+        Some(metadata) => metadata,
+        None => return Vec::new(),
+    };
+
+    samples
+        .iter()
+        .filter(|sample| sample.subject() == subject.id())
+        .filter_map(|sample| {
+            sample
+                .metadata()
+                .map(|sample_metadata| (sample, sample_metadata))
+        })
+        .flat_map(|(sample, sample_metadata)| {
+            RULES.iter().filter_map(move |rule| {
+                (rule.check)(metadata, sample_metadata).map(|message| Violation {
+                    subject: subject.id().clone(),
+                    sample: sample.id().clone(),
+                    rule: rule.name,
+                    message,
+                })
+            })
+        })
+        .collect()
+}
+
+/// Checks every subject against every [`Rule`].
+///
+/// Violations are returned in the order of the subjects to which they relate
+/// (and then in the order of the samples). Samples that refer to a subject
+/// that is not provided are not checked.
+///
+/// # Examples
+///
+/// ```
+/// use ccdi_server as server;
+///
+/// use server::consistency;
+///
+/// assert!(consistency::subject::check(&[], &[]).is_empty());
+/// ```
+pub fn check(subjects: &[Subject], samples: &[Sample]) -> Vec<Violation> {
+    let mut samples_by_subject = BTreeMap::<&models::subject::Identifier, Vec<&Sample>>::new();
+
+    for sample in samples {
+        samples_by_subject
+            .entry(sample.subject())
+            .or_default()
+            .push(sample);
+    }
+
+    subjects
+        .iter()
+        .flat_map(|subject| {
+            check_subject(
+                subject,
+                samples_by_subject
+                    .get(subject.id())
+                    .map(|samples| samples.as_slice())
+                    .unwrap_or_default(),
+            )
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use ordered_float::OrderedFloat;
+
+    use models::metadata::field::unowned::sample::AgeAtCollection;
+    use models::metadata::field::unowned::sample::DiseasePhase as DiseasePhaseField;
+    use models::metadata::field::unowned::subject::AgeAtVitalStatus;
+    use models::metadata::field::unowned::subject::VitalStatus as VitalStatusField;
+    use models::namespace;
+    use models::subject::Kind;
+
+    use super::*;
+
+    fn namespace_id() -> namespace::Identifier {
+        namespace::Identifier::new(
+            "example-organization"
+                .parse::<models::organization::Identifier>()
+                .unwrap(),
+            namespace::identifier::Name::try_new("ExampleNamespace").unwrap(),
+        )
+    }
+
+    fn subject(name: &str, vital_status: VitalStatus, age_at_vital_status: f32) -> Subject {
+        Subject::new(
+            models::subject::Identifier::new(namespace_id(), name),
+            Kind::Participant,
+            None,
+            Some(
+                models::subject::metadata::Builder::default()
+                    .vital_status(VitalStatusField::new(vital_status, None, None, None))
+                    .age_at_vital_status(AgeAtVitalStatus::new(
+                        models::subject::metadata::AgeAtVitalStatus::from(OrderedFloat(
+                            age_at_vital_status,
+                        )),
+                        None,
+                        None,
+                        None,
+                    ))
+                    .build(),
+            ),
+        )
+    }
+
+    fn sample(
+        name: &str,
+        subject: &str,
+        disease_phase: DiseasePhase,
+        age_at_collection: f32,
+    ) -> Sample {
+        Sample::new(
+            models::sample::Identifier::new(namespace_id(), name),
+            models::subject::Identifier::new(namespace_id(), subject),
+            None,
+            Some(
+                models::sample::metadata::Builder::default()
+                    .disease_phase(DiseasePhaseField::new(disease_phase, None, None, None))
+                    .age_at_collection(AgeAtCollection::new(
+                        models::sample::metadata::AgeAtCollection::from(OrderedFloat(
+                            age_at_collection,
+                        )),
+                        None,
+                        None,
+                        None,
+                    ))
+                    .build(),
+            ),
+        )
+    }
+
+    #[test]
+    fn it_accepts_a_consistent_subject() {
+        let subjects = [subject("Subject1", VitalStatus::Dead, 3650.0)];
+        let samples = [
+            sample("Sample1", "Subject1", DiseasePhase::InitialDiagnosis, 365.0),
+            sample("Sample2", "Subject1", DiseasePhase::PostMortem, 3650.0),
+        ];
+
+        assert!(check(&subjects, &samples).is_empty());
+    }
+
+    #[test]
+    fn it_reports_a_post_mortem_sample_from_a_living_subject() {
+        let subjects = [subject("Subject1", VitalStatus::Alive, 3650.0)];
+        let samples = [sample(
+            "Sample1",
+            "Subject1",
+            DiseasePhase::PostMortem,
+            365.0,
+        )];
+
+        let violations = check(&subjects, &samples);
+
+        assert_eq!(violations.len(), 1);
+        assert_eq!(
+            violations[0].rule(),
+            "post-mortem-sample-from-living-subject"
+        );
+        assert_eq!(
+            violations[0].message(),
+            "the disease phase of the sample is `Post-Mortem`, but the vital status of the \
+             subject is `Alive`"
+        );
+        assert_eq!(violations[0].subject().name().as_str(), "Subject1");
+        assert_eq!(violations[0].sample().name(), "Sample1");
+    }
+
+    #[test]
+    fn it_reports_a_sample_collected_after_the_age_at_vital_status() {
+        let subjects = [subject("Subject1", VitalStatus::Alive, 365.0)];
+        let samples = [
+            sample("Sample1", "Subject1", DiseasePhase::InitialDiagnosis, 365.0),
+            sample("Sample2", "Subject1", DiseasePhase::Relapse, 730.0),
+        ];
+
+        let violations = check(&subjects, &samples);
+
+        assert_eq!(violations.len(), 1);
+        assert_eq!(violations[0].rule(), "collected-after-vital-status");
+        assert_eq!(violations[0].sample().name(), "Sample2");
+        assert_eq!(
+            violations[0].to_string(),
+            "collected-after-vital-status: the sample was collected at 730 days of age, which \
+             is after the age at vital status of the subject (365 days)"
+        );
+    }
+
+    #[test]
+    fn it_ignores_samples_of_other_subjects() {
+        let subjects = [subject("Subject1", VitalStatus::Alive, 365.0)];
+        let samples = [sample(
+            "Sample1",
+            "Subject2",
+            DiseasePhase::PostMortem,
+            730.0,
+        )];
+
+        assert!(check(&subjects, &samples).is_empty());
+    }
+}
@@ -0,0 +1,673 @@
+//! Datasets of entities that are served instead of randomly generated ones.
+//!
+//! A dataset contains subjects, samples, and files in their wire formats (the
+//! same JSON representations that are returned by the API). Datasets are
+//! stored in one of two [`Format`]s:
+//!
+//! * [`Format::Json`]: a single JSON object with a `subjects`, `samples`, and
+//!   `files` array (any of which may be omitted when empty).
+//! * [`Format::Ndjson`]: one record per line, where each record is a JSON
+//!   object with a single `subject`, `sample`, or `file` key holding the
+//!   entity.
+//!
+//! Every record is validated with the deserializers from `ccdi-models`, and
+//! errors identify the offending record (by its kind and index) along with the
+//! path to the offending field within that record. Samples and files refer to
+//! the entities they were derived from by identifier, so a loaded dataset
+//! should also be checked with [`integrity::check()`](crate::integrity::check)
+//! before it is served.
+
+use std::path::Path;
+
+use serde::de::DeserializeOwned;
+use serde::Deserialize;
+use serde::Serialize;
+use serde_json::Value;
+
+use ccdi_models as models;
+
+use models::File;
+use models::Sample;
+use models::Subject;
+
+use crate::routes::duplicates;
+use crate::routes::file;
+use crate::routes::namespace::is_known;
+use crate::routes::sample;
+use crate::routes::subject;
+
+/// The format of a [`Dataset`].
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum Format {
+    /// A single JSON object with a `subjects`, `samples`, and `files` array.
+    #[default]
+    Json,
+
+    /// Newline-delimited JSON with one record per line.
+    Ndjson,
+}
+
+impl Format {
+    /// Gets the [`Format`] of a dataset from the extension of its path.
+    ///
+    /// Paths ending in `.ndjson` or `.jsonl` are [`Format::Ndjson`]; every
+    /// other path is [`Format::Json`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::path::Path;
+    ///
+    /// use ccdi_server as server;
+    ///
+    /// use server::dataset::Format;
+    ///
+    /// assert_eq!(Format::from_path(Path::new("data.json")), Format::Json);
+    /// assert_eq!(Format::from_path(Path::new("data.ndjson")), Format::Ndjson);
+    /// assert_eq!(Format::from_path(Path::new("data.jsonl")), Format::Ndjson);
+    /// assert_eq!(Format::from_path(Path::new("data")), Format::Json);
+    /// ```
+    pub fn from_path(path: &Path) -> Self {
+        match path.extension().and_then(|extension| extension.to_str()) {
+            Some("ndjson") | Some("jsonl") => Format::Ndjson,
+            _ => Format::Json,
+        }
+    }
+}
+
+/// The kind of a record within a [`Dataset`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Kind {
+    /// A subject.
+    Subject,
+
+    /// A sample.
+    Sample,
+
+    /// A file.
+    File,
+}
+
+impl std::fmt::Display for Kind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Kind::Subject => write!(f, "subject"),
+            Kind::Sample => write!(f, "sample"),
+            Kind::File => write!(f, "file"),
+        }
+    }
+}
+
+/// An error related to a [`Dataset`].
+#[derive(Debug)]
+pub enum Error {
+    /// An input/output error.
+    Io(std::io::Error),
+
+    /// The dataset (or, for [`Format::Ndjson`], a line of the dataset) is not
+    /// valid JSON or does not have the expected shape.
+    Syntax {
+        /// The line of the dataset that could not be parsed (only for
+        /// [`Format::Ndjson`]).
+        line: Option<usize>,
+
+        /// The inner error.
+        inner: serde_json::Error,
+    },
+
+    /// A record is not a valid entity.
+    InvalidRecord {
+        /// The kind of the record.
+        kind: Kind,
+
+        /// The index of the record amongst the records of the same kind.
+        index: usize,
+
+        /// The path to the offending field within the record.
+        field: String,
+
+        /// The inner error.
+        inner: serde_json::Error,
+    },
+
+    /// More than one record of the same kind has the same identifier.
+    DuplicateIdentifier {
+        /// The kind of the records.
+        kind: Kind,
+
+        /// The index of the first record with the identifier.
+        first: usize,
+
+        /// The index of the duplicate record.
+        index: usize,
+
+        /// The duplicated identifier.
+        identifier: String,
+    },
+
+    /// A record has an identifier within a namespace that is not known by
+    /// this server.
+    UnknownNamespace {
+        /// The kind of the record.
+        kind: Kind,
+
+        /// The index of the record amongst the records of the same kind.
+        index: usize,
+
+        /// The unknown namespace.
+        namespace: String,
+    },
+
+    /// The subject store could not be populated with the subjects.
+    SubjectStore(subject::Error),
+
+    /// The sample store could not be populated with the samples.
+    SampleStore(sample::Error),
+
+    /// The file store could not be populated with the files.
+    FileStore(file::Error),
+}
+
+impl std::fmt::Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::Io(err) => write!(f, "i/o error: {err}"),
+            Error::Syntax { line, inner } => match line {
+                Some(line) => write!(f, "invalid record on line {line}: {inner}"),
+                None => write!(f, "invalid dataset: {inner}"),
+            },
+            Error::InvalidRecord {
+                kind,
+                index,
+                field,
+                inner,
+            } => write!(
+                f,
+                "invalid {kind} at index {index} (field `{field}`): {inner}"
+            ),
+            Error::DuplicateIdentifier {
+                kind,
+                first,
+                index,
+                identifier,
+            } => write!(
+                f,
+                "duplicate {kind} at index {index}: {identifier} is already used by the {kind} \
+                 at index {first}"
+            ),
+            Error::UnknownNamespace {
+                kind,
+                index,
+                namespace,
+            } => write!(
+                f,
+                "{kind} at index {index} has an unknown namespace: {namespace}"
+            ),
+            Error::SubjectStore(err) => write!(f, "unable to populate the subject store: {err}"),
+            Error::SampleStore(err) => write!(f, "unable to populate the sample store: {err}"),
+            Error::FileStore(err) => write!(f, "unable to populate the file store: {err}"),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+/// A [`Result`](std::result::Result) with an [`Error`].
+pub type Result<T> = std::result::Result<T, Error>;
+
+/// A record within a [`Format::Ndjson`] dataset.
+#[derive(Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+enum Record<S, M, F> {
+    /// A subject.
+    Subject(S),
+
+    /// A sample.
+    Sample(M),
+
+    /// A file.
+    File(F),
+}
+
+/// The shape of a [`Format::Json`] dataset.
+#[derive(Default, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+struct Document<S, M, F> {
+    /// The subjects.
+    #[serde(default)]
+    subjects: Vec<S>,
+
+    /// The samples.
+    #[serde(default)]
+    samples: Vec<M>,
+
+    /// The files.
+    #[serde(default)]
+    files: Vec<F>,
+}
+
+/// A dataset of subjects, samples, and files.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct Dataset {
+    /// The subjects.
+    subjects: Vec<Subject>,
+
+    /// The samples.
+    samples: Vec<Sample>,
+
+    /// The files.
+    files: Vec<File>,
+}
+
+impl Dataset {
+    /// Creates a new [`Dataset`] from the entities held within the stores.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ccdi_server as server;
+    ///
+    /// use server::dataset::Dataset;
+    /// use server::routes::file;
+    /// use server::routes::sample;
+    /// use server::routes::subject;
+    ///
+    /// let subjects = subject::Store::random(10);
+    /// let samples = sample::Store::random(10, subjects.subjects.lock().unwrap());
+    /// let files = file::Store::random(10, samples.samples.lock().unwrap());
+    ///
+    /// let dataset = Dataset::from_stores(&subjects, &samples, &files);
+    /// assert_eq!(dataset.subjects().len(), 10);
+    /// ```
+    pub fn from_stores(
+        subjects: &subject::Store,
+        samples: &sample::Store,
+        files: &file::Store,
+    ) -> Self {
+        Self {
+            subjects: subjects.subjects.lock().unwrap().clone(),
+            samples: samples.samples.lock().unwrap().clone(),
+            files: files.files.lock().unwrap().clone(),
+        }
+    }
+
+    /// Gets the subjects within the [`Dataset`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ccdi_server as server;
+    ///
+    /// use server::dataset::Dataset;
+    /// use server::dataset::Format;
+    ///
+    /// let dataset = Dataset::parse("{}", Format::Json)?;
+    /// assert!(dataset.subjects().is_empty());
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn subjects(&self) -> &[Subject] {
+        self.subjects.as_slice()
+    }
+
+    /// Gets the samples within the [`Dataset`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ccdi_server as server;
+    ///
+    /// use server::dataset::Dataset;
+    /// use server::dataset::Format;
+    ///
+    /// let dataset = Dataset::parse("{}", Format::Json)?;
+    /// assert!(dataset.samples().is_empty());
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn samples(&self) -> &[Sample] {
+        self.samples.as_slice()
+    }
+
+    /// Gets the files within the [`Dataset`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ccdi_server as server;
+    ///
+    /// use server::dataset::Dataset;
+    /// use server::dataset::Format;
+    ///
+    /// let dataset = Dataset::parse("{}", Format::Json)?;
+    /// assert!(dataset.files().is_empty());
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn files(&self) -> &[File] {
+        self.files.as_slice()
+    }
+
+    /// Reads a [`Dataset`] from a file (the [`Format`] is determined by the
+    /// extension of the path).
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use ccdi_server as server;
+    ///
+    /// use server::dataset::Dataset;
+    ///
+    /// let dataset = Dataset::from_path("data.json")?;
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn from_path<P: AsRef<Path>>(path: P) -> Result<Self> {
+        let path = path.as_ref();
+        let contents = std::fs::read_to_string(path).map_err(Error::Io)?;
+        Self::parse(&contents, Format::from_path(path))
+    }
+
+    /// Parses a [`Dataset`] in the provided [`Format`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ccdi_server as server;
+    ///
+    /// use server::dataset::Dataset;
+    /// use server::dataset::Error;
+    /// use server::dataset::Format;
+    ///
+    /// let err = Dataset::parse(r#"{"subjects": [{"id": 1}]}"#, Format::Json).unwrap_err();
+    /// assert!(matches!(err, Error::InvalidRecord { index: 0, .. }));
+    ///
+    /// let err = Dataset::parse("{\"sample\": {}}\n[", Format::Ndjson).unwrap_err();
+    /// assert!(matches!(err, Error::Syntax { line: Some(2), .. }));
+    /// ```
+    pub fn parse(contents: &str, format: Format) -> Result<Self> {
+        let document = match format {
+            Format::Json => serde_json::from_str::<Document<Value, Value, Value>>(contents)
+                .map_err(|inner| Error::Syntax { line: None, inner })?,
+            Format::Ndjson => {
+                let mut document = Document::default();
+
+                for (i, line) in contents.lines().enumerate() {
+                    if line.trim().is_empty() {
+                        continue;
+                    }
+
+                    let record = serde_json::from_str::<Record<Value, Value, Value>>(line)
+                        .map_err(|inner| Error::Syntax {
+                            line: Some(i + 1),
+                            inner,
+                        })?;
+
+                    match record {
+                        Record::Subject(subject) => document.subjects.push(subject),
+                        Record::Sample(sample) => document.samples.push(sample),
+                        Record::File(file) => document.files.push(file),
+                    }
+                }
+
+                document
+            }
+        };
+
+        let subjects = deserialize_all::<Subject>(Kind::Subject, document.subjects)?;
+        check_duplicates(Kind::Subject, subjects.iter().map(|subject| subject.id()))?;
+        check_namespaces(
+            Kind::Subject,
+            subjects.iter().map(|subject| subject.id().namespace()),
+        )?;
+
+        let samples = deserialize_all::<Sample>(Kind::Sample, document.samples)?;
+        check_duplicates(Kind::Sample, samples.iter().map(|sample| sample.id()))?;
+        check_namespaces(
+            Kind::Sample,
+            samples.iter().map(|sample| sample.id().namespace()),
+        )?;
+
+        let files = deserialize_all::<File>(Kind::File, document.files)?;
+        check_duplicates(Kind::File, files.iter().map(|file| file.id()))?;
+        check_namespaces(Kind::File, files.iter().map(|file| file.id().namespace()))?;
+
+        Ok(Self {
+            subjects,
+            samples,
+            files,
+        })
+    }
+
+    /// Serializes the [`Dataset`] in the provided [`Format`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ccdi_server as server;
+    ///
+    /// use server::dataset::Dataset;
+    /// use server::dataset::Format;
+    /// use server::routes::file;
+    /// use server::routes::sample;
+    /// use server::routes::subject;
+    ///
+    /// let subjects = subject::Store::random(10);
+    /// let samples = sample::Store::random(10, subjects.subjects.lock().unwrap());
+    /// let files = file::Store::random(10, samples.samples.lock().unwrap());
+    ///
+    /// let dataset = Dataset::from_stores(&subjects, &samples, &files);
+    /// let contents = dataset.to_string(Format::Ndjson);
+    ///
+    /// assert_eq!(contents.lines().count(), 30);
+    /// assert_eq!(Dataset::parse(&contents, Format::Ndjson)?, dataset);
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn to_string(&self, format: Format) -> String {
+        // SAFETY: the entities are always serializable to JSON, so these
+        // always unwrap.
+        match format {
+            Format::Json => serde_json::to_string_pretty(&Document {
+                subjects: self.subjects.iter().collect::<Vec<_>>(),
+                samples: self.samples.iter().collect::<Vec<_>>(),
+                files: self.files.iter().collect::<Vec<_>>(),
+            })
+            .unwrap(),
+            Format::Ndjson => self
+                .subjects
+                .iter()
+                .map(Record::<_, &Sample, &File>::Subject)
+                .chain(self.samples.iter().map(Record::Sample))
+                .chain(self.files.iter().map(Record::File))
+                .map(|record| serde_json::to_string(&record).unwrap() + "\n")
+                .collect(),
+        }
+    }
+
+    /// Consumes `self` to populate the stores with exactly the entities
+    /// within the [`Dataset`].
+    ///
+    /// Each store checks the entities with which it is populated, as a
+    /// [`Dataset`] created with [`Dataset::from_stores()`] holds whatever the
+    /// stores held (including, for instance, duplicate identifiers).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ccdi_server as server;
+    ///
+    /// use server::dataset::Dataset;
+    /// use server::dataset::Format;
+    ///
+    /// let (subjects, samples, files) = Dataset::parse("{}", Format::Json)?.into_stores()?;
+    /// assert!(subjects.subjects.lock().unwrap().is_empty());
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn into_stores(self) -> Result<(subject::Store, sample::Store, file::Store)> {
+        Ok((
+            subject::Store::try_new(self.subjects).map_err(Error::SubjectStore)?,
+            sample::Store::try_new(self.samples).map_err(Error::SampleStore)?,
+            file::Store::try_new(self.files).map_err(Error::FileStore)?,
+        ))
+    }
+}
+
+/// Deserializes each record of a particular [`Kind`].
+fn deserialize_all<T: DeserializeOwned>(kind: Kind, records: Vec<Value>) -> Result<Vec<T>> {
+    records
+        .into_iter()
+        .enumerate()
+        .map(|(index, record)| {
+            serde_path_to_error::deserialize(record).map_err(|err| Error::InvalidRecord {
+                kind,
+                index,
+                field: err.path().to_string(),
+                inner: err.into_inner(),
+            })
+        })
+        .collect()
+}
+
+/// Checks that no two records of a particular [`Kind`] share an identifier.
+fn check_duplicates<'a, I, T>(kind: Kind, identifiers: I) -> Result<()>
+where
+    I: Iterator<Item = &'a T>,
+    T: Ord + std::fmt::Display + 'a,
+{
+    match duplicates(identifiers).into_iter().next() {
+        Some(duplicate) => Err(Error::DuplicateIdentifier {
+            kind,
+            first: duplicate.first,
+            index: duplicate.index,
+            identifier: duplicate.identifier.to_string(),
+        }),
+        None => Ok(()),
+    }
+}
+
+/// Checks that every record of a particular [`Kind`] has an identifier within
+/// a namespace known by this server.
+fn check_namespaces<'a, I>(kind: Kind, namespaces: I) -> Result<()>
+where
+    I: Iterator<Item = &'a models::namespace::Identifier>,
+{
+    for (index, namespace) in namespaces.enumerate() {
+        if !is_known(namespace.organization().as_str(), namespace.name().as_str()) {
+            return Err(Error::UnknownNamespace {
+                kind,
+                index,
+                namespace: namespace.to_string(),
+            });
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_points_at_the_offending_record_and_field() {
+        let subjects = subject::Store::random(3);
+        let samples = sample::Store::random(3, subjects.subjects.lock().unwrap());
+        let files = file::Store::random(3, samples.samples.lock().unwrap());
+        let dataset = Dataset::from_stores(&subjects, &samples, &files);
+
+        let mut document = serde_json::from_str::<Value>(&dataset.to_string(Format::Json)).unwrap();
+        document["samples"][2]["metadata"] = serde_json::json!({ "tissue_type": 1 });
+
+        let err = Dataset::parse(&document.to_string(), Format::Json).unwrap_err();
+        match err {
+            Error::InvalidRecord {
+                kind, index, field, ..
+            } => {
+                assert_eq!(kind, Kind::Sample);
+                assert_eq!(index, 2);
+                assert!(field.starts_with("metadata"), "{field}");
+            }
+            err => panic!("unexpected error: {err}"),
+        }
+    }
+
+    #[test]
+    fn it_rejects_duplicate_identifiers() {
+        let subjects = subject::Store::random(2);
+        let samples = sample::Store::random(0, subjects.subjects.lock().unwrap());
+        let files = file::Store::random(0, samples.samples.lock().unwrap());
+        let dataset = Dataset::from_stores(&subjects, &samples, &files);
+
+        let mut document = serde_json::from_str::<Value>(&dataset.to_string(Format::Json)).unwrap();
+        let first = document["subjects"][0].clone();
+        document["subjects"].as_array_mut().unwrap().push(first);
+
+        let err = Dataset::parse(&document.to_string(), Format::Json).unwrap_err();
+        assert!(matches!(
+            err,
+            Error::DuplicateIdentifier {
+                kind: Kind::Subject,
+                first: 0,
+                index: 2,
+                ..
+            }
+        ));
+    }
+
+    #[test]
+    fn it_reports_duplicate_identifiers_when_populating_the_stores() {
+        let subjects = subject::Store::random(2);
+        let samples = sample::Store::random(0, subjects.subjects.lock().unwrap());
+        let files = file::Store::random(0, samples.samples.lock().unwrap());
+
+        // NOTE: the fields of a store are public, so a store may hold
+        // duplicate identifiers that are then copied into the dataset.
+        {
+            let mut subjects = subjects.subjects.lock().unwrap();
+            let first = subjects[0].clone();
+            subjects.push(first);
+        }
+
+        let dataset = Dataset::from_stores(&subjects, &samples, &files);
+        let err = dataset.into_stores().err().unwrap();
+        assert!(matches!(
+            err,
+            Error::SubjectStore(subject::Error::DuplicateIdentifiers(_))
+        ));
+    }
+
+    #[test]
+    fn it_rejects_unknown_namespaces() {
+        let subjects = subject::Store::random(2);
+        let samples = sample::Store::random(0, subjects.subjects.lock().unwrap());
+        let files = file::Store::random(0, samples.samples.lock().unwrap());
+        let dataset = Dataset::from_stores(&subjects, &samples, &files);
+
+        let mut document = serde_json::from_str::<Value>(&dataset.to_string(Format::Json)).unwrap();
+        document["subjects"][1]["id"]["namespace"]["name"] = Value::from("ExampleNamespaceThree");
+
+        let err = Dataset::parse(&document.to_string(), Format::Json).unwrap_err();
+        assert!(matches!(
+            err,
+            Error::UnknownNamespace {
+                kind: Kind::Subject,
+                index: 1,
+                ..
+            }
+        ));
+    }
+
+    #[test]
+    fn it_rejects_unknown_record_kinds() {
+        let err = Dataset::parse("{\"subject\": {}}", Format::Ndjson).unwrap_err();
+        assert!(matches!(err, Error::InvalidRecord { index: 0, .. }));
+
+        let err = Dataset::parse("{\"organization\": {}}", Format::Ndjson).unwrap_err();
+        assert!(matches!(err, Error::Syntax { line: Some(1), .. }));
+
+        let err = Dataset::parse("{\"organizations\": []}", Format::Json).unwrap_err();
+        assert!(matches!(err, Error::Syntax { line: None, .. }));
+    }
+}
@@ -0,0 +1,189 @@
+//! Guarding against response amplification when expanding referenced
+//! entities.
+//!
+//! Expanding a page of entities (e.g., `/sample?expand=subject`) embeds the
+//! referenced entities within the response, so a single request with a large
+//! `per_page` can produce a response that is many times larger than a
+//! non-expanded page. Before a page is serialized, its estimated [`cost()`]
+//! (the page size multiplied by the expansion factor) is compared against a
+//! configurable budget (see [`Config`]), and requests that exceed the budget
+//! are rejected rather than served.
+//!
+//! Referenced entities are embedded only once per page (keyed by [`key()`]),
+//! so the cost of an expanded page grows with the page size rather than with
+//! the number of references to each entity.
+
+use ccdi_models as models;
+
+use crate::params::expand::Expand;
+use crate::responses::error;
+
+/// The default budget for the estimated cost of an expanded page.
+///
+/// With this budget, a page expanded with `subject` may contain up to `500`
+/// samples.
+pub const DEFAULT_BUDGET: usize = 1_000;
+
+/// Configuration for expanding referenced entities.
+#[derive(Clone, Debug)]
+pub struct Config {
+    /// The maximum estimated cost of an expanded page.
+    budget: usize,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            budget: DEFAULT_BUDGET,
+        }
+    }
+}
+
+impl Config {
+    /// Creates a new [`Config`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ccdi_server as server;
+    ///
+    /// use server::expand::Config;
+    ///
+    /// let config = Config::new(200);
+    /// assert_eq!(config.budget(), 200);
+    /// ```
+    pub fn new(budget: usize) -> Self {
+        Self { budget }
+    }
+
+    /// Gets the maximum estimated cost of an expanded page for the [`Config`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ccdi_server as server;
+    ///
+    /// use server::expand::Config;
+    /// use server::expand::DEFAULT_BUDGET;
+    ///
+    /// let config = Config::default();
+    /// assert_eq!(config.budget(), DEFAULT_BUDGET);
+    /// ```
+    pub fn budget(&self) -> usize {
+        self.budget
+    }
+}
+
+/// Gets the expansion factor of an [`Expand`]: the estimated cost of each
+/// entity within an expanded page.
+///
+/// Each entity on the page costs one, and each entity may embed at most one
+/// referenced entity.
+///
+/// # Examples
+///
+/// ```
+/// use ccdi_server as server;
+///
+/// use server::expand::factor;
+/// use server::params::expand::Expand;
+///
+/// assert_eq!(factor(Expand::Subject), 2);
+/// ```
+pub fn factor(expand: Expand) -> usize {
+    match expand {
+        Expand::Subject => 2,
+    }
+}
+
+/// Computes the estimated cost of a page of `per_page` entities expanded with
+/// `expand`.
+///
+/// # Examples
+///
+/// ```
+/// use ccdi_server as server;
+///
+/// use server::expand::cost;
+/// use server::params::expand::Expand;
+///
+/// assert_eq!(cost(100, Expand::Subject), 200);
+/// assert_eq!(cost(usize::MAX, Expand::Subject), usize::MAX);
+/// ```
+pub fn cost(per_page: usize, expand: Expand) -> usize {
+    per_page.saturating_mul(factor(expand))
+}
+
+/// Checks that a page of `per_page` entities expanded with `expand` is within
+/// the budget of the [`Config`].
+///
+/// If the page is over budget, an error suggesting a lower `per_page` (or
+/// dropping `expand`) is returned.
+///
+/// # Examples
+///
+/// ```
+/// use ccdi_server as server;
+///
+/// use server::expand::check;
+/// use server::expand::Config;
+/// use server::params::expand::Expand;
+///
+/// let config = Config::new(200);
+///
+/// assert!(check(&config, 100, Expand::Subject).is_ok());
+/// assert!(check(&config, 101, Expand::Subject).is_err());
+/// ```
+pub fn check(config: &Config, per_page: usize, expand: Expand) -> Result<(), error::Kind> {
+    let cost = cost(per_page, expand);
+
+    if cost <= config.budget {
+        return Ok(());
+    }
+
+    Err(error::Kind::invalid_parameters(
+        Some(vec![String::from("per_page"), String::from("expand")]),
+        format!(
+            "the estimated cost of a page of {per_page} entities with `expand={expand}` is \
+             {cost} (each entity costs {}), which exceeds the budget of {}. Lower `per_page` \
+             to at most {} or drop `expand`.",
+            factor(expand),
+            config.budget,
+            config.budget / factor(expand),
+        ),
+    ))
+}
+
+/// Gets the key of a subject within an embedded map of subjects
+/// (`<organization>/<namespace>/<name>`).
+///
+/// # Examples
+///
+/// ```
+/// use ccdi_models as models;
+/// use ccdi_server as server;
+///
+/// use models::namespace;
+/// use models::subject::Identifier;
+/// use server::expand::key;
+///
+/// let namespace = namespace::Identifier::new(
+///     "example-organization"
+///         .parse::<models::organization::Identifier>()
+///         .unwrap(),
+///     namespace::identifier::Name::try_new("ExampleNamespace").unwrap(),
+/// );
+///
+/// assert_eq!(
+///     key(&Identifier::new(namespace, "Subject1")),
+///     "example-organization/ExampleNamespace/Subject1"
+/// );
+/// ```
+pub fn key(subject: &models::subject::Identifier) -> String {
+    format!(
+        "{}/{}/{}",
+        subject.namespace().organization().as_str(),
+        subject.namespace().name().as_str(),
+        subject.name()
+    )
+}
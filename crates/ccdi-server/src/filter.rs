@@ -1,16 +1,32 @@
 //! Common filtering utilities.
 
 use introspect::Introspected;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use serde_json::Value;
+use utoipa::IntoParams;
 
 use ccdi_models as models;
 
 use models::Entity;
 
+use crate::filter::provenance::ProvenanceQuery;
+use crate::filter::rule::Case;
+use crate::filter::rule::Rules;
+use crate::responses::error;
+use crate::suggest;
+
+pub mod alias;
+pub mod expression;
 pub mod file;
+pub mod provenance;
+pub mod rule;
 pub mod sample;
 pub mod sample_diagnosis;
+pub mod scope;
 pub mod subject;
 pub mod subject_diagnosis;
+pub mod unharmonized;
 
 /// A trait that defines a method for filtering by metadata values.
 ///
@@ -20,9 +36,249 @@ where
     T: Entity,
 {
     /// Filters entities by checking if the value of the provided field name
-    /// matches the value of that field within the filter parameters. Matches
-    /// are case-sensitive.
-    fn filter_metadata_field(self, field: String, filter_params: &P) -> Vec<T>;
+    /// matches the value of that field within the filter parameters. Matching
+    /// is done according to the [`Strategy`](rule::Strategy) declared for the
+    /// field within the parameters' [rule table](rule::Rules) with regard to
+    /// the provided [`Case`].
+    fn filter_metadata_field(self, field: String, filter_params: &P, case: Case) -> Vec<T>;
+}
+
+/// A trait that defines a method for getting the values of the filterable
+/// fields of an entity.
+pub trait FieldValues {
+    /// Gets the values of the provided field as they are matched against a
+    /// filter parameter (or [`None`] if the field has no value). Fields with
+    /// multiple values have one value per element, in the order in which the
+    /// elements are serialized.
+    fn field_values(&self, field: &str) -> Option<Vec<String>>;
+}
+
+/// Validates the raw value of every provided filter parameter against the
+/// [`Strategy`](rule::Strategy) declared for it within the parameters'
+/// [rule table](rule::Rules).
+///
+/// # Examples
+///
+/// ```
+/// use ccdi_server as server;
+///
+/// use server::filter::validate;
+/// use server::params::filter::Sample as SampleFilterParams;
+///
+/// let params = SampleFilterParams {
+///     age_at_collection: Some(String::from(r#"{"gte":0,"lte":1826.25}"#)),
+///     ..Default::default()
+/// };
+///
+/// assert!(validate(&params).is_ok());
+///
+/// let params = SampleFilterParams {
+///     age_at_collection: Some(String::from(r#"{"gte":1826.25,"lte":0}"#)),
+///     ..Default::default()
+/// };
+///
+/// assert!(validate(&params).is_err());
+/// ```
+pub fn validate<P>(filter_params: &P) -> Result<(), error::Kind>
+where
+    P: Rules + Serialize,
+{
+    validate_with_prefix(filter_params, "")
+}
+
+/// Validates the raw value of every provided filter parameter exactly as
+/// [`validate()`] does, but reports each invalid parameter with `prefix` (for
+/// parameters that were provided with a prefix, such as `sample.`).
+///
+/// # Examples
+///
+/// ```
+/// use ccdi_server as server;
+///
+/// use server::filter::validate_with_prefix;
+/// use server::params::filter::Sample as SampleFilterParams;
+///
+/// let params = SampleFilterParams {
+///     age_at_collection: Some(String::from(r#"{"gte":1826.25,"lte":0}"#)),
+///     ..Default::default()
+/// };
+///
+/// let err = validate_with_prefix(&params, "sample.").unwrap_err();
+/// assert!(err
+///     .to_string()
+///     .starts_with("Invalid value for parameter 'sample.age_at_collection'"));
+/// ```
+pub fn validate_with_prefix<P>(filter_params: &P, prefix: &str) -> Result<(), error::Kind>
+where
+    P: Rules + Serialize,
+{
+    // SAFETY: filter parameters are always serializable to a JSON object.
+    let params = serde_json::to_value(filter_params).unwrap();
+
+    for (field, strategy) in P::RULES {
+        if let Some(Value::String(query)) = params.get(field) {
+            let invalid = |reason: String| {
+                error::Kind::invalid_parameters(Some(vec![format!("{prefix}{field}")]), reason)
+            };
+
+            // NOTE: a provenance query is validated in place of the query, as
+            // its value is matched according to the strategy of the field.
+            if P::PROVENANCE.contains(field) {
+                // NOTE: the reason may end with a parenthesis or a backtick,
+                // which would otherwise be left without a period when it is
+                // formalized.
+                if let Some(query) =
+                    ProvenanceQuery::parse(query).map_err(|err| invalid(format!("{err}.")))?
+                {
+                    query
+                        .validate(*strategy)
+                        .map_err(|err| invalid(err.to_string()))?;
+                    continue;
+                }
+            }
+
+            strategy
+                .validate(query)
+                .map_err(|err| invalid(err.to_string()))?;
+        }
+    }
+
+    // NOTE: the scoping parameters are only present for entities that are
+    // identified within a namespace (they are absent for diagnoses).
+    let query = |name: &str| params.get(name).and_then(Value::as_str);
+    scope::check(query(scope::NAMESPACE), query(scope::ORGANIZATION), prefix)?;
+
+    Ok(())
+}
+
+/// The prefix of the query parameters that filter on unharmonized fields.
+pub(crate) const UNHARMONIZED_PREFIX: &str = "metadata.unharmonized.";
+
+/// Gets the names of the query parameters described by `P`.
+///
+/// # Examples
+///
+/// ```
+/// use ccdi_server as server;
+///
+/// use server::filter::parameter_names;
+/// use server::params::filter::Sample as SampleFilterParams;
+///
+/// let names = parameter_names::<SampleFilterParams>();
+/// assert!(names.contains(&String::from("anatomical_sites")));
+/// ```
+pub fn parameter_names<P>() -> Vec<String>
+where
+    P: IntoParams,
+{
+    P::into_params(|| None)
+        .into_iter()
+        .map(|parameter| parameter.name)
+        .collect()
+}
+
+/// Checks that every key within a raw `query` string is one of the `known`
+/// parameter names.
+///
+/// Keys that filter on unharmonized fields (prefixed with
+/// `metadata.unharmonized.`) are always accepted. Otherwise, an
+/// [`InvalidParameters`](error::Kind::invalid_parameters) error is returned
+/// that lists each unrecognized key alongside the known names that are within
+/// [`MAX_DISTANCE`](suggest::MAX_DISTANCE) edits of it (if any).
+///
+/// # Examples
+///
+/// ```
+/// use ccdi_server as server;
+///
+/// use server::filter::check_keys;
+/// use server::filter::parameter_names;
+/// use server::params::filter::Sample as SampleFilterParams;
+///
+/// let known = parameter_names::<SampleFilterParams>();
+///
+/// assert!(check_keys("anatomical_sites=Chest", &known).is_ok());
+/// assert!(check_keys("metadata.unharmonized.foo=bar", &known).is_ok());
+///
+/// let err = check_keys("anatomic_sites=Chest", &known).unwrap_err();
+/// assert_eq!(
+///     err.to_string(),
+///     "Invalid value for parameter 'anatomic_sites': unrecognized \
+///      parameter(s): `anatomic_sites` (did you mean `anatomical_sites`?)."
+/// );
+/// ```
+pub fn check_keys(query: &str, known: &[String]) -> Result<(), error::Kind> {
+    let mut unknown = Vec::new();
+
+    for (key, _) in url::form_urlencoded::parse(query.as_bytes()) {
+        if known.iter().any(|name| *name == key)
+            || key.starts_with(UNHARMONIZED_PREFIX)
+            || unknown.contains(&key.to_string())
+        {
+            continue;
+        }
+
+        unknown.push(key.to_string());
+    }
+
+    if unknown.is_empty() {
+        return Ok(());
+    }
+
+    let reason = unknown
+        .iter()
+        .map(|key| {
+            let suggestions = suggest::nearest(key, known.iter())
+                .into_iter()
+                .map(|suggestion| format!("`{suggestion}`"))
+                .collect::<Vec<_>>();
+
+            match suggestions.is_empty() {
+                true => format!("`{key}`"),
+                false => format!("`{key}` (did you mean {}?)", suggestions.join(" or ")),
+            }
+        })
+        .collect::<Vec<_>>()
+        .join(", ");
+
+    Err(error::Kind::invalid_parameters(
+        Some(unknown),
+        // NOTE: the reason ends with a backtick or a parenthesis, which
+        // would otherwise be left without a period when it is formalized.
+        format!("unrecognized parameter(s): {reason}."),
+    ))
+}
+
+/// Removes the keys of a query string that are not recognized (i.e., the keys
+/// that would be reported by [`check_keys()`]).
+///
+/// This is used when unrecognized keys are ignored rather than rejected (see
+/// the [`strict`](crate::strict) module) where the mere presence of a key
+/// changes the results (e.g., any key prefixed with `sample.`).
+///
+/// # Examples
+///
+/// ```
+/// use ccdi_server as server;
+///
+/// use server::filter::retain_keys;
+///
+/// let known = vec![String::from("sex")];
+///
+/// assert_eq!(
+///     retain_keys("sex=F&sexx=M&metadata.unharmonized.foo=bar", &known),
+///     "sex=F&metadata.unharmonized.foo=bar"
+/// );
+/// assert_eq!(retain_keys("sexx=M", &known), "");
+/// ```
+pub fn retain_keys(query: &str, known: &[String]) -> String {
+    url::form_urlencoded::Serializer::new(String::new())
+        .extend_pairs(
+            url::form_urlencoded::parse(query.as_bytes()).filter(|(key, _)| {
+                known.iter().any(|name| *name == *key) || key.starts_with(UNHARMONIZED_PREFIX)
+            }),
+        )
+        .finish()
 }
 
 /// Filters a list of entities based on the provided filter parameters.
@@ -126,6 +382,9 @@ where
 ///         vital_status: None,
 ///         age_at_vital_status: None,
 ///         depositions: None,
+///         has_metadata: None,
+///         namespace: None,
+///         organization: None,
 ///     },
 /// );
 ///
@@ -150,6 +409,9 @@ where
 ///         vital_status: None,
 ///         age_at_vital_status: None,
 ///         depositions: None,
+///         has_metadata: None,
+///         namespace: None,
+///         organization: None,
 ///     },
 /// );
 ///
@@ -170,17 +432,147 @@ where
 ///         vital_status: None,
 ///         age_at_vital_status: None,
 ///         depositions: None,
+///         has_metadata: None,
+///         namespace: None,
+///         organization: None,
 ///     },
 /// );
 ///
 /// assert_eq!(results.len(), 0);
+///
+/// // Filtering of subjects by whether they have metadata. Subjects with an
+/// // effectively empty metadata block are considered to have no metadata.
+/// let results = filter::<Subject, SubjectFilterParams>(
+///     subjects.clone(),
+///     SubjectFilterParams {
+///         has_metadata: Some(false),
+///         ..Default::default()
+///     },
+/// );
+///
+/// assert_eq!(results.len(), 2);
+///
+/// let results = filter::<Subject, SubjectFilterParams>(
+///     subjects.clone(),
+///     SubjectFilterParams {
+///         has_metadata: Some(true),
+///         ..Default::default()
+///     },
+/// );
+///
+/// assert_eq!(results.len(), 2);
+///
+/// // Filtering of subjects that do _not_ have sex "F". Subjects without a
+/// // value for the field are included.
+/// let results = filter::<Subject, SubjectFilterParams>(
+///     subjects.clone(),
+///     SubjectFilterParams {
+///         sex: Some(String::from("not:F")),
+///         ..Default::default()
+///     },
+/// );
+///
+/// assert_eq!(results.len(), 2);
+/// assert_eq!(
+///     results.first().unwrap().id().name().as_str(),
+///     "SubjectName001"
+/// );
+///
+/// // Filtering of subjects that have any value for sex.
+/// let results = filter::<Subject, SubjectFilterParams>(
+///     subjects.clone(),
+///     SubjectFilterParams {
+///         sex: Some(String::from("not:null")),
+///         ..Default::default()
+///     },
+/// );
+///
+/// assert_eq!(results.len(), 2);
+/// assert_eq!(
+///     results.first().unwrap().id().name().as_str(),
+///     "SubjectName003"
+/// );
 /// ```
-pub fn filter<T, P>(mut entities: Vec<T>, filter_params: P) -> Vec<T>
+pub fn filter<T, P>(entities: Vec<T>, filter_params: P) -> Vec<T>
 where
-    T: Entity,
+    T: Entity + FieldValues + Serialize,
+    Vec<T>: FilterMetadataField<T, P>,
+    P: DeserializeOwned + Introspected + Rules + Serialize,
+{
+    filter_with_case(entities, filter_params, Case::Sensitive)
+}
+
+/// Filters a list of entities based on the provided filter parameters with
+/// regard to the provided [`Case`].
+///
+/// Matching is done exactly as in [`filter()`], except that, when `case` is
+/// [`Case::Insensitive`], text is compared without regard to case.
+///
+/// Any filter parameter that also constrains the provenance of its field is
+/// split out of the filter parameters, and its value and provenance
+/// constraints are then matched together against each value of the field
+/// within the metadata of each entity (see the [`provenance`] module).
+///
+/// # Examples
+///
+/// ```
+/// use ccdi_cde as cde;
+/// use ccdi_models as models;
+/// use ccdi_server as server;
+///
+/// use models::metadata::field::unowned::subject::Ethnicity;
+/// use models::namespace;
+/// use models::subject::metadata::Builder;
+/// use models::subject::Kind;
+/// use models::Subject;
+/// use server::filter::filter_with_case;
+/// use server::filter::rule::Case;
+/// use server::params::filter::Subject as SubjectFilterParams;
+///
+/// let namespace = namespace::Identifier::new(
+///     "example-organization"
+///         .parse::<models::organization::Identifier>()
+///         .unwrap(),
+///     "ExampleNamespace"
+///         .parse::<namespace::identifier::Name>()
+///         .unwrap(),
+/// );
+///
+/// let subjects = vec![Subject::new(
+///     models::subject::Identifier::new(namespace, "SubjectName001"),
+///     Kind::Participant,
+///     None,
+///     Some(
+///         Builder::default()
+///             .ethnicity(Ethnicity::new(
+///                 cde::v2::subject::Ethnicity::HispanicOrLatino,
+///                 None,
+///                 None,
+///                 None,
+///             ))
+///             .build(),
+///     ),
+/// )];
+///
+/// let params = || SubjectFilterParams {
+///     ethnicity: Some(String::from("hispanic or latino")),
+///     ..Default::default()
+/// };
+///
+/// let results = filter_with_case(subjects.clone(), params(), Case::Sensitive);
+/// assert!(results.is_empty());
+///
+/// let results = filter_with_case(subjects, params(), Case::Insensitive);
+/// assert_eq!(results.len(), 1);
+/// ```
+pub fn filter_with_case<T, P>(mut entities: Vec<T>, filter_params: P, case: Case) -> Vec<T>
+where
+    T: Entity + FieldValues + Serialize,
     Vec<T>: FilterMetadataField<T, P>,
-    P: Introspected,
+    P: DeserializeOwned + Introspected + Rules + Serialize,
 {
+    let (filter_params, provenance) = provenance::split(filter_params);
+
     for member in P::introspected_members() {
         let field = match member {
             // SAFETY: parameters will _always_ be expression as a struct with
@@ -197,8 +589,561 @@ where
             false => field,
         };
 
-        entities = entities.filter_metadata_field(field, &filter_params);
+        entities = entities.filter_metadata_field(field, &filter_params, case);
     }
 
-    entities
+    provenance::filter::<T, P>(entities, &provenance, case)
+}
+
+#[cfg(test)]
+mod tests {
+    use ccdi_cde as cde;
+    use nonempty::NonEmpty;
+
+    use ccdi_models::gateway::AnonymousOrReference;
+    use ccdi_models::gateway::Controlled;
+    use ccdi_models::gateway::Link;
+    use ccdi_models::gateway::Open;
+    use ccdi_models::metadata::field::unowned::file::Size;
+    use ccdi_models::metadata::field::unowned::sample::DiseasePhase;
+    use ccdi_models::metadata::field::unowned::sample::TumorTissueSite;
+    use ccdi_models::namespace;
+    use ccdi_models::File;
+    use ccdi_models::Gateway;
+    use ccdi_models::Sample;
+    use ccdi_models::Url;
+    use models::metadata::field::description::harmonized;
+    use models::metadata::field::description::Description;
+    use rand::rngs::StdRng;
+    use rand::SeedableRng as _;
+    use serde_json::json;
+
+    use crate::params::filter::File as FilterFileParams;
+    use crate::params::filter::Sample as FilterSampleParams;
+    use crate::params::filter::Subject as FilterSubjectParams;
+    use crate::routes::sample;
+    use crate::routes::subject;
+
+    use super::*;
+
+    fn namespace() -> namespace::Identifier {
+        namespace::Identifier::new(
+            "example-organization"
+                .parse::<models::organization::Identifier>()
+                .unwrap(),
+            namespace::identifier::Name::try_new("ExampleNamespace").unwrap(),
+        )
+    }
+
+    fn samples() -> Vec<Sample> {
+        let sample = |name: &str, metadata: Option<models::sample::Metadata>| {
+            Sample::new(
+                models::sample::Identifier::new(namespace(), name),
+                models::subject::Identifier::new(namespace(), "Subject1"),
+                None,
+                metadata,
+            )
+        };
+
+        vec![
+            sample("Sample1", None),
+            sample(
+                "Sample2",
+                Some(models::sample::metadata::Builder::default().build()),
+            ),
+            sample(
+                "Sample3",
+                Some(
+                    models::sample::metadata::Builder::default()
+                        .disease_phase(DiseasePhase::new(
+                            cde::v1::sample::DiseasePhase::InitialDiagnosis,
+                            None,
+                            None,
+                            None,
+                        ))
+                        .tumor_tissue_site(TumorTissueSite::new(
+                            models::sample::metadata::TumorTissueSite::try_new("C71.9").unwrap(),
+                            None,
+                            None,
+                            None,
+                        ))
+                        .build(),
+                ),
+            ),
+        ]
+    }
+
+    fn files() -> Vec<File> {
+        let file = |name: &str, metadata: Option<models::file::Metadata>| {
+            File::new(
+                models::file::Identifier::new(namespace(), cde::v1::file::Name::new(name)),
+                NonEmpty::new(models::sample::Identifier::new(namespace(), "Sample1")),
+                None,
+                metadata,
+            )
+        };
+
+        vec![
+            file("File1.txt", None),
+            file(
+                "File2.txt",
+                Some(models::file::metadata::Builder::default().build()),
+            ),
+            file(
+                "File3.txt",
+                Some(
+                    models::file::metadata::Builder::default()
+                        .size(Size::new(cde::v1::file::Size::new(42), None, None, None))
+                        .build(),
+                ),
+            ),
+        ]
+    }
+
+    #[test]
+    fn it_filters_samples_by_whether_they_have_metadata() {
+        let results = filter::<Sample, FilterSampleParams>(
+            samples(),
+            FilterSampleParams {
+                has_metadata: Some(true),
+                ..Default::default()
+            },
+        );
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].id().name(), "Sample3");
+
+        let results = filter::<Sample, FilterSampleParams>(
+            samples(),
+            FilterSampleParams {
+                has_metadata: Some(false),
+                ..Default::default()
+            },
+        );
+        assert_eq!(results.len(), 2);
+
+        let results = filter::<Sample, FilterSampleParams>(samples(), Default::default());
+        assert_eq!(results.len(), 3);
+    }
+
+    #[test]
+    fn it_filters_files_by_whether_they_have_metadata() {
+        let results = filter::<File, FilterFileParams>(
+            files(),
+            FilterFileParams {
+                has_metadata: Some(true),
+                ..Default::default()
+            },
+        );
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].id().name().as_str(), "File3.txt");
+
+        let results = filter::<File, FilterFileParams>(
+            files(),
+            FilterFileParams {
+                has_metadata: Some(false),
+                ..Default::default()
+            },
+        );
+        assert_eq!(results.len(), 2);
+
+        let results = filter::<File, FilterFileParams>(files(), Default::default());
+        assert_eq!(results.len(), 3);
+    }
+
+    #[test]
+    fn it_filters_files_by_access() {
+        let link = || Link::Direct {
+            url: "https://example.com".parse::<Url>().unwrap(),
+        };
+        let file = |name: &str, gateway: Option<AnonymousOrReference>| {
+            File::new(
+                models::file::Identifier::new(namespace(), cde::v1::file::Name::new(name)),
+                NonEmpty::new(models::sample::Identifier::new(namespace(), "Sample1")),
+                gateway.map(NonEmpty::new),
+                None,
+            )
+        };
+
+        let files = vec![
+            file(
+                "File1.txt",
+                Some(AnonymousOrReference::Anonymous {
+                    gateway: Gateway::Open(Open::try_new(link(), None).unwrap()),
+                }),
+            ),
+            file(
+                "File2.txt",
+                Some(AnonymousOrReference::Anonymous {
+                    gateway: Gateway::Controlled(
+                        Controlled::try_new(link(), "phs000000", "Apply through dbGaP.", None)
+                            .unwrap(),
+                    ),
+                }),
+            ),
+            // The named gateway that this refers to is open access.
+            file(
+                "File3.txt",
+                Some(AnonymousOrReference::Reference {
+                    gateway: String::from("gateway"),
+                }),
+            ),
+            file("File4.txt", None),
+        ];
+
+        let names = |access: &str| {
+            filter::<File, FilterFileParams>(
+                files.clone(),
+                FilterFileParams {
+                    access: Some(String::from(access)),
+                    ..Default::default()
+                },
+            )
+            .into_iter()
+            .map(|file| file.id().name().as_str().to_owned())
+            .collect::<Vec<_>>()
+        };
+
+        assert_eq!(names("open"), vec!["File1.txt", "File3.txt"]);
+        assert_eq!(names("Controlled"), vec!["File2.txt"]);
+        assert!(names("registered").is_empty());
+        assert_eq!(names("not:open"), vec!["File2.txt", "File4.txt"]);
+    }
+
+    #[test]
+    fn it_filters_files_by_name() {
+        let results = filter::<File, FilterFileParams>(
+            files(),
+            FilterFileParams {
+                name: Some(String::from("File2.txt")),
+                ..Default::default()
+            },
+        );
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].id().name().as_str(), "File2.txt");
+
+        // Matching is exact and case-sensitive.
+        let results = filter::<File, FilterFileParams>(
+            files(),
+            FilterFileParams {
+                name: Some(String::from("file2.txt")),
+                ..Default::default()
+            },
+        );
+        assert!(results.is_empty());
+    }
+
+    #[test]
+    fn it_filters_samples_by_negated_queries() {
+        let results = filter::<Sample, FilterSampleParams>(
+            samples(),
+            FilterSampleParams {
+                disease_phase: Some(String::from("not:Initial Diagnosis")),
+                ..Default::default()
+            },
+        );
+        assert_eq!(results.len(), 2);
+        assert_eq!(results[0].id().name(), "Sample1");
+        assert_eq!(results[1].id().name(), "Sample2");
+
+        let results = filter::<Sample, FilterSampleParams>(
+            samples(),
+            FilterSampleParams {
+                disease_phase: Some(String::from("not:null")),
+                ..Default::default()
+            },
+        );
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].id().name(), "Sample3");
+    }
+
+    #[test]
+    fn it_filters_samples_by_tumor_tissue_site() {
+        let results = filter::<Sample, FilterSampleParams>(
+            samples(),
+            FilterSampleParams {
+                tumor_tissue_site: Some(String::from("C71.9")),
+                ..Default::default()
+            },
+        );
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].id().name(), "Sample3");
+
+        // Matching is exact, so a code does not match its subsites.
+        let results = filter::<Sample, FilterSampleParams>(
+            samples(),
+            FilterSampleParams {
+                tumor_tissue_site: Some(String::from("C71")),
+                ..Default::default()
+            },
+        );
+        assert!(results.is_empty());
+    }
+
+    #[test]
+    fn it_filters_samples_by_every_harmonized_field() {
+        let mut rng = StdRng::seed_from_u64(0);
+        let subjects = subject::Store::random_with_rng(10, 0.0, &mut rng);
+        let samples =
+            sample::Store::random_with_rng(50, subjects.subjects.lock().unwrap(), 0.0, &mut rng)
+                .samples
+                .into_inner()
+                .unwrap();
+
+        // The values of a field as serialized (which is independent of how the
+        // values are matched by the filter).
+        let values = |sample: &Sample, field: &str| -> Vec<Value> {
+            let metadata = serde_json::to_value(sample.metadata()).unwrap();
+
+            match &metadata[field] {
+                Value::Null => Vec::new(),
+                Value::Array(members) => members
+                    .iter()
+                    .map(|member| member["value"].clone())
+                    .collect(),
+                value => vec![value["value"].clone()],
+            }
+        };
+
+        let mut fields = harmonized::sample::get_field_descriptions()
+            .into_iter()
+            .filter_map(|description| match description {
+                Description::Harmonized(field) => Some(field.path().to_string()),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+        fields.push(String::from("identifiers"));
+
+        let parameters = parameter_names::<FilterSampleParams>();
+        for field in &fields {
+            assert!(parameters.contains(field), "`{field}` cannot be filtered");
+        }
+
+        // NOTE: array fields are queried by their first member.
+        let queries = |sample: &Sample| {
+            let metadata = sample.metadata().unwrap();
+
+            [
+                (
+                    "age_at_diagnosis",
+                    metadata.age_at_diagnosis().map(|v| v.to_string()),
+                ),
+                (
+                    "anatomical_sites",
+                    metadata.anatomical_sites().map(|v| v[0].to_string()),
+                ),
+                ("diagnosis", metadata.diagnosis().map(|v| v.to_string())),
+                (
+                    "diagnosis_category",
+                    metadata.diagnosis_category().map(|v| v.to_string()),
+                ),
+                (
+                    "disease_phase",
+                    metadata.disease_phase().map(|v| v.to_string()),
+                ),
+                (
+                    "library_selection_method",
+                    metadata.library_selection_method().map(|v| v.to_string()),
+                ),
+                (
+                    "library_strategy",
+                    metadata.library_strategy().map(|v| v.to_string()),
+                ),
+                (
+                    "library_source_material",
+                    metadata.library_source_material().map(|v| v.to_string()),
+                ),
+                (
+                    "preservation_method",
+                    metadata.preservation_method().map(|v| v.to_string()),
+                ),
+                ("tumor_grade", metadata.tumor_grade().map(|v| v.to_string())),
+                (
+                    "specimen_molecular_analyte_type",
+                    metadata
+                        .specimen_molecular_analyte_type()
+                        .map(|v| v.to_string()),
+                ),
+                ("tissue_type", metadata.tissue_type().map(|v| v.to_string())),
+                (
+                    "tumor_classification",
+                    metadata.tumor_classification().map(|v| v.to_string()),
+                ),
+                (
+                    "tumor_tissue_morphology",
+                    metadata.tumor_tissue_morphology().map(|v| v.to_string()),
+                ),
+                (
+                    "age_at_collection",
+                    metadata.age_at_collection().map(|v| v.to_string()),
+                ),
+                (
+                    "tumor_tissue_site",
+                    metadata.tumor_tissue_site().map(|v| v.to_string()),
+                ),
+                (
+                    "identifiers",
+                    metadata.identifiers().map(|v| v[0].to_string()),
+                ),
+            ]
+        };
+
+        // Every harmonized field (and nothing else) is queried.
+        let mut queried = queries(&samples[0])
+            .iter()
+            .map(|(field, _)| field.to_string())
+            .collect::<Vec<_>>();
+        queried.sort();
+        fields.sort();
+        assert_eq!(queried, fields);
+
+        let names = |samples: Vec<&Sample>| {
+            samples
+                .into_iter()
+                .map(|sample| sample.id().name().to_string())
+                .collect::<Vec<_>>()
+        };
+
+        // The samples that match a field of a target sample (computed from the
+        // serialized values rather than by the filter).
+        let matching = |target: &Sample, field: &str| {
+            let value = values(target, field).remove(0);
+
+            samples
+                .iter()
+                .filter(|sample| values(sample, field).contains(&value))
+                .collect::<Vec<_>>()
+        };
+
+        // Each field is filtered individually using the first sample that has
+        // a value for the field.
+        for field in &fields {
+            let (target, query) = samples
+                .iter()
+                .find_map(|sample| {
+                    queries(sample)
+                        .into_iter()
+                        .find(|(name, _)| *name == field.as_str())
+                        .and_then(|(_, query)| query)
+                        .map(|query| (sample, query))
+                })
+                .unwrap_or_else(|| panic!("no sample has a value for `{field}`"));
+
+            let params =
+                serde_json::from_value::<FilterSampleParams>(json!({ field: query })).unwrap();
+            let results = filter::<Sample, FilterSampleParams>(samples.clone(), params);
+
+            assert!(results.contains(target), "{field}");
+            assert_eq!(
+                names(results.iter().collect()),
+                names(matching(target, field)),
+                "{field}"
+            );
+        }
+
+        // Every populated field of the most populated sample is filtered at
+        // once.
+        let target = samples
+            .iter()
+            .max_by_key(|sample| queries(sample).iter().filter(|(_, q)| q.is_some()).count())
+            .unwrap();
+
+        let mut combined = serde_json::Map::new();
+        let mut expected = samples.iter().collect::<Vec<_>>();
+
+        for (field, query) in queries(target) {
+            if let Some(query) = query {
+                let matching = matching(target, field);
+                expected.retain(|sample| matching.contains(sample));
+                combined.insert(field.to_string(), Value::String(query));
+            }
+        }
+
+        let params = serde_json::from_value::<FilterSampleParams>(Value::Object(combined)).unwrap();
+        let results = filter::<Sample, FilterSampleParams>(samples.clone(), params);
+
+        assert!(results.contains(target));
+        assert_eq!(names(results.iter().collect()), names(expected));
+    }
+
+    #[test]
+    fn it_filters_files_by_negated_queries() {
+        let results = filter::<File, FilterFileParams>(
+            files(),
+            FilterFileParams {
+                name: Some(String::from("not:File2.txt")),
+                ..Default::default()
+            },
+        );
+        assert_eq!(results.len(), 2);
+        assert_eq!(results[0].id().name().as_str(), "File1.txt");
+        assert_eq!(results[1].id().name().as_str(), "File3.txt");
+
+        let results = filter::<File, FilterFileParams>(
+            files(),
+            FilterFileParams {
+                size: Some(String::from("not:null")),
+                ..Default::default()
+            },
+        );
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].id().name().as_str(), "File3.txt");
+
+        // Negation combines with the other filters using a logical AND (`&&`).
+        let results = filter::<File, FilterFileParams>(
+            files(),
+            FilterFileParams {
+                name: Some(String::from("not:File1.txt")),
+                has_metadata: Some(false),
+                ..Default::default()
+            },
+        );
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].id().name().as_str(), "File2.txt");
+    }
+
+    #[test]
+    fn it_suggests_keys_for_unrecognized_parameters() {
+        for (known, query, parameters, reason) in [
+            (
+                parameter_names::<FilterSampleParams>(),
+                "anatomic_sites=Chest",
+                vec!["anatomic_sites"],
+                "Unrecognized parameter(s): `anatomic_sites` (did you mean \
+                 `anatomical_sites`?).",
+            ),
+            (
+                parameter_names::<FilterSubjectParams>(),
+                "sex=F&vital_stats=Alive&foo=bar&foo=baz",
+                vec!["vital_stats", "foo"],
+                "Unrecognized parameter(s): `vital_stats` (did you mean `vital_status`?), \
+                 `foo`.",
+            ),
+            (
+                parameter_names::<FilterFileParams>(),
+                "checksum=md5:d41d8cd98f00b204e9800998ecf8427e",
+                vec!["checksum"],
+                "Unrecognized parameter(s): `checksum` (did you mean `checksums`?).",
+            ),
+        ] {
+            let err = check_keys(query, &known).unwrap_err();
+            let value = serde_json::to_value(err).unwrap();
+
+            assert_eq!(
+                value["parameters"],
+                serde_json::json!(parameters),
+                "{query}"
+            );
+            assert_eq!(value["reason"], reason, "{query}");
+        }
+    }
+
+    #[test]
+    fn it_accepts_known_and_unharmonized_parameters() {
+        let known = parameter_names::<FilterSampleParams>();
+
+        assert!(check_keys("", &known).is_ok());
+        assert!(check_keys("anatomical_sites=Chest&diagnosis=foo", &known).is_ok());
+        assert!(check_keys("metadata.unharmonized.anatomical_site=Chest", &known).is_ok());
+        assert!(check_keys("metadata.unharmonized=Chest", &known).is_err());
+    }
 }
@@ -0,0 +1,249 @@
+//! Alternate spellings of filter parameters.
+//!
+//! Array-valued fields are named in the plural (e.g., `anatomical_sites`), but
+//! clients commonly filter on them in the singular (e.g., `anatomical_site`).
+//! Each set of filter parameters declares the alternate spelling accepted for
+//! such fields within an alias table (see [`Aliases`]). Before the parameters
+//! are deserialized, each alias within a query string is resolved to its
+//! canonical name (see [`resolve()`]), and the OpenAPI specification uses the
+//! same table to call out each alias within the documentation of the canonical
+//! parameter.
+
+use actix_web::web::Query;
+use serde::de::DeserializeOwned;
+use url::form_urlencoded;
+
+use crate::responses::error;
+
+/// An alias table that declares the alternate spelling accepted for fields
+/// within a set of filter parameters.
+///
+/// **Note:** each alias must also be declared as a `#[serde(alias)]` of the
+/// field so that the filter parameters can be deserialized directly.
+pub trait Aliases {
+    /// The canonical names of the fields and their respective aliases.
+    const ALIASES: &'static [(&'static str, &'static str)];
+
+    /// Gets the alias of a field (if the field has one).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ccdi_server as server;
+    ///
+    /// use server::filter::alias::Aliases as _;
+    /// use server::params::filter::Sample;
+    ///
+    /// assert_eq!(Sample::alias("anatomical_sites"), Some("anatomical_site"));
+    /// assert_eq!(Sample::alias("diagnosis"), None);
+    /// ```
+    fn alias(field: &str) -> Option<&'static str> {
+        Self::ALIASES
+            .iter()
+            .find(|(name, _)| *name == field)
+            .map(|(_, alias)| *alias)
+    }
+
+    /// Gets the canonical name of an alias (if it is one).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ccdi_server as server;
+    ///
+    /// use server::filter::alias::Aliases as _;
+    /// use server::params::filter::Sample;
+    ///
+    /// assert_eq!(
+    ///     Sample::canonical("anatomical_site"),
+    ///     Some("anatomical_sites")
+    /// );
+    /// assert_eq!(Sample::canonical("anatomical_sites"), None);
+    /// ```
+    fn canonical(alias: &str) -> Option<&'static str> {
+        Self::ALIASES
+            .iter()
+            .find(|(_, name)| *name == alias)
+            .map(|(canonical, _)| *canonical)
+    }
+
+    /// Gets every alias within the alias table.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ccdi_server as server;
+    ///
+    /// use server::filter::alias::Aliases as _;
+    /// use server::params::filter::File;
+    ///
+    /// assert!(File::names().contains(&String::from("checksum")));
+    /// ```
+    fn names() -> Vec<String> {
+        Self::ALIASES
+            .iter()
+            .map(|(_, alias)| alias.to_string())
+            .collect()
+    }
+}
+
+/// Gets the sentence that calls out the alias of a field within its
+/// documentation.
+///
+/// # Examples
+///
+/// ```
+/// use ccdi_server as server;
+///
+/// use server::filter::alias::sentence;
+///
+/// assert!(sentence("anatomical_site").contains("`anatomical_site`"));
+/// ```
+pub fn sentence(alias: &str) -> String {
+    format!(
+        "This parameter may also be provided as `{alias}`. Providing both spellings with \
+         different values is an error."
+    )
+}
+
+/// Resolves each alias within a raw `query` string to its canonical name.
+///
+/// When both the canonical name and the alias of a field are provided with the
+/// same value, the value is only included once. When they are provided with
+/// different values, an [`InvalidParameters`](error::Kind::invalid_parameters)
+/// error is returned rather than silently choosing one of the values. All
+/// other parameters are left as they are.
+///
+/// # Examples
+///
+/// ```
+/// use ccdi_server as server;
+///
+/// use server::filter::alias::resolve;
+/// use server::params::filter::Sample;
+///
+/// assert_eq!(
+///     resolve::<Sample>("anatomical_site=Chest&page=2").unwrap(),
+///     "anatomical_sites=Chest&page=2"
+/// );
+/// assert_eq!(
+///     resolve::<Sample>("anatomical_site=Chest&anatomical_sites=Chest").unwrap(),
+///     "anatomical_sites=Chest"
+/// );
+/// assert!(resolve::<Sample>("anatomical_site=Chest&anatomical_sites=Lung").is_err());
+/// ```
+pub fn resolve<P>(query: &str) -> Result<String, error::Kind>
+where
+    P: Aliases,
+{
+    let mut pairs: Vec<(String, String, String)> = Vec::new();
+
+    for (key, value) in form_urlencoded::parse(query.as_bytes()) {
+        let name = P::canonical(&key)
+            .map(String::from)
+            .unwrap_or_else(|| key.to_string());
+
+        let existing = pairs
+            .iter()
+            .find(|(canonical, spelling, _)| *canonical == name && *spelling != key);
+
+        if let Some((_, spelling, existing)) = existing {
+            if *existing != value {
+                return Err(error::Kind::invalid_parameters(
+                    Some(vec![spelling.clone(), key.to_string()]),
+                    format!(
+                        "`{spelling}` and `{key}` are the same parameter but were provided \
+                         with different values"
+                    ),
+                ));
+            }
+
+            continue;
+        }
+
+        pairs.push((name, key.to_string(), value.to_string()));
+    }
+
+    Ok(form_urlencoded::Serializer::new(String::new())
+        .extend_pairs(pairs.iter().map(|(name, _, value)| (name, value)))
+        .finish())
+}
+
+/// Deserializes a set of filter parameters from a raw `query` string after
+/// [resolving](resolve()) the aliases within it.
+///
+/// # Examples
+///
+/// ```
+/// use ccdi_server as server;
+///
+/// use server::filter::alias::deserialize;
+/// use server::params::filter::Sample;
+///
+/// let params = deserialize::<Sample>("anatomical_site=Chest").unwrap();
+/// assert_eq!(params.anatomical_sites, Some(String::from("Chest")));
+/// ```
+pub fn deserialize<P>(query: &str) -> Result<P, error::Kind>
+where
+    P: Aliases + DeserializeOwned,
+{
+    let query = resolve::<P>(query)?;
+
+    Query::<P>::from_query(&query)
+        .map(Query::into_inner)
+        .map_err(|err| error::Kind::invalid_parameters(None, err.to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::params::filter::File as FilterFileParams;
+    use crate::params::filter::Sample as FilterSampleParams;
+    use crate::params::filter::Subject as FilterSubjectParams;
+
+    use super::*;
+
+    fn assert_deserializes<P>()
+    where
+        P: Aliases + DeserializeOwned + serde::Serialize,
+    {
+        for (name, alias) in P::ALIASES {
+            let params = Query::<P>::from_query(&format!("{alias}=value")).unwrap();
+            let params = serde_json::to_value(params.0).unwrap();
+            assert_eq!(params[*name], "value", "{alias}");
+        }
+    }
+
+    #[test]
+    fn it_deserializes_every_alias() {
+        assert_deserializes::<FilterSubjectParams>();
+        assert_deserializes::<FilterSampleParams>();
+        assert_deserializes::<FilterFileParams>();
+    }
+
+    #[test]
+    fn it_rejects_conflicting_values() {
+        let err = resolve::<FilterFileParams>(
+            "checksums=md5:d41d8cd98f00b204e9800998ecf8427e&\
+             checksum=md5:d41d8cd98f00b204e9800998ecf8427f",
+        )
+        .unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            "Invalid value for parameters 'checksums' and 'checksum': `checksums` and \
+             `checksum` are the same parameter but were provided with different values."
+        );
+    }
+
+    #[test]
+    fn it_leaves_other_parameters_as_they_are() {
+        assert_eq!(
+            resolve::<FilterSubjectParams>("race=Asian&race=White&sex=F").unwrap(),
+            "race=Asian&race=White&sex=F"
+        );
+        assert_eq!(
+            resolve::<FilterSubjectParams>("identifier=foo%20bar").unwrap(),
+            "identifiers=foo+bar"
+        );
+    }
+}
@@ -0,0 +1,562 @@
+//! Boolean composition of filters.
+//!
+//! Filters provided within a query string are always combined with a logical
+//! AND (`&&`). A filter expression instead describes an explicit boolean
+//! structure within a JSON body, where each group combines its members with
+//! either a logical OR (`{"any":[...]}`) or a logical AND (`{"all":[...]}`)
+//! and each leaf is a set of filters keyed exactly as within a query string
+//! (e.g., `{"sex":"F","has_metadata":true}`).
+//!
+//! Each leaf is converted to the equivalent query string and then parsed,
+//! validated, and matched with the very same functions as the query string of
+//! the list endpoints, so a single leaf always matches exactly the same
+//! entities as the equivalent `GET` request.
+
+use std::collections::BTreeSet;
+
+use introspect::Introspected;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use serde_json::Map;
+use serde_json::Value;
+use url::form_urlencoded;
+use utoipa::IntoParams;
+
+use ccdi_models as models;
+
+use models::Entity;
+
+use crate::filter::alias::deserialize;
+use crate::filter::alias::Aliases;
+use crate::filter::check_keys;
+use crate::filter::filter_with_case;
+use crate::filter::parameter_names;
+use crate::filter::rule::Case;
+use crate::filter::rule::Rules;
+use crate::filter::unharmonized;
+use crate::filter::unharmonized::UnharmonizedQuery;
+use crate::filter::validate;
+use crate::filter::FieldValues;
+use crate::filter::FilterMetadataField;
+use crate::responses::error;
+
+/// The maximum number of groups that may be nested within one another
+/// (including the outermost group).
+pub const MAX_DEPTH: usize = 4;
+
+/// The key of a group that matches when _any_ of its members match.
+const ANY: &str = "any";
+
+/// The key of a group that matches when _all_ of its members match.
+const ALL: &str = "all";
+
+/// A leaf of an [`Expression`].
+#[derive(Debug)]
+pub struct Leaf<P> {
+    /// The filter parameters.
+    params: P,
+
+    /// The queries for unharmonized fields.
+    unharmonized: Vec<UnharmonizedQuery>,
+}
+
+/// A boolean composition of filters.
+#[derive(Debug)]
+pub enum Expression<P> {
+    /// Matches an entity when _any_ of the expressions match (a logical OR
+    /// (`||`)).
+    Any(Vec<Expression<P>>),
+
+    /// Matches an entity when _all_ of the expressions match (a logical AND
+    /// (`&&`)).
+    All(Vec<Expression<P>>),
+
+    /// Matches an entity when all of the filters match (exactly as when the
+    /// filters are provided within a query string).
+    Leaf(Leaf<P>),
+}
+
+impl<P> Expression<P>
+where
+    P: Aliases + DeserializeOwned + IntoParams + Rules + Serialize,
+{
+    /// Parses an [`Expression`] from a JSON `value`.
+    ///
+    /// An [`InvalidParameters`](error::Kind::invalid_parameters) error is
+    /// returned if a group is empty, if groups are nested more than
+    /// [`MAX_DEPTH`] levels deep, or if any leaf would be rejected were it
+    /// provided as a query string.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ccdi_server as server;
+    ///
+    /// use server::filter::expression::Expression;
+    /// use server::params::filter::Subject as SubjectFilterParams;
+    ///
+    /// let value = serde_json::json!({
+    ///     "any": [
+    ///         { "sex": "F" },
+    ///         { "all": [{ "vital_status": "Dead" }, { "has_metadata": true }] }
+    ///     ]
+    /// });
+    ///
+    /// assert!(Expression::<SubjectFilterParams>::parse(&value).is_ok());
+    ///
+    /// let err = Expression::<SubjectFilterParams>::parse(&serde_json::json!({ "any": [] }))
+    ///     .unwrap_err();
+    /// assert_eq!(
+    ///     err.to_string(),
+    ///     " Invalid parameters: the `any` group at `/` must contain at least one \
+    ///      expression."
+    /// );
+    /// ```
+    pub fn parse(value: &Value) -> Result<Self, error::Kind> {
+        let known = [parameter_names::<P>(), P::names()].concat();
+        parse(value, "", 0, &known)
+    }
+}
+
+/// Parses the [`Expression`] at the JSON `pointer` within `depth` enclosing
+/// groups.
+fn parse<P>(
+    value: &Value,
+    pointer: &str,
+    depth: usize,
+    known: &[String],
+) -> Result<Expression<P>, error::Kind>
+where
+    P: Aliases + DeserializeOwned + Rules + Serialize,
+{
+    let location = match pointer.is_empty() {
+        true => "/",
+        false => pointer,
+    };
+
+    let object = match value {
+        Value::Object(object) => object,
+        _ => {
+            return Err(error::Kind::invalid_parameters(
+                None,
+                format!("The expression at `{location}` must be a JSON object."),
+            ))
+        }
+    };
+
+    let key = match (object.get(ANY), object.get(ALL)) {
+        (Some(_), None) => ANY,
+        (None, Some(_)) => ALL,
+        (None, None) => return leaf(object, location, known).map(Expression::Leaf),
+        (Some(_), Some(_)) => {
+            return Err(error::Kind::invalid_parameters(
+                None,
+                format!(
+                    "The group at `{location}` must contain either the `{ANY}` or the \
+                     `{ALL}` key, but not both."
+                ),
+            ))
+        }
+    };
+
+    if object.len() > 1 {
+        return Err(error::Kind::invalid_parameters(
+            None,
+            format!("The `{key}` group at `{location}` must not contain any other keys."),
+        ));
+    }
+
+    let depth = depth + 1;
+
+    if depth > MAX_DEPTH {
+        return Err(error::Kind::invalid_parameters(
+            None,
+            format!(
+                "The `{key}` group at `{location}` is nested more than {MAX_DEPTH} groups \
+                 deep."
+            ),
+        ));
+    }
+
+    let members = match &object[key] {
+        Value::Array(members) => members,
+        _ => {
+            return Err(error::Kind::invalid_parameters(
+                None,
+                format!("The `{key}` group at `{location}` must be a JSON array."),
+            ))
+        }
+    };
+
+    if members.is_empty() {
+        return Err(error::Kind::invalid_parameters(
+            None,
+            format!("The `{key}` group at `{location}` must contain at least one expression."),
+        ));
+    }
+
+    let members = members
+        .iter()
+        .enumerate()
+        .map(|(i, member)| parse(member, &format!("{pointer}/{key}/{i}"), depth, known))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    Ok(match key {
+        ANY => Expression::Any(members),
+        _ => Expression::All(members),
+    })
+}
+
+/// Parses the [`Leaf`] at the JSON pointer `location`.
+///
+/// The leaf is converted to the equivalent query string, which is then parsed
+/// and validated exactly as the query string of a list endpoint.
+fn leaf<P>(
+    object: &Map<String, Value>,
+    location: &str,
+    known: &[String],
+) -> Result<Leaf<P>, error::Kind>
+where
+    P: Aliases + DeserializeOwned + Rules + Serialize,
+{
+    let mut query = form_urlencoded::Serializer::new(String::new());
+
+    for (key, value) in object {
+        let value = match value {
+            Value::String(value) => value.clone(),
+            Value::Bool(_) | Value::Number(_) | Value::Array(_) | Value::Object(_) => {
+                value.to_string()
+            }
+            Value::Null => {
+                return Err(error::Kind::invalid_parameters(
+                    Some(vec![key.clone()]),
+                    format!(
+                        "The filter at `{location}` must not be `null` (use `not:null` to \
+                         match entities with a value)."
+                    ),
+                ))
+            }
+        };
+
+        query.append_pair(key, &value);
+    }
+
+    let query = query.finish();
+
+    check_keys(&query, known)?;
+
+    let params = deserialize::<P>(&query)?;
+    validate(&params)?;
+
+    Ok(Leaf {
+        params,
+        unharmonized: unharmonized::queries(&query)?,
+    })
+}
+
+/// Filters a list of entities based on the provided [`Expression`] with regard
+/// to the provided [`Case`].
+///
+/// The order of the entities is preserved.
+///
+/// # Examples
+///
+/// ```
+/// use ccdi_cde as cde;
+/// use ccdi_models as models;
+/// use ccdi_server as server;
+///
+/// use models::metadata::field::unowned::subject::Sex;
+/// use models::metadata::field::unowned::subject::VitalStatus;
+/// use models::subject::metadata::Builder;
+/// use models::subject::Kind;
+/// use models::Subject;
+/// use server::filter::expression::evaluate;
+/// use server::filter::expression::Expression;
+/// use server::filter::rule::Case;
+/// use server::params::filter::Subject as SubjectFilterParams;
+///
+/// let namespace = models::namespace::Identifier::new(
+///     "example-organization"
+///         .parse::<models::organization::Identifier>()
+///         .unwrap(),
+///     "ExampleNamespace"
+///         .parse::<models::namespace::identifier::Name>()
+///         .unwrap(),
+/// );
+///
+/// let subject = |name: &str, sex, vital_status| {
+///     Subject::new(
+///         models::subject::Identifier::new(namespace.clone(), name),
+///         Kind::Participant,
+///         None,
+///         Some(
+///             Builder::default()
+///                 .sex(Sex::new(sex, None, None, None))
+///                 .vital_status(VitalStatus::new(vital_status, None, None, None))
+///                 .build(),
+///         ),
+///     )
+/// };
+///
+/// let subjects = vec![
+///     subject(
+///         "Subject1",
+///         cde::v1::subject::Sex::Female,
+///         cde::v1::subject::VitalStatus::Alive,
+///     ),
+///     subject(
+///         "Subject2",
+///         cde::v1::subject::Sex::Male,
+///         cde::v1::subject::VitalStatus::Dead,
+///     ),
+///     subject(
+///         "Subject3",
+///         cde::v1::subject::Sex::Male,
+///         cde::v1::subject::VitalStatus::Alive,
+///     ),
+/// ];
+///
+/// let expression = Expression::<SubjectFilterParams>::parse(&serde_json::json!({
+///     "any": [{ "sex": "F" }, { "vital_status": "Dead" }]
+/// }))
+/// .unwrap();
+///
+/// let results = evaluate(subjects, expression, Case::Sensitive);
+/// assert_eq!(results.len(), 2);
+/// assert_eq!(results[0].id().name().as_str(), "Subject1");
+/// assert_eq!(results[1].id().name().as_str(), "Subject2");
+/// ```
+pub fn evaluate<T, P>(entities: Vec<T>, expression: Expression<P>, case: Case) -> Vec<T>
+where
+    T: Clone + Entity + FieldValues + Ord + Serialize,
+    Vec<T>: FilterMetadataField<T, P>,
+    P: DeserializeOwned + Introspected + Rules + Serialize,
+{
+    match expression {
+        Expression::Any(members) => {
+            let matched = members
+                .into_iter()
+                .flat_map(|member| evaluate(entities.clone(), member, case))
+                .collect::<BTreeSet<_>>();
+
+            entities
+                .into_iter()
+                .filter(|entity| matched.contains(entity))
+                .collect()
+        }
+        Expression::All(members) => members.into_iter().fold(entities, |entities, member| {
+            evaluate(entities, member, case)
+        }),
+        Expression::Leaf(leaf) => {
+            let entities = filter_with_case(entities, leaf.params, case);
+            unharmonized::filter(entities, &leaf.unharmonized, case)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ccdi_cde as cde;
+
+    use ccdi_models::metadata::field::unowned::subject::Sex;
+    use ccdi_models::metadata::field::unowned::subject::VitalStatus;
+    use ccdi_models::subject::metadata::Builder;
+    use ccdi_models::subject::Kind;
+    use ccdi_models::Subject;
+
+    use crate::params::filter::Subject as FilterSubjectParams;
+
+    use super::*;
+
+    fn subjects() -> Vec<Subject> {
+        let namespace = models::namespace::Identifier::new(
+            "example-organization"
+                .parse::<models::organization::Identifier>()
+                .unwrap(),
+            "ExampleNamespace"
+                .parse::<models::namespace::identifier::Name>()
+                .unwrap(),
+        );
+
+        let subject = |name: &str, sex, vital_status| {
+            Subject::new(
+                models::subject::Identifier::new(namespace.clone(), name),
+                Kind::Participant,
+                None,
+                Some(
+                    Builder::default()
+                        .sex(Sex::new(sex, None, None, None))
+                        .vital_status(VitalStatus::new(vital_status, None, None, None))
+                        .build(),
+                ),
+            )
+        };
+
+        vec![
+            subject(
+                "Subject1",
+                cde::v1::subject::Sex::Female,
+                cde::v1::subject::VitalStatus::Alive,
+            ),
+            subject(
+                "Subject2",
+                cde::v1::subject::Sex::Female,
+                cde::v1::subject::VitalStatus::Dead,
+            ),
+            subject(
+                "Subject3",
+                cde::v1::subject::Sex::Male,
+                cde::v1::subject::VitalStatus::Dead,
+            ),
+            subject(
+                "Subject4",
+                cde::v1::subject::Sex::Male,
+                cde::v1::subject::VitalStatus::Alive,
+            ),
+        ]
+    }
+
+    fn names(value: Value) -> Vec<String> {
+        let expression = Expression::<FilterSubjectParams>::parse(&value).unwrap();
+
+        evaluate(subjects(), expression, Case::Sensitive)
+            .into_iter()
+            .map(|subject| subject.id().name().to_string())
+            .collect()
+    }
+
+    fn reason(value: Value) -> String {
+        let err = Expression::<FilterSubjectParams>::parse(&value).unwrap_err();
+        serde_json::to_value(err).unwrap()["reason"]
+            .as_str()
+            .unwrap()
+            .to_string()
+    }
+
+    #[test]
+    fn it_evaluates_nested_groups() {
+        assert_eq!(
+            names(serde_json::json!({ "any": [{ "sex": "F" }, { "vital_status": "Dead" }] })),
+            vec!["Subject1", "Subject2", "Subject3"]
+        );
+
+        assert_eq!(
+            names(serde_json::json!({ "all": [{ "sex": "F" }, { "vital_status": "Dead" }] })),
+            vec!["Subject2"]
+        );
+
+        assert_eq!(
+            names(serde_json::json!({
+                "any": [
+                    { "all": [{ "sex": "F" }, { "vital_status": "Alive" }] },
+                    { "all": [{ "sex": "M" }, { "vital_status": "Dead" }] }
+                ]
+            })),
+            vec!["Subject1", "Subject3"]
+        );
+
+        assert_eq!(
+            names(serde_json::json!({
+                "all": [
+                    { "any": [{ "sex": "F" }, { "sex": "M" }] },
+                    { "any": [{ "vital_status": "not:Dead" }] }
+                ]
+            })),
+            vec!["Subject1", "Subject4"]
+        );
+    }
+
+    #[test]
+    fn it_matches_a_leaf_exactly_as_a_query_string() {
+        for (leaf, query) in [
+            (serde_json::json!({}), ""),
+            (serde_json::json!({ "sex": "F" }), "sex=F"),
+            (serde_json::json!({ "sex": "not:F" }), "sex=not:F"),
+            (serde_json::json!({ "sex": ["F", "M"] }), r#"sex=["F","M"]"#),
+            (
+                serde_json::json!({ "sex": "M", "vital_status": "Alive" }),
+                "sex=M&vital_status=Alive",
+            ),
+        ] {
+            let params = deserialize::<FilterSubjectParams>(query).unwrap();
+            let expected = filter_with_case(subjects(), params, Case::Sensitive)
+                .into_iter()
+                .map(|subject| subject.id().name().to_string())
+                .collect::<Vec<_>>();
+
+            assert_eq!(names(leaf), expected, "{query}");
+        }
+    }
+
+    #[test]
+    fn it_rejects_empty_groups() {
+        assert_eq!(
+            reason(serde_json::json!({ "any": [] })),
+            "The `any` group at `/` must contain at least one expression."
+        );
+
+        assert_eq!(
+            reason(serde_json::json!({ "any": [{ "all": [] }] })),
+            "The `all` group at `/any/0` must contain at least one expression."
+        );
+    }
+
+    #[test]
+    fn it_rejects_groups_nested_too_deeply() {
+        let mut value = serde_json::json!({ "sex": "F" });
+
+        for _ in 0..MAX_DEPTH {
+            value = serde_json::json!({ "any": [value] });
+        }
+
+        assert!(Expression::<FilterSubjectParams>::parse(&value).is_ok());
+
+        let value = serde_json::json!({ "all": [value] });
+        assert_eq!(
+            reason(value),
+            format!(
+                "The `any` group at `/all/0/any/0/any/0/any/0` is nested more than \
+                 {MAX_DEPTH} groups deep."
+            )
+        );
+    }
+
+    #[test]
+    fn it_rejects_invalid_expressions() {
+        assert_eq!(
+            reason(serde_json::json!({ "any": [{ "sex": "F" }], "all": [{ "sex": "M" }] })),
+            "The group at `/` must contain either the `any` or the `all` key, but not both."
+        );
+
+        assert_eq!(
+            reason(serde_json::json!({ "any": [{ "sex": "F" }], "sex": "M" })),
+            "The `any` group at `/` must not contain any other keys."
+        );
+
+        assert_eq!(
+            reason(serde_json::json!({ "any": { "sex": "F" } })),
+            "The `any` group at `/` must be a JSON array."
+        );
+
+        assert_eq!(
+            reason(serde_json::json!({ "any": ["F"] })),
+            "The expression at `/any/0` must be a JSON object."
+        );
+
+        assert_eq!(
+            reason(serde_json::json!({ "any": [{ "sexx": "F" }] })),
+            "Unrecognized parameter(s): `sexx` (did you mean `sex`?)."
+        );
+
+        // NOTE: ranges may be provided either as a JSON object or as a string
+        // (exactly as within a query string), and both are validated.
+        for range in [
+            serde_json::json!({ "gte": 10, "lte": 1 }),
+            serde_json::json!(r#"{"gte":10,"lte":1}"#),
+        ] {
+            let value = serde_json::json!({ "age_at_vital_status": range });
+            assert!(Expression::<FilterSubjectParams>::parse(&value).is_err());
+        }
+    }
+}
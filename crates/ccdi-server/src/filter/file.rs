@@ -2,23 +2,83 @@
 
 use ccdi_models as models;
 
+use models::gateway::AnonymousOrReference;
 use models::metadata::common::deposition::Accession;
 use models::File;
 
+use crate::filter::alias::Aliases;
+use crate::filter::rule::Case;
+use crate::filter::rule::Rules;
+use crate::filter::rule::Strategy;
+use crate::filter::scope;
+use crate::filter::FieldValues;
 use crate::filter::FilterMetadataField;
 use crate::params::filter::File as FilterFileParams;
+use crate::responses::file::named_gateway;
+
+impl Aliases for FilterFileParams {
+    const ALIASES: &'static [(&'static str, &'static str)] =
+        &[("checksums", "checksum"), ("depositions", "deposition")];
+}
+
+impl Rules for FilterFileParams {
+    const RULES: &'static [(&'static str, Strategy)] = &[
+        ("name", Strategy::Exact),
+        ("type", Strategy::Exact),
+        ("size", Strategy::Exact),
+        ("checksums", Strategy::Checksums),
+        ("description", Strategy::Substring),
+        ("drs_uri", Strategy::Exact),
+        ("access_url", Strategy::Exact),
+        ("depositions", Strategy::AnyExact),
+        ("access", Strategy::AnyExactIgnoreCase),
+        ("has_metadata", Strategy::Boolean),
+        ("namespace", Strategy::Exact),
+        ("organization", Strategy::Exact),
+    ];
+
+    const PROVENANCE: &'static [&'static str] = &[
+        "type",
+        "size",
+        "checksums",
+        "description",
+        "drs_uri",
+        "access_url",
+    ];
+}
 
 impl FilterMetadataField<File, FilterFileParams> for Vec<File> {
-    fn filter_metadata_field(self, field: String, params: &FilterFileParams) -> Vec<File> {
+    fn filter_metadata_field(
+        self,
+        field: String,
+        params: &FilterFileParams,
+        case: Case,
+    ) -> Vec<File> {
+        // Boolean parameters are matched against the string representation of
+        // the value (`true` or `false`).
+        let has_metadata = params
+            .has_metadata
+            .map(|has_metadata| has_metadata.to_string());
+
         let parameter = match field.as_str() {
+            "name" => params.name.as_ref(),
             "type" => params.r#type.as_ref(),
             "size" => params.size.as_ref(),
             "checksums" => params.checksums.as_ref(),
             "description" => params.description.as_ref(),
+            "drs_uri" => params.drs_uri.as_ref(),
+            "access_url" => params.access_url.as_ref(),
             "depositions" => params.depositions.as_ref(),
+            "access" => params.access.as_ref(),
+            "has_metadata" => has_metadata.as_ref(),
+            "namespace" => params.namespace.as_ref(),
+            "organization" => params.organization.as_ref(),
             _ => unreachable!("unhandled file metadata field: {field}"),
         };
 
+        let strategy = FilterFileParams::strategy(&field)
+            .unwrap_or_else(|| unreachable!("unhandled file filter rule: {field}"));
+
         let query = match parameter {
             Some(query) => query,
             // If the parameter has no value, just return the original list of
@@ -28,67 +88,82 @@ impl FilterMetadataField<File, FilterFileParams> for Vec<File> {
 
         self.into_iter()
             .filter(|file| {
-                if field.as_str() == "description" {
-                    if let Some(metadata) = file.metadata() {
-                        if let Some(description) = metadata.description() {
-                            // Only return the entry if the query is a substring
-                            // of the description.
-                            return description.to_string().contains(query);
-                        }
-
-                        // If the metadata doesn't have a description, the entry
-                        // should not be included.
-                        return false;
-                    }
-
-                    // If no metadata is included, this entry should not be
-                    // included.
-                    false
-                } else {
-                    // All other "non-description" fields.
-                    let values: Option<Vec<String>> = match field.as_str() {
-                        "type" => file
-                            .metadata()
-                            .and_then(|metadata| metadata.r#type())
-                            .map(|r#type| vec![r#type.to_string()]),
-                        "size" => file
-                            .metadata()
-                            .and_then(|metadata| metadata.size())
-                            .map(|size| vec![size.to_string()]),
-                        "checksums" => file
-                            .metadata()
-                            .and_then(|metadata| metadata.checksums())
-                            .map(|checksums| {
-                                checksums
-                                    .value()
-                                    .as_map()
-                                    .into_values()
-                                    .map(|r| r.to_string())
-                                    .collect::<Vec<String>>()
-                            }),
-                        "depositions" => file
-                            .metadata()
-                            .and_then(|metadata| metadata.common().depositions())
-                            .map(|deposition| {
-                                deposition
-                                    .iter()
-                                    .cloned()
-                                    .map(|accession| match accession {
-                                        Accession::dbGaP(accession) => accession.to_string(),
-                                    })
-                                    .collect::<Vec<String>>()
-                            }),
-                        _ => unreachable!("unhandled file metadata field: {field}"),
-                    };
-
-                    match values {
-                        Some(values) => values.into_iter().any(|s| s.eq(query)),
-                        // Files with no values for this field are automatically
-                        // filtered as described in the rules for filtering.
-                        None => false,
-                    }
-                }
+                // Files with no values for this field are handled as
+                // described in the rules for filtering (including negation).
+                strategy.evaluate_with_case(file.field_values(&field).as_deref(), query, case)
             })
             .collect::<Vec<_>>()
     }
 }
+
+impl FieldValues for File {
+    fn field_values(&self, field: &str) -> Option<Vec<String>> {
+        match field {
+            "name" => Some(vec![self.id().name().to_string()]),
+            "type" => self
+                .metadata()
+                .and_then(|metadata| metadata.r#type())
+                .map(|r#type| vec![r#type.to_string()]),
+            "size" => self
+                .metadata()
+                .and_then(|metadata| metadata.size())
+                .map(|size| vec![size.to_string()]),
+            // Each checksum can be matched by its digest alone or in
+            // the `algorithm:value` form (the form in which each digest
+            // of a JSON object query is matched).
+            "checksums" => self
+                .metadata()
+                .and_then(|metadata| metadata.checksums())
+                .map(|checksums| {
+                    checksums
+                        .value()
+                        .checksums()
+                        .into_iter()
+                        .flat_map(|checksum| [checksum.value().to_string(), checksum.to_string()])
+                        .collect::<Vec<String>>()
+                }),
+            "description" => self
+                .metadata()
+                .and_then(|metadata| metadata.description())
+                .map(|description| vec![description.to_string()]),
+            "drs_uri" => self
+                .metadata()
+                .and_then(|metadata| metadata.drs_uri())
+                .map(|drs_uri| vec![drs_uri.to_string()]),
+            "access_url" => self
+                .metadata()
+                .and_then(|metadata| metadata.access_url())
+                .map(|access_url| vec![access_url.to_string()]),
+            "depositions" => self
+                .metadata()
+                .and_then(|metadata| metadata.common().depositions())
+                .map(|deposition| {
+                    deposition
+                        .iter()
+                        .cloned()
+                        .map(|accession| match accession {
+                            Accession::dbGaP(accession) => accession.to_string(),
+                        })
+                        .collect::<Vec<String>>()
+                }),
+            // Gateways that are referred to by name are matched by
+            // the kind of the gateway that the name resolves to.
+            "access" => self.gateways().map(|gateways| {
+                gateways
+                    .iter()
+                    .map(|gateway| match gateway {
+                        AnonymousOrReference::Anonymous { gateway } => gateway.kind(),
+                        AnonymousOrReference::Reference { gateway } => {
+                            named_gateway(gateway.clone()).gateway().kind()
+                        }
+                    })
+                    .map(String::from)
+                    .collect::<Vec<String>>()
+            }),
+            "has_metadata" => Some(vec![self.has_metadata().to_string()]),
+            "namespace" => Some(vec![scope::namespace(self.id().namespace())]),
+            "organization" => Some(vec![scope::organization(self.id().namespace())]),
+            _ => unreachable!("unhandled file metadata field: {field}"),
+        }
+    }
+}
@@ -0,0 +1,544 @@
+//! Filtering entities by the provenance of their harmonized fields.
+//!
+//! Every harmonized metadata field may describe the provenance of its value:
+//! the `source` from which the value was drawn and the `method` by which it was
+//! harmonized (both within the `details` object of the field) and a free-text
+//! `comment`. A filter for such a field may constrain the provenance of the
+//! field alongside its value by providing a JSON object with a `value` key and
+//! any of the `source`, `method`, and `comment` keys instead of a bare value
+//! (e.g., `?diagnosis={"value":"Neuroblastoma","source":"pathology report"}`).
+//! The constraints are combined with the match on the value with a logical AND
+//! (`&&`).
+//!
+//! The `value` is matched exactly as a bare value would be (according to the
+//! [`Strategy`] of the field), and it may be omitted to match any value with
+//! the provenance provided. The `source` and the `method` must match exactly,
+//! and the `comment` must contain the string provided. Each constraint is
+//! case-sensitive (unless case-insensitive matching is requested) and may be
+//! negated with `not:`. For fields with multiple values, at least one of the
+//! values must satisfy every constraint (including the `value` itself), and
+//! entities without a value for the field never satisfy the constraints.
+//!
+//! Only the fields declared within the [`PROVENANCE`](Rules::PROVENANCE) table
+//! of a set of filter parameters accept these objects.
+
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use serde_json::Map;
+use serde_json::Value;
+
+use crate::filter::rule::Case;
+use crate::filter::rule::Rules;
+use crate::filter::rule::Strategy;
+use crate::filter::rule::ValidationError;
+use crate::filter::FieldValues;
+
+/// The key of the value within a provenance query.
+const VALUE: &str = "value";
+
+/// The keys that constrain the provenance of a field along with the match
+/// [`Strategy`] for each.
+pub const KEYS: &[(&str, Strategy)] = &[
+    ("source", Strategy::Exact),
+    ("method", Strategy::Exact),
+    ("comment", Strategy::Substring),
+];
+
+/// The sentence that describes provenance queries within the documentation of
+/// a filter parameter.
+pub const SENTENCE: &str = "Provide a JSON object with a `value` key and any of the `source`, \
+                            `method`, and `comment` keys (e.g., \
+                            `{\"value\":\"A\",\"source\":\"pathology report\"}`) to also \
+                            constrain the provenance of the field (described within its \
+                            `details`). The `source` and `method` must match exactly, the \
+                            `comment` must contain the string provided, and the `value` may be \
+                            omitted to match any value.";
+
+/// An error related to a provenance query.
+#[derive(Debug)]
+pub enum Error {
+    /// A key that is neither `value` nor a provenance key was provided.
+    UnknownKey(String),
+
+    /// A provenance constraint was not a string.
+    NonString {
+        /// The key of the constraint.
+        key: String,
+
+        /// The value that was provided.
+        value: String,
+    },
+
+    /// The value or a provenance constraint was invalid.
+    Invalid {
+        /// The key of the value or of the constraint.
+        key: &'static str,
+
+        /// The reason the value or the constraint was invalid.
+        err: ValidationError,
+    },
+}
+
+impl std::fmt::Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::UnknownKey(key) => write!(
+                f,
+                "unrecognized key `{key}` (expected `value`, `source`, `method`, or `comment`)"
+            ),
+            Error::NonString { key, value } => write!(
+                f,
+                "the `{key}` constraint must be a string, but `{value}` was provided"
+            ),
+            Error::Invalid { key, err } => write!(f, "invalid `{key}`: {err}"),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+/// A query that constrains the provenance of a field (and, optionally, its
+/// value).
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct ProvenanceQuery {
+    /// The query for the value of the field (if any).
+    value: Option<String>,
+
+    /// The queries for the provenance of the field keyed by provenance key.
+    constraints: Vec<(&'static str, String)>,
+}
+
+impl ProvenanceQuery {
+    /// Attempts to parse a [`ProvenanceQuery`] from the raw value of a filter
+    /// parameter.
+    ///
+    /// Only a JSON object with at least one of the provenance keys (see
+    /// [`KEYS`]) is a provenance query: for any other value, `None` is
+    /// returned (and the value is matched as usual).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ccdi_server as server;
+    ///
+    /// use server::filter::provenance::ProvenanceQuery;
+    ///
+    /// let query = ProvenanceQuery::parse(r#"{"value":"A","source":"pathology report"}"#)
+    ///     .unwrap()
+    ///     .unwrap();
+    /// assert_eq!(query.value(), Some("A"));
+    ///
+    /// assert!(ProvenanceQuery::parse("A").unwrap().is_none());
+    /// assert!(ProvenanceQuery::parse(r#"{"gte":0}"#).unwrap().is_none());
+    ///
+    /// // NOTE: without any provenance keys, the object is matched as usual.
+    /// let query = ProvenanceQuery::parse(r#"{"value":"A","sorce":"pathology report"}"#);
+    /// assert!(query.unwrap().is_none());
+    ///
+    /// let err = ProvenanceQuery::parse(r#"{"value":"A","source":1}"#).unwrap_err();
+    /// assert_eq!(
+    ///     err.to_string(),
+    ///     "the `source` constraint must be a string, but `1` was provided"
+    /// );
+    /// ```
+    pub fn parse(query: &str) -> Result<Option<Self>, Error> {
+        if !query.trim_start().starts_with('{') {
+            return Ok(None);
+        }
+
+        let object = match serde_json::from_str::<Map<String, Value>>(query) {
+            Ok(object) => object,
+            Err(_) => return Ok(None),
+        };
+
+        if !KEYS.iter().any(|(key, _)| object.contains_key(*key)) {
+            return Ok(None);
+        }
+
+        let mut result = Self::default();
+
+        for (key, value) in object {
+            if key == VALUE {
+                result.value = match value {
+                    Value::Null => None,
+                    Value::String(value) => Some(value),
+                    value => Some(value.to_string()),
+                };
+
+                continue;
+            }
+
+            let key = match KEYS.iter().find(|(name, _)| *name == key) {
+                Some((name, _)) => *name,
+                None => return Err(Error::UnknownKey(key)),
+            };
+
+            match value {
+                Value::String(value) => result.constraints.push((key, value)),
+                value => {
+                    return Err(Error::NonString {
+                        key: key.to_string(),
+                        value: value.to_string(),
+                    })
+                }
+            }
+        }
+
+        Ok(Some(result))
+    }
+
+    /// Gets the query for the value of the field from the [`ProvenanceQuery`]
+    /// (if any).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ccdi_server as server;
+    ///
+    /// use server::filter::provenance::ProvenanceQuery;
+    ///
+    /// let query = ProvenanceQuery::parse(r#"{"source":"pathology report"}"#)
+    ///     .unwrap()
+    ///     .unwrap();
+    /// assert_eq!(query.value(), None);
+    /// ```
+    pub fn value(&self) -> Option<&str> {
+        self.value.as_deref()
+    }
+
+    /// Validates the [`ProvenanceQuery`] for a field matched with the provided
+    /// [`Strategy`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ccdi_server as server;
+    ///
+    /// use server::filter::provenance::ProvenanceQuery;
+    /// use server::filter::rule::Strategy;
+    ///
+    /// let query = ProvenanceQuery::parse(r#"{"value":{"gte":1,"lte":0},"source":"A"}"#)
+    ///     .unwrap()
+    ///     .unwrap();
+    ///
+    /// assert!(query.validate(Strategy::Exact).is_ok());
+    /// assert!(query.validate(Strategy::Range).is_err());
+    /// ```
+    pub fn validate(&self, strategy: Strategy) -> Result<(), Error> {
+        if let Some(value) = &self.value {
+            strategy
+                .validate(value)
+                .map_err(|err| Error::Invalid { key: VALUE, err })?;
+        }
+
+        for &(key, ref query) in &self.constraints {
+            key_strategy(key)
+                .validate(query)
+                .map_err(|err| Error::Invalid { key, err })?;
+        }
+
+        Ok(())
+    }
+
+    /// Checks whether the serialized `field` satisfies the [`ProvenanceQuery`]
+    /// with regard to the provided [`Case`].
+    ///
+    /// The `values` are the values of the field as they are matched against a
+    /// filter parameter (see [`FieldValues`]), and the query for the value is
+    /// matched against them with the provided [`Strategy`]. For fields with
+    /// multiple values, the value and every provenance constraint must be
+    /// satisfied by the same element of the field.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ccdi_server as server;
+    ///
+    /// use server::filter::provenance::ProvenanceQuery;
+    /// use server::filter::rule::Case;
+    /// use server::filter::rule::Strategy;
+    ///
+    /// let field = serde_json::json!({
+    ///     "value": "Neuroblastoma",
+    ///     "details": { "source": "pathology report", "method": "Mapped" },
+    ///     "comment": "Reviewed by the curation team."
+    /// });
+    /// let values = [String::from("Neuroblastoma")];
+    ///
+    /// let matches = |query: &str, case: Case| {
+    ///     ProvenanceQuery::parse(query).unwrap().unwrap().matches(
+    ///         Some(&values),
+    ///         &field,
+    ///         Strategy::Exact,
+    ///         case,
+    ///     )
+    /// };
+    ///
+    /// assert!(matches(r#"{"source":"pathology report"}"#, Case::Sensitive));
+    /// assert!(matches(
+    ///     r#"{"value":"Neuroblastoma","method":"Mapped","comment":"curation"}"#,
+    ///     Case::Sensitive
+    /// ));
+    /// assert!(!matches(
+    ///     r#"{"value":"Ewing Sarcoma","source":"pathology report"}"#,
+    ///     Case::Sensitive
+    /// ));
+    /// assert!(!matches(r#"{"source":"clinical record"}"#, Case::Sensitive));
+    ///
+    /// assert!(!matches(r#"{"source":"Pathology Report"}"#, Case::Sensitive));
+    /// assert!(matches(r#"{"source":"Pathology Report"}"#, Case::Insensitive));
+    /// ```
+    pub fn matches(
+        &self,
+        values: Option<&[String]>,
+        field: &Value,
+        strategy: Strategy,
+        case: Case,
+    ) -> bool {
+        let values = match values {
+            Some(values) => values,
+            None => return false,
+        };
+
+        // NOTE: the elements of a field with multiple values are paired with
+        // its values by index. Any other field is a single element that is
+        // paired with all of its values (e.g., a set of checksums).
+        let elements = match field {
+            Value::Null => return false,
+            Value::Array(elements) => elements.iter().zip(values.chunks(1)).collect::<Vec<_>>(),
+            element => vec![(element, values)],
+        };
+
+        elements.into_iter().any(|(element, values)| {
+            let value = self
+                .value
+                .as_deref()
+                .map(|query| strategy.evaluate_with_case(Some(values), query, case))
+                .unwrap_or(true);
+
+            value
+                && self.constraints.iter().all(|(key, query)| {
+                    let value = match *key {
+                        "comment" => &element[key],
+                        _ => &element["details"][key],
+                    };
+
+                    let values = value.as_str().map(|value| vec![value.to_string()]);
+                    key_strategy(key).evaluate_with_case(values.as_deref(), query, case)
+                })
+        })
+    }
+}
+
+/// Gets the match [`Strategy`] for a provenance key.
+fn key_strategy(key: &str) -> Strategy {
+    // SAFETY: the key of every constraint is drawn from [`KEYS`].
+    KEYS.iter()
+        .find(|(name, _)| *name == key)
+        .map(|(_, strategy)| *strategy)
+        .unwrap()
+}
+
+/// Splits the provenance queries out of the filter parameters.
+///
+/// Each field within the [`PROVENANCE`](Rules::PROVENANCE) table whose value is
+/// a [`ProvenanceQuery`] is removed from the filter parameters, and the queries
+/// are returned alongside the field they constrain (the value of each query is
+/// matched along with its provenance constraints by [`filter()`]). Invalid
+/// queries are rejected before filtering (see
+/// [`validate()`](crate::filter::validate)), so they are left as they are.
+///
+/// # Examples
+///
+/// ```
+/// use ccdi_server as server;
+///
+/// use server::filter::provenance::split;
+/// use server::params::filter::Sample as SampleFilterParams;
+///
+/// let params = SampleFilterParams {
+///     diagnosis: Some(String::from(r#"{"value":"A","source":"pathology report"}"#)),
+///     tissue_type: Some(String::from(r#"{"source":"pathology report"}"#)),
+///     disease_phase: Some(String::from("Initial Diagnosis")),
+///     ..Default::default()
+/// };
+///
+/// let (params, queries) = split(params);
+///
+/// assert_eq!(params.diagnosis, None);
+/// assert_eq!(params.tissue_type, None);
+/// assert_eq!(
+///     params.disease_phase,
+///     Some(String::from("Initial Diagnosis"))
+/// );
+/// assert_eq!(queries.len(), 2);
+/// ```
+pub fn split<P>(params: P) -> (P, Vec<(&'static str, ProvenanceQuery)>)
+where
+    P: DeserializeOwned + Rules + Serialize,
+{
+    if P::PROVENANCE.is_empty() {
+        return (params, Vec::new());
+    }
+
+    // SAFETY: filter parameters are always serializable to a JSON object.
+    let mut object = match serde_json::to_value(&params).unwrap() {
+        Value::Object(object) => object,
+        _ => unreachable!("filter parameters must serialize to a JSON object"),
+    };
+
+    let mut queries = Vec::new();
+
+    for field in P::PROVENANCE {
+        let query = match object
+            .get(*field)
+            .and_then(Value::as_str)
+            .and_then(|query| ProvenanceQuery::parse(query).ok().flatten())
+        {
+            Some(query) => query,
+            None => continue,
+        };
+
+        object.remove(*field);
+        queries.push((*field, query));
+    }
+
+    if queries.is_empty() {
+        return (params, queries);
+    }
+
+    // SAFETY: the object was serialized from the filter parameters, and only
+    // string values were removed, so it always deserializes.
+    let params = serde_json::from_value(Value::Object(object)).unwrap();
+
+    (params, queries)
+}
+
+/// Filters a list of entities to those that satisfy every provenance query
+/// (keyed by the field within the metadata of the entity that it constrains)
+/// with regard to the provided [`Case`].
+///
+/// The value of each query is matched with the [`Strategy`] of its field
+/// within the provided filter parameters. The order of the entities is
+/// preserved.
+pub fn filter<T, P>(
+    entities: Vec<T>,
+    queries: &[(&'static str, ProvenanceQuery)],
+    case: Case,
+) -> Vec<T>
+where
+    T: FieldValues + Serialize,
+    P: Rules,
+{
+    if queries.is_empty() {
+        return entities;
+    }
+
+    entities
+        .into_iter()
+        .filter(|entity| {
+            // SAFETY: entities are always serializable to JSON.
+            let json = serde_json::to_value(entity).unwrap();
+
+            queries.iter().all(|(field, query)| {
+                // SAFETY: every field within the provenance table has a rule
+                // (see the tests within the [`rule`](crate::filter::rule)
+                // module).
+                let strategy = P::strategy(field).unwrap();
+
+                query.matches(
+                    entity.field_values(field).as_deref(),
+                    &json["metadata"][*field],
+                    strategy,
+                    case,
+                )
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_rejects_unknown_keys() {
+        let err = ProvenanceQuery::parse(r#"{"value":"A","source":"B","origin":"C"}"#).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "unrecognized key `origin` (expected `value`, `source`, `method`, or `comment`)"
+        );
+    }
+
+    fn matches(query: &str, values: Option<&[String]>, field: &Value) -> bool {
+        ProvenanceQuery::parse(query).unwrap().unwrap().matches(
+            values,
+            field,
+            Strategy::Exact,
+            Case::Sensitive,
+        )
+    }
+
+    #[test]
+    fn it_matches_any_element_of_a_field_with_multiple_values() {
+        let field = serde_json::json!([
+            { "value": "Asian" },
+            { "value": "White", "details": { "source": "clinical record" } }
+        ]);
+        let values = [String::from("Asian"), String::from("White")];
+
+        assert!(matches(
+            r#"{"source":"clinical record"}"#,
+            Some(&values),
+            &field
+        ));
+        assert!(matches(
+            r#"{"source":"not:clinical record"}"#,
+            Some(&values),
+            &field
+        ));
+        assert!(!matches(
+            r#"{"source":"pathology report"}"#,
+            Some(&values),
+            &field
+        ));
+        assert!(!matches(
+            r#"{"source":"clinical record"}"#,
+            None,
+            &Value::Null
+        ));
+    }
+
+    #[test]
+    fn it_matches_the_value_and_the_provenance_against_the_same_element() {
+        let field = serde_json::json!([
+            { "value": "Asian" },
+            { "value": "White", "details": { "source": "clinical record" } }
+        ]);
+        let values = [String::from("Asian"), String::from("White")];
+
+        assert!(matches(
+            r#"{"value":"White","source":"clinical record"}"#,
+            Some(&values),
+            &field
+        ));
+        assert!(matches(
+            r#"{"value":"Asian","source":"not:clinical record"}"#,
+            Some(&values),
+            &field
+        ));
+
+        // NOTE: one element has the value and another has the source, so no
+        // single element satisfies both constraints.
+        assert!(!matches(
+            r#"{"value":"Asian","source":"clinical record"}"#,
+            Some(&values),
+            &field
+        ));
+        assert!(!matches(
+            r#"{"value":"not:White","source":"clinical record"}"#,
+            Some(&values),
+            &field
+        ));
+    }
+}
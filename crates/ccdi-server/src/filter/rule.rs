@@ -0,0 +1,1355 @@
+//! The rules that govern how filter parameters are matched.
+//!
+//! Each set of filter parameters declares a [match strategy](Strategy) for
+//! every field within a rule table (see [`Rules`]). The filter engine uses this
+//! table to match entities, and the OpenAPI specification uses this table to
+//! document the matching behavior of each parameter. As such, the behavior and
+//! the documentation of a filter parameter cannot diverge.
+//!
+//! ## Negation
+//!
+//! Any query for a field whose strategy is [negatable](Strategy::negatable)
+//! may be prefixed with `not:` to negate it (see [`Query`]). A negated query
+//! includes every entity that does _not_ match the remainder of the query
+//! according to the field's strategy, including entities with no value for the
+//! field. The special query `not:null` includes only those entities that _do_
+//! have a value for the field.
+//!
+//! ## Ranges
+//!
+//! Numeric fields are matched with the [`Strategy::Range`] strategy: the query
+//! is either a bare number (matched exactly) or a JSON object with any of the
+//! `gt`, `gte`, `lt`, and `lte` keys (see [`Range`]). Because query parameters
+//! are strings, the object is provided as JSON within the value of the
+//! parameter (e.g., `?age_at_collection={"gte":0,"lte":1826.25}`). Invalid
+//! ranges are rejected before filtering (see [`Strategy::validate`]).
+//!
+//! Every numeric field is an age stored in days, so the query may instead
+//! provide its number with a unit (e.g., `{"value":2,"unit":"years"}`) or
+//! provide a `unit` alongside the bounds of a range (e.g.,
+//! `{"gte":1,"lt":5,"unit":"years"}`). The query is normalized to days before
+//! it is compared.
+//!
+//! ## Arrays
+//!
+//! Any query for a field whose strategy [accepts
+//! arrays](Strategy::accepts_arrays) may instead be a JSON array of values
+//! (e.g., `?sex=["F","M"]`), which matches an entity if _any_ of the values
+//! match according to the field's strategy (a logical OR). Each element must be
+//! a string, a number, or a boolean (see [`Alternatives`]), and invalid arrays
+//! are rejected before filtering. A negated array includes only those entities
+//! that match _none_ of the values.
+//!
+//! ## Checksums
+//!
+//! Checksums are matched with the [`Strategy::Checksums`] strategy: the query
+//! is a digest (matched against a checksum computed with any algorithm), a
+//! digest prefixed by its algorithm (e.g., `md5:<digest>`), a JSON array of
+//! either (a logical OR), or a JSON object of algorithms and digests (e.g.,
+//! `{"md5":"<digest>","sha256":"<digest>"}`) that matches only those files
+//! with _every_ digest provided (a logical AND, see [`Digests`]). Any other
+//! JSON is rejected before filtering.
+//!
+//! ## Case
+//!
+//! Matching is case-sensitive by default. When a client opts in to
+//! case-insensitive matching (see [`Case`]), the exact and substring strategies
+//! compare lowercased values instead. Strategies that are already
+//! case-insensitive, boolean strategies, and numeric strategies are unaffected.
+
+use serde::Deserialize;
+use serde_json::Value;
+
+use ccdi_models as models;
+
+use models::file::checksum;
+use models::file::checksum::Algorithm;
+use models::metadata::common::age::Unit;
+
+/// The prefix that negates a query.
+pub const NEGATION_PREFIX: &str = "not:";
+
+/// The query that, when negated, matches any entity with a value for a field.
+pub const NULL: &str = "null";
+
+/// The canonical sentence that describes negation for negatable strategies.
+const NEGATION_SENTENCE: &str =
+    "Prefix the value with `not:` to exclude matches instead (entities \
+                                 without a value for the field are included), or use `not:null` \
+                                 to match any entity with a value for the field.";
+
+/// The canonical sentence that describes arrays for strategies that accept
+/// them.
+const ARRAY_SENTENCE: &str = "Provide a JSON array of values (e.g., `[\"A\",\"B\"]`) to match \
+                              any of the values instead.";
+
+/// Whether the text of a query is matched with regard to case.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum Case {
+    /// Matches are case-sensitive (the default).
+    #[default]
+    Sensitive,
+
+    /// Matches are case-insensitive.
+    Insensitive,
+}
+
+/// A parsed query for a filter parameter.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Query<'a> {
+    /// The values of the field must match the query.
+    Positive(&'a str),
+
+    /// The values of the field must _not_ match the query (the `not:` prefix).
+    /// Entities with no value for the field are included.
+    Negated(&'a str),
+
+    /// The field must have a value (the `not:null` query).
+    NotNull,
+}
+
+impl<'a> Query<'a> {
+    /// Parses a [`Query`] from the raw value of a filter parameter.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ccdi_server as server;
+    ///
+    /// use server::filter::rule::Query;
+    ///
+    /// assert_eq!(Query::parse("Normal"), Query::Positive("Normal"));
+    /// assert_eq!(Query::parse("not:Normal"), Query::Negated("Normal"));
+    /// assert_eq!(Query::parse("not:null"), Query::NotNull);
+    ///
+    /// // Only the first prefix is considered.
+    /// assert_eq!(Query::parse("not:not:Normal"), Query::Negated("not:Normal"));
+    /// ```
+    pub fn parse(query: &'a str) -> Self {
+        match query.strip_prefix(NEGATION_PREFIX) {
+            Some(NULL) => Query::NotNull,
+            Some(query) => Query::Negated(query),
+            None => Query::Positive(query),
+        }
+    }
+}
+
+/// An error related to parsing a [`Range`].
+#[derive(Debug)]
+pub enum RangeError {
+    /// The query was neither a number nor a valid JSON object of bounds.
+    Invalid(String),
+
+    /// The JSON object of bounds did not contain any bounds.
+    Empty,
+
+    /// The JSON object contained both a `value` and bounds.
+    ValueWithBounds,
+
+    /// Both an exclusive and an inclusive bound were provided for the same
+    /// side of the range.
+    Ambiguous(&'static str, &'static str),
+
+    /// The lower bound is above the upper bound, so no value can fall within
+    /// the range.
+    Unsatisfiable {
+        /// The key and the value of the lower bound.
+        lower: (&'static str, f64),
+
+        /// The key and the value of the upper bound.
+        upper: (&'static str, f64),
+    },
+}
+
+impl std::fmt::Display for RangeError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            RangeError::Invalid(reason) => write!(
+                f,
+                "expected a number or a JSON object with any of the `gt`, `gte`, `lt`, and `lte` \
+                 keys: {reason}"
+            ),
+            RangeError::ValueWithBounds => write!(
+                f,
+                "a `value` cannot be combined with any of the `gt`, `gte`, `lt`, and `lte` keys"
+            ),
+            RangeError::Empty => write!(
+                f,
+                "a range must contain at least one of the `gt`, `gte`, `lt`, and `lte` keys"
+            ),
+            RangeError::Ambiguous(a, b) => {
+                write!(f, "only one of `{a}` and `{b}` may be provided")
+            }
+            RangeError::Unsatisfiable {
+                lower: (lower_key, lower),
+                upper: (upper_key, upper),
+            } => write!(
+                f,
+                "no value can satisfy both `{lower_key}` ({lower}) and `{upper_key}` ({upper})"
+            ),
+        }
+    }
+}
+
+impl std::error::Error for RangeError {}
+
+/// A JSON object provided as a numeric query.
+#[derive(Debug, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct Bounds {
+    /// A value to match exactly.
+    #[serde(default)]
+    value: Option<f64>,
+
+    /// The value must be greater than this bound.
+    #[serde(default)]
+    gt: Option<f64>,
+
+    /// The value must be greater than or equal to this bound.
+    #[serde(default)]
+    gte: Option<f64>,
+
+    /// The value must be less than this bound.
+    #[serde(default)]
+    lt: Option<f64>,
+
+    /// The value must be less than or equal to this bound.
+    #[serde(default)]
+    lte: Option<f64>,
+
+    /// The unit of the value and the bounds (defaults to days).
+    #[serde(default)]
+    unit: Option<Unit>,
+}
+
+/// A numeric range that the value of a field must fall within.
+///
+/// Each bound is optional, but at least one bound must be provided. Bounds are
+/// always stored in days.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Range {
+    /// The value must be greater than this bound.
+    gt: Option<f64>,
+
+    /// The value must be greater than or equal to this bound.
+    gte: Option<f64>,
+
+    /// The value must be less than this bound.
+    lt: Option<f64>,
+
+    /// The value must be less than or equal to this bound.
+    lte: Option<f64>,
+}
+
+impl Range {
+    /// Parses a [`Range`] from a query (with any negation already removed).
+    ///
+    /// A bare number is parsed as a range that contains only that number, as
+    /// is an object with a `value` (and, optionally, a `unit`). When a `unit`
+    /// is provided, the value or the bounds are converted to days.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ccdi_server as server;
+    ///
+    /// use server::filter::rule::Range;
+    ///
+    /// let range = Range::parse(r#"{"gte":0,"lte":1826.25}"#).unwrap();
+    /// assert!(range.contains(0.0));
+    /// assert!(range.contains(1826.25));
+    /// assert!(!range.contains(1826.5));
+    ///
+    /// let range = Range::parse("365.25").unwrap();
+    /// assert!(range.contains(365.25));
+    /// assert!(!range.contains(365.0));
+    ///
+    /// let range = Range::parse(r#"{"value":1,"unit":"years"}"#).unwrap();
+    /// assert!(range.contains(365.25));
+    ///
+    /// let range = Range::parse(r#"{"lt":1,"unit":"years"}"#).unwrap();
+    /// assert!(range.contains(365.0));
+    /// assert!(!range.contains(365.25));
+    ///
+    /// assert!(Range::parse(r#"{"gte":10,"lte":5}"#).is_err());
+    /// assert!(Range::parse(r#"{"gte":"zero"}"#).is_err());
+    /// assert!(Range::parse("ten").is_err());
+    /// ```
+    pub fn parse(query: &str) -> Result<Self, RangeError> {
+        let query = query.trim();
+
+        if !query.starts_with('{') {
+            let value = query
+                .parse::<f64>()
+                .ok()
+                .filter(|value| value.is_finite())
+                .ok_or_else(|| RangeError::Invalid(format!("`{query}` is not a number")))?;
+
+            return Ok(Self {
+                gte: Some(value),
+                lte: Some(value),
+                ..Default::default()
+            });
+        }
+
+        let bounds = serde_json::from_str::<Bounds>(query)
+            .map_err(|err| RangeError::Invalid(err.to_string()))?;
+
+        let days = |value: Option<f64>| {
+            value.map(|value| value * f64::from(bounds.unit.unwrap_or(Unit::Days).days()))
+        };
+
+        if let Some(value) = days(bounds.value) {
+            return match (bounds.gt, bounds.gte, bounds.lt, bounds.lte) {
+                (None, None, None, None) => Ok(Self {
+                    gte: Some(value),
+                    lte: Some(value),
+                    ..Default::default()
+                }),
+                _ => Err(RangeError::ValueWithBounds),
+            };
+        }
+
+        let range = Self {
+            gt: days(bounds.gt),
+            gte: days(bounds.gte),
+            lt: days(bounds.lt),
+            lte: days(bounds.lte),
+        };
+
+        let lower = match (range.gt, range.gte) {
+            (Some(_), Some(_)) => return Err(RangeError::Ambiguous("gt", "gte")),
+            (Some(gt), None) => Some(("gt", gt)),
+            (None, Some(gte)) => Some(("gte", gte)),
+            (None, None) => None,
+        };
+
+        let upper = match (range.lt, range.lte) {
+            (Some(_), Some(_)) => return Err(RangeError::Ambiguous("lt", "lte")),
+            (Some(lt), None) => Some(("lt", lt)),
+            (None, Some(lte)) => Some(("lte", lte)),
+            (None, None) => None,
+        };
+
+        match (lower, upper) {
+            (None, None) => Err(RangeError::Empty),
+            (Some(lower), Some(upper)) => {
+                let inclusive = lower.0 == "gte" && upper.0 == "lte";
+
+                match (inclusive && lower.1 > upper.1) || (!inclusive && lower.1 >= upper.1) {
+                    true => Err(RangeError::Unsatisfiable { lower, upper }),
+                    false => Ok(range),
+                }
+            }
+            _ => Ok(range),
+        }
+    }
+
+    /// Returns whether a value falls within the [`Range`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ccdi_server as server;
+    ///
+    /// use server::filter::rule::Range;
+    ///
+    /// let range = Range::parse(r#"{"gt":0}"#).unwrap();
+    /// assert!(!range.contains(0.0));
+    /// assert!(range.contains(0.5));
+    /// ```
+    pub fn contains(&self, value: f64) -> bool {
+        self.gt.into_iter().all(|gt| value > gt)
+            && self.gte.into_iter().all(|gte| value >= gte)
+            && self.lt.into_iter().all(|lt| value < lt)
+            && self.lte.into_iter().all(|lte| value <= lte)
+    }
+}
+
+/// An error related to parsing [`Alternatives`].
+#[derive(Debug)]
+pub enum ArrayError {
+    /// The query was not a valid JSON array.
+    Invalid(String),
+
+    /// The array did not contain any values.
+    Empty,
+
+    /// An element of the array was not a string, a number, or a boolean.
+    NonScalar(String),
+}
+
+impl std::fmt::Display for ArrayError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ArrayError::Invalid(reason) => write!(
+                f,
+                "expected a JSON array of strings, numbers, or booleans: {reason}"
+            ),
+            ArrayError::Empty => write!(f, "an array must contain at least one value"),
+            ArrayError::NonScalar(value) => write!(
+                f,
+                "an array may only contain strings, numbers, or booleans, but `{value}` was \
+                 provided"
+            ),
+        }
+    }
+}
+
+impl std::error::Error for ArrayError {}
+
+/// The alternative values of a query (any of which may match).
+///
+/// A query that is a JSON array has one alternative for each of its elements.
+/// Any other query is a single alternative.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Alternatives(Vec<String>);
+
+impl Alternatives {
+    /// Parses [`Alternatives`] from a query (with any negation already
+    /// removed).
+    ///
+    /// Numbers and booleans within an array are matched by their string
+    /// representation.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ccdi_server as server;
+    ///
+    /// use server::filter::rule::Alternatives;
+    ///
+    /// let alternatives = Alternatives::parse(r#"["F","M"]"#).unwrap();
+    /// assert_eq!(alternatives.iter().collect::<Vec<_>>(), vec!["F", "M"]);
+    ///
+    /// let alternatives = Alternatives::parse("F").unwrap();
+    /// assert_eq!(alternatives.iter().collect::<Vec<_>>(), vec!["F"]);
+    ///
+    /// let alternatives = Alternatives::parse("[42, true]").unwrap();
+    /// assert_eq!(alternatives.iter().collect::<Vec<_>>(), vec!["42", "true"]);
+    ///
+    /// assert!(Alternatives::parse("[]").is_err());
+    /// assert!(Alternatives::parse(r#"[["F"]]"#).is_err());
+    /// assert!(Alternatives::parse(r#"["F""#).is_err());
+    /// ```
+    pub fn parse(query: &str) -> Result<Self, ArrayError> {
+        if !query.trim_start().starts_with('[') {
+            return Ok(Self(vec![query.to_string()]));
+        }
+
+        let values = serde_json::from_str::<Vec<Value>>(query)
+            .map_err(|err| ArrayError::Invalid(err.to_string()))?;
+
+        if values.is_empty() {
+            return Err(ArrayError::Empty);
+        }
+
+        values
+            .into_iter()
+            .map(|value| match value {
+                Value::String(value) => Ok(value),
+                Value::Number(_) | Value::Bool(_) => Ok(value.to_string()),
+                value => Err(ArrayError::NonScalar(value.to_string())),
+            })
+            .collect::<Result<Vec<_>, _>>()
+            .map(Self)
+    }
+
+    /// Gets an iterator over the alternatives.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ccdi_server as server;
+    ///
+    /// use server::filter::rule::Alternatives;
+    ///
+    /// let alternatives = Alternatives::parse(r#"["F","M"]"#).unwrap();
+    /// assert_eq!(alternatives.iter().count(), 2);
+    /// ```
+    pub fn iter(&self) -> impl Iterator<Item = &str> {
+        self.0.iter().map(String::as_str)
+    }
+}
+
+/// An error related to parsing a query for the [`Strategy::Checksums`]
+/// strategy.
+#[derive(Debug)]
+pub enum ChecksumError {
+    /// The query was not one of the accepted forms.
+    Invalid(String),
+
+    /// The JSON object did not contain any algorithms.
+    Empty,
+
+    /// A key of the JSON object was not a supported [`Algorithm`].
+    UnknownAlgorithm(checksum::Error),
+
+    /// A value of the JSON object was not a string.
+    NonString {
+        /// The algorithm.
+        algorithm: String,
+
+        /// The value.
+        value: String,
+    },
+}
+
+impl std::fmt::Display for ChecksumError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ChecksumError::Invalid(reason) => write!(
+                f,
+                "expected a digest, a digest prefixed by its algorithm (e.g., \
+                 `md5:<digest>`), a JSON array of strings, or a JSON object of algorithms and \
+                 digests (e.g., `{{\"md5\":\"<digest>\",\"sha256\":\"<digest>\"}}`): {reason}"
+            ),
+            ChecksumError::Empty => write!(
+                f,
+                "a JSON object of checksums must contain at least one algorithm"
+            ),
+            ChecksumError::UnknownAlgorithm(err) => write!(f, "{err}"),
+            ChecksumError::NonString { algorithm, value } => write!(
+                f,
+                "the digest for `{algorithm}` must be a string, but `{value}` was provided"
+            ),
+        }
+    }
+}
+
+impl std::error::Error for ChecksumError {}
+
+/// The digests of a JSON object provided as a checksum query (all of which
+/// must match).
+///
+/// Each digest is keyed by the [`Algorithm`] with which it was computed, and
+/// each is matched in the `algorithm:digest` form.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Digests(Vec<(Algorithm, String)>);
+
+impl Digests {
+    /// Parses [`Digests`] from a JSON object of algorithms and digests.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ccdi_server as server;
+    ///
+    /// use server::filter::rule::Digests;
+    ///
+    /// let digests = Digests::parse(r#"{"md5":"AAAA","SHA-256":"BBBB"}"#).unwrap();
+    /// assert_eq!(
+    ///     digests.iter().collect::<Vec<_>>(),
+    ///     vec!["md5:AAAA", "sha256:BBBB"]
+    /// );
+    ///
+    /// assert!(Digests::parse("{}").is_err());
+    /// assert!(Digests::parse(r#"{"crc32":"AAAA"}"#).is_err());
+    /// assert!(Digests::parse(r#"{"md5":42}"#).is_err());
+    /// assert!(Digests::parse(r#"["AAAA"]"#).is_err());
+    /// ```
+    pub fn parse(query: &str) -> Result<Self, ChecksumError> {
+        let object = serde_json::from_str::<serde_json::Map<String, Value>>(query)
+            .map_err(|err| ChecksumError::Invalid(err.to_string()))?;
+
+        if object.is_empty() {
+            return Err(ChecksumError::Empty);
+        }
+
+        object
+            .into_iter()
+            .map(|(algorithm, value)| {
+                let digest = match value {
+                    Value::String(digest) => digest,
+                    value => {
+                        return Err(ChecksumError::NonString {
+                            algorithm,
+                            value: value.to_string(),
+                        })
+                    }
+                };
+
+                algorithm
+                    .parse::<Algorithm>()
+                    .map(|algorithm| (algorithm, digest))
+                    .map_err(ChecksumError::UnknownAlgorithm)
+            })
+            .collect::<Result<Vec<_>, _>>()
+            .map(Self)
+    }
+
+    /// Gets an iterator over the digests in the `algorithm:digest` form.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ccdi_server as server;
+    ///
+    /// use server::filter::rule::Digests;
+    ///
+    /// let digests = Digests::parse(r#"{"md5":"AAAA"}"#).unwrap();
+    /// assert_eq!(digests.iter().collect::<Vec<_>>(), vec!["md5:AAAA"]);
+    /// ```
+    pub fn iter(&self) -> impl Iterator<Item = String> + '_ {
+        self.0
+            .iter()
+            .map(|(algorithm, digest)| format!("{algorithm}:{digest}"))
+    }
+}
+
+/// An error related to validating the raw value of a filter parameter.
+#[derive(Debug)]
+pub enum ValidationError {
+    /// The query was not a valid [`Range`].
+    Range(RangeError),
+
+    /// The query was not a valid array of [`Alternatives`].
+    Array(ArrayError),
+
+    /// The query was not a valid checksum query.
+    Checksums(ChecksumError),
+}
+
+impl std::fmt::Display for ValidationError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ValidationError::Range(err) => write!(f, "{err}"),
+            ValidationError::Array(err) => write!(f, "{err}"),
+            ValidationError::Checksums(err) => write!(f, "{err}"),
+        }
+    }
+}
+
+impl std::error::Error for ValidationError {}
+
+/// A strategy for matching the value(s) of a field against a query.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Strategy {
+    /// The value of the field must exactly match the query. Matches are
+    /// case-sensitive.
+    Exact,
+
+    /// Any of the values of the field must exactly match the query. Matches
+    /// are case-sensitive.
+    AnyExact,
+
+    /// Any of the values of the field must exactly match the query. Matches
+    /// are case-insensitive.
+    AnyExactIgnoreCase,
+
+    /// The value of the field must contain the query. Matches are
+    /// case-sensitive.
+    Substring,
+
+    /// Any of the values of the field must contain the query. Matches are
+    /// case-insensitive.
+    AnySubstringIgnoreCase,
+
+    /// The field is a boolean, and its value must match the query (either
+    /// `true` or `false`).
+    Boolean,
+
+    /// The field is numeric, and its value must fall within the [`Range`]
+    /// described by the query.
+    Range,
+
+    /// The field is a list of checksums, and any of them must exactly match
+    /// the query (or, for a JSON object of [`Digests`], all of the digests
+    /// must match). Matches are case-insensitive.
+    Checksums,
+}
+
+impl Strategy {
+    /// Gets the canonical sentence that describes the [`Strategy`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ccdi_server as server;
+    ///
+    /// use server::filter::rule::Strategy;
+    ///
+    /// assert_eq!(
+    ///     Strategy::Exact.sentence(),
+    ///     "Matching is exact and case-sensitive."
+    /// );
+    /// ```
+    pub fn sentence(&self) -> &'static str {
+        match self {
+            Strategy::Exact => "Matching is exact and case-sensitive.",
+            Strategy::AnyExact => {
+                "Matching is exact and case-sensitive. A logical OR (`||`) is performed across \
+                 the values of the field."
+            }
+            Strategy::AnyExactIgnoreCase => {
+                "Matching is exact and case-insensitive. A logical OR (`||`) is performed across \
+                 the values of the field."
+            }
+            Strategy::Substring => {
+                "Matching is done by substring (the value must contain the string provided) and \
+                 is case-sensitive."
+            }
+            Strategy::AnySubstringIgnoreCase => {
+                "Matching is done by substring (a value must contain the string provided) and is \
+                 case-insensitive. A logical OR (`||`) is performed across the values of the \
+                 field."
+            }
+            Strategy::Boolean => "The value must be either `true` or `false`.",
+            Strategy::Range => {
+                "The value must be either a number, which is matched exactly, or a JSON object \
+                 with any of the `gt`, `gte`, `lt`, and `lte` keys, which matches values within \
+                 the bounds provided (e.g., `{\"gte\":0,\"lte\":1826.25}`). Numbers are \
+                 interpreted as days unless a `unit` of `days`, `months`, or `years` is provided \
+                 (e.g., `{\"gte\":2,\"unit\":\"years\"}`)."
+            }
+            Strategy::Checksums => {
+                "Matching is exact and case-insensitive. The value may be a digest, which matches \
+                 a checksum computed with any algorithm, or a digest prefixed by its algorithm \
+                 (e.g., `md5:d41d8cd98f00b204e9800998ecf8427e`). A logical OR (`||`) is \
+                 performed across the checksums of the file. Provide a JSON object of \
+                 algorithms and digests (e.g., \
+                 `{\"md5\":\"<digest>\",\"sha256\":\"<digest>\"}`) to match only those files \
+                 with every digest provided instead (a logical AND (`&&`) across the \
+                 algorithms)."
+            }
+        }
+    }
+
+    /// Returns whether queries matched with the [`Strategy`] may be negated
+    /// with the `not:` prefix.
+    ///
+    /// Boolean fields cannot be negated, as the negation of one boolean value
+    /// can already be expressed as the other.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ccdi_server as server;
+    ///
+    /// use server::filter::rule::Strategy;
+    ///
+    /// assert!(Strategy::Exact.negatable());
+    /// assert!(!Strategy::Boolean.negatable());
+    /// ```
+    pub fn negatable(&self) -> bool {
+        !matches!(self, Strategy::Boolean)
+    }
+
+    /// Returns whether queries matched with the [`Strategy`] may be provided
+    /// as a JSON array of [`Alternatives`].
+    ///
+    /// Boolean and numeric fields do not accept arrays.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ccdi_server as server;
+    ///
+    /// use server::filter::rule::Strategy;
+    ///
+    /// assert!(Strategy::Exact.accepts_arrays());
+    /// assert!(!Strategy::Boolean.accepts_arrays());
+    /// assert!(!Strategy::Range.accepts_arrays());
+    /// ```
+    pub fn accepts_arrays(&self) -> bool {
+        !matches!(self, Strategy::Boolean | Strategy::Range)
+    }
+
+    /// Gets the full description of the matching behavior of the [`Strategy`],
+    /// which is the [canonical sentence](Strategy::sentence) followed by a
+    /// description of arrays (if the strategy [accepts
+    /// arrays](Strategy::accepts_arrays)) and of negation (if the strategy is
+    /// [negatable](Strategy::negatable)).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ccdi_server as server;
+    ///
+    /// use server::filter::rule::Strategy;
+    ///
+    /// assert!(Strategy::Exact.description().contains("`not:null`"));
+    /// assert!(Strategy::Exact.description().contains("JSON array"));
+    /// assert_eq!(
+    ///     Strategy::Boolean.description(),
+    ///     "The value must be either `true` or `false`."
+    /// );
+    /// ```
+    pub fn description(&self) -> String {
+        let mut description = self.sentence().to_string();
+
+        if self.accepts_arrays() {
+            description.push_str("\n\n");
+            description.push_str(ARRAY_SENTENCE);
+        }
+
+        if self.negatable() {
+            description.push_str("\n\n");
+            description.push_str(NEGATION_SENTENCE);
+        }
+
+        description
+    }
+
+    /// Returns whether the provided values of a field match the query
+    /// according to the [`Strategy`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ccdi_server as server;
+    ///
+    /// use server::filter::rule::Strategy;
+    ///
+    /// let values = vec![String::from("Neuroblastoma")];
+    ///
+    /// assert!(Strategy::Exact.matches(&values, "Neuroblastoma"));
+    /// assert!(!Strategy::Exact.matches(&values, "blast"));
+    /// assert!(Strategy::Substring.matches(&values, "blast"));
+    /// assert!(!Strategy::Substring.matches(&values, "NEURO"));
+    /// assert!(Strategy::AnySubstringIgnoreCase.matches(&values, "NEURO"));
+    ///
+    /// // A JSON array matches if any of its values match.
+    /// assert!(Strategy::Exact.matches(&values, r#"["Medulloblastoma","Neuroblastoma"]"#));
+    /// assert!(!Strategy::Exact.matches(&values, r#"["Medulloblastoma"]"#));
+    /// ```
+    pub fn matches(&self, values: &[String], query: &str) -> bool {
+        self.matches_with_case(values, query, Case::Sensitive)
+    }
+
+    /// Returns whether the provided values of a field match the query
+    /// according to the [`Strategy`] with regard to the provided [`Case`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ccdi_server as server;
+    ///
+    /// use server::filter::rule::Case;
+    /// use server::filter::rule::Strategy;
+    ///
+    /// let values = vec![String::from("Hispanic or Latino")];
+    ///
+    /// assert!(!Strategy::Exact.matches_with_case(&values, "hispanic or latino", Case::Sensitive));
+    /// assert!(Strategy::Exact.matches_with_case(&values, "hispanic or latino", Case::Insensitive));
+    /// assert!(!Strategy::Exact.matches_with_case(&values, "hispanic", Case::Insensitive));
+    /// assert!(Strategy::Substring.matches_with_case(&values, "LATINO", Case::Insensitive));
+    /// ```
+    pub fn matches_with_case(&self, values: &[String], query: &str, case: Case) -> bool {
+        if *self == Strategy::Checksums && query.trim_start().starts_with('{') {
+            return match Digests::parse(query) {
+                Ok(digests) => digests
+                    .iter()
+                    .all(|query| self.matches_alternative(values, &query, case)),
+                // NOTE: invalid checksum queries are rejected before filtering
+                // (see [`Strategy::validate`]), so they never match.
+                Err(_) => false,
+            };
+        }
+
+        if !self.accepts_arrays() {
+            return self.matches_alternative(values, query, case);
+        }
+
+        match Alternatives::parse(query) {
+            Ok(alternatives) => alternatives
+                .iter()
+                .any(|query| self.matches_alternative(values, query, case)),
+            // NOTE: invalid arrays are rejected before filtering (see
+            // [`Strategy::validate`]), so they never match.
+            Err(_) => false,
+        }
+    }
+
+    /// Returns whether the provided values of a field match a single
+    /// alternative of a query according to the [`Strategy`] with regard to the
+    /// provided [`Case`].
+    fn matches_alternative(&self, values: &[String], query: &str, case: Case) -> bool {
+        match (self, case) {
+            (Strategy::Exact | Strategy::AnyExact, Case::Sensitive) | (Strategy::Boolean, _) => {
+                values.iter().any(|value| value == query)
+            }
+            (Strategy::Exact | Strategy::AnyExact, Case::Insensitive)
+            | (Strategy::AnyExactIgnoreCase | Strategy::Checksums, _) => {
+                // Matching on `to_lowercase()` is an approximation and will not
+                // cover all unicode characters.
+                let query = query.to_lowercase();
+                values.iter().any(|value| value.to_lowercase() == query)
+            }
+            (Strategy::Substring, Case::Sensitive) => {
+                values.iter().any(|value| value.contains(query))
+            }
+            (Strategy::Substring, Case::Insensitive) | (Strategy::AnySubstringIgnoreCase, _) => {
+                // Matching on `to_lowercase()` is an approximation and will not
+                // cover all unicode characters.
+                let query = query.to_lowercase();
+
+                values
+                    .iter()
+                    .any(|value| value.to_lowercase().contains(&query))
+            }
+            (Strategy::Range, _) => match Range::parse(query) {
+                Ok(range) => values
+                    .iter()
+                    .filter_map(|value| value.parse::<f64>().ok())
+                    .any(|value| range.contains(value)),
+                // NOTE: invalid ranges are rejected before filtering (see
+                // [`Strategy::validate`]), so they never match.
+                Err(_) => false,
+            },
+        }
+    }
+
+    /// Validates the raw value of a filter parameter according to the
+    /// [`Strategy`].
+    ///
+    /// Queries for the [`Strategy::Range`] strategy must be valid ranges, and
+    /// JSON arrays provided to strategies that [accept
+    /// arrays](Strategy::accepts_arrays) must be valid [`Alternatives`].
+    /// Queries for the [`Strategy::Checksums`] strategy must additionally be
+    /// valid [`Digests`] when they are JSON objects. All other queries are
+    /// valid.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ccdi_server as server;
+    ///
+    /// use server::filter::rule::Strategy;
+    ///
+    /// assert!(Strategy::Range
+    ///     .validate(r#"{"gte":0,"lte":1826.25}"#)
+    ///     .is_ok());
+    /// assert!(Strategy::Range.validate("not:365.25").is_ok());
+    /// assert!(Strategy::Range.validate("not:null").is_ok());
+    /// assert!(Strategy::Range.validate(r#"{"gte":10,"lte":5}"#).is_err());
+    /// assert!(Strategy::Exact.validate("anything").is_ok());
+    /// assert!(Strategy::Exact.validate(r#"not:["F","M"]"#).is_ok());
+    /// assert!(Strategy::Exact.validate(r#"[{"value":"F"}]"#).is_err());
+    /// ```
+    pub fn validate(&self, query: &str) -> Result<(), ValidationError> {
+        let query = match Query::parse(query) {
+            Query::Positive(query) | Query::Negated(query) => query,
+            Query::NotNull => return Ok(()),
+        };
+
+        match self {
+            Strategy::Range => Range::parse(query)
+                .map(|_| ())
+                .map_err(ValidationError::Range),
+            Strategy::Checksums if query.trim_start().starts_with('{') => Digests::parse(query)
+                .map(|_| ())
+                .map_err(ValidationError::Checksums),
+            Strategy::Checksums => Alternatives::parse(query)
+                .map(|_| ())
+                .map_err(|err| ValidationError::Checksums(ChecksumError::Invalid(err.to_string()))),
+            strategy if strategy.accepts_arrays() => Alternatives::parse(query)
+                .map(|_| ())
+                .map_err(ValidationError::Array),
+            _ => Ok(()),
+        }
+    }
+
+    /// Returns whether the values of a field (or [`None`] if the field has no
+    /// value) satisfy the raw value of a filter parameter according to the
+    /// [`Strategy`], taking negation into account.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ccdi_server as server;
+    ///
+    /// use server::filter::rule::Strategy;
+    ///
+    /// let values = vec![String::from("Normal")];
+    /// let values = Some(values.as_slice());
+    ///
+    /// assert!(Strategy::Exact.evaluate(values, "Normal"));
+    /// assert!(!Strategy::Exact.evaluate(values, "not:Normal"));
+    /// assert!(Strategy::Exact.evaluate(values, "not:Tumor"));
+    /// assert!(Strategy::Exact.evaluate(values, "not:null"));
+    ///
+    /// // Entities with no value for the field are included in negated queries
+    /// // (except for `not:null`).
+    /// assert!(!Strategy::Exact.evaluate(None, "Normal"));
+    /// assert!(Strategy::Exact.evaluate(None, "not:Normal"));
+    /// assert!(!Strategy::Exact.evaluate(None, "not:null"));
+    /// ```
+    pub fn evaluate(&self, values: Option<&[String]>, query: &str) -> bool {
+        self.evaluate_with_case(values, query, Case::Sensitive)
+    }
+
+    /// Returns whether the values of a field (or [`None`] if the field has no
+    /// value) satisfy the raw value of a filter parameter according to the
+    /// [`Strategy`] with regard to the provided [`Case`], taking negation into
+    /// account.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ccdi_server as server;
+    ///
+    /// use server::filter::rule::Case;
+    /// use server::filter::rule::Strategy;
+    ///
+    /// let values = vec![String::from("Female")];
+    /// let values = Some(values.as_slice());
+    ///
+    /// assert!(!Strategy::Exact.evaluate_with_case(values, "female", Case::Sensitive));
+    /// assert!(Strategy::Exact.evaluate_with_case(values, "female", Case::Insensitive));
+    /// assert!(!Strategy::Exact.evaluate_with_case(values, "not:female", Case::Insensitive));
+    /// ```
+    pub fn evaluate_with_case(&self, values: Option<&[String]>, query: &str, case: Case) -> bool {
+        let query = match self.negatable() {
+            true => Query::parse(query),
+            false => Query::Positive(query),
+        };
+
+        // NOTE: an empty set of values is treated the same as no value.
+        let values = values.filter(|values| !values.is_empty());
+
+        match (query, values) {
+            (Query::Positive(query), Some(values)) => self.matches_with_case(values, query, case),
+            (Query::Positive(_), None) => false,
+            (Query::Negated(query), Some(values)) => !self.matches_with_case(values, query, case),
+            (Query::Negated(_), None) => true,
+            (Query::NotNull, values) => values.is_some(),
+        }
+    }
+}
+
+/// A rule table that declares the match [`Strategy`] for every field within a
+/// set of filter parameters.
+pub trait Rules {
+    /// The field names and their respective match strategies.
+    const RULES: &'static [(&'static str, Strategy)];
+
+    /// The names of the fields that are backed by a harmonized metadata field
+    /// of the same name and, as such, may also constrain the provenance of
+    /// that field (see the [`provenance`](crate::filter::provenance) module).
+    const PROVENANCE: &'static [&'static str] = &[];
+
+    /// Gets the match [`Strategy`] for a field (if the field has a rule).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ccdi_server as server;
+    ///
+    /// use server::filter::rule::Rules as _;
+    /// use server::filter::rule::Strategy;
+    /// use server::params::filter::File;
+    ///
+    /// assert_eq!(File::strategy("type"), Some(Strategy::Exact));
+    /// assert_eq!(File::strategy("description"), Some(Strategy::Substring));
+    /// assert_eq!(File::strategy("handedness"), None);
+    /// ```
+    fn strategy(field: &str) -> Option<Strategy> {
+        Self::RULES
+            .iter()
+            .find(|(name, _)| *name == field)
+            .map(|(_, strategy)| *strategy)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use introspect::Introspected;
+
+    use crate::params::filter;
+
+    use super::*;
+
+    /// Gets the (Rust-artifact-stripped) names of the fields within a set of
+    /// filter parameters.
+    fn fields<P: Introspected>() -> Vec<String> {
+        P::introspected_members()
+            .into_iter()
+            .map(|member| match member {
+                introspect::Member::Field(field) => {
+                    let field = field.identifier().unwrap().to_string();
+                    field
+                        .strip_prefix("r#")
+                        .map(|field| field.to_string())
+                        .unwrap_or(field)
+                }
+                introspect::Member::Variant(_) => unreachable!(),
+            })
+            .collect()
+    }
+
+    fn assert_rules_cover_fields<P: Introspected + Rules>() {
+        let mut fields = fields::<P>();
+        fields.sort();
+
+        let mut rules = P::RULES
+            .iter()
+            .map(|(name, _)| name.to_string())
+            .collect::<Vec<_>>();
+        rules.sort();
+
+        assert_eq!(fields, rules);
+    }
+
+    #[test]
+    fn every_filter_field_has_exactly_one_rule() {
+        assert_rules_cover_fields::<filter::Subject>();
+        assert_rules_cover_fields::<filter::SubjectDiagnosis>();
+        assert_rules_cover_fields::<filter::Sample>();
+        assert_rules_cover_fields::<filter::SampleDiagnosis>();
+        assert_rules_cover_fields::<filter::File>();
+    }
+
+    fn assert_provenance_fields_have_rules<P: Rules>() {
+        for field in P::PROVENANCE {
+            assert!(
+                P::strategy(field).is_some(),
+                "provenance field `{field}` has no rule"
+            );
+        }
+    }
+
+    #[test]
+    fn every_provenance_field_has_a_rule() {
+        assert_provenance_fields_have_rules::<filter::Subject>();
+        assert_provenance_fields_have_rules::<filter::Sample>();
+        assert_provenance_fields_have_rules::<filter::File>();
+    }
+
+    #[test]
+    fn strategies_match_correctly() {
+        let values = vec![String::from("Asian"), String::from("White")];
+
+        assert!(Strategy::AnyExact.matches(&values, "White"));
+        assert!(!Strategy::AnyExact.matches(&values, "white"));
+        assert!(Strategy::AnyExactIgnoreCase.matches(&values, "white"));
+        assert!(!Strategy::AnyExactIgnoreCase.matches(&values, "whit"));
+        assert!(Strategy::AnySubstringIgnoreCase.matches(&values, "hit"));
+        assert!(!Strategy::AnySubstringIgnoreCase.matches(&values, "Black"));
+        assert!(!Strategy::Exact.matches(&[], "White"));
+
+        let values = vec![String::from("false")];
+
+        assert!(Strategy::Boolean.matches(&values, "false"));
+        assert!(!Strategy::Boolean.matches(&values, "true"));
+    }
+
+    #[test]
+    fn negated_queries_respect_the_strategy() {
+        let values = vec![String::from("Asian"), String::from("White")];
+        let values = Some(values.as_slice());
+
+        // A negated query excludes an entity if _any_ of its values match.
+        assert!(!Strategy::AnyExact.evaluate(values, "not:White"));
+        assert!(Strategy::AnyExact.evaluate(values, "not:white"));
+        assert!(!Strategy::AnySubstringIgnoreCase.evaluate(values, "not:hit"));
+        assert!(Strategy::AnySubstringIgnoreCase.evaluate(values, "not:Black"));
+
+        // Empty values are treated the same as missing values.
+        assert!(Strategy::AnyExact.evaluate(Some(&[]), "not:White"));
+        assert!(!Strategy::AnyExact.evaluate(Some(&[]), "not:null"));
+    }
+
+    #[test]
+    fn case_insensitive_matching_is_opt_in() {
+        let values = vec![String::from("Hispanic or Latino")];
+        let values = Some(values.as_slice());
+
+        assert!(!Strategy::Exact.evaluate(values, "hispanic or latino"));
+        assert!(Strategy::Exact.evaluate_with_case(
+            values,
+            "HISPANIC OR LATINO",
+            Case::Insensitive
+        ));
+        assert!(Strategy::AnyExact.evaluate_with_case(
+            values,
+            "hispanic or latino",
+            Case::Insensitive
+        ));
+        assert!(Strategy::Substring.evaluate_with_case(values, "latino", Case::Insensitive));
+        assert!(!Strategy::Exact.evaluate_with_case(
+            values,
+            "not:hispanic or latino",
+            Case::Insensitive
+        ));
+
+        // Booleans and numbers are unaffected.
+        let values = vec![String::from("true")];
+        assert!(!Strategy::Boolean.evaluate_with_case(Some(&values), "TRUE", Case::Insensitive));
+
+        let values = vec![String::from("365.25")];
+        assert!(Strategy::Range.evaluate_with_case(Some(&values), "365.25", Case::Insensitive));
+    }
+
+    #[test]
+    fn range_queries_match_numerically() {
+        let values = vec![String::from("365.25")];
+        let values = Some(values.as_slice());
+
+        assert!(Strategy::Range.evaluate(values, "365.25"));
+        assert!(Strategy::Range.evaluate(values, "365.250"));
+        assert!(Strategy::Range.evaluate(values, r#"{"gte":0,"lte":1826.25}"#));
+        assert!(Strategy::Range.evaluate(values, r#"{"gt":365}"#));
+        assert!(!Strategy::Range.evaluate(values, r#"{"lt":365.25}"#));
+        assert!(!Strategy::Range.evaluate(values, r#"not:{"lte":1826.25}"#));
+
+        // Queries with a unit are normalized to days.
+        assert!(Strategy::Range.evaluate(values, r#"{"value":1,"unit":"years"}"#));
+        assert!(Strategy::Range.evaluate(values, r#"{"value":12,"unit":"months"}"#));
+        assert!(Strategy::Range.evaluate(values, r#"{"gte":1,"lt":2,"unit":"years"}"#));
+        assert!(!Strategy::Range.evaluate(values, r#"{"gt":1,"unit":"years"}"#));
+
+        // Entities with no value for the field are never within a range.
+        assert!(!Strategy::Range.evaluate(None, r#"{"gte":0}"#));
+        assert!(Strategy::Range.evaluate(None, r#"not:{"gte":0}"#));
+    }
+
+    #[test]
+    fn invalid_ranges_are_rejected() {
+        let reason = |query: &str| Strategy::Range.validate(query).unwrap_err().to_string();
+
+        assert_eq!(
+            reason(r#"{"gte":10,"lte":5}"#),
+            "no value can satisfy both `gte` (10) and `lte` (5)"
+        );
+        assert_eq!(
+            reason(r#"{"gt":5,"lt":5}"#),
+            "no value can satisfy both `gt` (5) and `lt` (5)"
+        );
+        assert_eq!(
+            reason(r#"{"gt":0,"gte":0}"#),
+            "only one of `gt` and `gte` may be provided"
+        );
+        assert_eq!(
+            reason("{}"),
+            "a range must contain at least one of the `gt`, `gte`, `lt`, and `lte` keys"
+        );
+        assert!(reason(r#"{"gte":"zero"}"#).starts_with("expected a number or a JSON object"));
+        assert!(reason(r#"{"between":[0,5]}"#).contains("unknown field `between`"));
+        assert!(reason("ten").ends_with("`ten` is not a number"));
+        assert!(reason("NaN").ends_with("`NaN` is not a number"));
+        assert_eq!(
+            reason(r#"{"value":1,"gte":0}"#),
+            "a `value` cannot be combined with any of the `gt`, `gte`, `lt`, and `lte` keys"
+        );
+        assert!(reason(r#"{"value":1,"unit":"weeks"}"#).contains("unknown variant `weeks`"));
+
+        // A range that contains a single value is valid.
+        assert!(Strategy::Range.validate(r#"{"gte":5,"lte":5}"#).is_ok());
+    }
+
+    #[test]
+    fn array_queries_match_any_value() {
+        let values = vec![String::from("Asian"), String::from("White")];
+        let values = Some(values.as_slice());
+
+        assert!(Strategy::AnyExact.evaluate(values, r#"["Black","White"]"#));
+        assert!(!Strategy::AnyExact.evaluate(values, r#"["Black","Other"]"#));
+        assert!(!Strategy::AnyExact.evaluate(values, r#"not:["Black","White"]"#));
+        assert!(Strategy::AnyExact.evaluate(values, r#"not:["Black","Other"]"#));
+        assert!(Strategy::AnySubstringIgnoreCase.evaluate(values, r#"["blac","HIT"]"#));
+        assert!(Strategy::Exact.evaluate_with_case(
+            values,
+            r#"["black","white"]"#,
+            Case::Insensitive
+        ));
+
+        // Numbers and booleans are matched by their string representation.
+        let values = vec![String::from("42")];
+        assert!(Strategy::Exact.evaluate(Some(&values), "[41, 42]"));
+
+        // Entities with no value for the field never match an array.
+        assert!(!Strategy::AnyExact.evaluate(None, r#"["Black","White"]"#));
+        assert!(Strategy::AnyExact.evaluate(None, r#"not:["Black","White"]"#));
+    }
+
+    #[test]
+    fn invalid_arrays_are_rejected() {
+        let reason = |query: &str| Strategy::Exact.validate(query).unwrap_err().to_string();
+
+        assert_eq!(reason("[]"), "an array must contain at least one value");
+        assert_eq!(
+            reason(r#"["F",["M"]]"#),
+            "an array may only contain strings, numbers, or booleans, but `[\"M\"]` was provided"
+        );
+        assert_eq!(
+            reason(r#"not:[null]"#),
+            "an array may only contain strings, numbers, or booleans, but `null` was provided"
+        );
+        assert!(reason(r#"["F""#).starts_with("expected a JSON array"));
+
+        // Boolean and numeric strategies do not accept arrays.
+        assert!(Strategy::Boolean.validate("[]").is_ok());
+        assert!(Strategy::Range.validate(r#"["F"]"#).is_err());
+    }
+
+    #[test]
+    fn checksum_queries_match_any_or_every_digest() {
+        let values = vec![
+            String::from("D41D8CD98F00B204E9800998ECF8427E"),
+            String::from("md5:D41D8CD98F00B204E9800998ECF8427E"),
+        ];
+        let values = Some(values.as_slice());
+
+        // Scalars.
+        assert!(Strategy::Checksums.evaluate(values, "d41d8cd98f00b204e9800998ecf8427e"));
+        assert!(Strategy::Checksums.evaluate(values, "MD5:d41d8cd98f00b204e9800998ecf8427e"));
+        assert!(!Strategy::Checksums.evaluate(values, "sha1:d41d8cd98f00b204e9800998ecf8427e"));
+
+        // Arrays (any of the values).
+        assert!(Strategy::Checksums.evaluate(
+            values,
+            r#"["sha256:0000","md5:d41d8cd98f00b204e9800998ecf8427e"]"#
+        ));
+        assert!(!Strategy::Checksums.evaluate(values, r#"["sha256:0000","md5:0000"]"#));
+
+        // Objects (every algorithm).
+        assert!(
+            Strategy::Checksums.evaluate(values, r#"{"md5":"d41d8cd98f00b204e9800998ecf8427e"}"#)
+        );
+        assert!(!Strategy::Checksums.evaluate(
+            values,
+            r#"{"md5":"d41d8cd98f00b204e9800998ecf8427e","sha256":"0000"}"#
+        ));
+        assert!(Strategy::Checksums.evaluate(
+            values,
+            r#"not:{"md5":"d41d8cd98f00b204e9800998ecf8427e","sha256":"0000"}"#
+        ));
+        assert!(!Strategy::Checksums.evaluate(None, r#"{"md5":"0000"}"#));
+    }
+
+    #[test]
+    fn invalid_checksum_queries_are_rejected() {
+        let reason = |query: &str| Strategy::Checksums.validate(query).unwrap_err().to_string();
+
+        assert_eq!(
+            reason("{}"),
+            "a JSON object of checksums must contain at least one algorithm"
+        );
+        assert!(reason(r#"{"crc32":"0000"}"#).starts_with("unknown checksum algorithm `crc32`"));
+        assert_eq!(
+            reason(r#"{"md5":["0000"]}"#),
+            "the digest for `md5` must be a string, but `[\"0000\"]` was provided"
+        );
+        assert!(reason(r#"[{"md5":"0000"}]"#).starts_with(
+            "expected a digest, a digest prefixed by its algorithm (e.g., `md5:<digest>`), a \
+             JSON array of strings, or a JSON object of algorithms and digests"
+        ));
+        assert!(reason(r#"{"md5":"0000""#).starts_with("expected a digest"));
+        assert!(reason("[]").ends_with("an array must contain at least one value"));
+
+        assert!(Strategy::Checksums.validate("md5:0000").is_ok());
+        assert!(Strategy::Checksums
+            .validate(r#"not:{"md5":"0000"}"#)
+            .is_ok());
+    }
+
+    #[test]
+    fn boolean_queries_cannot_be_negated() {
+        let values = vec![String::from("true")];
+        let values = Some(values.as_slice());
+
+        assert!(!Strategy::Boolean.evaluate(values, "not:true"));
+        assert!(!Strategy::Boolean.evaluate(values, "not:null"));
+    }
+}
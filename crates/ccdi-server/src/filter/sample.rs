@@ -2,14 +2,85 @@
 
 use ccdi_models as models;
 
-use models::metadata::common::deposition::Accession;
 use models::Sample;
 
+use crate::filter::alias::Aliases;
+use crate::filter::rule::Case;
+use crate::filter::rule::Rules;
+use crate::filter::rule::Strategy;
+use crate::filter::scope;
+use crate::filter::FieldValues;
 use crate::filter::FilterMetadataField;
 use crate::params::filter::Sample as FilterSampleParams;
 
+impl Aliases for FilterSampleParams {
+    const ALIASES: &'static [(&'static str, &'static str)] = &[
+        ("anatomical_sites", "anatomical_site"),
+        ("tumor_tissue_morphology", "tumor_tissue_morphologies"),
+        ("identifiers", "identifier"),
+        ("depositions", "deposition"),
+    ];
+}
+
+impl Rules for FilterSampleParams {
+    const RULES: &'static [(&'static str, Strategy)] = &[
+        ("diagnosis_category", Strategy::Exact),
+        ("disease_phase", Strategy::Exact),
+        ("anatomical_sites", Strategy::AnyExact),
+        ("library_selection_method", Strategy::Exact),
+        ("library_strategy", Strategy::Exact),
+        ("library_source_material", Strategy::Exact),
+        ("preservation_method", Strategy::Exact),
+        ("tumor_grade", Strategy::Exact),
+        ("specimen_molecular_analyte_type", Strategy::Exact),
+        ("tissue_type", Strategy::Exact),
+        ("tumor_classification", Strategy::Exact),
+        ("age_at_diagnosis", Strategy::Range),
+        ("age_at_collection", Strategy::Range),
+        ("tumor_tissue_morphology", Strategy::Exact),
+        ("tumor_tissue_site", Strategy::Exact),
+        ("identifiers", Strategy::AnyExact),
+        ("depositions", Strategy::AnyExact),
+        ("diagnosis", Strategy::Exact),
+        ("has_metadata", Strategy::Boolean),
+        ("namespace", Strategy::Exact),
+        ("organization", Strategy::Exact),
+    ];
+
+    const PROVENANCE: &'static [&'static str] = &[
+        "diagnosis_category",
+        "disease_phase",
+        "anatomical_sites",
+        "library_selection_method",
+        "library_strategy",
+        "library_source_material",
+        "preservation_method",
+        "tumor_grade",
+        "specimen_molecular_analyte_type",
+        "tissue_type",
+        "tumor_classification",
+        "age_at_diagnosis",
+        "age_at_collection",
+        "tumor_tissue_morphology",
+        "tumor_tissue_site",
+        "identifiers",
+        "diagnosis",
+    ];
+}
+
 impl FilterMetadataField<Sample, FilterSampleParams> for Vec<Sample> {
-    fn filter_metadata_field(self, field: String, params: &FilterSampleParams) -> Vec<Sample> {
+    fn filter_metadata_field(
+        self,
+        field: String,
+        params: &FilterSampleParams,
+        case: Case,
+    ) -> Vec<Sample> {
+        // Boolean parameters are matched against the string representation of
+        // the value (`true` or `false`).
+        let has_metadata = params
+            .has_metadata
+            .map(|has_metadata| has_metadata.to_string());
+
         let parameter = match field.as_str() {
             "anatomical_sites" => params.anatomical_sites.as_ref(),
             "diagnosis_category" => params.diagnosis_category.as_ref(),
@@ -25,11 +96,19 @@ impl FilterMetadataField<Sample, FilterSampleParams> for Vec<Sample> {
             "age_at_diagnosis" => params.age_at_diagnosis.as_ref(),
             "age_at_collection" => params.age_at_collection.as_ref(),
             "tumor_tissue_morphology" => params.tumor_tissue_morphology.as_ref(),
+            "tumor_tissue_site" => params.tumor_tissue_site.as_ref(),
+            "identifiers" => params.identifiers.as_ref(),
             "depositions" => params.depositions.as_ref(),
             "diagnosis" => params.diagnosis.as_ref(),
+            "has_metadata" => has_metadata.as_ref(),
+            "namespace" => params.namespace.as_ref(),
+            "organization" => params.organization.as_ref(),
             _ => unreachable!("unhandled sample metadata field: {field}"),
         };
 
+        let strategy = FilterSampleParams::strategy(&field)
+            .unwrap_or_else(|| unreachable!("unhandled sample filter rule: {field}"));
+
         let query = match parameter {
             Some(query) => query,
             // If the parameter has no value, just return the original list of
@@ -39,96 +118,106 @@ impl FilterMetadataField<Sample, FilterSampleParams> for Vec<Sample> {
 
         self.into_iter()
             .filter(|sample| {
-                let values: Option<Vec<String>> = match field.as_str() {
-                    "anatomical_sites" => sample
-                        .metadata()
-                        .and_then(|metadata| metadata.anatomical_sites())
-                        .map(|sites| {
-                            sites
-                                .iter()
-                                .map(|site| site.to_string())
-                                .collect::<Vec<_>>()
-                        }),
-                    "diagnosis_category" => sample
-                        .metadata()
-                        .and_then(|metadata| metadata.diagnosis_category())
-                        .map(|diagnosis_category| vec![diagnosis_category.to_string()]),
-                    "disease_phase" => sample
-                        .metadata()
-                        .and_then(|metadata| metadata.disease_phase())
-                        .map(|disease_phase| vec![disease_phase.to_string()]),
-                    "library_selection_method" => sample
-                        .metadata()
-                        .and_then(|metadata| metadata.library_selection_method())
-                        .map(|library_selection_method| vec![library_selection_method.to_string()]),
-                    "library_strategy" => sample
-                        .metadata()
-                        .and_then(|metadata| metadata.library_strategy())
-                        .map(|library_strategy| vec![library_strategy.to_string()]),
-                    "library_source_material" => sample
-                        .metadata()
-                        .and_then(|metadata| metadata.library_source_material())
-                        .map(|library_source_material| vec![library_source_material.to_string()]),
-                    "preservation_method" => sample
-                        .metadata()
-                        .and_then(|metadata| metadata.preservation_method())
-                        .map(|preservation_method| vec![preservation_method.to_string()]),
-                    "tumor_grade" => sample
-                        .metadata()
-                        .and_then(|metadata| metadata.tumor_grade())
-                        .map(|tumor_grade| vec![tumor_grade.to_string()]),
-                    "specimen_molecular_analyte_type" => sample
-                        .metadata()
-                        .and_then(|metadata| metadata.specimen_molecular_analyte_type())
-                        .map(|specimen_molecular_analyte_type| {
-                            vec![specimen_molecular_analyte_type.to_string()]
-                        }),
-                    "tissue_type" => sample
-                        .metadata()
-                        .and_then(|metadata| metadata.tissue_type())
-                        .map(|tissue_type| vec![tissue_type.to_string()]),
-                    "tumor_classification" => sample
-                        .metadata()
-                        .and_then(|metadata| metadata.tumor_classification())
-                        .map(|tumor_classification| vec![tumor_classification.to_string()]),
-                    "age_at_diagnosis" => sample
-                        .metadata()
-                        .and_then(|metadata| metadata.age_at_diagnosis())
-                        .map(|age_at_diagnosis| vec![age_at_diagnosis.to_string()]),
-                    "age_at_collection" => sample
-                        .metadata()
-                        .and_then(|metadata| metadata.age_at_collection())
-                        .map(|age_at_collection| vec![age_at_collection.to_string()]),
-                    "tumor_tissue_morphology" => sample
-                        .metadata()
-                        .and_then(|metadata| metadata.tumor_tissue_morphology())
-                        .map(|tumor_tissue_morphology| vec![tumor_tissue_morphology.to_string()]),
-                    "depositions" => sample
-                        .metadata()
-                        .and_then(|metadata| metadata.common().depositions())
-                        .map(|deposition| {
-                            deposition
-                                .iter()
-                                .cloned()
-                                .map(|accession| match accession {
-                                    Accession::dbGaP(accession) => accession.to_string(),
-                                })
-                                .collect::<Vec<String>>()
-                        }),
-                    "diagnosis" => sample
-                        .metadata()
-                        .and_then(|metadata| metadata.diagnosis())
-                        .map(|diagnosis| vec![diagnosis.to_string()]),
-                    _ => unreachable!("unhandled sample metadata field: {field}"),
-                };
-
-                match values {
-                    Some(values) => values.into_iter().any(|s| s.eq(query)),
-                    // Samples with no values for this field are automatically
-                    // filtered as described in the rules for filtering.
-                    None => false,
-                }
+                // Samples with no values for this field are handled as
+                // described in the rules for filtering (including negation).
+                strategy.evaluate_with_case(sample.field_values(&field).as_deref(), query, case)
             })
             .collect::<Vec<_>>()
     }
 }
+
+impl FieldValues for Sample {
+    fn field_values(&self, field: &str) -> Option<Vec<String>> {
+        match field {
+            "anatomical_sites" => self
+                .metadata()
+                .and_then(|metadata| metadata.anatomical_sites())
+                .map(|sites| {
+                    sites
+                        .iter()
+                        .map(|site| site.to_string())
+                        .collect::<Vec<_>>()
+                }),
+            "diagnosis_category" => self
+                .metadata()
+                .and_then(|metadata| metadata.diagnosis_category())
+                .map(|diagnosis_category| vec![diagnosis_category.to_string()]),
+            "disease_phase" => self
+                .metadata()
+                .and_then(|metadata| metadata.disease_phase())
+                .map(|disease_phase| vec![disease_phase.to_string()]),
+            "library_selection_method" => self
+                .metadata()
+                .and_then(|metadata| metadata.library_selection_method())
+                .map(|library_selection_method| vec![library_selection_method.to_string()]),
+            "library_strategy" => self
+                .metadata()
+                .and_then(|metadata| metadata.library_strategy())
+                .map(|library_strategy| vec![library_strategy.to_string()]),
+            "library_source_material" => self
+                .metadata()
+                .and_then(|metadata| metadata.library_source_material())
+                .map(|library_source_material| vec![library_source_material.to_string()]),
+            "preservation_method" => self
+                .metadata()
+                .and_then(|metadata| metadata.preservation_method())
+                .map(|preservation_method| vec![preservation_method.to_string()]),
+            "tumor_grade" => self
+                .metadata()
+                .and_then(|metadata| metadata.tumor_grade())
+                .map(|tumor_grade| vec![tumor_grade.to_string()]),
+            "specimen_molecular_analyte_type" => self
+                .metadata()
+                .and_then(|metadata| metadata.specimen_molecular_analyte_type())
+                .map(|specimen_molecular_analyte_type| {
+                    vec![specimen_molecular_analyte_type.to_string()]
+                }),
+            "tissue_type" => self
+                .metadata()
+                .and_then(|metadata| metadata.tissue_type())
+                .map(|tissue_type| vec![tissue_type.to_string()]),
+            "tumor_classification" => self
+                .metadata()
+                .and_then(|metadata| metadata.tumor_classification())
+                .map(|tumor_classification| vec![tumor_classification.to_string()]),
+            "age_at_diagnosis" => self
+                .metadata()
+                .and_then(|metadata| metadata.age_at_diagnosis())
+                .map(|age_at_diagnosis| vec![age_at_diagnosis.to_string()]),
+            "age_at_collection" => self
+                .metadata()
+                .and_then(|metadata| metadata.age_at_collection())
+                .map(|age_at_collection| vec![age_at_collection.to_string()]),
+            "tumor_tissue_morphology" => self
+                .metadata()
+                .and_then(|metadata| metadata.tumor_tissue_morphology())
+                .map(|tumor_tissue_morphology| vec![tumor_tissue_morphology.to_string()]),
+            "tumor_tissue_site" => self
+                .metadata()
+                .and_then(|metadata| metadata.tumor_tissue_site())
+                .map(|tumor_tissue_site| vec![tumor_tissue_site.to_string()]),
+            "identifiers" => self
+                .metadata()
+                .and_then(|metadata| metadata.identifiers())
+                .map(|identifiers| {
+                    identifiers
+                        .iter()
+                        .map(|identifier| identifier.to_string())
+                        .collect::<Vec<_>>()
+                }),
+            "depositions" => scope::depositions(
+                self.id().namespace(),
+                self.metadata()
+                    .and_then(|metadata| metadata.common().depositions()),
+            ),
+            "diagnosis" => self
+                .metadata()
+                .and_then(|metadata| metadata.diagnosis())
+                .map(|diagnosis| vec![diagnosis.to_string()]),
+            "has_metadata" => Some(vec![self.has_metadata().to_string()]),
+            "namespace" => Some(vec![scope::namespace(self.id().namespace())]),
+            "organization" => Some(vec![scope::organization(self.id().namespace())]),
+            _ => unreachable!("unhandled sample metadata field: {field}"),
+        }
+    }
+}
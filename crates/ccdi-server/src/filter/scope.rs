@@ -0,0 +1,289 @@
+//! Scoping entities to a namespace or an organization.
+//!
+//! Every entity is identified within a namespace, and every namespace is
+//! owned by an organization. The `namespace` and `organization` filter
+//! parameters are matched exactly against the namespace of an entity's
+//! identifier (formatted as `{organization}/{namespace}`) and the organization
+//! that owns it, respectively.
+//!
+//! Unlike the metadata fields, the possible values of these parameters are
+//! known by the server, so a value that is not known is reported as an error
+//! (listing the values that are known) rather than silently matching nothing.
+//!
+//! Namespaces may also describe a study that has been deposited within dbGaP,
+//! in which case the accession of the study applies to every entity within the
+//! namespace (see [`depositions()`]).
+
+use nonempty::NonEmpty;
+
+use ccdi_models as models;
+
+use models::metadata::common::deposition::Accession;
+use models::namespace;
+
+use crate::filter::rule::Alternatives;
+use crate::filter::rule::Case;
+use crate::filter::rule::Query;
+use crate::filter::rule::Strategy;
+use crate::responses::error;
+use crate::routes::namespace::known;
+use crate::routes::namespace::NAMESPACES;
+use crate::routes::organization::ORGANIZATIONS;
+
+/// The name of the parameter that scopes entities to a namespace.
+pub const NAMESPACE: &str = "namespace";
+
+/// The name of the parameter that scopes entities to an organization.
+pub const ORGANIZATION: &str = "organization";
+
+/// Gets the value of a namespace as it is matched by the `namespace`
+/// parameter (`{organization}/{namespace}`).
+///
+/// # Examples
+///
+/// ```
+/// use ccdi_server as server;
+///
+/// use server::filter::scope::namespace;
+/// use server::routes::namespace::NAMESPACES;
+///
+/// let example = NAMESPACES.values().next().unwrap();
+/// assert_eq!(
+///     namespace(example.id()),
+///     "example-organization/ExampleNamespaceOne"
+/// );
+/// ```
+pub fn namespace(namespace: &namespace::Identifier) -> String {
+    format!(
+        "{}/{}",
+        namespace.organization().as_str(),
+        namespace.name().as_str()
+    )
+}
+
+/// Gets the value of a namespace as it is matched by the `organization`
+/// parameter (the identifier of the organization that owns the namespace).
+///
+/// # Examples
+///
+/// ```
+/// use ccdi_server as server;
+///
+/// use server::filter::scope::organization;
+/// use server::routes::namespace::NAMESPACES;
+///
+/// let example = NAMESPACES.values().next().unwrap();
+/// assert_eq!(organization(example.id()), "example-organization");
+/// ```
+pub fn organization(namespace: &namespace::Identifier) -> String {
+    namespace.organization().as_str().to_string()
+}
+
+/// Gets the values of the depositions of an entity as they are matched by the
+/// `depositions` parameter.
+///
+/// The values include the accessions deposited for the entity itself along
+/// with the dbGaP accession of the study described by the namespace of the
+/// entity (if the namespace describes a study with an accession). If neither
+/// exist, `None` is returned.
+///
+/// # Examples
+///
+/// ```
+/// use ccdi_cde as cde;
+/// use ccdi_models as models;
+/// use ccdi_server as server;
+/// use nonempty::NonEmpty;
+///
+/// use models::metadata::common::deposition::Accession;
+/// use server::filter::scope::depositions;
+/// use server::routes::namespace::NAMESPACES;
+///
+/// let one = NAMESPACES
+///     .get("example-organization-namespace-one")
+///     .unwrap();
+/// let two = NAMESPACES
+///     .get("example-organization-namespace-two")
+///     .unwrap();
+///
+/// let accessions = NonEmpty::new(Accession::dbGaP(
+///     cde::v1::deposition::DbgapPhsAccession::from(String::from("phs000001")),
+/// ));
+///
+/// assert_eq!(
+///     depositions(one.id(), Some(&accessions)),
+///     Some(vec![
+///         String::from("phs000001"),
+///         String::from("phs000000.v1.p1")
+///     ])
+/// );
+/// assert_eq!(
+///     depositions(one.id(), None),
+///     Some(vec![String::from("phs000000.v1.p1")])
+/// );
+/// assert_eq!(
+///     depositions(two.id(), Some(&accessions)),
+///     Some(vec![String::from("phs000001")])
+/// );
+/// assert_eq!(depositions(two.id(), None), None);
+/// ```
+pub fn depositions(
+    id: &namespace::Identifier,
+    depositions: Option<&NonEmpty<Accession>>,
+) -> Option<Vec<String>> {
+    let mut values = depositions
+        .map(|depositions| {
+            depositions
+                .iter()
+                .map(|accession| match accession {
+                    Accession::dbGaP(accession) => accession.to_string(),
+                })
+                .collect::<Vec<_>>()
+        })
+        .unwrap_or_default();
+
+    let study = NAMESPACES
+        .values()
+        .find(|namespace| namespace.id() == id)
+        .and_then(|namespace| namespace.study())
+        .and_then(|study| study.accession())
+        .map(|accession| accession.to_string());
+
+    if let Some(accession) = study {
+        if !values.contains(&accession) {
+            values.push(accession);
+        }
+    }
+
+    (!values.is_empty()).then_some(values)
+}
+
+/// Gets the values within a query that must be known by the server.
+///
+/// `not:null` has no values, and queries that are not valid are skipped (they
+/// are reported by the validation of the matching strategy instead).
+fn values(query: &str) -> Vec<String> {
+    let query = match Query::parse(query) {
+        Query::Positive(query) | Query::Negated(query) => query,
+        Query::NotNull => return Vec::new(),
+    };
+
+    match Alternatives::parse(query) {
+        Ok(alternatives) => alternatives.iter().map(String::from).collect(),
+        Err(_) => Vec::new(),
+    }
+}
+
+/// Checks that every namespace and organization provided to the scoping
+/// parameters is known by the server.
+///
+/// An unknown namespace is reported as an
+/// [`UnknownNamespace`](error::kind::Inner::UnknownNamespace) error (listing
+/// the known namespaces), and an unknown organization is reported as an
+/// [`InvalidParameters`](error::kind::Inner::InvalidParameters) error
+/// (listing the known organizations). The names of the parameters within the
+/// error are prefixed with `prefix`.
+///
+/// # Examples
+///
+/// ```
+/// use ccdi_server as server;
+///
+/// use server::filter::scope::check;
+///
+/// assert!(check(
+///     Some("example-organization/ExampleNamespaceOne"),
+///     Some("example-organization"),
+///     ""
+/// )
+/// .is_ok());
+/// assert!(check(Some("not:null"), None, "").is_ok());
+///
+/// let err = check(Some("example-organization/ExampleNamespaceOn"), None, "").unwrap_err();
+/// assert!(err
+///     .to_string()
+///     .contains("did you mean 'example-organization/ExampleNamespaceOne'?"));
+///
+/// let err = check(None, Some("another-organization"), "sample.").unwrap_err();
+/// assert!(err.to_string().contains("sample.organization"));
+/// assert!(err.to_string().contains("`example-organization`"));
+/// ```
+pub fn check(
+    namespace: Option<&str>,
+    organization: Option<&str>,
+    prefix: &str,
+) -> Result<(), error::Kind> {
+    let known_namespaces = known();
+
+    for value in namespace.map(values).unwrap_or_default() {
+        if !known_namespaces.contains(&value) {
+            return Err(error::Kind::unknown_namespace(value, known_namespaces));
+        }
+    }
+
+    for value in organization.map(values).unwrap_or_default() {
+        if !ORGANIZATIONS.contains_key(value.as_str()) {
+            let known = ORGANIZATIONS
+                .keys()
+                .map(|organization| format!("`{organization}`"))
+                .collect::<Vec<_>>()
+                .join(", ");
+
+            return Err(error::Kind::invalid_parameters(
+                Some(vec![format!("{prefix}{ORGANIZATION}")]),
+                format!("unknown organization `{value}` (expected one of {known})"),
+            ));
+        }
+    }
+
+    Ok(())
+}
+
+/// Gets whether a namespace is within the scope described by the scoping
+/// parameters.
+///
+/// Both parameters are matched with the [`Strategy::Exact`] strategy (so they
+/// may be negated or provided as a JSON array), and a namespace is within
+/// scope when it matches every parameter that is provided.
+///
+/// # Examples
+///
+/// ```
+/// use ccdi_server as server;
+///
+/// use server::filter::scope::contains;
+/// use server::routes::namespace::NAMESPACES;
+///
+/// let namespace = NAMESPACES.values().next().unwrap().id();
+///
+/// assert!(contains(None, None, namespace));
+/// assert!(contains(
+///     Some("example-organization/ExampleNamespaceOne"),
+///     Some("example-organization"),
+///     namespace
+/// ));
+/// assert!(!contains(
+///     Some("example-organization/ExampleNamespaceTwo"),
+///     None,
+///     namespace
+/// ));
+/// assert!(!contains(
+///     Some("not:example-organization/ExampleNamespaceOne"),
+///     None,
+///     namespace
+/// ));
+/// ```
+pub fn contains(
+    namespace: Option<&str>,
+    organization: Option<&str>,
+    id: &namespace::Identifier,
+) -> bool {
+    let matches = |query: Option<&str>, value: String| match query {
+        Some(query) => {
+            Strategy::Exact.evaluate_with_case(Some(&[value][..]), query, Case::Sensitive)
+        }
+        None => true,
+    };
+
+    matches(namespace, self::namespace(id)) && matches(organization, self::organization(id))
+}
@@ -2,14 +2,62 @@
 
 use ccdi_models as models;
 
-use models::metadata::common::deposition::Accession;
 use models::Subject;
 
+use crate::filter::alias::Aliases;
+use crate::filter::rule::Case;
+use crate::filter::rule::Rules;
+use crate::filter::rule::Strategy;
+use crate::filter::scope;
+use crate::filter::FieldValues;
 use crate::filter::FilterMetadataField;
 use crate::params::filter::Subject as FilterSubjectParams;
 
+impl Aliases for FilterSubjectParams {
+    const ALIASES: &'static [(&'static str, &'static str)] = &[
+        ("race", "races"),
+        ("identifiers", "identifier"),
+        ("depositions", "deposition"),
+    ];
+}
+
+impl Rules for FilterSubjectParams {
+    const RULES: &'static [(&'static str, Strategy)] = &[
+        ("sex", Strategy::Exact),
+        ("race", Strategy::AnyExact),
+        ("ethnicity", Strategy::Exact),
+        ("identifiers", Strategy::AnyExact),
+        ("vital_status", Strategy::Exact),
+        ("age_at_vital_status", Strategy::Range),
+        ("depositions", Strategy::AnyExact),
+        ("has_metadata", Strategy::Boolean),
+        ("namespace", Strategy::Exact),
+        ("organization", Strategy::Exact),
+    ];
+
+    const PROVENANCE: &'static [&'static str] = &[
+        "sex",
+        "race",
+        "ethnicity",
+        "identifiers",
+        "vital_status",
+        "age_at_vital_status",
+    ];
+}
+
 impl FilterMetadataField<Subject, FilterSubjectParams> for Vec<Subject> {
-    fn filter_metadata_field(self, field: String, params: &FilterSubjectParams) -> Vec<Subject> {
+    fn filter_metadata_field(
+        self,
+        field: String,
+        params: &FilterSubjectParams,
+        case: Case,
+    ) -> Vec<Subject> {
+        // Boolean parameters are matched against the string representation of
+        // the value (`true` or `false`).
+        let has_metadata = params
+            .has_metadata
+            .map(|has_metadata| has_metadata.to_string());
+
         let parameter = match field.as_str() {
             "sex" => params.sex.as_ref(),
             "race" => params.race.as_ref(),
@@ -18,9 +66,15 @@ impl FilterMetadataField<Subject, FilterSubjectParams> for Vec<Subject> {
             "vital_status" => params.vital_status.as_ref(),
             "age_at_vital_status" => params.age_at_vital_status.as_ref(),
             "depositions" => params.depositions.as_ref(),
+            "has_metadata" => has_metadata.as_ref(),
+            "namespace" => params.namespace.as_ref(),
+            "organization" => params.organization.as_ref(),
             _ => unreachable!("unhandled subject metadata field: {field}"),
         };
 
+        let strategy = FilterSubjectParams::strategy(&field)
+            .unwrap_or_else(|| unreachable!("unhandled subject filter rule: {field}"));
+
         let query = match parameter {
             Some(query) => query,
             // If the parameter has no value, just return the original list of
@@ -30,58 +84,55 @@ impl FilterMetadataField<Subject, FilterSubjectParams> for Vec<Subject> {
 
         self.into_iter()
             .filter(|subject| {
-                let values: Option<Vec<String>> = match field.as_str() {
-                    "sex" => subject
-                        .metadata()
-                        .and_then(|metadata| metadata.sex())
-                        .map(|sex| vec![sex.to_string()]),
-                    "race" => subject
-                        .metadata()
-                        .and_then(|metadata| metadata.race())
-                        .map(|race| race.iter().map(|r| r.to_string()).collect::<Vec<String>>()),
-                    "ethnicity" => subject
-                        .metadata()
-                        .and_then(|metadata| metadata.ethnicity())
-                        .map(|ethnicity| vec![ethnicity.to_string()]),
-                    "identifiers" => subject
-                        .metadata()
-                        .and_then(|metadata| metadata.identifiers())
-                        .map(|identifiers| {
-                            identifiers
-                                .iter()
-                                .map(|r| r.to_string())
-                                .collect::<Vec<String>>()
-                        }),
-                    "vital_status" => subject
-                        .metadata()
-                        .and_then(|metadata| metadata.vital_status())
-                        .map(|vital_status| vec![vital_status.to_string()]),
-                    "age_at_vital_status" => subject
-                        .metadata()
-                        .and_then(|metadata| metadata.age_at_vital_status())
-                        .map(|age_at_vital_status| vec![age_at_vital_status.to_string()]),
-                    "depositions" => subject
-                        .metadata()
-                        .and_then(|metadata| metadata.common().depositions())
-                        .map(|deposition| {
-                            deposition
-                                .iter()
-                                .cloned()
-                                .map(|accession| match accession {
-                                    Accession::dbGaP(accession) => accession.to_string(),
-                                })
-                                .collect::<Vec<String>>()
-                        }),
-                    _ => unreachable!("unhandled subject metadata field: {field}"),
-                };
-
-                match values {
-                    Some(values) => values.into_iter().any(|s| s.eq(query)),
-                    // Subjects with no values for this field are automatically
-                    // filtered as described in the rules for filtering.
-                    None => false,
-                }
+                // Subjects with no values for this field are handled as
+                // described in the rules for filtering (including negation).
+                strategy.evaluate_with_case(subject.field_values(&field).as_deref(), query, case)
             })
             .collect::<Vec<_>>()
     }
 }
+
+impl FieldValues for Subject {
+    fn field_values(&self, field: &str) -> Option<Vec<String>> {
+        match field {
+            "sex" => self
+                .metadata()
+                .and_then(|metadata| metadata.sex())
+                .map(|sex| vec![sex.to_string()]),
+            "race" => self
+                .metadata()
+                .and_then(|metadata| metadata.race())
+                .map(|race| race.iter().map(|r| r.to_string()).collect::<Vec<String>>()),
+            "ethnicity" => self
+                .metadata()
+                .and_then(|metadata| metadata.ethnicity())
+                .map(|ethnicity| vec![ethnicity.to_string()]),
+            "identifiers" => self
+                .metadata()
+                .and_then(|metadata| metadata.identifiers())
+                .map(|identifiers| {
+                    identifiers
+                        .iter()
+                        .map(|r| r.to_string())
+                        .collect::<Vec<String>>()
+                }),
+            "vital_status" => self
+                .metadata()
+                .and_then(|metadata| metadata.vital_status())
+                .map(|vital_status| vec![vital_status.to_string()]),
+            "age_at_vital_status" => self
+                .metadata()
+                .and_then(|metadata| metadata.age_at_vital_status())
+                .map(|age_at_vital_status| vec![age_at_vital_status.to_string()]),
+            "depositions" => scope::depositions(
+                self.id().namespace(),
+                self.metadata()
+                    .and_then(|metadata| metadata.common().depositions()),
+            ),
+            "has_metadata" => Some(vec![self.has_metadata().to_string()]),
+            "namespace" => Some(vec![scope::namespace(self.id().namespace())]),
+            "organization" => Some(vec![scope::organization(self.id().namespace())]),
+            _ => unreachable!("unhandled subject metadata field: {field}"),
+        }
+    }
+}
@@ -2,17 +2,35 @@
 
 use ccdi_models as models;
 
-use models::metadata::common::deposition::Accession;
 use models::Subject;
 
+use crate::filter::rule::Case;
+use crate::filter::rule::Rules;
+use crate::filter::rule::Strategy;
+use crate::filter::scope;
 use crate::filter::FilterMetadataField;
 use crate::params::filter::SubjectDiagnosis as FilterSubjectDiagnosisParams;
 
+impl Rules for FilterSubjectDiagnosisParams {
+    const RULES: &'static [(&'static str, Strategy)] = &[
+        ("search", Strategy::AnySubstringIgnoreCase),
+        ("sex", Strategy::Exact),
+        ("race", Strategy::AnyExact),
+        ("ethnicity", Strategy::Exact),
+        ("identifiers", Strategy::AnyExact),
+        ("vital_status", Strategy::Exact),
+        ("age_at_vital_status", Strategy::Exact),
+        ("depositions", Strategy::AnyExact),
+        ("associated_diagnosis_categories", Strategy::AnyExact),
+    ];
+}
+
 impl FilterMetadataField<Subject, FilterSubjectDiagnosisParams> for Vec<Subject> {
     fn filter_metadata_field(
         self,
         field: String,
         params: &FilterSubjectDiagnosisParams,
+        case: Case,
     ) -> Vec<Subject> {
         let parameter = match field.as_str() {
             "sex" => params.sex.as_ref(),
@@ -26,6 +44,9 @@ impl FilterMetadataField<Subject, FilterSubjectDiagnosisParams> for Vec<Subject>
             _ => unreachable!("unhandled subject metadata field: {field}"),
         };
 
+        let strategy = FilterSubjectDiagnosisParams::strategy(&field)
+            .unwrap_or_else(|| unreachable!("unhandled subject filter rule: {field}"));
+
         let query = match parameter {
             Some(query) => query,
             // If the parameter has no value, just return the original list of
@@ -35,95 +56,67 @@ impl FilterMetadataField<Subject, FilterSubjectDiagnosisParams> for Vec<Subject>
 
         self.into_iter()
             .filter(|subject| {
-                if field.as_str() == "search" {
-                    if let Some(metadata) = subject.metadata() {
-                        if let Some(associated_diagnoses) = metadata.associated_diagnoses() {
-                            // Only return the entry if the query is a substring
-                            // of at least one of the associated_diagnoses, ignoring case.
-                            // Matching on to_lowercase is an approximation and will not cover
-                            // all unicode characters.
-                            let query_lower = query.to_string().to_lowercase();
-
-                            let diagnoses_lower = associated_diagnoses
+                let values: Option<Vec<String>> = match field.as_str() {
+                    "sex" => subject
+                        .metadata()
+                        .and_then(|metadata| metadata.sex())
+                        .map(|sex| vec![sex.to_string()]),
+                    "race" => subject
+                        .metadata()
+                        .and_then(|metadata| metadata.race())
+                        .map(|race| race.iter().map(|r| r.to_string()).collect::<Vec<String>>()),
+                    "ethnicity" => subject
+                        .metadata()
+                        .and_then(|metadata| metadata.ethnicity())
+                        .map(|ethnicity| vec![ethnicity.to_string()]),
+                    "identifiers" => subject
+                        .metadata()
+                        .and_then(|metadata| metadata.identifiers())
+                        .map(|identifiers| {
+                            identifiers
                                 .iter()
-                                .map(|r| r.value().to_string().to_lowercase())
-                                .collect::<Vec<String>>();
-
-                            return diagnoses_lower
+                                .map(|r| r.to_string())
+                                .collect::<Vec<String>>()
+                        }),
+                    "vital_status" => subject
+                        .metadata()
+                        .and_then(|metadata| metadata.vital_status())
+                        .map(|vital_status| vec![vital_status.to_string()]),
+                    "age_at_vital_status" => subject
+                        .metadata()
+                        .and_then(|metadata| metadata.age_at_vital_status())
+                        .map(|age_at_vital_status| vec![age_at_vital_status.to_string()]),
+                    "depositions" => scope::depositions(
+                        subject.id().namespace(),
+                        subject
+                            .metadata()
+                            .and_then(|metadata| metadata.common().depositions()),
+                    ),
+                    "associated_diagnosis_categories" => subject
+                        .metadata()
+                        .and_then(|metadata| metadata.associated_diagnosis_categories())
+                        .map(|categories_vec| {
+                            categories_vec
+                                .iter()
+                                .flat_map(|cat| cat.value().iter()) // unwrap the Vec<DiagnosisCategory>
+                                .map(|diag_cat| diag_cat.to_string())
+                                .collect::<Vec<String>>()
+                        }),
+                    "search" => subject
+                        .metadata()
+                        .and_then(|metadata| metadata.associated_diagnoses())
+                        .map(|associated_diagnoses| {
+                            associated_diagnoses
                                 .iter()
-                                .any(|diagnosis_lower| diagnosis_lower.contains(&query_lower));
-                        }
-                        // If the metadata doesn't have associated_diagnoses, the entry
-                        // should not be included.
-                        return false;
-                    }
-                    // If no metadata is included, this entry should not be
-                    // included.
-                    false
-                } else {
-                    // All other "non-search" fields require an exact case-sensitive match.
-                    let values: Option<Vec<String>> =
-                        match field.as_str() {
-                            "sex" => subject
-                                .metadata()
-                                .and_then(|metadata| metadata.sex())
-                                .map(|sex| vec![sex.to_string()]),
-                            "race" => subject.metadata().and_then(|metadata| metadata.race()).map(
-                                |race| race.iter().map(|r| r.to_string()).collect::<Vec<String>>(),
-                            ),
-                            "ethnicity" => subject
-                                .metadata()
-                                .and_then(|metadata| metadata.ethnicity())
-                                .map(|ethnicity| vec![ethnicity.to_string()]),
-                            "identifiers" => subject
-                                .metadata()
-                                .and_then(|metadata| metadata.identifiers())
-                                .map(|identifiers| {
-                                    identifiers
-                                        .iter()
-                                        .map(|r| r.to_string())
-                                        .collect::<Vec<String>>()
-                                }),
-                            "vital_status" => subject
-                                .metadata()
-                                .and_then(|metadata| metadata.vital_status())
-                                .map(|vital_status| vec![vital_status.to_string()]),
-                            "age_at_vital_status" => subject
-                                .metadata()
-                                .and_then(|metadata| metadata.age_at_vital_status())
-                                .map(|age_at_vital_status| vec![age_at_vital_status.to_string()]),
-                            "depositions" => subject
-                                .metadata()
-                                .and_then(|metadata| metadata.common().depositions())
-                                .map(|deposition| {
-                                    deposition
-                                        .iter()
-                                        .cloned()
-                                        .map(|accession| match accession {
-                                            Accession::dbGaP(accession) => accession.to_string(),
-                                        })
-                                        .collect::<Vec<String>>()
-                                }),
-                            "associated_diagnosis_categories" => subject
-                                .metadata()
-                                .and_then(|metadata| metadata.associated_diagnosis_categories())
-                                .map(|categories_vec| {
-                                    categories_vec
-                                        .iter()
-                                        .flat_map(|cat| cat.value().iter()) // unwrap the Vec<DiagnosisCategory>
-                                        .map(|diag_cat| diag_cat.to_string())
-                                        .collect::<Vec<String>>()
-                                }),
-                            _ => unreachable!("unhandled subject metadata field: {field}"),
-                        };
+                                .map(|diagnosis| diagnosis.value().to_string())
+                                .collect::<Vec<String>>()
+                        }),
+                    _ => unreachable!("unhandled subject metadata field: {field}"),
+                };
 
-                    match values {
-                        Some(values) => values.into_iter().any(|s| s.eq(query)),
-                        // Subjects with no values for this field are automatically
-                        // filtered as described in the rules for filtering.
-                        None => false,
-                    }
-                }
+                // Subjects with no values for this field are handled as
+                // described in the rules for filtering (including negation).
+                strategy.evaluate_with_case(values.as_deref(), query, case)
             })
             .collect::<Vec<_>>()
     }
@@ -0,0 +1,373 @@
+//! Filtering entities by their unharmonized metadata fields.
+//!
+//! Unharmonized fields are filtered with query parameters prefixed with
+//! `metadata.unharmonized.` (e.g., `?metadata.unharmonized.consortium_id=A`).
+//! Because unharmonized fields are not known ahead of time, they are not
+//! declared within any set of filter parameters: instead, the queries are read
+//! from the raw query string (see [`queries()`]) and matched against the
+//! serialized metadata of each entity (see [`filter()`]).
+//!
+//! Every unharmonized field is matched with the [`Strategy::AnyExact`]
+//! strategy. A scalar query must exactly match the value of the field (or any
+//! of the values of a field with multiple values), and a JSON array query
+//! (e.g., `?metadata.unharmonized.consortium_id=["A","B"]`) matches if _any_
+//! of its values do. Negation and case-insensitive matching work exactly as
+//! they do for harmonized fields.
+//!
+//! If the server has registered the unharmonized fields it emits (see
+//! [`UnharmonizedRegistry`]), filtering on a field that was not registered is
+//! still allowed, but it is logged as a warning (see [`warn_unregistered()`]).
+
+use log::warn;
+use serde::Serialize;
+use serde_json::Value;
+use url::form_urlencoded;
+
+use ccdi_models as models;
+
+use models::metadata::field::description::UnharmonizedRegistry;
+
+use crate::filter::rule::Case;
+use crate::filter::rule::Strategy;
+use crate::filter::UNHARMONIZED_PREFIX;
+use crate::responses::error;
+
+/// The match [`Strategy`] for every unharmonized field.
+const STRATEGY: Strategy = Strategy::AnyExact;
+
+/// A query for an unharmonized field.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct UnharmonizedQuery {
+    /// The key of the unharmonized field.
+    field: String,
+
+    /// The raw value of the query.
+    query: String,
+}
+
+impl UnharmonizedQuery {
+    /// Gets the key of the unharmonized field from the [`UnharmonizedQuery`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ccdi_server as server;
+    ///
+    /// use server::filter::unharmonized::queries;
+    ///
+    /// let queries = queries("metadata.unharmonized.consortium_id=A").unwrap();
+    /// assert_eq!(queries.first().unwrap().field(), "consortium_id");
+    /// ```
+    pub fn field(&self) -> &str {
+        self.field.as_str()
+    }
+
+    /// Gets the raw value of the query from the [`UnharmonizedQuery`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ccdi_server as server;
+    ///
+    /// use server::filter::unharmonized::queries;
+    ///
+    /// let queries = queries(r#"metadata.unharmonized.consortium_id=["A","B"]"#).unwrap();
+    /// assert_eq!(queries.first().unwrap().query(), r#"["A","B"]"#);
+    /// ```
+    pub fn query(&self) -> &str {
+        self.query.as_str()
+    }
+}
+
+/// Gets each [`UnharmonizedQuery`] within a raw `query` string.
+///
+/// All other parameters are ignored. An
+/// [`InvalidParameters`](error::Kind::invalid_parameters) error is returned if
+/// any of the queries are invalid (e.g., an array that contains an object).
+///
+/// # Examples
+///
+/// ```
+/// use ccdi_server as server;
+///
+/// use server::filter::unharmonized::queries;
+///
+/// let parsed = queries("sex=F&metadata.unharmonized.consortium_id=A").unwrap();
+/// assert_eq!(parsed.len(), 1);
+///
+/// let err = queries(r#"metadata.unharmonized.consortium_id=[{"id":"A"}]"#).unwrap_err();
+/// assert_eq!(
+///     err.to_string(),
+///     "Invalid value for parameter 'metadata.unharmonized.consortium_id': an array may \
+///      only contain strings, numbers, or booleans, but `{\"id\":\"a\"}` was provided."
+/// );
+/// ```
+pub fn queries(query: &str) -> Result<Vec<UnharmonizedQuery>, error::Kind> {
+    let mut queries = Vec::new();
+
+    for (key, value) in form_urlencoded::parse(query.as_bytes()) {
+        let field = match key.strip_prefix(UNHARMONIZED_PREFIX) {
+            Some(field) => field,
+            None => continue,
+        };
+
+        STRATEGY.validate(&value).map_err(|err| {
+            error::Kind::invalid_parameters(Some(vec![key.to_string()]), err.to_string())
+        })?;
+
+        queries.push(UnharmonizedQuery {
+            field: field.to_string(),
+            query: value.to_string(),
+        });
+    }
+
+    Ok(queries)
+}
+
+/// Filters a list of entities to those that satisfy every
+/// [`UnharmonizedQuery`] with regard to the provided [`Case`].
+///
+/// # Examples
+///
+/// ```
+/// use ccdi_models as models;
+/// use ccdi_server as server;
+/// use serde_json::Value;
+///
+/// use models::metadata::field::unowned::Field;
+/// use models::metadata::field::UnharmonizedField;
+/// use models::subject::metadata::Builder;
+/// use models::subject::Identifier;
+/// use models::subject::Kind;
+/// use models::Subject;
+/// use server::filter::rule::Case;
+/// use server::filter::unharmonized::filter;
+/// use server::filter::unharmonized::queries;
+///
+/// let namespace = models::namespace::Identifier::new(
+///     "example-organization"
+///         .parse::<models::organization::Identifier>()
+///         .unwrap(),
+///     "ExampleNamespace"
+///         .parse::<models::namespace::identifier::Name>()
+///         .unwrap(),
+/// );
+///
+/// let subject = |name: &str, consortium_id: &str| {
+///     Subject::new(
+///         Identifier::new(namespace.clone(), name),
+///         Kind::Participant,
+///         None,
+///         Some(
+///             Builder::default()
+///                 .insert_unharmonized(
+///                     "consortium_id",
+///                     UnharmonizedField::Unowned(Field::new(
+///                         Value::from(consortium_id),
+///                         None,
+///                         None,
+///                         None,
+///                     )),
+///                 )
+///                 .unwrap()
+///                 .build(),
+///         ),
+///     )
+/// };
+///
+/// let subjects = vec![
+///     subject("Subject1", "A"),
+///     subject("Subject2", "B"),
+///     subject("Subject3", "C"),
+/// ];
+///
+/// let queries = queries(r#"metadata.unharmonized.consortium_id=["A","B"]"#).unwrap();
+/// let results = filter(subjects, &queries, Case::Sensitive);
+/// assert_eq!(results.len(), 2);
+/// ```
+pub fn filter<T>(entities: Vec<T>, queries: &[UnharmonizedQuery], case: Case) -> Vec<T>
+where
+    T: Serialize,
+{
+    if queries.is_empty() {
+        return entities;
+    }
+
+    entities
+        .into_iter()
+        .filter(|entity| {
+            // SAFETY: entities are always serializable to JSON.
+            let entity = serde_json::to_value(entity).unwrap();
+
+            queries.iter().all(|query| {
+                let values = values(&entity["metadata"]["unharmonized"][query.field()]["value"]);
+                STRATEGY.evaluate_with_case(values.as_deref(), query.query(), case)
+            })
+        })
+        .collect()
+}
+
+/// Logs a warning for each [`UnharmonizedQuery`] that filters on a field that
+/// is not within the `registry` and returns the keys of those fields.
+///
+/// Filtering on an unregistered field is not an error: the server may still
+/// emit fields that it did not register. If no `registry` is provided, no
+/// fields are considered to be unregistered.
+///
+/// # Examples
+///
+/// ```
+/// use ccdi_models as models;
+/// use ccdi_server as server;
+///
+/// use models::metadata::field::description::unharmonized::ValueKind;
+/// use models::metadata::field::description::UnharmonizedRegistry;
+/// use server::filter::unharmonized::queries;
+/// use server::filter::unharmonized::warn_unregistered;
+///
+/// let mut registry = UnharmonizedRegistry::default();
+/// registry
+///     .register(
+///         "consortium_id",
+///         "The consortium identifier.",
+///         ValueKind::String,
+///     )
+///     .unwrap();
+///
+/// let queries =
+///     queries("metadata.unharmonized.consortium_id=A&metadata.unharmonized.batch=1").unwrap();
+///
+/// assert_eq!(warn_unregistered(&queries, Some(&registry)), vec!["batch"]);
+/// assert!(warn_unregistered(&queries, None).is_empty());
+/// ```
+pub fn warn_unregistered<'a>(
+    queries: &'a [UnharmonizedQuery],
+    registry: Option<&UnharmonizedRegistry>,
+) -> Vec<&'a str> {
+    let registry = match registry {
+        Some(registry) => registry,
+        None => return Vec::new(),
+    };
+
+    let unregistered = queries
+        .iter()
+        .map(|query| query.field())
+        .filter(|field| !registry.contains(field))
+        .collect::<Vec<_>>();
+
+    for field in &unregistered {
+        warn!("filtering on the unregistered unharmonized field `{field}`");
+    }
+
+    unregistered
+}
+
+/// Gets the values of an unharmonized field (or [`None`] if the field has no
+/// value).
+///
+/// Each element of an array is a separate value. Objects (and `null` elements)
+/// cannot be matched, so they are ignored.
+fn values(value: &Value) -> Option<Vec<String>> {
+    match value {
+        Value::Null => None,
+        Value::Array(values) => Some(values.iter().filter_map(scalar).collect()),
+        value => scalar(value).map(|value| vec![value]),
+    }
+}
+
+/// Gets the string representation of a scalar value (if the value is a
+/// scalar).
+fn scalar(value: &Value) -> Option<String> {
+    match value {
+        Value::String(value) => Some(value.clone()),
+        Value::Number(_) | Value::Bool(_) => Some(value.to_string()),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn it_gets_the_values_of_a_field() {
+        assert_eq!(values(&json!("A")), Some(vec![String::from("A")]));
+        assert_eq!(values(&json!(42)), Some(vec![String::from("42")]));
+        assert_eq!(
+            values(&json!(["A", 1, { "id": "B" }, null])),
+            Some(vec![String::from("A"), String::from("1")])
+        );
+        assert_eq!(values(&json!({ "id": "A" })), None);
+        assert_eq!(values(&Value::Null), None);
+    }
+
+    #[test]
+    fn it_matches_arrays_of_queries() {
+        let entities = vec![
+            json!({ "metadata": { "unharmonized": { "consortium_id": { "value": "A" } } } }),
+            json!({ "metadata": { "unharmonized": { "consortium_id": { "value": ["B", "C"] } } } }),
+            json!({ "metadata": { "unharmonized": { "consortium_id": { "value": "D" } } } }),
+            json!({ "metadata": { "unharmonized": {} } }),
+            json!({ "metadata": null }),
+        ];
+
+        let matches = |query: &str, case: Case| {
+            let queries = queries(query).unwrap();
+            filter(entities.clone(), &queries, case).len()
+        };
+
+        assert_eq!(
+            matches("metadata.unharmonized.consortium_id=A", Case::Sensitive),
+            1
+        );
+        assert_eq!(
+            matches("metadata.unharmonized.consortium_id=a", Case::Sensitive),
+            0
+        );
+        assert_eq!(
+            matches("metadata.unharmonized.consortium_id=a", Case::Insensitive),
+            1
+        );
+        assert_eq!(
+            matches(
+                r#"metadata.unharmonized.consortium_id=["A","C"]"#,
+                Case::Sensitive
+            ),
+            2
+        );
+        assert_eq!(
+            matches(
+                r#"metadata.unharmonized.consortium_id=not:["A","C"]"#,
+                Case::Sensitive
+            ),
+            3
+        );
+        assert_eq!(
+            matches(
+                "metadata.unharmonized.consortium_id=not:null",
+                Case::Sensitive
+            ),
+            3
+        );
+        assert_eq!(matches("sex=F", Case::Sensitive), 5);
+    }
+
+    #[test]
+    fn it_rejects_arrays_of_non_scalars() {
+        for query in [
+            "metadata.unharmonized.consortium_id=[]",
+            r#"metadata.unharmonized.consortium_id=[["A"]]"#,
+            r#"metadata.unharmonized.consortium_id=["A",null]"#,
+        ] {
+            let err = queries(query).unwrap_err();
+            assert!(
+                err.to_string().starts_with(
+                    "Invalid value for parameter 'metadata.unharmonized.consortium_id'"
+                ),
+                "{query}"
+            );
+        }
+    }
+}
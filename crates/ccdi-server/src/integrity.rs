@@ -0,0 +1,349 @@
+//! Referential integrity checks across the entities held by the server.
+//!
+//! Samples refer to the subject from which they were taken, and files refer to
+//! the samples from which they were derived. Before serving, these references
+//! should be checked to ensure that every target exists (and that the
+//! namespaces of related entities agree) so that consumers walking the
+//! relationships never encounter a dangling reference.
+
+use std::collections::BTreeSet;
+
+use ccdi_models as models;
+
+use models::File;
+use models::Sample;
+use models::Subject;
+
+/// A violation of referential integrity.
+#[derive(Debug, Eq, PartialEq)]
+pub enum Violation {
+    /// A sample refers to a subject that does not exist.
+    MissingSubject {
+        /// The sample containing the reference.
+        sample: models::sample::Identifier,
+
+        /// The subject that does not exist.
+        subject: models::subject::Identifier,
+    },
+
+    /// A file refers to a sample that does not exist.
+    MissingSample {
+        /// The file containing the reference.
+        file: models::file::Identifier,
+
+        /// The sample that does not exist.
+        sample: models::sample::Identifier,
+    },
+
+    /// A sample belongs to a different namespace than the subject from which it
+    /// was taken.
+    SampleNamespaceMismatch {
+        /// The sample containing the reference.
+        sample: models::sample::Identifier,
+
+        /// The subject from which the sample was taken.
+        subject: models::subject::Identifier,
+    },
+
+    /// A file belongs to a different namespace than a sample from which it was
+    /// derived.
+    FileNamespaceMismatch {
+        /// The file containing the reference.
+        file: models::file::Identifier,
+
+        /// The sample from which the file was derived.
+        sample: models::sample::Identifier,
+    },
+}
+
+impl std::fmt::Display for Violation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Violation::MissingSubject { sample, subject } => {
+                write!(f, "sample {sample} refers to missing subject {subject}")
+            }
+            Violation::MissingSample { file, sample } => {
+                write!(f, "file {file} refers to missing sample {sample}")
+            }
+            Violation::SampleNamespaceMismatch { sample, subject } => write!(
+                f,
+                "sample {sample} is not in the same namespace as its subject {subject}"
+            ),
+            Violation::FileNamespaceMismatch { file, sample } => write!(
+                f,
+                "file {file} is not in the same namespace as its sample {sample}"
+            ),
+        }
+    }
+}
+
+/// An error indicating that one or more referential integrity [`Violation`]s
+/// were found.
+#[derive(Debug)]
+pub struct Error(Vec<Violation>);
+
+impl Error {
+    /// Gets the [`Violation`]s contained within the [`Error`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ccdi_models as models;
+    /// use ccdi_server as server;
+    ///
+    /// use models::namespace;
+    /// use models::sample;
+    /// use models::subject;
+    /// use models::Sample;
+    /// use server::integrity;
+    ///
+    /// let namespace = namespace::Identifier::new(
+    ///     "example-organization"
+    ///         .parse::<models::organization::Identifier>()
+    ///         .unwrap(),
+    ///     namespace::identifier::Name::try_new("ExampleNamespace").unwrap(),
+    /// );
+    ///
+    /// let sample = Sample::new(
+    ///     sample::Identifier::new(namespace.clone(), "Sample1"),
+    ///     subject::Identifier::new(namespace, "Subject1"),
+    ///     None,
+    ///     None,
+    /// );
+    ///
+    /// let err = integrity::check(&[], &[sample], &[]).unwrap_err();
+    /// assert_eq!(err.violations().len(), 1);
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn violations(&self) -> &[Violation] {
+        self.0.as_slice()
+    }
+}
+
+impl std::fmt::Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} referential integrity violation(s) found:",
+            self.0.len()
+        )?;
+
+        for violation in &self.0 {
+            write!(f, "\n  - {violation}")?;
+        }
+
+        Ok(())
+    }
+}
+
+impl std::error::Error for Error {}
+
+/// Checks the referential integrity of the provided entities.
+///
+/// The following are checked:
+///
+/// * Every sample's subject exists.
+/// * Every file's samples exist.
+/// * Every sample is within the same namespace as its subject.
+/// * Every file is within the same namespace as each of its samples.
+///
+/// All violations are reported (rather than only the first violation found).
+///
+/// # Examples
+///
+/// ```
+/// use ccdi_server as server;
+///
+/// use server::integrity;
+/// use server::routes::file;
+/// use server::routes::sample;
+/// use server::routes::subject;
+///
+/// let subjects = subject::Store::random(10);
+/// let samples = sample::Store::random(10, subjects.subjects.lock().unwrap());
+/// let files = file::Store::random(10, samples.samples.lock().unwrap());
+///
+/// integrity::check(
+///     &subjects.subjects.lock().unwrap(),
+///     &samples.samples.lock().unwrap(),
+///     &files.files.lock().unwrap(),
+/// )?;
+///
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub fn check(subjects: &[Subject], samples: &[Sample], files: &[File]) -> Result<(), Error> {
+    let subject_ids = subjects
+        .iter()
+        .map(|subject| subject.id())
+        .collect::<BTreeSet<_>>();
+
+    let sample_ids = samples
+        .iter()
+        .map(|sample| sample.id())
+        .collect::<BTreeSet<_>>();
+
+    let mut violations = Vec::new();
+
+    for sample in samples {
+        if !subject_ids.contains(sample.subject()) {
+            violations.push(Violation::MissingSubject {
+                sample: sample.id().clone(),
+                subject: sample.subject().clone(),
+            });
+        } else if sample.id().namespace() != sample.subject().namespace() {
+            violations.push(Violation::SampleNamespaceMismatch {
+                sample: sample.id().clone(),
+                subject: sample.subject().clone(),
+            });
+        }
+    }
+
+    for file in files {
+        for sample in file.samples().iter() {
+            if !sample_ids.contains(sample) {
+                violations.push(Violation::MissingSample {
+                    file: file.id().clone(),
+                    sample: sample.clone(),
+                });
+            } else if file.id().namespace() != sample.namespace() {
+                violations.push(Violation::FileNamespaceMismatch {
+                    file: file.id().clone(),
+                    sample: sample.clone(),
+                });
+            }
+        }
+    }
+
+    match violations.is_empty() {
+        true => Ok(()),
+        false => Err(Error(violations)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ccdi_cde as cde;
+    use nonempty::NonEmpty;
+
+    use ccdi_models::namespace;
+
+    use super::*;
+
+    fn namespace_id(name: &str) -> namespace::Identifier {
+        namespace::Identifier::new(
+            "example-organization"
+                .parse::<models::organization::Identifier>()
+                .unwrap(),
+            namespace::identifier::Name::try_new(name).unwrap(),
+        )
+    }
+
+    fn subject(namespace: &str, name: &str) -> Subject {
+        Subject::new(
+            models::subject::Identifier::new(namespace_id(namespace), name),
+            models::subject::Kind::Participant,
+            None,
+            None,
+        )
+    }
+
+    fn sample(namespace: &str, name: &str, subject: &str) -> Sample {
+        Sample::new(
+            models::sample::Identifier::new(namespace_id(namespace), name),
+            models::subject::Identifier::new(namespace_id("ExampleNamespaceOne"), subject),
+            None,
+            None,
+        )
+    }
+
+    fn file(namespace: &str, name: &str, samples: &[&str]) -> File {
+        let samples = samples
+            .iter()
+            .map(|sample| {
+                models::sample::Identifier::new(namespace_id("ExampleNamespaceOne"), *sample)
+            })
+            .collect::<Vec<_>>();
+
+        File::new(
+            models::file::Identifier::new(namespace_id(namespace), cde::v1::file::Name::new(name)),
+            // SAFETY: every file in these tests refers to at least one sample.
+            NonEmpty::from_vec(samples).unwrap(),
+            None,
+            None,
+        )
+    }
+
+    #[test]
+    fn it_accepts_consistent_entities() {
+        let subjects = [subject("ExampleNamespaceOne", "Subject1")];
+        let samples = [sample("ExampleNamespaceOne", "Sample1", "Subject1")];
+        let files = [file("ExampleNamespaceOne", "File1.txt", &["Sample1"])];
+
+        assert!(check(&subjects, &samples, &files).is_ok());
+    }
+
+    #[test]
+    fn it_detects_a_missing_subject() {
+        let samples = [sample("ExampleNamespaceOne", "Sample1", "Subject1")];
+
+        let err = check(&[], &samples, &[]).unwrap_err();
+        assert!(matches!(
+            err.violations(),
+            [Violation::MissingSubject { sample, subject }]
+                if sample.name() == "Sample1" && subject.name().as_str() == "Subject1"
+        ));
+    }
+
+    #[test]
+    fn it_detects_a_missing_sample() {
+        let subjects = [subject("ExampleNamespaceOne", "Subject1")];
+        let samples = [sample("ExampleNamespaceOne", "Sample1", "Subject1")];
+        let files = [file(
+            "ExampleNamespaceOne",
+            "File1.txt",
+            &["Sample1", "Sample2"],
+        )];
+
+        let err = check(&subjects, &samples, &files).unwrap_err();
+        assert!(matches!(
+            err.violations(),
+            [Violation::MissingSample { file, sample }]
+                if file.name().as_str() == "File1.txt" && sample.name() == "Sample2"
+        ));
+    }
+
+    #[test]
+    fn it_detects_inconsistent_namespaces() {
+        let subjects = [subject("ExampleNamespaceOne", "Subject1")];
+        let samples = [
+            sample("ExampleNamespaceOne", "Sample1", "Subject1"),
+            sample("ExampleNamespaceTwo", "Sample2", "Subject1"),
+        ];
+        let files = [file("ExampleNamespaceTwo", "File1.txt", &["Sample1"])];
+
+        let err = check(&subjects, &samples, &files).unwrap_err();
+        assert!(matches!(
+            err.violations(),
+            [
+                Violation::SampleNamespaceMismatch { .. },
+                Violation::FileNamespaceMismatch { .. }
+            ]
+        ));
+    }
+
+    #[test]
+    fn it_reports_every_violation() {
+        let samples = [
+            sample("ExampleNamespaceOne", "Sample1", "Subject1"),
+            sample("ExampleNamespaceOne", "Sample2", "Subject2"),
+        ];
+        let files = [file("ExampleNamespaceOne", "File1.txt", &["Sample3"])];
+
+        let err = check(&[], &samples, &files).unwrap_err();
+        assert_eq!(err.violations().len(), 3);
+        assert!(err
+            .to_string()
+            .starts_with("3 referential integrity violation(s) found:"));
+    }
+}
@@ -8,8 +8,21 @@
 #![warn(missing_debug_implementations)]
 #![deny(rustdoc::broken_intra_doc_links)]
 
+pub mod canonical;
+pub mod consistency;
+pub mod dataset;
+pub mod expand;
 pub mod filter;
+pub mod integrity;
+pub mod metrics;
+pub mod ndjson;
 pub mod paginate;
 pub mod params;
+pub mod project;
 pub mod responses;
 pub mod routes;
+pub mod sort;
+pub mod strict;
+pub mod suggest;
+pub mod uberon;
+pub mod values;
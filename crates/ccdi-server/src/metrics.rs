@@ -0,0 +1,420 @@
+//! Request metrics for the test server.
+//!
+//! When metrics are enabled, the [`Metrics`] middleware counts the requests
+//! made to (and the client and server errors returned by) each route and
+//! records a histogram of their latencies. Routes are grouped by a coarse
+//! label (e.g., every endpoint beneath `/subject` is labeled `subject`) so
+//! that the number of series stays small. The [`Registry`] is exposed in the
+//! Prometheus text format at `/metrics` (see [`crate::routes::metrics`]).
+
+use std::collections::BTreeMap;
+use std::fmt::Write as _;
+use std::future::ready;
+use std::future::Future;
+use std::future::Ready;
+use std::pin::Pin;
+use std::sync::Mutex;
+use std::time::Duration;
+use std::time::Instant;
+
+use actix_web::dev::forward_ready;
+use actix_web::dev::Service;
+use actix_web::dev::ServiceRequest;
+use actix_web::dev::ServiceResponse;
+use actix_web::dev::Transform;
+use actix_web::http::StatusCode;
+use actix_web::web::Data;
+
+/// The upper bounds (in seconds) of the buckets of the latency histograms.
+pub const LATENCY_BUCKETS: &[f64] = &[
+    0.005, 0.01, 0.025, 0.05, 0.1, 0.25, 0.5, 1.0, 2.5, 5.0, 10.0,
+];
+
+/// The label of routes that do not fall into any of the other labels.
+pub const OTHER: &str = "other";
+
+/// Gets the label of the route that serves a request for `path`.
+///
+/// # Examples
+///
+/// ```
+/// use ccdi_server as server;
+///
+/// use server::metrics::route;
+///
+/// assert_eq!(route("/subject"), "subject");
+/// assert_eq!(route("/subject/org/ns/name"), "subject");
+/// assert_eq!(route("/sample/by/disease_phase/count"), "sample");
+/// assert_eq!(route("/file/filter"), "file");
+/// assert_eq!(route("/metadata/fields/subject"), "metadata");
+/// assert_eq!(route("/info"), "info");
+/// assert_eq!(route("/subject-diagnosis"), "other");
+/// ```
+pub fn route(path: &str) -> &'static str {
+    let segment = path
+        .trim_start_matches('/')
+        .split('/')
+        .next()
+        .unwrap_or_default();
+
+    match segment {
+        "subject" => "subject",
+        "sample" => "sample",
+        "file" => "file",
+        "metadata" => "metadata",
+        "info" => "info",
+        _ => OTHER,
+    }
+}
+
+/// The metrics of a single route.
+#[derive(Debug)]
+struct Route {
+    /// The number of requests.
+    requests: u64,
+
+    /// The number of responses with a `4xx` status.
+    client_errors: u64,
+
+    /// The number of responses with a `5xx` status.
+    server_errors: u64,
+
+    /// The (cumulative) number of requests within each of the
+    /// [`LATENCY_BUCKETS`].
+    buckets: Vec<u64>,
+
+    /// The sum of the latencies (in seconds).
+    seconds: f64,
+}
+
+impl Default for Route {
+    fn default() -> Self {
+        Self {
+            requests: 0,
+            client_errors: 0,
+            server_errors: 0,
+            buckets: vec![0; LATENCY_BUCKETS.len()],
+            seconds: 0.0,
+        }
+    }
+}
+
+/// A registry of the metrics of every route.
+///
+/// The registry is shared between every worker (and with the `/metrics`
+/// route) through [`Data`].
+#[derive(Debug, Default)]
+pub struct Registry {
+    /// The metrics keyed by the label of each route.
+    routes: Mutex<BTreeMap<&'static str, Route>>,
+}
+
+impl Registry {
+    /// Records a request to the route labeled `route` that was responded to
+    /// with `status` after `latency`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::time::Duration;
+    ///
+    /// use actix_web::http::StatusCode;
+    /// use ccdi_server as server;
+    ///
+    /// use server::metrics::Registry;
+    ///
+    /// let registry = Registry::default();
+    /// registry.observe("subject", StatusCode::OK, Duration::from_millis(3));
+    /// registry.observe("subject", StatusCode::NOT_FOUND, Duration::from_millis(2));
+    ///
+    /// assert_eq!(registry.requests("subject"), 2);
+    /// assert_eq!(registry.client_errors("subject"), 1);
+    /// assert_eq!(registry.server_errors("subject"), 0);
+    /// ```
+    pub fn observe(&self, route: &'static str, status: StatusCode, latency: Duration) {
+        let mut routes = self.routes.lock().unwrap();
+        let metrics = routes.entry(route).or_default();
+
+        metrics.requests += 1;
+
+        if status.is_client_error() {
+            metrics.client_errors += 1;
+        } else if status.is_server_error() {
+            metrics.server_errors += 1;
+        }
+
+        let seconds = latency.as_secs_f64();
+        metrics.seconds += seconds;
+
+        for (count, bound) in metrics.buckets.iter_mut().zip(LATENCY_BUCKETS) {
+            if seconds <= *bound {
+                *count += 1;
+            }
+        }
+    }
+
+    /// Gets the number of requests made to the route labeled `route`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ccdi_server as server;
+    ///
+    /// use server::metrics::Registry;
+    ///
+    /// assert_eq!(Registry::default().requests("subject"), 0);
+    /// ```
+    pub fn requests(&self, route: &str) -> u64 {
+        self.get(route, |metrics| metrics.requests)
+    }
+
+    /// Gets the number of responses with a `4xx` status from the route
+    /// labeled `route`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ccdi_server as server;
+    ///
+    /// use server::metrics::Registry;
+    ///
+    /// assert_eq!(Registry::default().client_errors("subject"), 0);
+    /// ```
+    pub fn client_errors(&self, route: &str) -> u64 {
+        self.get(route, |metrics| metrics.client_errors)
+    }
+
+    /// Gets the number of responses with a `5xx` status from the route
+    /// labeled `route`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ccdi_server as server;
+    ///
+    /// use server::metrics::Registry;
+    ///
+    /// assert_eq!(Registry::default().server_errors("subject"), 0);
+    /// ```
+    pub fn server_errors(&self, route: &str) -> u64 {
+        self.get(route, |metrics| metrics.server_errors)
+    }
+
+    /// Gets a value from the metrics of the route labeled `route` (or zero if
+    /// no requests have been made to that route).
+    fn get(&self, route: &str, f: impl FnOnce(&Route) -> u64) -> u64 {
+        self.routes
+            .lock()
+            .unwrap()
+            .get(route)
+            .map(f)
+            .unwrap_or_default()
+    }
+
+    /// Renders every metric in the Prometheus text format.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::time::Duration;
+    ///
+    /// use actix_web::http::StatusCode;
+    /// use ccdi_server as server;
+    ///
+    /// use server::metrics::Registry;
+    ///
+    /// let registry = Registry::default();
+    /// registry.observe("info", StatusCode::OK, Duration::from_millis(1));
+    ///
+    /// let text = registry.render();
+    /// assert!(text.contains("ccdi_http_requests_total{route=\"info\"} 1\n"));
+    /// assert!(text.contains("ccdi_http_request_duration_seconds_count{route=\"info\"} 1\n"));
+    /// ```
+    pub fn render(&self) -> String {
+        let routes = self.routes.lock().unwrap();
+        let mut text = String::new();
+
+        // NOTE: writing to a [`String`] cannot fail.
+        let mut counter = |name: &str, help: &str, value: fn(&Route) -> u64| {
+            writeln!(text, "# HELP {name} {help}").unwrap();
+            writeln!(text, "# TYPE {name} counter").unwrap();
+
+            for (route, metrics) in routes.iter() {
+                writeln!(text, "{name}{{route=\"{route}\"}} {}", value(metrics)).unwrap();
+            }
+        };
+
+        counter(
+            "ccdi_http_requests_total",
+            "The number of requests by route.",
+            |metrics| metrics.requests,
+        );
+        counter(
+            "ccdi_http_client_errors_total",
+            "The number of responses with a 4xx status by route.",
+            |metrics| metrics.client_errors,
+        );
+        counter(
+            "ccdi_http_server_errors_total",
+            "The number of responses with a 5xx status by route.",
+            |metrics| metrics.server_errors,
+        );
+
+        let name = "ccdi_http_request_duration_seconds";
+        writeln!(text, "# HELP {name} The latency of requests by route.").unwrap();
+        writeln!(text, "# TYPE {name} histogram").unwrap();
+
+        for (route, metrics) in routes.iter() {
+            for (count, bound) in metrics.buckets.iter().zip(LATENCY_BUCKETS) {
+                writeln!(
+                    text,
+                    "{name}_bucket{{route=\"{route}\",le=\"{bound}\"}} {count}"
+                )
+                .unwrap();
+            }
+
+            writeln!(
+                text,
+                "{name}_bucket{{route=\"{route}\",le=\"+Inf\"}} {}",
+                metrics.requests
+            )
+            .unwrap();
+            writeln!(text, "{name}_sum{{route=\"{route}\"}} {}", metrics.seconds).unwrap();
+            writeln!(
+                text,
+                "{name}_count{{route=\"{route}\"}} {}",
+                metrics.requests
+            )
+            .unwrap();
+        }
+
+        text
+    }
+}
+
+/// A middleware that records the metrics of every request within a
+/// [`Registry`].
+///
+/// # Examples
+///
+/// ```
+/// use actix_web::web::Data;
+/// use actix_web::App;
+/// use ccdi_server as server;
+///
+/// use server::metrics::Metrics;
+/// use server::metrics::Registry;
+///
+/// let registry = Data::new(Registry::default());
+/// let app = App::new().wrap(Metrics::new(registry.clone()));
+/// ```
+#[derive(Clone, Debug)]
+pub struct Metrics {
+    /// The registry within which the metrics are recorded.
+    registry: Data<Registry>,
+}
+
+impl Metrics {
+    /// Creates a new [`Metrics`] middleware that records within `registry`.
+    pub fn new(registry: Data<Registry>) -> Self {
+        Self { registry }
+    }
+}
+
+impl<S, B> Transform<S, ServiceRequest> for Metrics
+where
+    S: Service<ServiceRequest, Response = ServiceResponse<B>, Error = actix_web::Error> + 'static,
+    B: 'static,
+{
+    type Response = ServiceResponse<B>;
+    type Error = actix_web::Error;
+    type Transform = MetricsMiddleware<S>;
+    type InitError = ();
+    type Future = Ready<Result<Self::Transform, Self::InitError>>;
+
+    fn new_transform(&self, service: S) -> Self::Future {
+        ready(Ok(MetricsMiddleware {
+            service,
+            registry: self.registry.clone(),
+        }))
+    }
+}
+
+/// The service created by the [`Metrics`] middleware.
+#[derive(Debug)]
+pub struct MetricsMiddleware<S> {
+    /// The wrapped service.
+    service: S,
+
+    /// The registry within which the metrics are recorded.
+    registry: Data<Registry>,
+}
+
+impl<S, B> Service<ServiceRequest> for MetricsMiddleware<S>
+where
+    S: Service<ServiceRequest, Response = ServiceResponse<B>, Error = actix_web::Error> + 'static,
+    B: 'static,
+{
+    type Response = ServiceResponse<B>;
+    type Error = actix_web::Error;
+    type Future = Pin<Box<dyn Future<Output = Result<Self::Response, Self::Error>>>>;
+
+    forward_ready!(service);
+
+    fn call(&self, req: ServiceRequest) -> Self::Future {
+        let route = route(req.path());
+        let registry = self.registry.clone();
+        let start = Instant::now();
+        let response = self.service.call(req);
+
+        Box::pin(async move {
+            let response = response.await;
+
+            let status = match &response {
+                Ok(response) => response.status(),
+                Err(err) => err.as_response_error().status_code(),
+            };
+
+            registry.observe(route, status, start.elapsed());
+            response
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_buckets_latencies_cumulatively() {
+        let registry = Registry::default();
+        registry.observe("file", StatusCode::OK, Duration::from_millis(20));
+        registry.observe(
+            "file",
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Duration::from_secs(20),
+        );
+
+        let text = registry.render();
+
+        let bucket = |le: &str| {
+            format!("ccdi_http_request_duration_seconds_bucket{{route=\"file\",le=\"{le}\"}}")
+        };
+
+        assert!(text.contains(&format!("{} 0\n", bucket("0.01"))));
+        assert!(text.contains(&format!("{} 1\n", bucket("0.025"))));
+        assert!(text.contains(&format!("{} 1\n", bucket("10"))));
+        assert!(text.contains(&format!("{} 2\n", bucket("+Inf"))));
+        assert!(text.contains("ccdi_http_server_errors_total{route=\"file\"} 1\n"));
+        assert!(text.contains("ccdi_http_client_errors_total{route=\"file\"} 0\n"));
+    }
+
+    #[test]
+    fn it_renders_nothing_but_the_headers_without_requests() {
+        let text = Registry::default().render();
+
+        assert!(text.contains("# TYPE ccdi_http_requests_total counter\n"));
+        assert!(text.contains("# TYPE ccdi_http_request_duration_seconds histogram\n"));
+        assert!(!text.contains("route="));
+    }
+}
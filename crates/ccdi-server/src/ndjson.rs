@@ -0,0 +1,168 @@
+//! Streaming entities as newline-delimited JSON (NDJSON).
+//!
+//! When NDJSON is requested from a listing endpoint (see
+//! [`Format::Ndjson`](crate::params::format::Format::Ndjson)), every matching
+//! entity is written as a JSON object on its own line rather than within a
+//! paginated response. Each line is only serialized as the body is polled, so
+//! large listings are streamed to the client rather than being buffered in
+//! their entirety.
+
+use std::pin::Pin;
+use std::task::Context;
+use std::task::Poll;
+
+use actix_web::body::BodySize;
+use actix_web::body::MessageBody;
+use actix_web::web::Bytes;
+use actix_web::HttpResponse;
+use serde::Serialize;
+
+use crate::params::PaginationParams;
+use crate::responses::error;
+
+/// The media type of newline-delimited JSON.
+pub const MEDIA_TYPE: &str = "application/x-ndjson";
+
+/// A response body that streams each item as a line of JSON.
+#[derive(Debug)]
+pub struct Body<I> {
+    /// The items that remain to be streamed.
+    items: I,
+}
+
+impl<I> Body<I>
+where
+    I: Iterator,
+    I::Item: Serialize,
+{
+    /// Creates a new [`Body`] that streams the `items`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ccdi_server as server;
+    ///
+    /// use server::ndjson::Body;
+    ///
+    /// let body = Body::new(vec![1, 2, 3]);
+    /// ```
+    pub fn new(items: impl IntoIterator<IntoIter = I>) -> Self {
+        Self {
+            items: items.into_iter(),
+        }
+    }
+}
+
+impl<I> MessageBody for Body<I>
+where
+    I: Iterator + Unpin,
+    I::Item: Serialize,
+{
+    type Error = serde_json::Error;
+
+    fn size(&self) -> BodySize {
+        BodySize::Stream
+    }
+
+    fn poll_next(
+        self: Pin<&mut Self>,
+        _: &mut Context<'_>,
+    ) -> Poll<Option<Result<Bytes, Self::Error>>> {
+        let item = match self.get_mut().items.next() {
+            Some(item) => item,
+            None => return Poll::Ready(None),
+        };
+
+        Poll::Ready(Some(serde_json::to_vec(&item).map(|mut line| {
+            line.push(b'\n');
+            Bytes::from(line)
+        })))
+    }
+}
+
+/// Creates a response that streams each of the `items` as a line of JSON.
+pub(crate) fn response<I>(items: I) -> HttpResponse
+where
+    I: IntoIterator,
+    I::IntoIter: Unpin + 'static,
+    I::Item: Serialize,
+{
+    HttpResponse::Ok()
+        .content_type(MEDIA_TYPE)
+        .body(Body::new(items))
+}
+
+/// Ensures that no pagination parameters were provided.
+///
+/// Every matching entity is streamed within an NDJSON response, so an
+/// [`InvalidParameters`](error::Kind::invalid_parameters) error that lists the
+/// provided pagination parameters is returned rather than silently ignoring
+/// them.
+///
+/// # Examples
+///
+/// ```
+/// use actix_web::web::Query;
+/// use ccdi_server as server;
+///
+/// use server::ndjson::reject_pagination;
+/// use server::params::PaginationParams;
+///
+/// assert!(reject_pagination(&PaginationParams::default()).is_ok());
+///
+/// let params = Query::<PaginationParams>::from_query("page=2").unwrap();
+/// let err = reject_pagination(&params).unwrap_err();
+/// assert_eq!(
+///     err.to_string(),
+///     "Invalid value for parameter 'page': pagination is not supported when \
+///      streaming ndjson (every matching entity is returned)."
+/// );
+/// ```
+pub fn reject_pagination(params: &PaginationParams) -> Result<(), error::Kind> {
+    let parameters = [
+        ("page", params.page().is_some()),
+        ("per_page", params.per_page().is_some()),
+    ]
+    .into_iter()
+    .filter(|(_, provided)| *provided)
+    .map(|(name, _)| String::from(name))
+    .collect::<Vec<_>>();
+
+    if parameters.is_empty() {
+        return Ok(());
+    }
+
+    Err(error::Kind::invalid_parameters(
+        Some(parameters),
+        // NOTE: the reason ends with a parenthesis, which would otherwise be
+        // left without a period when it is formalized.
+        String::from(
+            "pagination is not supported when streaming NDJSON (every matching entity is \
+             returned).",
+        ),
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use actix_web::body::to_bytes;
+    use serde_json::json;
+
+    use super::*;
+
+    #[actix_web::test]
+    async fn it_streams_one_line_per_item() {
+        let body = Body::new(vec![json!({ "id": 1 }), json!({ "id": [2, 3] })]);
+
+        assert_eq!(
+            to_bytes(body).await.unwrap(),
+            "{\"id\":1}\n{\"id\":[2,3]}\n"
+        );
+    }
+
+    #[actix_web::test]
+    async fn it_streams_nothing_when_there_are_no_items() {
+        let body = Body::new(Vec::<serde_json::Value>::new());
+        assert!(to_bytes(body).await.unwrap().is_empty());
+    }
+}
@@ -2,11 +2,15 @@
 
 use std::num::NonZeroUsize;
 
+use actix_web::http::header::HeaderName;
+use actix_web::http::header::HeaderValue;
 use actix_web::HttpResponse;
 use serde::Serialize;
+use serde_json::Value;
 
 use crate::params::pagination;
 use crate::params::PaginationParams;
+use crate::responses::entity;
 use crate::responses::error;
 use crate::responses::Errors;
 
@@ -15,16 +19,193 @@ pub mod links;
 pub use links::Links;
 pub use links::Relationship;
 
+/// The default maximum number of entities per page.
+pub const DEFAULT_MAX_PER_PAGE: usize = 100;
+
+/// Configuration for pagination.
+#[derive(Clone, Debug)]
+pub struct Config {
+    /// The maximum number of entities per page.
+    max_per_page: NonZeroUsize,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            // SAFETY: the default maximum is non-zero, so this always unwraps.
+            max_per_page: NonZeroUsize::new(DEFAULT_MAX_PER_PAGE).unwrap(),
+        }
+    }
+}
+
+impl Config {
+    /// Creates a new [`Config`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::num::NonZeroUsize;
+    ///
+    /// use ccdi_server as server;
+    ///
+    /// use server::paginate::Config;
+    ///
+    /// let config = Config::new(NonZeroUsize::new(500).unwrap());
+    /// assert_eq!(config.max_per_page().get(), 500);
+    /// ```
+    pub fn new(max_per_page: NonZeroUsize) -> Self {
+        Self { max_per_page }
+    }
+
+    /// Gets the maximum number of entities per page for the [`Config`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ccdi_server as server;
+    ///
+    /// use server::paginate::Config;
+    /// use server::paginate::DEFAULT_MAX_PER_PAGE;
+    ///
+    /// let config = Config::default();
+    /// assert_eq!(config.max_per_page().get(), DEFAULT_MAX_PER_PAGE);
+    /// ```
+    pub fn max_per_page(&self) -> NonZeroUsize {
+        self.max_per_page
+    }
+}
+
+/// Gets the number of entities per page requested within the
+/// [`PaginationParams`] (or [`DEFAULT_PER_PAGE`](pagination::DEFAULT_PER_PAGE)
+/// if none was requested).
+///
+/// An error is returned if the requested number of entities per page is zero
+/// or exceeds the maximum within the [`Config`] (requests are rejected rather
+/// than silently clamped to the maximum). If no [`Config`] is provided, the
+/// default [`Config`] is used.
+///
+/// # Examples
+///
+/// ```
+/// use std::num::NonZeroUsize;
+///
+/// use ccdi_server as server;
+///
+/// use server::paginate::per_page;
+/// use server::paginate::Config;
+/// use server::params::PaginationParams;
+///
+/// let params = PaginationParams::new(None, Some(100));
+/// assert_eq!(per_page(&params, None).unwrap().get(), 100);
+///
+/// let params = PaginationParams::new(None, Some(101));
+/// assert_eq!(
+///     per_page(&params, None).unwrap_err().to_string(),
+///     "Invalid value for parameter 'per_page': must not exceed the maximum of 100 entities per page (101 were requested)."
+/// );
+///
+/// let config = Config::new(NonZeroUsize::new(500).unwrap());
+/// assert_eq!(per_page(&params, Some(&config)).unwrap().get(), 101);
+///
+/// let params = PaginationParams::new(None, Some(0));
+/// assert_eq!(
+///     per_page(&params, None).unwrap_err().to_string(),
+///     "Invalid value for parameter 'per_page': must be at least one."
+/// );
+/// ```
+pub fn per_page(
+    params: &PaginationParams,
+    config: Option<&Config>,
+) -> Result<NonZeroUsize, error::Kind> {
+    let max = config
+        .map(Config::max_per_page)
+        .unwrap_or_else(|| Config::default().max_per_page());
+
+    let per_page = params.per_page().unwrap_or(pagination::DEFAULT_PER_PAGE);
+
+    match NonZeroUsize::new(per_page) {
+        None => Err(error::Kind::invalid_parameters(
+            Some(vec![String::from("per_page")]),
+            String::from("must be at least one"),
+        )),
+        Some(per_page) if per_page > max => Err(error::Kind::invalid_parameters(
+            Some(vec![String::from("per_page")]),
+            // NOTE: the reason ends with a parenthesis, which would otherwise
+            // be left without a period when it is formalized.
+            format!(
+                "must not exceed the maximum of {max} entities per page ({per_page} were \
+                 requested)."
+            ),
+        )),
+        Some(per_page) => Ok(per_page),
+    }
+}
+
+/// A paginated response that reports the links to the other pages of its
+/// result set within its body (in addition to the `link` header).
+///
+/// The links are only reported within the body when the result set spans more
+/// than one page.
+pub trait Linked: Sized {
+    /// Includes the links to the other pages of the result set within the
+    /// response.
+    ///
+    /// By default, the links are only reported within the `link` header.
+    fn with_links(self, _links: entity::Links) -> Self {
+        self
+    }
+}
+
+/// A response that has been projected onto a selection of fields (see
+/// [`Selection::project()`](crate::project::Selection::project)) carries the
+/// links of the response it was projected from.
+impl Linked for Value {
+    fn with_links(mut self, links: entity::Links) -> Self {
+        if let Value::Object(response) = &mut self {
+            // SAFETY: the links are always serializable to JSON.
+            response.insert(String::from("links"), serde_json::to_value(links).unwrap());
+        }
+
+        self
+    }
+}
+
 pub(crate) fn response<T, R>(
     params: PaginationParams,
+    config: Option<&Config>,
     all_entities: Vec<T>,
     base_url: &str,
 ) -> HttpResponse
 where
     T: Clone,
-    R: Serialize,
+    R: Serialize + Linked,
     R: From<(Vec<T>, usize)>,
 {
+    response_with(params, config, all_entities, base_url, R::from)
+}
+
+/// Paginates the entities in the same manner as [`response()`], but builds
+/// the response for the selected page with `build` (e.g., to embed
+/// referenced entities within the page).
+pub(crate) fn response_with<T, R, F>(
+    params: PaginationParams,
+    config: Option<&Config>,
+    all_entities: Vec<T>,
+    base_url: &str,
+    build: F,
+) -> HttpResponse
+where
+    T: Clone,
+    R: Serialize + Linked,
+    F: FnOnce((Vec<T>, usize)) -> R,
+{
+    // NOTE: the number of entities per page is checked before anything else so
+    // that an invalid `per_page` is rejected regardless of the entities.
+    let per_page = match per_page(&params, config) {
+        Ok(value) => value,
+        Err(err) => return HttpResponse::UnprocessableEntity().json(Errors::from(err)),
+    };
+
     if all_entities.is_empty() {
         // If there are no entities to return, just return an empty array back.
         return HttpResponse::Ok().json(Vec::<R>::new());
@@ -42,20 +223,8 @@ where
         }
     };
 
-    let per_page =
-        match NonZeroUsize::try_from(params.per_page().unwrap_or(pagination::DEFAULT_PER_PAGE)) {
-            Ok(value) => value,
-            Err(_) => {
-                return HttpResponse::UnprocessableEntity().json(Errors::from(
-                    error::Kind::invalid_parameters(
-                        Some(vec![String::from("per_page")]),
-                        String::from("must be a non-zero usize"),
-                    ),
-                ))
-            }
-        };
-
     let pages = all_entities.chunks(per_page.get()).collect::<Vec<_>>();
+    let multiple_pages = pages.len() > 1;
 
     let links = links::Builder::try_new(base_url, page, per_page, pages.clone())
         .unwrap_or_else(|err| {
@@ -89,7 +258,150 @@ where
         ));
     }
 
+    let response = build((this_page_entities.to_vec(), all_entities.len()));
+
+    // NOTE: the links within the body are generated from the same links as
+    // the header so that the two can never diverge.
+    let response = match multiple_pages {
+        true => response.with_links(entity::Links::from(&links)),
+        false => response,
+    };
+
     HttpResponse::Ok()
         .insert_header(("link", links.to_string()))
-        .json(R::from((this_page_entities.to_vec(), all_entities.len())))
+        .json(response)
+}
+
+/// The header that reports the number of entities within the scope of a
+/// paginated endpoint before any filters were applied.
+pub const TOTAL_COUNT_HEADER: &str = "x-total-count";
+
+/// The header that reports the number of entities within the scope of a
+/// paginated endpoint that matched the filters (across every page).
+pub const FILTERED_COUNT_HEADER: &str = "x-filtered-count";
+
+/// The headers of a paginated response that browsers must be allowed to read
+/// (through the `access-control-expose-headers` header) for cross-origin
+/// clients to page through the results.
+pub const EXPOSED_HEADERS: &[&str] = &["link", TOTAL_COUNT_HEADER, FILTERED_COUNT_HEADER];
+
+/// The number of entities within the scope of a paginated endpoint before and
+/// after filtering.
+///
+/// These are computed once for a request (before the entities are paginated)
+/// and reported both within the [`TOTAL_COUNT_HEADER`] and
+/// [`FILTERED_COUNT_HEADER`] headers and within the summary of the response.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Totals {
+    /// The number of entities before any filters were applied.
+    total: usize,
+
+    /// The number of entities that matched the filters.
+    filtered: usize,
+}
+
+impl Totals {
+    /// Creates a new [`Totals`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ccdi_server as server;
+    ///
+    /// use server::paginate::Totals;
+    ///
+    /// let totals = Totals::new(100, 10);
+    /// assert_eq!(totals.total(), 100);
+    /// assert_eq!(totals.filtered(), 10);
+    /// ```
+    pub fn new(total: usize, filtered: usize) -> Self {
+        Self { total, filtered }
+    }
+
+    /// Gets the number of entities before any filters were applied.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ccdi_server as server;
+    ///
+    /// use server::paginate::Totals;
+    ///
+    /// let totals = Totals::new(100, 10);
+    /// assert_eq!(totals.total(), 100);
+    /// ```
+    pub fn total(&self) -> usize {
+        self.total
+    }
+
+    /// Gets the number of entities that matched the filters.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ccdi_server as server;
+    ///
+    /// use server::paginate::Totals;
+    ///
+    /// let totals = Totals::new(100, 10);
+    /// assert_eq!(totals.filtered(), 10);
+    /// ```
+    pub fn filtered(&self) -> usize {
+        self.filtered
+    }
+}
+
+/// Paginates the entities in the same manner as [`response_with()`] and, if
+/// the response is successful, reports the `totals` within the
+/// [`TOTAL_COUNT_HEADER`] and [`FILTERED_COUNT_HEADER`] headers.
+///
+/// The headers are included even when no entities matched the filters.
+pub(crate) fn response_with_totals<T, R, F>(
+    params: PaginationParams,
+    config: Option<&Config>,
+    all_entities: Vec<T>,
+    totals: Totals,
+    base_url: &str,
+    build: F,
+) -> HttpResponse
+where
+    T: Clone,
+    R: Serialize + Linked,
+    F: FnOnce((Vec<T>, usize)) -> R,
+{
+    let mut response = response_with(params, config, all_entities, base_url, build);
+
+    if response.status().is_success() {
+        let headers = response.headers_mut();
+        headers.insert(
+            HeaderName::from_static(TOTAL_COUNT_HEADER),
+            HeaderValue::from(totals.total()),
+        );
+        headers.insert(
+            HeaderName::from_static(FILTERED_COUNT_HEADER),
+            HeaderValue::from(totals.filtered()),
+        );
+    }
+
+    response
+}
+
+/// The warning included with every unpaginated response.
+pub const UNPAGINATED_WARNING: &str = "299 - \"Unpaginated responses (`paginate=false`) are \
+                                       deprecated and will be removed in the next release.\"";
+
+/// Responds with all of the entities as a bare array (rather than within the
+/// paginated envelope) for the clients that have opted out of pagination with
+/// `paginate=false`.
+///
+/// The response includes the `deprecation` and `warning` headers, as this
+/// escape hatch is only provided for a single release.
+pub(crate) fn unpaginated<T>(all_entities: Vec<T>) -> HttpResponse
+where
+    T: Serialize,
+{
+    HttpResponse::Ok()
+        .insert_header(("deprecation", "true"))
+        .insert_header(("warning", UNPAGINATED_WARNING))
+        .json(all_entities)
 }
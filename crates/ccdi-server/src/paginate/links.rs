@@ -49,6 +49,36 @@ impl std::fmt::Display for Link {
 #[derive(Debug)]
 pub struct Links(Vec<Link>);
 
+impl Links {
+    /// Gets the URL of the link with the provided [`Relationship`] (if it
+    /// exists).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ccdi_server as server;
+    ///
+    /// use server::paginate::links::Link;
+    /// use server::paginate::links::Links;
+    /// use server::paginate::links::Relationship;
+    /// use url::Url;
+    ///
+    /// let url = "https://example.com?page=1&per_page=10".parse::<Url>()?;
+    /// let links = Links::from(vec![Link::new(Relationship::First, url.clone())]);
+    ///
+    /// assert_eq!(links.get(Relationship::First), Some(&url));
+    /// assert_eq!(links.get(Relationship::Next), None);
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn get(&self, rel: Relationship) -> Option<&Url> {
+        self.0
+            .iter()
+            .find(|link| link.rel == rel)
+            .map(|link| &link.url)
+    }
+}
+
 impl From<Vec<Link>> for Links {
     fn from(value: Vec<Link>) -> Self {
         Links(value)
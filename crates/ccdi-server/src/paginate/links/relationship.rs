@@ -1,5 +1,5 @@
 /// Relationship of a [`Link`](super::Link) to the current page.
-#[derive(Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
 pub enum Relationship {
     /// The first page in a paginated result set.
     First,
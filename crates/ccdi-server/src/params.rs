@@ -1,6 +1,37 @@
 //! Common parameters used across the server.
 
+pub mod accept;
+pub mod aggregate;
+pub mod computed;
+pub mod count;
+pub mod expand;
+pub mod fields;
 pub mod filter;
+pub mod format;
+pub mod labels;
+pub mod lookup;
+pub mod manifest;
 pub mod pagination;
+pub mod related;
+pub mod search;
+pub mod sort;
+pub mod tabular;
+pub mod validate;
 
+pub use aggregate::AggregateParams;
+pub use computed::ComputedParams;
+pub use count::BucketParams;
+pub use count::CountParams;
+pub use count::MissingParams;
+pub use count::ScopeParams;
+pub use count::UberonParams;
+pub use expand::ExpandParams;
+pub use fields::FieldsParams;
+pub use format::FormatParams;
+pub use labels::LabelParams;
+pub use manifest::ManifestParams;
 pub use pagination::PaginationParams;
+pub use search::SearchParams;
+pub use sort::SortParams;
+pub use tabular::TabularParams;
+pub use validate::ValidateParams;
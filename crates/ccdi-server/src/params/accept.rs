@@ -0,0 +1,53 @@
+//! Negotiating a format from the `Accept` header of a request.
+
+/// Negotiates a format from the value of an `Accept` header given the
+/// `supported` media types and the format that each corresponds to.
+///
+/// The supported media type with the highest quality is chosen (media types
+/// with equal quality retain their order within the header). Media types that
+/// are not supported, including wildcards, are ignored, so [`None`] is returned
+/// when the header names no supported media type.
+///
+/// # Examples
+///
+/// ```
+/// use ccdi_server as server;
+///
+/// use server::params::accept::negotiate;
+///
+/// let supported = [("application/json", 1), ("text/plain", 2)];
+///
+/// assert_eq!(negotiate("text/plain", &supported), Some(2));
+/// assert_eq!(
+///     negotiate("text/plain;q=0.5, application/json", &supported),
+///     Some(1)
+/// );
+/// assert_eq!(negotiate("*/*", &supported), None);
+/// ```
+pub fn negotiate<F: Copy>(value: &str, supported: &[(&str, F)]) -> Option<F> {
+    value
+        .split(',')
+        .filter_map(|entry| {
+            let mut parts = entry.split(';').map(|part| part.trim());
+
+            let media_type = parts.next()?;
+            let format = supported
+                .iter()
+                .find(|(supported, _)| supported.eq_ignore_ascii_case(media_type))
+                .map(|(_, format)| *format)?;
+            let quality = match parts.find_map(|part| part.strip_prefix("q=")) {
+                Some(quality) => quality.parse::<f32>().ok()?,
+                None => 1.0,
+            };
+
+            (quality > 0.0).then_some((format, quality))
+        })
+        .fold(
+            None,
+            |best: Option<(F, f32)>, (format, quality)| match best {
+                Some((_, best_quality)) if best_quality >= quality => best,
+                _ => Some((format, quality)),
+            },
+        )
+        .map(|(format, _)| format)
+}
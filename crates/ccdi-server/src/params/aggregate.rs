@@ -0,0 +1,62 @@
+//! Parameters related to counting across multiple entities at once.
+
+use serde::Deserialize;
+use serde::Serialize;
+use utoipa::IntoParams;
+
+/// Parameters for an aggregate count request to the server.
+#[derive(Debug, Deserialize, IntoParams, Serialize)]
+#[into_params(parameter_in = Query)]
+pub struct AggregateParams {
+    /// A comma-delimited list of the entities and fields to group by and count.
+    ///
+    /// Each item is the name of the entity (`subject`, `sample`, or `file`)
+    /// and the name of the field joined by a period (e.g.,
+    /// `subject.sex,sample.library_strategy,file.type`).
+    include: String,
+}
+
+impl AggregateParams {
+    /// Creates a new [`AggregateParams`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ccdi_server as server;
+    ///
+    /// use server::params::AggregateParams;
+    ///
+    /// let params = AggregateParams::new("subject.sex,file.type");
+    /// assert_eq!(params.include().count(), 2);
+    /// ```
+    pub fn new(include: impl Into<String>) -> Self {
+        Self {
+            include: include.into(),
+        }
+    }
+
+    /// Gets an iterator over the entity and field pairs requested within the
+    /// [`AggregateParams`].
+    ///
+    /// Surrounding whitespace is trimmed and empty items are ignored.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ccdi_server as server;
+    ///
+    /// use server::params::AggregateParams;
+    ///
+    /// let params = AggregateParams::new("subject.sex, sample.library_strategy,,file.type");
+    /// assert_eq!(
+    ///     params.include().collect::<Vec<_>>(),
+    ///     vec!["subject.sex", "sample.library_strategy", "file.type"]
+    /// );
+    /// ```
+    pub fn include(&self) -> impl Iterator<Item = &str> {
+        self.include
+            .split(',')
+            .map(|pair| pair.trim())
+            .filter(|pair| !pair.is_empty())
+    }
+}
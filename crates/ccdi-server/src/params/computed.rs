@@ -0,0 +1,32 @@
+//! Parameters related to the fields that are computed by the server.
+
+use serde::Deserialize;
+use serde::Serialize;
+use utoipa::IntoParams;
+
+/// Optional parameters for the fields that are computed by the server.
+#[derive(Debug, Default, Deserialize, IntoParams, Serialize)]
+#[into_params(parameter_in = Query)]
+pub struct ComputedParams {
+    /// When `false`, the fields that are derived by the server (rather than
+    /// stored as metadata) are omitted from the response. Defaults to `true`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[param(required = false, nullable = false)]
+    computed: Option<bool>,
+}
+
+impl ComputedParams {
+    /// Gets whether the computed fields should be included.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ccdi_server as server;
+    ///
+    /// let params = server::params::ComputedParams::default();
+    /// assert!(params.computed());
+    /// ```
+    pub fn computed(&self) -> bool {
+        self.computed.unwrap_or(true)
+    }
+}
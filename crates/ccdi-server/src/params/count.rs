@@ -0,0 +1,339 @@
+//! Parameters related to grouping by fields and counting them.
+
+use serde::Deserialize;
+use serde::Serialize;
+use utoipa::IntoParams;
+use utoipa::ToSchema;
+
+use ccdi_models as models;
+
+use models::namespace;
+
+use crate::filter::rule::Strategy;
+use crate::filter::scope;
+use crate::responses::error;
+
+/// The default number of nested values to include within each group if no
+/// `top` parameter is provided.
+pub const DEFAULT_TOP: usize = 10;
+
+/// A set of values that may be nested within the groups of a count.
+#[derive(Clone, Copy, Debug, Deserialize, Eq, PartialEq, Serialize, ToSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum Include {
+    /// The free-text `diagnosis` values. This is only supported when grouping
+    /// samples by `diagnosis_category`.
+    Diagnoses,
+}
+
+/// Optional parameters for a group by and count request to the server.
+#[derive(Debug, Default, Deserialize, IntoParams, Serialize)]
+#[into_params(parameter_in = Query)]
+pub struct CountParams {
+    /// The values to nest within each group.
+    ///
+    /// Nesting is currently only supported for `include=diagnoses` when
+    /// grouping samples by `diagnosis_category`. In that case, each category
+    /// includes the counts of the free-text `diagnosis` values within that
+    /// category.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[param(required = false, nullable = false, inline)]
+    include: Option<Include>,
+
+    /// The maximum number of nested values to include within each group.
+    ///
+    /// The most common values are included, and the number of entities with
+    /// any of the remaining values is reported as `other`. This parameter is
+    /// only meaningful when `include` is provided, and it defaults to `10`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[param(required = false, nullable = false)]
+    top: Option<usize>,
+}
+
+impl CountParams {
+    /// Gets the values to nest from the [`CountParams`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ccdi_server as server;
+    ///
+    /// let params = server::params::CountParams::default();
+    /// assert_eq!(params.include(), None);
+    /// ```
+    pub fn include(&self) -> Option<Include> {
+        self.include
+    }
+
+    /// Gets the maximum number of nested values from the [`CountParams`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ccdi_server as server;
+    ///
+    /// let params = server::params::CountParams::default();
+    /// assert_eq!(params.top(), None);
+    /// ```
+    pub fn top(&self) -> Option<usize> {
+        self.top
+    }
+}
+
+/// Optional parameters for distinguishing the reasons that entities are
+/// missing values when grouping by and counting a field.
+#[derive(Debug, Default, Deserialize, IntoParams, Serialize)]
+#[into_params(parameter_in = Query)]
+pub struct MissingParams {
+    /// Whether entities with no metadata block at all are counted separately
+    /// from entities where the field is missing or `null`.
+    ///
+    /// When `true`, `missing` only counts the entities with no metadata block,
+    /// and the entities with a metadata block where the field is missing or
+    /// `null` are counted within `null`. Defaults to `false`, in which case
+    /// both are counted within `missing` and `null` is omitted.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[param(required = false, nullable = false)]
+    distinguish_missing: Option<bool>,
+}
+
+impl MissingParams {
+    /// Gets whether missing values should be distinguished from `null` values.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ccdi_server as server;
+    ///
+    /// let params = server::params::MissingParams::default();
+    /// assert!(!params.distinguish_missing());
+    /// ```
+    pub fn distinguish_missing(&self) -> bool {
+        self.distinguish_missing.unwrap_or_default()
+    }
+}
+
+/// Optional parameters for rolling anatomical sites up to an ancestor term
+/// when counting samples by `anatomical_sites`.
+#[derive(Debug, Default, Deserialize, IntoParams, Serialize)]
+#[into_params(parameter_in = Query)]
+pub struct UberonParams {
+    /// The identifier of an Uberon term (e.g., `UBERON:0001638` or
+    /// `http://purl.obolibrary.org/obo/UBERON_0001638`) to which anatomical
+    /// sites are rolled up.
+    ///
+    /// Every site that is the term or one of its descendants within the
+    /// curated Uberon hierarchy is counted under the label of the term (and
+    /// every other site is counted as is). This parameter is only supported
+    /// when counting samples by `anatomical_sites`, and only when the server
+    /// has been configured with the curated Uberon hierarchy.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[param(required = false, nullable = false)]
+    group_by_uberon_ancestor: Option<String>,
+}
+
+impl UberonParams {
+    /// Gets the identifier of the Uberon term to which anatomical sites are
+    /// rolled up from the [`UberonParams`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ccdi_server as server;
+    ///
+    /// let params = server::params::UberonParams::default();
+    /// assert_eq!(params.group_by_uberon_ancestor(), None);
+    /// ```
+    pub fn group_by_uberon_ancestor(&self) -> Option<&str> {
+        self.group_by_uberon_ancestor.as_deref()
+    }
+}
+
+/// Optional parameters for scoping the entities that are grouped and counted
+/// to a namespace or an organization.
+///
+/// These parameters are matched exactly as the parameters of the same name
+/// when filtering entities.
+#[derive(Debug, Default, Deserialize, IntoParams, Serialize)]
+#[into_params(parameter_in = Query)]
+pub struct ScopeParams {
+    /// Only counts the entities whose identifier is within the namespace
+    /// provided (formatted as `{organization}/{namespace}`, e.g.,
+    /// `example-organization/ExampleNamespaceOne`). A namespace that is not
+    /// known by the server is rejected with an error listing the known
+    /// namespaces.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[param(required = false, nullable = false)]
+    namespace: Option<String>,
+
+    /// Only counts the entities whose identifier is within a namespace owned
+    /// by the organization provided (e.g., `example-organization`). An
+    /// organization that is not known by the server is rejected with an error
+    /// listing the known organizations.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[param(required = false, nullable = false)]
+    organization: Option<String>,
+}
+
+impl ScopeParams {
+    /// Checks that the [`ScopeParams`] are valid and that every namespace and
+    /// organization within them is known by the server.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ccdi_server as server;
+    ///
+    /// let params = server::params::ScopeParams::default();
+    /// assert!(params.check().is_ok());
+    /// ```
+    pub fn check(&self) -> Result<(), error::Kind> {
+        let queries = [
+            (scope::NAMESPACE, &self.namespace),
+            (scope::ORGANIZATION, &self.organization),
+        ];
+
+        for (name, query) in queries {
+            if let Some(query) = query {
+                Strategy::Exact.validate(query).map_err(|err| {
+                    error::Kind::invalid_parameters(Some(vec![name.to_string()]), err.to_string())
+                })?;
+            }
+        }
+
+        scope::check(self.namespace.as_deref(), self.organization.as_deref(), "")
+    }
+
+    /// Gets whether an entity within the provided namespace is within the
+    /// scope described by the [`ScopeParams`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ccdi_server as server;
+    ///
+    /// use server::routes::namespace::NAMESPACES;
+    ///
+    /// let params = server::params::ScopeParams::default();
+    /// let namespace = NAMESPACES.values().next().unwrap().id();
+    ///
+    /// assert!(params.contains(namespace));
+    /// ```
+    pub fn contains(&self, namespace: &namespace::Identifier) -> bool {
+        scope::contains(
+            self.namespace.as_deref(),
+            self.organization.as_deref(),
+            namespace,
+        )
+    }
+}
+
+/// The default boundaries (in bytes) of the buckets into which file sizes are
+/// counted if no `buckets` parameter is provided (1 MiB, 1 GiB, and 100 GiB).
+pub const DEFAULT_SIZE_BUCKETS: &[usize] = &[1 << 20, 1 << 30, 100 << 30];
+
+/// Optional parameters for counting files by their size.
+#[derive(Debug, Default, Deserialize, IntoParams, Serialize)]
+#[into_params(parameter_in = Query)]
+pub struct BucketParams {
+    /// A comma-separated list of the boundaries (in bytes) of the buckets
+    /// into which file sizes are counted (e.g., `1024,1048576`).
+    ///
+    /// The boundaries must be positive and strictly increasing. Each bucket
+    /// includes its lower boundary and excludes its upper boundary: the first
+    /// bucket starts at zero and the last bucket has no upper boundary. This
+    /// parameter is only meaningful when counting files by `size`, and it
+    /// defaults to `1048576,1073741824,107374182400` (1 MiB, 1 GiB, and 100
+    /// GiB).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[param(required = false, nullable = false)]
+    buckets: Option<String>,
+}
+
+impl BucketParams {
+    /// Parses the boundaries of the buckets from the [`BucketParams`].
+    ///
+    /// The [`DEFAULT_SIZE_BUCKETS`] are returned when no boundaries are
+    /// provided. A description of the problem is returned when the boundaries
+    /// are not positive, strictly increasing integers.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ccdi_server as server;
+    ///
+    /// use server::params::count::BucketParams;
+    /// use server::params::count::DEFAULT_SIZE_BUCKETS;
+    ///
+    /// let params = BucketParams::default();
+    /// assert_eq!(params.boundaries().unwrap(), DEFAULT_SIZE_BUCKETS);
+    /// ```
+    pub fn boundaries(&self) -> Result<Vec<usize>, String> {
+        let buckets = match &self.buckets {
+            Some(buckets) => buckets,
+            None => return Ok(DEFAULT_SIZE_BUCKETS.to_vec()),
+        };
+
+        let boundaries = buckets
+            .split(',')
+            .map(|boundary| {
+                boundary
+                    .trim()
+                    .parse::<usize>()
+                    .map_err(|_| format!("`{}` is not a number of bytes", boundary.trim()))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        if boundaries.first() == Some(&0) {
+            return Err(String::from("bucket boundaries must be greater than zero"));
+        }
+
+        if boundaries.windows(2).any(|pair| pair[0] >= pair[1]) {
+            return Err(String::from(
+                "bucket boundaries must be strictly increasing",
+            ));
+        }
+
+        Ok(boundaries)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn params(buckets: &str) -> BucketParams {
+        BucketParams {
+            buckets: Some(String::from(buckets)),
+        }
+    }
+
+    #[test]
+    fn it_parses_bucket_boundaries() {
+        assert_eq!(params("1024").boundaries().unwrap(), vec![1024]);
+        assert_eq!(
+            params("1024, 1048576").boundaries().unwrap(),
+            vec![1024, 1048576]
+        );
+    }
+
+    #[test]
+    fn it_rejects_invalid_bucket_boundaries() {
+        assert_eq!(
+            params("1024,1KB").boundaries().unwrap_err(),
+            "`1KB` is not a number of bytes"
+        );
+        assert_eq!(
+            params("").boundaries().unwrap_err(),
+            "`` is not a number of bytes"
+        );
+        assert_eq!(
+            params("0,1024").boundaries().unwrap_err(),
+            "bucket boundaries must be greater than zero"
+        );
+        assert_eq!(
+            params("1024,1024").boundaries().unwrap_err(),
+            "bucket boundaries must be strictly increasing"
+        );
+    }
+}
@@ -0,0 +1,55 @@
+//! Parameters related to expanding referenced entities.
+
+use serde::Deserialize;
+use serde::Serialize;
+use utoipa::IntoParams;
+use utoipa::ToSchema;
+
+/// A referenced entity that can be embedded within a response.
+#[derive(Clone, Copy, Debug, Deserialize, Eq, PartialEq, Serialize, ToSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum Expand {
+    /// The subject from which each sample was derived.
+    Subject,
+}
+
+impl std::fmt::Display for Expand {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Expand::Subject => write!(f, "subject"),
+        }
+    }
+}
+
+/// Optional parameters for embedding referenced entities within a response.
+#[derive(Debug, Default, Deserialize, IntoParams, Serialize)]
+#[into_params(parameter_in = Query)]
+pub struct ExpandParams {
+    /// When set to `subject`, each unique subject referenced by the samples
+    /// within the page is embedded once within the `_embedded.subjects` map
+    /// (keyed by `<organization>/<namespace>/<name>`).
+    ///
+    /// The `subject` identifier of each sample is the reference to its
+    /// embedded subject. Because expanding a page increases the size of the
+    /// response, servers may reject a `per_page` that is too large when
+    /// combined with `expand` (with a `422` describing the limit).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[param(required = false, nullable = false, inline)]
+    expand: Option<Expand>,
+}
+
+impl ExpandParams {
+    /// Gets the entity to expand from the [`ExpandParams`] (if any).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ccdi_server as server;
+    ///
+    /// let params = server::params::ExpandParams::default();
+    /// assert_eq!(params.expand(), None);
+    /// ```
+    pub fn expand(&self) -> Option<Expand> {
+        self.expand
+    }
+}
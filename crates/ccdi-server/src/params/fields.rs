@@ -0,0 +1,45 @@
+//! Parameters related to selecting metadata fields.
+
+use serde::Deserialize;
+use serde::Serialize;
+use utoipa::IntoParams;
+
+/// Optional parameters for selecting which metadata fields are returned.
+#[derive(Debug, Default, Deserialize, IntoParams, Serialize)]
+#[into_params(parameter_in = Query)]
+pub struct FieldsParams {
+    /// A comma-separated list of the metadata fields to include within the
+    /// `metadata` block of each entity (e.g., `diagnosis,age_at_diagnosis`).
+    ///
+    /// Harmonized fields are selected by their key. Unharmonized fields are
+    /// selected by their key prefixed with `unharmonized.` (e.g.,
+    /// `unharmonized.some_key`), and `unharmonized` selects every unharmonized
+    /// field. Fields that are not selected are omitted from the response
+    /// (rather than being rendered as `null`). When this parameter is not
+    /// provided, every metadata field is included.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[param(required = false, nullable = false)]
+    fields: Option<String>,
+}
+
+impl FieldsParams {
+    /// Gets the names of the selected fields from the [`FieldsParams`] (if
+    /// any).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ccdi_server as server;
+    ///
+    /// let params = server::params::FieldsParams::default();
+    /// assert_eq!(params.fields(), None);
+    /// ```
+    pub fn fields(&self) -> Option<Vec<String>> {
+        self.fields.as_ref().map(|fields| {
+            fields
+                .split(',')
+                .map(|field| field.trim().to_string())
+                .collect()
+        })
+    }
+}
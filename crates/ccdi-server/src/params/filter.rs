@@ -4,15 +4,78 @@ use introspect::Introspect;
 use serde::Deserialize;
 use serde::Serialize;
 use utoipa::IntoParams;
+use utoipa::ToSchema;
+
+use ccdi_models as models;
+
+use models::metadata::common::age::Unit;
+
+use crate::filter::rule::Case;
+
+/// The value of a numeric filter parameter.
+///
+/// Query parameters are always strings, so a range is provided as a JSON object
+/// within the value of the parameter (e.g.,
+/// `?age_at_collection={"gte":0,"lte":1826.25}`).
+///
+/// Numbers are interpreted as a number of days unless a `unit` is provided, in
+/// which case they are converted to days before matching (e.g.,
+/// `?age_at_diagnosis={"gte":2,"unit":"years"}`).
+#[derive(Debug, Deserialize, Serialize, ToSchema)]
+#[serde(untagged)]
+pub enum Numeric {
+    /// A number, which is matched exactly.
+    Number(f64),
+
+    /// A number along with its unit, which is matched exactly.
+    WithUnit {
+        /// The number.
+        value: f64,
+
+        /// The unit of the number.
+        unit: Unit,
+    },
+
+    /// A range of numbers. At least one bound must be provided, and at most
+    /// one of `gt` and `gte` (and of `lt` and `lte`) may be provided.
+    Range {
+        /// The value must be greater than this bound.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        gt: Option<f64>,
+
+        /// The value must be greater than or equal to this bound.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        gte: Option<f64>,
+
+        /// The value must be less than this bound.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        lt: Option<f64>,
+
+        /// The value must be less than or equal to this bound.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        lte: Option<f64>,
+
+        /// The unit of the bounds (days when not provided).
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        unit: Option<Unit>,
+    },
+
+    /// A negated query (e.g., `not:365.25`, `not:{"lte":1826.25}`, or
+    /// `not:null`).
+    Negated(String),
+}
 
 /// Parameters for filtering subjects.
 ///
 /// None of the parameters are required, but they may be provided as a
 /// [`String`]. When a parameter is provided, the endpoint will filter the
-/// results to only include [`Subject`]s where the value for the key exactly
-/// matches the value provided for the parameter (i.e., matching is done by
-/// looking for the provided parameter as a substring). Matches are
-/// case-sensitive.
+/// results to only include [`Subject`]s where the value for the key matches the
+/// value provided for the parameter. How each parameter is matched is declared
+/// by the filter engine's rule table (see [`crate::filter::rule`]).
+///
+/// Parameters matched against text may instead be provided as a JSON array of
+/// values (e.g., `["F","M"]`), in which case the value for the key must match
+/// _any_ of the values within the array.
 #[derive(Debug, Default, Deserialize, IntoParams, Introspect, Serialize)]
 #[into_params(parameter_in = Query)]
 pub struct Subject {
@@ -23,10 +86,7 @@ pub struct Subject {
 
     /// Matches any subject where any member of the `race` field matches the
     /// string provided.
-    ///
-    /// **Note:** a logical OR (`||`) is performed across the values when
-    /// determining whether the subject should be included in the results.
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[serde(default, alias = "races", skip_serializing_if = "Option::is_none")]
     #[param(required = false, nullable = false)]
     pub race: Option<String>,
 
@@ -38,10 +98,7 @@ pub struct Subject {
 
     /// Matches any subject where any member of the `identifiers` field matches
     /// the string provided.
-    ///
-    /// **Note:** a logical OR (`||`) is performed across the values when
-    /// determining whether the subject should be included in the results.
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[serde(default, alias = "identifier", skip_serializing_if = "Option::is_none")]
     #[param(required = false, nullable = false)]
     pub identifiers: Option<String>,
 
@@ -52,37 +109,60 @@ pub struct Subject {
     pub vital_status: Option<String>,
 
     /// Matches any subject where the `age_at_vital_status` field matches the
-    /// string provided.
+    /// number provided or falls within the range provided.
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    #[param(required = false, nullable = false)]
+    #[param(required = false, nullable = false, value_type = Option<Numeric>, inline)]
     pub age_at_vital_status: Option<String>,
 
     /// Matches any subject where any member of the `depositions` fields match
-    /// the string provided.
-    ///
-    /// **Note:** a logical OR (`||`) is performed across the values when
-    /// determining whether the subject should be included in the results.
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    /// the string provided. The dbGaP accession of the study described by
+    /// the namespace of the subject (if any) is also matched.
+    #[serde(default, alias = "deposition", skip_serializing_if = "Option::is_none")]
     #[param(required = false, nullable = false)]
     pub depositions: Option<String>,
+
+    /// When `true`, matches any subject that has metadata. When `false`,
+    /// matches any subject that has no metadata block or whose metadata block
+    /// is effectively empty (every harmonized field is `null` and there are
+    /// no unharmonized fields).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[param(required = false, nullable = false)]
+    pub has_metadata: Option<bool>,
+
+    /// Matches any subject whose identifier is within the namespace provided
+    /// (formatted as `{organization}/{namespace}`, e.g.,
+    /// `example-organization/ExampleNamespaceOne`). A namespace that is not
+    /// known by the server is rejected with an error listing the known
+    /// namespaces.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[param(required = false, nullable = false)]
+    pub namespace: Option<String>,
+
+    /// Matches any subject whose identifier is within a namespace owned by the
+    /// organization provided (e.g., `example-organization`). An organization
+    /// that is not known by the server is rejected with an error listing the
+    /// known organizations.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[param(required = false, nullable = false)]
+    pub organization: Option<String>,
 }
 
 /// Parameters for filtering experimental subject-diagnosis endpoint.
 ///
 /// None of the parameters are required, but they may be provided as a
 /// [`String`]. When a parameter is provided, the endpoint will filter the
-/// results to only include [`Subject`]s where the value for the key exactly
-/// matches the value provided for the parameter (i.e., matching is done by
-/// looking for the provided parameter as a substring). Matches are
-/// case-sensitive.
+/// results to only include [`Subject`]s where the value for the key matches the
+/// value provided for the parameter. How each parameter is matched is declared
+/// by the filter engine's rule table (see [`crate::filter::rule`]).
+///
+/// Parameters matched against text may instead be provided as a JSON array of
+/// values (e.g., `["F","M"]`), in which case the value for the key must match
+/// _any_ of the values within the array.
 #[derive(Debug, Default, Deserialize, IntoParams, Introspect, Serialize)]
 #[into_params(parameter_in = Query)]
 pub struct SubjectDiagnosis {
     /// Matches any subject where any member of the `associated_diagnoses` field contains the
     /// string provided, ignoring case.
-    ///
-    /// **Note:** a logical OR (`||`) is performed across the values when
-    /// determining whether the subject should be included in the results.
     #[serde(default, skip_serializing_if = "Option::is_none")]
     #[param(required = false, nullable = false)]
     pub search: Option<String>,
@@ -94,9 +174,6 @@ pub struct SubjectDiagnosis {
 
     /// Matches any subject where any member of the `race` field matches the
     /// string provided.
-    ///
-    /// **Note:** a logical OR (`||`) is performed across the values when
-    /// determining whether the subject should be included in the results.
     #[serde(default, skip_serializing_if = "Option::is_none")]
     #[param(required = false, nullable = false)]
     pub race: Option<String>,
@@ -109,9 +186,6 @@ pub struct SubjectDiagnosis {
 
     /// Matches any subject where any member of the `identifiers` field matches
     /// the string provided.
-    ///
-    /// **Note:** a logical OR (`||`) is performed across the values when
-    /// determining whether the subject should be included in the results.
     #[serde(default, skip_serializing_if = "Option::is_none")]
     #[param(required = false, nullable = false)]
     pub identifiers: Option<String>,
@@ -129,19 +203,14 @@ pub struct SubjectDiagnosis {
     pub age_at_vital_status: Option<String>,
 
     /// Matches any subject where any member of the `depositions` fields match
-    /// the string provided.
-    ///
-    /// **Note:** a logical OR (`||`) is performed across the values when
-    /// determining whether the subject should be included in the results.
+    /// the string provided. The dbGaP accession of the study described by
+    /// the namespace of the subject (if any) is also matched.
     #[serde(default, skip_serializing_if = "Option::is_none")]
     #[param(required = false, nullable = false)]
     pub depositions: Option<String>,
 
     /// Matches any subject where any member of the `associated_diagnosis_categories` field matches the
     /// string provided.
-    ///
-    /// **Note:** a logical OR (`||`) is performed across the values when
-    /// determining whether the subject should be included in the results.
     #[serde(default, skip_serializing_if = "Option::is_none")]
     #[param(required = false, nullable = false)]
     pub associated_diagnosis_categories: Option<String>,
@@ -151,10 +220,13 @@ pub struct SubjectDiagnosis {
 ///
 /// None of the parameters are required, but they may be provided as a
 /// [`String`]. When a parameter is provided, the endpoint will filter the
-/// results to only include [`Sample`]s where the value for the key exactly
-/// matches the value provided for the parameter (i.e., matching is done by
-/// looking for the provided parameter as a substring). Matches are
-/// case-sensitive.
+/// results to only include [`Sample`]s where the value for the key matches the
+/// value provided for the parameter. How each parameter is matched is declared
+/// by the filter engine's rule table (see [`crate::filter::rule`]).
+///
+/// Parameters matched against text may instead be provided as a JSON array of
+/// values (e.g., `["F","M"]`), in which case the value for the key must match
+/// _any_ of the values within the array.
 #[derive(Debug, Default, Deserialize, IntoParams, Introspect, Serialize)]
 #[into_params(parameter_in = Query)]
 pub struct Sample {
@@ -172,10 +244,11 @@ pub struct Sample {
 
     /// Matches any sample where the `anatomical_sites` field matches the string
     /// provided.
-    ///
-    /// **Note:** a logical OR (`||`) is performed across the values when
-    /// determining whether the subject should be included in the results.
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[serde(
+        default,
+        alias = "anatomical_site",
+        skip_serializing_if = "Option::is_none"
+    )]
     #[param(required = false, nullable = false)]
     pub anatomical_sites: Option<String>,
 
@@ -227,31 +300,45 @@ pub struct Sample {
     #[param(required = false, nullable = false)]
     pub tumor_classification: Option<String>,
 
-    /// Matches any sample where the `age_at_diagnosis` field matches the string
-    /// provided.
+    /// Matches any sample where the `age_at_diagnosis` field matches the number
+    /// provided or falls within the range provided.
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    #[param(required = false, nullable = false)]
+    #[param(required = false, nullable = false, value_type = Option<Numeric>, inline)]
     pub age_at_diagnosis: Option<String>,
 
     /// Matches any sample where the `age_at_collection` field matches the
-    /// string provided.
+    /// number provided or falls within the range provided.
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    #[param(required = false, nullable = false)]
+    #[param(required = false, nullable = false, value_type = Option<Numeric>, inline)]
     pub age_at_collection: Option<String>,
 
     /// Matches any sample where the `tumor_tissue_morphology` field matches the
     /// string provided.
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[serde(
+        default,
+        alias = "tumor_tissue_morphologies",
+        skip_serializing_if = "Option::is_none"
+    )]
     #[param(required = false, nullable = false)]
     pub tumor_tissue_morphology: Option<String>,
 
-    /// Matches any sample where any member of the `depositions` fields match
-    /// the string provided.
-    ///
-    /// **Note:** a logical OR (`||`) is performed across the values when
-    /// determining whether the sample should be included in the results.
+    /// Matches any sample where the `tumor_tissue_site` (ICD-O-3 topography)
+    /// field matches the code provided.
     #[serde(default, skip_serializing_if = "Option::is_none")]
     #[param(required = false, nullable = false)]
+    pub tumor_tissue_site: Option<String>,
+
+    /// Matches any sample where any member of the `identifiers` field matches
+    /// the string provided.
+    #[serde(default, alias = "identifier", skip_serializing_if = "Option::is_none")]
+    #[param(required = false, nullable = false)]
+    pub identifiers: Option<String>,
+
+    /// Matches any sample where any member of the `depositions` fields match
+    /// the string provided. The dbGaP accession of the study described by
+    /// the namespace of the sample (if any) is also matched.
+    #[serde(default, alias = "deposition", skip_serializing_if = "Option::is_none")]
+    #[param(required = false, nullable = false)]
     pub depositions: Option<String>,
 
     /// Matches any sample where the `diagnosis` field matches the
@@ -259,18 +346,44 @@ pub struct Sample {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     #[param(required = false, nullable = false)]
     pub diagnosis: Option<String>,
+
+    /// When `true`, matches any sample that has metadata. When `false`,
+    /// matches any sample that has no metadata block or whose metadata block
+    /// is effectively empty (every harmonized field is `null` and there are
+    /// no unharmonized fields).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[param(required = false, nullable = false)]
+    pub has_metadata: Option<bool>,
+
+    /// Matches any sample whose identifier is within the namespace provided
+    /// (formatted as `{organization}/{namespace}`, e.g.,
+    /// `example-organization/ExampleNamespaceOne`). A namespace that is not
+    /// known by the server is rejected with an error listing the known
+    /// namespaces.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[param(required = false, nullable = false)]
+    pub namespace: Option<String>,
+
+    /// Matches any sample whose identifier is within a namespace owned by the
+    /// organization provided (e.g., `example-organization`). An organization
+    /// that is not known by the server is rejected with an error listing the
+    /// known organizations.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[param(required = false, nullable = false)]
+    pub organization: Option<String>,
 }
 
 /// Parameters for filtering experimental sample-diagnosis endpoint.
 ///
 /// None of the parameters are required, but they may be provided as a
 /// [`String`]. When a parameter is provided, the endpoint will filter the
-/// results to only include [`Sample`]s where the value for the key exactly
-/// matches the value provided for the parameter (i.e., matching is done by
-/// looking for the provided parameter as a substring). Matches are
-/// case-sensitive.
-/// For the "search" parameter only, matching is case-insensitive and requires
-/// only a substring match rather than an exact match.
+/// results to only include [`Sample`]s where the value for the key matches the
+/// value provided for the parameter. How each parameter is matched is declared
+/// by the filter engine's rule table (see [`crate::filter::rule`]).
+///
+/// Parameters matched against text may instead be provided as a JSON array of
+/// values (e.g., `["F","M"]`), in which case the value for the key must match
+/// _any_ of the values within the array.
 #[derive(Debug, Default, Deserialize, IntoParams, Introspect, Serialize)]
 #[into_params(parameter_in = Query)]
 pub struct SampleDiagnosis {
@@ -294,9 +407,6 @@ pub struct SampleDiagnosis {
 
     /// Matches any sample where the `anatomical_sites` field matches the string
     /// provided.
-    ///
-    /// **Note:** a logical OR (`||`) is performed across the values when
-    /// determining whether the subject should be included in the results.
     #[serde(default, skip_serializing_if = "Option::is_none")]
     #[param(required = false, nullable = false)]
     pub anatomical_sites: Option<String>,
@@ -362,10 +472,8 @@ pub struct SampleDiagnosis {
     pub tumor_tissue_morphology: Option<String>,
 
     /// Matches any sample where any member of the `depositions` fields match
-    /// the string provided.
-    ///
-    /// **Note:** a logical OR (`||`) is performed across the values when
-    /// determining whether the sample should be included in the results.
+    /// the string provided. The dbGaP accession of the study described by
+    /// the namespace of the sample (if any) is also matched.
     #[serde(default, skip_serializing_if = "Option::is_none")]
     #[param(required = false, nullable = false)]
     pub depositions: Option<String>,
@@ -381,13 +489,23 @@ pub struct SampleDiagnosis {
 ///
 /// None of the parameters are required, but they may be provided as a
 /// [`String`]. When a parameter is provided, the endpoint will filter the
-/// results to only include [`File`]s where the value for the key exactly
-/// matches the value provided for the parameter (i.e., matching is done by
-/// looking for the provided parameter as a substring). Matches are
-/// case-sensitive.
+/// results to only include [`File`]s where the value for the key matches the
+/// value provided for the parameter. How each parameter is matched is declared
+/// by the filter engine's rule table (see [`crate::filter::rule`]).
+///
+/// Parameters matched against text may instead be provided as a JSON array of
+/// values (e.g., `["F","M"]`), in which case the value for the key must match
+/// _any_ of the values within the array.
 #[derive(Debug, Default, Deserialize, IntoParams, Introspect, Serialize)]
 #[into_params(parameter_in = Query)]
 pub struct File {
+    /// Matches any file where the name within its identifier matches the
+    /// string provided. Names are only unique within a namespace, so this may
+    /// match files in more than one namespace.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[param(required = false, nullable = false)]
+    pub name: Option<String>,
+
     /// Matches any file where the `type` field matches the string provided.
     #[serde(default, skip_serializing_if = "Option::is_none")]
     #[param(required = false, nullable = false)]
@@ -398,30 +516,127 @@ pub struct File {
     #[param(required = false, nullable = false)]
     pub size: Option<String>,
 
-    /// Matches any file where the `checksums` field matches the string
+    /// Matches any file where one of the `checksums` matches the string
     /// provided.
     ///
-    /// **Note:** a logical OR (`||`) is performed across the values when
-    /// determining whether the file should be included in the results.
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    /// The string may either be a digest (e.g.,
+    /// `d41d8cd98f00b204e9800998ecf8427e`) or a digest prefixed by its
+    /// algorithm (e.g., `md5:d41d8cd98f00b204e9800998ecf8427e`). Clients that
+    /// know more than one checksum of a file may instead provide a JSON array
+    /// of strings (any of which may match) or a JSON object of algorithms and
+    /// digests (all of which must match).
+    #[serde(default, alias = "checksum", skip_serializing_if = "Option::is_none")]
     #[param(required = false, nullable = false)]
     pub checksums: Option<String>,
 
     /// Matches any file where the `description` field matches the string
     /// provided.
-    ///
-    /// **Note:** a file is returned if the value provided is a substring of the
-    /// description.
     #[serde(default, skip_serializing_if = "Option::is_none")]
     #[param(required = false, nullable = false)]
     pub description: Option<String>,
 
+    /// Matches any file where the `drs_uri` field matches the string
+    /// provided.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[param(required = false, nullable = false)]
+    pub drs_uri: Option<String>,
+
+    /// Matches any file where the `access_url` field matches the string
+    /// provided.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[param(required = false, nullable = false)]
+    pub access_url: Option<String>,
+
     /// Matches any file where any member of the `depositions` fields match
     /// the string provided.
-    ///
-    /// **Note:** a logical OR (`||`) is performed across the values when
-    /// determining whether the sample should be included in the results.
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[serde(default, alias = "deposition", skip_serializing_if = "Option::is_none")]
     #[param(required = false, nullable = false)]
     pub depositions: Option<String>,
+
+    /// Matches any file where the kind of any of its gateways (`Open`,
+    /// `Registered`, `Controlled`, or `Closed`) matches the string provided.
+    /// For example, `access=open` matches only files that are available
+    /// through an open access gateway.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[param(required = false, nullable = false)]
+    pub access: Option<String>,
+
+    /// When `true`, matches any file that has metadata. When `false`,
+    /// matches any file that has no metadata block or whose metadata block
+    /// is effectively empty (every harmonized field is `null` and there are
+    /// no unharmonized fields).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[param(required = false, nullable = false)]
+    pub has_metadata: Option<bool>,
+
+    /// Matches any file whose identifier is within the namespace provided
+    /// (formatted as `{organization}/{namespace}`, e.g.,
+    /// `example-organization/ExampleNamespaceOne`). A namespace that is not
+    /// known by the server is rejected with an error listing the known
+    /// namespaces.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[param(required = false, nullable = false)]
+    pub namespace: Option<String>,
+
+    /// Matches any file whose identifier is within a namespace owned by the
+    /// organization provided (e.g., `example-organization`). An organization
+    /// that is not known by the server is rejected with an error listing the
+    /// known organizations.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[param(required = false, nullable = false)]
+    pub organization: Option<String>,
+}
+
+/// Parameters for filtering organizations.
+///
+/// None of the parameters are required, but they may be provided as a
+/// [`String`]. When a parameter is provided, the endpoint will filter the
+/// results to only include organizations where the value for the key matches
+/// the value provided for the parameter.
+#[derive(Debug, Default, Deserialize, IntoParams, Serialize)]
+#[into_params(parameter_in = Query)]
+pub struct Organization {
+    /// Matches any organization where any member of the `institution` field
+    /// matches the string provided.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[param(required = false, nullable = false)]
+    pub institution: Option<String>,
+}
+
+/// Parameters that control how filter parameters are matched.
+#[derive(Debug, Default, Deserialize, IntoParams, Serialize)]
+#[into_params(parameter_in = Query)]
+pub struct Matching {
+    /// When `true`, filter parameters (including unharmonized fields) are
+    /// matched without regard to case (e.g., `ethnicity=hispanic or latino`
+    /// matches `Hispanic or Latino`). Matching is case-sensitive by default.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[param(required = false, nullable = false)]
+    pub case_insensitive: Option<bool>,
+}
+
+impl Matching {
+    /// Gets the [`Case`] with which filter parameters are matched.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ccdi_server as server;
+    ///
+    /// use server::filter::rule::Case;
+    /// use server::params::filter::Matching;
+    ///
+    /// assert_eq!(Matching::default().case(), Case::Sensitive);
+    ///
+    /// let params = Matching {
+    ///     case_insensitive: Some(true),
+    /// };
+    /// assert_eq!(params.case(), Case::Insensitive);
+    /// ```
+    pub fn case(&self) -> Case {
+        match self.case_insensitive {
+            Some(true) => Case::Insensitive,
+            _ => Case::Sensitive,
+        }
+    }
 }
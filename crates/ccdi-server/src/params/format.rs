@@ -0,0 +1,95 @@
+//! Parameters related to the format of a listing.
+
+use serde::Deserialize;
+use serde::Serialize;
+use utoipa::IntoParams;
+use utoipa::ToSchema;
+
+use crate::ndjson;
+use crate::params::accept::negotiate;
+
+/// The format of a listing.
+#[derive(Clone, Copy, Debug, Deserialize, Eq, PartialEq, Serialize, ToSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum Format {
+    /// A paginated JSON document (`application/json`).
+    Json,
+
+    /// Newline-delimited JSON (`application/x-ndjson`): every matching entity
+    /// as a JSON object on its own line.
+    Ndjson,
+}
+
+impl Format {
+    /// Negotiates a [`Format`] from the value of an `Accept` header.
+    ///
+    /// See [`negotiate()`] for the details of how the format is chosen.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ccdi_server as server;
+    ///
+    /// use server::params::format::Format;
+    ///
+    /// assert_eq!(
+    ///     Format::from_accept("application/x-ndjson"),
+    ///     Some(Format::Ndjson)
+    /// );
+    /// assert_eq!(
+    ///     Format::from_accept("application/x-ndjson;q=0.5, application/json"),
+    ///     Some(Format::Json)
+    /// );
+    /// assert_eq!(Format::from_accept("*/*"), None);
+    /// ```
+    pub fn from_accept(value: &str) -> Option<Format> {
+        negotiate(
+            value,
+            &[
+                ("application/json", Format::Json),
+                (ndjson::MEDIA_TYPE, Format::Ndjson),
+            ],
+        )
+    }
+}
+
+impl std::fmt::Display for Format {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Format::Json => write!(f, "json"),
+            Format::Ndjson => write!(f, "ndjson"),
+        }
+    }
+}
+
+/// Optional parameters for selecting the format of a listing.
+#[derive(Debug, Default, Deserialize, IntoParams, Serialize)]
+#[into_params(parameter_in = Query)]
+pub struct FormatParams {
+    /// The format of the results (`json` or `ndjson`).
+    ///
+    /// When this parameter is not provided, the format is negotiated using the
+    /// `Accept` header of the request (and defaults to `json`). With `ndjson`,
+    /// every matching entity is streamed as a JSON object on its own line
+    /// rather than as a paginated response, so the pagination parameters may
+    /// not be provided.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[param(required = false, nullable = false, inline)]
+    format: Option<Format>,
+}
+
+impl FormatParams {
+    /// Gets the requested format from the [`FormatParams`] (if any).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ccdi_server as server;
+    ///
+    /// let params = server::params::FormatParams::default();
+    /// assert_eq!(params.format(), None);
+    /// ```
+    pub fn format(&self) -> Option<Format> {
+        self.format
+    }
+}
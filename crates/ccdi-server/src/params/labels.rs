@@ -0,0 +1,118 @@
+//! Parameters related to the labels of permissible values.
+
+use serde::Deserialize;
+use serde::Serialize;
+use utoipa::IntoParams;
+use utoipa::ToSchema;
+
+/// A mode for labeling permissible values.
+#[derive(Clone, Copy, Debug, Deserialize, Eq, PartialEq, Serialize, ToSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum Mode {
+    /// Labels are localized to the languages in the `Accept-Language` header
+    /// (falling back to the English long name of each permissible value).
+    Localized,
+}
+
+/// Optional parameters for requesting labels for permissible values.
+#[derive(Debug, Default, Deserialize, IntoParams, Serialize)]
+#[into_params(parameter_in = Query)]
+pub struct LabelParams {
+    /// When set to `localized`, the permissible values of each harmonized field
+    /// backed by an `enum` are included alongside a display label for each
+    /// value.
+    ///
+    /// Labels are localized according to the `Accept-Language` header of the
+    /// request. When no translation exists for any of the accepted languages,
+    /// the English long name of the permissible value is used. Permissible
+    /// values themselves are never translated.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[param(required = false, nullable = false, inline)]
+    labels: Option<Mode>,
+}
+
+impl LabelParams {
+    /// Gets the labeling mode from the [`LabelParams`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ccdi_server as server;
+    ///
+    /// let params = server::params::LabelParams::default();
+    /// assert_eq!(params.labels(), None);
+    /// ```
+    pub fn labels(&self) -> Option<Mode> {
+        self.labels
+    }
+}
+
+/// Parses the value of an `Accept-Language` header into a list of language
+/// tags ordered by preference.
+///
+/// Tags with a quality of zero, tags with an unparsable quality, and the
+/// wildcard (`*`) are omitted. Tags with equal quality retain their order
+/// within the header.
+///
+/// # Examples
+///
+/// ```
+/// use ccdi_server as server;
+///
+/// use server::params::labels::parse_accept_language;
+///
+/// assert_eq!(
+///     parse_accept_language("en;q=0.5, es-MX, es;q=0.9, *;q=0.1"),
+///     vec![
+///         String::from("es-MX"),
+///         String::from("es"),
+///         String::from("en")
+///     ]
+/// );
+/// ```
+pub fn parse_accept_language(value: &str) -> Vec<String> {
+    let mut tags = value
+        .split(',')
+        .filter_map(|entry| {
+            let mut parts = entry.split(';').map(|part| part.trim());
+
+            let tag = parts.next().filter(|tag| !tag.is_empty() && *tag != "*")?;
+            let quality = match parts.find_map(|part| part.strip_prefix("q=")) {
+                Some(quality) => quality.parse::<f32>().ok()?,
+                None => 1.0,
+            };
+
+            (quality > 0.0).then(|| (tag.to_string(), quality))
+        })
+        .collect::<Vec<_>>();
+
+    // NOTE: this sort is stable, so tags with equal quality retain the order in
+    // which they were provided.
+    tags.sort_by(|(_, a), (_, b)| b.total_cmp(a));
+
+    tags.into_iter().map(|(tag, _)| tag).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_parses_an_accept_language_header() {
+        assert_eq!(parse_accept_language("es"), vec![String::from("es")]);
+        assert_eq!(
+            parse_accept_language("fr;q=0.8,es-MX;q=0.9"),
+            vec![String::from("es-MX"), String::from("fr")]
+        );
+    }
+
+    #[test]
+    fn it_ignores_wildcards_and_unacceptable_languages() {
+        assert!(parse_accept_language("*").is_empty());
+        assert!(parse_accept_language("").is_empty());
+        assert_eq!(
+            parse_accept_language("es;q=0, fr;q=foo, de"),
+            vec![String::from("de")]
+        );
+    }
+}
@@ -0,0 +1,97 @@
+//! Parameters for looking up a single entity by its identifier.
+
+use serde::Deserialize;
+use serde::Serialize;
+use utoipa::IntoParams;
+
+/// Parameters for looking up a file by the namespace to which it belongs and
+/// its name.
+///
+/// Because the name of a file is unique within its namespace, these
+/// parameters match at most one file.
+#[derive(Debug, Deserialize, IntoParams, Serialize)]
+#[into_params(parameter_in = Query)]
+pub struct FileLookupParams {
+    /// The organization identifier of the namespace to which the file belongs.
+    organization: String,
+
+    /// The name of the namespace to which the file belongs.
+    namespace: String,
+
+    /// The name of the file.
+    name: String,
+}
+
+impl FileLookupParams {
+    /// Creates a new [`FileLookupParams`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ccdi_server as server;
+    ///
+    /// use server::params::lookup::FileLookupParams;
+    ///
+    /// let params = FileLookupParams::new("example-organization", "ExampleNamespace", "File1.txt");
+    /// assert_eq!(params.name(), "File1.txt");
+    /// ```
+    pub fn new(
+        organization: impl Into<String>,
+        namespace: impl Into<String>,
+        name: impl Into<String>,
+    ) -> Self {
+        Self {
+            organization: organization.into(),
+            namespace: namespace.into(),
+            name: name.into(),
+        }
+    }
+
+    /// Gets the organization identifier from the [`FileLookupParams`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ccdi_server as server;
+    ///
+    /// use server::params::lookup::FileLookupParams;
+    ///
+    /// let params = FileLookupParams::new("example-organization", "ExampleNamespace", "File1.txt");
+    /// assert_eq!(params.organization(), "example-organization");
+    /// ```
+    pub fn organization(&self) -> &str {
+        &self.organization
+    }
+
+    /// Gets the namespace name from the [`FileLookupParams`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ccdi_server as server;
+    ///
+    /// use server::params::lookup::FileLookupParams;
+    ///
+    /// let params = FileLookupParams::new("example-organization", "ExampleNamespace", "File1.txt");
+    /// assert_eq!(params.namespace(), "ExampleNamespace");
+    /// ```
+    pub fn namespace(&self) -> &str {
+        &self.namespace
+    }
+
+    /// Gets the file name from the [`FileLookupParams`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ccdi_server as server;
+    ///
+    /// use server::params::lookup::FileLookupParams;
+    ///
+    /// let params = FileLookupParams::new("example-organization", "ExampleNamespace", "File1.txt");
+    /// assert_eq!(params.name(), "File1.txt");
+    /// ```
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+}
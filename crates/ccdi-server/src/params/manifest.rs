@@ -0,0 +1,122 @@
+//! Parameters related to file manifests.
+
+use serde::Deserialize;
+use serde::Serialize;
+use utoipa::IntoParams;
+use utoipa::ToSchema;
+
+use crate::params::accept::negotiate;
+
+/// The format of a file manifest.
+#[derive(Clone, Copy, Debug, Deserialize, Eq, PartialEq, Serialize, ToSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum Format {
+    /// A JSON document (`application/json`).
+    Json,
+
+    /// Tab-separated values with a header row (`text/tab-separated-values`).
+    Tsv,
+}
+
+impl Format {
+    /// Negotiates a [`Format`] from the value of an `Accept` header.
+    ///
+    /// See [`negotiate()`] for the details of how the format is chosen.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ccdi_server as server;
+    ///
+    /// use server::params::manifest::Format;
+    ///
+    /// assert_eq!(
+    ///     Format::from_accept("text/tab-separated-values"),
+    ///     Some(Format::Tsv)
+    /// );
+    /// assert_eq!(
+    ///     Format::from_accept("text/tab-separated-values;q=0.5, application/json"),
+    ///     Some(Format::Json)
+    /// );
+    /// assert_eq!(Format::from_accept("*/*"), None);
+    /// ```
+    pub fn from_accept(value: &str) -> Option<Format> {
+        negotiate(
+            value,
+            &[
+                ("application/json", Format::Json),
+                ("text/tab-separated-values", Format::Tsv),
+            ],
+        )
+    }
+}
+
+impl std::fmt::Display for Format {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Format::Json => write!(f, "json"),
+            Format::Tsv => write!(f, "tsv"),
+        }
+    }
+}
+
+/// Optional parameters for selecting the format of a file manifest.
+#[derive(Debug, Default, Deserialize, IntoParams, Serialize)]
+#[into_params(parameter_in = Query)]
+pub struct ManifestParams {
+    /// The format of the manifest (`json` or `tsv`).
+    ///
+    /// When this parameter is not provided, the format is negotiated using the
+    /// `Accept` header of the request (and defaults to `json`).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[param(required = false, nullable = false, inline)]
+    format: Option<Format>,
+}
+
+impl ManifestParams {
+    /// Gets the requested format from the [`ManifestParams`] (if any).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ccdi_server as server;
+    ///
+    /// let params = server::params::ManifestParams::default();
+    /// assert_eq!(params.format(), None);
+    /// ```
+    pub fn format(&self) -> Option<Format> {
+        self.format
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_negotiates_the_format_from_an_accept_header() {
+        assert_eq!(Format::from_accept("application/json"), Some(Format::Json));
+        assert_eq!(
+            Format::from_accept("Text/Tab-Separated-Values; charset=utf-8"),
+            Some(Format::Tsv)
+        );
+        assert_eq!(
+            Format::from_accept("application/json;q=0.9, text/tab-separated-values"),
+            Some(Format::Tsv)
+        );
+        assert_eq!(
+            Format::from_accept("text/tab-separated-values, application/json"),
+            Some(Format::Tsv)
+        );
+    }
+
+    #[test]
+    fn it_ignores_unsupported_and_unacceptable_media_types() {
+        assert_eq!(Format::from_accept(""), None);
+        assert_eq!(Format::from_accept("text/html, */*;q=0.8"), None);
+        assert_eq!(
+            Format::from_accept("text/tab-separated-values;q=0, application/json;q=foo"),
+            None
+        );
+    }
+}
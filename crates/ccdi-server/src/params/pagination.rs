@@ -1,6 +1,9 @@
 //! Parameters related to pagination.
 
+use serde::de;
+use serde::de::Visitor;
 use serde::Deserialize;
+use serde::Deserializer;
 use serde::Serialize;
 use utoipa::IntoParams;
 
@@ -44,12 +47,41 @@ pub struct PaginationParams {
     /// parameter is not provided. That said, the convention within the
     /// community is to use `100` as a default value if any value is equally
     /// reasonable.
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    #[param(required = false, nullable = false)]
+    ///
+    /// Each server can also select a maximum value for `per_page` (this server
+    /// uses `100` unless configured otherwise). Requests for more results per
+    /// page than the maximum, for zero results per page, or for a negative
+    /// number of results per page are rejected with an error (rather than
+    /// being silently clamped).
+    // NOTE: the maximum advertised here must be kept in sync with
+    // [`DEFAULT_MAX_PER_PAGE`](crate::paginate::DEFAULT_MAX_PER_PAGE).
+    #[serde(
+        default,
+        deserialize_with = "deserialize_per_page",
+        skip_serializing_if = "Option::is_none"
+    )]
+    #[param(required = false, nullable = false, minimum = 1, maximum = 100)]
     per_page: Option<usize>,
 }
 
 impl PaginationParams {
+    /// Creates a new [`PaginationParams`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ccdi_server as server;
+    ///
+    /// use server::params::PaginationParams;
+    ///
+    /// let params = PaginationParams::new(Some(2), Some(50));
+    /// assert_eq!(params.page(), Some(2));
+    /// assert_eq!(params.per_page(), Some(50));
+    /// ```
+    pub fn new(page: Option<usize>, per_page: Option<usize>) -> Self {
+        Self { page, per_page }
+    }
+
     /// Gets the page number from the [`PaginationParams`].
     ///
     /// # Examples
@@ -78,3 +110,128 @@ impl PaginationParams {
         self.per_page
     }
 }
+
+/// Deserializes the `per_page` parameter.
+///
+/// Negative values are rejected with a specific message (rather than the
+/// generic message for an unparsable integer).
+fn deserialize_per_page<'de, D>(deserializer: D) -> Result<Option<usize>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    struct PerPageVisitor;
+
+    impl<'de> Visitor<'de> for PerPageVisitor {
+        type Value = Option<usize>;
+
+        fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            formatter.write_str("a positive integer")
+        }
+
+        fn visit_unit<E: de::Error>(self) -> Result<Self::Value, E> {
+            Ok(None)
+        }
+
+        fn visit_u64<E: de::Error>(self, value: u64) -> Result<Self::Value, E> {
+            usize::try_from(value)
+                .map(Some)
+                .map_err(|_| E::custom(format!("`per_page` is too large ({value} was requested)")))
+        }
+
+        fn visit_i64<E: de::Error>(self, value: i64) -> Result<Self::Value, E> {
+            match u64::try_from(value) {
+                Ok(value) => self.visit_u64(value),
+                Err(_) => Err(E::custom(format!(
+                    "`per_page` must not be negative ({value} was requested)"
+                ))),
+            }
+        }
+
+        fn visit_str<E: de::Error>(self, value: &str) -> Result<Self::Value, E> {
+            match value.parse::<i64>() {
+                Ok(value) => self.visit_i64(value),
+                Err(_) => Err(E::custom(format!(
+                    "`per_page` must be a positive integer (`{value}` was requested)"
+                ))),
+            }
+        }
+    }
+
+    deserializer.deserialize_any(PerPageVisitor)
+}
+
+/// Optional parameters for opting out of pagination on endpoints that were
+/// previously unpaginated.
+///
+/// **Deprecated:** this escape hatch is only provided for a single release to
+/// give clients time to adopt the paginated envelope. It will be removed in the
+/// next release.
+#[derive(Debug, Default, Deserialize, IntoParams, Serialize)]
+#[into_params(parameter_in = Query)]
+pub struct Compatibility {
+    /// Whether the results are paginated (defaults to `true`).
+    ///
+    /// **Deprecated.** When `paginate=false` is provided, all results are
+    /// returned as a bare array (rather than within the paginated envelope),
+    /// any pagination parameters are ignored, and the response includes the
+    /// `deprecation` and `warning` headers. This parameter will be removed in
+    /// the next release.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[param(required = false, nullable = false)]
+    pub paginate: Option<bool>,
+}
+
+impl Compatibility {
+    /// Gets whether the results should be paginated.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ccdi_server as server;
+    ///
+    /// use server::params::pagination::Compatibility;
+    ///
+    /// let params = Compatibility::default();
+    /// assert!(params.paginate());
+    ///
+    /// let params = Compatibility {
+    ///     paginate: Some(false),
+    /// };
+    /// assert!(!params.paginate());
+    /// ```
+    pub fn paginate(&self) -> bool {
+        self.paginate.unwrap_or(true)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use actix_web::web::Query;
+
+    use super::*;
+
+    #[test]
+    fn it_deserializes_per_page() {
+        let params = Query::<PaginationParams>::from_query("per_page=50").unwrap();
+        assert_eq!(params.per_page(), Some(50));
+
+        let params = Query::<PaginationParams>::from_query("page=2").unwrap();
+        assert_eq!(params.per_page(), None);
+
+        let params = Query::<PaginationParams>::from_query("per_page=0").unwrap();
+        assert_eq!(params.per_page(), Some(0));
+    }
+
+    #[test]
+    fn it_rejects_a_negative_per_page() {
+        let err = Query::<PaginationParams>::from_query("per_page=-5").unwrap_err();
+        assert!(err
+            .to_string()
+            .contains("`per_page` must not be negative (-5 was requested)"));
+
+        let err = Query::<PaginationParams>::from_query("per_page=ten").unwrap_err();
+        assert!(err
+            .to_string()
+            .contains("`per_page` must be a positive integer (`ten` was requested)"));
+    }
+}
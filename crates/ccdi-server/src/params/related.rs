@@ -0,0 +1,99 @@
+//! Parameters that filter entities by the properties of related entities.
+//!
+//! Subjects may be filtered by the properties of their samples by prefixing
+//! any sample filter parameter with `sample.` (e.g.,
+//! `?sample.library_strategy=RNA-Seq`). The prefixed parameters are split from
+//! the raw query string (see [`split()`]) and, with the prefix removed, are
+//! treated exactly as they would be by the `/sample` endpoint.
+
+use url::form_urlencoded;
+
+use crate::filter::check_keys as check_unprefixed_keys;
+use crate::filter::UNHARMONIZED_PREFIX;
+use crate::responses::error;
+
+/// The prefix of the query parameters that filter subjects by the properties
+/// of their samples.
+pub const SAMPLE_PREFIX: &str = "sample.";
+
+/// Splits a raw `query` string into the parameters that are not prefixed with
+/// `prefix` and the parameters that are (with the prefix removed).
+///
+/// # Examples
+///
+/// ```
+/// use ccdi_server as server;
+///
+/// use server::params::related::split;
+/// use server::params::related::SAMPLE_PREFIX;
+///
+/// let (query, related) = split(
+///     "sex=F&sample.library_strategy=RNA-Seq&page=2",
+///     SAMPLE_PREFIX,
+/// );
+///
+/// assert_eq!(query, "sex=F&page=2");
+/// assert_eq!(related, "library_strategy=RNA-Seq");
+/// ```
+pub fn split(query: &str, prefix: &str) -> (String, String) {
+    let mut unprefixed = form_urlencoded::Serializer::new(String::new());
+    let mut prefixed = form_urlencoded::Serializer::new(String::new());
+
+    for (key, value) in form_urlencoded::parse(query.as_bytes()) {
+        match key.strip_prefix(prefix) {
+            Some(key) => prefixed.append_pair(key, &value),
+            None => unprefixed.append_pair(&key, &value),
+        };
+    }
+
+    (unprefixed.finish(), prefixed.finish())
+}
+
+/// Checks that every key within a `related` query string (as returned by
+/// [`split()`]) is one of the `known` parameter names of the related entity.
+///
+/// This behaves exactly as [`check_keys()`](crate::filter::check_keys), but
+/// each unrecognized key (and each suggestion) is reported with its `prefix`
+/// so that it matches the parameter that was provided.
+///
+/// # Examples
+///
+/// ```
+/// use ccdi_server as server;
+///
+/// use server::filter::parameter_names;
+/// use server::params::filter::Sample as SampleFilterParams;
+/// use server::params::related::check_keys;
+/// use server::params::related::SAMPLE_PREFIX;
+///
+/// let known = parameter_names::<SampleFilterParams>();
+///
+/// assert!(check_keys("library_strategy=RNA-Seq", SAMPLE_PREFIX, &known).is_ok());
+/// assert!(check_keys("metadata.unharmonized.foo=bar", SAMPLE_PREFIX, &known).is_ok());
+///
+/// let err = check_keys("library_stratgy=RNA-Seq", SAMPLE_PREFIX, &known).unwrap_err();
+/// assert_eq!(
+///     err.to_string(),
+///     "Invalid value for parameter 'sample.library_stratgy': unrecognized \
+///      parameter(s): `sample.library_stratgy` (did you mean \
+///      `sample.library_strategy`?)."
+/// );
+/// ```
+pub fn check_keys(related: &str, prefix: &str, known: &[String]) -> Result<(), error::Kind> {
+    let query = form_urlencoded::Serializer::new(String::new())
+        .extend_pairs(
+            form_urlencoded::parse(related.as_bytes())
+                // NOTE: unharmonized fields are always accepted, but they
+                // would no longer be recognized as such once prefixed.
+                .filter(|(key, _)| !key.starts_with(UNHARMONIZED_PREFIX))
+                .map(|(key, value)| (format!("{prefix}{key}"), value)),
+        )
+        .finish();
+
+    let known = known
+        .iter()
+        .map(|name| format!("{prefix}{name}"))
+        .collect::<Vec<_>>();
+
+    check_unprefixed_keys(&query, &known)
+}
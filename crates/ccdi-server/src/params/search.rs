@@ -0,0 +1,52 @@
+//! Parameters for searching across entities by a partial identifier.
+
+use serde::Deserialize;
+use serde::Serialize;
+use utoipa::IntoParams;
+
+/// Parameters for searching for entities by a fragment of their identifiers.
+#[derive(Debug, Deserialize, IntoParams, Serialize)]
+#[into_params(parameter_in = Query)]
+pub struct SearchParams {
+    /// The fragment of the identifier to search for.
+    ///
+    /// Entities whose name (or the name of any of their alternate identifiers)
+    /// contains the fragment are matched (case-insensitively). Surrounding
+    /// whitespace is trimmed, and the fragment must not be empty.
+    q: String,
+}
+
+impl SearchParams {
+    /// Creates a new [`SearchParams`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ccdi_server as server;
+    ///
+    /// use server::params::SearchParams;
+    ///
+    /// let params = SearchParams::new("OZNL7P");
+    /// assert_eq!(params.q(), "OZNL7P");
+    /// ```
+    pub fn new(q: impl Into<String>) -> Self {
+        Self { q: q.into() }
+    }
+
+    /// Gets the fragment to search for from the [`SearchParams`] (with any
+    /// surrounding whitespace trimmed).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ccdi_server as server;
+    ///
+    /// use server::params::SearchParams;
+    ///
+    /// let params = SearchParams::new("  OZNL7P ");
+    /// assert_eq!(params.q(), "OZNL7P");
+    /// ```
+    pub fn q(&self) -> &str {
+        self.q.trim()
+    }
+}
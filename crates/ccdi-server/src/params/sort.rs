@@ -0,0 +1,120 @@
+//! Parameters related to sorting.
+
+use serde::Deserialize;
+use serde::Serialize;
+use url::form_urlencoded;
+use utoipa::IntoParams;
+use utoipa::ToSchema;
+
+/// The direction in which results are sorted.
+#[derive(Clone, Copy, Debug, Default, Deserialize, Eq, PartialEq, Serialize, ToSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum Order {
+    /// Ascending order.
+    #[default]
+    Asc,
+
+    /// Descending order.
+    Desc,
+}
+
+impl std::fmt::Display for Order {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Order::Asc => write!(f, "asc"),
+            Order::Desc => write!(f, "desc"),
+        }
+    }
+}
+
+/// Optional parameters for sorting the results of a listing endpoint.
+#[derive(Debug, Default, Deserialize, IntoParams, Serialize)]
+#[into_params(parameter_in = Query)]
+pub struct SortParams {
+    /// The harmonized metadata field to sort the results by (e.g.,
+    /// `age_at_diagnosis`).
+    ///
+    /// Only a subset of the harmonized fields of each entity are sortable, and
+    /// requesting any other field is an error. Entities without a value for
+    /// the field are always sorted last (regardless of `order`), and ties are
+    /// broken by the identifier of each entity. When this parameter is not
+    /// provided, results are sorted by identifier.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[param(required = false, nullable = false)]
+    sort: Option<String>,
+
+    /// The direction in which to sort the results (`asc` or `desc`). Defaults
+    /// to `asc`, and may only be provided along with `sort`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[param(required = false, nullable = false, inline)]
+    order: Option<Order>,
+}
+
+impl SortParams {
+    /// Gets the field to sort by from the [`SortParams`] (if any).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ccdi_server as server;
+    ///
+    /// let params = server::params::SortParams::default();
+    /// assert_eq!(params.sort(), None);
+    /// ```
+    pub fn sort(&self) -> Option<&str> {
+        self.sort.as_deref()
+    }
+
+    /// Gets the requested [`Order`] from the [`SortParams`] (if any).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ccdi_server as server;
+    ///
+    /// let params = server::params::SortParams::default();
+    /// assert_eq!(params.order(), None);
+    /// ```
+    pub fn order(&self) -> Option<Order> {
+        self.order
+    }
+
+    /// Appends the sort parameters (if any) to the query of a `base` URL so
+    /// that they are preserved within the links of a paginated response.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use actix_web::web::Query;
+    /// use ccdi_server as server;
+    ///
+    /// use server::params::SortParams;
+    ///
+    /// let base = "http://localhost:8000/sample";
+    ///
+    /// let params = SortParams::default();
+    /// assert_eq!(params.url(base), base);
+    ///
+    /// let params = Query::<SortParams>::from_query("sort=diagnosis&order=desc").unwrap();
+    /// assert_eq!(
+    ///     params.url(base),
+    ///     "http://localhost:8000/sample?sort=diagnosis&order=desc"
+    /// );
+    /// ```
+    pub fn url(&self, base: &str) -> String {
+        let mut query = form_urlencoded::Serializer::new(String::new());
+
+        if let Some(sort) = &self.sort {
+            query.append_pair("sort", sort);
+        }
+
+        if let Some(order) = &self.order {
+            query.append_pair("order", &order.to_string());
+        }
+
+        match query.finish() {
+            query if query.is_empty() => base.to_string(),
+            query => format!("{base}?{query}"),
+        }
+    }
+}
@@ -0,0 +1,38 @@
+//! Parameters related to responses that may be rendered as a table.
+
+use serde::Deserialize;
+use serde::Serialize;
+use utoipa::IntoParams;
+
+use crate::params::manifest::Format;
+
+/// Optional parameters for selecting whether a response is rendered as JSON or
+/// as a table.
+#[derive(Debug, Default, Deserialize, IntoParams, Serialize)]
+#[into_params(parameter_in = Query)]
+pub struct TabularParams {
+    /// The format of the results (`json` or `tsv`).
+    ///
+    /// When this parameter is not provided, the format is negotiated using the
+    /// `Accept` header of the request (and defaults to `json`). With `tsv`, the
+    /// results are flattened into tab-separated values with a header row.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[param(required = false, nullable = false, inline)]
+    format: Option<Format>,
+}
+
+impl TabularParams {
+    /// Gets the requested format from the [`TabularParams`] (if any).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ccdi_server as server;
+    ///
+    /// let params = server::params::TabularParams::default();
+    /// assert_eq!(params.format(), None);
+    /// ```
+    pub fn format(&self) -> Option<Format> {
+        self.format
+    }
+}
@@ -0,0 +1,32 @@
+//! Parameters related to validating a single entity.
+
+use serde::Deserialize;
+use serde::Serialize;
+use utoipa::IntoParams;
+
+/// Optional parameters for validating a single entity.
+#[derive(Debug, Default, Deserialize, IntoParams, Serialize)]
+#[into_params(parameter_in = Query)]
+pub struct ValidateParams {
+    /// When `true`, the consistency of the entity is checked and the findings
+    /// are included within the response (under the `findings` key).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[param(required = false, nullable = false)]
+    validate: Option<bool>,
+}
+
+impl ValidateParams {
+    /// Gets whether validation was requested.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ccdi_server as server;
+    ///
+    /// let params = server::params::ValidateParams::default();
+    /// assert!(!params.validate());
+    /// ```
+    pub fn validate(&self) -> bool {
+        self.validate.unwrap_or_default()
+    }
+}
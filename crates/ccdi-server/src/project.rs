@@ -0,0 +1,323 @@
+//! Projecting the metadata of entities onto a selection of fields.
+//!
+//! Consumers that only need a handful of metadata fields can select them with
+//! the `fields` parameter (see [`FieldsParams`](crate::params::FieldsParams)).
+//! Responses are serialized as usual, and then the `metadata` block of each
+//! entity within `data` is pruned to only the selected fields.
+
+use serde::Serialize;
+use serde_json::Map;
+use serde_json::Value;
+
+use crate::params::FieldsParams;
+use crate::responses::error;
+
+/// The key of the unharmonized fields within a metadata block.
+const UNHARMONIZED: &str = "unharmonized";
+
+/// Gets the keys of the harmonized fields within a metadata block.
+///
+/// Every harmonized field is serialized (as `null` when it has no value), so
+/// the keys are read from the serialized form of an empty metadata block.
+///
+/// # Examples
+///
+/// ```
+/// use ccdi_models as models;
+/// use ccdi_server as server;
+///
+/// use server::project::harmonized_keys;
+///
+/// let keys = harmonized_keys(models::subject::metadata::Builder::default().build());
+/// assert!(keys.contains(&String::from("sex")));
+/// assert!(!keys.contains(&String::from("unharmonized")));
+/// ```
+pub fn harmonized_keys(metadata: impl Serialize) -> Vec<String> {
+    // SAFETY: metadata blocks are always serializable to a JSON object.
+    match serde_json::to_value(metadata).unwrap() {
+        Value::Object(metadata) => metadata
+            .into_iter()
+            .map(|(key, _)| key)
+            .filter(|key| key != UNHARMONIZED)
+            .collect(),
+        _ => unreachable!("metadata blocks are always serialized as objects"),
+    }
+}
+
+/// Gets the [`Selection`] requested within the [`FieldsParams`] (if any) given
+/// an empty metadata block for the entity.
+///
+/// # Examples
+///
+/// ```
+/// use ccdi_models as models;
+/// use ccdi_server as server;
+///
+/// use server::params::FieldsParams;
+/// use server::project::select;
+///
+/// let selection = select(
+///     &FieldsParams::default(),
+///     models::subject::metadata::Builder::default().build(),
+/// )
+/// .unwrap();
+/// assert!(selection.is_none());
+/// ```
+pub fn select(
+    params: &FieldsParams,
+    metadata: impl Serialize,
+) -> Result<Option<Selection>, error::Kind> {
+    params
+        .fields()
+        .map(|fields| Selection::try_new(&fields, &harmonized_keys(metadata)))
+        .transpose()
+}
+
+/// A selection of metadata fields.
+#[derive(Debug)]
+pub struct Selection {
+    /// The selected harmonized fields.
+    harmonized: Vec<String>,
+
+    /// The selected unharmonized fields.
+    unharmonized: Vec<String>,
+
+    /// Whether every unharmonized field is selected.
+    all_unharmonized: bool,
+}
+
+impl Selection {
+    /// Attempts to create a new [`Selection`] from the names of the selected
+    /// `fields` given the `harmonized` keys of the entity.
+    ///
+    /// Unharmonized fields are named `unharmonized.<key>` (or `unharmonized`
+    /// for every unharmonized field). An
+    /// [`InvalidParameters`](error::Kind::invalid_parameters) error that lists
+    /// each of the unknown names is returned if any of the names are not a
+    /// harmonized key or an unharmonized field.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ccdi_server as server;
+    ///
+    /// use server::project::Selection;
+    ///
+    /// let harmonized = vec![String::from("sex"), String::from("race")];
+    ///
+    /// assert!(Selection::try_new(&[String::from("sex")], &harmonized).is_ok());
+    /// assert!(Selection::try_new(&[String::from("unharmonized.foo")], &harmonized).is_ok());
+    /// assert!(Selection::try_new(&[String::from("foo")], &harmonized).is_err());
+    /// ```
+    pub fn try_new(fields: &[String], harmonized: &[String]) -> Result<Self, error::Kind> {
+        let mut selection = Self {
+            harmonized: Vec::new(),
+            unharmonized: Vec::new(),
+            all_unharmonized: false,
+        };
+        let mut unknown = Vec::new();
+
+        for field in fields {
+            if harmonized.contains(field) {
+                selection.harmonized.push(field.clone());
+            } else if field == UNHARMONIZED {
+                selection.all_unharmonized = true;
+            } else {
+                match field
+                    .strip_prefix(UNHARMONIZED)
+                    .and_then(|key| key.strip_prefix('.'))
+                {
+                    Some(key) if !key.is_empty() => selection.unharmonized.push(key.to_string()),
+                    _ => unknown.push(format!("`{field}`")),
+                }
+            }
+        }
+
+        if !unknown.is_empty() {
+            return Err(error::Kind::invalid_parameters(
+                Some(vec![String::from("fields")]),
+                // NOTE: the reason ends with a backtick, which would otherwise
+                // be left without a period when it is formalized.
+                format!("unknown field(s): {}.", unknown.join(", ")),
+            ));
+        }
+
+        Ok(selection)
+    }
+
+    /// Projects the `metadata` block of each entity within the `data` of a
+    /// response onto the [`Selection`].
+    ///
+    /// Entities without metadata are left as they are.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ccdi_server as server;
+    /// use serde_json::json;
+    ///
+    /// use server::project::Selection;
+    ///
+    /// let harmonized = vec![String::from("sex"), String::from("race")];
+    /// let selection = Selection::try_new(
+    ///     &[String::from("sex"), String::from("unharmonized.foo")],
+    ///     &harmonized,
+    /// )
+    /// .unwrap();
+    ///
+    /// let response = json!({
+    ///     "data": [
+    ///         {
+    ///             "metadata": {
+    ///                 "sex": { "value": "F" },
+    ///                 "race": null,
+    ///                 "unharmonized": { "foo": 1, "bar": 2 }
+    ///             }
+    ///         },
+    ///         { "metadata": null }
+    ///     ]
+    /// });
+    ///
+    /// assert_eq!(
+    ///     selection.project(&response),
+    ///     json!({
+    ///         "data": [
+    ///             {
+    ///                 "metadata": {
+    ///                     "sex": { "value": "F" },
+    ///                     "unharmonized": { "foo": 1 }
+    ///                 }
+    ///             },
+    ///             { "metadata": null }
+    ///         ]
+    ///     })
+    /// );
+    /// ```
+    pub fn project(&self, response: &impl Serialize) -> Value {
+        // SAFETY: responses are always serializable to JSON.
+        let mut response = serde_json::to_value(response).unwrap();
+
+        if let Some(Value::Array(entities)) = response.get_mut("data") {
+            for entity in entities {
+                if let Some(Value::Object(metadata)) = entity.get_mut("metadata") {
+                    self.project_metadata(metadata);
+                }
+            }
+        }
+
+        response
+    }
+
+    /// Projects the `metadata` block of a single entity onto the [`Selection`]
+    /// (e.g., for each line of a streamed response).
+    ///
+    /// An entity without metadata is left as it is.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ccdi_server as server;
+    /// use serde_json::json;
+    ///
+    /// use server::project::Selection;
+    ///
+    /// let harmonized = vec![String::from("sex"), String::from("race")];
+    /// let selection = Selection::try_new(&[String::from("sex")], &harmonized).unwrap();
+    ///
+    /// let entity = json!({
+    ///     "id": "Subject1",
+    ///     "metadata": { "sex": { "value": "F" }, "race": null }
+    /// });
+    ///
+    /// assert_eq!(
+    ///     selection.project_entity(&entity),
+    ///     json!({ "id": "Subject1", "metadata": { "sex": { "value": "F" } } })
+    /// );
+    /// ```
+    pub fn project_entity(&self, entity: &impl Serialize) -> Value {
+        // SAFETY: entities are always serializable to JSON.
+        let mut entity = serde_json::to_value(entity).unwrap();
+
+        if let Some(Value::Object(metadata)) = entity.get_mut("metadata") {
+            self.project_metadata(metadata);
+        }
+
+        entity
+    }
+
+    /// Prunes a metadata block to only the fields within the [`Selection`].
+    fn project_metadata(&self, metadata: &mut Map<String, Value>) {
+        metadata.retain(|key, value| {
+            if key != UNHARMONIZED {
+                return self.harmonized.contains(key);
+            }
+
+            if self.all_unharmonized {
+                return true;
+            }
+
+            match value {
+                Value::Object(fields) => {
+                    fields.retain(|key, _| self.unharmonized.contains(key));
+                    !fields.is_empty()
+                }
+                _ => false,
+            }
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    fn fields(names: &[&str]) -> Vec<String> {
+        names.iter().map(|name| name.to_string()).collect()
+    }
+
+    #[test]
+    fn it_lists_every_unknown_field() {
+        let err = Selection::try_new(
+            &fields(&["sex", "foo", "unharmonized.", "bar"]),
+            &fields(&["sex"]),
+        )
+        .unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            "Invalid value for parameter 'fields': unknown field(s): `foo`, `unharmonized.`, \
+             `bar`."
+        );
+    }
+
+    #[test]
+    fn it_selects_every_unharmonized_field() {
+        let selection = Selection::try_new(&fields(&["unharmonized"]), &fields(&["sex"])).unwrap();
+
+        let response = json!({
+            "data": [{ "metadata": { "sex": null, "unharmonized": { "foo": 1 } } }]
+        });
+
+        assert_eq!(
+            selection.project(&response),
+            json!({ "data": [{ "metadata": { "unharmonized": { "foo": 1 } } }] })
+        );
+    }
+
+    #[test]
+    fn it_omits_unharmonized_fields_that_are_not_present() {
+        let selection =
+            Selection::try_new(&fields(&["sex", "unharmonized.foo"]), &fields(&["sex"])).unwrap();
+
+        let response = json!({
+            "data": [{ "metadata": { "sex": null, "unharmonized": { "bar": 1 } } }]
+        });
+
+        assert_eq!(
+            selection.project(&response),
+            json!({ "data": [{ "metadata": { "sex": null } }] })
+        );
+    }
+}
@@ -1,27 +1,37 @@
 //! Responses for the server.
 
+pub mod aggregate;
 pub mod by;
+pub mod demographics;
 pub mod entity;
 pub mod error;
+pub mod examples;
 pub mod file;
 pub mod info;
+pub mod manifest;
 pub mod metadata;
-mod namespace;
+pub mod namespace;
 mod organization;
-mod sample;
-mod subject;
+pub mod sample;
+pub mod search;
+pub mod subject;
 pub mod summary;
+pub mod validation;
+pub mod values;
 
+pub use demographics::Demographics;
 pub use error::Errors;
 pub use file::File;
 pub use file::Files;
 pub use info::Information;
+pub use manifest::FileManifest;
 pub use namespace::Namespace;
 pub use namespace::Namespaces;
 pub use organization::Organization;
 pub use organization::Organizations;
 pub use sample::Sample;
 pub use sample::Samples;
+pub use search::Search;
 pub use subject::Subject;
 pub use subject::Subjects;
 pub use summary::Summary;
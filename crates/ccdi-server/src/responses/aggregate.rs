@@ -0,0 +1,125 @@
+//! Responses for grouping by fields across multiple entities and counting them.
+
+use std::collections::BTreeMap;
+
+use serde::Deserialize;
+use serde::Serialize;
+use utoipa::ToSchema;
+
+use crate::responses;
+use crate::responses::by::count::file;
+use crate::responses::by::count::sample;
+use crate::responses::by::count::subject;
+use crate::responses::by::count::ValueCount;
+
+/// A set of results from grouping one kind of entity by a metadata field and
+/// then summing the counts for each value.
+///
+/// These results have the same shape as those returned by the individual
+/// `by/{field}/count` endpoints for each entity.
+#[derive(Debug, Deserialize, Serialize, ToSchema)]
+#[schema(as = responses::aggregate::Results)]
+pub struct Results {
+    /// The total number of counts in this result set.
+    pub total: usize,
+
+    /// The total number of entries that are missing values. In this context,
+    /// "missing" means either (a) the individual metadata key is missing or (b)
+    /// the entire metadata object is missing.
+    pub missing: usize,
+
+    /// The counts per value observed for the result set.
+    #[schema(value_type = Vec<responses::by::count::ValueCount>)]
+    pub values: Vec<ValueCount>,
+}
+
+impl From<subject::Results> for Results {
+    fn from(results: subject::Results) -> Self {
+        Self {
+            total: results.total,
+            missing: results.missing,
+            values: results.values,
+        }
+    }
+}
+
+impl From<sample::Results> for Results {
+    fn from(results: sample::Results) -> Self {
+        Self {
+            total: results.total,
+            missing: results.missing,
+            values: results.values,
+        }
+    }
+}
+
+impl From<file::Results> for Results {
+    fn from(results: file::Results) -> Self {
+        Self {
+            total: results.total,
+            missing: results.missing,
+            values: results.values,
+        }
+    }
+}
+
+/// A set of counts for multiple entities and fields returned in a single
+/// response.
+///
+/// Each key is the name of the entity and the name of the field joined by a
+/// period (e.g., `subject.sex` or `sample.library_strategy`). Keys are always
+/// serialized in lexicographical order.
+#[derive(Debug, Default, Deserialize, Serialize, ToSchema)]
+#[schema(as = responses::aggregate::Counts)]
+pub struct Counts {
+    /// The results keyed by entity and field.
+    #[serde(flatten)]
+    inner: BTreeMap<String, responses::aggregate::Results>,
+}
+
+impl Counts {
+    /// Inserts the [`Results`] for an entity and field into the [`Counts`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ccdi_server as server;
+    ///
+    /// use server::responses::aggregate::Counts;
+    /// use server::responses::by::count::subject;
+    /// use server::responses::by::count::ValueCount;
+    ///
+    /// let mut counts = Counts::default();
+    /// counts.insert(
+    ///     String::from("subject.sex"),
+    ///     subject::Results::new(
+    ///         vec![ValueCount {
+    ///             value: "F".into(),
+    ///             count: 3,
+    ///         }],
+    ///         1,
+    ///     ),
+    /// );
+    ///
+    /// assert_eq!(counts.inner().get("subject.sex").unwrap().total, 4);
+    /// ```
+    pub fn insert(&mut self, key: String, results: impl Into<Results>) {
+        self.inner.insert(key, results.into());
+    }
+
+    /// Gets a reference to the inner [`BTreeMap`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ccdi_server as server;
+    ///
+    /// use server::responses::aggregate::Counts;
+    ///
+    /// let counts = Counts::default();
+    /// assert!(counts.inner().is_empty());
+    /// ```
+    pub fn inner(&self) -> &BTreeMap<String, Results> {
+        &self.inner
+    }
+}
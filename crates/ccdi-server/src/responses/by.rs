@@ -1,3 +1,4 @@
 //! Responses related to grouping by fields.
 
 pub mod count;
+pub mod group;
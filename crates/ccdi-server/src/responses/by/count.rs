@@ -19,3 +19,48 @@ pub struct ValueCount {
     /// The number of times the value was counted.
     pub count: usize,
 }
+
+/// Truncates a set of [`ValueCount`]s to the `n` most common values.
+///
+/// The values are expected to already be sorted in descending order by count.
+/// The sum of the counts for the values that were truncated is returned
+/// alongside the retained values.
+///
+/// # Examples
+///
+/// ```
+/// use ccdi_server as server;
+///
+/// use server::responses::by::count::top;
+/// use server::responses::by::count::ValueCount;
+///
+/// let counts = vec![
+///     ValueCount {
+///         value: "Neuroblastoma".into(),
+///         count: 3,
+///     },
+///     ValueCount {
+///         value: "Osteosarcoma".into(),
+///         count: 2,
+///     },
+///     ValueCount {
+///         value: "Ewing Sarcoma".into(),
+///         count: 1,
+///     },
+/// ];
+///
+/// let (values, other) = top(counts, 1);
+///
+/// assert_eq!(values.len(), 1);
+/// assert_eq!(values[0].value, "Neuroblastoma");
+/// assert_eq!(other, 3);
+/// ```
+pub fn top(mut values: Vec<ValueCount>, n: usize) -> (Vec<ValueCount>, usize) {
+    let other = values
+        .split_off(n.min(values.len()))
+        .into_iter()
+        .map(|value| value.count)
+        .sum::<usize>();
+
+    (values, other)
+}
@@ -2,6 +2,7 @@
 
 use serde::Deserialize;
 use serde::Serialize;
+use serde_json::Value;
 use utoipa::ToSchema;
 
 use crate::responses::by::count::ValueCount;
@@ -66,3 +67,151 @@ impl Results {
         }
     }
 }
+
+/// A value along with the number of counted files for that value and the
+/// total size of those files.
+#[derive(Debug, Deserialize, Serialize, ToSchema)]
+#[schema(as = responses::by::count::file::SizedValueCount)]
+pub struct SizedValueCount {
+    /// The value.
+    pub value: Value,
+
+    /// The number of files that were counted for the value.
+    pub count: usize,
+
+    /// The total size (in bytes) of the files that were counted for the
+    /// value. Files without a size do not contribute to the total. The total
+    /// is capped at the largest size that the server can represent.
+    pub bytes: usize,
+}
+
+/// A response from grouping [`Files`](ccdi_models::File) by a specified
+/// metadata field and then summing the counts (and sizes) of the files for
+/// each value.
+///
+/// When grouping by `size`, each value is a bucket of file sizes: an object
+/// with the inclusive lower boundary (`min`) and the exclusive upper boundary
+/// (`max`, which is `null` for the last bucket) of the bucket in bytes.
+#[derive(Debug, Deserialize, Serialize, ToSchema)]
+#[schema(as = responses::by::count::file::Response)]
+pub struct Response {
+    /// The total number of counts in this result set.
+    pub total: usize,
+
+    /// The total number of entries that are missing values. In this context,
+    /// "missing" means either (a) the individual metadata key is missing or (b)
+    /// the entire metadata object is missing. When `distinguish_missing=true`
+    /// is provided, only (b) is counted here (see `null`).
+    pub missing: usize,
+
+    /// The total number of entries that have a metadata object but no value
+    /// for the field (whether the key is missing or explicitly `null`). This
+    /// is only reported when `distinguish_missing=true` is provided, in which
+    /// case no `null` value is included within `values`.
+    #[schema(nullable = false)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub null: Option<usize>,
+
+    /// The total size (in bytes) of the files within this result set
+    /// (including the files that are missing values). The total is capped at
+    /// the largest size that the server can represent.
+    pub bytes: usize,
+
+    /// The counts per value observed for the result set.
+    #[schema(value_type = Vec<responses::by::count::file::SizedValueCount>)]
+    pub values: Vec<SizedValueCount>,
+}
+
+impl Response {
+    /// Creates a new [`Response`] from a [`Vec<SizedValueCount>`] along with the
+    /// number and the total size of the files that are missing values.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ccdi_server as server;
+    ///
+    /// use server::responses::by::count::file::Response;
+    /// use server::responses::by::count::file::SizedValueCount;
+    ///
+    /// let counts = vec![
+    ///     SizedValueCount {
+    ///         value: "BAM".into(),
+    ///         count: 2,
+    ///         bytes: 2048,
+    ///     },
+    ///     SizedValueCount {
+    ///         value: "VCF".into(),
+    ///         count: 1,
+    ///         bytes: 512,
+    ///     },
+    /// ];
+    ///
+    /// let response = Response::new(counts, 1, 256);
+    ///
+    /// assert_eq!(response.total, 4);
+    /// assert_eq!(response.bytes, 2816);
+    ///
+    /// // The total size saturates rather than overflowing.
+    /// let counts = vec![SizedValueCount {
+    ///     value: "BAM".into(),
+    ///     count: 1,
+    ///     bytes: usize::MAX,
+    /// }];
+    ///
+    /// assert_eq!(Response::new(counts, 1, 1).bytes, usize::MAX);
+    /// ```
+    pub fn new(values: Vec<SizedValueCount>, missing: usize, missing_bytes: usize) -> Self {
+        let total = values.iter().map(|result| result.count).sum::<usize>() + missing;
+        let bytes = values.iter().fold(missing_bytes, |bytes, result| {
+            bytes.saturating_add(result.bytes)
+        });
+
+        Self {
+            total,
+            missing,
+            null: None,
+            bytes,
+            values,
+        }
+    }
+
+    /// Splits the entries without a value into those where the entire metadata
+    /// object is missing (`absent`, which remain counted within `missing`) and
+    /// those with a metadata object (which are counted within `null`, along
+    /// with any entries counted under a `null` value).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ccdi_server as server;
+    ///
+    /// use server::responses::by::count::file::Response;
+    /// use server::responses::by::count::file::SizedValueCount;
+    ///
+    /// let counts = vec![SizedValueCount {
+    ///     value: "BAM".into(),
+    ///     count: 2,
+    ///     bytes: 2048,
+    /// }];
+    ///
+    /// let response = Response::new(counts, 5, 0).distinguish_missing(3);
+    ///
+    /// assert_eq!(response.total, 7);
+    /// assert_eq!(response.missing, 3);
+    /// assert_eq!(response.null, Some(2));
+    /// ```
+    pub fn distinguish_missing(mut self, absent: usize) -> Self {
+        let nulls = self
+            .values
+            .iter()
+            .filter(|value| value.value.is_null())
+            .map(|value| value.count)
+            .sum::<usize>();
+
+        self.values.retain(|value| !value.value.is_null());
+        self.null = Some(self.missing.saturating_sub(absent) + nulls);
+        self.missing = absent.min(self.missing);
+        self
+    }
+}
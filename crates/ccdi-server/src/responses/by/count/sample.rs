@@ -2,6 +2,7 @@
 
 use serde::Deserialize;
 use serde::Serialize;
+use serde_json::Value;
 use utoipa::ToSchema;
 
 use crate::responses::by::count::ValueCount;
@@ -17,9 +18,27 @@ pub struct Results {
 
     /// The total number of entries that are missing values. In this context,
     /// "missing" means either (a) the individual metadata key is missing or (b)
-    /// the entire metadata object is missing.
+    /// the entire metadata object is missing. When `distinguish_missing=true`
+    /// is provided, only (b) is counted here (see `null`).
     pub missing: usize,
 
+    /// The total number of entries that have a metadata object but no value
+    /// for the field (whether the key is missing or explicitly `null`). This
+    /// is only reported when `distinguish_missing=true` is provided, in which
+    /// case no `null` value is included within `values`.
+    #[schema(nullable = false)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub null: Option<usize>,
+
+    /// Whether the field is multi-valued (e.g., `anatomical_sites`), in which
+    /// case each entry is counted once for every distinct value it carries.
+    /// The counts within `values` (and thus `total`) may therefore sum to
+    /// more than the number of entries. This is only reported (as `true`) for
+    /// multi-valued fields.
+    #[schema(nullable = false)]
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub multivalued: bool,
+
     /// The counts per value observed for the result set.
     #[schema(value_type = Vec<responses::by::count::ValueCount>)]
     pub values: Vec<ValueCount>,
@@ -59,6 +78,162 @@ impl Results {
     pub fn new(values: Vec<ValueCount>, missing: usize) -> Self {
         let total = values.iter().map(|result| result.count).sum::<usize>() + missing;
 
+        Self {
+            total,
+            missing,
+            null: None,
+            multivalued: false,
+            values,
+        }
+    }
+
+    /// Marks the [`Results`] as counting a multi-valued field (see
+    /// [`Results::multivalued`](Results#structfield.multivalued)).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ccdi_server as server;
+    ///
+    /// use server::responses::by::count::sample::Results;
+    /// use server::responses::by::count::ValueCount;
+    ///
+    /// let counts = vec![
+    ///     ValueCount {
+    ///         value: "jugular vein".into(),
+    ///         count: 1,
+    ///     },
+    ///     ValueCount {
+    ///         value: "caecum".into(),
+    ///         count: 1,
+    ///     },
+    /// ];
+    ///
+    /// // A single sample with both sites.
+    /// let results = Results::new(counts, 0).into_multivalued();
+    ///
+    /// assert_eq!(results.total, 2);
+    /// assert!(results.multivalued);
+    /// ```
+    pub fn into_multivalued(mut self) -> Self {
+        self.multivalued = true;
+        self
+    }
+
+    /// Splits the entries without a value into those where the entire metadata
+    /// object is missing (`absent`, which remain counted within `missing`) and
+    /// those with a metadata object (which are counted within `null`, along
+    /// with any entries counted under a `null` value).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ccdi_server as server;
+    ///
+    /// use server::responses::by::count::sample::Results;
+    /// use server::responses::by::count::ValueCount;
+    ///
+    /// let counts = vec![
+    ///     ValueCount {
+    ///         value: "WGS".into(),
+    ///         count: 2,
+    ///     },
+    ///     ValueCount {
+    ///         value: serde_json::Value::Null,
+    ///         count: 4,
+    ///     },
+    /// ];
+    ///
+    /// let results = Results::new(counts, 3).distinguish_missing(3);
+    ///
+    /// assert_eq!(results.total, 9);
+    /// assert_eq!(results.missing, 3);
+    /// assert_eq!(results.null, Some(4));
+    /// assert_eq!(results.values.len(), 1);
+    /// ```
+    pub fn distinguish_missing(mut self, absent: usize) -> Self {
+        let nulls = self
+            .values
+            .iter()
+            .filter(|value| value.value.is_null())
+            .map(|value| value.count)
+            .sum::<usize>();
+
+        self.values.retain(|value| !value.value.is_null());
+        self.null = Some(self.missing.saturating_sub(absent) + nulls);
+        self.missing = absent.min(self.missing);
+        self
+    }
+}
+
+/// A `diagnosis_category` value along with the number of counted samples for
+/// that value and the most common free-text `diagnosis` values within it.
+#[derive(Debug, Deserialize, Serialize, ToSchema)]
+#[schema(as = responses::by::count::sample::CategoryCount)]
+pub struct CategoryCount {
+    /// The `diagnosis_category` value.
+    pub value: Value,
+
+    /// The number of samples with the `diagnosis_category` value.
+    pub count: usize,
+
+    /// The counts per `diagnosis` value observed within the category, limited
+    /// to the most common values.
+    #[schema(value_type = Vec<responses::by::count::ValueCount>)]
+    pub diagnoses: Vec<ValueCount>,
+
+    /// The number of samples within the category whose `diagnosis` value was
+    /// not among the most common values included in `diagnoses`.
+    pub other: usize,
+}
+
+/// A set of results from grouping [`Samples`](ccdi_models::Sample) by
+/// `diagnosis_category` and, within each category, by `diagnosis`.
+#[derive(Debug, Deserialize, Serialize, ToSchema)]
+#[schema(as = responses::by::count::sample::NestedResults)]
+pub struct NestedResults {
+    /// The total number of counts in this result set.
+    pub total: usize,
+
+    /// The total number of entries that are missing values. In this context,
+    /// "missing" means either (a) the individual metadata key is missing or (b)
+    /// the entire metadata object is missing.
+    pub missing: usize,
+
+    /// The counts per `diagnosis_category` value observed for the result set.
+    #[schema(value_type = Vec<responses::by::count::sample::CategoryCount>)]
+    pub values: Vec<CategoryCount>,
+}
+
+impl NestedResults {
+    /// Creates a new [`NestedResults`] from a [`Vec<CategoryCount>`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ccdi_server as server;
+    ///
+    /// use server::responses::by::count::sample::CategoryCount;
+    /// use server::responses::by::count::sample::NestedResults;
+    /// use server::responses::by::count::ValueCount;
+    ///
+    /// let counts = vec![CategoryCount {
+    ///     value: "Neuroblastoma".into(),
+    ///     count: 3,
+    ///     diagnoses: vec![ValueCount {
+    ///         value: "Ganglioneuroblastoma".into(),
+    ///         count: 2,
+    ///     }],
+    ///     other: 1,
+    /// }];
+    ///
+    /// let results = NestedResults::new(counts, 2);
+    ///
+    /// assert_eq!(results.total, 5);
+    /// ```
+    pub fn new(values: Vec<CategoryCount>, missing: usize) -> Self {
+        let total = values.iter().map(|result| result.count).sum::<usize>() + missing;
+
         Self {
             total,
             missing,
@@ -16,9 +16,18 @@ pub struct Results {
 
     /// The total number of entries that are missing values. In this context,
     /// "missing" means either (a) the individual metadata key is missing or (b)
-    /// the entire metadata object is missing.
+    /// the entire metadata object is missing. When `distinguish_missing=true`
+    /// is provided, only (b) is counted here (see `null`).
     pub missing: usize,
 
+    /// The total number of entries that have a metadata object but no value
+    /// for the field (whether the key is missing or explicitly `null`). This
+    /// is only reported when `distinguish_missing=true` is provided, in which
+    /// case no `null` value is included within `values`.
+    #[schema(nullable = false)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub null: Option<usize>,
+
     /// The counts per value observed for the result set.
     #[schema(value_type = Vec<responses::by::count::ValueCount>)]
     pub values: Vec<ValueCount>,
@@ -65,7 +74,53 @@ impl Results {
         Self {
             total,
             missing,
+            null: None,
             values,
         }
     }
+
+    /// Splits the entries without a value into those where the entire metadata
+    /// object is missing (`absent`, which remain counted within `missing`) and
+    /// those with a metadata object (which are counted within `null`, along
+    /// with any entries counted under a `null` value).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ccdi_server as server;
+    ///
+    /// use server::responses::by::count::subject::Results;
+    /// use server::responses::by::count::ValueCount;
+    ///
+    /// let counts = vec![
+    ///     ValueCount {
+    ///         value: "F".into(),
+    ///         count: 2,
+    ///     },
+    ///     ValueCount {
+    ///         value: serde_json::Value::Null,
+    ///         count: 4,
+    ///     },
+    /// ];
+    ///
+    /// let results = Results::new(counts, 3).distinguish_missing(3);
+    ///
+    /// assert_eq!(results.total, 9);
+    /// assert_eq!(results.missing, 3);
+    /// assert_eq!(results.null, Some(4));
+    /// assert_eq!(results.values.len(), 1);
+    /// ```
+    pub fn distinguish_missing(mut self, absent: usize) -> Self {
+        let nulls = self
+            .values
+            .iter()
+            .filter(|value| value.value.is_null())
+            .map(|value| value.count)
+            .sum::<usize>();
+
+        self.values.retain(|value| !value.value.is_null());
+        self.null = Some(self.missing.saturating_sub(absent) + nulls);
+        self.missing = absent.min(self.missing);
+        self
+    }
 }
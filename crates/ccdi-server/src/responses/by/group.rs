@@ -0,0 +1,4 @@
+//! Responses for grouping by fields and listing the entities within each
+//! group.
+
+pub mod sample;
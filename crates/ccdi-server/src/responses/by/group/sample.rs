@@ -0,0 +1,84 @@
+//! Responses for grouping samples by fields and listing them.
+
+use serde::Deserialize;
+use serde::Serialize;
+use serde_json::Value;
+use utoipa::ToSchema;
+
+use ccdi_models as models;
+
+use crate::paginate::Linked;
+use crate::responses::entity::Counts;
+use crate::responses::entity::Summary;
+
+/// A value along with the identifiers of the samples that have that value.
+#[derive(Clone, Debug, Deserialize, Serialize, ToSchema)]
+#[schema(as = responses::by::group::sample::Group)]
+pub struct Group {
+    /// The value. This is `null` for the samples where either (a) the
+    /// individual metadata key is missing or (b) the entire metadata object is
+    /// missing.
+    pub value: Value,
+
+    /// The number of samples with the value.
+    pub count: usize,
+
+    /// The identifiers of the samples with the value.
+    #[schema(value_type = Vec<models::sample::Identifier>)]
+    pub samples: Vec<models::sample::Identifier>,
+}
+
+/// A page of [`Group`]s from grouping [`Samples`](ccdi_models::Sample) by a
+/// specified metadata field.
+///
+/// Groups are sorted in descending order by count. The counts within the
+/// summary are the number of groups (not the number of samples).
+#[derive(Debug, Deserialize, Serialize, ToSchema)]
+#[schema(as = responses::by::group::sample::Response)]
+pub struct Response {
+    /// A summary of this paged result set.
+    #[schema(value_type = responses::entity::Summary)]
+    summary: Summary,
+
+    /// The groups.
+    #[schema(value_type = Vec<responses::by::group::sample::Group>, nullable = false)]
+    data: Vec<Group>,
+}
+
+impl Response {
+    /// Gets the groups within the [`Response`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ccdi_server as server;
+    ///
+    /// use server::responses::by::group::sample::Group;
+    /// use server::responses::by::group::sample::Response;
+    ///
+    /// let groups = vec![Group {
+    ///     value: "Neuroblastoma".into(),
+    ///     count: 0,
+    ///     samples: Vec::new(),
+    /// }];
+    ///
+    /// let response = Response::from((groups, 3));
+    /// assert_eq!(response.data().len(), 1);
+    /// ```
+    pub fn data(&self) -> &[Group] {
+        &self.data
+    }
+}
+
+// NOTE: the links to the other pages of groups are only reported within
+// the `link` header.
+impl Linked for Response {}
+
+impl From<(Vec<Group>, usize)> for Response {
+    fn from((groups, total): (Vec<Group>, usize)) -> Self {
+        Self {
+            summary: Summary::new(Counts::new(groups.len(), total)),
+            data: groups,
+        }
+    }
+}
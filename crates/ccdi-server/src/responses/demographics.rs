@@ -0,0 +1,112 @@
+//! Responses related to the demographics of subjects.
+
+use serde::Deserialize;
+use serde::Serialize;
+use utoipa::ToSchema;
+
+use ccdi_cde as cde;
+
+/// A single cell within the cross-tabulation of subject demographics.
+///
+/// A value of `null` for any of the demographic fields means that the value is
+/// missing for the subjects counted within the cell (either the field itself
+/// or the entire metadata object is missing).
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize, ToSchema)]
+#[schema(as = responses::demographics::Cell)]
+pub struct Cell {
+    /// The race.
+    #[schema(value_type = Option<cde::v1::subject::Race>)]
+    pub race: Option<cde::v1::subject::Race>,
+
+    /// The ethnicity.
+    #[schema(value_type = Option<cde::v2::subject::Ethnicity>)]
+    pub ethnicity: Option<cde::v2::subject::Ethnicity>,
+
+    /// The sex.
+    #[schema(value_type = Option<cde::v1::subject::Sex>)]
+    pub sex: Option<cde::v1::subject::Sex>,
+
+    /// The number of subjects within the cell.
+    pub count: usize,
+}
+
+/// A cross-tabulation of the race, ethnicity, and sex of subjects.
+///
+/// Race is a multiple-valued field, so a subject with more than one race is
+/// counted once within the cell for each of its races. As such, the sum of
+/// the counts of each cell may exceed the number of distinct subjects.
+/// Combinations of values that are not observed are not included.
+#[derive(Debug, Deserialize, Serialize, ToSchema)]
+#[schema(as = responses::Demographics)]
+pub struct Demographics {
+    /// The number of distinct subjects that were cross-tabulated.
+    distinct_subjects: usize,
+
+    /// The cells of the cross-tabulation.
+    #[schema(value_type = Vec<responses::demographics::Cell>)]
+    cells: Vec<Cell>,
+}
+
+impl Demographics {
+    /// Creates a new [`Demographics`] response.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ccdi_cde as cde;
+    /// use ccdi_server as server;
+    ///
+    /// use server::responses::demographics::Cell;
+    /// use server::responses::Demographics;
+    ///
+    /// let demographics = Demographics::new(
+    ///     1,
+    ///     vec![Cell {
+    ///         race: Some(cde::v1::subject::Race::Asian),
+    ///         ethnicity: None,
+    ///         sex: Some(cde::v1::subject::Sex::Female),
+    ///         count: 1,
+    ///     }],
+    /// );
+    ///
+    /// assert_eq!(demographics.distinct_subjects(), 1);
+    /// ```
+    pub fn new(distinct_subjects: usize, cells: Vec<Cell>) -> Self {
+        Self {
+            distinct_subjects,
+            cells,
+        }
+    }
+
+    /// Gets the number of distinct subjects that were cross-tabulated.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ccdi_server as server;
+    ///
+    /// use server::responses::Demographics;
+    ///
+    /// let demographics = Demographics::new(10, Vec::new());
+    /// assert_eq!(demographics.distinct_subjects(), 10);
+    /// ```
+    pub fn distinct_subjects(&self) -> usize {
+        self.distinct_subjects
+    }
+
+    /// Gets the cells of the cross-tabulation.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ccdi_server as server;
+    ///
+    /// use server::responses::Demographics;
+    ///
+    /// let demographics = Demographics::new(0, Vec::new());
+    /// assert!(demographics.cells().is_empty());
+    /// ```
+    pub fn cells(&self) -> &[Cell] {
+        &self.cells
+    }
+}
@@ -4,8 +4,13 @@ use serde::Deserialize;
 use serde::Serialize;
 use utoipa::ToSchema;
 
+use crate::paginate::Totals;
+
 mod counts;
+mod links;
+
 pub use counts::Counts;
+pub use links::Links;
 
 /// A summary of a paged entity response.
 #[derive(Debug, Deserialize, Serialize, ToSchema)]
@@ -32,4 +37,42 @@ impl Summary {
     pub fn new(counts: Counts) -> Self {
         Self { counts }
     }
+
+    /// Includes the [`Totals`] of the result set within the counts of the
+    /// [`Summary`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ccdi_server as server;
+    ///
+    /// use server::paginate::Totals;
+    /// use server::responses::entity::Counts;
+    /// use server::responses::entity::Summary;
+    ///
+    /// let summary = Summary::new(Counts::new(1, 10)).with_totals(Totals::new(100, 10));
+    /// assert_eq!(summary.counts().total(), Some(100));
+    /// ```
+    pub fn with_totals(self, totals: Totals) -> Self {
+        Self {
+            counts: self.counts.with_totals(totals),
+        }
+    }
+
+    /// Gets the counts of the [`Summary`] by reference.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ccdi_server as server;
+    ///
+    /// use server::responses::entity::Counts;
+    /// use server::responses::entity::Summary;
+    ///
+    /// let summary = Summary::new(Counts::new(1, 10));
+    /// assert_eq!(summary.counts().filtered(), None);
+    /// ```
+    pub fn counts(&self) -> &Counts {
+        &self.counts
+    }
 }
@@ -4,6 +4,8 @@ use serde::Deserialize;
 use serde::Serialize;
 use utoipa::ToSchema;
 
+use crate::paginate::Totals;
+
 /// Counts that summarize the contents of a paged entity response.
 #[derive(Debug, Deserialize, Serialize, ToSchema)]
 #[schema(as = responses::entity::Counts)]
@@ -13,6 +15,23 @@ pub struct Counts {
 
     /// The number of entities across all pages in the result set.
     all: usize,
+
+    /// The number of entities within the scope of the endpoint before any
+    /// filters were applied (also reported within the `x-total-count`
+    /// header).
+    ///
+    /// This count is optional so that servers that do not (yet) report it
+    /// remain conformant.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    total: Option<usize>,
+
+    /// The number of entities within the scope of the endpoint that matched
+    /// the filters (also reported within the `x-filtered-count` header).
+    ///
+    /// This count is optional so that servers that do not (yet) report it
+    /// remain conformant.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    filtered: Option<usize>,
 }
 
 impl Counts {
@@ -28,6 +47,64 @@ impl Counts {
     /// let summary = Counts::new(1, 10);
     /// ```
     pub fn new(current: usize, all: usize) -> Self {
-        Self { current, all }
+        Self {
+            current,
+            all,
+            total: None,
+            filtered: None,
+        }
+    }
+
+    /// Includes the [`Totals`] of the result set within the [`Counts`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ccdi_server as server;
+    ///
+    /// use server::paginate::Totals;
+    /// use server::responses::entity::Counts;
+    ///
+    /// let counts = Counts::new(1, 10).with_totals(Totals::new(100, 10));
+    /// assert_eq!(counts.total(), Some(100));
+    /// assert_eq!(counts.filtered(), Some(10));
+    /// ```
+    pub fn with_totals(mut self, totals: Totals) -> Self {
+        self.total = Some(totals.total());
+        self.filtered = Some(totals.filtered());
+        self
+    }
+
+    /// Gets the number of entities before any filters were applied (if
+    /// reported).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ccdi_server as server;
+    ///
+    /// use server::responses::entity::Counts;
+    ///
+    /// let counts = Counts::new(1, 10);
+    /// assert_eq!(counts.total(), None);
+    /// ```
+    pub fn total(&self) -> Option<usize> {
+        self.total
+    }
+
+    /// Gets the number of entities that matched the filters (if reported).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ccdi_server as server;
+    ///
+    /// use server::responses::entity::Counts;
+    ///
+    /// let counts = Counts::new(1, 10);
+    /// assert_eq!(counts.filtered(), None);
+    /// ```
+    pub fn filtered(&self) -> Option<usize> {
+        self.filtered
     }
 }
@@ -0,0 +1,131 @@
+//! Links to the other pages of a paged entity response.
+
+use serde::Deserialize;
+use serde::Serialize;
+use utoipa::ToSchema;
+
+use crate::paginate;
+use crate::paginate::Relationship;
+
+/// The links to the other pages of a paged entity response.
+///
+/// These are identical to the links within the `link` header of the response
+/// (both are generated from the same [`paginate::Links`]), and they are
+/// included for clients that cannot read that header (e.g., browsers making
+/// cross-origin requests).
+#[derive(Clone, Debug, Default, Deserialize, Eq, PartialEq, Serialize, ToSchema)]
+#[schema(as = responses::entity::Links)]
+pub struct Links {
+    /// The URL of the first page.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    first: Option<String>,
+
+    /// The URL of the previous page (omitted on the first page).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    prev: Option<String>,
+
+    /// The URL of the next page (omitted on the last page).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    next: Option<String>,
+
+    /// The URL of the last page.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    last: Option<String>,
+}
+
+impl Links {
+    /// Gets the URL of the first page (if it exists).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ccdi_server as server;
+    ///
+    /// use server::responses::entity::Links;
+    ///
+    /// assert_eq!(Links::default().first(), None);
+    /// ```
+    pub fn first(&self) -> Option<&str> {
+        self.first.as_deref()
+    }
+
+    /// Gets the URL of the previous page (if it exists).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ccdi_server as server;
+    ///
+    /// use server::responses::entity::Links;
+    ///
+    /// assert_eq!(Links::default().prev(), None);
+    /// ```
+    pub fn prev(&self) -> Option<&str> {
+        self.prev.as_deref()
+    }
+
+    /// Gets the URL of the next page (if it exists).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ccdi_server as server;
+    ///
+    /// use server::responses::entity::Links;
+    ///
+    /// assert_eq!(Links::default().next(), None);
+    /// ```
+    pub fn next(&self) -> Option<&str> {
+        self.next.as_deref()
+    }
+
+    /// Gets the URL of the last page (if it exists).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ccdi_server as server;
+    ///
+    /// use server::responses::entity::Links;
+    ///
+    /// assert_eq!(Links::default().last(), None);
+    /// ```
+    pub fn last(&self) -> Option<&str> {
+        self.last.as_deref()
+    }
+}
+
+impl From<&paginate::Links> for Links {
+    fn from(links: &paginate::Links) -> Self {
+        let url = |rel| links.get(rel).map(|url| url.to_string());
+
+        Self {
+            first: url(Relationship::First),
+            prev: url(Relationship::Prev),
+            next: url(Relationship::Next),
+            last: url(Relationship::Last),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use url::Url;
+
+    use crate::paginate::links::Link;
+
+    use super::*;
+
+    #[test]
+    fn it_omits_missing_links() {
+        let url = "https://example.com/subject?page=1&per_page=10"
+            .parse::<Url>()
+            .unwrap();
+        let links = paginate::Links::from(vec![Link::new(Relationship::First, url)]);
+
+        assert_eq!(
+            serde_json::to_value(Links::from(&links)).unwrap(),
+            serde_json::json!({ "first": "https://example.com/subject?page=1&per_page=10" })
+        );
+    }
+}
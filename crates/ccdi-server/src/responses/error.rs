@@ -1,8 +1,12 @@
 //! Error responses.
 
+use actix_web::error::JsonPayloadError;
+use actix_web::error::PathError;
+use actix_web::error::QueryPayloadError;
 use actix_web::http::header;
 use actix_web::http::StatusCode;
 use actix_web::web;
+use actix_web::HttpRequest;
 use actix_web::HttpResponseBuilder;
 use actix_web::ResponseError;
 use serde::Deserialize;
@@ -20,6 +24,21 @@ pub struct Errors {
     /// The errors within this response.
     #[schema(value_type = Vec<responses::error::Kind>)]
     errors: Vec<Kind>,
+
+    /// Identifiers similar to the one requested (if any are known).
+    ///
+    /// When a single entity cannot be found, servers may optionally include up
+    /// to three identifiers that are a small number of edits away from the
+    /// requested identifier (for example, to point out a likely typo). Each
+    /// suggestion is formatted as `{organization}/{namespace}/{name}`. This
+    /// field is omitted when suggestions are not enabled or when no similar
+    /// identifiers exist.
+    #[schema(
+        nullable = false,
+        example = json!(["example-organization/ExampleNamespaceOne/Sample-F62VO0JX"])
+    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    suggestions: Option<Vec<String>>,
 }
 
 impl std::fmt::Display for Errors {
@@ -79,7 +98,63 @@ impl Errors {
     /// # Ok::<(), Box<dyn std::error::Error>>(())
     /// ```
     pub fn new(errors: Vec<Kind>) -> Self {
-        Errors { errors }
+        Errors {
+            errors,
+            suggestions: None,
+        }
+    }
+
+    /// Sets the suggested identifiers for the [`Errors`].
+    ///
+    /// If `suggestions` is empty, no suggestions are included in the response.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ccdi_server as server;
+    ///
+    /// use server::responses::error::Kind;
+    /// use server::responses::Errors;
+    ///
+    /// let errors =
+    ///     Errors::from(Kind::not_found(String::from("Sample"))).with_suggestions(vec![String::from(
+    ///         "example-organization/ExampleNamespaceOne/Sample1",
+    ///     )]);
+    ///
+    /// assert_eq!(
+    ///     errors.suggestions().unwrap(),
+    ///     &[String::from(
+    ///         "example-organization/ExampleNamespaceOne/Sample1"
+    ///     )]
+    /// );
+    ///
+    /// let errors = Errors::from(Kind::not_found(String::from("Sample"))).with_suggestions(vec![]);
+    /// assert!(errors.suggestions().is_none());
+    /// ```
+    pub fn with_suggestions(mut self, suggestions: Vec<String>) -> Self {
+        self.suggestions = match suggestions.is_empty() {
+            true => None,
+            false => Some(suggestions),
+        };
+
+        self
+    }
+
+    /// Gets the suggested identifiers for the [`Errors`] (if they exist).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ccdi_server as server;
+    ///
+    /// use server::responses::error::Kind;
+    /// use server::responses::Errors;
+    ///
+    /// let errors = Errors::from(Kind::not_found(String::from("Sample")));
+    /// assert!(errors.suggestions().is_none());
+    /// ```
+    pub fn suggestions(&self) -> Option<&[String]> {
+        self.suggestions.as_deref()
     }
 }
 
@@ -89,15 +164,235 @@ impl From<Kind> for Errors {
     }
 }
 
+/// Converts an error encountered while extracting the query string of a
+/// request into an [`Errors`] response.
+///
+/// This is intended to be used as the error handler of a
+/// [`QueryConfig`](actix_web::web::QueryConfig): every malformed query string
+/// is reported as an [`InvalidParameters`](kind::Inner::InvalidParameters)
+/// error (rather than a panic or an empty response).
+///
+/// # Examples
+///
+/// ```
+/// use actix_web::web::QueryConfig;
+/// use ccdi_server as server;
+///
+/// use server::responses::error::from_query_error;
+///
+/// let config = QueryConfig::default().error_handler(from_query_error);
+/// ```
+pub fn from_query_error(err: QueryPayloadError, _: &HttpRequest) -> actix_web::Error {
+    let reason = match err {
+        QueryPayloadError::Deserialize(err) => err.to_string(),
+        // NOTE: [`QueryPayloadError`] is non-exhaustive, so any errors added
+        // in the future are reported using their own description.
+        err => err.to_string(),
+    };
+
+    Errors::from(Kind::invalid_parameters(None, reason)).into()
+}
+
+/// Converts an error encountered while extracting the path of a request into
+/// an [`Errors`] response.
+///
+/// This is intended to be used as the error handler of a
+/// [`PathConfig`](actix_web::web::PathConfig): a path that cannot be
+/// extracted does not match any route, so it is reported as an
+/// [`InvalidRoute`](kind::Inner::InvalidRoute) error.
+///
+/// # Examples
+///
+/// ```
+/// use actix_web::web::PathConfig;
+/// use ccdi_server as server;
+///
+/// use server::responses::error::from_path_error;
+///
+/// let config = PathConfig::default().error_handler(from_path_error);
+/// ```
+pub fn from_path_error(_: PathError, req: &HttpRequest) -> actix_web::Error {
+    Errors::from(Kind::invalid_route(
+        req.method().to_string(),
+        req.path().to_string(),
+        None,
+    ))
+    .into()
+}
+
+/// Converts an error encountered while extracting the JSON body of a request
+/// into an [`Errors`] response.
+///
+/// This is intended to be used as the error handler of a
+/// [`JsonConfig`](actix_web::web::JsonConfig): every malformed body is
+/// reported as an [`InvalidParameters`](kind::Inner::InvalidParameters) error.
+///
+/// # Examples
+///
+/// ```
+/// use actix_web::web::JsonConfig;
+/// use ccdi_server as server;
+///
+/// use server::responses::error::from_json_error;
+///
+/// let config = JsonConfig::default().error_handler(from_json_error);
+/// ```
+pub fn from_json_error(err: JsonPayloadError, _: &HttpRequest) -> actix_web::Error {
+    let reason = match err {
+        JsonPayloadError::OverflowKnownLength { length, limit } => {
+            format!("the request body ({length} bytes) exceeds the limit of {limit} bytes")
+        }
+        JsonPayloadError::Overflow { limit } => {
+            format!("the request body exceeds the limit of {limit} bytes")
+        }
+        JsonPayloadError::ContentType => {
+            String::from("the content type of the request body must be application/json")
+        }
+        JsonPayloadError::Deserialize(err) => format!("invalid request body: {err}"),
+        err => err.to_string(),
+    };
+
+    Errors::from(Kind::invalid_parameters(None, reason)).into()
+}
+
 #[cfg(test)]
 mod tests {
+    use actix_web::get;
+    use actix_web::post;
+    use actix_web::test::call_service;
+    use actix_web::test::init_service;
+    use actix_web::test::read_body_json;
+    use actix_web::test::TestRequest;
+    use actix_web::web::Json;
+    use actix_web::web::JsonConfig;
+    use actix_web::web::Path;
+    use actix_web::web::PathConfig;
+    use actix_web::web::Query;
+    use actix_web::web::QueryConfig;
+    use actix_web::App;
+    use actix_web::HttpResponse;
+    use actix_web::Responder;
+    use serde_json::Value;
+
     use super::*;
 
+    #[derive(Deserialize, Serialize)]
+    struct Params {
+        page: usize,
+        flag: Option<bool>,
+    }
+
+    #[get("/query")]
+    async fn query(params: Query<Params>) -> impl Responder {
+        HttpResponse::Ok().json(params.into_inner())
+    }
+
+    #[get("/path/{page}")]
+    async fn path(page: Path<usize>) -> impl Responder {
+        HttpResponse::Ok().json(page.into_inner())
+    }
+
+    #[post("/json")]
+    async fn json(params: Json<Params>) -> impl Responder {
+        HttpResponse::Ok().json(params.into_inner())
+    }
+
+    async fn call(request: TestRequest) -> (StatusCode, Value) {
+        let app = init_service(
+            App::new()
+                .app_data(QueryConfig::default().error_handler(from_query_error))
+                .app_data(PathConfig::default().error_handler(from_path_error))
+                .app_data(
+                    JsonConfig::default()
+                        .limit(64)
+                        .error_handler(from_json_error),
+                )
+                .service(query)
+                .service(path)
+                .service(json),
+        )
+        .await;
+
+        let response = call_service(&app, request.to_request()).await;
+        let status = response.status();
+
+        (status, read_body_json(response).await)
+    }
+
+    #[actix_web::test]
+    async fn malformed_query_strings_are_reported_as_invalid_parameters() {
+        for uri in [
+            "/query",
+            "/query?page=one",
+            "/query?page=-1",
+            "/query?page=1&flag=maybe",
+            "/query?page=1&page=2",
+        ] {
+            let (status, body) = call(TestRequest::get().uri(uri)).await;
+
+            assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY, "{uri}");
+            assert_eq!(body["errors"][0]["kind"], "InvalidParameters", "{uri}");
+            assert!(body["errors"][0]["reason"].is_string(), "{uri}");
+        }
+
+        let (status, _) = call(TestRequest::get().uri("/query?page=1&flag=true")).await;
+        assert_eq!(status, StatusCode::OK);
+    }
+
+    #[actix_web::test]
+    async fn malformed_paths_are_reported_as_invalid_routes() {
+        let (status, body) = call(TestRequest::get().uri("/path/one")).await;
+
+        assert_eq!(status, StatusCode::NOT_FOUND);
+        assert_eq!(body["errors"][0]["kind"], "InvalidRoute");
+        assert_eq!(body["errors"][0]["route"], "/path/one");
+    }
+
+    #[actix_web::test]
+    async fn malformed_bodies_are_reported_as_invalid_parameters() {
+        for (request, reason) in [
+            (
+                TestRequest::post()
+                    .uri("/json")
+                    .insert_header(header::ContentType::json())
+                    .set_payload("{\"page\":"),
+                "Invalid request body",
+            ),
+            (
+                TestRequest::post()
+                    .uri("/json")
+                    .insert_header(header::ContentType::plaintext())
+                    .set_payload("{\"page\":1}"),
+                "The content type of the request body must be application/json.",
+            ),
+            (
+                TestRequest::post()
+                    .uri("/json")
+                    .insert_header(header::ContentType::json())
+                    .set_payload(format!("{{\"page\":1,\"padding\":\"{}\"}}", "a".repeat(64))),
+                "The request body",
+            ),
+        ] {
+            let (status, body) = call(request).await;
+
+            assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+            assert_eq!(body["errors"][0]["kind"], "InvalidParameters");
+            assert!(
+                body["errors"][0]["reason"]
+                    .as_str()
+                    .unwrap()
+                    .starts_with(reason),
+                "{body}"
+            );
+        }
+    }
+
     #[test]
     fn invalid_route() -> Result<(), Box<dyn std::error::Error>> {
         let errors = Errors::from(Kind::invalid_route(
             String::from("GET"),
             String::from("/foobar"),
+            None,
         ));
 
         let result = serde_json::to_string(&errors)?;
@@ -153,6 +448,17 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn not_found_with_suggestions() -> Result<(), Box<dyn std::error::Error>> {
+        let error = Errors::from(Kind::not_found(String::from("Samples")))
+            .with_suggestions(vec![String::from("organization/namespace/Sample1")]);
+        let result = serde_json::to_string(&error)?;
+
+        assert_eq!(&result, "{\"errors\":[{\"kind\":\"NotFound\",\"entity\":\"Samples\",\"message\":\"Samples not found.\"}],\"suggestions\":[\"organization/namespace/Sample1\"]}");
+
+        Ok(())
+    }
+
     #[test]
     fn unsupported_field() -> Result<(), Box<dyn std::error::Error>> {
         let error = Errors::from(Kind::unsupported_field(
@@ -44,7 +44,9 @@ impl ResponseError for Kind {
             Inner::NotFound { .. } => StatusCode::NOT_FOUND,
             Inner::UnsupportedField { .. } => StatusCode::UNPROCESSABLE_ENTITY,
             Inner::UnshareableData { .. } => StatusCode::NOT_FOUND,
+            Inner::UnknownNamespace { .. } => StatusCode::NOT_FOUND,
             Inner::InvalidRoute { .. } => StatusCode::NOT_FOUND,
+            Inner::DuplicateIdentifier { .. } => StatusCode::INTERNAL_SERVER_ERROR,
         }
     }
 
@@ -65,15 +67,24 @@ impl Kind {
     ///
     /// let error = server::responses::error::Kind::invalid_route(
     ///     String::from("GET"),
-    ///     String::from("/foobar")
+    ///     String::from("/foobar"),
+    ///     None,
     /// );
     ///
     /// assert_eq!(serde_json::to_string(&error)?, String::from("{\"kind\":\"InvalidRoute\",\"method\":\"GET\",\"route\":\"/foobar\",\"message\":\"Invalid route: GET /foobar.\"}"));
     ///
+    /// let error = server::responses::error::Kind::invalid_route(
+    ///     String::from("GET"),
+    ///     String::from("/Subject"),
+    ///     Some(String::from("/subject")),
+    /// );
+    ///
+    /// assert_eq!(serde_json::to_string(&error)?, String::from("{\"kind\":\"InvalidRoute\",\"method\":\"GET\",\"route\":\"/Subject\",\"suggestion\":\"/subject\",\"message\":\"Invalid route: GET /Subject (did you mean '/subject'?).\"}"));
+    ///
     /// # Ok::<(), Box<dyn std::error::Error>>(())
     /// ```
-    pub fn invalid_route(method: String, route: String) -> Self {
-        let inner = Inner::invalid_route(method, route);
+    pub fn invalid_route(method: String, route: String, suggestion: Option<String>) -> Self {
+        let inner = Inner::invalid_route(method, route, suggestion);
 
         Self {
             message: inner.to_string(),
@@ -169,6 +180,32 @@ impl Kind {
         }
     }
 
+    /// Creates a new [Kind] with an
+    /// [`UnknownNamespace`](Inner::UnknownNamespace) inner.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ccdi_server as server;
+    ///
+    /// let error = server::responses::error::Kind::unknown_namespace(
+    ///     String::from("example-organization/ExampleNamespaceone"),
+    ///     vec![String::from("example-organization/ExampleNamespaceOne")],
+    /// );
+    ///
+    /// assert_eq!(serde_json::to_string(&error)?, String::from("{\"kind\":\"UnknownNamespace\",\"namespace\":\"example-organization/ExampleNamespaceone\",\"known\":[\"example-organization/ExampleNamespaceOne\"],\"similar\":[\"example-organization/ExampleNamespaceOne\"],\"message\":\"Unknown namespace 'example-organization/ExampleNamespaceone' (did you mean 'example-organization/ExampleNamespaceOne'?). Known namespaces: 'example-organization/ExampleNamespaceOne'.\"}"));
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn unknown_namespace(namespace: String, known: Vec<String>) -> Self {
+        let inner = Inner::unknown_namespace(namespace, known);
+
+        Self {
+            message: inner.to_string(),
+            inner,
+        }
+    }
+
     /// Creates a new [Kind] with an
     /// [`UnsupportedField`](Inner::UnsupportedField) inner.
     ///
@@ -194,4 +231,47 @@ impl Kind {
             inner,
         }
     }
+
+    /// Creates a new [Kind] with a
+    /// [`DuplicateIdentifier`](Inner::DuplicateIdentifier) inner.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use actix_web::http::StatusCode;
+    /// use actix_web::ResponseError as _;
+    /// use ccdi_server as server;
+    ///
+    /// let error = server::responses::error::Kind::duplicate_identifier(
+    ///     String::from("Sample with namespace 'foo' and name 'bar'"),
+    ///     2,
+    /// );
+    ///
+    /// assert_eq!(error.status_code(), StatusCode::INTERNAL_SERVER_ERROR);
+    /// assert_eq!(serde_json::to_string(&error)?, String::from("{\"kind\":\"DuplicateIdentifier\",\"entity\":\"Sample with namespace 'foo' and name 'bar'\",\"count\":2,\"message\":\"Sample with namespace 'foo' and name 'bar' is not unique: 2 entities share the identifier.\"}"));
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn duplicate_identifier(entity: String, count: usize) -> Self {
+        let inner = Inner::duplicate_identifier(entity, count);
+
+        Self {
+            message: inner.to_string(),
+            inner,
+        }
+    }
+
+    /// Gets the plain-text description of the [`Kind`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ccdi_server as server;
+    ///
+    /// let error = server::responses::error::Kind::not_found(String::from("Sample"));
+    /// assert_eq!(error.message(), "Sample not found.");
+    /// ```
+    pub fn message(&self) -> &str {
+        &self.message
+    }
 }
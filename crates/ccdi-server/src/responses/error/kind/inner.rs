@@ -11,6 +11,8 @@ use serde::Deserialize;
 use serde::Serialize;
 use utoipa::ToSchema;
 
+use crate::suggest;
+
 /// An inner type of a [Kind](super::Kind).
 #[derive(Debug, Deserialize, Serialize, ToSchema)]
 #[serde(tag = "kind")]
@@ -22,7 +24,8 @@ pub enum Inner {
     #[schema(example = json!(
         Inner::InvalidRoute {
             method: String::from("GET"),
-            route: String::from("/foobar")
+            route: String::from("/Subject"),
+            suggestion: Some(String::from("/subject")),
         }
     ))]
     InvalidRoute {
@@ -31,6 +34,12 @@ pub enum Inner {
 
         /// The route that was requested.
         route: String,
+
+        /// If the requested route only differs from a known route by a
+        /// trailing slash or by case, the canonical form of the known route.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        #[schema(nullable = false)]
+        suggestion: Option<String>,
     },
 
     /// One or more invalid query or path parameters were provided.
@@ -57,6 +66,31 @@ pub enum Inner {
         entity: String,
     },
 
+    /// The namespace of an identifier is not known by the server.
+    ///
+    /// Namespaces are compared case-sensitively.
+    #[schema(example = json!(Inner::UnknownNamespace {
+        namespace: String::from("example-organization/ExampleNamespaceOn"),
+        known: vec![
+            String::from("example-organization/ExampleNamespaceOne"),
+            String::from("example-organization/ExampleNamespaceTwo"),
+        ],
+        similar: vec![String::from("example-organization/ExampleNamespaceOne")],
+    }))]
+    UnknownNamespace {
+        /// The namespace that was requested (formatted as
+        /// `{organization}/{namespace}`).
+        namespace: String,
+
+        /// The namespaces known by the server (formatted as
+        /// `{organization}/{namespace}`).
+        known: Vec<String>,
+
+        /// The known namespaces that are a small number of edits away from
+        /// the requested namespace (if any).
+        similar: Vec<String>,
+    },
+
     /// Line-level data cannot be shared for the specified entity.
     #[schema(example = json!(Inner::UnshareableData {
         entity: String::from("Sample"),
@@ -82,13 +116,27 @@ pub enum Inner {
         /// The reason that the field is not supported.
         reason: String,
     },
+
+    /// More than one entity has the requested identifier.
+    ///
+    /// Identifiers are expected to be unique, so this indicates an issue with
+    /// the data held by the server rather than with the request.
+    #[schema(example = json!(Inner::DuplicateIdentifier {
+        entity: String::from("Sample with namespace 'foo' and name 'bar'"),
+        count: 2,
+    }))]
+    DuplicateIdentifier {
+        /// The entity that has a duplicated identifier.
+        entity: String,
+
+        /// The number of entities that share the identifier.
+        count: usize,
+    },
 }
 
 impl Inner {
-    /// Creates an [`Inner::InvalidParameters`] with a formalized `reason`.
-    ///
-    /// For more information on the definition of **formalizing** the `reason`
-    /// field, see the [`formalize_reason()`] method.
+    /// Creates an [`Inner::InvalidRoute`] (optionally suggesting the canonical
+    /// form of a known route).
     ///
     /// # Examples
     ///
@@ -97,15 +145,30 @@ impl Inner {
     ///
     /// use server::responses::error::kind::Inner;
     ///
-    /// let error = Inner::invalid_route(String::from("GET"), String::from("/foobar"));
+    /// let error = Inner::invalid_route(String::from("GET"), String::from("/foobar"), None);
     ///
     /// assert_eq!(
     ///     error.to_string(),
     ///     String::from("Invalid route: GET /foobar.")
     /// );
+    ///
+    /// let error = Inner::invalid_route(
+    ///     String::from("GET"),
+    ///     String::from("/Subject"),
+    ///     Some(String::from("/subject")),
+    /// );
+    ///
+    /// assert_eq!(
+    ///     error.to_string(),
+    ///     String::from("Invalid route: GET /Subject (did you mean '/subject'?).")
+    /// );
     /// ```
-    pub fn invalid_route(method: String, route: String) -> Self {
-        Inner::InvalidRoute { method, route }
+    pub fn invalid_route(method: String, route: String, suggestion: Option<String>) -> Self {
+        Inner::InvalidRoute {
+            method,
+            route,
+            suggestion,
+        }
     }
 
     /// Creates an [`Inner::InvalidParameters`] with a formalized `reason`.
@@ -157,6 +220,47 @@ impl Inner {
         Inner::NotFound { entity }
     }
 
+    /// Creates an [`Inner::UnknownNamespace`].
+    ///
+    /// The known namespaces that are within
+    /// [`MAX_DISTANCE`](crate::suggest::MAX_DISTANCE) edits of `namespace` are
+    /// included as similar namespaces.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ccdi_server as server;
+    ///
+    /// use server::responses::error::kind::Inner;
+    ///
+    /// let error = Inner::unknown_namespace(
+    ///     String::from("example-organization/ExampleNamespaceOn"),
+    ///     vec![
+    ///         String::from("example-organization/ExampleNamespaceOne"),
+    ///         String::from("example-organization/ExampleNamespaceTwo"),
+    ///     ],
+    /// );
+    ///
+    /// assert_eq!(
+    ///     error.to_string(),
+    ///     String::from(
+    ///         "Unknown namespace 'example-organization/ExampleNamespaceOn' (did you \
+    ///          mean 'example-organization/ExampleNamespaceOne'?). Known namespaces: \
+    ///          'example-organization/ExampleNamespaceOne', \
+    ///          'example-organization/ExampleNamespaceTwo'."
+    ///     )
+    /// );
+    /// ```
+    pub fn unknown_namespace(namespace: String, known: Vec<String>) -> Self {
+        let similar = suggest::nearest(&namespace, known.iter());
+
+        Inner::UnknownNamespace {
+            namespace,
+            known,
+            similar,
+        }
+    }
+
     /// Creates an [`Inner::UnshareableData`] with a formalized `reason`.
     ///
     /// For more information on the definition of **formalizing** the `reason`
@@ -222,13 +326,49 @@ impl Inner {
         });
         Inner::UnsupportedField { field, reason }
     }
+
+    /// Creates an [`Inner::DuplicateIdentifier`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ccdi_server as server;
+    ///
+    /// use server::responses::error::kind::Inner;
+    ///
+    /// let error = Inner::duplicate_identifier(
+    ///     String::from("Sample with namespace 'foo' and name 'bar'"),
+    ///     2,
+    /// );
+    ///
+    /// assert_eq!(
+    ///     error.to_string(),
+    ///     String::from(
+    ///         "Sample with namespace 'foo' and name 'bar' is not unique: 2 entities share \
+    ///          the identifier."
+    ///     )
+    /// );
+    /// ```
+    pub fn duplicate_identifier(entity: String, count: usize) -> Self {
+        Inner::DuplicateIdentifier { entity, count }
+    }
 }
 
 impl std::fmt::Display for Inner {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
-            Inner::InvalidRoute { method, route } => {
-                write!(f, "Invalid route: {method} {route}.")
+            Inner::InvalidRoute {
+                method,
+                route,
+                suggestion,
+            } => {
+                write!(f, "Invalid route: {method} {route}")?;
+
+                if let Some(suggestion) = suggestion {
+                    write!(f, " (did you mean '{suggestion}'?)")?;
+                }
+
+                write!(f, ".")
             }
             Inner::InvalidParameters { parameters, reason } => {
                 let reason = reason.to_lowercase();
@@ -255,6 +395,29 @@ impl std::fmt::Display for Inner {
                 }
             }
             Inner::NotFound { entity } => write!(f, "{entity} not found."),
+            Inner::UnknownNamespace {
+                namespace,
+                known,
+                similar,
+            } => {
+                let quote = |namespaces: &[String]| {
+                    namespaces
+                        .iter()
+                        .map(|namespace| format!("'{namespace}'"))
+                        .collect::<Vec<_>>()
+                };
+
+                write!(f, "Unknown namespace '{namespace}'")?;
+
+                if !similar.is_empty() {
+                    write!(f, " (did you mean {}?)", quote(similar).join(" or "))?;
+                }
+
+                match known.is_empty() {
+                    true => write!(f, ". No namespaces are known."),
+                    false => write!(f, ". Known namespaces: {}.", quote(known).join(", ")),
+                }
+            }
             Inner::UnshareableData { entity, reason } => {
                 let entity = entity.to_lowercase();
                 let reason = reason.to_lowercase();
@@ -264,6 +427,10 @@ impl std::fmt::Display for Inner {
                 let reason = reason.to_lowercase();
                 write!(f, "Field '{field}' is not supported: {reason}")
             }
+            Inner::DuplicateIdentifier { entity, count } => write!(
+                f,
+                "{entity} is not unique: {count} entities share the identifier."
+            ),
         }
     }
 }
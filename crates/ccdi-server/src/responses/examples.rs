@@ -0,0 +1,195 @@
+//! Example bodies of the main responses for the OpenAPI specification.
+//!
+//! Each example is serialized from a small set of entities that are randomly
+//! generated with a fixed seed, so the same examples are always generated and
+//! they always have the shape of the current models. The tests within this
+//! module deserialize each example back into its response type to ensure that
+//! the examples stay valid as the models evolve.
+
+use std::sync::Mutex;
+
+use lazy_static::lazy_static;
+use rand::rngs::StdRng;
+use rand::SeedableRng as _;
+use serde_json::Value;
+
+use ccdi_models as models;
+
+use crate::paginate::Totals;
+use crate::responses::subject::SurvivalDays;
+use crate::responses::Files;
+use crate::responses::Samples;
+use crate::responses::Subject;
+use crate::responses::Subjects;
+use crate::responses::Summary;
+use crate::routes::file;
+use crate::routes::sample;
+use crate::routes::subject;
+use crate::routes::GroupByResults;
+
+/// The seed with which the example entities are generated.
+const SEED: u64 = 0;
+
+/// The number of each entity within the examples.
+const NUMBER_OF_ENTITIES: usize = 3;
+
+/// The example entities.
+struct Entities {
+    /// The subjects.
+    subjects: Vec<models::Subject>,
+
+    /// The samples.
+    samples: Vec<models::Sample>,
+
+    /// The files.
+    files: Vec<models::File>,
+}
+
+impl Entities {
+    /// Generates the example entities (every entity has metadata).
+    fn generate() -> Self {
+        let mut rng = StdRng::seed_from_u64(SEED);
+
+        let subjects = subject::Store::random_with_rng(NUMBER_OF_ENTITIES, 0.0, &mut rng);
+        let samples = sample::Store::random_with_rng(
+            NUMBER_OF_ENTITIES,
+            subjects.subjects.lock().unwrap(),
+            0.0,
+            &mut rng,
+        );
+        let files = file::Store::random_with_rng(
+            NUMBER_OF_ENTITIES,
+            samples.samples.lock().unwrap(),
+            0.0,
+            &mut rng,
+        );
+
+        Self {
+            subjects: into_inner(subjects.subjects),
+            samples: into_inner(samples.samples),
+            files: into_inner(files.files),
+        }
+    }
+}
+
+/// Consumes the [`Mutex`] of a store and returns the entities within it.
+fn into_inner<T>(entities: Mutex<Vec<T>>) -> Vec<T> {
+    // SAFETY: the store was just created, so the lock cannot be poisoned.
+    entities.into_inner().unwrap()
+}
+
+/// Serializes an example.
+fn example<T: serde::Serialize>(value: T) -> Value {
+    // SAFETY: every response is serializable to JSON.
+    serde_json::to_value(value).unwrap()
+}
+
+/// Serializes the results of counting an example by a field.
+fn counted<T: serde::Serialize>(results: GroupByResults<T>, field: &str) -> Value {
+    match results {
+        GroupByResults::Supported(results) => example(results),
+        GroupByResults::Unsupported => unreachable!("`{field}` must be a countable field"),
+    }
+}
+
+lazy_static! {
+    static ref ENTITIES: Entities = Entities::generate();
+
+    /// An example of a single subject (including its computed fields).
+    pub static ref SUBJECT: Value = example(
+        Subject::new(ENTITIES.subjects[0].clone()).with_survival_days(SurvivalDays::derive(
+            &ENTITIES.subjects[0],
+            &ENTITIES.samples
+        ))
+    );
+
+    /// An example of a page of subjects (including their computed fields).
+    pub static ref SUBJECTS: Value = example(
+        Subjects::from((ENTITIES.subjects.clone(), NUMBER_OF_ENTITIES))
+            .with_totals(Totals::new(NUMBER_OF_ENTITIES, NUMBER_OF_ENTITIES))
+            .with_survival_days(&ENTITIES.samples)
+    );
+
+    /// An example of the counts of subjects by the `sex` field.
+    pub static ref SUBJECTS_BY_COUNT: Value =
+        counted(subject::group_by(ENTITIES.subjects.clone(), "sex"), "sex");
+
+    /// An example of a single sample.
+    pub static ref SAMPLE: Value = example(&ENTITIES.samples[0]);
+
+    /// An example of a page of samples.
+    pub static ref SAMPLES: Value = example(
+        Samples::from((ENTITIES.samples.clone(), NUMBER_OF_ENTITIES))
+            .with_totals(Totals::new(NUMBER_OF_ENTITIES, NUMBER_OF_ENTITIES))
+    );
+
+    /// An example of the counts of samples by the `tissue_type` field.
+    pub static ref SAMPLES_BY_COUNT: Value = counted(
+        sample::group_by(ENTITIES.samples.clone(), "tissue_type"),
+        "tissue_type"
+    );
+
+    /// An example of a single file.
+    pub static ref FILE: Value = example(&ENTITIES.files[0]);
+
+    /// An example of a page of files.
+    pub static ref FILES: Value = example(
+        Files::from((ENTITIES.files.clone(), NUMBER_OF_ENTITIES))
+            .with_totals(Totals::new(NUMBER_OF_ENTITIES, NUMBER_OF_ENTITIES))
+    );
+
+    /// An example of the counts (and sizes) of files by the `type` field.
+    pub static ref FILES_BY_COUNT: Value = counted(
+        file::count_by(ENTITIES.files.clone(), "type", &[]),
+        "type"
+    );
+
+    /// An example of the summary of an entity.
+    pub static ref SUMMARY: Value = example(Summary::new(NUMBER_OF_ENTITIES, 0));
+}
+
+#[cfg(test)]
+mod tests {
+    use serde::de::DeserializeOwned;
+
+    use crate::responses;
+
+    use super::*;
+
+    fn assert_deserializes<T: DeserializeOwned>(example: &Value) {
+        if let Err(err) = serde_json::from_value::<T>(example.clone()) {
+            panic!(
+                "example does not deserialize into `{}`: {err}",
+                std::any::type_name::<T>()
+            );
+        }
+    }
+
+    #[test]
+    fn every_example_deserializes_into_its_response() {
+        assert_deserializes::<responses::Subject>(&SUBJECT);
+        assert_deserializes::<responses::Subjects>(&SUBJECTS);
+        assert_deserializes::<responses::by::count::subject::Results>(&SUBJECTS_BY_COUNT);
+        assert_deserializes::<responses::Sample>(&SAMPLE);
+        assert_deserializes::<responses::Samples>(&SAMPLES);
+        assert_deserializes::<responses::by::count::sample::Results>(&SAMPLES_BY_COUNT);
+        assert_deserializes::<responses::File>(&FILE);
+        assert_deserializes::<responses::Files>(&FILES);
+        assert_deserializes::<responses::by::count::file::Response>(&FILES_BY_COUNT);
+        assert_deserializes::<responses::Summary>(&SUMMARY);
+    }
+
+    #[test]
+    fn examples_are_populated() {
+        for example in [&*SUBJECTS, &*SAMPLES, &*FILES] {
+            assert_eq!(
+                example["data"].as_array().unwrap().len(),
+                NUMBER_OF_ENTITIES
+            );
+        }
+
+        for example in [&*SUBJECT, &*SAMPLE, &*FILE] {
+            assert!(!example["metadata"].is_null());
+        }
+    }
+}
@@ -3,6 +3,7 @@
 use itertools::Itertools as _;
 use models::gateway;
 use models::gateway::Link;
+use models::gateway::Open;
 use models::Gateway;
 use models::Url;
 use serde::Deserialize;
@@ -11,7 +12,10 @@ use utoipa::ToSchema;
 
 use ccdi_models as models;
 
+use crate::paginate::Linked;
+use crate::paginate::Totals;
 use crate::responses::entity::Counts;
+use crate::responses::entity::Links;
 use crate::responses::entity::Summary;
 
 /// A response representing a single [`File`](models::File).
@@ -101,6 +105,37 @@ impl From<models::File> for File {
     }
 }
 
+/// Gets the [named gateway](gateway::Named) that a file refers to by name.
+///
+/// The reference server only refers to a single, open access gateway by name,
+/// so every name resolves to that gateway.
+///
+/// # Examples
+///
+/// ```
+/// use ccdi_server as server;
+///
+/// use server::responses::file::named_gateway;
+///
+/// let gateway = named_gateway(String::from("gateway"));
+/// assert_eq!(gateway.name(), "gateway");
+/// assert_eq!(gateway.gateway().kind(), "Open");
+/// ```
+pub fn named_gateway(name: String) -> gateway::Named {
+    gateway::Named::new(
+        name,
+        Gateway::Open(
+            Open::try_new(
+                Link::Direct {
+                    url: "https://example.com".parse::<Url>().unwrap(),
+                },
+                None,
+            )
+            .unwrap(),
+        ),
+    )
+}
+
 /// A response representing multiple files known about by the server.
 ///
 /// When no sort order is provided, files **must** be ordered by the primary
@@ -125,6 +160,20 @@ pub struct Files {
     #[schema(value_type = responses::entity::Summary)]
     summary: Summary,
 
+    /// The links to the other pages of this paged result set (identical to
+    /// the links within the `link` header). These are omitted when the result
+    /// set fits within a single page.
+    #[schema(value_type = responses::entity::Links, nullable = false)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    links: Option<Links>,
+
+    /// The descriptions of the query parameters that were not recognized and,
+    /// as such, were ignored (only present when the server does not reject
+    /// unrecognized query parameters).
+    #[schema(nullable = false)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    warnings: Option<Vec<String>>,
+
     /// The files.
     #[schema(nullable = false, value_type = Vec<responses::File>)]
     data: Vec<models::File>,
@@ -135,6 +184,142 @@ pub struct Files {
     gateways: Option<Vec<models::gateway::Named>>,
 }
 
+impl Files {
+    /// Includes the [`Totals`] of the result set within the summary of the
+    /// [`Files`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ccdi_server as server;
+    ///
+    /// use server::paginate::Totals;
+    /// use server::responses::Files;
+    ///
+    /// let files = Files::from((Vec::new(), 0)).with_totals(Totals::new(10, 0));
+    /// assert_eq!(files.summary().counts().total(), Some(10));
+    /// ```
+    pub fn with_totals(mut self, totals: Totals) -> Self {
+        self.summary = self.summary.with_totals(totals);
+        self
+    }
+
+    /// Gets the summary of the [`Files`] by reference.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ccdi_server as server;
+    ///
+    /// use server::responses::Files;
+    ///
+    /// let files = Files::from((Vec::new(), 0));
+    /// assert_eq!(files.summary().counts().total(), None);
+    /// ```
+    pub fn summary(&self) -> &Summary {
+        &self.summary
+    }
+
+    /// Gets the links to the other pages of the result set within the
+    /// [`Files`] (if they exist).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ccdi_server as server;
+    ///
+    /// use server::paginate::Linked as _;
+    /// use server::responses::entity::Links;
+    /// use server::responses::Files;
+    ///
+    /// let files = Files::from((Vec::new(), 0));
+    /// assert!(files.links().is_none());
+    ///
+    /// let files = files.with_links(Links::default());
+    /// assert!(files.links().is_some());
+    /// ```
+    pub fn links(&self) -> Option<&Links> {
+        self.links.as_ref()
+    }
+
+    /// Includes the descriptions of the ignored query parameters within the
+    /// [`Files`]. The warnings are omitted when none are provided.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ccdi_server as server;
+    ///
+    /// use server::responses::Files;
+    ///
+    /// let files = Files::from((Vec::new(), 0)).with_warnings(Vec::new());
+    /// assert!(files.warnings().is_none());
+    ///
+    /// let files = files.with_warnings(vec![String::from("Ignored.")]);
+    /// assert_eq!(files.warnings().unwrap().len(), 1);
+    /// ```
+    pub fn with_warnings(mut self, warnings: Vec<String>) -> Self {
+        self.warnings = (!warnings.is_empty()).then_some(warnings);
+        self
+    }
+
+    /// Gets the descriptions of the ignored query parameters within the
+    /// [`Files`] (if they exist).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ccdi_server as server;
+    ///
+    /// use server::responses::Files;
+    ///
+    /// let files = Files::from((Vec::new(), 0));
+    /// assert!(files.warnings().is_none());
+    /// ```
+    pub fn warnings(&self) -> Option<&[String]> {
+        self.warnings.as_deref()
+    }
+
+    /// Gets the files within the [`Files`] by reference.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ccdi_server as server;
+    ///
+    /// use server::responses::Files;
+    ///
+    /// let files = Files::from((Vec::new(), 0));
+    /// assert!(files.data().is_empty());
+    /// ```
+    pub fn data(&self) -> &[models::File] {
+        &self.data
+    }
+
+    /// Consumes the [`Files`] and returns the files within it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ccdi_server as server;
+    ///
+    /// use server::responses::Files;
+    ///
+    /// let files = Files::from((Vec::new(), 0));
+    /// assert!(files.into_data().is_empty());
+    /// ```
+    pub fn into_data(self) -> Vec<models::File> {
+        self.data
+    }
+}
+
+impl Linked for Files {
+    fn with_links(mut self, links: Links) -> Self {
+        self.links = Some(links);
+        self
+    }
+}
+
 impl From<(Vec<models::File>, usize)> for Files {
     fn from((files, total): (Vec<models::File>, usize)) -> Self {
         let gateways = files
@@ -143,22 +328,15 @@ impl From<(Vec<models::File>, usize)> for Files {
             .flatten()
             .flat_map(|gateway| gateway.as_reference().map(|gateway| gateway.to_owned()))
             .unique()
-            .map(|name| {
-                gateway::Named::new(
-                    name,
-                    Gateway::Open {
-                        link: Link::Direct {
-                            url: "https://example.com".parse::<Url>().unwrap(),
-                        },
-                    },
-                )
-            })
+            .map(named_gateway)
             .collect::<Vec<_>>();
 
         let counts = Counts::new(files.len(), total);
 
         Self {
             summary: Summary::new(counts),
+            links: None,
+            warnings: None,
             data: files,
             gateways: match gateways.is_empty() {
                 true => None,
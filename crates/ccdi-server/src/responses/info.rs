@@ -7,6 +7,9 @@ use utoipa::ToSchema;
 pub mod api;
 pub mod data;
 pub mod server;
+pub mod versions;
+
+pub use versions::Versions;
 
 /// A response for information regarding the server.
 #[derive(Debug, Default, Deserialize, Serialize, ToSchema)]
@@ -0,0 +1,83 @@
+//! Information regarding the versions implemented by a server.
+
+use clap::crate_version;
+use serde::Deserialize;
+use serde::Serialize;
+use utoipa::ToSchema;
+
+use ccdi_models as models;
+
+/// The filtering and querying capabilities supported by a server.
+#[derive(Debug, Deserialize, Serialize, ToSchema)]
+#[schema(as = responses::info::versions::Capabilities)]
+pub struct Capabilities {
+    /// Whether numeric fields may be filtered by a range (e.g.,
+    /// `age_at_collection={"gte":0,"lte":1826.25}`).
+    range_filters: bool,
+
+    /// Whether string fields may be matched without regard to case (with the
+    /// `case_insensitive` parameter).
+    case_insensitive_matching: bool,
+
+    /// Whether filters may be negated (with the `not:` prefix).
+    negated_filters: bool,
+
+    /// Whether the results of the listing endpoints may be sorted by metadata
+    /// fields (with the `sort` and `order` parameters).
+    sorting: bool,
+
+    /// Whether the metadata fields included within responses may be selected
+    /// (with the `fields` parameter).
+    field_selection: bool,
+}
+
+impl Default for Capabilities {
+    fn default() -> Self {
+        Self {
+            range_filters: true,
+            case_insensitive_matching: true,
+            negated_filters: true,
+            sorting: true,
+            field_selection: true,
+        }
+    }
+}
+
+/// The versions of the specification and the models implemented by a server.
+///
+/// Partners on different release cadences can use this response to discover
+/// which version of the specification a server implements at runtime.
+#[derive(Debug, Deserialize, Serialize, ToSchema)]
+#[schema(as = responses::info::Versions)]
+pub struct Versions {
+    /// The version of the API specification that this server implements.
+    #[schema(example = "v1.3.0")]
+    api_version: String,
+
+    /// The version of the `ccdi-models` crate that this server was built with.
+    #[schema(example = "v1.3.0")]
+    models_version: String,
+
+    /// The filtering and querying capabilities supported by this server.
+    #[schema(value_type = responses::info::versions::Capabilities)]
+    capabilities: Capabilities,
+
+    /// The hash of the commit that this server was built from (if it was built
+    /// with one).
+    ///
+    /// The reference server includes the value of the `GIT_COMMIT_HASH`
+    /// environment variable at build time (if it is set).
+    #[schema(example = "e3f14d4")]
+    commit: Option<String>,
+}
+
+impl Default for Versions {
+    fn default() -> Self {
+        Self {
+            api_version: format!("v{}", crate_version!()),
+            models_version: format!("v{}", models::VERSION),
+            capabilities: Capabilities::default(),
+            commit: option_env!("GIT_COMMIT_HASH").map(String::from),
+        }
+    }
+}
@@ -0,0 +1,261 @@
+//! Responses related to file manifests.
+
+use serde::Deserialize;
+use serde::Serialize;
+use utoipa::ToSchema;
+
+use ccdi_cde as cde;
+use ccdi_models as models;
+
+/// The columns of a file manifest rendered as tab-separated values.
+pub const TSV_COLUMNS: &[&str] = &[
+    "organization",
+    "namespace",
+    "name",
+    "size",
+    "md5",
+    "type",
+    "description",
+    "drs_uri",
+    "access_url",
+    "gateways",
+];
+
+/// A single file within a [`FileManifest`].
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize, ToSchema)]
+#[schema(as = responses::manifest::Entry)]
+pub struct Entry {
+    /// The identifier of the file.
+    #[schema(value_type = models::file::Identifier)]
+    id: models::file::Identifier,
+
+    /// The size of the file in bytes.
+    #[schema(value_type = cde::v1::file::Size, nullable = true)]
+    size: Option<cde::v1::file::Size>,
+
+    /// The checksums of the file.
+    #[schema(value_type = models::file::metadata::Checksums, nullable = true)]
+    checksums: Option<models::file::metadata::Checksums>,
+
+    /// The type of the file.
+    #[schema(value_type = cde::v1::file::Type, nullable = true)]
+    r#type: Option<cde::v1::file::Type>,
+
+    /// A free-text description of the file.
+    #[schema(value_type = cde::v1::file::Description, nullable = true)]
+    description: Option<cde::v1::file::Description>,
+
+    /// A GA4GH Data Repository Service (DRS) URI for the file.
+    #[schema(value_type = models::file::metadata::DrsUri, nullable = true)]
+    drs_uri: Option<models::file::metadata::DrsUri>,
+
+    /// An HTTP or HTTPS URL from which the file can be accessed.
+    #[schema(value_type = models::file::metadata::AccessUrl, nullable = true)]
+    access_url: Option<models::file::metadata::AccessUrl>,
+
+    /// The gateways through which the file may be accessed.
+    #[schema(value_type = Vec<models::gateway::AnonymousOrReference>)]
+    gateways: Vec<models::gateway::AnonymousOrReference>,
+}
+
+impl Entry {
+    /// Gets the identifier of the file within the [`Entry`].
+    pub fn id(&self) -> &models::file::Identifier {
+        &self.id
+    }
+
+    /// Renders the [`Entry`] as a single row of tab-separated values (in the
+    /// order of [`TSV_COLUMNS`]).
+    fn tsv_row(&self) -> String {
+        let fields = [
+            self.id.namespace().organization().as_str().to_string(),
+            self.id.namespace().name().as_str().to_string(),
+            self.id.name().as_str().to_string(),
+            optional(self.size.as_ref()),
+            optional(
+                self.checksums
+                    .as_ref()
+                    .and_then(|checksums| checksums.md5())
+                    .map(|md5| md5.inner()),
+            ),
+            optional(self.r#type.as_ref()),
+            optional(self.description.as_ref()),
+            optional(self.drs_uri.as_ref()),
+            optional(self.access_url.as_ref()),
+            match self.gateways.is_empty() {
+                true => String::new(),
+                // SAFETY: gateways are always able to be represented as JSON.
+                false => serde_json::to_string(&self.gateways).unwrap(),
+            },
+        ];
+
+        fields
+            .iter()
+            .map(|field| escape(field))
+            .collect::<Vec<_>>()
+            .join("\t")
+    }
+}
+
+impl From<&models::File> for Entry {
+    fn from(file: &models::File) -> Self {
+        let metadata = file.metadata();
+
+        Self {
+            id: file.id().clone(),
+            size: metadata
+                .and_then(|metadata| metadata.size())
+                .map(|size| size.value().clone()),
+            checksums: metadata
+                .and_then(|metadata| metadata.checksums())
+                .map(|checksums| checksums.value().clone()),
+            r#type: metadata
+                .and_then(|metadata| metadata.r#type())
+                .map(|r#type| r#type.value().clone()),
+            description: metadata
+                .and_then(|metadata| metadata.description())
+                .map(|description| description.value().clone()),
+            drs_uri: metadata
+                .and_then(|metadata| metadata.drs_uri())
+                .map(|drs_uri| drs_uri.value().clone()),
+            access_url: metadata
+                .and_then(|metadata| metadata.access_url())
+                .map(|access_url| access_url.value().clone()),
+            gateways: file
+                .gateways()
+                .map(|gateways| gateways.iter().cloned().collect())
+                .unwrap_or_default(),
+        }
+    }
+}
+
+/// A manifest of files suitable for bulk download tooling.
+///
+/// Each entry lists the identifier of a file alongside the information needed
+/// to retrieve and verify it (its size, checksums, type, and the DRS URI,
+/// access URL, and gateways through which it may be accessed). Entries are
+/// ordered by the identifier of the file.
+#[derive(Debug, Deserialize, Serialize, ToSchema)]
+#[schema(as = responses::FileManifest)]
+pub struct FileManifest {
+    /// The files within the manifest.
+    #[schema(nullable = false, value_type = Vec<responses::manifest::Entry>)]
+    data: Vec<Entry>,
+}
+
+impl FileManifest {
+    /// Gets the entries within the [`FileManifest`].
+    pub fn data(&self) -> &[Entry] {
+        &self.data
+    }
+
+    /// Renders the [`FileManifest`] as tab-separated values.
+    ///
+    /// The first row is a header naming each of the [`TSV_COLUMNS`]. Missing
+    /// values are empty, and backslashes, tabs, carriage returns, and newlines
+    /// within a value are escaped as `\\`, `\t`, `\r`, and `\n` respectively
+    /// (so that every file is on a single row). The gateways of each file are
+    /// written as a JSON array.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ccdi_cde as cde;
+    /// use ccdi_models as models;
+    /// use ccdi_server as server;
+    /// use nonempty::NonEmpty;
+    ///
+    /// use models::file::metadata::Builder;
+    /// use models::metadata::field::unowned::file::Description;
+    /// use models::namespace;
+    /// use server::responses::FileManifest;
+    ///
+    /// let namespace = namespace::Identifier::new(
+    ///     "example-organization"
+    ///         .parse::<models::organization::Identifier>()
+    ///         .unwrap(),
+    ///     "ExampleNamespace"
+    ///         .parse::<namespace::identifier::Name>()
+    ///         .unwrap(),
+    /// );
+    ///
+    /// let file = models::File::new(
+    ///     models::file::Identifier::new(namespace.clone(), cde::v1::file::Name::new("File1.txt")),
+    ///     NonEmpty::new(models::sample::Identifier::new(namespace, "Sample1")),
+    ///     None,
+    ///     Some(
+    ///         Builder::default()
+    ///             .description(Description::new(
+    ///                 cde::v1::file::Description::new("A\tdescription"),
+    ///                 None,
+    ///                 None,
+    ///                 None,
+    ///             ))
+    ///             .build(),
+    ///     ),
+    /// );
+    ///
+    /// let manifest = FileManifest::from(vec![file]);
+    /// let tsv = manifest.to_tsv();
+    /// let mut rows = tsv.lines().skip(1);
+    ///
+    /// assert_eq!(
+    ///     rows.next().unwrap(),
+    ///     "example-organization\tExampleNamespace\tFile1.txt\t\t\t\tA\\tdescription\t\t\t"
+    /// );
+    /// assert!(rows.next().is_none());
+    /// ```
+    pub fn to_tsv(&self) -> String {
+        let mut result = TSV_COLUMNS.join("\t");
+        result.push('\n');
+
+        for entry in &self.data {
+            result.push_str(&entry.tsv_row());
+            result.push('\n');
+        }
+
+        result
+    }
+}
+
+impl From<Vec<models::File>> for FileManifest {
+    fn from(files: Vec<models::File>) -> Self {
+        Self {
+            data: files.iter().map(Entry::from).collect(),
+        }
+    }
+}
+
+/// Renders an optional value as a field (where a missing value is empty).
+fn optional(value: Option<impl std::fmt::Display>) -> String {
+    value.map(|value| value.to_string()).unwrap_or_default()
+}
+
+/// Escapes the backslashes, tabs, carriage returns, and newlines within a
+/// field of tab-separated values.
+pub(crate) fn escape(field: &str) -> String {
+    let mut result = String::with_capacity(field.len());
+
+    for c in field.chars() {
+        match c {
+            '\\' => result.push_str("\\\\"),
+            '\t' => result.push_str("\\t"),
+            '\r' => result.push_str("\\r"),
+            '\n' => result.push_str("\\n"),
+            c => result.push(c),
+        }
+    }
+
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_escapes_fields() {
+        assert_eq!(escape("plain"), "plain");
+        assert_eq!(escape("a\tb\nc\r\nd\\e"), "a\\tb\\nc\\r\\nd\\\\e");
+    }
+}
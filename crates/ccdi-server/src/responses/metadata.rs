@@ -4,10 +4,40 @@ use serde::Deserialize;
 use serde::Serialize;
 use utoipa::ToSchema;
 
+use ccdi_cde as cde;
 use ccdi_models as models;
 
+use cde::parse::cde::member::Variant;
+use models::metadata::field::description::harmonized::Kind;
+use models::metadata::field::description::unharmonized::ValueKind;
+use models::metadata::field::description::Unharmonized;
 use models::metadata::field::Description;
 
+use crate::responses::manifest::escape;
+
+/// The columns of the field descriptions rendered as tab-separated values.
+pub const TSV_COLUMNS: &[&str] = &[
+    "field",
+    "harmonized",
+    "standard",
+    "url",
+    "value_type",
+    "multivalued",
+    "description",
+];
+
+/// The paths of the harmonized fields that hold multiple values (i.e., whose
+/// values are arrays within the `metadata` block of an entity).
+pub const MULTIVALUED_FIELDS: &[&str] = &[
+    "anatomical_sites",
+    "associated_diagnoses",
+    "associated_diagnosis_categories",
+    "identifiers",
+    "institution",
+    "race",
+    "study_funding_id",
+];
+
 /// A response for describing metadata fields for a subject, sample, or file.
 #[derive(Debug, Deserialize, Serialize, ToSchema)]
 #[schema(as = responses::metadata::FieldDescriptions)]
@@ -15,10 +45,519 @@ pub struct FieldDescriptions {
     /// Field descriptions.
     #[schema(value_type = Vec<models::metadata::field::Description>)]
     fields: Vec<Description>,
+
+    /// Descriptions of the unharmonized fields that the server has registered
+    /// as emitting.
+    ///
+    /// This is omitted when the server has not registered any unharmonized
+    /// fields. Unharmonized fields that are not registered may still be
+    /// emitted by the server.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    #[schema(value_type = Vec<models::metadata::field::description::Unharmonized>)]
+    unharmonized: Vec<Unharmonized>,
+}
+
+impl FieldDescriptions {
+    /// Gets the descriptions of the unharmonized fields registered by the
+    /// server by reference.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ccdi_models as models;
+    /// use ccdi_server as server;
+    ///
+    /// use models::metadata::field::description::unharmonized::ValueKind;
+    /// use models::metadata::field::description::UnharmonizedRegistry;
+    /// use server::responses::metadata::FieldDescriptions;
+    ///
+    /// let mut registry = UnharmonizedRegistry::default();
+    /// registry
+    ///     .register(
+    ///         "consortium_id",
+    ///         "The consortium identifier.",
+    ///         ValueKind::String,
+    ///     )
+    ///     .unwrap();
+    ///
+    /// let descriptions = FieldDescriptions::from((
+    ///     models::metadata::field::description::harmonized::subject::get_field_descriptions(),
+    ///     registry.descriptions().cloned().collect::<Vec<_>>(),
+    /// ));
+    ///
+    /// assert_eq!(descriptions.unharmonized().len(), 1);
+    /// ```
+    pub fn unharmonized(&self) -> &[Unharmonized] {
+        self.unharmonized.as_slice()
+    }
+}
+
+impl FieldDescriptions {
+    /// Flattens the [`FieldDescriptions`] into one [`FieldRow`] per field (the
+    /// harmonized fields followed by the registered unharmonized fields).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ccdi_models as models;
+    /// use ccdi_server as server;
+    ///
+    /// use server::responses::metadata::FieldDescriptions;
+    ///
+    /// let descriptions = FieldDescriptions::from(
+    ///     models::metadata::field::description::harmonized::subject::get_field_descriptions(),
+    /// );
+    /// let rows = descriptions.rows();
+    ///
+    /// let race = rows.iter().find(|row| row.field() == "race").unwrap();
+    /// assert!(race.harmonized());
+    /// assert!(race.multivalued());
+    /// assert_eq!(race.value_type(), Some("enum"));
+    /// ```
+    pub fn rows(&self) -> Vec<FieldRow> {
+        self.fields
+            .iter()
+            .map(FieldRow::from)
+            .chain(self.unharmonized.iter().map(FieldRow::from))
+            .collect()
+    }
+
+    /// Renders the [`FieldDescriptions`] as tab-separated values.
+    ///
+    /// The first row is a header naming each of the [`TSV_COLUMNS`], and each
+    /// following row describes a single field (see [`rows()`](Self::rows)).
+    /// Missing values are empty, booleans are written as `true` or `false`, and
+    /// backslashes, tabs, carriage returns, and newlines within a value are
+    /// escaped as `\\`, `\t`, `\r`, and `\n` respectively (so that every field
+    /// is on a single row).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ccdi_models as models;
+    /// use ccdi_server as server;
+    ///
+    /// use models::metadata::field::description::harmonized;
+    /// use server::responses::metadata::FieldDescriptions;
+    ///
+    /// let fields = harmonized::sample::get_field_descriptions();
+    /// let count = fields.len();
+    ///
+    /// let tsv = FieldDescriptions::from(fields).to_tsv();
+    /// let mut rows = tsv.lines();
+    ///
+    /// assert_eq!(
+    ///     rows.next().unwrap(),
+    ///     "field\tharmonized\tstandard\turl\tvalue_type\tmultivalued\tdescription"
+    /// );
+    /// assert_eq!(rows.count(), count);
+    /// ```
+    pub fn to_tsv(&self) -> String {
+        let mut result = TSV_COLUMNS.join("\t");
+        result.push('\n');
+
+        for row in self.rows() {
+            result.push_str(&row.tsv_row());
+            result.push('\n');
+        }
+
+        result
+    }
 }
 
 impl From<Vec<Description>> for FieldDescriptions {
     fn from(fields: Vec<Description>) -> Self {
-        Self { fields }
+        Self {
+            fields,
+            unharmonized: Vec::new(),
+        }
+    }
+}
+
+impl From<(Vec<Description>, Vec<Unharmonized>)> for FieldDescriptions {
+    fn from((fields, unharmonized): (Vec<Description>, Vec<Unharmonized>)) -> Self {
+        Self {
+            fields,
+            unharmonized,
+        }
+    }
+}
+
+/// A metadata field description flattened into a single row of a table (see
+/// [`FieldDescriptions::rows()`]).
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct FieldRow {
+    /// The path of the field within the `metadata` block.
+    field: String,
+
+    /// Whether the field is harmonized.
+    harmonized: bool,
+
+    /// The name of the standard to which the field is harmonized (if any).
+    standard: Option<String>,
+
+    /// A link to the definition of the standard (if any).
+    url: Option<String>,
+
+    /// The type of the value of the field (if known).
+    value_type: Option<String>,
+
+    /// Whether the field holds multiple values.
+    multivalued: bool,
+
+    /// The description of the field (if any).
+    description: Option<String>,
+}
+
+impl FieldRow {
+    /// Gets the path of the field within the `metadata` block.
+    pub fn field(&self) -> &str {
+        self.field.as_str()
+    }
+
+    /// Gets whether the field is harmonized.
+    pub fn harmonized(&self) -> bool {
+        self.harmonized
+    }
+
+    /// Gets the name of the standard to which the field is harmonized (if
+    /// any).
+    pub fn standard(&self) -> Option<&str> {
+        self.standard.as_deref()
+    }
+
+    /// Gets the link to the definition of the standard (if any).
+    pub fn url(&self) -> Option<&str> {
+        self.url.as_deref()
+    }
+
+    /// Gets the type of the value of the field (if known).
+    ///
+    /// For harmonized fields, this is the kind of the type that backs the
+    /// field (`enum` or `struct`). For unharmonized fields, this is the value
+    /// kind that was registered (if any).
+    pub fn value_type(&self) -> Option<&str> {
+        self.value_type.as_deref()
+    }
+
+    /// Gets whether the field holds multiple values.
+    pub fn multivalued(&self) -> bool {
+        self.multivalued
+    }
+
+    /// Gets the description of the field (if any).
+    pub fn description(&self) -> Option<&str> {
+        self.description.as_deref()
+    }
+
+    /// Renders the [`FieldRow`] as a single row of tab-separated values (in the
+    /// order of [`TSV_COLUMNS`]).
+    fn tsv_row(&self) -> String {
+        let fields = [
+            self.field.clone(),
+            self.harmonized.to_string(),
+            self.standard.clone().unwrap_or_default(),
+            self.url.clone().unwrap_or_default(),
+            self.value_type.clone().unwrap_or_default(),
+            self.multivalued.to_string(),
+            self.description.clone().unwrap_or_default(),
+        ];
+
+        fields
+            .iter()
+            .map(|field| escape(field))
+            .collect::<Vec<_>>()
+            .join("\t")
+    }
+}
+
+impl From<&Description> for FieldRow {
+    fn from(description: &Description) -> Self {
+        match description {
+            Description::Harmonized(harmonized) => Self {
+                field: harmonized.path().to_string(),
+                harmonized: true,
+                standard: harmonized
+                    .standard()
+                    .map(|standard| standard.name().to_string()),
+                url: harmonized
+                    .standard()
+                    .map(|standard| standard.url().to_string()),
+                value_type: Some(String::from(match harmonized.kind() {
+                    Kind::Enum => "enum",
+                    Kind::Struct => "struct",
+                })),
+                multivalued: MULTIVALUED_FIELDS.contains(&harmonized.path()),
+                description: Some(harmonized.description().to_string()),
+            },
+            Description::Unharmonized(unharmonized) => Self::from(unharmonized),
+        }
+    }
+}
+
+impl From<&Unharmonized> for FieldRow {
+    fn from(unharmonized: &Unharmonized) -> Self {
+        Self {
+            field: unharmonized.path().to_string(),
+            harmonized: false,
+            standard: unharmonized.standard().cloned(),
+            url: unharmonized.url().map(|url| url.to_string()),
+            value_type: unharmonized
+                .value_kind()
+                .map(|value_kind| value_kind.to_string()),
+            multivalued: unharmonized.value_kind() == Some(ValueKind::Array),
+            description: unharmonized.description().cloned(),
+        }
+    }
+}
+
+/// A permissible value of a common data element, as parsed from the
+/// documentation of the common data element.
+#[derive(Debug, Deserialize, Serialize, ToSchema)]
+#[schema(as = responses::metadata::CdePermissibleValue)]
+pub struct CdePermissibleValue {
+    /// The permissible value.
+    value: String,
+
+    /// The description of the permissible value.
+    description: String,
+
+    /// The value meaning long name of the permissible value (if available).
+    #[schema(nullable = true)]
+    vm_long_name: Option<String>,
+
+    /// The value meaning public identifier of the permissible value (if
+    /// available).
+    #[schema(nullable = true)]
+    vm_public_id: Option<String>,
+
+    /// The concept code of the permissible value (if available).
+    #[schema(nullable = true)]
+    concept_code: Option<String>,
+
+    /// The date at which the permissible value became effective (if
+    /// available).
+    #[schema(nullable = true)]
+    begin_date: Option<String>,
+}
+
+impl From<&Variant> for CdePermissibleValue {
+    fn from(variant: &Variant) -> Self {
+        let metadata = |key: &str| {
+            variant
+                .metadata()
+                .and_then(|metadata| metadata.get(key))
+                .cloned()
+        };
+
+        Self {
+            value: variant.permissible_value().to_string(),
+            description: variant.description().to_string(),
+            vm_long_name: metadata("VM Long Name"),
+            vm_public_id: metadata("VM Public ID"),
+            concept_code: metadata("Concept Code"),
+            begin_date: metadata("Begin Date"),
+        }
+    }
+}
+
+/// The provenance of a metadata field that is backed by a common data element.
+#[derive(Debug, Deserialize, Serialize, ToSchema)]
+#[schema(as = responses::metadata::Cde)]
+pub struct Cde {
+    /// The name of the common data element (e.g., `caDSR CDE 6343385 v1.00`).
+    standard: String,
+
+    /// A link to the definition of the common data element.
+    url: String,
+
+    /// The permissible values of the common data element.
+    ///
+    /// This is only present when the common data element is an enumeration.
+    #[schema(nullable = true)]
+    permissible_values: Option<Vec<CdePermissibleValue>>,
+}
+
+impl Cde {
+    /// Gets the name of the common data element by reference.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ccdi_cde as cde;
+    /// use ccdi_models as models;
+    /// use ccdi_server as server;
+    ///
+    /// use models::metadata::field::description::r#trait::Description as _;
+    /// use server::responses::metadata::FieldDescription;
+    ///
+    /// let description = FieldDescription::from(cde::v1::subject::Sex::description());
+    /// let cde = description.cde().unwrap();
+    ///
+    /// assert_eq!(cde.standard(), "caDSR CDE 6343385 v1.00");
+    /// ```
+    pub fn standard(&self) -> &str {
+        self.standard.as_str()
+    }
+
+    /// Gets the link to the definition of the common data element by
+    /// reference.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ccdi_cde as cde;
+    /// use ccdi_models as models;
+    /// use ccdi_server as server;
+    ///
+    /// use models::metadata::field::description::r#trait::Description as _;
+    /// use server::responses::metadata::FieldDescription;
+    ///
+    /// let description = FieldDescription::from(cde::v1::subject::Sex::description());
+    /// let cde = description.cde().unwrap();
+    ///
+    /// assert!(cde.url().starts_with("https://cadsr.cancer.gov/"));
+    /// ```
+    pub fn url(&self) -> &str {
+        self.url.as_str()
+    }
+
+    /// Gets the permissible values of the common data element by reference
+    /// (if the common data element is an enumeration).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ccdi_cde as cde;
+    /// use ccdi_models as models;
+    /// use ccdi_server as server;
+    ///
+    /// use models::metadata::field::description::r#trait::Description as _;
+    /// use server::responses::metadata::FieldDescription;
+    ///
+    /// let description = FieldDescription::from(cde::v1::subject::Sex::description());
+    /// let cde = description.cde().unwrap();
+    ///
+    /// assert_eq!(cde.permissible_values().unwrap().len(), 4);
+    /// ```
+    pub fn permissible_values(&self) -> Option<&[CdePermissibleValue]> {
+        self.permissible_values.as_deref()
+    }
+}
+
+/// A response for describing a single metadata field alongside the provenance
+/// of the common data element that backs it (if any).
+#[derive(Debug, Deserialize, Serialize, ToSchema)]
+#[schema(as = responses::metadata::FieldDescription)]
+pub struct FieldDescription {
+    /// The field description.
+    #[schema(value_type = models::metadata::field::Description)]
+    field: Description,
+
+    /// The provenance of the common data element that backs the field.
+    ///
+    /// This is `null` when the field is not backed by a common data element.
+    #[schema(value_type = Option<responses::metadata::Cde>, nullable = true)]
+    cde: Option<Cde>,
+}
+
+impl FieldDescription {
+    /// Gets the field description by reference.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ccdi_models as models;
+    /// use ccdi_server as server;
+    ///
+    /// use models::metadata::field::description::r#trait::Description as _;
+    /// use models::metadata::field::Description;
+    /// use server::responses::metadata::FieldDescription;
+    ///
+    /// let description =
+    ///     FieldDescription::from(models::sample::metadata::AgeAtDiagnosis::description());
+    ///
+    /// assert!(matches!(description.field(), Description::Harmonized(_)));
+    /// ```
+    pub fn field(&self) -> &Description {
+        &self.field
+    }
+
+    /// Gets the provenance of the common data element that backs the field by
+    /// reference (if any).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ccdi_models as models;
+    /// use ccdi_server as server;
+    ///
+    /// use models::metadata::field::description::r#trait::Description as _;
+    /// use server::responses::metadata::FieldDescription;
+    ///
+    /// let description =
+    ///     FieldDescription::from(models::sample::metadata::AgeAtDiagnosis::description());
+    ///
+    /// assert!(description.cde().is_none());
+    /// ```
+    pub fn cde(&self) -> Option<&Cde> {
+        self.cde.as_ref()
+    }
+}
+
+impl From<Description> for FieldDescription {
+    fn from(field: Description) -> Self {
+        let cde = match &field {
+            Description::Harmonized(harmonized) => harmonized.standard().map(|standard| {
+                let permissible_values = match harmonized.kind() {
+                    Kind::Enum => harmonized.members().map(|members| {
+                        members
+                            .iter()
+                            .filter_map(|(_, member)| member.get_variant())
+                            .map(CdePermissibleValue::from)
+                            .collect()
+                    }),
+                    Kind::Struct => None,
+                };
+
+                Cde {
+                    standard: standard.name().to_string(),
+                    url: standard.url().to_string(),
+                    permissible_values,
+                }
+            }),
+            Description::Unharmonized(_) => None,
+        };
+
+        Self { field, cde }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn every_multivalued_field_is_a_harmonized_field() {
+        use models::metadata::field::description::harmonized;
+
+        let paths = [
+            harmonized::subject::get_field_descriptions(),
+            harmonized::sample::get_field_descriptions(),
+            harmonized::file::get_field_descriptions(),
+            harmonized::namespace::get_field_descriptions(),
+            harmonized::organization::get_field_descriptions(),
+        ]
+        .into_iter()
+        .flatten()
+        .filter_map(|description| match description {
+            Description::Harmonized(harmonized) => Some(harmonized.path().to_string()),
+            Description::Unharmonized(_) => None,
+        })
+        .collect::<Vec<_>>();
+
+        for field in MULTIVALUED_FIELDS {
+            assert!(paths.iter().any(|path| path == field), "{field}");
+        }
     }
 }
@@ -6,6 +6,10 @@ use utoipa::ToSchema;
 
 use ccdi_models as models;
 
+use crate::paginate::Linked;
+use crate::responses::by::count::sample::Results;
+use crate::responses::entity;
+
 /// A response for describing a namespace.
 #[derive(Debug, Deserialize, Serialize, ToSchema)]
 #[schema(as = responses::Namespace)]
@@ -17,13 +21,307 @@ impl From<models::Namespace> for Namespace {
     }
 }
 
-/// A response for describing namespaces.
+/// A response representing multiple namespaces known about by the server.
 #[derive(Debug, Deserialize, Serialize, ToSchema)]
 #[schema(as = responses::Namespaces)]
-pub struct Namespaces(Vec<models::Namespace>);
+pub struct Namespaces {
+    /// A summary of this paged result set.
+    #[schema(value_type = responses::entity::Summary)]
+    summary: entity::Summary,
+
+    /// The namespaces.
+    #[schema(nullable = false, value_type = Vec<responses::Namespace>)]
+    data: Vec<models::Namespace>,
+}
+
+// NOTE: the links to the other pages of namespaces are only reported within
+// the `link` header.
+impl Linked for Namespaces {}
+
+impl From<(Vec<models::Namespace>, usize)> for Namespaces {
+    fn from((namespaces, total): (Vec<models::Namespace>, usize)) -> Self {
+        Self {
+            summary: entity::Summary::new(entity::Counts::new(namespaces.len(), total)),
+            data: namespaces,
+        }
+    }
+}
+
+/// The number of each kind of entity within a namespace.
+#[derive(Debug, Default, Deserialize, Eq, PartialEq, Serialize, ToSchema)]
+#[schema(as = responses::namespace::Counts)]
+pub struct Counts {
+    /// The number of subjects within the namespace.
+    pub subjects: usize,
+
+    /// The number of samples within the namespace.
+    pub samples: usize,
+
+    /// The number of files within the namespace.
+    pub files: usize,
+}
+
+/// A summary of the entities within a single namespace.
+#[derive(Debug, Deserialize, Serialize, ToSchema)]
+#[schema(as = responses::namespace::Summary)]
+pub struct Summary {
+    /// The identifier of the namespace.
+    #[schema(value_type = models::namespace::Identifier)]
+    id: models::namespace::Identifier,
+
+    /// The number of each kind of entity within the namespace.
+    #[schema(value_type = responses::namespace::Counts)]
+    counts: Counts,
+
+    /// The total size (in bytes) of the files within the namespace.
+    ///
+    /// Files without a known size are not included within the total. The
+    /// total is capped at the largest size that the server can represent.
+    total_file_bytes: usize,
+
+    /// The number of samples within the namespace for each `diagnosis`.
+    #[schema(value_type = responses::by::count::sample::Results)]
+    diagnoses: Results,
+
+    /// The number of samples within the namespace for each set of
+    /// `depositions` (i.e., the studies to which the samples were deposited).
+    #[schema(value_type = responses::by::count::sample::Results)]
+    studies: Results,
+}
+
+impl Summary {
+    /// Creates a new [`Summary`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ccdi_models as models;
+    /// use ccdi_server as server;
+    ///
+    /// use models::namespace::identifier::Name;
+    /// use models::namespace::Identifier;
+    /// use models::organization;
+    /// use server::responses::by::count::sample::Results;
+    /// use server::responses::namespace::Counts;
+    /// use server::responses::namespace::Summary;
+    ///
+    /// let id = Identifier::new(
+    ///     "example-organization"
+    ///         .parse::<organization::Identifier>()
+    ///         .unwrap(),
+    ///     "ExampleNamespace".parse::<Name>().unwrap(),
+    /// );
+    ///
+    /// let summary = Summary::new(
+    ///     id,
+    ///     Counts::default(),
+    ///     0,
+    ///     Results::new(Vec::new(), 0),
+    ///     Results::new(Vec::new(), 0),
+    /// );
+    ///
+    /// assert_eq!(summary.id().name().as_str(), "ExampleNamespace");
+    /// assert_eq!(summary.counts(), &Counts::default());
+    /// assert_eq!(summary.total_file_bytes(), 0);
+    /// assert_eq!(summary.diagnoses().total, 0);
+    /// assert_eq!(summary.studies().total, 0);
+    /// ```
+    pub fn new(
+        id: models::namespace::Identifier,
+        counts: Counts,
+        total_file_bytes: usize,
+        diagnoses: Results,
+        studies: Results,
+    ) -> Self {
+        Self {
+            id,
+            counts,
+            total_file_bytes,
+            diagnoses,
+            studies,
+        }
+    }
+
+    /// Gets the identifier of the namespace for the [`Summary`] by reference.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ccdi_models as models;
+    /// use ccdi_server as server;
+    ///
+    /// use models::namespace::identifier::Name;
+    /// use models::namespace::Identifier;
+    /// use models::organization;
+    /// use server::responses::by::count::sample::Results;
+    /// use server::responses::namespace::Counts;
+    /// use server::responses::namespace::Summary;
+    ///
+    /// let id = Identifier::new(
+    ///     "example-organization"
+    ///         .parse::<organization::Identifier>()
+    ///         .unwrap(),
+    ///     "ExampleNamespace".parse::<Name>().unwrap(),
+    /// );
+    ///
+    /// let summary = Summary::new(
+    ///     id,
+    ///     Counts::default(),
+    ///     0,
+    ///     Results::new(Vec::new(), 0),
+    ///     Results::new(Vec::new(), 0),
+    /// );
+    ///
+    /// assert_eq!(summary.id().name().as_str(), "ExampleNamespace");
+    /// ```
+    pub fn id(&self) -> &models::namespace::Identifier {
+        &self.id
+    }
+
+    /// Gets the number of each kind of entity for the [`Summary`] by
+    /// reference.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ccdi_models as models;
+    /// use ccdi_server as server;
+    ///
+    /// use models::namespace::identifier::Name;
+    /// use models::namespace::Identifier;
+    /// use models::organization;
+    /// use server::responses::by::count::sample::Results;
+    /// use server::responses::namespace::Counts;
+    /// use server::responses::namespace::Summary;
+    ///
+    /// let id = Identifier::new(
+    ///     "example-organization"
+    ///         .parse::<organization::Identifier>()
+    ///         .unwrap(),
+    ///     "ExampleNamespace".parse::<Name>().unwrap(),
+    /// );
+    ///
+    /// let summary = Summary::new(
+    ///     id,
+    ///     Counts::default(),
+    ///     0,
+    ///     Results::new(Vec::new(), 0),
+    ///     Results::new(Vec::new(), 0),
+    /// );
+    ///
+    /// assert_eq!(summary.counts().subjects, 0);
+    /// ```
+    pub fn counts(&self) -> &Counts {
+        &self.counts
+    }
+
+    /// Gets the total size (in bytes) of the files for the [`Summary`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ccdi_models as models;
+    /// use ccdi_server as server;
+    ///
+    /// use models::namespace::identifier::Name;
+    /// use models::namespace::Identifier;
+    /// use models::organization;
+    /// use server::responses::by::count::sample::Results;
+    /// use server::responses::namespace::Counts;
+    /// use server::responses::namespace::Summary;
+    ///
+    /// let id = Identifier::new(
+    ///     "example-organization"
+    ///         .parse::<organization::Identifier>()
+    ///         .unwrap(),
+    ///     "ExampleNamespace".parse::<Name>().unwrap(),
+    /// );
+    ///
+    /// let summary = Summary::new(
+    ///     id,
+    ///     Counts::default(),
+    ///     42,
+    ///     Results::new(Vec::new(), 0),
+    ///     Results::new(Vec::new(), 0),
+    /// );
+    ///
+    /// assert_eq!(summary.total_file_bytes(), 42);
+    /// ```
+    pub fn total_file_bytes(&self) -> usize {
+        self.total_file_bytes
+    }
+
+    /// Gets the number of samples for each diagnosis for the [`Summary`] by
+    /// reference.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ccdi_models as models;
+    /// use ccdi_server as server;
+    ///
+    /// use models::namespace::identifier::Name;
+    /// use models::namespace::Identifier;
+    /// use models::organization;
+    /// use server::responses::by::count::sample::Results;
+    /// use server::responses::namespace::Counts;
+    /// use server::responses::namespace::Summary;
+    ///
+    /// let id = Identifier::new(
+    ///     "example-organization"
+    ///         .parse::<organization::Identifier>()
+    ///         .unwrap(),
+    ///     "ExampleNamespace".parse::<Name>().unwrap(),
+    /// );
+    ///
+    /// let summary = Summary::new(
+    ///     id,
+    ///     Counts::default(),
+    ///     0,
+    ///     Results::new(Vec::new(), 0),
+    ///     Results::new(Vec::new(), 0),
+    /// );
+    ///
+    /// assert_eq!(summary.diagnoses().total, 0);
+    /// ```
+    pub fn diagnoses(&self) -> &Results {
+        &self.diagnoses
+    }
 
-impl From<Vec<models::Namespace>> for Namespaces {
-    fn from(namespaces: Vec<models::Namespace>) -> Self {
-        Self(namespaces)
+    /// Gets the number of samples for each set of depositions for the
+    /// [`Summary`] by reference.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ccdi_models as models;
+    /// use ccdi_server as server;
+    ///
+    /// use models::namespace::identifier::Name;
+    /// use models::namespace::Identifier;
+    /// use models::organization;
+    /// use server::responses::by::count::sample::Results;
+    /// use server::responses::namespace::Counts;
+    /// use server::responses::namespace::Summary;
+    ///
+    /// let id = Identifier::new(
+    ///     "example-organization"
+    ///         .parse::<organization::Identifier>()
+    ///         .unwrap(),
+    ///     "ExampleNamespace".parse::<Name>().unwrap(),
+    /// );
+    ///
+    /// let summary = Summary::new(
+    ///     id,
+    ///     Counts::default(),
+    ///     0,
+    ///     Results::new(Vec::new(), 0),
+    ///     Results::new(Vec::new(), 1),
+    /// );
+    ///
+    /// assert_eq!(summary.studies().missing, 1);
+    /// ```
+    pub fn studies(&self) -> &Results {
+        &self.studies
     }
 }
@@ -6,6 +6,10 @@ use utoipa::ToSchema;
 
 use ccdi_models as models;
 
+use crate::paginate::Linked;
+use crate::responses::entity::Counts;
+use crate::responses::entity::Summary;
+
 /// A response for describing a organization.
 #[derive(Debug, Deserialize, Serialize, ToSchema)]
 #[schema(as = responses::Organization)]
@@ -17,13 +21,28 @@ impl From<models::Organization> for Organization {
     }
 }
 
-/// A response for describing organizations.
+/// A response representing multiple organizations known about by the server.
 #[derive(Debug, Deserialize, Serialize, ToSchema)]
 #[schema(as = responses::Organizations)]
-pub struct Organizations(Vec<models::Organization>);
+pub struct Organizations {
+    /// A summary of this paged result set.
+    #[schema(value_type = responses::entity::Summary)]
+    summary: Summary,
+
+    /// The organizations.
+    #[schema(nullable = false, value_type = Vec<responses::Organization>)]
+    data: Vec<models::Organization>,
+}
+
+// NOTE: the links to the other pages of organizations are only reported within
+// the `link` header.
+impl Linked for Organizations {}
 
-impl From<Vec<models::Organization>> for Organizations {
-    fn from(organizations: Vec<models::Organization>) -> Self {
-        Self(organizations)
+impl From<(Vec<models::Organization>, usize)> for Organizations {
+    fn from((organizations, total): (Vec<models::Organization>, usize)) -> Self {
+        Self {
+            summary: Summary::new(Counts::new(organizations.len(), total)),
+            data: organizations,
+        }
     }
 }
@@ -1,6 +1,11 @@
+//! Responses related to samples.
+
+use std::collections::BTreeMap;
+
 use itertools::Itertools;
 use models::gateway;
 use models::gateway::Link;
+use models::gateway::Open;
 use models::Gateway;
 use models::Url;
 use serde::Deserialize;
@@ -9,8 +14,12 @@ use utoipa::ToSchema;
 
 use ccdi_models as models;
 
+use crate::paginate::Linked;
+use crate::paginate::Totals;
 use crate::responses::entity::Counts;
+use crate::responses::entity::Links;
 use crate::responses::entity::Summary;
+use crate::responses::validation::Finding;
 
 /// A response representing a single [`Sample`](models::Sample).
 #[derive(Debug, Deserialize, Serialize, ToSchema)]
@@ -19,6 +28,86 @@ pub struct Sample {
     /// Sample.
     #[serde(flatten)]
     inner: models::Sample,
+
+    /// The findings from checking the consistency of the sample against the
+    /// files derived from it.
+    ///
+    /// This field is only included when validation is requested (using
+    /// `?validate=true`).
+    #[schema(nullable = false, value_type = Option<Vec<responses::validation::Finding>>)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    findings: Option<Vec<Finding>>,
+}
+
+impl Sample {
+    /// Creates a new [`Sample`] response.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ccdi_models as models;
+    /// use ccdi_server as server;
+    ///
+    /// use models::namespace;
+    /// use models::sample;
+    /// use models::subject;
+    /// use server::responses;
+    ///
+    /// let namespace = namespace::Identifier::new(
+    ///     "example-organization"
+    ///         .parse::<models::organization::Identifier>()
+    ///         .unwrap(),
+    ///     namespace::identifier::Name::try_new("ExampleNamespace").unwrap(),
+    /// );
+    ///
+    /// let sample = models::Sample::new(
+    ///     sample::Identifier::new(namespace.clone(), "Sample1"),
+    ///     subject::Identifier::new(namespace, "Subject1"),
+    ///     None,
+    ///     None,
+    /// );
+    ///
+    /// let response = responses::Sample::new(sample, Some(Vec::new()));
+    /// assert_eq!(response.findings(), Some(&[][..]));
+    /// ```
+    pub fn new(inner: models::Sample, findings: Option<Vec<Finding>>) -> Self {
+        Self { inner, findings }
+    }
+
+    /// Gets the findings from validating the sample (if validation was
+    /// requested).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ccdi_models as models;
+    /// use ccdi_server as server;
+    ///
+    /// use models::namespace;
+    /// use models::sample;
+    /// use models::subject;
+    /// use server::responses;
+    ///
+    /// let namespace = namespace::Identifier::new(
+    ///     "example-organization"
+    ///         .parse::<models::organization::Identifier>()
+    ///         .unwrap(),
+    ///     namespace::identifier::Name::try_new("ExampleNamespace").unwrap(),
+    /// );
+    ///
+    /// let sample = models::Sample::new(
+    ///     sample::Identifier::new(namespace.clone(), "Sample1"),
+    ///     subject::Identifier::new(namespace, "Subject1"),
+    ///     None,
+    ///     None,
+    /// );
+    ///
+    /// let response = responses::Sample::new(sample, None);
+    /// assert!(response.findings().is_none());
+    /// ```
+    pub fn findings(&self) -> Option<&[Finding]> {
+        self.findings.as_deref()
+    }
 }
 
 /// A response representing multiple samples known about by the server.
@@ -45,6 +134,20 @@ pub struct Samples {
     #[schema(value_type = responses::entity::Summary)]
     summary: Summary,
 
+    /// The links to the other pages of this paged result set (identical to
+    /// the links within the `link` header). These are omitted when the result
+    /// set fits within a single page.
+    #[schema(value_type = responses::entity::Links, nullable = false)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    links: Option<Links>,
+
+    /// The descriptions of the query parameters that were not recognized and,
+    /// as such, were ignored (only present when the server does not reject
+    /// unrecognized query parameters).
+    #[schema(nullable = false)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    warnings: Option<Vec<String>>,
+
     /// The samples.
     #[schema(nullable = false)]
     data: Vec<models::Sample>,
@@ -53,6 +156,182 @@ pub struct Samples {
     #[schema(nullable = false)]
     #[serde(skip_serializing_if = "Option::is_none")]
     gateways: Option<Vec<models::gateway::Named>>,
+
+    /// The entities referenced by the samples within this page (only present
+    /// when the page was requested with the `expand` parameter).
+    #[schema(value_type = responses::sample::Embedded, nullable = false)]
+    #[serde(rename = "_embedded", skip_serializing_if = "Option::is_none")]
+    embedded: Option<Embedded>,
+}
+
+impl Samples {
+    /// Embeds the referenced entities within the [`Samples`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ccdi_server as server;
+    ///
+    /// use server::responses::sample::Embedded;
+    /// use server::responses::Samples;
+    ///
+    /// let samples = Samples::from((Vec::new(), 0)).with_embedded(Embedded::default());
+    /// assert!(samples.embedded().unwrap().subjects().is_empty());
+    /// ```
+    pub fn with_embedded(mut self, embedded: Embedded) -> Self {
+        self.embedded = Some(embedded);
+        self
+    }
+
+    /// Gets the entities embedded within the [`Samples`] (if they exist).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ccdi_server as server;
+    ///
+    /// use server::responses::Samples;
+    ///
+    /// let samples = Samples::from((Vec::new(), 0));
+    /// assert!(samples.embedded().is_none());
+    /// ```
+    pub fn embedded(&self) -> Option<&Embedded> {
+        self.embedded.as_ref()
+    }
+
+    /// Includes the [`Totals`] of the result set within the summary of the
+    /// [`Samples`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ccdi_server as server;
+    ///
+    /// use server::paginate::Totals;
+    /// use server::responses::Samples;
+    ///
+    /// let samples = Samples::from((Vec::new(), 0)).with_totals(Totals::new(10, 0));
+    /// assert_eq!(samples.summary().counts().total(), Some(10));
+    /// ```
+    pub fn with_totals(mut self, totals: Totals) -> Self {
+        self.summary = self.summary.with_totals(totals);
+        self
+    }
+
+    /// Gets the summary of the [`Samples`] by reference.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ccdi_server as server;
+    ///
+    /// use server::responses::Samples;
+    ///
+    /// let samples = Samples::from((Vec::new(), 0));
+    /// assert_eq!(samples.summary().counts().total(), None);
+    /// ```
+    pub fn summary(&self) -> &Summary {
+        &self.summary
+    }
+
+    /// Gets the links to the other pages of the result set within the
+    /// [`Samples`] (if they exist).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ccdi_server as server;
+    ///
+    /// use server::paginate::Linked as _;
+    /// use server::responses::entity::Links;
+    /// use server::responses::Samples;
+    ///
+    /// let samples = Samples::from((Vec::new(), 0));
+    /// assert!(samples.links().is_none());
+    ///
+    /// let samples = samples.with_links(Links::default());
+    /// assert!(samples.links().is_some());
+    /// ```
+    pub fn links(&self) -> Option<&Links> {
+        self.links.as_ref()
+    }
+
+    /// Includes the descriptions of the ignored query parameters within the
+    /// [`Samples`]. The warnings are omitted when none are provided.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ccdi_server as server;
+    ///
+    /// use server::responses::Samples;
+    ///
+    /// let samples = Samples::from((Vec::new(), 0)).with_warnings(Vec::new());
+    /// assert!(samples.warnings().is_none());
+    ///
+    /// let samples = samples.with_warnings(vec![String::from("Ignored.")]);
+    /// assert_eq!(samples.warnings().unwrap().len(), 1);
+    /// ```
+    pub fn with_warnings(mut self, warnings: Vec<String>) -> Self {
+        self.warnings = (!warnings.is_empty()).then_some(warnings);
+        self
+    }
+
+    /// Gets the descriptions of the ignored query parameters within the
+    /// [`Samples`] (if they exist).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ccdi_server as server;
+    ///
+    /// use server::responses::Samples;
+    ///
+    /// let samples = Samples::from((Vec::new(), 0));
+    /// assert!(samples.warnings().is_none());
+    /// ```
+    pub fn warnings(&self) -> Option<&[String]> {
+        self.warnings.as_deref()
+    }
+
+    /// Gets the samples within the [`Samples`] by reference.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ccdi_server as server;
+    ///
+    /// use server::responses::Samples;
+    ///
+    /// let samples = Samples::from((Vec::new(), 0));
+    /// assert!(samples.data().is_empty());
+    /// ```
+    pub fn data(&self) -> &[models::Sample] {
+        &self.data
+    }
+
+    /// Consumes the [`Samples`] and returns the samples within it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ccdi_server as server;
+    ///
+    /// use server::responses::Samples;
+    ///
+    /// let samples = Samples::from((Vec::new(), 0));
+    /// assert!(samples.into_data().is_empty());
+    /// ```
+    pub fn into_data(self) -> Vec<models::Sample> {
+        self.data
+    }
+}
+
+impl Linked for Samples {
+    fn with_links(mut self, links: Links) -> Self {
+        self.links = Some(links);
+        self
+    }
 }
 
 impl From<(Vec<models::Sample>, usize)> for Samples {
@@ -66,11 +345,15 @@ impl From<(Vec<models::Sample>, usize)> for Samples {
             .map(|name| {
                 gateway::Named::new(
                     name,
-                    Gateway::Open {
-                        link: Link::Direct {
-                            url: "https://example.com".parse::<Url>().unwrap(),
-                        },
-                    },
+                    Gateway::Open(
+                        Open::try_new(
+                            Link::Direct {
+                                url: "https://example.com".parse::<Url>().unwrap(),
+                            },
+                            None,
+                        )
+                        .unwrap(),
+                    ),
                 )
             })
             .collect::<Vec<_>>();
@@ -79,11 +362,68 @@ impl From<(Vec<models::Sample>, usize)> for Samples {
 
         Self {
             summary: Summary::new(counts),
+            links: None,
+            warnings: None,
             data: samples,
             gateways: match gateways.is_empty() {
                 true => None,
                 false => Some(gateways),
             },
+            embedded: None,
         }
     }
 }
+
+/// The entities referenced by a page of samples and embedded within the
+/// response.
+///
+/// Each referenced entity is embedded exactly once (regardless of how many
+/// samples within the page refer to it), and each sample refers to its
+/// embedded entities through its existing identifiers (e.g., the `subject`
+/// identifier of a sample refers to the subject with the matching key within
+/// `subjects`).
+#[derive(Debug, Default, Deserialize, Serialize, ToSchema)]
+#[schema(as = responses::sample::Embedded)]
+pub struct Embedded {
+    /// The unique subjects referenced by the samples, keyed by
+    /// `<organization>/<namespace>/<name>`.
+    #[serde(default)]
+    subjects: BTreeMap<String, models::Subject>,
+}
+
+impl Embedded {
+    /// Creates a new [`Embedded`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::collections::BTreeMap;
+    ///
+    /// use ccdi_server as server;
+    ///
+    /// use server::responses::sample::Embedded;
+    ///
+    /// let embedded = Embedded::new(BTreeMap::new());
+    /// assert!(embedded.subjects().is_empty());
+    /// ```
+    pub fn new(subjects: BTreeMap<String, models::Subject>) -> Self {
+        Self { subjects }
+    }
+
+    /// Gets the embedded subjects (keyed by
+    /// `<organization>/<namespace>/<name>`).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ccdi_server as server;
+    ///
+    /// use server::responses::sample::Embedded;
+    ///
+    /// let embedded = Embedded::default();
+    /// assert!(embedded.subjects().is_empty());
+    /// ```
+    pub fn subjects(&self) -> &BTreeMap<String, models::Subject> {
+        &self.subjects
+    }
+}
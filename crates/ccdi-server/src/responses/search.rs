@@ -0,0 +1,299 @@
+//! Responses related to searching for entities by a partial identifier.
+
+use serde::Deserialize;
+use serde::Serialize;
+use utoipa::ToSchema;
+
+use ccdi_models as models;
+
+use crate::paginate::Linked;
+use crate::responses::entity;
+
+/// A kind of entity matched by a search.
+#[derive(Clone, Copy, Debug, Deserialize, Eq, PartialEq, Serialize, ToSchema)]
+#[serde(rename_all = "lowercase")]
+#[schema(as = responses::search::Kind)]
+pub enum Kind {
+    /// A subject.
+    Subject,
+
+    /// A sample.
+    Sample,
+
+    /// A file.
+    File,
+}
+
+/// The identifier of an entity on which a search matched.
+#[derive(Clone, Copy, Debug, Deserialize, Eq, PartialEq, Serialize, ToSchema)]
+#[serde(rename_all = "lowercase")]
+#[schema(as = responses::search::MatchedOn)]
+pub enum MatchedOn {
+    /// The primary identifier of the entity.
+    Primary,
+
+    /// One of the alternate identifiers within the metadata of the entity.
+    Alternate,
+}
+
+/// A single entity matched by a search.
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize, ToSchema)]
+#[schema(as = responses::search::Entry)]
+pub struct Entry {
+    /// The kind of the entity.
+    #[schema(value_type = responses::search::Kind)]
+    kind: Kind,
+
+    /// The namespace to which the entity belongs.
+    #[schema(value_type = models::namespace::Identifier)]
+    namespace: models::namespace::Identifier,
+
+    /// The name of the entity (from its primary identifier).
+    name: String,
+
+    /// Whether the search matched the primary identifier of the entity or one
+    /// of its alternate identifiers.
+    #[schema(value_type = responses::search::MatchedOn)]
+    matched_on: MatchedOn,
+}
+
+impl Entry {
+    /// Creates a new [`Entry`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ccdi_server as server;
+    ///
+    /// use server::responses::search::Entry;
+    /// use server::responses::search::Kind;
+    /// use server::responses::search::MatchedOn;
+    /// use server::routes::namespace::NAMESPACES;
+    ///
+    /// let namespace = NAMESPACES.values().next().unwrap().id().clone();
+    /// let entry = Entry::new(Kind::Sample, namespace, "Sample1", MatchedOn::Primary);
+    ///
+    /// assert_eq!(entry.kind(), Kind::Sample);
+    /// assert_eq!(entry.namespace().name().as_str(), "ExampleNamespaceOne");
+    /// assert_eq!(entry.name(), "Sample1");
+    /// assert_eq!(entry.matched_on(), MatchedOn::Primary);
+    /// ```
+    pub fn new(
+        kind: Kind,
+        namespace: models::namespace::Identifier,
+        name: impl Into<String>,
+        matched_on: MatchedOn,
+    ) -> Self {
+        Self {
+            kind,
+            namespace,
+            name: name.into(),
+            matched_on,
+        }
+    }
+
+    /// Gets the kind of the entity within the [`Entry`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ccdi_server as server;
+    ///
+    /// use server::responses::search::Entry;
+    /// use server::responses::search::Kind;
+    /// use server::responses::search::MatchedOn;
+    /// use server::routes::namespace::NAMESPACES;
+    ///
+    /// let namespace = NAMESPACES.values().next().unwrap().id().clone();
+    /// let entry = Entry::new(Kind::File, namespace, "File1.txt", MatchedOn::Primary);
+    ///
+    /// assert_eq!(entry.kind(), Kind::File);
+    /// ```
+    pub fn kind(&self) -> Kind {
+        self.kind
+    }
+
+    /// Gets the namespace of the entity within the [`Entry`] by reference.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ccdi_server as server;
+    ///
+    /// use server::responses::search::Entry;
+    /// use server::responses::search::Kind;
+    /// use server::responses::search::MatchedOn;
+    /// use server::routes::namespace::NAMESPACES;
+    ///
+    /// let namespace = NAMESPACES.values().next().unwrap().id().clone();
+    /// let entry = Entry::new(
+    ///     Kind::File,
+    ///     namespace.clone(),
+    ///     "File1.txt",
+    ///     MatchedOn::Primary,
+    /// );
+    ///
+    /// assert_eq!(entry.namespace(), &namespace);
+    /// ```
+    pub fn namespace(&self) -> &models::namespace::Identifier {
+        &self.namespace
+    }
+
+    /// Gets the name of the entity within the [`Entry`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ccdi_server as server;
+    ///
+    /// use server::responses::search::Entry;
+    /// use server::responses::search::Kind;
+    /// use server::responses::search::MatchedOn;
+    /// use server::routes::namespace::NAMESPACES;
+    ///
+    /// let namespace = NAMESPACES.values().next().unwrap().id().clone();
+    /// let entry = Entry::new(Kind::File, namespace, "File1.txt", MatchedOn::Primary);
+    ///
+    /// assert_eq!(entry.name(), "File1.txt");
+    /// ```
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Gets the identifier on which the search matched the entity within the
+    /// [`Entry`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ccdi_server as server;
+    ///
+    /// use server::responses::search::Entry;
+    /// use server::responses::search::Kind;
+    /// use server::responses::search::MatchedOn;
+    /// use server::routes::namespace::NAMESPACES;
+    ///
+    /// let namespace = NAMESPACES.values().next().unwrap().id().clone();
+    /// let entry = Entry::new(Kind::Subject, namespace, "Subject1", MatchedOn::Alternate);
+    ///
+    /// assert_eq!(entry.matched_on(), MatchedOn::Alternate);
+    /// ```
+    pub fn matched_on(&self) -> MatchedOn {
+        self.matched_on
+    }
+}
+
+/// A response representing the entities matched by a search.
+///
+/// Subjects are listed before samples, and samples are listed before files.
+/// Within each kind, entities are ordered by their primary identifier.
+#[derive(Debug, Deserialize, Serialize, ToSchema)]
+#[schema(as = responses::Search)]
+pub struct Search {
+    /// A summary of this paged result set.
+    #[schema(value_type = responses::entity::Summary)]
+    summary: entity::Summary,
+
+    /// The links to the other pages of this paged result set (identical to
+    /// the links within the `link` header). These are omitted when the result
+    /// set fits within a single page.
+    #[schema(value_type = responses::entity::Links, nullable = false)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    links: Option<entity::Links>,
+
+    /// Whether more entities matched the search than are listed within the
+    /// result set (the number of matches is capped by the server).
+    truncated: bool,
+
+    /// The matched entities.
+    #[schema(nullable = false, value_type = Vec<responses::search::Entry>)]
+    data: Vec<Entry>,
+}
+
+impl Search {
+    /// Marks whether the matches within the [`Search`] were truncated.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ccdi_server as server;
+    ///
+    /// use server::responses::Search;
+    ///
+    /// let search = Search::from((Vec::new(), 0));
+    /// assert!(!search.truncated());
+    ///
+    /// let search = search.with_truncated(true);
+    /// assert!(search.truncated());
+    /// ```
+    pub fn with_truncated(mut self, truncated: bool) -> Self {
+        self.truncated = truncated;
+        self
+    }
+
+    /// Gets the summary of the [`Search`] by reference.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ccdi_server as server;
+    ///
+    /// use server::responses::Search;
+    ///
+    /// let search = Search::from((Vec::new(), 0));
+    /// assert_eq!(search.summary().counts().total(), None);
+    /// ```
+    pub fn summary(&self) -> &entity::Summary {
+        &self.summary
+    }
+
+    /// Gets whether the matches within the [`Search`] were truncated.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ccdi_server as server;
+    ///
+    /// use server::responses::Search;
+    ///
+    /// let search = Search::from((Vec::new(), 0));
+    /// assert!(!search.truncated());
+    /// ```
+    pub fn truncated(&self) -> bool {
+        self.truncated
+    }
+
+    /// Gets the matched entities within the [`Search`] by reference.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ccdi_server as server;
+    ///
+    /// use server::responses::Search;
+    ///
+    /// let search = Search::from((Vec::new(), 0));
+    /// assert!(search.data().is_empty());
+    /// ```
+    pub fn data(&self) -> &[Entry] {
+        &self.data
+    }
+}
+
+impl Linked for Search {
+    fn with_links(mut self, links: entity::Links) -> Self {
+        self.links = Some(links);
+        self
+    }
+}
+
+impl From<(Vec<Entry>, usize)> for Search {
+    fn from((entries, total): (Vec<Entry>, usize)) -> Self {
+        Self {
+            summary: entity::Summary::new(entity::Counts::new(entries.len(), total)),
+            links: None,
+            truncated: false,
+            data: entries,
+        }
+    }
+}
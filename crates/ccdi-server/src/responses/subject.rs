@@ -1,17 +1,171 @@
+//! Responses related to subjects.
+
+use std::collections::BTreeMap;
+
 use itertools::Itertools;
 use models::gateway;
 use models::gateway::Link;
+use models::gateway::Open;
 use models::Gateway;
 use models::Url;
 use serde::Deserialize;
 use serde::Serialize;
 use utoipa::ToSchema;
 
+use ccdi_cde as cde;
 use ccdi_models as models;
 
+use crate::paginate::Linked;
+use crate::paginate::Totals;
 use crate::responses::entity::Counts;
+use crate::responses::entity::Links;
 use crate::responses::entity::Summary;
 
+/// The number of days between the earliest diagnosis of a subject and its
+/// vital status.
+///
+/// **This value is derived by the server and is not a common data element.** It
+/// is computed as the `age_at_vital_status` of the subject minus the earliest
+/// `age_at_diagnosis` across the samples derived from the subject.
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize, ToSchema)]
+#[schema(as = responses::subject::SurvivalDays)]
+pub struct SurvivalDays {
+    /// The number of days between the earliest diagnosis and the vital
+    /// status of the subject.
+    value: f32,
+
+    /// The vital status of the subject (if it is known).
+    ///
+    /// When the subject is alive, `value` is the number of days that the
+    /// subject is known to have survived rather than the survival time.
+    #[schema(value_type = cde::v1::subject::VitalStatus, nullable = true)]
+    vital_status: Option<cde::v1::subject::VitalStatus>,
+
+    /// The values from which `value` was derived.
+    #[schema(value_type = responses::subject::Provenance)]
+    provenance: Provenance,
+}
+
+/// The values from which the [`SurvivalDays`] of a subject were derived.
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize, ToSchema)]
+#[schema(as = responses::subject::Provenance)]
+pub struct Provenance {
+    /// The `age_at_vital_status` of the subject (in days).
+    age_at_vital_status: f32,
+
+    /// The earliest `age_at_diagnosis` across the samples derived from the
+    /// subject (in days).
+    age_at_diagnosis: f32,
+
+    /// The samples that supplied the earliest `age_at_diagnosis` (every sample
+    /// is listed when more than one sample shares the earliest age).
+    #[schema(value_type = Vec<models::sample::Identifier>)]
+    samples: Vec<models::sample::Identifier>,
+}
+
+impl SurvivalDays {
+    /// Derives the [`SurvivalDays`] of a subject from the samples derived
+    /// from it.
+    ///
+    /// Samples that were not derived from the subject are ignored. [`None`] is
+    /// returned when the subject has no `age_at_vital_status` or when none of
+    /// its samples have an `age_at_diagnosis`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ccdi_models as models;
+    /// use ccdi_server as server;
+    ///
+    /// use models::namespace;
+    /// use models::sample;
+    /// use models::subject;
+    /// use server::responses::subject::SurvivalDays;
+    ///
+    /// let namespace = namespace::Identifier::new(
+    ///     "example-organization"
+    ///         .parse::<models::organization::Identifier>()
+    ///         .unwrap(),
+    ///     namespace::identifier::Name::try_new("ExampleNamespace").unwrap(),
+    /// );
+    ///
+    /// let subject = models::Subject::new(
+    ///     subject::Identifier::new(namespace.clone(), "Subject1"),
+    ///     subject::Kind::Participant,
+    ///     None,
+    ///     None,
+    /// );
+    ///
+    /// let sample = models::Sample::new(
+    ///     sample::Identifier::new(namespace, "Sample1"),
+    ///     subject.id().clone(),
+    ///     None,
+    ///     None,
+    /// );
+    ///
+    /// // The subject has no `age_at_vital_status`.
+    /// assert!(SurvivalDays::derive(&subject, &[sample]).is_none());
+    /// ```
+    pub fn derive<'a, I>(subject: &models::Subject, samples: I) -> Option<Self>
+    where
+        I: IntoIterator<Item = &'a models::Sample>,
+    {
+        let metadata = subject.metadata()?;
+        let age_at_vital_status = metadata.age_at_vital_status()?.value().as_days();
+
+        let diagnoses = samples
+            .into_iter()
+            .filter(|sample| sample.subject() == subject.id())
+            .filter_map(|sample| {
+                sample
+                    .metadata()
+                    .and_then(|metadata| metadata.age_at_diagnosis())
+                    .map(|age| (sample.id(), age.value().as_days()))
+            })
+            .collect::<Vec<_>>();
+
+        let age_at_diagnosis = diagnoses
+            .iter()
+            .map(|(_, age)| *age)
+            .min_by(|a, b| a.total_cmp(b))?;
+
+        let mut samples = diagnoses
+            .into_iter()
+            .filter(|(_, age)| *age == age_at_diagnosis)
+            .map(|(id, _)| id.clone())
+            .collect::<Vec<_>>();
+        samples.sort();
+
+        Some(Self {
+            value: age_at_vital_status - age_at_diagnosis,
+            vital_status: metadata
+                .vital_status()
+                .map(|vital_status| vital_status.value().clone()),
+            provenance: Provenance {
+                age_at_vital_status,
+                age_at_diagnosis,
+                samples,
+            },
+        })
+    }
+
+    /// Gets the number of days between the earliest diagnosis and the vital
+    /// status of the subject.
+    pub fn value(&self) -> f32 {
+        self.value
+    }
+
+    /// Gets the vital status of the subject (if it is known).
+    pub fn vital_status(&self) -> Option<&cde::v1::subject::VitalStatus> {
+        self.vital_status.as_ref()
+    }
+
+    /// Gets the samples that supplied the earliest `age_at_diagnosis`.
+    pub fn samples(&self) -> &[models::sample::Identifier] {
+        &self.provenance.samples
+    }
+}
+
 /// A response representing a single [`Subject`](models::Subject).
 #[derive(Debug, Deserialize, Serialize, ToSchema)]
 #[schema(as = responses::Subject)]
@@ -19,6 +173,143 @@ pub struct Subject {
     /// Subject.
     #[serde(flatten)]
     inner: models::Subject,
+
+    /// The number of days between the earliest diagnosis of the subject and
+    /// its vital status. **This field is derived by the server and is not a
+    /// common data element.**
+    ///
+    /// This field is `null` when the subject has no `age_at_vital_status` or
+    /// when none of the samples derived from the subject have an
+    /// `age_at_diagnosis`. It is omitted when `computed=false` is provided.
+    #[schema(value_type = responses::subject::SurvivalDays, nullable = true)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    survival_days: Option<Option<SurvivalDays>>,
+}
+
+impl Subject {
+    /// Creates a new [`Subject`] response (without any computed fields).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ccdi_models as models;
+    /// use ccdi_server as server;
+    ///
+    /// use models::namespace;
+    /// use models::subject;
+    /// use server::responses;
+    ///
+    /// let namespace = namespace::Identifier::new(
+    ///     "example-organization"
+    ///         .parse::<models::organization::Identifier>()
+    ///         .unwrap(),
+    ///     namespace::identifier::Name::try_new("ExampleNamespace").unwrap(),
+    /// );
+    ///
+    /// let subject = models::Subject::new(
+    ///     subject::Identifier::new(namespace, "Subject1"),
+    ///     subject::Kind::Participant,
+    ///     None,
+    ///     None,
+    /// );
+    ///
+    /// let response = responses::Subject::new(subject);
+    /// assert!(response.survival_days().is_none());
+    /// ```
+    pub fn new(inner: models::Subject) -> Self {
+        Self {
+            inner,
+            survival_days: None,
+        }
+    }
+
+    /// Includes the (possibly `null`) [`SurvivalDays`] within the [`Subject`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ccdi_models as models;
+    /// use ccdi_server as server;
+    ///
+    /// use models::namespace;
+    /// use models::subject;
+    /// use server::responses;
+    ///
+    /// let namespace = namespace::Identifier::new(
+    ///     "example-organization"
+    ///         .parse::<models::organization::Identifier>()
+    ///         .unwrap(),
+    ///     namespace::identifier::Name::try_new("ExampleNamespace").unwrap(),
+    /// );
+    ///
+    /// let subject = models::Subject::new(
+    ///     subject::Identifier::new(namespace, "Subject1"),
+    ///     subject::Kind::Participant,
+    ///     None,
+    ///     None,
+    /// );
+    ///
+    /// let response = responses::Subject::new(subject).with_survival_days(None);
+    /// let response = serde_json::to_value(response).unwrap();
+    ///
+    /// assert!(response["survival_days"].is_null());
+    /// ```
+    pub fn with_survival_days(mut self, survival_days: Option<SurvivalDays>) -> Self {
+        self.survival_days = Some(survival_days);
+        self
+    }
+
+    /// Gets the subject within the [`Subject`] by reference.
+    pub fn inner(&self) -> &models::Subject {
+        &self.inner
+    }
+
+    /// Gets the [`SurvivalDays`] of the subject (if they were computed and
+    /// could be derived).
+    pub fn survival_days(&self) -> Option<&SurvivalDays> {
+        self.survival_days.as_ref().and_then(Option::as_ref)
+    }
+}
+
+/// Groups samples by the subject from which they were derived.
+fn by_subject(
+    samples: &[models::Sample],
+) -> BTreeMap<&models::subject::Identifier, Vec<&models::Sample>> {
+    let mut groups = BTreeMap::<_, Vec<_>>::new();
+
+    for sample in samples {
+        groups.entry(sample.subject()).or_default().push(sample);
+    }
+
+    groups
+}
+
+/// Creates a [`Subject`] response for each subject that includes the
+/// [`SurvivalDays`] derived from the provided samples.
+///
+/// # Examples
+///
+/// ```
+/// use ccdi_server as server;
+///
+/// use server::responses::subject::with_survival_days;
+///
+/// assert!(with_survival_days(Vec::new(), &[]).is_empty());
+/// ```
+pub fn with_survival_days(
+    subjects: Vec<models::Subject>,
+    samples: &[models::Sample],
+) -> Vec<Subject> {
+    let samples = by_subject(samples);
+
+    subjects
+        .into_iter()
+        .map(|subject| {
+            let linked = samples.get(subject.id()).into_iter().flatten().copied();
+            let survival_days = SurvivalDays::derive(&subject, linked);
+            Subject::new(subject).with_survival_days(survival_days)
+        })
+        .collect()
 }
 
 /// A response representing multiple subjects known about by the server.
@@ -45,9 +336,23 @@ pub struct Subjects {
     #[schema(value_type = responses::entity::Summary)]
     summary: Summary,
 
-    /// The subjects.
+    /// The links to the other pages of this paged result set (identical to
+    /// the links within the `link` header). These are omitted when the result
+    /// set fits within a single page.
+    #[schema(value_type = responses::entity::Links, nullable = false)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    links: Option<Links>,
+
+    /// The descriptions of the query parameters that were not recognized and,
+    /// as such, were ignored (only present when the server does not reject
+    /// unrecognized query parameters).
     #[schema(nullable = false)]
-    data: Vec<models::Subject>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    warnings: Option<Vec<String>>,
+
+    /// The subjects.
+    #[schema(nullable = false, value_type = Vec<responses::Subject>)]
+    data: Vec<Subject>,
 
     // The gateways.
     #[schema(nullable = false)]
@@ -55,6 +360,164 @@ pub struct Subjects {
     gateways: Option<Vec<models::gateway::Named>>,
 }
 
+impl Subjects {
+    /// Includes the [`Totals`] of the result set within the summary of the
+    /// [`Subjects`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ccdi_server as server;
+    ///
+    /// use server::paginate::Totals;
+    /// use server::responses::Subjects;
+    ///
+    /// let subjects = Subjects::from((Vec::new(), 0)).with_totals(Totals::new(10, 0));
+    /// assert_eq!(subjects.summary().counts().total(), Some(10));
+    /// ```
+    pub fn with_totals(mut self, totals: Totals) -> Self {
+        self.summary = self.summary.with_totals(totals);
+        self
+    }
+
+    /// Gets the summary of the [`Subjects`] by reference.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ccdi_server as server;
+    ///
+    /// use server::responses::Subjects;
+    ///
+    /// let subjects = Subjects::from((Vec::new(), 0));
+    /// assert_eq!(subjects.summary().counts().total(), None);
+    /// ```
+    pub fn summary(&self) -> &Summary {
+        &self.summary
+    }
+
+    /// Gets the links to the other pages of the result set within the
+    /// [`Subjects`] (if they exist).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ccdi_server as server;
+    ///
+    /// use server::paginate::Linked as _;
+    /// use server::responses::entity::Links;
+    /// use server::responses::Subjects;
+    ///
+    /// let subjects = Subjects::from((Vec::new(), 0));
+    /// assert!(subjects.links().is_none());
+    ///
+    /// let subjects = subjects.with_links(Links::default());
+    /// assert!(subjects.links().is_some());
+    /// ```
+    pub fn links(&self) -> Option<&Links> {
+        self.links.as_ref()
+    }
+
+    /// Includes the descriptions of the ignored query parameters within the
+    /// [`Subjects`]. The warnings are omitted when none are provided.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ccdi_server as server;
+    ///
+    /// use server::responses::Subjects;
+    ///
+    /// let subjects = Subjects::from((Vec::new(), 0)).with_warnings(Vec::new());
+    /// assert!(subjects.warnings().is_none());
+    ///
+    /// let subjects = subjects.with_warnings(vec![String::from("Ignored.")]);
+    /// assert_eq!(subjects.warnings().unwrap().len(), 1);
+    /// ```
+    pub fn with_warnings(mut self, warnings: Vec<String>) -> Self {
+        self.warnings = (!warnings.is_empty()).then_some(warnings);
+        self
+    }
+
+    /// Gets the descriptions of the ignored query parameters within the
+    /// [`Subjects`] (if they exist).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ccdi_server as server;
+    ///
+    /// use server::responses::Subjects;
+    ///
+    /// let subjects = Subjects::from((Vec::new(), 0));
+    /// assert!(subjects.warnings().is_none());
+    /// ```
+    pub fn warnings(&self) -> Option<&[String]> {
+        self.warnings.as_deref()
+    }
+
+    /// Gets the subjects within the [`Subjects`] by reference.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ccdi_server as server;
+    ///
+    /// use server::responses::Subjects;
+    ///
+    /// let subjects = Subjects::from((Vec::new(), 0));
+    /// assert!(subjects.data().is_empty());
+    /// ```
+    pub fn data(&self) -> &[Subject] {
+        &self.data
+    }
+
+    /// Includes the [`SurvivalDays`] of each subject within the [`Subjects`]
+    /// (derived from the provided samples).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ccdi_server as server;
+    ///
+    /// use server::responses::Subjects;
+    ///
+    /// let subjects = Subjects::from((Vec::new(), 0)).with_survival_days(&[]);
+    /// assert!(subjects.data().is_empty());
+    /// ```
+    pub fn with_survival_days(mut self, samples: &[models::Sample]) -> Self {
+        let subjects = std::mem::take(&mut self.data)
+            .into_iter()
+            .map(|subject| subject.inner)
+            .collect();
+        self.data = with_survival_days(subjects, samples);
+        self
+    }
+
+    /// Consumes the [`Subjects`] and returns the subjects within it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ccdi_server as server;
+    ///
+    /// use server::responses::Subjects;
+    ///
+    /// let subjects = Subjects::from((Vec::new(), 0));
+    /// assert!(subjects.into_data().is_empty());
+    /// ```
+    pub fn into_data(self) -> Vec<models::Subject> {
+        self.data.into_iter().map(|subject| subject.inner).collect()
+    }
+}
+
+impl Linked for Subjects {
+    fn with_links(mut self, links: Links) -> Self {
+        self.links = Some(links);
+        self
+    }
+}
+
 impl From<(Vec<models::Subject>, usize)> for Subjects {
     fn from((subjects, total): (Vec<models::Subject>, usize)) -> Self {
         let gateways = subjects
@@ -66,11 +529,15 @@ impl From<(Vec<models::Subject>, usize)> for Subjects {
             .map(|name| {
                 gateway::Named::new(
                     name,
-                    Gateway::Open {
-                        link: Link::Direct {
-                            url: "https://example.com".parse::<Url>().unwrap(),
-                        },
-                    },
+                    Gateway::Open(
+                        Open::try_new(
+                            Link::Direct {
+                                url: "https://example.com".parse::<Url>().unwrap(),
+                            },
+                            None,
+                        )
+                        .unwrap(),
+                    ),
                 )
             })
             .collect::<Vec<_>>();
@@ -79,7 +546,9 @@ impl From<(Vec<models::Subject>, usize)> for Subjects {
 
         Self {
             summary: Summary::new(counts),
-            data: subjects,
+            links: None,
+            warnings: None,
+            data: subjects.into_iter().map(Subject::new).collect(),
             gateways: match gateways.is_empty() {
                 true => None,
                 false => Some(gateways),
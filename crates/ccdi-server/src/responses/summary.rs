@@ -8,7 +8,16 @@ use utoipa::ToSchema;
 #[derive(Debug, Deserialize, Serialize, ToSchema)]
 #[schema(as = responses::summary::Counts)]
 pub struct Counts {
+    /// The total number of entities.
     total: usize,
+
+    /// The number of entities that have no metadata (either the metadata
+    /// block is missing or it is effectively empty).
+    ///
+    /// This count is optional so that servers that do not (yet) report it
+    /// remain conformant.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    without_metadata: Option<usize>,
 }
 
 /// A summary response for an entity endpoint.
@@ -29,11 +38,50 @@ impl Summary {
     ///
     /// use server::responses::Summary;
     ///
-    /// let summary = Summary::new(1);
+    /// let summary = Summary::new(10, 1);
+    ///
+    /// assert_eq!(summary.total(), 10);
+    /// assert_eq!(summary.without_metadata(), Some(1));
     /// ```
-    pub fn new(total: usize) -> Self {
+    pub fn new(total: usize, without_metadata: usize) -> Self {
         Self {
-            counts: Counts { total },
+            counts: Counts {
+                total,
+                without_metadata: Some(without_metadata),
+            },
         }
     }
+
+    /// Gets the total number of entities in the [`Summary`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ccdi_server as server;
+    ///
+    /// use server::responses::Summary;
+    ///
+    /// let summary = Summary::new(10, 1);
+    /// assert_eq!(summary.total(), 10);
+    /// ```
+    pub fn total(&self) -> usize {
+        self.counts.total
+    }
+
+    /// Gets the number of entities that have no metadata in the [`Summary`]
+    /// (if it was reported).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ccdi_server as server;
+    ///
+    /// use server::responses::Summary;
+    ///
+    /// let summary = Summary::new(10, 1);
+    /// assert_eq!(summary.without_metadata(), Some(1));
+    /// ```
+    pub fn without_metadata(&self) -> Option<usize> {
+        self.counts.without_metadata
+    }
 }
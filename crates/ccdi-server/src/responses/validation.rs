@@ -0,0 +1,219 @@
+//! Responses related to validating entities.
+
+use serde::Deserialize;
+use serde::Serialize;
+use utoipa::ToSchema;
+
+use ccdi_models as models;
+
+/// A finding from checking the consistency of a sample's metadata against the
+/// files derived from it.
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize, ToSchema)]
+#[schema(as = responses::validation::Finding)]
+pub struct Finding {
+    /// The sample to which the finding relates.
+    #[schema(value_type = models::sample::Identifier)]
+    sample: models::sample::Identifier,
+
+    /// The name of the rule that produced the finding.
+    rule: String,
+
+    /// A description of the finding.
+    message: String,
+}
+
+impl Finding {
+    /// Creates a new [`Finding`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ccdi_models as models;
+    /// use ccdi_server as server;
+    ///
+    /// use models::namespace;
+    /// use models::sample;
+    /// use server::responses::validation::Finding;
+    ///
+    /// let namespace = namespace::Identifier::new(
+    ///     "example-organization"
+    ///         .parse::<models::organization::Identifier>()
+    ///         .unwrap(),
+    ///     namespace::identifier::Name::try_new("ExampleNamespace").unwrap(),
+    /// );
+    ///
+    /// let finding = Finding::new(
+    ///     sample::Identifier::new(namespace, "Sample1"),
+    ///     "imaging-only-sequencing-strategy",
+    ///     "the library strategy `WGS` is a sequencing strategy",
+    /// );
+    ///
+    /// assert_eq!(finding.sample().name(), "Sample1");
+    /// assert_eq!(finding.rule(), "imaging-only-sequencing-strategy");
+    /// ```
+    pub fn new(
+        sample: models::sample::Identifier,
+        rule: impl Into<String>,
+        message: impl Into<String>,
+    ) -> Self {
+        Self {
+            sample,
+            rule: rule.into(),
+            message: message.into(),
+        }
+    }
+
+    /// Gets the sample to which the [`Finding`] relates.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ccdi_models as models;
+    /// use ccdi_server as server;
+    ///
+    /// use models::namespace;
+    /// use models::sample;
+    /// use server::responses::validation::Finding;
+    ///
+    /// let namespace = namespace::Identifier::new(
+    ///     "example-organization"
+    ///         .parse::<models::organization::Identifier>()
+    ///         .unwrap(),
+    ///     namespace::identifier::Name::try_new("ExampleNamespace").unwrap(),
+    /// );
+    ///
+    /// let finding = Finding::new(
+    ///     sample::Identifier::new(namespace, "Sample1"),
+    ///     "rule",
+    ///     "message",
+    /// );
+    /// assert_eq!(finding.sample().name(), "Sample1");
+    /// ```
+    pub fn sample(&self) -> &models::sample::Identifier {
+        &self.sample
+    }
+
+    /// Gets the name of the rule that produced the [`Finding`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ccdi_models as models;
+    /// use ccdi_server as server;
+    ///
+    /// use models::namespace;
+    /// use models::sample;
+    /// use server::responses::validation::Finding;
+    ///
+    /// let namespace = namespace::Identifier::new(
+    ///     "example-organization"
+    ///         .parse::<models::organization::Identifier>()
+    ///         .unwrap(),
+    ///     namespace::identifier::Name::try_new("ExampleNamespace").unwrap(),
+    /// );
+    ///
+    /// let finding = Finding::new(
+    ///     sample::Identifier::new(namespace, "Sample1"),
+    ///     "rule",
+    ///     "message",
+    /// );
+    /// assert_eq!(finding.rule(), "rule");
+    /// ```
+    pub fn rule(&self) -> &str {
+        self.rule.as_str()
+    }
+
+    /// Gets the description of the [`Finding`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ccdi_models as models;
+    /// use ccdi_server as server;
+    ///
+    /// use models::namespace;
+    /// use models::sample;
+    /// use server::responses::validation::Finding;
+    ///
+    /// let namespace = namespace::Identifier::new(
+    ///     "example-organization"
+    ///         .parse::<models::organization::Identifier>()
+    ///         .unwrap(),
+    ///     namespace::identifier::Name::try_new("ExampleNamespace").unwrap(),
+    /// );
+    ///
+    /// let finding = Finding::new(
+    ///     sample::Identifier::new(namespace, "Sample1"),
+    ///     "rule",
+    ///     "message",
+    /// );
+    /// assert_eq!(finding.message(), "message");
+    /// ```
+    pub fn message(&self) -> &str {
+        self.message.as_str()
+    }
+}
+
+/// A report of the findings from validating samples.
+#[derive(Debug, Deserialize, Serialize, ToSchema)]
+#[schema(as = responses::validation::Report)]
+pub struct Report {
+    /// The number of samples that were validated.
+    total: usize,
+
+    /// The findings (in the order of the samples to which they relate).
+    #[schema(value_type = Vec<responses::validation::Finding>)]
+    findings: Vec<Finding>,
+}
+
+impl Report {
+    /// Creates a new [`Report`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ccdi_server as server;
+    ///
+    /// use server::responses::validation::Report;
+    ///
+    /// let report = Report::new(10, Vec::new());
+    ///
+    /// assert_eq!(report.total(), 10);
+    /// assert!(report.findings().is_empty());
+    /// ```
+    pub fn new(total: usize, findings: Vec<Finding>) -> Self {
+        Self { total, findings }
+    }
+
+    /// Gets the number of samples that were validated.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ccdi_server as server;
+    ///
+    /// use server::responses::validation::Report;
+    ///
+    /// let report = Report::new(10, Vec::new());
+    /// assert_eq!(report.total(), 10);
+    /// ```
+    pub fn total(&self) -> usize {
+        self.total
+    }
+
+    /// Gets the findings within the [`Report`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ccdi_server as server;
+    ///
+    /// use server::responses::validation::Report;
+    ///
+    /// let report = Report::new(10, Vec::new());
+    /// assert!(report.findings().is_empty());
+    /// ```
+    pub fn findings(&self) -> &[Finding] {
+        &self.findings
+    }
+}
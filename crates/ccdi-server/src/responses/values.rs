@@ -0,0 +1,67 @@
+//! Responses for listing the distinct values present for a field.
+
+use serde::Deserialize;
+use serde::Serialize;
+use utoipa::ToSchema;
+
+use crate::responses::by::count::ValueCount;
+
+/// A response listing the distinct, non-null values that are present for a
+/// metadata field along with the number of entities on which each value is
+/// present.
+#[derive(Debug, Deserialize, Serialize, ToSchema)]
+#[schema(as = responses::values::Response)]
+pub struct Response {
+    /// The field for which the values are listed.
+    pub field: String,
+
+    /// The distinct values that are present for the field (sorted in
+    /// descending order by the number of entities on which they are present).
+    #[schema(value_type = Vec<responses::by::count::ValueCount>)]
+    pub values: Vec<ValueCount>,
+
+    /// Whether the values were truncated because the field has more distinct
+    /// values than the limit configured for this server.
+    pub truncated: bool,
+}
+
+impl Response {
+    /// Creates a new [`Response`] from the distinct values of a field, keeping
+    /// at most `limit` values.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ccdi_server as server;
+    ///
+    /// use server::responses::by::count::ValueCount;
+    /// use server::responses::values::Response;
+    ///
+    /// let values = vec![
+    ///     ValueCount {
+    ///         value: "WGS".into(),
+    ///         count: 3,
+    ///     },
+    ///     ValueCount {
+    ///         value: "RNA-Seq".into(),
+    ///         count: 1,
+    ///     },
+    /// ];
+    ///
+    /// let response = Response::new("library_strategy", values, 1);
+    ///
+    /// assert_eq!(response.field, "library_strategy");
+    /// assert_eq!(response.values.len(), 1);
+    /// assert!(response.truncated);
+    /// ```
+    pub fn new(field: impl Into<String>, mut values: Vec<ValueCount>, limit: usize) -> Self {
+        let truncated = values.len() > limit;
+        values.truncate(limit);
+
+        Self {
+            field: field.into(),
+            values,
+            truncated,
+        }
+    }
+}
@@ -1,12 +1,17 @@
 //! Routing.
 
+use std::collections::BTreeMap;
+
+pub mod aggregate;
 pub mod file;
 pub mod info;
 pub mod metadata;
+pub mod metrics;
 pub mod namespace;
 pub mod organization;
 pub mod sample;
 pub mod sample_diagnosis;
+pub mod search;
 pub mod subject;
 pub mod subject_diagnosis;
 
@@ -19,3 +24,40 @@ pub enum GroupByResults<T> {
     /// The key specified to group by is _not_ supported.
     Unsupported,
 }
+
+/// An identifier that is repeated within a list of identifiers.
+#[derive(Debug, Eq, PartialEq)]
+pub(crate) struct Duplicate<'a, T> {
+    /// The repeated identifier.
+    pub identifier: &'a T,
+
+    /// The index of the first occurrence of the identifier.
+    pub first: usize,
+
+    /// The index of the repeated occurrence of the identifier.
+    pub index: usize,
+}
+
+/// Gets every repeated occurrence of an identifier within `identifiers` (in the
+/// order in which they appear).
+pub(crate) fn duplicates<'a, T: Ord + 'a>(
+    identifiers: impl IntoIterator<Item = &'a T>,
+) -> Vec<Duplicate<'a, T>> {
+    let mut seen = BTreeMap::new();
+    let mut duplicates = Vec::new();
+
+    for (index, identifier) in identifiers.into_iter().enumerate() {
+        match seen.get(identifier) {
+            Some(first) => duplicates.push(Duplicate {
+                identifier,
+                first: *first,
+                index,
+            }),
+            None => {
+                seen.insert(identifier, index);
+            }
+        }
+    }
+
+    duplicates
+}
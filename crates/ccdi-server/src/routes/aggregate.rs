@@ -0,0 +1,272 @@
+//! Routes related to counting across multiple entities at once.
+
+use actix_web::get;
+use actix_web::web::Data;
+use actix_web::web::Query;
+use actix_web::web::ServiceConfig;
+use actix_web::HttpRequest;
+use actix_web::HttpResponse;
+use actix_web::Responder;
+
+use crate::filter::parameter_names;
+use crate::params::AggregateParams;
+use crate::responses::aggregate::Counts;
+use crate::responses::aggregate::Results;
+use crate::responses::error;
+use crate::responses::Errors;
+use crate::routes::file;
+use crate::routes::sample;
+use crate::routes::subject;
+use crate::routes::GroupByResults;
+use crate::strict;
+
+/// The entities that may be counted along with the fields by which each may be
+/// grouped.
+const ENTITIES: &[(&str, &[&str])] = &[
+    ("subject", subject::GROUPABLE_FIELDS),
+    ("sample", sample::GROUPABLE_FIELDS),
+    ("file", file::GROUPABLE_FIELDS),
+];
+
+/// Configures the [`ServiceConfig`] with the aggregate paths.
+pub fn configure(
+    subjects: Data<subject::Store>,
+    samples: Data<sample::Store>,
+    files: Data<file::Store>,
+) -> impl FnOnce(&mut ServiceConfig) {
+    |config: &mut ServiceConfig| {
+        config
+            .app_data(subjects)
+            .app_data(samples)
+            .app_data(files)
+            .service(aggregate_counts);
+    }
+}
+
+/// Gets every supported entity and field pair joined by a period.
+fn valid_pairs() -> Vec<String> {
+    ENTITIES
+        .iter()
+        .flat_map(|(entity, fields)| {
+            fields
+                .iter()
+                .map(move |field| format!("{}.{}", entity, field))
+        })
+        .collect()
+}
+
+/// Inserts the results of grouping by a field into a set of [`Counts`].
+fn insert<T: Into<Results>>(counts: &mut Counts, pair: &str, results: GroupByResults<T>) {
+    match results {
+        GroupByResults::Supported(results) => counts.insert(pair.to_string(), results),
+        // SAFETY: only the groupable fields for each entity are ever requested,
+        // and those are always supported.
+        GroupByResults::Unsupported => unreachable!("groupable field was not supported: {pair}"),
+    }
+}
+
+/// Groups multiple entities by the specified metadata fields and returns the
+/// counts for each in a single response.
+///
+/// This is equivalent to calling the `by/{field}/count` endpoint for each of
+/// the requested entities and fields. The `include` parameter is a
+/// comma-delimited list of the entity and field pairs to count, such as
+/// `subject.sex,sample.library_strategy,file.type`. The results for each pair
+/// are keyed by that pair within the response.
+///
+/// If any of the requested pairs are not supported, no counts are returned.
+/// Instead, the unsupported pairs are listed alongside every supported pair
+/// within the error.
+#[utoipa::path(
+    get,
+    path = "/aggregate/counts",
+    params(AggregateParams),
+    tag = "Aggregate",
+    responses(
+        (status = 200, description = "Successful operation.", body = responses::aggregate::Counts),
+        (
+            status = 422,
+            description = "Invalid query parameters.",
+            body = responses::Errors,
+            example = json!(Errors::from(error::Kind::invalid_parameters(
+                Some(vec![String::from("include")]),
+                String::from(
+                    "unsupported entity and field pairs: subject.handedness. Supported \
+                     pairs are: subject.sex, subject.race, ..."
+                )
+            )))
+        ),
+    )
+)]
+#[get("/aggregate/counts")]
+pub async fn aggregate_counts(
+    params: Query<AggregateParams>,
+    request: HttpRequest,
+    subjects: Data<subject::Store>,
+    samples: Data<sample::Store>,
+    files: Data<file::Store>,
+    strict_config: Option<Data<strict::Config>>,
+) -> impl Responder {
+    if let Err(err) = strict::check(
+        request.query_string(),
+        &parameter_names::<AggregateParams>(),
+        strict_config.as_ref().map(|config| config.get_ref()),
+    ) {
+        return HttpResponse::UnprocessableEntity().json(Errors::from(err));
+    }
+
+    let valid = valid_pairs();
+
+    let invalid = params
+        .include()
+        .filter(|pair| !valid.iter().any(|valid| valid == pair))
+        .collect::<Vec<_>>();
+
+    if !invalid.is_empty() {
+        return HttpResponse::UnprocessableEntity().json(Errors::from(
+            error::Kind::invalid_parameters(
+                Some(vec![String::from("include")]),
+                format!(
+                    "unsupported entity and field pairs: {}. Supported pairs are: {}",
+                    invalid.join(", "),
+                    valid.join(", ")
+                ),
+            ),
+        ));
+    }
+
+    let mut counts = Counts::default();
+
+    for pair in params.include() {
+        // SAFETY: every pair was checked against the list of valid pairs above,
+        // and each valid pair contains a period.
+        let (entity, field) = pair.split_once('.').unwrap();
+
+        match entity {
+            "subject" => {
+                let subjects = subjects.subjects.lock().unwrap().clone();
+                insert(&mut counts, pair, subject::group_by(subjects, field));
+            }
+            "sample" => {
+                let samples = samples.samples.lock().unwrap().clone();
+                insert(&mut counts, pair, sample::group_by(samples, field));
+            }
+            "file" => {
+                let files = files.files.lock().unwrap().clone();
+                insert(&mut counts, pair, file::group_by(files, field));
+            }
+            _ => unreachable!("unhandled aggregate entity: {entity}"),
+        }
+    }
+
+    HttpResponse::Ok().json(counts)
+}
+
+#[cfg(test)]
+mod tests {
+    use actix_web::http::StatusCode;
+    use actix_web::test::call_and_read_body_json;
+    use actix_web::test::call_service;
+    use actix_web::test::init_service;
+    use actix_web::test::read_body_json;
+    use actix_web::test::TestRequest;
+    use actix_web::App;
+    use serde_json::Value;
+
+    use super::*;
+
+    fn stores() -> (Data<subject::Store>, Data<sample::Store>, Data<file::Store>) {
+        let subjects = subject::Store::random(10);
+        let samples = sample::Store::random(10, subjects.subjects.lock().unwrap());
+        let files = file::Store::random(10, samples.samples.lock().unwrap());
+
+        (Data::new(subjects), Data::new(samples), Data::new(files))
+    }
+
+    #[test]
+    fn every_groupable_field_is_supported() {
+        let (subjects, samples, files) = stores();
+
+        for field in subject::GROUPABLE_FIELDS {
+            let subjects = subjects.subjects.lock().unwrap().clone();
+            assert!(matches!(
+                subject::group_by(subjects, field),
+                GroupByResults::Supported(_)
+            ));
+        }
+
+        for field in sample::GROUPABLE_FIELDS {
+            let samples = samples.samples.lock().unwrap().clone();
+            assert!(matches!(
+                sample::group_by(samples, field),
+                GroupByResults::Supported(_)
+            ));
+        }
+
+        for field in file::GROUPABLE_FIELDS {
+            let files = files.files.lock().unwrap().clone();
+            assert!(matches!(
+                file::group_by(files, field),
+                GroupByResults::Supported(_)
+            ));
+        }
+    }
+
+    #[actix_web::test]
+    async fn it_lists_the_invalid_pairs() {
+        let (subjects, samples, files) = stores();
+
+        let app = init_service(App::new().configure(configure(subjects, samples, files))).await;
+
+        let req = TestRequest::get()
+            .uri("/aggregate/counts?include=subject.sex,subject.handedness,sample.library_strategy,specimen.type")
+            .to_request();
+        let res = call_service(&app, req).await;
+        assert_eq!(res.status(), StatusCode::UNPROCESSABLE_ENTITY);
+
+        let body: Value = read_body_json(res).await;
+        let reason = body["errors"][0]["reason"].as_str().unwrap();
+        assert!(reason.starts_with(
+            "Unsupported entity and field pairs: subject.handedness, specimen.type. Supported \
+             pairs are: "
+        ));
+        assert!(reason.contains("subject.sex"));
+        assert!(reason.contains("sample.library_strategy"));
+        assert!(reason.contains("file.type"));
+        assert_eq!(body["errors"][0]["parameters"][0], "include");
+    }
+
+    #[actix_web::test]
+    async fn it_matches_the_individual_endpoints() {
+        let (subjects, samples, files) = stores();
+
+        let app = init_service(
+            App::new()
+                .configure(subject::configure(subjects.clone()))
+                .configure(sample::configure(samples.clone()))
+                .configure(file::configure(files.clone()))
+                .configure(configure(subjects, samples, files)),
+        )
+        .await;
+
+        let req = TestRequest::get()
+            .uri("/aggregate/counts?include=subject.sex,sample.library_strategy,file.type")
+            .to_request();
+        let aggregate: Value = call_and_read_body_json(&app, req).await;
+
+        assert_eq!(aggregate.as_object().unwrap().len(), 3);
+
+        for (pair, uri) in [
+            ("subject.sex", "/subject/by/sex/count"),
+            (
+                "sample.library_strategy",
+                "/sample/by/library_strategy/count",
+            ),
+            ("file.type", "/file/by/type/count"),
+        ] {
+            let req = TestRequest::get().uri(uri).to_request();
+            let individual: Value = call_and_read_body_json(&app, req).await;
+            assert_eq!(aggregate[pair], individual);
+        }
+    }
+}
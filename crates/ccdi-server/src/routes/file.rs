@@ -1,37 +1,113 @@
 //! Routes related to files.
 
+use std::collections::BTreeSet;
 use std::sync::Mutex;
 use std::sync::MutexGuard;
 
 use actix_web::get;
+use actix_web::http::header;
+use actix_web::post;
+use actix_web::web::Bytes;
 use actix_web::web::Data;
 use actix_web::web::Path;
 use actix_web::web::Query;
 use actix_web::web::ServiceConfig;
+use actix_web::HttpRequest;
 use actix_web::HttpResponse;
 use actix_web::Responder;
 use ccdi_cde::v1::file;
+use rand::distributions::Alphanumeric;
 use rand::prelude::*;
 
 use ccdi_models as models;
 
 use models::file::Identifier;
+use models::metadata::DEFAULT_METADATA_LESS_FRACTION;
+use models::namespace;
 use models::File;
 use serde_json::Value;
 
-use crate::filter::filter;
+use crate::filter::alias::deserialize;
+use crate::filter::alias::Aliases as _;
+use crate::filter::check_keys;
+use crate::filter::expression::evaluate;
+use crate::filter::expression::Expression;
+use crate::filter::filter_with_case;
+use crate::filter::parameter_names;
+use crate::filter::unharmonized;
+use crate::filter::validate;
+use crate::ndjson;
 use crate::paginate;
+use crate::paginate::Totals;
+use crate::params::count::BucketParams;
 use crate::params::filter::File as FilterFileParams;
+use crate::params::filter::Matching as FilterMatchingParams;
+use crate::params::format::Format as ListingFormat;
+use crate::params::lookup::FileLookupParams;
+use crate::params::manifest::Format;
+use crate::params::FieldsParams;
+use crate::params::FormatParams;
+use crate::params::ManifestParams;
+use crate::params::MissingParams;
 use crate::params::PaginationParams;
+use crate::params::ScopeParams;
+use crate::params::SortParams;
+use crate::project;
 use crate::responses;
+use crate::responses::by::count::file::SizedValueCount;
 use crate::responses::by::count::ValueCount;
 use crate::responses::error;
 use crate::responses::Errors;
+use crate::responses::FileManifest;
 use crate::responses::Files;
 use crate::responses::Summary;
+use crate::routes::duplicates;
+use crate::routes::metadata::UnharmonizedRegistries;
+use crate::routes::namespace::check_known;
+use crate::routes::namespace::is_known;
 use crate::routes::GroupByResults;
+use crate::sort::sort;
+use crate::strict;
+use crate::suggest;
+use crate::values;
+
+/// An error related to a [`Store`].
+#[derive(Debug)]
+pub enum Error {
+    /// More than one file has the same name within a namespace (each
+    /// duplicated identifier is listed once).
+    DuplicateIdentifiers(Vec<Identifier>),
+
+    /// The namespace of a file's identifier is not known by this server.
+    UnknownNamespace(namespace::Identifier),
+}
+
+impl std::fmt::Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::DuplicateIdentifiers(identifiers) => write!(
+                f,
+                "duplicate file identifiers: {}",
+                identifiers
+                    .iter()
+                    .map(|identifier| identifier.to_string())
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            ),
+            Error::UnknownNamespace(namespace) => {
+                write!(f, "unknown namespace: {namespace}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for Error {}
 
 /// A store for [`File`]s.
+///
+/// The name of each file must be unique within its namespace (see
+/// [`Identifier`]). Use [`Store::try_new()`] to enforce this when the files do
+/// not come from the random generator.
 #[derive(Debug)]
 pub struct Store {
     /// The inner [`Files`](ccdi_models::File).
@@ -39,6 +115,69 @@ pub struct Store {
 }
 
 impl Store {
+    /// Attempts to create a new [`Store`] from the provided [`File`]s.
+    ///
+    /// An error is returned if more than one file has the same name within a
+    /// namespace (in which case, every duplicated identifier is reported) or if
+    /// the namespace of any file's identifier is not known by this server (see
+    /// [`NAMESPACES`](crate::routes::namespace::NAMESPACES)).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ccdi_cde as cde;
+    /// use ccdi_models as models;
+    /// use ccdi_server as server;
+    /// use nonempty::NonEmpty;
+    ///
+    /// use models::namespace;
+    /// use models::File;
+    /// use server::routes::file;
+    ///
+    /// let namespace = namespace::Identifier::new(
+    ///     "example-organization"
+    ///         .parse::<models::organization::Identifier>()
+    ///         .unwrap(),
+    ///     "ExampleNamespaceOne"
+    ///         .parse::<namespace::identifier::Name>()
+    ///         .unwrap(),
+    /// );
+    ///
+    /// let file = File::new(
+    ///     models::file::Identifier::new(namespace.clone(), cde::v1::file::Name::new("File1.txt")),
+    ///     NonEmpty::new(models::sample::Identifier::new(namespace, "Sample1")),
+    ///     None,
+    ///     None,
+    /// );
+    ///
+    /// assert!(file::Store::try_new(vec![file.clone()]).is_ok());
+    /// assert!(file::Store::try_new(vec![file.clone(), file]).is_err());
+    /// ```
+    pub fn try_new(files: Vec<File>) -> Result<Self, Error> {
+        for file in &files {
+            let namespace = file.id().namespace();
+
+            if !is_known(namespace.organization().as_str(), namespace.name().as_str()) {
+                return Err(Error::UnknownNamespace(namespace.clone()));
+            }
+        }
+
+        let duplicates = duplicates(files.iter().map(|file| file.id()))
+            .into_iter()
+            .map(|duplicate| duplicate.identifier.clone())
+            .collect::<BTreeSet<_>>();
+
+        if !duplicates.is_empty() {
+            return Err(Error::DuplicateIdentifiers(
+                duplicates.into_iter().collect(),
+            ));
+        }
+
+        Ok(Self {
+            files: Mutex::new(files),
+        })
+    }
+
     /// Creates a new [`Store`] with randomized [`File`]s.
     ///
     /// # Examples
@@ -55,36 +194,152 @@ impl Store {
     /// let files = file::Store::random(100, samples.samples.lock().unwrap());
     /// ```
     pub fn random(count: usize, samples: MutexGuard<'_, Vec<ccdi_models::Sample>>) -> Self {
+        Self::random_with_metadata_less_fraction(count, samples, DEFAULT_METADATA_LESS_FRACTION)
+    }
+
+    /// Creates a new [`Store`] with randomized [`File`]s where
+    /// `metadata_less_fraction` is the probability that each generated
+    /// [`File`] has no metadata.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `metadata_less_fraction` is not within `0.0..=1.0`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ccdi_server as server;
+    ///
+    /// use server::routes::file;
+    /// use server::routes::sample;
+    /// use server::routes::subject;
+    ///
+    /// let subjects = subject::Store::random(100);
+    /// let samples = sample::Store::random(100, subjects.subjects.lock().unwrap());
+    /// let files =
+    ///     file::Store::random_with_metadata_less_fraction(100, samples.samples.lock().unwrap(), 1.0);
+    ///
+    /// assert!(files
+    ///     .files
+    ///     .lock()
+    ///     .unwrap()
+    ///     .iter()
+    ///     .all(|file| !file.has_metadata()));
+    /// ```
+    pub fn random_with_metadata_less_fraction(
+        count: usize,
+        samples: MutexGuard<'_, Vec<ccdi_models::Sample>>,
+        metadata_less_fraction: f64,
+    ) -> Self {
+        Self::random_with_rng(
+            count,
+            samples,
+            metadata_less_fraction,
+            &mut rand::thread_rng(),
+        )
+    }
+
+    /// Creates a new [`Store`] with randomized [`File`]s using the provided
+    /// random number generator, where `metadata_less_fraction` is the
+    /// probability that each generated [`File`] has no metadata.
+    ///
+    /// The same seed (and the same `samples`) always generates the same
+    /// [`Store`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `metadata_less_fraction` is not within `0.0..=1.0`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ccdi_server as server;
+    /// use rand::rngs::StdRng;
+    /// use rand::SeedableRng as _;
+    ///
+    /// use server::routes::file;
+    /// use server::routes::sample;
+    /// use server::routes::subject;
+    ///
+    /// let mut rng = StdRng::seed_from_u64(42);
+    /// let subjects = subject::Store::random_with_rng(100, 0.5, &mut rng);
+    /// let samples =
+    ///     sample::Store::random_with_rng(100, subjects.subjects.lock().unwrap(), 0.5, &mut rng);
+    /// let files = file::Store::random_with_rng(100, samples.samples.lock().unwrap(), 0.5, &mut rng);
+    /// ```
+    pub fn random_with_rng<R: Rng + ?Sized>(
+        count: usize,
+        samples: MutexGuard<'_, Vec<ccdi_models::Sample>>,
+        metadata_less_fraction: f64,
+        rng: &mut R,
+    ) -> Self {
+        let mut taken = BTreeSet::new();
+
+        let files = (0..count)
+            .map(|_| {
+                // SAFETY: this should always unwrap because we manually ensure
+                // that subjects is never empty.
+                let sample = samples.choose(rng).unwrap().id().clone();
+
+                let identifier = unique_identifier(sample.namespace(), &mut taken, || {
+                    format!(
+                        "File-{}.txt",
+                        (0..8)
+                            .map(|_| rng.sample(Alphanumeric).to_ascii_uppercase() as char)
+                            .collect::<String>()
+                    )
+                });
+
+                File::random_with_rng(identifier, sample, metadata_less_fraction, rng)
+            })
+            .collect::<Vec<_>>();
+
+        // NOTE: the names of the generated files are never reused within a
+        // namespace (see [`unique_identifier()`]), so the identifiers of the
+        // generated files are never duplicated.
+        debug_assert!(duplicates(files.iter().map(|file| file.id())).is_empty());
+
         Self {
-            files: Mutex::new(
-                (0..count)
-                    .map(|i| {
-                        let mut rng = rand::thread_rng();
-
-                        // SAFETY: this should always unwrap because we manually ensure
-                        // that subjects is never empty.
-                        let sample = samples.choose(&mut rng).unwrap().id().clone();
-
-                        let identifier = Identifier::new(
-                            sample.namespace().clone(),
-                            file::Name::new(format!("File{}.txt", i + 1)),
-                        );
-
-                        File::random(identifier, sample)
-                    })
-                    .collect::<Vec<_>>(),
-            ),
+            files: Mutex::new(files),
+        }
+    }
+}
+
+/// Generates a file [`Identifier`] within the provided namespace using the
+/// provided name generator, retrying whenever the generated name has already
+/// been taken within the namespace.
+fn unique_identifier(
+    namespace: &namespace::Identifier,
+    taken: &mut BTreeSet<Identifier>,
+    mut generate: impl FnMut() -> String,
+) -> Identifier {
+    loop {
+        let identifier = Identifier::new(namespace.clone(), file::Name::new(generate()));
+
+        if taken.insert(identifier.clone()) {
+            return identifier;
         }
     }
 }
 
+/// Returns whether a file has the provided organization, namespace, and name.
+fn is_named(file: &File, organization: &str, namespace: &str, name: &str) -> bool {
+    file.id().namespace().organization().as_str() == organization
+        && file.id().namespace().name().as_str() == namespace
+        && **file.id().name() == name
+}
+
 /// Configures the [`ServiceConfig`] with the file paths.
 pub fn configure(store: Data<Store>) -> impl FnOnce(&mut ServiceConfig) {
     |config: &mut ServiceConfig| {
         config
             .app_data(store)
             .service(file_index)
+            .service(file_filter)
             .service(files_by_count)
+            .service(file_values)
+            .service(file_lookup)
+            .service(file_download_manifest)
             .service(file_show)
             .service(file_summary);
     }
@@ -112,17 +367,42 @@ pub fn configure(store: Data<Store>) -> impl FnOnce(&mut ServiceConfig) {
 ///   logical OR (`||`)). Matches are case-sensitive.
 /// * When the metadata field is `null` (in the case of singular or
 ///   multiple-valued metadata fields) or empty, the file is not included.
+/// * Prefixing the query with `not:` (e.g., `not:Value`) negates it: the file is
+///   included only if it does _not_ match the remainder of the query. When
+///   the metadata field is `null` or empty, the file _is_ included in negated
+///   results. The query `not:null` includes only those files where the
+///   metadata field has a value.
+/// * Providing `case_insensitive=true` opts in to case-insensitive matching for
+///   every filter parameter, including unharmonized fields (e.g., `hispanic or
+///   latino` matches `Hispanic or Latino`). Matching is case-sensitive by
+///   default.
 /// * When multiple fields are provided as filters, a logical AND (`&&`) strings
 ///   together the predicates. In other words, all filters must match for a
-///   file to be returned. Note that this means that servers do not natively
-///   support logical OR (`||`) across multiple fields: that must be done by
-///   calling this endpoint with each of your desired queries and performing a
-///   set union of those files out of band.
+///   file to be returned. To combine filters with a logical OR
+///   (`||`) (or to group them), post a filter expression to the
+///   `/file/filter` endpoint instead.
 ///
 /// ### Ordering
 ///
 /// This endpoint has default ordering requirements—those details are documented
 /// in the `responses::Files` schema.
+///
+/// Results may instead be sorted by one of a subset of the harmonized metadata
+/// fields with the `sort` and `order` parameters (e.g.,
+/// `?sort=size&order=desc`). Files without a value for the field are always
+/// sorted last, ties are broken by identifier, and the sort parameters are
+/// preserved within the `link` header. Sorting by any other field is rejected
+/// with a `422`.
+///
+/// ### Streaming
+///
+/// Large listings may instead be streamed as newline-delimited JSON
+/// (`application/x-ndjson`) by providing `format=ndjson` or, when the `format`
+/// parameter is not provided, an `Accept` header of `application/x-ndjson`.
+/// Every matching file (filtered and sorted as described above) is returned
+/// as a JSON object on its own line without the enclosing response or the
+/// `link` header. Because the results are not paginated, providing `page` or
+/// `per_page` along with NDJSON is rejected with a `422`.
 #[utoipa::path(
     get,
     path = "/file",
@@ -141,6 +421,12 @@ pub fn configure(store: Data<Store>) -> impl FnOnce(&mut ServiceConfig) {
             * Filtering on field with multiple values should include the \
             `File` in the results if the query exactly matches any of the \
             values of the field for that `File` (case-sensitive).\n\
+            * When `case_insensitive=true` is provided, matches are \
+            case-insensitive instead.\n\
+            * Providing a JSON array of values (e.g., `[\"A\",\"B\"]`) \
+            should include the `File` in the results if the query matches \
+            any of the values within the array. Arrays may only contain \
+            strings, numbers, or booleans.\n\
             * Unlike harmonized fields, unharmonized fields must be prefixed \
             with `metadata.unharmonized`.\n\n\
             **Note:** this query parameter is intended to be symbolic of any \
@@ -151,13 +437,23 @@ pub fn configure(store: Data<Store>) -> impl FnOnce(&mut ServiceConfig) {
             `?metadata.unharmonized.<field>=value` is not supported, so \
             attempting to use it within Swagger UI will not work!"
         ),
+        FilterMatchingParams,
+        FieldsParams,
+        SortParams,
+        FormatParams,
         PaginationParams
     ),
     responses(
         (
             status = 200,
             description = "Successful operation.",
-            body = responses::Files,
+            content(
+                (
+                    "application/json" = responses::Files,
+                    example = json!(*responses::examples::FILES)
+                ),
+                ("application/x-ndjson" = String)
+            ),
             headers(
                 (
                     "link" = String,
@@ -193,6 +489,18 @@ pub fn configure(store: Data<Store>) -> impl FnOnce(&mut ServiceConfig) {
                     headers are case insensitive. Though not required, we \
                     recommend an all lowercase name of `link` for this \
                     response header."
+                ),
+                (
+                    "x-total-count" = usize,
+                    description = "The number of entities known by the server \
+                    before any filters were applied (also reported as \
+                    `summary.counts.total`)."
+                ),
+                (
+                    "x-filtered-count" = usize,
+                    description = "The number of entities that matched the \
+                    filters across every page (also reported as \
+                    `summary.counts.filtered`)."
                 )
             )
         ),
@@ -227,20 +535,317 @@ pub fn configure(store: Data<Store>) -> impl FnOnce(&mut ServiceConfig) {
     )
 )]
 #[get("/file")]
+// NOTE: actix-web extracts each set of query parameters and each piece of
+// shared state of a handler as a separate argument.
+#[allow(clippy::too_many_arguments)]
 pub async fn file_index(
-    filter_params: Query<FilterFileParams>,
+    matching_params: Query<FilterMatchingParams>,
+    fields_params: Query<FieldsParams>,
+    sort_params: Query<SortParams>,
+    format_params: Query<FormatParams>,
     pagination_params: Query<PaginationParams>,
+    request: HttpRequest,
     files: Data<Store>,
+    registries: Option<Data<UnharmonizedRegistries>>,
+    pagination_config: Option<Data<paginate::Config>>,
+    strict_config: Option<Data<strict::Config>>,
 ) -> impl Responder {
+    let known = [
+        parameter_names::<FilterFileParams>(),
+        FilterFileParams::names(),
+        parameter_names::<FilterMatchingParams>(),
+        parameter_names::<FieldsParams>(),
+        parameter_names::<SortParams>(),
+        parameter_names::<FormatParams>(),
+        parameter_names::<PaginationParams>(),
+    ]
+    .concat();
+
+    let mut warnings = Vec::new();
+
+    if let Err(err) = strict::handle(
+        check_keys(request.query_string(), &known),
+        strict_config.as_ref().map(|config| config.get_ref()),
+        &mut warnings,
+    ) {
+        return HttpResponse::UnprocessableEntity().json(Errors::from(err));
+    }
+
+    let format = format_params
+        .format()
+        .or_else(|| {
+            request
+                .headers()
+                .get(header::ACCEPT)
+                .and_then(|value| value.to_str().ok())
+                .and_then(ListingFormat::from_accept)
+        })
+        .unwrap_or(ListingFormat::Json);
+
+    if format == ListingFormat::Ndjson {
+        if let Err(err) = ndjson::reject_pagination(&pagination_params) {
+            return HttpResponse::UnprocessableEntity().json(Errors::from(err));
+        }
+    }
+
+    let filter_params = match deserialize::<FilterFileParams>(request.query_string()) {
+        Ok(filter_params) => filter_params,
+        Err(err) => return HttpResponse::UnprocessableEntity().json(Errors::from(err)),
+    };
+
+    if let Err(err) = validate(&filter_params) {
+        return HttpResponse::UnprocessableEntity().json(Errors::from(err));
+    }
+
+    let unharmonized_queries = match unharmonized::queries(request.query_string()) {
+        Ok(queries) => queries,
+        Err(err) => return HttpResponse::UnprocessableEntity().json(Errors::from(err)),
+    };
+
+    unharmonized::warn_unregistered(
+        &unharmonized_queries,
+        registries.as_deref().map(|registries| &registries.file),
+    );
+
+    let selection = match project::select(
+        &fields_params.0,
+        models::file::metadata::Builder::default().build(),
+    ) {
+        Ok(selection) => selection,
+        Err(err) => return HttpResponse::UnprocessableEntity().json(Errors::from(err)),
+    };
+
     let mut files = files.files.lock().unwrap().clone();
+    let total = files.len();
 
     // See the note in the documentation for this endpoint: the results must be
     // sorted by identifier by default.
     files.sort();
 
-    let files = filter::<File, FilterFileParams>(files, filter_params.0);
+    let files =
+        filter_with_case::<File, FilterFileParams>(files, filter_params, matching_params.case());
+    let files = unharmonized::filter(files, &unharmonized_queries, matching_params.case());
+
+    // NOTE: the results are sorted after they are filtered but before they are
+    // paginated so that each page is a slice of the sorted results.
+    let files = match sort(files, &sort_params) {
+        Ok(files) => files,
+        Err(err) => return HttpResponse::UnprocessableEntity().json(Errors::from(err)),
+    };
+
+    // NOTE: every matching file is streamed rather than paginated.
+    if format == ListingFormat::Ndjson {
+        return match selection {
+            Some(selection) => ndjson::response(
+                files
+                    .into_iter()
+                    .map(move |file| selection.project_entity(&file)),
+            ),
+            None => ndjson::response(files),
+        };
+    }
+
+    let url = sort_params.url("http://localhost:8000/file");
+
+    // NOTE: the totals are computed once from the filtered results rather than
+    // for each page.
+    let totals = Totals::new(total, files.len());
+    let build = |page: (Vec<File>, usize)| {
+        Files::from(page)
+            .with_totals(totals)
+            .with_warnings(warnings.clone())
+    };
+
+    match selection {
+        Some(selection) => paginate::response_with_totals(
+            pagination_params.0,
+            pagination_config.as_ref().map(|config| config.get_ref()),
+            files,
+            totals,
+            &url,
+            |page| selection.project(&build(page)),
+        ),
+        None => paginate::response_with_totals(
+            pagination_params.0,
+            pagination_config.as_ref().map(|config| config.get_ref()),
+            files,
+            totals,
+            &url,
+            build,
+        ),
+    }
+}
+
+/// Gets the files known by this server that match a boolean composition of
+/// filters.
+///
+/// ### Filtering
+///
+/// The body of the request is a filter expression: either a group or a leaf.
+///
+/// * A group is a JSON object with a single key of either `any` or `all`
+///   whose value is a non-empty array of filter expressions. An `any` group
+///   matches a file when _any_ of its expressions match (a logical OR (`||`)),
+///   and an `all` group matches a file when _all_ of its expressions match (a
+///   logical AND (`&&`)). Groups may be nested up to four levels deep.
+/// * A leaf is a JSON object of filters keyed exactly as the query parameters
+///   of the `/file` endpoint (including unharmonized fields prefixed with
+///   `metadata.unharmonized.`). Each leaf is matched exactly as the same
+///   filters would be if provided as query parameters to the `/file` endpoint.
+///
+/// Empty groups, groups nested too deeply, and leaves that would be rejected
+/// as query parameters are rejected with a `422`.
+///
+/// ### Pagination, Ordering, and Matching
+///
+/// This endpoint is paginated and ordered in the same manner as the `/file`
+/// endpoint, and the `case_insensitive` parameter applies to every leaf.
+#[utoipa::path(
+    post,
+    path = "/file/filter",
+    tag = "File",
+    params(FilterMatchingParams, SortParams, PaginationParams),
+    request_body(
+        content = Object,
+        description = "A filter expression (see the description of this endpoint).",
+        content_type = "application/json",
+        example = json!({"any": [{"type": "BAM"}, {"type": "CRAM"}]})
+    ),
+    responses(
+        (
+            status = 200,
+            description = "Successful operation.",
+            body = responses::Files,
+            example = json!(*responses::examples::FILES),
+            headers(
+                (
+                    "link" = String,
+                    description = "Links to URLs that may be of interest \
+                    when paging through paginated responses. This header \
+                    contains two or more links of interest. The format of the \
+                    field is as follows: \
+                    \n\
+                    \n`Link: <URL>; rel=\"REL\"` \
+                    \n\
+                    ### Relationships\n\n\
+                    In the format above, `URL` represents a valid URL for \
+                    the link of interest and `REL` is one of four values: \n\
+                    - `first` (_Required_). A link to the first page in the \
+                    results (can be the same as `last` if there is only one \
+                    page).\n\
+                    - `last` (_Required_). A link to the first page in the \
+                    results (can be the same as `first` if there is only one \
+                    page).\n\
+                    - `next` (_Optional_). A link to the next page (if it \
+                    exists).\n\
+                    - `prev` (_Optional_). A link to the previous page (if it \
+                    exists).\n\n\
+                    ### Requirements\n\n\
+                    - This header _must_ provide links for at least the `first` \
+                    and `last` rels.\n \
+                    - The `prev` and `next` links must exist only (a) when there \
+                    are multiple pages in the result page set and (b) when the \
+                    current page is not the first or last page, respectively.\n\
+                    - This list of links is unordered.\n\n \
+                    ### Notes\n\n\
+                    - HTTP 1.1 and HTTP 2.0 dictate that response \
+                    headers are case insensitive. Though not required, we \
+                    recommend an all lowercase name of `link` for this \
+                    response header."
+                ),
+                (
+                    "x-total-count" = usize,
+                    description = "The number of entities known by the server \
+                    before any filters were applied (also reported as \
+                    `summary.counts.total`)."
+                ),
+                (
+                    "x-filtered-count" = usize,
+                    description = "The number of entities that matched the \
+                    filters across every page (also reported as \
+                    `summary.counts.filtered`)."
+                )
+            )
+        ),
+        (
+            status = 422,
+            description = "Invalid filter expression or query parameters.",
+            body = responses::Errors,
+            example = json!(Errors::from(error::Kind::invalid_parameters(
+                None,
+                String::from("The `any` group at `/` must contain at least one expression.")
+            )))
+        ),
+    )
+)]
+#[post("/file/filter")]
+// NOTE: actix-web extracts each set of query parameters and each piece of
+// shared state of a handler as a separate argument.
+#[allow(clippy::too_many_arguments)]
+pub async fn file_filter(
+    body: Bytes,
+    matching_params: Query<FilterMatchingParams>,
+    sort_params: Query<SortParams>,
+    pagination_params: Query<PaginationParams>,
+    request: HttpRequest,
+    files: Data<Store>,
+    pagination_config: Option<Data<paginate::Config>>,
+    strict_config: Option<Data<strict::Config>>,
+) -> impl Responder {
+    let known = [
+        parameter_names::<FilterMatchingParams>(),
+        parameter_names::<SortParams>(),
+        parameter_names::<PaginationParams>(),
+    ]
+    .concat();
+
+    let mut warnings = Vec::new();
+
+    if let Err(err) = strict::handle(
+        check_keys(request.query_string(), &known),
+        strict_config.as_ref().map(|config| config.get_ref()),
+        &mut warnings,
+    ) {
+        return HttpResponse::UnprocessableEntity().json(Errors::from(err));
+    }
+
+    let expression = match serde_json::from_slice::<Value>(&body)
+        .map_err(|err| error::Kind::invalid_parameters(None, format!("Invalid JSON body: {err}.")))
+        .and_then(|value| Expression::<FilterFileParams>::parse(&value))
+    {
+        Ok(expression) => expression,
+        Err(err) => return HttpResponse::UnprocessableEntity().json(Errors::from(err)),
+    };
+
+    let mut files = files.files.lock().unwrap().clone();
+    let total = files.len();
+
+    // See the note in the documentation for the `/file` endpoint: the
+    // results must be sorted by identifier by default.
+    files.sort();
+
+    let files = evaluate(files, expression, matching_params.case());
+
+    let files = match sort(files, &sort_params) {
+        Ok(files) => files,
+        Err(err) => return HttpResponse::UnprocessableEntity().json(Errors::from(err)),
+    };
 
-    paginate::response::<File, Files>(pagination_params.0, files, "http://localhost:8000/file")
+    let url = sort_params.url("http://localhost:8000/file/filter");
+    let totals = Totals::new(total, files.len());
+
+    paginate::response_with_totals(
+        pagination_params.0,
+        pagination_config.as_ref().map(|config| config.get_ref()),
+        files,
+        totals,
+        &url,
+        |page: (Vec<File>, usize)| {
+            Files::from(page)
+                .with_totals(totals)
+                .with_warnings(warnings)
+        },
+    )
 }
 
 /// Gets the file matching the provided name (if the file exists).
@@ -263,71 +868,475 @@ pub async fn file_index(
     ),
     tag = "File",
     responses(
-        (status = 200, description = "Successful operation.", body = responses::File),
+        (
+            status = 200,
+            description = "Successful operation.",
+            body = responses::File,
+            example = json!(*responses::examples::FILE)
+        ),
         (
             status = 404,
             description = "Not found.\nServers that cannot provide line-level \
             data should use this response rather than Forbidden (403), as \
             there is no level of authorization that would allow one to access \
-            the information included in the API.",
+            the information included in the API.\nIf the server has suggestions \
+            enabled, up to three similar identifiers may be included in the \
+            `suggestions` field.\nIf the namespace is not known by the server \
+            (namespaces are compared case-sensitively), an `UnknownNamespace` \
+            error listing the known namespaces (and any similar namespaces) is \
+            returned instead.",
             body = responses::Errors,
             example = json!(Errors::from(error::Kind::not_found(
                 String::from("File with namespace 'foo' and name 'bar'")
             )))
+        ),
+        (
+            status = 422,
+            description = "Invalid query parameters.",
+            body = responses::Errors,
+            example = json!(Errors::from(error::Kind::invalid_parameters(
+                Some(vec![String::from("foo")]),
+                String::from("unrecognized parameter(s): `foo`.")
+            )))
+        ),
+        (
+            status = 500,
+            description = "Internal server error.\nMore than one file has the \
+            requested identifier. Identifiers are expected to be unique, so this \
+            indicates an issue with the data held by the server.",
+            body = responses::Errors,
+            example = json!(Errors::from(error::Kind::duplicate_identifier(
+                String::from("File with namespace 'foo' and name 'bar'"),
+                2
+            )))
         )
     )
 )]
 #[get("/file/{organization}/{namespace}/{name}")]
-pub async fn file_show(path: Path<(String, String, String)>, files: Data<Store>) -> impl Responder {
-    let files = files.files.lock().unwrap();
+pub async fn file_show(
+    path: Path<(String, String, String)>,
+    request: HttpRequest,
+    files: Data<Store>,
+    suggestions: Option<Data<suggest::Config>>,
+    strict_config: Option<Data<strict::Config>>,
+) -> impl Responder {
+    if let Err(err) = strict::check(
+        request.query_string(),
+        &[],
+        strict_config.as_ref().map(|config| config.get_ref()),
+    ) {
+        return HttpResponse::UnprocessableEntity().json(Errors::from(err));
+    }
+
     let (organization, namespace, name) = path.into_inner();
 
-    files
+    if let Err(err) = check_known(&organization, &namespace) {
+        return HttpResponse::NotFound().json(Errors::from(err));
+    }
+
+    let files = files.files.lock().unwrap();
+
+    let mut matches = files
         .iter()
-        .find(|file| {
-            file.id().namespace().organization().as_str() == organization
-                && file.id().namespace().name().as_str() == namespace
-                && **file.id().name() == name
-        })
-        .map(|file| HttpResponse::Ok().json(file))
+        .filter(|file| is_named(file, &organization, &namespace, &name));
+
+    let file = matches.next();
+    let others = matches.count();
+
+    if others > 0 {
+        return HttpResponse::InternalServerError().json(Errors::from(
+            error::Kind::duplicate_identifier(
+                format!("File with namespace '{namespace}' and name '{name}'"),
+                others + 1,
+            ),
+        ));
+    }
+
+    file.map(|file| HttpResponse::Ok().json(file))
         .unwrap_or_else(|| {
-            HttpResponse::NotFound().json(Errors::from(error::Kind::not_found(format!(
+            let mut errors = Errors::from(error::Kind::not_found(format!(
                 "File with namespace '{namespace}' and name '{name}'"
-            ))))
+            )));
+
+            if suggestions.is_some_and(|config| config.enabled()) {
+                errors = errors.with_suggestions(suggest::nearest(
+                    &format!("{organization}/{namespace}/{name}"),
+                    files.iter().map(|file| {
+                        format!(
+                            "{}/{}/{}",
+                            file.id().namespace().organization().as_str(),
+                            file.id().namespace().name().as_str(),
+                            file.id().name().as_str()
+                        )
+                    }),
+                ));
+            }
+
+            HttpResponse::NotFound().json(errors)
         })
 }
 
-/// Groups the files by the specified metadata field and returns counts.
+/// Looks up the file with the provided name within the provided namespace.
+///
+/// The name of a file is unique within its namespace, so at most one file is
+/// returned. This is a convenience for clients that know the namespace and the
+/// name of a file but would rather not construct the path to the file
+/// themselves.
 #[utoipa::path(
     get,
-    path = "/file/by/{field}/count",
-    params(
-        ("field" = String, description = "The field to group by and count with."),
-    ),
+    path = "/file/lookup",
+    params(FileLookupParams),
     tag = "File",
     responses(
-        (status = 200, description = "Successful operation.", body = responses::by::count::file::Results),
         (
-            status = 422,
-            description = "Unsupported field.",
-            body = responses::Errors,
-            example = json!(Errors::from(
-                error::Kind::unsupported_field(
-                    String::from("handedness"),
-                    String::from("This field is not present for files."),
-                )
-            ))
+            status = 200,
+            description = "Successful operation.",
+            body = responses::File,
+            example = json!(*responses::examples::FILE)
         ),
-    )
-)]
-#[get("/file/by/{field}/count")]
-pub async fn files_by_count(path: Path<String>, files: Data<Store>) -> impl Responder {
-    let files = files.files.lock().unwrap().clone();
-    let field = path.into_inner();
+        (
+            status = 404,
+            description = "Not found.\nServers that cannot provide line-level \
+            data should use this response rather than Forbidden (403), as \
+            there is no level of authorization that would allow one to access \
+            the information included in the API.",
+            body = responses::Errors,
+            example = json!(Errors::from(error::Kind::not_found(
+                String::from("File with namespace 'foo' and name 'bar'")
+            )))
+        ),
+        (
+            status = 422,
+            description = "Invalid query parameters.",
+            body = responses::Errors,
+            example = json!(Errors::from(error::Kind::invalid_parameters(
+                None,
+                String::from("missing field `name`")
+            )))
+        ),
+    )
+)]
+#[get("/file/lookup")]
+pub async fn file_lookup(
+    params: Query<FileLookupParams>,
+    request: HttpRequest,
+    files: Data<Store>,
+    strict_config: Option<Data<strict::Config>>,
+) -> impl Responder {
+    if let Err(err) = strict::check(
+        request.query_string(),
+        &parameter_names::<FileLookupParams>(),
+        strict_config.as_ref().map(|config| config.get_ref()),
+    ) {
+        return HttpResponse::UnprocessableEntity().json(Errors::from(err));
+    }
+
+    let files = files.files.lock().unwrap();
+
+    files
+        .iter()
+        .find(|file| {
+            is_named(
+                file,
+                params.organization(),
+                params.namespace(),
+                params.name(),
+            )
+        })
+        .map(|file| HttpResponse::Ok().json(file))
+        .unwrap_or_else(|| {
+            HttpResponse::NotFound().json(Errors::from(error::Kind::not_found(format!(
+                "File with namespace '{}' and name '{}'",
+                params.namespace(),
+                params.name()
+            ))))
+        })
+}
+
+/// Gets a manifest of the files matching the provided filters.
+///
+/// The manifest lists the identifier, size, checksums, type, description, and
+/// access information (DRS URI, access URL, and gateways) of every matching
+/// file so that the files can be retrieved and verified by bulk download
+/// tooling. Unlike `/file`, this endpoint is not paginated.
+///
+/// ### Filtering
+///
+/// Files are filtered in the same manner as `/file` (including the
+/// `case_insensitive` parameter and filtering on unharmonized fields).
+///
+/// ### Format
+///
+/// The manifest is returned as JSON (`application/json`) or as tab-separated
+/// values (`text/tab-separated-values`). The format is selected with the
+/// `format` query parameter or, when it is not provided, by the `Accept`
+/// header of the request. When neither selects a format, JSON is returned.
+///
+/// Within the tab-separated values, the first row is a header, missing values
+/// are empty, and any backslashes, tabs, carriage returns, and newlines within
+/// a value are escaped as `\\`, `\t`, `\r`, and `\n` respectively. The
+/// gateways of each file are written as a JSON array.
+///
+/// ### Ordering
+///
+/// Files are ordered by their identifier (in the same manner as `/file`).
+#[utoipa::path(
+    get,
+    path = "/file/download-manifest",
+    tag = "File",
+    params(
+        FilterFileParams,
+        (
+            "metadata.unharmonized.<field>" = Option<String>,
+            Query,
+            nullable = false,
+            description = "All unharmonized fields should be filterable in the \
+            same manner as harmonized fields:\n\n\
+            * Filtering on a singular field should include the `File` in \
+            the results if the query exactly matches the value of that field \
+            for the `File` (case-sensitive).\n\
+            * Filtering on field with multiple values should include the \
+            `File` in the results if the query exactly matches any of the \
+            values of the field for that `File` (case-sensitive).\n\
+            * When `case_insensitive=true` is provided, matches are \
+            case-insensitive instead.\n\
+            * Providing a JSON array of values (e.g., `[\"A\",\"B\"]`) \
+            should include the `File` in the results if the query matches \
+            any of the values within the array. Arrays may only contain \
+            strings, numbers, or booleans.\n\
+            * Unlike harmonized fields, unharmonized fields must be prefixed \
+            with `metadata.unharmonized`.\n\n\
+            **Note:** this query parameter is intended to be symbolic of any \
+            unharmonized field. Because of limitations within Swagger UI, it \
+            will show up as a query parameter that can be optionally be \
+            submitted as part of a request within Swagger UI. Please keep in \
+            mind that the literal query parameter \
+            `?metadata.unharmonized.<field>=value` is not supported, so \
+            attempting to use it within Swagger UI will not work!"
+        ),
+        FilterMatchingParams,
+        ManifestParams
+    ),
+    responses(
+        (
+            status = 200,
+            description = "Successful operation.",
+            content(
+                ("application/json" = responses::FileManifest),
+                ("text/tab-separated-values" = String)
+            )
+        ),
+        (
+            status = 404,
+            description = "Not found.\nServers that cannot provide line-level \
+            data should use this response rather than Forbidden (403), as \
+            there is no level of authorization that would allow one to access \
+            the information included in the API.",
+            body = responses::Errors,
+            example = json!(
+                Errors::from(
+                    error::Kind::unshareable_data(
+                        String::from("files"),
+                        String::from(
+                            "Our agreement with data providers prohibits us \
+                            from sharing file-level data."
+                        ),
+                    )
+                )
+            )
+        ),
+        (
+            status = 422,
+            description = "Invalid query parameters.",
+            body = responses::Errors,
+            example = json!(Errors::from(error::Kind::invalid_parameters(
+                Some(vec![String::from("format")]),
+                String::from("unknown variant `csv`, expected `json` or `tsv`")
+            )))
+        ),
+    )
+)]
+#[get("/file/download-manifest")]
+pub async fn file_download_manifest(
+    matching_params: Query<FilterMatchingParams>,
+    manifest_params: Query<ManifestParams>,
+    request: HttpRequest,
+    files: Data<Store>,
+    registries: Option<Data<UnharmonizedRegistries>>,
+    strict_config: Option<Data<strict::Config>>,
+) -> impl Responder {
+    if let Err(err) = strict::check(
+        request.query_string(),
+        &[
+            parameter_names::<FilterFileParams>(),
+            FilterFileParams::names(),
+            parameter_names::<FilterMatchingParams>(),
+            parameter_names::<ManifestParams>(),
+        ]
+        .concat(),
+        strict_config.as_ref().map(|config| config.get_ref()),
+    ) {
+        return HttpResponse::UnprocessableEntity().json(Errors::from(err));
+    }
+
+    let filter_params = match deserialize::<FilterFileParams>(request.query_string()) {
+        Ok(filter_params) => filter_params,
+        Err(err) => return HttpResponse::UnprocessableEntity().json(Errors::from(err)),
+    };
+
+    if let Err(err) = validate(&filter_params) {
+        return HttpResponse::UnprocessableEntity().json(Errors::from(err));
+    }
 
-    let results = group_by(files, &field);
+    let unharmonized_queries = match unharmonized::queries(request.query_string()) {
+        Ok(queries) => queries,
+        Err(err) => return HttpResponse::UnprocessableEntity().json(Errors::from(err)),
+    };
 
-    match results {
+    unharmonized::warn_unregistered(
+        &unharmonized_queries,
+        registries.as_deref().map(|registries| &registries.file),
+    );
+
+    let mut files = files.files.lock().unwrap().clone();
+
+    // NOTE: the manifest is ordered in the same manner as `/file`.
+    files.sort();
+
+    let files =
+        filter_with_case::<File, FilterFileParams>(files, filter_params, matching_params.case());
+    let files = unharmonized::filter(files, &unharmonized_queries, matching_params.case());
+    let manifest = FileManifest::from(files);
+
+    let format = manifest_params
+        .format()
+        .or_else(|| {
+            request
+                .headers()
+                .get(header::ACCEPT)
+                .and_then(|value| value.to_str().ok())
+                .and_then(Format::from_accept)
+        })
+        .unwrap_or(Format::Json);
+
+    match format {
+        Format::Json => HttpResponse::Ok().json(manifest),
+        Format::Tsv => HttpResponse::Ok()
+            .insert_header(header::ContentType(mime::TEXT_TAB_SEPARATED_VALUES_UTF_8))
+            .body(manifest.to_tsv()),
+    }
+}
+
+/// Groups the files by the specified metadata field and returns counts.
+///
+/// Along with the number of files for each value, the total size (in bytes)
+/// of those files is reported (e.g., the total bytes per `type`).
+///
+/// ### Size buckets
+///
+/// When grouping by `size`, files are counted within buckets of file sizes
+/// rather than by their exact size. Each value is an object with the inclusive
+/// lower boundary (`min`) and the exclusive upper boundary (`max`, which is
+/// `null` for the last bucket) of the bucket in bytes. The boundaries of the
+/// buckets may be configured with the `buckets` parameter.
+///
+/// ### Missing values
+///
+/// By default, `missing` counts the files where the field is missing or `null`
+/// as well as the files with no metadata block at all. When
+/// `distinguish_missing=true` is provided, `missing` only counts the files
+/// with no metadata block, and the files with a metadata block where the field
+/// is missing or `null` are counted within `null`.
+///
+/// The `bytes` of the files that are missing values are included within the
+/// total `bytes` either way.
+///
+/// ### Scoping
+///
+/// Providing `namespace` (formatted as `{organization}/{namespace}`) or
+/// `organization` only counts the files within the matching namespace or
+/// organization, exactly as the parameters of the same name do when filtering
+/// files.
+#[utoipa::path(
+    get,
+    path = "/file/by/{field}/count",
+    params(
+        ("field" = String, description = "The field to group by and count with."),
+        BucketParams,
+        MissingParams,
+        ScopeParams,
+    ),
+    tag = "File",
+    responses(
+        (
+            status = 200,
+            description = "Successful operation.",
+            body = responses::by::count::file::Response,
+            example = json!(*responses::examples::FILES_BY_COUNT)
+        ),
+        (
+            status = 422,
+            description = "Unsupported field or invalid query parameters.",
+            body = responses::Errors,
+            example = json!(Errors::from(
+                error::Kind::unsupported_field(
+                    String::from("handedness"),
+                    String::from("This field is not present for files."),
+                )
+            ))
+        ),
+    )
+)]
+#[get("/file/by/{field}/count")]
+pub async fn files_by_count(
+    path: Path<String>,
+    params: Query<BucketParams>,
+    missing: Query<MissingParams>,
+    scope: Query<ScopeParams>,
+    request: HttpRequest,
+    files: Data<Store>,
+    strict_config: Option<Data<strict::Config>>,
+) -> impl Responder {
+    if let Err(err) = strict::check(
+        request.query_string(),
+        &[
+            parameter_names::<BucketParams>(),
+            parameter_names::<MissingParams>(),
+            parameter_names::<ScopeParams>(),
+        ]
+        .concat(),
+        strict_config.as_ref().map(|config| config.get_ref()),
+    ) {
+        return HttpResponse::UnprocessableEntity().json(Errors::from(err));
+    }
+
+    if let Err(err) = scope.check() {
+        return HttpResponse::UnprocessableEntity().json(Errors::from(err));
+    }
+
+    let mut files = files.files.lock().unwrap().clone();
+    files.retain(|file| scope.contains(file.id().namespace()));
+
+    let field = path.into_inner();
+
+    let absent = files
+        .iter()
+        .filter(|file| file.metadata().is_none())
+        .count();
+
+    let boundaries = match params.boundaries() {
+        Ok(boundaries) => boundaries,
+        Err(reason) => {
+            return HttpResponse::UnprocessableEntity().json(Errors::from(
+                error::Kind::invalid_parameters(Some(vec![String::from("buckets")]), reason),
+            ))
+        }
+    };
+
+    match count_by(files, &field, &boundaries) {
+        GroupByResults::Supported(results) if missing.distinguish_missing() => {
+            HttpResponse::Ok().json(results.distinguish_missing(absent))
+        }
         GroupByResults::Supported(results) => HttpResponse::Ok().json(results),
         GroupByResults::Unsupported => {
             HttpResponse::UnprocessableEntity().json(Errors::from(error::Kind::unsupported_field(
@@ -338,7 +1347,184 @@ pub async fn files_by_count(path: Path<String>, files: Data<Store>) -> impl Resp
     }
 }
 
-fn group_by(files: Vec<File>, field: &str) -> GroupByResults<responses::by::count::file::Results> {
+/// Lists the distinct values that are present for the specified metadata
+/// field across the files known by this server.
+///
+/// Only non-null values are listed, each along with the number of files on
+/// which the value is present (sorted in descending order by that number).
+/// Multi-valued fields (e.g., `checksums`) list each of their members, and a
+/// file is counted at most once for each value.
+///
+/// ### Truncation
+///
+/// Fields with more distinct values than the limit configured for this server
+/// (e.g., `identifiers`) only list the most common values, in which case the
+/// response is marked with `truncated: true`.
+#[utoipa::path(
+    get,
+    path = "/file/values/{field}",
+    params(
+        ("field" = String, description = "The field to list the distinct values of."),
+    ),
+    tag = "File",
+    responses(
+        (
+            status = 200,
+            description = "Successful operation.",
+            body = responses::values::Response
+        ),
+        (
+            status = 422,
+            description = "Unsupported field.",
+            body = responses::Errors,
+            example = json!(Errors::from(
+                error::Kind::unsupported_field(
+                    String::from("handedness"),
+                    String::from("This field is not present for files."),
+                )
+            ))
+        ),
+    )
+)]
+#[get("/file/values/{field}")]
+pub async fn file_values(
+    path: Path<String>,
+    request: HttpRequest,
+    files: Data<Store>,
+    config: Option<Data<values::Config>>,
+    strict_config: Option<Data<strict::Config>>,
+) -> impl Responder {
+    if let Err(err) = strict::check(
+        request.query_string(),
+        &[],
+        strict_config.as_ref().map(|config| config.get_ref()),
+    ) {
+        return HttpResponse::UnprocessableEntity().json(Errors::from(err));
+    }
+
+    let files = files.files.lock().unwrap();
+    let field = path.into_inner();
+
+    let observed = match files
+        .iter()
+        .map(|file| parse_field(&field, file))
+        .collect::<Option<Vec<_>>>()
+    {
+        Some(observed) => observed,
+        None => {
+            return HttpResponse::UnprocessableEntity().json(Errors::from(
+                error::Kind::unsupported_field(
+                    field,
+                    String::from("This field is not present for files."),
+                ),
+            ))
+        }
+    };
+
+    let limit = config
+        .map(|config| config.limit())
+        .unwrap_or(values::DEFAULT_LIMIT);
+
+    HttpResponse::Ok().json(responses::values::Response::new(
+        field,
+        values::distinct(observed),
+        limit,
+    ))
+}
+
+/// Counts the files by the specified metadata field, summing the sizes of the
+/// files for each value. Sizes are counted within the buckets delimited by
+/// `boundaries` when grouping by `size`.
+pub(crate) fn count_by(
+    files: Vec<File>,
+    field: &str,
+    boundaries: &[usize],
+) -> GroupByResults<responses::by::count::file::Response> {
+    let values = files
+        .iter()
+        .map(|file| match field {
+            "size" => Some(size(file).map(|size| bucket(size, boundaries))),
+            _ => parse_field(field, file).map(|value| value.filter(|value| !value.is_null())),
+        })
+        .collect::<Vec<_>>();
+
+    if values.iter().any(|value| value.is_none()) {
+        return GroupByResults::Unsupported;
+    }
+
+    let mut missing_values = 0usize;
+    let mut missing_bytes = 0usize;
+    let result = files
+        .iter()
+        // SAFETY: we just checked above to ensure that none of the values are
+        // [`None`].
+        .zip(values.into_iter().map(|value| value.unwrap()))
+        .fold(
+            Vec::new(),
+            |mut acc: Vec<SizedValueCount>, (file, value)| {
+                let bytes = size(file).unwrap_or_default();
+
+                // NOTE: the sizes of files may be arbitrarily large, so the
+                // totals saturate rather than overflow.
+
+                match value {
+                    Some(value) => match acc.iter_mut().find(|result| result.value == value) {
+                        Some(result) => {
+                            result.count += 1;
+                            result.bytes = result.bytes.saturating_add(bytes);
+                        }
+                        None => acc.push(SizedValueCount {
+                            value,
+                            count: 1,
+                            bytes,
+                        }),
+                    },
+                    None => {
+                        missing_values += 1;
+                        missing_bytes = missing_bytes.saturating_add(bytes);
+                    }
+                }
+
+                acc
+            },
+        );
+
+    GroupByResults::Supported(responses::by::count::file::Response::new(
+        result,
+        missing_values,
+        missing_bytes,
+    ))
+}
+
+/// Gets the size (in bytes) of a file (if it is known).
+pub(crate) fn size(file: &File) -> Option<usize> {
+    file.metadata()
+        .and_then(|metadata| metadata.size())
+        .map(|size| size.value().inner())
+}
+
+/// Gets the bucket delimited by `boundaries` into which `size` falls.
+fn bucket(size: usize, boundaries: &[usize]) -> Value {
+    let index = boundaries.partition_point(|boundary| *boundary <= size);
+
+    let min = match index {
+        0 => 0,
+        index => boundaries[index - 1],
+    };
+
+    serde_json::json!({
+        "min": min,
+        "max": boundaries.get(index),
+    })
+}
+
+/// The fields by which files may be grouped and counted.
+pub const GROUPABLE_FIELDS: &[&str] = &["type", "size", "checksums", "description", "depositions"];
+
+pub(crate) fn group_by(
+    files: Vec<File>,
+    field: &str,
+) -> GroupByResults<responses::by::count::file::Results> {
     let values = files
         .iter()
         .map(|file| parse_field(field, file))
@@ -451,21 +1637,620 @@ fn parse_field(field: &str, file: &File) -> Option<Option<Value>> {
     path = "/file/summary",
     tag = "File",
     responses(
-        (status = 200, description = "Successful operation.", body = responses::Summary),
+        (
+            status = 200,
+            description = "Successful operation.",
+            body = responses::Summary,
+            example = json!(*responses::examples::SUMMARY)
+        ),
     )
 )]
 #[get("/file/summary")]
 pub async fn file_summary(files: Data<Store>) -> impl Responder {
-    let files = files.files.lock().unwrap().clone();
-    HttpResponse::Ok().json(Summary::new(files.len()))
+    let files = files.files.lock().unwrap();
+
+    let without_metadata = files.iter().filter(|file| !file.has_metadata()).count();
+
+    HttpResponse::Ok().json(Summary::new(files.len(), without_metadata))
 }
 
 #[cfg(test)]
 mod tests {
+    use actix_web::http::StatusCode;
+    use actix_web::test::call_and_read_body_json;
+    use actix_web::test::call_service;
+    use actix_web::test::init_service;
+    use actix_web::test::read_body;
+    use actix_web::test::TestRequest;
+    use actix_web::App;
+    use nonempty::NonEmpty;
+
+    use models::metadata::field::unowned::file::Description;
+
+    use super::*;
     use crate::routes::namespace::random_namespace;
+    use crate::routes::sample;
+    use crate::routes::subject;
+
+    fn named_file(namespace: &str, name: &str) -> File {
+        let namespace = models::namespace::Identifier::new(
+            "example-organization"
+                .parse::<models::organization::Identifier>()
+                .unwrap(),
+            namespace
+                .parse::<models::namespace::identifier::Name>()
+                .unwrap(),
+        );
+
+        File::new(
+            Identifier::new(namespace.clone(), file::Name::new(name)),
+            NonEmpty::new(models::sample::Identifier::new(namespace, "Sample1")),
+            None,
+            None,
+        )
+    }
 
     #[test]
     fn it_generates_a_random_namespace() {
         random_namespace();
     }
+
+    /// Generates every store from `seed` and serializes the files (which
+    /// reference the samples, which in turn reference the subjects).
+    fn seeded_files(seed: u64) -> String {
+        use rand::rngs::StdRng;
+
+        let mut rng = StdRng::seed_from_u64(seed);
+
+        let subjects = subject::Store::random_with_rng(50, 0.25, &mut rng);
+        let samples =
+            sample::Store::random_with_rng(50, subjects.subjects.lock().unwrap(), 0.25, &mut rng);
+        let files = Store::random_with_rng(50, samples.samples.lock().unwrap(), 0.25, &mut rng);
+
+        let files = files.files.lock().unwrap();
+        serde_json::to_string(&*files).unwrap()
+    }
+
+    #[test]
+    fn it_generates_identical_stores_from_the_same_seed() {
+        assert_eq!(seeded_files(42), seeded_files(42));
+        assert_ne!(seeded_files(42), seeded_files(43));
+    }
+
+    #[test]
+    fn it_rejects_duplicate_names_within_a_namespace() {
+        let err = Store::try_new(vec![
+            named_file("ExampleNamespaceOne", "File1.txt"),
+            named_file("ExampleNamespaceOne", "File1.txt"),
+        ])
+        .unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            "duplicate file identifiers: { namespace: { organization: \
+             example-organization, name: ExampleNamespaceOne }, name: File1.txt }"
+        );
+    }
+
+    #[test]
+    fn it_reports_every_duplicate_name() {
+        let err = Store::try_new(vec![
+            named_file("ExampleNamespaceTwo", "File1.txt"),
+            named_file("ExampleNamespaceOne", "File1.txt"),
+            named_file("ExampleNamespaceOne", "File2.txt"),
+            named_file("ExampleNamespaceTwo", "File1.txt"),
+            named_file("ExampleNamespaceOne", "File2.txt"),
+            named_file("ExampleNamespaceOne", "File2.txt"),
+        ])
+        .unwrap_err();
+
+        let Error::DuplicateIdentifiers(identifiers) = &err else {
+            panic!("expected duplicate identifiers, found: {err}");
+        };
+
+        assert_eq!(
+            identifiers
+                .iter()
+                .map(|identifier| format!(
+                    "{}/{}",
+                    identifier.namespace().name().as_str(),
+                    identifier.name().as_str()
+                ))
+                .collect::<Vec<_>>(),
+            [
+                "ExampleNamespaceOne/File2.txt",
+                "ExampleNamespaceTwo/File1.txt"
+            ]
+        );
+    }
+
+    #[actix_web::test]
+    async fn it_responds_with_a_server_error_when_a_name_is_duplicated() {
+        // NOTE: the store is constructed directly, as [`Store::try_new()`]
+        // rejects duplicate names.
+        let store = Data::new(Store {
+            files: Mutex::new(vec![
+                named_file("ExampleNamespaceOne", "File1.txt"),
+                named_file("ExampleNamespaceOne", "File1.txt"),
+            ]),
+        });
+
+        let app = init_service(App::new().app_data(store).service(file_show)).await;
+
+        let request = TestRequest::get()
+            .uri("/file/example-organization/ExampleNamespaceOne/File1.txt")
+            .to_request();
+        let response = call_service(&app, request).await;
+        assert_eq!(response.status(), StatusCode::INTERNAL_SERVER_ERROR);
+
+        let body: Value = serde_json::from_slice(&read_body(response).await).unwrap();
+        assert_eq!(body["errors"][0]["kind"], "DuplicateIdentifier");
+        assert_eq!(
+            body["errors"][0]["message"],
+            "File with namespace 'ExampleNamespaceOne' and name 'File1.txt' is not \
+             unique: 2 entities share the identifier."
+        );
+    }
+
+    #[test]
+    fn it_rejects_unknown_namespaces() {
+        let err = Store::try_new(vec![
+            named_file("ExampleNamespaceOne", "File1.txt"),
+            named_file("ExampleNamespaceone", "File2.txt"),
+        ])
+        .unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            "unknown namespace: { organization: example-organization, name: \
+             ExampleNamespaceone }"
+        );
+    }
+
+    #[test]
+    fn it_allows_the_same_name_in_different_namespaces() {
+        Store::try_new(vec![
+            named_file("ExampleNamespaceOne", "File1.txt"),
+            named_file("ExampleNamespaceTwo", "File1.txt"),
+        ])
+        .unwrap();
+    }
+
+    #[test]
+    fn it_retries_when_a_generated_name_is_taken() {
+        let namespace = named_file("ExampleNamespaceOne", "File1.txt")
+            .id()
+            .namespace()
+            .clone();
+
+        let mut taken = BTreeSet::new();
+        let mut names = vec!["File2.txt", "File1.txt", "File1.txt"];
+
+        let first = unique_identifier(&namespace, &mut taken, || names.pop().unwrap().into());
+        let second = unique_identifier(&namespace, &mut taken, || names.pop().unwrap().into());
+
+        assert_eq!(first.name().as_str(), "File1.txt");
+        assert_eq!(second.name().as_str(), "File2.txt");
+        assert!(names.is_empty());
+    }
+
+    #[actix_web::test]
+    async fn it_looks_up_a_file_by_name() {
+        let store = Data::new(
+            Store::try_new(vec![
+                named_file("ExampleNamespaceOne", "File1.txt"),
+                named_file("ExampleNamespaceTwo", "File1.txt"),
+            ])
+            .unwrap(),
+        );
+
+        let app = init_service(App::new().app_data(store).service(file_lookup)).await;
+
+        let req = TestRequest::get()
+            .uri("/file/lookup?organization=example-organization&namespace=ExampleNamespaceTwo&name=File1.txt")
+            .to_request();
+        let file: File = call_and_read_body_json(&app, req).await;
+        assert_eq!(file.id().namespace().name().as_str(), "ExampleNamespaceTwo");
+        assert_eq!(file.id().name().as_str(), "File1.txt");
+
+        let req = TestRequest::get()
+            .uri("/file/lookup?organization=example-organization&namespace=ExampleNamespaceTwo&name=File2.txt")
+            .to_request();
+        let res = call_service(&app, req).await;
+        assert_eq!(res.status(), StatusCode::NOT_FOUND);
+    }
+
+    #[actix_web::test]
+    async fn it_filters_case_insensitively_when_requested() {
+        let store = Data::new(
+            Store::try_new(vec![
+                named_file("ExampleNamespaceOne", "File1.txt"),
+                named_file("ExampleNamespaceOne", "File2.txt"),
+            ])
+            .unwrap(),
+        );
+
+        let app = init_service(App::new().app_data(store).service(file_index)).await;
+
+        for (uri, expected) in [
+            ("/file?name=file1.txt", 0),
+            ("/file?name=file1.txt&case_insensitive=false", 0),
+            ("/file?name=file1.txt&case_insensitive=true", 1),
+            ("/file?name=not:FILE1.TXT&case_insensitive=true", 1),
+        ] {
+            let req = TestRequest::get().uri(uri).to_request();
+            let response: Value = call_and_read_body_json(&app, req).await;
+
+            // NOTE: an empty page is returned as an empty array.
+            assert_eq!(
+                response
+                    .get("data")
+                    .unwrap_or(&response)
+                    .as_array()
+                    .unwrap()
+                    .len(),
+                expected,
+                "{uri}"
+            );
+        }
+    }
+
+    #[actix_web::test]
+    async fn it_filters_by_checksum() {
+        let named = named_file("ExampleNamespaceOne", "File1.txt");
+        let md5 = file::checksum::MD5::try_new("D41D8CD98F00B204E9800998ECF8427E").unwrap();
+        let checksummed = File::new(
+            named.id().clone(),
+            named.samples().clone(),
+            None,
+            Some(
+                models::file::metadata::Builder::default()
+                    .checksums(models::metadata::field::unowned::file::Checksums::new(
+                        models::file::metadata::Checksums::new(Some(md5)),
+                        None,
+                        None,
+                        None,
+                    ))
+                    .build(),
+            ),
+        );
+
+        let store = Data::new(
+            Store::try_new(vec![
+                checksummed,
+                named_file("ExampleNamespaceOne", "File2.txt"),
+            ])
+            .unwrap(),
+        );
+
+        let app = init_service(App::new().app_data(store).service(file_index)).await;
+
+        for (uri, expected) in [
+            ("/file?checksums=D41D8CD98F00B204E9800998ECF8427E", 1),
+            ("/file?checksums=d41d8cd98f00b204e9800998ecf8427e", 1),
+            ("/file?checksums=md5:d41d8cd98f00b204e9800998ecf8427e", 1),
+            ("/file?checksums=MD5:D41D8CD98F00B204E9800998ECF8427E", 1),
+            ("/file?checksums=sha1:d41d8cd98f00b204e9800998ecf8427e", 0),
+            ("/file?checksums=md5:d41d8cd98f00b204e9800998ecf8427f", 0),
+            (
+                "/file?checksums=not:md5:d41d8cd98f00b204e9800998ecf8427e",
+                1,
+            ),
+            // `["sha1:0000","md5:d41d8cd98f00b204e9800998ecf8427e"]`
+            (
+                "/file?checksums=%5B%22sha1%3A0000%22%2C\
+                 %22md5%3Ad41d8cd98f00b204e9800998ecf8427e%22%5D",
+                1,
+            ),
+            // `["sha1:0000","d41d8cd98f00b204e9800998ecf8427f"]`
+            (
+                "/file?checksums=%5B%22sha1%3A0000%22%2C%22d41d8cd98f00b204e9800998ecf8427f%22%5D",
+                0,
+            ),
+            // `{"MD5":"D41D8CD98F00B204E9800998ECF8427E"}`
+            (
+                "/file?checksums=%7B%22MD5%22%3A%22D41D8CD98F00B204E9800998ECF8427E%22%7D",
+                1,
+            ),
+            // `{"md5":"d41d8cd98f00b204e9800998ecf8427e","sha256":"0000"}`
+            //
+            // The file has a matching md5 checksum but no sha256 checksum, so it
+            // does not match every algorithm.
+            (
+                "/file?checksums=%7B%22md5%22%3A%22d41d8cd98f00b204e9800998ecf8427e%22%2C\
+                 %22sha256%22%3A%220000%22%7D",
+                0,
+            ),
+            // `not:{"md5":"d41d8cd98f00b204e9800998ecf8427e"}`
+            (
+                "/file?checksums=not%3A%7B%22md5%22%3A%22d41d8cd98f00b204e9800998ecf8427e%22%7D",
+                1,
+            ),
+        ] {
+            let req = TestRequest::get().uri(uri).to_request();
+            let response: Value = call_and_read_body_json(&app, req).await;
+
+            // NOTE: an empty page is returned as an empty array.
+            assert_eq!(
+                response
+                    .get("data")
+                    .unwrap_or(&response)
+                    .as_array()
+                    .unwrap()
+                    .len(),
+                expected,
+                "{uri}"
+            );
+        }
+
+        for query in [
+            // `{}`
+            "%7B%7D",
+            // `{"crc32":"d41d8cd98f00b204e9800998ecf8427e"}`
+            "%7B%22crc32%22%3A%22d41d8cd98f00b204e9800998ecf8427e%22%7D",
+            // `{"md5":42}`
+            "%7B%22md5%22%3A42%7D",
+            // `[{"md5":"d41d8cd98f00b204e9800998ecf8427e"}]`
+            "%5B%7B%22md5%22%3A%22d41d8cd98f00b204e9800998ecf8427e%22%7D%5D",
+        ] {
+            let req = TestRequest::get()
+                .uri(&format!("/file?checksums={query}"))
+                .to_request();
+            let res = call_service(&app, req).await;
+            assert_eq!(res.status(), StatusCode::UNPROCESSABLE_ENTITY, "{query}");
+
+            let response: Value = serde_json::from_slice(&read_body(res).await).unwrap();
+            assert_eq!(response["errors"][0]["kind"], "InvalidParameters");
+            assert_eq!(
+                response["errors"][0]["parameters"],
+                serde_json::json!(["checksums"])
+            );
+        }
+    }
+
+    #[actix_web::test]
+    async fn it_produces_a_download_manifest() {
+        let named = named_file("ExampleNamespaceOne", "File2.txt");
+        let described = File::new(
+            named.id().clone(),
+            named.samples().clone(),
+            None,
+            Some(
+                models::file::metadata::Builder::default()
+                    .description(Description::new(
+                        file::Description::new("Line one\nColumn\ttwo"),
+                        None,
+                        None,
+                        None,
+                    ))
+                    .build(),
+            ),
+        );
+
+        let store = Data::new(
+            Store::try_new(vec![
+                described,
+                named_file("ExampleNamespaceOne", "File1.txt"),
+            ])
+            .unwrap(),
+        );
+
+        let app = init_service(App::new().app_data(store).service(file_download_manifest)).await;
+
+        // JSON is returned by default.
+        let req = TestRequest::get()
+            .uri("/file/download-manifest")
+            .to_request();
+        let response: Value = call_and_read_body_json(&app, req).await;
+        let names = response["data"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|entry| entry["id"]["name"].as_str().unwrap())
+            .collect::<Vec<_>>();
+        assert_eq!(names, vec!["File1.txt", "File2.txt"]);
+
+        // The format can be negotiated with the `Accept` header.
+        let req = TestRequest::get()
+            .uri("/file/download-manifest?name=File2.txt")
+            .insert_header((header::ACCEPT, "text/tab-separated-values"))
+            .to_request();
+        let res = call_service(&app, req).await;
+        assert_eq!(res.status(), StatusCode::OK);
+        assert_eq!(
+            res.headers().get(header::CONTENT_TYPE).unwrap(),
+            "text/tab-separated-values; charset=utf-8"
+        );
+
+        let body = String::from_utf8(read_body(res).await.to_vec()).unwrap();
+        let rows = body.lines().collect::<Vec<_>>();
+        assert_eq!(rows.len(), 2);
+        assert_eq!(rows[0], responses::manifest::TSV_COLUMNS.join("\t"));
+        assert_eq!(
+            rows[1],
+            "example-organization\tExampleNamespaceOne\tFile2.txt\t\t\t\t\
+             Line one\\nColumn\\ttwo\t\t\t"
+        );
+
+        // The `format` parameter takes precedence over the `Accept` header.
+        let req = TestRequest::get()
+            .uri("/file/download-manifest?format=json")
+            .insert_header((header::ACCEPT, "text/tab-separated-values"))
+            .to_request();
+        let response: Value = call_and_read_body_json(&app, req).await;
+        assert_eq!(response["data"].as_array().unwrap().len(), 2);
+    }
+
+    fn sized_file(name: &str, r#type: Option<file::Type>, size: usize) -> File {
+        let named = named_file("ExampleNamespaceOne", name);
+        let mut builder = models::file::metadata::Builder::default().size(
+            models::metadata::field::unowned::file::Size::new(
+                file::Size::new(size),
+                None,
+                None,
+                None,
+            ),
+        );
+
+        if let Some(r#type) = r#type {
+            builder = builder.r#type(models::metadata::field::unowned::file::Type::new(
+                r#type, None, None, None,
+            ));
+        }
+
+        File::new(
+            named.id().clone(),
+            named.samples().clone(),
+            None,
+            Some(builder.build()),
+        )
+    }
+
+    fn sized_files() -> Store {
+        Store::try_new(vec![
+            sized_file("File1.bam", Some(file::Type::BAM), 2 << 30),
+            sized_file("File2.bam", Some(file::Type::BAM), 512),
+            sized_file("File3.vcf", Some(file::Type::VCF), 4 << 20),
+            sized_file("File4.txt", None, 1024),
+            named_file("ExampleNamespaceOne", "File5.txt"),
+        ])
+        .unwrap()
+    }
+
+    #[actix_web::test]
+    async fn it_counts_files_and_bytes_by_type() {
+        let app = init_service(
+            App::new()
+                .app_data(Data::new(sized_files()))
+                .service(files_by_count),
+        )
+        .await;
+
+        let req = TestRequest::get().uri("/file/by/type/count").to_request();
+        let response: Value = call_and_read_body_json(&app, req).await;
+
+        assert_eq!(response["total"], 5);
+        assert_eq!(response["missing"], 2);
+        assert_eq!(response["bytes"], (2u64 << 30) + 512 + (4 << 20) + 1024);
+        assert_eq!(
+            response["values"],
+            serde_json::json!([
+                { "value": "BAM", "count": 2, "bytes": (2u64 << 30) + 512 },
+                { "value": "VCF", "count": 1, "bytes": 4 << 20 },
+            ])
+        );
+    }
+
+    #[actix_web::test]
+    async fn it_distinguishes_missing_metadata_from_null_values() {
+        let app = init_service(
+            App::new()
+                .app_data(Data::new(sized_files()))
+                .service(files_by_count),
+        )
+        .await;
+
+        // NOTE: `File4.txt` has metadata without a type, and `File5.txt` has no
+        // metadata at all.
+        let req = TestRequest::get()
+            .uri("/file/by/type/count?distinguish_missing=true")
+            .to_request();
+        let response: Value = call_and_read_body_json(&app, req).await;
+
+        assert_eq!(response["total"], 5);
+        assert_eq!(response["missing"], 1);
+        assert_eq!(response["null"], 1);
+        assert_eq!(response["bytes"], (2u64 << 30) + 512 + (4 << 20) + 1024);
+        assert_eq!(response["values"].as_array().unwrap().len(), 2);
+    }
+
+    #[actix_web::test]
+    async fn it_counts_files_by_size_buckets() {
+        let app = init_service(
+            App::new()
+                .app_data(Data::new(sized_files()))
+                .service(files_by_count),
+        )
+        .await;
+
+        // The default buckets are used when none are provided.
+        let req = TestRequest::get().uri("/file/by/size/count").to_request();
+        let response: Value = call_and_read_body_json(&app, req).await;
+
+        assert_eq!(response["total"], 5);
+        assert_eq!(response["missing"], 1);
+        assert_eq!(
+            response["values"],
+            serde_json::json!([
+                {
+                    "value": { "min": 1u64 << 30, "max": 100u64 << 30 },
+                    "count": 1,
+                    "bytes": 2u64 << 30,
+                },
+                {
+                    "value": { "min": 0, "max": 1 << 20 },
+                    "count": 2,
+                    "bytes": 1536,
+                },
+                {
+                    "value": { "min": 1 << 20, "max": 1u64 << 30 },
+                    "count": 1,
+                    "bytes": 4 << 20,
+                },
+            ])
+        );
+
+        // Custom buckets include their lower boundary and exclude their upper
+        // boundary.
+        let req = TestRequest::get()
+            .uri("/file/by/size/count?buckets=1024,1048576")
+            .to_request();
+        let response: Value = call_and_read_body_json(&app, req).await;
+
+        let buckets = response["values"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|value| (value["value"].clone(), value["count"].as_u64().unwrap()))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            buckets,
+            vec![
+                (serde_json::json!({ "min": 1048576, "max": null }), 2),
+                (serde_json::json!({ "min": 0, "max": 1024 }), 1),
+                (serde_json::json!({ "min": 1024, "max": 1048576 }), 1),
+            ]
+        );
+    }
+
+    #[actix_web::test]
+    async fn it_rejects_invalid_counts() {
+        let app = init_service(
+            App::new()
+                .app_data(Data::new(sized_files()))
+                .service(files_by_count),
+        )
+        .await;
+
+        let req = TestRequest::get()
+            .uri("/file/by/size/count?buckets=1024,512")
+            .to_request();
+        let res = call_service(&app, req).await;
+        assert_eq!(res.status(), StatusCode::UNPROCESSABLE_ENTITY);
+        let response: Value = serde_json::from_slice(&read_body(res).await).unwrap();
+        assert_eq!(response["errors"][0]["kind"], "InvalidParameters");
+        assert_eq!(
+            response["errors"][0]["parameters"],
+            serde_json::json!(["buckets"])
+        );
+
+        let req = TestRequest::get()
+            .uri("/file/by/handedness/count")
+            .to_request();
+        let res = call_service(&app, req).await;
+        assert_eq!(res.status(), StatusCode::UNPROCESSABLE_ENTITY);
+        let response: Value = serde_json::from_slice(&read_body(res).await).unwrap();
+        assert_eq!(response["errors"][0]["kind"], "UnsupportedField");
+        assert_eq!(response["errors"][0]["field"], "handedness");
+    }
 }
@@ -5,12 +5,13 @@ use actix_web::web::ServiceConfig;
 use actix_web::HttpResponse;
 use actix_web::Responder;
 
+use crate::responses::info::Versions;
 use crate::responses::Information;
 
 /// Configures the [`ServiceConfig`] with the info paths.
 pub fn configure() -> impl FnOnce(&mut ServiceConfig) {
     |config: &mut ServiceConfig| {
-        config.service(info_index);
+        config.service(info_index).service(info_versions);
     }
 }
 
@@ -31,3 +32,22 @@ pub fn configure() -> impl FnOnce(&mut ServiceConfig) {
 pub async fn info_index() -> impl Responder {
     HttpResponse::Ok().json(Information::default())
 }
+
+/// Gets the versions of the specification and the models implemented by this
+/// server along with the filtering capabilities that it supports.
+#[utoipa::path(
+    get,
+    path = "/info/versions",
+    tag = "Info",
+    responses(
+        (
+            status = 200,
+            description = "Successful operation.",
+            body = responses::info::Versions,
+        ),
+    )
+)]
+#[get("/info/versions")]
+pub async fn info_versions() -> impl Responder {
+    HttpResponse::Ok().json(Versions::default())
+}
@@ -1,13 +1,77 @@
 //! Routes related to metadata.
 
 use actix_web::get;
+use actix_web::http::header;
+use actix_web::web::Data;
+use actix_web::web::Path;
+use actix_web::web::Query;
 use actix_web::web::ServiceConfig;
+use actix_web::HttpRequest;
 use actix_web::HttpResponse;
 use actix_web::Responder;
 
+use ccdi_cde as cde;
 use ccdi_models as models;
 
+use cde::translations::Translations;
+use models::metadata::field::description::UnharmonizedRegistry;
+use models::metadata::field::Description;
+
+use crate::filter::parameter_names;
+use crate::params::labels;
+use crate::params::manifest::Format;
+use crate::params::LabelParams;
+use crate::params::TabularParams;
+use crate::responses::error;
+use crate::responses::metadata::FieldDescription;
 use crate::responses::metadata::FieldDescriptions;
+use crate::responses::Errors;
+use crate::strict;
+
+/// The unharmonized fields registered by the server for each entity.
+///
+/// Registering unharmonized fields is optional: when an
+/// [`UnharmonizedRegistries`] is provided as application data, the registered
+/// fields are listed within the `unharmonized` section of the
+/// `/metadata/fields/<entity>` responses, and filtering on an unharmonized
+/// field that was not registered is logged as a warning.
+#[derive(Clone, Debug, Default)]
+pub struct UnharmonizedRegistries {
+    /// The unharmonized fields registered for subjects.
+    pub subject: UnharmonizedRegistry,
+
+    /// The unharmonized fields registered for samples.
+    pub sample: UnharmonizedRegistry,
+
+    /// The unharmonized fields registered for files.
+    pub file: UnharmonizedRegistry,
+}
+
+impl UnharmonizedRegistries {
+    /// Gets the [`UnharmonizedRegistry`] for an `entity` (if the entity can
+    /// have unharmonized fields).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ccdi_server as server;
+    ///
+    /// use server::routes::metadata::UnharmonizedRegistries;
+    ///
+    /// let registries = UnharmonizedRegistries::default();
+    ///
+    /// assert!(registries.get("sample").is_some());
+    /// assert!(registries.get("organization").is_none());
+    /// ```
+    pub fn get(&self, entity: &str) -> Option<&UnharmonizedRegistry> {
+        match entity {
+            "subject" => Some(&self.subject),
+            "sample" => Some(&self.sample),
+            "file" => Some(&self.file),
+            _ => None,
+        }
+    }
+}
 
 /// Configures the [`ServiceConfig`] with the metadata paths.
 pub fn configure() -> impl FnOnce(&mut ServiceConfig) {
@@ -17,85 +81,665 @@ pub fn configure() -> impl FnOnce(&mut ServiceConfig) {
         config.service(metadata_fields_file);
         config.service(metadata_fields_namespace);
         config.service(metadata_fields_organization);
+        config.service(metadata_field_show);
     }
 }
 
 /// Gets the metadata fields for subjects that are supported by this server.
+///
+/// The fields are returned as JSON (`application/json`) or flattened into
+/// tab-separated values (`text/tab-separated-values`) with one row per field.
+/// The format is selected with the `format` query parameter or, when it is not
+/// provided, by the `Accept` header of the request (defaulting to JSON).
 #[utoipa::path(
     get,
     path = "/metadata/fields/subject",
+    params(LabelParams, TabularParams),
     tag = "Metadata",
     responses(
-        (status = 200, description = "Successful operation.", body = responses::metadata::FieldDescriptions)
+        (
+            status = 200,
+            description = "Successful operation.",
+            content(
+                ("application/json" = responses::metadata::FieldDescriptions),
+                ("text/tab-separated-values" = String)
+            )
+        ),
+        (
+            status = 422,
+            description = "Invalid query parameters.",
+            body = responses::Errors,
+            example = json!(Errors::from(error::Kind::invalid_parameters(
+                Some(vec![String::from("foo")]),
+                String::from("unrecognized parameter(s): `foo`.")
+            )))
+        )
     )
 )]
 #[get("/metadata/fields/subject")]
-pub async fn metadata_fields_subject() -> impl Responder {
-    HttpResponse::Ok().json(FieldDescriptions::from(
+pub async fn metadata_fields_subject(
+    params: Query<LabelParams>,
+    tabular_params: Query<TabularParams>,
+    request: HttpRequest,
+    translations: Option<Data<Translations>>,
+    registries: Option<Data<UnharmonizedRegistries>>,
+    strict_config: Option<Data<strict::Config>>,
+) -> impl Responder {
+    respond(
         models::metadata::field::description::harmonized::subject::get_field_descriptions(),
-    ))
+        registries.as_deref().map(|registries| &registries.subject),
+        params.0,
+        tabular_params.0,
+        &request,
+        translations,
+        strict_config.as_ref().map(|config| config.get_ref()),
+    )
 }
 
 /// Gets the metadata fields for samples that are supported by this server.
+///
+/// The fields are returned as JSON (`application/json`) or flattened into
+/// tab-separated values (`text/tab-separated-values`) with one row per field.
+/// The format is selected with the `format` query parameter or, when it is not
+/// provided, by the `Accept` header of the request (defaulting to JSON).
 #[utoipa::path(
     get,
     path = "/metadata/fields/sample",
+    params(LabelParams, TabularParams),
     tag = "Metadata",
     responses(
-        (status = 200, description = "Successful operation.", body = responses::metadata::FieldDescriptions)
+        (
+            status = 200,
+            description = "Successful operation.",
+            content(
+                ("application/json" = responses::metadata::FieldDescriptions),
+                ("text/tab-separated-values" = String)
+            )
+        ),
+        (
+            status = 422,
+            description = "Invalid query parameters.",
+            body = responses::Errors,
+            example = json!(Errors::from(error::Kind::invalid_parameters(
+                Some(vec![String::from("foo")]),
+                String::from("unrecognized parameter(s): `foo`.")
+            )))
+        )
     )
 )]
 #[get("/metadata/fields/sample")]
-pub async fn metadata_fields_sample() -> impl Responder {
-    HttpResponse::Ok().json(FieldDescriptions::from(
+pub async fn metadata_fields_sample(
+    params: Query<LabelParams>,
+    tabular_params: Query<TabularParams>,
+    request: HttpRequest,
+    translations: Option<Data<Translations>>,
+    registries: Option<Data<UnharmonizedRegistries>>,
+    strict_config: Option<Data<strict::Config>>,
+) -> impl Responder {
+    respond(
         models::metadata::field::description::harmonized::sample::get_field_descriptions(),
-    ))
+        registries.as_deref().map(|registries| &registries.sample),
+        params.0,
+        tabular_params.0,
+        &request,
+        translations,
+        strict_config.as_ref().map(|config| config.get_ref()),
+    )
 }
 
 /// Gets the metadata fields for files that are supported by this server.
+///
+/// The fields are returned as JSON (`application/json`) or flattened into
+/// tab-separated values (`text/tab-separated-values`) with one row per field.
+/// The format is selected with the `format` query parameter or, when it is not
+/// provided, by the `Accept` header of the request (defaulting to JSON).
 #[utoipa::path(
     get,
     path = "/metadata/fields/file",
+    params(LabelParams, TabularParams),
     tag = "Metadata",
     responses(
-        (status = 200, description = "Successful operation.", body = responses::metadata::FieldDescriptions)
+        (
+            status = 200,
+            description = "Successful operation.",
+            content(
+                ("application/json" = responses::metadata::FieldDescriptions),
+                ("text/tab-separated-values" = String)
+            )
+        ),
+        (
+            status = 422,
+            description = "Invalid query parameters.",
+            body = responses::Errors,
+            example = json!(Errors::from(error::Kind::invalid_parameters(
+                Some(vec![String::from("foo")]),
+                String::from("unrecognized parameter(s): `foo`.")
+            )))
+        )
     )
 )]
 #[get("/metadata/fields/file")]
-pub async fn metadata_fields_file() -> impl Responder {
-    HttpResponse::Ok().json(FieldDescriptions::from(
+pub async fn metadata_fields_file(
+    params: Query<LabelParams>,
+    tabular_params: Query<TabularParams>,
+    request: HttpRequest,
+    translations: Option<Data<Translations>>,
+    registries: Option<Data<UnharmonizedRegistries>>,
+    strict_config: Option<Data<strict::Config>>,
+) -> impl Responder {
+    respond(
         models::metadata::field::description::harmonized::file::get_field_descriptions(),
-    ))
+        registries.as_deref().map(|registries| &registries.file),
+        params.0,
+        tabular_params.0,
+        &request,
+        translations,
+        strict_config.as_ref().map(|config| config.get_ref()),
+    )
 }
 
 /// Gets the metadata fields for namespaces that are supported by this server.
+///
+/// The fields are returned as JSON (`application/json`) or flattened into
+/// tab-separated values (`text/tab-separated-values`) with one row per field.
+/// The format is selected with the `format` query parameter or, when it is not
+/// provided, by the `Accept` header of the request (defaulting to JSON).
 #[utoipa::path(
     get,
     path = "/metadata/fields/namespace",
+    params(LabelParams, TabularParams),
     tag = "Metadata",
     responses(
-        (status = 200, description = "Successful operation.", body = responses::metadata::FieldDescriptions)
+        (
+            status = 200,
+            description = "Successful operation.",
+            content(
+                ("application/json" = responses::metadata::FieldDescriptions),
+                ("text/tab-separated-values" = String)
+            )
+        ),
+        (
+            status = 422,
+            description = "Invalid query parameters.",
+            body = responses::Errors,
+            example = json!(Errors::from(error::Kind::invalid_parameters(
+                Some(vec![String::from("foo")]),
+                String::from("unrecognized parameter(s): `foo`.")
+            )))
+        )
     )
 )]
 #[get("/metadata/fields/namespace")]
-pub async fn metadata_fields_namespace() -> impl Responder {
-    HttpResponse::Ok().json(FieldDescriptions::from(
+pub async fn metadata_fields_namespace(
+    params: Query<LabelParams>,
+    tabular_params: Query<TabularParams>,
+    request: HttpRequest,
+    translations: Option<Data<Translations>>,
+    strict_config: Option<Data<strict::Config>>,
+) -> impl Responder {
+    respond(
         models::metadata::field::description::harmonized::namespace::get_field_descriptions(),
-    ))
+        None,
+        params.0,
+        tabular_params.0,
+        &request,
+        translations,
+        strict_config.as_ref().map(|config| config.get_ref()),
+    )
 }
 
 /// Gets the metadata fields for organizations that are supported by this server.
+///
+/// The fields are returned as JSON (`application/json`) or flattened into
+/// tab-separated values (`text/tab-separated-values`) with one row per field.
+/// The format is selected with the `format` query parameter or, when it is not
+/// provided, by the `Accept` header of the request (defaulting to JSON).
 #[utoipa::path(
     get,
     path = "/metadata/fields/organization",
+    params(LabelParams, TabularParams),
     tag = "Metadata",
     responses(
-        (status = 200, description = "Successful operation.", body = responses::metadata::FieldDescriptions)
+        (
+            status = 200,
+            description = "Successful operation.",
+            content(
+                ("application/json" = responses::metadata::FieldDescriptions),
+                ("text/tab-separated-values" = String)
+            )
+        ),
+        (
+            status = 422,
+            description = "Invalid query parameters.",
+            body = responses::Errors,
+            example = json!(Errors::from(error::Kind::invalid_parameters(
+                Some(vec![String::from("foo")]),
+                String::from("unrecognized parameter(s): `foo`.")
+            )))
+        )
     )
 )]
 #[get("/metadata/fields/organization")]
-pub async fn metadata_fields_organization() -> impl Responder {
-    HttpResponse::Ok().json(FieldDescriptions::from(
+pub async fn metadata_fields_organization(
+    params: Query<LabelParams>,
+    tabular_params: Query<TabularParams>,
+    request: HttpRequest,
+    translations: Option<Data<Translations>>,
+    strict_config: Option<Data<strict::Config>>,
+) -> impl Responder {
+    respond(
         models::metadata::field::description::harmonized::organization::get_field_descriptions(),
-    ))
+        None,
+        params.0,
+        tabular_params.0,
+        &request,
+        translations,
+        strict_config.as_ref().map(|config| config.get_ref()),
+    )
+}
+
+/// Gets a single metadata field alongside the provenance of its CDE (if any).
+///
+/// Fields that are backed by a common data element include the name of and a
+/// link to the common data element. When the common data element is an
+/// enumeration, each permissible value is included alongside its value
+/// meaning, public identifier, and concept code. Fields that are not backed by
+/// a common data element are returned with a `null` `cde` block.
+///
+/// Unharmonized fields that have been registered by the server can be
+/// described by their path (e.g., `unharmonized.consortium_id`).
+#[utoipa::path(
+    get,
+    path = "/metadata/fields/{entity}/{field_name}",
+    params(
+        (
+            "entity" = String,
+            description = "The entity to which the field belongs (`subject`, \
+            `sample`, `file`, `namespace`, or `organization`).",
+        ),
+        (
+            "field_name" = String,
+            description = "The path of the field within the `metadata` block of \
+            the entity (e.g., `sex` or `unharmonized.consortium_id`).",
+        ),
+    ),
+    tag = "Metadata",
+    responses(
+        (status = 200, description = "Successful operation.", body = responses::metadata::FieldDescription),
+        (
+            status = 404,
+            description = "Not found.",
+            body = responses::Errors,
+            example = json!(Errors::from(error::Kind::not_found(String::from(
+                "Metadata field 'foo' for subjects"
+            ))))
+        )
+    )
+)]
+#[get("/metadata/fields/{entity}/{field_name}")]
+pub async fn metadata_field_show(
+    path: Path<(String, String)>,
+    registries: Option<Data<UnharmonizedRegistries>>,
+) -> impl Responder {
+    let (entity, field_name) = path.into_inner();
+
+    let descriptions = match entity.as_str() {
+        "subject" => {
+            models::metadata::field::description::harmonized::subject::get_field_descriptions()
+        }
+        "sample" => {
+            models::metadata::field::description::harmonized::sample::get_field_descriptions()
+        }
+        "file" => models::metadata::field::description::harmonized::file::get_field_descriptions(),
+        "namespace" => {
+            models::metadata::field::description::harmonized::namespace::get_field_descriptions()
+        }
+        "organization" => {
+            models::metadata::field::description::harmonized::organization::get_field_descriptions()
+        }
+        _ => {
+            return HttpResponse::NotFound().json(Errors::from(error::Kind::not_found(format!(
+                "Metadata fields for entity '{entity}'"
+            ))))
+        }
+    };
+
+    let registered = registries
+        .as_deref()
+        .and_then(|registries| registries.get(&entity))
+        .and_then(|registry| {
+            registry
+                .descriptions()
+                .find(|unharmonized| *unharmonized.path() == field_name)
+        })
+        .cloned()
+        .map(Description::Unharmonized);
+
+    descriptions
+        .into_iter()
+        .find(|description| match description {
+            Description::Harmonized(harmonized) => harmonized.path() == field_name,
+            Description::Unharmonized(_) => false,
+        })
+        .or(registered)
+        .map(|description| HttpResponse::Ok().json(FieldDescription::from(description)))
+        .unwrap_or_else(|| {
+            HttpResponse::NotFound().json(Errors::from(error::Kind::not_found(format!(
+                "Metadata field '{field_name}' for {entity}s"
+            ))))
+        })
+}
+
+/// Responds with the field descriptions (alongside the unharmonized fields
+/// within the `registry`, if any), labeling the permissible values of each
+/// harmonized field if requested.
+///
+/// If no [`Translations`] were registered with the server, the translations
+/// embedded within [`ccdi_cde`] are used.
+///
+/// The descriptions are rendered as tab-separated values when requested (see
+/// [`FieldDescriptions::to_tsv()`]).
+fn respond(
+    mut descriptions: Vec<Description>,
+    registry: Option<&UnharmonizedRegistry>,
+    params: LabelParams,
+    tabular_params: TabularParams,
+    request: &HttpRequest,
+    translations: Option<Data<Translations>>,
+    strict_config: Option<&strict::Config>,
+) -> HttpResponse {
+    if let Err(err) = strict::check(
+        request.query_string(),
+        &[
+            parameter_names::<LabelParams>(),
+            parameter_names::<TabularParams>(),
+        ]
+        .concat(),
+        strict_config,
+    ) {
+        return HttpResponse::UnprocessableEntity().json(Errors::from(err));
+    }
+
+    if let Some(labels::Mode::Localized) = params.labels() {
+        let locales = request
+            .headers()
+            .get(header::ACCEPT_LANGUAGE)
+            .and_then(|value| value.to_str().ok())
+            .map(labels::parse_accept_language)
+            .unwrap_or_default();
+        let locales = locales
+            .iter()
+            .map(|locale| locale.as_str())
+            .collect::<Vec<_>>();
+
+        let translations = translations
+            .as_ref()
+            .map(|translations| translations.get_ref())
+            .unwrap_or_else(|| Translations::embedded());
+
+        for description in descriptions.iter_mut() {
+            if let Description::Harmonized(description) = description {
+                description.localize(&locales, translations);
+            }
+        }
+    }
+
+    let unharmonized = registry
+        .map(|registry| registry.descriptions().cloned().collect::<Vec<_>>())
+        .unwrap_or_default();
+
+    let descriptions = FieldDescriptions::from((descriptions, unharmonized));
+
+    let format = tabular_params
+        .format()
+        .or_else(|| {
+            request
+                .headers()
+                .get(header::ACCEPT)
+                .and_then(|value| value.to_str().ok())
+                .and_then(Format::from_accept)
+        })
+        .unwrap_or(Format::Json);
+
+    match format {
+        Format::Json => HttpResponse::Ok().json(descriptions),
+        Format::Tsv => HttpResponse::Ok()
+            .insert_header(header::ContentType(mime::TEXT_TAB_SEPARATED_VALUES_UTF_8))
+            .body(descriptions.to_tsv()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use actix_web::http::StatusCode;
+    use actix_web::test::call_and_read_body_json;
+    use actix_web::test::call_service;
+    use actix_web::test::init_service;
+    use actix_web::test::TestRequest;
+    use actix_web::App;
+    use serde_json::Value;
+
+    use ccdi_models::metadata::field::description::unharmonized::ValueKind;
+
+    use super::*;
+
+    /// Creates the registries with a couple of unharmonized fields registered
+    /// for samples.
+    fn registries() -> UnharmonizedRegistries {
+        let mut registries = UnharmonizedRegistries::default();
+
+        registries
+            .sample
+            .register(
+                "consortium_id",
+                "The consortium identifier.",
+                ValueKind::String,
+            )
+            .unwrap();
+        registries
+            .sample
+            .register("batch", "The batch number.", ValueKind::Number)
+            .unwrap();
+
+        registries
+    }
+
+    #[actix_web::test]
+    async fn it_returns_the_cde_provenance_of_a_field() {
+        let app = init_service(App::new().configure(configure())).await;
+
+        let request = TestRequest::get()
+            .uri("/metadata/fields/subject/sex")
+            .to_request();
+        let response: Value = call_and_read_body_json(&app, request).await;
+
+        assert_eq!(response["field"]["path"], "sex");
+        assert_eq!(response["cde"]["standard"], "caDSR CDE 6343385 v1.00");
+
+        let values = response["cde"]["permissible_values"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|value| value["value"].as_str().unwrap())
+            .collect::<Vec<_>>();
+        assert_eq!(values, vec!["U", "F", "M", "UNDIFFERENTIATED"]);
+        assert!(response["cde"]["permissible_values"][0]["concept_code"].is_string());
+    }
+
+    #[actix_web::test]
+    async fn it_returns_a_null_cde_for_fields_without_one() {
+        let app = init_service(App::new().configure(configure())).await;
+
+        let request = TestRequest::get()
+            .uri("/metadata/fields/sample/age_at_diagnosis")
+            .to_request();
+        let response: Value = call_and_read_body_json(&app, request).await;
+
+        assert_eq!(response["field"]["path"], "age_at_diagnosis");
+        assert_eq!(response["cde"], Value::Null);
+    }
+
+    #[actix_web::test]
+    async fn it_returns_not_found_for_unknown_fields() {
+        let app = init_service(App::new().configure(configure())).await;
+
+        for uri in [
+            "/metadata/fields/subject/not_a_field",
+            "/metadata/fields/not_an_entity/sex",
+        ] {
+            let request = TestRequest::get().uri(uri).to_request();
+            let response = call_service(&app, request).await;
+            assert_eq!(response.status(), StatusCode::NOT_FOUND, "{uri}");
+
+            let errors: Value = actix_web::test::read_body_json(response).await;
+            assert_eq!(errors["errors"][0]["kind"], "NotFound", "{uri}");
+        }
+    }
+
+    #[actix_web::test]
+    async fn it_lists_the_registered_unharmonized_fields() {
+        let app = init_service(
+            App::new()
+                .app_data(Data::new(registries()))
+                .configure(configure()),
+        )
+        .await;
+
+        let request = TestRequest::get()
+            .uri("/metadata/fields/sample")
+            .to_request();
+        let response: FieldDescriptions = call_and_read_body_json(&app, request).await;
+
+        let registered = registries();
+        let expected = registered.sample.descriptions().collect::<Vec<_>>();
+
+        assert_eq!(response.unharmonized().len(), expected.len());
+
+        for (actual, expected) in response.unharmonized().iter().zip(expected) {
+            assert_eq!(actual.path(), expected.path());
+            assert_eq!(actual.description(), expected.description());
+            assert_eq!(actual.value_kind(), expected.value_kind());
+        }
+
+        // NOTE: no unharmonized fields were registered for subjects, so the
+        // section is omitted entirely.
+        let request = TestRequest::get()
+            .uri("/metadata/fields/subject")
+            .to_request();
+        let response: Value = call_and_read_body_json(&app, request).await;
+        assert!(response.get("unharmonized").is_none());
+    }
+
+    #[actix_web::test]
+    async fn it_describes_a_registered_unharmonized_field() {
+        let app = init_service(
+            App::new()
+                .app_data(Data::new(registries()))
+                .configure(configure()),
+        )
+        .await;
+
+        let request = TestRequest::get()
+            .uri("/metadata/fields/sample/unharmonized.batch")
+            .to_request();
+        let response: Value = call_and_read_body_json(&app, request).await;
+
+        assert_eq!(response["field"]["harmonized"], false);
+        assert_eq!(response["field"]["path"], "unharmonized.batch");
+        assert_eq!(response["field"]["value_kind"], "number");
+        assert_eq!(response["cde"], Value::Null);
+
+        let request = TestRequest::get()
+            .uri("/metadata/fields/subject/unharmonized.batch")
+            .to_request();
+        let response = call_service(&app, request).await;
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+    }
+
+    #[actix_web::test]
+    async fn it_exports_the_field_descriptions_as_tab_separated_values() {
+        use actix_web::test::read_body;
+
+        use crate::responses::metadata::TSV_COLUMNS;
+
+        let mut registries = registries();
+        registries
+            .sample
+            .register(
+                "protocol",
+                "The protocol.\tSee the\nsite manual.",
+                ValueKind::Array,
+            )
+            .unwrap();
+
+        let app = init_service(
+            App::new()
+                .app_data(Data::new(registries))
+                .configure(configure()),
+        )
+        .await;
+
+        for entity in ["subject", "sample", "file"] {
+            let request = TestRequest::get()
+                .uri(&format!("/metadata/fields/{entity}"))
+                .to_request();
+            let json: Value = call_and_read_body_json(&app, request).await;
+            let expected = json["fields"].as_array().unwrap().len()
+                + json["unharmonized"]
+                    .as_array()
+                    .map(Vec::len)
+                    .unwrap_or_default();
+
+            for request in [
+                TestRequest::get().uri(&format!("/metadata/fields/{entity}?format=tsv")),
+                TestRequest::get()
+                    .uri(&format!("/metadata/fields/{entity}"))
+                    .insert_header((header::ACCEPT, "text/tab-separated-values")),
+            ] {
+                let response = call_service(&app, request.to_request()).await;
+                assert_eq!(response.status(), StatusCode::OK, "{entity}");
+                assert_eq!(
+                    response.headers().get(header::CONTENT_TYPE).unwrap(),
+                    "text/tab-separated-values; charset=utf-8"
+                );
+
+                let body = String::from_utf8(read_body(response).await.to_vec()).unwrap();
+                let mut rows = body.lines();
+
+                assert_eq!(rows.next().unwrap(), TSV_COLUMNS.join("\t"));
+
+                let rows = rows
+                    .map(|row| row.split('\t').collect::<Vec<_>>())
+                    .collect::<Vec<_>>();
+                assert_eq!(rows.len(), expected, "{entity}");
+                assert!(rows.iter().all(|row| row.len() == TSV_COLUMNS.len()));
+            }
+        }
+
+        let request = TestRequest::get()
+            .uri("/metadata/fields/sample?format=tsv")
+            .to_request();
+        let body =
+            String::from_utf8(read_body(call_service(&app, request).await).await.to_vec()).unwrap();
+
+        let anatomical_sites = body
+            .lines()
+            .find(|row| row.starts_with("anatomical_sites\t"))
+            .unwrap()
+            .split('\t')
+            .collect::<Vec<_>>();
+        assert_eq!(anatomical_sites[1], "true");
+        assert_eq!(anatomical_sites[5], "true");
+
+        // Embedded tabs and newlines within a description are escaped.
+        let protocol = body
+            .lines()
+            .find(|row| row.starts_with("unharmonized.protocol\t"))
+            .unwrap();
+        assert_eq!(
+            protocol,
+            "unharmonized.protocol\tfalse\t\t\tarray\ttrue\t\
+             The protocol.\\tSee the\\nsite manual."
+        );
+    }
 }
@@ -0,0 +1,30 @@
+//! Routes related to request metrics.
+//!
+//! These routes are not a part of the specification: they are only served by
+//! the test server when metrics are enabled (see [`crate::metrics`]).
+
+use actix_web::get;
+use actix_web::web::Data;
+use actix_web::web::ServiceConfig;
+use actix_web::HttpResponse;
+use actix_web::Responder;
+
+use crate::metrics::Registry;
+
+/// The content type of the Prometheus text format.
+pub const CONTENT_TYPE: &str = "text/plain; version=0.0.4; charset=utf-8";
+
+/// Configures the [`ServiceConfig`] with the metrics paths.
+pub fn configure(registry: Data<Registry>) -> impl FnOnce(&mut ServiceConfig) {
+    |config: &mut ServiceConfig| {
+        config.app_data(registry).service(metrics_index);
+    }
+}
+
+/// Gets the metrics of every route in the Prometheus text format.
+#[get("/metrics")]
+pub async fn metrics_index(registry: Data<Registry>) -> impl Responder {
+    HttpResponse::Ok()
+        .content_type(CONTENT_TYPE)
+        .body(registry.render())
+}
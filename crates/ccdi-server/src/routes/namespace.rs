@@ -1,8 +1,11 @@
 //! Routes related to namespaces.
 
 use actix_web::get;
+use actix_web::web::Data;
 use actix_web::web::Path;
+use actix_web::web::Query;
 use actix_web::web::ServiceConfig;
+use actix_web::HttpRequest;
 use actix_web::HttpResponse;
 use actix_web::Responder;
 use indexmap::IndexMap;
@@ -16,12 +19,24 @@ use models::namespace;
 use rand::distributions::Distribution as _;
 use rand::distributions::Uniform;
 use rand::thread_rng;
+use rand::Rng;
 
+use crate::filter::parameter_names;
+use crate::paginate;
+use crate::params::pagination::Compatibility as CompatibilityParams;
+use crate::params::PaginationParams;
 use crate::responses::error;
+use crate::responses::namespace::Counts;
+use crate::responses::namespace::Summary;
 use crate::responses::Errors;
 use crate::responses::Namespace;
 use crate::responses::Namespaces;
+use crate::routes::file;
 use crate::routes::organization::ORGANIZATIONS;
+use crate::routes::sample;
+use crate::routes::subject;
+use crate::routes::GroupByResults;
+use crate::strict;
 
 lazy_static! {
     /// Namespaces supported by this server.
@@ -31,7 +46,8 @@ lazy_static! {
         hm.insert(
             "example-organization-namespace-one",
             // SAFETY: this is manually crafted to unwrap every time, as the
-            // organization name conforms to the correct pattern.
+            // organization name and the study accession conform to the correct
+            // patterns.
             models::Namespace::new(
                 namespace::Identifier::new(
                     ORGANIZATIONS.get("example-organization").unwrap().id().clone(),
@@ -52,6 +68,14 @@ lazy_static! {
                             None, None, None)
                     ).build())
             )
+            .with_study(namespace::Study::new(
+                namespace::study::Identifier::Cde(
+                    cde::v1::namespace::StudyId::from(String::from("STUDY001"))
+                ),
+                "An Example Study",
+                Some(String::from("The first example study owned by Example Organization.")),
+                Some(namespace::study::Accession::try_new("phs000000.v1.p1").unwrap()),
+            ))
         );
 
         hm.insert(
@@ -78,6 +102,12 @@ lazy_static! {
                             None, None, None)
                     ).build())
             )
+            .with_study(namespace::Study::new(
+                namespace::study::Identifier::FreeText(String::from("An Unlisted Study")),
+                "An Unlisted Example Study",
+                None,
+                None,
+            ))
         );
 
         hm
@@ -96,40 +126,230 @@ lazy_static! {
 /// let ns = random_namespace();
 /// ```
 pub fn random_namespace() -> &'static ccdi_models::Namespace {
-    let mut rng = thread_rng();
+    random_namespace_with_rng(&mut thread_rng())
+}
+
+/// Picks a random namespace from the provided [`Namespaces`](ccdi_models::Namespace)
+/// using the provided random number generator.
+///
+/// # Examples
+///
+/// ```
+/// use ccdi_server as server;
+/// use rand::rngs::StdRng;
+/// use rand::SeedableRng as _;
+///
+/// use server::routes::namespace::random_namespace_with_rng;
+///
+/// assert_eq!(
+///     random_namespace_with_rng(&mut StdRng::seed_from_u64(42)),
+///     random_namespace_with_rng(&mut StdRng::seed_from_u64(42))
+/// );
+/// ```
+pub fn random_namespace_with_rng<R: Rng + ?Sized>(rng: &mut R) -> &'static ccdi_models::Namespace {
     let index_dist = Uniform::from(0..NAMESPACES.len());
-    let index = index_dist.sample(&mut rng);
+    let index = index_dist.sample(rng);
 
     // SAFETY: this is manually crafted to always return an element.
     let (_, namespace) = NAMESPACES.get_index(index).unwrap();
     namespace
 }
 
+/// Gets the namespaces known by this server, each formatted as
+/// `{organization}/{namespace}`.
+///
+/// # Examples
+///
+/// ```
+/// use ccdi_server as server;
+///
+/// use server::routes::namespace::known;
+///
+/// assert_eq!(
+///     known(),
+///     vec![
+///         String::from("example-organization/ExampleNamespaceOne"),
+///         String::from("example-organization/ExampleNamespaceTwo"),
+///     ]
+/// );
+/// ```
+pub fn known() -> Vec<String> {
+    NAMESPACES
+        .values()
+        .map(|namespace| {
+            format!(
+                "{}/{}",
+                namespace.id().organization().as_str(),
+                namespace.id().name().as_str()
+            )
+        })
+        .collect()
+}
+
+/// Gets whether the namespace with the provided organization and name is known
+/// by this server.
+///
+/// Per the specification, namespaces are compared case-sensitively.
+///
+/// # Examples
+///
+/// ```
+/// use ccdi_server as server;
+///
+/// use server::routes::namespace::is_known;
+///
+/// assert!(is_known("example-organization", "ExampleNamespaceOne"));
+/// assert!(!is_known("example-organization", "examplenamespaceone"));
+/// assert!(!is_known("example-organization", "ExampleNamespaceThree"));
+/// ```
+pub fn is_known(organization: &str, name: &str) -> bool {
+    NAMESPACES.values().any(|namespace| {
+        namespace.id().organization().as_str() == organization
+            && namespace.id().name().as_str() == name
+    })
+}
+
+/// Checks that the namespace with the provided organization and name is known
+/// by this server.
+///
+/// If the namespace is not known, an
+/// [`UnknownNamespace`](error::kind::Inner::UnknownNamespace) error listing
+/// the known namespaces (and any near misses) is returned.
+///
+/// # Examples
+///
+/// ```
+/// use ccdi_server as server;
+///
+/// use server::routes::namespace::check_known;
+///
+/// assert!(check_known("example-organization", "ExampleNamespaceOne").is_ok());
+///
+/// let err = check_known("example-organization", "ExampleNamespaceOn").unwrap_err();
+/// assert!(err
+///     .to_string()
+///     .contains("did you mean 'example-organization/ExampleNamespaceOne'?"));
+/// ```
+pub fn check_known(organization: &str, name: &str) -> Result<(), error::Kind> {
+    match is_known(organization, name) {
+        true => Ok(()),
+        false => Err(error::Kind::unknown_namespace(
+            format!("{organization}/{name}"),
+            known(),
+        )),
+    }
+}
+
 /// Configures the [`ServiceConfig`] with the namespace paths.
 pub fn configure() -> impl FnOnce(&mut ServiceConfig) {
     |config: &mut ServiceConfig| {
-        config.service(namespace_index).service(namespace_show);
+        config
+            .service(namespace_index)
+            .service(namespace_show)
+            .service(namespace_summary);
     }
 }
 
 /// Gets the namespaces known by this server.
+///
+/// ### Pagination
+///
+/// This endpoint is paginated. Providing `paginate=false` returns every
+/// namespace as a bare array (the format of this endpoint before it was
+/// paginated), but this is **deprecated** and will be removed in the next
+/// release.
 #[utoipa::path(
     get,
     path = "/namespace",
+    params(PaginationParams, CompatibilityParams),
     tag = "Namespace",
     responses(
         (
             status = 200,
-            description = "Successful operation.",
+            description = "Successful operation.\nWhen `paginate=false` is \
+            provided, the response is an array of `models.Namespace`s.",
             body = responses::Namespaces,
+            headers(
+                (
+                    "link" = String,
+                    description = "Links to URLs that may be of interest \
+                    when paging through paginated responses. This header \
+                    contains two or more links of interest. The format of the \
+                    field is as follows: \
+                    \n\
+                    \n`Link: <URL>; rel=\"REL\"` \
+                    \n\
+                    ### Relationships\n\n\
+                    In the format above, `URL` represents a valid URL for \
+                    the link of interest and `REL` is one of four values: \n\
+                    - `first` (_Required_). A link to the first page in the \
+                    results (can be the same as `last` if there is only one \
+                    page).\n\
+                    - `last` (_Required_). A link to the first page in the \
+                    results (can be the same as `first` if there is only one \
+                    page).\n\
+                    - `next` (_Optional_). A link to the next page (if it \
+                    exists).\n\
+                    - `prev` (_Optional_). A link to the previous page (if it \
+                    exists).\n\n\
+                    ### Requirements\n\n\
+                    - This header _must_ provide links for at least the `first` \
+                    and `last` rels.\n \
+                    - The `prev` and `next` links must exist only (a) when there \
+                    are multiple pages in the result page set and (b) when the \
+                    current page is not the first or last page, respectively.\n\
+                    - This list of links is unordered.\n\n \
+                    ### Notes\n\n\
+                    - HTTP 1.1 and HTTP 2.0 dictate that response \
+                    headers are case insensitive. Though not required, we \
+                    recommend an all lowercase name of `link` for this \
+                    response header."
+                )
+            )
+        ),
+        (
+            status = 422,
+            description = "Invalid query or path parameters.",
+            body = responses::Errors,
+            example = json!(Errors::from(error::Kind::invalid_parameters(
+                Some(vec![String::from("page"), String::from("per_page")]),
+                String::from("unable to calculate offset")
+            )))
         ),
     )
 )]
 #[get("/namespace")]
-pub async fn namespace_index() -> impl Responder {
-    HttpResponse::Ok().json(Namespaces::from(
-        NAMESPACES.clone().into_values().collect::<Vec<_>>(),
-    ))
+pub async fn namespace_index(
+    pagination_params: Query<PaginationParams>,
+    compatibility_params: Query<CompatibilityParams>,
+    request: HttpRequest,
+    pagination_config: Option<Data<paginate::Config>>,
+    strict_config: Option<Data<strict::Config>>,
+) -> impl Responder {
+    if let Err(err) = strict::check(
+        request.query_string(),
+        &[
+            parameter_names::<PaginationParams>(),
+            parameter_names::<CompatibilityParams>(),
+        ]
+        .concat(),
+        strict_config.as_ref().map(|config| config.get_ref()),
+    ) {
+        return HttpResponse::UnprocessableEntity().json(Errors::from(err));
+    }
+
+    let namespaces = NAMESPACES.values().cloned().collect::<Vec<_>>();
+
+    if !compatibility_params.paginate() {
+        return paginate::unpaginated(namespaces);
+    }
+
+    paginate::response::<models::Namespace, Namespaces>(
+        pagination_params.0,
+        pagination_config.as_ref().map(|config| config.get_ref()),
+        namespaces,
+        "http://localhost:8000/namespace",
+    )
 }
 
 /// Gets the namespace matching the provided name (if it exists).
@@ -174,3 +394,190 @@ pub async fn namespace_show(path: Path<(String, String)>) -> impl Responder {
             ))))
         })
 }
+
+/// Reports summary information for the entities within the namespace matching
+/// the provided name (if it exists).
+///
+/// The summary includes the number of subjects, samples, and files within the
+/// namespace, the total size of the files within the namespace, and the
+/// number of samples within the namespace for each diagnosis and each set of
+/// depositions (studies). A namespace without any entities is summarized with
+/// zeroed counts.
+#[utoipa::path(
+    get,
+    path = "/namespace/{organization}/{namespace}/summary",
+    params(
+        (
+            "organization" = String,
+            description = "The organization of the namespace.",
+        ),
+        (
+            "namespace" = String,
+            description = "The name of the namespace.",
+        ),
+    ),
+    tag = "Namespace",
+    responses(
+        (
+            status = 200,
+            description = "Successful operation.",
+            body = responses::namespace::Summary
+        ),
+        (
+            status = 404,
+            description = "Not found.",
+            body = responses::Errors,
+            example = json!(Errors::from(error::Kind::not_found(String::from(
+                "Namespace with organization 'foo' and name 'bar'"
+            ))))
+        )
+    )
+)]
+#[get("/namespace/{organization}/{namespace}/summary")]
+pub async fn namespace_summary(
+    path: Path<(String, String)>,
+    subjects: Data<subject::Store>,
+    samples: Data<sample::Store>,
+    files: Data<file::Store>,
+) -> impl Responder {
+    let (organization, namespace_name) = path.into_inner();
+
+    let namespace = match NAMESPACES.values().find(|namespace| {
+        namespace.id().organization().as_str() == organization
+            && namespace.id().name().as_str() == namespace_name
+    }) {
+        Some(namespace) => namespace.id(),
+        None => {
+            return HttpResponse::NotFound().json(Errors::from(error::Kind::not_found(format!(
+                "Namespace with organization '{organization}' and name '{namespace_name}'"
+            ))))
+        }
+    };
+
+    let subjects = subjects
+        .subjects
+        .lock()
+        .unwrap()
+        .iter()
+        .filter(|subject| subject.id().namespace() == namespace)
+        .count();
+
+    let samples = samples
+        .samples
+        .lock()
+        .unwrap()
+        .iter()
+        .filter(|sample| sample.id().namespace() == namespace)
+        .cloned()
+        .collect::<Vec<_>>();
+
+    let (files, total_file_bytes) = files
+        .files
+        .lock()
+        .unwrap()
+        .iter()
+        .filter(|file| file.id().namespace() == namespace)
+        // NOTE: the sizes of files may be arbitrarily large, so the total
+        // saturates rather than overflows.
+        .fold((0usize, 0usize), |(count, bytes), file| {
+            (
+                count + 1,
+                bytes.saturating_add(file::size(file).unwrap_or_default()),
+            )
+        });
+
+    let counts = Counts {
+        subjects,
+        samples: samples.len(),
+        files,
+    };
+
+    let (diagnoses, studies) = match (
+        sample::group_by(samples.clone(), "diagnosis"),
+        sample::group_by(samples, "depositions"),
+    ) {
+        (GroupByResults::Supported(diagnoses), GroupByResults::Supported(studies)) => {
+            (diagnoses, studies)
+        }
+        // SAFETY: both `diagnosis` and `depositions` are groupable fields for
+        // samples, so they are always supported.
+        _ => unreachable!("groupable sample field was not supported"),
+    };
+
+    HttpResponse::Ok().json(Summary::new(
+        namespace.clone(),
+        counts,
+        total_file_bytes,
+        diagnoses,
+        studies,
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use actix_web::http::StatusCode;
+    use actix_web::test::call_service;
+    use actix_web::test::init_service;
+    use actix_web::test::read_body_json;
+    use actix_web::test::TestRequest;
+    use actix_web::App;
+    use serde_json::Value;
+
+    use super::*;
+
+    #[actix_web::test]
+    async fn it_paginates_the_namespace_index() {
+        let app = init_service(App::new().configure(configure())).await;
+
+        let req = TestRequest::get()
+            .uri("/namespace?page=2&per_page=1")
+            .to_request();
+        let res = call_service(&app, req).await;
+        assert_eq!(res.status(), StatusCode::OK);
+
+        let link = res.headers().get("link").unwrap().to_str().unwrap();
+        assert!(link.contains("rel=\"prev\""));
+        assert!(!link.contains("rel=\"next\""));
+
+        let namespaces: Value = read_body_json(res).await;
+        assert_eq!(namespaces["summary"]["counts"]["current"], 1);
+        assert_eq!(namespaces["summary"]["counts"]["all"], 2);
+        assert_eq!(namespaces["data"][0]["id"]["name"], "ExampleNamespaceTwo");
+
+        let req = TestRequest::get().uri("/namespace").to_request();
+        let res = call_service(&app, req).await;
+        let namespaces: Value = read_body_json(res).await;
+        assert_eq!(namespaces["data"].as_array().unwrap().len(), 2);
+
+        // There are only two namespaces, so the third page is out of bounds.
+        let req = TestRequest::get()
+            .uri("/namespace?page=3&per_page=1")
+            .to_request();
+        let res = call_service(&app, req).await;
+        assert_eq!(res.status(), StatusCode::UNPROCESSABLE_ENTITY);
+
+        let req = TestRequest::get().uri("/namespace?page=0").to_request();
+        let res = call_service(&app, req).await;
+        assert_eq!(res.status(), StatusCode::UNPROCESSABLE_ENTITY);
+    }
+
+    #[actix_web::test]
+    async fn it_returns_an_unpaginated_namespace_index_with_a_warning() {
+        let app = init_service(App::new().configure(configure())).await;
+
+        let req = TestRequest::get()
+            .uri("/namespace?paginate=false&per_page=1")
+            .to_request();
+        let res = call_service(&app, req).await;
+        assert_eq!(res.status(), StatusCode::OK);
+        assert!(!res.headers().contains_key("link"));
+        assert_eq!(res.headers().get("deprecation").unwrap(), "true");
+        assert_eq!(
+            res.headers().get("warning").unwrap(),
+            paginate::UNPAGINATED_WARNING
+        );
+
+        let namespaces: Value = read_body_json(res).await;
+        assert_eq!(namespaces.as_array().unwrap().len(), 2);
+    }
+}
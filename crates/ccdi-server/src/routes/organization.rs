@@ -1,24 +1,35 @@
 //! Routes related to organizations.
 
 use actix_web::get;
+use actix_web::web::Data;
 use actix_web::web::Path;
+use actix_web::web::Query;
 use actix_web::web::ServiceConfig;
+use actix_web::HttpRequest;
 use actix_web::HttpResponse;
 use actix_web::Responder;
 use indexmap::IndexMap;
 use lazy_static::lazy_static;
 
+use ccdi_cde as cde;
 use ccdi_models as models;
 
+use models::metadata::field::unowned::organization::Institution;
 use models::organization;
 use rand::distributions::Distribution as _;
 use rand::distributions::Uniform;
 use rand::thread_rng;
 
+use crate::filter::parameter_names;
+use crate::paginate;
+use crate::params::filter::Organization as FilterOrganizationParams;
+use crate::params::pagination::Compatibility as CompatibilityParams;
+use crate::params::PaginationParams;
 use crate::responses::error;
 use crate::responses::Errors;
 use crate::responses::Organization;
 use crate::responses::Organizations;
+use crate::strict;
 
 lazy_static! {
     /// Organizations supported by this server.
@@ -30,7 +41,26 @@ lazy_static! {
             models::Organization::new(
                 "example-organization".parse::<organization::Identifier>().unwrap(),
                 "Example Organization".parse::<organization::Name>().unwrap(),
-                None
+                Some(
+                    organization::metadata::Builder::default()
+                        .push_institution(Institution::new(
+                            cde::v4::organization::Institution::from(String::from(
+                                "Children's Oncology Group",
+                            )),
+                            None,
+                            None,
+                            None,
+                        ))
+                        .push_institution(Institution::new(
+                            cde::v4::organization::Institution::from(String::from(
+                                "National Cancer Institute",
+                            )),
+                            None,
+                            None,
+                            None,
+                        ))
+                        .build(),
+                ),
             )
         );
 
@@ -69,23 +99,147 @@ pub fn configure() -> impl FnOnce(&mut ServiceConfig) {
 }
 
 /// Gets the organizations known by this server.
+///
+/// ### Filtering
+///
+/// Organizations may be filtered by any member of their `institution` field.
+/// An organization is included when any of its institutions exactly matches
+/// the value provided (organizations without metadata or without institutions
+/// never match). Matching is case-sensitive.
+///
+/// The `institution` field is backed by caDSR CDE 1100 v4.00, which is free
+/// text rather than a list of permissible values, so any value is accepted
+/// and an unknown institution simply matches no organizations.
+///
+/// ### Pagination
+///
+/// This endpoint is paginated. Providing `paginate=false` returns every
+/// organization as a bare array (the format of this endpoint before it was
+/// paginated), but this is **deprecated** and will be removed in the next
+/// release.
 #[utoipa::path(
     get,
     path = "/organization",
+    params(FilterOrganizationParams, PaginationParams, CompatibilityParams),
     tag = "Organization",
     responses(
         (
             status = 200,
-            description = "Successful operation.",
+            description = "Successful operation.\nWhen `paginate=false` is \
+            provided, the response is an array of `models.Organization`s.",
             body = responses::Organizations,
+            headers(
+                (
+                    "link" = String,
+                    description = "Links to URLs that may be of interest \
+                    when paging through paginated responses. This header \
+                    contains two or more links of interest. The format of the \
+                    field is as follows: \
+                    \n\
+                    \n`Link: <URL>; rel=\"REL\"` \
+                    \n\
+                    ### Relationships\n\n\
+                    In the format above, `URL` represents a valid URL for \
+                    the link of interest and `REL` is one of four values: \n\
+                    - `first` (_Required_). A link to the first page in the \
+                    results (can be the same as `last` if there is only one \
+                    page).\n\
+                    - `last` (_Required_). A link to the first page in the \
+                    results (can be the same as `first` if there is only one \
+                    page).\n\
+                    - `next` (_Optional_). A link to the next page (if it \
+                    exists).\n\
+                    - `prev` (_Optional_). A link to the previous page (if it \
+                    exists).\n\n\
+                    ### Requirements\n\n\
+                    - This header _must_ provide links for at least the `first` \
+                    and `last` rels.\n \
+                    - The `prev` and `next` links must exist only (a) when there \
+                    are multiple pages in the result page set and (b) when the \
+                    current page is not the first or last page, respectively.\n\
+                    - This list of links is unordered.\n\n \
+                    ### Notes\n\n\
+                    - HTTP 1.1 and HTTP 2.0 dictate that response \
+                    headers are case insensitive. Though not required, we \
+                    recommend an all lowercase name of `link` for this \
+                    response header."
+                )
+            )
+        ),
+        (
+            status = 422,
+            description = "Invalid query or path parameters.",
+            body = responses::Errors,
+            example = json!(Errors::from(error::Kind::invalid_parameters(
+                Some(vec![String::from("page"), String::from("per_page")]),
+                String::from("unable to calculate offset")
+            )))
         ),
     )
 )]
 #[get("/organization")]
-pub async fn organization_index() -> impl Responder {
-    HttpResponse::Ok().json(Organizations::from(
-        ORGANIZATIONS.clone().into_values().collect::<Vec<_>>(),
-    ))
+pub async fn organization_index(
+    filter_params: Query<FilterOrganizationParams>,
+    pagination_params: Query<PaginationParams>,
+    compatibility_params: Query<CompatibilityParams>,
+    request: HttpRequest,
+    pagination_config: Option<Data<paginate::Config>>,
+    strict_config: Option<Data<strict::Config>>,
+) -> impl Responder {
+    if let Err(err) = strict::check(
+        request.query_string(),
+        &[
+            parameter_names::<FilterOrganizationParams>(),
+            parameter_names::<PaginationParams>(),
+            parameter_names::<CompatibilityParams>(),
+        ]
+        .concat(),
+        strict_config.as_ref().map(|config| config.get_ref()),
+    ) {
+        return HttpResponse::UnprocessableEntity().json(Errors::from(err));
+    }
+
+    let organizations = filter(
+        ORGANIZATIONS.values().cloned().collect::<Vec<_>>(),
+        &filter_params.0,
+    );
+
+    if !compatibility_params.paginate() {
+        return paginate::unpaginated(organizations);
+    }
+
+    paginate::response::<models::Organization, Organizations>(
+        pagination_params.0,
+        pagination_config.as_ref().map(|config| config.get_ref()),
+        organizations,
+        "http://localhost:8000/organization",
+    )
+}
+
+/// Filters organizations by the provided [`FilterOrganizationParams`].
+fn filter(
+    organizations: Vec<models::Organization>,
+    params: &FilterOrganizationParams,
+) -> Vec<models::Organization> {
+    let institution = match params.institution.as_deref() {
+        Some(institution) => institution,
+        None => return organizations,
+    };
+
+    organizations
+        .into_iter()
+        .filter(|organization| {
+            organization
+                .metadata()
+                .and_then(|metadata| metadata.institution())
+                .map(|institutions| {
+                    institutions
+                        .iter()
+                        .any(|field| field.value().as_str() == institution)
+                })
+                .unwrap_or_default()
+        })
+        .collect()
 }
 
 /// Gets the organization matching the provided name (if it exists).
@@ -123,3 +277,144 @@ pub async fn organization_show(path: Path<String>) -> impl Responder {
             ))))
         })
 }
+
+#[cfg(test)]
+mod tests {
+    use actix_web::http::StatusCode;
+    use actix_web::test::call_and_read_body_json;
+    use actix_web::test::call_service;
+    use actix_web::test::init_service;
+    use actix_web::test::read_body_json;
+    use actix_web::test::TestRequest;
+    use actix_web::App;
+    use serde_json::Value;
+
+    use super::*;
+
+    fn organization(name: &str, institutions: &[&str]) -> models::Organization {
+        let metadata = institutions
+            .iter()
+            .fold(
+                organization::metadata::Builder::default(),
+                |builder, name| {
+                    builder.push_institution(Institution::new(
+                        cde::v4::organization::Institution::from(name.to_string()),
+                        None,
+                        None,
+                        None,
+                    ))
+                },
+            )
+            .build();
+
+        models::Organization::new(
+            name.parse::<organization::Identifier>().unwrap(),
+            name.parse::<organization::Name>().unwrap(),
+            (!institutions.is_empty()).then_some(metadata),
+        )
+    }
+
+    fn names(organizations: &[models::Organization]) -> Vec<&str> {
+        organizations
+            .iter()
+            .map(|organization| organization.id().as_str())
+            .collect()
+    }
+
+    #[test]
+    fn it_filters_organizations_by_any_institution() {
+        let organizations = vec![
+            organization("cog-and-nci", &["COG", "NCI"]),
+            organization("nci-only", &["NCI"]),
+            organization("no-institutions", &[]),
+        ];
+
+        let filtered = filter(
+            organizations.clone(),
+            &FilterOrganizationParams {
+                institution: Some(String::from("COG")),
+            },
+        );
+        assert_eq!(names(&filtered), vec!["cog-and-nci"]);
+
+        let filtered = filter(
+            organizations.clone(),
+            &FilterOrganizationParams {
+                institution: Some(String::from("NCI")),
+            },
+        );
+        assert_eq!(names(&filtered), vec!["cog-and-nci", "nci-only"]);
+
+        let filtered = filter(organizations, &FilterOrganizationParams::default());
+        assert_eq!(filtered.len(), 3);
+    }
+
+    #[actix_web::test]
+    async fn it_filters_the_organization_index_by_institution() {
+        let app = init_service(App::new().configure(configure())).await;
+
+        let req = TestRequest::get()
+            .uri("/organization?institution=National%20Cancer%20Institute")
+            .to_request();
+        let organizations: Value = call_and_read_body_json(&app, req).await;
+        assert_eq!(organizations["data"].as_array().unwrap().len(), 1);
+
+        let req = TestRequest::get()
+            .uri("/organization?institution=National%20Cancer")
+            .to_request();
+        let organizations: Value = call_and_read_body_json(&app, req).await;
+        assert!(organizations.as_array().unwrap().is_empty());
+    }
+
+    #[actix_web::test]
+    async fn it_paginates_the_organization_index() {
+        let app = init_service(App::new().configure(configure())).await;
+
+        let req = TestRequest::get()
+            .uri("/organization?page=1&per_page=1")
+            .to_request();
+        let res = call_service(&app, req).await;
+        assert_eq!(res.status(), StatusCode::OK);
+        assert!(res.headers().contains_key("link"));
+
+        let organizations: Value = read_body_json(res).await;
+        assert_eq!(organizations["summary"]["counts"]["current"], 1);
+        assert_eq!(organizations["summary"]["counts"]["all"], 1);
+        assert_eq!(
+            organizations["data"][0]["identifier"],
+            "example-organization"
+        );
+
+        // There is only one organization, so the second page is out of bounds.
+        let req = TestRequest::get()
+            .uri("/organization?page=2&per_page=1")
+            .to_request();
+        let res = call_service(&app, req).await;
+        assert_eq!(res.status(), StatusCode::UNPROCESSABLE_ENTITY);
+
+        let req = TestRequest::get()
+            .uri("/organization?per_page=0")
+            .to_request();
+        let res = call_service(&app, req).await;
+        assert_eq!(res.status(), StatusCode::UNPROCESSABLE_ENTITY);
+    }
+
+    #[actix_web::test]
+    async fn it_returns_an_unpaginated_organization_index_with_a_warning() {
+        let app = init_service(App::new().configure(configure())).await;
+
+        let req = TestRequest::get()
+            .uri("/organization?paginate=false&per_page=0")
+            .to_request();
+        let res = call_service(&app, req).await;
+        assert_eq!(res.status(), StatusCode::OK);
+        assert_eq!(res.headers().get("deprecation").unwrap(), "true");
+        assert_eq!(
+            res.headers().get("warning").unwrap(),
+            paginate::UNPAGINATED_WARNING
+        );
+
+        let organizations: Value = read_body_json(res).await;
+        assert_eq!(organizations.as_array().unwrap().len(), 1);
+    }
+}
@@ -1,13 +1,18 @@
 //! Routes related to samples.
 
+use std::collections::BTreeSet;
 use std::sync::Mutex;
 use std::sync::MutexGuard;
 
 use actix_web::get;
+use actix_web::http::header;
+use actix_web::post;
+use actix_web::web::Bytes;
 use actix_web::web::Data;
 use actix_web::web::Path;
 use actix_web::web::Query;
 use actix_web::web::ServiceConfig;
+use actix_web::HttpRequest;
 use actix_web::HttpResponse;
 use actix_web::Responder;
 use models::sample::Identifier;
@@ -16,19 +21,96 @@ use serde_json::Value;
 
 use ccdi_models as models;
 
+use models::metadata::Generation;
+use models::metadata::DEFAULT_METADATA_LESS_FRACTION;
+use models::File;
 use models::Sample;
 
-use crate::filter::filter;
+use crate::consistency;
+use crate::expand;
+use crate::filter::alias::deserialize;
+use crate::filter::alias::Aliases as _;
+use crate::filter::check_keys;
+use crate::filter::expression::evaluate;
+use crate::filter::expression::Expression;
+use crate::filter::filter_with_case;
+use crate::filter::parameter_names;
+use crate::filter::unharmonized;
+use crate::filter::validate;
+use crate::ndjson;
 use crate::paginate;
+use crate::paginate::Totals;
+use crate::params::count::Include;
+use crate::params::count::DEFAULT_TOP;
+use crate::params::filter::Matching as FilterMatchingParams;
 use crate::params::filter::Sample as FilterSampleParams;
+use crate::params::format::Format;
+use crate::params::CountParams;
+use crate::params::ExpandParams;
+use crate::params::FieldsParams;
+use crate::params::FormatParams;
+use crate::params::MissingParams;
 use crate::params::PaginationParams;
+use crate::params::ScopeParams;
+use crate::params::SortParams;
+use crate::params::UberonParams;
+use crate::params::ValidateParams;
+use crate::project;
 use crate::responses;
+use crate::responses::by::count::sample::CategoryCount;
 use crate::responses::by::count::ValueCount;
+use crate::responses::by::group::sample::Group;
 use crate::responses::error;
+use crate::responses::sample::Embedded;
+use crate::responses::validation::Report;
 use crate::responses::Errors;
+use crate::responses::Files;
 use crate::responses::Samples;
 use crate::responses::Summary;
+use crate::routes::duplicates;
+use crate::routes::file;
+use crate::routes::metadata::UnharmonizedRegistries;
+use crate::routes::namespace::check_known;
+use crate::routes::namespace::is_known;
+use crate::routes::subject;
 use crate::routes::GroupByResults;
+use crate::sort::sort;
+use crate::strict;
+use crate::suggest;
+use crate::uberon;
+use crate::values;
+
+/// An error related to a [`Store`].
+#[derive(Debug)]
+pub enum Error {
+    /// More than one sample has the same identifier (each duplicated identifier
+    /// is listed once).
+    DuplicateIdentifiers(Vec<Identifier>),
+
+    /// The namespace of a sample's identifier is not known by this server.
+    UnknownNamespace(models::namespace::Identifier),
+}
+
+impl std::fmt::Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::DuplicateIdentifiers(identifiers) => write!(
+                f,
+                "duplicate sample identifiers: {}",
+                identifiers
+                    .iter()
+                    .map(|identifier| identifier.to_string())
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            ),
+            Error::UnknownNamespace(namespace) => {
+                write!(f, "unknown namespace: {namespace}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for Error {}
 
 /// A store for [`Sample`]s.
 #[derive(Debug)]
@@ -38,6 +120,51 @@ pub struct Store {
 }
 
 impl Store {
+    /// Attempts to create a new [`Store`] from the provided [`Sample`]s.
+    ///
+    /// An error is returned if the namespace of any sample's identifier is not
+    /// known by this server (see
+    /// [`NAMESPACES`](crate::routes::namespace::NAMESPACES)) or if more than
+    /// one sample has the same identifier (in which case, every duplicated
+    /// identifier is reported).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ccdi_server as server;
+    ///
+    /// use server::routes::sample;
+    ///
+    /// let store = sample::Store::try_new(Vec::new())?;
+    /// assert!(store.samples.lock().unwrap().is_empty());
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn try_new(samples: Vec<Sample>) -> Result<Self, Error> {
+        for sample in &samples {
+            let namespace = sample.id().namespace();
+
+            if !is_known(namespace.organization().as_str(), namespace.name().as_str()) {
+                return Err(Error::UnknownNamespace(namespace.clone()));
+            }
+        }
+
+        let duplicates = duplicates(samples.iter().map(|sample| sample.id()))
+            .into_iter()
+            .map(|duplicate| duplicate.identifier.clone())
+            .collect::<BTreeSet<_>>();
+
+        if !duplicates.is_empty() {
+            return Err(Error::DuplicateIdentifiers(
+                duplicates.into_iter().collect(),
+            ));
+        }
+
+        Ok(Self {
+            samples: Mutex::new(samples),
+        })
+    }
+
     /// Creates a new [`Store`] with randomized [`Sample`]s.
     ///
     /// # Examples
@@ -52,25 +179,159 @@ impl Store {
     /// let samples = sample::Store::random(100, subjects.subjects.lock().unwrap());
     /// ```
     pub fn random(count: usize, subjects: MutexGuard<'_, Vec<ccdi_models::Subject>>) -> Self {
-        Self {
-            samples: Mutex::new(
-                (0..count)
-                    .map(|i| {
-                        let mut rng = rand::thread_rng();
+        Self::random_with_metadata_less_fraction(count, subjects, DEFAULT_METADATA_LESS_FRACTION)
+    }
+
+    /// Creates a new [`Store`] with randomized [`Sample`]s where
+    /// `metadata_less_fraction` is the probability that each generated
+    /// [`Sample`] has no metadata.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `metadata_less_fraction` is not within `0.0..=1.0`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ccdi_server as server;
+    ///
+    /// use server::routes::sample;
+    /// use server::routes::subject;
+    ///
+    /// let subjects = subject::Store::random(100);
+    /// let samples = sample::Store::random_with_metadata_less_fraction(
+    ///     100,
+    ///     subjects.subjects.lock().unwrap(),
+    ///     1.0,
+    /// );
+    ///
+    /// assert!(samples
+    ///     .samples
+    ///     .lock()
+    ///     .unwrap()
+    ///     .iter()
+    ///     .all(|sample| !sample.has_metadata()));
+    /// ```
+    pub fn random_with_metadata_less_fraction(
+        count: usize,
+        subjects: MutexGuard<'_, Vec<ccdi_models::Subject>>,
+        metadata_less_fraction: f64,
+    ) -> Self {
+        Self::random_with_rng(
+            count,
+            subjects,
+            metadata_less_fraction,
+            &mut rand::thread_rng(),
+        )
+    }
+
+    /// Creates a new [`Store`] with randomized [`Sample`]s using the provided
+    /// random number generator, where `metadata_less_fraction` is the
+    /// probability that each generated [`Sample`] has no metadata.
+    ///
+    /// The same seed (and the same `subjects`) always generates the same
+    /// [`Store`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `metadata_less_fraction` is not within `0.0..=1.0`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ccdi_server as server;
+    /// use rand::rngs::StdRng;
+    /// use rand::SeedableRng as _;
+    ///
+    /// use server::routes::sample;
+    /// use server::routes::subject;
+    ///
+    /// let mut rng = StdRng::seed_from_u64(42);
+    /// let subjects = subject::Store::random_with_rng(100, 0.5, &mut rng);
+    /// let samples =
+    ///     sample::Store::random_with_rng(100, subjects.subjects.lock().unwrap(), 0.5, &mut rng);
+    /// ```
+    pub fn random_with_rng<R: Rng + ?Sized>(
+        count: usize,
+        subjects: MutexGuard<'_, Vec<ccdi_models::Subject>>,
+        metadata_less_fraction: f64,
+        rng: &mut R,
+    ) -> Self {
+        Self::random_with_generation(
+            count,
+            subjects,
+            metadata_less_fraction,
+            Generation::Coherent,
+            rng,
+        )
+    }
+
+    /// Creates a new [`Store`] with randomized [`Sample`]s using the provided
+    /// random number generator, where `metadata_less_fraction` is the
+    /// probability that each generated [`Sample`] has no metadata and
+    /// `generation` is the strategy used to generate any metadata.
+    ///
+    /// The same seed (and the same `subjects`) always generates the same
+    /// [`Store`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `metadata_less_fraction` is not within `0.0..=1.0`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ccdi_models as models;
+    /// use ccdi_server as server;
+    /// use rand::rngs::StdRng;
+    /// use rand::SeedableRng as _;
+    ///
+    /// use models::metadata::Generation;
+    /// use server::routes::sample;
+    /// use server::routes::subject;
+    ///
+    /// let mut rng = StdRng::seed_from_u64(42);
+    /// let subjects = subject::Store::random_with_rng(100, 0.5, &mut rng);
+    /// let samples = sample::Store::random_with_generation(
+    ///     100,
+    ///     subjects.subjects.lock().unwrap(),
+    ///     0.5,
+    ///     Generation::Independent,
+    ///     &mut rng,
+    /// );
+    /// ```
+    pub fn random_with_generation<R: Rng + ?Sized>(
+        count: usize,
+        subjects: MutexGuard<'_, Vec<ccdi_models::Subject>>,
+        metadata_less_fraction: f64,
+        generation: Generation,
+        rng: &mut R,
+    ) -> Self {
+        let samples = (0..count)
+            .map(|i| {
+                // SAFETY: this should always unwrap because we manually ensure
+                // that subjects is never empty.
+                let subject = subjects.choose(rng).unwrap().id().clone();
 
-                        // SAFETY: this should always unwrap because we manually ensure
-                        // that subjects is never empty.
-                        let subject = subjects.choose(&mut rng).unwrap().id().clone();
+                let identifier =
+                    Identifier::new(subject.namespace().clone(), format!("Sample{}", i + 1));
 
-                        let identifier = Identifier::new(
-                            subject.namespace().clone(),
-                            format!("Sample{}", i + 1),
-                        );
+                Sample::random_with_generation(
+                    identifier,
+                    subject,
+                    metadata_less_fraction,
+                    generation,
+                    rng,
+                )
+            })
+            .collect::<Vec<_>>();
 
-                        Sample::random(identifier, subject)
-                    })
-                    .collect::<Vec<_>>(),
-            ),
+        // NOTE: the names of the generated samples are numbered, so the
+        // identifiers of the generated samples are never duplicated.
+        debug_assert!(duplicates(samples.iter().map(|sample| sample.id())).is_empty());
+
+        Self {
+            samples: Mutex::new(samples),
         }
     }
 }
@@ -81,9 +342,14 @@ pub fn configure(store: Data<Store>) -> impl FnOnce(&mut ServiceConfig) {
         config
             .app_data(store)
             .service(sample_index)
+            .service(sample_filter)
             .service(samples_by_count)
+            .service(samples_by_group)
+            .service(sample_values)
             .service(sample_show)
-            .service(sample_summary);
+            .service(sample_files)
+            .service(sample_summary)
+            .service(sample_validation);
     }
 }
 
@@ -109,17 +375,62 @@ pub fn configure(store: Data<Store>) -> impl FnOnce(&mut ServiceConfig) {
 ///   logical OR (`||`)). Matches are case-sensitive.
 /// * When the metadata field is `null` (in the case of singular or
 ///   multiple-valued metadata fields) or empty, the sample is not included.
+/// * Prefixing the query with `not:` (e.g., `not:Value`) negates it: the sample is
+///   included only if it does _not_ match the remainder of the query. When
+///   the metadata field is `null` or empty, the sample _is_ included in negated
+///   results. The query `not:null` includes only those samples where the
+///   metadata field has a value.
+/// * Numeric fields (`age_at_diagnosis` and `age_at_collection`) accept either a
+///   number, which is matched exactly, or a JSON object with any of the `gt`,
+///   `gte`, `lt`, and `lte` keys, which matches values within the bounds
+///   provided (e.g., `{"gte":0,"lte":1826.25}`). Numbers are interpreted as
+///   days unless a `unit` of `days`, `months`, or `years` is provided (e.g.,
+///   `{"gte":2,"unit":"years"}`). Invalid or unsatisfiable ranges are rejected
+///   with a `422`.
+/// * Providing `case_insensitive=true` opts in to case-insensitive matching for
+///   every filter parameter, including unharmonized fields (e.g., `hispanic or
+///   latino` matches `Hispanic or Latino`). Matching is case-sensitive by
+///   default.
 /// * When multiple fields are provided as filters, a logical AND (`&&`) strings
 ///   together the predicates. In other words, all filters must match for a
-///   sample to be returned. Note that this means that servers do not natively
-///   support logical OR (`||`) across multiple fields: that must be done by
-///   calling this endpoint with each of your desired queries and performing a
-///   set union of those samples out of band.
+///   sample to be returned. To combine filters with a logical OR
+///   (`||`) (or to group them), post a filter expression to the
+///   `/sample/filter` endpoint instead.
 ///
 /// ### Ordering
 ///
 /// This endpoint has default ordering requirements—those details are documented
 /// in the `responses::Samples` schema.
+///
+/// Results may instead be sorted by one of a subset of the harmonized metadata
+/// fields with the `sort` and `order` parameters (e.g.,
+/// `?sort=age_at_diagnosis&order=desc`). Samples without a value for the field
+/// are always sorted last, ties are broken by identifier, and the sort
+/// parameters are preserved within the `link` header. Sorting by any other
+/// field is rejected with a `422`.
+///
+/// ### Expansion
+///
+/// When `expand=subject` is provided, each unique subject referenced by the
+/// samples within the page is embedded exactly once within the
+/// `_embedded.subjects` map of the response, keyed by
+/// `<organization>/<namespace>/<name>`. The `subject` identifier of each sample
+/// refers to its embedded subject.
+///
+/// Because expansion increases the size of each page, the estimated cost of an
+/// expanded page (`per_page` multiplied by the expansion factor) must be within
+/// the budget of the server. Requests over the budget are rejected with a
+/// `422` that describes the largest `per_page` that is allowed.
+///
+/// ### Streaming
+///
+/// Large listings may instead be streamed as newline-delimited JSON
+/// (`application/x-ndjson`) by providing `format=ndjson` or, when the `format`
+/// parameter is not provided, an `Accept` header of `application/x-ndjson`.
+/// Every matching sample (filtered and sorted as described above) is returned
+/// as a JSON object on its own line without the enclosing response or the
+/// `link` header. Because the results are not paginated, providing `page` or
+/// `per_page` along with NDJSON is rejected with a `422` (as is `expand`).
 #[utoipa::path(
     get,
     path = "/sample",
@@ -138,6 +449,12 @@ pub fn configure(store: Data<Store>) -> impl FnOnce(&mut ServiceConfig) {
             * Filtering on field with multiple values should include the \
             `Sample` in the results if the query exactly matches any of the \
             values of the field for that `Sample` (case-sensitive).\n\
+            * When `case_insensitive=true` is provided, matches are \
+            case-insensitive instead.\n\
+            * Providing a JSON array of values (e.g., `[\"A\",\"B\"]`) \
+            should include the `Sample` in the results if the query matches \
+            any of the values within the array. Arrays may only contain \
+            strings, numbers, or booleans.\n\
             * Unlike harmonized fields, unharmonized fields must be prefixed \
             with `metadata.unharmonized`.\n\n\
             **Note:** this query parameter is intended to be symbolic of any \
@@ -148,13 +465,24 @@ pub fn configure(store: Data<Store>) -> impl FnOnce(&mut ServiceConfig) {
             `?metadata.unharmonized.<field>=value` is not supported, so \
             attempting to use it within Swagger UI will not work!"
         ),
+        FilterMatchingParams,
+        FieldsParams,
+        SortParams,
+        FormatParams,
         PaginationParams,
+        ExpandParams,
     ),
     responses(
         (
             status = 200,
             description = "Successful operation.",
-            body = responses::Samples,
+            content(
+                (
+                    "application/json" = responses::Samples,
+                    example = json!(*responses::examples::SAMPLES)
+                ),
+                ("application/x-ndjson" = String)
+            ),
             headers(
                 (
                     "link" = String,
@@ -190,6 +518,18 @@ pub fn configure(store: Data<Store>) -> impl FnOnce(&mut ServiceConfig) {
                     headers are case insensitive. Though not required, we \
                     recommend an all lowercase name of `link` for this \
                     response header."
+                ),
+                (
+                    "x-total-count" = usize,
+                    description = "The number of entities known by the server \
+                    before any filters were applied (also reported as \
+                    `summary.counts.total`)."
+                ),
+                (
+                    "x-filtered-count" = usize,
+                    description = "The number of entities that matched the \
+                    filters across every page (also reported as \
+                    `summary.counts.filtered`)."
                 )
             )
         ),
@@ -224,27 +564,418 @@ pub fn configure(store: Data<Store>) -> impl FnOnce(&mut ServiceConfig) {
     )
 )]
 #[get("/sample")]
+// NOTE: actix-web extracts each set of query parameters and each piece of
+// shared state of a handler as a separate argument.
+#[allow(clippy::too_many_arguments)]
 pub async fn sample_index(
-    filter_params: Query<FilterSampleParams>,
+    matching_params: Query<FilterMatchingParams>,
+    fields_params: Query<FieldsParams>,
+    sort_params: Query<SortParams>,
+    format_params: Query<FormatParams>,
     pagination_params: Query<PaginationParams>,
+    expand_params: Query<ExpandParams>,
+    request: HttpRequest,
     samples: Data<Store>,
+    subjects: Option<Data<subject::Store>>,
+    expansion: Option<Data<expand::Config>>,
+    registries: Option<Data<UnharmonizedRegistries>>,
+    pagination_config: Option<Data<paginate::Config>>,
+    strict_config: Option<Data<strict::Config>>,
 ) -> impl Responder {
+    let known = [
+        parameter_names::<FilterSampleParams>(),
+        FilterSampleParams::names(),
+        parameter_names::<FilterMatchingParams>(),
+        parameter_names::<FieldsParams>(),
+        parameter_names::<SortParams>(),
+        parameter_names::<FormatParams>(),
+        parameter_names::<PaginationParams>(),
+        parameter_names::<ExpandParams>(),
+    ]
+    .concat();
+
+    let mut warnings = Vec::new();
+
+    if let Err(err) = strict::handle(
+        check_keys(request.query_string(), &known),
+        strict_config.as_ref().map(|config| config.get_ref()),
+        &mut warnings,
+    ) {
+        return HttpResponse::UnprocessableEntity().json(Errors::from(err));
+    }
+
+    let format = format_params
+        .format()
+        .or_else(|| {
+            request
+                .headers()
+                .get(header::ACCEPT)
+                .and_then(|value| value.to_str().ok())
+                .and_then(Format::from_accept)
+        })
+        .unwrap_or(Format::Json);
+
+    if format == Format::Ndjson {
+        if let Err(err) = ndjson::reject_pagination(&pagination_params) {
+            return HttpResponse::UnprocessableEntity().json(Errors::from(err));
+        }
+
+        if expand_params.expand().is_some() {
+            return HttpResponse::UnprocessableEntity().json(Errors::from(
+                error::Kind::invalid_parameters(
+                    Some(vec![String::from("expand")]),
+                    String::from("expansion is not supported when streaming NDJSON"),
+                ),
+            ));
+        }
+    }
+
+    let filter_params = match deserialize::<FilterSampleParams>(request.query_string()) {
+        Ok(filter_params) => filter_params,
+        Err(err) => return HttpResponse::UnprocessableEntity().json(Errors::from(err)),
+    };
+
+    if let Err(err) = validate(&filter_params) {
+        return HttpResponse::UnprocessableEntity().json(Errors::from(err));
+    }
+
+    let unharmonized_queries = match unharmonized::queries(request.query_string()) {
+        Ok(queries) => queries,
+        Err(err) => return HttpResponse::UnprocessableEntity().json(Errors::from(err)),
+    };
+
+    unharmonized::warn_unregistered(
+        &unharmonized_queries,
+        registries.as_deref().map(|registries| &registries.sample),
+    );
+
+    let selection = match project::select(
+        &fields_params.0,
+        models::sample::metadata::Builder::default().build(),
+    ) {
+        Ok(selection) => selection,
+        Err(err) => return HttpResponse::UnprocessableEntity().json(Errors::from(err)),
+    };
+
     let mut samples = samples.samples.lock().unwrap().clone();
+    let total = samples.len();
 
     // See the note in the documentation for this endpoint: the results must be
     // sorted by identifier by default.
     samples.sort();
 
-    let samples = filter::<Sample, FilterSampleParams>(samples, filter_params.0);
+    let samples = filter_with_case::<Sample, FilterSampleParams>(
+        samples,
+        filter_params,
+        matching_params.case(),
+    );
+    let samples = unharmonized::filter(samples, &unharmonized_queries, matching_params.case());
+
+    // NOTE: the results are sorted after they are filtered but before they are
+    // paginated so that each page is a slice of the sorted results.
+    let samples = match sort(samples, &sort_params) {
+        Ok(samples) => samples,
+        Err(err) => return HttpResponse::UnprocessableEntity().json(Errors::from(err)),
+    };
+
+    // NOTE: every matching sample is streamed rather than paginated.
+    if format == Format::Ndjson {
+        return match selection {
+            Some(selection) => ndjson::response(
+                samples
+                    .into_iter()
+                    .map(move |sample| selection.project_entity(&sample)),
+            ),
+            None => ndjson::response(samples),
+        };
+    }
+
+    let url = sort_params.url("http://localhost:8000/sample");
+
+    // NOTE: the totals are computed once from the filtered results rather than
+    // for each page.
+    let totals = Totals::new(total, samples.len());
+
+    let expand = match expand_params.expand() {
+        Some(expand) => expand,
+        None => {
+            let build = |page: (Vec<Sample>, usize)| {
+                Samples::from(page)
+                    .with_totals(totals)
+                    .with_warnings(warnings.clone())
+            };
+
+            return match selection {
+                Some(selection) => paginate::response_with_totals(
+                    pagination_params.0,
+                    pagination_config.as_ref().map(|config| config.get_ref()),
+                    samples,
+                    totals,
+                    &url,
+                    |page| selection.project(&build(page)),
+                ),
+                None => paginate::response_with_totals(
+                    pagination_params.0,
+                    pagination_config.as_ref().map(|config| config.get_ref()),
+                    samples,
+                    totals,
+                    &url,
+                    build,
+                ),
+            };
+        }
+    };
+
+    let config = expansion
+        .map(|config| config.get_ref().clone())
+        .unwrap_or_default();
+
+    let per_page = match paginate::per_page(
+        &pagination_params.0,
+        pagination_config.as_ref().map(|config| config.get_ref()),
+    ) {
+        Ok(per_page) => per_page.get(),
+        Err(err) => return HttpResponse::UnprocessableEntity().json(Errors::from(err)),
+    };
+
+    // NOTE: the budget is checked before the page is built so that an
+    // over-budget request never serializes any embedded entities.
+    if let Err(err) = expand::check(&config, per_page, expand) {
+        return HttpResponse::UnprocessableEntity().json(Errors::from(err));
+    }
+
+    let guard = subjects
+        .as_ref()
+        .map(|store| store.subjects.lock().unwrap());
+    let subjects = guard.as_deref().map(Vec::as_slice).unwrap_or_default();
+
+    let build = |(samples, all): (Vec<Sample>, usize)| {
+        let embedded = embed(&samples, subjects);
+        Samples::from((samples, all))
+            .with_totals(totals)
+            .with_warnings(warnings.clone())
+            .with_embedded(embedded)
+    };
+
+    // NOTE: only the metadata of the samples themselves is projected—embedded
+    // subjects are always returned in full.
+    match selection {
+        Some(selection) => paginate::response_with_totals(
+            pagination_params.0,
+            pagination_config.as_ref().map(|config| config.get_ref()),
+            samples,
+            totals,
+            &url,
+            |page| selection.project(&build(page)),
+        ),
+        None => paginate::response_with_totals(
+            pagination_params.0,
+            pagination_config.as_ref().map(|config| config.get_ref()),
+            samples,
+            totals,
+            &url,
+            build,
+        ),
+    }
+}
+
+/// Gets the samples known by this server that match a boolean composition of
+/// filters.
+///
+/// ### Filtering
+///
+/// The body of the request is a filter expression: either a group or a leaf.
+///
+/// * A group is a JSON object with a single key of either `any` or `all`
+///   whose value is a non-empty array of filter expressions. An `any` group
+///   matches a sample when _any_ of its expressions match (a logical OR (`||`)),
+///   and an `all` group matches a sample when _all_ of its expressions match (a
+///   logical AND (`&&`)). Groups may be nested up to four levels deep.
+/// * A leaf is a JSON object of filters keyed exactly as the query parameters
+///   of the `/sample` endpoint (including unharmonized fields prefixed with
+///   `metadata.unharmonized.`). Each leaf is matched exactly as the same
+///   filters would be if provided as query parameters to the `/sample` endpoint.
+///
+/// Empty groups, groups nested too deeply, and leaves that would be rejected
+/// as query parameters are rejected with a `422`.
+///
+/// ### Pagination, Ordering, and Matching
+///
+/// This endpoint is paginated and ordered in the same manner as the `/sample`
+/// endpoint, and the `case_insensitive` parameter applies to every leaf.
+#[utoipa::path(
+    post,
+    path = "/sample/filter",
+    tag = "Sample",
+    params(FilterMatchingParams, SortParams, PaginationParams),
+    request_body(
+        content = Object,
+        description = "A filter expression (see the description of this endpoint).",
+        content_type = "application/json",
+        example = json!({"any": [{"disease_phase": "Relapse"}, {"all": [{"tissue_type": "Tumor"}, {"has_metadata": true}]}]})
+    ),
+    responses(
+        (
+            status = 200,
+            description = "Successful operation.",
+            body = responses::Samples,
+            example = json!(*responses::examples::SAMPLES),
+            headers(
+                (
+                    "link" = String,
+                    description = "Links to URLs that may be of interest \
+                    when paging through paginated responses. This header \
+                    contains two or more links of interest. The format of the \
+                    field is as follows: \
+                    \n\
+                    \n`Link: <URL>; rel=\"REL\"` \
+                    \n\
+                    ### Relationships\n\n\
+                    In the format above, `URL` represents a valid URL for \
+                    the link of interest and `REL` is one of four values: \n\
+                    - `first` (_Required_). A link to the first page in the \
+                    results (can be the same as `last` if there is only one \
+                    page).\n\
+                    - `last` (_Required_). A link to the first page in the \
+                    results (can be the same as `first` if there is only one \
+                    page).\n\
+                    - `next` (_Optional_). A link to the next page (if it \
+                    exists).\n\
+                    - `prev` (_Optional_). A link to the previous page (if it \
+                    exists).\n\n\
+                    ### Requirements\n\n\
+                    - This header _must_ provide links for at least the `first` \
+                    and `last` rels.\n \
+                    - The `prev` and `next` links must exist only (a) when there \
+                    are multiple pages in the result page set and (b) when the \
+                    current page is not the first or last page, respectively.\n\
+                    - This list of links is unordered.\n\n \
+                    ### Notes\n\n\
+                    - HTTP 1.1 and HTTP 2.0 dictate that response \
+                    headers are case insensitive. Though not required, we \
+                    recommend an all lowercase name of `link` for this \
+                    response header."
+                ),
+                (
+                    "x-total-count" = usize,
+                    description = "The number of entities known by the server \
+                    before any filters were applied (also reported as \
+                    `summary.counts.total`)."
+                ),
+                (
+                    "x-filtered-count" = usize,
+                    description = "The number of entities that matched the \
+                    filters across every page (also reported as \
+                    `summary.counts.filtered`)."
+                )
+            )
+        ),
+        (
+            status = 422,
+            description = "Invalid filter expression or query parameters.",
+            body = responses::Errors,
+            example = json!(Errors::from(error::Kind::invalid_parameters(
+                None,
+                String::from("The `any` group at `/` must contain at least one expression.")
+            )))
+        ),
+    )
+)]
+#[post("/sample/filter")]
+// NOTE: actix-web extracts each set of query parameters and each piece of
+// shared state of a handler as a separate argument.
+#[allow(clippy::too_many_arguments)]
+pub async fn sample_filter(
+    body: Bytes,
+    matching_params: Query<FilterMatchingParams>,
+    sort_params: Query<SortParams>,
+    pagination_params: Query<PaginationParams>,
+    request: HttpRequest,
+    samples: Data<Store>,
+    pagination_config: Option<Data<paginate::Config>>,
+    strict_config: Option<Data<strict::Config>>,
+) -> impl Responder {
+    let known = [
+        parameter_names::<FilterMatchingParams>(),
+        parameter_names::<SortParams>(),
+        parameter_names::<PaginationParams>(),
+    ]
+    .concat();
+
+    let mut warnings = Vec::new();
+
+    if let Err(err) = strict::handle(
+        check_keys(request.query_string(), &known),
+        strict_config.as_ref().map(|config| config.get_ref()),
+        &mut warnings,
+    ) {
+        return HttpResponse::UnprocessableEntity().json(Errors::from(err));
+    }
+
+    let expression = match serde_json::from_slice::<Value>(&body)
+        .map_err(|err| error::Kind::invalid_parameters(None, format!("Invalid JSON body: {err}.")))
+        .and_then(|value| Expression::<FilterSampleParams>::parse(&value))
+    {
+        Ok(expression) => expression,
+        Err(err) => return HttpResponse::UnprocessableEntity().json(Errors::from(err)),
+    };
+
+    let mut samples = samples.samples.lock().unwrap().clone();
+    let total = samples.len();
+
+    // See the note in the documentation for the `/sample` endpoint: the
+    // results must be sorted by identifier by default.
+    samples.sort();
+
+    let samples = evaluate(samples, expression, matching_params.case());
+
+    let samples = match sort(samples, &sort_params) {
+        Ok(samples) => samples,
+        Err(err) => return HttpResponse::UnprocessableEntity().json(Errors::from(err)),
+    };
 
-    paginate::response::<Sample, Samples>(
+    let url = sort_params.url("http://localhost:8000/sample/filter");
+    let totals = Totals::new(total, samples.len());
+
+    paginate::response_with_totals(
         pagination_params.0,
+        pagination_config.as_ref().map(|config| config.get_ref()),
         samples,
-        "http://localhost:8000/sample",
+        totals,
+        &url,
+        |page: (Vec<Sample>, usize)| {
+            Samples::from(page)
+                .with_totals(totals)
+                .with_warnings(warnings)
+        },
+    )
+}
+
+/// Embeds each unique subject referenced by the `samples` (subjects that are
+/// not known by the server are omitted).
+fn embed(samples: &[Sample], subjects: &[models::Subject]) -> Embedded {
+    let referenced = samples
+        .iter()
+        .map(|sample| sample.subject())
+        .collect::<BTreeSet<_>>();
+
+    Embedded::new(
+        subjects
+            .iter()
+            .filter(|subject| referenced.contains(subject.id()))
+            .map(|subject| (expand::key(subject.id()), subject.clone()))
+            .collect(),
     )
 }
 
 /// Gets the sample matching the provided name (if the sample exists).
+///
+/// ### Validation
+///
+/// When `validate=true` is provided, the metadata of the sample is checked for
+/// consistency against the files derived from it (see the
+/// `/sample/validation` endpoint), and the findings are included within the
+/// response under the `findings` key.
 #[utoipa::path(
     get,
     path = "/sample/{organization}/{namespace}/{name}",
@@ -260,60 +991,382 @@ pub async fn sample_index(
         (
             "name" = String,
             description = "The name portion of the sample identifier."
-        )
+        ),
+        ValidateParams
     ),
     tag = "Sample",
     responses(
-        (status = 200, description = "Successful operation.", body = responses::Sample),
+        (
+            status = 200,
+            description = "Successful operation.",
+            body = responses::Sample,
+            example = json!(*responses::examples::SAMPLE)
+        ),
         (
             status = 404,
             description = "Not found.\nServers that cannot provide line-level \
             data should use this response rather than Forbidden (403), as \
             there is no level of authorization that would allow one to access \
-            the information included in the API.",
+            the information included in the API.\nIf the server has suggestions \
+            enabled, up to three similar identifiers may be included in the \
+            `suggestions` field.\nIf the namespace is not known by the server \
+            (namespaces are compared case-sensitively), an `UnknownNamespace` \
+            error listing the known namespaces (and any similar namespaces) is \
+            returned instead.",
             body = responses::Errors,
             example = json!(Errors::from(error::Kind::not_found(
                 String::from("Sample with namespace 'foo' and name 'bar'")
             )))
+        ),
+        (
+            status = 422,
+            description = "Invalid query parameters.",
+            body = responses::Errors,
+            example = json!(Errors::from(error::Kind::invalid_parameters(
+                Some(vec![String::from("foo")]),
+                String::from("unrecognized parameter(s): `foo`.")
+            )))
+        ),
+        (
+            status = 500,
+            description = "Internal server error.\nMore than one sample has the \
+            requested identifier. Identifiers are expected to be unique, so this \
+            indicates an issue with the data held by the server.",
+            body = responses::Errors,
+            example = json!(Errors::from(error::Kind::duplicate_identifier(
+                String::from("Sample with namespace 'foo' and name 'bar'"),
+                2
+            )))
         )
     )
 )]
 #[get("/sample/{organization}/{namespace}/{name}")]
 pub async fn sample_show(
     path: Path<(String, String, String)>,
+    params: Query<ValidateParams>,
+    request: HttpRequest,
     samples: Data<Store>,
+    files: Option<Data<file::Store>>,
+    suggestions: Option<Data<suggest::Config>>,
+    strict_config: Option<Data<strict::Config>>,
 ) -> impl Responder {
-    let samples = samples.samples.lock().unwrap();
+    if let Err(err) = strict::check(
+        request.query_string(),
+        &parameter_names::<ValidateParams>(),
+        strict_config.as_ref().map(|config| config.get_ref()),
+    ) {
+        return HttpResponse::UnprocessableEntity().json(Errors::from(err));
+    }
+
     let (organization, namespace, name) = path.into_inner();
 
-    samples
-        .iter()
-        .find(|sample| {
-            sample.id().namespace().organization().as_str() == organization
-                && sample.id().namespace().name().as_str() == namespace
-                && sample.id().name() == name
-        })
-        .map(|sample| HttpResponse::Ok().json(sample))
-        .unwrap_or_else(|| {
-            HttpResponse::NotFound().json(Errors::from(error::Kind::not_found(format!(
-                "Sample with namespace '{namespace}' and name '{name}'"
-            ))))
-        })
-}
+    if let Err(err) = check_known(&organization, &namespace) {
+        return HttpResponse::NotFound().json(Errors::from(err));
+    }
 
-/// Groups the samples by the specified metadata field and returns counts.
-#[utoipa::path(
-    get,
+    let samples = samples.samples.lock().unwrap();
+
+    let mut matches = samples.iter().filter(|sample| {
+        sample.id().namespace().organization().as_str() == organization
+            && sample.id().namespace().name().as_str() == namespace
+            && sample.id().name() == name
+    });
+
+    let sample = matches.next();
+    let others = matches.count();
+
+    if others > 0 {
+        return HttpResponse::InternalServerError().json(Errors::from(
+            error::Kind::duplicate_identifier(
+                format!("Sample with namespace '{namespace}' and name '{name}'"),
+                others + 1,
+            ),
+        ));
+    }
+
+    sample
+        .map(|sample| match params.validate() {
+            true => {
+                let files = files_of(sample, files.as_ref().map(|files| files.get_ref()));
+                let findings = consistency::check_sample(sample, &files.iter().collect::<Vec<_>>());
+
+                HttpResponse::Ok().json(responses::Sample::new(sample.clone(), Some(findings)))
+            }
+            false => HttpResponse::Ok().json(sample),
+        })
+        .unwrap_or_else(|| {
+            let mut errors = Errors::from(error::Kind::not_found(format!(
+                "Sample with namespace '{namespace}' and name '{name}'"
+            )));
+
+            if suggestions.is_some_and(|config| config.enabled()) {
+                errors = errors.with_suggestions(suggest::nearest(
+                    &format!("{organization}/{namespace}/{name}"),
+                    samples.iter().map(|sample| {
+                        format!(
+                            "{}/{}/{}",
+                            sample.id().namespace().organization().as_str(),
+                            sample.id().namespace().name().as_str(),
+                            sample.id().name()
+                        )
+                    }),
+                ));
+            }
+
+            HttpResponse::NotFound().json(errors)
+        })
+}
+
+/// Gets the files derived from the sample matching the provided id.
+///
+/// ### Pagination
+///
+/// This endpoint is paginated in the same manner as the `/file` endpoint,
+/// and the files are sorted by identifier. A sample without any
+/// files returns an empty page rather than an error.
+#[utoipa::path(
+    get,
+    path = "/sample/{organization}/{namespace}/{name}/files",
+    params(
+        (
+            "organization" = String,
+            description = "The organization identifier of the namespace to which the sample belongs.",
+        ),
+        (
+            "namespace" = String,
+            description = "The name of the namespace to which the sample belongs.",
+        ),
+        (
+            "name" = String,
+            description = "The name portion of the sample identifier."
+        ),
+        PaginationParams,
+    ),
+    tag = "Sample",
+    responses(
+        (
+            status = 200,
+            description = "Successful operation.",
+            body = responses::Files,
+            example = json!(*responses::examples::FILES),
+            headers(
+                (
+                    "link" = String,
+                    description = "Links to URLs that may be of interest \
+                    when paging through paginated responses. This header \
+                    contains two or more links of interest. The format of the \
+                    field is as follows: \
+                    \n\
+                    \n`Link: <URL>; rel=\"REL\"` \
+                    \n\
+                    ### Relationships\n\n\
+                    In the format above, `URL` represents a valid URL for \
+                    the link of interest and `REL` is one of four values: \n\
+                    - `first` (_Required_). A link to the first page in the \
+                    results (can be the same as `last` if there is only one \
+                    page).\n\
+                    - `last` (_Required_). A link to the first page in the \
+                    results (can be the same as `first` if there is only one \
+                    page).\n\
+                    - `next` (_Optional_). A link to the next page (if it \
+                    exists).\n\
+                    - `prev` (_Optional_). A link to the previous page (if it \
+                    exists).\n\n\
+                    ### Requirements\n\n\
+                    - This header _must_ provide links for at least the `first` \
+                    and `last` rels.\n \
+                    - The `prev` and `next` links must exist only (a) when there \
+                    are multiple pages in the result page set and (b) when the \
+                    current page is not the first or last page, respectively.\n\
+                    - This list of links is unordered.\n\n \
+                    ### Notes\n\n\
+                    - HTTP 1.1 and HTTP 2.0 dictate that response \
+                    headers are case insensitive. Though not required, we \
+                    recommend an all lowercase name of `link` for this \
+                    response header."
+                )
+            )
+        ),
+        (
+            status = 404,
+            description = "Not found.\nServers that cannot provide line-level \
+            data should use this response rather than Forbidden (403), as \
+            there is no level of authorization that would allow one to access \
+            the information included in the API.\nIf the namespace is not known \
+            by the server (namespaces are compared case-sensitively), an \
+            `UnknownNamespace` error listing the known namespaces (and any \
+            similar namespaces) is returned instead.",
+            body = responses::Errors,
+            example = json!(Errors::from(error::Kind::not_found(
+                String::from("Sample with namespace 'foo' and name 'bar'")
+            )))
+        ),
+        (
+            status = 422,
+            description = "Invalid query parameters.",
+            body = responses::Errors,
+            example = json!(Errors::from(error::Kind::invalid_parameters(
+                Some(vec![String::from("page"), String::from("per_page")]),
+                String::from("unable to calculate offset")
+            )))
+        ),
+        (
+            status = 500,
+            description = "Internal server error.\nMore than one sample has the \
+            requested identifier. Identifiers are expected to be unique, so this \
+            indicates an issue with the data held by the server.",
+            body = responses::Errors,
+            example = json!(Errors::from(error::Kind::duplicate_identifier(
+                String::from("Sample with namespace 'foo' and name 'bar'"),
+                2
+            )))
+        ),
+    )
+)]
+#[get("/sample/{organization}/{namespace}/{name}/files")]
+pub async fn sample_files(
+    path: Path<(String, String, String)>,
+    pagination_params: Query<PaginationParams>,
+    request: HttpRequest,
+    samples: Data<Store>,
+    files: Data<file::Store>,
+    pagination_config: Option<Data<paginate::Config>>,
+    strict_config: Option<Data<strict::Config>>,
+) -> impl Responder {
+    let mut warnings = Vec::new();
+
+    if let Err(err) = strict::handle(
+        check_keys(
+            request.query_string(),
+            &parameter_names::<PaginationParams>(),
+        ),
+        strict_config.as_ref().map(|config| config.get_ref()),
+        &mut warnings,
+    ) {
+        return HttpResponse::UnprocessableEntity().json(Errors::from(err));
+    }
+
+    let (organization, namespace, name) = path.into_inner();
+
+    if let Err(err) = check_known(&organization, &namespace) {
+        return HttpResponse::NotFound().json(Errors::from(err));
+    }
+
+    let sample = {
+        let samples = samples.samples.lock().unwrap();
+
+        let mut matches = samples.iter().filter(|sample| {
+            sample.id().namespace().organization().as_str() == organization
+                && sample.id().namespace().name().as_str() == namespace
+                && sample.id().name() == name
+        });
+
+        let sample = matches.next().cloned();
+        let others = matches.count();
+
+        if others > 0 {
+            return HttpResponse::InternalServerError().json(Errors::from(
+                error::Kind::duplicate_identifier(
+                    format!("Sample with namespace '{namespace}' and name '{name}'"),
+                    others + 1,
+                ),
+            ));
+        }
+
+        sample
+    };
+
+    let sample = match sample {
+        Some(sample) => sample,
+        None => {
+            return HttpResponse::NotFound().json(Errors::from(error::Kind::not_found(format!(
+                "Sample with namespace '{namespace}' and name '{name}'"
+            ))))
+        }
+    };
+
+    let mut files = files_of(&sample, Some(files.get_ref()));
+    files.sort();
+
+    // NOTE: an empty page is returned (rather than the empty array returned
+    // by [`paginate::response()`]) so that a sample without files is
+    // distinguishable from an error.
+    if files.is_empty() {
+        return HttpResponse::Ok().json(Files::from((files, 0)).with_warnings(warnings));
+    }
+
+    paginate::response_with(
+        pagination_params.0,
+        pagination_config.as_ref().map(|config| config.get_ref()),
+        files,
+        &format!("http://localhost:8000/sample/{organization}/{namespace}/{name}/files"),
+        |page| Files::from(page).with_warnings(warnings),
+    )
+}
+
+/// Groups the samples by the specified metadata field and returns counts.
+///
+/// ### Nesting
+///
+/// When grouping by `diagnosis_category`, the free-text `diagnosis` values
+/// within each category may be included by providing `include=diagnoses`. In
+/// that case, the response is a `responses.by.count.sample.NestedResults`
+/// rather than a `responses.by.count.sample.Results`, and only the `top` most
+/// common diagnoses are included within each category (the number of samples
+/// with any of the remaining diagnoses is reported as `other`).
+///
+/// ### Missing values
+///
+/// By default, `missing` counts the samples with no metadata block at all, and
+/// the samples with a metadata block but no value for the field are counted
+/// under a `null` value within `values`. When `distinguish_missing=true` is
+/// provided, the latter are instead counted within a separate `null` bucket
+/// (so `values` only contains actual values). Distinguishing missing values
+/// is not supported when nesting.
+///
+/// ### Multi-valued fields
+///
+/// When grouping by `anatomical_sites` (which is array-valued), each sample is
+/// counted once for every distinct site that it carries, so the counts within
+/// `values` (and `total`) may sum to more than the number of samples. Such
+/// responses are marked with `multivalued: true`. Samples with a metadata
+/// block but no sites are counted under a `null` value (or within the `null`
+/// bucket when `distinguish_missing=true` is provided).
+///
+/// When the server has been configured with the curated Uberon hierarchy,
+/// providing `group_by_uberon_ancestor` rolls every site that is the provided
+/// term (or one of its descendants) up to that term, and each sample is
+/// counted at most once for the term.
+///
+/// ### Scoping
+///
+/// Providing `namespace` (formatted as `{organization}/{namespace}`) or
+/// `organization` only counts the samples within the matching namespace or
+/// organization, exactly as the parameters of the same name do when filtering
+/// samples.
+#[utoipa::path(
+    get,
     path = "/sample/by/{field}/count",
     params(
         ("field" = String, description = "The field to group by and count with."),
+        CountParams,
+        MissingParams,
+        UberonParams,
+        ScopeParams,
     ),
     tag = "Sample",
     responses(
-        (status = 200, description = "Successful operation.", body = responses::by::count::sample::Results),
+        (
+            status = 200,
+            description = "Successful operation.\nWhen `include=diagnoses` is \
+            provided, the response is a \
+            `responses.by.count.sample.NestedResults`.",
+            body = responses::by::count::sample::Results,
+            example = json!(*responses::examples::SAMPLES_BY_COUNT)
+        ),
         (
             status = 422,
-            description = "Unsupported field.",
+            description = "Unsupported field or invalid query parameters.",
             body = responses::Errors,
             example = json!(Errors::from(
                 error::Kind::unsupported_field(
@@ -325,84 +1378,565 @@ pub async fn sample_show(
     )
 )]
 #[get("/sample/by/{field}/count")]
-pub async fn samples_by_count(path: Path<String>, samples: Data<Store>) -> impl Responder {
-    let samples = samples.samples.lock().unwrap().clone();
+// NOTE: actix-web extracts each set of query parameters and each piece of
+// shared state of a handler as a separate argument.
+#[allow(clippy::too_many_arguments)]
+pub async fn samples_by_count(
+    path: Path<String>,
+    params: Query<CountParams>,
+    missing: Query<MissingParams>,
+    uberon: Query<UberonParams>,
+    scope: Query<ScopeParams>,
+    request: HttpRequest,
+    samples: Data<Store>,
+    hierarchy: Option<Data<uberon::Hierarchy>>,
+    strict_config: Option<Data<strict::Config>>,
+) -> impl Responder {
+    if let Err(err) = strict::check(
+        request.query_string(),
+        &[
+            parameter_names::<CountParams>(),
+            parameter_names::<MissingParams>(),
+            parameter_names::<UberonParams>(),
+            parameter_names::<ScopeParams>(),
+        ]
+        .concat(),
+        strict_config.as_ref().map(|config| config.get_ref()),
+    ) {
+        return HttpResponse::UnprocessableEntity().json(Errors::from(err));
+    }
+
+    if let Err(err) = scope.check() {
+        return HttpResponse::UnprocessableEntity().json(Errors::from(err));
+    }
+
+    let mut samples = samples.samples.lock().unwrap().clone();
+    samples.retain(|sample| scope.contains(sample.id().namespace()));
+
     let field = path.into_inner();
 
-    let results = group_by(samples, &field);
+    if field == "anatomical_sites" {
+        if params.include().is_some() {
+            return HttpResponse::UnprocessableEntity().json(Errors::from(
+                error::Kind::invalid_parameters(
+                    Some(vec![String::from("include")]),
+                    String::from(UNSUPPORTED_NESTING),
+                ),
+            ));
+        }
 
-    match results {
-        GroupByResults::Supported(results) => HttpResponse::Ok().json(results),
-        GroupByResults::Unsupported => {
-            HttpResponse::UnprocessableEntity().json(Errors::from(error::Kind::unsupported_field(
-                field.to_string(),
-                String::from("This field is not present for samples."),
-            )))
+        let ancestor = match uberon.group_by_uberon_ancestor() {
+            Some(ancestor) => match hierarchy.as_ref().map(|hierarchy| hierarchy.get_ref()) {
+                Some(hierarchy) if hierarchy.label(ancestor).is_some() => {
+                    Some((hierarchy, ancestor))
+                }
+                Some(_) => {
+                    return invalid_uberon_ancestor(format!(
+                        "`{ancestor}` is not a term within the curated Uberon hierarchy"
+                    ))
+                }
+                None => {
+                    return invalid_uberon_ancestor(String::from(
+                        "no curated Uberon hierarchy is configured for this server",
+                    ))
+                }
+            },
+            None => None,
+        };
+
+        let absent = samples
+            .iter()
+            .filter(|sample| sample.metadata().is_none())
+            .count();
+        let results = count_anatomical_sites(&samples, ancestor);
+
+        return match missing.distinguish_missing() {
+            true => HttpResponse::Ok().json(results.distinguish_missing(absent)),
+            false => HttpResponse::Ok().json(results),
+        };
+    }
+
+    if uberon.group_by_uberon_ancestor().is_some() {
+        return invalid_uberon_ancestor(String::from(
+            "only supported when counting samples by the `anatomical_sites` field",
+        ));
+    }
+
+    let include = match params.include() {
+        Some(include) => include,
+        None => {
+            let absent = samples
+                .iter()
+                .filter(|sample| sample.metadata().is_none())
+                .count();
+
+            return match group_by(samples, &field) {
+                GroupByResults::Supported(results) if missing.distinguish_missing() => {
+                    HttpResponse::Ok().json(results.distinguish_missing(absent))
+                }
+                GroupByResults::Supported(results) => HttpResponse::Ok().json(results),
+                GroupByResults::Unsupported => unsupported_field(&field),
+            };
         }
+    };
+
+    if missing.distinguish_missing() {
+        return HttpResponse::UnprocessableEntity().json(Errors::from(
+            error::Kind::invalid_parameters(
+                Some(vec![String::from("distinguish_missing")]),
+                String::from("distinguishing missing values is not supported when nesting"),
+            ),
+        ));
     }
-}
 
-fn group_by(
-    samples: Vec<Sample>,
-    field: &str,
-) -> GroupByResults<responses::by::count::sample::Results> {
-    let values: Vec<Option<Option<Value>>> = samples
-        .iter()
-        .map(|sample| parse_field(field, sample))
-        .collect::<Vec<_>>();
+    if field != "diagnosis_category" || include != Include::Diagnoses {
+        return HttpResponse::UnprocessableEntity().json(Errors::from(
+            error::Kind::invalid_parameters(
+                Some(vec![String::from("include")]),
+                String::from(UNSUPPORTED_NESTING),
+            ),
+        ));
+    }
+
+    let top = params.top().unwrap_or(DEFAULT_TOP);
 
-    if values.iter().any(|value| value.is_none()) {
-        return GroupByResults::Unsupported;
+    if top == 0 {
+        return HttpResponse::UnprocessableEntity().json(Errors::from(
+            error::Kind::invalid_parameters(
+                Some(vec![String::from("top")]),
+                String::from("must be greater than zero"),
+            ),
+        ));
     }
 
-    let values = values
-        .into_iter()
-        // SAFETY: we just checked above to ensure that none of the values are
-        // [`None`].
-        .map(|value| value.unwrap())
-        .collect::<Vec<_>>();
+    match group_by_nested(samples, &field, "diagnosis", top) {
+        GroupByResults::Supported(results) => HttpResponse::Ok().json(results),
+        GroupByResults::Unsupported => unsupported_field(&field),
+    }
+}
 
-    let mut missing_values = 0usize;
-    let mut result = values
-        .into_iter()
-        .flat_map(|value| match value {
-            Some(value) => Some(value),
-            None => {
-                missing_values += 1;
-                None
-            }
-        })
-        .fold(Vec::new(), |mut acc: Vec<ValueCount>, value| {
-            match acc.iter_mut().find(|result| result.value == value) {
-                Some(result) => result.count += 1,
-                None => acc.push(ValueCount { value, count: 1 }),
-            }
-            acc
-        });
+/// Lists the distinct values that are present for the specified metadata
+/// field across the samples known by this server.
+///
+/// Only non-null values are listed, each along with the number of samples on
+/// which the value is present (sorted in descending order by that number).
+/// Multi-valued fields (e.g., `depositions`) list each of their members, and a
+/// sample is counted at most once for each value.
+///
+/// ### Truncation
+///
+/// Fields with more distinct values than the limit configured for this server
+/// (e.g., `identifiers`) only list the most common values, in which case the
+/// response is marked with `truncated: true`.
+#[utoipa::path(
+    get,
+    path = "/sample/values/{field}",
+    params(
+        ("field" = String, description = "The field to list the distinct values of."),
+    ),
+    tag = "Sample",
+    responses(
+        (
+            status = 200,
+            description = "Successful operation.",
+            body = responses::values::Response
+        ),
+        (
+            status = 422,
+            description = "Unsupported field.",
+            body = responses::Errors,
+            example = json!(Errors::from(
+                error::Kind::unsupported_field(
+                    String::from("handedness"),
+                    String::from("This field is not present for samples."),
+                )
+            ))
+        ),
+    )
+)]
+#[get("/sample/values/{field}")]
+pub async fn sample_values(
+    path: Path<String>,
+    request: HttpRequest,
+    samples: Data<Store>,
+    config: Option<Data<values::Config>>,
+    strict_config: Option<Data<strict::Config>>,
+) -> impl Responder {
+    if let Err(err) = strict::check(
+        request.query_string(),
+        &[],
+        strict_config.as_ref().map(|config| config.get_ref()),
+    ) {
+        return HttpResponse::UnprocessableEntity().json(Errors::from(err));
+    }
 
-    // NOTE: the `std::cmp::Reverse` here is used to sort the values in
-    // descending order.
-    result.sort_by_key(|value| std::cmp::Reverse(value.count));
+    let samples = samples.samples.lock().unwrap();
+    let field = path.into_inner();
+
+    let observed = match parse_fields(&samples, &field) {
+        Some(observed) => observed,
+        None => return unsupported_field(&field),
+    };
 
-    GroupByResults::Supported(responses::by::count::sample::Results::new(
-        result,
-        missing_values,
+    let limit = config
+        .map(|config| config.limit())
+        .unwrap_or(values::DEFAULT_LIMIT);
+
+    HttpResponse::Ok().json(responses::values::Response::new(
+        field,
+        values::distinct(observed),
+        limit,
     ))
 }
 
-fn parse_field(field: &str, sample: &Sample) -> Option<Option<Value>> {
-    match field {
-        "age_at_diagnosis" => match sample.metadata() {
-            Some(metadata) => Some(
-                metadata
-                    .age_at_diagnosis()
-                    .as_ref()
-                    // SAFETY: all metadata fields are able to be represented as
-                    // [`serde_json::Value`]s.
-                    .map(|age_at_diagnosis| serde_json::to_value(age_at_diagnosis.value()).unwrap())
-                    .or(Some(Value::Null)),
-            ),
-            None => Some(None),
+/// The reason reported when nesting is requested for an unsupported pair of
+/// fields.
+const UNSUPPORTED_NESTING: &str =
+    "nesting is only supported for `include=diagnoses` when grouping by `diagnosis_category`";
+
+fn invalid_uberon_ancestor(reason: String) -> HttpResponse {
+    HttpResponse::UnprocessableEntity().json(Errors::from(error::Kind::invalid_parameters(
+        Some(vec![String::from("group_by_uberon_ancestor")]),
+        reason,
+    )))
+}
+
+/// Counts the samples by their `anatomical_sites`.
+///
+/// Each sample contributes one count for every distinct site that it carries
+/// (after rolling the sites up to the `ancestor`, if provided). Samples with a
+/// metadata block but no sites are counted under a `null` value, and samples
+/// without a metadata block are counted as missing.
+fn count_anatomical_sites(
+    samples: &[Sample],
+    ancestor: Option<(&uberon::Hierarchy, &str)>,
+) -> responses::by::count::sample::Results {
+    let values = samples
+        .iter()
+        .flat_map(|sample| {
+            let metadata = match sample.metadata() {
+                Some(metadata) => metadata,
+                None => return vec![None],
+            };
+
+            let mut sites = Vec::new();
+
+            for site in metadata.anatomical_sites().into_iter().flatten() {
+                let value = match ancestor {
+                    Some((hierarchy, ancestor))
+                        if hierarchy.is_a(&site.value().to_string(), ancestor) =>
+                    {
+                        // SAFETY: the ancestor is checked to be within the
+                        // hierarchy before the samples are counted.
+                        Value::from(hierarchy.label(ancestor).unwrap())
+                    }
+                    // SAFETY: all metadata fields are able to be represented
+                    // as [`serde_json::Value`]s.
+                    _ => serde_json::to_value(site.value()).unwrap(),
+                };
+
+                if !sites.contains(&value) {
+                    sites.push(value);
+                }
+            }
+
+            match sites.is_empty() {
+                true => vec![Some(Value::Null)],
+                false => sites.into_iter().map(Some).collect(),
+            }
+        })
+        .collect::<Vec<_>>();
+
+    let (values, missing) = count_values(values);
+    responses::by::count::sample::Results::new(values, missing).into_multivalued()
+}
+
+fn unsupported_field(field: &str) -> HttpResponse {
+    HttpResponse::UnprocessableEntity().json(Errors::from(error::Kind::unsupported_field(
+        field.to_string(),
+        String::from("This field is not present for samples."),
+    )))
+}
+
+/// Groups the samples by the specified metadata field and lists the
+/// identifiers of the samples within each group.
+///
+/// The same fields that are supported by the `/sample/by/{field}/count`
+/// endpoint are supported. Samples for which the field is missing (including
+/// samples with no metadata) are listed within an explicit `null` group.
+///
+/// ### Pagination
+///
+/// This endpoint is paginated across groups (not samples). Groups are sorted
+/// in descending order by the number of samples within them, and every group
+/// lists all of its samples (sorted by identifier).
+#[utoipa::path(
+    get,
+    path = "/sample/by/{field}/group",
+    params(
+        ("field" = String, description = "The field to group by."),
+        PaginationParams,
+    ),
+    tag = "Sample",
+    responses(
+        (
+            status = 200,
+            description = "Successful operation.",
+            body = responses::by::group::sample::Response,
+            headers(
+                (
+                    "link" = String,
+                    description = "Links to URLs that may be of interest \
+                    when paging through paginated responses. This header \
+                    contains two or more links of interest. The format of the \
+                    field is as follows: \
+                    \n\
+                    \n`Link: <URL>; rel=\"REL\"` \
+                    \n\
+                    ### Relationships\n\n\
+                    In the format above, `URL` represents a valid URL for \
+                    the link of interest and `REL` is one of four values: \n\
+                    - `first` (_Required_). A link to the first page in the \
+                    results (can be the same as `last` if there is only one \
+                    page).\n\
+                    - `last` (_Required_). A link to the first page in the \
+                    results (can be the same as `first` if there is only one \
+                    page).\n\
+                    - `next` (_Optional_). A link to the next page (if it \
+                    exists).\n\
+                    - `prev` (_Optional_). A link to the previous page (if it \
+                    exists).\n\n\
+                    ### Requirements\n\n\
+                    - This header _must_ provide links for at least the `first` \
+                    and `last` rels.\n \
+                    - The `prev` and `next` links must exist only (a) when there \
+                    are multiple pages in the result page set and (b) when the \
+                    current page is not the first or last page, respectively.\n\
+                    - This list of links is unordered.\n\n \
+                    ### Notes\n\n\
+                    - HTTP 1.1 and HTTP 2.0 dictate that response \
+                    headers are case insensitive. Though not required, we \
+                    recommend an all lowercase name of `link` for this \
+                    response header."
+                )
+            )
+        ),
+        (
+            status = 422,
+            description = "Unsupported field or invalid query parameters.",
+            body = responses::Errors,
+            example = json!(Errors::from(
+                error::Kind::unsupported_field(
+                    String::from("handedness"),
+                    String::from("This field is not present for samples."),
+                )
+            ))
+        ),
+    )
+)]
+#[get("/sample/by/{field}/group")]
+pub async fn samples_by_group(
+    path: Path<String>,
+    pagination_params: Query<PaginationParams>,
+    request: HttpRequest,
+    samples: Data<Store>,
+    pagination_config: Option<Data<paginate::Config>>,
+    strict_config: Option<Data<strict::Config>>,
+) -> impl Responder {
+    if let Err(err) = strict::check(
+        request.query_string(),
+        &parameter_names::<PaginationParams>(),
+        strict_config.as_ref().map(|config| config.get_ref()),
+    ) {
+        return HttpResponse::UnprocessableEntity().json(Errors::from(err));
+    }
+
+    let mut samples = samples.samples.lock().unwrap().clone();
+    let field = path.into_inner();
+
+    // NOTE: the samples are sorted so that the samples within each group (and
+    // the order of groups with equal counts) are stable across pages.
+    samples.sort();
+
+    let values = match parse_fields(&samples, &field) {
+        Some(values) => values,
+        None => return unsupported_field(&field),
+    };
+
+    let mut groups: Vec<Group> = Vec::new();
+
+    for (sample, value) in samples.into_iter().zip(values) {
+        let value = value.unwrap_or(Value::Null);
+
+        match groups.iter_mut().find(|group| group.value == value) {
+            Some(group) => {
+                group.count += 1;
+                group.samples.push(sample.id().clone());
+            }
+            None => groups.push(Group {
+                value,
+                count: 1,
+                samples: vec![sample.id().clone()],
+            }),
+        }
+    }
+
+    // NOTE: the `std::cmp::Reverse` here is used to sort the groups in
+    // descending order.
+    groups.sort_by_key(|group| std::cmp::Reverse(group.count));
+
+    paginate::response::<Group, responses::by::group::sample::Response>(
+        pagination_params.0,
+        pagination_config.as_ref().map(|config| config.get_ref()),
+        groups,
+        &format!("http://localhost:8000/sample/by/{field}/group"),
+    )
+}
+
+/// The fields by which samples may be grouped and counted.
+pub const GROUPABLE_FIELDS: &[&str] = &[
+    "age_at_diagnosis",
+    "age_at_collection",
+    "diagnosis",
+    "diagnosis_category",
+    "disease_phase",
+    "library_selection_method",
+    "library_strategy",
+    "library_source_material",
+    "preservation_method",
+    "tumor_grade",
+    "specimen_molecular_analyte_type",
+    "tissue_type",
+    "tumor_classification",
+    "tumor_tissue_morphology",
+    "tumor_tissue_site",
+    "depositions",
+];
+
+pub(crate) fn group_by(
+    samples: Vec<Sample>,
+    field: &str,
+) -> GroupByResults<responses::by::count::sample::Results> {
+    match parse_fields(&samples, field) {
+        Some(values) => {
+            let (values, missing) = count_values(values);
+            GroupByResults::Supported(responses::by::count::sample::Results::new(values, missing))
+        }
+        None => GroupByResults::Unsupported,
+    }
+}
+
+/// Groups the samples by the `parent` field and then, within each value of the
+/// `parent` field, by the `child` field. Only the `top` most common values of
+/// the `child` field are retained within each group.
+fn group_by_nested(
+    samples: Vec<Sample>,
+    parent: &str,
+    child: &str,
+    top: usize,
+) -> GroupByResults<responses::by::count::sample::NestedResults> {
+    let (parents, children) = match (
+        parse_fields(&samples, parent),
+        parse_fields(&samples, child),
+    ) {
+        (Some(parents), Some(children)) => (parents, children),
+        _ => return GroupByResults::Unsupported,
+    };
+
+    let mut missing = 0usize;
+    let mut groups: Vec<(Value, Vec<Option<Value>>)> = Vec::new();
+
+    for (parent, child) in parents.into_iter().zip(children) {
+        let parent = match parent {
+            Some(parent) => parent,
+            None => {
+                missing += 1;
+                continue;
+            }
+        };
+
+        match groups.iter_mut().find(|(value, _)| *value == parent) {
+            Some((_, children)) => children.push(child),
+            None => groups.push((parent, vec![child])),
+        }
+    }
+
+    let mut values = groups
+        .into_iter()
+        .map(|(value, children)| {
+            let count = children.len();
+            let (diagnoses, _) = count_values(children);
+            let (diagnoses, other) = responses::by::count::top(diagnoses, top);
+
+            CategoryCount {
+                value,
+                count,
+                diagnoses,
+                other,
+            }
+        })
+        .collect::<Vec<_>>();
+
+    // NOTE: the `std::cmp::Reverse` here is used to sort the values in
+    // descending order.
+    values.sort_by_key(|value| std::cmp::Reverse(value.count));
+
+    GroupByResults::Supported(responses::by::count::sample::NestedResults::new(
+        values, missing,
+    ))
+}
+
+/// Parses the provided field for each of the samples.
+///
+/// If the field is not supported for samples, [`None`] is returned.
+fn parse_fields(samples: &[Sample], field: &str) -> Option<Vec<Option<Value>>> {
+    samples
+        .iter()
+        .map(|sample| parse_field(field, sample))
+        .collect::<Option<Vec<_>>>()
+}
+
+/// Counts the occurrences of each value, sorted in descending order by count,
+/// along with the number of values that are missing.
+fn count_values(values: Vec<Option<Value>>) -> (Vec<ValueCount>, usize) {
+    let mut missing_values = 0usize;
+    let mut result = values
+        .into_iter()
+        .flat_map(|value| match value {
+            Some(value) => Some(value),
+            None => {
+                missing_values += 1;
+                None
+            }
+        })
+        .fold(Vec::new(), |mut acc: Vec<ValueCount>, value| {
+            match acc.iter_mut().find(|result| result.value == value) {
+                Some(result) => result.count += 1,
+                None => acc.push(ValueCount { value, count: 1 }),
+            }
+            acc
+        });
+
+    // NOTE: the `std::cmp::Reverse` here is used to sort the values in
+    // descending order.
+    result.sort_by_key(|value| std::cmp::Reverse(value.count));
+
+    (result, missing_values)
+}
+
+fn parse_field(field: &str, sample: &Sample) -> Option<Option<Value>> {
+    match field {
+        "age_at_diagnosis" => match sample.metadata() {
+            Some(metadata) => Some(
+                metadata
+                    .age_at_diagnosis()
+                    .as_ref()
+                    // SAFETY: all metadata fields are able to be represented as
+                    // [`serde_json::Value`]s.
+                    .map(|age_at_diagnosis| serde_json::to_value(age_at_diagnosis.value()).unwrap())
+                    .or(Some(Value::Null)),
+            ),
+            None => Some(None),
         },
         "age_at_collection" => match sample.metadata() {
             Some(metadata) => Some(
@@ -576,6 +2110,20 @@ fn parse_field(field: &str, sample: &Sample) -> Option<Option<Value>> {
             ),
             None => Some(None),
         },
+        "tumor_tissue_site" => match sample.metadata() {
+            Some(metadata) => Some(
+                metadata
+                    .tumor_tissue_site()
+                    .as_ref()
+                    // SAFETY: all metadata fields are able to be represented as
+                    // [`serde_json::Value`]s.
+                    .map(|tumor_tissue_site| {
+                        serde_json::to_value(tumor_tissue_site.value()).unwrap()
+                    })
+                    .or(Some(Value::Null)),
+            ),
+            None => Some(None),
+        },
         "depositions" => match sample.metadata() {
             Some(metadata) => Some(
                 metadata
@@ -598,21 +2146,1261 @@ fn parse_field(field: &str, sample: &Sample) -> Option<Option<Value>> {
     path = "/sample/summary",
     tag = "Sample",
     responses(
-        (status = 200, description = "Successful operation.", body = responses::Summary),
+        (
+            status = 200,
+            description = "Successful operation.",
+            body = responses::Summary,
+            example = json!(*responses::examples::SUMMARY)
+        ),
     )
 )]
 #[get("/sample/summary")]
 pub async fn sample_summary(samples: Data<Store>) -> impl Responder {
-    let samples = samples.samples.lock().unwrap().clone();
-    HttpResponse::Ok().json(Summary::new(samples.len()))
+    let samples = samples.samples.lock().unwrap();
+
+    let without_metadata = samples
+        .iter()
+        .filter(|sample| !sample.has_metadata())
+        .count();
+
+    HttpResponse::Ok().json(Summary::new(samples.len(), without_metadata))
+}
+
+/// Gets the files derived from a sample (if the files are known).
+fn files_of(sample: &Sample, files: Option<&file::Store>) -> Vec<File> {
+    files
+        .map(|files| {
+            files
+                .files
+                .lock()
+                .unwrap()
+                .iter()
+                .filter(|file| file.samples().iter().any(|id| id == sample.id()))
+                .cloned()
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+/// Reports the findings from checking the consistency of every sample known by
+/// this server against the files derived from it.
+///
+/// The declared metadata of each sample (for example, its library strategy
+/// and specimen molecular analyte type) is compared against the broad
+/// categories of the types of the files derived from it (sequence data,
+/// images, or other). For example, a sample with a sequencing library strategy
+/// whose only files are images is reported.
+///
+/// The checks are conservative: samples without metadata, samples without any
+/// files, and samples with any file whose type is unknown are never reported.
+/// Each finding includes the name of the rule that produced it.
+#[utoipa::path(
+    get,
+    path = "/sample/validation",
+    tag = "Sample",
+    responses(
+        (status = 200, description = "Successful operation.", body = responses::validation::Report),
+    )
+)]
+#[get("/sample/validation")]
+pub async fn sample_validation(
+    samples: Data<Store>,
+    files: Option<Data<file::Store>>,
+) -> impl Responder {
+    let samples = samples.samples.lock().unwrap();
+
+    let findings = match files {
+        Some(files) => consistency::check(&samples, &files.files.lock().unwrap()),
+        None => Vec::new(),
+    };
+
+    HttpResponse::Ok().json(Report::new(samples.len(), findings))
 }
 
 #[cfg(test)]
 mod tests {
+    use actix_web::http::StatusCode;
+    use actix_web::test::call_and_read_body_json;
+    use actix_web::test::call_service;
+    use actix_web::test::init_service;
+    use actix_web::test::read_body;
+    use actix_web::test::read_body_json;
+    use actix_web::test::TestRequest;
+    use actix_web::App;
+    use ccdi_cde as cde;
+    use serde_json::json;
+
+    use ccdi_models::metadata::field::unowned::sample::Diagnosis;
+    use ccdi_models::metadata::field::unowned::sample::DiagnosisCategory;
+    use ccdi_models::sample::metadata::Builder;
+
+    use super::*;
     use crate::routes::namespace::random_namespace;
 
+    /// Creates a metadata-less sample (taken from `Subject1`) within the first
+    /// namespace known by this server.
+    fn named_sample(name: &str) -> Sample {
+        use crate::routes::namespace::NAMESPACES;
+
+        let namespace = NAMESPACES.values().next().unwrap().id().clone();
+
+        Sample::new(
+            Identifier::new(namespace.clone(), name),
+            models::subject::Identifier::new(namespace, "Subject1"),
+            None,
+            None,
+        )
+    }
+
+    #[test]
+    fn it_reports_every_duplicate_identifier() {
+        let err = Store::try_new(vec![
+            named_sample("Sample3"),
+            named_sample("Sample1"),
+            named_sample("Sample3"),
+            named_sample("Sample2"),
+            named_sample("Sample1"),
+        ])
+        .unwrap_err();
+
+        let Error::DuplicateIdentifiers(identifiers) = &err else {
+            panic!("expected duplicate identifiers, found: {err}");
+        };
+
+        assert_eq!(
+            identifiers
+                .iter()
+                .map(|identifier| identifier.name())
+                .collect::<Vec<_>>(),
+            ["Sample1", "Sample3"]
+        );
+
+        assert!(err
+            .to_string()
+            .starts_with("duplicate sample identifiers: "));
+    }
+
+    #[actix_web::test]
+    async fn it_responds_with_a_server_error_when_an_identifier_is_duplicated() {
+        // NOTE: the store is constructed directly, as [`Store::try_new()`]
+        // rejects duplicate identifiers.
+        let store = Data::new(Store {
+            samples: Mutex::new(vec![
+                named_sample("Sample1"),
+                named_sample("Sample1"),
+                named_sample("Sample1"),
+            ]),
+        });
+
+        let files = Data::new(file::Store::try_new(Vec::new()).unwrap());
+
+        let app = init_service(App::new().app_data(files).configure(configure(store))).await;
+        let namespace = named_sample("Sample1").id().namespace().clone();
+
+        for suffix in ["", "/files"] {
+            let request = TestRequest::get()
+                .uri(&format!(
+                    "/sample/{}/{}/Sample1{suffix}",
+                    namespace.organization().as_str(),
+                    namespace.name().as_str()
+                ))
+                .to_request();
+            let response = call_service(&app, request).await;
+            assert_eq!(response.status(), StatusCode::INTERNAL_SERVER_ERROR);
+
+            let body: Value = read_body_json(response).await;
+            assert_eq!(body["errors"][0]["kind"], "DuplicateIdentifier");
+            assert_eq!(body["errors"][0]["count"], 3);
+        }
+    }
+
+    fn samples() -> Vec<Sample> {
+        let namespace = random_namespace();
+
+        let sample = |name: &str,
+                      category: Option<cde::v1::sample::DiagnosisCategory>,
+                      diagnosis: Option<&str>,
+                      has_metadata: bool| {
+            let metadata = has_metadata.then(|| {
+                let mut builder = Builder::default();
+
+                if let Some(category) = category {
+                    builder = builder
+                        .diagnosis_category(DiagnosisCategory::new(category, None, None, None));
+                }
+
+                if let Some(diagnosis) = diagnosis {
+                    builder = builder.diagnosis(Diagnosis::new(
+                        models::sample::metadata::Diagnosis::from(String::from(diagnosis)),
+                        None,
+                        None,
+                        None,
+                    ));
+                }
+
+                builder.build()
+            });
+
+            Sample::new(
+                Identifier::new(namespace.id().clone(), name),
+                models::subject::Identifier::new(namespace.id().clone(), "Subject1"),
+                None,
+                metadata,
+            )
+        };
+
+        let atrt = || Some(cde::v1::sample::DiagnosisCategory::AtypicalTeratoidRhabdoidTumors);
+        let cpt = || Some(cde::v1::sample::DiagnosisCategory::ChoroidPlexusTumors);
+
+        vec![
+            sample("Sample1", atrt(), Some("Diagnosis A"), true),
+            sample("Sample2", atrt(), Some("Diagnosis A"), true),
+            sample("Sample3", atrt(), Some("Diagnosis B"), true),
+            sample("Sample4", atrt(), Some("Diagnosis C"), true),
+            sample("Sample5", cpt(), Some("Diagnosis D"), true),
+            sample("Sample6", cpt(), None, true),
+            // A sample with metadata but without a diagnosis category.
+            sample("Sample7", None, Some("Diagnosis E"), true),
+            // A sample without any metadata.
+            sample("Sample8", None, None, false),
+        ]
+    }
+
+    fn category(category: cde::v1::sample::DiagnosisCategory) -> Value {
+        serde_json::to_value(category).unwrap()
+    }
+
     #[test]
     fn it_generates_a_random_namespace() {
         random_namespace();
     }
+
+    #[test]
+    fn it_nests_diagnoses_within_diagnosis_categories() {
+        let results = match group_by_nested(samples(), "diagnosis_category", "diagnosis", 2) {
+            GroupByResults::Supported(results) => results,
+            GroupByResults::Unsupported => panic!("nesting should be supported"),
+        };
+
+        assert_eq!(results.total, 8);
+        assert_eq!(results.missing, 1);
+        assert_eq!(results.values.len(), 3);
+
+        let atrt = &results.values[0];
+        assert_eq!(
+            atrt.value,
+            category(cde::v1::sample::DiagnosisCategory::AtypicalTeratoidRhabdoidTumors)
+        );
+        assert_eq!(atrt.count, 4);
+        assert_eq!(atrt.diagnoses.len(), 2);
+        assert_eq!(atrt.diagnoses[0].value, "Diagnosis A");
+        assert_eq!(atrt.diagnoses[0].count, 2);
+        assert_eq!(atrt.diagnoses[1].value, "Diagnosis B");
+        assert_eq!(atrt.diagnoses[1].count, 1);
+        assert_eq!(atrt.other, 1);
+
+        let cpt = &results.values[1];
+        assert_eq!(
+            cpt.value,
+            category(cde::v1::sample::DiagnosisCategory::ChoroidPlexusTumors)
+        );
+        assert_eq!(cpt.count, 2);
+        assert_eq!(cpt.diagnoses[0].value, "Diagnosis D");
+        assert_eq!(cpt.diagnoses[1].value, Value::Null);
+        assert_eq!(cpt.other, 0);
+
+        // Samples with metadata but without a diagnosis category are grouped
+        // under `null`, just as they are when counting without nesting.
+        let uncategorized = &results.values[2];
+        assert_eq!(uncategorized.value, Value::Null);
+        assert_eq!(uncategorized.count, 1);
+        assert_eq!(uncategorized.diagnoses[0].value, "Diagnosis E");
+        assert_eq!(uncategorized.other, 0);
+    }
+
+    #[test]
+    fn nested_counts_match_unnested_counts() {
+        let nested = match group_by_nested(samples(), "diagnosis_category", "diagnosis", 10) {
+            GroupByResults::Supported(results) => results,
+            GroupByResults::Unsupported => panic!("nesting should be supported"),
+        };
+
+        let unnested = match group_by(samples(), "diagnosis_category") {
+            GroupByResults::Supported(results) => results,
+            GroupByResults::Unsupported => panic!("grouping should be supported"),
+        };
+
+        assert_eq!(nested.total, unnested.total);
+        assert_eq!(nested.missing, unnested.missing);
+
+        for (nested, unnested) in nested.values.iter().zip(unnested.values.iter()) {
+            assert_eq!(nested.value, unnested.value);
+            assert_eq!(nested.count, unnested.count);
+            assert_eq!(
+                nested.diagnoses.iter().map(|v| v.count).sum::<usize>(),
+                nested.count
+            );
+        }
+    }
+
+    #[actix_web::test]
+    async fn it_rejects_unsupported_nesting() {
+        let store = Data::new(Store {
+            samples: Mutex::new(samples()),
+        });
+
+        let app = init_service(App::new().configure(configure(store))).await;
+
+        for uri in [
+            "/sample/by/disease_phase/count?include=diagnoses",
+            "/sample/by/diagnosis_category/count?include=diagnoses&top=0",
+            "/sample/by/diagnosis_category/count?include=diagnoses&distinguish_missing=true",
+        ] {
+            let request = TestRequest::get().uri(uri).to_request();
+            let response = call_service(&app, request).await;
+            assert_eq!(response.status(), StatusCode::UNPROCESSABLE_ENTITY);
+        }
+
+        let request = TestRequest::get()
+            .uri("/sample/by/diagnosis_category/count?include=diagnoses&top=1")
+            .to_request();
+        let response = call_service(&app, request).await;
+        assert_eq!(response.status(), StatusCode::OK);
+    }
+
+    #[actix_web::test]
+    async fn it_groups_samples_by_a_field() {
+        let store = Data::new(Store {
+            samples: Mutex::new(samples()),
+        });
+
+        let app = init_service(App::new().configure(configure(store))).await;
+
+        let request = TestRequest::get()
+            .uri("/sample/by/diagnosis/group")
+            .to_request();
+        let response: responses::by::group::sample::Response =
+            call_and_read_body_json(&app, request).await;
+
+        let groups = response
+            .data()
+            .iter()
+            .map(|group| {
+                (
+                    group.value.clone(),
+                    group.count,
+                    group
+                        .samples
+                        .iter()
+                        .map(|sample| sample.name().to_string())
+                        .collect::<Vec<_>>(),
+                )
+            })
+            .collect::<Vec<_>>();
+
+        // Samples without a diagnosis (whether or not they have metadata) are
+        // listed within an explicit `null` group.
+        assert_eq!(
+            groups,
+            vec![
+                (
+                    Value::from("Diagnosis A"),
+                    2,
+                    vec![String::from("Sample1"), String::from("Sample2")]
+                ),
+                (
+                    Value::Null,
+                    2,
+                    vec![String::from("Sample6"), String::from("Sample8")]
+                ),
+                (Value::from("Diagnosis B"), 1, vec![String::from("Sample3")]),
+                (Value::from("Diagnosis C"), 1, vec![String::from("Sample4")]),
+                (Value::from("Diagnosis D"), 1, vec![String::from("Sample5")]),
+                (Value::from("Diagnosis E"), 1, vec![String::from("Sample7")]),
+            ]
+        );
+    }
+
+    #[actix_web::test]
+    async fn it_paginates_across_groups() {
+        let store = Data::new(Store {
+            samples: Mutex::new(samples()),
+        });
+
+        let app = init_service(App::new().configure(configure(store))).await;
+
+        let request = TestRequest::get()
+            .uri("/sample/by/diagnosis/group?page=2&per_page=2")
+            .to_request();
+        let response = call_service(&app, request).await;
+        assert_eq!(response.status(), StatusCode::OK);
+        assert!(response.headers().contains_key("link"));
+
+        let request = TestRequest::get()
+            .uri("/sample/by/diagnosis/group?page=2&per_page=2")
+            .to_request();
+        let response: responses::by::group::sample::Response =
+            call_and_read_body_json(&app, request).await;
+
+        let values = response
+            .data()
+            .iter()
+            .map(|group| group.value.clone())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            values,
+            vec![Value::from("Diagnosis B"), Value::from("Diagnosis C")]
+        );
+    }
+
+    #[actix_web::test]
+    async fn it_rejects_grouping_by_an_unsupported_field() {
+        let store = Data::new(Store {
+            samples: Mutex::new(samples()),
+        });
+
+        let app = init_service(App::new().configure(configure(store))).await;
+
+        let request = TestRequest::get()
+            .uri("/sample/by/handedness/group")
+            .to_request();
+        let response = call_service(&app, request).await;
+        assert_eq!(response.status(), StatusCode::UNPROCESSABLE_ENTITY);
+    }
+
+    #[actix_web::test]
+    async fn it_includes_findings_when_validation_is_requested() {
+        use ccdi_models::metadata::field::unowned::file::Type;
+        use ccdi_models::metadata::field::unowned::sample::LibraryStrategy;
+        use nonempty::NonEmpty;
+
+        let namespace = random_namespace();
+
+        let sample = Sample::new(
+            Identifier::new(namespace.id().clone(), "Sample1"),
+            models::subject::Identifier::new(namespace.id().clone(), "Subject1"),
+            None,
+            Some(
+                Builder::default()
+                    .library_strategy(LibraryStrategy::new(
+                        cde::v1::sample::LibraryStrategy::Wgs,
+                        None,
+                        None,
+                        None,
+                    ))
+                    .build(),
+            ),
+        );
+
+        let file = File::new(
+            models::file::Identifier::new(
+                namespace.id().clone(),
+                cde::v1::file::Name::new("File1.svs"),
+            ),
+            NonEmpty::new(sample.id().clone()),
+            None,
+            Some(
+                models::file::metadata::Builder::default()
+                    .r#type(Type::new(cde::v1::file::Type::SVS, None, None, None))
+                    .build(),
+            ),
+        );
+
+        let uri = format!(
+            "/sample/{}/{}/Sample1",
+            namespace.id().organization().as_str(),
+            namespace.id().name().as_str()
+        );
+
+        let samples = Data::new(Store {
+            samples: Mutex::new(vec![sample]),
+        });
+        let files = Data::new(file::Store {
+            files: Mutex::new(vec![file]),
+        });
+
+        let app = init_service(App::new().app_data(files).configure(configure(samples))).await;
+
+        let request = TestRequest::get().uri(&uri).to_request();
+        let response: Value = call_and_read_body_json(&app, request).await;
+        assert!(response.get("findings").is_none());
+
+        let request = TestRequest::get()
+            .uri(&format!("{uri}?validate=true"))
+            .to_request();
+        let response: Value = call_and_read_body_json(&app, request).await;
+        assert_eq!(
+            response["findings"][0]["rule"],
+            "imaging-only-sequencing-strategy"
+        );
+
+        let request = TestRequest::get().uri("/sample/validation").to_request();
+        let report: Report = call_and_read_body_json(&app, request).await;
+        assert_eq!(report.total(), 1);
+        assert_eq!(report.findings().len(), 1);
+    }
+
+    #[actix_web::test]
+    async fn it_embeds_each_unique_subject_once() {
+        let namespace = random_namespace();
+
+        let subject = |name: &str| {
+            models::Subject::new(
+                models::subject::Identifier::new(namespace.id().clone(), name),
+                models::subject::Kind::Participant,
+                None,
+                None,
+            )
+        };
+
+        // NOTE: `Subject3` is not referenced by any sample.
+        let subjects = vec![
+            subject("Subject1"),
+            subject("Subject2"),
+            subject("Subject3"),
+        ];
+
+        let samples = (0..6)
+            .map(|i| {
+                Sample::new(
+                    Identifier::new(namespace.id().clone(), format!("Sample{}", i + 1)),
+                    subjects[i % 2].id().clone(),
+                    None,
+                    None,
+                )
+            })
+            .collect::<Vec<_>>();
+
+        let keys = subjects
+            .iter()
+            .take(2)
+            .map(|subject| expand::key(subject.id()))
+            .collect::<Vec<_>>();
+
+        let app = init_service(
+            App::new()
+                .configure(subject::configure(Data::new(subject::Store {
+                    subjects: Mutex::new(subjects),
+                })))
+                .configure(configure(Data::new(Store {
+                    samples: Mutex::new(samples),
+                }))),
+        )
+        .await;
+
+        let request = TestRequest::get().uri("/sample").to_request();
+        let response: Value = call_and_read_body_json(&app, request).await;
+        assert!(response.get("_embedded").is_none());
+
+        let request = TestRequest::get()
+            .uri("/sample?expand=subject")
+            .to_request();
+        let response: Value = call_and_read_body_json(&app, request).await;
+
+        let embedded = response["_embedded"]["subjects"].as_object().unwrap();
+        assert_eq!(
+            embedded.keys().collect::<Vec<_>>(),
+            keys.iter().collect::<Vec<_>>()
+        );
+
+        let data = response["data"].as_array().unwrap();
+        assert_eq!(data.len(), 6);
+
+        for sample in data {
+            let reference =
+                serde_json::from_value::<models::subject::Identifier>(sample["subject"].clone())
+                    .unwrap();
+
+            // Each reference resolves to exactly one embedded copy.
+            assert_eq!(
+                embedded
+                    .values()
+                    .filter(|subject| subject["id"] == sample["subject"])
+                    .count(),
+                1
+            );
+            assert_eq!(embedded[&expand::key(&reference)]["id"], sample["subject"]);
+        }
+    }
+
+    #[actix_web::test]
+    async fn it_rejects_expanded_pages_over_budget() {
+        let store = Data::new(Store {
+            samples: Mutex::new(samples()),
+        });
+
+        let app = init_service(
+            App::new()
+                .app_data(Data::new(expand::Config::new(10)))
+                .configure(configure(store)),
+        )
+        .await;
+
+        for (uri, status) in [
+            (
+                "/sample?expand=subject&per_page=6",
+                StatusCode::UNPROCESSABLE_ENTITY,
+            ),
+            // The default `per_page` is also subject to the budget.
+            ("/sample?expand=subject", StatusCode::UNPROCESSABLE_ENTITY),
+            ("/sample?expand=subject&per_page=5", StatusCode::OK),
+            // Pages that are not expanded are not subject to the budget.
+            ("/sample?per_page=100", StatusCode::OK),
+        ] {
+            let request = TestRequest::get().uri(uri).to_request();
+            let response = call_service(&app, request).await;
+            assert_eq!(response.status(), status, "{uri}");
+        }
+
+        let request = TestRequest::get()
+            .uri("/sample?expand=subject&per_page=6")
+            .to_request();
+        let response: Value = call_and_read_body_json(&app, request).await;
+
+        assert_eq!(response["errors"][0]["kind"], "InvalidParameters");
+        assert_eq!(
+            response["errors"][0]["parameters"],
+            serde_json::json!(["per_page", "expand"])
+        );
+        assert!(response["errors"][0]["reason"]
+            .as_str()
+            .unwrap()
+            .contains("Lower `per_page` to at most 5 or drop `expand`."));
+    }
+
+    #[actix_web::test]
+    async fn it_filters_numeric_fields_by_range() {
+        let namespace = random_namespace();
+
+        let sample = |name: &str, age: Option<f64>| {
+            let metadata = age.map(|age| {
+                Builder::default()
+                    .age_at_collection(
+                        serde_json::from_value(serde_json::json!({ "value": age })).unwrap(),
+                    )
+                    .build()
+            });
+
+            Sample::new(
+                Identifier::new(namespace.id().clone(), name),
+                models::subject::Identifier::new(namespace.id().clone(), "Subject1"),
+                None,
+                metadata,
+            )
+        };
+
+        let store = Data::new(Store {
+            samples: Mutex::new(vec![
+                sample("Sample1", Some(0.0)),
+                sample("Sample2", Some(365.25)),
+                sample("Sample3", Some(1826.25)),
+                sample("Sample4", Some(3652.5)),
+                sample("Sample5", None),
+            ]),
+        });
+
+        let app = init_service(App::new().configure(configure(store))).await;
+
+        for (query, expected) in [
+            ("365.25", vec!["Sample2"]),
+            // `{"gte":0,"lte":1826.25}`
+            (
+                "%7B%22gte%22%3A0%2C%22lte%22%3A1826.25%7D",
+                vec!["Sample1", "Sample2", "Sample3"],
+            ),
+            // `{"gt":365.25}`
+            ("%7B%22gt%22%3A365.25%7D", vec!["Sample3", "Sample4"]),
+            // `not:{"lt":1826.25}`
+            (
+                "not%3A%7B%22lt%22%3A1826.25%7D",
+                vec!["Sample3", "Sample4", "Sample5"],
+            ),
+        ] {
+            let request = TestRequest::get()
+                .uri(&format!("/sample?age_at_collection={query}"))
+                .to_request();
+            let response: Value = call_and_read_body_json(&app, request).await;
+
+            let names = response["data"]
+                .as_array()
+                .unwrap()
+                .iter()
+                .map(|sample| sample["id"]["name"].as_str().unwrap())
+                .collect::<Vec<_>>();
+            assert_eq!(names, expected, "{query}");
+        }
+
+        for query in [
+            // `{"gte":10,"lte":5}`
+            "%7B%22gte%22%3A10%2C%22lte%22%3A5%7D",
+            // `{"gte":"ten"}`
+            "%7B%22gte%22%3A%22ten%22%7D",
+            "ten",
+        ] {
+            let request = TestRequest::get()
+                .uri(&format!("/sample?age_at_collection={query}"))
+                .to_request();
+            let response = call_service(&app, request).await;
+            assert_eq!(
+                response.status(),
+                StatusCode::UNPROCESSABLE_ENTITY,
+                "{query}"
+            );
+
+            let request = TestRequest::get()
+                .uri(&format!("/sample?age_at_collection={query}"))
+                .to_request();
+            let response: Value = call_and_read_body_json(&app, request).await;
+            assert_eq!(response["errors"][0]["kind"], "InvalidParameters");
+            assert_eq!(
+                response["errors"][0]["parameters"],
+                serde_json::json!(["age_at_collection"])
+            );
+        }
+    }
+
+    #[actix_web::test]
+    async fn it_filters_case_insensitively_when_requested() {
+        let store = Data::new(Store {
+            samples: Mutex::new(samples()),
+        });
+
+        let app = init_service(App::new().configure(configure(store))).await;
+
+        for (uri, expected) in [
+            ("/sample?diagnosis=diagnosis%20a", vec![]),
+            (
+                "/sample?diagnosis=diagnosis%20a&case_insensitive=true",
+                vec!["Sample1", "Sample2"],
+            ),
+            (
+                "/sample?diagnosis_category=choroid%20plexus%20tumors&case_insensitive=true",
+                vec!["Sample5", "Sample6"],
+            ),
+        ] {
+            let request = TestRequest::get().uri(uri).to_request();
+            let response: Value = call_and_read_body_json(&app, request).await;
+
+            // NOTE: an empty page is returned as an empty array.
+            let names = response
+                .get("data")
+                .unwrap_or(&response)
+                .as_array()
+                .unwrap()
+                .iter()
+                .map(|sample| sample["id"]["name"].as_str().unwrap())
+                .collect::<Vec<_>>();
+            assert_eq!(names, expected, "{uri}");
+        }
+    }
+
+    #[actix_web::test]
+    async fn it_suggests_keys_for_unrecognized_filter_parameters() {
+        let store = Data::new(Store {
+            samples: Mutex::new(samples()),
+        });
+
+        let app = init_service(App::new().configure(configure(store))).await;
+
+        let request = TestRequest::get()
+            .uri("/sample?anatomic_sites=Chest&metadata.unharmonized.foo=bar")
+            .to_request();
+        let response = call_service(&app, request).await;
+        assert_eq!(response.status(), StatusCode::UNPROCESSABLE_ENTITY);
+
+        let errors: Value = actix_web::test::read_body_json(response).await;
+        assert_eq!(
+            errors["errors"][0]["parameters"],
+            serde_json::json!(["anatomic_sites"])
+        );
+        assert_eq!(
+            errors["errors"][0]["reason"],
+            "Unrecognized parameter(s): `anatomic_sites` (did you mean `anatomical_sites`?)."
+        );
+
+        let request = TestRequest::get()
+            .uri("/sample?anatomical_sites=Chest&metadata.unharmonized.foo=bar&expand=subject")
+            .to_request();
+        let response = call_service(&app, request).await;
+        assert_eq!(response.status(), StatusCode::OK);
+    }
+
+    /// Requests `uri` from an application serving `samples` and returns the
+    /// names of the samples within the response (in order).
+    async fn sorted_names(samples: Vec<Sample>, uri: &str) -> Vec<String> {
+        let store = Data::new(Store {
+            samples: Mutex::new(samples),
+        });
+
+        let app = init_service(App::new().configure(configure(store))).await;
+        let request = TestRequest::get().uri(uri).to_request();
+        let response: Value = call_and_read_body_json(&app, request).await;
+
+        response["data"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|sample| sample["id"]["name"].as_str().unwrap().to_string())
+            .collect()
+    }
+
+    #[actix_web::test]
+    async fn it_sorts_samples_by_a_string_field() {
+        // Ties are broken by identifier and samples without a diagnosis
+        // (whether or not they have metadata) are sorted last.
+        assert_eq!(
+            sorted_names(samples(), "/sample?sort=diagnosis").await,
+            vec![
+                "Sample1", "Sample2", "Sample3", "Sample4", "Sample5", "Sample7", "Sample6",
+                "Sample8"
+            ]
+        );
+        assert_eq!(
+            sorted_names(samples(), "/sample?sort=diagnosis&order=desc").await,
+            vec![
+                "Sample7", "Sample5", "Sample4", "Sample3", "Sample1", "Sample2", "Sample6",
+                "Sample8"
+            ]
+        );
+    }
+
+    #[actix_web::test]
+    async fn it_sorts_samples_by_a_numeric_field() {
+        use ccdi_models::metadata::field::unowned::sample::AgeAtDiagnosis;
+        use ordered_float::OrderedFloat;
+
+        let namespace = random_namespace();
+
+        let sample = |name: &str, age_at_diagnosis: Option<f32>| {
+            let mut builder = Builder::default();
+
+            if let Some(age_at_diagnosis) = age_at_diagnosis {
+                builder = builder.age_at_diagnosis(AgeAtDiagnosis::new(
+                    models::sample::metadata::AgeAtDiagnosis::from(OrderedFloat(age_at_diagnosis)),
+                    None,
+                    None,
+                    None,
+                ));
+            }
+
+            Sample::new(
+                Identifier::new(namespace.id().clone(), name),
+                models::subject::Identifier::new(namespace.id().clone(), "Subject1"),
+                None,
+                Some(builder.build()),
+            )
+        };
+
+        let samples = || {
+            vec![
+                sample("Sample1", Some(365.25)),
+                sample("Sample2", None),
+                sample("Sample3", Some(9.0)),
+                sample("Sample4", Some(10.5)),
+            ]
+        };
+
+        // NOTE: `9` sorts before `10.5` numerically (but not lexicographically).
+        assert_eq!(
+            sorted_names(samples(), "/sample?sort=age_at_diagnosis").await,
+            vec!["Sample3", "Sample4", "Sample1", "Sample2"]
+        );
+        assert_eq!(
+            sorted_names(samples(), "/sample?sort=age_at_diagnosis&order=desc").await,
+            vec!["Sample1", "Sample4", "Sample3", "Sample2"]
+        );
+    }
+
+    #[actix_web::test]
+    async fn it_preserves_the_sort_within_links() {
+        let store = Data::new(Store {
+            samples: Mutex::new(samples()),
+        });
+
+        let app = init_service(App::new().configure(configure(store))).await;
+
+        let request = TestRequest::get()
+            .uri("/sample?sort=diagnosis&order=desc&page=2&per_page=2")
+            .to_request();
+        let response = call_service(&app, request).await;
+        assert_eq!(response.status(), StatusCode::OK);
+
+        let link = response.headers().get("link").unwrap().to_str().unwrap();
+        assert!(link.contains(
+            "<http://localhost:8000/sample?sort=diagnosis&order=desc&page=3&per_page=2>; \
+             rel=\"next\""
+        ));
+
+        assert_eq!(
+            sorted_names(
+                samples(),
+                "/sample?sort=diagnosis&order=desc&page=2&per_page=2"
+            )
+            .await,
+            vec!["Sample4", "Sample3"]
+        );
+
+        for uri in ["/sample?sort=handedness", "/sample?order=desc"] {
+            let request = TestRequest::get().uri(uri).to_request();
+            let response = call_service(&app, request).await;
+            assert_eq!(response.status(), StatusCode::UNPROCESSABLE_ENTITY, "{uri}");
+        }
+    }
+
+    /// Requests `uri` (with the `Accept` header, if provided) and parses each
+    /// line of the NDJSON response as a [`Sample`].
+    async fn streamed(uri: &str, accept: Option<&str>) -> Vec<Sample> {
+        let store = Data::new(Store {
+            samples: Mutex::new(samples()),
+        });
+
+        let app = init_service(App::new().configure(configure(store))).await;
+
+        let mut request = TestRequest::get().uri(uri);
+
+        if let Some(accept) = accept {
+            request = request.insert_header((header::ACCEPT, accept));
+        }
+
+        let response = call_service(&app, request.to_request()).await;
+        assert_eq!(response.status(), StatusCode::OK, "{uri}");
+        assert_eq!(
+            response.headers().get(header::CONTENT_TYPE).unwrap(),
+            "application/x-ndjson"
+        );
+
+        let body = String::from_utf8(read_body(response).await.to_vec()).unwrap();
+        body.lines()
+            .map(|line| serde_json::from_str::<Sample>(line).unwrap())
+            .collect()
+    }
+
+    #[actix_web::test]
+    async fn it_streams_samples_as_ndjson() {
+        // Every sample is streamed (regardless of the default page size).
+        assert_eq!(
+            streamed("/sample", Some("application/x-ndjson"))
+                .await
+                .len(),
+            samples().len()
+        );
+
+        // The `format` parameter takes precedence over the `Accept` header.
+        assert_eq!(
+            streamed("/sample?format=ndjson", Some("application/json"))
+                .await
+                .len(),
+            samples().len()
+        );
+
+        // Filters and sorting still apply.
+        let names = |samples: Vec<Sample>| {
+            samples
+                .iter()
+                .map(|sample| sample.id().name().to_string())
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(
+            names(streamed("/sample?format=ndjson&diagnosis=Diagnosis%20A", None).await),
+            vec!["Sample1", "Sample2"]
+        );
+        assert_eq!(
+            names(streamed("/sample?format=ndjson&sort=diagnosis&order=desc", None).await),
+            vec![
+                "Sample7", "Sample5", "Sample4", "Sample3", "Sample1", "Sample2", "Sample6",
+                "Sample8"
+            ]
+        );
+    }
+
+    #[actix_web::test]
+    async fn it_rejects_pagination_when_streaming_ndjson() {
+        let store = Data::new(Store {
+            samples: Mutex::new(samples()),
+        });
+
+        let app = init_service(App::new().configure(configure(store))).await;
+
+        for (uri, parameters) in [
+            (
+                "/sample?format=ndjson&page=2&per_page=2",
+                json!(["page", "per_page"]),
+            ),
+            ("/sample?format=ndjson&per_page=2", json!(["per_page"])),
+            ("/sample?format=ndjson&expand=subject", json!(["expand"])),
+        ] {
+            let request = TestRequest::get().uri(uri).to_request();
+            let response = call_service(&app, request).await;
+            assert_eq!(response.status(), StatusCode::UNPROCESSABLE_ENTITY, "{uri}");
+
+            let errors: Value = serde_json::from_slice(&read_body(response).await).unwrap();
+            assert_eq!(errors["errors"][0]["parameters"], parameters, "{uri}");
+        }
+
+        // Pagination is still supported for JSON responses.
+        let request = TestRequest::get()
+            .uri("/sample?format=json&page=2&per_page=2")
+            .to_request();
+        let response = call_service(&app, request).await;
+        assert_eq!(response.status(), StatusCode::OK);
+    }
+
+    /// A curated Uberon hierarchy covering the anatomical sites within
+    /// [`site_samples()`].
+    const HIERARCHY: &str = "child_id\tchild_label\tparent_id\tparent_label
+UBERON:0004711\tjugular vein\tUBERON:0001638\tvein
+UBERON:0001638\tvein\tUBERON:0001062\tanatomical entity
+UBERON:0001153\tcaecum\tUBERON:0001062\tanatomical entity
+";
+
+    fn site_samples() -> Vec<Sample> {
+        use models::metadata::field::unowned::sample::AnatomicalSite;
+        use models::sample::metadata::AnatomicalSite as Site;
+
+        let namespace = random_namespace();
+
+        let sample = |name: &str, sites: Option<Vec<Site>>| {
+            let metadata = sites.map(|sites| {
+                sites
+                    .into_iter()
+                    .fold(Builder::default(), |builder, site| {
+                        builder.append_anatomical_site(AnatomicalSite::new(site, None, None, None))
+                    })
+                    .build()
+            });
+
+            Sample::new(
+                Identifier::new(namespace.id().clone(), name),
+                models::subject::Identifier::new(namespace.id().clone(), "Subject1"),
+                None,
+                metadata,
+            )
+        };
+
+        vec![
+            sample("Sample1", Some(vec![Site::JugularVein, Site::Caecum])),
+            sample("Sample2", Some(vec![Site::JugularVein])),
+            // A sample with metadata but without any sites.
+            sample("Sample3", Some(Vec::new())),
+            // A sample without any metadata.
+            sample("Sample4", None),
+        ]
+    }
+
+    async fn count_sites(uri: &str, hierarchy: Option<&str>) -> (StatusCode, Value) {
+        let store = Data::new(Store {
+            samples: Mutex::new(site_samples()),
+        });
+
+        let mut app = App::new();
+
+        if let Some(hierarchy) = hierarchy {
+            app = app.app_data(Data::new(hierarchy.parse::<uberon::Hierarchy>().unwrap()));
+        }
+
+        let app = init_service(app.configure(configure(store))).await;
+        let response = call_service(&app, TestRequest::get().uri(uri).to_request()).await;
+        let status = response.status();
+
+        (
+            status,
+            serde_json::from_slice(&read_body(response).await).unwrap(),
+        )
+    }
+
+    #[actix_web::test]
+    async fn it_counts_a_sample_with_two_sites_twice() {
+        let (status, body) = count_sites("/sample/by/anatomical_sites/count", None).await;
+
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body["multivalued"], true);
+        assert_eq!(body["total"], 5);
+        assert_eq!(body["missing"], 1);
+        assert_eq!(
+            body["values"],
+            json!([
+                { "value": "jugular vein", "count": 2 },
+                { "value": "caecum", "count": 1 },
+                { "value": null, "count": 1 },
+            ])
+        );
+    }
+
+    #[actix_web::test]
+    async fn it_counts_samples_without_sites_within_the_null_bucket() {
+        let (status, body) = count_sites(
+            "/sample/by/anatomical_sites/count?distinguish_missing=true",
+            None,
+        )
+        .await;
+
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body["missing"], 1);
+        assert_eq!(body["null"], 1);
+        assert!(body["values"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .all(|value| !value["value"].is_null()));
+    }
+
+    #[actix_web::test]
+    async fn it_rolls_sites_up_to_an_uberon_ancestor() {
+        let (status, body) = count_sites(
+            "/sample/by/anatomical_sites/count?group_by_uberon_ancestor=UBERON:0001638",
+            Some(HIERARCHY),
+        )
+        .await;
+
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(
+            body["values"],
+            json!([
+                { "value": "vein", "count": 2 },
+                { "value": "caecum", "count": 1 },
+                { "value": null, "count": 1 },
+            ])
+        );
+
+        // Both sites of `Sample1` roll up to the ancestor, so the sample is
+        // only counted once.
+        let (status, body) = count_sites(
+            "/sample/by/anatomical_sites/count?group_by_uberon_ancestor=http://purl.obolibrary.org/obo/UBERON_0001062",
+            Some(HIERARCHY),
+        )
+        .await;
+
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body["total"], 4);
+        assert_eq!(
+            body["values"],
+            json!([
+                { "value": "anatomical entity", "count": 2 },
+                { "value": null, "count": 1 },
+            ])
+        );
+    }
+
+    #[actix_web::test]
+    async fn it_rejects_invalid_uberon_ancestors() {
+        for (uri, hierarchy, message) in [
+            (
+                "/sample/by/anatomical_sites/count?group_by_uberon_ancestor=UBERON:0001638",
+                None,
+                "no curated uberon hierarchy is configured for this server",
+            ),
+            (
+                "/sample/by/anatomical_sites/count?group_by_uberon_ancestor=UBERON:0000000",
+                Some(HIERARCHY),
+                "`uberon:0000000` is not a term within the curated uberon hierarchy",
+            ),
+            (
+                "/sample/by/tissue_type/count?group_by_uberon_ancestor=UBERON:0001638",
+                Some(HIERARCHY),
+                "only supported when counting samples by the `anatomical_sites` field",
+            ),
+        ] {
+            // NOTE: the reasons of invalid parameters errors are lowercased.
+            let (status, body) = count_sites(uri, hierarchy).await;
+
+            assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY, "{uri}");
+            assert_eq!(
+                body["errors"][0]["parameters"],
+                json!(["group_by_uberon_ancestor"]),
+                "{uri}"
+            );
+            assert_eq!(
+                body["errors"][0]["message"],
+                format!("Invalid value for parameter 'group_by_uberon_ancestor': {message}."),
+                "{uri}"
+            );
+        }
+    }
+
+    #[actix_web::test]
+    async fn it_does_not_mark_single_valued_counts_as_multivalued() {
+        let (status, body) = count_sites("/sample/by/tissue_type/count", None).await;
+
+        assert_eq!(status, StatusCode::OK);
+        assert!(body.get("multivalued").is_none());
+    }
+
+    #[actix_web::test]
+    async fn it_lists_the_distinct_values_of_a_field() {
+        use std::collections::BTreeMap;
+
+        use rand::rngs::StdRng;
+        use rand::SeedableRng as _;
+
+        let mut rng = StdRng::seed_from_u64(0);
+        let subjects = subject::Store::random_with_rng(20, 0.1, &mut rng);
+        let store = Data::new(Store::random_with_rng(
+            50,
+            subjects.subjects.lock().unwrap(),
+            0.1,
+            &mut rng,
+        ));
+
+        let mut expected = BTreeMap::new();
+
+        for sample in store.samples.lock().unwrap().iter() {
+            if let Some(phase) = sample
+                .metadata()
+                .and_then(|metadata| metadata.disease_phase())
+            {
+                // SAFETY: all metadata fields are able to be represented as
+                // [`serde_json::Value`]s.
+                let value = serde_json::to_value(phase.value()).unwrap();
+                *expected.entry(value.to_string()).or_insert(0usize) += 1;
+            }
+        }
+
+        let app = init_service(App::new().configure(configure(store.clone()))).await;
+        let request = TestRequest::get()
+            .uri("/sample/values/disease_phase")
+            .to_request();
+        let body: Value = call_and_read_body_json(&app, request).await;
+
+        let observed = body["values"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|value| {
+                (
+                    value["value"].to_string(),
+                    value["count"].as_u64().unwrap() as usize,
+                )
+            })
+            .collect::<BTreeMap<_, _>>();
+
+        assert_eq!(body["field"], "disease_phase");
+        assert_eq!(body["truncated"], false);
+        assert!(!observed.is_empty());
+        assert_eq!(observed, expected);
+
+        let app = init_service(
+            App::new()
+                .app_data(Data::new(values::Config::new(1)))
+                .configure(configure(store)),
+        )
+        .await;
+        let request = TestRequest::get()
+            .uri("/sample/values/disease_phase")
+            .to_request();
+        let body: Value = call_and_read_body_json(&app, request).await;
+
+        assert_eq!(body["truncated"], expected.len() > 1);
+        assert_eq!(body["values"].as_array().unwrap().len(), 1);
+        assert_eq!(
+            body["values"][0]["count"].as_u64().unwrap() as usize,
+            *expected.values().max().unwrap()
+        );
+
+        let request = TestRequest::get()
+            .uri("/sample/values/handedness")
+            .to_request();
+        let response = call_service(&app, request).await;
+        assert_eq!(response.status(), StatusCode::UNPROCESSABLE_ENTITY);
+    }
 }
@@ -4,6 +4,8 @@ use actix_web::get;
 use actix_web::web::Data;
 use actix_web::web::Query;
 use actix_web::web::ServiceConfig;
+use actix_web::HttpRequest;
+use actix_web::HttpResponse;
 use actix_web::Responder;
 
 use ccdi_models as models;
@@ -11,12 +13,14 @@ use ccdi_models as models;
 use models::Sample;
 
 use crate::filter::filter;
+use crate::filter::parameter_names;
 use crate::paginate;
 use crate::params::filter::SampleDiagnosis as FilterSampleDiagnosisParams;
 use crate::params::PaginationParams;
 use crate::responses::error;
 use crate::responses::Errors;
 use crate::responses::Samples;
+use crate::strict;
 
 use crate::routes::sample::Store;
 
@@ -58,6 +62,11 @@ pub fn configure(store: Data<Store>) -> impl FnOnce(&mut ServiceConfig) {
 ///   logical OR (`||`)). Matches are case-sensitive.
 /// * When the metadata field is `null` (in the case of singular or
 ///   multiple-valued metadata fields) or empty, the sample is not included.
+/// * Prefixing the query with `not:` (e.g., `not:Value`) negates it: the sample is
+///   included only if it does _not_ match the remainder of the query. When
+///   the metadata field is `null` or empty, the sample _is_ included in negated
+///   results. The query `not:null` includes only those samples where the
+///   metadata field has a value.
 /// * When multiple fields are provided as filters, a logical AND (`&&`) strings
 ///   together the predicates. In other words, all filters must match for a
 ///   sample to be returned. Note that this means that servers do not natively
@@ -179,8 +188,23 @@ pub fn configure(store: Data<Store>) -> impl FnOnce(&mut ServiceConfig) {
 pub async fn sample_diagnosis_index(
     filter_params: Query<FilterSampleDiagnosisParams>,
     pagination_params: Query<PaginationParams>,
+    request: HttpRequest,
     samples: Data<Store>,
+    pagination_config: Option<Data<paginate::Config>>,
+    strict_config: Option<Data<strict::Config>>,
 ) -> impl Responder {
+    if let Err(err) = strict::check(
+        request.query_string(),
+        &[
+            parameter_names::<FilterSampleDiagnosisParams>(),
+            parameter_names::<PaginationParams>(),
+        ]
+        .concat(),
+        strict_config.as_ref().map(|config| config.get_ref()),
+    ) {
+        return HttpResponse::UnprocessableEntity().json(Errors::from(err));
+    }
+
     let mut samples = samples.samples.lock().unwrap().clone();
 
     // See the note in the documentation for this endpoint: the results must be
@@ -191,6 +215,7 @@ pub async fn sample_diagnosis_index(
 
     paginate::response::<Sample, Samples>(
         pagination_params.0,
+        pagination_config.as_ref().map(|config| config.get_ref()),
         samples,
         "http://localhost:8000/sample-diagnosis",
     )
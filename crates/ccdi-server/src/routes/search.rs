@@ -0,0 +1,470 @@
+//! Routes related to searching across entities by a partial identifier.
+
+use actix_web::get;
+use actix_web::web::Data;
+use actix_web::web::Query;
+use actix_web::web::ServiceConfig;
+use actix_web::HttpRequest;
+use actix_web::HttpResponse;
+use actix_web::Responder;
+use url::form_urlencoded;
+
+use ccdi_models as models;
+
+use models::namespace;
+
+use crate::filter::parameter_names;
+use crate::paginate;
+use crate::params::PaginationParams;
+use crate::params::SearchParams;
+use crate::responses::error;
+use crate::responses::search::Entry;
+use crate::responses::search::Kind;
+use crate::responses::search::MatchedOn;
+use crate::responses::Errors;
+use crate::responses::Search;
+use crate::routes::file;
+use crate::routes::sample;
+use crate::routes::subject;
+use crate::strict;
+
+/// The maximum number of entities matched by a search (across every page).
+///
+/// Short fragments may match a large portion of the entities known by the
+/// server, so the matches beyond this number are dropped (and the response is
+/// marked as truncated).
+pub const MAX_RESULTS: usize = 1_000;
+
+/// Configures the [`ServiceConfig`] with the search paths.
+pub fn configure(
+    subjects: Data<subject::Store>,
+    samples: Data<sample::Store>,
+    files: Data<file::Store>,
+) -> impl FnOnce(&mut ServiceConfig) {
+    |config: &mut ServiceConfig| {
+        config
+            .app_data(subjects)
+            .app_data(samples)
+            .app_data(files)
+            .service(search_index);
+    }
+}
+
+/// An entity that may be matched by a search.
+struct Candidate<'a> {
+    /// The kind of the entity.
+    kind: Kind,
+
+    /// The namespace to which the entity belongs.
+    namespace: &'a namespace::Identifier,
+
+    /// The name of the entity.
+    name: String,
+
+    /// The names of the alternate identifiers of the entity.
+    alternates: Vec<String>,
+}
+
+/// Gets the names of the alternate identifiers of a subject.
+fn subject_alternates(subject: &models::Subject) -> Vec<String> {
+    use models::subject::identifier::referenced::Identifier;
+
+    subject
+        .metadata()
+        .and_then(|metadata| metadata.identifiers())
+        .into_iter()
+        .flatten()
+        .map(|identifier| match identifier.value() {
+            Identifier::Linked(identifier) => identifier.inner().name().to_string(),
+            Identifier::Unlinked(identifier) => identifier.to_string(),
+        })
+        .collect()
+}
+
+/// Gets the names of the alternate identifiers of a sample.
+fn sample_alternates(sample: &models::Sample) -> Vec<String> {
+    use models::sample::identifier::referenced::Identifier;
+
+    sample
+        .metadata()
+        .and_then(|metadata| metadata.identifiers())
+        .into_iter()
+        .flatten()
+        .map(|identifier| match identifier.value() {
+            Identifier::Linked(identifier) => identifier.inner().name().to_string(),
+            Identifier::Unlinked(identifier) => identifier.to_string(),
+        })
+        .collect()
+}
+
+/// Gets every entity that may be matched by a search in the order in which
+/// they are listed (subjects, then samples, then files, each ordered by
+/// identifier).
+fn candidates<'a>(
+    subjects: &'a [models::Subject],
+    samples: &'a [models::Sample],
+    files: &'a [models::File],
+) -> impl Iterator<Item = Candidate<'a>> {
+    let mut subjects = subjects.iter().collect::<Vec<_>>();
+    subjects.sort();
+
+    let mut samples = samples.iter().collect::<Vec<_>>();
+    samples.sort();
+
+    let mut files = files.iter().collect::<Vec<_>>();
+    files.sort();
+
+    let subjects = subjects.into_iter().map(|subject| Candidate {
+        kind: Kind::Subject,
+        namespace: subject.id().namespace(),
+        name: subject.id().name().to_string(),
+        alternates: subject_alternates(subject),
+    });
+
+    let samples = samples.into_iter().map(|sample| Candidate {
+        kind: Kind::Sample,
+        namespace: sample.id().namespace(),
+        name: sample.id().name().to_string(),
+        alternates: sample_alternates(sample),
+    });
+
+    // NOTE: files do not have alternate identifiers.
+    let files = files.into_iter().map(|file| Candidate {
+        kind: Kind::File,
+        namespace: file.id().namespace(),
+        name: file.id().name().to_string(),
+        alternates: Vec::new(),
+    });
+
+    subjects.chain(samples).chain(files)
+}
+
+/// Searches the subjects, samples, and files for the entities whose name (or
+/// the name of any of their alternate identifiers) contains `fragment`
+/// (case-insensitively).
+///
+/// At most `limit` entities are returned along with whether any more entities
+/// matched. An entity whose primary identifier matches is always reported as
+/// such, even when its alternate identifiers also match.
+///
+/// # Examples
+///
+/// ```
+/// use ccdi_server as server;
+///
+/// use server::responses::search::Kind;
+/// use server::routes::search::search;
+/// use server::routes::subject;
+///
+/// let subjects = subject::Store::random(10);
+/// let subjects = subjects.subjects.lock().unwrap();
+/// let name = subjects[0].id().name().to_string();
+///
+/// let (entries, truncated) = search(&subjects, &[], &[], &name.to_lowercase(), 10);
+/// assert!(entries
+///     .iter()
+///     .any(|entry| entry.kind() == Kind::Subject && entry.name() == name));
+/// assert!(!truncated);
+///
+/// let (entries, truncated) = search(&subjects, &[], &[], "", 1);
+/// assert_eq!(entries.len(), 1);
+/// assert!(truncated);
+/// ```
+pub fn search(
+    subjects: &[models::Subject],
+    samples: &[models::Sample],
+    files: &[models::File],
+    fragment: &str,
+    limit: usize,
+) -> (Vec<Entry>, bool) {
+    let fragment = fragment.to_lowercase();
+    let contains = |name: &str| name.to_lowercase().contains(&fragment);
+
+    let mut matches = candidates(subjects, samples, files).filter_map(|candidate| {
+        let matched_on = if contains(&candidate.name) {
+            MatchedOn::Primary
+        } else if candidate.alternates.iter().any(|name| contains(name)) {
+            MatchedOn::Alternate
+        } else {
+            return None;
+        };
+
+        Some(Entry::new(
+            candidate.kind,
+            candidate.namespace.clone(),
+            candidate.name,
+            matched_on,
+        ))
+    });
+
+    let entries = matches.by_ref().take(limit).collect::<Vec<_>>();
+    let truncated = matches.next().is_some();
+
+    (entries, truncated)
+}
+
+/// Searches for subjects, samples, and files by a fragment of their
+/// identifiers.
+///
+/// An entity matches when the name of its primary identifier or the name of
+/// any of its alternate identifiers (listed within the `identifiers` field of
+/// its metadata) contains the fragment provided as `q`. The fragment is
+/// matched case-insensitively, and each match reports whether it was made on
+/// the primary identifier or on an alternate identifier.
+///
+/// ### Ordering
+///
+/// Subjects are listed before samples, and samples are listed before files.
+/// Within each kind, entities are ordered by their primary identifier.
+///
+/// ### Pagination
+///
+/// This endpoint is paginated in the same manner as the `/subject` endpoint.
+/// At most 1,000 entities are matched across every page—when more entities
+/// match, the remaining entities are dropped and the response is marked as
+/// `truncated`. A search that matches no entities returns an empty page.
+#[utoipa::path(
+    get,
+    path = "/search",
+    params(SearchParams, PaginationParams),
+    tag = "Search",
+    responses(
+        (status = 200, description = "Successful operation.", body = responses::Search),
+        (
+            status = 422,
+            description = "Invalid query parameters.",
+            body = responses::Errors,
+            example = json!(Errors::from(error::Kind::invalid_parameters(
+                Some(vec![String::from("q")]),
+                String::from("must not be empty")
+            )))
+        ),
+    )
+)]
+#[get("/search")]
+// NOTE: actix-web extracts each set of query parameters and each piece of
+// shared state of a handler as a separate argument.
+#[allow(clippy::too_many_arguments)]
+pub async fn search_index(
+    params: Query<SearchParams>,
+    pagination_params: Query<PaginationParams>,
+    request: HttpRequest,
+    subjects: Data<subject::Store>,
+    samples: Data<sample::Store>,
+    files: Data<file::Store>,
+    pagination_config: Option<Data<paginate::Config>>,
+    strict_config: Option<Data<strict::Config>>,
+) -> impl Responder {
+    if let Err(err) = strict::check(
+        request.query_string(),
+        &[
+            parameter_names::<SearchParams>(),
+            parameter_names::<PaginationParams>(),
+        ]
+        .concat(),
+        strict_config.as_ref().map(|config| config.get_ref()),
+    ) {
+        return HttpResponse::UnprocessableEntity().json(Errors::from(err));
+    }
+
+    if params.q().is_empty() {
+        return HttpResponse::UnprocessableEntity().json(Errors::from(
+            error::Kind::invalid_parameters(
+                Some(vec![String::from("q")]),
+                String::from("must not be empty"),
+            ),
+        ));
+    }
+
+    let (entries, truncated) = search(
+        &subjects.subjects.lock().unwrap(),
+        &samples.samples.lock().unwrap(),
+        &files.files.lock().unwrap(),
+        params.q(),
+        MAX_RESULTS,
+    );
+
+    // NOTE: an empty page is returned (rather than the empty array returned
+    // by [`paginate::response()`]) so that a search without matches is
+    // distinguishable from an error.
+    if entries.is_empty() {
+        return HttpResponse::Ok().json(Search::from((entries, 0)));
+    }
+
+    let url = format!(
+        "http://localhost:8000/search?{}",
+        form_urlencoded::Serializer::new(String::new())
+            .append_pair("q", params.q())
+            .finish()
+    );
+
+    paginate::response_with(
+        pagination_params.0,
+        pagination_config.as_ref().map(|config| config.get_ref()),
+        entries,
+        &url,
+        |page| Search::from(page).with_truncated(truncated),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Mutex;
+
+    use actix_web::http::StatusCode;
+    use actix_web::test::call_and_read_body_json;
+    use actix_web::test::call_service;
+    use actix_web::test::init_service;
+    use actix_web::test::TestRequest;
+    use actix_web::App;
+    use nonempty::NonEmpty;
+    use serde_json::Value;
+
+    use ccdi_cde as cde;
+    use ccdi_models::metadata::field::unowned;
+    use ccdi_models::subject::identifier::referenced;
+    use ccdi_models::subject::identifier::unlinked;
+    use ccdi_models::subject::Kind as SubjectKind;
+
+    use crate::routes::namespace::NAMESPACES;
+
+    use super::*;
+
+    /// Creates a subject with `Subject-OZNL7P` as an alternate identifier, a
+    /// subject without any matching identifier, and a sample and a file whose
+    /// names contain `oznl7p` (in any case).
+    fn entities() -> (Vec<models::Subject>, Vec<models::Sample>, Vec<models::File>) {
+        let namespace = NAMESPACES.values().next().unwrap().id().clone();
+
+        let alternate = unowned::subject::Identifier::new(
+            referenced::Identifier::Unlinked(unlinked::Identifier::from(String::from(
+                "Legacy-OZNL7P",
+            ))),
+            None,
+            None,
+            None,
+        );
+
+        let subjects = vec![
+            models::Subject::new(
+                models::subject::Identifier::new(namespace.clone(), "Subject-AAAAAA"),
+                SubjectKind::Participant,
+                None,
+                Some(
+                    models::subject::metadata::Builder::default()
+                        .append_identifier(alternate)
+                        .build(),
+                ),
+            ),
+            models::Subject::new(
+                models::subject::Identifier::new(namespace.clone(), "Subject-BBBBBB"),
+                SubjectKind::Participant,
+                None,
+                None,
+            ),
+        ];
+
+        let sample_id = models::sample::Identifier::new(namespace.clone(), "Sample-oznl7p");
+        let samples = vec![models::Sample::new(
+            sample_id.clone(),
+            subjects[0].id().clone(),
+            None,
+            None,
+        )];
+
+        let files = vec![models::File::new(
+            models::file::Identifier::new(namespace, cde::v1::file::Name::new("File-Oznl7P.txt")),
+            NonEmpty::new(sample_id),
+            None,
+            None,
+        )];
+
+        (subjects, samples, files)
+    }
+
+    fn configured(
+        (subjects, samples, files): (Vec<models::Subject>, Vec<models::Sample>, Vec<models::File>),
+    ) -> impl FnOnce(&mut ServiceConfig) {
+        configure(
+            Data::new(subject::Store {
+                subjects: Mutex::new(subjects),
+            }),
+            Data::new(sample::Store {
+                samples: Mutex::new(samples),
+            }),
+            Data::new(file::Store {
+                files: Mutex::new(files),
+            }),
+        )
+    }
+
+    #[test]
+    fn it_matches_across_entities() {
+        let (subjects, samples, files) = entities();
+        let (entries, truncated) = search(&subjects, &samples, &files, "OZNL7P", MAX_RESULTS);
+
+        assert!(!truncated);
+        assert_eq!(
+            entries
+                .iter()
+                .map(|entry| (entry.kind(), entry.name(), entry.matched_on()))
+                .collect::<Vec<_>>(),
+            vec![
+                (Kind::Subject, "Subject-AAAAAA", MatchedOn::Alternate),
+                (Kind::Sample, "Sample-oznl7p", MatchedOn::Primary),
+                (Kind::File, "File-Oznl7P.txt", MatchedOn::Primary),
+            ]
+        );
+    }
+
+    #[test]
+    fn it_caps_the_matches() {
+        let (subjects, samples, files) = entities();
+
+        let (entries, truncated) = search(&subjects, &samples, &files, "-", 2);
+        assert_eq!(entries.len(), 2);
+        assert!(truncated);
+
+        let (entries, truncated) = search(&subjects, &samples, &files, "-", 4);
+        assert_eq!(entries.len(), 4);
+        assert!(!truncated);
+    }
+
+    #[actix_web::test]
+    async fn it_searches_and_paginates() {
+        let app = init_service(App::new().configure(configured(entities()))).await;
+
+        let request = TestRequest::get()
+            .uri("/search?q=oznl7p&per_page=2")
+            .to_request();
+        let response: Value = call_and_read_body_json(&app, request).await;
+
+        assert_eq!(response["truncated"], false);
+        assert_eq!(response["summary"]["counts"]["all"], 3);
+        assert_eq!(
+            response["data"][0],
+            serde_json::json!({
+                "kind": "subject",
+                "namespace": {
+                    "organization": "example-organization",
+                    "name": "ExampleNamespaceOne"
+                },
+                "name": "Subject-AAAAAA",
+                "matched_on": "alternate"
+            })
+        );
+        assert_eq!(response["data"].as_array().unwrap().len(), 2);
+        assert!(response["links"]["next"]
+            .as_str()
+            .unwrap()
+            .contains("q=oznl7p"));
+
+        let request = TestRequest::get().uri("/search?q=nothing").to_request();
+        let response: Value = call_and_read_body_json(&app, request).await;
+        assert!(response["data"].as_array().unwrap().is_empty());
+
+        let request = TestRequest::get().uri("/search?q=%20").to_request();
+        let response = call_service(&app, request).await;
+        assert_eq!(response.status(), StatusCode::UNPROCESSABLE_ENTITY);
+    }
+}
@@ -1,34 +1,115 @@
 //! Routes related to subjects.
 
+use std::collections::BTreeSet;
 use std::sync::Mutex;
 
 use actix_web::get;
+use actix_web::http::header;
+use actix_web::post;
+use actix_web::web::Bytes;
 use actix_web::web::Data;
 use actix_web::web::Path;
 use actix_web::web::Query;
 use actix_web::web::ServiceConfig;
+use actix_web::HttpRequest;
 use actix_web::HttpResponse;
 use actix_web::Responder;
+use rand::Rng;
 use serde_json::Value;
 
 use ccdi_cde as cde;
 use ccdi_models as models;
 
+use models::metadata::Generation;
+use models::metadata::DEFAULT_METADATA_LESS_FRACTION;
 use models::subject::Identifier;
+use models::Sample;
 use models::Subject;
 
-use crate::filter::filter;
+use crate::filter::alias::deserialize;
+use crate::filter::alias::Aliases as _;
+use crate::filter::check_keys;
+use crate::filter::expression::evaluate;
+use crate::filter::expression::Expression;
+use crate::filter::filter_with_case;
+use crate::filter::parameter_names;
+use crate::filter::retain_keys;
+use crate::filter::rule::Case;
+use crate::filter::unharmonized;
+use crate::filter::unharmonized::UnharmonizedQuery;
+use crate::filter::validate;
+use crate::filter::validate_with_prefix;
+use crate::ndjson;
 use crate::paginate;
+use crate::paginate::Totals;
+use crate::params::filter::Matching as FilterMatchingParams;
+use crate::params::filter::Sample as FilterSampleParams;
 use crate::params::filter::Subject as FilterSubjectParams;
+use crate::params::format::Format;
+use crate::params::related;
+use crate::params::related::SAMPLE_PREFIX;
+use crate::params::ComputedParams;
+use crate::params::FieldsParams;
+use crate::params::FormatParams;
+use crate::params::MissingParams;
 use crate::params::PaginationParams;
+use crate::params::ScopeParams;
+use crate::params::SortParams;
+use crate::project;
 use crate::responses;
 use crate::responses::by::count::ValueCount;
+use crate::responses::demographics::Cell;
 use crate::responses::error;
+use crate::responses::Demographics;
 use crate::responses::Errors;
+use crate::responses::Files;
+use crate::responses::Samples;
 use crate::responses::Subjects;
 use crate::responses::Summary;
-use crate::routes::namespace::random_namespace;
+use crate::routes::duplicates;
+use crate::routes::file;
+use crate::routes::metadata::UnharmonizedRegistries;
+use crate::routes::namespace::check_known;
+use crate::routes::namespace::is_known;
+use crate::routes::namespace::random_namespace_with_rng;
+use crate::routes::sample;
 use crate::routes::GroupByResults;
+use crate::sort::sort;
+use crate::strict;
+use crate::suggest;
+use crate::values;
+
+/// An error related to a [`Store`].
+#[derive(Debug)]
+pub enum Error {
+    /// More than one subject has the same identifier (each duplicated identifier
+    /// is listed once).
+    DuplicateIdentifiers(Vec<Identifier>),
+
+    /// The namespace of a subject's identifier is not known by this server.
+    UnknownNamespace(models::namespace::Identifier),
+}
+
+impl std::fmt::Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::DuplicateIdentifiers(identifiers) => write!(
+                f,
+                "duplicate subject identifiers: {}",
+                identifiers
+                    .iter()
+                    .map(|identifier| identifier.to_string())
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            ),
+            Error::UnknownNamespace(namespace) => {
+                write!(f, "unknown namespace: {namespace}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for Error {}
 
 /// A store for [`Subject`]s.
 #[derive(Debug)]
@@ -38,6 +119,51 @@ pub struct Store {
 }
 
 impl Store {
+    /// Attempts to create a new [`Store`] from the provided [`Subject`]s.
+    ///
+    /// An error is returned if the namespace of any subject's identifier is not
+    /// known by this server (see
+    /// [`NAMESPACES`](crate::routes::namespace::NAMESPACES)) or if more than
+    /// one subject has the same identifier (in which case, every duplicated
+    /// identifier is reported).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ccdi_server as server;
+    ///
+    /// use server::routes::subject;
+    ///
+    /// let store = subject::Store::try_new(Vec::new())?;
+    /// assert!(store.subjects.lock().unwrap().is_empty());
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn try_new(subjects: Vec<Subject>) -> Result<Self, Error> {
+        for subject in &subjects {
+            let namespace = subject.id().namespace();
+
+            if !is_known(namespace.organization().as_str(), namespace.name().as_str()) {
+                return Err(Error::UnknownNamespace(namespace.clone()));
+            }
+        }
+
+        let duplicates = duplicates(subjects.iter().map(|subject| subject.id()))
+            .into_iter()
+            .map(|duplicate| duplicate.identifier.clone())
+            .collect::<BTreeSet<_>>();
+
+        if !duplicates.is_empty() {
+            return Err(Error::DuplicateIdentifiers(
+                duplicates.into_iter().collect(),
+            ));
+        }
+
+        Ok(Self {
+            subjects: Mutex::new(subjects),
+        })
+    }
+
     /// Creates a new [`Store`] with randomized [`Subject`]s.
     ///
     /// # Examples
@@ -50,19 +176,121 @@ impl Store {
     /// let subjects = subject::Store::random(100);
     /// ```
     pub fn random(count: usize) -> Self {
+        Self::random_with_metadata_less_fraction(count, DEFAULT_METADATA_LESS_FRACTION)
+    }
+
+    /// Creates a new [`Store`] with randomized [`Subject`]s where
+    /// `metadata_less_fraction` is the probability that each generated
+    /// [`Subject`] has no metadata.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `metadata_less_fraction` is not within `0.0..=1.0`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ccdi_server as server;
+    ///
+    /// use server::routes::subject;
+    ///
+    /// let subjects = subject::Store::random_with_metadata_less_fraction(100, 1.0);
+    ///
+    /// assert!(subjects
+    ///     .subjects
+    ///     .lock()
+    ///     .unwrap()
+    ///     .iter()
+    ///     .all(|subject| !subject.has_metadata()));
+    /// ```
+    pub fn random_with_metadata_less_fraction(count: usize, metadata_less_fraction: f64) -> Self {
+        Self::random_with_rng(count, metadata_less_fraction, &mut rand::thread_rng())
+    }
+
+    /// Creates a new [`Store`] with randomized [`Subject`]s using the provided
+    /// random number generator, where `metadata_less_fraction` is the
+    /// probability that each generated [`Subject`] has no metadata.
+    ///
+    /// The same seed always generates the same [`Store`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `metadata_less_fraction` is not within `0.0..=1.0`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ccdi_server as server;
+    /// use rand::rngs::StdRng;
+    /// use rand::SeedableRng as _;
+    ///
+    /// use server::routes::subject;
+    ///
+    /// let a = subject::Store::random_with_rng(100, 0.5, &mut StdRng::seed_from_u64(42));
+    /// let b = subject::Store::random_with_rng(100, 0.5, &mut StdRng::seed_from_u64(42));
+    ///
+    /// assert_eq!(*a.subjects.lock().unwrap(), *b.subjects.lock().unwrap());
+    /// ```
+    pub fn random_with_rng<R: Rng + ?Sized>(
+        count: usize,
+        metadata_less_fraction: f64,
+        rng: &mut R,
+    ) -> Self {
+        Self::random_with_generation(count, metadata_less_fraction, Generation::Coherent, rng)
+    }
+
+    /// Creates a new [`Store`] with randomized [`Subject`]s using the provided
+    /// random number generator, where `metadata_less_fraction` is the
+    /// probability that each generated [`Subject`] has no metadata and
+    /// `generation` is the strategy used to generate any metadata.
+    ///
+    /// The same seed always generates the same [`Store`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `metadata_less_fraction` is not within `0.0..=1.0`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ccdi_models as models;
+    /// use ccdi_server as server;
+    /// use rand::rngs::StdRng;
+    /// use rand::SeedableRng as _;
+    ///
+    /// use models::metadata::Generation;
+    /// use server::routes::subject;
+    ///
+    /// let subjects = subject::Store::random_with_generation(
+    ///     100,
+    ///     0.5,
+    ///     Generation::Independent,
+    ///     &mut StdRng::seed_from_u64(42),
+    /// );
+    /// ```
+    pub fn random_with_generation<R: Rng + ?Sized>(
+        count: usize,
+        metadata_less_fraction: f64,
+        generation: Generation,
+        rng: &mut R,
+    ) -> Self {
+        let subjects = (0..count)
+            .map(|i| {
+                let identifier = Identifier::new(
+                    random_namespace_with_rng(rng).id().clone(),
+                    cde::v1::subject::Name::new(format!("Subject{}", i + 1)),
+                );
+
+                Subject::random_with_generation(identifier, metadata_less_fraction, generation, rng)
+            })
+            .collect::<Vec<_>>();
+
+        // NOTE: the names of the generated subjects are numbered, so the
+        // identifiers of the generated subjects are never duplicated.
+        debug_assert!(duplicates(subjects.iter().map(|subject| subject.id())).is_empty());
+
         Self {
-            subjects: Mutex::new(
-                (0..count)
-                    .map(|i| {
-                        let identifier = Identifier::new(
-                            random_namespace().id().clone(),
-                            cde::v1::subject::Name::new(format!("Subject{}", i + 1)),
-                        );
-
-                        Subject::random(identifier)
-                    })
-                    .collect::<Vec<_>>(),
-            ),
+            subjects: Mutex::new(subjects),
         }
     }
 }
@@ -73,9 +301,14 @@ pub fn configure(store: Data<Store>) -> impl FnOnce(&mut ServiceConfig) {
         config
             .app_data(store)
             .service(subject_index)
+            .service(subject_filter)
             .service(subjects_by_count)
+            .service(subject_values)
             .service(subject_show)
-            .service(subject_summary);
+            .service(subject_samples)
+            .service(subject_files)
+            .service(subject_summary)
+            .service(subject_demographics);
     }
 }
 
@@ -101,17 +334,73 @@ pub fn configure(store: Data<Store>) -> impl FnOnce(&mut ServiceConfig) {
 ///   logical OR (`||`)). Matches are case-sensitive.
 /// * When the metadata field is `null` (in the case of singular or
 ///   multiple-valued metadata fields) or empty, the subject is not included.
+/// * Prefixing the query with `not:` (e.g., `not:Value`) negates it: the subject is
+///   included only if it does _not_ match the remainder of the query. When
+///   the metadata field is `null` or empty, the subject _is_ included in negated
+///   results. The query `not:null` includes only those subjects where the
+///   metadata field has a value.
+/// * Numeric fields (`age_at_vital_status`) accept either a
+///   number, which is matched exactly, or a JSON object with any of the `gt`,
+///   `gte`, `lt`, and `lte` keys, which matches values within the bounds
+///   provided (e.g., `{"gte":0,"lte":1826.25}`). Numbers are interpreted as
+///   days unless a `unit` of `days`, `months`, or `years` is provided (e.g.,
+///   `{"gte":2,"unit":"years"}`). Invalid or unsatisfiable ranges are rejected
+///   with a `422`.
+/// * Providing `case_insensitive=true` opts in to case-insensitive matching for
+///   every filter parameter, including unharmonized fields (e.g., `hispanic or
+///   latino` matches `Hispanic or Latino`). Matching is case-sensitive by
+///   default.
 /// * When multiple fields are provided as filters, a logical AND (`&&`) strings
 ///   together the predicates. In other words, all filters must match for a
-///   subject to be returned. Note that this means that servers do not natively
-///   support logical OR (`||`) across multiple fields: that must be done by
-///   calling this endpoint with each of your desired queries and performing a
-///   set union of those subjects out of band.
+///   subject to be returned. To combine filters with a logical OR
+///   (`||`) (or to group them), post a filter expression to the
+///   `/subject/filter` endpoint instead.
+///
+/// ### Filtering by Samples
+///
+/// Subjects may also be filtered by the properties of their samples by
+/// prefixing any filter parameter of the `/sample` endpoint with `sample.`
+/// (e.g., `?sample.library_strategy=RNA-Seq&sample.disease_phase=Relapse`,
+/// including unharmonized fields as `sample.metadata.unharmonized.<field>`).
+///
+/// * The prefixed filters are matched against each sample exactly as they
+///   would be by the `/sample` endpoint (including `case_insensitive`), and a
+///   subject is included in the results only if _at least one_ of its samples
+///   matches _all_ of them.
+/// * The subject-level filters must also match (a logical AND (`&&`)).
+/// * A prefixed parameter that is not a filter parameter of the `/sample`
+///   endpoint is rejected with a `422`.
 ///
 /// ### Ordering
 ///
 /// This endpoint has default ordering requirements—those details are documented
 /// in the `responses::Subjects` schema.
+///
+/// Results may instead be sorted by one of a subset of the harmonized metadata
+/// fields with the `sort` and `order` parameters (e.g.,
+/// `?sort=vital_status&order=desc`). Subjects without a value for the field are
+/// always sorted last, ties are broken by identifier, and the sort parameters
+/// are preserved within the `link` header. Sorting by any other field is
+/// rejected with a `422`.
+///
+/// ### Streaming
+///
+/// Large listings may instead be streamed as newline-delimited JSON
+/// (`application/x-ndjson`) by providing `format=ndjson` or, when the `format`
+/// parameter is not provided, an `Accept` header of `application/x-ndjson`.
+/// Every matching subject (filtered and sorted as described above) is returned
+/// as a JSON object on its own line without the enclosing response or the
+/// `link` header. Because the results are not paginated, providing `page` or
+/// `per_page` along with NDJSON is rejected with a `422`.
+///
+/// ### Computed Fields
+///
+/// Each subject includes a `survival_days` field that is derived by the server
+/// (it is not a common data element): the `age_at_vital_status` of the subject
+/// minus the earliest `age_at_diagnosis` across its samples. The samples that
+/// supplied the earliest `age_at_diagnosis` are listed within its
+/// `provenance`, and the field is `null` when either value is missing.
+/// Providing `computed=false` omits the field.
 #[utoipa::path(
     get,
     path = "/subject",
@@ -130,6 +419,12 @@ pub fn configure(store: Data<Store>) -> impl FnOnce(&mut ServiceConfig) {
             * Filtering on field with multiple values should include the \
             `Subject` in the results if the query exactly matches any of the \
             values of the field for that `Subject` (case-sensitive).\n\
+            * When `case_insensitive=true` is provided, matches are \
+            case-insensitive instead.\n\
+            * Providing a JSON array of values (e.g., `[\"A\",\"B\"]`) \
+            should include the `Subject` in the results if the query matches \
+            any of the values within the array. Arrays may only contain \
+            strings, numbers, or booleans.\n\
             * Unlike harmonized fields, unharmonized fields must be prefixed \
             with `metadata.unharmonized`.\n\n\
             **Note:** this query parameter is intended to be symbolic of any \
@@ -140,13 +435,37 @@ pub fn configure(store: Data<Store>) -> impl FnOnce(&mut ServiceConfig) {
             `?metadata.unharmonized.<field>=value` is not supported, so \
             attempting to use it within Swagger UI will not work!"
         ),
+        (
+            "sample.<field>" = Option<String>,
+            Query,
+            nullable = false,
+            description = "Any filter parameter of the `/sample` endpoint \
+            prefixed with `sample.` (e.g., `sample.library_strategy=RNA-Seq`). \
+            Matches any subject with at least one sample that matches every \
+            prefixed filter.\n\n\
+            **Note:** this query parameter is intended to be symbolic of any \
+            sample filter parameter. The literal query parameter \
+            `?sample.<field>=value` is not supported, so attempting to use it \
+            within Swagger UI will not work!"
+        ),
+        FilterMatchingParams,
+        FieldsParams,
+        SortParams,
+        FormatParams,
+        ComputedParams,
         PaginationParams,
     ),
     responses(
         (
             status = 200,
             description = "Successful operation.",
-            body = responses::Subjects,
+            content(
+                (
+                    "application/json" = responses::Subjects,
+                    example = json!(*responses::examples::SUBJECTS)
+                ),
+                ("application/x-ndjson" = String)
+            ),
             headers(
                 (
                     "link" = String,
@@ -182,6 +501,18 @@ pub fn configure(store: Data<Store>) -> impl FnOnce(&mut ServiceConfig) {
                     headers are case insensitive. Though not required, we \
                     recommend an all lowercase name of `link` for this \
                     response header."
+                ),
+                (
+                    "x-total-count" = usize,
+                    description = "The number of entities known by the server \
+                    before any filters were applied (also reported as \
+                    `summary.counts.total`)."
+                ),
+                (
+                    "x-filtered-count" = usize,
+                    description = "The number of entities that matched the \
+                    filters across every page (also reported as \
+                    `summary.counts.filtered`)."
                 )
             )
         ),
@@ -216,27 +547,404 @@ pub fn configure(store: Data<Store>) -> impl FnOnce(&mut ServiceConfig) {
     )
 )]
 #[get("/subject")]
+// NOTE: actix-web extracts each set of query parameters and each piece of
+// shared state of a handler as a separate argument.
+#[allow(clippy::too_many_arguments)]
 pub async fn subject_index(
-    filter_params: Query<FilterSubjectParams>,
+    matching_params: Query<FilterMatchingParams>,
+    fields_params: Query<FieldsParams>,
+    sort_params: Query<SortParams>,
+    format_params: Query<FormatParams>,
+    computed_params: Query<ComputedParams>,
     pagination_params: Query<PaginationParams>,
+    request: HttpRequest,
     subjects: Data<Store>,
+    samples: Option<Data<sample::Store>>,
+    registries: Option<Data<UnharmonizedRegistries>>,
+    pagination_config: Option<Data<paginate::Config>>,
+    strict_config: Option<Data<strict::Config>>,
 ) -> impl Responder {
+    let (query, sample_query) = related::split(request.query_string(), SAMPLE_PREFIX);
+
+    let known = [
+        parameter_names::<FilterSubjectParams>(),
+        FilterSubjectParams::names(),
+        parameter_names::<FilterMatchingParams>(),
+        parameter_names::<FieldsParams>(),
+        parameter_names::<SortParams>(),
+        parameter_names::<FormatParams>(),
+        parameter_names::<ComputedParams>(),
+        parameter_names::<PaginationParams>(),
+    ]
+    .concat();
+
+    let mut warnings = Vec::new();
+
+    if let Err(err) = strict::handle(
+        check_keys(&query, &known),
+        strict_config.as_ref().map(|config| config.get_ref()),
+        &mut warnings,
+    ) {
+        return HttpResponse::UnprocessableEntity().json(Errors::from(err));
+    }
+
+    let known_sample = [
+        parameter_names::<FilterSampleParams>(),
+        FilterSampleParams::names(),
+    ]
+    .concat();
+
+    if let Err(err) = strict::handle(
+        related::check_keys(&sample_query, SAMPLE_PREFIX, &known_sample),
+        strict_config.as_ref().map(|config| config.get_ref()),
+        &mut warnings,
+    ) {
+        return HttpResponse::UnprocessableEntity().json(Errors::from(err));
+    }
+
+    // NOTE: any unrecognized sample keys that were ignored are removed so that
+    // they do not restrict the results to the subjects with samples.
+    let sample_query = retain_keys(&sample_query, &known_sample);
+
+    let format = format_params
+        .format()
+        .or_else(|| {
+            request
+                .headers()
+                .get(header::ACCEPT)
+                .and_then(|value| value.to_str().ok())
+                .and_then(Format::from_accept)
+        })
+        .unwrap_or(Format::Json);
+
+    if format == Format::Ndjson {
+        if let Err(err) = ndjson::reject_pagination(&pagination_params) {
+            return HttpResponse::UnprocessableEntity().json(Errors::from(err));
+        }
+    }
+
+    let filter_params = match deserialize::<FilterSubjectParams>(&query) {
+        Ok(filter_params) => filter_params,
+        Err(err) => return HttpResponse::UnprocessableEntity().json(Errors::from(err)),
+    };
+
+    if let Err(err) = validate(&filter_params) {
+        return HttpResponse::UnprocessableEntity().json(Errors::from(err));
+    }
+
+    let unharmonized_queries = match unharmonized::queries(&query) {
+        Ok(queries) => queries,
+        Err(err) => return HttpResponse::UnprocessableEntity().json(Errors::from(err)),
+    };
+
+    let sample_filter_params = match deserialize::<FilterSampleParams>(&sample_query) {
+        Ok(filter_params) => filter_params,
+        Err(err) => return HttpResponse::UnprocessableEntity().json(Errors::from(err)),
+    };
+
+    if let Err(err) = validate_with_prefix(&sample_filter_params, SAMPLE_PREFIX) {
+        return HttpResponse::UnprocessableEntity().json(Errors::from(err));
+    }
+
+    let sample_unharmonized_queries = match unharmonized::queries(&sample_query) {
+        Ok(queries) => queries,
+        Err(err) => return HttpResponse::UnprocessableEntity().json(Errors::from(err)),
+    };
+
+    unharmonized::warn_unregistered(
+        &unharmonized_queries,
+        registries.as_deref().map(|registries| &registries.subject),
+    );
+
+    let selection = match project::select(
+        &fields_params.0,
+        models::subject::metadata::Builder::default().build(),
+    ) {
+        Ok(selection) => selection,
+        Err(err) => return HttpResponse::UnprocessableEntity().json(Errors::from(err)),
+    };
+
     let mut subjects = subjects.subjects.lock().unwrap().clone();
+    let total = subjects.len();
 
     // See the note in the documentation for this endpoint: the results must be
     // sorted by identifier by default.
     subjects.sort();
 
-    let subjects = filter::<Subject, FilterSubjectParams>(subjects, filter_params.0);
+    let subjects = filter_with_case::<Subject, FilterSubjectParams>(
+        subjects,
+        filter_params,
+        matching_params.case(),
+    );
+    let mut subjects =
+        unharmonized::filter(subjects, &unharmonized_queries, matching_params.case());
+
+    if !sample_query.is_empty() {
+        let linked = subjects_of_matching_samples(
+            samples.as_ref().map(|samples| samples.get_ref()),
+            sample_filter_params,
+            &sample_unharmonized_queries,
+            matching_params.case(),
+        );
+
+        subjects.retain(|subject| linked.contains(subject.id()));
+    }
+
+    // NOTE: the results are sorted after they are filtered but before they are
+    // paginated so that each page is a slice of the sorted results.
+    let subjects = match sort(subjects, &sort_params) {
+        Ok(subjects) => subjects,
+        Err(err) => return HttpResponse::UnprocessableEntity().json(Errors::from(err)),
+    };
+
+    let computed = computed_from(
+        &computed_params,
+        samples.as_ref().map(|samples| samples.get_ref()),
+    );
+
+    // NOTE: every matching subject is streamed rather than paginated.
+    if format == Format::Ndjson {
+        let subjects = match &computed {
+            Some(samples) => responses::subject::with_survival_days(subjects, samples),
+            None => subjects.into_iter().map(responses::Subject::new).collect(),
+        };
+
+        return match selection {
+            Some(selection) => ndjson::response(
+                subjects
+                    .into_iter()
+                    .map(move |subject| selection.project_entity(&subject)),
+            ),
+            None => ndjson::response(subjects),
+        };
+    }
+
+    let url = sort_params.url("http://localhost:8000/subject");
+
+    // NOTE: the totals are computed once from the filtered results rather than
+    // for each page.
+    let totals = Totals::new(total, subjects.len());
+    let build = |page: (Vec<Subject>, usize)| {
+        let subjects = Subjects::from(page)
+            .with_totals(totals)
+            .with_warnings(warnings.clone());
+
+        match &computed {
+            Some(samples) => subjects.with_survival_days(samples),
+            None => subjects,
+        }
+    };
+
+    match selection {
+        Some(selection) => paginate::response_with_totals(
+            pagination_params.0,
+            pagination_config.as_ref().map(|config| config.get_ref()),
+            subjects,
+            totals,
+            &url,
+            |page| selection.project(&build(page)),
+        ),
+        None => paginate::response_with_totals(
+            pagination_params.0,
+            pagination_config.as_ref().map(|config| config.get_ref()),
+            subjects,
+            totals,
+            &url,
+            build,
+        ),
+    }
+}
+
+/// Gets the subjects known by this server that match a boolean composition of
+/// filters.
+///
+/// ### Filtering
+///
+/// The body of the request is a filter expression: either a group or a leaf.
+///
+/// * A group is a JSON object with a single key of either `any` or `all`
+///   whose value is a non-empty array of filter expressions. An `any` group
+///   matches a subject when _any_ of its expressions match (a logical OR (`||`)),
+///   and an `all` group matches a subject when _all_ of its expressions match (a
+///   logical AND (`&&`)). Groups may be nested up to four levels deep.
+/// * A leaf is a JSON object of filters keyed exactly as the query parameters
+///   of the `/subject` endpoint (including unharmonized fields prefixed with
+///   `metadata.unharmonized.`). Each leaf is matched exactly as the same
+///   filters would be if provided as query parameters to the `/subject` endpoint.
+///
+/// Empty groups, groups nested too deeply, and leaves that would be rejected
+/// as query parameters are rejected with a `422`.
+///
+/// ### Pagination, Ordering, and Matching
+///
+/// This endpoint is paginated and ordered in the same manner as the `/subject`
+/// endpoint, and the `case_insensitive` parameter applies to every leaf. The
+/// `survival_days` field is computed (or omitted with `computed=false`) in the
+/// same manner as well.
+#[utoipa::path(
+    post,
+    path = "/subject/filter",
+    tag = "Subject",
+    params(FilterMatchingParams, SortParams, ComputedParams, PaginationParams),
+    request_body(
+        content = Object,
+        description = "A filter expression (see the description of this endpoint).",
+        content_type = "application/json",
+        example = json!({"any": [{"sex": "F"}, {"vital_status": "Dead"}]})
+    ),
+    responses(
+        (
+            status = 200,
+            description = "Successful operation.",
+            body = responses::Subjects,
+            example = json!(*responses::examples::SUBJECTS),
+            headers(
+                (
+                    "link" = String,
+                    description = "Links to URLs that may be of interest \
+                    when paging through paginated responses. This header \
+                    contains two or more links of interest. The format of the \
+                    field is as follows: \
+                    \n\
+                    \n`Link: <URL>; rel=\"REL\"` \
+                    \n\
+                    ### Relationships\n\n\
+                    In the format above, `URL` represents a valid URL for \
+                    the link of interest and `REL` is one of four values: \n\
+                    - `first` (_Required_). A link to the first page in the \
+                    results (can be the same as `last` if there is only one \
+                    page).\n\
+                    - `last` (_Required_). A link to the first page in the \
+                    results (can be the same as `first` if there is only one \
+                    page).\n\
+                    - `next` (_Optional_). A link to the next page (if it \
+                    exists).\n\
+                    - `prev` (_Optional_). A link to the previous page (if it \
+                    exists).\n\n\
+                    ### Requirements\n\n\
+                    - This header _must_ provide links for at least the `first` \
+                    and `last` rels.\n \
+                    - The `prev` and `next` links must exist only (a) when there \
+                    are multiple pages in the result page set and (b) when the \
+                    current page is not the first or last page, respectively.\n\
+                    - This list of links is unordered.\n\n \
+                    ### Notes\n\n\
+                    - HTTP 1.1 and HTTP 2.0 dictate that response \
+                    headers are case insensitive. Though not required, we \
+                    recommend an all lowercase name of `link` for this \
+                    response header."
+                ),
+                (
+                    "x-total-count" = usize,
+                    description = "The number of entities known by the server \
+                    before any filters were applied (also reported as \
+                    `summary.counts.total`)."
+                ),
+                (
+                    "x-filtered-count" = usize,
+                    description = "The number of entities that matched the \
+                    filters across every page (also reported as \
+                    `summary.counts.filtered`)."
+                )
+            )
+        ),
+        (
+            status = 422,
+            description = "Invalid filter expression or query parameters.",
+            body = responses::Errors,
+            example = json!(Errors::from(error::Kind::invalid_parameters(
+                None,
+                String::from("The `any` group at `/` must contain at least one expression.")
+            )))
+        ),
+    )
+)]
+#[post("/subject/filter")]
+// NOTE: actix-web extracts each set of query parameters and each piece of
+// shared state of a handler as a separate argument.
+#[allow(clippy::too_many_arguments)]
+pub async fn subject_filter(
+    body: Bytes,
+    matching_params: Query<FilterMatchingParams>,
+    sort_params: Query<SortParams>,
+    computed_params: Query<ComputedParams>,
+    pagination_params: Query<PaginationParams>,
+    request: HttpRequest,
+    subjects: Data<Store>,
+    samples: Option<Data<sample::Store>>,
+    pagination_config: Option<Data<paginate::Config>>,
+    strict_config: Option<Data<strict::Config>>,
+) -> impl Responder {
+    let known = [
+        parameter_names::<FilterMatchingParams>(),
+        parameter_names::<SortParams>(),
+        parameter_names::<ComputedParams>(),
+        parameter_names::<PaginationParams>(),
+    ]
+    .concat();
+
+    let mut warnings = Vec::new();
+
+    if let Err(err) = strict::handle(
+        check_keys(request.query_string(), &known),
+        strict_config.as_ref().map(|config| config.get_ref()),
+        &mut warnings,
+    ) {
+        return HttpResponse::UnprocessableEntity().json(Errors::from(err));
+    }
+
+    let expression = match serde_json::from_slice::<Value>(&body)
+        .map_err(|err| error::Kind::invalid_parameters(None, format!("Invalid JSON body: {err}.")))
+        .and_then(|value| Expression::<FilterSubjectParams>::parse(&value))
+    {
+        Ok(expression) => expression,
+        Err(err) => return HttpResponse::UnprocessableEntity().json(Errors::from(err)),
+    };
+
+    let mut subjects = subjects.subjects.lock().unwrap().clone();
+    let total = subjects.len();
+
+    // See the note in the documentation for the `/subject` endpoint: the
+    // results must be sorted by identifier by default.
+    subjects.sort();
+
+    let subjects = evaluate(subjects, expression, matching_params.case());
+
+    let subjects = match sort(subjects, &sort_params) {
+        Ok(subjects) => subjects,
+        Err(err) => return HttpResponse::UnprocessableEntity().json(Errors::from(err)),
+    };
 
-    paginate::response::<Subject, Subjects>(
+    let url = sort_params.url("http://localhost:8000/subject/filter");
+    let totals = Totals::new(total, subjects.len());
+    let computed = computed_from(
+        &computed_params,
+        samples.as_ref().map(|samples| samples.get_ref()),
+    );
+
+    paginate::response_with_totals(
         pagination_params.0,
+        pagination_config.as_ref().map(|config| config.get_ref()),
         subjects,
-        "http://localhost:8000/subject",
+        totals,
+        &url,
+        |page: (Vec<Subject>, usize)| {
+            let subjects = Subjects::from(page)
+                .with_totals(totals)
+                .with_warnings(warnings);
+
+            match &computed {
+                Some(samples) => subjects.with_survival_days(samples),
+                None => subjects,
+            }
+        },
     )
 }
 
 /// Gets the subject matching the provided id (if the subject exists).
+///
+/// The subject includes the `survival_days` field that is derived by the
+/// server (see the `/subject` endpoint) unless `computed=false` is provided.
 #[utoipa::path(
     get,
     path = "/subject/{organization}/{namespace}/{name}",
@@ -252,55 +960,593 @@ pub async fn subject_index(
         (
             "name" = String,
             description = "The name portion of the subject identifier."
-        )
+        ),
+        ComputedParams,
     ),
     tag = "Subject",
     responses(
-        (status = 200, description = "Successful operation.", body = responses::Subject),
+        (
+            status = 200,
+            description = "Successful operation.",
+            body = responses::Subject,
+            example = json!(*responses::examples::SUBJECT)
+        ),
         (
             status = 404,
             description = "Not found.\nServers that cannot provide line-level \
             data should use this response rather than Forbidden (403), as \
             there is no level of authorization that would allow one to access \
-            the information included in the API.",
+            the information included in the API.\nIf the server has suggestions \
+            enabled, up to three similar identifiers may be included in the \
+            `suggestions` field.\nIf the namespace is not known by the server \
+            (namespaces are compared case-sensitively), an `UnknownNamespace` \
+            error listing the known namespaces (and any similar namespaces) is \
+            returned instead.",
             body = responses::Errors,
             example = json!(Errors::from(error::Kind::not_found(String::from("Subjects"))))
+        ),
+        (
+            status = 422,
+            description = "Invalid query parameters.",
+            body = responses::Errors,
+            example = json!(Errors::from(error::Kind::invalid_parameters(
+                Some(vec![String::from("foo")]),
+                String::from("unrecognized parameter(s): `foo`.")
+            )))
+        ),
+        (
+            status = 500,
+            description = "Internal server error.\nMore than one subject has the \
+            requested identifier. Identifiers are expected to be unique, so this \
+            indicates an issue with the data held by the server.",
+            body = responses::Errors,
+            example = json!(Errors::from(error::Kind::duplicate_identifier(
+                String::from("Subject with namespace 'foo' and name 'bar'"),
+                2
+            )))
         )
     )
 )]
 #[get("/subject/{organization}/{namespace}/{name}")]
 pub async fn subject_show(
     path: Path<(String, String, String)>,
+    computed_params: Query<ComputedParams>,
+    request: HttpRequest,
     subjects: Data<Store>,
+    samples: Option<Data<sample::Store>>,
+    suggestions: Option<Data<suggest::Config>>,
+    strict_config: Option<Data<strict::Config>>,
 ) -> impl Responder {
-    let subjects = subjects.subjects.lock().unwrap();
+    if let Err(err) = strict::check(
+        request.query_string(),
+        &parameter_names::<ComputedParams>(),
+        strict_config.as_ref().map(|config| config.get_ref()),
+    ) {
+        return HttpResponse::UnprocessableEntity().json(Errors::from(err));
+    }
+
     let (organization, namespace, name) = path.into_inner();
 
-    subjects
-        .iter()
-        .find(|subject| {
-            subject.id().namespace().organization().as_str() == organization
-                && subject.id().namespace().name().as_str() == namespace
-                && subject.id().name().as_str() == name
+    if let Err(err) = check_known(&organization, &namespace) {
+        return HttpResponse::NotFound().json(Errors::from(err));
+    }
+
+    let subjects = subjects.subjects.lock().unwrap();
+
+    let mut matches = subjects.iter().filter(|subject| {
+        subject.id().namespace().organization().as_str() == organization
+            && subject.id().namespace().name().as_str() == namespace
+            && subject.id().name().as_str() == name
+    });
+
+    let subject = matches.next();
+    let others = matches.count();
+
+    if others > 0 {
+        return HttpResponse::InternalServerError().json(Errors::from(
+            error::Kind::duplicate_identifier(
+                format!("Subject with namespace '{namespace}' and name '{name}'"),
+                others + 1,
+            ),
+        ));
+    }
+
+    subject
+        .map(|subject| {
+            let response = responses::Subject::new(subject.clone());
+
+            match computed_from(
+                &computed_params,
+                samples.as_ref().map(|samples| samples.get_ref()),
+            ) {
+                Some(samples) => {
+                    let survival_days = responses::subject::SurvivalDays::derive(subject, &samples);
+                    HttpResponse::Ok().json(response.with_survival_days(survival_days))
+                }
+                None => HttpResponse::Ok().json(response),
+            }
         })
-        .map(|subject| HttpResponse::Ok().json(subject))
         .unwrap_or_else(|| {
-            HttpResponse::NotFound().json(Errors::from(error::Kind::not_found(format!(
+            let mut errors = Errors::from(error::Kind::not_found(format!(
                 "Subject with namespace '{namespace}' and name '{name}'"
-            ))))
-        })
+            )));
+
+            if suggestions.is_some_and(|config| config.enabled()) {
+                errors = errors.with_suggestions(suggest::nearest(
+                    &format!("{organization}/{namespace}/{name}"),
+                    subjects.iter().map(|subject| {
+                        format!(
+                            "{}/{}/{}",
+                            subject.id().namespace().organization().as_str(),
+                            subject.id().namespace().name().as_str(),
+                            subject.id().name().as_str()
+                        )
+                    }),
+                ));
+            }
+
+            HttpResponse::NotFound().json(errors)
+        })
+}
+
+/// Gets the samples derived from the subject matching the provided id.
+///
+/// ### Pagination
+///
+/// This endpoint is paginated in the same manner as the `/sample` endpoint,
+/// and the samples are sorted by identifier. A subject without any
+/// samples returns an empty page rather than an error.
+#[utoipa::path(
+    get,
+    path = "/subject/{organization}/{namespace}/{name}/samples",
+    params(
+        (
+            "organization" = String,
+            description = "The organization identifier of the namespace to which the subject belongs.",
+        ),
+        (
+            "namespace" = String,
+            description = "The name of the namespace to which the subject belongs.",
+        ),
+        (
+            "name" = String,
+            description = "The name portion of the subject identifier."
+        ),
+        PaginationParams,
+    ),
+    tag = "Subject",
+    responses(
+        (
+            status = 200,
+            description = "Successful operation.",
+            body = responses::Samples,
+            example = json!(*responses::examples::SAMPLES),
+            headers(
+                (
+                    "link" = String,
+                    description = "Links to URLs that may be of interest \
+                    when paging through paginated responses. This header \
+                    contains two or more links of interest. The format of the \
+                    field is as follows: \
+                    \n\
+                    \n`Link: <URL>; rel=\"REL\"` \
+                    \n\
+                    ### Relationships\n\n\
+                    In the format above, `URL` represents a valid URL for \
+                    the link of interest and `REL` is one of four values: \n\
+                    - `first` (_Required_). A link to the first page in the \
+                    results (can be the same as `last` if there is only one \
+                    page).\n\
+                    - `last` (_Required_). A link to the first page in the \
+                    results (can be the same as `first` if there is only one \
+                    page).\n\
+                    - `next` (_Optional_). A link to the next page (if it \
+                    exists).\n\
+                    - `prev` (_Optional_). A link to the previous page (if it \
+                    exists).\n\n\
+                    ### Requirements\n\n\
+                    - This header _must_ provide links for at least the `first` \
+                    and `last` rels.\n \
+                    - The `prev` and `next` links must exist only (a) when there \
+                    are multiple pages in the result page set and (b) when the \
+                    current page is not the first or last page, respectively.\n\
+                    - This list of links is unordered.\n\n \
+                    ### Notes\n\n\
+                    - HTTP 1.1 and HTTP 2.0 dictate that response \
+                    headers are case insensitive. Though not required, we \
+                    recommend an all lowercase name of `link` for this \
+                    response header."
+                )
+            )
+        ),
+        (
+            status = 404,
+            description = "Not found.\nServers that cannot provide line-level \
+            data should use this response rather than Forbidden (403), as \
+            there is no level of authorization that would allow one to access \
+            the information included in the API.\nIf the namespace is not known \
+            by the server (namespaces are compared case-sensitively), an \
+            `UnknownNamespace` error listing the known namespaces (and any \
+            similar namespaces) is returned instead.",
+            body = responses::Errors,
+            example = json!(Errors::from(error::Kind::not_found(
+                String::from("Subject with namespace 'foo' and name 'bar'")
+            )))
+        ),
+        (
+            status = 422,
+            description = "Invalid query parameters.",
+            body = responses::Errors,
+            example = json!(Errors::from(error::Kind::invalid_parameters(
+                Some(vec![String::from("page"), String::from("per_page")]),
+                String::from("unable to calculate offset")
+            )))
+        ),
+        (
+            status = 500,
+            description = "Internal server error.\nMore than one subject has the \
+            requested identifier. Identifiers are expected to be unique, so this \
+            indicates an issue with the data held by the server.",
+            body = responses::Errors,
+            example = json!(Errors::from(error::Kind::duplicate_identifier(
+                String::from("Subject with namespace 'foo' and name 'bar'"),
+                2
+            )))
+        ),
+    )
+)]
+#[get("/subject/{organization}/{namespace}/{name}/samples")]
+pub async fn subject_samples(
+    path: Path<(String, String, String)>,
+    pagination_params: Query<PaginationParams>,
+    request: HttpRequest,
+    subjects: Data<Store>,
+    samples: Data<sample::Store>,
+    pagination_config: Option<Data<paginate::Config>>,
+    strict_config: Option<Data<strict::Config>>,
+) -> impl Responder {
+    let mut warnings = Vec::new();
+
+    if let Err(err) = strict::handle(
+        check_keys(
+            request.query_string(),
+            &parameter_names::<PaginationParams>(),
+        ),
+        strict_config.as_ref().map(|config| config.get_ref()),
+        &mut warnings,
+    ) {
+        return HttpResponse::UnprocessableEntity().json(Errors::from(err));
+    }
+
+    let (organization, namespace, name) = path.into_inner();
+
+    if let Err(err) = check_known(&organization, &namespace) {
+        return HttpResponse::NotFound().json(Errors::from(err));
+    }
+
+    let subject = match find(&subjects, &organization, &namespace, &name) {
+        Ok(subject) => subject,
+        Err(response) => return response,
+    };
+
+    let samples = samples_of(&subject, &samples);
+
+    // NOTE: an empty page is returned (rather than the empty array returned
+    // by [`paginate::response()`]) so that a subject without samples is
+    // distinguishable from an error.
+    if samples.is_empty() {
+        return HttpResponse::Ok().json(Samples::from((samples, 0)).with_warnings(warnings));
+    }
+
+    paginate::response_with(
+        pagination_params.0,
+        pagination_config.as_ref().map(|config| config.get_ref()),
+        samples,
+        &format!("http://localhost:8000/subject/{organization}/{namespace}/{name}/samples"),
+        |page| Samples::from(page).with_warnings(warnings),
+    )
+}
+
+/// Gets the files derived from any of the samples of the subject matching the
+/// provided id.
+///
+/// ### Pagination
+///
+/// This endpoint is paginated in the same manner as the `/file` endpoint,
+/// and the files are sorted by identifier. A subject without any
+/// files returns an empty page rather than an error.
+#[utoipa::path(
+    get,
+    path = "/subject/{organization}/{namespace}/{name}/files",
+    params(
+        (
+            "organization" = String,
+            description = "The organization identifier of the namespace to which the subject belongs.",
+        ),
+        (
+            "namespace" = String,
+            description = "The name of the namespace to which the subject belongs.",
+        ),
+        (
+            "name" = String,
+            description = "The name portion of the subject identifier."
+        ),
+        PaginationParams,
+    ),
+    tag = "Subject",
+    responses(
+        (
+            status = 200,
+            description = "Successful operation.",
+            body = responses::Files,
+            example = json!(*responses::examples::FILES),
+            headers(
+                (
+                    "link" = String,
+                    description = "Links to URLs that may be of interest \
+                    when paging through paginated responses. This header \
+                    contains two or more links of interest. The format of the \
+                    field is as follows: \
+                    \n\
+                    \n`Link: <URL>; rel=\"REL\"` \
+                    \n\
+                    ### Relationships\n\n\
+                    In the format above, `URL` represents a valid URL for \
+                    the link of interest and `REL` is one of four values: \n\
+                    - `first` (_Required_). A link to the first page in the \
+                    results (can be the same as `last` if there is only one \
+                    page).\n\
+                    - `last` (_Required_). A link to the first page in the \
+                    results (can be the same as `first` if there is only one \
+                    page).\n\
+                    - `next` (_Optional_). A link to the next page (if it \
+                    exists).\n\
+                    - `prev` (_Optional_). A link to the previous page (if it \
+                    exists).\n\n\
+                    ### Requirements\n\n\
+                    - This header _must_ provide links for at least the `first` \
+                    and `last` rels.\n \
+                    - The `prev` and `next` links must exist only (a) when there \
+                    are multiple pages in the result page set and (b) when the \
+                    current page is not the first or last page, respectively.\n\
+                    - This list of links is unordered.\n\n \
+                    ### Notes\n\n\
+                    - HTTP 1.1 and HTTP 2.0 dictate that response \
+                    headers are case insensitive. Though not required, we \
+                    recommend an all lowercase name of `link` for this \
+                    response header."
+                )
+            )
+        ),
+        (
+            status = 404,
+            description = "Not found.\nServers that cannot provide line-level \
+            data should use this response rather than Forbidden (403), as \
+            there is no level of authorization that would allow one to access \
+            the information included in the API.\nIf the namespace is not known \
+            by the server (namespaces are compared case-sensitively), an \
+            `UnknownNamespace` error listing the known namespaces (and any \
+            similar namespaces) is returned instead.",
+            body = responses::Errors,
+            example = json!(Errors::from(error::Kind::not_found(
+                String::from("Subject with namespace 'foo' and name 'bar'")
+            )))
+        ),
+        (
+            status = 422,
+            description = "Invalid query parameters.",
+            body = responses::Errors,
+            example = json!(Errors::from(error::Kind::invalid_parameters(
+                Some(vec![String::from("page"), String::from("per_page")]),
+                String::from("unable to calculate offset")
+            )))
+        ),
+        (
+            status = 500,
+            description = "Internal server error.\nMore than one subject has the \
+            requested identifier. Identifiers are expected to be unique, so this \
+            indicates an issue with the data held by the server.",
+            body = responses::Errors,
+            example = json!(Errors::from(error::Kind::duplicate_identifier(
+                String::from("Subject with namespace 'foo' and name 'bar'"),
+                2
+            )))
+        ),
+    )
+)]
+#[get("/subject/{organization}/{namespace}/{name}/files")]
+// NOTE: actix-web extracts each set of query parameters and each piece of
+// shared state of a handler as a separate argument.
+#[allow(clippy::too_many_arguments)]
+pub async fn subject_files(
+    path: Path<(String, String, String)>,
+    pagination_params: Query<PaginationParams>,
+    request: HttpRequest,
+    subjects: Data<Store>,
+    samples: Data<sample::Store>,
+    files: Data<file::Store>,
+    pagination_config: Option<Data<paginate::Config>>,
+    strict_config: Option<Data<strict::Config>>,
+) -> impl Responder {
+    let mut warnings = Vec::new();
+
+    if let Err(err) = strict::handle(
+        check_keys(
+            request.query_string(),
+            &parameter_names::<PaginationParams>(),
+        ),
+        strict_config.as_ref().map(|config| config.get_ref()),
+        &mut warnings,
+    ) {
+        return HttpResponse::UnprocessableEntity().json(Errors::from(err));
+    }
+
+    let (organization, namespace, name) = path.into_inner();
+
+    if let Err(err) = check_known(&organization, &namespace) {
+        return HttpResponse::NotFound().json(Errors::from(err));
+    }
+
+    let subject = match find(&subjects, &organization, &namespace, &name) {
+        Ok(subject) => subject,
+        Err(response) => return response,
+    };
+
+    let samples = samples_of(&subject, &samples)
+        .into_iter()
+        .map(|sample| sample.id().clone())
+        .collect::<BTreeSet<_>>();
+
+    let mut files = files
+        .files
+        .lock()
+        .unwrap()
+        .iter()
+        .filter(|file| file.samples().iter().any(|id| samples.contains(id)))
+        .cloned()
+        .collect::<Vec<_>>();
+
+    files.sort();
+
+    // NOTE: see the note within [`subject_samples()`].
+    if files.is_empty() {
+        return HttpResponse::Ok().json(Files::from((files, 0)).with_warnings(warnings));
+    }
+
+    paginate::response_with(
+        pagination_params.0,
+        pagination_config.as_ref().map(|config| config.get_ref()),
+        files,
+        &format!("http://localhost:8000/subject/{organization}/{namespace}/{name}/files"),
+        |page| Files::from(page).with_warnings(warnings),
+    )
+}
+
+/// Finds the identifier of the subject matching the provided organization,
+/// namespace, and name.
+///
+/// If no subject matches, the standard error for a subject that could not be
+/// found is returned as the response. If more than one subject matches, a
+/// duplicate identifier error is returned as the response instead (as is done
+/// when showing a single subject).
+fn find(
+    store: &Store,
+    organization: &str,
+    namespace: &str,
+    name: &str,
+) -> Result<Identifier, HttpResponse> {
+    let subjects = store.subjects.lock().unwrap();
+
+    let mut matches = subjects.iter().filter(|subject| {
+        subject.id().namespace().organization().as_str() == organization
+            && subject.id().namespace().name().as_str() == namespace
+            && subject.id().name().as_str() == name
+    });
+
+    let subject = matches.next();
+    let others = matches.count();
+
+    if others > 0 {
+        return Err(HttpResponse::InternalServerError().json(Errors::from(
+            error::Kind::duplicate_identifier(
+                format!("Subject with namespace '{namespace}' and name '{name}'"),
+                others + 1,
+            ),
+        )));
+    }
+
+    subject
+        .map(|subject| subject.id().clone())
+        .ok_or_else(|| not_found(namespace, name))
+}
+
+/// Gets the samples from which the computed fields of subjects are derived
+/// (or [`None`] when the computed fields were not requested).
+///
+/// When the server has no samples, the computed fields are still included
+/// (they are all `null`).
+fn computed_from(params: &ComputedParams, samples: Option<&sample::Store>) -> Option<Vec<Sample>> {
+    params.computed().then(|| {
+        samples
+            .map(|samples| samples.samples.lock().unwrap().clone())
+            .unwrap_or_default()
+    })
+}
+
+/// Gets the samples derived from the subject (sorted by identifier).
+fn samples_of(subject: &Identifier, samples: &sample::Store) -> Vec<Sample> {
+    let mut samples = samples
+        .samples
+        .lock()
+        .unwrap()
+        .iter()
+        .filter(|sample| sample.subject() == subject)
+        .cloned()
+        .collect::<Vec<_>>();
+
+    samples.sort();
+    samples
+}
+
+/// Gets the identifiers of the subjects with at least one sample that matches
+/// the provided sample filters (if the samples are known).
+fn subjects_of_matching_samples(
+    samples: Option<&sample::Store>,
+    filter_params: FilterSampleParams,
+    unharmonized_queries: &[UnharmonizedQuery],
+    case: Case,
+) -> BTreeSet<Identifier> {
+    let samples = samples
+        .map(|samples| samples.samples.lock().unwrap().clone())
+        .unwrap_or_default();
+
+    let samples = filter_with_case::<Sample, FilterSampleParams>(samples, filter_params, case);
+
+    unharmonized::filter(samples, unharmonized_queries, case)
+        .into_iter()
+        .map(|sample| sample.subject().clone())
+        .collect()
+}
+
+/// Responds with the standard error for a subject that could not be found.
+fn not_found(namespace: &str, name: &str) -> HttpResponse {
+    HttpResponse::NotFound().json(Errors::from(error::Kind::not_found(format!(
+        "Subject with namespace '{namespace}' and name '{name}'"
+    ))))
 }
 
 /// Groups the subjects by the specified metadata field and returns counts.
+///
+/// ### Missing values
+///
+/// By default, `missing` counts the subjects with no metadata block at all, and
+/// the subjects with a metadata block but no value for the field are counted
+/// under a `null` value within `values`. When `distinguish_missing=true` is
+/// provided, the latter are instead counted within a separate `null` bucket
+/// (so `values` only contains actual values).
+///
+/// ### Scoping
+///
+/// Providing `namespace` (formatted as `{organization}/{namespace}`) or
+/// `organization` only counts the subjects within the matching namespace or
+/// organization, exactly as the parameters of the same name do when filtering
+/// subjects.
 #[utoipa::path(
     get,
     path = "/subject/by/{field}/count",
     params(
         ("field" = String, description = "The field to group by and count with."),
+        MissingParams,
+        ScopeParams,
     ),
     tag = "Subject",
     responses(
-        (status = 200, description = "Successful operation.", body = responses::by::count::subject::Results),
+        (
+            status = 200,
+            description = "Successful operation.",
+            body = responses::by::count::subject::Results,
+            example = json!(*responses::examples::SUBJECTS_BY_COUNT)
+        ),
         (
             status = 422,
             description = "Unsupported field.",
@@ -315,13 +1561,46 @@ pub async fn subject_show(
     )
 )]
 #[get("/subject/by/{field}/count")]
-pub async fn subjects_by_count(path: Path<String>, subjects: Data<Store>) -> impl Responder {
-    let subjects = subjects.subjects.lock().unwrap().clone();
+pub async fn subjects_by_count(
+    path: Path<String>,
+    params: Query<MissingParams>,
+    scope: Query<ScopeParams>,
+    request: HttpRequest,
+    subjects: Data<Store>,
+    strict_config: Option<Data<strict::Config>>,
+) -> impl Responder {
+    if let Err(err) = strict::check(
+        request.query_string(),
+        &[
+            parameter_names::<MissingParams>(),
+            parameter_names::<ScopeParams>(),
+        ]
+        .concat(),
+        strict_config.as_ref().map(|config| config.get_ref()),
+    ) {
+        return HttpResponse::UnprocessableEntity().json(Errors::from(err));
+    }
+
+    if let Err(err) = scope.check() {
+        return HttpResponse::UnprocessableEntity().json(Errors::from(err));
+    }
+
+    let mut subjects = subjects.subjects.lock().unwrap().clone();
+    subjects.retain(|subject| scope.contains(subject.id().namespace()));
+
     let field = path.into_inner();
 
+    let absent = subjects
+        .iter()
+        .filter(|subject| subject.metadata().is_none())
+        .count();
+
     let results = group_by(subjects, &field);
 
     match results {
+        GroupByResults::Supported(results) if params.distinguish_missing() => {
+            HttpResponse::Ok().json(results.distinguish_missing(absent))
+        }
         GroupByResults::Supported(results) => HttpResponse::Ok().json(results),
         GroupByResults::Unsupported => {
             HttpResponse::UnprocessableEntity().json(Errors::from(error::Kind::unsupported_field(
@@ -332,7 +1611,105 @@ pub async fn subjects_by_count(path: Path<String>, subjects: Data<Store>) -> imp
     }
 }
 
-fn group_by(
+/// Lists the distinct values that are present for the specified metadata
+/// field across the subjects known by this server.
+///
+/// Only non-null values are listed, each along with the number of subjects on
+/// which the value is present (sorted in descending order by that number).
+/// Multi-valued fields (e.g., `race`) list each of their members, and a
+/// subject is counted at most once for each value.
+///
+/// ### Truncation
+///
+/// Fields with more distinct values than the limit configured for this server
+/// (e.g., `identifiers`) only list the most common values, in which case the
+/// response is marked with `truncated: true`.
+#[utoipa::path(
+    get,
+    path = "/subject/values/{field}",
+    params(
+        ("field" = String, description = "The field to list the distinct values of."),
+    ),
+    tag = "Subject",
+    responses(
+        (
+            status = 200,
+            description = "Successful operation.",
+            body = responses::values::Response
+        ),
+        (
+            status = 422,
+            description = "Unsupported field.",
+            body = responses::Errors,
+            example = json!(Errors::from(
+                error::Kind::unsupported_field(
+                    String::from("handedness"),
+                    String::from("This field is not present for subjects."),
+                )
+            ))
+        ),
+    )
+)]
+#[get("/subject/values/{field}")]
+pub async fn subject_values(
+    path: Path<String>,
+    request: HttpRequest,
+    subjects: Data<Store>,
+    config: Option<Data<values::Config>>,
+    strict_config: Option<Data<strict::Config>>,
+) -> impl Responder {
+    if let Err(err) = strict::check(
+        request.query_string(),
+        &[],
+        strict_config.as_ref().map(|config| config.get_ref()),
+    ) {
+        return HttpResponse::UnprocessableEntity().json(Errors::from(err));
+    }
+
+    let subjects = subjects.subjects.lock().unwrap();
+    let field = path.into_inner();
+
+    let observed = match subjects
+        .iter()
+        .map(|subject| parse_field(&field, subject))
+        .collect::<Option<Vec<_>>>()
+    {
+        Some(observed) => observed,
+        None => {
+            return HttpResponse::UnprocessableEntity().json(Errors::from(
+                error::Kind::unsupported_field(
+                    field,
+                    String::from("This field is not present for subjects."),
+                ),
+            ))
+        }
+    };
+
+    let limit = config
+        .map(|config| config.limit())
+        .unwrap_or(values::DEFAULT_LIMIT);
+
+    HttpResponse::Ok().json(responses::values::Response::new(
+        field,
+        values::distinct(observed),
+        limit,
+    ))
+}
+
+/// The fields by which subjects may be grouped and counted.
+pub const GROUPABLE_FIELDS: &[&str] = &[
+    "sex",
+    "race",
+    "ethnicity",
+    "identifiers",
+    "vital_status",
+    "age_at_vital_status",
+    "associated_diagnoses",
+    "associated_diagnosis_categories",
+    "depositions",
+];
+
+pub(crate) fn group_by(
     subjects: Vec<Subject>,
     field: &str,
 ) -> GroupByResults<responses::by::count::subject::Results> {
@@ -505,21 +1882,940 @@ fn parse_field(field: &str, subject: &Subject) -> Option<Option<Value>> {
     path = "/subject/summary",
     tag = "Subject",
     responses(
-        (status = 200, description = "Successful operation.", body = responses::Summary),
+        (
+            status = 200,
+            description = "Successful operation.",
+            body = responses::Summary,
+            example = json!(*responses::examples::SUMMARY)
+        ),
     )
 )]
 #[get("/subject/summary")]
 pub async fn subject_summary(subjects: Data<Store>) -> impl Responder {
-    let subjects = subjects.subjects.lock().unwrap().clone();
-    HttpResponse::Ok().json(Summary::new(subjects.len()))
+    let subjects = subjects.subjects.lock().unwrap();
+
+    let without_metadata = subjects
+        .iter()
+        .filter(|subject| !subject.has_metadata())
+        .count();
+
+    HttpResponse::Ok().json(Summary::new(subjects.len(), without_metadata))
+}
+
+/// Reports the cross-tabulation of the race, ethnicity, and sex of the
+/// subjects known by this server.
+///
+/// Each cell of the cross-tabulation is a combination of values of race,
+/// ethnicity, and sex alongside the number of subjects with that combination.
+/// Only the combinations that are observed are reported, and missing values
+/// (including subjects without metadata) are reported as `null`.
+///
+/// Race is a multiple-valued field: a subject with more than one race is
+/// counted within the cell for each of its (distinct) races. As such, the sum
+/// of the counts of the cells may exceed the number of distinct subjects,
+/// which is reported separately.
+#[utoipa::path(
+    get,
+    path = "/subject/demographics",
+    tag = "Subject",
+    responses(
+        (status = 200, description = "Successful operation.", body = responses::Demographics),
+    )
+)]
+#[get("/subject/demographics")]
+pub async fn subject_demographics(subjects: Data<Store>) -> impl Responder {
+    let subjects = subjects.subjects.lock().unwrap();
+    HttpResponse::Ok().json(demographics(&subjects))
+}
+
+/// Cross-tabulates the race, ethnicity, and sex of the subjects in a single
+/// pass.
+fn demographics(subjects: &[Subject]) -> Demographics {
+    let mut cells = Vec::<Cell>::new();
+
+    for subject in subjects {
+        let metadata = subject.metadata();
+
+        let ethnicity = metadata
+            .and_then(|metadata| metadata.ethnicity())
+            .map(|ethnicity| ethnicity.value().clone());
+        let sex = metadata
+            .and_then(|metadata| metadata.sex())
+            .map(|sex| sex.value().clone());
+
+        let mut races = Vec::new();
+
+        for race in metadata
+            .and_then(|metadata| metadata.race())
+            .into_iter()
+            .flatten()
+            .map(|race| Some(race.value().clone()))
+        {
+            // NOTE: a subject is only counted once for each distinct race.
+            if !races.contains(&race) {
+                races.push(race);
+            }
+        }
+
+        if races.is_empty() {
+            races.push(None);
+        }
+
+        for race in races {
+            match cells
+                .iter_mut()
+                .find(|cell| cell.race == race && cell.ethnicity == ethnicity && cell.sex == sex)
+            {
+                Some(cell) => cell.count += 1,
+                None => cells.push(Cell {
+                    race,
+                    ethnicity: ethnicity.clone(),
+                    sex: sex.clone(),
+                    count: 1,
+                }),
+            }
+        }
+    }
+
+    Demographics::new(subjects.len(), cells)
 }
 
 #[cfg(test)]
 mod tests {
+    use actix_web::http::StatusCode;
+    use actix_web::test::call_and_read_body_json;
+    use actix_web::test::call_service;
+    use actix_web::test::init_service;
+    use actix_web::test::read_body_json;
+    use actix_web::test::TestRequest;
+    use actix_web::App;
+
+    use ccdi_models::metadata::field::unowned::subject::Ethnicity;
+    use ccdi_models::metadata::field::unowned::subject::Race;
+    use ccdi_models::metadata::field::unowned::subject::Sex;
+    use ccdi_models::subject::metadata::Builder;
+    use ccdi_models::subject::Kind;
+
     use super::*;
+    use crate::routes::namespace::random_namespace;
 
     #[test]
     fn it_generates_a_random_namespace() {
         random_namespace();
     }
+
+    /// Creates a metadata-less subject within the first namespace known by
+    /// this server.
+    fn named_subject(name: &str) -> Subject {
+        use crate::routes::namespace::NAMESPACES;
+
+        let namespace = NAMESPACES.values().next().unwrap().id().clone();
+        Subject::new(
+            Identifier::new(namespace, name),
+            Kind::Participant,
+            None,
+            None,
+        )
+    }
+
+    #[test]
+    fn it_reports_every_duplicate_identifier() {
+        let err = Store::try_new(vec![
+            named_subject("Subject2"),
+            named_subject("Subject1"),
+            named_subject("Subject2"),
+            named_subject("Subject3"),
+            named_subject("Subject1"),
+            named_subject("Subject2"),
+        ])
+        .unwrap_err();
+
+        let Error::DuplicateIdentifiers(identifiers) = &err else {
+            panic!("expected duplicate identifiers, found: {err}");
+        };
+
+        assert_eq!(
+            identifiers
+                .iter()
+                .map(|identifier| identifier.name().as_str())
+                .collect::<Vec<_>>(),
+            ["Subject1", "Subject2"]
+        );
+
+        Store::try_new(vec![named_subject("Subject1"), named_subject("Subject2")]).unwrap();
+    }
+
+    #[actix_web::test]
+    async fn it_responds_with_a_server_error_when_an_identifier_is_duplicated() {
+        // NOTE: the store is constructed directly, as [`Store::try_new()`]
+        // rejects duplicate identifiers.
+        let store = Data::new(Store {
+            subjects: Mutex::new(vec![
+                named_subject("Subject1"),
+                named_subject("Subject1"),
+                named_subject("Subject2"),
+            ]),
+        });
+
+        let samples = Data::new(sample::Store::try_new(Vec::new()).unwrap());
+        let files = Data::new(file::Store::try_new(Vec::new()).unwrap());
+
+        let app = init_service(
+            App::new()
+                .app_data(samples)
+                .app_data(files)
+                .configure(configure(store)),
+        )
+        .await;
+        let namespace = named_subject("Subject1").id().namespace().clone();
+        let uri = |name: &str| {
+            format!(
+                "/subject/{}/{}/{name}",
+                namespace.organization().as_str(),
+                namespace.name().as_str()
+            )
+        };
+
+        for name in ["Subject1", "Subject1/samples", "Subject1/files"] {
+            let request = TestRequest::get().uri(&uri(name)).to_request();
+            let response = call_service(&app, request).await;
+            assert_eq!(response.status(), StatusCode::INTERNAL_SERVER_ERROR);
+
+            let body: Value = read_body_json(response).await;
+            assert_eq!(body["errors"][0]["kind"], "DuplicateIdentifier");
+            assert_eq!(body["errors"][0]["count"], 2);
+        }
+
+        let request = TestRequest::get().uri(&uri("Subject2")).to_request();
+        let response = call_service(&app, request).await;
+        assert_eq!(response.status(), StatusCode::OK);
+    }
+
+    #[actix_web::test]
+    async fn it_counts_subjects_without_metadata_in_the_summary() {
+        let subject = |name: &str, metadata: Option<models::subject::Metadata>| {
+            Subject::new(
+                Identifier::new(random_namespace().id().clone(), name),
+                Kind::Participant,
+                None,
+                metadata,
+            )
+        };
+
+        let store = Data::new(Store {
+            subjects: Mutex::new(vec![
+                subject("Subject1", None),
+                subject("Subject2", Some(Builder::default().build())),
+                subject(
+                    "Subject3",
+                    Some(
+                        Builder::default()
+                            .sex(Sex::new(cde::v1::subject::Sex::Female, None, None, None))
+                            .build(),
+                    ),
+                ),
+            ]),
+        });
+
+        let app = init_service(App::new().configure(configure(store))).await;
+        let request = TestRequest::get().uri("/subject/summary").to_request();
+        let summary: Summary = call_and_read_body_json(&app, request).await;
+
+        assert_eq!(summary.total(), 3);
+        assert_eq!(summary.without_metadata(), Some(2));
+    }
+
+    #[actix_web::test]
+    async fn it_distinguishes_missing_metadata_from_null_values_when_counting() {
+        let subject = |name: &str, metadata: Option<models::subject::Metadata>| {
+            Subject::new(
+                Identifier::new(random_namespace().id().clone(), name),
+                Kind::Participant,
+                None,
+                metadata,
+            )
+        };
+
+        let store = Data::new(Store {
+            subjects: Mutex::new(vec![
+                // A value.
+                subject(
+                    "Subject1",
+                    Some(
+                        Builder::default()
+                            .sex(Sex::new(cde::v1::subject::Sex::Female, None, None, None))
+                            .build(),
+                    ),
+                ),
+                // An explicit `null`.
+                subject("Subject2", Some(Builder::default().build())),
+                subject("Subject3", Some(Builder::default().build())),
+                // A missing metadata block.
+                subject("Subject4", None),
+            ]),
+        });
+
+        let app = init_service(App::new().configure(configure(store))).await;
+
+        let request = TestRequest::get().uri("/subject/by/sex/count").to_request();
+        let results: serde_json::Value = call_and_read_body_json(&app, request).await;
+
+        assert_eq!(results["total"], 4);
+        assert_eq!(results["missing"], 1);
+        assert!(results.get("null").is_none());
+        assert_eq!(results["values"].as_array().unwrap().len(), 2);
+
+        let request = TestRequest::get()
+            .uri("/subject/by/sex/count?distinguish_missing=true")
+            .to_request();
+        let results: serde_json::Value = call_and_read_body_json(&app, request).await;
+
+        assert_eq!(results["total"], 4);
+        assert_eq!(results["missing"], 1);
+        assert_eq!(results["null"], 2);
+        assert_eq!(results["values"].as_array().unwrap().len(), 1);
+        assert_eq!(results["values"][0]["count"], 1);
+    }
+
+    #[actix_web::test]
+    async fn it_cross_tabulates_demographics() {
+        use cde::v1::subject::Race::Asian;
+        use cde::v1::subject::Race::White;
+        use cde::v1::subject::Sex::Female;
+        use cde::v1::subject::Sex::Male;
+        use cde::v2::subject::Ethnicity::HispanicOrLatino;
+        use cde::v2::subject::Ethnicity::NotHispanicOrLatino;
+
+        let subject = |name: &str, metadata: Option<models::subject::Metadata>| {
+            Subject::new(
+                Identifier::new(random_namespace().id().clone(), name),
+                Kind::Participant,
+                None,
+                metadata,
+            )
+        };
+
+        let race = |race| Race::new(race, None, None, None);
+        let ethnicity = |ethnicity| Ethnicity::new(ethnicity, None, None, None);
+        let sex = |sex| Sex::new(sex, None, None, None);
+
+        let store = Data::new(Store {
+            subjects: Mutex::new(vec![
+                // A subject with two races contributes to two cells.
+                subject(
+                    "Subject1",
+                    Some(
+                        Builder::default()
+                            .append_race(race(White))
+                            .append_race(race(Asian))
+                            .ethnicity(ethnicity(NotHispanicOrLatino))
+                            .sex(sex(Female))
+                            .build(),
+                    ),
+                ),
+                subject(
+                    "Subject2",
+                    Some(
+                        Builder::default()
+                            .append_race(race(White))
+                            .ethnicity(ethnicity(NotHispanicOrLatino))
+                            .sex(sex(Female))
+                            .build(),
+                    ),
+                ),
+                // A repeated race is only counted once.
+                subject(
+                    "Subject3",
+                    Some(
+                        Builder::default()
+                            .append_race(race(White))
+                            .append_race(race(White))
+                            .ethnicity(ethnicity(HispanicOrLatino))
+                            .sex(sex(Male))
+                            .build(),
+                    ),
+                ),
+                subject("Subject4", None),
+                subject("Subject5", Some(Builder::default().sex(sex(Male)).build())),
+            ]),
+        });
+
+        let app = init_service(App::new().configure(configure(store))).await;
+        let request = TestRequest::get().uri("/subject/demographics").to_request();
+        let demographics: Demographics = call_and_read_body_json(&app, request).await;
+
+        let cell = |race, ethnicity, sex, count| Cell {
+            race,
+            ethnicity,
+            sex,
+            count,
+        };
+
+        assert_eq!(demographics.distinct_subjects(), 5);
+        assert_eq!(
+            demographics.cells(),
+            &[
+                cell(Some(White), Some(NotHispanicOrLatino), Some(Female), 2),
+                cell(Some(Asian), Some(NotHispanicOrLatino), Some(Female), 1),
+                cell(Some(White), Some(HispanicOrLatino), Some(Male), 1),
+                cell(None, None, None, 1),
+                cell(None, None, Some(Male), 1),
+            ]
+        );
+    }
+
+    #[actix_web::test]
+    async fn it_filters_case_insensitively_when_requested() {
+        use cde::v2::subject::Ethnicity::HispanicOrLatino;
+        use cde::v2::subject::Ethnicity::NotHispanicOrLatino;
+
+        let subject = |name: &str, ethnicity| {
+            Subject::new(
+                Identifier::new(random_namespace().id().clone(), name),
+                Kind::Participant,
+                None,
+                Some(
+                    Builder::default()
+                        .ethnicity(Ethnicity::new(ethnicity, None, None, None))
+                        .build(),
+                ),
+            )
+        };
+
+        let store = Data::new(Store {
+            subjects: Mutex::new(vec![
+                subject("Subject1", HispanicOrLatino),
+                subject("Subject2", NotHispanicOrLatino),
+            ]),
+        });
+
+        let app = init_service(App::new().configure(configure(store))).await;
+
+        for (uri, expected) in [
+            ("/subject?ethnicity=hispanic%20or%20latino", vec![]),
+            (
+                "/subject?ethnicity=hispanic%20or%20latino&case_insensitive=false",
+                vec![],
+            ),
+            (
+                "/subject?ethnicity=hispanic%20or%20latino&case_insensitive=true",
+                vec!["Subject1"],
+            ),
+            (
+                "/subject?ethnicity=not:HISPANIC%20OR%20LATINO&case_insensitive=true",
+                vec!["Subject2"],
+            ),
+        ] {
+            let request = TestRequest::get().uri(uri).to_request();
+            let response: Value = call_and_read_body_json(&app, request).await;
+
+            // NOTE: an empty page is returned as an empty array.
+            let names = response
+                .get("data")
+                .unwrap_or(&response)
+                .as_array()
+                .unwrap()
+                .iter()
+                .map(|subject| subject["id"]["name"].as_str().unwrap())
+                .collect::<Vec<_>>();
+            assert_eq!(names, expected, "{uri}");
+        }
+    }
+
+    #[actix_web::test]
+    async fn it_selects_metadata_fields() {
+        use ccdi_models::metadata::field::unowned::Field;
+        use ccdi_models::metadata::field::UnharmonizedField;
+
+        let unharmonized = |value: &str| {
+            UnharmonizedField::Unowned(Field::new(Value::from(value), None, None, None))
+        };
+
+        let store = Data::new(Store {
+            subjects: Mutex::new(vec![Subject::new(
+                Identifier::new(random_namespace().id().clone(), "Subject1"),
+                Kind::Participant,
+                None,
+                Some(
+                    Builder::default()
+                        .sex(Sex::new(cde::v1::subject::Sex::Female, None, None, None))
+                        .insert_unharmonized("foo", unharmonized("bar"))
+                        .unwrap()
+                        .insert_unharmonized("baz", unharmonized("quux"))
+                        .unwrap()
+                        .build(),
+                ),
+            )]),
+        });
+
+        let app = init_service(App::new().configure(configure(store))).await;
+
+        let request = TestRequest::get()
+            .uri("/subject?fields=sex,race,unharmonized.foo")
+            .to_request();
+        let response: Value = call_and_read_body_json(&app, request).await;
+
+        // NOTE: unselected fields are omitted rather than rendered as `null`.
+        let metadata = response["data"][0]["metadata"].as_object().unwrap();
+        assert_eq!(
+            metadata.keys().collect::<Vec<_>>(),
+            vec!["sex", "race", "unharmonized"]
+        );
+        assert_eq!(metadata["sex"]["value"], "F");
+        assert_eq!(metadata["race"], Value::Null);
+        assert_eq!(
+            metadata["unharmonized"],
+            serde_json::json!({ "foo": { "value": "bar" } })
+        );
+
+        let request = TestRequest::get().uri("/subject").to_request();
+        let response: Value = call_and_read_body_json(&app, request).await;
+        assert!(response["data"][0]["metadata"]
+            .as_object()
+            .unwrap()
+            .contains_key("vital_status"));
+    }
+
+    #[actix_web::test]
+    async fn it_rejects_unknown_metadata_fields() {
+        let store = Data::new(Store::random(10));
+        let app = init_service(App::new().configure(configure(store))).await;
+
+        let request = TestRequest::get()
+            .uri("/subject?fields=sex,not_a_field,unharmonized.")
+            .to_request();
+        let response = actix_web::test::call_service(&app, request).await;
+        assert_eq!(
+            response.status(),
+            actix_web::http::StatusCode::UNPROCESSABLE_ENTITY
+        );
+
+        let errors: Value = actix_web::test::read_body_json(response).await;
+        assert_eq!(
+            errors["errors"][0]["parameters"],
+            serde_json::json!(["fields"])
+        );
+        assert_eq!(
+            errors["errors"][0]["reason"],
+            "Unknown field(s): `not_a_field`, `unharmonized.`."
+        );
+    }
+
+    #[actix_web::test]
+    async fn it_filters_by_arrays_of_values() {
+        use ccdi_models::metadata::field::unowned::Field;
+        use ccdi_models::metadata::field::UnharmonizedField;
+
+        let namespace = random_namespace();
+
+        let subject = |name: &str, sex, consortium_id: Value| {
+            Subject::new(
+                Identifier::new(namespace.id().clone(), name),
+                Kind::Participant,
+                None,
+                Some(
+                    Builder::default()
+                        .sex(Sex::new(sex, None, None, None))
+                        .insert_unharmonized(
+                            "consortium_id",
+                            UnharmonizedField::Unowned(Field::new(consortium_id, None, None, None)),
+                        )
+                        .unwrap()
+                        .build(),
+                ),
+            )
+        };
+
+        let store = Data::new(Store {
+            subjects: Mutex::new(vec![
+                subject("Subject1", cde::v1::subject::Sex::Female, Value::from("A")),
+                subject("Subject2", cde::v1::subject::Sex::Male, Value::from("B")),
+                subject(
+                    "Subject3",
+                    cde::v1::subject::Sex::Unknown,
+                    serde_json::json!(["C", "D"]),
+                ),
+            ]),
+        });
+
+        let app = init_service(App::new().configure(configure(store))).await;
+
+        // NOTE: `%5B`, `%22`, and `%5D` are the encoded forms of `[`, `"`, and
+        // `]` respectively.
+        for (uri, expected) in [
+            (
+                "/subject?sex=%5B%22F%22,%22M%22%5D",
+                vec!["Subject1", "Subject2"],
+            ),
+            ("/subject?sex=not:%5B%22F%22,%22M%22%5D", vec!["Subject3"]),
+            (
+                "/subject?sex=%5B%22f%22,%22m%22%5D&case_insensitive=true",
+                vec!["Subject1", "Subject2"],
+            ),
+            (
+                "/subject?metadata.unharmonized.consortium_id=B",
+                vec!["Subject2"],
+            ),
+            (
+                "/subject?metadata.unharmonized.consortium_id=%5B%22A%22,%22D%22%5D",
+                vec!["Subject1", "Subject3"],
+            ),
+            (
+                "/subject?sex=%5B%22F%22,%22M%22%5D&\
+                 metadata.unharmonized.consortium_id=%5B%22A%22,%22D%22%5D",
+                vec!["Subject1"],
+            ),
+        ] {
+            let request = TestRequest::get().uri(uri).to_request();
+            let response: Value = call_and_read_body_json(&app, request).await;
+
+            let names = response["data"]
+                .as_array()
+                .unwrap()
+                .iter()
+                .map(|subject| subject["id"]["name"].as_str().unwrap())
+                .collect::<Vec<_>>();
+            assert_eq!(names, expected, "{uri}");
+        }
+
+        for (uri, parameter) in [
+            ("/subject?sex=%5B%7B%7D%5D", "sex"),
+            (
+                "/subject?metadata.unharmonized.consortium_id=%5B%5D",
+                "metadata.unharmonized.consortium_id",
+            ),
+        ] {
+            let request = TestRequest::get().uri(uri).to_request();
+            let response = actix_web::test::call_service(&app, request).await;
+            assert_eq!(
+                response.status(),
+                actix_web::http::StatusCode::UNPROCESSABLE_ENTITY,
+                "{uri}"
+            );
+
+            let errors: Value = actix_web::test::read_body_json(response).await;
+            assert_eq!(
+                errors["errors"][0]["parameters"],
+                serde_json::json!([parameter]),
+                "{uri}"
+            );
+        }
+    }
+
+    #[actix_web::test]
+    async fn it_scopes_subjects_to_a_namespace_or_an_organization() {
+        use crate::routes::namespace::known;
+        use crate::routes::namespace::NAMESPACES;
+
+        let mut namespaces = NAMESPACES.values();
+        let one = namespaces.next().unwrap().id().clone();
+        let two = namespaces.next().unwrap().id().clone();
+
+        let subject = |namespace: &models::namespace::Identifier, name: &str| {
+            Subject::new(
+                Identifier::new(namespace.clone(), name),
+                Kind::Participant,
+                None,
+                Some(
+                    Builder::default()
+                        .sex(Sex::new(cde::v1::subject::Sex::Female, None, None, None))
+                        .build(),
+                ),
+            )
+        };
+
+        let store = Data::new(Store {
+            subjects: Mutex::new(vec![
+                subject(&one, "Subject1"),
+                subject(&two, "Subject2"),
+                subject(&two, "Subject3"),
+            ]),
+        });
+
+        let app = init_service(App::new().configure(configure(store))).await;
+
+        for (uri, expected) in [
+            (
+                "/subject?namespace=example-organization/ExampleNamespaceOne",
+                vec!["Subject1"],
+            ),
+            (
+                "/subject?namespace=example-organization/ExampleNamespaceTwo",
+                vec!["Subject2", "Subject3"],
+            ),
+            (
+                "/subject?namespace=not:example-organization/ExampleNamespaceTwo",
+                vec!["Subject1"],
+            ),
+            (
+                "/subject?organization=example-organization",
+                vec!["Subject1", "Subject2", "Subject3"],
+            ),
+        ] {
+            let request = TestRequest::get().uri(uri).to_request();
+            let response: Value = call_and_read_body_json(&app, request).await;
+
+            let names = response["data"]
+                .as_array()
+                .unwrap()
+                .iter()
+                .map(|subject| subject["id"]["name"].as_str().unwrap())
+                .collect::<Vec<_>>();
+            assert_eq!(names, expected, "{uri}");
+        }
+
+        for (uri, expected) in [
+            ("/subject/by/sex/count", 3),
+            (
+                "/subject/by/sex/count?namespace=example-organization/ExampleNamespaceOne",
+                1,
+            ),
+            (
+                "/subject/by/sex/count?namespace=example-organization/ExampleNamespaceTwo",
+                2,
+            ),
+        ] {
+            let request = TestRequest::get().uri(uri).to_request();
+            let response: Value = call_and_read_body_json(&app, request).await;
+            assert_eq!(response["total"], expected, "{uri}");
+        }
+
+        for uri in [
+            "/subject?namespace=example-organization/ExampleNamespaceThree",
+            "/subject/by/sex/count?namespace=example-organization/ExampleNamespaceThree",
+        ] {
+            let request = TestRequest::get().uri(uri).to_request();
+            let response = actix_web::test::call_service(&app, request).await;
+            assert_eq!(
+                response.status(),
+                actix_web::http::StatusCode::UNPROCESSABLE_ENTITY,
+                "{uri}"
+            );
+
+            let errors: Value = actix_web::test::read_body_json(response).await;
+            assert_eq!(errors["errors"][0]["kind"], "UnknownNamespace", "{uri}");
+            assert_eq!(
+                errors["errors"][0]["known"],
+                serde_json::json!(known()),
+                "{uri}"
+            );
+        }
+
+        let request = TestRequest::get()
+            .uri("/subject?organization=another-organization")
+            .to_request();
+        let response: Value = call_and_read_body_json(&app, request).await;
+        assert_eq!(
+            response["errors"][0]["parameters"],
+            serde_json::json!(["organization"])
+        );
+    }
+
+    #[actix_web::test]
+    async fn it_matches_depositions_against_the_study_of_the_namespace() {
+        use models::metadata::common::deposition::Accession;
+        use models::metadata::common::metadata::Builder as CommonBuilder;
+
+        use crate::routes::namespace::NAMESPACES;
+
+        let one = NAMESPACES
+            .get("example-organization-namespace-one")
+            .unwrap()
+            .id()
+            .clone();
+        let two = NAMESPACES
+            .get("example-organization-namespace-two")
+            .unwrap()
+            .id()
+            .clone();
+
+        let subject =
+            |namespace: &models::namespace::Identifier, name: &str, deposition: Option<&str>| {
+                let mut common = CommonBuilder::default();
+
+                if let Some(deposition) = deposition {
+                    common = common.push_deposition(Accession::dbGaP(
+                        cde::v1::deposition::DbgapPhsAccession::from(deposition.to_string()),
+                    ));
+                }
+
+                Subject::new(
+                    Identifier::new(namespace.clone(), name),
+                    Kind::Participant,
+                    None,
+                    Some(Builder::default().common(common.build()).build()),
+                )
+            };
+
+        let store = Data::new(Store {
+            subjects: Mutex::new(vec![
+                subject(&one, "Subject1", None),
+                subject(&one, "Subject2", Some("phs000001")),
+                subject(&two, "Subject3", None),
+                subject(&two, "Subject4", Some("phs000001")),
+            ]),
+        });
+
+        let app = init_service(App::new().configure(configure(store))).await;
+
+        for (uri, expected) in [
+            // The first namespace describes a study with the accession
+            // `phs000000.v1.p1`, so every subject within it matches.
+            (
+                "/subject?deposition=phs000000.v1.p1",
+                vec!["Subject1", "Subject2"],
+            ),
+            (
+                "/subject?deposition=phs000001",
+                vec!["Subject2", "Subject4"],
+            ),
+            (
+                "/subject?deposition=not:phs000000.v1.p1",
+                vec!["Subject3", "Subject4"],
+            ),
+            // The second namespace describes a study without an accession, so
+            // subjects within it without depositions of their own have no
+            // values.
+            (
+                "/subject?deposition=not:null",
+                vec!["Subject1", "Subject2", "Subject4"],
+            ),
+        ] {
+            let request = TestRequest::get().uri(uri).to_request();
+            let response: Value = call_and_read_body_json(&app, request).await;
+
+            let names = response["data"]
+                .as_array()
+                .unwrap()
+                .iter()
+                .map(|subject| subject["id"]["name"].as_str().unwrap())
+                .collect::<Vec<_>>();
+            assert_eq!(names, expected, "{uri}");
+        }
+    }
+
+    #[actix_web::test]
+    async fn it_computes_survival_days_from_the_earliest_diagnosis() {
+        use ccdi_models::metadata::field::unowned::sample::AgeAtDiagnosis;
+        use ccdi_models::metadata::field::unowned::subject::AgeAtVitalStatus;
+        use ccdi_models::metadata::field::unowned::subject::VitalStatus;
+        use ordered_float::OrderedFloat;
+
+        let namespace = random_namespace();
+
+        let subject = |name: &str, age_at_vital_status: Option<f32>| {
+            let mut builder = Builder::default().vital_status(VitalStatus::new(
+                cde::v1::subject::VitalStatus::Dead,
+                None,
+                None,
+                None,
+            ));
+
+            if let Some(age_at_vital_status) = age_at_vital_status {
+                builder = builder.age_at_vital_status(AgeAtVitalStatus::new(
+                    models::subject::metadata::AgeAtVitalStatus::from(OrderedFloat(
+                        age_at_vital_status,
+                    )),
+                    None,
+                    None,
+                    None,
+                ));
+            }
+
+            Subject::new(
+                Identifier::new(namespace.id().clone(), name),
+                Kind::Participant,
+                None,
+                Some(builder.build()),
+            )
+        };
+
+        let sample = |name: &str, subject: &str, age_at_diagnosis: Option<f32>| {
+            let mut builder = models::sample::metadata::Builder::default();
+
+            if let Some(age_at_diagnosis) = age_at_diagnosis {
+                builder = builder.age_at_diagnosis(AgeAtDiagnosis::new(
+                    models::sample::metadata::AgeAtDiagnosis::from(OrderedFloat(age_at_diagnosis)),
+                    None,
+                    None,
+                    None,
+                ));
+            }
+
+            Sample::new(
+                models::sample::Identifier::new(namespace.id().clone(), name),
+                Identifier::new(namespace.id().clone(), subject),
+                None,
+                Some(builder.build()),
+            )
+        };
+
+        let subjects = Data::new(Store {
+            subjects: Mutex::new(vec![
+                subject("Subject1", Some(3650.0)),
+                subject("Subject2", None),
+                subject("Subject3", Some(3650.0)),
+            ]),
+        });
+
+        let samples = Data::new(sample::Store {
+            samples: Mutex::new(vec![
+                sample("Sample1", "Subject1", Some(730.0)),
+                sample("Sample2", "Subject1", Some(365.0)),
+                sample("Sample3", "Subject1", None),
+                sample("Sample4", "Subject2", Some(365.0)),
+                sample("Sample5", "Subject3", None),
+            ]),
+        });
+
+        let app = init_service(App::new().app_data(samples).configure(configure(subjects))).await;
+
+        let request = TestRequest::get().uri("/subject").to_request();
+        let response: Value = call_and_read_body_json(&app, request).await;
+        let data = response["data"].as_array().unwrap();
+
+        // The earliest diagnosis across the samples of the subject is used.
+        assert_eq!(data[0]["survival_days"]["value"], 3285.0);
+        assert_eq!(data[0]["survival_days"]["vital_status"], "Dead");
+        assert_eq!(
+            data[0]["survival_days"]["provenance"]["age_at_diagnosis"],
+            365.0
+        );
+        assert_eq!(
+            data[0]["survival_days"]["provenance"]["samples"][0]["name"],
+            "Sample2"
+        );
+
+        // Subjects without an `age_at_vital_status` or without a diagnosed
+        // sample have no survival days.
+        assert!(data[1]["survival_days"].is_null());
+        assert!(data[1].as_object().unwrap().contains_key("survival_days"));
+        assert!(data[2]["survival_days"].is_null());
+
+        let uri = format!(
+            "/subject/{}/{}/Subject1",
+            namespace.id().organization().as_str(),
+            namespace.id().name().as_str()
+        );
+
+        let request = TestRequest::get().uri(&uri).to_request();
+        let response: Value = call_and_read_body_json(&app, request).await;
+        assert_eq!(response["survival_days"]["value"], 3285.0);
+
+        let request = TestRequest::get()
+            .uri(&format!("{uri}?computed=false"))
+            .to_request();
+        let response: Value = call_and_read_body_json(&app, request).await;
+        assert!(!response.as_object().unwrap().contains_key("survival_days"));
+
+        let request = TestRequest::get()
+            .uri("/subject?computed=false")
+            .to_request();
+        let response: Value = call_and_read_body_json(&app, request).await;
+        assert!(response["data"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .all(|subject| !subject.as_object().unwrap().contains_key("survival_days")));
+    }
 }
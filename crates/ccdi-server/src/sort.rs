@@ -0,0 +1,191 @@
+//! Sorting entities by their harmonized metadata fields.
+//!
+//! Each entity declares the harmonized fields it may be sorted by (see
+//! [`Sortable`]). Entities are sorted by the `value` of the requested field
+//! within their serialized metadata, so numeric fields (e.g.,
+//! `age_at_diagnosis`) are sorted numerically and every other field is sorted
+//! lexicographically. Because sorting changes which entities fall on each
+//! page, entities must be sorted _before_ they are paginated.
+
+use std::cmp::Ordering;
+
+use serde::Serialize;
+use serde_json::Value;
+
+use ccdi_models as models;
+
+use models::File;
+use models::Sample;
+use models::Subject;
+
+use crate::params::sort::Order;
+use crate::params::SortParams;
+use crate::responses::error;
+
+/// An entity that can be sorted by a set of its harmonized metadata fields.
+///
+/// **Note:** entities are ordered by their identifiers (see [`Ord`]), which is
+/// how ties are broken.
+pub trait Sortable: Ord + Serialize {
+    /// The harmonized metadata fields that the entity can be sorted by.
+    const FIELDS: &'static [&'static str];
+}
+
+impl Sortable for Subject {
+    const FIELDS: &'static [&'static str] =
+        &["sex", "ethnicity", "vital_status", "age_at_vital_status"];
+}
+
+impl Sortable for Sample {
+    const FIELDS: &'static [&'static str] = &[
+        "age_at_diagnosis",
+        "age_at_collection",
+        "diagnosis",
+        "diagnosis_category",
+        "disease_phase",
+        "library_selection_method",
+        "library_strategy",
+        "library_source_material",
+        "preservation_method",
+        "specimen_molecular_analyte_type",
+        "tissue_type",
+        "tumor_classification",
+        "tumor_grade",
+    ];
+}
+
+impl Sortable for File {
+    const FIELDS: &'static [&'static str] = &["type", "size", "description"];
+}
+
+/// Sorts the entities according to the [`SortParams`].
+///
+/// When no field is requested, the entities are returned as they are. An
+/// [`InvalidParameters`](error::Kind::invalid_parameters) error is returned if
+/// the requested field is not [sortable](Sortable::FIELDS) or if an order is
+/// requested without a field.
+///
+/// # Examples
+///
+/// ```
+/// use actix_web::web::Query;
+/// use ccdi_models as models;
+/// use ccdi_server as server;
+///
+/// use models::Subject;
+/// use server::params::SortParams;
+/// use server::sort::sort;
+///
+/// let params = Query::<SortParams>::from_query("sort=handedness").unwrap();
+/// assert!(sort(Vec::<Subject>::new(), &params).is_err());
+///
+/// let params = Query::<SortParams>::from_query("order=desc").unwrap();
+/// assert!(sort(Vec::<Subject>::new(), &params).is_err());
+/// ```
+pub fn sort<T>(entities: Vec<T>, params: &SortParams) -> Result<Vec<T>, error::Kind>
+where
+    T: Sortable,
+{
+    let field = match params.sort() {
+        Some(field) => field,
+        None => {
+            return match params.order() {
+                Some(_) => Err(error::Kind::invalid_parameters(
+                    Some(vec![String::from("order")]),
+                    String::from("an order may only be provided along with `sort`"),
+                )),
+                None => Ok(entities),
+            }
+        }
+    };
+
+    if !T::FIELDS.contains(&field) {
+        return Err(error::Kind::invalid_parameters(
+            Some(vec![String::from("sort")]),
+            format!(
+                "unsupported sort field `{field}` (expected one of {})",
+                T::FIELDS
+                    .iter()
+                    .map(|field| format!("`{field}`"))
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            ),
+        ));
+    }
+
+    let order = params.order().unwrap_or_default();
+
+    let mut entities = entities
+        .into_iter()
+        .map(|entity| (value(&entity, field), entity))
+        .collect::<Vec<_>>();
+
+    entities.sort_by(|(a, a_entity), (b, b_entity)| {
+        compare(a, b, order).then_with(|| a_entity.cmp(b_entity))
+    });
+
+    Ok(entities.into_iter().map(|(_, entity)| entity).collect())
+}
+
+/// Gets the value of a harmonized metadata `field` of an entity (or
+/// [`Value::Null`] if the entity has no value for the field).
+fn value<T: Serialize>(entity: &T, field: &str) -> Value {
+    // SAFETY: entities are always serializable to JSON.
+    let entity = serde_json::to_value(entity).unwrap();
+    let value = &entity["metadata"][field]["value"];
+
+    // NOTE: ages may be serialized in their canonical object form (which is
+    // always in days) rather than as a bare number.
+    match value.get("unit") {
+        Some(_) => value["value"].clone(),
+        None => value.clone(),
+    }
+}
+
+/// Compares two values in the provided [`Order`].
+///
+/// Null values are always ordered last (regardless of the [`Order`]).
+fn compare(a: &Value, b: &Value, order: Order) -> Ordering {
+    let ordering = match (a, b) {
+        (Value::Null, Value::Null) => return Ordering::Equal,
+        (Value::Null, _) => return Ordering::Greater,
+        (_, Value::Null) => return Ordering::Less,
+        (Value::Number(a), Value::Number(b)) => a
+            .as_f64()
+            .partial_cmp(&b.as_f64())
+            .unwrap_or(Ordering::Equal),
+        (Value::String(a), Value::String(b)) => a.cmp(b),
+        // NOTE: the values of a sortable field always share the same type.
+        _ => Ordering::Equal,
+    };
+
+    match order {
+        Order::Asc => ordering,
+        Order::Desc => ordering.reverse(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn it_sorts_null_values_last_regardless_of_order() {
+        for order in [Order::Asc, Order::Desc] {
+            assert_eq!(compare(&Value::Null, &json!(1), order), Ordering::Greater);
+            assert_eq!(compare(&json!("a"), &Value::Null, order), Ordering::Less);
+            assert_eq!(compare(&Value::Null, &Value::Null, order), Ordering::Equal);
+        }
+    }
+
+    #[test]
+    fn it_compares_numbers_numerically() {
+        assert_eq!(compare(&json!(9.5), &json!(10), Order::Asc), Ordering::Less);
+        assert_eq!(
+            compare(&json!(9.5), &json!(10), Order::Desc),
+            Ordering::Greater
+        );
+    }
+}
@@ -0,0 +1,160 @@
+//! Handling query parameters that are not recognized by an endpoint.
+//!
+//! Servers within the federation have historically disagreed on whether an
+//! unrecognized query parameter (e.g., a misspelled filter such as
+//! `anatomic_sites` or an unknown pagination parameter) is an error or is
+//! silently ignored. This server makes that choice explicit:
+//!
+//! * In strict mode (the default), an unrecognized parameter is rejected with
+//!   an [`InvalidParameters`](crate::responses::error::kind::Inner::InvalidParameters)
+//!   error that lists every unrecognized parameter.
+//! * In lenient mode, unrecognized parameters are ignored. Each is logged, and
+//!   the description of the error that would have been returned in strict
+//!   mode is included within the `warnings` of the response (for the
+//!   endpoints whose responses have `warnings`).
+
+use log::warn;
+
+use crate::filter::check_keys;
+use crate::responses::error;
+
+/// Configuration for handling unrecognized query parameters.
+#[derive(Clone, Debug)]
+pub struct Config {
+    /// Whether or not unrecognized query parameters are rejected.
+    strict: bool,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self { strict: true }
+    }
+}
+
+impl Config {
+    /// Creates a new [`Config`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ccdi_server as server;
+    ///
+    /// use server::strict::Config;
+    ///
+    /// let config = Config::new(false);
+    /// assert!(!config.strict());
+    /// ```
+    pub fn new(strict: bool) -> Self {
+        Self { strict }
+    }
+
+    /// Gets whether unrecognized query parameters are rejected for the
+    /// [`Config`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ccdi_server as server;
+    ///
+    /// use server::strict::Config;
+    ///
+    /// let config = Config::default();
+    /// assert!(config.strict());
+    /// ```
+    pub fn strict(&self) -> bool {
+        self.strict
+    }
+}
+
+/// Handles the result of checking the keys of a query string (e.g., with
+/// [`check_keys()`](crate::filter::check_keys)) with regard to the [`Config`]
+/// (the default [`Config`] is used when none is provided).
+///
+/// In strict mode, the result is returned as is. In lenient mode, an error is
+/// logged and its description is pushed onto `warnings` instead.
+///
+/// # Examples
+///
+/// ```
+/// use ccdi_server as server;
+///
+/// use server::filter::check_keys;
+/// use server::strict::handle;
+/// use server::strict::Config;
+///
+/// let known = vec![String::from("anatomical_sites")];
+/// let mut warnings = Vec::new();
+///
+/// let result = check_keys("anatomic_sites=Chest", &known);
+/// assert!(handle(result, None, &mut warnings).is_err());
+/// assert!(warnings.is_empty());
+///
+/// let result = check_keys("anatomic_sites=Chest", &known);
+/// assert!(handle(result, Some(&Config::new(false)), &mut warnings).is_ok());
+/// assert_eq!(warnings.len(), 1);
+/// assert!(warnings[0].contains("anatomic_sites"));
+/// ```
+pub fn handle(
+    result: Result<(), error::Kind>,
+    config: Option<&Config>,
+    warnings: &mut Vec<String>,
+) -> Result<(), error::Kind> {
+    match (result, config) {
+        (Err(err), Some(config)) if !config.strict() => {
+            warn!("ignoring unrecognized query parameters: {err}");
+            warnings.push(err.message().to_string());
+            Ok(())
+        }
+        (result, _) => result,
+    }
+}
+
+/// Checks the keys of a `query` string against the `known` parameter names
+/// for an endpoint whose response has no `warnings` (e.g., a single entity or
+/// a set of counts) with regard to the [`Config`] (the default [`Config`] is
+/// used when none is provided).
+///
+/// This is the same as [`handle()`], except that, in lenient mode, the
+/// unrecognized parameters are only logged.
+///
+/// # Examples
+///
+/// ```
+/// use ccdi_server as server;
+///
+/// use server::strict::check;
+/// use server::strict::Config;
+///
+/// let known = vec![String::from("validate")];
+///
+/// assert!(check("validate=true", &known, None).is_ok());
+/// assert!(check("valdate=true", &known, None).is_err());
+/// assert!(check("valdate=true", &known, Some(&Config::new(false))).is_ok());
+/// ```
+pub fn check(query: &str, known: &[String], config: Option<&Config>) -> Result<(), error::Kind> {
+    handle(check_keys(query, known), config, &mut Vec::new())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::params::related;
+    use crate::params::related::SAMPLE_PREFIX;
+
+    use super::*;
+
+    #[test]
+    fn it_collects_a_warning_for_each_lenient_check() {
+        let config = Config::new(false);
+        let known = vec![String::from("library_strategy")];
+        let mut warnings = Vec::new();
+
+        let result = related::check_keys("library_stratgy=RNA-Seq", SAMPLE_PREFIX, &known);
+        assert!(handle(result, Some(&config), &mut warnings).is_ok());
+
+        let result = related::check_keys("library_strategy=RNA-Seq", SAMPLE_PREFIX, &known);
+        assert!(handle(result, Some(&config), &mut warnings).is_ok());
+
+        assert_eq!(warnings.len(), 1);
+        assert!(warnings[0].contains("sample.library_stratgy"));
+    }
+}
@@ -0,0 +1,238 @@
+//! Suggestions for near-miss entity identifiers.
+//!
+//! When a single entity lookup fails (e.g., `/sample/org/ns/Sample-F62VO0JX`)
+//! because of a small typo, it is helpful to point the user towards the
+//! identifiers they most likely intended. This module provides a bounded
+//! edit-distance search over the identifiers known by a store to compute
+//! those suggestions.
+
+/// The maximum edit distance for an identifier to be considered similar.
+pub const MAX_DISTANCE: usize = 2;
+
+/// The maximum number of suggestions returned.
+pub const MAX_SUGGESTIONS: usize = 3;
+
+/// The maximum number of identifiers that will be scanned when computing
+/// suggestions.
+///
+/// Because there is no index over the identifiers within a store, computing
+/// suggestions requires a linear scan of every identifier. To avoid expensive
+/// scans on very large stores, no suggestions are computed when the number of
+/// identifiers exceeds this threshold.
+pub const MAX_SCAN_SIZE: usize = 10_000;
+
+/// Configuration for identifier suggestions.
+#[derive(Clone, Debug, Default)]
+pub struct Config {
+    /// Whether or not suggestions are enabled.
+    enabled: bool,
+}
+
+impl Config {
+    /// Creates a new [`Config`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ccdi_server as server;
+    ///
+    /// use server::suggest::Config;
+    ///
+    /// let config = Config::new(true);
+    /// assert!(config.enabled());
+    /// ```
+    pub fn new(enabled: bool) -> Self {
+        Self { enabled }
+    }
+
+    /// Gets whether suggestions are enabled for the [`Config`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ccdi_server as server;
+    ///
+    /// use server::suggest::Config;
+    ///
+    /// let config = Config::default();
+    /// assert!(!config.enabled());
+    /// ```
+    pub fn enabled(&self) -> bool {
+        self.enabled
+    }
+}
+
+/// Computes up to [`MAX_SUGGESTIONS`] identifiers from `candidates` that are
+/// within [`MAX_DISTANCE`] edits of `query`.
+///
+/// Suggestions are ordered by increasing edit distance (ties are broken
+/// lexicographically). If there are more than [`MAX_SCAN_SIZE`] candidates, no
+/// suggestions are computed.
+///
+/// # Examples
+///
+/// ```
+/// use ccdi_server as server;
+///
+/// use server::suggest::nearest;
+///
+/// let candidates = vec!["org/ns/Sample-F62VO0JX", "org/ns/Sample-AAAAAAAA"];
+///
+/// assert_eq!(
+///     nearest("org/ns/Sample-F62VOOJX", candidates.iter()),
+///     vec![String::from("org/ns/Sample-F62VO0JX")]
+/// );
+/// ```
+pub fn nearest<I>(query: &str, candidates: I) -> Vec<String>
+where
+    I: ExactSizeIterator,
+    I::Item: AsRef<str>,
+{
+    // NOTE: the length of the iterator is checked _before_ any of the
+    // candidates are consumed so that callers may lazily compute candidates
+    // without paying for that computation on large stores.
+    if candidates.len() > MAX_SCAN_SIZE {
+        return Vec::new();
+    }
+
+    let query = query.chars().collect::<Vec<_>>();
+
+    let mut results = candidates
+        .filter_map(|candidate| {
+            let candidate = candidate.as_ref();
+            let chars = candidate.chars().collect::<Vec<_>>();
+
+            bounded_distance(&query, &chars, MAX_DISTANCE)
+                .map(|distance| (distance, candidate.to_string()))
+        })
+        .collect::<Vec<_>>();
+
+    results.sort();
+
+    results
+        .into_iter()
+        .map(|(_, candidate)| candidate)
+        .take(MAX_SUGGESTIONS)
+        .collect()
+}
+
+/// Computes the Levenshtein distance between `a` and `b` if that distance is
+/// less than or equal to `bound`. If the distance exceeds `bound`, [`None`] is
+/// returned (the computation is abandoned as soon as that is known).
+fn bounded_distance(a: &[char], b: &[char], bound: usize) -> Option<usize> {
+    if a.len().abs_diff(b.len()) > bound {
+        return None;
+    }
+
+    let mut previous = (0..=b.len()).collect::<Vec<_>>();
+    let mut current = vec![0; b.len() + 1];
+
+    for (i, ca) in a.iter().enumerate() {
+        current[0] = i + 1;
+
+        for (j, cb) in b.iter().enumerate() {
+            let substitution = previous[j] + usize::from(ca != cb);
+            let insertion = current[j] + 1;
+            let deletion = previous[j + 1] + 1;
+            current[j + 1] = substitution.min(insertion).min(deletion);
+        }
+
+        // If every value in the row exceeds the bound, no later row can get
+        // back within the bound.
+        if current.iter().all(|distance| *distance > bound) {
+            return None;
+        }
+
+        std::mem::swap(&mut previous, &mut current);
+    }
+
+    Some(previous[b.len()]).filter(|distance| *distance <= bound)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn chars(value: &str) -> Vec<char> {
+        value.chars().collect()
+    }
+
+    #[test]
+    fn it_computes_bounded_distances() {
+        assert_eq!(
+            bounded_distance(&chars("kitten"), &chars("kitten"), 2),
+            Some(0)
+        );
+        assert_eq!(
+            bounded_distance(&chars("kitten"), &chars("sitten"), 2),
+            Some(1)
+        );
+        assert_eq!(
+            bounded_distance(&chars("kitten"), &chars("sittin"), 2),
+            Some(2)
+        );
+        assert_eq!(
+            bounded_distance(&chars("kitten"), &chars("sitting"), 2),
+            None
+        );
+        assert_eq!(bounded_distance(&chars(""), &chars("ab"), 2), Some(2));
+        assert_eq!(bounded_distance(&chars("a"), &chars("abcd"), 2), None);
+    }
+
+    #[test]
+    fn it_suggests_a_near_miss_identifier() {
+        let candidates = [
+            "example-organization/ExampleNamespaceOne/Sample-F62VO0JX",
+            "example-organization/ExampleNamespaceOne/Sample-Q81KD2MZ",
+            "example-organization/ExampleNamespaceTwo/Sample-F62VO0JX",
+        ];
+
+        let suggestions = nearest(
+            "example-organization/ExampleNamespaceOne/Sample-F62VOOJX",
+            candidates.iter(),
+        );
+
+        assert_eq!(
+            suggestions,
+            vec![String::from(
+                "example-organization/ExampleNamespaceOne/Sample-F62VO0JX"
+            )]
+        );
+    }
+
+    #[test]
+    fn it_does_not_suggest_anything_for_a_garbage_identifier() {
+        let candidates = [
+            "example-organization/ExampleNamespaceOne/Sample-F62VO0JX",
+            "example-organization/ExampleNamespaceOne/Sample-Q81KD2MZ",
+        ];
+
+        let suggestions = nearest("foo/bar/baz", candidates.iter());
+        assert!(suggestions.is_empty());
+    }
+
+    #[test]
+    fn it_limits_and_orders_the_suggestions() {
+        let candidates = ["abcde", "abcdx", "abcxx", "abxde", "xbcde"];
+        let suggestions = nearest("abcde", candidates.iter());
+
+        assert_eq!(
+            suggestions,
+            vec![
+                String::from("abcde"),
+                String::from("abcdx"),
+                String::from("abxde")
+            ]
+        );
+    }
+
+    #[test]
+    fn it_does_not_scan_stores_above_the_threshold() {
+        let candidates = (0..=MAX_SCAN_SIZE)
+            .map(|i| format!("Sample{i}"))
+            .collect::<Vec<_>>();
+
+        let suggestions = nearest("Sample1", candidates.iter());
+        assert!(suggestions.is_empty());
+    }
+}
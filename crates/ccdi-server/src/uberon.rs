@@ -0,0 +1,294 @@
+//! The curated hierarchy of Uberon terms used to roll up anatomical sites.
+//!
+//! Anatomical sites are Uberon terms, and counting samples by the most
+//! specific terms is often too granular to be useful (e.g., `jugular vein`
+//! and `portal vein` are both a `vein`). A [`Hierarchy`] holds the `is_a`
+//! relationships between the curated Uberon terms so that each site can be
+//! checked against an ancestor term.
+//!
+//! The hierarchy is written by `ccdi-curate uberon-hierarchy` as tab-separated
+//! values with a header row and one row per `is_a` relationship (where `\t`
+//! is a tab):
+//!
+//! ```text
+//! child_id\tchild_label\tparent_id\tparent_label
+//! ```
+//!
+//! Identifiers may be written as URLs
+//! (`http://purl.obolibrary.org/obo/UBERON_0001586`) or as compact
+//! identifiers (`UBERON:0001586`), and they are always compared in their
+//! compact form.
+
+use std::collections::HashMap;
+use std::collections::HashSet;
+use std::path::Path;
+
+/// The prefix of the URL of an Uberon term.
+const URL_PREFIX: &str = "http://purl.obolibrary.org/obo/";
+
+/// The columns of the header row of a hierarchy.
+const HEADER: &[&str] = &["child_id", "child_label", "parent_id", "parent_label"];
+
+/// An error related to a [`Hierarchy`].
+#[derive(Debug)]
+pub enum Error {
+    /// An input/output error.
+    Io(std::io::Error),
+
+    /// The header row of the hierarchy was missing or malformed.
+    InvalidHeader(String),
+
+    /// A row of the hierarchy was malformed.
+    InvalidRow {
+        /// The line number of the row (starting at one).
+        line: usize,
+
+        /// The contents of the row.
+        row: String,
+    },
+}
+
+impl std::fmt::Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::Io(err) => write!(f, "i/o error: {err}"),
+            Error::InvalidHeader(header) => write!(
+                f,
+                "invalid header: expected `{}`, found `{header}`",
+                HEADER.join("\t")
+            ),
+            Error::InvalidRow { line, row } => write!(
+                f,
+                "invalid row on line {line}: expected four tab-separated values, found `{row}`"
+            ),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+/// A [`Result`](std::result::Result) with an [`Error`].
+pub type Result<T> = std::result::Result<T, Error>;
+
+/// Normalizes an Uberon identifier to its compact form (`UBERON:0001586`).
+///
+/// # Examples
+///
+/// ```
+/// use ccdi_server as server;
+///
+/// use server::uberon::normalize;
+///
+/// assert_eq!(
+///     normalize("http://purl.obolibrary.org/obo/UBERON_0001586"),
+///     "UBERON:0001586"
+/// );
+/// assert_eq!(normalize("UBERON_0001586"), "UBERON:0001586");
+/// assert_eq!(normalize(" UBERON:0001586 "), "UBERON:0001586");
+/// ```
+pub fn normalize(id: &str) -> String {
+    let id = id.trim();
+    let id = id.strip_prefix(URL_PREFIX).unwrap_or(id);
+
+    match id.split_once(':') {
+        Some(_) => id.to_string(),
+        None => id.replacen('_', ":", 1),
+    }
+}
+
+/// The `is_a` hierarchy of the curated Uberon terms.
+#[derive(Clone, Debug, Default)]
+pub struct Hierarchy {
+    /// The label of each term keyed by its identifier.
+    labels: HashMap<String, String>,
+
+    /// The identifier of each term keyed by its label.
+    ids: HashMap<String, String>,
+
+    /// The identifiers of the parents of each term keyed by its identifier.
+    parents: HashMap<String, Vec<String>>,
+}
+
+impl Hierarchy {
+    /// Reads a [`Hierarchy`] from a file.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use ccdi_server as server;
+    ///
+    /// use server::uberon::Hierarchy;
+    ///
+    /// let hierarchy = Hierarchy::from_path("uberon-hierarchy.tsv")?;
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn from_path<P: AsRef<Path>>(path: P) -> Result<Self> {
+        let contents = std::fs::read_to_string(path).map_err(Error::Io)?;
+        contents.parse()
+    }
+
+    /// Gets the label of the term with the provided identifier (if the term is
+    /// within the [`Hierarchy`]).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ccdi_server as server;
+    ///
+    /// use server::uberon::Hierarchy;
+    ///
+    /// let hierarchy = "child_id\tchild_label\tparent_id\tparent_label\n\
+    ///                  UBERON:0001586\tinternal jugular vein\tUBERON:0004711\tjugular vein"
+    ///     .parse::<Hierarchy>()?;
+    ///
+    /// assert_eq!(hierarchy.label("UBERON:0004711"), Some("jugular vein"));
+    /// assert_eq!(
+    ///     hierarchy.label("http://purl.obolibrary.org/obo/UBERON_0001586"),
+    ///     Some("internal jugular vein")
+    /// );
+    /// assert_eq!(hierarchy.label("UBERON:0000000"), None);
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn label(&self, id: &str) -> Option<&str> {
+        self.labels.get(&normalize(id)).map(|label| label.as_str())
+    }
+
+    /// Gets whether the term with the provided label is the term with the
+    /// identifier `ancestor` or one of its descendants.
+    ///
+    /// Terms that are not within the [`Hierarchy`] are never descendants of
+    /// another term.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ccdi_server as server;
+    ///
+    /// use server::uberon::Hierarchy;
+    ///
+    /// let hierarchy = "child_id\tchild_label\tparent_id\tparent_label\n\
+    ///                  UBERON:0001586\tinternal jugular vein\tUBERON:0004711\tjugular vein\n\
+    ///                  UBERON:0004711\tjugular vein\tUBERON:0001638\tvein"
+    ///     .parse::<Hierarchy>()?;
+    ///
+    /// assert!(hierarchy.is_a("internal jugular vein", "UBERON:0001638"));
+    /// assert!(hierarchy.is_a("vein", "UBERON:0001638"));
+    /// assert!(!hierarchy.is_a("vein", "UBERON:0004711"));
+    /// assert!(!hierarchy.is_a("caecum", "UBERON:0001638"));
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn is_a(&self, label: &str, ancestor: &str) -> bool {
+        let ancestor = normalize(ancestor);
+
+        let id = match self.ids.get(label) {
+            Some(id) => id,
+            None => return false,
+        };
+
+        // NOTE: the hierarchy is a directed acyclic graph (a term may have
+        // multiple parents), so the visited terms are tracked to avoid walking
+        // shared ancestors more than once.
+        let mut visited = HashSet::new();
+        let mut queue = vec![id];
+
+        while let Some(id) = queue.pop() {
+            if *id == ancestor {
+                return true;
+            }
+
+            if !visited.insert(id) {
+                continue;
+            }
+
+            if let Some(parents) = self.parents.get(id) {
+                queue.extend(parents);
+            }
+        }
+
+        false
+    }
+}
+
+impl std::str::FromStr for Hierarchy {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let mut lines = s.lines().enumerate();
+
+        match lines.next() {
+            Some((_, header)) if header.split('\t').eq(HEADER.iter().copied()) => {}
+            Some((_, header)) => return Err(Error::InvalidHeader(header.to_string())),
+            None => return Err(Error::InvalidHeader(String::new())),
+        }
+
+        let mut hierarchy = Hierarchy::default();
+
+        for (index, row) in lines {
+            if row.trim().is_empty() {
+                continue;
+            }
+
+            let columns = row.split('\t').collect::<Vec<_>>();
+
+            let [child_id, child_label, parent_id, parent_label] = columns[..] else {
+                return Err(Error::InvalidRow {
+                    line: index + 1,
+                    row: row.to_string(),
+                });
+            };
+
+            let child_id = normalize(child_id);
+            let parent_id = normalize(parent_id);
+
+            for (id, label) in [(&child_id, child_label), (&parent_id, parent_label)] {
+                hierarchy.labels.insert(id.clone(), label.to_string());
+                hierarchy.ids.insert(label.to_string(), id.clone());
+            }
+
+            hierarchy
+                .parents
+                .entry(child_id)
+                .or_default()
+                .push(parent_id);
+        }
+
+        Ok(hierarchy)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_follows_multiple_parents() {
+        let hierarchy = "child_id\tchild_label\tparent_id\tparent_label\n\
+                         UBERON:1\tc\tUBERON:2\tb1\n\
+                         UBERON:1\tc\tUBERON:3\tb2\n\
+                         UBERON:3\tb2\tUBERON:4\ta\n"
+            .parse::<Hierarchy>()
+            .unwrap();
+
+        assert!(hierarchy.is_a("c", "UBERON:2"));
+        assert!(hierarchy.is_a("c", "UBERON:4"));
+        assert!(!hierarchy.is_a("b1", "UBERON:4"));
+    }
+
+    #[test]
+    fn it_rejects_malformed_hierarchies() {
+        let err = "child\tparent\n".parse::<Hierarchy>().unwrap_err();
+        assert!(matches!(err, Error::InvalidHeader(_)));
+
+        let err = "child_id\tchild_label\tparent_id\tparent_label\nUBERON:1\tc\tUBERON:2\n"
+            .parse::<Hierarchy>()
+            .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "invalid row on line 2: expected four tab-separated values, found \
+             `UBERON:1\tc\tUBERON:2`"
+        );
+    }
+}
@@ -0,0 +1,130 @@
+//! Listing the distinct values present for a metadata field.
+//!
+//! Clients building filter interfaces want to know which values actually
+//! occur within the data (rather than every permissible value of a common
+//! data element). The `/{entity}/values/{field}` endpoints list the distinct,
+//! non-null values of a field along with the number of entities on which each
+//! value is present. Fields with many distinct values (e.g., `identifiers`)
+//! are capped at a configurable limit (see [`Config`]).
+
+use serde_json::Value;
+
+use crate::responses::by::count::ValueCount;
+
+/// The default maximum number of distinct values listed for a field.
+pub const DEFAULT_LIMIT: usize = 100;
+
+/// Configuration for listing the distinct values of a field.
+#[derive(Clone, Debug)]
+pub struct Config {
+    /// The maximum number of distinct values listed for a field.
+    limit: usize,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            limit: DEFAULT_LIMIT,
+        }
+    }
+}
+
+impl Config {
+    /// Creates a new [`Config`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ccdi_server as server;
+    ///
+    /// use server::values::Config;
+    ///
+    /// let config = Config::new(10);
+    /// assert_eq!(config.limit(), 10);
+    /// ```
+    pub fn new(limit: usize) -> Self {
+        Self { limit }
+    }
+
+    /// Gets the maximum number of distinct values listed for a field for the
+    /// [`Config`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ccdi_server as server;
+    ///
+    /// use server::values::Config;
+    /// use server::values::DEFAULT_LIMIT;
+    ///
+    /// let config = Config::default();
+    /// assert_eq!(config.limit(), DEFAULT_LIMIT);
+    /// ```
+    pub fn limit(&self) -> usize {
+        self.limit
+    }
+}
+
+/// Computes the distinct, non-null values within the values of a field (one
+/// entry per entity) along with the number of entities on which each value is
+/// present.
+///
+/// Entities that are missing the field (`None`) or that have a `null` value are
+/// skipped. Array values (from multi-valued fields) contribute each of their
+/// distinct, non-null members, so an entity is counted at most once for each
+/// value. The values are sorted in descending order by count (ties are broken
+/// by the JSON representation of the value).
+///
+/// # Examples
+///
+/// ```
+/// use ccdi_server as server;
+/// use serde_json::json;
+///
+/// use server::values::distinct;
+///
+/// let values = distinct(vec![
+///     Some(json!("Female")),
+///     Some(json!(["Male", "Male", null])),
+///     Some(json!(null)),
+///     None,
+///     Some(json!("Male")),
+/// ]);
+///
+/// assert_eq!(values.len(), 2);
+/// assert_eq!(values[0].value, "Male");
+/// assert_eq!(values[0].count, 2);
+/// assert_eq!(values[1].value, "Female");
+/// assert_eq!(values[1].count, 1);
+/// ```
+pub fn distinct(values: Vec<Option<Value>>) -> Vec<ValueCount> {
+    let mut counts: Vec<ValueCount> = Vec::new();
+
+    for value in values.into_iter().flatten() {
+        let members = match value {
+            Value::Array(members) => members,
+            value => vec![value],
+        };
+
+        let mut present: Vec<Value> = Vec::new();
+
+        for member in members {
+            if !member.is_null() && !present.contains(&member) {
+                present.push(member);
+            }
+        }
+
+        for value in present {
+            match counts.iter_mut().find(|result| result.value == value) {
+                Some(result) => result.count += 1,
+                None => counts.push(ValueCount { value, count: 1 }),
+            }
+        }
+    }
+
+    // NOTE: the `std::cmp::Reverse` here is used to sort the values in
+    // descending order. The values are compared by their JSON representation
+    // so that values with equal counts are listed in a stable order.
+    counts.sort_by_cached_key(|result| (std::cmp::Reverse(result.count), result.value.to_string()));
+    counts
+}
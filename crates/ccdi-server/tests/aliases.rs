@@ -0,0 +1,119 @@
+//! Integration tests for the aliases of filter parameters.
+//!
+//! Each alias declared within the alias table of a set of filter parameters
+//! (see [`Aliases`]) is checked against a demo store: filtering with the alias
+//! must return exactly the same response as filtering with the canonical name,
+//! and providing both spellings with different values must be rejected.
+
+use actix_web::http::StatusCode;
+use actix_web::test::call_service;
+use actix_web::test::init_service;
+use actix_web::test::read_body_json;
+use actix_web::test::TestRequest;
+use actix_web::web::Data;
+use actix_web::App;
+use rand::rngs::StdRng;
+use rand::SeedableRng as _;
+use serde_json::Value;
+
+use ccdi_server as server;
+
+use server::filter::alias::Aliases;
+use server::params::filter::File as FilterFileParams;
+use server::params::filter::Sample as FilterSampleParams;
+use server::params::filter::Subject as FilterSubjectParams;
+use server::routes::file;
+use server::routes::sample;
+use server::routes::subject;
+
+/// The number of each entity within the demo store.
+const NUMBER_OF_ENTITIES: usize = 100;
+
+/// The fraction of entities within the demo store that have no metadata.
+const METADATA_LESS_FRACTION: f64 = 0.5;
+
+/// Requests `uri` from an application serving a seeded demo store and returns
+/// the status and the body of the response.
+async fn get(uri: &str) -> (StatusCode, Value) {
+    let mut rng = StdRng::seed_from_u64(0);
+
+    let subjects =
+        subject::Store::random_with_rng(NUMBER_OF_ENTITIES, METADATA_LESS_FRACTION, &mut rng);
+    let samples = sample::Store::random_with_rng(
+        NUMBER_OF_ENTITIES,
+        subjects.subjects.lock().unwrap(),
+        METADATA_LESS_FRACTION,
+        &mut rng,
+    );
+    let files = file::Store::random_with_rng(
+        NUMBER_OF_ENTITIES,
+        samples.samples.lock().unwrap(),
+        METADATA_LESS_FRACTION,
+        &mut rng,
+    );
+
+    let app = init_service(
+        App::new()
+            .configure(subject::configure(Data::new(subjects)))
+            .configure(sample::configure(Data::new(samples)))
+            .configure(file::configure(Data::new(files))),
+    )
+    .await;
+
+    let response = call_service(&app, TestRequest::get().uri(uri).to_request()).await;
+    let status = response.status();
+
+    (status, read_body_json(response).await)
+}
+
+/// Checks each alias of the filter parameters `P` against the `path`.
+async fn assert_aliases<P: Aliases>(path: &str) {
+    let (_, all) = get(&format!("{path}?per_page=100")).await;
+
+    for (name, alias) in P::ALIASES {
+        // NOTE: `not:null` matches every entity with a value for the field,
+        // which is never every entity within the demo store (as some entities
+        // have no metadata).
+        let (status, canonical) = get(&format!("{path}?{name}=not:null&per_page=100")).await;
+        assert_eq!(status, StatusCode::OK, "{name}");
+        assert_ne!(canonical["data"], all["data"], "{name}");
+
+        let (status, aliased) = get(&format!("{path}?{alias}=not:null&per_page=100")).await;
+        assert_eq!(status, StatusCode::OK, "{alias}");
+        assert_eq!(aliased, canonical, "{alias}");
+
+        let (status, both) = get(&format!(
+            "{path}?{name}=not:null&{alias}=not:null&per_page=100"
+        ))
+        .await;
+        assert_eq!(status, StatusCode::OK, "{name} and {alias}");
+        assert_eq!(both, canonical, "{name} and {alias}");
+
+        let (status, errors) = get(&format!("{path}?{name}=not:null&{alias}=null")).await;
+        assert_eq!(
+            status,
+            StatusCode::UNPROCESSABLE_ENTITY,
+            "{name} and {alias}"
+        );
+        assert_eq!(
+            errors["errors"][0]["parameters"],
+            serde_json::json!([name, alias]),
+            "{name} and {alias}"
+        );
+    }
+}
+
+#[actix_web::test]
+async fn it_accepts_the_aliases_of_subject_filters() {
+    assert_aliases::<FilterSubjectParams>("/subject").await;
+}
+
+#[actix_web::test]
+async fn it_accepts_the_aliases_of_sample_filters() {
+    assert_aliases::<FilterSampleParams>("/sample").await;
+}
+
+#[actix_web::test]
+async fn it_accepts_the_aliases_of_file_filters() {
+    assert_aliases::<FilterFileParams>("/file").await;
+}
@@ -0,0 +1,124 @@
+//! Integration tests for compressed responses.
+//!
+//! The example server compresses its responses (using the [`Compress`]
+//! middleware) when the client asks for it with an `Accept-Encoding` header.
+//! Because partner implementations mirror the behavior of the example server,
+//! these tests check that compressed responses are still valid responses once
+//! they are decompressed.
+
+use actix_web::body::to_bytes;
+use actix_web::body::BodyStream;
+use actix_web::dev::Decompress;
+use actix_web::dev::Payload;
+use actix_web::dev::ServiceResponse;
+use actix_web::http::header;
+use actix_web::http::StatusCode;
+use actix_web::middleware::Compress;
+use actix_web::test::call_service;
+use actix_web::test::init_service;
+use actix_web::test::read_body;
+use actix_web::test::TestRequest;
+use actix_web::web::Bytes;
+use actix_web::web::Data;
+use actix_web::App;
+use serde_json::Value;
+
+use ccdi_server as server;
+
+use server::responses::Errors;
+use server::responses::Samples;
+use server::routes::sample;
+use server::routes::subject;
+
+/// The number of samples served by the test application.
+const NUMBER_OF_SAMPLES: usize = 100;
+
+/// Requests `uri` from an application wrapped in the [`Compress`] middleware,
+/// optionally sending an `Accept-Encoding` header.
+async fn get(uri: &str, accept_encoding: Option<&str>) -> ServiceResponse {
+    let subjects = subject::Store::random(NUMBER_OF_SAMPLES);
+    let samples = sample::Store::random(NUMBER_OF_SAMPLES, subjects.subjects.lock().unwrap());
+
+    let app = init_service(
+        App::new()
+            .wrap(Compress::default())
+            .configure(sample::configure(Data::new(samples))),
+    )
+    .await;
+
+    let mut request = TestRequest::get().uri(uri);
+
+    if let Some(accept_encoding) = accept_encoding {
+        request = request.insert_header((header::ACCEPT_ENCODING, accept_encoding));
+    }
+
+    call_service(&app, request.to_request())
+        .await
+        .map_into_boxed_body()
+}
+
+/// Reads the body of a [`ServiceResponse`], decompressing it according to its
+/// `Content-Encoding` header.
+async fn decompressed_body(response: ServiceResponse) -> Bytes {
+    let headers = response.headers().clone();
+    let body = read_body(response).await;
+    let decoder = Decompress::<Payload>::from_headers(Payload::from(body), &headers);
+
+    to_bytes(BodyStream::new(decoder)).await.unwrap()
+}
+
+/// Gets the `Content-Encoding` header of a [`ServiceResponse`] (if it has
+/// one).
+fn content_encoding(response: &ServiceResponse) -> Option<&str> {
+    response
+        .headers()
+        .get(header::CONTENT_ENCODING)
+        .map(|value| value.to_str().unwrap())
+}
+
+#[actix_web::test]
+async fn it_compresses_responses_with_gzip_when_requested() {
+    let response = get("/sample?per_page=100", Some("gzip")).await;
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(content_encoding(&response), Some("gzip"));
+
+    let body = decompressed_body(response).await;
+    serde_json::from_slice::<Samples>(&body).unwrap();
+
+    let value = serde_json::from_slice::<Value>(&body).unwrap();
+    assert_eq!(value["data"].as_array().unwrap().len(), NUMBER_OF_SAMPLES);
+}
+
+#[actix_web::test]
+async fn it_compresses_responses_with_brotli_when_requested() {
+    let response = get("/sample?per_page=100", Some("br")).await;
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(content_encoding(&response), Some("br"));
+
+    let body = decompressed_body(response).await;
+    serde_json::from_slice::<Samples>(&body).unwrap();
+}
+
+#[actix_web::test]
+async fn it_does_not_compress_responses_unless_requested() {
+    let response = get("/sample?per_page=100", None).await;
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(content_encoding(&response), None);
+
+    let body = read_body(response).await;
+    serde_json::from_slice::<Samples>(&body).unwrap();
+}
+
+#[actix_web::test]
+async fn it_compresses_error_responses() {
+    let response = get(
+        "/sample/example-organization/ExampleNamespace/NotASample",
+        Some("gzip"),
+    )
+    .await;
+    assert_eq!(response.status(), StatusCode::NOT_FOUND);
+    assert_eq!(content_encoding(&response), Some("gzip"));
+
+    let body = decompressed_body(response).await;
+    serde_json::from_slice::<Errors>(&body).unwrap();
+}
@@ -0,0 +1,342 @@
+//! Integration tests for the filter expression endpoints.
+//!
+//! Filter expressions are posted to an application serving a seeded demo store
+//! and checked against the equivalent queries of the list endpoints.
+
+use actix_web::http::StatusCode;
+use actix_web::test::call_service;
+use actix_web::test::init_service;
+use actix_web::test::read_body_json;
+use actix_web::test::TestRequest;
+use actix_web::web::Data;
+use actix_web::App;
+use rand::rngs::StdRng;
+use rand::SeedableRng as _;
+use serde_json::Value;
+
+use ccdi_server as server;
+
+use server::routes::file;
+use server::routes::sample;
+use server::routes::subject;
+
+/// The number of each entity within the demo store.
+const NUMBER_OF_ENTITIES: usize = 100;
+
+/// The fraction of entities within the demo store that have no metadata.
+const METADATA_LESS_FRACTION: f64 = 0.5;
+
+/// Sends `request` to an application serving a seeded demo store and returns
+/// the status and the body of the response.
+async fn call(request: TestRequest) -> (StatusCode, Value) {
+    let mut rng = StdRng::seed_from_u64(0);
+
+    let subjects =
+        subject::Store::random_with_rng(NUMBER_OF_ENTITIES, METADATA_LESS_FRACTION, &mut rng);
+    let samples = sample::Store::random_with_rng(
+        NUMBER_OF_ENTITIES,
+        subjects.subjects.lock().unwrap(),
+        METADATA_LESS_FRACTION,
+        &mut rng,
+    );
+    let files = file::Store::random_with_rng(
+        NUMBER_OF_ENTITIES,
+        samples.samples.lock().unwrap(),
+        METADATA_LESS_FRACTION,
+        &mut rng,
+    );
+
+    let app = init_service(
+        App::new()
+            .configure(subject::configure(Data::new(subjects)))
+            .configure(sample::configure(Data::new(samples)))
+            .configure(file::configure(Data::new(files))),
+    )
+    .await;
+
+    let response = call_service(&app, request.to_request()).await;
+    let status = response.status();
+
+    (status, read_body_json(response).await)
+}
+
+/// Gets the entities returned within the body of a response.
+///
+/// When no entities match the filters, the body is an empty array.
+fn data(body: Value) -> Value {
+    match body {
+        Value::Array(_) => Value::Array(Vec::new()),
+        mut body => body["data"].take(),
+    }
+}
+
+/// Gets the entities matching the raw `query` from the list endpoint at
+/// `path`.
+async fn get(path: &str, query: &str) -> Value {
+    let uri = format!("{path}?{query}&per_page={NUMBER_OF_ENTITIES}");
+    let (status, body) = call(TestRequest::get().uri(&uri)).await;
+    assert_eq!(status, StatusCode::OK, "{uri}");
+
+    data(body)
+}
+
+/// Gets the entities matching the filter `expression` from the filter
+/// endpoint at `path`.
+async fn post(path: &str, expression: Value) -> Value {
+    let uri = format!("{path}/filter?per_page={NUMBER_OF_ENTITIES}");
+    let (status, body) = call(TestRequest::post().uri(&uri).set_json(&expression)).await;
+    assert_eq!(status, StatusCode::OK, "{uri}: {expression}");
+
+    data(body)
+}
+
+/// Gets the union of the entities within `a` and `b` (sorted by identifier).
+fn union(a: &Value, b: &Value) -> Vec<Value> {
+    let mut entities = a.as_array().unwrap().clone();
+
+    for entity in b.as_array().unwrap() {
+        if !entities.contains(entity) {
+            entities.push(entity.clone());
+        }
+    }
+
+    entities.sort_by_key(|entity| entity["id"].to_string());
+    entities
+}
+
+#[actix_web::test]
+async fn a_single_leaf_matches_the_equivalent_query() {
+    for (path, leaf, query) in [
+        ("/subject", serde_json::json!({}), ""),
+        ("/subject", serde_json::json!({ "sex": "F" }), "sex=F"),
+        (
+            "/subject",
+            serde_json::json!({ "sex": "not:F", "has_metadata": true }),
+            "sex=not:F&has_metadata=true",
+        ),
+        (
+            "/sample",
+            serde_json::json!({ "disease_phase": "not:null" }),
+            "disease_phase=not:null",
+        ),
+        (
+            "/sample",
+            serde_json::json!({ "has_metadata": false }),
+            "has_metadata=false",
+        ),
+        (
+            "/file",
+            serde_json::json!({ "type": "not:null" }),
+            "type=not:null",
+        ),
+        (
+            "/file",
+            serde_json::json!({ "has_metadata": true }),
+            "has_metadata=true",
+        ),
+    ] {
+        assert_eq!(
+            post(path, leaf).await,
+            get(path, query).await,
+            "{path}?{query}"
+        );
+    }
+}
+
+#[actix_web::test]
+async fn ages_with_units_match_the_equivalent_number_of_days() {
+    for (days, years) in [
+        (
+            serde_json::json!({ "age_at_diagnosis": 730.5 }),
+            serde_json::json!({ "age_at_diagnosis": { "value": 2, "unit": "years" } }),
+        ),
+        (
+            serde_json::json!({ "age_at_diagnosis": { "gte": 730.5 } }),
+            serde_json::json!({ "age_at_diagnosis": { "gte": 2, "unit": "years" } }),
+        ),
+        (
+            serde_json::json!({ "age_at_collection": { "lt": 365.25 } }),
+            serde_json::json!({ "age_at_collection": { "lt": 12, "unit": "months" } }),
+        ),
+    ] {
+        assert_eq!(
+            post("/sample", days.clone()).await,
+            post("/sample", years.clone()).await,
+            "{years}"
+        );
+    }
+
+    // NOTE: the range must match some samples for the comparison above to be
+    // meaningful.
+    let older = post(
+        "/sample",
+        serde_json::json!({ "age_at_diagnosis": { "gte": 2, "unit": "years" } }),
+    )
+    .await;
+    assert!(!older.as_array().unwrap().is_empty());
+
+    // The same normalization applies to the query string of the list endpoint.
+    assert_eq!(
+        get(
+            "/sample",
+            "age_at_diagnosis=%7B%22gte%22%3A2%2C%22unit%22%3A%22years%22%7D"
+        )
+        .await,
+        older
+    );
+}
+
+#[actix_web::test]
+async fn provenance_constraints_are_combined_with_the_value() {
+    let source = "pathology report";
+    let samples = get("/sample", "has_metadata=true").await;
+
+    let matching = |value: Option<&Value>| {
+        samples
+            .as_array()
+            .unwrap()
+            .iter()
+            .filter(|sample| {
+                let diagnosis = &sample["metadata"]["diagnosis"];
+                diagnosis["details"]["source"] == source
+                    && value.into_iter().all(|value| diagnosis["value"] == *value)
+            })
+            .cloned()
+            .collect::<Vec<_>>()
+    };
+
+    // NOTE: the demo store must describe the provenance of some diagnoses for
+    // the comparisons below to be meaningful.
+    let expected = matching(None);
+    assert!(!expected.is_empty());
+
+    let results = post(
+        "/sample",
+        serde_json::json!({ "diagnosis": { "source": source } }),
+    )
+    .await;
+    assert_eq!(results, Value::Array(expected.clone()));
+
+    let value = &expected[0]["metadata"]["diagnosis"]["value"];
+    let leaf = serde_json::json!({ "diagnosis": { "value": value, "source": source } });
+
+    let results = post("/sample", leaf.clone()).await;
+    assert_eq!(results, Value::Array(matching(Some(value))));
+
+    // The same constraints apply to the query string of the list endpoint.
+    let query = url::form_urlencoded::Serializer::new(String::new())
+        .append_pair("diagnosis", &leaf["diagnosis"].to_string())
+        .finish();
+    assert_eq!(get("/sample", &query).await, results);
+
+    // A source that is never used matches no samples, even when the value does.
+    let results = post(
+        "/sample",
+        serde_json::json!({ "diagnosis": { "value": value, "source": "hearsay" } }),
+    )
+    .await;
+    assert!(results.as_array().unwrap().is_empty());
+
+    let (status, body) = call(
+        TestRequest::post()
+            .uri("/sample/filter")
+            .set_json(serde_json::json!({ "diagnosis": { "source": source, "origin": "A" } })),
+    )
+    .await;
+    assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+    assert_eq!(
+        body["errors"][0]["parameters"],
+        serde_json::json!(["diagnosis"])
+    );
+    assert_eq!(
+        body["errors"][0]["reason"],
+        "Unrecognized key `origin` (expected `value`, `source`, `method`, or `comment`)."
+    );
+}
+
+#[actix_web::test]
+async fn an_any_group_matches_the_union_of_its_members() {
+    let female = get("/subject", "sex=F").await;
+    let without_metadata = get("/subject", "has_metadata=false").await;
+
+    let results = post(
+        "/subject",
+        serde_json::json!({ "any": [{ "sex": "F" }, { "has_metadata": false }] }),
+    )
+    .await;
+
+    let mut results = results.as_array().unwrap().clone();
+    results.sort_by_key(|entity| entity["id"].to_string());
+    assert_eq!(results, union(&female, &without_metadata));
+}
+
+#[actix_web::test]
+async fn an_all_group_matches_the_intersection_of_its_members() {
+    let results = post(
+        "/subject",
+        serde_json::json!({ "all": [{ "sex": "F" }, { "has_metadata": true }] }),
+    )
+    .await;
+
+    assert_eq!(results, get("/subject", "sex=F&has_metadata=true").await);
+}
+
+#[actix_web::test]
+async fn nested_groups_are_evaluated() {
+    // NOTE: a filter OR its negation matches every entity, so the nested
+    // group only narrows the results by the other member of the outer group.
+    let results = post(
+        "/sample",
+        serde_json::json!({
+            "all": [
+                { "any": [{ "disease_phase": "Relapse" }, { "disease_phase": "not:Relapse" }] },
+                { "has_metadata": true }
+            ]
+        }),
+    )
+    .await;
+
+    assert_eq!(results, get("/sample", "has_metadata=true").await);
+}
+
+#[actix_web::test]
+async fn invalid_expressions_are_rejected() {
+    for (expression, reason) in [
+        (
+            serde_json::json!({ "any": [] }),
+            "The `any` group at `/` must contain at least one expression.",
+        ),
+        (
+            serde_json::json!({ "any": [{ "sex": "F" }, { "all": [] }] }),
+            "The `all` group at `/any/1` must contain at least one expression.",
+        ),
+        (
+            serde_json::json!({ "all": [{ "all": [{ "all": [{ "all": [{ "all": [{}] }] }] }] }] }),
+            "The `all` group at `/all/0/all/0/all/0/all/0` is nested more than 4 groups deep.",
+        ),
+    ] {
+        let (status, body) = call(
+            TestRequest::post()
+                .uri("/subject/filter")
+                .set_json(&expression),
+        )
+        .await;
+
+        assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY, "{expression}");
+        assert_eq!(
+            body["errors"][0]["kind"], "InvalidParameters",
+            "{expression}"
+        );
+        assert_eq!(body["errors"][0]["reason"], reason, "{expression}");
+    }
+
+    let (status, body) = call(
+        TestRequest::post()
+            .uri("/subject/filter")
+            .insert_header(("content-type", "application/json"))
+            .set_payload("{"),
+    )
+    .await;
+    assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+    assert_eq!(body["errors"][0]["kind"], "InvalidParameters");
+}
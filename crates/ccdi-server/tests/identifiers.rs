@@ -0,0 +1,211 @@
+//! Integration tests for looking up single entities by identifier.
+//!
+//! Each entity is requested from an application serving a seeded demo store,
+//! and the namespace of the requested identifier is checked against the
+//! namespaces known by the server before the entity itself (or the entities
+//! related to it) is looked up.
+
+use actix_web::http::StatusCode;
+use actix_web::test::call_service;
+use actix_web::test::init_service;
+use actix_web::test::read_body_json;
+use actix_web::test::TestRequest;
+use actix_web::web::Data;
+use actix_web::App;
+use rand::rngs::StdRng;
+use rand::SeedableRng as _;
+use serde_json::Value;
+
+use ccdi_server as server;
+
+use server::routes::file;
+use server::routes::sample;
+use server::routes::subject;
+
+/// The number of each entity within the demo store.
+const NUMBER_OF_ENTITIES: usize = 100;
+
+/// The fraction of entities within the demo store that have no metadata.
+const METADATA_LESS_FRACTION: f64 = 0.5;
+
+/// The entities that can be looked up by identifier.
+const ENTITIES: [&str; 3] = ["subject", "sample", "file"];
+
+/// Creates a seeded demo store with `count` of each entity (the same seed
+/// always creates the same store).
+fn stores(count: usize) -> (subject::Store, sample::Store, file::Store) {
+    let mut rng = StdRng::seed_from_u64(0);
+
+    let subjects = subject::Store::random_with_rng(count, METADATA_LESS_FRACTION, &mut rng);
+    let samples = sample::Store::random_with_rng(
+        count,
+        subjects.subjects.lock().unwrap(),
+        METADATA_LESS_FRACTION,
+        &mut rng,
+    );
+    let files = file::Store::random_with_rng(
+        count,
+        samples.samples.lock().unwrap(),
+        METADATA_LESS_FRACTION,
+        &mut rng,
+    );
+
+    (subjects, samples, files)
+}
+
+/// Gets the path (`{organization}/{namespace}/{name}`) of the first entity of
+/// the provided kind within the seeded demo store.
+fn first(entity: &str) -> String {
+    let (subjects, samples, files) = stores(NUMBER_OF_ENTITIES);
+
+    let (namespace, name) = match entity {
+        "subject" => {
+            let subject = subjects.subjects.lock().unwrap()[0].clone();
+            (
+                subject.id().namespace().clone(),
+                subject.id().name().to_string(),
+            )
+        }
+        "sample" => {
+            let sample = samples.samples.lock().unwrap()[0].clone();
+            (
+                sample.id().namespace().clone(),
+                sample.id().name().to_string(),
+            )
+        }
+        "file" => {
+            let file = files.files.lock().unwrap()[0].clone();
+            (file.id().namespace().clone(), file.id().name().to_string())
+        }
+        _ => unreachable!("unknown entity: {entity}"),
+    };
+
+    format!(
+        "{}/{}/{name}",
+        namespace.organization().as_str(),
+        namespace.name().as_str()
+    )
+}
+
+/// Requests `uri` from an application serving a seeded demo store and
+/// returns the status and the body of the response.
+async fn get(uri: &str) -> (StatusCode, Value) {
+    let (subjects, samples, files) = stores(NUMBER_OF_ENTITIES);
+
+    let app = init_service(
+        App::new()
+            .configure(subject::configure(Data::new(subjects)))
+            .configure(sample::configure(Data::new(samples)))
+            .configure(file::configure(Data::new(files))),
+    )
+    .await;
+
+    let response = call_service(&app, TestRequest::get().uri(uri).to_request()).await;
+    let status = response.status();
+
+    (status, read_body_json(response).await)
+}
+
+#[actix_web::test]
+async fn it_looks_up_entities_with_a_known_namespace() {
+    for entity in ENTITIES {
+        let path = first(entity);
+        let (status, body) = get(&format!("/{entity}/{path}")).await;
+
+        assert_eq!(status, StatusCode::OK, "{entity}");
+        assert_eq!(
+            format!(
+                "{}/{}/{}",
+                body["id"]["namespace"]["organization"].as_str().unwrap(),
+                body["id"]["namespace"]["name"].as_str().unwrap(),
+                body["id"]["name"].as_str().unwrap(),
+            ),
+            path
+        );
+    }
+}
+
+#[actix_web::test]
+async fn it_reports_an_unknown_name_within_a_known_namespace_as_not_found() {
+    for entity in ENTITIES {
+        let (status, body) = get(&format!(
+            "/{entity}/example-organization/ExampleNamespaceOne/Unknown"
+        ))
+        .await;
+
+        assert_eq!(status, StatusCode::NOT_FOUND, "{entity}");
+        assert_eq!(body["errors"][0]["kind"], "NotFound", "{entity}");
+    }
+}
+
+#[actix_web::test]
+async fn it_reports_a_typo_in_the_namespace_as_an_unknown_namespace() {
+    for entity in ENTITIES {
+        let (status, body) = get(&format!(
+            "/{entity}/example-organization/ExampleNamespaceOen/Unknown"
+        ))
+        .await;
+
+        assert_eq!(status, StatusCode::NOT_FOUND, "{entity}");
+
+        let error = &body["errors"][0];
+        assert_eq!(error["kind"], "UnknownNamespace", "{entity}");
+        assert_eq!(
+            error["namespace"],
+            "example-organization/ExampleNamespaceOen"
+        );
+        assert_eq!(
+            error["known"],
+            serde_json::json!([
+                "example-organization/ExampleNamespaceOne",
+                "example-organization/ExampleNamespaceTwo"
+            ])
+        );
+        assert_eq!(
+            error["similar"],
+            serde_json::json!(["example-organization/ExampleNamespaceOne"])
+        );
+    }
+}
+
+#[actix_web::test]
+async fn it_reports_a_typo_in_the_namespace_of_a_related_listing_as_an_unknown_namespace() {
+    for uri in [
+        "/subject/example-organization/ExampleNamespaceOen/Unknown/samples",
+        "/subject/example-organization/ExampleNamespaceOen/Unknown/files",
+        "/sample/example-organization/ExampleNamespaceOen/Unknown/files",
+    ] {
+        let (status, body) = get(uri).await;
+
+        assert_eq!(status, StatusCode::NOT_FOUND, "{uri}");
+        assert_eq!(body["errors"][0]["kind"], "UnknownNamespace", "{uri}");
+        assert_eq!(
+            body["errors"][0]["similar"],
+            serde_json::json!(["example-organization/ExampleNamespaceOne"]),
+            "{uri}"
+        );
+    }
+}
+
+#[actix_web::test]
+async fn it_compares_namespaces_case_sensitively() {
+    let path = first("subject");
+    let (status, body) = get(&format!("/subject/{}", path.to_lowercase())).await;
+
+    assert_eq!(status, StatusCode::NOT_FOUND);
+    assert_eq!(body["errors"][0]["kind"], "UnknownNamespace");
+}
+
+#[actix_web::test]
+async fn it_reports_an_unknown_organization_as_an_unknown_namespace() {
+    let (status, body) = get("/sample/other-organization/ExampleNamespaceOne/Sample1").await;
+
+    assert_eq!(status, StatusCode::NOT_FOUND);
+    assert_eq!(body["errors"][0]["kind"], "UnknownNamespace");
+    assert_eq!(
+        body["errors"][0]["message"],
+        "Unknown namespace 'other-organization/ExampleNamespaceOne'. Known namespaces: \
+         'example-organization/ExampleNamespaceOne', \
+         'example-organization/ExampleNamespaceTwo'."
+    );
+}
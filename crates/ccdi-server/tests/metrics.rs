@@ -0,0 +1,73 @@
+//! Integration tests for request metrics.
+//!
+//! Requests are made to an application wrapped in the [`Metrics`] middleware,
+//! and the counters within the shared [`Registry`] (and those scraped from
+//! `/metrics`) are checked afterwards.
+
+use actix_web::http::header;
+use actix_web::http::StatusCode;
+use actix_web::test::call_service;
+use actix_web::test::init_service;
+use actix_web::test::read_body;
+use actix_web::test::TestRequest;
+use actix_web::web::Data;
+use actix_web::App;
+
+use ccdi_server as server;
+
+use server::metrics::Metrics;
+use server::metrics::Registry;
+use server::routes::info;
+use server::routes::metrics;
+use server::routes::subject;
+
+/// The number of subjects served by the test application.
+const NUMBER_OF_SUBJECTS: usize = 10;
+
+#[actix_web::test]
+async fn it_counts_requests_by_route() {
+    let registry = Data::new(Registry::default());
+
+    let app = init_service(
+        App::new()
+            .wrap(Metrics::new(registry.clone()))
+            .configure(subject::configure(Data::new(subject::Store::random(
+                NUMBER_OF_SUBJECTS,
+            ))))
+            .configure(info::configure())
+            .configure(metrics::configure(registry.clone())),
+    )
+    .await;
+
+    for uri in [
+        "/subject",
+        "/subject?page=2&per_page=5",
+        "/subject/org/ns/missing",
+        "/info",
+    ] {
+        call_service(&app, TestRequest::get().uri(uri).to_request()).await;
+    }
+
+    assert_eq!(registry.requests("subject"), 3);
+    assert_eq!(registry.client_errors("subject"), 1);
+    assert_eq!(registry.server_errors("subject"), 0);
+    assert_eq!(registry.requests("info"), 1);
+    assert_eq!(registry.requests("sample"), 0);
+
+    let response = call_service(&app, TestRequest::get().uri("/metrics").to_request()).await;
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(
+        response.headers().get(header::CONTENT_TYPE).unwrap(),
+        metrics::CONTENT_TYPE
+    );
+
+    let body = read_body(response).await;
+    let text = std::str::from_utf8(&body).unwrap();
+
+    assert!(text.contains("ccdi_http_requests_total{route=\"subject\"} 3\n"));
+    assert!(text.contains("ccdi_http_client_errors_total{route=\"subject\"} 1\n"));
+    assert!(text.contains("ccdi_http_requests_total{route=\"info\"} 1\n"));
+    assert!(text.contains("ccdi_http_request_duration_seconds_count{route=\"subject\"} 3\n"));
+    assert!(text
+        .contains("ccdi_http_request_duration_seconds_bucket{route=\"subject\",le=\"+Inf\"} 3\n"));
+}
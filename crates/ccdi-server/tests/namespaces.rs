@@ -0,0 +1,188 @@
+//! Integration tests for the namespace summary endpoint.
+//!
+//! The summary of each namespace is requested from an application serving a
+//! seeded demo store and checked against counts computed directly from the
+//! store.
+
+use actix_web::http::StatusCode;
+use actix_web::test::call_service;
+use actix_web::test::init_service;
+use actix_web::test::read_body_json;
+use actix_web::test::TestRequest;
+use actix_web::web::Data;
+use actix_web::App;
+use rand::rngs::StdRng;
+use rand::SeedableRng as _;
+use serde_json::Value;
+
+use ccdi_server as server;
+
+use server::routes::file;
+use server::routes::namespace;
+use server::routes::namespace::NAMESPACES;
+use server::routes::sample;
+use server::routes::subject;
+
+/// The number of each entity within the demo store.
+const NUMBER_OF_ENTITIES: usize = 100;
+
+/// The fraction of entities within the demo store that have no metadata.
+const METADATA_LESS_FRACTION: f64 = 0.5;
+
+/// Creates a seeded demo store with `count` of each entity (the same seed
+/// always creates the same store).
+fn stores(count: usize) -> (subject::Store, sample::Store, file::Store) {
+    let mut rng = StdRng::seed_from_u64(0);
+
+    let subjects = subject::Store::random_with_rng(count, METADATA_LESS_FRACTION, &mut rng);
+    let samples = sample::Store::random_with_rng(
+        count,
+        subjects.subjects.lock().unwrap(),
+        METADATA_LESS_FRACTION,
+        &mut rng,
+    );
+    let files = file::Store::random_with_rng(
+        count,
+        samples.samples.lock().unwrap(),
+        METADATA_LESS_FRACTION,
+        &mut rng,
+    );
+
+    (subjects, samples, files)
+}
+
+/// Requests `uri` from an application serving a seeded demo store with
+/// `count` of each entity and returns the status and the body of the
+/// response.
+async fn get(count: usize, uri: &str) -> (StatusCode, Value) {
+    let (subjects, samples, files) = stores(count);
+
+    let app = init_service(
+        App::new()
+            .configure(subject::configure(Data::new(subjects)))
+            .configure(sample::configure(Data::new(samples)))
+            .configure(file::configure(Data::new(files)))
+            .configure(namespace::configure()),
+    )
+    .await;
+
+    let response = call_service(&app, TestRequest::get().uri(uri).to_request()).await;
+    let status = response.status();
+
+    (status, read_body_json(response).await)
+}
+
+#[actix_web::test]
+async fn it_summarizes_every_namespace() {
+    let (subjects, samples, files) = stores(NUMBER_OF_ENTITIES);
+    let subjects = subjects.subjects.lock().unwrap().clone();
+    let samples = samples.samples.lock().unwrap().clone();
+    let files = files.files.lock().unwrap().clone();
+
+    let mut totals = (0, 0, 0);
+
+    for namespace in NAMESPACES.values() {
+        let id = namespace.id();
+
+        let expected_subjects = subjects
+            .iter()
+            .filter(|subject| subject.id().namespace() == id)
+            .count();
+        let expected_samples = samples
+            .iter()
+            .filter(|sample| sample.id().namespace() == id)
+            .collect::<Vec<_>>();
+        let expected_files = files
+            .iter()
+            .filter(|file| file.id().namespace() == id)
+            .collect::<Vec<_>>();
+        let expected_bytes = expected_files
+            .iter()
+            .filter_map(|file| file.metadata().and_then(|metadata| metadata.size()))
+            .map(|size| size.value().inner())
+            .fold(0usize, |total, size| total.saturating_add(size));
+
+        let (status, body) = get(
+            NUMBER_OF_ENTITIES,
+            &format!(
+                "/namespace/{}/{}/summary",
+                id.organization().as_str(),
+                id.name().as_str()
+            ),
+        )
+        .await;
+        assert_eq!(status, StatusCode::OK);
+
+        assert_eq!(body["id"], serde_json::to_value(id).unwrap());
+        assert_eq!(body["counts"]["subjects"], expected_subjects);
+        assert_eq!(body["counts"]["samples"], expected_samples.len());
+        assert_eq!(body["counts"]["files"], expected_files.len());
+        assert_eq!(body["total_file_bytes"], expected_bytes);
+
+        // NOTE: every sample within the namespace is counted exactly once
+        // within the diagnoses and the studies (either as a value or as
+        // missing).
+        assert_eq!(body["diagnoses"]["total"], expected_samples.len());
+        assert_eq!(body["studies"]["total"], expected_samples.len());
+
+        // NOTE: only samples without metadata are counted as missing (samples
+        // with metadata but without a value are counted as `null`).
+        let with_metadata = expected_samples
+            .iter()
+            .filter(|sample| sample.metadata().is_some())
+            .count();
+        assert_eq!(
+            body["diagnoses"]["total"].as_u64().unwrap()
+                - body["diagnoses"]["missing"].as_u64().unwrap(),
+            with_metadata as u64
+        );
+
+        totals.0 += expected_subjects;
+        totals.1 += expected_samples.len();
+        totals.2 += expected_files.len();
+    }
+
+    // NOTE: every entity within the demo store belongs to one of the
+    // namespaces known by the server.
+    assert_eq!(totals, (subjects.len(), samples.len(), files.len()));
+}
+
+#[actix_web::test]
+async fn it_returns_zeroed_counts_for_an_empty_namespace() {
+    let (status, body) = get(
+        0,
+        "/namespace/example-organization/ExampleNamespaceOne/summary",
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+
+    assert_eq!(
+        body["counts"],
+        serde_json::json!({ "subjects": 0, "samples": 0, "files": 0 })
+    );
+    assert_eq!(body["total_file_bytes"], 0);
+    assert_eq!(
+        body["diagnoses"],
+        serde_json::json!({ "total": 0, "missing": 0, "values": [] })
+    );
+    assert_eq!(
+        body["studies"],
+        serde_json::json!({ "total": 0, "missing": 0, "values": [] })
+    );
+}
+
+#[actix_web::test]
+async fn it_returns_not_found_for_an_unknown_namespace() {
+    let (status, body) = get(
+        NUMBER_OF_ENTITIES,
+        "/namespace/example-organization/UnknownNamespace/summary",
+    )
+    .await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+
+    assert_eq!(body["errors"][0]["kind"], "NotFound");
+    assert_eq!(
+        body["errors"][0]["entity"],
+        "Namespace with organization 'example-organization' and name 'UnknownNamespace'"
+    );
+}
@@ -0,0 +1,79 @@
+//! Integration tests for the organization endpoints.
+//!
+//! Organizations are requested from an application serving the organizations
+//! supported by this server.
+
+use actix_web::http::StatusCode;
+use actix_web::test::call_service;
+use actix_web::test::init_service;
+use actix_web::test::read_body_json;
+use actix_web::test::TestRequest;
+use actix_web::App;
+use serde_json::Value;
+
+use ccdi_server as server;
+
+use server::routes::organization;
+
+/// Requests `uri` and returns the status and the body of the response.
+async fn get(uri: &str) -> (StatusCode, Value) {
+    let app = init_service(App::new().configure(organization::configure())).await;
+
+    let response = call_service(&app, TestRequest::get().uri(uri).to_request()).await;
+    let status = response.status();
+
+    (status, read_body_json(response).await)
+}
+
+/// Gets the identifiers of the organizations matching the provided
+/// `institution`.
+async fn matching(institution: &str) -> Vec<String> {
+    let uri = format!("/organization?institution={institution}");
+    let (status, body) = get(&uri).await;
+    assert_eq!(status, StatusCode::OK, "{uri}");
+
+    // NOTE: an empty page is returned as an empty array.
+    body.get("data")
+        .unwrap_or(&body)
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|organization| organization["identifier"].as_str().unwrap().to_string())
+        .collect()
+}
+
+#[actix_web::test]
+async fn it_looks_up_an_organization_by_identifier() {
+    let (status, body) = get("/organization/example-organization").await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["identifier"], "example-organization");
+
+    let (status, _) = get("/organization/missing-organization").await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+}
+
+#[actix_web::test]
+async fn it_filters_by_a_known_institution() {
+    assert_eq!(
+        matching("Children%27s%20Oncology%20Group").await,
+        vec!["example-organization"]
+    );
+    assert_eq!(
+        matching("National%20Cancer%20Institute").await,
+        vec!["example-organization"]
+    );
+}
+
+#[actix_web::test]
+async fn it_matches_nothing_for_an_unknown_institution() {
+    // NOTE: the institution common data element is free text (any name of an
+    // institution is permissible), so an unknown institution is not an error.
+    assert!(matching("Treehouse").await.is_empty());
+    assert!(matching("Children%27s%20Oncology").await.is_empty());
+}
+
+#[actix_web::test]
+async fn it_matches_institutions_case_sensitively() {
+    assert!(matching("children%27s%20oncology%20group").await.is_empty());
+    assert!(matching("NATIONAL%20CANCER%20INSTITUTE").await.is_empty());
+}
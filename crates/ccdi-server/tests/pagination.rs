@@ -0,0 +1,237 @@
+//! Integration tests for the bounds of the `per_page` parameter and the links
+//! to the other pages.
+//!
+//! Each paginated list endpoint is requested with `per_page` values around
+//! the maximum (and with zero) from an application serving a seeded demo
+//! store, optionally with a configured maximum. The links within the body of
+//! each page are checked against the links within its `link` header.
+
+use std::num::NonZeroUsize;
+
+use actix_web::http::StatusCode;
+use actix_web::test::call_service;
+use actix_web::test::init_service;
+use actix_web::test::read_body_json;
+use actix_web::test::TestRequest;
+use actix_web::web::Data;
+use actix_web::App;
+use rand::rngs::StdRng;
+use rand::SeedableRng as _;
+use serde_json::Value;
+
+use ccdi_server as server;
+
+use server::paginate;
+use server::paginate::DEFAULT_MAX_PER_PAGE;
+use server::routes::file;
+use server::routes::sample;
+use server::routes::subject;
+
+/// The number of each entity within the demo store.
+///
+/// This is more than the maximum number of entities per page so that a page
+/// with the maximum number of entities is always full.
+const NUMBER_OF_ENTITIES: usize = 150;
+
+/// The fraction of entities within the demo store that have no metadata.
+const METADATA_LESS_FRACTION: f64 = 0.5;
+
+/// The paginated list endpoints that are checked.
+const ENDPOINTS: [&str; 3] = ["/subject", "/sample", "/file"];
+
+/// Requests `uri` from an application serving a seeded demo store (with the
+/// maximum number of entities per page within `config`, if provided) and
+/// returns the status, the `link` header (if it exists), and the body of the
+/// response.
+async fn get_with_link(
+    uri: &str,
+    config: Option<paginate::Config>,
+) -> (StatusCode, Option<String>, Value) {
+    let mut rng = StdRng::seed_from_u64(0);
+
+    let subjects =
+        subject::Store::random_with_rng(NUMBER_OF_ENTITIES, METADATA_LESS_FRACTION, &mut rng);
+    let samples = sample::Store::random_with_rng(
+        NUMBER_OF_ENTITIES,
+        subjects.subjects.lock().unwrap(),
+        METADATA_LESS_FRACTION,
+        &mut rng,
+    );
+    let files = file::Store::random_with_rng(
+        NUMBER_OF_ENTITIES,
+        samples.samples.lock().unwrap(),
+        METADATA_LESS_FRACTION,
+        &mut rng,
+    );
+
+    let mut app = App::new();
+
+    if let Some(config) = config {
+        app = app.app_data(Data::new(config));
+    }
+
+    let app = init_service(
+        app.configure(subject::configure(Data::new(subjects)))
+            .configure(sample::configure(Data::new(samples)))
+            .configure(file::configure(Data::new(files))),
+    )
+    .await;
+
+    let response = call_service(&app, TestRequest::get().uri(uri).to_request()).await;
+    let status = response.status();
+    let link = response
+        .headers()
+        .get("link")
+        .map(|link| link.to_str().unwrap().to_string());
+
+    (status, link, read_body_json(response).await)
+}
+
+/// Requests `uri` in the same manner as [`get_with_link()`] and returns the
+/// status and the body of the response.
+async fn get(uri: &str, config: Option<paginate::Config>) -> (StatusCode, Value) {
+    let (status, _, body) = get_with_link(uri, config).await;
+    (status, body)
+}
+
+/// Parses the links within a `link` header into a JSON object keyed by their
+/// relationships (the shape of the `links` within the body).
+fn parse_links(header: &str) -> Value {
+    let links = header
+        .split(", ")
+        .map(|link| {
+            let (url, rel) = link.split_once("; ").unwrap();
+            let url = url.strip_prefix('<').unwrap().strip_suffix('>').unwrap();
+            let rel = rel
+                .strip_prefix("rel=\"")
+                .unwrap()
+                .strip_suffix('"')
+                .unwrap();
+
+            (rel.to_string(), Value::String(url.to_string()))
+        })
+        .collect::<serde_json::Map<_, _>>();
+
+    Value::Object(links)
+}
+
+#[actix_web::test]
+async fn it_serves_the_maximum_number_of_entities_per_page() {
+    for endpoint in ENDPOINTS {
+        let (status, body) =
+            get(&format!("{endpoint}?per_page={DEFAULT_MAX_PER_PAGE}"), None).await;
+
+        assert_eq!(status, StatusCode::OK, "{endpoint}");
+        assert_eq!(
+            body["data"].as_array().unwrap().len(),
+            DEFAULT_MAX_PER_PAGE,
+            "{endpoint}"
+        );
+    }
+}
+
+#[actix_web::test]
+async fn it_rejects_more_than_the_maximum_number_of_entities_per_page() {
+    for endpoint in ENDPOINTS {
+        let (status, body) = get(
+            &format!("{endpoint}?per_page={}", DEFAULT_MAX_PER_PAGE + 1),
+            None,
+        )
+        .await;
+
+        assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY, "{endpoint}");
+        assert_eq!(body["errors"][0]["kind"], "InvalidParameters");
+        assert_eq!(
+            body["errors"][0]["parameters"],
+            serde_json::json!(["per_page"])
+        );
+        assert_eq!(
+            body["errors"][0]["message"],
+            "Invalid value for parameter 'per_page': must not exceed the maximum of 100 \
+             entities per page (101 were requested).",
+            "{endpoint}"
+        );
+    }
+}
+
+#[actix_web::test]
+async fn it_rejects_zero_entities_per_page() {
+    for endpoint in ENDPOINTS {
+        let (status, body) = get(&format!("{endpoint}?per_page=0"), None).await;
+
+        assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY, "{endpoint}");
+        assert_eq!(
+            body["errors"][0]["message"],
+            "Invalid value for parameter 'per_page': must be at least one.",
+            "{endpoint}"
+        );
+    }
+}
+
+#[actix_web::test]
+async fn it_enforces_a_configured_maximum() {
+    let config = || paginate::Config::new(NonZeroUsize::new(120).unwrap());
+
+    for endpoint in ENDPOINTS {
+        let (status, body) = get(&format!("{endpoint}?per_page=120"), Some(config())).await;
+        assert_eq!(status, StatusCode::OK, "{endpoint}");
+        assert_eq!(body["data"].as_array().unwrap().len(), 120, "{endpoint}");
+
+        let (status, body) = get(&format!("{endpoint}?per_page=121"), Some(config())).await;
+        assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY, "{endpoint}");
+        assert_eq!(
+            body["errors"][0]["message"],
+            "Invalid value for parameter 'per_page': must not exceed the maximum of 120 \
+             entities per page (121 were requested).",
+            "{endpoint}"
+        );
+    }
+}
+
+#[actix_web::test]
+async fn it_reports_the_same_links_within_the_header_and_the_body() {
+    const PER_PAGE: usize = 10;
+
+    for endpoint in ENDPOINTS {
+        for filter in ["", "&has_metadata=true", "&has_metadata=false"] {
+            let uri = |page: usize| format!("{endpoint}?page={page}&per_page={PER_PAGE}{filter}");
+
+            let (_, _, body) = get_with_link(&uri(1), None).await;
+            let all = body["summary"]["counts"]["all"].as_u64().unwrap() as usize;
+            let pages = all.div_ceil(PER_PAGE);
+            assert!(pages > 2, "{endpoint} ({filter})");
+
+            // The first page, a page in the middle, and the last page.
+            for page in [1, 2, pages / 2, pages] {
+                let (status, link, body) = get_with_link(&uri(page), None).await;
+                let context = format!("{endpoint} ({filter}, page {page})");
+
+                assert_eq!(status, StatusCode::OK, "{context}");
+                assert_eq!(body["links"], parse_links(&link.unwrap()), "{context}");
+                assert_eq!(body["links"].get("prev").is_some(), page > 1, "{context}");
+                assert_eq!(
+                    body["links"].get("next").is_some(),
+                    page < pages,
+                    "{context}"
+                );
+            }
+        }
+    }
+}
+
+#[actix_web::test]
+async fn it_omits_the_links_within_the_body_for_a_single_page() {
+    let config = || paginate::Config::new(NonZeroUsize::new(NUMBER_OF_ENTITIES).unwrap());
+
+    for endpoint in ENDPOINTS {
+        let (status, link, body) = get_with_link(
+            &format!("{endpoint}?per_page={NUMBER_OF_ENTITIES}"),
+            Some(config()),
+        )
+        .await;
+
+        assert_eq!(status, StatusCode::OK, "{endpoint}");
+        assert!(link.is_some(), "{endpoint}");
+        assert!(body.get("links").is_none(), "{endpoint}");
+    }
+}
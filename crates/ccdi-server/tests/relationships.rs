@@ -0,0 +1,271 @@
+//! Integration tests for the relationship endpoints.
+//!
+//! The samples of a subject, the files of a subject, and the files of a sample
+//! are each requested from an application serving a seeded demo store and
+//! checked against the relationships computed directly from the store.
+
+use std::collections::BTreeSet;
+
+use actix_web::http::StatusCode;
+use actix_web::test::call_service;
+use actix_web::test::init_service;
+use actix_web::test::read_body_json;
+use actix_web::test::TestRequest;
+use actix_web::web::Data;
+use actix_web::App;
+use rand::rngs::StdRng;
+use rand::SeedableRng as _;
+use serde_json::Value;
+
+use ccdi_models as models;
+use ccdi_server as server;
+
+use models::File;
+use models::Sample;
+use models::Subject;
+use server::routes::file;
+use server::routes::sample;
+use server::routes::subject;
+
+/// The number of each entity within the demo store.
+const NUMBER_OF_ENTITIES: usize = 100;
+
+/// The fraction of entities within the demo store that have no metadata.
+const METADATA_LESS_FRACTION: f64 = 0.5;
+
+/// Creates the seeded demo store (the same seed always creates the same
+/// store).
+fn stores() -> (subject::Store, sample::Store, file::Store) {
+    let mut rng = StdRng::seed_from_u64(0);
+
+    let subjects =
+        subject::Store::random_with_rng(NUMBER_OF_ENTITIES, METADATA_LESS_FRACTION, &mut rng);
+    let samples = sample::Store::random_with_rng(
+        NUMBER_OF_ENTITIES,
+        subjects.subjects.lock().unwrap(),
+        METADATA_LESS_FRACTION,
+        &mut rng,
+    );
+    let files = file::Store::random_with_rng(
+        NUMBER_OF_ENTITIES,
+        samples.samples.lock().unwrap(),
+        METADATA_LESS_FRACTION,
+        &mut rng,
+    );
+
+    (subjects, samples, files)
+}
+
+/// Requests `uri` from an application serving the seeded demo store and
+/// returns the status and the body of the response.
+async fn get(uri: &str) -> (StatusCode, Value) {
+    let (subjects, samples, files) = stores();
+
+    let app = init_service(
+        App::new()
+            .configure(subject::configure(Data::new(subjects)))
+            .configure(sample::configure(Data::new(samples)))
+            .configure(file::configure(Data::new(files))),
+    )
+    .await;
+
+    let response = call_service(&app, TestRequest::get().uri(uri).to_request()).await;
+    let status = response.status();
+
+    (status, read_body_json(response).await)
+}
+
+/// Gets the path of the subject.
+fn subject_path(subject: &Subject) -> String {
+    format!(
+        "/subject/{}/{}/{}",
+        subject.id().namespace().organization().as_str(),
+        subject.id().namespace().name().as_str(),
+        subject.id().name().as_str()
+    )
+}
+
+/// Gets the path of the sample.
+fn sample_path(sample: &Sample) -> String {
+    format!(
+        "/sample/{}/{}/{}",
+        sample.id().namespace().organization().as_str(),
+        sample.id().namespace().name().as_str(),
+        sample.id().name()
+    )
+}
+
+/// Gets the samples of the subject (sorted by identifier).
+fn samples_of(subject: &Subject, samples: &[Sample]) -> Vec<Sample> {
+    let mut samples = samples
+        .iter()
+        .filter(|sample| sample.subject() == subject.id())
+        .cloned()
+        .collect::<Vec<_>>();
+
+    samples.sort();
+    samples
+}
+
+/// Gets the files derived from any of the samples (sorted by identifier).
+fn files_of(samples: &[Sample], files: &[File]) -> Vec<File> {
+    let samples = samples
+        .iter()
+        .map(|sample| sample.id())
+        .collect::<BTreeSet<_>>();
+
+    let mut files = files
+        .iter()
+        .filter(|file| file.samples().iter().any(|id| samples.contains(id)))
+        .cloned()
+        .collect::<Vec<_>>();
+
+    files.sort();
+    files
+}
+
+#[actix_web::test]
+async fn it_gets_the_samples_and_files_of_every_subject() {
+    let (subjects, samples, files) = stores();
+    let subjects = subjects.subjects.lock().unwrap().clone();
+    let samples = samples.samples.lock().unwrap().clone();
+    let files = files.files.lock().unwrap().clone();
+
+    for subject in subjects.iter() {
+        let path = subject_path(subject);
+        let expected_samples = samples_of(subject, &samples);
+        let expected_files = files_of(&expected_samples, &files);
+
+        let (status, body) = get(&format!("{path}/samples?per_page=100")).await;
+        assert_eq!(status, StatusCode::OK, "{path}");
+        assert_eq!(
+            body["data"],
+            serde_json::to_value(&expected_samples).unwrap(),
+            "{path}"
+        );
+        assert_eq!(body["summary"]["counts"]["all"], expected_samples.len());
+
+        let (status, body) = get(&format!("{path}/files?per_page=100")).await;
+        assert_eq!(status, StatusCode::OK, "{path}");
+        assert_eq!(
+            body["data"],
+            serde_json::to_value(&expected_files).unwrap(),
+            "{path}"
+        );
+        assert_eq!(body["summary"]["counts"]["all"], expected_files.len());
+    }
+}
+
+#[actix_web::test]
+async fn it_gets_the_files_of_every_sample() {
+    let (_, samples, files) = stores();
+    let samples = samples.samples.lock().unwrap().clone();
+    let files = files.files.lock().unwrap().clone();
+
+    for sample in samples.iter() {
+        let path = sample_path(sample);
+        let expected = files_of(std::slice::from_ref(sample), &files);
+
+        let (status, body) = get(&format!("{path}/files?per_page=100")).await;
+        assert_eq!(status, StatusCode::OK, "{path}");
+        assert_eq!(
+            body["data"],
+            serde_json::to_value(&expected).unwrap(),
+            "{path}"
+        );
+    }
+}
+
+#[actix_web::test]
+async fn it_returns_an_empty_page_for_a_subject_without_samples() {
+    let (subjects, samples, _) = stores();
+    let samples = samples.samples.lock().unwrap().clone();
+
+    // NOTE: samples are assigned to subjects at random, so the seeded store
+    // always contains subjects without any samples.
+    let subject = subjects
+        .subjects
+        .lock()
+        .unwrap()
+        .iter()
+        .find(|subject| samples_of(subject, &samples).is_empty())
+        .cloned()
+        .expect("a subject without samples");
+
+    for relationship in ["samples", "files"] {
+        let (status, body) = get(&format!("{}/{relationship}", subject_path(&subject))).await;
+        assert_eq!(status, StatusCode::OK, "{relationship}");
+        assert_eq!(body["data"], serde_json::json!([]), "{relationship}");
+        assert_eq!(body["summary"]["counts"]["all"], 0, "{relationship}");
+    }
+}
+
+#[actix_web::test]
+async fn it_paginates_the_samples_of_a_subject() {
+    let (subjects, samples, _) = stores();
+    let samples = samples.samples.lock().unwrap().clone();
+
+    let (subject, expected) = subjects
+        .subjects
+        .lock()
+        .unwrap()
+        .iter()
+        .map(|subject| (subject.clone(), samples_of(subject, &samples)))
+        .max_by_key(|(_, samples)| samples.len())
+        .unwrap();
+    assert!(expected.len() > 1);
+
+    let path = subject_path(&subject);
+
+    for (i, sample) in expected.iter().enumerate() {
+        let (status, body) = get(&format!("{path}/samples?page={}&per_page=1", i + 1)).await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body["data"], serde_json::json!([sample]));
+        assert_eq!(body["summary"]["counts"]["current"], 1);
+        assert_eq!(body["summary"]["counts"]["all"], expected.len());
+    }
+
+    let (status, _) = get(&format!(
+        "{path}/samples?page={}&per_page=1",
+        expected.len() + 1
+    ))
+    .await;
+    assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+}
+
+#[actix_web::test]
+async fn it_returns_not_found_for_an_unknown_subject_or_sample() {
+    for (path, entity) in [
+        (
+            "/subject/example-organization/ExampleNamespaceOne/Unknown/samples",
+            "Subject",
+        ),
+        (
+            "/subject/example-organization/ExampleNamespaceOne/Unknown/files",
+            "Subject",
+        ),
+        (
+            "/sample/example-organization/ExampleNamespaceOne/Unknown/files",
+            "Sample",
+        ),
+    ] {
+        let (status, body) = get(path).await;
+        assert_eq!(status, StatusCode::NOT_FOUND, "{path}");
+        assert_eq!(body["errors"][0]["kind"], "NotFound", "{path}");
+        assert_eq!(
+            body["errors"][0]["entity"],
+            format!("{entity} with namespace 'ExampleNamespaceOne' and name 'Unknown'"),
+            "{path}"
+        );
+    }
+}
+
+#[actix_web::test]
+async fn it_rejects_unknown_query_parameters() {
+    let (subjects, _, _) = stores();
+    let path = subject_path(&subjects.subjects.lock().unwrap()[0]);
+
+    let (status, body) = get(&format!("{path}/samples?sex=F")).await;
+    assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+    assert_eq!(body["errors"][0]["kind"], "InvalidParameters");
+}
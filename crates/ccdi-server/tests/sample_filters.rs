@@ -0,0 +1,242 @@
+//! Integration tests for filtering subjects by the properties of their
+//! samples.
+//!
+//! Subjects are requested with `sample.`-prefixed filters from an application
+//! serving a seeded demo store and checked against a join of the subjects and
+//! samples computed directly from the store.
+
+use std::collections::BTreeSet;
+
+use actix_web::http::StatusCode;
+use actix_web::test::call_service;
+use actix_web::test::init_service;
+use actix_web::test::read_body_json;
+use actix_web::test::TestRequest;
+use actix_web::web::Data;
+use actix_web::App;
+use rand::rngs::StdRng;
+use rand::SeedableRng as _;
+use serde_json::Value;
+
+use ccdi_models as models;
+use ccdi_server as server;
+
+use models::Sample;
+use models::Subject;
+use server::routes::file;
+use server::routes::sample;
+use server::routes::subject;
+
+/// The number of each entity within the demo store.
+const NUMBER_OF_ENTITIES: usize = 100;
+
+/// The fraction of entities within the demo store that have no metadata.
+const METADATA_LESS_FRACTION: f64 = 0.5;
+
+/// Creates the seeded demo store (the same seed always creates the same
+/// store).
+fn stores() -> (subject::Store, sample::Store, file::Store) {
+    let mut rng = StdRng::seed_from_u64(0);
+
+    let subjects =
+        subject::Store::random_with_rng(NUMBER_OF_ENTITIES, METADATA_LESS_FRACTION, &mut rng);
+    let samples = sample::Store::random_with_rng(
+        NUMBER_OF_ENTITIES,
+        subjects.subjects.lock().unwrap(),
+        METADATA_LESS_FRACTION,
+        &mut rng,
+    );
+    let files = file::Store::random_with_rng(
+        NUMBER_OF_ENTITIES,
+        samples.samples.lock().unwrap(),
+        METADATA_LESS_FRACTION,
+        &mut rng,
+    );
+
+    (subjects, samples, files)
+}
+
+/// Requests `uri` from an application serving the seeded demo store and
+/// returns the status and the body of the response.
+async fn get(uri: &str) -> (StatusCode, Value) {
+    let (subjects, samples, files) = stores();
+
+    let app = init_service(
+        App::new()
+            .configure(subject::configure(Data::new(subjects)))
+            .configure(sample::configure(Data::new(samples)))
+            .configure(file::configure(Data::new(files))),
+    )
+    .await;
+
+    let response = call_service(&app, TestRequest::get().uri(uri).to_request()).await;
+    let status = response.status();
+
+    (status, read_body_json(response).await)
+}
+
+/// Gets the identifiers of the subjects returned by the `/subject` endpoint
+/// for the raw `query`.
+async fn subjects(query: &str) -> BTreeSet<String> {
+    let uri = format!("/subject?{query}&per_page={NUMBER_OF_ENTITIES}");
+    let (status, body) = get(&uri).await;
+    assert_eq!(status, StatusCode::OK, "{uri}");
+
+    // NOTE: when no subjects match the filters, the body is an empty array.
+    match body {
+        Value::Array(_) => BTreeSet::new(),
+        body => body["data"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|subject| subject["id"].to_string())
+            .collect(),
+    }
+}
+
+/// Percent-encodes a value for use within a query string.
+fn encode(value: &str) -> String {
+    url::form_urlencoded::byte_serialize(value.as_bytes()).collect()
+}
+
+/// Gets the value of a harmonized metadata field of an entity (if it has one).
+fn value<T: serde::Serialize>(entity: &T, field: &str) -> Option<String> {
+    serde_json::to_value(entity).unwrap()["metadata"][field]["value"]
+        .as_str()
+        .map(String::from)
+}
+
+/// Manually joins the subjects matching `subject_predicate` with their samples
+/// and returns the identifiers of the subjects where at least one sample
+/// matches `sample_predicate`.
+fn join(
+    subject_predicate: impl Fn(&Subject) -> bool,
+    sample_predicate: impl Fn(&Sample) -> bool,
+) -> BTreeSet<String> {
+    let (subjects, samples, _) = stores();
+    let subjects = subjects.subjects.lock().unwrap();
+    let samples = samples.samples.lock().unwrap();
+
+    subjects
+        .iter()
+        .filter(|subject| subject_predicate(subject))
+        .filter(|subject| {
+            samples
+                .iter()
+                .any(|sample| sample.subject() == subject.id() && sample_predicate(sample))
+        })
+        .map(|subject| serde_json::to_value(subject.id()).unwrap().to_string())
+        .collect()
+}
+
+/// Gets the first sample within the demo store that has a value for each of
+/// the provided fields.
+fn sample_with(fields: &[&str]) -> Sample {
+    let (_, samples, _) = stores();
+    let samples = samples.samples.lock().unwrap();
+
+    samples
+        .iter()
+        .find(|sample| fields.iter().all(|field| value(*sample, field).is_some()))
+        .cloned()
+        .expect("a sample with a value for each field")
+}
+
+#[actix_web::test]
+async fn a_sample_filter_matches_a_manual_join() {
+    let strategy = value(&sample_with(&["library_strategy"]), "library_strategy").unwrap();
+
+    let expected = join(
+        |_| true,
+        |sample| value(sample, "library_strategy").as_ref() == Some(&strategy),
+    );
+
+    assert!(!expected.is_empty());
+    assert_eq!(
+        subjects(&format!("sample.library_strategy={}", encode(&strategy))).await,
+        expected
+    );
+}
+
+#[actix_web::test]
+async fn multiple_sample_filters_must_match_the_same_sample() {
+    let sample = sample_with(&["library_strategy", "disease_phase"]);
+    let strategy = value(&sample, "library_strategy").unwrap();
+    let phase = value(&sample, "disease_phase").unwrap();
+
+    let expected = join(
+        |_| true,
+        |sample| {
+            value(sample, "library_strategy").as_ref() == Some(&strategy)
+                && value(sample, "disease_phase").as_ref() == Some(&phase)
+        },
+    );
+
+    assert!(!expected.is_empty());
+    assert_eq!(
+        subjects(&format!(
+            "sample.library_strategy={}&sample.disease_phase={}",
+            encode(&strategy),
+            encode(&phase)
+        ))
+        .await,
+        expected
+    );
+}
+
+#[actix_web::test]
+async fn sample_filters_are_intersected_with_subject_filters() {
+    let expected = join(
+        |subject| value(subject, "sex").as_deref() == Some("F"),
+        |sample| value(sample, "disease_phase").is_some(),
+    );
+
+    let results = subjects("sex=F&sample.disease_phase=not:null").await;
+    assert_eq!(results, expected);
+
+    // NOTE: the intersection must be a subset of both of its operands.
+    assert!(results.is_subset(&subjects("sex=F").await));
+    assert!(results.is_subset(&subjects("sample.disease_phase=not:null").await));
+}
+
+#[actix_web::test]
+async fn samples_without_metadata_are_filterable() {
+    assert_eq!(
+        subjects("sample.has_metadata=false").await,
+        join(|_| true, |sample| !sample.has_metadata())
+    );
+}
+
+#[actix_web::test]
+async fn invalid_sample_filters_are_rejected() {
+    for (query, reason) in [
+        (
+            "sample.library_stratgy=RNA-Seq",
+            "Unrecognized parameter(s): `sample.library_stratgy` (did you mean \
+             `sample.library_strategy`?).",
+        ),
+        // NOTE: `sex` is a filter parameter of subjects but not of samples.
+        ("sample.sex=F", "Unrecognized parameter(s): `sample.sex`."),
+    ] {
+        let (status, body) = get(&format!("/subject?{query}")).await;
+
+        assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY, "{query}");
+        assert_eq!(body["errors"][0]["kind"], "InvalidParameters", "{query}");
+        assert!(
+            body["errors"][0]["reason"]
+                .as_str()
+                .unwrap()
+                .starts_with(reason),
+            "{query}: {}",
+            body["errors"][0]["reason"]
+        );
+    }
+
+    let (status, body) =
+        get("/subject?sample.age_at_collection=%7B%22gte%22%3A10%2C%22lte%22%3A0%7D").await;
+    assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+    assert_eq!(
+        body["errors"][0]["parameters"],
+        serde_json::json!(["sample.age_at_collection"])
+    );
+}
@@ -0,0 +1,204 @@
+//! Integration tests for the handling of unrecognized query parameters.
+//!
+//! The subject and sample endpoints are requested with misspelled filter and
+//! pagination parameters from an application serving a seeded demo store. In
+//! strict mode, the requests are rejected with an error that lists every
+//! unrecognized parameter. In lenient mode, the requests succeed (returning
+//! the same entities as if the parameters were never provided) and the
+//! unrecognized parameters are described within the `warnings` of the
+//! response. Every other endpoint that takes query parameters is checked in the
+//! same manner (though its response has no `warnings`).
+
+use actix_web::http::StatusCode;
+use actix_web::test::call_service;
+use actix_web::test::init_service;
+use actix_web::test::read_body_json;
+use actix_web::test::TestRequest;
+use actix_web::web::Data;
+use actix_web::App;
+use rand::rngs::StdRng;
+use rand::SeedableRng as _;
+use serde_json::Value;
+
+use ccdi_server as server;
+
+use server::routes::file;
+use server::routes::sample;
+use server::routes::search;
+use server::routes::subject;
+use server::strict;
+
+/// The number of each entity within the demo store.
+const NUMBER_OF_ENTITIES: usize = 20;
+
+/// Requests with unrecognized parameters, the unrecognized parameters, and the
+/// equivalent request without them.
+const REQUESTS: &[(&str, &[&str], &str)] = &[
+    ("/subject?sexx=F", &["sexx"], "/subject"),
+    (
+        "/subject?sex=F&pagesize=5&page=1",
+        &["pagesize"],
+        "/subject?sex=F&page=1",
+    ),
+    (
+        "/subject?sample.library_stratgy=RNA-Seq",
+        &["sample.library_stratgy"],
+        "/subject",
+    ),
+    (
+        "/sample?anatomic_sites=Chest&pre_page=5",
+        &["anatomic_sites", "pre_page"],
+        "/sample",
+    ),
+    (
+        "/sample?has_metadata=true&foo=bar",
+        &["foo"],
+        "/sample?has_metadata=true",
+    ),
+];
+
+/// Requests to endpoints whose responses have no `warnings` with an
+/// unrecognized parameter (`foo`).
+const UNENVELOPED: &[&str] = &[
+    "/subject/by/sex/count?foo=bar",
+    "/subject/values/sex?foo=bar",
+    "/sample/by/disease_phase/count?foo=bar",
+    "/sample/by/disease_phase/group?foo=bar",
+    "/sample/values/disease_phase?foo=bar",
+    "/file/by/type/count?foo=bar",
+    "/file/values/type?foo=bar",
+    "/search?q=sample&foo=bar",
+];
+
+/// Requests `uri` from an application serving a seeded demo store (with the
+/// provided [`strict::Config`], if any).
+async fn get(uri: &str, config: Option<strict::Config>) -> (StatusCode, Value) {
+    let mut rng = StdRng::seed_from_u64(0);
+
+    let subjects = subject::Store::random_with_rng(NUMBER_OF_ENTITIES, 0.0, &mut rng);
+    let samples = sample::Store::random_with_rng(
+        NUMBER_OF_ENTITIES,
+        subjects.subjects.lock().unwrap(),
+        0.0,
+        &mut rng,
+    );
+    let files = file::Store::random_with_rng(
+        NUMBER_OF_ENTITIES,
+        samples.samples.lock().unwrap(),
+        0.0,
+        &mut rng,
+    );
+
+    let mut app = App::new();
+
+    if let Some(config) = config {
+        app = app.app_data(Data::new(config));
+    }
+
+    let subjects = Data::new(subjects);
+    let samples = Data::new(samples);
+    let files = Data::new(files);
+
+    let app = init_service(
+        app.configure(subject::configure(subjects.clone()))
+            .configure(sample::configure(samples.clone()))
+            .configure(file::configure(files.clone()))
+            .configure(search::configure(subjects, samples, files)),
+    )
+    .await;
+
+    let response = call_service(&app, TestRequest::get().uri(uri).to_request()).await;
+    (response.status(), read_body_json(response).await)
+}
+
+#[actix_web::test]
+async fn strict_mode_rejects_unrecognized_parameters() {
+    for config in [None, Some(strict::Config::new(true))] {
+        for (uri, unrecognized, _) in REQUESTS {
+            let (status, body) = get(uri, config.clone()).await;
+            assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY, "{uri}");
+
+            let error = &body["errors"][0];
+            assert_eq!(error["kind"], "InvalidParameters", "{uri}");
+            assert_eq!(
+                error["parameters"],
+                serde_json::json!(unrecognized),
+                "{uri}"
+            );
+        }
+    }
+}
+
+#[actix_web::test]
+async fn lenient_mode_ignores_unrecognized_parameters_with_a_warning() {
+    for (uri, unrecognized, equivalent) in REQUESTS {
+        let (status, body) = get(uri, Some(strict::Config::new(false))).await;
+        assert_eq!(status, StatusCode::OK, "{uri}");
+
+        let warnings = body["warnings"].as_array().unwrap();
+        assert_eq!(warnings.len(), 1, "{uri}");
+
+        for parameter in *unrecognized {
+            assert!(
+                warnings[0].as_str().unwrap().contains(parameter),
+                "{uri}: {warnings:?}"
+            );
+        }
+
+        let (_, expected) = get(equivalent, Some(strict::Config::new(false))).await;
+        assert!(expected.get("warnings").is_none(), "{equivalent}");
+        assert_eq!(body["data"], expected["data"], "{uri}");
+    }
+}
+
+#[actix_web::test]
+async fn lenient_mode_warns_within_the_related_listings() {
+    let (_, subjects) = get("/subject", None).await;
+    let id = &subjects["data"][0]["id"];
+    let uri = format!(
+        "/subject/{}/{}/{}/samples?pagesize=1",
+        id["namespace"]["organization"].as_str().unwrap(),
+        id["namespace"]["name"].as_str().unwrap(),
+        id["name"].as_str().unwrap()
+    );
+
+    let (status, _) = get(&uri, None).await;
+    assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+
+    let (status, body) = get(&uri, Some(strict::Config::new(false))).await;
+    assert_eq!(status, StatusCode::OK);
+    assert!(body["warnings"][0].as_str().unwrap().contains("pagesize"));
+}
+
+#[actix_web::test]
+async fn every_endpoint_checks_its_query_parameters() {
+    let mut uris = UNENVELOPED
+        .iter()
+        .map(|uri| uri.to_string())
+        .collect::<Vec<_>>();
+
+    for entity in ["subject", "sample", "file"] {
+        let (_, body) = get(&format!("/{entity}"), None).await;
+        let id = &body["data"][0]["id"];
+
+        uris.push(format!(
+            "/{entity}/{}/{}/{}?foo=bar",
+            id["namespace"]["organization"].as_str().unwrap(),
+            id["namespace"]["name"].as_str().unwrap(),
+            id["name"].as_str().unwrap()
+        ));
+    }
+
+    for uri in uris {
+        let (status, body) = get(&uri, None).await;
+        assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY, "{uri}");
+        assert_eq!(
+            body["errors"][0]["parameters"],
+            serde_json::json!(["foo"]),
+            "{uri}"
+        );
+
+        let (status, _) = get(&uri, Some(strict::Config::new(false))).await;
+        assert_eq!(status, StatusCode::OK, "{uri}");
+    }
+}
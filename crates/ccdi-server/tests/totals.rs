@@ -0,0 +1,206 @@
+//! Integration tests for the total and filtered counts of the paginated list
+//! endpoints.
+//!
+//! Each list endpoint is requested under several combinations of filters from
+//! an application serving a seeded demo store, and the counts reported within
+//! the `x-total-count` and `x-filtered-count` headers (and within the summary
+//! of the body) are checked against the results that were actually returned.
+
+use actix_web::http::StatusCode;
+use actix_web::test::call_service;
+use actix_web::test::init_service;
+use actix_web::test::read_body_json;
+use actix_web::test::TestRequest;
+use actix_web::web::Data;
+use actix_web::App;
+use rand::rngs::StdRng;
+use rand::SeedableRng as _;
+use serde_json::Value;
+
+use ccdi_server as server;
+
+use server::paginate::FILTERED_COUNT_HEADER;
+use server::paginate::TOTAL_COUNT_HEADER;
+use server::routes::file;
+use server::routes::sample;
+use server::routes::subject;
+
+/// The number of each entity within the demo store.
+const NUMBER_OF_ENTITIES: usize = 100;
+
+/// The fraction of entities within the demo store that have no metadata.
+const METADATA_LESS_FRACTION: f64 = 0.5;
+
+/// The combinations of filters that are checked for each endpoint.
+const QUERIES: &[(&str, &[&str])] = &[
+    (
+        "/subject",
+        &[
+            "",
+            "sex=F",
+            "sex=not:F",
+            "sex=F&has_metadata=true",
+            "has_metadata=false",
+            "metadata.unharmonized.consortium_id=A",
+        ],
+    ),
+    (
+        "/sample",
+        &[
+            "",
+            "disease_phase=not:null",
+            "disease_phase=not:null&has_metadata=true",
+            "has_metadata=false",
+            "metadata.unharmonized.batch=1",
+        ],
+    ),
+    (
+        "/file",
+        &[
+            "",
+            "type=not:null",
+            "type=not:null&has_metadata=true",
+            "has_metadata=false",
+            "metadata.unharmonized.pipeline_version=1",
+        ],
+    ),
+];
+
+/// A response from a paginated list endpoint.
+struct Response {
+    /// The status of the response.
+    status: StatusCode,
+
+    /// The value of the `x-total-count` header (if it exists).
+    total: Option<usize>,
+
+    /// The value of the `x-filtered-count` header (if it exists).
+    filtered: Option<usize>,
+
+    /// The body of the response.
+    body: Value,
+}
+
+/// Requests `uri` from an application serving a seeded demo store.
+async fn get(uri: &str) -> Response {
+    let mut rng = StdRng::seed_from_u64(0);
+
+    let subjects =
+        subject::Store::random_with_rng(NUMBER_OF_ENTITIES, METADATA_LESS_FRACTION, &mut rng);
+    let samples = sample::Store::random_with_rng(
+        NUMBER_OF_ENTITIES,
+        subjects.subjects.lock().unwrap(),
+        METADATA_LESS_FRACTION,
+        &mut rng,
+    );
+    let files = file::Store::random_with_rng(
+        NUMBER_OF_ENTITIES,
+        samples.samples.lock().unwrap(),
+        METADATA_LESS_FRACTION,
+        &mut rng,
+    );
+
+    let app = init_service(
+        App::new()
+            .configure(subject::configure(Data::new(subjects)))
+            .configure(sample::configure(Data::new(samples)))
+            .configure(file::configure(Data::new(files))),
+    )
+    .await;
+
+    let response = call_service(&app, TestRequest::get().uri(uri).to_request()).await;
+
+    let header = |name: &str| {
+        response
+            .headers()
+            .get(name)
+            .map(|value| value.to_str().unwrap().parse::<usize>().unwrap())
+    };
+
+    Response {
+        status: response.status(),
+        total: header(TOTAL_COUNT_HEADER),
+        filtered: header(FILTERED_COUNT_HEADER),
+        body: read_body_json(response).await,
+    }
+}
+
+/// Gets the number of entities within the body of a response.
+///
+/// When no entities match the filters, the body is an empty array.
+fn returned(body: &Value) -> usize {
+    match body {
+        Value::Array(entities) => entities.len(),
+        body => body["data"].as_array().unwrap().len(),
+    }
+}
+
+#[actix_web::test]
+async fn the_counts_are_consistent_with_the_results() {
+    for (path, queries) in QUERIES {
+        for query in *queries {
+            let uri = format!("{path}?{query}&per_page={NUMBER_OF_ENTITIES}");
+            let response = get(&uri).await;
+            assert_eq!(response.status, StatusCode::OK, "{uri}");
+
+            let filtered = returned(&response.body);
+            assert_eq!(response.total, Some(NUMBER_OF_ENTITIES), "{uri}");
+            assert_eq!(response.filtered, Some(filtered), "{uri}");
+
+            if filtered > 0 {
+                let counts = &response.body["summary"]["counts"];
+                assert_eq!(counts["total"], NUMBER_OF_ENTITIES, "{uri}");
+                assert_eq!(counts["filtered"], filtered, "{uri}");
+                assert_eq!(counts["all"], filtered, "{uri}");
+            }
+        }
+    }
+}
+
+#[actix_web::test]
+async fn the_counts_are_the_same_on_every_page() {
+    for (path, queries) in QUERIES {
+        for query in *queries {
+            let first = get(&format!("{path}?{query}&per_page=10")).await;
+            let filtered = first.filtered.unwrap();
+            let pages = filtered.div_ceil(10);
+
+            let mut returned_across_pages = returned(&first.body);
+
+            for page in 2..=pages {
+                let uri = format!("{path}?{query}&page={page}&per_page=10");
+                let response = get(&uri).await;
+                assert_eq!(response.status, StatusCode::OK, "{uri}");
+                assert_eq!(response.total, first.total, "{uri}");
+                assert_eq!(response.filtered, first.filtered, "{uri}");
+                assert_eq!(
+                    response.body["summary"]["counts"]["filtered"], filtered,
+                    "{uri}"
+                );
+
+                returned_across_pages += returned(&response.body);
+            }
+
+            assert_eq!(returned_across_pages, filtered, "{path}?{query}");
+        }
+    }
+}
+
+#[actix_web::test]
+async fn a_filter_and_its_negation_partition_the_total() {
+    let matching = get(&format!("/subject?sex=F&per_page={NUMBER_OF_ENTITIES}")).await;
+    let negated = get(&format!("/subject?sex=not:F&per_page={NUMBER_OF_ENTITIES}")).await;
+
+    assert_eq!(
+        matching.filtered.unwrap() + negated.filtered.unwrap(),
+        NUMBER_OF_ENTITIES
+    );
+}
+
+#[actix_web::test]
+async fn the_counts_are_not_reported_for_errors() {
+    let response = get("/subject?page=1000&per_page=10").await;
+    assert_eq!(response.status, StatusCode::UNPROCESSABLE_ENTITY);
+    assert_eq!(response.total, None);
+    assert_eq!(response.filtered, None);
+}
@@ -0,0 +1,969 @@
+//! Checking that the responses of a server conform to the specification.
+//!
+//! By default, only the page at the provided URL is checked. When pagination
+//! is followed (see [`Config::with_follow_pagination()`]), the number of pages
+//! is discovered from the `last` relationship within the `link` header of the
+//! first response, and every page (up to an optional maximum) is then fetched
+//! and checked. Pages are fetched concurrently (up to a configurable limit),
+//! and requests that fail with a `429 Too Many Requests` or a `5xx` status are
+//! retried with backoff (respecting the `Retry-After` header when it is
+//! provided).
+//!
+//! Each page is checked in the following ways:
+//!
+//! * The body must parse as the expected response type. When it does not and
+//!   the body contains a `data` array, the index of the first record that
+//!   fails to parse is reported.
+//! * When pagination is followed, the `link` header (if any) must point to the
+//!   correct `first`, `prev`, `next`, and `last` pages.
+//! * When the body contains a `summary`, the number of entities it reports
+//!   for the page must match the length of the `data` array.
+//!
+//! Across pages, every page must report the same total number of entities
+//! and, when every page was checked, the number of entities on each page must
+//! sum to that total.
+//!
+//! Response bodies are dropped as soon as they are checked, so only the
+//! failures are retained while crawling. The failures are reported in page
+//! order regardless of the order in which the pages completed.
+
+use std::collections::BTreeMap;
+use std::num::NonZeroUsize;
+use std::time::Duration;
+
+use futures::stream;
+use futures::StreamExt as _;
+use log::info;
+use reqwest::header::HeaderMap;
+use reqwest::StatusCode;
+use serde_json::Value;
+use url::Url;
+
+use ccdi_client as client;
+
+use client::links::last_page;
+use client::links::links;
+use client::links::page_of;
+use client::links::with_page;
+use client::Client;
+
+use crate::parse_response;
+use crate::ResponseType;
+
+/// The default number of requests that may be in flight at once.
+pub const DEFAULT_CONCURRENCY: usize = 8;
+
+/// The default number of times that a request is retried.
+pub const DEFAULT_RETRIES: usize = client::client::DEFAULT_RETRIES;
+
+/// An error related to checking a server.
+#[derive(Debug)]
+pub enum Error {
+    /// The URL to check could not be parsed.
+    InvalidUrl(url::ParseError),
+
+    /// A request could not be completed.
+    Request(reqwest::Error),
+
+    /// A request failed with a status code that indicates a server error (and
+    /// all retries were exhausted).
+    Status(StatusCode),
+
+    /// A response did not match the specification.
+    Parse(String),
+
+    /// The `link` header of a response did not point to the expected pages.
+    Link(String),
+
+    /// The counts reported by a response (or across the pages of a
+    /// collection) were not self-consistent.
+    Count(String),
+}
+
+impl std::fmt::Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::InvalidUrl(err) => write!(f, "invalid url: {err}"),
+            Error::Request(err) => write!(f, "request error: {err}"),
+            Error::Status(status) => write!(f, "unsuccessful status: {status}"),
+            Error::Parse(err) => write!(f, "parse error: {err}"),
+            Error::Link(err) => write!(f, "link error: {err}"),
+            Error::Count(err) => write!(f, "count error: {err}"),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+impl From<client::Error> for Error {
+    fn from(err: client::Error) -> Self {
+        match err {
+            client::Error::InvalidUrl(err) => Error::InvalidUrl(err),
+            client::Error::Request(err) => Error::Request(err),
+            client::Error::Status(status) => Error::Status(status),
+            client::Error::Api { status, .. } => Error::Status(status),
+            client::Error::UnexpectedStatus { status, .. } => Error::Status(status),
+            client::Error::Decode(err) => Error::Parse(err.to_string()),
+        }
+    }
+}
+
+/// A failure found while checking a page (or a collection of pages).
+#[derive(Debug, Eq, PartialEq)]
+pub struct Failure {
+    /// Whether the page was fetched but did not match the specification
+    /// (rather than could not be fetched at all).
+    nonconforming: bool,
+
+    /// A description of the failure.
+    message: String,
+
+    /// The URL of the page (if the failure relates to a single page).
+    url: Option<String>,
+}
+
+impl Failure {
+    /// Returns whether the page was fetched but did not match the
+    /// specification.
+    pub fn is_nonconforming(&self) -> bool {
+        self.nonconforming
+    }
+
+    /// Sets the URL of the page to which the [`Failure`] relates.
+    fn at(mut self, url: &Url) -> Self {
+        self.url = Some(url.to_string());
+        self
+    }
+}
+
+impl From<Error> for Failure {
+    fn from(err: Error) -> Self {
+        Self {
+            nonconforming: matches!(err, Error::Parse(_) | Error::Link(_) | Error::Count(_)),
+            message: err.to_string(),
+            url: None,
+        }
+    }
+}
+
+impl std::fmt::Display for Failure {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.message)?;
+
+        if let Some(url) = &self.url {
+            write!(f, " ({url})")?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Configuration for checking a server.
+#[derive(Clone, Debug)]
+pub struct Config {
+    /// The maximum number of requests that may be in flight at once.
+    concurrency: NonZeroUsize,
+
+    /// The number of times a request is retried before giving up.
+    retries: usize,
+
+    /// The delay before the first retry of a request (doubled on each
+    /// subsequent retry).
+    base_backoff: Duration,
+
+    /// Whether every page of a paginated endpoint is checked (rather than only
+    /// the page at the provided URL).
+    follow_pagination: bool,
+
+    /// The maximum number of pages to check when following pagination.
+    max_pages: Option<NonZeroUsize>,
+}
+
+impl Config {
+    /// Creates a new [`Config`].
+    pub fn new(concurrency: NonZeroUsize, retries: usize) -> Self {
+        Self {
+            concurrency,
+            retries,
+            base_backoff: client::client::DEFAULT_BASE_BACKOFF,
+            follow_pagination: false,
+            max_pages: None,
+        }
+    }
+
+    /// Sets whether every page of a paginated endpoint is checked (rather than
+    /// only the page at the provided URL).
+    pub fn with_follow_pagination(mut self, follow_pagination: bool) -> Self {
+        self.follow_pagination = follow_pagination;
+        self
+    }
+
+    /// Sets the maximum number of pages to check when following pagination.
+    pub fn with_max_pages(mut self, max_pages: Option<NonZeroUsize>) -> Self {
+        self.max_pages = max_pages;
+        self
+    }
+
+    /// Gets the maximum number of requests that may be in flight at once.
+    pub fn concurrency(&self) -> NonZeroUsize {
+        self.concurrency
+    }
+
+    /// Creates a client of the server at `base_url` that retries requests
+    /// as configured.
+    pub(crate) fn client(
+        &self,
+        base_url: &Url,
+        timeout: Option<Duration>,
+    ) -> Result<Client, Error> {
+        let mut builder = client::Builder::new(base_url.clone())
+            .retries(self.retries)
+            .base_backoff(self.base_backoff);
+
+        if let Some(timeout) = timeout {
+            builder = builder.timeout(timeout);
+        }
+
+        Ok(builder.build()?)
+    }
+}
+
+/// A report of the pages that were checked.
+#[derive(Debug, Default, Eq, PartialEq)]
+pub struct Report {
+    /// The number of pages that were checked.
+    pages: usize,
+
+    /// The failures keyed by page number.
+    failures: BTreeMap<usize, Vec<Failure>>,
+
+    /// The failures that relate to the collection of pages as a whole.
+    collection: Vec<Failure>,
+}
+
+impl Report {
+    /// Gets the number of pages that were checked.
+    pub fn pages(&self) -> usize {
+        self.pages
+    }
+
+    /// Gets the failures keyed by page number.
+    pub fn failures(&self) -> &BTreeMap<usize, Vec<Failure>> {
+        &self.failures
+    }
+
+    /// Gets the failures that relate to the collection of pages as a whole.
+    pub fn collection(&self) -> &[Failure] {
+        &self.collection
+    }
+
+    /// Gets the total number of failures.
+    pub fn failure_count(&self) -> usize {
+        self.failures.values().map(Vec::len).sum::<usize>() + self.collection.len()
+    }
+
+    /// Records a failure for a page.
+    fn fail(&mut self, page: usize, failure: Failure) {
+        self.failures.entry(page).or_default().push(failure);
+    }
+}
+
+impl std::fmt::Display for Report {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "checked {} page(s): {} failure(s)",
+            self.pages,
+            self.failure_count()
+        )?;
+
+        for (page, failures) in &self.failures {
+            for failure in failures {
+                write!(f, "\n  - page {page}: {failure}")?;
+            }
+        }
+
+        for failure in &self.collection {
+            write!(f, "\n  - collection: {failure}")?;
+        }
+
+        Ok(())
+    }
+}
+
+/// The counts reported within the `summary` of a page.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+struct Counts {
+    /// The number of entities reported on the page.
+    current: u64,
+
+    /// The number of entities reported across all pages.
+    all: u64,
+}
+
+/// Checks a single page that was fetched and returns the counts it reported
+/// (if any).
+///
+/// The `link` header is only checked when `last` (the number of the last page
+/// reported by the first page) is provided.
+fn check_page(
+    report: &mut Report,
+    page: usize,
+    last: Option<usize>,
+    url: &Url,
+    headers: &HeaderMap,
+    body: &str,
+    response_type: ResponseType,
+) -> Option<Counts> {
+    if let Err(err) = parse_response(body, response_type.clone()) {
+        let message = match failing_record(body, response_type) {
+            Some(index) => format!("{err} (record {index})"),
+            None => err.to_string(),
+        };
+
+        report.fail(page, Failure::from(Error::Parse(message)).at(url));
+    }
+
+    if let Some(last) = last {
+        if let Err(err) = check_links(headers, page, last) {
+            report.fail(page, Failure::from(err).at(url));
+        }
+    }
+
+    let body = serde_json::from_str::<Value>(body).ok()?;
+    let counts = counts_of(&body)?;
+
+    if let Some(records) = body.get("data").and_then(Value::as_array) {
+        if counts.current != records.len() as u64 {
+            report.fail(
+                page,
+                Failure::from(Error::Count(format!(
+                    "the summary reports {} entities on the page, but the page contains {}",
+                    counts.current,
+                    records.len()
+                )))
+                .at(url),
+            );
+        }
+    }
+
+    Some(counts)
+}
+
+/// Gets the counts reported within the `summary` of a page (if any).
+fn counts_of(body: &Value) -> Option<Counts> {
+    let counts = body.get("summary")?.get("counts")?;
+
+    Some(Counts {
+        current: counts.get("current")?.as_u64()?,
+        all: counts.get("all")?.as_u64()?,
+    })
+}
+
+/// Finds the index of the first record within the `data` array of a page that
+/// fails to parse (when the page as a whole fails to parse).
+///
+/// Each record is checked by parsing a copy of the page that only contains
+/// that record.
+pub(crate) fn failing_record(body: &str, response_type: ResponseType) -> Option<usize> {
+    let body = serde_json::from_str::<Value>(body).ok()?;
+    let records = body.get("data")?.as_array()?;
+
+    records.iter().position(|record| {
+        let mut page = body.clone();
+        page["data"] = Value::Array(vec![record.clone()]);
+
+        parse_response(&page.to_string(), response_type.clone()).is_err()
+    })
+}
+
+/// Checks that the `link` header of a page points to the expected pages.
+///
+/// A page without a `link` header is not checked.
+pub(crate) fn check_links(headers: &HeaderMap, page: usize, last: usize) -> Result<(), Error> {
+    let links = match links(headers) {
+        Some(links) => links,
+        None => return Ok(()),
+    };
+
+    let expected = [
+        ("first", Some(1)),
+        ("prev", page.checked_sub(1).filter(|prev| *prev > 0)),
+        ("next", (page < last).then_some(page + 1)),
+        ("last", Some(last)),
+    ];
+
+    let problems = expected
+        .into_iter()
+        .filter_map(|(rel, expected)| {
+            let actual = links.get(rel).copied();
+
+            match (expected, actual) {
+                (Some(expected), Some(actual)) if expected == actual => None,
+                (None, None) => None,
+                (Some(expected), Some(actual)) => Some(format!(
+                    "expected `{rel}` to be page {expected}, but it is page {actual}"
+                )),
+                (Some(expected), None) => Some(format!(
+                    "expected `{rel}` to be page {expected}, but it is missing"
+                )),
+                (None, Some(actual)) => {
+                    Some(format!("expected no `{rel}` link, but it is page {actual}"))
+                }
+            }
+        })
+        .collect::<Vec<_>>();
+
+    match problems.is_empty() {
+        true => Ok(()),
+        false => Err(Error::Link(problems.join("; "))),
+    }
+}
+
+/// Checks that the counts reported by the pages of a collection are
+/// consistent with one another.
+///
+/// The counts on each page are only summed when every page of the collection
+/// was checked.
+fn check_collection(report: &mut Report, counts: &BTreeMap<usize, Counts>, complete: bool) {
+    let (first, reference) = match counts.iter().next() {
+        Some((page, counts)) => (*page, *counts),
+        None => return,
+    };
+
+    let mut consistent = true;
+
+    for (page, counts) in counts {
+        if counts.all != reference.all {
+            consistent = false;
+            report.collection.push(
+                Error::Count(format!(
+                    "page {page} reports {} entities in total, but page {first} reports {}",
+                    counts.all, reference.all
+                ))
+                .into(),
+            );
+        }
+    }
+
+    if !consistent || !complete {
+        return;
+    }
+
+    let sum = counts.values().map(|counts| counts.current).sum::<u64>();
+
+    if sum != reference.all {
+        report.collection.push(
+            Error::Count(format!(
+                "the pages contain {sum} entities, but the summary reports {} entities in total",
+                reference.all
+            ))
+            .into(),
+        );
+    }
+}
+
+/// Checks the page at the provided URL (and, if configured, every other page
+/// of the collection) against the provided response type.
+///
+/// An error is only returned if the first page cannot be fetched (as the
+/// number of pages cannot be determined). All other failures are collected
+/// within the returned [`Report`].
+pub async fn check(
+    url: &str,
+    response_type: ResponseType,
+    config: &Config,
+) -> Result<Report, Error> {
+    let url = url.parse::<Url>().map_err(Error::InvalidUrl)?;
+    let client = config.client(&url, None)?;
+
+    let first = page_of(&url).unwrap_or(1);
+    let (headers, body) = fetch(&client, &url).await?;
+
+    let mut report = Report {
+        pages: 1,
+        ..Default::default()
+    };
+
+    if !config.follow_pagination {
+        check_page(
+            &mut report,
+            first,
+            None,
+            &url,
+            &headers,
+            &body,
+            response_type,
+        );
+
+        return Ok(report);
+    }
+
+    let last = last_page(&headers).unwrap_or(first);
+
+    let mut counts = BTreeMap::new();
+    let mut complete = true;
+
+    if let Some(page_counts) = check_page(
+        &mut report,
+        first,
+        Some(last),
+        &url,
+        &headers,
+        &body,
+        response_type.clone(),
+    ) {
+        counts.insert(first, page_counts);
+    }
+
+    drop(body);
+
+    let mut remaining = (1..=last).filter(|page| *page != first).collect::<Vec<_>>();
+
+    if let Some(max_pages) = config.max_pages {
+        if remaining.len() >= max_pages.get() {
+            info!("only checking {max_pages} of {last} page(s)");
+            remaining.truncate(max_pages.get() - 1);
+            complete = false;
+        }
+    }
+
+    let total = remaining.len() + 1;
+
+    let mut results = stream::iter(remaining)
+        .map(|page| {
+            let client = &client;
+            let url = with_page(&url, page);
+
+            async move {
+                let result = fetch(client, &url).await;
+                (page, url, result)
+            }
+        })
+        .buffer_unordered(config.concurrency.get());
+
+    while let Some((page, url, result)) = results.next().await {
+        report.pages += 1;
+
+        match result {
+            Ok((headers, body)) => {
+                if let Some(page_counts) = check_page(
+                    &mut report,
+                    page,
+                    Some(last),
+                    &url,
+                    &headers,
+                    &body,
+                    response_type.clone(),
+                ) {
+                    counts.insert(page, page_counts);
+                }
+            }
+            Err(err) => {
+                complete = false;
+                report.fail(page, Failure::from(err).at(&url));
+            }
+        }
+
+        info!(
+            "{}/{} pages fetched, {} failure(s) so far",
+            report.pages,
+            total,
+            report.failure_count()
+        );
+    }
+
+    check_collection(&mut report, &counts, complete);
+
+    Ok(report)
+}
+
+/// Fetches a URL, retrying (with backoff) when the request fails with a
+/// transient error.
+///
+/// Only `429 Too Many Requests` and `5xx` statuses are considered transient:
+/// responses with any other status are returned so that their bodies can be
+/// checked (e.g., when checking an error response).
+pub(crate) async fn fetch(client: &Client, url: &Url) -> Result<(HeaderMap, String), Error> {
+    fetch_with_status(client, url)
+        .await
+        .map(|(_, headers, body)| (headers, body))
+}
+
+/// Fetches a URL in the same manner as [`fetch()`], but also returns the
+/// status of the response.
+pub(crate) async fn fetch_with_status(
+    client: &Client,
+    url: &Url,
+) -> Result<(StatusCode, HeaderMap, String), Error> {
+    Ok(client.get(url).await?.into_parts())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+    use std::sync::atomic::AtomicUsize;
+    use std::sync::atomic::Ordering;
+    use std::sync::Arc;
+    use std::sync::Mutex;
+
+    use actix_web::dev::Service as _;
+    use actix_web::error::ErrorInternalServerError;
+    use actix_web::rt;
+    use actix_web::web::Data;
+    use actix_web::App;
+    use actix_web::HttpServer;
+    use reqwest::header::LINK;
+
+    use ccdi_models as models;
+    use ccdi_server::routes::subject;
+
+    use super::*;
+
+    /// The state of the in-process test server.
+    #[derive(Debug, Default)]
+    struct State {
+        /// The number of requests currently in flight.
+        in_flight: AtomicUsize,
+
+        /// The maximum number of requests observed in flight at once.
+        max_in_flight: AtomicUsize,
+
+        /// The number of failures injected by the server.
+        injected: AtomicUsize,
+
+        /// The pages that have been requested at least once.
+        seen: Mutex<HashSet<String>>,
+    }
+
+    /// Starts an in-process server with 50 subjects that fails the first
+    /// request for every page matched by `flaky` (and every request for pages
+    /// matched by `broken`).
+    async fn serve(
+        state: Arc<State>,
+        flaky: fn(&str) -> bool,
+        broken: fn(&str) -> bool,
+    ) -> (String, actix_web::dev::ServerHandle) {
+        let subjects = Data::new(subject::Store::random(50));
+
+        let server = HttpServer::new(move || {
+            let state = state.clone();
+
+            App::new()
+                .wrap_fn(move |req, srv| {
+                    let state = state.clone();
+
+                    let page = req
+                        .query_string()
+                        .split('&')
+                        .find_map(|pair| pair.strip_prefix("page="))
+                        .unwrap_or("1")
+                        .to_string();
+
+                    let first_request = state.seen.lock().unwrap().insert(page.clone());
+                    let fail = broken(&page) || (first_request && flaky(&page));
+                    let response = (!fail).then(|| srv.call(req));
+
+                    async move {
+                        let current = state.in_flight.fetch_add(1, Ordering::SeqCst) + 1;
+                        state.max_in_flight.fetch_max(current, Ordering::SeqCst);
+
+                        // Hold the request open so that concurrent requests
+                        // overlap.
+                        rt::time::sleep(Duration::from_millis(25)).await;
+
+                        let result = match response {
+                            Some(response) => response.await,
+                            None => {
+                                state.injected.fetch_add(1, Ordering::SeqCst);
+                                Err(ErrorInternalServerError("injected failure"))
+                            }
+                        };
+
+                        state.in_flight.fetch_sub(1, Ordering::SeqCst);
+                        result
+                    }
+                })
+                .configure(subject::configure(subjects.clone()))
+        })
+        .workers(1)
+        .bind(("127.0.0.1", 0))
+        .unwrap();
+
+        let address = server.addrs()[0];
+        let server = server.run();
+        let handle = server.handle();
+        rt::spawn(server);
+
+        (format!("http://{address}/subject?per_page=5"), handle)
+    }
+
+    fn config(concurrency: usize, retries: usize) -> Config {
+        Config {
+            base_backoff: Duration::from_millis(1),
+            ..Config::new(NonZeroUsize::new(concurrency).unwrap(), retries)
+        }
+        .with_follow_pagination(true)
+    }
+
+    fn headers(link: &str) -> HeaderMap {
+        let mut headers = HeaderMap::new();
+        headers.insert(LINK, link.parse().unwrap());
+        headers
+    }
+
+    /// Creates the body of a page of subjects, where `records` is the JSON of
+    /// each subject.
+    fn subjects_body(current: usize, all: usize, records: &[&str]) -> String {
+        format!(
+            r#"{{"summary":{{"counts":{{"current":{current},"all":{all}}}}},"data":[{}]}}"#,
+            records.join(",")
+        )
+    }
+
+    /// The JSON of a valid subject with the provided name.
+    fn subject_json(name: &str) -> String {
+        let namespace = models::namespace::Identifier::new(
+            "example-organization"
+                .parse::<models::organization::Identifier>()
+                .unwrap(),
+            models::namespace::identifier::Name::try_new("ExampleNamespace").unwrap(),
+        );
+
+        serde_json::to_string(&models::Subject::new(
+            models::subject::Identifier::new(namespace, name),
+            models::subject::Kind::Participant,
+            None,
+            None,
+        ))
+        .unwrap()
+    }
+
+    #[actix_web::test]
+    async fn it_retries_intermittent_failures() {
+        let state = Arc::new(State::default());
+        let (url, handle) = serve(
+            state.clone(),
+            |page| page.parse::<usize>().unwrap() % 2 == 0,
+            |_| false,
+        )
+        .await;
+
+        let report = check(&url, ResponseType::Subjects, &config(3, 2))
+            .await
+            .unwrap();
+
+        handle.stop(true).await;
+
+        assert_eq!(report.pages, 10);
+        assert!(report.failures().is_empty(), "{report}");
+        assert_eq!(state.injected.load(Ordering::SeqCst), 5);
+    }
+
+    #[actix_web::test]
+    async fn it_respects_the_concurrency_limit() {
+        let state = Arc::new(State::default());
+        let (url, handle) = serve(state.clone(), |_| false, |_| false).await;
+
+        let report = check(&url, ResponseType::Subjects, &config(3, 0))
+            .await
+            .unwrap();
+
+        handle.stop(true).await;
+
+        assert_eq!(report.pages, 10);
+
+        let max_in_flight = state.max_in_flight.load(Ordering::SeqCst);
+        assert!(
+            max_in_flight <= 3,
+            "{max_in_flight} requests were in flight"
+        );
+        assert!(max_in_flight > 1, "requests were not made concurrently");
+    }
+
+    #[actix_web::test]
+    async fn it_reports_failures_in_page_order() {
+        let state = Arc::new(State::default());
+        let (url, handle) = serve(
+            state.clone(),
+            |_| false,
+            |page| matches!(page, "3" | "7" | "9"),
+        )
+        .await;
+
+        let report = check(&url, ResponseType::Subjects, &config(8, 1))
+            .await
+            .unwrap();
+
+        handle.stop(true).await;
+
+        assert_eq!(report.pages, 10);
+        assert_eq!(
+            report.failures().keys().copied().collect::<Vec<_>>(),
+            vec![3, 7, 9]
+        );
+        assert!(report
+            .to_string()
+            .starts_with("checked 10 page(s): 3 failure(s)\n  - page 3: unsuccessful status"));
+    }
+
+    #[actix_web::test]
+    async fn it_only_checks_the_provided_page_by_default() {
+        let state = Arc::new(State::default());
+        let (url, handle) = serve(state.clone(), |_| false, |_| false).await;
+
+        let config = Config::new(NonZeroUsize::new(3).unwrap(), 0);
+        let report = check(&url, ResponseType::Subjects, &config).await.unwrap();
+
+        handle.stop(true).await;
+
+        assert_eq!(report.pages, 1);
+        assert_eq!(report.failure_count(), 0, "{report}");
+        assert_eq!(state.seen.lock().unwrap().len(), 1);
+    }
+
+    #[actix_web::test]
+    async fn it_respects_the_maximum_number_of_pages() {
+        let state = Arc::new(State::default());
+        let (url, handle) = serve(state.clone(), |_| false, |_| false).await;
+
+        let config = config(3, 0).with_max_pages(NonZeroUsize::new(4));
+        let report = check(&url, ResponseType::Subjects, &config).await.unwrap();
+
+        handle.stop(true).await;
+
+        assert_eq!(report.pages, 4);
+        assert_eq!(report.failure_count(), 0, "{report}");
+        assert_eq!(state.seen.lock().unwrap().len(), 4);
+    }
+
+    #[test]
+    fn it_reports_the_record_that_failed_to_parse() {
+        let valid = subject_json("Subject1");
+        let body = subjects_body(3, 3, &[&valid, &valid, r#"{"id":null}"#]);
+        assert_eq!(failing_record(&body, ResponseType::Subjects), Some(2));
+
+        let body = subjects_body(1, 1, &[&valid]);
+        assert_eq!(failing_record(&body, ResponseType::Subjects), None);
+
+        // Pages without a `data` array cannot be narrowed down to a record.
+        assert_eq!(failing_record("[]", ResponseType::Subjects), None);
+    }
+
+    #[test]
+    fn it_checks_each_page() {
+        let url = "http://localhost/subject?page=2".parse::<Url>().unwrap();
+        let valid = subject_json("Subject1");
+
+        let mut report = Report::default();
+        let counts = check_page(
+            &mut report,
+            2,
+            Some(3),
+            &url,
+            &headers(
+                "<http://localhost/subject?page=1>; rel=\"first\", \
+                 <http://localhost/subject?page=1>; rel=\"prev\", \
+                 <http://localhost/subject?page=3>; rel=\"next\", \
+                 <http://localhost/subject?page=3>; rel=\"last\"",
+            ),
+            &subjects_body(1, 3, &[&valid]),
+            ResponseType::Subjects,
+        );
+
+        assert_eq!(counts, Some(Counts { current: 1, all: 3 }));
+        assert_eq!(report.failure_count(), 0, "{report}");
+
+        let mut report = Report::default();
+        let counts = check_page(
+            &mut report,
+            2,
+            Some(3),
+            &url,
+            &headers(
+                "<http://localhost/subject?page=1>; rel=\"first\", \
+                 <http://localhost/subject?page=4>; rel=\"last\"",
+            ),
+            &subjects_body(2, 3, &[&valid, r#"{"id":null}"#]),
+            ResponseType::Subjects,
+        );
+
+        assert_eq!(counts, Some(Counts { current: 2, all: 3 }));
+
+        let failures = report.failures().get(&2).unwrap();
+        assert_eq!(failures.len(), 2);
+        assert!(failures.iter().all(Failure::is_nonconforming));
+        assert!(failures[0].to_string().starts_with("parse error: "));
+        assert!(failures[0]
+            .to_string()
+            .ends_with("(record 1) (http://localhost/subject?page=2)"));
+        assert_eq!(
+            failures[1].to_string(),
+            "link error: expected `prev` to be page 1, but it is missing; expected `next` to \
+             be page 3, but it is missing; expected `last` to be page 3, but it is page 4 \
+             (http://localhost/subject?page=2)"
+        );
+
+        let mut report = Report::default();
+        check_page(
+            &mut report,
+            1,
+            None,
+            &url,
+            &HeaderMap::new(),
+            &subjects_body(2, 3, &[&valid]),
+            ResponseType::Subjects,
+        );
+
+        assert_eq!(
+            report.failures().get(&1).unwrap()[0].to_string(),
+            "count error: the summary reports 2 entities on the page, but the page contains 1 \
+             (http://localhost/subject?page=2)"
+        );
+    }
+
+    #[test]
+    fn it_checks_the_counts_across_pages() {
+        let counts = |pages: &[(u64, u64)]| {
+            pages
+                .iter()
+                .enumerate()
+                .map(|(i, (current, all))| {
+                    (
+                        i + 1,
+                        Counts {
+                            current: *current,
+                            all: *all,
+                        },
+                    )
+                })
+                .collect::<BTreeMap<_, _>>()
+        };
+
+        let mut report = Report::default();
+        check_collection(&mut report, &counts(&[(2, 5), (2, 5), (1, 5)]), true);
+        assert!(report.collection().is_empty());
+
+        let mut report = Report::default();
+        check_collection(&mut report, &counts(&[(2, 5), (2, 5), (2, 5)]), true);
+        assert_eq!(
+            report.collection()[0].to_string(),
+            "count error: the pages contain 6 entities, but the summary reports 5 entities in \
+             total"
+        );
+
+        // The sum is not checked when pages are missing.
+        let mut report = Report::default();
+        check_collection(&mut report, &counts(&[(2, 5), (2, 5)]), false);
+        assert!(report.collection().is_empty());
+
+        let mut report = Report::default();
+        check_collection(&mut report, &counts(&[(2, 5), (2, 6), (1, 5)]), true);
+        assert_eq!(report.collection().len(), 1);
+        assert_eq!(
+            report.to_string(),
+            "checked 0 page(s): 1 failure(s)\n  - collection: count error: page 2 reports 6 \
+             entities in total, but page 1 reports 5"
+        );
+    }
+}
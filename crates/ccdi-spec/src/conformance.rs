@@ -0,0 +1,798 @@
+//! Checking that a server conforms to the specification across every endpoint.
+//!
+//! Whereas the `check` subcommand checks a single URL against a single response
+//! type, a conformance run exercises a fixed matrix of checks (see
+//! [`matrix()`]) against the base URL of a server:
+//!
+//! * Every listing endpoint (`/subject`, `/sample`, and `/file`), every
+//!   `/{entity}/by/{field}/count` endpoint, every `/metadata/fields/*`
+//!   endpoint, `/info`, `/namespace`, and `/organization` must respond with a
+//!   `200 OK` that parses as the expected response type.
+//! * The `link` header of each listing endpoint must point to the expected
+//!   `first`, `prev`, `next`, and `last` pages.
+//! * An unknown route must respond with a `404 Not Found` containing an
+//!   `InvalidRoute` error, and an invalid filter must respond with a `422
+//!   Unprocessable Entity` containing an `InvalidParameters` error.
+//!
+//! Each check is either [required](Requirement::Required) or
+//! [recommended](Requirement::Recommended): a server conforms when every
+//! required check passes. Requests are made concurrently, retried in the same
+//! manner as the `check` subcommand (see [`check::Config`]), and abandoned
+//! after a timeout. The results are reported in the order of the matrix
+//! regardless of the order in which the requests completed.
+
+use std::time::Duration;
+
+use futures::stream;
+use futures::StreamExt as _;
+use reqwest::header::HeaderMap;
+use reqwest::StatusCode;
+use serde::Serialize;
+use serde_json::Value;
+use url::Url;
+
+use ccdi_client as client;
+use ccdi_server as server;
+
+use client::links;
+use server::routes::file;
+use server::routes::sample;
+use server::routes::subject;
+
+use crate::check;
+use crate::parse_response;
+use crate::ResponseType;
+
+/// The default number of seconds after which a request is abandoned.
+pub const DEFAULT_TIMEOUT_SECS: u64 = 30;
+
+/// A route that no server implements.
+const UNKNOWN_ROUTE: &str = "ccdi-spec-conformance/unknown-route";
+
+/// An error related to a single check.
+#[derive(Debug)]
+pub enum Error {
+    /// The request could not be completed.
+    Request(check::Error),
+
+    /// The response had an unexpected status.
+    Status {
+        /// The expected status.
+        expected: StatusCode,
+
+        /// The actual status.
+        actual: StatusCode,
+    },
+
+    /// The response did not parse as the expected response type.
+    Parse(String),
+
+    /// The `link` header of the response was missing or did not point to the
+    /// expected pages.
+    Link(String),
+
+    /// The error response did not contain an error of the expected kind.
+    Kind {
+        /// The expected kind of error.
+        expected: &'static str,
+
+        /// The kinds of the errors within the response.
+        actual: Vec<String>,
+    },
+}
+
+impl Error {
+    /// Returns whether the response was received but did not match the
+    /// specification (rather than the request could not be completed at all).
+    pub fn is_nonconforming(&self) -> bool {
+        !matches!(self, Error::Request(_))
+    }
+}
+
+impl std::fmt::Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::Request(err) => write!(f, "{err}"),
+            Error::Status { expected, actual } => {
+                write!(f, "expected status {expected}, but received {actual}")
+            }
+            Error::Parse(err) => write!(f, "parse error: {err}"),
+            Error::Link(err) => write!(f, "link error: {err}"),
+            Error::Kind { expected, actual } => write!(
+                f,
+                "expected an error of kind `{expected}`, but received [{}]",
+                actual
+                    .iter()
+                    .map(|kind| format!("`{kind}`"))
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            ),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+/// Whether a check must pass for a server to conform.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Requirement {
+    /// The check must pass.
+    Required,
+
+    /// The check should pass, but a failure does not prevent conformance.
+    Recommended,
+}
+
+impl std::fmt::Display for Requirement {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Requirement::Required => write!(f, "required"),
+            Requirement::Recommended => write!(f, "recommended"),
+        }
+    }
+}
+
+/// What is expected of the response to a check.
+#[derive(Clone, Debug)]
+pub enum Expectation {
+    /// A `200 OK` that parses as the response type.
+    Response(ResponseType),
+
+    /// A `200 OK` that parses as the response type and whose `link` header
+    /// points to the expected pages.
+    Paginated(ResponseType),
+
+    /// An error response with the status that contains an error of the kind.
+    Error(StatusCode, &'static str),
+}
+
+/// A single check within the conformance matrix.
+#[derive(Clone, Debug)]
+pub struct Check {
+    /// The name of the check.
+    name: String,
+
+    /// The path (and query) relative to the base URL of the server.
+    path: String,
+
+    /// What is expected of the response.
+    expectation: Expectation,
+
+    /// Whether the check must pass.
+    requirement: Requirement,
+}
+
+impl Check {
+    /// Creates a new, [required](Requirement::Required) [`Check`] of the path
+    /// (and query) relative to the base URL of a server.
+    pub fn new(name: impl Into<String>, path: impl Into<String>, expectation: Expectation) -> Self {
+        Self {
+            name: name.into(),
+            path: path.into(),
+            expectation,
+            requirement: Requirement::Required,
+        }
+    }
+
+    /// Marks the [`Check`] as [recommended](Requirement::Recommended).
+    pub fn recommended(mut self) -> Self {
+        self.requirement = Requirement::Recommended;
+        self
+    }
+
+    /// Gets the URL of the [`Check`] relative to the base URL of a server.
+    fn url(&self, base: &Url) -> Result<Url, check::Error> {
+        let (path, query) = match self.path.split_once('?') {
+            Some((path, query)) => (path, Some(query)),
+            None => (self.path.as_str(), None),
+        };
+
+        let mut url = base.clone();
+
+        url.path_segments_mut()
+            .map_err(|_| {
+                check::Error::InvalidUrl(url::ParseError::RelativeUrlWithCannotBeABaseBase)
+            })?
+            .pop_if_empty()
+            .extend(path.split('/'));
+        url.set_query(query);
+
+        Ok(url)
+    }
+
+    /// Evaluates a response against the expectation of the [`Check`].
+    fn evaluate(&self, status: StatusCode, headers: &HeaderMap, body: &str) -> Result<(), Error> {
+        let (expected, response_type) = match &self.expectation {
+            Expectation::Response(response_type) | Expectation::Paginated(response_type) => {
+                (StatusCode::OK, response_type.clone())
+            }
+            Expectation::Error(status, _) => (*status, ResponseType::Errors),
+        };
+
+        if status != expected {
+            return Err(Error::Status {
+                expected,
+                actual: status,
+            });
+        }
+
+        if let Err(err) = parse_response(body, response_type.clone()) {
+            return Err(Error::Parse(
+                match check::failing_record(body, response_type) {
+                    Some(index) => format!("{err} (record {index})"),
+                    None => err.to_string(),
+                },
+            ));
+        }
+
+        match &self.expectation {
+            Expectation::Response(_) => Ok(()),
+            Expectation::Paginated(_) => check_pagination(headers),
+            Expectation::Error(_, kind) => check_kind(body, kind),
+        }
+    }
+}
+
+/// Checks that the `link` header of the first page of a paginated response
+/// exists and points to the expected pages.
+fn check_pagination(headers: &HeaderMap) -> Result<(), Error> {
+    let last = match links::links(headers) {
+        Some(links) if links.contains_key("first") => match links.get("last") {
+            Some(last) => *last,
+            None => return Err(Error::Link(String::from("the `last` link is missing"))),
+        },
+        Some(_) => return Err(Error::Link(String::from("the `first` link is missing"))),
+        None => return Err(Error::Link(String::from("the `link` header is missing"))),
+    };
+
+    check::check_links(headers, 1, last).map_err(|err| match err {
+        check::Error::Link(err) => Error::Link(err),
+        err => Error::Link(err.to_string()),
+    })
+}
+
+/// Checks that an error response contains an error of the provided `kind`.
+fn check_kind(body: &str, kind: &'static str) -> Result<(), Error> {
+    // SAFETY: the body has already been parsed as [`ResponseType::Errors`].
+    let body = serde_json::from_str::<Value>(body).unwrap();
+
+    let actual = body["errors"]
+        .as_array()
+        .into_iter()
+        .flatten()
+        .filter_map(|error| error["kind"].as_str())
+        .map(String::from)
+        .collect::<Vec<_>>();
+
+    match actual.iter().any(|actual| actual == kind) {
+        true => Ok(()),
+        false => Err(Error::Kind {
+            expected: kind,
+            actual,
+        }),
+    }
+}
+
+/// Gets the checks within the conformance matrix (in the order in which they
+/// are reported).
+pub fn matrix() -> Vec<Check> {
+    let mut checks = vec![
+        Check::new(
+            "subjects",
+            "subject",
+            Expectation::Response(ResponseType::Subjects),
+        ),
+        Check::new(
+            "subjects (pagination)",
+            "subject?per_page=1",
+            Expectation::Paginated(ResponseType::Subjects),
+        ),
+        Check::new(
+            "samples",
+            "sample",
+            Expectation::Response(ResponseType::Samples),
+        ),
+        Check::new(
+            "samples (pagination)",
+            "sample?per_page=1",
+            Expectation::Paginated(ResponseType::Samples),
+        ),
+        Check::new("files", "file", Expectation::Response(ResponseType::Files)),
+        Check::new(
+            "files (pagination)",
+            "file?per_page=1",
+            Expectation::Paginated(ResponseType::Files),
+        ),
+    ];
+
+    for (entity, fields, response_type) in [
+        (
+            "subject",
+            subject::GROUPABLE_FIELDS,
+            ResponseType::SubjectsByCount,
+        ),
+        (
+            "sample",
+            sample::GROUPABLE_FIELDS,
+            ResponseType::SamplesByCount,
+        ),
+        ("file", file::GROUPABLE_FIELDS, ResponseType::FilesByCount),
+    ] {
+        checks.extend(fields.iter().map(|field| {
+            Check::new(
+                format!("{entity}s by {field} count"),
+                format!("{entity}/by/{field}/count"),
+                Expectation::Response(response_type.clone()),
+            )
+        }));
+    }
+
+    checks.extend(
+        ["subject", "sample", "file", "namespace", "organization"]
+            .into_iter()
+            .map(|entity| {
+                Check::new(
+                    format!("{entity} metadata fields"),
+                    format!("metadata/fields/{entity}"),
+                    Expectation::Response(ResponseType::FieldDescriptions),
+                )
+            }),
+    );
+
+    checks.extend([
+        Check::new(
+            "information",
+            "info",
+            Expectation::Response(ResponseType::Information),
+        ),
+        Check::new(
+            "versions",
+            "info/versions",
+            Expectation::Response(ResponseType::Versions),
+        )
+        .recommended(),
+        Check::new(
+            "namespaces",
+            "namespace",
+            Expectation::Response(ResponseType::Namespaces),
+        ),
+        Check::new(
+            "organizations",
+            "organization",
+            Expectation::Response(ResponseType::Organizations),
+        ),
+        Check::new(
+            "unknown route",
+            UNKNOWN_ROUTE,
+            Expectation::Error(StatusCode::NOT_FOUND, "InvalidRoute"),
+        ),
+        Check::new(
+            "invalid filter",
+            r#"subject?age_at_vital_status={"gt":1,"lt":0}"#,
+            Expectation::Error(StatusCode::UNPROCESSABLE_ENTITY, "InvalidParameters"),
+        ),
+    ]);
+
+    checks
+}
+
+/// The result of a single check.
+#[derive(Debug, Serialize)]
+pub struct CheckResult {
+    /// The name of the check.
+    name: String,
+
+    /// The URL that was checked.
+    url: String,
+
+    /// Whether the check must pass.
+    requirement: Requirement,
+
+    /// Whether the check passed.
+    passed: bool,
+
+    /// The reason the check failed (if it failed).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    error: Option<String>,
+
+    /// Whether the check failed because the response did not match the
+    /// specification (rather than because the request could not be completed).
+    #[serde(skip)]
+    nonconforming: bool,
+}
+
+impl CheckResult {
+    /// Gets the name of the check.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Gets whether the check must pass.
+    pub fn requirement(&self) -> Requirement {
+        self.requirement
+    }
+
+    /// Gets whether the check passed.
+    pub fn passed(&self) -> bool {
+        self.passed
+    }
+
+    /// Gets the reason the check failed (if it failed).
+    pub fn error(&self) -> Option<&str> {
+        self.error.as_deref()
+    }
+}
+
+/// A report of every check within a conformance run.
+#[derive(Debug, Serialize)]
+pub struct Report {
+    /// The base URL of the server that was checked.
+    base_url: String,
+
+    /// The results of the checks (in the order of the matrix).
+    checks: Vec<CheckResult>,
+}
+
+impl Report {
+    /// Gets the results of the checks (in the order of the matrix).
+    pub fn checks(&self) -> &[CheckResult] {
+        &self.checks
+    }
+
+    /// Gets the results of the failed checks that are
+    /// [required](Requirement::Required).
+    pub fn required_failures(&self) -> impl Iterator<Item = &CheckResult> {
+        self.checks
+            .iter()
+            .filter(|check| !check.passed && check.requirement == Requirement::Required)
+    }
+
+    /// Returns whether every failed required check failed because the response
+    /// did not match the specification (rather than because the request could
+    /// not be completed).
+    pub fn failures_are_nonconforming(&self) -> bool {
+        self.required_failures().all(|check| check.nonconforming)
+    }
+
+    /// Renders the [`Report`] as pretty-printed JSON.
+    pub fn to_json(&self) -> String {
+        // SAFETY: a [`Report`] is always serializable to JSON.
+        serde_json::to_string_pretty(self).unwrap() + "\n"
+    }
+
+    /// Renders the [`Report`] as a table for humans.
+    pub fn to_table(&self) -> String {
+        let rows = self
+            .checks
+            .iter()
+            .map(|check| {
+                [
+                    String::from(match check.passed {
+                        true => "pass",
+                        false => "FAIL",
+                    }),
+                    check.requirement.to_string(),
+                    check.name.clone(),
+                    check.error.clone().unwrap_or_default(),
+                ]
+            })
+            .collect::<Vec<_>>();
+
+        let header = [
+            String::from("RESULT"),
+            String::from("REQUIREMENT"),
+            String::from("CHECK"),
+            String::from("ERROR"),
+        ];
+
+        let widths = (0..header.len())
+            .map(|i| {
+                std::iter::once(&header)
+                    .chain(&rows)
+                    .map(|row| row[i].chars().count())
+                    .max()
+                    .unwrap_or_default()
+            })
+            .collect::<Vec<_>>();
+
+        std::iter::once(&header)
+            .chain(&rows)
+            .map(|row| {
+                let line = row
+                    .iter()
+                    .zip(&widths)
+                    .map(|(cell, width)| format!("{cell:width$}"))
+                    .collect::<Vec<_>>()
+                    .join("  ");
+
+                line.trim_end().to_string() + "\n"
+            })
+            .collect()
+    }
+}
+
+impl std::fmt::Display for Report {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let failed = self.checks.iter().filter(|check| !check.passed).count();
+
+        write!(
+            f,
+            "ran {} check(s) against {}: {} failed ({} required)",
+            self.checks.len(),
+            self.base_url,
+            failed,
+            self.required_failures().count()
+        )
+    }
+}
+
+/// Runs every check within the `checks` against the server at `base_url`.
+///
+/// An error is only returned if the base URL is invalid. Every other failure
+/// (including requests that could not be completed) is recorded within the
+/// returned [`Report`].
+pub async fn run(
+    base_url: &str,
+    checks: Vec<Check>,
+    timeout: Duration,
+    config: &check::Config,
+) -> Result<Report, check::Error> {
+    let base = base_url.parse::<Url>().map_err(check::Error::InvalidUrl)?;
+    let client = config.client(&base, Some(timeout))?;
+
+    let mut results = stream::iter(checks.into_iter().enumerate())
+        .map(|(index, check)| {
+            let client = &client;
+            let base = &base;
+
+            async move {
+                let url = check.url(base)?;
+
+                let result = match check::fetch_with_status(client, &url).await {
+                    Ok((status, headers, body)) => check.evaluate(status, &headers, &body),
+                    Err(err) => Err(Error::Request(err)),
+                };
+
+                Ok::<_, check::Error>((
+                    index,
+                    CheckResult {
+                        name: check.name,
+                        url: url.to_string(),
+                        requirement: check.requirement,
+                        passed: result.is_ok(),
+                        nonconforming: result.as_ref().is_err_and(Error::is_nonconforming),
+                        error: result.err().map(|err| err.to_string()),
+                    },
+                ))
+            }
+        })
+        .buffer_unordered(config.concurrency().get())
+        .collect::<Vec<_>>()
+        .await
+        .into_iter()
+        .collect::<Result<Vec<_>, _>>()?;
+
+    results.sort_by_key(|(index, _)| *index);
+
+    Ok(Report {
+        base_url: base.to_string(),
+        checks: results.into_iter().map(|(_, result)| result).collect(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use std::num::NonZeroUsize;
+
+    use actix_web::rt;
+    use actix_web::web;
+    use actix_web::web::Data;
+    use actix_web::App;
+    use actix_web::HttpRequest;
+    use actix_web::HttpResponse;
+    use actix_web::HttpServer;
+
+    use server::responses::error;
+    use server::responses::Errors;
+    use server::routes::info;
+    use server::routes::metadata;
+    use server::routes::namespace;
+    use server::routes::organization;
+
+    use super::*;
+
+    /// Starts an in-process reference server with 10 of each entity.
+    ///
+    /// When `broken` is set, `/info` is not served and unknown routes respond
+    /// with an empty `200 OK` instead of the standard error.
+    async fn serve(broken: bool) -> (String, actix_web::dev::ServerHandle) {
+        let subjects = subject::Store::random(10);
+        let samples = sample::Store::random(10, subjects.subjects.lock().unwrap());
+        let files = file::Store::random(10, samples.samples.lock().unwrap());
+
+        let subjects = Data::new(subjects);
+        let samples = Data::new(samples);
+        let files = Data::new(files);
+
+        let server = HttpServer::new(move || {
+            let app = App::new()
+                .configure(subject::configure(subjects.clone()))
+                .configure(sample::configure(samples.clone()))
+                .configure(file::configure(files.clone()))
+                .configure(metadata::configure())
+                .configure(namespace::configure())
+                .configure(organization::configure());
+
+            match broken {
+                true => app.default_service(web::to(HttpResponse::Ok)),
+                false => app.configure(info::configure()).default_service(web::to(
+                    |req: HttpRequest| async move {
+                        HttpResponse::NotFound().json(Errors::from(error::Kind::invalid_route(
+                            req.method().to_string(),
+                            req.path().to_string(),
+                            None,
+                        )))
+                    },
+                )),
+            }
+        })
+        .workers(1)
+        .bind(("127.0.0.1", 0))
+        .unwrap();
+
+        let address = server.addrs()[0];
+        let server = server.run();
+        let handle = server.handle();
+        rt::spawn(server);
+
+        (format!("http://{address}"), handle)
+    }
+
+    fn config() -> check::Config {
+        check::Config::new(NonZeroUsize::new(4).unwrap(), 0)
+    }
+
+    #[actix_web::test]
+    async fn the_reference_server_conforms() {
+        let (url, handle) = serve(false).await;
+        let report = run(&url, matrix(), Duration::from_secs(10), &config())
+            .await
+            .unwrap();
+        handle.stop(true).await;
+
+        let failures = report
+            .checks()
+            .iter()
+            .filter(|check| !check.passed())
+            .map(|check| format!("{}: {}", check.name(), check.error().unwrap()))
+            .collect::<Vec<_>>();
+
+        assert!(failures.is_empty(), "{failures:#?}");
+        assert_eq!(report.checks().len(), matrix().len());
+        assert_eq!(report.required_failures().count(), 0);
+    }
+
+    #[actix_web::test]
+    async fn it_reports_each_failure_in_matrix_order() {
+        let (url, handle) = serve(true).await;
+        let report = run(&url, matrix(), Duration::from_secs(10), &config())
+            .await
+            .unwrap();
+        handle.stop(true).await;
+
+        let failures = report
+            .checks()
+            .iter()
+            .filter(|check| !check.passed())
+            .map(|check| (check.name(), check.requirement(), check.error().unwrap()))
+            .collect::<Vec<_>>();
+
+        assert_eq!(failures.len(), 3, "{failures:#?}");
+
+        assert_eq!(failures[0].0, "information");
+        assert_eq!(failures[0].1, Requirement::Required);
+        assert!(
+            failures[0].2.starts_with("parse error: "),
+            "{}",
+            failures[0].2
+        );
+
+        assert_eq!(failures[1].0, "versions");
+        assert_eq!(failures[1].1, Requirement::Recommended);
+
+        assert_eq!(failures[2].0, "unknown route");
+        assert_eq!(
+            failures[2].2,
+            "expected status 404 Not Found, but received 200 OK"
+        );
+
+        assert_eq!(report.required_failures().count(), 2);
+        assert!(report.failures_are_nonconforming());
+    }
+
+    #[actix_web::test]
+    async fn it_records_requests_that_cannot_be_completed() {
+        // NOTE: nothing is listening on the port once the server is stopped.
+        let (url, handle) = serve(false).await;
+        handle.stop(true).await;
+
+        let checks = vec![Check::new(
+            "subjects",
+            "subject",
+            Expectation::Response(ResponseType::Subjects),
+        )];
+        let report = run(&url, checks, Duration::from_secs(10), &config())
+            .await
+            .unwrap();
+
+        assert!(!report.checks()[0].passed());
+        assert!(!report.failures_are_nonconforming());
+    }
+
+    #[test]
+    fn it_joins_paths_and_queries_to_the_base_url() {
+        let check = Check::new(
+            "subjects",
+            "subject/by/sex/count?per_page=1",
+            Expectation::Response(ResponseType::SubjectsByCount),
+        );
+
+        for base in ["https://example.org/api/v1", "https://example.org/api/v1/"] {
+            assert_eq!(
+                check.url(&base.parse().unwrap()).unwrap().as_str(),
+                "https://example.org/api/v1/subject/by/sex/count?per_page=1"
+            );
+        }
+    }
+
+    #[test]
+    fn it_checks_the_kind_of_an_error() {
+        let body = r#"{"errors":[{"kind":"NotFound","entity":"Subjects","message":"x"}]}"#;
+
+        assert!(check_kind(body, "NotFound").is_ok());
+        assert_eq!(
+            check_kind(body, "InvalidRoute").unwrap_err().to_string(),
+            "expected an error of kind `InvalidRoute`, but received [`NotFound`]"
+        );
+    }
+
+    #[test]
+    fn it_renders_a_table() {
+        let report = Report {
+            base_url: String::from("https://example.org/api/v1"),
+            checks: vec![
+                CheckResult {
+                    name: String::from("subjects"),
+                    url: String::from("https://example.org/api/v1/subject"),
+                    requirement: Requirement::Required,
+                    passed: true,
+                    error: None,
+                    nonconforming: false,
+                },
+                CheckResult {
+                    name: String::from("versions"),
+                    url: String::from("https://example.org/api/v1/info/versions"),
+                    requirement: Requirement::Recommended,
+                    passed: false,
+                    error: Some(String::from(
+                        "expected status 200 OK, but received 404 Not Found",
+                    )),
+                    nonconforming: true,
+                },
+            ],
+        };
+
+        assert_eq!(
+            report.to_table(),
+            "RESULT  REQUIREMENT  CHECK     ERROR\n\
+             pass    required     subjects\n\
+             FAIL    recommended  versions  expected status 200 OK, but received 404 Not Found\n"
+        );
+        assert_eq!(
+            report.to_string(),
+            "ran 2 check(s) against https://example.org/api/v1: 1 failed (0 required)"
+        );
+        assert_eq!(report.required_failures().count(), 0);
+    }
+}
@@ -0,0 +1,374 @@
+//! Probing a server for inconsistencies between subjects and their samples.
+//!
+//! The first `N` subjects of a server (the first page of `/subject` with
+//! `per_page=N`) are fetched, and then every page of `/sample` is crawled to
+//! find the samples collected from those subjects (as the API has no endpoint
+//! that lists the samples of a particular subject). Each subject and its
+//! samples are then checked against the rules within
+//! [`ccdi_server::consistency::subject`].
+//!
+//! Pages of samples are fetched concurrently and retried in the same manner as
+//! the `check` subcommand (see [`check::Config`]). Samples collected from
+//! subjects that are not probed are dropped as soon as their page is parsed.
+
+use std::collections::BTreeSet;
+use std::num::NonZeroUsize;
+
+use futures::stream;
+use futures::StreamExt as _;
+use log::info;
+use serde::de::DeserializeOwned;
+use serde::Deserialize;
+use url::Url;
+
+use ccdi_client as client;
+use ccdi_models as models;
+use ccdi_server as server;
+
+use client::links;
+use models::Sample;
+use models::Subject;
+use server::consistency::subject::Violation;
+
+use crate::check;
+use crate::check::Error;
+
+/// The default number of subjects to probe.
+pub const DEFAULT_SUBJECTS: usize = 100;
+
+/// The number of samples requested within each page of `/sample`.
+const SAMPLES_PER_PAGE: usize = 100;
+
+/// The entities within a page of a paginated response.
+#[derive(Deserialize)]
+struct Page<T> {
+    /// The entities.
+    data: Vec<T>,
+}
+
+/// A report of the subjects and samples that were probed.
+#[derive(Debug, Default, Eq, PartialEq)]
+pub struct Report {
+    /// The number of subjects that were probed.
+    subjects: usize,
+
+    /// The number of samples collected from the probed subjects.
+    samples: usize,
+
+    /// The violations in the order of the subjects to which they relate.
+    violations: Vec<Violation>,
+}
+
+impl Report {
+    /// Gets the number of subjects that were probed.
+    pub fn subjects(&self) -> usize {
+        self.subjects
+    }
+
+    /// Gets the number of samples collected from the probed subjects.
+    pub fn samples(&self) -> usize {
+        self.samples
+    }
+
+    /// Gets the violations in the order of the subjects to which they relate.
+    pub fn violations(&self) -> &[Violation] {
+        &self.violations
+    }
+}
+
+/// Gets the URL of an endpoint relative to the base URL of a server.
+fn endpoint(base: &Url, path: &str) -> Result<Url, Error> {
+    let mut url = base.clone();
+
+    url.path_segments_mut()
+        .map_err(|_| Error::InvalidUrl(url::ParseError::RelativeUrlWithCannotBeABaseBase))?
+        .pop_if_empty()
+        .push(path);
+
+    Ok(url)
+}
+
+/// Parses the entities within a page of a paginated response.
+fn parse_page<T: DeserializeOwned>(body: &str) -> Result<Vec<T>, Error> {
+    serde_json::from_str::<Page<T>>(body)
+        .map(|page| page.data)
+        .map_err(|err| Error::Parse(err.to_string()))
+}
+
+/// Probes the first `subjects` subjects of the server at `base_url` (and the
+/// samples collected from them) for inconsistencies.
+///
+/// Unlike [`check::check()`], any page that cannot be fetched or parsed ends
+/// the probe, as the relationships between the entities would otherwise be
+/// incomplete.
+pub async fn probe(
+    base_url: &str,
+    subjects: NonZeroUsize,
+    config: &check::Config,
+) -> Result<Report, Error> {
+    let base = base_url.parse::<Url>().map_err(Error::InvalidUrl)?;
+    let client = config.client(&base, None)?;
+
+    let mut url = endpoint(&base, "subject")?;
+    url.query_pairs_mut()
+        .append_pair("per_page", &subjects.to_string());
+
+    let (_, body) = check::fetch(&client, &url).await?;
+    let subjects = parse_page::<Subject>(&body)?;
+
+    let probed = subjects
+        .iter()
+        .map(|subject| subject.id())
+        .collect::<BTreeSet<_>>();
+
+    let mut url = endpoint(&base, "sample")?;
+    url.query_pairs_mut()
+        .append_pair("per_page", &SAMPLES_PER_PAGE.to_string());
+
+    let (headers, body) = check::fetch(&client, &url).await?;
+
+    let mut samples = parse_page::<Sample>(&body)?;
+    samples.retain(|sample| probed.contains(sample.subject()));
+
+    let last = links::last_page(&headers).unwrap_or(1);
+
+    let mut results = stream::iter(2..=last)
+        .map(|page| {
+            let client = &client;
+            let url = links::with_page(&url, page);
+
+            async move {
+                let (_, body) = check::fetch(client, &url).await?;
+                parse_page::<Sample>(&body)
+            }
+        })
+        .buffer_unordered(config.concurrency().get());
+
+    let mut fetched = 1;
+
+    while let Some(result) = results.next().await {
+        samples.extend(
+            result?
+                .into_iter()
+                .filter(|sample| probed.contains(sample.subject())),
+        );
+
+        fetched += 1;
+        info!("{fetched}/{last} pages of samples fetched");
+    }
+
+    Ok(Report {
+        subjects: subjects.len(),
+        samples: samples.len(),
+        violations: server::consistency::subject::check(&subjects, &samples),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Mutex;
+
+    use actix_web::rt;
+    use actix_web::web::Data;
+    use actix_web::App;
+    use actix_web::HttpServer;
+    use ordered_float::OrderedFloat;
+
+    use ccdi_cde as cde;
+
+    use cde::v1::sample::DiseasePhase;
+    use cde::v1::subject::VitalStatus;
+    use models::metadata::field::unowned::sample::AgeAtCollection;
+    use models::metadata::field::unowned::sample::DiseasePhase as DiseasePhaseField;
+    use models::metadata::field::unowned::subject::AgeAtVitalStatus;
+    use models::metadata::field::unowned::subject::VitalStatus as VitalStatusField;
+    use models::namespace;
+    use models::subject::Kind;
+    use server::routes::sample;
+    use server::routes::subject;
+
+    use super::*;
+
+    fn namespace_id() -> namespace::Identifier {
+        namespace::Identifier::new(
+            "example-organization"
+                .parse::<models::organization::Identifier>()
+                .unwrap(),
+            namespace::identifier::Name::try_new("ExampleNamespace").unwrap(),
+        )
+    }
+
+    fn subject(name: &str, vital_status: VitalStatus, age_at_vital_status: f32) -> Subject {
+        Subject::new(
+            models::subject::Identifier::new(namespace_id(), name),
+            Kind::Participant,
+            None,
+            Some(
+                models::subject::metadata::Builder::default()
+                    .vital_status(VitalStatusField::new(vital_status, None, None, None))
+                    .age_at_vital_status(AgeAtVitalStatus::new(
+                        models::subject::metadata::AgeAtVitalStatus::from(OrderedFloat(
+                            age_at_vital_status,
+                        )),
+                        None,
+                        None,
+                        None,
+                    ))
+                    .build(),
+            ),
+        )
+    }
+
+    fn sample(name: &str, of: &str, disease_phase: DiseasePhase, age_at_collection: f32) -> Sample {
+        Sample::new(
+            models::sample::Identifier::new(namespace_id(), name),
+            models::subject::Identifier::new(namespace_id(), of),
+            None,
+            Some(
+                models::sample::metadata::Builder::default()
+                    .disease_phase(DiseasePhaseField::new(disease_phase, None, None, None))
+                    .age_at_collection(AgeAtCollection::new(
+                        models::sample::metadata::AgeAtCollection::from(OrderedFloat(
+                            age_at_collection,
+                        )),
+                        None,
+                        None,
+                        None,
+                    ))
+                    .build(),
+            ),
+        )
+    }
+
+    /// Starts an in-process server seeded with deliberate inconsistencies.
+    ///
+    /// Enough consistent samples are served that the samples span multiple
+    /// pages.
+    async fn serve() -> (String, actix_web::dev::ServerHandle) {
+        let subjects = vec![
+            subject("Subject1", VitalStatus::Alive, 3650.0),
+            subject("Subject2", VitalStatus::Dead, 3650.0),
+            subject("Subject3", VitalStatus::Alive, 365.0),
+        ];
+
+        let mut samples = (0..250)
+            .map(|i| {
+                sample(
+                    &format!("Sample{i:03}"),
+                    "Subject2",
+                    DiseasePhase::InitialDiagnosis,
+                    365.0,
+                )
+            })
+            .collect::<Vec<_>>();
+
+        samples.push(sample(
+            "SampleA",
+            "Subject1",
+            DiseasePhase::PostMortem,
+            365.0,
+        ));
+        samples.push(sample(
+            "SampleB",
+            "Subject2",
+            DiseasePhase::PostMortem,
+            3650.0,
+        ));
+        samples.push(sample("SampleC", "Subject3", DiseasePhase::Relapse, 730.0));
+
+        let subjects = Data::new(subject::Store {
+            subjects: Mutex::new(subjects),
+        });
+        let samples = Data::new(sample::Store {
+            samples: Mutex::new(samples),
+        });
+
+        let server = HttpServer::new(move || {
+            App::new()
+                .configure(subject::configure(subjects.clone()))
+                .configure(sample::configure(samples.clone()))
+        })
+        .workers(1)
+        .bind(("127.0.0.1", 0))
+        .unwrap();
+
+        let address = server.addrs()[0];
+        let server = server.run();
+        let handle = server.handle();
+        rt::spawn(server);
+
+        (format!("http://{address}"), handle)
+    }
+
+    fn config() -> check::Config {
+        check::Config::new(NonZeroUsize::new(2).unwrap(), 0)
+    }
+
+    #[actix_web::test]
+    async fn it_reports_violations_across_pages() {
+        let (url, handle) = serve().await;
+
+        let report = probe(&url, NonZeroUsize::new(3).unwrap(), &config())
+            .await
+            .unwrap();
+
+        handle.stop(true).await;
+
+        assert_eq!(report.subjects(), 3);
+        assert_eq!(report.samples(), 253);
+        assert_eq!(
+            report
+                .violations()
+                .iter()
+                .map(|violation| (
+                    violation.subject().name().to_string(),
+                    violation.sample().name().to_string(),
+                    violation.rule()
+                ))
+                .collect::<Vec<_>>(),
+            vec![
+                (
+                    String::from("Subject1"),
+                    String::from("SampleA"),
+                    "post-mortem-sample-from-living-subject"
+                ),
+                (
+                    String::from("Subject3"),
+                    String::from("SampleC"),
+                    "collected-after-vital-status"
+                ),
+            ]
+        );
+    }
+
+    #[actix_web::test]
+    async fn it_only_probes_the_requested_number_of_subjects() {
+        let (url, handle) = serve().await;
+
+        let report = probe(&url, NonZeroUsize::new(2).unwrap(), &config())
+            .await
+            .unwrap();
+
+        handle.stop(true).await;
+
+        assert_eq!(report.subjects(), 2);
+        assert_eq!(report.samples(), 252);
+        assert_eq!(report.violations().len(), 1);
+        assert_eq!(report.violations()[0].sample().name(), "SampleA");
+    }
+
+    #[test]
+    fn it_joins_endpoints_to_the_base_url() {
+        let base = "http://localhost:8000/api/v1/".parse::<Url>().unwrap();
+        assert_eq!(
+            endpoint(&base, "subject").unwrap().as_str(),
+            "http://localhost:8000/api/v1/subject"
+        );
+
+        let base = "http://localhost:8000".parse::<Url>().unwrap();
+        assert_eq!(
+            endpoint(&base, "sample").unwrap().as_str(),
+            "http://localhost:8000/sample"
+        );
+    }
+}
@@ -0,0 +1,156 @@
+//! Structural differences between JSON documents.
+//!
+//! Two documents are compared value by value: objects are compared key by key
+//! (regardless of the order of the keys), and arrays are compared element by
+//! element. Each difference is reported at the
+//! [JSON Pointer](https://www.rfc-editor.org/rfc/rfc6901) of the value that
+//! differs.
+
+use serde_json::Value;
+
+/// A single difference between two JSON documents.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Difference {
+    /// The JSON Pointer to the value that differs.
+    pointer: String,
+
+    /// The expected value (if it exists).
+    expected: Option<Value>,
+
+    /// The actual value (if it exists).
+    actual: Option<Value>,
+}
+
+impl Difference {
+    /// Creates a new [`Difference`].
+    pub fn new(pointer: impl Into<String>, expected: Option<Value>, actual: Option<Value>) -> Self {
+        Self {
+            pointer: pointer.into(),
+            expected,
+            actual,
+        }
+    }
+}
+
+impl std::fmt::Display for Difference {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let pointer = match self.pointer.as_str() {
+            "" => "/",
+            pointer => pointer,
+        };
+
+        match (&self.expected, &self.actual) {
+            (Some(expected), Some(actual)) => {
+                write!(f, "{pointer}: expected `{expected}`, found `{actual}`")
+            }
+            (Some(expected), None) => write!(f, "{pointer}: missing `{expected}`"),
+            (None, Some(actual)) => write!(f, "{pointer}: unexpected `{actual}`"),
+            (None, None) => write!(f, "{pointer}: no values"),
+        }
+    }
+}
+
+/// Escapes a key for use within a JSON Pointer.
+fn escape(key: &str) -> String {
+    key.replace('~', "~0").replace('/', "~1")
+}
+
+/// Computes the structural differences between the `expected` and `actual`
+/// JSON documents.
+pub fn diff(expected: &Value, actual: &Value) -> Vec<Difference> {
+    let mut differences = Vec::new();
+    diff_at(String::new(), expected, actual, &mut differences);
+    differences
+}
+
+fn diff_at(pointer: String, expected: &Value, actual: &Value, differences: &mut Vec<Difference>) {
+    match (expected, actual) {
+        (Value::Object(expected), Value::Object(actual)) => {
+            for (key, expected) in expected {
+                let pointer = format!("{pointer}/{}", escape(key));
+
+                match actual.get(key) {
+                    Some(actual) => diff_at(pointer, expected, actual, differences),
+                    None => {
+                        differences.push(Difference::new(pointer, Some(expected.clone()), None))
+                    }
+                }
+            }
+
+            for (key, actual) in actual {
+                if !expected.contains_key(key) {
+                    differences.push(Difference::new(
+                        format!("{pointer}/{}", escape(key)),
+                        None,
+                        Some(actual.clone()),
+                    ));
+                }
+            }
+        }
+        (Value::Array(expected), Value::Array(actual)) => {
+            for i in 0..expected.len().max(actual.len()) {
+                let pointer = format!("{pointer}/{i}");
+
+                match (expected.get(i), actual.get(i)) {
+                    (Some(expected), Some(actual)) => {
+                        diff_at(pointer, expected, actual, differences)
+                    }
+                    (expected, actual) => differences.push(Difference::new(
+                        pointer,
+                        expected.cloned(),
+                        actual.cloned(),
+                    )),
+                }
+            }
+        }
+        (expected, actual) if expected != actual => differences.push(Difference::new(
+            pointer,
+            Some(expected.clone()),
+            Some(actual.clone()),
+        )),
+        _ => {}
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn it_finds_no_differences_between_equal_documents() {
+        let value = json!({ "a": [1, { "b": null }], "c": "d" });
+        assert!(diff(&value, &value).is_empty());
+    }
+
+    #[test]
+    fn it_ignores_the_order_of_keys() {
+        let expected = serde_json::from_str::<Value>(r#"{"a":1,"b":2}"#).unwrap();
+        let actual = serde_json::from_str::<Value>(r#"{"b":2,"a":1}"#).unwrap();
+
+        assert!(diff(&expected, &actual).is_empty());
+    }
+
+    #[test]
+    fn it_reports_differences_at_their_pointers() {
+        let expected = json!({ "a": [1, 2], "b/c": true, "d": "e" });
+        let actual = json!({ "a": [1, 3, 4], "b/c": false, "f": null });
+
+        let differences = diff(&expected, &actual)
+            .into_iter()
+            .map(|difference| difference.to_string())
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            differences,
+            vec![
+                "/a/1: expected `2`, found `3`",
+                "/a/2: unexpected `4`",
+                "/b~1c: expected `true`, found `false`",
+                "/d: missing `\"e\"`",
+                "/f: unexpected `null`",
+            ]
+        );
+    }
+}
@@ -0,0 +1,614 @@
+//! Changes to the harmonized fields between versions of the specification.
+//!
+//! The harmonized fields of each entity are extracted from the
+//! `models.<entity>.Metadata` schema of a generated OpenAPI document (including
+//! the fields common to every metadata block). Each field is reduced to the
+//! type of its value (the named schema describing the value, such as a common
+//! data element, along with its JSON type), its permissible values, and its
+//! description. The fields of two documents are then compared to report the
+//! fields that were added, removed, or renamed and the fields whose type,
+//! permissible values, or description changed.
+
+use std::collections::BTreeMap;
+
+use clap::ValueEnum;
+use serde::Serialize;
+use serde_json::Value;
+
+/// The entities whose harmonized fields are extracted.
+pub const ENTITIES: &[&str] = &["subject", "sample", "file"];
+
+/// The prefix of a reference to a schema within the same document.
+const SCHEMA_REFERENCE_PREFIX: &str = "#/components/schemas/";
+
+/// The prefix of the names of the schemas that wrap the value of a field along
+/// with its ancestors, details, and comment.
+const FIELD_WRAPPER_PREFIX: &str = "field.";
+
+/// The key of the unharmonized fields within a metadata block (which is not a
+/// harmonized field).
+const UNHARMONIZED_KEY: &str = "unharmonized";
+
+/// An error related to extracting the harmonized fields.
+#[derive(Debug, Eq, PartialEq)]
+pub enum Error {
+    /// A schema referred to by the document does not exist.
+    MissingSchema(String),
+}
+
+impl std::fmt::Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::MissingSchema(name) => write!(f, "missing schema: {name}"),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+/// The format in which the changes are written.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, ValueEnum)]
+pub enum Format {
+    /// A readable markdown table.
+    #[default]
+    Markdown,
+
+    /// A JSON array of changes.
+    Json,
+}
+
+/// A harmonized field.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Field {
+    /// The type of the value of the field.
+    r#type: String,
+
+    /// The named schema describing the value of the field (if the value is
+    /// described by a named schema).
+    schema: Option<String>,
+
+    /// The permissible values of the field (if the values are enumerated).
+    values: Vec<String>,
+
+    /// The description of the field.
+    description: Option<String>,
+}
+
+/// The harmonized fields of each entity (keyed by the entity and then by the
+/// name of the field).
+pub type Fields = BTreeMap<String, BTreeMap<String, Field>>;
+
+/// The kind of a [`Change`].
+#[derive(Clone, Debug, Eq, PartialEq, Serialize)]
+#[serde(tag = "change", rename_all = "snake_case")]
+pub enum Kind {
+    /// The field was added.
+    Added,
+
+    /// The field was removed.
+    Removed,
+
+    /// The field was renamed (the value of the field is described by the same
+    /// named schema under a new name).
+    Renamed {
+        /// The new name of the field.
+        to: String,
+    },
+
+    /// The type of the value of the field changed.
+    TypeChanged {
+        /// The previous type.
+        from: String,
+
+        /// The new type.
+        to: String,
+    },
+
+    /// Permissible values were added to the field.
+    ValuesAdded {
+        /// The added values.
+        values: Vec<String>,
+    },
+
+    /// Permissible values were removed from the field.
+    ValuesRemoved {
+        /// The removed values.
+        values: Vec<String>,
+    },
+
+    /// The description of the field changed.
+    DescriptionChanged {
+        /// The previous description.
+        from: Option<String>,
+
+        /// The new description.
+        to: Option<String>,
+    },
+}
+
+impl std::fmt::Display for Kind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Kind::Added => write!(f, "added"),
+            Kind::Removed => write!(f, "removed"),
+            Kind::Renamed { .. } => write!(f, "renamed"),
+            Kind::TypeChanged { .. } => write!(f, "type changed"),
+            Kind::ValuesAdded { .. } => write!(f, "values added"),
+            Kind::ValuesRemoved { .. } => write!(f, "values removed"),
+            Kind::DescriptionChanged { .. } => write!(f, "description changed"),
+        }
+    }
+}
+
+/// A change to a harmonized field.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize)]
+pub struct Change {
+    /// The entity to which the field belongs.
+    entity: String,
+
+    /// The name of the field (within the previous version).
+    field: String,
+
+    /// The kind of change.
+    #[serde(flatten)]
+    kind: Kind,
+}
+
+impl Change {
+    /// Creates a new [`Change`].
+    pub fn new(entity: impl Into<String>, field: impl Into<String>, kind: Kind) -> Self {
+        Self {
+            entity: entity.into(),
+            field: field.into(),
+            kind,
+        }
+    }
+
+    /// Gets the details of the [`Change`] for a row of the markdown table.
+    fn details(&self) -> String {
+        match &self.kind {
+            Kind::Added | Kind::Removed => String::new(),
+            Kind::Renamed { to } => format!("to `{to}`"),
+            Kind::TypeChanged { from, to } => format!("`{from}` → `{to}`"),
+            Kind::ValuesAdded { values } | Kind::ValuesRemoved { values } => values
+                .iter()
+                .map(|value| format!("`{value}`"))
+                .collect::<Vec<_>>()
+                .join(", "),
+            Kind::DescriptionChanged { from, to } => {
+                let summary = |description: &Option<String>| match description {
+                    Some(description) => description.lines().next().unwrap_or_default().to_owned(),
+                    None => String::from("(none)"),
+                };
+
+                format!("{} → {}", summary(from), summary(to))
+            }
+        }
+    }
+}
+
+/// Gets the name of the schema referred to by `value` (if `value` is a
+/// reference to a schema or a single-element `allOf` wrapping a reference).
+fn reference(value: &Value) -> Option<&str> {
+    if let Some(reference) = value.get("$ref").and_then(Value::as_str) {
+        return reference.strip_prefix(SCHEMA_REFERENCE_PREFIX);
+    }
+
+    match value.get("allOf").and_then(Value::as_array) {
+        Some(schemas) if schemas.len() == 1 => reference(&schemas[0]),
+        _ => None,
+    }
+}
+
+/// Gets a named schema from the schemas of a document.
+fn schema<'a>(schemas: &'a Value, name: &str) -> Result<&'a Value, Error> {
+    schemas
+        .get(name)
+        .ok_or_else(|| Error::MissingSchema(name.to_owned()))
+}
+
+/// Gets the JSON type of an inline schema (including its format, if any).
+fn json_type(value: &Value) -> String {
+    let base = match value.get("type").and_then(Value::as_str) {
+        Some(base) => base,
+        None if value.get("properties").is_some() => "object",
+        None => "any",
+    };
+
+    match value.get("format").and_then(Value::as_str) {
+        Some(format) => format!("{base} ({format})"),
+        None => base.to_owned(),
+    }
+}
+
+/// Gets the permissible values of an inline schema.
+fn enumerated_values(value: &Value) -> Vec<String> {
+    value
+        .get("enum")
+        .and_then(Value::as_array)
+        .map(|values| {
+            values
+                .iter()
+                .map(|value| match value {
+                    Value::String(value) => value.clone(),
+                    value => value.to_string(),
+                })
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+/// Gets the description of an inline schema.
+fn description(value: &Value) -> Option<String> {
+    value
+        .get("description")
+        .and_then(Value::as_str)
+        .map(String::from)
+}
+
+/// Resolves the schema of a property into a [`Field`].
+///
+/// Arrays are resolved through their items, and the `field.*` wrappers are
+/// resolved through their `value`.
+fn resolve(schemas: &Value, property: &Value) -> Result<Field, Error> {
+    if property.get("type").and_then(Value::as_str) == Some("array") {
+        let items = property.get("items").unwrap_or(&Value::Null);
+        let mut field = resolve(schemas, items)?;
+
+        field.r#type = format!("array of {}", field.r#type);
+        field.description = field.description.or_else(|| description(property));
+
+        return Ok(field);
+    }
+
+    let name = match reference(property) {
+        Some(name) => name,
+        None => {
+            return Ok(Field {
+                r#type: json_type(property),
+                schema: None,
+                values: enumerated_values(property),
+                description: description(property),
+            })
+        }
+    };
+
+    let value = schema(schemas, name)?;
+
+    if name.starts_with(FIELD_WRAPPER_PREFIX) {
+        let inner = value.pointer("/properties/value").unwrap_or(&Value::Null);
+        let mut field = resolve(schemas, inner)?;
+        field.description = field.description.or_else(|| description(property));
+        return Ok(field);
+    }
+
+    Ok(Field {
+        r#type: format!("{name}: {}", json_type(value)),
+        schema: Some(name.to_owned()),
+        values: enumerated_values(value),
+        description: description(value).or_else(|| description(property)),
+    })
+}
+
+/// Collects the properties of a schema (following the references within an
+/// `allOf`).
+fn properties<'a>(
+    schemas: &'a Value,
+    value: &'a Value,
+    properties: &mut Vec<(&'a String, &'a Value)>,
+) -> Result<(), Error> {
+    if let Some(name) = value.get("$ref").and_then(Value::as_str) {
+        let name = name.strip_prefix(SCHEMA_REFERENCE_PREFIX).unwrap_or(name);
+        return self::properties(schemas, schema(schemas, name)?, properties);
+    }
+
+    if let Some(parts) = value.get("allOf").and_then(Value::as_array) {
+        for part in parts {
+            self::properties(schemas, part, properties)?;
+        }
+    }
+
+    if let Some(inner) = value.get("properties").and_then(Value::as_object) {
+        properties.extend(inner.iter());
+    }
+
+    Ok(())
+}
+
+/// Extracts the harmonized fields of each entity from an OpenAPI document.
+pub fn extract(document: &Value) -> Result<Fields, Error> {
+    let schemas = document
+        .pointer("/components/schemas")
+        .ok_or_else(|| Error::MissingSchema(String::from("components.schemas")))?;
+
+    let mut result = Fields::new();
+
+    for entity in ENTITIES {
+        let metadata = schema(schemas, &format!("models.{entity}.Metadata"))?;
+
+        let mut all = Vec::new();
+        properties(schemas, metadata, &mut all)?;
+
+        let fields = result.entry(entity.to_string()).or_default();
+
+        for (name, property) in all {
+            if name == UNHARMONIZED_KEY {
+                continue;
+            }
+
+            fields.insert(name.clone(), resolve(schemas, property)?);
+        }
+    }
+
+    Ok(result)
+}
+
+/// Gets the values within `a` that are not within `b` (in the order of `a`).
+fn difference(a: &[String], b: &[String]) -> Vec<String> {
+    a.iter()
+        .filter(|value| !b.contains(value))
+        .cloned()
+        .collect()
+}
+
+/// Compares the harmonized fields of two versions of the specification.
+///
+/// A field that was removed is reported as renamed when a field that was added
+/// to the same entity has its value described by the same named schema.
+pub fn diff(old: &Fields, new: &Fields) -> Vec<Change> {
+    let empty = BTreeMap::new();
+    let mut changes = Vec::new();
+
+    for entity in ENTITIES {
+        let old = old.get(*entity).unwrap_or(&empty);
+        let new = new.get(*entity).unwrap_or(&empty);
+
+        let mut added = new
+            .iter()
+            .filter(|(name, _)| !old.contains_key(*name))
+            .collect::<Vec<_>>();
+
+        for (name, field) in old {
+            let updated = match new.get(name) {
+                Some(updated) => updated,
+                None => {
+                    let renamed = field.schema.as_ref().and_then(|schema| {
+                        added
+                            .iter()
+                            .position(|(_, candidate)| candidate.schema.as_ref() == Some(schema))
+                    });
+
+                    match renamed {
+                        Some(i) => {
+                            let (to, _) = added.remove(i);
+                            changes.push(Change::new(
+                                *entity,
+                                name,
+                                Kind::Renamed { to: to.clone() },
+                            ));
+                        }
+                        None => changes.push(Change::new(*entity, name, Kind::Removed)),
+                    }
+
+                    continue;
+                }
+            };
+
+            if field.r#type != updated.r#type {
+                changes.push(Change::new(
+                    *entity,
+                    name,
+                    Kind::TypeChanged {
+                        from: field.r#type.clone(),
+                        to: updated.r#type.clone(),
+                    },
+                ));
+            }
+
+            let values = difference(&updated.values, &field.values);
+            if !values.is_empty() {
+                changes.push(Change::new(*entity, name, Kind::ValuesAdded { values }));
+            }
+
+            let values = difference(&field.values, &updated.values);
+            if !values.is_empty() {
+                changes.push(Change::new(*entity, name, Kind::ValuesRemoved { values }));
+            }
+
+            if field.description != updated.description {
+                changes.push(Change::new(
+                    *entity,
+                    name,
+                    Kind::DescriptionChanged {
+                        from: field.description.clone(),
+                        to: updated.description.clone(),
+                    },
+                ));
+            }
+        }
+
+        for (name, _) in added {
+            changes.push(Change::new(*entity, name, Kind::Added));
+        }
+    }
+
+    changes
+}
+
+/// Renders the changes as a markdown table.
+pub fn to_markdown(changes: &[Change]) -> String {
+    if changes.is_empty() {
+        return String::from("No harmonized fields changed.\n");
+    }
+
+    let mut result = String::from("| Entity | Field | Change | Details |\n");
+    result.push_str("| --- | --- | --- | --- |\n");
+
+    for change in changes {
+        result.push_str(&format!(
+            "| {} | `{}` | {} | {} |\n",
+            change.entity,
+            change.field,
+            change.kind,
+            change.details().replace('|', "\\|")
+        ));
+    }
+
+    result
+}
+
+/// Renders the changes in the provided [`Format`].
+pub fn render(changes: &[Change], format: Format) -> String {
+    match format {
+        Format::Markdown => to_markdown(changes),
+        // SAFETY: the changes are always serializable to JSON.
+        Format::Json => serde_json::to_string_pretty(changes).unwrap() + "\n",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// The previous version of the fixture specification.
+    const OLD: &str = include_str!("../fixtures/fields/old.yml");
+
+    /// The new version of the fixture specification.
+    const NEW: &str = include_str!("../fixtures/fields/new.yml");
+
+    fn fields(document: &str) -> Fields {
+        extract(&serde_yaml::from_str::<Value>(document).unwrap()).unwrap()
+    }
+
+    #[test]
+    fn it_extracts_the_harmonized_fields() {
+        let fields = fields(OLD);
+
+        assert_eq!(
+            fields["subject"].keys().collect::<Vec<_>>(),
+            ["age_at_vital_status", "depositions", "sex", "vital_status"]
+        );
+        assert_eq!(
+            fields["sample"].keys().collect::<Vec<_>>(),
+            ["depositions", "diagnosis", "tumor_site"]
+        );
+        assert_eq!(
+            fields["file"].keys().collect::<Vec<_>>(),
+            ["checksums", "depositions", "size"]
+        );
+
+        let vital_status = &fields["subject"]["vital_status"];
+        assert_eq!(vital_status.r#type, "cde.v1.subject.VitalStatus: string");
+        assert_eq!(vital_status.values, ["Alive", "Dead", "Unknown"]);
+
+        let depositions = &fields["subject"]["depositions"];
+        assert_eq!(
+            depositions.r#type,
+            "array of models.metadata.common.deposition.Accession: object"
+        );
+    }
+
+    #[test]
+    fn it_reports_the_known_differences_between_the_fixtures() {
+        assert_eq!(
+            diff(&fields(OLD), &fields(NEW)),
+            [
+                Change::new(
+                    "subject",
+                    "age_at_vital_status",
+                    Kind::TypeChanged {
+                        from: String::from("number (float)"),
+                        to: String::from("cde.v1.subject.AgeAtVitalStatus: number (float)"),
+                    }
+                ),
+                Change::new(
+                    "subject",
+                    "age_at_vital_status",
+                    Kind::DescriptionChanged {
+                        from: Some(String::from("The approximate age at vital status.")),
+                        to: Some(String::from("The approximate age at vital status in days.")),
+                    }
+                ),
+                Change::new(
+                    "subject",
+                    "vital_status",
+                    Kind::ValuesAdded {
+                        values: vec![String::from("Not reported")],
+                    }
+                ),
+                Change::new(
+                    "subject",
+                    "vital_status",
+                    Kind::ValuesRemoved {
+                        values: vec![String::from("Unknown")],
+                    }
+                ),
+                Change::new(
+                    "sample",
+                    "diagnosis",
+                    Kind::DescriptionChanged {
+                        from: Some(String::from("The diagnosis for a sample.")),
+                        to: Some(String::from(
+                            "The diagnosis for a sample (from the CCDI Submission Template)."
+                        )),
+                    }
+                ),
+                Change::new(
+                    "sample",
+                    "tumor_site",
+                    Kind::Renamed {
+                        to: String::from("tumor_tissue_site"),
+                    }
+                ),
+                Change::new("file", "checksums", Kind::Removed),
+                Change::new("file", "drs_uri", Kind::Added),
+            ]
+        );
+    }
+
+    #[test]
+    fn it_reports_no_differences_for_the_same_specification() {
+        assert!(diff(&fields(NEW), &fields(NEW)).is_empty());
+
+        let specification = include_str!("../../../swagger.yml");
+        let fields = fields(specification);
+        assert!(fields["subject"].contains_key("vital_status"));
+        assert!(diff(&fields, &fields).is_empty());
+    }
+
+    #[test]
+    fn it_reports_missing_schemas() {
+        let document = serde_json::json!({ "components": { "schemas": {} } });
+        assert_eq!(
+            extract(&document).unwrap_err(),
+            Error::MissingSchema(String::from("models.subject.Metadata"))
+        );
+    }
+
+    #[test]
+    fn it_renders_the_changes() {
+        let changes = diff(&fields(OLD), &fields(NEW));
+
+        let markdown = render(&changes, Format::Markdown);
+        assert!(markdown.starts_with("| Entity | Field | Change | Details |\n"));
+        assert!(markdown.contains("| sample | `tumor_site` | renamed | to `tumor_tissue_site` |\n"));
+        assert!(markdown.contains("| file | `drs_uri` | added |  |\n"));
+
+        let json = serde_json::from_str::<Value>(&render(&changes, Format::Json)).unwrap();
+        assert_eq!(
+            json[0],
+            serde_json::json!({
+                "entity": "subject",
+                "field": "age_at_vital_status",
+                "change": "type_changed",
+                "from": "number (float)",
+                "to": "cde.v1.subject.AgeAtVitalStatus: number (float)"
+            })
+        );
+
+        assert_eq!(
+            render(&[], Format::Markdown),
+            "No harmonized fields changed.\n"
+        );
+    }
+}
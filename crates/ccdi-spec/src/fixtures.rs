@@ -0,0 +1,569 @@
+//! Generating canned request and response fixtures for the test suites of
+//! clients.
+//!
+//! The fixtures are generated by issuing a fixed list of requests (see
+//! [`fixtures()`]) to the actual route handlers of the test server (in
+//! process, through [`actix_web::test`]), so the fixtures cannot drift from the
+//! behavior of the server. Alongside the main endpoints (and a handful of
+//! filters of each listing endpoint), the fixtures include the error responses
+//! to an unknown route, an unknown entity, and an invalid filter.
+//!
+//! Each fixture is written as a JSON file named after the fixture (e.g.,
+//! `subjects-sex-f.json`) within the output directory. The layout of each file
+//! is the same as that of a recording (see [`crate::record`]):
+//!
+//! ```json
+//! {
+//!   "request": {
+//!     "method": "GET",
+//!     "path": "/subject",
+//!     "query": "sex=F",
+//!     "headers": { "accept": "application/json" }
+//!   },
+//!   "response": {
+//!     "status": 200,
+//!     "body": { "data": [], "summary": {} }
+//!   }
+//! }
+//! ```
+//!
+//! As such, a directory of fixtures can also be replayed against another
+//! server with the `replay` subcommand.
+
+use std::collections::BTreeMap;
+use std::fs;
+use std::io;
+use std::path::Path;
+use std::path::PathBuf;
+
+use actix_web::http::header::ACCEPT;
+use actix_web::http::StatusCode;
+use actix_web::test::call_service;
+use actix_web::test::init_service;
+use actix_web::test::read_body;
+use actix_web::test::TestRequest;
+use actix_web::web;
+use actix_web::web::Data;
+use actix_web::web::JsonConfig;
+use actix_web::web::PathConfig;
+use actix_web::web::QueryConfig;
+use actix_web::App;
+
+use ccdi_models as models;
+use ccdi_server as server;
+
+use server::canonical;
+use server::filter::scope;
+use server::responses::error;
+use server::routes::aggregate;
+use server::routes::file;
+use server::routes::info;
+use server::routes::metadata;
+use server::routes::namespace;
+use server::routes::namespace::NAMESPACES;
+use server::routes::organization;
+use server::routes::organization::ORGANIZATIONS;
+use server::routes::sample;
+use server::routes::sample_diagnosis;
+use server::routes::search;
+use server::routes::subject;
+use server::routes::subject_diagnosis;
+
+use crate::parse_response;
+use crate::record;
+use crate::record::Recording;
+use crate::ResponseType;
+
+/// The media type requested by every fixture.
+const MEDIA_TYPE: &str = "application/json";
+
+/// A route that no server implements.
+const UNKNOWN_ROUTE: &str = "/ccdi-spec-fixtures/unknown-route";
+
+/// An error related to generating fixtures.
+#[derive(Debug)]
+pub enum Error {
+    /// An input/output error.
+    Io(io::Error),
+
+    /// The output directory already contains fixtures (and overwriting them was
+    /// not requested).
+    NotEmpty(PathBuf),
+
+    /// The server did not respond to a fixture as expected.
+    Unexpected {
+        /// The name of the fixture.
+        name: String,
+
+        /// The reason the response was not expected.
+        reason: String,
+    },
+}
+
+impl std::fmt::Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::Io(err) => write!(f, "i/o error: {err}"),
+            Error::NotEmpty(path) => write!(
+                f,
+                "the output directory already contains fixtures: {} (use `--force` to \
+                 overwrite them)",
+                path.display()
+            ),
+            Error::Unexpected { name, reason } => {
+                write!(f, "unexpected response to the `{name}` fixture: {reason}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+/// A single request (and the response that is expected of it).
+#[derive(Clone, Debug)]
+pub struct Fixture {
+    /// The name of the fixture (which is also the stem of its file name).
+    name: String,
+
+    /// The path (and query) of the request.
+    path: String,
+
+    /// The expected status of the response.
+    status: StatusCode,
+
+    /// The type that the body of the response must parse as.
+    response_type: ResponseType,
+}
+
+impl Fixture {
+    /// Creates a new [`Fixture`] that is expected to respond with a `200 OK`
+    /// that parses as the response type.
+    fn new(name: impl Into<String>, path: impl Into<String>, response_type: ResponseType) -> Self {
+        Self {
+            name: name.into(),
+            path: path.into(),
+            status: StatusCode::OK,
+            response_type,
+        }
+    }
+
+    /// Creates a new [`Fixture`] that is expected to respond with an error
+    /// response with the status.
+    fn error(name: impl Into<String>, path: impl Into<String>, status: StatusCode) -> Self {
+        Self {
+            name: name.into(),
+            path: path.into(),
+            status,
+            response_type: ResponseType::Errors,
+        }
+    }
+
+    /// Gets the name of the [`Fixture`].
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Gets the name of the file that the [`Fixture`] is written to.
+    pub fn file_name(&self) -> String {
+        format!("{}.json", self.name)
+    }
+
+    /// Gets the expected status of the response to the [`Fixture`].
+    pub fn status(&self) -> StatusCode {
+        self.status
+    }
+
+    /// Gets the type that the body of the response to the [`Fixture`] must
+    /// parse as.
+    pub fn response_type(&self) -> ResponseType {
+        self.response_type.clone()
+    }
+
+    /// Gets the request of the [`Fixture`] in its recorded form.
+    fn request(&self) -> record::Request {
+        let (path, query) = self.path.split_once('?').unwrap_or((&self.path, ""));
+
+        record::Request {
+            method: String::from("GET"),
+            path: path.to_string(),
+            query: query.to_string(),
+            headers: BTreeMap::from([(ACCEPT.to_string(), String::from(MEDIA_TYPE))]),
+        }
+    }
+}
+
+/// Gets the path of a single entity.
+fn entity(entity: &str, namespace: &models::namespace::Identifier, name: impl ToString) -> String {
+    format!(
+        "/{entity}/{}/{}",
+        scope::namespace(namespace),
+        name.to_string()
+    )
+}
+
+/// Gets the fixtures (in the order in which they are generated).
+///
+/// The fixtures of single entities request the first of each entity, so these
+/// fixtures are skipped when there are no entities of that kind. Likewise, the
+/// disease phase fixture filters by the disease phase of the first sample that
+/// has one, so it is skipped when no sample has a disease phase.
+pub fn fixtures(
+    subjects: &[models::Subject],
+    samples: &[models::Sample],
+    files: &[models::File],
+) -> Vec<Fixture> {
+    let mut fixtures = vec![
+        Fixture::new("subjects", "/subject", ResponseType::Subjects),
+        Fixture::new("subjects-sex-f", "/subject?sex=F", ResponseType::Subjects),
+        Fixture::new(
+            "subjects-page-2",
+            "/subject?page=2&per_page=5",
+            ResponseType::Subjects,
+        ),
+        Fixture::new(
+            "subjects-by-sex-count",
+            "/subject/by/sex/count",
+            ResponseType::SubjectsByCount,
+        ),
+        Fixture::new(
+            "subjects-summary",
+            "/subject/summary",
+            ResponseType::Summary,
+        ),
+        Fixture::new("samples", "/sample", ResponseType::Samples),
+        Fixture::new(
+            "samples-by-tissue-type-count",
+            "/sample/by/tissue_type/count",
+            ResponseType::SamplesByCount,
+        ),
+        Fixture::new("samples-summary", "/sample/summary", ResponseType::Summary),
+        Fixture::new("files", "/file", ResponseType::Files),
+        Fixture::new("files-type-bam", "/file?type=BAM", ResponseType::Files),
+        Fixture::new(
+            "files-by-type-count",
+            "/file/by/type/count",
+            ResponseType::FilesByCount,
+        ),
+        Fixture::new("files-summary", "/file/summary", ResponseType::Summary),
+        Fixture::new("search-sample", "/search?q=sample", ResponseType::Search),
+    ];
+
+    if let Some(subject) = subjects.first() {
+        let id = subject.id();
+        fixtures.push(Fixture::new(
+            "subject",
+            entity("subject", id.namespace(), id.name()),
+            ResponseType::Subject,
+        ));
+    }
+
+    if let Some(sample) = samples.first() {
+        let id = sample.id();
+        fixtures.push(Fixture::new(
+            "sample",
+            entity("sample", id.namespace(), id.name()),
+            ResponseType::Sample,
+        ));
+    }
+
+    // NOTE: the disease phase is taken from the samples themselves (rather
+    // than fixed) so that the filter always matches at least one sample.
+    if let Some(disease_phase) = samples.iter().find_map(|sample| {
+        sample
+            .metadata()
+            .and_then(|metadata| metadata.disease_phase())
+    }) {
+        let query = url::form_urlencoded::Serializer::new(String::new())
+            .append_pair("disease_phase", &disease_phase.value().to_string())
+            .finish();
+
+        fixtures.push(Fixture::new(
+            "samples-disease-phase",
+            format!("/sample?{query}"),
+            ResponseType::Samples,
+        ));
+    }
+
+    if let Some(file) = files.first() {
+        let id = file.id();
+        fixtures.push(Fixture::new(
+            "file",
+            entity("file", id.namespace(), id.name()),
+            ResponseType::File,
+        ));
+    }
+
+    fixtures.extend(
+        ["subject", "sample", "file", "namespace", "organization"]
+            .into_iter()
+            .map(|entity| {
+                Fixture::new(
+                    format!("metadata-fields-{entity}"),
+                    format!("/metadata/fields/{entity}"),
+                    ResponseType::FieldDescriptions,
+                )
+            }),
+    );
+
+    fixtures.extend([
+        Fixture::new("namespaces", "/namespace", ResponseType::Namespaces),
+        Fixture::new(
+            "organizations",
+            "/organization",
+            ResponseType::Organizations,
+        ),
+        Fixture::new("information", "/info", ResponseType::Information),
+        Fixture::new("versions", "/info/versions", ResponseType::Versions),
+    ]);
+
+    if let Some(namespace) = NAMESPACES.values().next() {
+        fixtures.push(Fixture::new(
+            "namespace",
+            format!("/namespace/{}", scope::namespace(namespace.id())),
+            ResponseType::Namespace,
+        ));
+    }
+
+    if let Some(organization) = ORGANIZATIONS.keys().next() {
+        fixtures.push(Fixture::new(
+            "organization",
+            format!("/organization/{organization}"),
+            ResponseType::Organization,
+        ));
+    }
+
+    fixtures.extend([
+        Fixture::error("error-unknown-route", UNKNOWN_ROUTE, StatusCode::NOT_FOUND),
+        Fixture::error(
+            "error-unknown-subject",
+            "/subject/example-organization/ExampleNamespaceOne/ccdi-spec-fixtures-unknown",
+            StatusCode::NOT_FOUND,
+        ),
+        // NOTE: `{"gt":1,"lt":0}` (an empty range).
+        Fixture::error(
+            "error-invalid-filter",
+            "/subject?age_at_vital_status=%7B%22gt%22%3A1%2C%22lt%22%3A0%7D",
+            StatusCode::UNPROCESSABLE_ENTITY,
+        ),
+    ]);
+
+    fixtures
+}
+
+/// Returns whether a directory contains any fixtures (or any other JSON
+/// files).
+fn contains_fixtures(directory: &Path) -> io::Result<bool> {
+    for entry in fs::read_dir(directory)? {
+        let name = entry?.file_name();
+
+        if name.to_string_lossy().ends_with(".json") {
+            return Ok(true);
+        }
+    }
+
+    Ok(false)
+}
+
+/// Generates every fixture (see [`fixtures()`]) against the provided stores
+/// and writes them to `directory` (creating it if it does not exist).
+///
+/// Unless `force` is set, an error is returned if the directory already
+/// contains fixtures. An error is also returned if the server does not respond
+/// to a fixture with the expected status or if the body of the response does
+/// not parse as the expected response type. The fixtures that were written are
+/// returned (in the order in which they were generated).
+pub async fn generate(
+    directory: &Path,
+    force: bool,
+    subjects: Data<subject::Store>,
+    samples: Data<sample::Store>,
+    files: Data<file::Store>,
+) -> Result<Vec<Fixture>, Error> {
+    fs::create_dir_all(directory).map_err(Error::Io)?;
+
+    if !force && contains_fixtures(directory).map_err(Error::Io)? {
+        return Err(Error::NotEmpty(directory.to_path_buf()));
+    }
+
+    let fixtures = fixtures(
+        &subjects.subjects.lock().unwrap(),
+        &samples.samples.lock().unwrap(),
+        &files.files.lock().unwrap(),
+    );
+
+    let app = init_service(
+        App::new()
+            .app_data(QueryConfig::default().error_handler(error::from_query_error))
+            .app_data(PathConfig::default().error_handler(error::from_path_error))
+            .app_data(JsonConfig::default().error_handler(error::from_json_error))
+            .configure(subject::configure(subjects.clone()))
+            .configure(sample::configure(samples.clone()))
+            .configure(file::configure(files.clone()))
+            .configure(aggregate::configure(
+                subjects.clone(),
+                samples.clone(),
+                files.clone(),
+            ))
+            .configure(search::configure(
+                subjects.clone(),
+                samples.clone(),
+                files.clone(),
+            ))
+            .configure(metadata::configure())
+            .configure(namespace::configure())
+            .configure(organization::configure())
+            .configure(info::configure())
+            .configure(sample_diagnosis::configure(samples.clone()))
+            .configure(subject_diagnosis::configure(subjects.clone()))
+            .default_service(web::to(canonical::fallback)),
+    )
+    .await;
+
+    for fixture in &fixtures {
+        let request = TestRequest::get()
+            .uri(&fixture.path)
+            .insert_header((ACCEPT, MEDIA_TYPE))
+            .to_request();
+
+        let response = call_service(&app, request).await;
+        let status = response.status();
+        let body = read_body(response).await;
+
+        let unexpected = |reason: String| Error::Unexpected {
+            name: fixture.name().to_string(),
+            reason,
+        };
+
+        if status != fixture.status() {
+            return Err(unexpected(format!(
+                "expected status {}, but received {status}",
+                fixture.status()
+            )));
+        }
+
+        parse_response(&String::from_utf8_lossy(&body), fixture.response_type())
+            .map_err(|err| unexpected(format!("parse error: {err}")))?;
+
+        let recording = Recording {
+            request: fixture.request(),
+            response: record::Response {
+                status: status.as_u16(),
+                body: record::parse_body(&body),
+            },
+        };
+
+        // SAFETY: a [`Recording`] is always serializable to JSON.
+        let mut contents = serde_json::to_vec_pretty(&recording).unwrap();
+        contents.push(b'\n');
+
+        fs::write(directory.join(fixture.file_name()), contents).map_err(Error::Io)?;
+    }
+
+    Ok(fixtures)
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::Value;
+
+    use super::*;
+
+    fn directory(name: &str) -> PathBuf {
+        std::env::temp_dir().join(format!("ccdi-spec-{name}-{}", std::process::id()))
+    }
+
+    fn stores() -> (Data<subject::Store>, Data<sample::Store>, Data<file::Store>) {
+        let subjects = subject::Store::random(10);
+        let samples = sample::Store::random(10, subjects.subjects.lock().unwrap());
+        let files = file::Store::random(10, samples.samples.lock().unwrap());
+
+        (Data::new(subjects), Data::new(samples), Data::new(files))
+    }
+
+    #[actix_web::test]
+    async fn every_fixture_parses_as_its_response_type() {
+        let directory = directory("fixtures");
+        let _ = fs::remove_dir_all(&directory);
+
+        let (subjects, samples, files) = stores();
+        let generated = generate(&directory, false, subjects, samples, files)
+            .await
+            .unwrap();
+
+        let recordings = record::read(&directory)
+            .unwrap()
+            .into_iter()
+            .collect::<BTreeMap<_, _>>();
+
+        fs::remove_dir_all(&directory).unwrap();
+
+        assert_eq!(recordings.len(), generated.len());
+
+        for fixture in &generated {
+            let recording = &recordings[&fixture.file_name()];
+
+            assert_eq!(
+                recording.response.status,
+                fixture.status().as_u16(),
+                "{}",
+                fixture.name()
+            );
+
+            if let Err(err) = parse_response(
+                &recording.response.body.to_string(),
+                fixture.response_type(),
+            ) {
+                panic!("{}: {err}", fixture.name());
+            }
+        }
+
+        let kind = |name: &str| {
+            recordings[&format!("{name}.json")].response.body["errors"][0]["kind"].clone()
+        };
+
+        assert_eq!(kind("error-unknown-route"), Value::from("InvalidRoute"));
+        assert_eq!(kind("error-unknown-subject"), Value::from("NotFound"));
+        assert_eq!(
+            kind("error-invalid-filter"),
+            Value::from("InvalidParameters")
+        );
+
+        let request = &recordings["subjects-sex-f.json"].request;
+        assert_eq!(request.path_and_query(), "/subject?sex=F");
+        assert_eq!(request.headers["accept"], MEDIA_TYPE);
+    }
+
+    #[actix_web::test]
+    async fn it_only_overwrites_fixtures_when_forced() {
+        let directory = directory("fixtures-force");
+        let _ = fs::remove_dir_all(&directory);
+
+        let (subjects, samples, files) = stores();
+
+        generate(
+            &directory,
+            false,
+            subjects.clone(),
+            samples.clone(),
+            files.clone(),
+        )
+        .await
+        .unwrap();
+
+        let err = generate(
+            &directory,
+            false,
+            subjects.clone(),
+            samples.clone(),
+            files.clone(),
+        )
+        .await
+        .unwrap_err();
+        assert!(matches!(err, Error::NotEmpty(_)));
+
+        let result = generate(&directory, true, subjects, samples, files).await;
+        fs::remove_dir_all(&directory).unwrap();
+
+        assert!(result.is_ok());
+    }
+}
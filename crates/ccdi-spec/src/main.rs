@@ -1,17 +1,26 @@
-use std::fs::File;
+use std::collections::BTreeMap;
 use std::io;
 use std::net::Ipv4Addr;
+use std::num::NonZeroUsize;
+use std::path::Path;
 use std::path::PathBuf;
+use std::sync::Arc;
+use std::time::Duration;
+use std::time::Instant;
 
-use actix_web::error::QueryPayloadError;
+use actix_web::dev::Service as _;
+use actix_web::http::header;
+use actix_web::middleware::Compress;
+use actix_web::middleware::Condition;
+use actix_web::middleware::DefaultHeaders;
 use actix_web::middleware::Logger;
 use actix_web::rt;
 use actix_web::web;
 use actix_web::web::Data;
+use actix_web::web::JsonConfig;
+use actix_web::web::PathConfig;
 use actix_web::web::QueryConfig;
 use actix_web::App;
-use actix_web::HttpRequest;
-use actix_web::HttpResponse;
 use actix_web::HttpServer;
 use ccdi_models::sample::metadata::AnatomicalSite;
 use clap::Parser;
@@ -19,37 +28,78 @@ use clap::Subcommand;
 use clap::ValueEnum;
 use itertools::Itertools as _;
 use log::info;
-#[cfg(not(feature = "all-anatomical-site"))]
 use log::warn;
 use log::LevelFilter;
+use rand::rngs::StdRng;
+use rand::SeedableRng as _;
 use server::routes::file;
 use server::routes::organization;
 use strum::VariantArray;
+use utoipa::Modify as _;
 use utoipa::OpenApi;
 use utoipa_swagger_ui::SwaggerUi;
 
+use ccdi_cde as cde;
 use ccdi_models as models;
 use ccdi_openapi as api;
 use ccdi_server as server;
 
+use api::servers::Server;
+use api::servers::Servers;
 use api::Api;
+use cde::parse::cde::lint::lint_registry;
+use cde::parse::cde::snapshot;
+use cde::parse::cde::snapshot::snapshot_registry;
+use cde::parse::cde::snapshot::Snapshot;
+use cde::translations::LoadError;
+use cde::translations::Translations;
+use models::metadata::field::description::unharmonized::ValueKind;
+use models::metadata::Generation;
+use models::metadata::DEFAULT_METADATA_LESS_FRACTION;
+use models::validation;
+use models::Sample;
 
+use server::canonical;
+use server::dataset;
+use server::dataset::Dataset;
+use server::expand;
+use server::integrity;
+use server::metrics::Metrics;
+use server::metrics::Registry;
+use server::paginate;
 use server::responses::error;
-use server::responses::Errors;
+use server::routes::aggregate;
 use server::routes::info;
 use server::routes::metadata;
+use server::routes::metrics;
 use server::routes::namespace;
 use server::routes::sample;
 use server::routes::sample_diagnosis;
+use server::routes::search;
 use server::routes::subject;
 use server::routes::subject_diagnosis;
+use server::strict;
+use server::suggest;
+use server::uberon;
+use server::values;
 
+mod check;
+mod conformance;
+mod consistency;
+mod diff;
+mod fields;
+mod fixtures;
+mod output;
+mod record;
+mod replay;
 mod utils;
 
+use output::Detail;
+use output::Format;
+use output::Outcome;
+use output::Status;
 use utils::markdown;
 
-const ERROR_EXIT_CODE: i32 = 1;
-
 #[derive(Clone, Debug, clap::ValueEnum)]
 pub enum Entity {
     /// A subject.
@@ -60,6 +110,9 @@ pub enum Entity {
 
     /// A file.
     File,
+
+    /// An index of every common data element (rather than a single entity).
+    Index,
 }
 
 /// An error related to the main program.
@@ -70,6 +123,10 @@ pub enum Error {
 
     /// An input/output error.
     IoError(io::Error),
+
+    /// The number of server descriptions did not match the number of server
+    /// URLs.
+    MismatchedServerDescriptions(usize, usize),
 }
 
 impl std::fmt::Display for Error {
@@ -77,6 +134,11 @@ impl std::fmt::Display for Error {
         match self {
             Error::FileExists(path) => write!(f, "file already exists: {}", path.display()),
             Error::IoError(err) => write!(f, "i/o error: {err}"),
+            Error::MismatchedServerDescriptions(urls, descriptions) => write!(
+                f,
+                "{descriptions} server description(s) were provided for {urls} server url(s): \
+                 either provide a description for every server url or provide none"
+            ),
         }
     }
 }
@@ -92,6 +154,55 @@ pub struct GenerateArgs {
     /// Whether to force the output file to be overwritten (if it exists).
     #[arg(short, long)]
     force: bool,
+
+    /// The URL of a server to add to the specification (can be repeated).
+    #[arg(long = "server-url")]
+    server_urls: Vec<String>,
+
+    /// The description of each server added with `--server-url` (can be
+    /// repeated, and descriptions are matched to URLs in order).
+    #[arg(long = "server-description")]
+    server_descriptions: Vec<String>,
+
+    /// A TOML file listing servers to add to the specification (added before
+    /// any servers provided with `--server-url`).
+    #[arg(long)]
+    servers_config: Option<PathBuf>,
+
+    /// Whether the added servers replace the servers listed by default.
+    #[arg(long)]
+    replace_default_servers: bool,
+
+    /// The format of the generated specification.
+    #[arg(long = "format", value_enum, default_value_t = SpecificationFormat::Yaml)]
+    specification_format: SpecificationFormat,
+}
+
+/// The format in which the OpenAPI specification is generated.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, ValueEnum)]
+pub enum SpecificationFormat {
+    /// YAML (the format of the committed `swagger.yml`).
+    #[default]
+    Yaml,
+
+    /// Pretty-printed JSON.
+    Json,
+}
+
+impl SpecificationFormat {
+    /// Renders the specification in the [`SpecificationFormat`].
+    ///
+    /// Both formats serialize the keys of the specification in the same
+    /// (stable) order, so the JSON and YAML documents only differ in syntax.
+    fn render(&self, api: &utoipa::openapi::OpenApi) -> Result<String, output::Error> {
+        match self {
+            SpecificationFormat::Yaml => api.to_yaml().map_err(output::Error::failure),
+            SpecificationFormat::Json => api
+                .to_pretty_json()
+                .map(|json| json + "\n")
+                .map_err(output::Error::failure),
+        }
+    }
 }
 
 #[derive(Clone, Debug, clap::ValueEnum)]
@@ -100,17 +211,25 @@ pub enum ResponseType {
     Samples,
     Sample,
     SamplesByCount,
+    SamplesByGroup,
     Subjects,
     Subject,
     SubjectsByCount,
     Files,
+    File,
+    FilesByCount,
+    FileManifest,
     Namespaces,
     Namespace,
+    NamespaceSummary,
     Organizations,
     Organization,
     Summary,
     Information,
+    Versions,
     FieldDescriptions,
+    Values,
+    Search,
     Errors,
 }
 
@@ -129,6 +248,10 @@ fn parse_response(
             serde_json::from_str::<server::responses::by::count::sample::Results>(text)
                 .map(|_| ())?;
         }
+        ResponseType::SamplesByGroup => {
+            serde_json::from_str::<server::responses::by::group::sample::Response>(text)
+                .map(|_| ())?;
+        }
         ResponseType::Subjects => {
             serde_json::from_str::<server::responses::Subjects>(text).map(|_| ())?;
         }
@@ -142,12 +265,25 @@ fn parse_response(
         ResponseType::Files => {
             serde_json::from_str::<server::responses::Files>(text).map(|_| ())?;
         }
+        ResponseType::File => {
+            serde_json::from_str::<server::responses::File>(text).map(|_| ())?;
+        }
+        ResponseType::FilesByCount => {
+            serde_json::from_str::<server::responses::by::count::file::Response>(text)
+                .map(|_| ())?;
+        }
+        ResponseType::FileManifest => {
+            serde_json::from_str::<server::responses::FileManifest>(text).map(|_| ())?;
+        }
         ResponseType::Namespaces => {
             serde_json::from_str::<server::responses::Namespaces>(text).map(|_| ())?;
         }
         ResponseType::Namespace => {
             serde_json::from_str::<server::responses::Namespace>(text).map(|_| ())?;
         }
+        ResponseType::NamespaceSummary => {
+            serde_json::from_str::<server::responses::namespace::Summary>(text).map(|_| ())?;
+        }
         ResponseType::Organizations => {
             serde_json::from_str::<server::responses::Organizations>(text).map(|_| ())?;
         }
@@ -160,10 +296,19 @@ fn parse_response(
         ResponseType::Information => {
             serde_json::from_str::<server::responses::Information>(text).map(|_| ())?;
         }
+        ResponseType::Versions => {
+            serde_json::from_str::<server::responses::info::Versions>(text).map(|_| ())?;
+        }
         ResponseType::FieldDescriptions => {
             serde_json::from_str::<server::responses::metadata::FieldDescriptions>(text)
                 .map(|_| ())?;
         }
+        ResponseType::Values => {
+            serde_json::from_str::<server::responses::values::Response>(text).map(|_| ())?;
+        }
+        ResponseType::Search => {
+            serde_json::from_str::<server::responses::Search>(text).map(|_| ())?;
+        }
         ResponseType::Errors => {
             serde_json::from_str::<server::responses::Errors>(text).map(|_| ())?;
         }
@@ -172,6 +317,26 @@ fn parse_response(
     Ok(())
 }
 
+#[derive(Debug, Parser)]
+pub struct CdeSnapshotArgs {
+    /// A path to write the snapshot to.
+    #[arg(short = 'o')]
+    output: Option<PathBuf>,
+
+    /// Whether to force the output file to be overwritten (if it exists).
+    #[arg(short, long)]
+    force: bool,
+}
+
+#[derive(Debug, Parser)]
+pub struct CdeDiffArgs {
+    /// The snapshot of the previous release.
+    old: PathBuf,
+
+    /// The snapshot of the new release.
+    new: PathBuf,
+}
+
 #[derive(Debug, Parser)]
 pub struct CheckArgs {
     /// The URL to retreive.
@@ -179,6 +344,83 @@ pub struct CheckArgs {
 
     /// The type of response to parse.
     response_type: ResponseType,
+
+    /// The maximum number of requests to have in flight at once when checking
+    /// the pages of a paginated endpoint.
+    #[arg(long, default_value_t = NonZeroUsize::new(check::DEFAULT_CONCURRENCY).unwrap())]
+    concurrency: NonZeroUsize,
+
+    /// The number of times to retry a request that fails with a `429` or `5xx`
+    /// status.
+    #[arg(long, default_value_t = check::DEFAULT_RETRIES)]
+    retries: usize,
+
+    /// Whether to follow the `link` header of a paginated endpoint and check
+    /// every page (rather than only the page at the provided URL).
+    ///
+    /// When following pagination, the `link` header of each page and the
+    /// counts reported across pages are also checked for consistency.
+    #[arg(long)]
+    follow_pagination: bool,
+
+    /// The maximum number of pages to check when following pagination.
+    #[arg(long, requires = "follow_pagination")]
+    max_pages: Option<NonZeroUsize>,
+}
+
+#[derive(Debug, Parser)]
+pub struct CheckConsistencyArgs {
+    /// The base URL of the server to probe.
+    url: String,
+
+    /// The number of subjects to probe (the first page of subjects with this
+    /// many subjects per page).
+    #[arg(long, default_value_t = NonZeroUsize::new(consistency::DEFAULT_SUBJECTS).unwrap())]
+    subjects: NonZeroUsize,
+
+    /// The maximum number of requests to have in flight at once when fetching
+    /// the pages of samples.
+    #[arg(long, default_value_t = NonZeroUsize::new(check::DEFAULT_CONCURRENCY).unwrap())]
+    concurrency: NonZeroUsize,
+
+    /// The number of times to retry a request that fails with a `429` or `5xx`
+    /// status.
+    #[arg(long, default_value_t = check::DEFAULT_RETRIES)]
+    retries: usize,
+}
+
+/// The formats in which a conformance report can be written.
+#[derive(Clone, Debug, ValueEnum)]
+pub enum ReportFormat {
+    /// A table for humans.
+    Table,
+
+    /// A JSON document.
+    Json,
+}
+
+#[derive(Debug, Parser)]
+pub struct ConformanceArgs {
+    /// The base URL of the server to check.
+    #[arg(long)]
+    base_url: String,
+
+    /// The maximum number of requests to have in flight at once.
+    #[arg(long, default_value_t = NonZeroUsize::new(check::DEFAULT_CONCURRENCY).unwrap())]
+    concurrency: NonZeroUsize,
+
+    /// The number of times to retry a request that fails with a `429` or `5xx`
+    /// status.
+    #[arg(long, default_value_t = check::DEFAULT_RETRIES)]
+    retries: usize,
+
+    /// The number of seconds after which a request is abandoned.
+    #[arg(long, default_value_t = conformance::DEFAULT_TIMEOUT_SECS)]
+    timeout: u64,
+
+    /// The format in which to write the report.
+    #[arg(long, value_enum, default_value_t = ReportFormat::Table)]
+    report: ReportFormat,
 }
 
 /// Entities that can be exported.
@@ -188,6 +430,23 @@ pub enum ExportEntity {
     AnatomicalSite,
 }
 
+#[derive(Debug, Parser)]
+pub struct DiffFieldsArgs {
+    /// The specification of the previous version (YAML, or JSON when the path
+    /// ends in `.json`).
+    #[arg(long)]
+    from: PathBuf,
+
+    /// The specification of the new version (YAML, or JSON when the path ends
+    /// in `.json`).
+    #[arg(long)]
+    to: PathBuf,
+
+    /// The format in which to write the changes.
+    #[arg(long = "format", value_enum, default_value_t = fields::Format::Markdown)]
+    changes_format: fields::Format,
+}
+
 #[derive(Debug, Parser)]
 pub struct ExportArgs {
     /// The entity to be exported.
@@ -195,22 +454,180 @@ pub struct ExportArgs {
 }
 
 #[derive(Debug, Parser)]
-pub struct ServeArgs {
-    /// Number of subjects for the server to generate.
+pub struct GenerationArgs {
+    /// Number of subjects to generate.
     #[arg(default_value_t = 100)]
     number_of_subjects: usize,
 
-    /// Number of samples for the server to generate.
+    /// Number of samples to generate.
     #[arg(default_value_t = 100)]
     number_of_samples: usize,
 
-    /// Number of files for the server to generate.
+    /// Number of files to generate.
     #[arg(default_value_t = 1000)]
     number_of_files: usize,
 
+    /// The fraction of generated entities that have no metadata (between `0.0`
+    /// and `1.0`).
+    #[arg(long, default_value_t = DEFAULT_METADATA_LESS_FRACTION, value_parser = parse_fraction)]
+    metadata_less_fraction: f64,
+
+    /// Whether to generate every metadata field independently rather than
+    /// generating related fields jointly (the resulting metadata is frequently
+    /// contradictory, which is useful for fuzzing clients).
+    #[arg(long)]
+    independent_metadata: bool,
+
+    /// A seed for generating the entities. The same seed (and the same
+    /// numbers of entities) always generates the same entities. When omitted,
+    /// different entities are generated on every run.
+    #[arg(long)]
+    seed: Option<u64>,
+}
+
+#[derive(Debug, Parser)]
+pub struct DumpArgs {
+    #[command(flatten)]
+    generation: GenerationArgs,
+
+    /// A path to write the dataset to. The dataset is written as NDJSON when
+    /// the path ends in `.ndjson` or `.jsonl` (and as JSON otherwise).
+    #[arg(short = 'o')]
+    output: Option<PathBuf>,
+
+    /// Whether to force the output file to be overwritten (if it exists).
+    #[arg(short, long)]
+    force: bool,
+
+    /// Whether to write the dataset as NDJSON regardless of the output path.
+    #[arg(long)]
+    ndjson: bool,
+}
+
+#[derive(Debug, Parser)]
+pub struct FixturesArgs {
+    #[command(flatten)]
+    generation: GenerationArgs,
+
+    /// The directory to write the fixtures to (created if it does not exist).
+    #[arg(long)]
+    output_dir: PathBuf,
+
+    /// Whether to overwrite the fixtures within the output directory (if any
+    /// exist).
+    #[arg(short, long)]
+    force: bool,
+}
+
+#[derive(Debug, Parser)]
+pub struct ServeArgs {
+    #[command(flatten)]
+    generation: GenerationArgs,
+
+    /// A dataset of subjects, samples, and files (in their wire formats) to
+    /// serve instead of generating random entities. The dataset is read as
+    /// NDJSON when the path ends in `.ndjson` or `.jsonl` (and as JSON
+    /// otherwise). See the `dump` subcommand for an example of the format.
+    #[arg(long)]
+    data: Option<PathBuf>,
+
     /// Port to run the server on.
     #[arg(short = 'p', default_value_t = 8000)]
     port: u16,
+
+    /// Whether to suggest similar identifiers when an entity is not found.
+    #[arg(long)]
+    suggestions: bool,
+
+    /// The maximum estimated cost of an expanded page (the page size
+    /// multiplied by the expansion factor). Expanded requests over this budget
+    /// are rejected.
+    #[arg(long, default_value_t = expand::DEFAULT_BUDGET)]
+    expand_budget: usize,
+
+    /// The maximum number of entities per page of a paginated endpoint.
+    /// Requests for more entities per page are rejected (rather than
+    /// clamped).
+    #[arg(long, default_value_t = paginate::Config::default().max_per_page())]
+    max_per_page: NonZeroUsize,
+
+    /// The maximum number of distinct values listed by the
+    /// `/{entity}/values/{field}` endpoints. Fields with more distinct values
+    /// are truncated (and marked with `truncated: true`).
+    #[arg(long, default_value_t = values::DEFAULT_LIMIT)]
+    values_limit: usize,
+
+    /// A TOML file containing translations for the labels of permissible
+    /// values (the embedded translations are used if not provided).
+    #[arg(long)]
+    translations: Option<PathBuf>,
+
+    /// A curated Uberon hierarchy (as written by `ccdi-curate
+    /// uberon-hierarchy`) used to roll anatomical sites up to an ancestor term
+    /// when counting samples by `anatomical_sites`.
+    #[arg(long)]
+    uberon_hierarchy: Option<PathBuf>,
+
+    /// Whether to skip checking the referential integrity of the entities
+    /// before serving (useful for intentionally partial datasets).
+    #[arg(long)]
+    skip_integrity_check: bool,
+
+    /// A directory to record each request and its response to (as numbered
+    /// JSON files that can be re-issued with the `replay` subcommand).
+    #[arg(long)]
+    record: Option<PathBuf>,
+
+    /// The maximum number of requests to record.
+    #[arg(long, default_value_t = record::DEFAULT_MAX_FILES)]
+    record_max_files: usize,
+
+    /// Whether to compress responses (with gzip, brotli, or zstd) for clients
+    /// that send an `Accept-Encoding` header (`--compression false` disables
+    /// compression).
+    #[arg(long, default_value_t = true, action = clap::ArgAction::Set)]
+    compression: bool,
+
+    /// Whether to record the number of requests, the number of errors, and
+    /// the latency of each route and serve them (in the Prometheus text
+    /// format) at `/metrics`.
+    #[arg(long)]
+    metrics: bool,
+
+    /// Whether to reject query parameters that are not recognized by an
+    /// endpoint (`--strict-params false` ignores them instead, logging each
+    /// and describing them within the `warnings` of the listings).
+    #[arg(long, default_value_t = true, action = clap::ArgAction::Set)]
+    strict_params: bool,
+}
+
+/// Parses a fraction between `0.0` and `1.0` (inclusive).
+fn parse_fraction(s: &str) -> Result<f64, String> {
+    let fraction = s
+        .parse::<f64>()
+        .map_err(|err| format!("invalid fraction: {err}"))?;
+
+    match (0.0..=1.0).contains(&fraction) {
+        true => Ok(fraction),
+        false => Err(format!("fraction must be between 0.0 and 1.0: {fraction}")),
+    }
+}
+
+#[derive(Debug, Parser)]
+pub struct ReplayArgs {
+    /// The directory containing the recorded requests.
+    directory: PathBuf,
+
+    /// The base URL of the server to replay the requests against.
+    #[arg(long)]
+    base_url: String,
+}
+
+#[derive(Debug, Parser)]
+pub struct ValidateArgs {
+    /// A path to the dataset (as written by the `dump` subcommand) to validate.
+    #[arg(long)]
+    data: PathBuf,
 }
 
 #[derive(Debug, Parser)]
@@ -221,176 +638,992 @@ pub struct WikiArgs {
 
 #[derive(Debug, Subcommand)]
 pub enum Command {
+    /// Compares two snapshots of the common data elements and reports the
+    /// changes to each common data element.
+    CdeDiff(CdeDiffArgs),
+
+    /// Writes a snapshot of every common data element (for comparing between
+    /// releases with the `cde-diff` subcommand).
+    CdeSnapshot(CdeSnapshotArgs),
+
     /// Checks that a URL matches the specification.
     Check(CheckArgs),
 
+    /// Probes a server for inconsistencies between the metadata of subjects
+    /// and the samples collected from them (e.g., a post-mortem sample from a
+    /// living subject).
+    CheckConsistency(CheckConsistencyArgs),
+
+    /// Runs every endpoint of the specification (including the expected
+    /// errors and pagination) against a server and reports the result of
+    /// each check.
+    Conformance(ConformanceArgs),
+
+    /// Compares the harmonized fields of two versions of the specification
+    /// and reports the fields that were added, removed, renamed, or changed.
+    DiffFields(DiffFieldsArgs),
+
+    /// Writes a randomly generated set of subjects, samples, and files as a
+    /// dataset that can be edited and then served with `serve --data`.
+    Dump(DumpArgs),
+
     /// Exports a particular entity to an external file.
     Export(ExportArgs),
 
+    /// Writes canned request and response fixtures (generated by the test
+    /// server from randomly generated entities) for the test suites of
+    /// clients. Pass `--seed` to generate the same fixtures on every run.
+    Fixtures(FixturesArgs),
+
     /// Generate the OpenAPI specification.
     Generate(GenerateArgs),
 
+    /// Lints the documentation of every common data element.
+    LintCdes,
+
+    /// Replays recorded requests against a server and reports any
+    /// differences from the recorded responses.
+    Replay(ReplayArgs),
+
     /// Runs the test server.
     Serve(ServeArgs),
 
+    /// Validates the longitudinal consistency of the subjects and samples
+    /// within a dataset (e.g., a relapse sample collected before the initial
+    /// diagnosis) and reports a warning for each anomaly.
+    Validate(ValidateArgs),
+
     /// Generates the documentation for the wiki page.
     Wiki(WikiArgs),
 }
 
+impl Command {
+    /// Gets the name of the subcommand.
+    fn name(&self) -> &'static str {
+        match self {
+            Command::CdeDiff(_) => "cde-diff",
+            Command::CdeSnapshot(_) => "cde-snapshot",
+            Command::Check(_) => "check",
+            Command::CheckConsistency(_) => "check-consistency",
+            Command::Conformance(_) => "conformance",
+            Command::DiffFields(_) => "diff-fields",
+            Command::Dump(_) => "dump",
+            Command::Export(_) => "export",
+            Command::Fixtures(_) => "fixtures",
+            Command::Generate(_) => "generate",
+            Command::LintCdes => "lint-cdes",
+            Command::Replay(_) => "replay",
+            Command::Serve(_) => "serve",
+            Command::Validate(_) => "validate",
+            Command::Wiki(_) => "wiki",
+        }
+    }
+}
+
 /// A program to generate the Childhood Cancer Data Initiative OpenAPI
 /// specification.
 #[derive(Parser, Debug)]
-#[command(author, version, about, long_about)]
+#[command(author, version, about, long_about, after_help = output::EXIT_CODES_HELP)]
 struct Args {
+    /// The format in which to write the outcome of the subcommand to stdout
+    /// (logging is always written to stderr).
+    #[arg(long = "output", value_enum, default_value_t = Format::Text, global = true)]
+    format: Format,
+
+    /// Whether to only log errors.
+    #[arg(short, long, global = true)]
+    quiet: bool,
+
     /// The subcommand to execute.
     #[command(subcommand)]
     command: Command,
 }
 
-fn get_output(path: Option<PathBuf>, force: bool) -> Result<Box<dyn std::io::Write>, Error> {
+/// Writes a document produced by a subcommand to the provided path or, if no
+/// path is provided, includes the document within the [`Outcome`].
+fn write_document(
+    path: Option<PathBuf>,
+    force: bool,
+    document: String,
+    summary: impl Into<String>,
+) -> Result<Outcome, output::Error> {
+    let summary = summary.into();
+
     match path {
         Some(path) => {
             if !force && path.exists() {
-                return Err(Error::FileExists(path));
+                return Err(output::Error::usage(Error::FileExists(path)));
             }
 
-            File::create(path)
-                .map(|file| Box::new(file) as Box<dyn std::io::Write>)
-                .map_err(Error::IoError)
+            std::fs::write(&path, document)
+                .map_err(|err| output::Error::network(Error::IoError(err)))?;
+
+            Ok(Outcome::success(format!(
+                "{summary} (written to {})",
+                path.display()
+            )))
         }
-        None => Ok(Box::new(std::io::stdout())),
+        None => Ok(Outcome::success(summary).with_output(document)),
     }
 }
 
-fn inner() -> Result<(), Box<dyn std::error::Error>> {
-    let args = Args::parse();
+/// Gets the servers to add to the specification from the arguments to the
+/// `generate` subcommand.
+fn get_servers(args: &GenerateArgs) -> Result<Servers, output::Error> {
+    let mut servers = match &args.servers_config {
+        Some(path) => {
+            let contents = std::fs::read_to_string(path)
+                .map_err(|err| output::Error::network(Error::IoError(err)))?;
+            Servers::from_toml(&contents).map_err(output::Error::usage)?
+        }
+        None => Servers::default(),
+    };
 
-    env_logger::builder()
-        .filter_level(LevelFilter::Debug)
-        .init();
+    if !args.server_descriptions.is_empty()
+        && args.server_descriptions.len() != args.server_urls.len()
+    {
+        return Err(output::Error::usage(Error::MismatchedServerDescriptions(
+            args.server_urls.len(),
+            args.server_descriptions.len(),
+        )));
+    }
+
+    for (i, url) in args.server_urls.iter().enumerate() {
+        servers.push(
+            Server::try_new(url.clone(), args.server_descriptions.get(i).cloned())
+                .map_err(output::Error::usage)?,
+        );
+    }
+
+    if args.replace_default_servers {
+        servers.replace_defaults(true);
+    }
+
+    Ok(servers)
+}
+
+/// Reads a [`Snapshot`] of the common data elements from a file.
+fn read_snapshot(path: &Path) -> Result<Snapshot, output::Error> {
+    let contents =
+        std::fs::read_to_string(path).map_err(|err| output::Error::network(Error::IoError(err)))?;
+
+    serde_json::from_str(&contents).map_err(|err| {
+        output::Error::usage(format!("invalid snapshot `{}`: {err}", path.display()))
+    })
+}
+
+/// Reads a generated OpenAPI specification from a file.
+fn read_specification(path: &Path) -> Result<serde_json::Value, output::Error> {
+    let contents =
+        std::fs::read_to_string(path).map_err(|err| output::Error::network(Error::IoError(err)))?;
 
-    match args.command {
-        Command::Check(args) => {
-            let response = reqwest::blocking::get(&args.url)?;
-            let text = response.text()?;
-            parse_response(&text, args.response_type)?;
-            println!("Success!");
+    let result = match path.extension().and_then(|extension| extension.to_str()) {
+        Some("json") => serde_json::from_str(&contents).map_err(|err| err.to_string()),
+        _ => serde_yaml::from_str(&contents).map_err(|err| err.to_string()),
+    };
+
+    result.map_err(|err| {
+        output::Error::usage(format!("invalid specification `{}`: {err}", path.display()))
+    })
+}
+
+fn diff_fields(args: DiffFieldsArgs) -> Result<Outcome, output::Error> {
+    let old = fields::extract(&read_specification(&args.from)?).map_err(output::Error::usage)?;
+    let new = fields::extract(&read_specification(&args.to)?).map_err(output::Error::usage)?;
+
+    let changes = fields::diff(&old, &new);
+
+    Ok(Outcome::success(format!(
+        "compared the harmonized fields of `{}` to `{}`: {} change(s)",
+        args.from.display(),
+        args.to.display(),
+        changes.len()
+    ))
+    .with_output(fields::render(&changes, args.changes_format)))
+}
+
+fn cde_diff(args: CdeDiffArgs) -> Result<Outcome, output::Error> {
+    let old = read_snapshot(&args.old)?;
+    let new = read_snapshot(&args.new)?;
+
+    let changes = snapshot::diff(&old, &new);
+
+    let details = changes
+        .iter()
+        .flat_map(|(name, changes)| {
+            changes
+                .iter()
+                .map(move |change| Detail::new(name.clone(), change.to_string()))
+        })
+        .collect();
+
+    Ok(Outcome::success(format!(
+        "compared version {} to version {}: {} common data element(s) changed",
+        old.version(),
+        new.version(),
+        changes.len()
+    ))
+    .with_details(details))
+}
+
+fn cde_snapshot(args: CdeSnapshotArgs) -> Result<Outcome, output::Error> {
+    let snapshot = snapshot_registry().map_err(output::Error::failure)?;
+
+    write_document(
+        args.output,
+        args.force,
+        snapshot.to_json(),
+        format!(
+            "took a snapshot of {} common data element(s)",
+            snapshot.elements().len()
+        ),
+    )
+}
+
+async fn run_check(args: &CheckArgs) -> Result<Outcome, output::Error> {
+    let config = check::Config::new(args.concurrency, args.retries)
+        .with_follow_pagination(args.follow_pagination)
+        .with_max_pages(args.max_pages);
+
+    let report = check::check(&args.url, args.response_type.clone(), &config)
+        .await
+        .map_err(|err| match err {
+            check::Error::InvalidUrl(_) => output::Error::usage(err),
+            check::Error::Parse(_) | check::Error::Link(_) | check::Error::Count(_) => {
+                output::Error::new(Status::ConformanceFailure, err)
+            }
+            check::Error::Request(_) | check::Error::Status(_) => output::Error::network(err),
+        })?;
+
+    let details = report
+        .failures()
+        .iter()
+        .flat_map(|(page, failures)| {
+            failures
+                .iter()
+                .map(move |failure| Detail::new(format!("page {page}"), failure.to_string()))
+        })
+        .chain(
+            report
+                .collection()
+                .iter()
+                .map(|failure| Detail::new("collection", failure.to_string())),
+        )
+        .collect();
+
+    // NOTE: when any page could not be fetched, the check is incomplete, so
+    // the network failure takes precedence over any conformance failures.
+    let status = match report.failure_count() {
+        0 => Status::Success,
+        _ if report
+            .failures()
+            .values()
+            .flatten()
+            .chain(report.collection())
+            .all(|failure| failure.is_nonconforming()) =>
+        {
+            Status::ConformanceFailure
         }
+        _ => Status::NetworkFailure,
+    };
 
-        Command::Export(args) => match args.entity {
-            ExportEntity::AnatomicalSite => {
-                let mut wtr = csv::WriterBuilder::new()
-                    .delimiter(b'\t')
-                    .from_writer(io::stdout());
+    let summary = match (status, report.failures().len()) {
+        (Status::Success, _) => format!("checked {} page(s): 0 failure(s)", report.pages()),
+        (_, 0) => format!(
+            "the counts across {} page(s) are inconsistent",
+            report.pages()
+        ),
+        (_, failed) => format!("{} of {} page(s) failed the check", failed, report.pages()),
+    };
 
-                wtr.write_record(["Anatomical Site Name"])
-                    .expect("writing CSV header");
+    Ok(Outcome::new(status, summary).with_details(details))
+}
 
-                for variant in AnatomicalSite::VARIANTS {
-                    wtr.serialize(variant).expect("writing CSV record");
-                }
+async fn run_check_consistency(args: &CheckConsistencyArgs) -> Result<Outcome, output::Error> {
+    let config = check::Config::new(args.concurrency, args.retries);
+    let report = consistency::probe(&args.url, args.subjects, &config)
+        .await
+        .map_err(|err| match err {
+            check::Error::InvalidUrl(_) => output::Error::usage(err),
+            check::Error::Parse(_) | check::Error::Link(_) | check::Error::Count(_) => {
+                output::Error::new(Status::ConformanceFailure, err)
             }
-        },
-        Command::Generate(args) => {
-            #[cfg(not(feature = "all-anatomical-site"))]
-            warn!(
-                "You're building the spec without all anatomical sites! \
-                 Please be sure that's what you want."
-            );
+            check::Error::Request(_) | check::Error::Status(_) => output::Error::network(err),
+        })?;
 
-            let api = Api::openapi();
-            let mut writer = get_output(args.output, args.force)?;
-            write!(writer, "{}", api.to_yaml()?)?;
+    let details = report
+        .violations()
+        .iter()
+        .map(|violation| {
+            Detail::new(
+                format!(
+                    "subject {} / sample {}",
+                    expand::key(violation.subject()),
+                    violation.sample().name()
+                ),
+                violation.to_string(),
+            )
+        })
+        .collect();
+
+    let status = match report.violations().is_empty() {
+        true => Status::Success,
+        false => Status::ConformanceFailure,
+    };
+
+    let summary = format!(
+        "checked {} subject(s) and {} sample(s): {} violation(s)",
+        report.subjects(),
+        report.samples(),
+        report.violations().len()
+    );
+
+    Ok(Outcome::new(status, summary).with_details(details))
+}
+
+async fn run_conformance(args: &ConformanceArgs) -> Result<Outcome, output::Error> {
+    let config = check::Config::new(args.concurrency, args.retries);
+    let report = conformance::run(
+        &args.base_url,
+        conformance::matrix(),
+        Duration::from_secs(args.timeout),
+        &config,
+    )
+    .await
+    .map_err(|err| match err {
+        check::Error::InvalidUrl(_) => output::Error::usage(err),
+        _ => output::Error::network(err),
+    })?;
+
+    let details = report
+        .checks()
+        .iter()
+        .filter(|check| !check.passed())
+        .map(|check| {
+            Detail::new(
+                format!("{} ({})", check.name(), check.requirement()),
+                check.error().unwrap_or_default(),
+            )
+        })
+        .collect();
+
+    // NOTE: only failures of required checks affect the status. When any
+    // required check could not be completed, the run is incomplete, so the
+    // network failure takes precedence over any conformance failures.
+    let required = report
+        .checks()
+        .iter()
+        .filter(|check| check.requirement() == conformance::Requirement::Required)
+        .count();
+    let status = match report.required_failures().count() {
+        0 => Status::Success,
+        _ if report.failures_are_nonconforming() => Status::ConformanceFailure,
+        _ => Status::NetworkFailure,
+    };
+
+    let summary = format!(
+        "{} of {} required check(s) failed",
+        report.required_failures().count(),
+        required
+    );
+
+    let document = match args.report {
+        ReportFormat::Table => report.to_table(),
+        ReportFormat::Json => report.to_json(),
+    };
+
+    Ok(Outcome::new(status, summary)
+        .with_details(details)
+        .with_output(document))
+}
+
+fn export(args: ExportArgs) -> Result<Outcome, output::Error> {
+    match args.entity {
+        ExportEntity::AnatomicalSite => {
+            let mut wtr = csv::WriterBuilder::new()
+                .delimiter(b'\t')
+                .from_writer(Vec::new());
+
+            wtr.write_record(["Anatomical Site Name"])
+                .map_err(output::Error::failure)?;
+
+            for variant in AnatomicalSite::VARIANTS {
+                wtr.serialize(variant).map_err(output::Error::failure)?;
+            }
+
+            let document = wtr
+                .into_inner()
+                .map_err(|err| output::Error::failure(err.to_string()))?;
+
+            Ok(Outcome::success(format!(
+                "exported {} anatomical site(s)",
+                AnatomicalSite::VARIANTS.len()
+            ))
+            .with_output(String::from_utf8(document).map_err(output::Error::failure)?))
         }
-        Command::Serve(args) => {
-            info!("Starting server at http://localhost:{}", args.port);
+    }
+}
 
-            let subjects = subject::Store::random(args.number_of_subjects);
+fn generate(args: GenerateArgs) -> Result<Outcome, output::Error> {
+    #[cfg(not(feature = "all-anatomical-site"))]
+    warn!(
+        "You're building the spec without all anatomical sites! \
+         Please be sure that's what you want."
+    );
 
-            let samples =
-                sample::Store::random(args.number_of_samples, subjects.subjects.lock().unwrap());
+    let servers = get_servers(&args)?;
 
-            let files = file::Store::random(args.number_of_files, samples.samples.lock().unwrap());
+    let mut api = Api::openapi();
+    servers.modify(&mut api);
 
-            let subjects = Data::new(subjects);
-            let samples = Data::new(samples);
-            let files = Data::new(files);
+    write_document(
+        args.output,
+        args.force,
+        args.specification_format.render(&api)?,
+        "generated the specification",
+    )
+}
 
-            rt::System::new().block_on(
-                HttpServer::new(move || {
-                    App::new()
-                        .app_data(QueryConfig::default().error_handler(|err, _| {
-                            match err {
-                                QueryPayloadError::Deserialize(err) => {
-                                    Errors::new(vec![error::Kind::invalid_parameters(
-                                        None,
-                                        err.to_string(),
-                                    )])
-                                    .into()
-                                }
-                                _ => todo!(),
+fn lint_cdes() -> Result<Outcome, output::Error> {
+    let findings = lint_registry();
+
+    if findings.is_empty() {
+        return Ok(Outcome::success(
+            "none of the common data elements have findings",
+        ));
+    }
+
+    let details = findings
+        .iter()
+        .flat_map(|(name, findings)| {
+            findings
+                .iter()
+                .map(move |finding| Detail::new(*name, finding.to_string()))
+        })
+        .collect();
+
+    Ok(Outcome::new(
+        Status::ConformanceFailure,
+        format!("{} common data element(s) have findings", findings.len()),
+    )
+    .with_details(details))
+}
+
+async fn run_replay(args: &ReplayArgs) -> Result<Outcome, output::Error> {
+    let report = replay::replay(&args.directory, &args.base_url)
+        .await
+        .map_err(|err| match err {
+            replay::Error::Io(_) | replay::Error::Request(_) => output::Error::network(err),
+            replay::Error::InvalidUrl(_) | replay::Error::InvalidRequest(_) => {
+                output::Error::usage(err)
+            }
+        })?;
+
+    if report.differences().is_empty() {
+        return Ok(Outcome::success(format!(
+            "replayed {} request(s) without differences",
+            report.requests()
+        )));
+    }
+
+    let details = report
+        .differences()
+        .iter()
+        .flat_map(|(name, differences)| {
+            differences
+                .iter()
+                .map(move |difference| Detail::new(name.clone(), difference.to_string()))
+        })
+        .collect();
+
+    Ok(Outcome::new(
+        Status::ConformanceFailure,
+        format!(
+            "{} of {} response(s) differed from the recording",
+            report.differences().len(),
+            report.requests()
+        ),
+    )
+    .with_details(details))
+}
+
+/// Gets the unharmonized fields registered by the example server.
+///
+/// The randomly generated entities do not include any unharmonized fields, so
+/// these only demonstrate the `unharmonized` section of the
+/// `/metadata/fields/<entity>` responses.
+fn unharmonized_registries() -> metadata::UnharmonizedRegistries {
+    let mut registries = metadata::UnharmonizedRegistries::default();
+
+    // SAFETY: each key is only registered once for each entity, so these
+    // registrations will always succeed.
+    registries
+        .subject
+        .register(
+            "consortium_id",
+            "The identifier of the subject within the consortium that enrolled it.",
+            ValueKind::String,
+        )
+        .unwrap();
+    registries
+        .sample
+        .register(
+            "batch",
+            "The number of the batch within which the sample was processed.",
+            ValueKind::Number,
+        )
+        .unwrap();
+    registries
+        .file
+        .register(
+            "pipeline_version",
+            "The version of the pipeline that produced the file.",
+            ValueKind::String,
+        )
+        .unwrap();
+
+    registries
+}
+
+/// Generates random stores of subjects, samples, and files.
+fn generate_stores(args: &GenerationArgs) -> (subject::Store, sample::Store, file::Store) {
+    let mut rng = match args.seed {
+        Some(seed) => {
+            info!("Generating the entities with seed {seed}.");
+            StdRng::seed_from_u64(seed)
+        }
+        None => StdRng::from_entropy(),
+    };
+
+    let generation = match args.independent_metadata {
+        true => Generation::Independent,
+        false => Generation::Coherent,
+    };
+
+    let subjects = subject::Store::random_with_generation(
+        args.number_of_subjects,
+        args.metadata_less_fraction,
+        generation,
+        &mut rng,
+    );
+
+    let samples = sample::Store::random_with_generation(
+        args.number_of_samples,
+        subjects.subjects.lock().unwrap(),
+        args.metadata_less_fraction,
+        generation,
+        &mut rng,
+    );
+
+    let files = file::Store::random_with_rng(
+        args.number_of_files,
+        samples.samples.lock().unwrap(),
+        args.metadata_less_fraction,
+        &mut rng,
+    );
+
+    (subjects, samples, files)
+}
+
+fn dump(args: DumpArgs) -> Result<Outcome, output::Error> {
+    let (subjects, samples, files) = generate_stores(&args.generation);
+    let dataset = Dataset::from_stores(&subjects, &samples, &files);
+
+    let format = match (args.ndjson, &args.output) {
+        (true, _) => dataset::Format::Ndjson,
+        (false, Some(path)) => dataset::Format::from_path(path),
+        (false, None) => dataset::Format::Json,
+    };
+
+    write_document(
+        args.output,
+        args.force,
+        dataset.to_string(format),
+        format!(
+            "dumped {} subject(s), {} sample(s), and {} file(s)",
+            dataset.subjects().len(),
+            dataset.samples().len(),
+            dataset.files().len()
+        ),
+    )
+}
+
+async fn run_fixtures(args: &FixturesArgs) -> Result<Outcome, output::Error> {
+    let (subjects, samples, files) = generate_stores(&args.generation);
+
+    let written = fixtures::generate(
+        &args.output_dir,
+        args.force,
+        Data::new(subjects),
+        Data::new(samples),
+        Data::new(files),
+    )
+    .await
+    .map_err(|err| match err {
+        fixtures::Error::Io(_) => output::Error::network(err),
+        fixtures::Error::NotEmpty(_) => output::Error::usage(err),
+        fixtures::Error::Unexpected { .. } => output::Error::failure(err),
+    })?;
+
+    Ok(Outcome::success(format!(
+        "wrote {} fixture(s) to {}",
+        written.len(),
+        args.output_dir.display()
+    )))
+}
+
+fn serve(args: ServeArgs) -> Result<Outcome, output::Error> {
+    info!("Starting server at http://localhost:{}", args.port);
+
+    // NOTE: the stores are generated (or loaded) exactly once (here) and then
+    // shared between every worker through [`Data`], so every worker serves the
+    // same entities.
+    let (subjects, samples, files) = match &args.data {
+        Some(path) => {
+            info!("Loading the entities from {}.", path.display());
+
+            Dataset::from_path(path)
+                .and_then(Dataset::into_stores)
+                .map_err(|err| match err {
+                    dataset::Error::Io(_) => output::Error::network(err),
+                    _ => output::Error::usage(err),
+                })?
+        }
+        None => generate_stores(&args.generation),
+    };
+
+    if args.skip_integrity_check {
+        warn!("Skipping the referential integrity check of the entities.");
+    } else {
+        integrity::check(
+            &subjects.subjects.lock().unwrap(),
+            &samples.samples.lock().unwrap(),
+            &files.files.lock().unwrap(),
+        )
+        .map_err(|err| output::Error::new(Status::ConformanceFailure, err))?;
+    }
+
+    let subjects = Data::new(subjects);
+    let samples = Data::new(samples);
+    let files = Data::new(files);
+    let suggestions = Data::new(suggest::Config::new(args.suggestions));
+    let expansion = Data::new(expand::Config::new(args.expand_budget));
+    let pagination = Data::new(paginate::Config::new(args.max_per_page));
+    let distinct_values = Data::new(values::Config::new(args.values_limit));
+    let strictness = Data::new(strict::Config::new(args.strict_params));
+    let translations = Data::new(match args.translations {
+        Some(path) => Translations::from_path(path).map_err(|err| match err {
+            LoadError::Io(_) => output::Error::network(err),
+            _ => output::Error::usage(err),
+        })?,
+        None => Translations::embedded().clone(),
+    });
+    let registries = Data::new(unharmonized_registries());
+    let hierarchy = match args.uberon_hierarchy {
+        Some(path) => {
+            info!("Loading the Uberon hierarchy from {}.", path.display());
+
+            Some(Data::new(uberon::Hierarchy::from_path(path).map_err(
+                |err| match err {
+                    uberon::Error::Io(_) => output::Error::network(err),
+                    _ => output::Error::usage(err),
+                },
+            )?))
+        }
+        None => None,
+    };
+
+    let recorder = match args.record {
+        Some(directory) => {
+            info!("Recording requests to {}", directory.display());
+            Some(Arc::new(
+                record::Recorder::try_new(directory, args.record_max_files)
+                    .map_err(output::Error::network)?,
+            ))
+        }
+        None => None,
+    };
+
+    let compression = args.compression;
+
+    if !compression {
+        info!("Response compression is disabled.");
+    }
+
+    if !args.strict_params {
+        info!("Unrecognized query parameters are ignored rather than rejected.");
+    }
+
+    let enable_metrics = args.metrics;
+    let registry = Data::new(Registry::default());
+
+    if enable_metrics {
+        info!(
+            "Serving request metrics at http://localhost:{}/metrics",
+            args.port
+        );
+    }
+
+    rt::System::new()
+        .block_on(
+            HttpServer::new(move || {
+                App::new()
+                    .app_data(QueryConfig::default().error_handler(error::from_query_error))
+                    .app_data(PathConfig::default().error_handler(error::from_path_error))
+                    .app_data(JsonConfig::default().error_handler(error::from_json_error))
+                    .app_data(suggestions.clone())
+                    .app_data(expansion.clone())
+                    .app_data(pagination.clone())
+                    .app_data(distinct_values.clone())
+                    .app_data(strictness.clone())
+                    .app_data(translations.clone())
+                    .app_data(registries.clone())
+                    .configure({
+                        let hierarchy = hierarchy.clone();
+
+                        move |config: &mut web::ServiceConfig| {
+                            if let Some(hierarchy) = hierarchy {
+                                config.app_data(hierarchy);
                             }
-                        }))
-                        .wrap(Logger::default())
-                        // TODO: these clones could be avoided if the objects
-                        // were referred to by reference.
-                        .configure(subject::configure(subjects.clone()))
-                        .configure(sample::configure(samples.clone()))
-                        .configure(file::configure(files.clone()))
-                        .configure(metadata::configure())
-                        .configure(namespace::configure())
-                        .configure(organization::configure())
-                        .configure(info::configure())
-                        .configure(sample_diagnosis::configure(samples.clone()))
-                        .configure(subject_diagnosis::configure(subjects.clone()))
-                        .service(
-                            SwaggerUi::new("/swagger-ui/{_:.*}")
-                                .url("/api-docs/openapi.json", Api::openapi()),
-                        )
-                        .default_service(web::to(|req: HttpRequest| async move {
-                            HttpResponse::NotFound().json(Errors::from(error::Kind::invalid_route(
-                                req.method().to_string(),
-                                req.path().to_string(),
-                            )))
-                        }))
-                })
-                .bind((Ipv4Addr::UNSPECIFIED, args.port))?
-                .run(),
-            )?;
-        }
-        Command::Wiki(args) => {
-            let fields = match args.entity {
-                Entity::Subject => {
-                    models::metadata::field::description::harmonized::subject::get_field_descriptions()
-                }
-                Entity::Sample => {
-                    models::metadata::field::description::harmonized::sample::get_field_descriptions(
+                        }
+                    })
+                    // NOTE: browsers only allow cross-origin clients to read
+                    // the headers that are explicitly exposed, so the
+                    // pagination headers are exposed for clients that page
+                    // through the results from another origin.
+                    .wrap(
+                        DefaultHeaders::new()
+                            .add((header::ACCESS_CONTROL_ALLOW_ORIGIN, "*"))
+                            .add((
+                                header::ACCESS_CONTROL_EXPOSE_HEADERS,
+                                paginate::EXPOSED_HEADERS.join(", "),
+                            )),
                     )
-                }
-                Entity::File => {
-                    models::metadata::field::description::harmonized::file::get_field_descriptions(
+                    .wrap(Logger::default())
+                    .wrap_fn({
+                        let recorder = recorder.clone();
+
+                        move |req, srv| {
+                            let recorder = recorder.clone();
+                            let request = recorder.as_ref().map(|_| record::Request::capture(&req));
+                            let response = srv.call(req);
+
+                            async move {
+                                let response = response.await?;
+
+                                match (recorder, request) {
+                                    (Some(recorder), Some(request)) => {
+                                        recorder.record(request, response).await
+                                    }
+                                    _ => Ok(response.map_into_boxed_body()),
+                                }
+                            }
+                        }
+                    })
+                    // NOTE: this is wrapped after the recorder so that
+                    // recordings contain the uncompressed responses.
+                    .wrap(Condition::new(compression, Compress::default()))
+                    // NOTE: this is wrapped last so that the latencies include
+                    // the time spent in every other middleware.
+                    .wrap(Condition::new(
+                        enable_metrics,
+                        Metrics::new(registry.clone()),
+                    ))
+                    // TODO: these clones could be avoided if the objects
+                    // were referred to by reference.
+                    .configure(subject::configure(subjects.clone()))
+                    .configure(sample::configure(samples.clone()))
+                    .configure(file::configure(files.clone()))
+                    .configure(aggregate::configure(
+                        subjects.clone(),
+                        samples.clone(),
+                        files.clone(),
+                    ))
+                    .configure(search::configure(
+                        subjects.clone(),
+                        samples.clone(),
+                        files.clone(),
+                    ))
+                    .configure(metadata::configure())
+                    .configure(namespace::configure())
+                    .configure(organization::configure())
+                    .configure(info::configure())
+                    .configure(sample_diagnosis::configure(samples.clone()))
+                    .configure(subject_diagnosis::configure(subjects.clone()))
+                    .configure(|config| {
+                        if enable_metrics {
+                            metrics::configure(registry.clone())(config);
+                        }
+                    })
+                    .service(
+                        SwaggerUi::new("/swagger-ui/{_:.*}")
+                            .url("/api-docs/openapi.json", Api::openapi()),
                     )
-                }
-            };
+                    .default_service(web::to(canonical::fallback))
+            })
+            .bind((Ipv4Addr::UNSPECIFIED, args.port))
+            .map_err(output::Error::network)?
+            .run(),
+        )
+        .map_err(output::Error::network)?;
+
+    Ok(Outcome::success("the server was stopped"))
+}
 
-            print!(
-                "{}",
-                fields.into_iter().map(markdown::Section::from).join("\n")
+fn wiki(args: WikiArgs) -> Result<Outcome, output::Error> {
+    let fields = match args.entity {
+        Entity::Index => {
+            let descriptors = cde::registry::all();
+            let summary = format!(
+                "generated the wiki index for {} common data element(s)",
+                descriptors.len()
             );
+            let index = markdown::Index::try_new(descriptors)
+                .map_err(|err| output::Error::new(Status::ConformanceFailure, err))?;
+
+            return Ok(Outcome::success(summary).with_output(index.to_string()));
+        }
+        Entity::Subject => {
+            models::metadata::field::description::harmonized::subject::get_field_descriptions()
         }
+        Entity::Sample => {
+            models::metadata::field::description::harmonized::sample::get_field_descriptions()
+        }
+        Entity::File => {
+            models::metadata::field::description::harmonized::file::get_field_descriptions()
+        }
+    };
+
+    let summary = format!("generated the wiki page for {} field(s)", fields.len());
+
+    Ok(Outcome::success(summary)
+        .with_output(fields.into_iter().map(markdown::Section::from).join("\n")))
+}
+
+fn validate(args: ValidateArgs) -> Result<Outcome, output::Error> {
+    let dataset = Dataset::from_path(&args.data).map_err(|err| match err {
+        dataset::Error::Io(_) => output::Error::network(err),
+        _ => output::Error::usage(err),
+    })?;
+
+    let mut samples_by_subject = BTreeMap::<&models::subject::Identifier, Vec<&Sample>>::new();
+
+    for sample in dataset.samples() {
+        samples_by_subject
+            .entry(sample.subject())
+            .or_default()
+            .push(sample);
     }
 
-    Ok(())
+    let warnings = dataset
+        .subjects()
+        .iter()
+        .flat_map(|subject| {
+            validation::validate(
+                subject,
+                samples_by_subject
+                    .get(subject.id())
+                    .map(|samples| samples.as_slice())
+                    .unwrap_or_default(),
+            )
+        })
+        .collect::<Vec<_>>();
+
+    let details = warnings
+        .iter()
+        .map(|warning| {
+            let target = match warning.sample() {
+                Some(sample) => format!(
+                    "subject {} / sample {}",
+                    expand::key(warning.subject()),
+                    sample.name()
+                ),
+                None => format!("subject {}", expand::key(warning.subject())),
+            };
+
+            Detail::new(target, warning.to_string())
+        })
+        .collect();
+
+    // NOTE: warnings describe data that is unlikely (rather than invalid), so
+    // they never cause the subcommand to fail.
+    let summary = format!(
+        "found {} warning(s) across {} subject(s)",
+        warnings.len(),
+        dataset.subjects().len()
+    );
+
+    Ok(Outcome::success(summary).with_details(details))
+}
+
+/// Runs a subcommand and gets its [`Outcome`].
+fn run(command: Command) -> Outcome {
+    let name = command.name();
+    let start = Instant::now();
+
+    let result = match command {
+        Command::CdeDiff(args) => cde_diff(args),
+        Command::CdeSnapshot(args) => cde_snapshot(args),
+        Command::Check(args) => rt::System::new().block_on(run_check(&args)),
+        Command::CheckConsistency(args) => rt::System::new().block_on(run_check_consistency(&args)),
+        Command::Conformance(args) => rt::System::new().block_on(run_conformance(&args)),
+        Command::DiffFields(args) => diff_fields(args),
+        Command::Dump(args) => dump(args),
+        Command::Export(args) => export(args),
+        Command::Fixtures(args) => rt::System::new().block_on(run_fixtures(&args)),
+        Command::Generate(args) => generate(args),
+        Command::LintCdes => lint_cdes(),
+        Command::Replay(args) => rt::System::new().block_on(run_replay(&args)),
+        Command::Serve(args) => serve(args),
+        Command::Validate(args) => validate(args),
+        Command::Wiki(args) => wiki(args),
+    };
+
+    result
+        .unwrap_or_else(Outcome::from_error)
+        .finish(name, start.elapsed())
+}
+
+/// Gets the exit code for an error encountered while parsing the arguments.
+///
+/// Requests for help (or for the version) are reported by `clap` as errors,
+/// but they are not usage errors.
+fn parse_error_exit_code(err: &clap::Error) -> i32 {
+    match err.use_stderr() {
+        true => Status::UsageError.exit_code(),
+        false => Status::Success.exit_code(),
+    }
 }
 
 fn main() {
-    match inner() {
-        Ok(_) => {}
+    let args = match Args::try_parse() {
+        Ok(args) => args,
         Err(err) => {
-            eprintln!("error: {err}");
-            std::process::exit(ERROR_EXIT_CODE);
+            // NOTE: if the error cannot be printed, the exit code is all that
+            // is left to report it.
+            let _ = err.print();
+            std::process::exit(parse_error_exit_code(&err));
         }
+    };
+
+    env_logger::builder()
+        .filter_level(match args.quiet {
+            true => LevelFilter::Error,
+            false => LevelFilter::Debug,
+        })
+        .target(env_logger::Target::Stderr)
+        .init();
+
+    let outcome = run(args.command);
+
+    if outcome
+        .write(args.format, &mut io::stdout(), &mut io::stderr())
+        .is_err()
+    {
+        std::process::exit(Status::NetworkFailure.exit_code());
     }
+
+    std::process::exit(outcome.status().exit_code());
 }
 
 #[cfg(test)]
@@ -402,4 +1635,243 @@ mod tests {
         use clap::CommandFactory;
         Args::command().debug_assert()
     }
+
+    fn generate_to(path: &Path, specification_format: SpecificationFormat) -> String {
+        generate(GenerateArgs {
+            output: Some(path.to_path_buf()),
+            force: true,
+            server_urls: Vec::new(),
+            server_descriptions: Vec::new(),
+            servers_config: None,
+            replace_default_servers: false,
+            specification_format,
+        })
+        .unwrap();
+
+        std::fs::read_to_string(path).unwrap()
+    }
+
+    #[test]
+    fn generates_the_same_specification_in_yaml_and_json() {
+        let directory =
+            std::env::temp_dir().join(format!("ccdi-spec-generate-{}", std::process::id()));
+        std::fs::create_dir_all(&directory).unwrap();
+
+        let yaml = generate_to(&directory.join("swagger.yml"), SpecificationFormat::Yaml);
+        let json = generate_to(&directory.join("swagger.json"), SpecificationFormat::Json);
+
+        std::fs::remove_dir_all(&directory).unwrap();
+
+        let from_yaml = serde_yaml::from_str::<utoipa::openapi::OpenApi>(&yaml).unwrap();
+        let from_json = serde_json::from_str::<utoipa::openapi::OpenApi>(&json).unwrap();
+
+        assert!(from_yaml == from_json);
+
+        // NOTE: the keys are serialized in the same order in both formats.
+        let yaml = serde_yaml::from_str::<serde_json::Value>(&yaml).unwrap();
+        let json = serde_json::from_str::<serde_json::Value>(&json).unwrap();
+        assert_eq!(
+            serde_json::to_string(&yaml).unwrap(),
+            serde_json::to_string(&json).unwrap()
+        );
+    }
+
+    #[test]
+    fn dumped_datasets_reload_to_the_same_entities() {
+        let directory = std::env::temp_dir().join(format!("ccdi-spec-dump-{}", std::process::id()));
+        std::fs::create_dir_all(&directory).unwrap();
+
+        let generation = || GenerationArgs {
+            number_of_subjects: 20,
+            number_of_samples: 20,
+            number_of_files: 50,
+            metadata_less_fraction: 0.5,
+            independent_metadata: false,
+            seed: Some(0),
+        };
+
+        let (subjects, samples, files) = generate_stores(&generation());
+        let expected = Dataset::from_stores(&subjects, &samples, &files);
+
+        for name in ["dataset.json", "dataset.ndjson"] {
+            let path = directory.join(name);
+
+            dump(DumpArgs {
+                generation: generation(),
+                output: Some(path.clone()),
+                force: true,
+                ndjson: false,
+            })
+            .unwrap();
+
+            assert_eq!(Dataset::from_path(&path).unwrap(), expected, "{name}");
+        }
+
+        std::fs::remove_dir_all(&directory).unwrap();
+    }
+
+    /// Starts an in-process server with 10 subjects (along with the example
+    /// namespaces and organizations).
+    async fn serve() -> (String, actix_web::dev::ServerHandle) {
+        let subjects = Data::new(subject::Store::random(10));
+
+        let server = HttpServer::new(move || {
+            App::new()
+                .configure(subject::configure(subjects.clone()))
+                .configure(server::routes::namespace::configure())
+                .configure(server::routes::organization::configure())
+        })
+        .workers(1)
+        .bind(("127.0.0.1", 0))
+        .unwrap();
+
+        let address = server.addrs()[0];
+        let server = server.run();
+        let handle = server.handle();
+        rt::spawn(server);
+
+        (format!("http://{address}"), handle)
+    }
+
+    fn check_args(url: String, response_type: ResponseType) -> CheckArgs {
+        CheckArgs {
+            url,
+            response_type,
+            concurrency: NonZeroUsize::new(1).unwrap(),
+            retries: 0,
+            follow_pagination: false,
+            max_pages: None,
+        }
+    }
+
+    async fn check_outcome(args: CheckArgs) -> serde_json::Value {
+        let outcome = run_check(&args)
+            .await
+            .unwrap_or_else(Outcome::from_error)
+            .finish("check", std::time::Duration::from_millis(5));
+
+        serde_json::to_value(&outcome).unwrap()
+    }
+
+    #[actix_web::test]
+    async fn check_reports_a_success() {
+        let (url, handle) = serve().await;
+
+        let outcome =
+            check_outcome(check_args(format!("{url}/subject"), ResponseType::Subjects)).await;
+
+        handle.stop(true).await;
+
+        assert_eq!(
+            outcome,
+            serde_json::json!({
+                "command": "check",
+                "status": "success",
+                "exit_code": 0,
+                "summary": "checked 1 page(s): 0 failure(s)",
+                "details": [],
+                "duration_ms": 5
+            })
+        );
+    }
+
+    #[actix_web::test]
+    async fn check_crawls_every_page_of_namespaces_and_organizations() {
+        let (url, handle) = serve().await;
+
+        let namespaces = check_outcome(CheckArgs {
+            follow_pagination: true,
+            ..check_args(
+                format!("{url}/namespace?per_page=1"),
+                ResponseType::Namespaces,
+            )
+        })
+        .await;
+
+        let organizations = check_outcome(CheckArgs {
+            follow_pagination: true,
+            ..check_args(format!("{url}/organization"), ResponseType::Organizations)
+        })
+        .await;
+
+        // Without following pagination, only the provided page is checked.
+        let first = check_outcome(check_args(
+            format!("{url}/namespace?per_page=1"),
+            ResponseType::Namespaces,
+        ))
+        .await;
+
+        // The deprecated, unpaginated format is not a conformant response.
+        let unpaginated = check_outcome(check_args(
+            format!("{url}/namespace?paginate=false"),
+            ResponseType::Namespaces,
+        ))
+        .await;
+
+        handle.stop(true).await;
+
+        assert_eq!(namespaces["status"], "success");
+        assert_eq!(namespaces["summary"], "checked 2 page(s): 0 failure(s)");
+        assert_eq!(organizations["status"], "success");
+        assert_eq!(organizations["summary"], "checked 1 page(s): 0 failure(s)");
+        assert_eq!(first["summary"], "checked 1 page(s): 0 failure(s)");
+        assert_eq!(unpaginated["status"], "conformance_failure");
+    }
+
+    #[actix_web::test]
+    async fn check_reports_a_deserialization_failure_as_a_conformance_failure() {
+        let (url, handle) = serve().await;
+
+        let outcome =
+            check_outcome(check_args(format!("{url}/subject"), ResponseType::Samples)).await;
+
+        handle.stop(true).await;
+
+        assert_eq!(outcome["status"], "conformance_failure");
+        assert_eq!(outcome["exit_code"], 2);
+        assert_eq!(outcome["summary"], "1 of 1 page(s) failed the check");
+        assert_eq!(outcome["details"][0]["target"], "page 1");
+        assert!(outcome["details"][0]["message"]
+            .as_str()
+            .unwrap()
+            .starts_with("parse error: "));
+    }
+
+    #[actix_web::test]
+    async fn check_reports_a_connection_error_as_a_network_failure() {
+        // NOTE: binding (and then dropping) a listener finds a port on which
+        // nothing is listening.
+        let address = std::net::TcpListener::bind(("127.0.0.1", 0))
+            .unwrap()
+            .local_addr()
+            .unwrap();
+
+        let outcome = check_outcome(check_args(
+            format!("http://{address}/subject"),
+            ResponseType::Subjects,
+        ))
+        .await;
+
+        assert_eq!(outcome["status"], "network_failure");
+        assert_eq!(outcome["exit_code"], 3);
+        assert!(outcome["summary"]
+            .as_str()
+            .unwrap()
+            .starts_with("request error: "));
+        assert_eq!(outcome["details"], serde_json::json!([]));
+    }
+
+    #[test]
+    fn it_maps_argument_errors_to_exit_codes() {
+        let err = Args::try_parse_from(["ccdi-spec", "check"]).unwrap_err();
+        assert_eq!(parse_error_exit_code(&err), 4);
+
+        let err = Args::try_parse_from(["ccdi-spec", "--help"]).unwrap_err();
+        assert_eq!(parse_error_exit_code(&err), 0);
+
+        let args =
+            Args::try_parse_from(["ccdi-spec", "lint-cdes", "--output", "json", "-q"]).unwrap();
+        assert_eq!(args.format, Format::Json);
+        assert!(args.quiet);
+    }
 }
@@ -0,0 +1,322 @@
+//! Reporting the outcome of a subcommand.
+//!
+//! Every subcommand produces an [`Outcome`]: a [`Status`] (which determines
+//! the exit code of the program), a one-line summary, the details of each
+//! individual check (or finding), the document produced by the subcommand (if
+//! any), and the time taken. The outcome is written to stdout either as text
+//! for humans or as a single JSON object for machines (see [`Format`]).
+//!
+//! Logging is always written to stderr, so stdout only ever contains the
+//! result of the subcommand.
+
+use std::io;
+use std::time::Duration;
+
+use clap::ValueEnum;
+use serde::Serialize;
+
+/// The exit codes of the program, as documented within `--help`.
+pub const EXIT_CODES_HELP: &str = "\
+Exit codes:
+  0  Success.
+  1  Any other failure.
+  2  Conformance failure (e.g., a response does not match the specification).
+  3  Network or i/o failure.
+  4  Usage error (e.g., an invalid argument or configuration file).";
+
+/// The format in which the outcome of a subcommand is written to stdout.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, ValueEnum)]
+pub enum Format {
+    /// Human-readable text.
+    #[default]
+    Text,
+
+    /// A single JSON object.
+    Json,
+}
+
+/// The status of a subcommand.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Status {
+    /// The subcommand succeeded.
+    Success,
+
+    /// The subcommand failed for a reason not covered by any other status.
+    Failure,
+
+    /// The subcommand completed, but something did not conform to the
+    /// specification.
+    ConformanceFailure,
+
+    /// A network request or an input/output operation failed.
+    NetworkFailure,
+
+    /// The subcommand was invoked incorrectly.
+    UsageError,
+}
+
+impl Status {
+    /// Gets the exit code of the program for the [`Status`].
+    pub fn exit_code(&self) -> i32 {
+        match self {
+            Status::Success => 0,
+            Status::Failure => 1,
+            Status::ConformanceFailure => 2,
+            Status::NetworkFailure => 3,
+            Status::UsageError => 4,
+        }
+    }
+}
+
+/// An error that ended a subcommand along with the [`Status`] it maps to.
+#[derive(Debug)]
+pub struct Error {
+    /// The status of the subcommand.
+    status: Status,
+
+    /// The underlying error.
+    inner: Box<dyn std::error::Error>,
+}
+
+impl Error {
+    /// Creates a new [`Error`].
+    pub fn new(status: Status, inner: impl Into<Box<dyn std::error::Error>>) -> Self {
+        Self {
+            status,
+            inner: inner.into(),
+        }
+    }
+
+    /// Creates a new [`Error`] with a [`Status::Failure`].
+    pub fn failure(inner: impl Into<Box<dyn std::error::Error>>) -> Self {
+        Self::new(Status::Failure, inner)
+    }
+
+    /// Creates a new [`Error`] with a [`Status::NetworkFailure`].
+    pub fn network(inner: impl Into<Box<dyn std::error::Error>>) -> Self {
+        Self::new(Status::NetworkFailure, inner)
+    }
+
+    /// Creates a new [`Error`] with a [`Status::UsageError`].
+    pub fn usage(inner: impl Into<Box<dyn std::error::Error>>) -> Self {
+        Self::new(Status::UsageError, inner)
+    }
+
+    /// Gets the status of the subcommand.
+    pub fn status(&self) -> Status {
+        self.status
+    }
+}
+
+impl std::fmt::Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.inner)
+    }
+}
+
+impl std::error::Error for Error {}
+
+/// The details of a single check (or finding) within an [`Outcome`].
+#[derive(Clone, Debug, Eq, PartialEq, Serialize)]
+pub struct Detail {
+    /// What the check relates to (e.g., a page or a common data element).
+    target: String,
+
+    /// A description of the result of the check.
+    message: String,
+}
+
+impl Detail {
+    /// Creates a new [`Detail`].
+    pub fn new(target: impl Into<String>, message: impl Into<String>) -> Self {
+        Self {
+            target: target.into(),
+            message: message.into(),
+        }
+    }
+}
+
+impl std::fmt::Display for Detail {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}: {}", self.target, self.message)
+    }
+}
+
+/// The outcome of a subcommand.
+#[derive(Debug, Serialize)]
+pub struct Outcome {
+    /// The name of the subcommand.
+    command: &'static str,
+
+    /// The status of the subcommand.
+    status: Status,
+
+    /// The exit code of the program.
+    exit_code: i32,
+
+    /// A one-line summary of the outcome.
+    summary: String,
+
+    /// The details of each check (or finding).
+    details: Vec<Detail>,
+
+    /// The document produced by the subcommand (when it was not written to a
+    /// file).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    output: Option<String>,
+
+    /// The time taken by the subcommand in milliseconds.
+    duration_ms: u64,
+}
+
+impl Outcome {
+    /// Creates a new [`Outcome`].
+    pub fn new(status: Status, summary: impl Into<String>) -> Self {
+        Self {
+            command: "",
+            status,
+            exit_code: status.exit_code(),
+            summary: summary.into(),
+            details: Vec::new(),
+            output: None,
+            duration_ms: 0,
+        }
+    }
+
+    /// Creates a new [`Outcome`] with a [`Status::Success`].
+    pub fn success(summary: impl Into<String>) -> Self {
+        Self::new(Status::Success, summary)
+    }
+
+    /// Creates a new [`Outcome`] from an [`Error`].
+    pub fn from_error(err: Error) -> Self {
+        Self::new(err.status(), err.to_string())
+    }
+
+    /// Sets the details of each check within the [`Outcome`].
+    pub fn with_details(mut self, details: Vec<Detail>) -> Self {
+        self.details = details;
+        self
+    }
+
+    /// Sets the document produced by the subcommand.
+    pub fn with_output(mut self, output: String) -> Self {
+        self.output = Some(output);
+        self
+    }
+
+    /// Sets the name of the subcommand and the time it took.
+    pub fn finish(mut self, command: &'static str, duration: Duration) -> Self {
+        self.command = command;
+        self.duration_ms = duration.as_millis().try_into().unwrap_or(u64::MAX);
+        self
+    }
+
+    /// Gets the status of the [`Outcome`].
+    pub fn status(&self) -> Status {
+        self.status
+    }
+
+    /// Writes the [`Outcome`] in the provided [`Format`].
+    ///
+    /// In the text format, the document produced by the subcommand (or else
+    /// the summary and details) is written to `stdout`, and failures are
+    /// summarized within `stderr`. In the JSON format, the [`Outcome`] is
+    /// written to `stdout` as a single JSON object.
+    pub fn write(
+        &self,
+        format: Format,
+        stdout: &mut impl io::Write,
+        stderr: &mut impl io::Write,
+    ) -> io::Result<()> {
+        match format {
+            Format::Text => {
+                match (&self.output, self.status) {
+                    (Some(output), _) => write!(stdout, "{output}")?,
+                    (None, Status::Success) => {
+                        writeln!(stdout, "{}", self.summary)?;
+
+                        for detail in &self.details {
+                            writeln!(stdout, "  - {detail}")?;
+                        }
+                    }
+                    (None, _) => {
+                        for detail in &self.details {
+                            writeln!(stdout, "  - {detail}")?;
+                        }
+                    }
+                }
+
+                if self.status != Status::Success {
+                    writeln!(stderr, "error: {}", self.summary)?;
+                }
+            }
+            Format::Json => {
+                // SAFETY: an [`Outcome`] is always serializable to JSON.
+                writeln!(stdout, "{}", serde_json::to_string(self).unwrap())?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn written(outcome: &Outcome, format: Format) -> (String, String) {
+        let mut stdout = Vec::new();
+        let mut stderr = Vec::new();
+
+        outcome.write(format, &mut stdout, &mut stderr).unwrap();
+
+        (
+            String::from_utf8(stdout).unwrap(),
+            String::from_utf8(stderr).unwrap(),
+        )
+    }
+
+    #[test]
+    fn it_maps_each_status_to_a_distinct_exit_code() {
+        assert_eq!(Status::Success.exit_code(), 0);
+        assert_eq!(Status::Failure.exit_code(), 1);
+        assert_eq!(Status::ConformanceFailure.exit_code(), 2);
+        assert_eq!(Status::NetworkFailure.exit_code(), 3);
+        assert_eq!(Status::UsageError.exit_code(), 4);
+    }
+
+    #[test]
+    fn it_writes_text() {
+        let outcome = Outcome::new(Status::ConformanceFailure, "1 page(s) failed the check")
+            .with_details(vec![Detail::new("page 3", "parse error: missing field")]);
+
+        let (stdout, stderr) = written(&outcome, Format::Text);
+        assert_eq!(stdout, "  - page 3: parse error: missing field\n");
+        assert_eq!(stderr, "error: 1 page(s) failed the check\n");
+
+        let outcome = Outcome::success("checked 1 page(s): 0 failure(s)");
+        let (stdout, stderr) = written(&outcome, Format::Text);
+        assert_eq!(stdout, "checked 1 page(s): 0 failure(s)\n");
+        assert!(stderr.is_empty());
+
+        let outcome = Outcome::success("generated").with_output(String::from("openapi: 3.0.3\n"));
+        let (stdout, _) = written(&outcome, Format::Text);
+        assert_eq!(stdout, "openapi: 3.0.3\n");
+    }
+
+    #[test]
+    fn it_writes_a_single_json_object() {
+        let outcome = Outcome::from_error(Error::usage("file already exists: spec.yml"))
+            .finish("generate", Duration::from_millis(12));
+
+        let (stdout, stderr) = written(&outcome, Format::Json);
+        assert!(stderr.is_empty());
+        assert_eq!(
+            stdout,
+            "{\"command\":\"generate\",\"status\":\"usage_error\",\"exit_code\":4,\
+             \"summary\":\"file already exists: spec.yml\",\"details\":[],\"duration_ms\":12}\n"
+        );
+    }
+}
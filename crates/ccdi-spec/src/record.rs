@@ -0,0 +1,302 @@
+//! Recording the requests made to (and the responses from) the test server.
+//!
+//! When recording is enabled, each request and its response are written as a
+//! numbered JSON file (e.g., `000001.json`) within the recording directory.
+//! These files can later be re-issued against another server using the
+//! `replay` subcommand, which makes it easy to attach the exact requests
+//! involved in a discrepancy to a bug report.
+//!
+//! Recordings are safe to make under concurrency: each request is assigned a
+//! unique number, and each file is written to a temporary file that is then
+//! atomically renamed into place. The number of files written is bounded, and
+//! the values of any authorization headers are redacted.
+
+use std::collections::BTreeMap;
+use std::fs;
+use std::io;
+use std::path::Path;
+use std::path::PathBuf;
+use std::sync::atomic::AtomicUsize;
+use std::sync::atomic::Ordering;
+
+use actix_web::body::to_bytes;
+use actix_web::body::BoxBody;
+use actix_web::body::MessageBody;
+use actix_web::dev::ServiceRequest;
+use actix_web::dev::ServiceResponse;
+use actix_web::error::ErrorInternalServerError;
+use actix_web::http::header::AUTHORIZATION;
+use actix_web::http::header::PROXY_AUTHORIZATION;
+use log::warn;
+use serde::Deserialize;
+use serde::Serialize;
+use serde_json::Value;
+
+/// The default maximum number of files to write to a recording directory.
+pub const DEFAULT_MAX_FILES: usize = 1000;
+
+/// The value that replaces the value of a redacted header.
+pub const REDACTED: &str = "[REDACTED]";
+
+/// A recorded request.
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub struct Request {
+    /// The method of the request.
+    pub method: String,
+
+    /// The path of the request.
+    pub path: String,
+
+    /// The query string of the request (without the leading `?`).
+    pub query: String,
+
+    /// The headers of the request keyed by their (lowercase) names.
+    ///
+    /// The values of authorization headers are replaced with [`REDACTED`].
+    pub headers: BTreeMap<String, String>,
+}
+
+impl Request {
+    /// Captures a [`Request`] from a [`ServiceRequest`].
+    pub fn capture(request: &ServiceRequest) -> Self {
+        let headers = request
+            .headers()
+            .iter()
+            .filter_map(|(name, value)| {
+                let value = if *name == AUTHORIZATION || *name == PROXY_AUTHORIZATION {
+                    REDACTED
+                } else {
+                    value.to_str().ok()?
+                };
+
+                Some((name.as_str().to_string(), value.to_string()))
+            })
+            .collect();
+
+        Self {
+            method: request.method().to_string(),
+            path: request.path().to_string(),
+            query: request.query_string().to_string(),
+            headers,
+        }
+    }
+
+    /// Gets the path and the query string of the request joined together.
+    pub fn path_and_query(&self) -> String {
+        match self.query.is_empty() {
+            true => self.path.clone(),
+            false => format!("{}?{}", self.path, self.query),
+        }
+    }
+}
+
+/// A recorded response.
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub struct Response {
+    /// The status code of the response.
+    pub status: u16,
+
+    /// The body of the response.
+    ///
+    /// Bodies that are not valid JSON are recorded as a JSON string.
+    pub body: Value,
+}
+
+/// A request and its response.
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub struct Recording {
+    /// The request.
+    pub request: Request,
+
+    /// The response.
+    pub response: Response,
+}
+
+/// Parses a body as JSON (falling back to a JSON string if the body is not
+/// valid JSON).
+pub fn parse_body(body: &[u8]) -> Value {
+    serde_json::from_slice(body)
+        .unwrap_or_else(|_| Value::String(String::from_utf8_lossy(body).into_owned()))
+}
+
+/// Records requests and responses to a directory.
+#[derive(Debug)]
+pub struct Recorder {
+    /// The directory to write recordings to.
+    directory: PathBuf,
+
+    /// The maximum number of files to write.
+    max_files: usize,
+
+    /// The number of requests seen so far.
+    seen: AtomicUsize,
+}
+
+impl Recorder {
+    /// Creates a new [`Recorder`] that writes to `directory` (creating it if
+    /// it does not exist).
+    pub fn try_new(directory: impl Into<PathBuf>, max_files: usize) -> io::Result<Self> {
+        let directory = directory.into();
+        fs::create_dir_all(&directory)?;
+
+        Ok(Self {
+            directory,
+            max_files,
+            seen: AtomicUsize::new(0),
+        })
+    }
+
+    /// Writes a [`Recording`] to the next numbered file.
+    ///
+    /// If the maximum number of files has already been written, nothing is
+    /// written and [`None`] is returned.
+    pub fn write(&self, recording: &Recording) -> io::Result<Option<PathBuf>> {
+        let number = self.seen.fetch_add(1, Ordering::SeqCst) + 1;
+
+        if number > self.max_files {
+            if number == self.max_files + 1 {
+                warn!(
+                    "the maximum number of recorded requests ({}) was reached: no further \
+                     requests will be recorded",
+                    self.max_files
+                );
+            }
+
+            return Ok(None);
+        }
+
+        let name = format!("{number:06}.json");
+        let path = self.directory.join(&name);
+        let temporary = self.directory.join(format!(".{name}.tmp"));
+
+        let contents = serde_json::to_vec_pretty(recording)?;
+        fs::write(&temporary, contents)?;
+        fs::rename(&temporary, &path)?;
+
+        Ok(Some(path))
+    }
+
+    /// Records a request and its response, returning the response unchanged.
+    ///
+    /// A failure to write the recording is logged rather than failing the
+    /// request.
+    pub async fn record<B>(
+        &self,
+        request: Request,
+        response: ServiceResponse<B>,
+    ) -> Result<ServiceResponse<BoxBody>, actix_web::Error>
+    where
+        B: MessageBody + 'static,
+    {
+        let (http_request, http_response) = response.into_parts();
+        let (http_response, body) = http_response.into_parts();
+
+        let body = to_bytes(body).await.map_err(|err| {
+            let err: Box<dyn std::error::Error> = err.into();
+            ErrorInternalServerError(err.to_string())
+        })?;
+
+        let recording = Recording {
+            request,
+            response: Response {
+                status: http_response.status().as_u16(),
+                body: parse_body(&body),
+            },
+        };
+
+        if let Err(err) = self.write(&recording) {
+            warn!("failed to record request: {err}");
+        }
+
+        Ok(ServiceResponse::new(
+            http_request,
+            http_response.set_body(BoxBody::new(body)),
+        ))
+    }
+}
+
+/// Reads every [`Recording`] within a directory in the order in which they
+/// were recorded.
+pub fn read(directory: &Path) -> io::Result<Vec<(String, Recording)>> {
+    let mut names = fs::read_dir(directory)?
+        .map(|entry| entry.map(|entry| entry.file_name().to_string_lossy().into_owned()))
+        .collect::<io::Result<Vec<_>>>()?
+        .into_iter()
+        .filter(|name| !name.starts_with('.') && name.ends_with(".json"))
+        .collect::<Vec<_>>();
+
+    names.sort();
+
+    names
+        .into_iter()
+        .map(|name| {
+            let contents = fs::read(directory.join(&name))?;
+            let recording = serde_json::from_slice::<Recording>(&contents)?;
+            Ok((name, recording))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use actix_web::test::TestRequest;
+
+    use super::*;
+
+    fn directory(name: &str) -> PathBuf {
+        std::env::temp_dir().join(format!("ccdi-spec-{name}-{}", std::process::id()))
+    }
+
+    fn recording(path: &str) -> Recording {
+        Recording {
+            request: Request {
+                method: String::from("GET"),
+                path: path.to_string(),
+                query: String::new(),
+                headers: BTreeMap::new(),
+            },
+            response: Response {
+                status: 200,
+                body: Value::Null,
+            },
+        }
+    }
+
+    #[test]
+    fn it_redacts_authorization_headers() {
+        let request = TestRequest::get()
+            .uri("/subject?page=2")
+            .insert_header((AUTHORIZATION, "Bearer secret"))
+            .insert_header(("x-custom", "value"))
+            .to_srv_request();
+
+        let request = Request::capture(&request);
+
+        assert_eq!(request.path_and_query(), "/subject?page=2");
+        assert_eq!(request.headers["authorization"], REDACTED);
+        assert_eq!(request.headers["x-custom"], "value");
+    }
+
+    #[test]
+    fn it_stops_recording_at_the_maximum_number_of_files() {
+        let directory = directory("record-max-files");
+        let _ = fs::remove_dir_all(&directory);
+
+        let recorder = Recorder::try_new(&directory, 2).unwrap();
+
+        assert!(recorder.write(&recording("/a")).unwrap().is_some());
+        assert!(recorder.write(&recording("/b")).unwrap().is_some());
+        assert!(recorder.write(&recording("/c")).unwrap().is_none());
+
+        let recordings = read(&directory).unwrap();
+        fs::remove_dir_all(&directory).unwrap();
+
+        assert_eq!(
+            recordings
+                .iter()
+                .map(|(name, recording)| (name.as_str(), recording.request.path.as_str()))
+                .collect::<Vec<_>>(),
+            vec![("000001.json", "/a"), ("000002.json", "/b")]
+        );
+    }
+}
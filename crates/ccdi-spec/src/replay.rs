@@ -0,0 +1,311 @@
+//! Replaying recorded requests against a server.
+//!
+//! Each request within a recording directory (see [`crate::record`]) is
+//! re-issued against the provided base URL, and the response is compared to
+//! the recorded response. Statuses are compared directly, and bodies are
+//! compared structurally (see [`crate::diff`]), so the order of the keys
+//! within an object does not matter.
+
+use std::collections::BTreeMap;
+use std::io;
+use std::path::Path;
+
+use reqwest::header::HeaderMap;
+use reqwest::header::HeaderName;
+use reqwest::header::HeaderValue;
+use reqwest::Client;
+use reqwest::Method;
+use serde_json::Value;
+use url::Url;
+
+use crate::diff::diff;
+use crate::diff::Difference;
+use crate::record;
+use crate::record::Recording;
+
+/// Headers that are never re-issued (as they are specific to the original
+/// connection).
+const SKIPPED_HEADERS: &[&str] = &["connection", "content-length", "host", "transfer-encoding"];
+
+/// An error related to replaying recorded requests.
+#[derive(Debug)]
+pub enum Error {
+    /// The recordings could not be read.
+    Io(io::Error),
+
+    /// The base URL could not be parsed.
+    InvalidUrl(url::ParseError),
+
+    /// A recorded request was not valid.
+    InvalidRequest(String),
+
+    /// A request could not be completed.
+    Request(reqwest::Error),
+}
+
+impl std::fmt::Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::Io(err) => write!(f, "i/o error: {err}"),
+            Error::InvalidUrl(err) => write!(f, "invalid url: {err}"),
+            Error::InvalidRequest(err) => write!(f, "invalid recorded request: {err}"),
+            Error::Request(err) => write!(f, "request error: {err}"),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+/// A report of the recorded requests that were replayed.
+#[derive(Debug, Default, Eq, PartialEq)]
+pub struct Report {
+    /// The number of requests that were replayed.
+    requests: usize,
+
+    /// The differences keyed by the name of the recording.
+    differences: BTreeMap<String, Vec<Difference>>,
+}
+
+impl Report {
+    /// Gets the number of requests that were replayed.
+    pub fn requests(&self) -> usize {
+        self.requests
+    }
+
+    /// Gets the differences keyed by the name of the recording.
+    pub fn differences(&self) -> &BTreeMap<String, Vec<Difference>> {
+        &self.differences
+    }
+}
+
+impl std::fmt::Display for Report {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "replayed {} request(s): {} with differences",
+            self.requests,
+            self.differences.len()
+        )?;
+
+        for (name, differences) in &self.differences {
+            write!(f, "\n  - {name}:")?;
+
+            for difference in differences {
+                write!(f, "\n    - {difference}")?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Replays every recording within `directory` against `base_url`.
+///
+/// An error is returned if a request cannot be completed. All differences
+/// between the recorded and replayed responses are collected within the
+/// returned [`Report`].
+pub async fn replay(directory: &Path, base_url: &str) -> Result<Report, Error> {
+    let base_url = base_url.parse::<Url>().map_err(Error::InvalidUrl)?;
+    let recordings = record::read(directory).map_err(Error::Io)?;
+    let client = Client::new();
+
+    let mut report = Report::default();
+
+    for (name, recording) in recordings {
+        let differences = replay_one(&client, &base_url, &recording).await?;
+        report.requests += 1;
+
+        if !differences.is_empty() {
+            report.differences.insert(name, differences);
+        }
+    }
+
+    Ok(report)
+}
+
+/// Replays a single recording and returns the differences from the recorded
+/// response.
+async fn replay_one(
+    client: &Client,
+    base_url: &Url,
+    recording: &Recording,
+) -> Result<Vec<Difference>, Error> {
+    let request = &recording.request;
+
+    let method = request
+        .method
+        .parse::<Method>()
+        .map_err(|err| Error::InvalidRequest(err.to_string()))?;
+
+    let url = base_url
+        .join(request.path_and_query().trim_start_matches('/'))
+        .map_err(Error::InvalidUrl)?;
+
+    let response = client
+        .request(method, url)
+        .headers(headers(&request.headers)?)
+        .send()
+        .await
+        .map_err(Error::Request)?;
+
+    let status = response.status().as_u16();
+    let body = response.bytes().await.map_err(Error::Request)?;
+
+    let mut differences = Vec::new();
+
+    if status != recording.response.status {
+        differences.push(Difference::new(
+            "status",
+            Some(Value::from(recording.response.status)),
+            Some(Value::from(status)),
+        ));
+    }
+
+    differences.extend(diff(&recording.response.body, &record::parse_body(&body)));
+
+    Ok(differences)
+}
+
+/// Converts recorded headers into a [`HeaderMap`], skipping any redacted or
+/// connection-specific headers.
+fn headers(recorded: &BTreeMap<String, String>) -> Result<HeaderMap, Error> {
+    recorded
+        .iter()
+        .filter(|(name, value)| {
+            !SKIPPED_HEADERS.contains(&name.as_str()) && value.as_str() != record::REDACTED
+        })
+        .map(|(name, value)| {
+            let name = name
+                .parse::<HeaderName>()
+                .map_err(|err| Error::InvalidRequest(err.to_string()))?;
+            let value = value
+                .parse::<HeaderValue>()
+                .map_err(|err| Error::InvalidRequest(err.to_string()))?;
+            Ok((name, value))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+    use std::sync::Arc;
+
+    use actix_web::dev::Service as _;
+    use actix_web::rt;
+    use actix_web::web::Data;
+    use actix_web::App;
+    use actix_web::HttpServer;
+
+    use ccdi_server::routes::subject;
+
+    use super::*;
+    use crate::record::Recorder;
+
+    /// Starts an in-process server with 20 subjects that records every request
+    /// to `directory`.
+    async fn serve(directory: &Path) -> (String, actix_web::dev::ServerHandle) {
+        let subjects = Data::new(subject::Store::random(20));
+        let recorder = Arc::new(Recorder::try_new(directory, 10).unwrap());
+
+        let server = HttpServer::new(move || {
+            let recorder = recorder.clone();
+
+            App::new()
+                .wrap_fn(move |req, srv| {
+                    let recorder = recorder.clone();
+                    let request = record::Request::capture(&req);
+                    let response = srv.call(req);
+
+                    async move { recorder.record(request, response.await?).await }
+                })
+                .configure(subject::configure(subjects.clone()))
+        })
+        .workers(1)
+        .bind(("127.0.0.1", 0))
+        .unwrap();
+
+        let address = server.addrs()[0];
+        let server = server.run();
+        let handle = server.handle();
+        rt::spawn(server);
+
+        (format!("http://{address}/"), handle)
+    }
+
+    fn directory(name: &str) -> std::path::PathBuf {
+        std::env::temp_dir().join(format!("ccdi-spec-{name}-{}", std::process::id()))
+    }
+
+    /// Makes a few requests (with an authorization header) to the server.
+    async fn request(base_url: &str) {
+        let client = Client::new();
+
+        for path in [
+            "subject?per_page=5",
+            "subject?sex=F",
+            "subject/by/sex/count",
+            "subject/example-organization/ExampleNamespace/Missing",
+        ] {
+            client
+                .get(format!("{base_url}{path}"))
+                .header("authorization", "Bearer secret")
+                .send()
+                .await
+                .unwrap();
+        }
+    }
+
+    #[actix_web::test]
+    async fn it_replays_recorded_requests_without_differences() {
+        let directory = directory("replay");
+        let _ = fs::remove_dir_all(&directory);
+
+        let (base_url, handle) = serve(&directory).await;
+        request(&base_url).await;
+
+        let recordings = record::read(&directory).unwrap();
+        assert_eq!(recordings.len(), 4);
+        assert!(recordings
+            .iter()
+            .all(|(_, recording)| recording.request.headers["authorization"] == record::REDACTED));
+        assert_eq!(recordings[3].1.response.status, 404);
+
+        let report = replay(&directory, &base_url).await.unwrap();
+
+        handle.stop(true).await;
+        fs::remove_dir_all(&directory).unwrap();
+
+        assert_eq!(report.requests, 4);
+        assert!(report.differences().is_empty(), "{report}");
+    }
+
+    #[actix_web::test]
+    async fn it_reports_differences() {
+        let directory = directory("replay-differences");
+        let _ = fs::remove_dir_all(&directory);
+
+        let (base_url, handle) = serve(&directory).await;
+        request(&base_url).await;
+
+        // Tamper with the recorded response to the count request.
+        let path = directory.join("000003.json");
+        let mut recording = serde_json::from_slice::<Recording>(&fs::read(&path).unwrap()).unwrap();
+        recording.response.body["total"] = Value::from(1000);
+        fs::write(&path, serde_json::to_vec(&recording).unwrap()).unwrap();
+
+        let report = replay(&directory, &base_url).await.unwrap();
+
+        handle.stop(true).await;
+        fs::remove_dir_all(&directory).unwrap();
+
+        assert_eq!(report.requests, 4);
+        assert_eq!(
+            report.differences()["000003.json"]
+                .iter()
+                .map(|difference| difference.to_string())
+                .collect::<Vec<_>>(),
+            vec!["/total: expected `1000`, found `20`"]
+        );
+    }
+}
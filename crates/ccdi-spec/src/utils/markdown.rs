@@ -1,7 +1,9 @@
 use ccdi_cde as cde;
 use ccdi_models as models;
 
+use cde::parse::cde::Entity;
 use cde::parse::cde::Member;
+use cde::registry::CdeDescriptor;
 use itertools::Itertools;
 use models::metadata::field::description;
 use models::metadata::field::description::harmonized::Kind;
@@ -32,6 +34,42 @@ impl std::fmt::Display for Section {
     }
 }
 
+/// An index of every registered common data element.
+pub struct Index(Vec<(CdeDescriptor, Entity)>);
+
+impl Index {
+    /// Creates an index of the provided common data elements, failing if the
+    /// documentation of any of them cannot be parsed.
+    pub fn try_new(descriptors: Vec<CdeDescriptor>) -> cde::Result<Self> {
+        descriptors
+            .into_iter()
+            .map(|descriptor| descriptor.entity().map(|entity| (descriptor, entity)))
+            .collect::<cde::Result<Vec<_>>>()
+            .map(Self)
+    }
+}
+
+impl std::fmt::Display for Index {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "### Common Data Elements\n")?;
+        writeln!(f, "| Formal Name | Rust Path | Kind |")?;
+        writeln!(f, "|:-- | -- | -- |")?;
+
+        for (descriptor, entity) in &self.0 {
+            writeln!(
+                f,
+                "| [`{}`]({}) | `ccdi_cde::{}` | {} |",
+                entity.standard_name(),
+                entity.standard_url(),
+                descriptor.path(),
+                descriptor.kind()
+            )?;
+        }
+
+        Ok(())
+    }
+}
+
 fn display_harmonized(
     f: &mut std::fmt::Formatter<'_>,
     harmonized: &description::Harmonized,
@@ -176,3 +214,57 @@ fn write_variant_members(
 
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Renders the wiki section for the harmonized sample field at `path`.
+    fn sample_section(path: &str) -> String {
+        description::harmonized::sample::get_field_descriptions()
+            .into_iter()
+            .find(|description| match description {
+                Description::Harmonized(harmonized) => harmonized.path() == path,
+                Description::Unharmonized(_) => false,
+            })
+            .map(|description| Section::from(description).to_string())
+            .unwrap()
+    }
+
+    #[test]
+    fn it_writes_a_table_of_permissible_values_for_enums() {
+        let section = sample_section("library_strategy");
+
+        assert!(section.contains(
+            "| Permissible Value | Description | VM Long Name | VM Public ID | Concept Code | \
+             Begin Date |"
+        ));
+        assert!(section.contains("| `AMPLICON` |"));
+        assert!(section.contains("| AMPLICON | 6273354 | C204813 | 05/11/2018 |"));
+
+        let section = sample_section("disease_phase");
+        assert!(section.contains("| `Post-Mortem` |"));
+        assert!(section.contains(" | 5236215 | C94193 |"));
+    }
+
+    #[test]
+    fn it_does_not_write_a_table_for_structs() {
+        assert!(!sample_section("age_at_diagnosis").contains("| Permissible Value |"));
+    }
+
+    #[test]
+    fn it_writes_an_index_of_every_registered_cde() {
+        let descriptors = cde::registry::all();
+        let count = descriptors.len();
+        let page = Index::try_new(descriptors).unwrap().to_string();
+
+        assert_eq!(
+            page.lines().filter(|line| line.starts_with("| [`")).count(),
+            count
+        );
+        assert!(page.contains("| Formal Name | Rust Path | Kind |"));
+        assert!(page.contains("| [`caDSR CDE 6343385 v1.00`]("));
+        assert!(page.contains("| `ccdi_cde::v1::subject::Sex` | enum |"));
+        assert!(page.contains("| `ccdi_cde::v1::subject::Name` | struct |"));
+    }
+}
@@ -0,0 +1,101 @@
+//! An example crate that exposes the validation within `ccdi-models` to
+//! JavaScript by compiling to WebAssembly.
+//!
+//! The validation performed here is exactly the validation that is performed
+//! when deserializing the models in Rust—including the checking of permissible
+//! values, the parsing of identifiers, and the patterns for ICD-O-3 codes—so
+//! tooling in the browser does not need to reimplement it.
+//!
+//! To build a package that can be imported from JavaScript, run the following
+//! from the `crates` directory:
+//!
+//! ```bash
+//! wasm-pack build --target web ccdi-wasm
+//! ```
+
+#![warn(missing_docs)]
+#![warn(rust_2018_idioms)]
+#![warn(rust_2021_compatibility)]
+#![warn(missing_debug_implementations)]
+#![deny(rustdoc::broken_intra_doc_links)]
+
+use wasm_bindgen::prelude::wasm_bindgen;
+
+use ccdi_models as models;
+
+/// A report of the validation of a metadata block.
+#[wasm_bindgen]
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct ValidationReport {
+    /// The errors encountered during validation.
+    errors: Vec<String>,
+}
+
+#[wasm_bindgen]
+impl ValidationReport {
+    /// Returns whether the metadata block was valid.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ccdi_wasm::validate_sample_metadata;
+    ///
+    /// assert!(validate_sample_metadata("{}").valid());
+    /// assert!(!validate_sample_metadata("[]").valid());
+    /// ```
+    #[wasm_bindgen(getter)]
+    pub fn valid(&self) -> bool {
+        self.errors.is_empty()
+    }
+
+    /// Gets the errors encountered during validation.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ccdi_wasm::validate_sample_metadata;
+    ///
+    /// assert!(validate_sample_metadata("{}").errors().is_empty());
+    /// assert_eq!(validate_sample_metadata("[]").errors().len(), 1);
+    /// ```
+    #[wasm_bindgen(getter)]
+    pub fn errors(&self) -> Vec<String> {
+        self.errors.clone()
+    }
+}
+
+/// Validates a JSON-serialized sample metadata block.
+///
+/// # Examples
+///
+/// ```
+/// use ccdi_wasm::validate_sample_metadata;
+///
+/// let report = validate_sample_metadata(r#"{"disease_phase":{"value":"Initial Diagnosis"}}"#);
+/// assert!(report.valid());
+///
+/// let report = validate_sample_metadata(r#"{"disease_phase":{"value":"Foo"}}"#);
+/// assert!(!report.valid());
+/// ```
+#[wasm_bindgen]
+pub fn validate_sample_metadata(json: &str) -> ValidationReport {
+    match serde_json::from_str::<models::sample::Metadata>(json) {
+        Ok(_) => ValidationReport::default(),
+        Err(err) => ValidationReport {
+            errors: vec![err.to_string()],
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_reports_the_reason_for_an_invalid_permissible_value() {
+        let report = validate_sample_metadata(r#"{"tissue_type":{"value":"Foo"}}"#);
+
+        assert!(!report.valid());
+        assert!(report.errors()[0].contains("unknown variant `Foo`"));
+    }
+}
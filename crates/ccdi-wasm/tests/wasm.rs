@@ -0,0 +1,67 @@
+//! Tests that run within a WebAssembly environment.
+//!
+//! Run these from the `crates` directory using `wasm-pack test --node ccdi-wasm`.
+
+#![cfg(target_arch = "wasm32")]
+
+use wasm_bindgen_test::wasm_bindgen_test;
+
+use ccdi_cde as cde;
+use ccdi_models as models;
+use ccdi_wasm as wasm;
+
+use models::metadata::field::unowned::sample::DiseasePhase;
+use models::sample::metadata::Builder;
+use models::sample::Metadata;
+
+fn sample_identifier() -> models::sample::Identifier {
+    let namespace = models::namespace::Identifier::new(
+        "example-organization"
+            .parse::<models::organization::Identifier>()
+            .unwrap(),
+        "ExampleNamespace"
+            .parse::<models::namespace::identifier::Name>()
+            .unwrap(),
+    );
+
+    models::sample::Identifier::new(namespace, "SampleName001")
+}
+
+#[wasm_bindgen_test]
+fn sample_metadata_round_trips() {
+    let metadata = Builder::default()
+        .disease_phase(DiseasePhase::new(
+            cde::v1::sample::DiseasePhase::InitialDiagnosis,
+            None,
+            None,
+            None,
+        ))
+        .build();
+
+    let json = serde_json::to_string(&metadata).unwrap();
+    assert!(wasm::validate_sample_metadata(&json).valid());
+
+    let roundtrip = serde_json::from_str::<Metadata>(&json).unwrap();
+    assert_eq!(roundtrip, metadata);
+}
+
+#[wasm_bindgen_test]
+fn random_sample_metadata_round_trips() {
+    // NOTE: generating random metadata exercises the source of entropy that is
+    // available within the WebAssembly environment.
+    let metadata = Metadata::random(sample_identifier());
+
+    let json = serde_json::to_string(&metadata).unwrap();
+    assert!(wasm::validate_sample_metadata(&json).valid());
+
+    let roundtrip = serde_json::from_str::<Metadata>(&json).unwrap();
+    assert_eq!(serde_json::to_string(&roundtrip).unwrap(), json);
+}
+
+#[wasm_bindgen_test]
+fn an_invalid_permissible_value_is_rejected() {
+    let report = wasm::validate_sample_metadata(r#"{"disease_phase":{"value":"Foo"}}"#);
+
+    assert!(!report.valid());
+    assert_eq!(report.errors().len(), 1);
+}